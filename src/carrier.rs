@@ -0,0 +1,67 @@
+use crate::prefix::longest_prefix;
+use crate::{NumberType, PhoneNumber};
+
+mod data;
+
+impl PhoneNumber {
+    /// The name of the carrier the range of this mobile phone number was allocated to, or of its
+    /// successor after a merger, according to the longest known prefix of the number.
+    ///
+    /// This is not necessarily the carrier serving the number: in most territories, subscribers
+    /// can keep their numbers when switching carriers. Telling the current carrier needs a query
+    /// to the number portability database of the territory.
+    ///
+    /// There is none for numbers which are not mobile, like fixed-line or toll-free numbers, nor
+    /// for the many ranges which are not known.
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+44 7712 345678".parse().unwrap();
+    /// assert_eq!(Some("O2"), phone.carrier());
+    /// let phone: PhoneNumber = "+49 151 23456789".parse().unwrap();
+    /// assert_eq!(Some("T-Mobile"), phone.carrier());
+    /// let landline: PhoneNumber = "+49 30 12345678".parse().unwrap();
+    /// assert_eq!(None, landline.carrier());
+    /// ```
+    pub fn carrier(&self) -> Option<&'static str> {
+        if !matches!(
+            self.number_type(),
+            NumberType::Mobile | NumberType::FixedLineOrMobile | NumberType::Pager
+        ) {
+            return None;
+        }
+        longest_prefix(&data::CARRIERS, self).map(|&(_, carrier)| carrier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prefix::tests::check_table;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn carriers_extend_the_lookup_table() {
+        check_table(&data::CARRIERS);
+    }
+
+    #[test]
+    fn find_carriers() {
+        for (phone, carrier) in [
+            (447712345678, Some("O2")),
+            (447700112345, Some("Nationwide Telephone")),
+            (4917612345678, Some("O2")),
+            (4917212345678, Some("Vodafone")),
+            (41791234567, Some("Swisscom")),
+            (393331234567, Some("TIM")),
+            (79031234567, Some("Beeline")),
+            (8613812345678, Some("China Mobile")),
+            (905321234567, Some("Turkcell")),
+            (4915912345678, None),
+            (4930123456, None),
+            (12125550123, None),
+        ] {
+            let phone = PhoneNumber::try_from(phone).unwrap();
+            assert_eq!(carrier, phone.carrier(), "{}", phone);
+        }
+    }
+}
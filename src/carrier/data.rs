@@ -0,0 +1,28837 @@
+// Generated by `cargo run -p metadata-gen` from `data/carriers.csv`. Do not edit.
+
+/// The carriers of the prefixes, sorted by prefix
+#[rustfmt::skip]
+pub(super) static CARRIERS: [(u64, &str); 28831] = [
+    (455, "telenor"),
+    (535, "etecsa"),
+    (536, "etecsa"),
+    (774, "Tele2"),
+    (778, "Darkhan Telecom"),
+    (792, "MegaFon"),
+    (794, "GLONASS"),
+    (796, "Beeline"),
+    (843, "Viettel"),
+    (847, "MobiFone"),
+    (2010, "Vodafone"),
+    (2011, "Etisalat"),
+    (2012, "Orange"),
+    (2015, "TE"),
+    (2135, "Ooredoo"),
+    (2136, "Mobilis"),
+    (2137, "Djezzy"),
+    (2162, "Ooredoo"),
+    (2164, "Tunisie Telecom"),
+    (2165, "Orange"),
+    (2169, "Tunisie Telecom"),
+    (2202, "Africell"),
+    (2203, "QCell"),
+    (2206, "Comium"),
+    (2207, "Africell"),
+    (2209, "Gamcel"),
+    (2235, "Atel"),
+    (2236, "Sotelma"),
+    (2237, "Orange"),
+    (2264, "Orange"),
+    (2316, "Lonestar Cell"),
+    (2317, "Orange"),
+    (2318, "Lonestar Cell"),
+    (2326, "Onlime"),
+    (2327, "Orange"),
+    (2328, "Africell"),
+    (2329, "Africell"),
+    (2353, "Moov"),
+    (2356, "Airtel"),
+    (2357, "Sotel"),
+    (2358, "Airtel"),
+    (2359, "Tigo"),
+    (2402, "GETESA"),
+    (2405, "Muni"),
+    (2413, "Libertis"),
+    (2414, "Airtel"),
+    (2415, "Moov"),
+    (2416, "Libertis"),
+    (2417, "Airtel"),
+    (2517, "Safaricom"),
+    (2519, "Ethio Telecom"),
+    (2529, "STG"),
+    (2537, "Evatis"),
+    (2576, "Viettel"),
+    (2653, "TNM"),
+    (2657, "Globally Advanced Integrated Networks Ltd"),
+    (2658, "TNM"),
+    (2659, "Airtel"),
+    (2665, "Vodacom Lesotho (Pty) Ltd"),
+    (2666, "Econet Ezi-Cel Lesotho"),
+    (2693, "Comores Telecom"),
+    (2694, "TELCO"),
+    (2719, "Telkom Mobile"),
+    (2750, "Rain"),
+    (2761, "Cell C"),
+    (2762, "Cell C"),
+    (2763, "MTN"),
+    (2767, "Telkom Mobile"),
+    (2768, "Telkom Mobile"),
+    (2771, "Vodacom"),
+    (2772, "Vodacom"),
+    (2773, "MTN"),
+    (2774, "Cell C"),
+    (2775, "Telkom Mobile"),
+    (2776, "Vodacom"),
+    (2778, "MTN"),
+    (2779, "Vodacom"),
+    (2782, "Vodacom"),
+    (2783, "MTN"),
+    (2784, "Cell C"),
+    (2917, "EriTel"),
+    (2985, "Vodafone"),
+    (2987, "Vodafone"),
+    (2992, "TELE Greenland A/S"),
+    (2994, "TELE Greenland A/S"),
+    (2995, "TELE Greenland A/S"),
+    (3094, "Vodafone"),
+    (3161, "KPN"),
+    (3247, "Proximus"),
+    (3249, "Orange"),
+    (3361, "SFR"),
+    (3362, "SFR"),
+    (3363, "Orange France"),
+    (3366, "Bouygues"),
+    (3367, "Orange France"),
+    (3368, "Orange France"),
+    (3376, "Bouygues"),
+    (3378, "Orange France"),
+    (3465, "Orange"),
+    (3467, "Vodafone"),
+    (3511, "NOS"),
+    (3620, "Yettel Hungary"),
+    (3630, "Magyar Telekom"),
+    (3670, "One"),
+    (3745, "Ucom"),
+    (3747, "VivaCell-MTS"),
+    (3763, "Andorra Telecom"),
+    (3765, "Andorra Telecom"),
+    (3766, "Andorra Telecom"),
+    (3773, "Monaco Telecom"),
+    (3774, "Monaco Telecom"),
+    (3776, "Monaco Telecom"),
+    (3777, "Monaco Telecom"),
+    (3932, "WIND"),
+    (3933, "TIM"),
+    (3934, "Vodafone"),
+    (3936, "TIM"),
+    (3938, "WIND"),
+    (3939, "3 Italia"),
+    (4060, "Telekom"),
+    (4062, "Vodafone"),
+    (4063, "Digi Mobil"),
+    (4072, "Vodafone"),
+    (4073, "Vodafone"),
+    (4074, "Orange"),
+    (4075, "Orange"),
+    (4076, "Telekom"),
+    (4079, "Vodafone"),
+    (4168, "Swisscom"),
+    (4169, "Swisscom"),
+    (4172, "Swisscom"),
+    (4173, "Swisscom"),
+    (4521, "tdc"),
+    (4522, "telenor"),
+    (4523, "Nuuday"),
+    (4524, "tdc"),
+    (4525, "telenor"),
+    (4526, "telia"),
+    (4527, "telia"),
+    (4528, "telia"),
+    (4529, "tdc"),
+    (4530, "tdc"),
+    (4531, "3"),
+    (4532, "telenor"),
+    (4533, "telenor"),
+    (4535, "telenor"),
+    (4536, "telenor"),
+    (4538, "telenor"),
+    (4539, "telenor"),
+    (4541, "telenor"),
+    (4543, "telenor"),
+    (4544, "telenor"),
+    (4545, "telenor"),
+    (4547, "telenor"),
+    (4548, "telenor"),
+    (4551, "tdc"),
+    (4561, "tdc"),
+    (4562, "telenor"),
+    (4563, "telenor"),
+    (4565, "telenor"),
+    (4566, "telenor"),
+    (4572, "telenor"),
+    (4573, "telenor"),
+    (4574, "telenor"),
+    (4575, "telenor"),
+    (4576, "telenor"),
+    (4577, "telenor"),
+    (4578, "telenor"),
+    (4579, "telenor"),
+    (4582, "telenor"),
+    (4586, "telenor"),
+    (4587, "telenor"),
+    (4588, "telenor"),
+    (4589, "telenor"),
+    (4592, "telenor"),
+    (4596, "telenor"),
+    (4598, "telenor"),
+    (4599, "telenor"),
+    (4741, "telenor norge"),
+    (4745, "telia"),
+    (4790, "telenor norge"),
+    (4791, "telenor norge"),
+    (4792, "telia"),
+    (4795, "telenor norge"),
+    (4797, "telenor norge"),
+    (4850, "Orange"),
+    (4851, "Orange"),
+    (4853, "Play"),
+    (4879, "Play"),
+    (5005, "Sure South Atlantic Limited"),
+    (5006, "Sure South Atlantic Limited"),
+    (5028, "Tigo"),
+    (5035, "Digicel"),
+    (5043, "Sercom (Claro)"),
+    (5047, "HONDUTEL"),
+    (5048, "Digicel Honduras"),
+    (5049, "Celtel (Tigo)"),
+    (5055, "Claro"),
+    (5056, "CooTel"),
+    (5057, "Movistar"),
+    (5063, "Kolbi ICE"),
+    (5066, "Movistar"),
+    (5093, "Digicel"),
+    (5095, "Natcom"),
+    (5190, "Entel"),
+    (5192, "Claro"),
+    (5195, "Movistar"),
+    (5198, "Claro"),
+    (5791, "Movistar"),
+    (5794, "Hablame"),
+    (5916, "Tigo"),
+    (5917, "Tigo"),
+    (5976, "Telesur"),
+    (6012, "Maxis"),
+    (6016, "DiGi"),
+    (6019, "Celcom"),
+    (6145, "Telstra"),
+    (6146, "Telstra"),
+    (6149, "Telstra"),
+    (6389, "Dito"),
+    (6392, "Smart"),
+    (6393, "Smart"),
+    (6394, "Smart"),
+    (6395, "Globe"),
+    (6396, "Smart"),
+    (6421, "Vodafone"),
+    (6422, "2degrees"),
+    (6427, "Spark"),
+    (6429, "Vodafone"),
+    (6581, "StarHub"),
+    (6582, "StarHub"),
+    (6664, "Penguin SIM"),
+    (6666, "DTAC"),
+    (6667, "AIS"),
+    (6683, "True Move"),
+    (6684, "AIS"),
+    (6692, "AIS"),
+    (6693, "AIS"),
+    (6694, "DTAC"),
+    (6695, "True Move"),
+    (6696, "True Move"),
+    (6698, "AIS"),
+    (6742, "NEOTEL"),
+    (6746, "Digicel"),
+    (6747, "NEOTEL"),
+    (6748, "NEOTEL"),
+    (6749, "NEOTEL"),
+    (6757, "Digicel"),
+    (6769, "Digicel"),
+    (6777, "Solomon Telekom"),
+    (6778, "BMobile"),
+    (6779, "Smile"),
+    (6785, "Digicel"),
+    (6787, "Telecom Vanuatu Ltd"),
+    (6788, "Wan Tok"),
+    (6792, "Vodafone"),
+    (6794, "Vodafone"),
+    (6797, "Digicel"),
+    (6799, "Vodafone"),
+    (6817, "Service des Postes et Télécommunications"),
+    (6825, "Bluesky"),
+    (6827, "Bluesky"),
+    (6828, "Bluesky"),
+    (6835, "Telecom Niue"),
+    (6836, "Telecom Niue"),
+    (6838, "Telecom Niue"),
+    (6875, "OPT-NC"),
+    (6877, "OPT-NC"),
+    (6879, "OPT-NC"),
+    (6887, "Tuvalu Telecom"),
+    (6889, "Tuvalu Telecom"),
+    (6907, "Teletok telecommunications"),
+    (6918, "FSMTC"),
+    (6923, "National Telecommunications Authority"),
+    (6924, "National Telecommunications Authority"),
+    (7700, "Altel"),
+    (7701, "Kcell/Activ"),
+    (7702, "Kcell/Activ"),
+    (7705, "Beeline"),
+    (7706, "Beeline"),
+    (7707, "Tele2"),
+    (7708, "Altel"),
+    (7760, "Kulan"),
+    (7761, "Tele2"),
+    (7762, "Nursat"),
+    (7763, "Arna"),
+    (7764, "2Day Telecom"),
+    (7771, "Beeline"),
+    (7775, "Kcell/Activ"),
+    (7776, "Beeline"),
+    (7777, "Beeline"),
+    (7778, "Kcell/Activ"),
+    (7903, "Beeline"),
+    (7905, "Beeline"),
+    (7906, "Beeline"),
+    (7908, "Tele2"),
+    (7909, "Beeline"),
+    (7931, "MegaFon"),
+    (7937, "MegaFon"),
+    (7950, "Tele2"),
+    (7952, "Tele2"),
+    (7953, "Tele2"),
+    (7982, "MTS"),
+    (7987, "MTS"),
+    (7999, "MegaFon"),
+    (8211, "SKTellink"),
+    (8216, "KT"),
+    (8217, "SKTellink"),
+    (8218, "KT"),
+    (8219, "LG U+"),
+    (8430, "MobiFone"),
+    (8451, "MobiFone"),
+    (8452, "Vietnamobile"),
+    (8455, "Reddi"),
+    (8456, "Vietnamobile"),
+    (8458, "Vietnamobile"),
+    (8459, "G-Mobile"),
+    (8481, "Vinaphone"),
+    (8482, "Vinaphone"),
+    (8483, "Vinaphone"),
+    (8484, "Vinaphone"),
+    (8485, "Vinaphone"),
+    (8486, "Viettel"),
+    (8487, "Vinaphone"),
+    (8488, "Vinaphone"),
+    (8489, "MobiFone"),
+    (8490, "MobiFone"),
+    (8491, "Vinaphone"),
+    (8492, "Vietnamobile"),
+    (8493, "MobiFone"),
+    (8494, "Vinaphone"),
+    (8496, "Viettel"),
+    (8497, "Viettel"),
+    (8498, "Viettel"),
+    (8615, "China Mobile"),
+    (8810, "ico satellite"),
+    (8811, "ico satellite"),
+    (8812, "ellipso satellite"),
+    (8813, "ellipso satellite"),
+    (8816, "iridium satellite"),
+    (8817, "iridium satellite"),
+    (8818, "globalstar"),
+    (8819, "globalstar"),
+    (9050, "Turk Telekom"),
+    (9053, "Turkcell"),
+    (9054, "Vodafone"),
+    (9055, "Turk Telekom"),
+    (9192, "Tata Docomo"),
+    (9193, "Reliance Jio"),
+    (9194, "BSNL MOBILE"),
+    (9230, "Jazz"),
+    (9231, "Zong"),
+    (9232, "Jazz"),
+    (9233, "Ufone"),
+    (9234, "Telenor"),
+    (9235, "Special Communications Organization"),
+    (9236, "Instaphone"),
+    (9237, "Zong"),
+    (9370, "AWCC"),
+    (9371, "AWCC"),
+    (9372, "Roshan"),
+    (9373, "Etisalat"),
+    (9375, "Afghan Telecom"),
+    (9376, "MTN"),
+    (9377, "MTN"),
+    (9378, "Etisalat"),
+    (9379, "Roshan"),
+    (9470, "Mobitel"),
+    (9471, "Mobitel"),
+    (9472, "Etisalat"),
+    (9474, "Dialog"),
+    (9475, "Airtel"),
+    (9476, "Dialog"),
+    (9477, "Dialog"),
+    (9478, "Hutch"),
+    (9592, "MPT"),
+    (9593, "MPT"),
+    (9595, "MPT"),
+    (9596, "Mytel"),
+    (9597, "Telenor"),
+    (9607, "Dhiraagu"),
+    (9609, "Ooredoo"),
+    (9624, "Orange"),
+    (9654, "Virgin mobile"),
+    (9655, "VIVA"),
+    (9656, "Ooredoo"),
+    (9659, "Zain"),
+    (9681, "Ooredoo"),
+    (9751, "B-Mobile of Bhutan Telecom"),
+    (9890, "Irancell"),
+    (9891, "IR-MCI"),
+    (9892, "Rightel"),
+    (9893, "Irancell"),
+    (9920, "ZET-MOBILE"),
+    (9926, "ZET-MOBILE"),
+    (9937, "TM-Cell"),
+    (9946, "Naxtel"),
+    (9947, "Nar Mobile"),
+    (9982, "Beeline"),
+    (9983, "HUMANS"),
+    (12428, "aliv"),
+    (12468, "Digicel"),
+    (14412, "Cellular One"),
+    (14413, "Mobility"),
+    (14417, "Cellular One"),
+    (14418, "Cellular One"),
+    (16493, "Digicel"),
+    (16847, "ASTCA"),
+    (17583, "Cable & Wireless"),
+    (17588, "Digicel"),
+    (17673, "Digicel"),
+    (17676, "Digicel"),
+    (18093, "Claro"),
+    (18098, "Orange"),
+    (18683, "Digicel"),
+    (18687, "bmobile"),
+    (21112, "Sudatel Group"),
+    (21191, "Zain"),
+    (21192, "MTN"),
+    (21195, "Network of the World"),
+    (21197, "Gemtel"),
+    (21198, "Digitel"),
+    (21199, "MTN"),
+    (21260, "Inwi"),
+    (21261, "Maroc Telecom"),
+    (21265, "Maroc Telecom"),
+    (21267, "Maroc Telecom"),
+    (21270, "Inwi"),
+    (21271, "Inwi"),
+    (21272, "Inwi"),
+    (21275, "Maroc Telecom"),
+    (21276, "Maroc Telecom"),
+    (21277, "Orange"),
+    (21278, "Orange"),
+    (21645, "Watany Ettisalat"),
+    (21646, "Ooredoo"),
+    (21648, "Ooredoo"),
+    (21891, "Al-Madar"),
+    (21892, "Libyana"),
+    (21893, "Al-Madar"),
+    (21894, "Libyana"),
+    (21895, "Libya Telecom & Technology"),
+    (21896, "Libya Telecom & Technology"),
+    (22040, "Africell"),
+    (22041, "Africell"),
+    (22045, "Africell"),
+    (22050, "QCell"),
+    (22051, "QCell"),
+    (22052, "QCell"),
+    (22053, "QCell"),
+    (22054, "QCell"),
+    (22058, "QCell"),
+    (22059, "QCell"),
+    (22086, "Comium"),
+    (22087, "Comium"),
+    (22170, "Expresso"),
+    (22171, "Orange"),
+    (22172, "HAYO"),
+    (22175, "Promobile"),
+    (22176, "Free"),
+    (22177, "Orange"),
+    (22178, "Orange"),
+    (22179, "ADIE"),
+    (22220, "Chinguitel"),
+    (22221, "Chinguitel"),
+    (22222, "Chinguitel"),
+    (22223, "Chinguitel"),
+    (22224, "Chinguitel"),
+    (22226, "Chinguitel"),
+    (22227, "Chinguitel"),
+    (22228, "Chinguitel"),
+    (22229, "Chinguitel"),
+    (22230, "Mattel"),
+    (22231, "Mattel"),
+    (22232, "Mattel"),
+    (22233, "Mattel"),
+    (22234, "Mattel"),
+    (22236, "Mattel"),
+    (22237, "Mattel"),
+    (22238, "Mattel"),
+    (22239, "Mattel"),
+    (22240, "Mauritel"),
+    (22241, "Mauritel"),
+    (22242, "Mauritel"),
+    (22243, "Mauritel"),
+    (22244, "Mauritel"),
+    (22246, "Mauritel"),
+    (22247, "Mauritel"),
+    (22248, "Mauritel"),
+    (22249, "Mauritel"),
+    (22382, "Orange"),
+    (22383, "Orange"),
+    (22384, "Orange"),
+    (22385, "Orange"),
+    (22389, "Sotelma"),
+    (22390, "Orange"),
+    (22391, "Orange"),
+    (22392, "Orange"),
+    (22393, "Orange"),
+    (22394, "Orange"),
+    (22395, "Sotelma"),
+    (22396, "Sotelma"),
+    (22397, "Sotelma"),
+    (22398, "Sotelma"),
+    (22399, "Sotelma"),
+    (22460, "Sotelgui"),
+    (22461, "Orange"),
+    (22462, "Orange"),
+    (22463, "Intercel"),
+    (22465, "Cellcom"),
+    (22466, "Areeba"),
+    (22501, "Moov"),
+    (22505, "MTN"),
+    (22507, "Orange"),
+    (22601, "Onatel"),
+    (22602, "Onatel"),
+    (22603, "Onatel"),
+    (22604, "Orange"),
+    (22605, "Orange"),
+    (22606, "Orange"),
+    (22607, "Orange"),
+    (22650, "Onatel"),
+    (22651, "Onatel"),
+    (22652, "Onatel"),
+    (22653, "Onatel"),
+    (22654, "Orange"),
+    (22655, "Orange"),
+    (22656, "Orange"),
+    (22657, "Orange"),
+    (22658, "Telecel Faso"),
+    (22660, "Onatel"),
+    (22661, "Onatel"),
+    (22662, "Onatel"),
+    (22663, "Onatel"),
+    (22664, "Orange"),
+    (22665, "Orange"),
+    (22666, "Orange"),
+    (22667, "Orange"),
+    (22668, "Telecel Faso"),
+    (22669, "Telecel Faso"),
+    (22670, "Onatel"),
+    (22671, "Onatel"),
+    (22672, "Onatel"),
+    (22673, "Onatel"),
+    (22674, "Orange"),
+    (22675, "Orange"),
+    (22676, "Orange"),
+    (22677, "Orange"),
+    (22678, "Telecel Faso"),
+    (22679, "Telecel Faso"),
+    (22723, "Orange"),
+    (22770, "Orange"),
+    (22774, "Moov"),
+    (22777, "Airtel"),
+    (22780, "Orange"),
+    (22781, "Orange"),
+    (22782, "Orange"),
+    (22783, "Niger Telecoms"),
+    (22784, "Moov"),
+    (22785, "Moov"),
+    (22786, "Airtel"),
+    (22787, "Airtel"),
+    (22788, "Airtel"),
+    (22789, "Airtel"),
+    (22790, "Orange"),
+    (22791, "Orange"),
+    (22792, "Orange"),
+    (22793, "Niger Telecoms"),
+    (22794, "Moov"),
+    (22795, "Moov"),
+    (22796, "Airtel"),
+    (22797, "Airtel"),
+    (22798, "Airtel"),
+    (22799, "Airtel"),
+    (22870, "Togo Telecom"),
+    (22871, "Togo Telecom"),
+    (22872, "Togo Telecom"),
+    (22879, "Moov"),
+    (22890, "Togo Telecom"),
+    (22891, "Togo Telecom"),
+    (22892, "Togo Telecom"),
+    (22893, "Togo Telecom"),
+    (22896, "Moov"),
+    (22897, "TOGOCEL"),
+    (22898, "Moov"),
+    (22899, "Moov"),
+    (23054, "Emtel"),
+    (23055, "Emtel"),
+    (23057, "Cellplus"),
+    (23142, "Connect"),
+    (23225, "Sierratel"),
+    (23230, "Africell"),
+    (23231, "QCELL"),
+    (23232, "QCELL"),
+    (23233, "Africell"),
+    (23234, "QCELL"),
+    (23235, "IPTEL"),
+    (23277, "Africell"),
+    (23320, "Telecel"),
+    (23323, "airteltiGO"),
+    (23324, "MTN"),
+    (23325, "MTN"),
+    (23326, "airteltiGO"),
+    (23327, "airteltiGO"),
+    (23328, "Expresso"),
+    (23329, "National Security"),
+    (23350, "Telecel"),
+    (23353, "MTN"),
+    (23354, "MTN"),
+    (23355, "MTN"),
+    (23356, "airteltiGO"),
+    (23357, "airteltiGO"),
+    (23359, "MTN"),
+    (23670, "A-Cell"),
+    (23672, "Orange"),
+    (23673, "Orange"),
+    (23674, "Orange"),
+    (23675, "Telecel"),
+    (23676, "Telecel"),
+    (23677, "Nationlink"),
+    (23724, "Camtel"),
+    (23762, "Camtel"),
+    (23764, "Orange"),
+    (23766, "NEXTTEL"),
+    (23767, "MTN Cameroon"),
+    (23769, "Orange"),
+    (23836, "CVMOVEL"),
+    (23851, "T+"),
+    (23852, "T+"),
+    (23853, "T+"),
+    (23858, "CVMOVEL"),
+    (23859, "CVMOVEL"),
+    (23891, "T+"),
+    (23892, "T+"),
+    (23893, "T+"),
+    (23895, "CVMOVEL"),
+    (23897, "CVMOVEL"),
+    (23898, "CVMOVEL"),
+    (23899, "CVMOVEL"),
+    (23990, "Unitel"),
+    (23998, "CSTmovel"),
+    (23999, "CSTmovel"),
+    (24104, "Airtel"),
+    (24105, "Moov"),
+    (24106, "Libertis"),
+    (24107, "Airtel"),
+    (24120, "Libertis"),
+    (24121, "Libertis"),
+    (24122, "Libertis"),
+    (24123, "Libertis"),
+    (24124, "Libertis"),
+    (24125, "Libertis"),
+    (24126, "Libertis"),
+    (24127, "Libertis"),
+    (24165, "Moov"),
+    (24201, "Equateur Telecom"),
+    (24202, "Congo telecom"),
+    (24204, "Warid"),
+    (24205, "Airtel"),
+    (24206, "MTN"),
+    (24380, "Orange"),
+    (24381, "Vodacom"),
+    (24382, "Vodacom"),
+    (24383, "Vodacom"),
+    (24384, "Orange"),
+    (24385, "Orange"),
+    (24386, "Vodacom"),
+    (24388, "Yozma Timeturns sprl -YTT"),
+    (24389, "Orange"),
+    (24390, "Africell"),
+    (24391, "Africell"),
+    (24397, "Airtel"),
+    (24398, "Airtel"),
+    (24399, "Airtel"),
+    (24491, "Movicel"),
+    (24492, "UNITEL"),
+    (24493, "UNITEL"),
+    (24494, "UNITEL"),
+    (24495, "Africell"),
+    (24496, "Africell"),
+    (24497, "UNITEL"),
+    (24499, "Movicel"),
+    (24595, "Orange"),
+    (24596, "Spacetel"),
+    (24597, "Guinetel"),
+    (24638, "Sure Ltd"),
+    (24741, "Sure South Atlantic"),
+    (24742, "Sure South Atlantic"),
+    (24743, "Sure South Atlantic"),
+    (24745, "Sure South Atlantic"),
+    (24746, "Sure South Atlantic"),
+    (24747, "Sure South Atlantic"),
+    (24748, "Sure South Atlantic"),
+    (24821, "Intelvision"),
+    (24822, "Intelvision"),
+    (24825, "CWS"),
+    (24826, "CWS"),
+    (24827, "Airtel"),
+    (24828, "Airtel"),
+    (24910, "Sudatel"),
+    (24911, "Sudatel"),
+    (24912, "Sudatel"),
+    (24990, "Zain"),
+    (24991, "Zain"),
+    (24992, "MTN"),
+    (24993, "MTN"),
+    (24995, "Network of The World Ltd"),
+    (24996, "Zain"),
+    (24999, "MTN"),
+    (25072, "TIGO"),
+    (25073, "Airtel"),
+    (25077, "KtRN"),
+    (25078, "MTN"),
+    (25079, "MTN"),
+    (25224, "Telesom"),
+    (25228, "Nationlink"),
+    (25235, "AirSom"),
+    (25239, "AirSom"),
+    (25248, "AirSom"),
+    (25249, "AirSom"),
+    (25260, "Golis Telecom"),
+    (25261, "Hormuud"),
+    (25262, "Somtel"),
+    (25263, "Telesom"),
+    (25264, "Somali Networks"),
+    (25265, "Somtel"),
+    (25266, "Somtel"),
+    (25267, "Nationlink"),
+    (25268, "SomNet"),
+    (25269, "Nationlink"),
+    (25270, "Golis Telecom"),
+    (25271, "Amtel"),
+    (25272, "Golis Telecom"),
+    (25276, "Somtel"),
+    (25279, "Somtel"),
+    (25280, "Somali Networks"),
+    (25288, "Somali Networks"),
+    (25290, "Golis Telecom"),
+    (25410, "Airtel"),
+    (25411, "Safaricom"),
+    (25413, "NRG Media Limited"),
+    (25414, "Safaricom"),
+    (25470, "Safaricom"),
+    (25471, "Safaricom"),
+    (25472, "Safaricom"),
+    (25473, "Airtel"),
+    (25474, "Safaricom"),
+    (25475, "Airtel"),
+    (25477, "Telkom"),
+    (25478, "Airtel"),
+    (25479, "Safaricom"),
+    (25561, "Viettel"),
+    (25562, "Viettel"),
+    (25563, "Viettel"),
+    (25565, "Yas"),
+    (25566, "SMILE"),
+    (25567, "Yas"),
+    (25568, "Airtel"),
+    (25569, "Airtel"),
+    (25571, "Yas"),
+    (25573, "Tanzania Telecom"),
+    (25574, "Vodacom"),
+    (25575, "Vodacom"),
+    (25576, "Vodacom"),
+    (25577, "Yas"),
+    (25578, "Airtel"),
+    (25579, "Vodacom"),
+    (25670, "Airtel"),
+    (25671, "UTL"),
+    (25674, "Airtel"),
+    (25675, "Airtel"),
+    (25676, "MTN"),
+    (25677, "MTN"),
+    (25678, "MTN"),
+    (25729, "Leo"),
+    (25771, "Leo"),
+    (25772, "Leo"),
+    (25775, "Smart Mobile"),
+    (25776, "Leo"),
+    (25777, "Onatel"),
+    (25778, "Smart Mobile"),
+    (25779, "Leo"),
+    (25882, "mcel"),
+    (25883, "mcel"),
+    (25884, "Vodacom"),
+    (25885, "Vodacom"),
+    (25886, "Movitel"),
+    (25887, "Movitel"),
+    (25889, "GMPCS"),
+    (26055, "ZAMTEL"),
+    (26056, "MTN"),
+    (26057, "Airtel"),
+    (26058, "Beeline Telecoms"),
+    (26075, "ZAMTEL"),
+    (26076, "MTN"),
+    (26077, "Airtel"),
+    (26078, "Beeline Telecoms"),
+    (26095, "ZAMTEL"),
+    (26096, "MTN"),
+    (26097, "Airtel"),
+    (26098, "Beeline Telecoms"),
+    (26132, "Orange"),
+    (26133, "Airtel"),
+    (26134, "Yas"),
+    (26135, "Airtel"),
+    (26136, "Yas"),
+    (26137, "Orange"),
+    (26138, "Yas"),
+    (26139, "Blueline"),
+    (26371, "Net*One"),
+    (26372, "Net*One"),
+    (26373, "Telecel"),
+    (26377, "Econet"),
+    (26378, "Econet"),
+    (26460, "Telecom Namibia"),
+    (26481, "MTC"),
+    (26482, "Telecom Namibia"),
+    (26484, "MTN"),
+    (26485, "TN Mobile"),
+    (26511, "Malawi Telecom-munications Ltd (MTL)"),
+    (26732, "Mascom"),
+    (26771, "Mascom"),
+    (26772, "Orange"),
+    (26773, "BTC Mobile"),
+    (26774, "Mascom"),
+    (26778, "Orange"),
+    (26875, "Eswatini Mobile"),
+    (26876, "Swazi MTN"),
+    (26877, "SPTC"),
+    (26878, "Swazi MTN"),
+    (26879, "Eswatini Mobile"),
+    (27600, "Liquid Intelligent Technology"),
+    (27601, "Telkom Mobile"),
+    (27602, "Telkom Mobile"),
+    (27603, "MTN"),
+    (27604, "MTN"),
+    (27605, "MTN"),
+    (27606, "Vodacom"),
+    (27607, "Vodacom"),
+    (27608, "Vodacom"),
+    (27609, "Vodacom"),
+    (27614, "Telkom Mobile"),
+    (27636, "Vodacom"),
+    (27637, "Vodacom"),
+    (27640, "MTN"),
+    (27641, "Cell C"),
+    (27642, "Cell C"),
+    (27643, "Cell C"),
+    (27644, "Cell C"),
+    (27645, "Cell C"),
+    (27646, "Vodacom"),
+    (27647, "Vodacom"),
+    (27648, "Vodacom"),
+    (27649, "Vodacom"),
+    (27650, "Cell C"),
+    (27651, "Cell C"),
+    (27652, "Cell C"),
+    (27653, "Cell C"),
+    (27654, "Cell C"),
+    (27655, "MTN"),
+    (27656, "MTN"),
+    (27657, "MTN"),
+    (27658, "Telkom Mobile"),
+    (27659, "Telkom Mobile"),
+    (27660, "Vodacom"),
+    (27661, "Vodacom"),
+    (27662, "Vodacom"),
+    (27663, "Vodacom"),
+    (27664, "Vodacom"),
+    (27665, "Vodacom"),
+    (27673, "Vodacom"),
+    (27674, "Vodacom"),
+    (27675, "Vodacom"),
+    (27686, "MTN"),
+    (27687, "MTN"),
+    (27688, "MTN"),
+    (27689, "MTN"),
+    (27710, "MTN"),
+    (27717, "MTN"),
+    (27718, "MTN"),
+    (27719, "MTN"),
+    (27810, "MTN"),
+    (27811, "Telkom Mobile"),
+    (27812, "Telkom Mobile"),
+    (27813, "Telkom Mobile"),
+    (27814, "Telkom Mobile"),
+    (27815, "Telkom Mobile"),
+    (27816, "WBS Mobile"),
+    (27817, "Telkom Mobile"),
+    (27818, "Vodacom"),
+    (29051, "Sure South Atlantic Ltd"),
+    (29052, "Sure South Atlantic Ltd"),
+    (29053, "Sure South Atlantic Ltd"),
+    (29054, "Sure South Atlantic Ltd"),
+    (29055, "Sure South Atlantic Ltd"),
+    (29056, "Sure South Atlantic Ltd"),
+    (29057, "Sure South Atlantic Ltd"),
+    (29058, "Sure South Atlantic Ltd"),
+    (29061, "Sure South Atlantic Ltd"),
+    (29062, "Sure South Atlantic Ltd"),
+    (29063, "Sure South Atlantic Ltd"),
+    (29064, "Sure South Atlantic Ltd"),
+    (29065, "Sure South Atlantic Ltd"),
+    (29066, "Sure South Atlantic Ltd"),
+    (29067, "Sure South Atlantic Ltd"),
+    (29068, "Sure South Atlantic Ltd"),
+    (29117, "EriTel"),
+    (29729, "Digicel"),
+    (29756, "SETAR"),
+    (29759, "SETAR"),
+    (29760, "SETAR"),
+    (29762, "MIO Wireless"),
+    (29763, "MIO Wireless"),
+    (29764, "Digicel"),
+    (29766, "SETAR"),
+    (29773, "Digicel"),
+    (29774, "Digicel"),
+    (29777, "SETAR"),
+    (29821, "Faroese Telecom"),
+    (29822, "Faroese Telecom"),
+    (29823, "Faroese Telecom"),
+    (29824, "Faroese Telecom"),
+    (29825, "Faroese Telecom"),
+    (29826, "Faroese Telecom"),
+    (29827, "Faroese Telecom"),
+    (29828, "Faroese Telecom"),
+    (29829, "Faroese Telecom"),
+    (29878, "Faroese Telecom"),
+    (29879, "Faroese Telecom"),
+    (29891, "Faroese Telecom"),
+    (29896, "Faroese Telecom"),
+    (30693, "Wind"),
+    (30694, "Vodafone"),
+    (30697, "Cosmote"),
+    (30698, "Cosmote"),
+    (31611, "Vodafone Libertel B.V."),
+    (31614, "T-Mobile"),
+    (31615, "Vodafone Libertel B.V."),
+    (31618, "T-Mobile Thuis"),
+    (31620, "KPN"),
+    (31621, "Vodafone Libertel B.V."),
+    (31622, "KPN"),
+    (31623, "KPN"),
+    (31624, "T-Mobile"),
+    (31625, "Vodafone Libertel B.V."),
+    (31626, "KPN"),
+    (31627, "Vodafone Libertel B.V."),
+    (31628, "T-Mobile Thuis"),
+    (31629, "Vodafone Libertel B.V."),
+    (31630, "KPN"),
+    (31631, "Vodafone Libertel B.V."),
+    (31633, "KPN"),
+    (31634, "T-Mobile"),
+    (31636, "Tele2"),
+    (31637, "Teleena (MVNE)"),
+    (31638, "T-Mobile Thuis"),
+    (31639, "T-Mobile Thuis"),
+    (31640, "Tele2"),
+    (31641, "T-Mobile"),
+    (31642, "T-Mobile"),
+    (31643, "T-Mobile"),
+    (31644, "KPN"),
+    (31645, "Telfort"),
+    (31646, "Vodafone Libertel B.V."),
+    (31647, "KPN"),
+    (31648, "T-Mobile Thuis"),
+    (31649, "KPN"),
+    (31650, "Vodafone Libertel B.V."),
+    (31651, "KPN"),
+    (31652, "Vodafone Libertel B.V."),
+    (31653, "KPN"),
+    (31654, "Vodafone Libertel B.V."),
+    (31655, "Vodafone Libertel B.V."),
+    (31656, "T-Mobile"),
+    (31657, "KPN"),
+    (31658, "Lebara"),
+    (31659, "Vectone Mobile/Delight Mobile"),
+    (31680, "Vodafone Libertel B.V."),
+    (31681, "T-Mobile"),
+    (31682, "KPN"),
+    (31683, "KPN"),
+    (31684, "Lycamobile"),
+    (31685, "Lycamobile"),
+    (31686, "Lycamobile"),
+    (31687, "Lycamobile"),
+    (32455, "VOO"),
+    (32456, "Mobile Vikings/JIM Mobile"),
+    (32458, "Citymesh"),
+    (32460, "Proximus"),
+    (32465, "Lycamobile"),
+    (32468, "Telenet"),
+    (32469, "Telenet"),
+    (32483, "Telenet"),
+    (32484, "Telenet"),
+    (32485, "Telenet"),
+    (32486, "Telenet"),
+    (32487, "Telenet"),
+    (32488, "Telenet"),
+    (32489, "Telenet"),
+    (33601, "SFR"),
+    (33602, "SFR"),
+    (33603, "SFR"),
+    (33607, "Orange France"),
+    (33608, "Orange France"),
+    (33609, "SFR"),
+    (33634, "SFR"),
+    (33635, "SFR"),
+    (33636, "Euroinformation Telecom"),
+    (33640, "Orange France"),
+    (33642, "Orange France"),
+    (33643, "Orange France"),
+    (33645, "Orange France"),
+    (33646, "SFR"),
+    (33647, "Orange France"),
+    (33648, "Orange France"),
+    (33649, "Orange France"),
+    (33650, "Bouygues"),
+    (33651, "Free Mobile"),
+    (33652, "Free Mobile"),
+    (33654, "Orange France"),
+    (33655, "SFR"),
+    (33656, "e*Message"),
+    (33657, "e*Message"),
+    (33658, "Bouygues"),
+    (33659, "Bouygues"),
+    (33695, "Free Mobile"),
+    (33698, "Bouygues"),
+    (33699, "Bouygues"),
+    (33751, "Lycamobile"),
+    (33753, "Lycamobile"),
+    (33755, "Lebara France Limited"),
+    (33758, "Lycamobile"),
+    (33759, "Vectone mobile"),
+    (33766, "Free Mobile"),
+    (33767, "Free Mobile"),
+    (33768, "Free Mobile"),
+    (33769, "Free Mobile"),
+    (33772, "Orange France"),
+    (33773, "Syma mobile"),
+    (33774, "Syma mobile"),
+    (33776, "SFR"),
+    (33777, "SFR"),
+    (33778, "SFR"),
+    (33779, "SFR"),
+    (33780, "Afone"),
+    (33781, "Free Mobile"),
+    (33782, "Free Mobile"),
+    (33783, "Free Mobile"),
+    (34600, "Vodafone"),
+    (34601, "Vodafone"),
+    (34605, "Orange"),
+    (34606, "Movistar"),
+    (34607, "Vodafone"),
+    (34608, "Movistar"),
+    (34609, "Movistar"),
+    (34610, "Vodafone"),
+    (34611, "Republica Movil"),
+    (34612, "Syma"),
+    (34613, "Yoigo"),
+    (34614, "DigiMobil"),
+    (34615, "Orange"),
+    (34616, "Movistar"),
+    (34617, "Vodafone"),
+    (34618, "Movistar"),
+    (34619, "Movistar"),
+    (34620, "Movistar"),
+    (34622, "Yoigo"),
+    (34624, "DigiMobil"),
+    (34625, "Orange"),
+    (34626, "Movistar"),
+    (34627, "Vodafone"),
+    (34628, "Movistar"),
+    (34629, "Movistar"),
+    (34630, "Movistar"),
+    (34631, "Lycamobile"),
+    (34632, "Lycamobile"),
+    (34633, "Yoigo"),
+    (34634, "Vodafone"),
+    (34635, "Orange"),
+    (34636, "Movistar"),
+    (34637, "Vodafone"),
+    (34638, "Movistar"),
+    (34639, "Movistar"),
+    (34640, "Orange"),
+    (34641, "DigiMobil"),
+    (34642, "DigiMobil"),
+    (34643, "DigiMobil"),
+    (34644, "Orange"),
+    (34645, "Orange"),
+    (34646, "Movistar"),
+    (34647, "Vodafone"),
+    (34648, "Movistar"),
+    (34649, "Movistar"),
+    (34650, "Movistar"),
+    (34659, "Movistar"),
+    (34660, "Movistar"),
+    (34661, "Vodafone"),
+    (34662, "Vodafone"),
+    (34663, "Vodafone"),
+    (34664, "Vodafone"),
+    (34665, "Orange"),
+    (34666, "Vodafone"),
+    (34667, "Vodafone"),
+    (34669, "Movistar"),
+    (34675, "Orange"),
+    (34676, "Movistar"),
+    (34679, "Movistar"),
+    (34680, "Movistar"),
+    (34682, "Movistar"),
+    (34683, "Movistar"),
+    (34685, "Orange"),
+    (34686, "Movistar"),
+    (34687, "Vodafone"),
+    (34689, "Movistar"),
+    (34690, "Movistar"),
+    (34691, "Orange"),
+    (34692, "Orange"),
+    (34694, "Movistar"),
+    (34695, "Orange"),
+    (34696, "Movistar"),
+    (34697, "Vodafone"),
+    (34698, "Yoigo"),
+    (34699, "Movistar"),
+    (35051, "Gibfibre"),
+    (35052, "Gibfibre"),
+    (35054, "GibTel"),
+    (35056, "GibTel"),
+    (35057, "GibTel"),
+    (35058, "GibTel"),
+    (35191, "Vodafone"),
+    (35193, "NOS"),
+    (35196, "MEO"),
+    (35262, "POST"),
+    (35266, "Orange"),
+    (35269, "Tango"),
+    (35383, "3"),
+    (35385, "Meteor"),
+    (35386, "O2"),
+    (35387, "Vodafone"),
+    (35388, "eMobile"),
+    (35461, "Vodafone"),
+    (35462, "Vodafone"),
+    (35466, "Vodafone"),
+    (35467, "Vodafone"),
+    (35469, "Vodafone"),
+    (35476, "Nova"),
+    (35477, "Nova"),
+    (35478, "Nova"),
+    (35479, "Nova"),
+    (35482, "Vodafone"),
+    (35483, "Síminn"),
+    (35484, "Síminn"),
+    (35485, "Síminn"),
+    (35486, "Síminn"),
+    (35489, "Síminn"),
+    (35567, "One"),
+    (35568, "One"),
+    (35569, "Vodafone"),
+    (35672, "GO Mobile"),
+    (35677, "Melita Mobile"),
+    (35679, "GO Mobile"),
+    (35692, "epic"),
+    (35699, "epic"),
+    (35791, "Cytamobile-Vodafone"),
+    (35794, "Lemontel"),
+    (35795, "PrimeTel"),
+    (35796, "MTN"),
+    (35797, "Cytamobile-Vodafone"),
+    (35799, "Cytamobile-Vodafone"),
+    (35840, "Telia"),
+    (35841, "DNA"),
+    (35842, "Telia"),
+    (35844, "DNA"),
+    (35846, "Elisa"),
+    (35849, "Elisa"),
+    (35850, "Elisa"),
+    (35987, "Vivacom"),
+    (35988, "A1"),
+    (35989, "Telenor"),
+    (36501, "One"),
+    (36502, "One"),
+    (36508, "MVM Net"),
+    (36509, "MVM Net"),
+    (37060, "Tele 2"),
+    (37061, "Telia"),
+    (37062, "Telia"),
+    (37063, "BITĖ"),
+    (37064, "BITĖ"),
+    (37065, "BITĖ"),
+    (37067, "Tele 2"),
+    (37121, "Bite Latvia"),
+    (37126, "LMT"),
+    (37250, "Telia Eesti AS"),
+    (37251, "Telia Eesti AS"),
+    (37252, "Telia Eesti AS"),
+    (37253, "Telia Eesti AS"),
+    (37254, "Telia Eesti AS"),
+    (37255, "Tele 2"),
+    (37256, "Elisa"),
+    (37257, "Telia Eesti AS"),
+    (37258, "Tele 2"),
+    (37259, "Telia Eesti AS"),
+    (37281, "Telia Eesti AS"),
+    (37282, "Elisa"),
+    (37283, "Tele 2"),
+    (37284, "Tele 2"),
+    (37356, "IDC"),
+    (37360, "Orange"),
+    (37367, "Moldtelecom"),
+    (37368, "Orange"),
+    (37369, "Orange"),
+    (37376, "Moldcell"),
+    (37378, "Moldcell"),
+    (37379, "Moldcell"),
+    (37433, "Team Telecom Armenia"),
+    (37441, "Ucom"),
+    (37443, "Team Telecom Armenia"),
+    (37444, "Ucom"),
+    (37449, "VivaCell-MTS"),
+    (37488, "VivaCell-MTS"),
+    (37491, "Team Telecom Armenia"),
+    (37493, "VivaCell-MTS"),
+    (37494, "VivaCell-MTS"),
+    (37495, "Ucom"),
+    (37496, "Team Telecom Armenia"),
+    (37497, "Team Telecom Armenia"),
+    (37498, "VivaCell-MTS"),
+    (37499, "Team Telecom Armenia"),
+    (37525, "life:)"),
+    (37533, "MTS"),
+    (37544, "Velcom"),
+    (37861, "TELENET"),
+    (37866, "Telecom Italia San Marino"),
+    (38039, "Kyivstar"),
+    (38050, "Vodafone"),
+    (38063, "lifecell"),
+    (38066, "Vodafone"),
+    (38067, "Kyivstar"),
+    (38068, "Kyivstar"),
+    (38073, "lifecell"),
+    (38075, "Vodafone"),
+    (38077, "Kyivstar"),
+    (38079, "J&Y"),
+    (38091, "TriMob"),
+    (38092, "PEOPLEnet"),
+    (38093, "lifecell"),
+    (38094, "Intertelecom"),
+    (38095, "Vodafone"),
+    (38096, "Kyivstar"),
+    (38097, "Kyivstar"),
+    (38098, "Kyivstar"),
+    (38099, "Vodafone"),
+    (38160, "A1"),
+    (38161, "A1"),
+    (38162, "Telenor"),
+    (38163, "Telenor"),
+    (38164, "Telekom Srbija a.d."),
+    (38165, "Telekom Srbija a.d."),
+    (38166, "Telekom Srbija a.d."),
+    (38168, "A1"),
+    (38169, "Telenor"),
+    (38260, "m:tel"),
+    (38263, "Telenor"),
+    (38266, "Telekom"),
+    (38267, "Telekom"),
+    (38268, "m:tel"),
+    (38269, "Telenor"),
+    (38590, "Tele2"),
+    (38591, "A1 Telekom"),
+    (38592, "A1 Telekom"),
+    (38595, "Tele2"),
+    (38598, "Hrvatski Telekom"),
+    (38599, "Hrvatski Telekom"),
+    (38630, "A1"),
+    (38631, "Telekom Slovenije"),
+    (38640, "A1"),
+    (38641, "Telekom Slovenije"),
+    (38643, "Telekom Slovenije"),
+    (38649, "Telekom Slovenije"),
+    (38651, "Telekom Slovenije"),
+    (38664, "T-2"),
+    (38668, "A1"),
+    (38669, "A1"),
+    (38670, "Telemach"),
+    (38671, "Telemach"),
+    (38760, "BH Telecom"),
+    (38761, "BH Telecom"),
+    (38762, "BH Telecom"),
+    (38763, "HT ERONET"),
+    (38764, "HT ERONET"),
+    (38765, "m:tel"),
+    (38766, "m:tel"),
+    (38767, "m:tel"),
+    (38970, "T-Mobile"),
+    (38971, "T-Mobile"),
+    (38972, "T-Mobile"),
+    (38975, "A1"),
+    (38976, "A1"),
+    (38977, "A1"),
+    (38978, "A1"),
+    (39319, "Intermatica"),
+    (39370, "TIM"),
+    (39371, "Vodafone"),
+    (39373, "3 Italia"),
+    (39377, "Vodafone"),
+    (39383, "Vodafone"),
+    (40702, "Lycamobile"),
+    (40705, "Iristel"),
+    (40711, "Orange"),
+    (40712, "Orange"),
+    (40713, "Orange"),
+    (40770, "Digi Mobil"),
+    (40771, "Digi Mobil"),
+    (40772, "Digi Mobil"),
+    (40773, "Digi Mobil"),
+    (40774, "Digi Mobil"),
+    (40775, "Digi Mobil"),
+    (40776, "Digi Mobil"),
+    (40777, "Digi Mobil"),
+    (40780, "Telekom"),
+    (40783, "Orange"),
+    (40784, "Telekom"),
+    (40785, "Telekom"),
+    (40786, "Telekom"),
+    (40787, "Orange"),
+    (40788, "Telekom"),
+    (41752, "Swisscom"),
+    (41753, "Swisscom"),
+    (41754, "Swisscom"),
+    (41757, "Swisscom"),
+    (41762, "Sunrise"),
+    (41763, "Sunrise"),
+    (41764, "Sunrise"),
+    (41765, "Sunrise"),
+    (41766, "Sunrise"),
+    (41767, "Sunrise"),
+    (41768, "Sunrise"),
+    (41769, "Sunrise"),
+    (41770, "Swisscom"),
+    (41772, "Sunrise"),
+    (41774, "Swisscom"),
+    (41779, "Lycamobile"),
+    (41780, "Salt"),
+    (41781, "Salt"),
+    (41782, "Salt"),
+    (41783, "Salt"),
+    (41785, "Salt"),
+    (41786, "Salt"),
+    (41787, "Salt"),
+    (41788, "Salt"),
+    (41789, "Salt"),
+    (41790, "Swisscom"),
+    (41791, "Swisscom"),
+    (41792, "Swisscom"),
+    (41793, "Swisscom"),
+    (41794, "Swisscom"),
+    (41795, "Swisscom"),
+    (41796, "Swisscom"),
+    (41797, "Swisscom"),
+    (41798, "Swisscom"),
+    (42071, "O2"),
+    (42072, "O2"),
+    (42364, "Soracom"),
+    (42365, "Cubic"),
+    (42366, "Telecom Liechtenstein"),
+    (42373, "Telecom Liechtenstein"),
+    (42374, "First Mobile"),
+    (42377, "Swisscom"),
+    (42378, "Salt"),
+    (42379, "Telecom Liechtenstein"),
+    (43650, "T-Mobile AT"),
+    (43653, "A1 TA"),
+    (43655, "T-Mobile AT"),
+    (43657, "A1 TA"),
+    (43659, "A1 TA"),
+    (43660, "Three"),
+    (43661, "T-Mobile AT"),
+    (43663, "A1 TA"),
+    (43664, "A1 TA"),
+    (43665, "Three"),
+    (43667, "A1 TA"),
+    (43668, "Three"),
+    (43669, "A1 TA"),
+    (43670, "spusu"),
+    (43672, "Three"),
+    (43676, "T-Mobile AT"),
+    (43686, "Three"),
+    (43688, "Orange AT"),
+    (43690, "Three"),
+    (43696, "Three"),
+    (43699, "Orange AT"),
+    (44747, "Three"),
+    (44751, "O2"),
+    (44754, "O2"),
+    (44756, "O2"),
+    (44759, "O2"),
+    (44770, "O2"),
+    (44771, "O2"),
+    (44773, "O2"),
+    (44776, "Vodafone"),
+    (44777, "Vodafone"),
+    (44778, "Vodafone"),
+    (44781, "Orange"),
+    (44784, "O2"),
+    (44792, "O2"),
+    (44794, "EE"),
+    (44795, "EE"),
+    (44796, "Orange"),
+    (44798, "EE"),
+    (45201, "tdc"),
+    (45202, "tdc"),
+    (45203, "tdc"),
+    (45204, "tdc"),
+    (45205, "tdc"),
+    (45206, "telenor"),
+    (45207, "telenor"),
+    (45208, "telenor"),
+    (45209, "telenor"),
+    (45237, "tdc"),
+    (45318, "lycamobile denmark ltd"),
+    (45319, "telenor"),
+    (45341, "telenor"),
+    (45342, "telenor"),
+    (45344, "telenor"),
+    (45345, "telenor"),
+    (45346, "telenor"),
+    (45347, "telenor"),
+    (45348, "telenor"),
+    (45349, "telenor"),
+    (45401, "tdc"),
+    (45402, "tdc"),
+    (45403, "tdc"),
+    (45404, "tdc"),
+    (45405, "telenor"),
+    (45406, "telenor"),
+    (45407, "telenor"),
+    (45408, "telenor"),
+    (45409, "telenor"),
+    (45421, "telia"),
+    (45422, "telia"),
+    (45423, "telia"),
+    (45424, "telenor"),
+    (45425, "telenor"),
+    (45426, "telenor"),
+    (45427, "telenor"),
+    (45428, "telenor"),
+    (45461, "telenor"),
+    (45463, "telenor"),
+    (45464, "telenor"),
+    (45465, "telenor"),
+    (45466, "telenor"),
+    (45467, "telenor"),
+    (45468, "telenor"),
+    (45469, "telenor"),
+    (45493, "telenor"),
+    (45494, "telenor"),
+    (45498, "telenor"),
+    (45499, "telenor"),
+    (45521, "telia"),
+    (45522, "telia"),
+    (45523, "telia"),
+    (45524, "telia"),
+    (45531, "cbb mobil"),
+    (45532, "telia"),
+    (45533, "telia"),
+    (45534, "telia"),
+    (45535, "3"),
+    (45536, "3"),
+    (45537, "3"),
+    (45538, "3"),
+    (45539, "cbb mobil"),
+    (45601, "telia"),
+    (45602, "telia"),
+    (45603, "telia"),
+    (45604, "telia"),
+    (45605, "3"),
+    (45606, "cbb mobil"),
+    (45607, "cbb mobil"),
+    (45608, "cbb mobil"),
+    (45618, "telenor"),
+    (45619, "telenor"),
+    (45691, "telenor"),
+    (45692, "telenor"),
+    (45693, "telenor"),
+    (45694, "telenor"),
+    (45696, "telenor"),
+    (45697, "telenor"),
+    (45698, "telenor"),
+    (45699, "telenor"),
+    (45711, "telenor"),
+    (45712, "telenor"),
+    (45713, "lycamobile denmark ltd"),
+    (45714, "lycamobile denmark ltd"),
+    (45715, "lycamobile denmark ltd"),
+    (45716, "lycamobile denmark ltd"),
+    (45718, "lycamobile denmark ltd"),
+    (45811, "telenor"),
+    (45812, "telenor"),
+    (45815, "cbb mobil"),
+    (45816, "cbb mobil"),
+    (45818, "cbb mobil"),
+    (45914, "lycamobile denmark ltd"),
+    (45916, "lycamobile denmark ltd"),
+    (45917, "lycamobile denmark ltd"),
+    (45918, "lebara limited"),
+    (45919, "lebara limited"),
+    (45921, "tdc"),
+    (45935, "telenor"),
+    (45936, "simservice"),
+    (45938, "3"),
+    (45939, "3"),
+    (45971, "telenor"),
+    (45972, "telenor"),
+    (45973, "telenor"),
+    (45974, "telenor"),
+    (45975, "telenor"),
+    (45976, "telenor"),
+    (45978, "telenor"),
+    (45979, "telenor"),
+    (46700, "Tele2 Sverige"),
+    (46702, "TeliaSonera"),
+    (46703, "TeliaSonera"),
+    (46704, "Tele2 Sverige"),
+    (46705, "TeliaSonera"),
+    (46706, "TeliaSonera"),
+    (46707, "Tele2 Sverige"),
+    (46708, "Telenor Sverige"),
+    (46709, "Telenor Sverige"),
+    (46722, "TeliaSonera"),
+    (46725, "TeliaSonera"),
+    (46730, "TeliaSonera"),
+    (46733, "Telenor Sverige"),
+    (46736, "Tele2 Sverige"),
+    (46737, "Tele2 Sverige"),
+    (46739, "Tele2 Sverige"),
+    (46762, "Tele2 Sverige"),
+    (46763, "HI3G Access"),
+    (46765, "Tele2 Sverige"),
+    (47400, "telenor norge"),
+    (47401, "telenor norge"),
+    (47403, "telia"),
+    (47404, "telia"),
+    (47405, "telia"),
+    (47407, "telia"),
+    (47408, "telenor norge"),
+    (47409, "lyca mobile"),
+    (47412, "telia"),
+    (47413, "telia"),
+    (47453, "telenor norge"),
+    (47455, "lyca mobile"),
+    (47458, "telenor norge"),
+    (47460, "telenor norge"),
+    (47461, "chilimobil"),
+    (47462, "telia"),
+    (47463, "telia"),
+    (47464, "NetCom"),
+    (47465, "telia"),
+    (47466, "telia"),
+    (47467, "telia"),
+    (47468, "telenor norge"),
+    (47470, "telenor norge"),
+    (47472, "telia"),
+    (47473, "telia"),
+    (47474, "telia"),
+    (47475, "telenor norge"),
+    (47476, "telenor norge"),
+    (47477, "telia"),
+    (47478, "telenor norge"),
+    (47479, "telia"),
+    (47480, "telenor norge"),
+    (47481, "telenor norge"),
+    (47482, "telenor norge"),
+    (47484, "telia"),
+    (47927, "telenor norge"),
+    (47929, "Lyse Tele"),
+    (47930, "telia"),
+    (47932, "telia"),
+    (47934, "telia"),
+    (47936, "telia"),
+    (47938, "telia"),
+    (47939, "telia"),
+    (47941, "telenor norge"),
+    (47942, "telia"),
+    (47943, "telenor norge"),
+    (47948, "telenor norge"),
+    (47949, "telenor norge"),
+    (47961, "telenor norge"),
+    (47962, "telenor norge"),
+    (47965, "telenor norge"),
+    (47966, "telia"),
+    (47968, "telia"),
+    (47978, "telia"),
+    (47980, "telia"),
+    (47981, "telia"),
+    (47982, "telia"),
+    (47983, "telia"),
+    (47984, "telia"),
+    (47985, "telenor norge"),
+    (47986, "telia"),
+    (47988, "telia"),
+    (47989, "telenor norge"),
+    (47990, "telenor norge"),
+    (47991, "telenor norge"),
+    (47992, "telenor norge"),
+    (47993, "telenor norge"),
+    (47994, "telenor norge"),
+    (47995, "telenor norge"),
+    (47996, "telenor norge"),
+    (47997, "telenor norge"),
+    (48212, "Orange"),
+    (48213, "Plus"),
+    (48450, "Play"),
+    (48454, "Orange"),
+    (48456, "Play"),
+    (48457, "Play"),
+    (48532, "T-Mobile"),
+    (48538, "T-Mobile"),
+    (48539, "T-Mobile"),
+    (48570, "Play"),
+    (48571, "Orange"),
+    (48572, "Orange"),
+    (48574, "Play"),
+    (48575, "Play"),
+    (48576, "Play"),
+    (48577, "Play"),
+    (48578, "Play"),
+    (48600, "T-Mobile"),
+    (48601, "Plus"),
+    (48602, "T-Mobile"),
+    (48603, "Plus"),
+    (48604, "T-Mobile"),
+    (48605, "Plus"),
+    (48606, "T-Mobile"),
+    (48607, "Plus"),
+    (48608, "T-Mobile"),
+    (48609, "Plus"),
+    (48660, "T-Mobile"),
+    (48661, "Plus"),
+    (48662, "T-Mobile"),
+    (48663, "Plus"),
+    (48664, "T-Mobile"),
+    (48665, "Plus"),
+    (48666, "T-Mobile"),
+    (48667, "Plus"),
+    (48668, "T-Mobile"),
+    (48669, "Plus"),
+    (48690, "Orange"),
+    (48691, "Plus"),
+    (48692, "T-Mobile"),
+    (48693, "Plus"),
+    (48694, "T-Mobile"),
+    (48695, "Plus"),
+    (48696, "T-Mobile"),
+    (48697, "Plus"),
+    (48698, "T-Mobile"),
+    (48699, "Plus"),
+    (48720, "Plus"),
+    (48721, "Plus"),
+    (48722, "Plus"),
+    (48723, "Plus"),
+    (48724, "Plus"),
+    (48725, "Plus"),
+    (48726, "Plus"),
+    (48727, "Plus"),
+    (48728, "T-Mobile"),
+    (48730, "Play"),
+    (48731, "Play"),
+    (48732, "Play"),
+    (48733, "Play"),
+    (48734, "T-Mobile"),
+    (48735, "T-Mobile"),
+    (48736, "T-Mobile"),
+    (48737, "Play"),
+    (48738, "PKP Polskie Linie Kolejowe S.A."),
+    (48739, "Plus"),
+    (48781, "Plus"),
+    (48782, "Plus"),
+    (48783, "Plus"),
+    (48784, "T-Mobile"),
+    (48785, "Plus"),
+    (48787, "T-Mobile"),
+    (48788, "T-Mobile"),
+    (48797, "Orange"),
+    (48798, "Orange"),
+    (48880, "T-Mobile"),
+    (48881, "Play"),
+    (48882, "T-Mobile"),
+    (48883, "Play"),
+    (48885, "Plus"),
+    (48886, "T-Mobile"),
+    (48887, "Plus"),
+    (48888, "T-Mobile"),
+    (48889, "T-Mobile"),
+    (49151, "T-Mobile"),
+    (49160, "T-Mobile"),
+    (49162, "Vodafone"),
+    (49163, "Eplus"),
+    (49170, "T-Mobile"),
+    (49171, "T-Mobile"),
+    (49172, "Vodafone"),
+    (49173, "Vodafone"),
+    (49174, "Vodafone"),
+    (49175, "T-Mobile"),
+    (49176, "O2"),
+    (49177, "Eplus"),
+    (49178, "Eplus"),
+    (49179, "O2"),
+    (50160, "Belize Telemedia Ltd (Digi)"),
+    (50161, "Belize Telemedia Ltd (Digi)"),
+    (50162, "Belize Telemedia Ltd (Digi)"),
+    (50163, "Belize Telemedia Ltd (Digi)"),
+    (50166, "Speednet (Smart)"),
+    (50167, "Speednet (Smart)"),
+    (50230, "Tigo"),
+    (50231, "Tigo"),
+    (50232, "Tigo"),
+    (50233, "Tigo"),
+    (50234, "Movistar"),
+    (50239, "Telgua"),
+    (50240, "Tigo"),
+    (50241, "Telgua"),
+    (50242, "Telgua"),
+    (50243, "Movistar"),
+    (50244, "Movistar"),
+    (50245, "Tigo"),
+    (50246, "Tigo"),
+    (50247, "Telgua"),
+    (50248, "Tigo"),
+    (50249, "Tigo"),
+    (50252, "Movistar"),
+    (50253, "Tigo"),
+    (50254, "Telgua"),
+    (50256, "Movistar"),
+    (50257, "Tigo"),
+    (50258, "Telgua"),
+    (50259, "Telgua"),
+    (50361, "Movistar"),
+    (50365, "SIGET"),
+    (50366, "SIGET"),
+    (50367, "SIGET"),
+    (50371, "Movistar"),
+    (50372, "Tigo"),
+    (50373, "Digicel"),
+    (50374, "Digicel"),
+    (50375, "Tigo"),
+    (50376, "Claro"),
+    (50581, "Movistar"),
+    (50582, "Movistar"),
+    (50584, "Claro"),
+    (50586, "Claro"),
+    (50588, "Movistar"),
+    (50650, "Kolbi ICE"),
+    (50657, "Kolbi ICE"),
+    (50671, "Claro"),
+    (50672, "Claro"),
+    (50683, "Kolbi ICE"),
+    (50684, "Kolbi ICE"),
+    (50685, "Kolbi ICE"),
+    (50686, "Kolbi ICE"),
+    (50687, "Kolbi ICE"),
+    (50688, "Kolbi ICE"),
+    (50689, "Kolbi ICE"),
+    (50760, "Digicel"),
+    (50761, "Digicel"),
+    (50762, "Claro"),
+    (50764, "Telefónica Móviles"),
+    (50765, "Cable & Wireless"),
+    (50767, "Cable & Wireless"),
+    (50768, "Telefónica Móviles"),
+    (50769, "Cable & Wireless"),
+    (50781, "Mobilphone"),
+    (50840, "Globaltel"),
+    (50842, "Orange"),
+    (50843, "Diabolocom"),
+    (50844, "Globaltel"),
+    (50850, "Keyyo"),
+    (50855, "SPM Telecom"),
+    (50856, "Kav El International"),
+    (50932, "Natcom"),
+    (50933, "Natcom"),
+    (50935, "Natcom"),
+    (50940, "Natcom"),
+    (50941, "Natcom"),
+    (50942, "Natcom"),
+    (50943, "Natcom"),
+    (50944, "Digicel"),
+    (50946, "Digicel"),
+    (50947, "Digicel"),
+    (50948, "Digicel"),
+    (50949, "Digicel"),
+    (51900, "Claro"),
+    (51901, "Claro"),
+    (51909, "Americatel"),
+    (51910, "Claro"),
+    (51912, "Entel"),
+    (51913, "Claro"),
+    (51914, "Claro"),
+    (51915, "Claro"),
+    (51916, "Claro"),
+    (51917, "Claro"),
+    (51918, "Claro"),
+    (51920, "Movistar"),
+    (51922, "Entel"),
+    (51923, "Entel"),
+    (51924, "Entel"),
+    (51930, "Claro"),
+    (51931, "Claro"),
+    (51932, "Claro"),
+    (51933, "Entel"),
+    (51934, "Entel"),
+    (51935, "Claro"),
+    (51936, "Entel"),
+    (51937, "Movistar"),
+    (51938, "Movistar"),
+    (51939, "Movistar"),
+    (51940, "Claro"),
+    (51941, "Claro"),
+    (51942, "Movistar"),
+    (51943, "Movistar"),
+    (51944, "Claro"),
+    (51945, "Movistar"),
+    (51946, "Entel"),
+    (51947, "Movistar"),
+    (51948, "Movistar"),
+    (51953, "Claro"),
+    (51955, "Entel"),
+    (51962, "Movistar"),
+    (51963, "Claro"),
+    (51964, "Movistar"),
+    (51965, "Claro"),
+    (51966, "Movistar"),
+    (51967, "Claro"),
+    (51968, "Movistar"),
+    (51969, "Movistar"),
+    (51970, "Entel"),
+    (51971, "Movistar"),
+    (51973, "Claro"),
+    (51974, "Claro"),
+    (51975, "Movistar"),
+    (51977, "Entel"),
+    (51978, "Movistar"),
+    (51979, "Movistar"),
+    (51981, "Entel"),
+    (51985, "Movistar"),
+    (51988, "Movistar"),
+    (51990, "Movistar"),
+    (51991, "Claro"),
+    (51992, "Claro"),
+    (51993, "Claro"),
+    (51995, "Movistar"),
+    (51996, "Movistar"),
+    (51997, "Claro"),
+    (51998, "Movistar"),
+    (51999, "Movistar"),
+    (56211, "Rural Telecommunications Chile S.A."),
+    (56235, "Gtd Telesat S.A."),
+    (56236, "Entel"),
+    (56930, "WOM"),
+    (56933, "WOM"),
+    (56934, "Entel"),
+    (56935, "WOM"),
+    (56936, "WOM"),
+    (56937, "WOM"),
+    (56940, "Entel"),
+    (56941, "Movistar"),
+    (56942, "Entel"),
+    (56944, "Entel"),
+    (56945, "WOM"),
+    (56947, "Movistar"),
+    (56948, "WOM"),
+    (56949, "WOM"),
+    (56950, "Claro"),
+    (56951, "Entel"),
+    (56952, "Entel"),
+    (56953, "Movistar"),
+    (56954, "Claro"),
+    (56956, "Entel"),
+    (56957, "Entel"),
+    (56958, "Movistar"),
+    (56959, "Claro"),
+    (56961, "Movistar"),
+    (56965, "Claro"),
+    (56966, "Entel"),
+    (56968, "Movistar"),
+    (56972, "Claro"),
+    (56973, "Claro"),
+    (56974, "Movistar"),
+    (56975, "Entel"),
+    (56978, "Claro"),
+    (56982, "Entel"),
+    (56983, "Movistar"),
+    (56984, "Entel"),
+    (56985, "Movistar"),
+    (56986, "Claro"),
+    (56989, "Movistar"),
+    (56994, "Movistar"),
+    (56997, "Movistar"),
+    (56998, "Entel"),
+    (57303, "Uff!"),
+    (57315, "Movistar"),
+    (57316, "Movistar"),
+    (57317, "Movistar"),
+    (57318, "Movistar"),
+    (57322, "Claro"),
+    (57330, "Movistar"),
+    (57351, "Avantel"),
+    (58412, "Digitel GSM"),
+    (58414, "movistar"),
+    (58416, "Movilnet"),
+    (58422, "Digitel GSM"),
+    (58424, "movistar"),
+    (58426, "Movilnet"),
+    (59174, "Entel"),
+    (59261, "GTT"),
+    (59262, "GTT"),
+    (59264, "GTT"),
+    (59265, "GTT"),
+    (59266, "Digicel Guyana"),
+    (59267, "Digicel Guyana"),
+    (59268, "Digicel Guyana"),
+    (59269, "Digicel Guyana"),
+    (59271, "E-Networks"),
+    (59273, "E-Networks"),
+    (59274, "Digicel Guyana"),
+    (59275, "GTT"),
+    (59276, "E-Networks"),
+    (59393, "Claro"),
+    (59398, "Claro"),
+    (59595, "VOX"),
+    (59596, "VOX"),
+    (59597, "Personal"),
+    (59599, "Claro"),
+    (59771, "Digicel"),
+    (59772, "Digicel"),
+    (59774, "Digicel"),
+    (59775, "Telesur"),
+    (59776, "Digicel"),
+    (59777, "Telesur"),
+    (59781, "Digicel"),
+    (59782, "Digicel"),
+    (59783, "Digicel"),
+    (59784, "Telesur"),
+    (59785, "Telesur"),
+    (59786, "Telesur"),
+    (59787, "Telesur"),
+    (59788, "Telesur"),
+    (59789, "Telesur"),
+    (59891, "Antel"),
+    (59892, "Antel"),
+    (59893, "Movistar"),
+    (59894, "Movistar"),
+    (59895, "Movistar"),
+    (59896, "Claro"),
+    (59897, "Claro"),
+    (59898, "Antel"),
+    (59899, "Antel"),
+    (59970, "Digicel"),
+    (59977, "Kla"),
+    (59978, "Digicel"),
+    (59979, "Chippie"),
+    (59996, "Digicel"),
+    (60102, "DiGi"),
+    (60109, "DiGi"),
+    (60131, "Celcom"),
+    (60132, "Celcom"),
+    (60133, "Celcom"),
+    (60134, "Celcom"),
+    (60135, "Celcom"),
+    (60136, "Celcom"),
+    (60137, "Celcom"),
+    (60138, "Celcom"),
+    (60139, "Celcom"),
+    (60142, "Maxis"),
+    (60143, "DiGi"),
+    (60144, "Tune Talk"),
+    (60145, "Celcom"),
+    (60146, "DiGi"),
+    (60147, "Maxis"),
+    (60148, "Celcom"),
+    (60149, "DiGi"),
+    (60153, "Tune Talk"),
+    (60171, "Maxis"),
+    (60172, "Maxis"),
+    (60173, "Maxis"),
+    (60174, "Maxis"),
+    (60175, "Maxis"),
+    (60176, "Maxis"),
+    (60177, "Maxis"),
+    (60178, "Maxis"),
+    (60179, "Maxis"),
+    (60182, "U Mobile"),
+    (60183, "U Mobile"),
+    (60184, "U Mobile"),
+    (60186, "YTL"),
+    (60189, "U Mobile"),
+    (61400, "Telstra"),
+    (61401, "Optus"),
+    (61402, "Optus"),
+    (61403, "Optus"),
+    (61404, "Vodafone"),
+    (61405, "Vodafone"),
+    (61406, "Vodafone"),
+    (61407, "Telstra"),
+    (61408, "Telstra"),
+    (61409, "Telstra"),
+    (61410, "Vodafone"),
+    (61411, "Optus"),
+    (61412, "Optus"),
+    (61413, "Optus"),
+    (61414, "Vodafone"),
+    (61415, "Vodafone"),
+    (61416, "Vodafone"),
+    (61417, "Telstra"),
+    (61418, "Telstra"),
+    (61419, "Telstra"),
+    (61420, "Vodafone"),
+    (61421, "Optus"),
+    (61422, "Optus"),
+    (61423, "Optus"),
+    (61424, "Vodafone"),
+    (61425, "Vodafone"),
+    (61426, "Vodafone"),
+    (61427, "Telstra"),
+    (61428, "Telstra"),
+    (61429, "Telstra"),
+    (61430, "Vodafone"),
+    (61431, "Optus"),
+    (61432, "Optus"),
+    (61433, "Vodafone"),
+    (61434, "Optus"),
+    (61435, "Optus"),
+    (61436, "Telstra"),
+    (61437, "Telstra"),
+    (61438, "Telstra"),
+    (61439, "Telstra"),
+    (61440, "MessageBird"),
+    (61447, "Telstra"),
+    (61448, "Telstra"),
+    (61449, "Vodafone"),
+    (61450, "Vodafone"),
+    (61451, "Vodafone"),
+    (61452, "Vodafone"),
+    (61466, "Optus"),
+    (61468, "Optus"),
+    (61469, "Lycamobile"),
+    (61472, "Telstra"),
+    (61473, "Telstra"),
+    (61474, "Telstra"),
+    (61475, "Telstra"),
+    (61476, "Telstra"),
+    (61477, "Telstra"),
+    (61478, "Optus"),
+    (61479, "Optus"),
+    (61481, "Optus"),
+    (61482, "Optus"),
+    (61483, "Telstra"),
+    (61484, "Telstra"),
+    (61485, "Optus"),
+    (61486, "Telstra"),
+    (61487, "Telstra"),
+    (61488, "Telstra"),
+    (61495, "Optus"),
+    (62811, "Telkomsel"),
+    (62812, "Telkomsel"),
+    (62813, "Telkomsel"),
+    (62814, "Indosat Ooredoo Hutchison"),
+    (62815, "Indosat Ooredoo Hutchison"),
+    (62816, "Indosat Ooredoo Hutchison"),
+    (62817, "XL"),
+    (62818, "XL"),
+    (62819, "XL"),
+    (62821, "Telkomsel"),
+    (62822, "Telkomsel"),
+    (62823, "Telkomsel"),
+    (62831, "AXIS"),
+    (62832, "AXIS"),
+    (62833, "AXIS"),
+    (62838, "AXIS"),
+    (62851, "Telkomsel"),
+    (62852, "Telkomsel"),
+    (62853, "Telkomsel"),
+    (62855, "Indosat Ooredoo Hutchison"),
+    (62856, "Indosat Ooredoo Hutchison"),
+    (62857, "Indosat Ooredoo Hutchison"),
+    (62858, "Indosat Ooredoo Hutchison"),
+    (62859, "XL"),
+    (62877, "XL"),
+    (62878, "XL"),
+    (62879, "XL"),
+    (62881, "Smartfren"),
+    (62882, "Smartfren"),
+    (62883, "Smartfren"),
+    (62887, "Smartfren"),
+    (62888, "Smartfren"),
+    (62889, "Smartfren"),
+    (62895, "Indosat Ooredoo Hutchison"),
+    (62896, "Indosat Ooredoo Hutchison"),
+    (62897, "Indosat Ooredoo Hutchison"),
+    (62898, "Indosat Ooredoo Hutchison"),
+    (62899, "Indosat Ooredoo Hutchison"),
+    (63813, "Smart"),
+    (63905, "Globe"),
+    (63906, "Globe"),
+    (63907, "Smart"),
+    (63908, "Smart"),
+    (63909, "Smart"),
+    (63910, "Smart"),
+    (63911, "Smart"),
+    (63912, "Smart"),
+    (63914, "Globe"),
+    (63915, "Globe"),
+    (63916, "Globe"),
+    (63917, "Globe"),
+    (63918, "Smart"),
+    (63919, "Smart"),
+    (63924, "Dito"),
+    (63926, "Globe"),
+    (63927, "Globe"),
+    (63934, "Dito"),
+    (63935, "Globe"),
+    (63936, "Globe"),
+    (63937, "Globe"),
+    (63944, "Dito"),
+    (63945, "Globe"),
+    (63950, "Smart"),
+    (63951, "Smart"),
+    (63958, "Smart"),
+    (63965, "Globe"),
+    (63966, "Globe"),
+    (63967, "Globe"),
+    (63970, "Smart"),
+    (63971, "Globe"),
+    (63972, "Globe"),
+    (63975, "Globe"),
+    (63976, "Globe"),
+    (63977, "Globe"),
+    (63978, "Globe"),
+    (63981, "Smart"),
+    (63983, "Globe"),
+    (63985, "Smart"),
+    (63986, "Globe"),
+    (63987, "Globe"),
+    (63988, "Smart"),
+    (63991, "Dito"),
+    (63992, "Dito"),
+    (63993, "Dito"),
+    (63994, "Dito"),
+    (63995, "Globe"),
+    (63996, "Globe"),
+    (63997, "Globe"),
+    (63998, "Smart"),
+    (63999, "Smart"),
+    (64201, "Callplus"),
+    (64202, "Callplus"),
+    (64203, "Voyager Internet Limted"),
+    (64204, "Sure (Guernsey) New Zealand Limited"),
+    (64205, "Vodafone"),
+    (64206, "Voyager Internet Limted"),
+    (64208, "Vodafone"),
+    (64260, "2degrees"),
+    (64261, "Spark"),
+    (64262, "Spark"),
+    (64263, "Spark"),
+    (64264, "Spark"),
+    (64266, "2degrees"),
+    (64268, "Spark"),
+    (64269, "Spark"),
+    (64280, "Vodafone"),
+    (64281, "Sure (Guernsey) New Zealand Limited"),
+    (64284, "2degrees"),
+    (64287, "Link Telecom"),
+    (65801, "Simba"),
+    (65803, "SingTel"),
+    (65804, "M1"),
+    (65806, "Simba"),
+    (65807, "Simba"),
+    (65808, "Simba"),
+    (65810, "M1"),
+    (65812, "SingTel"),
+    (65817, "M1"),
+    (65819, "M1"),
+    (65821, "M1"),
+    (65826, "SingTel"),
+    (65827, "M1"),
+    (65828, "SingTel"),
+    (65830, "SingTel"),
+    (65831, "SingTel"),
+    (65833, "StarHub"),
+    (65834, "SingTel"),
+    (65835, "SingTel"),
+    (65836, "StarHub"),
+    (65837, "SingTel"),
+    (65840, "SingTel"),
+    (65841, "M1"),
+    (65842, "SingTel"),
+    (65843, "SingTel"),
+    (65844, "M1"),
+    (65845, "SingTel"),
+    (65846, "M1"),
+    (65847, "StarHub"),
+    (65848, "M1"),
+    (65849, "StarHub"),
+    (65851, "StarHub"),
+    (65852, "StarHub"),
+    (65854, "M1"),
+    (65855, "M1"),
+    (65856, "StarHub"),
+    (65857, "M1"),
+    (65858, "SingTel"),
+    (65859, "StarHub"),
+    (65860, "StarHub"),
+    (65861, "SingTel"),
+    (65862, "M1"),
+    (65863, "SingTel"),
+    (65865, "SingTel"),
+    (65866, "SingTel"),
+    (65867, "SingTel"),
+    (65868, "M1"),
+    (65870, "SingTel"),
+    (65871, "M1"),
+    (65872, "StarHub"),
+    (65873, "SingTel"),
+    (65874, "SingTel"),
+    (65878, "M1"),
+    (65880, "Simba"),
+    (65881, "M1"),
+    (65882, "M1"),
+    (65883, "Simba"),
+    (65885, "Simba"),
+    (65891, "Simba"),
+    (65895, "Simba"),
+    (65896, "Simba"),
+    (65897, "Simba"),
+    (65901, "SingTel"),
+    (65902, "StarHub"),
+    (65903, "SingTel"),
+    (65904, "M1"),
+    (65905, "SingTel"),
+    (65906, "StarHub"),
+    (65907, "M1"),
+    (65908, "SingTel"),
+    (65909, "M1"),
+    (65910, "StarHub"),
+    (65911, "SingTel"),
+    (65912, "SingTel"),
+    (65913, "SingTel"),
+    (65914, "StarHub"),
+    (65915, "SingTel"),
+    (65916, "StarHub"),
+    (65917, "SingTel"),
+    (65918, "StarHub"),
+    (65919, "M1"),
+    (65922, "M1"),
+    (65923, "SingTel"),
+    (65924, "StarHub"),
+    (65927, "M1"),
+    (65932, "M1"),
+    (65934, "M1"),
+    (65935, "SingTel"),
+    (65936, "M1"),
+    (65937, "SingTel"),
+    (65938, "StarHub"),
+    (65939, "SingTel"),
+    (65942, "StarHub"),
+    (65943, "M1"),
+    (65944, "SingTel"),
+    (65945, "StarHub"),
+    (65946, "SingTel"),
+    (65947, "M1"),
+    (65948, "StarHub"),
+    (65961, "SingTel"),
+    (65962, "SingTel"),
+    (65963, "SingTel"),
+    (65964, "SingTel"),
+    (65965, "SingTel"),
+    (65966, "SingTel"),
+    (65967, "SingTel"),
+    (65968, "M1"),
+    (65969, "M1"),
+    (65971, "SingTel"),
+    (65972, "SingTel"),
+    (65973, "SingTel"),
+    (65974, "M1"),
+    (65975, "SingTel"),
+    (65976, "M1"),
+    (65977, "SingTel"),
+    (65978, "SingTel"),
+    (65979, "M1"),
+    (65981, "SingTel"),
+    (65982, "SingTel"),
+    (65983, "SingTel"),
+    (65984, "M1"),
+    (65985, "StarHub"),
+    (65986, "SingTel"),
+    (65987, "M1"),
+    (65988, "StarHub"),
+    (65989, "SingTel"),
+    (66611, "AIS"),
+    (66612, "AIS"),
+    (66613, "AIS"),
+    (66614, "AIS"),
+    (66615, "AIS"),
+    (66617, "DTAC"),
+    (66618, "DTAC"),
+    (66619, "DTAC"),
+    (66620, "DTAC"),
+    (66621, "AIS"),
+    (66622, "AIS"),
+    (66623, "AIS"),
+    (66624, "AIS"),
+    (66625, "AIS"),
+    (66626, "DTAC"),
+    (66627, "DTAC"),
+    (66628, "DTAC"),
+    (66629, "DTAC"),
+    (66630, "AIS"),
+    (66631, "AIS"),
+    (66633, "DTAC"),
+    (66634, "DTAC"),
+    (66636, "AIS"),
+    (66637, "True Move"),
+    (66638, "True Move"),
+    (66639, "True Move"),
+    (66649, "AIS"),
+    (66650, "AIS"),
+    (66651, "AIS"),
+    (66652, "AIS"),
+    (66653, "AIS"),
+    (66654, "AIS"),
+    (66655, "AIS"),
+    (66656, "AIS"),
+    (66659, "AIS"),
+    (66800, "AIS"),
+    (66801, "AIS"),
+    (66802, "True Move"),
+    (66804, "DTAC"),
+    (66805, "DTAC"),
+    (66806, "AIS"),
+    (66807, "AIS"),
+    (66808, "AIS"),
+    (66809, "True Move"),
+    (66811, "AIS"),
+    (66813, "AIS"),
+    (66814, "DTAC"),
+    (66815, "DTAC"),
+    (66816, "DTAC"),
+    (66817, "AIS"),
+    (66818, "AIS"),
+    (66819, "AIS"),
+    (66820, "True Move"),
+    (66821, "AIS"),
+    (66822, "AIS"),
+    (66823, "DTAC"),
+    (66824, "DTAC"),
+    (66825, "True Move"),
+    (66827, "DTAC"),
+    (66828, "AIS"),
+    (66829, "True Move"),
+    (66831, "DTAC"),
+    (66833, "AIS"),
+    (66836, "DTAC"),
+    (66840, "True Move"),
+    (66841, "True Move"),
+    (66842, "True Move"),
+    (66846, "DTAC"),
+    (66850, "DTAC"),
+    (66851, "DTAC"),
+    (66852, "AIS"),
+    (66853, "DTAC"),
+    (66854, "AIS"),
+    (66855, "True Move"),
+    (66856, "AIS"),
+    (66857, "AIS"),
+    (66858, "True Move"),
+    (66859, "True Move"),
+    (66860, "AIS"),
+    (66861, "AIS"),
+    (66862, "AIS"),
+    (66863, "True Move"),
+    (66864, "True Move"),
+    (66865, "True Move"),
+    (66866, "True Move"),
+    (66868, "DTAC"),
+    (66869, "DTAC"),
+    (66870, "AIS"),
+    (66871, "AIS"),
+    (66872, "AIS"),
+    (66873, "DTAC"),
+    (66874, "DTAC"),
+    (66875, "DTAC"),
+    (66876, "DTAC"),
+    (66877, "DTAC"),
+    (66878, "AIS"),
+    (66879, "AIS"),
+    (66880, "DTAC"),
+    (66881, "DTAC"),
+    (66883, "AIS"),
+    (66884, "AIS"),
+    (66886, "True Move"),
+    (66888, "DTAC"),
+    (66889, "True Move"),
+    (66890, "AIS"),
+    (66891, "True Move"),
+    (66892, "AIS"),
+    (66893, "TOT Mobile"),
+    (66894, "DTAC"),
+    (66895, "AIS"),
+    (66896, "DTAC"),
+    (66897, "DTAC"),
+    (66898, "AIS"),
+    (66899, "AIS"),
+    (66900, "DTAC"),
+    (66901, "AIS"),
+    (66903, "AIS"),
+    (66904, "AIS"),
+    (66906, "DTAC"),
+    (66908, "AIS"),
+    (66909, "True Move"),
+    (66910, "True Move"),
+    (66911, "DTAC"),
+    (66913, "AIS"),
+    (66914, "DTAC"),
+    (66916, "AIS"),
+    (66917, "True Move"),
+    (66918, "True Move"),
+    (66919, "TOT Mobile"),
+    (66920, "DTAC"),
+    (66921, "DTAC"),
+    (66938, "True Move"),
+    (66939, "True Move"),
+    (66940, "True Move"),
+    (66941, "True Move"),
+    (66950, "DTAC"),
+    (66970, "True Move"),
+    (66971, "True Move"),
+    (66972, "True Move"),
+    (66973, "True Move"),
+    (66974, "TOT Mobile"),
+    (66975, "TOT Mobile"),
+    (66976, "TOT Mobile"),
+    (66977, "TOT Mobile"),
+    (66978, "TOT Mobile"),
+    (66979, "AIS"),
+    (66990, "DTAC"),
+    (66991, "DTAC"),
+    (66992, "DTAC"),
+    (66993, "DTAC"),
+    (66994, "DTAC"),
+    (66995, "True Move"),
+    (66996, "True Move"),
+    (66997, "True Move"),
+    (66998, "True Move"),
+    (66999, "True Move"),
+    (67072, "Timor Telecom"),
+    (67073, "Telkomcel"),
+    (67074, "Telkomcel"),
+    (67075, "Viettel (Telemor brand)"),
+    (67076, "Viettel (Telemor brand)"),
+    (67077, "Timor Telecom"),
+    (67078, "Timor Telecom"),
+    (67214, "REACH"),
+    (67235, "Norfolk Telecom"),
+    (67238, "Norfolk Telecom"),
+    (67371, "UNN"),
+    (67372, "UNN"),
+    (67373, "UNN"),
+    (67374, "UNN"),
+    (67375, "UNN"),
+    (67376, "UNN"),
+    (67377, "UNN"),
+    (67379, "UNN"),
+    (67381, "UNN"),
+    (67382, "UNN"),
+    (67383, "UNN"),
+    (67384, "UNN"),
+    (67386, "UNN"),
+    (67387, "UNN"),
+    (67388, "UNN"),
+    (67389, "UNN"),
+    (67575, "bmobile"),
+    (67576, "bmobile"),
+    (67577, "bmobile"),
+    (67578, "bmobile"),
+    (67581, "Vodafone"),
+    (67582, "Vodafone"),
+    (67583, "Vodafone"),
+    (67584, "Vodafone"),
+    (67588, "Digicel"),
+    (67654, "Toko Wireless"),
+    (67668, "Digicel"),
+    (67670, "Digicel"),
+    (67672, "U-Call Tonga"),
+    (67673, "U-Call Tonga"),
+    (67674, "U-Call Tonga"),
+    (67675, "U-Call Tonga"),
+    (67676, "U-Call Tonga"),
+    (67677, "U-Call Tonga"),
+    (67678, "U-Call Tonga"),
+    (67679, "U-Call Tonga"),
+    (67684, "Digicel"),
+    (67686, "Digicel"),
+    (67687, "Digicel"),
+    (67688, "Digicel"),
+    (67689, "Digicel"),
+    (67768, "Satsol"),
+    (67769, "Satsol"),
+    (67791, "Satsol"),
+    (67792, "Satsol"),
+    (67793, "Satsol"),
+    (67950, "Digicel"),
+    (67951, "Digicel"),
+    (67955, "Digicel"),
+    (67956, "Digicel"),
+    (67958, "Vodafone"),
+    (67980, "Vodafone"),
+    (67983, "Vodafone"),
+    (67984, "Vodafone"),
+    (67986, "Vodafone"),
+    (67987, "Vodafone"),
+    (67989, "Vodafone"),
+    (68045, "PMCI"),
+    (68046, "PMCI"),
+    (68077, "PalauCel"),
+    (68078, "PalauCel"),
+    (68083, "PMCI"),
+    (68088, "PalauTel"),
+    (68182, "Service des Postes et Télécommunications"),
+    (68183, "Service des Postes et Télécommunications"),
+    (68571, "Bluesky"),
+    (68572, "Digicel"),
+    (68573, "Digicel"),
+    (68575, "Bluesky"),
+    (68576, "Bluesky"),
+    (68577, "Digicel"),
+    (68578, "Digicel"),
+    (68583, "Digicel"),
+    (68584, "Digicel"),
+    (68585, "Digicel"),
+    (68586, "Digicel"),
+    (68587, "Digicel"),
+    (68662, "Ocean Link"),
+    (68663, "Ocean Link"),
+    (68670, "ATHKL"),
+    (68671, "ATHKL"),
+    (68674, "ATHKL"),
+    (68676, "ATHKL"),
+    (68677, "ATHKL"),
+    (68678, "ATHKL"),
+    (68679, "ATHKL"),
+    (68780, "OPT-NC"),
+    (68781, "OPT-NC"),
+    (68782, "OPT-NC"),
+    (68783, "OPT-NC"),
+    (68784, "OPT-NC"),
+    (68785, "OPT-NC"),
+    (68786, "OPT-NC"),
+    (68787, "OPT-NC"),
+    (68789, "OPT-NC"),
+    (68987, "Vini"),
+    (68988, "Viti"),
+    (68989, "Vodafone"),
+    (69132, "FSMTC"),
+    (69133, "FSMTC"),
+    (69135, "FSMTC"),
+    (69137, "FSMTC"),
+    (69192, "FSMTC"),
+    (69193, "FSMTC"),
+    (69194, "FSMTC"),
+    (69195, "FSMTC"),
+    (69196, "BOOM!"),
+    (69197, "FSMTC"),
+    (69223, "National Telecommunications Authority"),
+    (69254, "National Telecommunications Authority"),
+    (79000, "Tele2"),
+    (79001, "Tele2"),
+    (79002, "Tele2"),
+    (79005, "Tele2"),
+    (79006, "Tele2"),
+    (79007, "Antares"),
+    (79009, "Tele2"),
+    (79013, "Tele2"),
+    (79015, "Tele2"),
+    (79017, "Tele2"),
+    (79019, "Tele2"),
+    (79020, "Tele2"),
+    (79021, "Tele2"),
+    (79022, "Tele2"),
+    (79025, "Tele2"),
+    (79026, "Tele2"),
+    (79027, "Tele2"),
+    (79028, "Tele2"),
+    (79029, "Tele2"),
+    (79040, "Tele2"),
+    (79041, "Tele2"),
+    (79042, "Tele2"),
+    (79043, "Tele2"),
+    (79044, "Tele2"),
+    (79046, "Tele2"),
+    (79048, "Tele2"),
+    (79300, "MegaFon"),
+    (79301, "MegaFon"),
+    (79302, "MegaFon"),
+    (79303, "MegaFon"),
+    (79304, "MegaFon"),
+    (79307, "MegaFon"),
+    (79308, "MegaFon"),
+    (79320, "MegaFon"),
+    (79321, "MegaFon"),
+    (79322, "MegaFon"),
+    (79323, "MegaFon"),
+    (79324, "MegaFon"),
+    (79325, "MegaFon"),
+    (79326, "MegaFon"),
+    (79328, "MegaFon"),
+    (79331, "MegaFon"),
+    (79332, "MegaFon"),
+    (79333, "MegaFon"),
+    (79341, "MegaFon"),
+    (79344, "MegaFon"),
+    (79360, "MegaFon"),
+    (79366, "MegaFon"),
+    (79367, "MegaFon"),
+    (79380, "MegaFon"),
+    (79381, "MegaFon"),
+    (79382, "MegaFon"),
+    (79383, "MegaFon"),
+    (79384, "MegaFon"),
+    (79385, "MegaFon"),
+    (79388, "MegaFon"),
+    (79389, "MegaFon"),
+    (79397, "MegaFon"),
+    (79511, "Tele2"),
+    (79512, "Tele2"),
+    (79513, "Tele2"),
+    (79514, "Tele2"),
+    (79515, "Tele2"),
+    (79516, "Tele2"),
+    (79517, "Tele2"),
+    (79518, "Tele2"),
+    (79519, "Tele2"),
+    (79582, "Tele2"),
+    (79583, "Global Telecom"),
+    (79589, "Global Telecom"),
+    (79771, "Tele2"),
+    (79772, "Tele2"),
+    (79773, "Tele2"),
+    (79774, "Tele2"),
+    (79775, "Tele2"),
+    (79776, "Tele2"),
+    (79777, "Tele2"),
+    (79778, "Tele2"),
+    (79779, "Tele2"),
+    (79780, "MTS"),
+    (79787, "MTS"),
+    (79788, "MTS"),
+    (79789, "K-Telecom Ltd"),
+    (79807, "MTS"),
+    (79831, "MTS"),
+    (79832, "MTS"),
+    (79833, "MTS"),
+    (79835, "MTS"),
+    (79867, "MTS"),
+    (79869, "MTS"),
+    (79911, "Tele2"),
+    (79914, "Tele2"),
+    (79916, "Tele2"),
+    (79919, "Tele2"),
+    (79920, "Tele2"),
+    (79961, "Tele2"),
+    (79963, "MegaFon"),
+    (79964, "MegaFon"),
+    (79965, "MegaFon"),
+    (79966, "MegaFon"),
+    (79969, "MegaFon"),
+    (81701, "Softbank"),
+    (81702, "KDDI"),
+    (81704, "NTT Docomo"),
+    (81707, "NTT Docomo"),
+    (81709, "Rakuten Communications"),
+    (81801, "NTT Docomo"),
+    (81802, "NTT Docomo"),
+    (81803, "Softbank"),
+    (81804, "Softbank"),
+    (81805, "KDDI"),
+    (81806, "Okinawa Cellular"),
+    (81807, "Softbank"),
+    (81808, "NTT Docomo"),
+    (81902, "NTT Docomo"),
+    (81904, "NTT Docomo"),
+    (81905, "NTT Docomo"),
+    (81907, "NTT Docomo"),
+    (82100, "LG U+"),
+    (82104, "SKTellink"),
+    (82122, "KT"),
+    (84993, "G-Mobile"),
+    (84994, "G-Mobile"),
+    (84995, "G-Mobile"),
+    (84996, "G-Mobile"),
+    (84997, "G-Mobile"),
+    (84998, "Indochina Telecom"),
+    (84999, "Indochina Telecom"),
+    (85261, "HKT"),
+    (85510, "Smart"),
+    (85511, "Cellcard"),
+    (85512, "Cellcard"),
+    (85513, "qbmore/Cadcomms"),
+    (85514, "Cellcard"),
+    (85515, "Smart"),
+    (85516, "Smart"),
+    (85517, "Cellcard"),
+    (85518, "Seatel"),
+    (85560, "Metfone"),
+    (85561, "Cellcard"),
+    (85566, "Metfone"),
+    (85567, "Metfone"),
+    (85568, "Metfone"),
+    (85569, "Smart"),
+    (85570, "Smart"),
+    (85571, "Metfone"),
+    (85576, "Cellcard"),
+    (85577, "Cellcard"),
+    (85578, "Cellcard"),
+    (85581, "Smart"),
+    (85585, "Cellcard"),
+    (85586, "Smart"),
+    (85588, "Metfone"),
+    (85589, "Cellcard"),
+    (85590, "Metfone"),
+    (85592, "Cellcard"),
+    (85593, "Smart"),
+    (85595, "Cellcard"),
+    (85596, "Smart"),
+    (85597, "Metfone"),
+    (85598, "Smart"),
+    (85599, "Cellcard"),
+    (86130, "China Unicom"),
+    (86131, "China Unicom"),
+    (86132, "China Unicom"),
+    (86133, "China Telecom"),
+    (86135, "China Mobile"),
+    (86136, "China Mobile"),
+    (86137, "China Mobile"),
+    (86138, "China Mobile"),
+    (86139, "China Mobile"),
+    (86145, "China Unicom"),
+    (86147, "China Mobile"),
+    (86153, "China Telecom"),
+    (86155, "China Unicom"),
+    (86156, "China Unicom"),
+    (86166, "China Unicom"),
+    (86167, "China Unicom"),
+    (86171, "China Unicom"),
+    (86172, "China Mobile"),
+    (86173, "China Telecom"),
+    (86174, "China Telecom"),
+    (86175, "China Unicom"),
+    (86176, "China Unicom"),
+    (86177, "China Telecom"),
+    (86178, "China Mobile"),
+    (86180, "China Telecom"),
+    (86181, "China Telecom"),
+    (86182, "China Mobile"),
+    (86183, "China Mobile"),
+    (86184, "China Mobile"),
+    (86185, "China Unicom"),
+    (86186, "China Unicom"),
+    (86187, "China Mobile"),
+    (86188, "China Mobile"),
+    (86189, "China Telecom"),
+    (86190, "China Telecom"),
+    (86191, "China Telecom"),
+    (86192, "China Broadnet"),
+    (86193, "China Telecom"),
+    (86195, "China Mobile"),
+    (86196, "China Unicom"),
+    (86197, "China Mobile"),
+    (86198, "China Mobile"),
+    (86199, "China Telecom"),
+    (88011, "Citycell"),
+    (88013, "Grameenphone"),
+    (88014, "Banglalink"),
+    (88015, "TeleTalk"),
+    (88016, "Robi"),
+    (88017, "Grameenphone"),
+    (88018, "Robi"),
+    (88019, "Banglalink"),
+    (88232, "Maritime Communications Partner (MCP)"),
+    (88233, "Oration Technologies"),
+    (88237, "AT&T Cingular Wireless Network"),
+    (88249, "Monaco Telecom"),
+    (88250, "Phonegroup"),
+    (91600, "Reliance Jio"),
+    (91602, "BSNL MOBILE"),
+    (91603, "BSNL MOBILE"),
+    (91620, "Reliance Jio"),
+    (91626, "Reliance Jio"),
+    (91628, "Reliance Jio"),
+    (91629, "Reliance Jio"),
+    (91630, "Reliance Jio"),
+    (91635, "Reliance Jio"),
+    (91636, "Reliance Jio"),
+    (91637, "Reliance Jio"),
+    (91638, "Reliance Jio"),
+    (91639, "Reliance Jio"),
+    (91690, "Airtel"),
+    (91691, "Vodafone"),
+    (91700, "Reliance Jio"),
+    (91701, "Reliance Jio"),
+    (91720, "Tata Docomo"),
+    (91723, "Vodafone"),
+    (91727, "Aircel"),
+    (91735, "Idea"),
+    (91769, "Idea"),
+    (91785, "Reliance Jio"),
+    (91789, "Airtel"),
+    (91790, "Reliance Jio"),
+    (91798, "Reliance Jio"),
+    (91819, "Idea"),
+    (91825, "Reliance Jio"),
+    (91833, "Vodafone"),
+    (91837, "Vodafone"),
+    (91840, "Vodafone"),
+    (91846, "Idea"),
+    (91849, "Idea"),
+    (91850, "Idea"),
+    (91868, "Idea"),
+    (91886, "Telewings"),
+    (91887, "Vodafone"),
+    (91900, "Airtel"),
+    (91901, "Reliance Jio"),
+    (91902, "Reliance Jio"),
+    (91903, "Tata Docomo"),
+    (91904, "Tata Docomo"),
+    (91908, "Idea"),
+    (91918, "BSNL MOBILE"),
+    (91919, "BSNL MOBILE"),
+    (91926, "Reliance Jio"),
+    (91928, "Reliance Jio"),
+    (91929, "Reliance Jio"),
+    (91958, "Vodafone"),
+    (91966, "Airtel"),
+    (91967, "Vodafone"),
+    (91973, "Vodafone"),
+    (91979, "Airtel"),
+    (91980, "Aircel"),
+    (91985, "Aircel"),
+    (91989, "Airtel"),
+    (91990, "Airtel"),
+    (91993, "Airtel"),
+    (91995, "Airtel"),
+    (91997, "Airtel"),
+    (91998, "Vodafone"),
+    (91999, "Airtel"),
+    (93744, "Afghan Telecom"),
+    (93747, "Afghan Telecom"),
+    (93748, "Afghan Telecom"),
+    (93749, "Afghan Telecom"),
+    (95940, "MPT"),
+    (95941, "MPT"),
+    (95942, "MPT"),
+    (95943, "MPT"),
+    (95944, "MPT"),
+    (95945, "MPT"),
+    (95947, "MPT"),
+    (95949, "MPT"),
+    (95973, "MPT"),
+    (95981, "MPT"),
+    (95983, "MPT"),
+    (95984, "MPT"),
+    (95985, "MPT"),
+    (95986, "MPT"),
+    (95987, "MPT"),
+    (95988, "MPT"),
+    (95989, "MPT"),
+    (95991, "MPT"),
+    (95994, "Ooredoo"),
+    (95995, "Ooredoo"),
+    (95996, "Ooredoo"),
+    (95997, "Ooredoo"),
+    (95998, "Ooredoo"),
+    (96130, "Touch"),
+    (96131, "Alfa"),
+    (96132, "Alfa"),
+    (96133, "Alfa"),
+    (96134, "Alfa"),
+    (96135, "Alfa"),
+    (96136, "Touch"),
+    (96137, "Touch"),
+    (96138, "Touch"),
+    (96139, "Touch"),
+    (96277, "Orange"),
+    (96278, "Umniah"),
+    (96279, "Zain JO"),
+    (96350, "Rcell"),
+    (96391, "Wafa Telecom"),
+    (96392, "Wafa Telecom"),
+    (96393, "Syriatel"),
+    (96394, "MTN"),
+    (96395, "MTN"),
+    (96396, "MTN"),
+    (96397, "MTN"),
+    (96398, "Syriatel"),
+    (96399, "Syriatel"),
+    (96473, "ITPC"),
+    (96475, "Korek"),
+    (96476, "Omnnea"),
+    (96477, "Asiacell"),
+    (96478, "Zain"),
+    (96479, "Zain"),
+    (96650, "STC"),
+    (96653, "STC"),
+    (96654, "Mobily"),
+    (96655, "STC"),
+    (96656, "Mobily"),
+    (96658, "Zain"),
+    (96659, "Zain"),
+    (96770, "Y"),
+    (96771, "SabaFon"),
+    (96773, "MTN"),
+    (96777, "Yemen Mobile"),
+    (96778, "Yemen Mobile"),
+    (96871, "Omantel"),
+    (96872, "Omantel"),
+    (96874, "Ooredoo"),
+    (96875, "Vodafone Oman"),
+    (96876, "Vodafone Oman"),
+    (96877, "Vodafone Oman"),
+    (96878, "Ooredoo"),
+    (96891, "Omantel"),
+    (96892, "Omantel"),
+    (96893, "Omantel"),
+    (96894, "Ooredoo"),
+    (96895, "Ooredoo"),
+    (96896, "Ooredoo"),
+    (96897, "Ooredoo"),
+    (96898, "Omantel"),
+    (96899, "Omantel"),
+    (97056, "Ooredoo Group"),
+    (97059, "Palestine Cellular Communications"),
+    (97150, "Etisalat"),
+    (97152, "du"),
+    (97154, "Etisalat"),
+    (97155, "du"),
+    (97156, "Etisalat"),
+    (97158, "du"),
+    (97250, "Pelephone"),
+    (97251, "Xphone"),
+    (97252, "Cellcom"),
+    (97253, "Hot Mobile"),
+    (97254, "Orange"),
+    (97256, "Ooredoo"),
+    (97258, "Golan Telecom"),
+    (97259, "Jawwal"),
+    (97330, "Telecommunications Regulatory Authority"),
+    (97331, "Royal Court"),
+    (97332, "Batelco"),
+    (97333, "VIVA"),
+    (97334, "VIVA"),
+    (97335, "VIVA"),
+    (97336, "zain BH"),
+    (97337, "zain BH"),
+    (97338, "Batelco"),
+    (97339, "Batelco"),
+    (97363, "VIVA"),
+    (97364, "Batelco"),
+    (97430, "Vodafone"),
+    (97433, "ooredoo"),
+    (97450, "ooredoo"),
+    (97451, "ooredoo"),
+    (97452, "ooredoo"),
+    (97455, "ooredoo"),
+    (97466, "ooredoo"),
+    (97470, "Vodafone"),
+    (97471, "Vodafone"),
+    (97474, "Vodafone"),
+    (97477, "Vodafone"),
+    (97577, "TashiCell of Tashi InfoComm"),
+    (97587, "TashiCell of Tashi InfoComm"),
+    (97650, "Unitel"),
+    (97655, "Unitel"),
+    (97660, "ONDO"),
+    (97666, "ONDO"),
+    (97669, "Skytel"),
+    (97672, "Lime"),
+    (97678, "G-Mobile"),
+    (97680, "Unitel"),
+    (97681, "ONDO"),
+    (97683, "G-Mobile"),
+    (97685, "Mobicom"),
+    (97686, "Unitel"),
+    (97688, "Unitel"),
+    (97689, "Unitel"),
+    (97690, "Skytel"),
+    (97691, "Skytel"),
+    (97692, "Skytel"),
+    (97693, "G-Mobile"),
+    (97694, "Mobicom"),
+    (97695, "Mobicom"),
+    (97696, "Skytel"),
+    (97697, "G-Mobile"),
+    (97698, "G-Mobile"),
+    (97699, "Mobicom"),
+    (97790, "NCell"),
+    (98931, "MTCE"),
+    (98932, "IR-MCI"),
+    (98934, "TeleKish"),
+    (98990, "IR-MCI"),
+    (98991, "IR-MCI"),
+    (98992, "IR-MCI"),
+    (98993, "IR-MCI"),
+    (98994, "IR-MCI"),
+    (98996, "IR-MCI"),
+    (99200, "Megafon"),
+    (99201, "Megafon"),
+    (99202, "Megafon"),
+    (99207, "Megafon"),
+    (99210, "O-Mobile"),
+    (99211, "Tcell"),
+    (99212, "Megafon"),
+    (99217, "Babilon-M"),
+    (99218, "ZET-MOBILE"),
+    (99219, "ZET-MOBILE"),
+    (99220, "O-Mobile"),
+    (99221, "Megafon"),
+    (99222, "O-Mobile"),
+    (99227, "Megafon"),
+    (99230, "O-Mobile"),
+    (99238, "ZET-MOBILE"),
+    (99240, "ZET-MOBILE"),
+    (99241, "Megafon"),
+    (99250, "Tcell"),
+    (99255, "Megafon"),
+    (99270, "Tcell"),
+    (99271, "Babilon-M"),
+    (99275, "Babilon-M"),
+    (99277, "Tcell"),
+    (99278, "Megafon"),
+    (99280, "ZET-MOBILE"),
+    (99281, "ZET-MOBILE"),
+    (99287, "Megafon"),
+    (99288, "Megafon"),
+    (99290, "Megafon"),
+    (99291, "ZET-MOBILE"),
+    (99292, "Tcell"),
+    (99293, "Tcell"),
+    (99294, "Babilon-M"),
+    (99297, "Megafon"),
+    (99298, "Babilon-M"),
+    (99299, "Tcell"),
+    (99361, "TM-Cell"),
+    (99362, "TM-Cell"),
+    (99363, "TM-Cell"),
+    (99364, "TM-Cell"),
+    (99365, "TM-Cell"),
+    (99366, "MTS (BARASH Communication)"),
+    (99367, "MTS (BARASH Communication)"),
+    (99368, "MTS (BARASH Communication)"),
+    (99369, "MTS (BARASH Communication)"),
+    (99410, "Azercell"),
+    (99440, "FONEX"),
+    (99444, "Aztelekom"),
+    (99450, "Azercell"),
+    (99451, "Azercell"),
+    (99455, "Bakcell"),
+    (99499, "Bakcell"),
+    (99556, "Cellfie"),
+    (99579, "MagtiCom"),
+    (99620, "Aktel"),
+    (99622, "Sky mobile"),
+    (99650, "Nur Telecom"),
+    (99651, "Katel"),
+    (99654, "Aktel"),
+    (99655, "ALFA Telecom"),
+    (99656, "Winline"),
+    (99657, "Sotel"),
+    (99670, "Nur Telecom"),
+    (99675, "ALFA Telecom"),
+    (99677, "Sky mobile"),
+    (99688, "ALFA Telecom"),
+    (99691, "Smart Connect"),
+    (99699, "ALFA Telecom"),
+    (99850, "Ucell"),
+    (99870, "MobiUZ"),
+    (99877, "Uzbektelecom"),
+    (99880, "Perfectum"),
+    (99887, "MobiUZ"),
+    (99888, "MobiUZ"),
+    (99890, "Beeline"),
+    (99891, "Beeline"),
+    (99892, "Beeline"),
+    (99893, "Ucell"),
+    (99894, "Ucell"),
+    (99895, "Uzbektelecom"),
+    (99897, "MobiUZ"),
+    (99898, "Perfectum"),
+    (99899, "Uzbektelecom"),
+    (124242, "BaTelCo"),
+    (124243, "BaTelCo"),
+    (124244, "BaTelCo"),
+    (124245, "BaTelCo"),
+    (124247, "BaTelCo"),
+    (124248, "BaTelCo"),
+    (124252, "BaTelCo"),
+    (124253, "BaTelCo"),
+    (124254, "BaTelCo"),
+    (124255, "BaTelCo"),
+    (124256, "BaTelCo"),
+    (124257, "BaTelCo"),
+    (124263, "BaTelCo"),
+    (124272, "BaTelCo"),
+    (124273, "aliv"),
+    (124623, "Cable & Wireless"),
+    (124624, "Cable & Wireless"),
+    (124625, "Cable & Wireless"),
+    (124626, "Digicel"),
+    (124628, "Cable & Wireless"),
+    (124635, "Cable & Wireless"),
+    (124645, "Sunbeach Communications"),
+    (124669, "Ozone"),
+    (126453, "Weblinks Limited"),
+    (126458, "Digicel"),
+    (126477, "Cable & Wireless"),
+    (126871, "Digicel"),
+    (128424, "Cable & Wireless"),
+    (128434, "Digicel"),
+    (128436, "Digicel"),
+    (128439, "Digicel"),
+    (128444, "CCT"),
+    (128456, "Cable & Wireless"),
+    (128459, "Cable & Wireless"),
+    (134044, "GIGSKY Mobile"),
+    (134532, "Digicel"),
+    (134542, "Digicel"),
+    (134551, "Digicel"),
+    (134552, "Digicel"),
+    (134554, "Digicel"),
+    (134555, "Digicel"),
+    (134582, "Logic Communications"),
+    (134599, "Cable & Wireless"),
+    (144150, "Digicel Bermuda"),
+    (144151, "Digicel Bermuda"),
+    (144152, "Digicel Bermuda"),
+    (144153, "Digicel Bermuda"),
+    (144159, "Digicel Bermuda"),
+    (144190, "Paradise Mobile"),
+    (144192, "Deltronics"),
+    (147341, "Digicel Grenada"),
+    (147342, "Digicel Grenada"),
+    (147352, "Affordable Island Communications"),
+    (147353, "AWS Grenada"),
+    (147390, "Affordable Island Communications"),
+    (164923, "C&W"),
+    (164924, "Cable & Wireless"),
+    (164943, "Islandcom"),
+    (166434, "Cable & Wireless"),
+    (166439, "Digicel"),
+    (167174, "PTI PACIFICA"),
+    (167183, "i CAN_GSM"),
+    (167184, "i CAN_GSM"),
+    (167185, "i CAN_GSM"),
+    (167187, "Choice Phone"),
+    (167188, "Choice Phone"),
+    (167189, "Choice Phone"),
+    (168424, "ASTCA"),
+    (168425, "Blue Sky"),
+    (168427, "Blue Sky"),
+    (175828, "Cable & Wireless"),
+    (175851, "Digicel"),
+    (175852, "Digicel"),
+    (175858, "Cable & Wireless"),
+    (175871, "Digicel"),
+    (175872, "Digicel"),
+    (175873, "Digicel"),
+    (176722, "Cable & Wireless"),
+    (176723, "Cable & Wireless"),
+    (176724, "Cable & Wireless"),
+    (176727, "Cable & Wireless"),
+    (176728, "Cable & Wireless"),
+    (176729, "Cable & Wireless"),
+    (178452, "Digicel"),
+    (178453, "Digicel"),
+    (178472, "Digicel"),
+    (178764, "CENTENNIAL"),
+    (178765, "CENTENNIAL"),
+    (180920, "Tricom"),
+    (180922, "Claro"),
+    (180923, "Claro"),
+    (180924, "Claro"),
+    (180925, "Claro"),
+    (180926, "Claro"),
+    (180927, "Claro"),
+    (180928, "Claro"),
+    (180929, "Tricom"),
+    (180930, "Viva"),
+    (180931, "Tricom"),
+    (180932, "Tricom"),
+    (180934, "Tricom"),
+    (180941, "Viva"),
+    (180942, "Claro"),
+    (180943, "Viva"),
+    (180944, "Viva"),
+    (180945, "Claro"),
+    (180947, "Tricom"),
+    (180948, "Claro"),
+    (180949, "Claro"),
+    (180951, "Claro"),
+    (180954, "Claro"),
+    (180960, "Claro"),
+    (180962, "Tricom"),
+    (180963, "Tricom"),
+    (180964, "Tricom"),
+    (180965, "Tricom"),
+    (180967, "Claro"),
+    (180969, "Claro"),
+    (180970, "Claro"),
+    (180971, "Claro"),
+    (180972, "Claro"),
+    (180974, "Claro"),
+    (180975, "Claro"),
+    (180976, "Claro"),
+    (180977, "Viva"),
+    (180978, "Claro"),
+    (180979, "Claro"),
+    (180981, "Viva"),
+    (180982, "Claro"),
+    (180983, "Claro"),
+    (180987, "Tricom"),
+    (180991, "Orange"),
+    (180992, "Tricom"),
+    (180993, "Tricom"),
+    (180994, "Tricom"),
+    (180995, "Claro"),
+    (180997, "Orange"),
+    (180998, "Orange"),
+    (180999, "Tricom"),
+    (186825, "Digicel"),
+    (186826, "Digicel"),
+    (186827, "bmobile"),
+    (186828, "bmobile"),
+    (186829, "bmobile"),
+    (186843, "Digicel"),
+    (186846, "bmobile"),
+    (186847, "bmobile"),
+    (186848, "bmobile"),
+    (186849, "bmobile"),
+    (186868, "bmobile"),
+    (186948, "Cable & Wireless"),
+    (186955, "CariGlobe St. Kitts"),
+    (187620, "Cable & Wireless"),
+    (187622, "Cable & Wireless"),
+    (187623, "Cable & Wireless"),
+    (187624, "Digicel"),
+    (187625, "Digicel"),
+    (187626, "Digicel"),
+    (187628, "Digicel"),
+    (187629, "Digicel"),
+    (187630, "Digicel"),
+    (187632, "Cable & Wireless"),
+    (187633, "Cable & Wireless"),
+    (187634, "Cable & Wireless"),
+    (187635, "Digicel"),
+    (187636, "Digicel"),
+    (187637, "Digicel"),
+    (187638, "Digicel"),
+    (187639, "Digicel"),
+    (187640, "Digicel"),
+    (187641, "Digicel"),
+    (187642, "Digicel"),
+    (187643, "Digicel"),
+    (187645, "Digicel"),
+    (187646, "Digicel"),
+    (187647, "Digicel"),
+    (187648, "Digicel"),
+    (187649, "Digicel"),
+    (187650, "Digicel"),
+    (187651, "C&W"),
+    (187652, "Digicel"),
+    (187653, "Cable & Wireless"),
+    (187654, "Cable & Wireless"),
+    (187656, "Digicel"),
+    (187657, "Digicel"),
+    (187658, "Digicel"),
+    (187659, "Digicel"),
+    (187677, "Cable & Wireless"),
+    (187680, "Cable & Wireless"),
+    (187682, "Cable & Wireless"),
+    (187683, "Cable & Wireless"),
+    (187684, "Digicel"),
+    (187685, "Digicel"),
+    (187686, "Digicel"),
+    (187687, "Digicel"),
+    (187688, "Digicel"),
+    (187689, "Digicel"),
+    (193925, "Claro"),
+    (212612, "Orange"),
+    (212614, "Orange"),
+    (212617, "Orange"),
+    (212619, "Orange"),
+    (212620, "Orange"),
+    (212621, "Orange"),
+    (212622, "Maroc Telecom"),
+    (212623, "Maroc Telecom"),
+    (212624, "Maroc Telecom"),
+    (212625, "Orange"),
+    (212626, "Inwi"),
+    (212627, "Inwi"),
+    (212628, "Maroc Telecom"),
+    (212629, "Inwi"),
+    (212630, "Inwi"),
+    (212631, "Orange"),
+    (212632, "Orange"),
+    (212633, "Inwi"),
+    (212634, "Inwi"),
+    (212635, "Inwi"),
+    (212636, "Maroc Telecom"),
+    (212637, "Maroc Telecom"),
+    (212638, "Inwi"),
+    (212639, "Maroc Telecom"),
+    (212640, "Inwi"),
+    (212641, "Maroc Telecom"),
+    (212642, "Maroc Telecom"),
+    (212643, "Maroc Telecom"),
+    (212644, "Orange"),
+    (212645, "Orange"),
+    (212646, "Inwi"),
+    (212647, "Inwi"),
+    (212648, "Maroc Telecom"),
+    (212649, "Orange"),
+    (212656, "Orange"),
+    (212657, "Orange"),
+    (212660, "Orange"),
+    (212661, "Maroc Telecom"),
+    (212662, "Maroc Telecom"),
+    (212663, "Orange"),
+    (212664, "Orange"),
+    (212665, "Orange"),
+    (212666, "Maroc Telecom"),
+    (212667, "Maroc Telecom"),
+    (212668, "Maroc Telecom"),
+    (212669, "Orange"),
+    (212674, "Orange"),
+    (212675, "Orange"),
+    (212679, "Orange"),
+    (212680, "Inwi"),
+    (212681, "Inwi"),
+    (212682, "Maroc Telecom"),
+    (212684, "Orange"),
+    (212687, "Inwi"),
+    (212688, "Orange"),
+    (212689, "Maroc Telecom"),
+    (212690, "Inwi"),
+    (212691, "Orange"),
+    (212693, "Orange"),
+    (212694, "Orange"),
+    (212695, "Inwi"),
+    (212696, "Maroc Telecom"),
+    (212697, "Maroc Telecom"),
+    (212698, "Inwi"),
+    (212699, "Inwi"),
+    (220556, "QCell"),
+    (223200, "Orange"),
+    (223217, "Sotelma"),
+    (229014, "Celtiis"),
+    (229015, "MTN"),
+    (230525, "Cellplus"),
+    (230526, "Cellplus"),
+    (230527, "MTML"),
+    (230528, "MTML"),
+    (230529, "MTML"),
+    (230550, "Cellplus"),
+    (230552, "MTML"),
+    (230553, "Cellplus"),
+    (230571, "Emtel"),
+    (230572, "Emtel"),
+    (230573, "Emtel"),
+    (230574, "Emtel"),
+    (230580, "Cellplus"),
+    (230581, "Cellplus"),
+    (230582, "Cellplus"),
+    (230583, "Cellplus"),
+    (230584, "Emtel"),
+    (230585, "Emtel"),
+    (230586, "MTML"),
+    (230588, "MTML"),
+    (230589, "MTML"),
+    (230590, "Cellplus"),
+    (230591, "Cellplus"),
+    (230592, "Cellplus"),
+    (230593, "Emtel"),
+    (230594, "Cellplus"),
+    (230595, "MTML"),
+    (230596, "MTML"),
+    (230597, "Emtel"),
+    (230598, "Emtel"),
+    (230700, "Cellplus"),
+    (230701, "Emtel"),
+    (230702, "MTML"),
+    (230703, "Emtel"),
+    (230704, "Emtel"),
+    (230705, "Cellplus"),
+    (230706, "Cellplus"),
+    (230707, "Emtel"),
+    (230730, "Emtel"),
+    (230731, "MTML"),
+    (230733, "Cellplus"),
+    (231220, "Liberia Telecom"),
+    (231330, "West Africa Telecom"),
+    (231555, "Lonestar Cell"),
+    (234701, "Airtel"),
+    (234703, "MTN"),
+    (234704, "MTN"),
+    (234705, "Glo"),
+    (234706, "MTN"),
+    (234707, "MTN"),
+    (234708, "Airtel"),
+    (234709, "Multilinks"),
+    (234801, "MAFAB"),
+    (234802, "Airtel"),
+    (234803, "MTN"),
+    (234804, "Ntel"),
+    (234805, "Glo"),
+    (234806, "MTN"),
+    (234807, "Glo"),
+    (234808, "Airtel"),
+    (234809, "9mobile"),
+    (234810, "MTN"),
+    (234811, "Glo"),
+    (234812, "Airtel"),
+    (234813, "MTN"),
+    (234814, "MTN"),
+    (234815, "Glo"),
+    (234816, "MTN"),
+    (234817, "9mobile"),
+    (234818, "9mobile"),
+    (234819, "Starcomms"),
+    (234901, "Airtel"),
+    (234902, "Airtel"),
+    (234903, "MTN"),
+    (234904, "Airtel"),
+    (234905, "Glo"),
+    (234906, "MTN"),
+    (234907, "Airtel"),
+    (234908, "9mobile"),
+    (234909, "9mobile"),
+    (234911, "Airtel"),
+    (234912, "Airtel"),
+    (234913, "MTN"),
+    (234915, "Glo"),
+    (234916, "MTN"),
+    (237650, "MTN Cameroon"),
+    (237651, "MTN Cameroon"),
+    (237652, "MTN Cameroon"),
+    (237653, "MTN Cameroon"),
+    (237654, "MTN Cameroon"),
+    (237655, "Orange"),
+    (237656, "Orange"),
+    (237657, "Orange"),
+    (237658, "Orange"),
+    (237659, "Orange"),
+    (237680, "MTN Cameroon"),
+    (237681, "MTN Cameroon"),
+    (237682, "MTN Cameroon"),
+    (237683, "MTN Cameroon"),
+    (237684, "NEXTTEL"),
+    (237685, "NEXTTEL"),
+    (237686, "Orange"),
+    (237687, "Orange"),
+    (237688, "Orange"),
+    (237689, "Orange"),
+    (254120, "Telkom"),
+    (254121, "Infura"),
+    (254124, "Finserve"),
+    (254744, "Homeland Media"),
+    (254747, "JTL"),
+    (254757, "Safaricom"),
+    (254758, "Safaricom"),
+    (254759, "Safaricom"),
+    (254760, "Mobile Pay"),
+    (254761, "Airtel"),
+    (254762, "Airtel"),
+    (254763, "Finserve"),
+    (254764, "Finserve"),
+    (254765, "Finserve"),
+    (254766, "Finserve"),
+    (254767, "Sema Mobile"),
+    (254768, "Safaricom"),
+    (254769, "Safaricom"),
+    (256720, "Smile"),
+    (256721, "LycaMobile"),
+    (256724, "Hamilton Telecom"),
+    (256726, "LycaMobile"),
+    (256727, "LycaMobile"),
+    (256728, "Talkio"),
+    (256730, "Airtel"),
+    (256736, "Hamilton Telecom"),
+    (256790, "MTN"),
+    (256791, "MTN"),
+    (256792, "MTN"),
+    (256795, "Airtel"),
+    (256798, "Africell"),
+    (256799, "Africell"),
+    (262692, "SFR"),
+    (267743, "Orange"),
+    (267744, "Orange"),
+    (267748, "Orange"),
+    (267749, "BTC Mobile"),
+    (267750, "Orange"),
+    (267751, "Orange"),
+    (267752, "Orange"),
+    (267753, "Orange"),
+    (267754, "Mascom"),
+    (267755, "Mascom"),
+    (267756, "Mascom"),
+    (267757, "Orange"),
+    (267758, "BTC Mobile"),
+    (267759, "Mascom"),
+    (267760, "Mascom"),
+    (267761, "Mascom"),
+    (267762, "Mascom"),
+    (267763, "Orange"),
+    (267764, "Orange"),
+    (267765, "Orange"),
+    (267766, "Mascom"),
+    (267767, "Mascom"),
+    (267768, "BTC Mobile"),
+    (267769, "Orange"),
+    (267770, "Mascom"),
+    (267771, "Mascom"),
+    (267772, "BTC Mobile"),
+    (267773, "Orange"),
+    (267774, "Orange"),
+    (267775, "Orange"),
+    (267776, "Mascom"),
+    (267777, "Mascom"),
+    (267778, "Mascom"),
+    (267779, "Orange"),
+    (278190, "TelAfrica (Wirles Connect)"),
+    (278191, "TelAfrica (Wirles Connect)"),
+    (278192, "TelAfrica (Wirles Connect)"),
+    (297690, "SETAR"),
+    (297699, "SETAR"),
+    (297995, "SETAR"),
+    (306900, "BWS"),
+    (306906, "Wind"),
+    (306907, "Wind"),
+    (306908, "Wind"),
+    (306909, "Wind"),
+    (306950, "Vodafone"),
+    (306951, "Vodafone"),
+    (306955, "Vodafone"),
+    (306956, "Vodafone"),
+    (306957, "Vodafone"),
+    (306958, "Vodafone"),
+    (306959, "Vodafone"),
+    (306991, "Wind"),
+    (306992, "Wind"),
+    (306993, "Wind"),
+    (306994, "Wind"),
+    (306995, "Wind"),
+    (306996, "Wind"),
+    (306997, "Wind"),
+    (306998, "Wind"),
+    (306999, "Wind"),
+    (316351, "Glotell B.V (V-Tell NL)"),
+    (316352, "Lancelot"),
+    (316353, "KPN"),
+    (316356, "ASPIDER Solutions Nederland B.V."),
+    (316357, "ASPIDER Solutions Nederland B.V."),
+    (316358, "ASPIDER Solutions Nederland B.V."),
+    (316359, "ASPIDER Solutions Nederland B.V."),
+    (316580, "Private Mobility Nederland"),
+    (316587, "KPN"),
+    (316588, "KPN"),
+    (316589, "KPN"),
+    (316599, "Motto"),
+    (324510, "DIGI Communications"),
+    (324618, "N.M.B.S."),
+    (324630, "Lancelot Telecom"),
+    (324631, "Lancelot Telecom"),
+    (324650, "Telenet"),
+    (324660, "Lycamobile"),
+    (324661, "Lycamobile"),
+    (324662, "Lycamobile"),
+    (324663, "Lycamobile"),
+    (324664, "Lycamobile"),
+    (324665, "Vectone"),
+    (324666, "Vectone"),
+    (324667, "Vectone"),
+    (324669, "Voxbone SA"),
+    (324670, "Telenet"),
+    (324671, "Join Experience Belgium"),
+    (324672, "Join Experience Belgium"),
+    (324674, "Febo Telecom"),
+    (324676, "Lycamobile"),
+    (324677, "Lycamobile"),
+    (324678, "Lycamobile"),
+    (324679, "Interactive Digital Media GmbH"),
+    (324686, "OnOff Télécom SASU"),
+    (324687, "Lancelot Telecom"),
+    (324688, "Lancelot Telecom"),
+    (324689, "Febo Telecom"),
+    (324802, "TISMI BV"),
+    (324803, "Lancelot Telecom"),
+    (324805, "Citymesh"),
+    (324806, "Telenet"),
+    (324807, "MessageBird BV"),
+    (324809, "Ericsson NV"),
+    (336000, "Free Mobile"),
+    (336001, "Orange France"),
+    (336002, "SFR"),
+    (336003, "Bouygues"),
+    (336006, "Free Mobile"),
+    (336007, "SFR"),
+    (336008, "Orange France"),
+    (336009, "Bouygues"),
+    (336040, "Afone"),
+    (336041, "Afone"),
+    (336042, "e*Message"),
+    (336043, "e*Message"),
+    (336044, "Afone"),
+    (336045, "SFR"),
+    (336046, "SFR"),
+    (336047, "SFR"),
+    (336048, "SFR"),
+    (336049, "SFR"),
+    (336050, "Euroinformation Telecom"),
+    (336051, "Euroinformation Telecom"),
+    (336052, "Euroinformation Telecom"),
+    (336053, "Euroinformation Telecom"),
+    (336054, "Euroinformation Telecom"),
+    (336055, "Lycamobile"),
+    (336056, "Lycamobile"),
+    (336057, "Lycamobile"),
+    (336058, "Lycamobile"),
+    (336059, "Lycamobile"),
+    (336060, "e*Message"),
+    (336061, "e*Message"),
+    (336062, "e*Message"),
+    (336063, "e*Message"),
+    (336064, "Afone"),
+    (336065, "Euroinformation Telecom"),
+    (336066, "Euroinformation Telecom"),
+    (336067, "Euroinformation Telecom"),
+    (336068, "Euroinformation Telecom"),
+    (336069, "Euroinformation Telecom"),
+    (336410, "La poste telecom"),
+    (336411, "La poste telecom"),
+    (336412, "La poste telecom"),
+    (336413, "La poste telecom"),
+    (336414, "La poste telecom"),
+    (336415, "La poste telecom"),
+    (336440, "La poste telecom"),
+    (336441, "Orange France"),
+    (336442, "Orange France"),
+    (336443, "Orange France"),
+    (336444, "Transatel"),
+    (336445, "Transatel"),
+    (336446, "Transatel"),
+    (336447, "La poste telecom"),
+    (336448, "La poste telecom"),
+    (336449, "La poste telecom"),
+    (336530, "Bouygues"),
+    (336531, "Bouygues"),
+    (336532, "Bouygues"),
+    (336533, "Bouygues"),
+    (336534, "Bouygues"),
+    (336535, "Free Mobile"),
+    (336536, "Free Mobile"),
+    (336537, "Free Mobile"),
+    (336538, "Free Mobile"),
+    (336539, "Free Mobile"),
+    (336567, "La poste telecom"),
+    (336568, "La poste telecom"),
+    (337500, "Euroinformation Telecom"),
+    (337501, "SFR"),
+    (337502, "SFR"),
+    (337503, "SFR"),
+    (337504, "SFR"),
+    (337506, "Orange France"),
+    (337508, "SFR"),
+    (337509, "SFR"),
+    (337516, "SFR"),
+    (337517, "Completel"),
+    (337518, "Lebara France Limited"),
+    (337519, "Lebara France Limited"),
+    (337521, "Lebara France Limited"),
+    (337522, "Lebara France Limited"),
+    (337523, "Lebara France Limited"),
+    (337524, "Lebara France Limited"),
+    (337525, "Lebara France Limited"),
+    (337526, "SFR"),
+    (337527, "Lebara France Limited"),
+    (337528, "Lebara France Limited"),
+    (337529, "Lebara France Limited"),
+    (337540, "Lebara France Limited"),
+    (337541, "Lebara France Limited"),
+    (337542, "Lebara France Limited"),
+    (337543, "Prixtel"),
+    (337544, "Lebara France Limited"),
+    (337545, "Lebara France Limited"),
+    (337546, "Mobiquithings"),
+    (337547, "ACN Communications"),
+    (337548, "Completel"),
+    (337549, "Completel"),
+    (337560, "Euroinformation Telecom"),
+    (337561, "Euroinformation Telecom"),
+    (337562, "Euroinformation Telecom"),
+    (337565, "Transatel"),
+    (337566, "Transatel"),
+    (337567, "Transatel"),
+    (337568, "Transatel"),
+    (337569, "Transatel"),
+    (337572, "Mobiquithings"),
+    (337573, "Mobiquithings"),
+    (337574, "Coriolis Telecom"),
+    (337575, "Coriolis Telecom"),
+    (337579, "Legos"),
+    (337700, "Orange France"),
+    (337701, "Orange France"),
+    (337702, "Orange France"),
+    (337703, "SFR"),
+    (337704, "SFR"),
+    (337705, "Euroinformation Telecom"),
+    (337706, "Euroinformation Telecom"),
+    (337707, "Euroinformation Telecom"),
+    (337708, "Euroinformation Telecom"),
+    (337709, "Euroinformation Telecom"),
+    (337710, "Euroinformation Telecom"),
+    (337711, "Euroinformation Telecom"),
+    (337712, "Euroinformation Telecom"),
+    (337713, "SFR"),
+    (337714, "SFR"),
+    (337716, "Euroinformation Telecom"),
+    (337717, "Euroinformation Telecom"),
+    (337718, "Euroinformation Telecom"),
+    (337750, "SFR"),
+    (337751, "SFR"),
+    (337752, "SFR"),
+    (337753, "SFR"),
+    (337754, "SFR"),
+    (337755, "Mobiquithings"),
+    (337756, "Mobiquithings"),
+    (337757, "Free Mobile"),
+    (337807, "Lebara France Limited"),
+    (337808, "Lebara France Limited"),
+    (337809, "Onoff telecom"),
+    (337846, "La poste telecom"),
+    (337847, "La poste telecom"),
+    (337848, "La poste telecom"),
+    (337849, "Euroinformation Telecom"),
+    (345901, "Movistar"),
+    (345906, "Vodafone"),
+    (346016, "Orange"),
+    (346018, "Orange"),
+    (346019, "Orange"),
+    (346020, "Lycamobile"),
+    (346021, "Lycamobile"),
+    (346023, "Lycamobile"),
+    (346024, "Yoigo"),
+    (346025, "Yoigo"),
+    (346026, "Yoigo"),
+    (346027, "Lebara"),
+    (346028, "Lycamobile"),
+    (346029, "DIA"),
+    (346031, "Yoigo"),
+    (346032, "Yoigo"),
+    (346033, "Yoigo"),
+    (346034, "Vodafone"),
+    (346035, "Vodafone"),
+    (346036, "Vodafone"),
+    (346037, "Vodafone"),
+    (346038, "Vodafone"),
+    (346039, "Lebara"),
+    (346040, "Orange"),
+    (346041, "Yoigo"),
+    (346042, "Yoigo"),
+    (346043, "Yoigo"),
+    (346044, "Lebara"),
+    (346045, "Orange"),
+    (346046, "Lebara"),
+    (346047, "Lebara"),
+    (346048, "Lebara"),
+    (346049, "Lebara"),
+    (346110, "Orange"),
+    (346112, "Yoigo"),
+    (346113, "Yoigo"),
+    (346122, "Lycamobile"),
+    (346124, "Lycamobile"),
+    (346125, "Lycamobile"),
+    (346210, "Republica Movil"),
+    (346211, "Republica Movil"),
+    (346212, "Movistar"),
+    (346213, "Republica Movil"),
+    (346214, "Republica Movil"),
+    (346215, "Aire Networks"),
+    (346216, "Republica Movil"),
+    (346218, "Vodafone"),
+    (346230, "Yoigo"),
+    (346231, "Yoigo"),
+    (346236, "Altecom"),
+    (346340, "Lebara"),
+    (346341, "Lebara"),
+    (346343, "Carrier Enabler"),
+    (346345, "Movistar"),
+    (346681, "Truphone"),
+    (346685, "Orange"),
+    (346686, "Parlem"),
+    (346688, "Parlem"),
+    (346725, "Lebara"),
+    (346728, "Lebara"),
+    (346729, "Lebara"),
+    (346810, "Movistar"),
+    (346811, "Movistar"),
+    (346812, "Movistar"),
+    (346813, "Movistar"),
+    (346814, "Movistar"),
+    (346815, "Movistar"),
+    (346816, "Yoigo"),
+    (346840, "Movistar"),
+    (346841, "Movistar"),
+    (346842, "Movistar"),
+    (346843, "Movistar"),
+    (346845, "Movistar"),
+    (346846, "Telecable"),
+    (346848, "Euskaltel"),
+    (346880, "YouMobile"),
+    (346881, "YouMobile"),
+    (346882, "Yoigo"),
+    (346883, "Yoigo"),
+    (346884, "Yoigo"),
+    (346885, "YouMobile"),
+    (346886, "Euskaltel"),
+    (346887, "Euskaltel"),
+    (346888, "Euskaltel"),
+    (346889, "PepePhone"),
+    (346919, "Yoigo"),
+    (346927, "Carrefour"),
+    (346931, "Orange"),
+    (346932, "Yoigo"),
+    (346933, "Carrefour"),
+    (346935, "Yoigo"),
+    (346937, "Yoigo"),
+    (346938, "Yoigo"),
+    (346939, "Yoigo"),
+    (346942, "Orange"),
+    (346944, "Yoigo"),
+    (346945, "Yoigo"),
+    (346946, "Yoigo"),
+    (346981, "R"),
+    (346989, "Vodafone"),
+    (347110, "Zinnia"),
+    (347111, "Vodafone"),
+    (347112, "Orange"),
+    (347115, "Orange"),
+    (347117, "Vodafone"),
+    (347121, "Yoigo"),
+    (347122, "Yoigo"),
+    (347123, "Yoigo"),
+    (347124, "Yoigo"),
+    (347125, "Yoigo"),
+    (347126, "Yoigo"),
+    (347127, "Yoigo"),
+    (347128, "Yoigo"),
+    (347170, "Movistar"),
+    (347171, "Vodafone"),
+    (347177, "Movistar"),
+    (347221, "Yoigo"),
+    (347222, "Yoigo"),
+    (347223, "Yoigo"),
+    (347224, "Yoigo"),
+    (347225, "Yoigo"),
+    (347226, "Yoigo"),
+    (347227, "Yoigo"),
+    (347228, "Yoigo"),
+    (347277, "Vodafone"),
+    (347440, "Alai"),
+    (347442, "PTV"),
+    (347446, "PTV"),
+    (347449, "Alai"),
+    (347477, "Orange"),
+    (347478, "Orange"),
+    (350601, "Melmasti"),
+    (350606, "GibTel"),
+    (351921, "Vodafone"),
+    (351923, "NOS"),
+    (351924, "MEO"),
+    (351925, "MEO"),
+    (351926, "MEO"),
+    (351927, "MEO"),
+    (352651, "POST"),
+    (352658, "POST"),
+    (352671, "JOIN"),
+    (352678, "JOIN"),
+    (353890, "Tesco Mobile"),
+    (353891, "Tesco Mobile"),
+    (353892, "Liffey Telecom"),
+    (353893, "Tesco Mobile"),
+    (353894, "Liffey Telecom"),
+    (353895, "3"),
+    (353896, "Tesco Mobile"),
+    (353897, "Tesco Mobile"),
+    (353898, "Tesco Mobile"),
+    (354385, "Síminn"),
+    (354388, "IMC"),
+    (354389, "IMC"),
+    (354630, "IMC"),
+    (354632, "Tismi"),
+    (354636, "Öryggisfjarskipti"),
+    (354637, "Öryggisfjarskipti"),
+    (354638, "Öryggisfjarskipti"),
+    (354639, "Öryggisfjarskipti"),
+    (354640, "Öryggisfjarskipti"),
+    (354641, "Öryggisfjarskipti"),
+    (354644, "Nova"),
+    (354646, "IMC"),
+    (354647, "IMC"),
+    (354649, "Vodafone"),
+    (354650, "IMC"),
+    (354651, "IMC"),
+    (354655, "Vodafone"),
+    (354659, "Vodafone"),
+    (354680, "Vodafone"),
+    (354686, "Vodafone"),
+    (354687, "Vodafone"),
+    (354688, "Vodafone"),
+    (354750, "Síminn"),
+    (354755, "Síminn"),
+    (354757, "Vodafone"),
+    (354882, "Síminn"),
+    (354883, "Síminn"),
+    (354888, "Síminn"),
+    (356981, "Melita Mobile"),
+    (356988, "GO Mobile"),
+    (356989, "epic"),
+    (358436, "DNA"),
+    (358438, "DNA"),
+    (358450, "Telia"),
+    (358451, "Elisa"),
+    (358452, "Elisa"),
+    (358453, "Elisa"),
+    (358456, "Elisa"),
+    (358458, "Elisa"),
+    (359988, "Bob"),
+    (359989, "A1"),
+    (363178, "One"),
+    (370645, "Tele 2"),
+    (370646, "Tele 2"),
+    (370647, "Tele 2"),
+    (370648, "Tele 2"),
+    (370660, "BITĖ"),
+    (370662, "Telia"),
+    (370669, "Telia"),
+    (370680, "Telia"),
+    (370681, "BITĖ"),
+    (370682, "Telia"),
+    (370683, "Tele 2"),
+    (370684, "Tele 2"),
+    (370685, "BITĖ"),
+    (370686, "Telia"),
+    (370687, "Telia"),
+    (370688, "Telia"),
+    (370689, "BITĖ"),
+    (370690, "BITĖ"),
+    (370691, "BITĖ"),
+    (370692, "Telia"),
+    (370693, "Telia"),
+    (370694, "Telia"),
+    (370695, "Telia"),
+    (370696, "Telia"),
+    (370698, "Telia"),
+    (370699, "BITĖ"),
+    (371200, "Tele2"),
+    (371201, "Bite Latvia"),
+    (371202, "LMT"),
+    (371203, "Tele2"),
+    (371204, "Tele2"),
+    (371205, "Tele2"),
+    (371206, "Bite Latvia"),
+    (371207, "Bite Latvia"),
+    (371208, "Bite Latvia"),
+    (371221, "Bite Latvia"),
+    (371222, "Bite Latvia"),
+    (371223, "Tele2"),
+    (371224, "LMT"),
+    (371225, "Bite Latvia"),
+    (371230, "Bite Latvia"),
+    (371248, "Tele2"),
+    (371249, "Tele2"),
+    (371251, "Bite Latvia"),
+    (371252, "Tele2"),
+    (371253, "Tele2"),
+    (371254, "LMT"),
+    (371255, "Bite Latvia"),
+    (371256, "LMT"),
+    (371257, "LMT"),
+    (371258, "Triatel"),
+    (371259, "Tele2"),
+    (371260, "Tele2"),
+    (371267, "Tele2"),
+    (371268, "Tele2"),
+    (371269, "Tele2"),
+    (371270, "Tele2"),
+    (371271, "Tele2"),
+    (371273, "LMT"),
+    (371274, "Bite Latvia"),
+    (371275, "Bite Latvia"),
+    (371277, "Bite Latvia"),
+    (371278, "LMT"),
+    (371280, "LMT"),
+    (371281, "Tele2"),
+    (371282, "Tele2"),
+    (371283, "LMT"),
+    (371286, "LMT"),
+    (371287, "LMT"),
+    (371288, "Tele2"),
+    (371289, "Tele2"),
+    (371291, "LMT"),
+    (371292, "LMT"),
+    (371293, "LMT"),
+    (371294, "LMT"),
+    (371295, "Tele2"),
+    (371296, "Tele2"),
+    (371297, "Tele2"),
+    (371298, "Tele2"),
+    (371299, "Tele2"),
+    (372545, "Elisa"),
+    (372589, "Elisa"),
+    (372829, "Tele 2"),
+    (373610, "Orange"),
+    (373611, "Orange"),
+    (373620, "Orange"),
+    (373621, "Orange"),
+    (373774, "IDC"),
+    (373775, "IDC"),
+    (373776, "IDC"),
+    (373777, "IDC"),
+    (373778, "IDC"),
+    (373779, "IDC"),
+    (375291, "Velcom"),
+    (375292, "MTS"),
+    (375293, "Velcom"),
+    (375294, "Belcel"),
+    (375295, "MTS"),
+    (375296, "Velcom"),
+    (375297, "MTS"),
+    (375298, "MTS"),
+    (375299, "Velcom"),
+    (381676, "GLOBALTEL"),
+    (381677, "GLOBALTEL"),
+    (381678, "Vectone Mobile"),
+    (385970, "Hrvatski Telekom"),
+    (385976, "Hrvatski Telekom"),
+    (385977, "Hrvatski Telekom"),
+    (385979, "Hrvatski Telekom"),
+    (386651, "SŽ - Infrastruktura"),
+    (386656, "SoftNet"),
+    (386657, "Novatel"),
+    (386658, "Novatel"),
+    (386695, "Novatel"),
+    (389731, "A1"),
+    (389732, "A1"),
+    (389733, "A1"),
+    (389734, "A1"),
+    (389735, "A1"),
+    (389736, "T-Mobile"),
+    (389737, "MTEL"),
+    (389738, "MTEL"),
+    (389742, "T-Mobile"),
+    (389746, "T-Mobile"),
+    (389790, "A1"),
+    (389791, "A1"),
+    (389792, "Lyca Mobile"),
+    (389793, "Lyca Mobile"),
+    (389794, "Lyca Mobile"),
+    (389795, "Lyca Mobile"),
+    (389799, "A1"),
+    (393780, "spusu"),
+    (393784, "Vodafone"),
+    (407000, "Enigma-System"),
+    (407013, "Lycamobile"),
+    (407014, "Lycamobile"),
+    (407015, "Lycamobile"),
+    (407016, "Lycamobile"),
+    (407017, "Lycamobile"),
+    (407018, "Lycamobile"),
+    (407019, "Lycamobile"),
+    (417500, "Swisscom"),
+    (417507, "Swisscom"),
+    (417508, "Swisscom"),
+    (417509, "Swisscom"),
+    (417519, "Swisscom"),
+    (417550, "Swisscom"),
+    (417551, "Swisscom"),
+    (417552, "Swisscom"),
+    (417553, "Swisscom"),
+    (417557, "Swisscom"),
+    (417600, "Sunrise"),
+    (417601, "Sunrise"),
+    (417602, "Sunrise"),
+    (417603, "Sunrise"),
+    (417604, "Sunrise"),
+    (417605, "Sunrise"),
+    (417710, "Swisscom"),
+    (417712, "Swisscom"),
+    (417713, "Swisscom"),
+    (417715, "Swisscom"),
+    (417730, "Sunrise"),
+    (417750, "Swisscom"),
+    (417751, "Swisscom"),
+    (417752, "Swisscom"),
+    (417753, "Swisscom"),
+    (417780, "BeeOne Communications"),
+    (417781, "BeeOne Communications"),
+    (417788, "Vectone Mobile Limited (Mundio)"),
+    (417789, "Vectone Mobile Limited (Mundio)"),
+    (417840, "Sunrise"),
+    (417841, "Sunrise"),
+    (417842, "Sunrise"),
+    (417844, "spusu"),
+    (417847, "MTEL"),
+    (417990, "Swisscom"),
+    (417991, "Swisscom"),
+    (417992, "Swisscom"),
+    (417993, "Swisscom"),
+    (417994, "Swisscom"),
+    (417995, "Swisscom"),
+    (417996, "Swisscom"),
+    (417999, "Comfone AG"),
+    (420601, "O2"),
+    (420602, "O2"),
+    (420603, "T-Mobile"),
+    (420604, "T-Mobile"),
+    (420605, "T-Mobile"),
+    (420606, "O2"),
+    (420607, "O2"),
+    (420608, "Vodafone"),
+    (420702, "O2"),
+    (420706, "DataCell"),
+    (420731, "T-Mobile"),
+    (420732, "T-Mobile"),
+    (420733, "T-Mobile"),
+    (420734, "T-Mobile"),
+    (420735, "T-Mobile"),
+    (420736, "T-Mobile"),
+    (420737, "T-Mobile"),
+    (420738, "T-Mobile"),
+    (420739, "T-Mobile"),
+    (420773, "Vodafone"),
+    (420774, "Vodafone"),
+    (420775, "Vodafone"),
+    (420776, "Vodafone"),
+    (420777, "Vodafone"),
+    (420792, "O2"),
+    (421901, "T-Mobile (Slovak Telekom)"),
+    (421902, "T-Mobile (Slovak Telekom)"),
+    (421903, "T-Mobile (Slovak Telekom)"),
+    (421904, "T-Mobile (Slovak Telekom)"),
+    (421905, "Orange"),
+    (421906, "Orange"),
+    (421907, "Orange"),
+    (421908, "Orange"),
+    (421910, "T-Mobile (Slovak Telekom)"),
+    (421911, "T-Mobile (Slovak Telekom)"),
+    (421912, "T-Mobile (Slovak Telekom)"),
+    (421914, "T-Mobile (Slovak Telekom)"),
+    (421915, "Orange"),
+    (421916, "Orange"),
+    (421917, "Orange"),
+    (421918, "Orange"),
+    (421919, "Orange"),
+    (421940, "Telefonica O2"),
+    (421944, "Telefonica O2"),
+    (421945, "Orange"),
+    (421947, "Telefonica O2"),
+    (421948, "Telefonica O2"),
+    (421949, "Telefonica O2"),
+    (421950, "4ka of SWAN"),
+    (421951, "4ka of SWAN"),
+    (421952, "4ka of SWAN"),
+    (423650, "Telecom Liechtenstein"),
+    (423659, "Telecom Liechtenstein"),
+    (423666, "Datamobile AG"),
+    (423668, "Velos IoT"),
+    (436485, "Three"),
+    (436770, "T-Mobile AT"),
+    (436771, "T-Mobile AT"),
+    (436772, "T-Mobile AT"),
+    (436776, "T-Mobile AT"),
+    (436778, "T-Mobile AT"),
+    (436779, "T-Mobile AT"),
+    (436819, "Three"),
+    (447106, "O2"),
+    (447107, "O2"),
+    (447300, "EE"),
+    (447301, "EE"),
+    (447302, "EE"),
+    (447303, "EE"),
+    (447304, "EE"),
+    (447305, "Virgin Mobile"),
+    (447306, "Virgin Mobile"),
+    (447340, "Vodafone"),
+    (447341, "Vodafone"),
+    (447342, "Vodafone"),
+    (447343, "Lebara"),
+    (447349, "Vodafone"),
+    (447350, "Vodafone"),
+    (447351, "Vodafone"),
+    (447352, "Vodafone"),
+    (447353, "Vodafone"),
+    (447360, "Three"),
+    (447361, "Three"),
+    (447362, "Three"),
+    (447363, "Three"),
+    (447364, "Three"),
+    (447365, "Three"),
+    (447366, "Three"),
+    (447367, "Three"),
+    (447370, "Vodafone"),
+    (447371, "Vodafone"),
+    (447372, "Vodafone"),
+    (447373, "Vodafone"),
+    (447375, "EE"),
+    (447376, "EE"),
+    (447377, "EE"),
+    (447378, "Three"),
+    (447379, "Vodafone"),
+    (447380, "Three"),
+    (447381, "O2"),
+    (447382, "O2"),
+    (447383, "Three"),
+    (447384, "Vodafone"),
+    (447385, "Vodafone"),
+    (447386, "Vodafone"),
+    (447387, "Vodafone"),
+    (447388, "Vodafone"),
+    (447391, "Vodafone"),
+    (447392, "Vodafone"),
+    (447393, "Vodafone"),
+    (447394, "O2"),
+    (447395, "O2"),
+    (447396, "EE"),
+    (447398, "EE"),
+    (447399, "EE"),
+    (447400, "Three"),
+    (447401, "Three"),
+    (447402, "Three"),
+    (447403, "Three"),
+    (447404, "Lycamobile"),
+    (447405, "Lycamobile"),
+    (447407, "Vodafone"),
+    (447409, "Orange"),
+    (447410, "Orange"),
+    (447411, "Three"),
+    (447412, "Three"),
+    (447413, "Three"),
+    (447414, "Three"),
+    (447415, "EE"),
+    (447416, "Orange"),
+    (447419, "Orange"),
+    (447420, "Orange"),
+    (447421, "Orange"),
+    (447422, "Orange"),
+    (447423, "Vodafone"),
+    (447424, "Lycamobile"),
+    (447425, "Vodafone"),
+    (447426, "Three"),
+    (447427, "Three"),
+    (447428, "Three"),
+    (447429, "Three"),
+    (447430, "O2"),
+    (447431, "O2"),
+    (447432, "EE"),
+    (447433, "EE"),
+    (447434, "EE"),
+    (447435, "Vodafone"),
+    (447436, "Vodafone"),
+    (447437, "Vodafone"),
+    (447438, "Lycamobile"),
+    (447440, "Lycamobile"),
+    (447442, "Vodafone"),
+    (447443, "Vodafone"),
+    (447444, "Vodafone"),
+    (447445, "Three"),
+    (447446, "Three"),
+    (447447, "Three"),
+    (447448, "Lycamobile"),
+    (447449, "Three"),
+    (447450, "Three"),
+    (447451, "Vectone Mobile"),
+    (447452, "Manx Telecom"),
+    (447453, "Three"),
+    (447454, "Three"),
+    (447455, "Three"),
+    (447456, "Three"),
+    (447459, "Lycamobile"),
+    (447460, "Three"),
+    (447461, "O2"),
+    (447462, "Three"),
+    (447463, "Three"),
+    (447464, "Vodafone"),
+    (447465, "Three"),
+    (447466, "Lycamobile"),
+    (447467, "Vodafone"),
+    (447468, "Vodafone"),
+    (447469, "Vodafone"),
+    (447470, "Vodafone"),
+    (447471, "Vodafone"),
+    (447480, "Three"),
+    (447481, "Three"),
+    (447482, "Three"),
+    (447483, "EE"),
+    (447484, "EE"),
+    (447485, "EE"),
+    (447486, "EE"),
+    (447487, "EE"),
+    (447489, "O2"),
+    (447490, "Three"),
+    (447491, "Three"),
+    (447492, "Three"),
+    (447493, "Vodafone"),
+    (447494, "EE"),
+    (447495, "EE"),
+    (447496, "EE"),
+    (447497, "EE"),
+    (447498, "EE"),
+    (447499, "O2"),
+    (447500, "Vodafone"),
+    (447501, "Vodafone"),
+    (447502, "Vodafone"),
+    (447503, "Vodafone"),
+    (447504, "EE"),
+    (447505, "EE"),
+    (447506, "EE"),
+    (447507, "EE"),
+    (447508, "EE"),
+    (447521, "O2"),
+    (447522, "O2"),
+    (447523, "O2"),
+    (447525, "O2"),
+    (447526, "O2"),
+    (447527, "Orange"),
+    (447528, "Orange"),
+    (447529, "Orange"),
+    (447530, "Orange"),
+    (447531, "Orange"),
+    (447533, "Three"),
+    (447534, "EE"),
+    (447535, "EE"),
+    (447536, "Orange"),
+    (447538, "EE"),
+    (447539, "EE"),
+    (447550, "EE"),
+    (447551, "Vodafone"),
+    (447552, "Vodafone"),
+    (447553, "Vodafone"),
+    (447554, "Vodafone"),
+    (447555, "Vodafone"),
+    (447556, "Orange"),
+    (447557, "Vodafone"),
+    (447570, "Vodafone"),
+    (447572, "EE"),
+    (447573, "EE"),
+    (447574, "EE"),
+    (447575, "Three"),
+    (447576, "Three"),
+    (447577, "Three"),
+    (447578, "Three"),
+    (447579, "Orange"),
+    (447580, "Orange"),
+    (447581, "Orange"),
+    (447582, "Orange"),
+    (447583, "Orange"),
+    (447584, "Vodafone"),
+    (447585, "Vodafone"),
+    (447586, "Vodafone"),
+    (447587, "Vodafone"),
+    (447588, "Three"),
+    (447624, "Manx Telecom"),
+    (447717, "Vodafone"),
+    (447720, "O2"),
+    (447721, "Vodafone"),
+    (447722, "EE"),
+    (447723, "Three"),
+    (447724, "O2"),
+    (447725, "O2"),
+    (447726, "EE"),
+    (447727, "Three"),
+    (447728, "Three"),
+    (447729, "O2"),
+    (447733, "Vodafone"),
+    (447735, "Three"),
+    (447737, "Three"),
+    (447740, "O2"),
+    (447741, "Vodafone"),
+    (447742, "O2"),
+    (447743, "O2"),
+    (447745, "O2"),
+    (447746, "O2"),
+    (447747, "Vodafone"),
+    (447748, "Vodafone"),
+    (447749, "O2"),
+    (447750, "O2"),
+    (447751, "O2"),
+    (447752, "O2"),
+    (447753, "O2"),
+    (447754, "O2"),
+    (447756, "O2"),
+    (447757, "EE"),
+    (447758, "EE"),
+    (447759, "O2"),
+    (447761, "O2"),
+    (447762, "O2"),
+    (447763, "O2"),
+    (447764, "O2"),
+    (447772, "Orange"),
+    (447773, "Orange"),
+    (447777, "EE"),
+    (447779, "Orange"),
+    (447781, "Sure"),
+    (447782, "Three"),
+    (447783, "O2"),
+    (447784, "O2"),
+    (447790, "Orange"),
+    (447791, "Orange"),
+    (447792, "Orange"),
+    (447793, "O2"),
+    (447794, "Orange"),
+    (447795, "Vodafone"),
+    (447796, "Vodafone"),
+    (447797, "JT"),
+    (447798, "Vodafone"),
+    (447799, "Vodafone"),
+    (447800, "Orange"),
+    (447801, "O2"),
+    (447802, "O2"),
+    (447803, "O2"),
+    (447804, "EE"),
+    (447805, "Orange"),
+    (447806, "EE"),
+    (447807, "Orange"),
+    (447808, "O2"),
+    (447809, "O2"),
+    (447810, "Vodafone"),
+    (447818, "Vodafone"),
+    (447819, "O2"),
+    (447820, "O2"),
+    (447821, "O2"),
+    (447823, "Vodafone"),
+    (447824, "Vodafone"),
+    (447825, "Vodafone"),
+    (447826, "Vodafone"),
+    (447827, "Vodafone"),
+    (447828, "Three"),
+    (447830, "Three"),
+    (447831, "Vodafone"),
+    (447832, "Three"),
+    (447833, "Vodafone"),
+    (447834, "O2"),
+    (447835, "O2"),
+    (447836, "Vodafone"),
+    (447837, "Orange"),
+    (447838, "Three"),
+    (447846, "Three"),
+    (447847, "EE"),
+    (447848, "Three"),
+    (447850, "O2"),
+    (447851, "O2"),
+    (447852, "EE"),
+    (447853, "Three"),
+    (447854, "Orange"),
+    (447855, "Orange"),
+    (447856, "O2"),
+    (447857, "O2"),
+    (447858, "O2"),
+    (447859, "Three"),
+    (447860, "O2"),
+    (447861, "Three"),
+    (447862, "Three"),
+    (447863, "Three"),
+    (447865, "Three"),
+    (447866, "Orange"),
+    (447867, "Vodafone"),
+    (447868, "Three"),
+    (447869, "Three"),
+    (447870, "Orange"),
+    (447871, "O2"),
+    (447872, "O2"),
+    (447873, "O2"),
+    (447875, "Orange"),
+    (447876, "Vodafone"),
+    (447877, "Three"),
+    (447878, "Three"),
+    (447879, "Vodafone"),
+    (447880, "Vodafone"),
+    (447881, "Vodafone"),
+    (447882, "Three"),
+    (447883, "Three"),
+    (447884, "Vodafone"),
+    (447885, "O2"),
+    (447886, "Three"),
+    (447887, "Vodafone"),
+    (447888, "Three"),
+    (447889, "O2"),
+    (447890, "Orange"),
+    (447891, "Orange"),
+    (447894, "O2"),
+    (447895, "O2"),
+    (447896, "Orange"),
+    (447897, "Three"),
+    (447898, "Three"),
+    (447899, "Vodafone"),
+    (447900, "Vodafone"),
+    (447901, "Vodafone"),
+    (447902, "O2"),
+    (447903, "EE"),
+    (447904, "EE"),
+    (447905, "EE"),
+    (447906, "EE"),
+    (447907, "O2"),
+    (447908, "EE"),
+    (447909, "Vodafone"),
+    (447910, "EE"),
+    (447912, "O2"),
+    (447913, "EE"),
+    (447914, "EE"),
+    (447915, "Three"),
+    (447916, "Three"),
+    (447917, "Vodafone"),
+    (447918, "Vodafone"),
+    (447919, "Vodafone"),
+    (447920, "Vodafone"),
+    (447924, "Manx Telecom"),
+    (447929, "Orange"),
+    (447930, "EE"),
+    (447931, "EE"),
+    (447932, "EE"),
+    (447933, "O2"),
+    (447934, "O2"),
+    (447935, "O2"),
+    (447936, "O2"),
+    (447937, "JT"),
+    (447938, "O2"),
+    (447939, "EE"),
+    (447955, "O2"),
+    (447960, "EE"),
+    (447961, "EE"),
+    (447962, "EE"),
+    (447963, "EE"),
+    (447970, "Orange"),
+    (447971, "Orange"),
+    (447972, "Orange"),
+    (447973, "Orange"),
+    (447974, "Orange"),
+    (447975, "Orange"),
+    (447976, "Orange"),
+    (447977, "Orange"),
+    (447979, "Vodafone"),
+    (447980, "Orange"),
+    (447988, "Three"),
+    (447989, "Orange"),
+    (447990, "Vodafone"),
+    (447999, "O2"),
+    (452395, "telia"),
+    (452590, "mi carrier services"),
+    (452591, "link mobile"),
+    (452592, "link mobile"),
+    (452593, "compatel limited"),
+    (452594, "firmafon"),
+    (452595, "link mobile"),
+    (452596, "viptel"),
+    (452597, "3"),
+    (453434, "telenor"),
+    (454291, "3"),
+    (454292, "3"),
+    (454293, "cbb mobil"),
+    (454294, "3"),
+    (454295, "3"),
+    (454296, "telia"),
+    (454297, "telia"),
+    (454298, "telia"),
+    (454299, "telia"),
+    (454626, "Lancelot Telecom"),
+    (454911, "tdc"),
+    (454912, "tdc"),
+    (454914, "tdc"),
+    (454917, "tdc"),
+    (454919, "tdc"),
+    (454921, "tdc"),
+    (454927, "tdc"),
+    (454928, "tdc"),
+    (454971, "tdc"),
+    (454977, "tdc"),
+    (455060, "ipvision"),
+    (455061, "svr technologies (mach connectivity)"),
+    (455062, "cbb mobil"),
+    (455063, "mundio mobile"),
+    (455064, "lycamobile denmark ltd"),
+    (455065, "lebara limited"),
+    (455066, "cbb mobil"),
+    (455067, "cbb mobil"),
+    (455068, "cbb mobil"),
+    (455069, "3"),
+    (455188, "telia"),
+    (455189, "telia"),
+    (455210, "firstcom"),
+    (455211, "3"),
+    (455212, "3"),
+    (455220, "link mobile"),
+    (455222, "lebara limited"),
+    (455225, "cbb mobil"),
+    (455230, "tdc"),
+    (455233, "cbb mobil"),
+    (455240, "tdc"),
+    (455242, "cbb mobil"),
+    (455244, "cbb mobil"),
+    (455250, "tdc"),
+    (455251, "link mobile"),
+    (455252, "lebara limited"),
+    (455253, "cbb mobil"),
+    (455254, "simservice"),
+    (455255, "cbb mobil"),
+    (455256, "simservice"),
+    (455257, "simservice"),
+    (455258, "tdc"),
+    (455259, "42 telecom ab"),
+    (455319, "telia"),
+    (455333, "lebara limited"),
+    (455398, "nextgen mobile ldt t/a cardboardfish"),
+    (456050, "telenor"),
+    (456090, "lebara limited"),
+    (456091, "telenor"),
+    (456092, "telenor"),
+    (456093, "telenor"),
+    (456094, "telenor"),
+    (456095, "telenor"),
+    (456096, "tripple track europe"),
+    (456097, "tripple track europe"),
+    (456098, "telavox"),
+    (456099, "svr technologies (mach connectivity)"),
+    (456146, "telia"),
+    (456441, "tdc"),
+    (456463, "telenor"),
+    (456464, "waoo"),
+    (456465, "waoo"),
+    (456466, "waoo"),
+    (456467, "waoo"),
+    (456468, "waoo"),
+    (456469, "waoo"),
+    (456471, "tdc"),
+    (456957, "telenor"),
+    (456958, "telenor"),
+    (456959, "telenor"),
+    (457010, "tdc"),
+    (457011, "tdc"),
+    (457012, "tdc"),
+    (457013, "tdc"),
+    (457014, "tdc"),
+    (457015, "tdc"),
+    (457019, "telenor"),
+    (457030, "telenor"),
+    (457031, "telenor"),
+    (457033, "telenor"),
+    (457060, "telenor"),
+    (457070, "telenor"),
+    (457071, "telenor"),
+    (457080, "telenor"),
+    (457087, "telenor"),
+    (457088, "supertel danmark"),
+    (457089, "telenor"),
+    (457170, "yousee"),
+    (457171, "telenor"),
+    (457172, "tdc"),
+    (457173, "cbb mobil"),
+    (457175, "telenor"),
+    (457176, "telenor"),
+    (457177, "tdc"),
+    (457178, "telenor"),
+    (457179, "telenor"),
+    (457190, "3"),
+    (457191, "telecom x"),
+    (457192, "fullrate"),
+    (457193, "cbb mobil"),
+    (457194, "telenor"),
+    (457195, "telenor"),
+    (457197, "mundio mobile"),
+    (457198, "mundio mobile"),
+    (457199, "firmafon"),
+    (457879, "supertel danmark"),
+    (458130, "cbb mobil"),
+    (458131, "cbb mobil"),
+    (458132, "cbb mobil"),
+    (458133, "cbb mobil"),
+    (458134, "cbb mobil"),
+    (458135, "cbb mobil"),
+    (458136, "cbb mobil"),
+    (458138, "mundio mobile"),
+    (458139, "mundio mobile"),
+    (458140, "ipnordic"),
+    (458141, "3"),
+    (458144, "fullrate"),
+    (458145, "telavox"),
+    (458146, "mundio mobile"),
+    (458147, "mundio mobile"),
+    (458148, "mundio mobile"),
+    (458149, "mundio mobile"),
+    (458161, "tdc"),
+    (458170, "cbb mobil"),
+    (458171, "tdc"),
+    (458172, "fullrate"),
+    (458173, "tdc"),
+    (458174, "tdc"),
+    (458175, "tdc"),
+    (458176, "cbb mobil"),
+    (458177, "ipvision"),
+    (458178, "cbb mobil"),
+    (458179, "cbb mobil"),
+    (458180, "ipvision"),
+    (458181, "maxtel.dk"),
+    (458182, "polperro"),
+    (458188, "ipvision"),
+    (458190, "lebara limited"),
+    (458191, "lebara limited"),
+    (458192, "lebara limited"),
+    (458193, "lebara limited"),
+    (458194, "lebara limited"),
+    (458195, "cbb mobil"),
+    (458196, "cbb mobil"),
+    (458197, "cbb mobil"),
+    (458198, "cbb mobil"),
+    (458199, "telenor"),
+    (459110, "lebara limited"),
+    (459111, "lebara limited"),
+    (459112, "simservice"),
+    (459113, "simservice"),
+    (459114, "simservice"),
+    (459115, "tdc"),
+    (459116, "tdc"),
+    (459117, "tdc"),
+    (459118, "tdc"),
+    (459119, "lebara limited"),
+    (459120, "tismi bv"),
+    (459121, "simservice"),
+    (459122, "tdc"),
+    (459123, "tdc"),
+    (459124, "tdc"),
+    (459125, "tdc"),
+    (459126, "mundio mobile"),
+    (459127, "mundio mobile"),
+    (459128, "mundio mobile"),
+    (459129, "mundio mobile"),
+    (459131, "telenor"),
+    (459132, "telenor"),
+    (459133, "telenor"),
+    (459134, "telenor"),
+    (459135, "telenor"),
+    (459136, "telenor"),
+    (459137, "telenor"),
+    (459138, "telenor"),
+    (459139, "telenor"),
+    (459150, "telenor"),
+    (459151, "telenor"),
+    (459152, "tdc"),
+    (459153, "tdc"),
+    (459154, "tdc"),
+    (459155, "tdc"),
+    (459156, "tdc"),
+    (459157, "mundio mobile"),
+    (459158, "nextgen mobile ldt t/a cardboardfish"),
+    (459159, "simservice"),
+    (459189, "tdc"),
+    (459190, "intelecom"),
+    (459191, "maxtel.dk"),
+    (459217, "interactive digital media gmbh"),
+    (459221, "tdc"),
+    (459222, "tdc"),
+    (459223, "42 telecom ab"),
+    (459224, "simservice"),
+    (459225, "mundio mobile"),
+    (459226, "mundio mobile"),
+    (459227, "mundio mobile"),
+    (459228, "mundio mobile"),
+    (459229, "beepsend ab"),
+    (459240, "gigsky aps"),
+    (459241, "gigsky aps"),
+    (459242, "gigsky aps"),
+    (459243, "tdc"),
+    (459244, "ipnordic"),
+    (459245, "compatel limited"),
+    (459270, "ice danmark"),
+    (459272, "thyfon"),
+    (459280, "voxbone"),
+    (459281, "gigsky aps"),
+    (459282, "flexfone"),
+    (459283, "tdc"),
+    (459290, "fullrate"),
+    (459299, "ipvision"),
+    (459310, "fullrate"),
+    (459311, "benemen lso (tdc)"),
+    (459312, "tdc"),
+    (459313, "tdc"),
+    (459314, "simservice"),
+    (459315, "simservice"),
+    (459316, "simservice"),
+    (459317, "simservice"),
+    (459318, "simservice"),
+    (459319, "tdc"),
+    (459320, "fullrate"),
+    (459321, "simservice"),
+    (459322, "simservice"),
+    (459323, "simservice"),
+    (459324, "simservice"),
+    (459325, "telenor"),
+    (459326, "telenor"),
+    (459327, "telenor"),
+    (459328, "telenor"),
+    (459329, "telenor"),
+    (459330, "fullrate"),
+    (459331, "tdc"),
+    (459332, "telenor"),
+    (459333, "onoffapp"),
+    (459334, "simservice"),
+    (459335, "simservice"),
+    (459336, "simservice"),
+    (459337, "simservice"),
+    (459338, "simservice"),
+    (459339, "uni-tel"),
+    (459340, "fullrate"),
+    (459341, "telenor"),
+    (459342, "telenor"),
+    (459343, "telenor"),
+    (459344, "telenor"),
+    (459345, "telenor"),
+    (459346, "simservice"),
+    (459347, "simservice"),
+    (459348, "simservice"),
+    (459349, "simservice"),
+    (459360, "3"),
+    (459361, "telenor"),
+    (459362, "telenor"),
+    (459363, "tdc"),
+    (459370, "telenor"),
+    (459371, "simservice"),
+    (459372, "simservice"),
+    (459373, "simservice"),
+    (459375, "telenor"),
+    (459376, "tdc"),
+    (459377, "tdc"),
+    (459378, "telenor"),
+    (459379, "tdc"),
+    (459381, "tdc"),
+    (459382, "tdc"),
+    (459440, "Nuuday"),
+    (459441, "Nuuday"),
+    (459442, "Nuuday"),
+    (459481, "Nuuday"),
+    (467010, "SPINBOX AB"),
+    (467011, "Telenor Sverige"),
+    (467012, "SPINBOX AB"),
+    (467014, "Telenor Sverige"),
+    (467015, "Tele2 Sverige"),
+    (467016, "Tele2 Sverige"),
+    (467018, "SPINBOX AB"),
+    (467200, "Tele2 Sverige"),
+    (467201, "Tele2 Sverige"),
+    (467202, "Tele2 Sverige"),
+    (467203, "Tele2 Sverige"),
+    (467204, "Tele2 Sverige"),
+    (467206, "Com Hem"),
+    (467207, "SOLUNO BC AB"),
+    (467210, "SVENSK KONSUMENTMOBI"),
+    (467211, "SVENSK KONSUMENTMOBI"),
+    (467212, "TeliaSonera"),
+    (467213, "TeliaSonera"),
+    (467215, "Telenor Sverige"),
+    (467216, "Telenor Sverige"),
+    (467217, "Telenor Sverige"),
+    (467218, "Telenor Sverige"),
+    (467219, "Telenor Sverige"),
+    (467230, "HI3G Access"),
+    (467231, "HI3G Access"),
+    (467232, "HI3G Access"),
+    (467233, "HI3G Access"),
+    (467235, "Telenor Sverige"),
+    (467236, "Telenor Sverige"),
+    (467237, "Telenor Sverige"),
+    (467238, "Telenor Sverige"),
+    (467239, "Telenor Sverige"),
+    (467242, "WIFOG AB"),
+    (467243, "WIFOG AB"),
+    (467244, "Telenor Sverige"),
+    (467245, "TeliaSonera"),
+    (467246, "TeliaSonera"),
+    (467247, "TeliaSonera"),
+    (467248, "TeliaSonera"),
+    (467249, "TeliaSonera"),
+    (467261, "GLOBETOUCH AB"),
+    (467262, "GLOBETOUCH AB"),
+    (467263, "GLOBETOUCH AB"),
+    (467265, "TeliaSonera"),
+    (467267, "TeliaSonera"),
+    (467268, "TeliaSonera"),
+    (467270, "TeliaSonera"),
+    (467271, "TeliaSonera"),
+    (467272, "TeliaSonera"),
+    (467273, "TeliaSonera"),
+    (467274, "TeliaSonera"),
+    (467276, "Lycamobile Sweden"),
+    (467277, "Lycamobile Sweden"),
+    (467278, "Lycamobile Sweden"),
+    (467279, "Telenor Sverige"),
+    (467282, "Telecom3 Networks"),
+    (467283, "Tele2 Sverige"),
+    (467284, "Tele2 Sverige"),
+    (467285, "Tele2 Sverige"),
+    (467286, "Tele2 Sverige"),
+    (467287, "Tele2 Sverige"),
+    (467288, "Telenor Sverige"),
+    (467289, "Qall Telecom AB"),
+    (467290, "Tele2 Sverige"),
+    (467291, "Tele2 Sverige"),
+    (467292, "Tele2 Sverige"),
+    (467293, "Tele2 Sverige"),
+    (467294, "Tele2 Sverige"),
+    (467296, "Telenor Sverige"),
+    (467297, "Telenor Sverige"),
+    (467298, "Telenor Sverige"),
+    (467299, "Telenor Sverige"),
+    (467301, "Maingate (Sierra Wireless)"),
+    (467310, "Telenor Sverige"),
+    (467311, "TeliaSonera"),
+    (467313, "iMEZ"),
+    (467314, "Telenor Sverige"),
+    (467315, "Telenor Sverige"),
+    (467316, "Alltele Sverige"),
+    (467318, "ACN Communications Sweden"),
+    (467319, "TeliaSonera"),
+    (467320, "Telenor Sverige"),
+    (467321, "Tele2 Sverige"),
+    (467322, "Tele2 Sverige"),
+    (467323, "Telenor Sverige"),
+    (467324, "Telenor Sverige"),
+    (467325, "Telenor Sverige"),
+    (467326, "Telenor Sverige"),
+    (467327, "Ventelo Sverige"),
+    (467328, "Telenor Sverige"),
+    (467340, "Telenor Sverige"),
+    (467341, "Telenor Sverige"),
+    (467342, "Telenor Sverige"),
+    (467343, "Telenor Sverige"),
+    (467344, "Telenor Sverige"),
+    (467346, "Telenor Sverige"),
+    (467348, "Lycamobile Sweden"),
+    (467349, "Lycamobile Sweden"),
+    (467350, "HI3G Access"),
+    (467351, "HI3G Access"),
+    (467352, "HI3G Access"),
+    (467353, "HI3G Access"),
+    (467354, "HI3G Access"),
+    (467355, "Tele2 Sverige"),
+    (467356, "Tele2 Sverige"),
+    (467357, "Tele2 Sverige"),
+    (467358, "Tele2 Sverige"),
+    (467359, "Tele2 Sverige"),
+    (467380, "TeliaSonera"),
+    (467381, "TeliaSonera"),
+    (467382, "TeliaSonera"),
+    (467383, "TeliaSonera"),
+    (467384, "TeliaSonera"),
+    (467385, "Telenor Sverige"),
+    (467387, "Tele2 Sverige"),
+    (467388, "Telenor Sverige"),
+    (467389, "Tele2 Sverige"),
+    (467600, "HI3G Access"),
+    (467601, "HI3G Access"),
+    (467602, "HI3G Access"),
+    (467603, "HI3G Access"),
+    (467604, "HI3G Access"),
+    (467605, "Tele2 Sverige"),
+    (467606, "Tele2 Sverige"),
+    (467607, "Tele2 Sverige"),
+    (467608, "Tele2 Sverige"),
+    (467609, "Tele2 Sverige"),
+    (467610, "TeliaSonera"),
+    (467611, "TeliaSonera"),
+    (467612, "TeliaSonera"),
+    (467613, "TeliaSonera"),
+    (467614, "TeliaSonera"),
+    (467615, "Lycamobile Sweden"),
+    (467616, "HI3G Access"),
+    (467617, "HI3G Access"),
+    (467618, "HI3G Access"),
+    (467619, "HI3G Access"),
+    (467635, "Telenor Sverige"),
+    (467636, "Telenor Sverige"),
+    (467637, "Telenor Sverige"),
+    (467638, "Easy Telecom AB (BILDNINGSAGENTEN 559)"),
+    (467640, "Tele2 Sverige"),
+    (467641, "Tele2 Sverige"),
+    (467642, "Tele2 Sverige"),
+    (467643, "Lycamobile Sweden"),
+    (467644, "Lycamobile Sweden"),
+    (467645, "Lycamobile Sweden"),
+    (467647, "Tele2 Sverige"),
+    (467648, "GLOBETOUCH AB"),
+    (467660, "Telenor Sverige"),
+    (467661, "Telenor Sverige"),
+    (467662, "Telenor Sverige"),
+    (467663, "Telenor Sverige"),
+    (467664, "Telenor Sverige"),
+    (467665, "Tele2 Sverige"),
+    (467668, "Tele2 Sverige"),
+    (467670, "Tele2 Sverige"),
+    (467671, "Tele2 Sverige"),
+    (467674, "Lycamobile Sweden"),
+    (467675, "Lycamobile Sweden"),
+    (467676, "TeliaSonera"),
+    (467677, "TeliaSonera"),
+    (467678, "TeliaSonera"),
+    (467679, "TeliaSonera"),
+    (467680, "TeliaSonera"),
+    (467681, "TeliaSonera"),
+    (467682, "TeliaSonera"),
+    (467683, "TeliaSonera"),
+    (467684, "TeliaSonera"),
+    (467685, "Telenor Sverige"),
+    (467686, "Telenor Sverige"),
+    (467687, "Telenor Sverige"),
+    (467688, "Telenor Sverige"),
+    (467689, "Telenor Sverige"),
+    (467690, "Tele2 Sverige"),
+    (467691, "Tele2 Sverige"),
+    (467692, "Tele2 Sverige"),
+    (467693, "Tele2 Sverige"),
+    (467694, "Tele2 Sverige"),
+    (467695, "Lycamobile Sweden"),
+    (467696, "Lycamobile Sweden"),
+    (467697, "Lycamobile Sweden"),
+    (467698, "TeliaSonera"),
+    (467699, "TeliaSonera"),
+    (467901, "Tele2 Sverige"),
+    (467902, "Tele2 Sverige"),
+    (467903, "Tele2 Sverige"),
+    (467904, "Tele2 Sverige"),
+    (467905, "Tele2 Sverige"),
+    (467906, "Tele2 Sverige"),
+    (467907, "Tele2 Sverige"),
+    (467908, "Tele2 Sverige"),
+    (467909, "Tele2 Sverige"),
+    (467910, "TELL ESS AB"),
+    (467915, "TeliaSonera"),
+    (467930, "HI3G Access"),
+    (467931, "HI3G Access"),
+    (467932, "HI3G Access"),
+    (467933, "HI3G Access"),
+    (467934, "HI3G Access"),
+    (467950, "JUNYVERSE AB"),
+    (467951, "JUNYVERSE AB"),
+    (467952, "JUNYVERSE AB"),
+    (467953, "JUNYVERSE AB"),
+    (467954, "JUNYVERSE AB"),
+    (467997, "Telenor Sverige"),
+    (474000, "telia"),
+    (474001, "telia"),
+    (474002, "telia"),
+    (474003, "telia"),
+    (474010, "telia"),
+    (474011, "telia"),
+    (474014, "telia"),
+    (474020, "telia"),
+    (474021, "telia"),
+    (474022, "telenor norge"),
+    (474023, "telia"),
+    (474024, "telia"),
+    (474025, "sierra wireless"),
+    (474026, "sierra wireless"),
+    (474027, "sierra wireless"),
+    (474028, "telenor norge"),
+    (474029, "telia"),
+    (474035, "sierra wireless"),
+    (474036, "sierra wireless"),
+    (474037, "sierra wireless"),
+    (474060, "telia"),
+    (474061, "telia"),
+    (474062, "telia"),
+    (474063, "telia"),
+    (474064, "telia"),
+    (474065, "telia telecom solution"),
+    (474066, "Billity"),
+    (474067, "nextgentel"),
+    (474068, "telenor norge"),
+    (474069, "telenor norge"),
+    (474080, "telia telecom solution"),
+    (474081, "telia telecom solution"),
+    (474090, "telia telecom solution"),
+    (474091, "telia telecom solution"),
+    (474093, "telenor norge"),
+    (474100, "telia"),
+    (474101, "telia"),
+    (474104, "telia"),
+    (474106, "telia"),
+    (474107, "telia"),
+    (474110, "telia"),
+    (474111, "chilimobil"),
+    (474112, "chilimobil"),
+    (474113, "chilimobil"),
+    (474114, "telia"),
+    (474115, "chilimobil"),
+    (474116, "chilimobil"),
+    (474117, "telia"),
+    (474118, "telia"),
+    (474119, "telia"),
+    (474536, "nkom (nasjonal kommunikasjonsmyndighet)"),
+    (474537, "erate"),
+    (474538, "erate"),
+    (474590, "telenor norge"),
+    (474592, "lyca mobile"),
+    (474595, "telenor norge"),
+    (474596, "telenor norge"),
+    (474598, "telenor norge"),
+    (474599, "telenor norge"),
+    (474610, "telenor norge"),
+    (474617, "telenor norge"),
+    (474618, "telenor norge"),
+    (474619, "telenor norge"),
+    (474620, "telenor norge"),
+    (474628, "erate"),
+    (474629, "erate"),
+    (474651, "Lyse Tele"),
+    (474652, "Lyse Tele"),
+    (474653, "Lyse Tele"),
+    (474666, "telenor norge"),
+    (474667, "telenor norge"),
+    (474671, "lyca mobile"),
+    (474672, "lyca mobile"),
+    (474673, "Ixt AS"),
+    (474690, "telenor norge"),
+    (474691, "telenor norge"),
+    (474692, "telenor norge"),
+    (474693, "telenor norge"),
+    (474694, "telenor norge"),
+    (474695, "telenor norge"),
+    (474696, "telenor norge"),
+    (474697, "telia"),
+    (474698, "telenor norge"),
+    (474710, "telenor norge"),
+    (474711, "telenor norge"),
+    (474712, "telenor norge"),
+    (474713, "telia"),
+    (474714, "telia"),
+    (474715, "telia"),
+    (474716, "telia"),
+    (474717, "telia"),
+    (474718, "chilimobil"),
+    (474719, "chilimobil"),
+    (474740, "telenor norge"),
+    (474741, "telenor norge"),
+    (474742, "telenor norge"),
+    (474743, "telenor norge"),
+    (474756, "altibox"),
+    (474757, "altibox"),
+    (474758, "altibox"),
+    (474759, "altibox"),
+    (474769, "telia"),
+    (474770, "telenor norge"),
+    (474771, "telenor norge"),
+    (474775, "telenor norge"),
+    (474776, "telenor norge"),
+    (474790, "telenor norge"),
+    (474798, "telenor norge"),
+    (474799, "telenor norge"),
+    (474830, "telenor norge"),
+    (474831, "telenor norge"),
+    (474832, "telenor norge"),
+    (474833, "telia"),
+    (474834, "telia"),
+    (474835, "telia"),
+    (474836, "telia"),
+    (474838, "Lyse Tele"),
+    (474839, "Lyse Tele"),
+    (474841, "telenor norge"),
+    (474842, "telenor norge"),
+    (474848, "erate"),
+    (474849, "erate"),
+    (474850, "telia"),
+    (474851, "telia"),
+    (474858, "telenor norge"),
+    (474859, "erate"),
+    (474860, "telia"),
+    (474861, "telia"),
+    (474862, "telia"),
+    (474863, "telia"),
+    (474864, "telia"),
+    (474865, "telia"),
+    (474866, "telia"),
+    (474867, "telia"),
+    (474868, "telia"),
+    (474884, "telenor norge"),
+    (474885, "telenor norge"),
+    (474886, "telia"),
+    (474888, "telia"),
+    (474889, "telia"),
+    (474890, "Lyse Tele"),
+    (474891, "Lyse Tele"),
+    (474892, "Lyse Tele"),
+    (474893, "telia"),
+    (474894, "telenor norge"),
+    (474895, "telia"),
+    (474896, "telenor norge"),
+    (474898, "telenor norge"),
+    (474899, "telia"),
+    (479042, "svea billing services"),
+    (479043, "svea billing services"),
+    (479044, "telia"),
+    (479048, "telavox"),
+    (479049, "telavox"),
+    (479120, "chilimobil"),
+    (479121, "chilimobil"),
+    (479122, "chilimobil"),
+    (479123, "chilimobil"),
+    (479125, "lyca mobile"),
+    (479126, "lyca mobile"),
+    (479127, "lyca mobile"),
+    (479128, "lyca mobile"),
+    (479129, "lyca mobile"),
+    (479218, "telenor norge"),
+    (479219, "telenor norge"),
+    (479236, "telenor norge"),
+    (479238, "telenor norge"),
+    (479239, "telenor norge"),
+    (479258, "telenor norge"),
+    (479259, "telenor norge"),
+    (479290, "telenor norge"),
+    (479310, "telenor norge"),
+    (479311, "telenor norge"),
+    (479312, "telenor norge"),
+    (479313, "telenor norge"),
+    (479314, "telenor norge"),
+    (479315, "telenor norge"),
+    (479316, "telenor norge"),
+    (479318, "telenor norge"),
+    (479319, "telenor norge"),
+    (479330, "telenor norge"),
+    (479331, "telenor norge"),
+    (479332, "telenor norge"),
+    (479333, "telenor norge"),
+    (479334, "telenor norge"),
+    (479335, "telenor norge"),
+    (479336, "telenor norge"),
+    (479337, "telia"),
+    (479338, "telenor norge"),
+    (479339, "telenor norge"),
+    (479350, "telenor norge"),
+    (479351, "telenor norge"),
+    (479352, "telenor norge"),
+    (479353, "telenor norge"),
+    (479354, "telenor norge"),
+    (479355, "telenor norge"),
+    (479356, "telenor norge"),
+    (479357, "telia"),
+    (479358, "telenor norge"),
+    (479359, "telenor norge"),
+    (479370, "telenor norge"),
+    (479371, "telenor norge"),
+    (479372, "telenor norge"),
+    (479373, "telenor norge"),
+    (479374, "telenor norge"),
+    (479375, "telenor norge"),
+    (479376, "telenor norge"),
+    (479377, "telia"),
+    (479378, "telenor norge"),
+    (479379, "telenor norge"),
+    (479390, "telenor norge"),
+    (479400, "telia"),
+    (479401, "telia"),
+    (479402, "telia"),
+    (479403, "telenor norge"),
+    (479404, "com4"),
+    (479405, "telenor norge"),
+    (479406, "Lyse Tele"),
+    (479407, "Lyse Tele"),
+    (479408, "Lyse Tele"),
+    (479409, "Lyse Tele"),
+    (479410, "telia"),
+    (479411, "telia"),
+    (479412, "telia"),
+    (479440, "telenor norge"),
+    (479441, "telenor norge"),
+    (479442, "telia"),
+    (479443, "telia"),
+    (479444, "Lyse Tele"),
+    (479445, "Lyse Tele"),
+    (479446, "Lyse Tele"),
+    (479447, "telia"),
+    (479448, "telia"),
+    (479449, "telia"),
+    (479450, "telia telecom solution"),
+    (479451, "telia telecom solution"),
+    (479452, "telia telecom solution"),
+    (479453, "telia telecom solution"),
+    (479454, "telia telecom solution"),
+    (479471, "lyca mobile"),
+    (479472, "lyca mobile"),
+    (479473, "lyca mobile"),
+    (479474, "telenor norge"),
+    (479475, "telenor norge"),
+    (479476, "telenor norge"),
+    (479477, "telenor norge"),
+    (479478, "telenor norge"),
+    (479479, "telenor norge"),
+    (479499, "telia"),
+    (479600, "phonect"),
+    (479601, "telenor norge"),
+    (479604, "telenor norge"),
+    (479609, "telenor norge"),
+    (479660, "erate"),
+    (479661, "erate"),
+    (479662, "erate"),
+    (479663, "erate"),
+    (479670, "telia"),
+    (479671, "telia"),
+    (479672, "telia"),
+    (479673, "telia"),
+    (479674, "telia"),
+    (479675, "telia"),
+    (479679, "telenor norge"),
+    (479689, "telenor norge"),
+    (479690, "erate"),
+    (479691, "erate"),
+    (479692, "telia"),
+    (479693, "telenor norge"),
+    (479694, "telia"),
+    (479695, "lyca mobile"),
+    (479696, "lyca mobile"),
+    (479697, "lyca mobile"),
+    (479698, "lyca mobile"),
+    (479699, "lyca mobile"),
+    (479725, "Lyse Tele"),
+    (479726, "Lyse Tele"),
+    (479727, "Lyse Tele"),
+    (479728, "Lyse Tele"),
+    (479729, "Lyse Tele"),
+    (479730, "Lyse Tele"),
+    (479731, "Lyse Tele"),
+    (479732, "Lyse Tele"),
+    (479733, "Lyse Tele"),
+    (479734, "Lyse Tele"),
+    (479735, "lyca mobile"),
+    (479736, "lyca mobile"),
+    (479737, "lyca mobile"),
+    (479738, "lyca mobile"),
+    (479739, "lyca mobile"),
+    (479790, "telia"),
+    (479791, "telia"),
+    (479792, "telia"),
+    (479793, "telia"),
+    (479794, "telia"),
+    (479838, "telenor norge"),
+    (479839, "telenor norge"),
+    (479854, "telia"),
+    (479870, "kvantel"),
+    (479876, "telia"),
+    (479877, "chilimobil"),
+    (479890, "telia"),
+    (479899, "telia"),
+    (479908, "telia"),
+    (479909, "telia"),
+    (479967, "telia"),
+    (479968, "telia"),
+    (479980, "telenor norge"),
+    (479981, "telenor norge"),
+    (479982, "telenor norge"),
+    (479983, "telenor norge"),
+    (479984, "telenor norge"),
+    (479985, "telia"),
+    (479986, "telia"),
+    (479987, "telia"),
+    (479988, "telia"),
+    (479989, "telia"),
+    (482110, "Plus"),
+    (482111, "Polska Spoka Gazownictwa Sp"),
+    (482114, "Plus"),
+    (482115, "Plus"),
+    (484590, "Play"),
+    (484593, "Play"),
+    (484595, "Plus"),
+    (484596, "Plus"),
+    (484597, "Plus"),
+    (484598, "Plus"),
+    (484599, "Plus"),
+    (485366, "Plus"),
+    (485730, "Orange"),
+    (485731, "Orange"),
+    (485732, "Orange"),
+    (485733, "Orange"),
+    (485734, "Orange"),
+    (485739, "Orange"),
+    (485790, "Play"),
+    (485791, "Plus"),
+    (485792, "Plus"),
+    (485793, "Plus"),
+    (485795, "Play"),
+    (485796, "Play"),
+    (485798, "Play"),
+    (485799, "Play"),
+    (486666, "Play"),
+    (486900, "Play"),
+    (486907, "Play"),
+    (486908, "Play"),
+    (486909, "Play"),
+    (487208, "Play"),
+    (487272, "T-Mobile"),
+    (487273, "T-Mobile"),
+    (487280, "Plus"),
+    (487290, "Play"),
+    (487291, "Play"),
+    (487292, "Plus"),
+    (487293, "Plus"),
+    (487294, "Plus"),
+    (487295, "Plus"),
+    (487296, "Plus"),
+    (487298, "Plus"),
+    (487299, "Plus"),
+    (487360, "Play"),
+    (487367, "Play"),
+    (487368, "Play"),
+    (487369, "Play"),
+    (487370, "Plus"),
+    (487371, "Plus"),
+    (487372, "Plus"),
+    (487390, "Play"),
+    (487391, "Play"),
+    (487392, "Play"),
+    (487800, "Orange"),
+    (487801, "Orange"),
+    (487802, "Play"),
+    (487803, "T-Mobile"),
+    (487805, "Orange"),
+    (487806, "Orange"),
+    (487807, "Play"),
+    (487808, "Play"),
+    (487860, "Plus"),
+    (487861, "Play"),
+    (487862, "Play"),
+    (487863, "Orange"),
+    (487864, "Orange"),
+    (487865, "Orange"),
+    (487866, "Orange"),
+    (487868, "Orange"),
+    (487869, "Orange"),
+    (487890, "Orange"),
+    (487891, "Orange"),
+    (487892, "Orange"),
+    (487893, "Orange"),
+    (487894, "Orange"),
+    (487895, "Plus"),
+    (487896, "Plus"),
+    (487897, "Plus"),
+    (487898, "Plus"),
+    (487899, "Plus"),
+    (487951, "T-Mobile"),
+    (487952, "T-Mobile"),
+    (487953, "T-Mobile"),
+    (487954, "T-Mobile"),
+    (487955, "T-Mobile"),
+    (487990, "Orange"),
+    (487996, "Orange"),
+    (488810, "T-Mobile"),
+    (488811, "Plus"),
+    (488818, "T-Mobile"),
+    (488819, "T-Mobile"),
+    (488833, "T-Mobile"),
+    (488838, "T-Mobile"),
+    (488840, "Play"),
+    (488841, "T-Mobile"),
+    (488842, "T-Mobile"),
+    (488843, "Play"),
+    (488845, "Plus"),
+    (488846, "Play"),
+    (488847, "Play"),
+    (488848, "Play"),
+    (488849, "Play"),
+    (491520, "Vodafone"),
+    (491521, "Vodafone/Lycamobile"),
+    (491522, "Vodafone"),
+    (491523, "Vodafone"),
+    (491525, "Vodafone"),
+    (491526, "Vodafone"),
+    (491529, "Vodafone/Truphone"),
+    (491531, "Mtel"),
+    (491533, "Simsalasim"),
+    (491555, "1&1 Mobilfunk"),
+    (491556, "1&1 Mobilfunk"),
+    (491570, "Eplus/Telogic"),
+    (491573, "Eplus"),
+    (491575, "Eplus"),
+    (491577, "Eplus"),
+    (491578, "Eplus"),
+    (491579, "Eplus/Sipgate"),
+    (491590, "O2"),
+    (501650, "Speednet (Smart)"),
+    (501651, "Speednet (Smart)"),
+    (501652, "Speednet (Smart)"),
+    (501653, "Speednet (Smart)"),
+    (501654, "Speednet (Smart)"),
+    (501655, "Speednet (Smart)"),
+    (501656, "Belize Telemedia Ltd (Digi)"),
+    (502350, "Movistar"),
+    (502351, "Movistar"),
+    (502352, "Movistar"),
+    (502353, "Movistar"),
+    (502354, "Movistar"),
+    (502355, "Movistar"),
+    (502356, "Movistar"),
+    (502370, "Tigo"),
+    (502371, "Tigo"),
+    (502372, "Tigo"),
+    (502373, "Tigo"),
+    (502374, "Tigo"),
+    (502375, "Tigo"),
+    (502390, "Tigo"),
+    (502391, "Tigo"),
+    (502392, "Tigo"),
+    (502393, "Tigo"),
+    (502400, "Movistar"),
+    (502448, "Tigo"),
+    (502449, "Tigo"),
+    (502477, "Tigo"),
+    (502478, "Tigo"),
+    (502479, "Tigo"),
+    (502500, "Tigo"),
+    (502501, "Telgua"),
+    (502502, "Movistar"),
+    (502503, "Tigo"),
+    (502504, "Tigo"),
+    (502505, "Tigo"),
+    (502506, "Tigo"),
+    (502507, "Movistar"),
+    (502508, "Movistar"),
+    (502509, "Movistar"),
+    (502510, "Movistar"),
+    (502511, "Telgua"),
+    (502512, "Telgua"),
+    (502513, "Telgua"),
+    (502514, "Movistar"),
+    (502515, "Tigo"),
+    (502516, "Tigo"),
+    (502517, "Tigo"),
+    (502518, "Tigo"),
+    (502519, "Tigo"),
+    (502520, "Tigo"),
+    (502539, "Movistar"),
+    (502540, "Movistar"),
+    (502550, "Movistar"),
+    (502551, "Telgua"),
+    (502552, "Tigo"),
+    (502554, "Movistar"),
+    (502555, "Telgua"),
+    (502556, "Telgua"),
+    (502557, "Telgua"),
+    (502558, "Telgua"),
+    (502559, "Telgua"),
+    (502561, "Telgua"),
+    (502562, "Telgua"),
+    (502563, "Telgua"),
+    (502569, "Telgua"),
+    (502571, "Telgua"),
+    (502579, "Movistar"),
+    (502580, "Tigo"),
+    (502588, "Tigo"),
+    (502589, "Tigo"),
+    (502590, "Tigo"),
+    (502599, "Tigo"),
+    (503600, "Tigo"),
+    (503601, "Tigo"),
+    (503602, "Tigo"),
+    (503603, "Tigo"),
+    (503604, "Tigo"),
+    (503605, "Tigo"),
+    (503608, "Movistar"),
+    (503609, "Tigo"),
+    (503620, "Digicel"),
+    (503630, "Claro"),
+    (503632, "SIGET"),
+    (503633, "SIGET"),
+    (503634, "SIGET"),
+    (503635, "SIGET"),
+    (503636, "SIGET"),
+    (503637, "SIGET"),
+    (503638, "SIGET"),
+    (503639, "SIGET"),
+    (503640, "SIGET"),
+    (503641, "SIGET"),
+    (503642, "Movistar"),
+    (503643, "Tigo"),
+    (503644, "Tigo"),
+    (503645, "Tigo"),
+    (503646, "Tigo"),
+    (503649, "SIGET"),
+    (503690, "Tigo"),
+    (503692, "Tigo"),
+    (503693, "Tigo"),
+    (503694, "Tigo"),
+    (503695, "Tigo"),
+    (503696, "Tigo"),
+    (503697, "Claro"),
+    (503698, "Claro"),
+    (503699, "Claro"),
+    (503700, "Claro"),
+    (503701, "Claro"),
+    (503702, "Claro"),
+    (503703, "Claro"),
+    (503704, "Claro"),
+    (503705, "Claro"),
+    (503706, "Claro"),
+    (503708, "Claro"),
+    (503709, "Claro"),
+    (503745, "Movistar"),
+    (503747, "Tigo"),
+    (503748, "Tigo"),
+    (503749, "Tigo"),
+    (503767, "Tigo"),
+    (503768, "Tigo"),
+    (503770, "Movistar"),
+    (503771, "Movistar"),
+    (503772, "Tigo"),
+    (503773, "Tigo"),
+    (503774, "Claro"),
+    (503775, "Claro"),
+    (503776, "Digicel"),
+    (503777, "Digicel"),
+    (503781, "Movistar"),
+    (503782, "Movistar"),
+    (503783, "Movistar"),
+    (503785, "Claro"),
+    (503786, "Claro"),
+    (503787, "Tigo"),
+    (503788, "Tigo"),
+    (503789, "Tigo"),
+    (503790, "Tigo"),
+    (503791, "Tigo"),
+    (503792, "Tigo"),
+    (503793, "Tigo"),
+    (503794, "Tigo"),
+    (503795, "Claro"),
+    (503796, "Claro"),
+    (503797, "Digicel"),
+    (503799, "Movistar"),
+    (505820, "Claro"),
+    (505821, "Claro"),
+    (505822, "Claro"),
+    (505823, "Claro"),
+    (505832, "Movistar"),
+    (505833, "Claro"),
+    (505835, "Claro"),
+    (505836, "Claro"),
+    (505837, "Movistar"),
+    (505838, "Movistar"),
+    (505839, "Movistar"),
+    (505845, "Movistar"),
+    (505846, "Movistar"),
+    (505847, "Movistar"),
+    (505848, "Movistar"),
+    (505850, "Claro"),
+    (505851, "Claro"),
+    (505852, "Claro"),
+    (505853, "Claro"),
+    (505854, "Claro"),
+    (505855, "Movistar"),
+    (505856, "Movistar"),
+    (505857, "Movistar"),
+    (505858, "Movistar"),
+    (505859, "Movistar"),
+    (505867, "Movistar"),
+    (505868, "Movistar"),
+    (505870, "Claro"),
+    (505871, "Claro"),
+    (505872, "Claro"),
+    (505873, "Claro"),
+    (505874, "Claro"),
+    (505875, "Movistar"),
+    (505876, "Movistar"),
+    (505877, "Movistar"),
+    (505878, "Movistar"),
+    (505879, "Movistar"),
+    (505882, "Claro"),
+    (505883, "Claro"),
+    (505884, "Claro"),
+    (505885, "Claro"),
+    (505890, "Claro"),
+    (505891, "Claro"),
+    (505892, "Claro"),
+    (505893, "Claro"),
+    (505894, "Claro"),
+    (505895, "Movistar"),
+    (505896, "Movistar"),
+    (505897, "Movistar"),
+    (505898, "Movistar"),
+    (505899, "Movistar"),
+    (506701, "Claro"),
+    (506702, "Claro"),
+    (506703, "Claro"),
+    (506704, "Claro"),
+    (506705, "Claro"),
+    (506706, "Claro"),
+    (506707, "Claro"),
+    (506708, "Claro"),
+    (506709, "Claro"),
+    (507111, "Claro"),
+    (507161, "Cable & Wireless"),
+    (507218, "Telefónica Móviles"),
+    (507219, "Telefónica Móviles"),
+    (507616, "Telefónica Móviles"),
+    (507617, "Claro"),
+    (507618, "Claro"),
+    (507619, "Telefónica Móviles"),
+    (507630, "Claro"),
+    (507631, "Claro"),
+    (507632, "Claro"),
+    (507633, "Cable & Wireless"),
+    (507634, "Cable & Wireless"),
+    (507635, "Telefónica Móviles"),
+    (507636, "Telefónica Móviles"),
+    (507637, "Cable & Wireless"),
+    (507638, "Telefónica Móviles"),
+    (507639, "Telefónica Móviles"),
+    (507656, "Telefónica Móviles"),
+    (507657, "Telefónica Móviles"),
+    (507658, "Telefónica Móviles"),
+    (507659, "Telefónica Móviles"),
+    (507660, "Telefónica Móviles"),
+    (507661, "Telefónica Móviles"),
+    (507662, "Telefónica Móviles"),
+    (507663, "Telefónica Móviles"),
+    (507664, "Telefónica Móviles"),
+    (507665, "Cable & Wireless"),
+    (507666, "Cable & Wireless"),
+    (507667, "Cable & Wireless"),
+    (507668, "Cable & Wireless"),
+    (507669, "Cable & Wireless"),
+    (507680, "Cable & Wireless"),
+    (507684, "Cable & Wireless"),
+    (507687, "Cable & Wireless"),
+    (507688, "Cable & Wireless"),
+    (507692, "Telefónica Móviles"),
+    (507693, "Telefónica Móviles"),
+    (507697, "Telefónica Móviles"),
+    (507872, "Cable & Wireless"),
+    (507873, "Cable & Wireless"),
+    (519190, "Claro"),
+    (519191, "Claro"),
+    (519198, "Entel"),
+    (519199, "Intermax"),
+    (519266, "Entel"),
+    (519267, "Entel"),
+    (519268, "Entel"),
+    (519269, "Entel"),
+    (519327, "Movistar"),
+    (519328, "Movistar"),
+    (519329, "Movistar"),
+    (519370, "Entel"),
+    (519371, "Entel"),
+    (519372, "Entel"),
+    (519373, "Claro"),
+    (519418, "Movistar"),
+    (519419, "Movistar"),
+    (519422, "Claro"),
+    (519423, "Claro"),
+    (519427, "Claro"),
+    (519433, "Claro"),
+    (519435, "Claro"),
+    (519437, "Claro"),
+    (519444, "Movistar"),
+    (519446, "Movistar"),
+    (519448, "Movistar"),
+    (519449, "Movistar"),
+    (519466, "Claro"),
+    (519467, "Claro"),
+    (519468, "Claro"),
+    (519469, "Movistar"),
+    (519471, "Entel"),
+    (519472, "Entel"),
+    (519473, "Entel"),
+    (519477, "Claro"),
+    (519482, "Claro"),
+    (519483, "Claro"),
+    (519487, "Claro"),
+    (519490, "Movistar"),
+    (519491, "Claro"),
+    (519492, "Claro"),
+    (519493, "Claro"),
+    (519494, "Movistar"),
+    (519495, "Movistar"),
+    (519496, "Movistar"),
+    (519497, "Claro"),
+    (519498, "Movistar"),
+    (519501, "Claro"),
+    (519502, "Claro"),
+    (519503, "Claro"),
+    (519507, "Claro"),
+    (519511, "Claro"),
+    (519512, "Claro"),
+    (519513, "Claro"),
+    (519517, "Claro"),
+    (519521, "Claro"),
+    (519523, "Claro"),
+    (519524, "Claro"),
+    (519541, "Claro"),
+    (519547, "Claro"),
+    (519557, "Claro"),
+    (519562, "Claro"),
+    (519563, "Claro"),
+    (519567, "Claro"),
+    (519570, "Claro"),
+    (519571, "Claro"),
+    (519572, "Claro"),
+    (519573, "Claro"),
+    (519577, "Claro"),
+    (519581, "Claro"),
+    (519582, "Claro"),
+    (519583, "Claro"),
+    (519587, "Claro"),
+    (519591, "Claro"),
+    (519592, "Claro"),
+    (519593, "Claro"),
+    (519597, "Claro"),
+    (519601, "Entel"),
+    (519602, "Entel"),
+    (519603, "Entel"),
+    (519604, "Entel"),
+    (519605, "Entel"),
+    (519606, "Entel"),
+    (519607, "Entel"),
+    (519608, "Entel"),
+    (519609, "Entel"),
+    (519610, "Movistar"),
+    (519611, "Movistar"),
+    (519612, "Claro"),
+    (519613, "Claro"),
+    (519614, "Claro"),
+    (519615, "Movistar"),
+    (519616, "Movistar"),
+    (519617, "Claro"),
+    (519618, "Claro"),
+    (519619, "Movistar"),
+    (519622, "Claro"),
+    (519623, "Claro"),
+    (519627, "Claro"),
+    (519636, "Movistar"),
+    (519639, "Movistar"),
+    (519641, "Claro"),
+    (519642, "Claro"),
+    (519643, "Claro"),
+    (519650, "Movistar"),
+    (519656, "Movistar"),
+    (519658, "Movistar"),
+    (519659, "Movistar"),
+    (519663, "Claro"),
+    (519664, "Claro"),
+    (519667, "Claro"),
+    (519683, "Claro"),
+    (519687, "Claro"),
+    (519693, "Claro"),
+    (519697, "Claro"),
+    (519700, "Movistar"),
+    (519702, "Claro"),
+    (519709, "Movistar"),
+    (519720, "Entel"),
+    (519721, "Entel"),
+    (519722, "Claro"),
+    (519723, "Claro"),
+    (519724, "Claro"),
+    (519725, "Claro"),
+    (519726, "Movistar"),
+    (519727, "Claro"),
+    (519728, "Movistar"),
+    (519729, "Movistar"),
+    (519738, "Movistar"),
+    (519739, "Movistar"),
+    (519740, "Movistar"),
+    (519741, "Movistar"),
+    (519749, "Movistar"),
+    (519760, "Movistar"),
+    (519761, "Movistar"),
+    (519762, "Claro"),
+    (519763, "Claro"),
+    (519766, "Movistar"),
+    (519767, "Movistar"),
+    (519768, "Movistar"),
+    (519769, "Movistar"),
+    (519770, "Claro"),
+    (519771, "Claro"),
+    (519772, "Movistar"),
+    (519783, "Claro"),
+    (519786, "Claro"),
+    (519787, "Claro"),
+    (519793, "Claro"),
+    (519797, "Claro"),
+    (519800, "Movistar"),
+    (519801, "Movistar"),
+    (519802, "Movistar"),
+    (519803, "Movistar"),
+    (519816, "Movistar"),
+    (519817, "Movistar"),
+    (519818, "Movistar"),
+    (519819, "Movistar"),
+    (519834, "Entel"),
+    (519835, "Entel"),
+    (519836, "Movistar"),
+    (519839, "Movistar"),
+    (519840, "Movistar"),
+    (519845, "Movistar"),
+    (519846, "Movistar"),
+    (519848, "Movistar"),
+    (519849, "Movistar"),
+    (519940, "Entel"),
+    (519941, "Entel"),
+    (519942, "Entel"),
+    (519943, "Claro"),
+    (519944, "Movistar"),
+    (519945, "Movistar"),
+    (519946, "Claro"),
+    (519947, "Claro"),
+    (519948, "Claro"),
+    (519949, "Claro"),
+    (519981, "Entel"),
+    (519982, "Entel"),
+    (519983, "Entel"),
+    (562198, "Compania De Telecomunicaciones De Chile S.A."),
+    (562220, "Compania De Telecomunicaciones De Chile S.A."),
+    (562221, "Compania De Telecomunicaciones De Chile S.A."),
+    (562222, "Compania De Telecomunicaciones De Chile S.A."),
+    (562223, "Compania De Telecomunicaciones De Chile S.A."),
+    (562224, "Gtd Manquehue S.A."),
+    (562225, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (562226, "Compania De Telecomunicaciones De Chile S.A."),
+    (562227, "Compania De Telecomunicaciones De Chile S.A."),
+    (562228, "Compania De Telecomunicaciones De Chile S.A."),
+    (562231, "Vtr Banda Ancha (Chile) S.A."),
+    (562232, "Compania De Telecomunicaciones De Chile S.A."),
+    (562233, "Compania De Telecomunicaciones De Chile S.A."),
+    (562234, "Compania De Telecomunicaciones De Chile S.A."),
+    (562235, "Compania De Telecomunicaciones De Chile S.A."),
+    (562236, "Entel"),
+    (562237, "Entel"),
+    (562238, "Gtd Telesat S.A."),
+    (562239, "Gtd Telesat S.A."),
+    (562242, "Entel"),
+    (562243, "Entel"),
+    (562244, "Entel"),
+    (562246, "Entel"),
+    (562247, "Entel"),
+    (562248, "Gtd Telesat S.A."),
+    (562249, "Gtd Telesat S.A."),
+    (562251, "Compania De Telecomunicaciones De Chile S.A."),
+    (562252, "Compania De Telecomunicaciones De Chile S.A."),
+    (562254, "Compania De Telecomunicaciones De Chile S.A."),
+    (562255, "Compania De Telecomunicaciones De Chile S.A."),
+    (562257, "Compania De Telecomunicaciones De Chile S.A."),
+    (562258, "Claro"),
+    (562262, "Compania De Telecomunicaciones De Chile S.A."),
+    (562263, "Compania De Telecomunicaciones De Chile S.A."),
+    (562264, "Compania De Telecomunicaciones De Chile S.A."),
+    (562265, "Entel"),
+    (562266, "Compania De Telecomunicaciones De Chile S.A."),
+    (562267, "Compania De Telecomunicaciones De Chile S.A."),
+    (562268, "Compania De Telecomunicaciones De Chile S.A."),
+    (562269, "Compania De Telecomunicaciones De Chile S.A."),
+    (562270, "Entel"),
+    (562273, "Compania De Telecomunicaciones De Chile S.A."),
+    (562274, "Compania De Telecomunicaciones De Chile S.A."),
+    (562275, "Gtd Manquehue S.A."),
+    (562276, "Compania De Telecomunicaciones De Chile S.A."),
+    (562277, "Compania De Telecomunicaciones De Chile S.A."),
+    (562278, "Compania De Telecomunicaciones De Chile S.A."),
+    (562281, "Compania De Telecomunicaciones De Chile S.A."),
+    (562283, "Compania De Telecomunicaciones De Chile S.A."),
+    (562284, "Compania De Telecomunicaciones De Chile S.A."),
+    (562285, "Compania De Telecomunicaciones De Chile S.A."),
+    (562287, "Compania De Telecomunicaciones De Chile S.A."),
+    (562292, "Entel"),
+    (562294, "Gtd Manquehue S.A."),
+    (562295, "Gtd Manquehue S.A."),
+    (562298, "Vtr Banda Ancha (Chile) S.A."),
+    (562320, "Entel"),
+    (562322, "Entel"),
+    (562323, "Entel"),
+    (562324, "Entel"),
+    (562325, "Entel"),
+    (562326, "Vtr Comunicaciones Spa."),
+    (562327, "Movistar"),
+    (562328, "Movistar"),
+    (562329, "Movistar"),
+    (562330, "Movistar"),
+    (562331, "Movistar"),
+    (562334, "Entel"),
+    (562335, "Movistar"),
+    (562336, "Movistar"),
+    (562337, "Claro"),
+    (563222, "Compania De Telecomunicaciones De Chile S.A."),
+    (563225, "Compania De Telecomunicaciones De Chile S.A."),
+    (563226, "Compania De Telecomunicaciones De Chile S.A."),
+    (563228, "Compania De Telecomunicaciones De Chile S.A."),
+    (563229, "Compania De Telecomunicaciones De Chile S.A."),
+    (563235, "Entel"),
+    (563236, "Movistar"),
+    (563237, "Movistar"),
+    (563322, "Compania De Telecomunicaciones De Chile S.A."),
+    (563522, "Compania De Telecomunicaciones De Chile S.A."),
+    (564122, "Compania De Telecomunicaciones De Chile S.A."),
+    (564123, "Compania De Telecomunicaciones De Chile S.A."),
+    (564124, "Compania De Telecomunicaciones De Chile S.A."),
+    (564125, "Compania De Telecomunicaciones De Chile S.A."),
+    (564126, "Compania De Telecomunicaciones De Chile S.A."),
+    (564129, "Vtr Banda Ancha (Chile) S.A."),
+    (564222, "Compania De Telecomunicaciones De Chile S.A."),
+    (564522, "Compania De Telecomunicaciones De Chile S.A."),
+    (565122, "Compania De Telecomunicaciones De Chile S.A."),
+    (565222, "Compania De Telecomunicaciones De Chile S.A."),
+    (565522, "Compania De Telecomunicaciones De Chile S.A."),
+    (565529, "Vtr Banda Ancha (Chile) S.A."),
+    (565724, "Compania De Telecomunicaciones De Chile S.A."),
+    (565822, "Compania De Telecomunicaciones De Chile S.A."),
+    (566122, "Compania De Telecomunicaciones De Chile S.A."),
+    (566322, "Telefonica Del Sur S.A."),
+    (567122, "Compania De Telecomunicaciones De Chile S.A."),
+    (567222, "Compania De Telecomunicaciones De Chile S.A."),
+    (567226, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (569100, "WOM"),
+    (569101, "Pacifico Cable S.P.A."),
+    (569102, "Pacifico Cable S.P.A."),
+    (569304, "Claro"),
+    (569310, "WOM"),
+    (569311, "WOM"),
+    (569312, "WOM"),
+    (569313, "WOM"),
+    (569314, "WOM"),
+    (569315, "Ops Ingenieria Ltda."),
+    (569316, "Ops Ingenieria Ltda."),
+    (569317, "Entel"),
+    (569318, "Entel"),
+    (569319, "Entel"),
+    (569320, "Entel"),
+    (569321, "Entel"),
+    (569322, "Entel"),
+    (569323, "Entel"),
+    (569325, "Entel"),
+    (569326, "Entel"),
+    (569327, "WOM"),
+    (569328, "WOM"),
+    (569329, "WOM"),
+    (569337, "Entel"),
+    (569338, "Entel"),
+    (569339, "Entel"),
+    (569347, "Inversiones Santa Fe Ltda."),
+    (569348, "WOM"),
+    (569349, "WOM"),
+    (569379, "Ops Ingenieria Ltda."),
+    (569380, "Ops Ingenieria Ltda."),
+    (569381, "Ops Ingenieria Ltda."),
+    (569388, "Entel"),
+    (569434, "Movistar"),
+    (569435, "Movistar"),
+    (569436, "TelefÃ³nica Uno Uno Cuatro"),
+    (569437, "TelefÃ³nica Uno Uno Cuatro"),
+    (569438, "Tribe Mobile Chile Spa"),
+    (569439, "Tribe Mobile Chile Spa"),
+    (569461, "Claro"),
+    (569462, "Claro"),
+    (569463, "Claro"),
+    (569464, "Claro"),
+    (569465, "Claro"),
+    (569466, "Claro"),
+    (569467, "Claro"),
+    (569468, "Claro"),
+    (569469, "Claro"),
+    (569470, "Claro"),
+    (569471, "Claro"),
+    (569472, "Claro"),
+    (569480, "Movistar"),
+    (569481, "Movistar"),
+    (569482, "Movistar"),
+    (569483, "Empresas Bunker S.A."),
+    (569510, "Movistar"),
+    (569511, "Movistar"),
+    (569512, "Movistar"),
+    (569513, "Movistar"),
+    (569524, "Movistar"),
+    (569525, "Movistar"),
+    (569526, "Sociedad Falabella Movil Spa."),
+    (569527, "Sociedad Falabella Movil Spa."),
+    (569550, "Ops Ingenieria Ltda."),
+    (569551, "WOM"),
+    (569552, "WOM"),
+    (569553, "WOM"),
+    (569554, "Movistar"),
+    (569556, "Entel"),
+    (569557, "Movistar"),
+    (569558, "Sociedad Falabella Movil Spa."),
+    (569559, "Sociedad Falabella Movil Spa."),
+    (569620, "Entel"),
+    (569621, "Entel"),
+    (569622, "Entel"),
+    (569623, "Entel"),
+    (569624, "Entel"),
+    (569625, "Claro"),
+    (569626, "Claro"),
+    (569627, "Claro"),
+    (569628, "Movistar"),
+    (569629, "Movistar"),
+    (569630, "Movistar"),
+    (569631, "Movistar"),
+    (569632, "Movistar"),
+    (569633, "Movistar"),
+    (569634, "VTR Móvil"),
+    (569635, "VTR Móvil"),
+    (569636, "VTR Móvil"),
+    (569637, "VTR Móvil"),
+    (569638, "VTR Móvil"),
+    (569639, "Movistar"),
+    (569640, "Movistar"),
+    (569641, "WOM"),
+    (569642, "WOM"),
+    (569643, "WOM"),
+    (569644, "WOM"),
+    (569645, "WOM"),
+    (569646, "Movistar"),
+    (569647, "Movistar"),
+    (569648, "Movistar"),
+    (569649, "Movistar"),
+    (569650, "Entel"),
+    (569651, "Entel"),
+    (569658, "Entel"),
+    (569659, "Entel"),
+    (569670, "Claro"),
+    (569671, "Claro"),
+    (569672, "Claro"),
+    (569673, "Claro"),
+    (569674, "Claro"),
+    (569675, "Claro"),
+    (569688, "NÃ³made Telecomunicaciones"),
+    (569690, "Entel"),
+    (569710, "Entel"),
+    (569712, "Movistar"),
+    (569713, "Movistar"),
+    (569714, "Movistar"),
+    (569715, "Movistar"),
+    (569716, "Movistar"),
+    (569717, "Claro"),
+    (569718, "Claro"),
+    (569719, "Claro"),
+    (569737, "Entel"),
+    (569738, "Entel"),
+    (569739, "Entel"),
+    (569740, "Claro"),
+    (569750, "WOM"),
+    (569759, "Claro"),
+    (569760, "Entel"),
+    (569761, "Movistar"),
+    (569762, "Movistar"),
+    (569763, "Movistar"),
+    (569764, "Entel"),
+    (569765, "Entel"),
+    (569766, "Entel"),
+    (569767, "Claro"),
+    (569768, "Claro"),
+    (569769, "Claro"),
+    (569770, "Entel"),
+    (569771, "Movistar"),
+    (569772, "Movistar"),
+    (569773, "Movistar"),
+    (569774, "Entel"),
+    (569775, "Entel"),
+    (569776, "Entel"),
+    (569777, "Claro"),
+    (569778, "Claro"),
+    (569779, "Claro"),
+    (569780, "Entel"),
+    (569787, "Entel"),
+    (569788, "Entel"),
+    (569789, "Entel"),
+    (569790, "Claro"),
+    (569791, "Claro"),
+    (569792, "Claro"),
+    (569793, "Claro"),
+    (569794, "Claro"),
+    (569795, "Entel"),
+    (569796, "Entel"),
+    (569797, "Entel"),
+    (569798, "Entel"),
+    (569799, "Entel"),
+    (569801, "Entel"),
+    (569811, "Entel"),
+    (569812, "Movistar"),
+    (569813, "Entel"),
+    (569814, "Claro"),
+    (569815, "Entel"),
+    (569816, "Movistar"),
+    (569817, "Movistar"),
+    (569818, "Entel"),
+    (569819, "Entel"),
+    (569848, "Claro"),
+    (569849, "Claro"),
+    (569870, "Claro"),
+    (569871, "Claro"),
+    (569872, "Entel"),
+    (569873, "Entel"),
+    (569874, "Entel"),
+    (569875, "Entel"),
+    (569876, "Entel"),
+    (569877, "Claro"),
+    (569878, "Movistar"),
+    (569879, "Movistar"),
+    (569880, "Claro"),
+    (569881, "Entel"),
+    (569882, "Entel"),
+    (569883, "Entel"),
+    (569884, "Movistar"),
+    (569885, "Movistar"),
+    (569886, "Movistar"),
+    (569887, "Movistar"),
+    (569888, "Entel"),
+    (569889, "Entel"),
+    (569900, "Claro"),
+    (569901, "Movistar"),
+    (569902, "Movistar"),
+    (569903, "Movistar"),
+    (569904, "Movistar"),
+    (569905, "Claro"),
+    (569906, "Claro"),
+    (569907, "Entel"),
+    (569908, "Entel"),
+    (569909, "Entel"),
+    (569910, "Claro"),
+    (569912, "Entel"),
+    (569913, "Entel"),
+    (569914, "Movistar"),
+    (569915, "Entel"),
+    (569916, "Movistar"),
+    (569917, "Claro"),
+    (569918, "Movistar"),
+    (569919, "Movistar"),
+    (569920, "Claro"),
+    (569921, "Entel"),
+    (569922, "Movistar"),
+    (569923, "Movistar"),
+    (569924, "Movistar"),
+    (569925, "Movistar"),
+    (569926, "Movistar"),
+    (569927, "Movistar"),
+    (569929, "Entel"),
+    (569930, "Entel"),
+    (569931, "Entel"),
+    (569932, "Movistar"),
+    (569933, "Movistar"),
+    (569934, "Movistar"),
+    (569935, "Entel"),
+    (569936, "Movistar"),
+    (569937, "Movistar"),
+    (569939, "Claro"),
+    (569941, "Entel"),
+    (569947, "Entel"),
+    (569948, "Entel"),
+    (569949, "Entel"),
+    (569950, "Entel"),
+    (569951, "Entel"),
+    (569952, "Movistar"),
+    (569953, "Movistar"),
+    (569954, "Movistar"),
+    (569955, "Movistar"),
+    (569956, "Movistar"),
+    (569957, "Entel"),
+    (569959, "Entel"),
+    (569961, "Entel"),
+    (569962, "Movistar"),
+    (569963, "Movistar"),
+    (569964, "Movistar"),
+    (569965, "Movistar"),
+    (569966, "Movistar"),
+    (569967, "Entel"),
+    (569968, "Claro"),
+    (569969, "Entel"),
+    (569971, "Entel"),
+    (569977, "Entel"),
+    (569978, "Entel"),
+    (569979, "Entel"),
+    (569980, "Claro"),
+    (569984, "Claro"),
+    (569985, "Claro"),
+    (569986, "Claro"),
+    (569990, "Movistar"),
+    (569991, "Entel"),
+    (569992, "Claro"),
+    (569993, "Entel"),
+    (569994, "Entel"),
+    (569995, "Movistar"),
+    (569998, "Claro"),
+    (569999, "Entel"),
+    (573000, "Tigo"),
+    (573002, "Tigo"),
+    (573003, "Tigo"),
+    (573004, "Tigo"),
+    (573005, "Tigo"),
+    (573006, "Tigo"),
+    (573007, "Tigo"),
+    (573008, "Tigo"),
+    (573009, "Tigo"),
+    (573010, "Tigo"),
+    (573012, "Tigo"),
+    (573013, "Tigo"),
+    (573014, "Tigo"),
+    (573015, "Tigo"),
+    (573016, "Tigo"),
+    (573017, "Tigo"),
+    (573020, "Tigo"),
+    (573022, "Tigo"),
+    (573023, "Tigo"),
+    (573024, "Tigo"),
+    (573025, "Partners Telecom Colombia"),
+    (573026, "Partners Telecom Colombia"),
+    (573027, "Partners Telecom Colombia"),
+    (573028, "Partners Telecom Colombia"),
+    (573029, "Tigo"),
+    (573042, "Tigo"),
+    (573043, "Tigo"),
+    (573044, "Tigo"),
+    (573053, "Tigo"),
+    (573057, "Tigo"),
+    (573058, "Tigo"),
+    (573102, "Claro"),
+    (573103, "Claro"),
+    (573104, "Claro"),
+    (573105, "Claro"),
+    (573106, "Claro"),
+    (573107, "Claro"),
+    (573108, "Claro"),
+    (573112, "Claro"),
+    (573113, "Claro"),
+    (573114, "Claro"),
+    (573115, "Claro"),
+    (573116, "Claro"),
+    (573117, "Claro"),
+    (573118, "Claro"),
+    (573122, "Claro"),
+    (573123, "Claro"),
+    (573124, "Claro"),
+    (573125, "Claro"),
+    (573126, "Claro"),
+    (573127, "Claro"),
+    (573128, "Claro"),
+    (573132, "Claro"),
+    (573133, "Claro"),
+    (573134, "Claro"),
+    (573135, "Claro"),
+    (573136, "Claro"),
+    (573137, "Claro"),
+    (573138, "Claro"),
+    (573142, "Claro"),
+    (573143, "Claro"),
+    (573144, "Claro"),
+    (573145, "Claro"),
+    (573146, "Claro"),
+    (573147, "Claro"),
+    (573148, "Claro"),
+    (573192, "Movistar"),
+    (573193, "Movistar"),
+    (573194, "Movistar"),
+    (573195, "Movistar"),
+    (573196, "Movistar"),
+    (573197, "Movistar"),
+    (573198, "Movistar"),
+    (573199, "Movistar"),
+    (573202, "Claro"),
+    (573203, "Claro"),
+    (573204, "Claro"),
+    (573205, "Claro"),
+    (573206, "Claro"),
+    (573207, "Claro"),
+    (573208, "Claro"),
+    (573209, "Claro"),
+    (573212, "Claro"),
+    (573213, "Claro"),
+    (573214, "Claro"),
+    (573215, "Claro"),
+    (573216, "Claro"),
+    (573217, "Claro"),
+    (573218, "Claro"),
+    (573219, "Claro"),
+    (573232, "Claro"),
+    (573233, "Claro"),
+    (573234, "Claro"),
+    (573235, "Claro"),
+    (573236, "Partners Telecom Colombia"),
+    (573237, "Partners Telecom Colombia"),
+    (573238, "Partners Telecom Colombia"),
+    (573239, "Partners Telecom Colombia"),
+    (573241, "Partners Telecom Colombia"),
+    (573242, "Tigo"),
+    (573243, "Tigo"),
+    (573244, "Tigo"),
+    (573245, "Tigo"),
+    (573246, "Tigo"),
+    (573247, "Partners Telecom Colombia"),
+    (573248, "Partners Telecom Colombia"),
+    (573249, "Partners Telecom Colombia"),
+    (573332, "Movil Exito"),
+    (573502, "Avantel"),
+    (573503, "Avantel"),
+    (573504, "Avantel"),
+    (573505, "Avantel"),
+    (573506, "Avantel"),
+    (573507, "Avantel"),
+    (573508, "Avantel"),
+    (590694, "Digicel"),
+    (590696, "Digicel"),
+    (592510, "Digicel Guyana"),
+    (592511, "Digicel Guyana"),
+    (592600, "Digicel Guyana"),
+    (592601, "Digicel Guyana"),
+    (592602, "Digicel Guyana"),
+    (592603, "Digicel Guyana"),
+    (592604, "Digicel Guyana"),
+    (592608, "Digicel Guyana"),
+    (592609, "GTT"),
+    (592630, "Digicel Guyana"),
+    (592631, "Green Gibraltar"),
+    (592632, "Digicel Guyana"),
+    (592633, "Digicel Guyana"),
+    (592634, "GTT"),
+    (592635, "E-Networks"),
+    (592636, "Digicel Guyana"),
+    (592637, "Digicel Guyana"),
+    (592638, "GTT"),
+    (592639, "GTT"),
+    (592659, "Digicel Guyana"),
+    (592700, "Digicel Guyana"),
+    (592701, "Digicel Guyana"),
+    (592702, "Digicel Guyana"),
+    (592703, "Digicel Guyana"),
+    (592704, "Digicel Guyana"),
+    (592705, "GTT"),
+    (592706, "GTT"),
+    (592707, "GTT"),
+    (592708, "GTT"),
+    (592709, "GTT"),
+    (592720, "E-Networks"),
+    (592721, "Digicel Guyana"),
+    (592722, "Digicel Guyana"),
+    (592723, "Digicel Guyana"),
+    (592724, "Digicel Guyana"),
+    (592725, "GTT"),
+    (592726, "GTT"),
+    (592727, "GTT"),
+    (592728, "GTT"),
+    (592729, "GTT"),
+    (592740, "E-Networks"),
+    (592741, "E-Networks"),
+    (592742, "E-Networks"),
+    (592750, "Digicel Guyana"),
+    (592770, "E-Networks"),
+    (593959, "Claro"),
+    (593960, "CNT"),
+    (593961, "CNT"),
+    (593963, "Movistar"),
+    (593964, "Movistar"),
+    (593966, "CNT"),
+    (593967, "Claro"),
+    (593968, "Claro"),
+    (593969, "Claro"),
+    (593979, "Claro"),
+    (593983, "Movistar"),
+    (593984, "Movistar"),
+    (593987, "Movistar"),
+    (593990, "Claro"),
+    (593991, "Claro"),
+    (593993, "Claro"),
+    (593994, "Claro"),
+    (593995, "Movistar"),
+    (593996, "CNT"),
+    (593997, "Claro"),
+    (593998, "Movistar"),
+    (595981, "Tigo"),
+    (595982, "Tigo"),
+    (595983, "Tigo"),
+    (595984, "Tigo"),
+    (595985, "Tigo"),
+    (595986, "Tigo"),
+    (595987, "Tigo"),
+    (599319, "WIC"),
+    (599951, "Chippie"),
+    (599952, "Chippie"),
+    (599953, "Chippie"),
+    (599954, "Chippie"),
+    (599956, "Chippie"),
+    (599957, "Antelecom"),
+    (601030, "XOX"),
+    (601031, "XOX"),
+    (601032, "XOX"),
+    (601033, "XOX"),
+    (601034, "XOX"),
+    (601035, "Webe"),
+    (601036, "DiGi"),
+    (601037, "DiGi"),
+    (601038, "DiGi"),
+    (601039, "DiGi"),
+    (601040, "Celcom"),
+    (601041, "Celcom"),
+    (601042, "Maxis"),
+    (601043, "Maxis"),
+    (601044, "Webe"),
+    (601045, "Webe"),
+    (601046, "DiGi"),
+    (601050, "Tune Talk"),
+    (601051, "Tune Talk"),
+    (601052, "Tune Talk"),
+    (601053, "Tune Talk"),
+    (601054, "Tune Talk"),
+    (601055, "Webe"),
+    (601056, "DiGi"),
+    (601057, "Celcom"),
+    (601058, "Celcom"),
+    (601059, "Celcom"),
+    (601060, "Baraka"),
+    (601061, "Baraka"),
+    (601062, "Baraka"),
+    (601063, "Baraka"),
+    (601064, "Baraka"),
+    (601065, "Packcet One"),
+    (601066, "DiGi"),
+    (601070, "Maxis"),
+    (601071, "Maxis"),
+    (601076, "DiGi"),
+    (601077, "Tune Talk"),
+    (601078, "Tune Talk"),
+    (601079, "Tune Talk"),
+    (601080, "Tune Talk"),
+    (601081, "Tune Talk"),
+    (601082, "DiGi"),
+    (601083, "XOX"),
+    (601084, "XOX"),
+    (601085, "XOX"),
+    (601086, "XOX"),
+    (601087, "XOX"),
+    (601088, "DiGi"),
+    (601089, "Maxis"),
+    (601111, "U Mobile"),
+    (601112, "Maxis"),
+    (601116, "DiGi"),
+    (601119, "Celcom"),
+    (601121, "U Mobile"),
+    (601122, "DiGi"),
+    (601123, "Maxis"),
+    (601125, "Maxis"),
+    (601126, "DiGi"),
+    (601128, "U Mobile"),
+    (601129, "Celecom"),
+    (601130, "YTL"),
+    (601131, "DiGi"),
+    (601132, "Celcom"),
+    (601133, "DiGi"),
+    (601134, "Enabling Asia"),
+    (601135, "Tune Talk"),
+    (601136, "DiGi"),
+    (601137, "U Mobile"),
+    (601138, "U Mobile"),
+    (601139, "U Mobile"),
+    (601140, "Celcom"),
+    (601141, "Celcom"),
+    (601142, "U Mobile"),
+    (601150, "DiGi"),
+    (601151, "DiGi"),
+    (601152, "Altel"),
+    (601153, "Tune Talk"),
+    (601154, "Celcom"),
+    (601155, "red ONE"),
+    (601160, "U Mobile"),
+    (601161, "U Mobile"),
+    (601163, "Webe"),
+    (601164, "U Mobile"),
+    (601165, "YTL"),
+    (601166, "YTL"),
+    (601167, "YTL"),
+    (601168, "Webe"),
+    (601169, "U Mobile"),
+    (601170, "DiGi"),
+    (601171, "Celecom"),
+    (601173, "YTL"),
+    (601174, "YTL"),
+    (601175, "YTL"),
+    (601591, "DiGi"),
+    (601592, "Celcom"),
+    (601596, "DiGi"),
+    (601830, "YTL"),
+    (601833, "YTL"),
+    (601834, "YTL"),
+    (601854, "XOX"),
+    (601857, "U Mobile"),
+    (601858, "YTL"),
+    (601859, "YTL"),
+    (601866, "U Mobile"),
+    (601870, "YTL"),
+    (601871, "YTL"),
+    (601876, "U Mobile"),
+    (601877, "U Mobile"),
+    (601878, "U Mobile"),
+    (601880, "YTL"),
+    (601881, "YTL"),
+    (601882, "YTL"),
+    (601887, "U Mobile"),
+    (601892, "YTL"),
+    (601893, "YTL"),
+    (614400, "Aazad distribution discretionary trust"),
+    (614444, "Telstra"),
+    (614445, "Telstra"),
+    (614493, "Soul Pattinson"),
+    (614700, "Lycamobile"),
+    (614701, "Lycamobile"),
+    (614702, "Lycamobile"),
+    (614703, "Lycamobile"),
+    (614704, "Lycamobile"),
+    (614705, "Lycamobile"),
+    (614706, "Lycamobile"),
+    (614707, "Telstra"),
+    (614800, "Pivotel Satellite"),
+    (614801, "Telstra"),
+    (614802, "Telstra"),
+    (614803, "Telstra"),
+    (614804, "Telstra"),
+    (614805, "Telstra"),
+    (614806, "Telstra"),
+    (614807, "Telstra"),
+    (614808, "Pivotel Satellite"),
+    (614839, "Optus"),
+    (614858, "Pivotel Satellite"),
+    (614859, "TravelSIM"),
+    (614888, "My Number"),
+    (614890, "Optus"),
+    (614891, "Optus"),
+    (614892, "Optus"),
+    (614893, "Optus"),
+    (614899, "Pivotel Satellite"),
+    (642820, "Voxbone"),
+    (642824, "Vodafone"),
+    (642825, "Vodafone"),
+    (642829, "Callplus"),
+    (642880, "Symbio Networks PTY"),
+    (642881, "Symbio Networks PTY"),
+    (642882, "Symbio Networks PTY"),
+    (642885, "Vodafone"),
+    (642886, "Vodafone"),
+    (642887, "Callplus"),
+    (642888, "Callplus"),
+    (642889, "Callplus"),
+    (642896, "Airnet New Zealand NOW"),
+    (642899, "Devoli"),
+    (658018, "SingTel"),
+    (658019, "SingTel"),
+    (658020, "SingTel"),
+    (658021, "SingTel"),
+    (658022, "M1"),
+    (658023, "M1"),
+    (658024, "Simba"),
+    (658025, "M1"),
+    (658026, "M1"),
+    (658027, "SingTel"),
+    (658028, "SingTel"),
+    (658029, "SingTel"),
+    (658032, "Changi Travel Services Pte Ltd"),
+    (658033, "Simba"),
+    (658034, "Simba"),
+    (658035, "Simba"),
+    (658040, "SingTel"),
+    (658045, "SingTel"),
+    (658048, "myrepublic"),
+    (658049, "SingTel"),
+    (658050, "M1"),
+    (658051, "M1"),
+    (658052, "M1"),
+    (658053, "Simba"),
+    (658054, "SingTel"),
+    (658055, "M1"),
+    (658056, "Simba"),
+    (658057, "Simba"),
+    (658058, "Simba"),
+    (658059, "M1"),
+    (658067, "SingTel"),
+    (658070, "SingTel"),
+    (658071, "SingTel"),
+    (658082, "SingTel"),
+    (658083, "M1"),
+    (658084, "SingTel"),
+    (658090, "SingTel"),
+    (658091, "M1"),
+    (658092, "Simba"),
+    (658093, "SingTel"),
+    (658094, "M1"),
+    (658095, "SingTel"),
+    (658096, "Simba"),
+    (658097, "CUniq"),
+    (658098, "Simba"),
+    (658099, "SingTel"),
+    (658140, "SingTel"),
+    (658181, "SingTel"),
+    (658182, "SingTel"),
+    (658188, "M1"),
+    (658218, "SingTel"),
+    (658222, "M1"),
+    (658223, "SingTel"),
+    (658228, "SingTel"),
+    (658274, "Simba"),
+    (658282, "M1"),
+    (658288, "M1"),
+    (658299, "SingTel"),
+    (658320, "M1"),
+    (658321, "StarHub"),
+    (658322, "StarHub"),
+    (658323, "M1"),
+    (658324, "M1"),
+    (658325, "M1"),
+    (658326, "M1"),
+    (658328, "StarHub"),
+    (658329, "M1"),
+    (658333, "M1"),
+    (658338, "SingTel"),
+    (658339, "SingTel"),
+    (658352, "StarHub"),
+    (658360, "SingTel"),
+    (658366, "M1"),
+    (658368, "M1"),
+    (658370, "StarHub"),
+    (658373, "StarHub"),
+    (658377, "StarHub"),
+    (658378, "StarHub"),
+    (658380, "StarHub"),
+    (658381, "SingTel"),
+    (658382, "M1"),
+    (658383, "M1"),
+    (658384, "StarHub"),
+    (658385, "SingTel"),
+    (658386, "SingTel"),
+    (658387, "SingTel"),
+    (658388, "M1"),
+    (658389, "StarHub"),
+    (658390, "StarHub"),
+    (658391, "StarHub"),
+    (658392, "StarHub"),
+    (658393, "SingTel"),
+    (658394, "StarHub"),
+    (658395, "StarHub"),
+    (658396, "StarHub"),
+    (658398, "SingTel"),
+    (658399, "SingTel"),
+    (658400, "StarHub"),
+    (658411, "SingTel"),
+    (658456, "M1"),
+    (658468, "StarHub"),
+    (658496, "M1"),
+    (658497, "SingTel"),
+    (658499, "SingTel"),
+    (658500, "M1"),
+    (658501, "StarHub"),
+    (658502, "SingTel"),
+    (658503, "StarHub"),
+    (658504, "SingTel"),
+    (658505, "StarHub"),
+    (658506, "SingTel"),
+    (658507, "SingTel"),
+    (658508, "StarHub"),
+    (658509, "StarHub"),
+    (658510, "SingTel"),
+    (658511, "SingTel"),
+    (658515, "SingTel"),
+    (658518, "SingTel"),
+    (658522, "SingTel"),
+    (658523, "SingTel"),
+    (658524, "SingTel"),
+    (658525, "SingTel"),
+    (658530, "StarHub"),
+    (658531, "StarHub"),
+    (658532, "StarHub"),
+    (658533, "SingTel"),
+    (658534, "SingTel"),
+    (658535, "SingTel"),
+    (658536, "SingTel"),
+    (658537, "SingTel"),
+    (658538, "StarHub"),
+    (658539, "M1"),
+    (658557, "StarHub"),
+    (658559, "StarHub"),
+    (658570, "StarHub"),
+    (658571, "StarHub"),
+    (658572, "StarHub"),
+    (658575, "SingTel"),
+    (658580, "M1"),
+    (658581, "M1"),
+    (658585, "M1"),
+    (658588, "M1"),
+    (658590, "SingTel"),
+    (658591, "SingTel"),
+    (658595, "SingTel"),
+    (658600, "M1"),
+    (658611, "M1"),
+    (658612, "M1"),
+    (658613, "M1"),
+    (658614, "M1"),
+    (658620, "SingTel"),
+    (658621, "SingTel"),
+    (658622, "SingTel"),
+    (658630, "M1"),
+    (658640, "SingTel"),
+    (658641, "M1"),
+    (658642, "M1"),
+    (658643, "M1"),
+    (658644, "M1"),
+    (658645, "M1"),
+    (658646, "SingTel"),
+    (658647, "SingTel"),
+    (658648, "SingTel"),
+    (658649, "SingTel"),
+    (658658, "StarHub"),
+    (658659, "StarHub"),
+    (658660, "StarHub"),
+    (658661, "StarHub"),
+    (658666, "M1"),
+    (658668, "M1"),
+    (658680, "StarHub"),
+    (658681, "StarHub"),
+    (658682, "StarHub"),
+    (658689, "StarHub"),
+    (658690, "StarHub"),
+    (658691, "M1"),
+    (658692, "M1"),
+    (658693, "M1"),
+    (658694, "SingTel"),
+    (658695, "SingTel"),
+    (658696, "SingTel"),
+    (658697, "SingTel"),
+    (658698, "SingTel"),
+    (658699, "M1"),
+    (658700, "StarHub"),
+    (658710, "SingTel"),
+    (658712, "SingTel"),
+    (658713, "SingTel"),
+    (658720, "M1"),
+    (658721, "M1"),
+    (658723, "M1"),
+    (658733, "M1"),
+    (658738, "M1"),
+    (658742, "M1"),
+    (658748, "M1"),
+    (658749, "M1"),
+    (658750, "M1"),
+    (658751, "M1"),
+    (658752, "M1"),
+    (658753, "M1"),
+    (658754, "myrepublic"),
+    (658755, "myrepublic"),
+    (658756, "red one"),
+    (658757, "red one"),
+    (658758, "red one"),
+    (658759, "red one"),
+    (658760, "red one"),
+    (658761, "Simba"),
+    (658762, "SingTel"),
+    (658763, "SingTel"),
+    (658764, "SingTel"),
+    (658765, "StarHub"),
+    (658766, "M1"),
+    (658767, "M1"),
+    (658768, "M1"),
+    (658769, "myrepublic"),
+    (658770, "vivohub"),
+    (658771, "vivohub"),
+    (658772, "vivohub"),
+    (658773, "SingTel"),
+    (658774, "SingTel"),
+    (658775, "SingTel"),
+    (658776, "M1"),
+    (658777, "M1"),
+    (658778, "M1"),
+    (658779, "M1"),
+    (658789, "StarHub"),
+    (658790, "StarHub"),
+    (658791, "myrepublic"),
+    (658792, "M1"),
+    (658793, "M1"),
+    (658796, "Simba"),
+    (658797, "M1"),
+    (658798, "SingTel"),
+    (658799, "SingTel"),
+    (658800, "M1"),
+    (658808, "M1"),
+    (658809, "SingTel"),
+    (658810, "Simba"),
+    (658819, "Simba"),
+    (658824, "Simba"),
+    (658825, "Simba"),
+    (658827, "Simba"),
+    (658829, "StarHub"),
+    (658830, "StarHub"),
+    (658831, "StarHub"),
+    (658833, "M1"),
+    (658838, "M1"),
+    (658840, "SingTel"),
+    (658841, "SingTel"),
+    (658842, "vivohub"),
+    (658843, "vivohub"),
+    (658844, "M1"),
+    (658845, "SingTel"),
+    (658846, "SingTel"),
+    (658847, "Simba"),
+    (658848, "M1"),
+    (658849, "Simba"),
+    (658855, "M1"),
+    (658858, "M1"),
+    (658862, "M1"),
+    (658865, "Simba"),
+    (658866, "M1"),
+    (658867, "M1"),
+    (658868, "M1"),
+    (658869, "SingTel"),
+    (658876, "SingTel"),
+    (658877, "M1"),
+    (658890, "M1"),
+    (658891, "M1"),
+    (658892, "M1"),
+    (658893, "M1"),
+    (658894, "M1"),
+    (658895, "Simba"),
+    (658896, "Simba"),
+    (658897, "Simba"),
+    (658898, "Simba"),
+    (658899, "Simba"),
+    (658900, "Simba"),
+    (658901, "Simba"),
+    (658902, "Simba"),
+    (658903, "Simba"),
+    (658904, "Simba"),
+    (658905, "SingTel"),
+    (658906, "SingTel"),
+    (658907, "SingTel"),
+    (658908, "SingTel"),
+    (658909, "SingTel"),
+    (658920, "StarHub"),
+    (658921, "M1"),
+    (658922, "M1"),
+    (658923, "M1"),
+    (658924, "Simba"),
+    (658925, "Simba"),
+    (658926, "Simba"),
+    (658927, "Simba"),
+    (658928, "Simba"),
+    (658929, "SingTel"),
+    (658930, "Simba"),
+    (658931, "Simba"),
+    (658932, "Simba"),
+    (658933, "Simba"),
+    (658934, "Simba"),
+    (658935, "M1"),
+    (658936, "M1"),
+    (658937, "M1"),
+    (658938, "SingTel"),
+    (658939, "SingTel"),
+    (658940, "SingTel"),
+    (658941, "SingTel"),
+    (658942, "SingTel"),
+    (658943, "SingTel"),
+    (658944, "Simba"),
+    (658945, "Simba"),
+    (658946, "Simba"),
+    (658947, "Simba"),
+    (658948, "Simba"),
+    (658949, "M1"),
+    (658950, "M1"),
+    (658951, "M1"),
+    (658952, "M1"),
+    (658963, "SingTel"),
+    (658964, "SingTel"),
+    (658965, "SingTel"),
+    (658971, "Circle.Life"),
+    (658972, "SingTel"),
+    (658976, "SingTel"),
+    (658977, "SingTel"),
+    (658980, "Simba"),
+    (658982, "SingTel"),
+    (658983, "SingTel"),
+    (658984, "SingTel"),
+    (658985, "SingTel"),
+    (658986, "Simba"),
+    (658987, "Simba"),
+    (658988, "Simba"),
+    (658989, "Simba"),
+    (658990, "SingTel"),
+    (658991, "M1"),
+    (658992, "Simba"),
+    (658993, "Simba"),
+    (658994, "Simba"),
+    (659001, "StarHub"),
+    (659002, "StarHub"),
+    (659003, "StarHub"),
+    (659004, "StarHub"),
+    (659005, "StarHub"),
+    (659006, "StarHub"),
+    (659007, "StarHub"),
+    (659008, "StarHub"),
+    (659009, "StarHub"),
+    (659199, "StarHub"),
+    (659237, "StarHub"),
+    (659238, "StarHub"),
+    (659239, "StarHub"),
+    (659270, "StarHub"),
+    (659271, "StarHub"),
+    (659272, "StarHub"),
+    (659295, "SingTel"),
+    (659296, "SingTel"),
+    (659297, "SingTel"),
+    (659298, "SingTel"),
+    (659299, "SingTel"),
+    (659334, "SingTel"),
+    (659335, "StarHub"),
+    (659336, "StarHub"),
+    (659337, "StarHub"),
+    (659338, "StarHub"),
+    (659339, "StarHub"),
+    (659499, "StarHub"),
+    (659800, "StarHub"),
+    (659806, "SingTel"),
+    (659807, "SingTel"),
+    (659813, "StarHub"),
+    (659814, "StarHub"),
+    (659888, "SingTel"),
+    (659889, "M1"),
+    (666102, "AIS"),
+    (666103, "AIS"),
+    (666104, "AIS"),
+    (666105, "AIS"),
+    (666106, "AIS"),
+    (666107, "AIS"),
+    (666108, "AIS"),
+    (666109, "AIS"),
+    (666160, "AIS"),
+    (666161, "AIS"),
+    (666162, "AIS"),
+    (666163, "AIS"),
+    (666164, "AIS"),
+    (666165, "AIS"),
+    (666166, "AIS"),
+    (666167, "AIS"),
+    (666168, "DTAC"),
+    (666260, "AIS"),
+    (666298, "AIS"),
+    (666299, "AIS"),
+    (666320, "AIS"),
+    (666321, "AIS"),
+    (666322, "AIS"),
+    (666323, "AIS"),
+    (666324, "AIS"),
+    (666325, "AIS"),
+    (666326, "AIS"),
+    (666327, "AIS"),
+    (666328, "AIS"),
+    (666357, "AIS"),
+    (666358, "AIS"),
+    (666359, "AIS"),
+    (666520, "DTAC"),
+    (666521, "DTAC"),
+    (666522, "DTAC"),
+    (666523, "DTAC"),
+    (666570, "AIS"),
+    (666571, "AIS"),
+    (666572, "AIS"),
+    (666574, "DTAC"),
+    (666575, "DTAC"),
+    (666576, "DTAC"),
+    (666577, "DTAC"),
+    (668030, "True Move"),
+    (668031, "True Move"),
+    (668032, "True Move"),
+    (668034, "True Move"),
+    (668035, "True Move"),
+    (668036, "True Move"),
+    (668037, "True Move"),
+    (668038, "True Move"),
+    (668039, "True Move"),
+    (668121, "TOT Mobile"),
+    (668122, "TOT Mobile"),
+    (668123, "True Move"),
+    (668125, "AIS"),
+    (668126, "AIS"),
+    (668127, "AIS"),
+    (668128, "AIS"),
+    (668129, "AIS"),
+    (668130, "DTAC"),
+    (668134, "DTAC"),
+    (668135, "True Move"),
+    (668139, "DTAC"),
+    (668146, "True Move"),
+    (668150, "AIS"),
+    (668151, "AIS"),
+    (668152, "AIS"),
+    (668261, "True Move"),
+    (668262, "True Move"),
+    (668263, "True Move"),
+    (668264, "True Move"),
+    (668265, "True Move"),
+    (668266, "True Move"),
+    (668267, "True Move"),
+    (668268, "True Move"),
+    (668269, "True Move"),
+    (668292, "AIS"),
+    (668326, "AIS"),
+    (668328, "DTAC"),
+    (668329, "DTAC"),
+    (668341, "DTAC"),
+    (668348, "AIS"),
+    (668354, "DTAC"),
+    (668355, "DTAC"),
+    (668356, "DTAC"),
+    (668357, "DTAC"),
+    (668358, "DTAC"),
+    (668359, "AIS"),
+    (668372, "DTAC"),
+    (668373, "DTAC"),
+    (668374, "DTAC"),
+    (668376, "DTAC"),
+    (668395, "DTAC"),
+    (668396, "DTAC"),
+    (668430, "DTAC"),
+    (668440, "DTAC"),
+    (668450, "True Move"),
+    (668451, "DTAC"),
+    (668458, "True Move"),
+    (668459, "DTAC"),
+    (668497, "True Move"),
+    (668498, "True Move"),
+    (668499, "True Move"),
+    (668540, "DTAC"),
+    (668541, "DTAC"),
+    (668549, "DTAC"),
+    (668561, "DTAC"),
+    (668590, "DTAC"),
+    (668591, "DTAC"),
+    (668592, "DTAC"),
+    (668670, "True Move"),
+    (668671, "True Move"),
+    (668672, "True Move"),
+    (668673, "True Move"),
+    (668674, "True Move"),
+    (668675, "DTAC"),
+    (668676, "DTAC"),
+    (668677, "DTAC"),
+    (668678, "DTAC"),
+    (668679, "DTAC"),
+    (668820, "DTAC"),
+    (668821, "DTAC"),
+    (668822, "DTAC"),
+    (668823, "DTAC"),
+    (668824, "DTAC"),
+    (668825, "True Move"),
+    (668826, "True Move"),
+    (668827, "True Move"),
+    (668828, "True Move"),
+    (668829, "True Move"),
+    (668850, "AIS"),
+    (668851, "AIS"),
+    (668852, "AIS"),
+    (668853, "AIS"),
+    (668854, "AIS"),
+    (668855, "True Move"),
+    (668856, "True Move"),
+    (668857, "True Move"),
+    (668858, "True Move"),
+    (668859, "True Move"),
+    (668870, "AIS"),
+    (668871, "AIS"),
+    (668872, "AIS"),
+    (668873, "AIS"),
+    (668874, "AIS"),
+    (668875, "True Move"),
+    (668876, "True Move"),
+    (668877, "True Move"),
+    (668878, "True Move"),
+    (668879, "True Move"),
+    (668890, "DTAC"),
+    (669021, "True Move"),
+    (669022, "True Move"),
+    (669023, "True Move"),
+    (669024, "True Move"),
+    (669025, "True Move"),
+    (669026, "True Move"),
+    (669027, "True Move"),
+    (669028, "True Move"),
+    (669029, "True Move"),
+    (669032, "True Move"),
+    (669050, "TOT Mobile"),
+    (669051, "TOT Mobile"),
+    (669052, "TOT Mobile"),
+    (669053, "TOT Mobile"),
+    (669054, "TOT Mobile"),
+    (669055, "DTAC"),
+    (669056, "DTAC"),
+    (669057, "DTAC"),
+    (669058, "DTAC"),
+    (669059, "DTAC"),
+    (669070, "DTAC"),
+    (669071, "DTAC"),
+    (669072, "DTAC"),
+    (669073, "DTAC"),
+    (669074, "DTAC"),
+    (669075, "AIS"),
+    (669076, "AIS"),
+    (669077, "AIS"),
+    (669078, "AIS"),
+    (669079, "AIS"),
+    (669089, "True Move"),
+    (669108, "DTAC"),
+    (669109, "DTAC"),
+    (669120, "AIS"),
+    (669122, "AIS"),
+    (669123, "AIS"),
+    (669124, "AIS"),
+    (669125, "AIS"),
+    (669126, "AIS"),
+    (669127, "AIS"),
+    (669128, "AIS"),
+    (669129, "AIS"),
+    (669138, "DTAC"),
+    (669139, "DTAC"),
+    (669150, "DTAC"),
+    (669151, "DTAC"),
+    (669152, "DTAC"),
+    (669153, "DTAC"),
+    (669154, "DTAC"),
+    (669155, "AIS"),
+    (669156, "AIS"),
+    (669157, "AIS"),
+    (669158, "AIS"),
+    (669159, "AIS"),
+    (669169, "True Move"),
+    (669189, "TOT Mobile"),
+    (669198, "AIS"),
+    (669199, "AIS"),
+    (669220, "DTAC"),
+    (669221, "DTAC"),
+    (669222, "DTAC"),
+    (669223, "DTAC"),
+    (669224, "DTAC"),
+    (669420, "True Move"),
+    (669421, "True Move"),
+    (669422, "True Move"),
+    (669423, "True Move"),
+    (669510, "DTAC"),
+    (669511, "DTAC"),
+    (669512, "DTAC"),
+    (669515, "AIS"),
+    (669530, "AIS"),
+    (669736, "TOT Mobile"),
+    (669737, "TOT Mobile"),
+    (669738, "TOT Mobile"),
+    (669739, "TOT Mobile"),
+    (669790, "TOT Mobile"),
+    (669791, "TOT Mobile"),
+    (669900, "AIS"),
+    (669901, "AIS"),
+    (669950, "DTAC"),
+    (673228, "DSTCom"),
+    (673229, "DSTCom"),
+    (674553, "Digicel"),
+    (674554, "Digicel"),
+    (674556, "Digicel"),
+    (674557, "Digicel"),
+    (674558, "Digicel"),
+    (674559, "Digicel"),
+    (676554, "Toko Wireless"),
+    (676555, "Toko Wireless"),
+    (676556, "Toko Wireless"),
+    (686720, "ATHKL"),
+    (686721, "ATHKL"),
+    (686722, "ATHKL"),
+    (686723, "ATHKL"),
+    (686724, "ATHKL"),
+    (686725, "ATHKL"),
+    (686726, "ATHKL"),
+    (686728, "ATHKL"),
+    (686729, "ATHKL"),
+    (686730, "ATHKL"),
+    (686732, "ATHKL"),
+    (686733, "ATHKL"),
+    (686734, "ATHKL"),
+    (686735, "ATHKL"),
+    (686736, "ATHKL"),
+    (686737, "ATHKL"),
+    (686738, "ATHKL"),
+    (686739, "ATHKL"),
+    (686756, "ATHKL"),
+    (686757, "ATHKL"),
+    (686758, "ATHKL"),
+    (686759, "ATHKL"),
+    (790003, "Motiv"),
+    (790004, "Motiv"),
+    (790020, "Motiv"),
+    (790021, "Motiv"),
+    (790030, "Tele2"),
+    (790031, "Tele2"),
+    (790032, "Tele2"),
+    (790035, "Tele2"),
+    (790036, "Tele2"),
+    (790038, "Motiv"),
+    (790039, "Motiv"),
+    (790041, "Tele2"),
+    (790042, "Tele2"),
+    (790043, "Tele2"),
+    (790044, "Tele2"),
+    (790045, "Tele2"),
+    (790046, "Tele2"),
+    (790047, "Tele2"),
+    (790049, "Tele2"),
+    (790100, "Tele2"),
+    (790105, "Tele2"),
+    (790108, "MTS"),
+    (790117, "Tele2"),
+    (790118, "Tele2"),
+    (790119, "Tele2"),
+    (790120, "Tele2"),
+    (790125, "Apex Telecom"),
+    (790127, "Tele2"),
+    (790128, "Tele2"),
+    (790129, "Tele2"),
+    (790140, "Tele2"),
+    (790142, "Tele2"),
+    (790143, "Tele2"),
+    (790145, "Tele2"),
+    (790148, "Tele2"),
+    (790160, "Tele2"),
+    (790161, "Tele2"),
+    (790165, "Tele2"),
+    (790166, "Tele2"),
+    (790167, "Tele2"),
+    (790168, "Tele2"),
+    (790169, "Tele2"),
+    (790180, "Tele2"),
+    (790181, "MTS"),
+    (790185, "Tele2"),
+    (790186, "Tele2"),
+    (790205, "Beeline"),
+    (790206, "Beeline"),
+    (790207, "Beeline"),
+    (790208, "MTS"),
+    (790211, "MegaFon"),
+    (790220, "MTS"),
+    (790222, "MegaFon"),
+    (790229, "MegaFon"),
+    (790230, "Tele2"),
+    (790232, "MegaFon"),
+    (790233, "MegaFon"),
+    (790234, "MTS"),
+    (790236, "Tele2"),
+    (790237, "MegaFon"),
+    (790238, "Tele2"),
+    (790239, "Tele2"),
+    (790241, "Tele2"),
+    (790243, "Tele2"),
+    (790244, "Tele2"),
+    (790245, "Tele2"),
+    (790247, "Tele2"),
+    (790248, "Tele2"),
+    (790249, "Motiv"),
+    (790252, "Beeline"),
+    (790255, "Beeline"),
+    (790259, "Motiv"),
+    (790287, "Motiv"),
+    (790416, "Motiv"),
+    (790417, "Motiv"),
+    (790438, "Motiv"),
+    (790450, "Tele2"),
+    (790451, "Tele2"),
+    (790452, "Tele2"),
+    (790453, "Tele2"),
+    (790454, "Motiv"),
+    (790455, "Tele2"),
+    (790457, "Tele2"),
+    (790458, "Tele2"),
+    (790459, "Tele2"),
+    (790462, "Beeline"),
+    (790470, "Beeline"),
+    (790471, "TMT"),
+    (790472, "MegaFon"),
+    (790474, "MegaFon"),
+    (790475, "Tele2"),
+    (790476, "Tele2"),
+    (790477, "Tele2"),
+    (790478, "Tele2"),
+    (790479, "Tele2"),
+    (790490, "Tele2"),
+    (790491, "Tele2"),
+    (790492, "Tele2"),
+    (790493, "Tele2"),
+    (790494, "Tele2"),
+    (790496, "Tele2"),
+    (790497, "Tele2"),
+    (790498, "Motiv"),
+    (790499, "Tele2"),
+    (790833, "TMT"),
+    (790834, "TMT"),
+    (790835, "MTS"),
+    (790836, "MegaFon"),
+    (790838, "MegaFon"),
+    (790839, "MegaFon"),
+    (790840, "MegaFon"),
+    (790841, "MegaFon"),
+    (790842, "MegaFon"),
+    (790844, "Beeline"),
+    (790845, "Beeline"),
+    (790852, "MTS"),
+    (790853, "MTS"),
+    (790856, "MTS"),
+    (790861, "MegaFon"),
+    (790862, "MegaFon"),
+    (790863, "Motiv"),
+    (790884, "MegaFon"),
+    (790890, "Motiv"),
+    (790891, "Motiv"),
+    (790892, "Motiv"),
+    (790896, "Beeline"),
+    (790897, "Beeline"),
+    (790898, "Beeline"),
+    (790899, "Beeline"),
+    (793065, "MegaFon"),
+    (793066, "MegaFon"),
+    (793067, "MegaFon"),
+    (793068, "MegaFon"),
+    (793069, "MegaFon"),
+    (793393, "MegaFon"),
+    (793399, "MegaFon"),
+    (793650, "MegaFon"),
+    (793651, "MegaFon"),
+    (793652, "MegaFon"),
+    (793653, "MegaFon"),
+    (793865, "MegaFon"),
+    (793869, "MegaFon"),
+    (793870, "MegaFon"),
+    (793877, "MegaFon"),
+    (793878, "MegaFon"),
+    (793879, "MegaFon"),
+    (793930, "TMT"),
+    (793931, "TMT"),
+    (793933, "TMT"),
+    (793934, "TMT"),
+    (793935, "TMT"),
+    (793936, "TMT"),
+    (793937, "TMT"),
+    (793938, "TMT"),
+    (793939, "TMT"),
+    (793950, "TMT"),
+    (795019, "Motiv"),
+    (795020, "Motiv"),
+    (795023, "MTS"),
+    (795063, "Motiv"),
+    (795064, "Motiv"),
+    (795065, "Motiv"),
+    (795093, "MTS"),
+    (795100, "Beeline"),
+    (795101, "Beeline"),
+    (795102, "Beeline"),
+    (795103, "Tele2"),
+    (795105, "Tele2"),
+    (795106, "Tele2"),
+    (795107, "Tele2"),
+    (795108, "Tele2"),
+    (795109, "Tele2"),
+    (795213, "Motiv"),
+    (795214, "Motiv"),
+    (795273, "Motiv"),
+    (795300, "Motiv"),
+    (795304, "Motiv"),
+    (795305, "Motiv"),
+    (795320, "Beeline"),
+    (795321, "Beeline"),
+    (795322, "Beeline"),
+    (795338, "Motiv"),
+    (795360, "Motiv"),
+    (795810, "Tele2"),
+    (795811, "Tele2"),
+    (795812, "Tele2"),
+    (795813, "Tele2"),
+    (795814, "Tele2"),
+    (795815, "Tele2"),
+    (795816, "Tele2"),
+    (795817, "Tele2"),
+    (795818, "Tele2"),
+    (795839, "Tele2"),
+    (795841, "Multiregional Transit Telecom (MTT)"),
+    (795842, "Multiregional Transit Telecom (MTT)"),
+    (795844, "Global Telecom"),
+    (795845, "MegaFon"),
+    (795846, "Multiregional Transit Telecom (MTT)"),
+    (795848, "Multiregional Transit Telecom (MTT)"),
+    (795850, "Tele2"),
+    (795856, "Tele2"),
+    (795858, "Tele2"),
+    (795860, "Multiregional Transit Telecom (MTT)"),
+    (795861, "Multiregional Transit Telecom (MTT)"),
+    (795862, "TMT"),
+    (795863, "Tele2"),
+    (795864, "Tele2"),
+    (795865, "Tele2"),
+    (795866, "Tele2"),
+    (795867, "Tele2"),
+    (795868, "Tele2"),
+    (795880, "MTS"),
+    (795881, "MTS"),
+    (795882, "MTS"),
+    (795884, "Tele2"),
+    (795885, "Tele2"),
+    (795886, "Tele2"),
+    (795887, "Tele2"),
+    (795889, "Nat Nat Bai Holding"),
+    (797721, "Motiv"),
+    (797722, "Motiv"),
+    (797723, "Motiv"),
+    (797724, "Motiv"),
+    (797810, "MTS"),
+    (797811, "MTS"),
+    (797812, "MTS"),
+    (797813, "MTS"),
+    (797814, "MTS"),
+    (797815, "Elemte-Invest"),
+    (797820, "MTS"),
+    (797821, "MTS"),
+    (797822, "MTS"),
+    (797826, "MTS"),
+    (797827, "MTS"),
+    (798091, "Tele2"),
+    (798092, "Tele2"),
+    (798093, "Tele2"),
+    (798094, "Tele2"),
+    (798098, "Sberbank-Telecom"),
+    (798099, "Sberbank-Telecom"),
+    (799100, "Tele2"),
+    (799102, "Tele2"),
+    (799103, "Tele2"),
+    (799104, "Tele2"),
+    (799105, "Tele2"),
+    (799106, "Tele2"),
+    (799107, "Tele2"),
+    (799108, "Tele2"),
+    (799109, "Tele2"),
+    (799111, "MegaFon"),
+    (799132, "Tele2"),
+    (799135, "Tele2"),
+    (799136, "Tele2"),
+    (799137, "Tele2"),
+    (799138, "Tele2"),
+    (799139, "Tele2"),
+    (799220, "Tele2"),
+    (799221, "Tele2"),
+    (799222, "Tele2"),
+    (799223, "Tele2"),
+    (799224, "Tele2"),
+    (799228, "Tele2"),
+    (799229, "Tele2"),
+    (799230, "Tele2"),
+    (799231, "Tele2"),
+    (799233, "Tele2"),
+    (799234, "Tele2"),
+    (799235, "Tele2"),
+    (799236, "Tele2"),
+    (799240, "Tele2"),
+    (799241, "Tele2"),
+    (799242, "Tele2"),
+    (799250, "Tele2"),
+    (799251, "Tele2"),
+    (799252, "Tele2"),
+    (799300, "Tele2"),
+    (799301, "Tele2"),
+    (799302, "Tele2"),
+    (799400, "Tele2"),
+    (799401, "Tele2"),
+    (799510, "Tele2"),
+    (799512, "Tele2"),
+    (799518, "Tele2"),
+    (799519, "Tele2"),
+    (799520, "Tele2"),
+    (799540, "Tele2"),
+    (799541, "Tele2"),
+    (799542, "Tele2"),
+    (799550, "Tele2"),
+    (799555, "MegaFon"),
+    (799559, "Tele2"),
+    (799560, "Tele2"),
+    (799571, "Tele2"),
+    (799572, "Tele2"),
+    (799573, "Tele2"),
+    (799574, "Tele2"),
+    (799575, "Tele2"),
+    (799576, "Tele2"),
+    (799577, "Tele2"),
+    (799579, "Internod"),
+    (799580, "Vainakh Telecom CJSC"),
+    (799581, "Vainakh Telecom CJSC"),
+    (799582, "Vainakh Telecom CJSC"),
+    (799588, "Tele2"),
+    (799589, "Tele2"),
+    (799590, "Tele2"),
+    (799591, "Tele2"),
+    (799592, "Tele2"),
+    (799593, "Tele2"),
+    (799595, "MegaFon"),
+    (799596, "Tele2"),
+    (799597, "Lyca Mobile"),
+    (799600, "Tele2"),
+    (799602, "MegaFon"),
+    (799603, "MegaFon"),
+    (799604, "MegaFon"),
+    (799605, "MegaFon"),
+    (799606, "MegaFon"),
+    (799607, "MegaFon"),
+    (799608, "MegaFon"),
+    (799609, "MegaFon"),
+    (799610, "MegaFon"),
+    (799611, "MegaFon"),
+    (799612, "MegaFon"),
+    (799613, "MegaFon"),
+    (799621, "MegaFon"),
+    (799622, "MegaFon"),
+    (799623, "MegaFon"),
+    (799624, "MegaFon"),
+    (799626, "MegaFon"),
+    (799628, "MegaFon"),
+    (799629, "MegaFon"),
+    (799670, "Tele2"),
+    (799672, "Tele2"),
+    (799673, "Tele2"),
+    (799674, "Tele2"),
+    (799676, "Tele2"),
+    (799677, "Tele2"),
+    (799678, "Tele2"),
+    (799679, "Tele2"),
+    (799680, "Tele2"),
+    (799689, "MegaFon"),
+    (799690, "Tele2"),
+    (817010, "NTT Docomo"),
+    (817026, "NTT Docomo"),
+    (817027, "NTT Docomo"),
+    (817028, "NTT Docomo"),
+    (817029, "NTT Docomo"),
+    (817030, "NTT Docomo"),
+    (817031, "Softbank"),
+    (817032, "Softbank"),
+    (817033, "Softbank"),
+    (817034, "KDDI"),
+    (817035, "KDDI"),
+    (817036, "KDDI"),
+    (817037, "KDDI"),
+    (817038, "Okinawa Cellular"),
+    (817039, "NTT Docomo"),
+    (817045, "KDDI"),
+    (817046, "KDDI"),
+    (817047, "KDDI"),
+    (817051, "Softbank"),
+    (817052, "Softbank"),
+    (817053, "Softbank"),
+    (817054, "Softbank"),
+    (817055, "Softbank"),
+    (817056, "Softbank"),
+    (817059, "KDDI"),
+    (817060, "KDDI"),
+    (817061, "Softbank"),
+    (817062, "Softbank"),
+    (817064, "Softbank"),
+    (817065, "Softbank"),
+    (817066, "Softbank"),
+    (817067, "KDDI"),
+    (817069, "Softbank"),
+    (817074, "KDDI"),
+    (817075, "KDDI"),
+    (817076, "KDDI"),
+    (817077, "KDDI"),
+    (817080, "NTT Docomo"),
+    (817082, "KDDI"),
+    (817083, "Rakuten Communications"),
+    (817084, "Rakuten Communications"),
+    (817085, "Rakuten Communications"),
+    (817086, "KDDI"),
+    (817087, "KDDI"),
+    (817088, "KDDI"),
+    (818077, "NTT Docomo"),
+    (818078, "NTT Docomo"),
+    (818090, "KDDI"),
+    (818091, "Softbank"),
+    (818092, "NTT Docomo"),
+    (818093, "KDDI"),
+    (818094, "Softbank"),
+    (818095, "NTT Docomo"),
+    (818096, "Softbank"),
+    (818097, "Softbank"),
+    (818098, "Okinawa Cellular"),
+    (818099, "NTT Docomo"),
+    (819010, "NTT Docomo"),
+    (819011, "KDDI"),
+    (819012, "KDDI"),
+    (819013, "Softbank"),
+    (819014, "NTT Docomo"),
+    (819015, "NTT Docomo"),
+    (819016, "NTT Docomo"),
+    (819017, "Softbank"),
+    (819018, "KDDI"),
+    (819019, "KDDI"),
+    (819020, "KDDI"),
+    (819028, "Softbank"),
+    (819029, "Softbank"),
+    (819030, "NTT Docomo"),
+    (819031, "NTT Docomo"),
+    (819032, "NTT Docomo"),
+    (819033, "NTT Docomo"),
+    (819035, "KDDI"),
+    (819036, "KDDI"),
+    (819037, "Okinawa Cellular"),
+    (819038, "KDDI"),
+    (819039, "Softbank"),
+    (819042, "KDDI"),
+    (819044, "Okinawa Cellular"),
+    (819060, "Softbank"),
+    (819061, "KDDI"),
+    (819062, "KDDI"),
+    (819063, "KDDI"),
+    (819064, "Softbank"),
+    (819065, "Softbank"),
+    (819066, "Softbank"),
+    (819067, "KDDI"),
+    (819068, "NTT Docomo"),
+    (819069, "NTT Docomo"),
+    (819075, "KDDI"),
+    (819081, "KDDI"),
+    (819082, "Okinawa Cellular"),
+    (819083, "KDDI"),
+    (819084, "Softbank"),
+    (819085, "Softbank"),
+    (819086, "NTT Docomo"),
+    (819087, "NTT Docomo"),
+    (819088, "NTT Docomo"),
+    (819089, "NTT Docomo"),
+    (819090, "NTT Docomo"),
+    (819091, "Softbank"),
+    (819092, "KDDI"),
+    (819093, "KDDI"),
+    (819094, "Softbank"),
+    (819095, "NTT Docomo"),
+    (819096, "KDDI"),
+    (819097, "Okinawa Cellular"),
+    (819098, "Softbank"),
+    (819099, "Softbank"),
+    (821020, "SKTellink"),
+    (821021, "SKTellink"),
+    (821022, "LG U+"),
+    (821023, "LG U+"),
+    (821024, "LG U+"),
+    (821025, "KT"),
+    (821026, "KT"),
+    (821027, "KT"),
+    (821028, "KT"),
+    (821029, "KT"),
+    (821030, "KT"),
+    (821031, "SKTellink"),
+    (821032, "KT"),
+    (821033, "KT"),
+    (821034, "KT"),
+    (821035, "SKTellink"),
+    (821036, "SKTellink"),
+    (821037, "SKTellink"),
+    (821038, "SKTellink"),
+    (821039, "LG U+"),
+    (821042, "KT"),
+    (821043, "KT"),
+    (821044, "KT"),
+    (821050, "SKTellink"),
+    (821051, "KT"),
+    (821052, "SKTellink"),
+    (821053, "SKTellink"),
+    (821054, "SKTellink"),
+    (821055, "LG U+"),
+    (821056, "LG U+"),
+    (821057, "LG U+"),
+    (821058, "LG U+"),
+    (821062, "SKTellink"),
+    (821063, "SKTellink"),
+    (821064, "SKTellink"),
+    (821065, "KT"),
+    (821066, "KT"),
+    (821067, "KT"),
+    (821068, "KT"),
+    (821071, "SKTellink"),
+    (821072, "KT"),
+    (821073, "KT"),
+    (821074, "KT"),
+    (821075, "LG U+"),
+    (821076, "LG U+"),
+    (821077, "LG U+"),
+    (821079, "LG U+"),
+    (821080, "LG U+"),
+    (821081, "LG U+"),
+    (821082, "LG U+"),
+    (821083, "LG U+"),
+    (821084, "LG U+"),
+    (821085, "SKTellink"),
+    (821086, "SKTellink"),
+    (821087, "SKTellink"),
+    (821088, "SKTellink"),
+    (821089, "SKTellink"),
+    (821090, "SKTellink"),
+    (821091, "SKTellink"),
+    (821092, "SKTellink"),
+    (821093, "SKTellink"),
+    (821094, "SKTellink"),
+    (821095, "KT"),
+    (821096, "KT"),
+    (821097, "KT"),
+    (821098, "KT"),
+    (821099, "KT"),
+    (850191, "Koryolink"),
+    (850192, "Koryolink"),
+    (850193, "KPTC"),
+    (852440, "China Mobile"),
+    (852441, "HKT"),
+    (852442, "Hutchison Telecom"),
+    (852443, "HKT"),
+    (852444, "HKT"),
+    (852445, "HKT"),
+    (852446, "Hutchison Telecom"),
+    (852447, "China Mobile"),
+    (852448, "Hutchison Telecom"),
+    (852449, "Hutchison Telecom"),
+    (852460, "China Unicom"),
+    (852463, "China Mobile"),
+    (852467, "China Mobile"),
+    (852468, "China Mobile"),
+    (852469, "China Mobile"),
+    (852470, "Hutchison Telecom"),
+    (852471, "Hutchison Telecom"),
+    (852472, "China Mobile"),
+    (852473, "SmarTone"),
+    (852475, "Hutchison Telecom"),
+    (852476, "China Mobile"),
+    (852510, "China Mobile"),
+    (852511, "HKT"),
+    (852512, "China Mobile"),
+    (852513, "China Mobile"),
+    (852514, "HKT"),
+    (852516, "China Mobile"),
+    (852517, "China Mobile"),
+    (852518, "HKT"),
+    (852519, "3"),
+    (852520, "HKT"),
+    (852521, "HKT"),
+    (852522, "China Mobile"),
+    (852524, "HKT"),
+    (852525, "SmarTone"),
+    (852526, "China Mobile"),
+    (852527, "HKT"),
+    (852529, "HKT"),
+    (852530, "China Mobile"),
+    (852531, "China Mobile"),
+    (852532, "HKT"),
+    (852533, "3"),
+    (852534, "China Mobile"),
+    (852535, "Hutchison Telecom"),
+    (852536, "HKT"),
+    (852537, "China Mobile"),
+    (852538, "China Unicom"),
+    (852539, "China Mobile"),
+    (852540, "HKT"),
+    (852541, "HKT"),
+    (852542, "China Mobile"),
+    (852543, "HKT"),
+    (852544, "China Mobile"),
+    (852546, "3"),
+    (852547, "HKT"),
+    (852548, "China Mobile"),
+    (852549, "China Mobile"),
+    (852550, "SmarTone"),
+    (852551, "HKT"),
+    (852552, "HKT"),
+    (852553, "China Mobile"),
+    (852556, "3"),
+    (852557, "China Mobile"),
+    (852558, "HKT"),
+    (852559, "SmarTone"),
+    (852560, "China Mobile"),
+    (852561, "China Mobile"),
+    (852562, "China Mobile"),
+    (852563, "3"),
+    (852564, "China Mobile"),
+    (852566, "3"),
+    (852568, "HKT"),
+    (852569, "3"),
+    (852571, "HKT"),
+    (852572, "SmarTone"),
+    (852573, "China Mobile"),
+    (852576, "China Mobile"),
+    (852577, "China Mobile"),
+    (852578, "HKT"),
+    (852579, "HKT"),
+    (852590, "HKT"),
+    (852591, "HKT"),
+    (852592, "HKT"),
+    (852593, "China Mobile"),
+    (852594, "HKT"),
+    (852595, "China Mobile"),
+    (852596, "3"),
+    (852597, "3"),
+    (852598, "China Mobile"),
+    (852599, "HKT"),
+    (852601, "SmarTone"),
+    (852602, "HKT"),
+    (852603, "HKT"),
+    (852604, "3"),
+    (852605, "HKT"),
+    (852606, "China Mobile"),
+    (852607, "3"),
+    (852608, "3"),
+    (852609, "China Mobile"),
+    (852610, "3"),
+    (852611, "SmarTone"),
+    (852615, "China Mobile"),
+    (852619, "3"),
+    (852620, "3"),
+    (852621, "China Mobile"),
+    (852622, "China Mobile"),
+    (852623, "China Mobile"),
+    (852625, "HKT"),
+    (852626, "China Mobile"),
+    (852627, "3"),
+    (852628, "HKT"),
+    (852629, "HKT"),
+    (852630, "HKT"),
+    (852631, "3"),
+    (852632, "SmarTone"),
+    (852633, "3"),
+    (852634, "3"),
+    (852635, "China Mobile"),
+    (852637, "HKT"),
+    (852638, "HKT"),
+    (852639, "HKT"),
+    (852640, "3"),
+    (852641, "HKT"),
+    (852642, "HKT"),
+    (852643, "China Mobile"),
+    (852644, "HKT"),
+    (852645, "China Mobile"),
+    (852646, "SmarTone"),
+    (852647, "3"),
+    (852648, "China Mobile"),
+    (852649, "HKT"),
+    (852650, "3"),
+    (852651, "HKT"),
+    (852652, "China Unicom"),
+    (852653, "HKT"),
+    (852654, "HKT"),
+    (852655, "China Unicom"),
+    (852656, "China Unicom"),
+    (852657, "China Mobile"),
+    (852658, "China Mobile"),
+    (852659, "HKT"),
+    (852660, "HKT"),
+    (852661, "China Unicom"),
+    (852662, "HKT"),
+    (852663, "China Unicom"),
+    (852664, "China Mobile"),
+    (852665, "HKT"),
+    (852667, "China Mobile"),
+    (852668, "3"),
+    (852669, "HKT"),
+    (852670, "China Mobile"),
+    (852671, "HKT"),
+    (852672, "China Unicom"),
+    (852673, "China Mobile"),
+    (852674, "China Mobile"),
+    (852675, "HKT"),
+    (852676, "China Mobile"),
+    (852677, "HKT"),
+    (852678, "China Mobile"),
+    (852679, "3"),
+    (852680, "HKT"),
+    (852681, "China Unicom"),
+    (852682, "HKT"),
+    (852684, "China Mobile"),
+    (852685, "China Mobile"),
+    (852687, "China Mobile"),
+    (852688, "China Unicom"),
+    (852689, "HKT"),
+    (852690, "3"),
+    (852691, "China Mobile"),
+    (852692, "HKT"),
+    (852693, "China Mobile"),
+    (852694, "China Mobile"),
+    (852695, "China Mobile"),
+    (852697, "HKT"),
+    (852699, "China Mobile"),
+    (852701, "China Mobile"),
+    (852702, "Hutchison Telecom"),
+    (852703, "Hutchison Telecom"),
+    (852704, "Hutchison Telecom"),
+    (852705, "Hutchison Telecom"),
+    (852709, "Hutchison Telecom"),
+    (852840, "China Mobile"),
+    (852841, "Hutchison Telecom"),
+    (852842, "HKT"),
+    (852843, "Hutchison Telecom"),
+    (852844, "China Mobile"),
+    (852849, "China Mobile"),
+    (852901, "SmarTone"),
+    (852902, "HKT"),
+    (852903, "HKT"),
+    (852904, "Hutchison Telecom"),
+    (852905, "Hutchison Telecom"),
+    (852906, "China Mobile"),
+    (852907, "HKT"),
+    (852908, "HKT"),
+    (852909, "HKT"),
+    (852910, "HKT"),
+    (852912, "3"),
+    (852913, "SmarTone"),
+    (852914, "China Mobile"),
+    (852915, "HKT"),
+    (852916, "SmarTone"),
+    (852917, "SmarTone"),
+    (852918, "HKT"),
+    (852919, "HKT"),
+    (852920, "China Mobile"),
+    (852921, "HKT"),
+    (852922, "SmarTone"),
+    (852923, "HKT"),
+    (852924, "SmarTone"),
+    (852925, "HKT"),
+    (852926, "HKT"),
+    (852927, "HKT"),
+    (852928, "3"),
+    (852929, "China Unicom"),
+    (852930, "HKT"),
+    (852931, "SmarTone"),
+    (852932, "3"),
+    (852933, "HKT"),
+    (852934, "China Mobile"),
+    (852935, "3"),
+    (852936, "3"),
+    (852937, "3"),
+    (852938, "SmarTone"),
+    (852939, "China Mobile"),
+    (852940, "HKT"),
+    (852941, "3"),
+    (852942, "3"),
+    (852943, "SmarTone"),
+    (852944, "SmarTone"),
+    (852945, "SmarTone"),
+    (852946, "HKT"),
+    (852947, "3"),
+    (852948, "3"),
+    (852949, "3"),
+    (852950, "3"),
+    (852951, "China Mobile"),
+    (852952, "HKT"),
+    (852953, "HKT"),
+    (852954, "SmarTone"),
+    (852955, "HKT"),
+    (852956, "China Unicom"),
+    (852957, "3"),
+    (852958, "China Mobile"),
+    (852959, "HKT"),
+    (852960, "China Mobile"),
+    (852961, "HKT"),
+    (852962, "HKT"),
+    (852963, "3"),
+    (852964, "HKT"),
+    (852965, "HKT"),
+    (852966, "SmarTone"),
+    (852967, "3"),
+    (852968, "SmarTone"),
+    (852969, "HKT"),
+    (852970, "3"),
+    (852971, "China Mobile"),
+    (852972, "SmarTone"),
+    (852973, "SmarTone"),
+    (852974, "3"),
+    (852975, "3"),
+    (852976, "HKT"),
+    (852977, "HKT"),
+    (852978, "HKT"),
+    (852979, "China Mobile"),
+    (852980, "HKT"),
+    (852981, "3"),
+    (852982, "3"),
+    (852983, "3"),
+    (852984, "SmarTone"),
+    (852985, "SmarTone"),
+    (852986, "SmarTone"),
+    (852987, "HKT"),
+    (852988, "HKT"),
+    (852989, "HKT"),
+    (853620, "CTM"),
+    (853621, "China Telecom"),
+    (853622, "3"),
+    (853623, "CTM"),
+    (853624, "3"),
+    (853625, "SmarTone"),
+    (853626, "SmarTone"),
+    (853628, "CTM"),
+    (853629, "CTM"),
+    (853630, "3"),
+    (853631, "3"),
+    (853632, "CTM"),
+    (853633, "CTM"),
+    (853635, "China Telecom"),
+    (853636, "SmarTone"),
+    (853637, "China Telecom"),
+    (853638, "3"),
+    (853639, "CTM"),
+    (853652, "CTM"),
+    (853653, "CTM"),
+    (853655, "CTM"),
+    (853656, "China Telecom"),
+    (853657, "3"),
+    (853658, "China Telecom"),
+    (853659, "CTM"),
+    (853662, "SmarTone"),
+    (853663, "3"),
+    (853664, "3"),
+    (853665, "CTM"),
+    (853666, "CTM"),
+    (853668, "CTM"),
+    (853682, "China Telecom"),
+    (853683, "SmarTone"),
+    (853686, "China Telecom"),
+    (853689, "CTM"),
+    (856202, "ETL"),
+    (856203, "ETL"),
+    (856205, "Lao Telecom"),
+    (856207, "Beeline"),
+    (856208, "Best Telecom"),
+    (856209, "Unitel"),
+    (856302, "ETL"),
+    (856304, "Unitel"),
+    (856305, "Lao Telecom"),
+    (856307, "Lao Telecom"),
+    (856309, "Unitel"),
+    (861340, "China Mobile"),
+    (861341, "China Mobile"),
+    (861342, "China Mobile"),
+    (861343, "China Mobile"),
+    (861344, "China Mobile"),
+    (861345, "China Mobile"),
+    (861346, "China Mobile"),
+    (861347, "China Mobile"),
+    (861348, "China Mobile"),
+    (861700, "China Telecom"),
+    (861701, "China Telecom"),
+    (861702, "China Telecom"),
+    (861703, "China Mobile"),
+    (861704, "China Unicom"),
+    (861705, "China Mobile"),
+    (861706, "China Mobile"),
+    (861707, "China Unicom"),
+    (861708, "China Unicom"),
+    (861709, "China Unicom"),
+    (882342, "BebbiCell AG"),
+    (882347, "BebbiCell AG"),
+    (886900, "FarEasTone"),
+    (886903, "FarEasTone"),
+    (886905, "Chunghwa Telecom"),
+    (886906, "Asia-Pacific Telecom"),
+    (886907, "Asia-Pacific Telecom"),
+    (886908, "T Star"),
+    (886909, "Taiwan Mobile"),
+    (886910, "Chunghwa Telecom"),
+    (886911, "Chunghwa Telecom"),
+    (886912, "Chunghwa Telecom"),
+    (886913, "FarEasTone"),
+    (886915, "FarEasTone"),
+    (886916, "FarEasTone"),
+    (886917, "FarEasTone"),
+    (886918, "Taiwan Mobile"),
+    (886919, "Chunghwa Telecom"),
+    (886920, "Taiwan Mobile"),
+    (886921, "Chunghwa Telecom"),
+    (886922, "Taiwan Mobile"),
+    (886923, "Taiwan Mobile"),
+    (886925, "FarEasTone"),
+    (886926, "FarEasTone"),
+    (886927, "FarEasTone"),
+    (886928, "Chunghwa Telecom"),
+    (886929, "Taiwan Mobile"),
+    (886930, "FarEasTone"),
+    (886931, "Taiwan Mobile"),
+    (886932, "Chunghwa Telecom"),
+    (886933, "Chunghwa Telecom"),
+    (886934, "Chunghwa Telecom"),
+    (886935, "Taiwan Mobile"),
+    (886936, "FarEasTone"),
+    (886937, "Chunghwa Telecom"),
+    (886938, "FarEasTone"),
+    (886939, "Taiwan Mobile"),
+    (886952, "Taiwan Mobile"),
+    (886953, "Taiwan Mobile"),
+    (886955, "FarEasTone"),
+    (886956, "Taiwan Mobile"),
+    (886958, "Taiwan Mobile"),
+    (886963, "Chunghwa Telecom"),
+    (886970, "Taiwan Mobile"),
+    (886972, "Chunghwa Telecom"),
+    (886973, "T Star"),
+    (886975, "Chunghwa Telecom"),
+    (886976, "FarEasTone"),
+    (886978, "Chunghwa Telecom"),
+    (886979, "Taiwan Mobile"),
+    (886980, "Asia-Pacific Telecom"),
+    (886981, "FarEasTone"),
+    (886982, "Asia-Pacific Telecom"),
+    (886983, "Taiwan Mobile"),
+    (886985, "Asia-Pacific Telecom"),
+    (886986, "T Star"),
+    (886987, "Taiwan Mobile"),
+    (886988, "Chunghwa Telecom"),
+    (886989, "FarEasTone"),
+    (905610, "Selam Mobile"),
+    (905616, "Turkcell"),
+    (916208, "Vodafone"),
+    (916209, "Idea"),
+    (916230, "Airtel"),
+    (916232, "Airtel"),
+    (916235, "Idea"),
+    (916238, "Reliance Jio"),
+    (916239, "Reliance Jio"),
+    (916262, "Idea"),
+    (916269, "Idea"),
+    (916287, "Airtel"),
+    (916292, "Vodafone"),
+    (916293, "Idea"),
+    (916309, "Airtel"),
+    (916356, "Idea"),
+    (916357, "Vodafone"),
+    (916358, "Airtel"),
+    (916359, "Vodafone"),
+    (916364, "Airtel"),
+    (916366, "Airtel"),
+    (916384, "Vodafone"),
+    (916385, "Airtel"),
+    (916389, "Vodafone"),
+    (916390, "Vodafone"),
+    (916391, "Vodafone"),
+    (916399, "Idea"),
+    (917020, "Reliance Jio"),
+    (917021, "Reliance Jio"),
+    (917022, "Airtel"),
+    (917023, "Airtel"),
+    (917024, "Airtel"),
+    (917025, "Idea"),
+    (917026, "Idea"),
+    (917027, "Vodafone"),
+    (917028, "Airtel"),
+    (917029, "Reliance Jio"),
+    (917030, "Vodafone"),
+    (917031, "Aircel"),
+    (917032, "Airtel"),
+    (917033, "Airtel"),
+    (917034, "Vodafone"),
+    (917035, "Aircel"),
+    (917036, "Idea"),
+    (917037, "Telewings"),
+    (917038, "Idea"),
+    (917039, "Idea"),
+    (917040, "Aircel"),
+    (917041, "Telewings"),
+    (917042, "Airtel"),
+    (917043, "Airtel"),
+    (917044, "Airtel"),
+    (917045, "Vodafone"),
+    (917046, "Idea"),
+    (917047, "Airtel"),
+    (917048, "Tata Docomo"),
+    (917049, "Idea"),
+    (917050, "Telewings"),
+    (917051, "Airtel"),
+    (917052, "Idea"),
+    (917053, "Aircel"),
+    (917054, "Airtel"),
+    (917055, "Idea"),
+    (917056, "Idea"),
+    (917057, "Idea"),
+    (917058, "Telewings"),
+    (917059, "Aircel"),
+    (917060, "Airtel"),
+    (917061, "Reliance Jio"),
+    (917062, "Aircel"),
+    (917063, "Airtel"),
+    (917064, "Vodafone"),
+    (917065, "Idea"),
+    (917066, "Vodafone"),
+    (917067, "Reliance Jio"),
+    (917068, "Telewings"),
+    (917069, "Vodafone"),
+    (917070, "Airtel"),
+    (917071, "Aircel"),
+    (917072, "Vodafone"),
+    (917073, "Airtel"),
+    (917074, "Idea"),
+    (917075, "Telewings"),
+    (917076, "Vodafone"),
+    (917077, "Airtel"),
+    (917078, "Telewings"),
+    (917079, "Idea"),
+    (917080, "Airtel"),
+    (917081, "Vodafone"),
+    (917082, "Airtel"),
+    (917083, "Idea"),
+    (917084, "Idea"),
+    (917085, "Airtel"),
+    (917086, "Airtel"),
+    (917087, "Airtel"),
+    (917088, "Idea"),
+    (917089, "Idea"),
+    (917090, "Idea"),
+    (917091, "Airtel"),
+    (917092, "Idea"),
+    (917093, "Airtel"),
+    (917094, "Vodafone"),
+    (917095, "Idea"),
+    (917096, "Idea"),
+    (917097, "Aircel"),
+    (917098, "Aircel"),
+    (917099, "Telewings"),
+    (917190, "Airtel"),
+    (917191, "Airtel"),
+    (917192, "Airtel"),
+    (917193, "Airtel"),
+    (917194, "Airtel"),
+    (917195, "Airtel"),
+    (917201, "Idea"),
+    (917202, "Idea"),
+    (917203, "Idea"),
+    (917210, "Aircel"),
+    (917211, "Idea"),
+    (917217, "Tata Docomo"),
+    (917218, "Idea"),
+    (917219, "Airtel"),
+    (917220, "Aircel"),
+    (917221, "Aircel"),
+    (917222, "Aircel"),
+    (917223, "Airtel"),
+    (917224, "Airtel"),
+    (917225, "Airtel"),
+    (917226, "Airtel"),
+    (917227, "Airtel"),
+    (917228, "Airtel"),
+    (917236, "Idea"),
+    (917237, "Idea"),
+    (917238, "Idea"),
+    (917239, "Idea"),
+    (917240, "Idea"),
+    (917241, "Idea"),
+    (917247, "Idea"),
+    (917248, "Idea"),
+    (917249, "Telewings"),
+    (917250, "Airtel"),
+    (917251, "Vodafone"),
+    (917252, "Vodafone"),
+    (917253, "Vodafone"),
+    (917254, "Idea"),
+    (917255, "Idea"),
+    (917256, "Idea"),
+    (917258, "Airtel"),
+    (917259, "Airtel"),
+    (917260, "Airtel"),
+    (917262, "Vodafone"),
+    (917263, "Vodafone"),
+    (917264, "Vodafone"),
+    (917265, "Idea"),
+    (917266, "Telewings"),
+    (917267, "Telewings"),
+    (917268, "Telewings"),
+    (917275, "Tata Docomo"),
+    (917276, "Tata Docomo"),
+    (917280, "Vodafone"),
+    (917281, "Vodafone"),
+    (917282, "Vodafone"),
+    (917283, "Vodafone"),
+    (917287, "Airtel"),
+    (917289, "Airtel"),
+    (917293, "Aircel"),
+    (917298, "Aircel"),
+    (917299, "Aircel"),
+    (917300, "Airtel"),
+    (917301, "Aircel"),
+    (917302, "Airtel"),
+    (917303, "Airtel"),
+    (917304, "Airtel"),
+    (917305, "Airtel"),
+    (917306, "Reliance Jio"),
+    (917307, "Reliance Jio"),
+    (917308, "Reliance Jio"),
+    (917309, "Idea"),
+    (917318, "Airtel"),
+    (917319, "Airtel"),
+    (917330, "Aircel"),
+    (917338, "Airtel"),
+    (917339, "Airtel"),
+    (917340, "Airtel"),
+    (917341, "Airtel"),
+    (917348, "Idea"),
+    (917349, "Airtel"),
+    (917355, "Reliance Jio"),
+    (917356, "Airtel"),
+    (917357, "Airtel"),
+    (917358, "Airtel"),
+    (917373, "Aircel"),
+    (917376, "BSNL MOBILE"),
+    (917377, "Idea"),
+    (917378, "Vodafone"),
+    (917379, "Vodafone"),
+    (917380, "Airtel"),
+    (917381, "Vodafone"),
+    (917382, "BSNL MOBILE"),
+    (917383, "Telewings"),
+    (917384, "Airtel"),
+    (917385, "Telewings"),
+    (917386, "Telewings"),
+    (917387, "Airtel"),
+    (917388, "Airtel"),
+    (917389, "Airtel"),
+    (917396, "Telewings"),
+    (917397, "Airtel"),
+    (917398, "Telewings"),
+    (917399, "Aircel"),
+    (917401, "Aircel"),
+    (917402, "Aircel"),
+    (917403, "Aircel"),
+    (917404, "Tata Docomo"),
+    (917405, "Tata Docomo"),
+    (917406, "Vodafone"),
+    (917407, "Vodafone"),
+    (917408, "Vodafone"),
+    (917409, "Vodafone"),
+    (917410, "Airtel"),
+    (917411, "Tata Docomo"),
+    (917415, "Tata Docomo"),
+    (917416, "Tata Docomo"),
+    (917417, "Tata Docomo"),
+    (917418, "Tata Docomo"),
+    (917419, "Reliance Jio"),
+    (917428, "Reliance Jio"),
+    (917429, "Reliance Jio"),
+    (917439, "Reliance Jio"),
+    (917440, "Idea"),
+    (917441, "Idea"),
+    (917448, "Idea"),
+    (917449, "Idea"),
+    (917470, "Airtel"),
+    (917471, "Airtel"),
+    (917478, "Vodafone"),
+    (917479, "Telenor"),
+    (917483, "Reliance Jio"),
+    (917488, "Reliance Jio"),
+    (917489, "Reliance Jio"),
+    (917498, "Reliance Jio"),
+    (917499, "Reliance Jio"),
+    (917500, "Idea"),
+    (917501, "Tata Docomo"),
+    (917502, "Aircel"),
+    (917503, "Aircel"),
+    (917504, "Aircel"),
+    (917505, "Reliance Jio"),
+    (917506, "Vodafone"),
+    (917507, "Vodafone"),
+    (917508, "Vodafone"),
+    (917509, "Vodafone"),
+    (917510, "Idea"),
+    (917511, "Idea"),
+    (917517, "Idea"),
+    (917518, "Airtel"),
+    (917519, "Aircel"),
+    (917523, "Airtel"),
+    (917524, "Airtel"),
+    (917525, "Airtel"),
+    (917526, "Idea"),
+    (917527, "Idea"),
+    (917528, "Idea"),
+    (917529, "Idea"),
+    (917530, "Aircel"),
+    (917531, "Aircel"),
+    (917532, "Aircel"),
+    (917534, "Idea"),
+    (917535, "Idea"),
+    (917537, "Aircel"),
+    (917538, "Aircel"),
+    (917539, "Aircel"),
+    (917540, "Aircel"),
+    (917541, "Airtel"),
+    (917542, "Airtel"),
+    (917543, "Airtel"),
+    (917549, "Vodafone"),
+    (917558, "Airtel"),
+    (917559, "Idea"),
+    (917560, "Idea"),
+    (917561, "Idea"),
+    (917562, "Idea"),
+    (917563, "Idea"),
+    (917564, "Idea"),
+    (917566, "Vodafone"),
+    (917567, "Vodafone"),
+    (917568, "Airtel"),
+    (917569, "Reliance Jio"),
+    (917572, "Vodafone"),
+    (917573, "Vodafone"),
+    (917574, "Vodafone"),
+    (917575, "Vodafone"),
+    (917576, "Vodafone"),
+    (917579, "BSNL MOBILE"),
+    (917580, "Vodafone"),
+    (917581, "Vodafone"),
+    (917582, "Vodafone"),
+    (917584, "Airtel"),
+    (917585, "Airtel"),
+    (917586, "Airtel"),
+    (917587, "BSNL MOBILE"),
+    (917588, "BSNL MOBILE"),
+    (917589, "BSNL MOBILE"),
+    (917597, "BSNL MOBILE"),
+    (917598, "BSNL MOBILE"),
+    (917599, "BSNL MOBILE"),
+    (917600, "Airtel"),
+    (917601, "Reliance Jio"),
+    (917602, "Airtel"),
+    (917603, "Reliance Jio"),
+    (917604, "Reliance Jio"),
+    (917607, "Airtel"),
+    (917610, "Idea"),
+    (917611, "Aircel"),
+    (917615, "Aircel"),
+    (917617, "Idea"),
+    (917618, "Vodafone"),
+    (917619, "Airtel"),
+    (917620, "Reliance Jio"),
+    (917621, "Idea"),
+    (917622, "Idea"),
+    (917623, "Idea"),
+    (917624, "Idea"),
+    (917631, "Vodafone"),
+    (917639, "Vodafone"),
+    (917654, "Idea"),
+    (917658, "Idea"),
+    (917659, "Idea"),
+    (917660, "Idea"),
+    (917661, "Idea"),
+    (917662, "Idea"),
+    (917663, "Idea"),
+    (917664, "Idea"),
+    (917665, "Vodafone"),
+    (917666, "Reliance Jio"),
+    (917667, "Reliance Jio"),
+    (917668, "Reliance Jio"),
+    (917669, "Vodafone"),
+    (917671, "Reliance Jio"),
+    (917672, "Reliance Jio"),
+    (917674, "Airtel"),
+    (917675, "Airtel"),
+    (917676, "Reliance Jio"),
+    (917677, "Reliance Jio"),
+    (917679, "Reliance Jio"),
+    (917680, "Airtel"),
+    (917681, "Airtel"),
+    (917682, "Airtel"),
+    (917683, "Airtel"),
+    (917684, "Airtel"),
+    (917685, "Aircel"),
+    (917686, "Aircel"),
+    (917687, "Aircel"),
+    (917689, "Idea"),
+    (917695, "Reliance Jio"),
+    (917696, "Tata Docomo"),
+    (917702, "Airtel"),
+    (917708, "Airtel"),
+    (917709, "Airtel"),
+    (917710, "Idea"),
+    (917715, "Airtel"),
+    (917718, "Vodafone"),
+    (917720, "Idea"),
+    (917721, "Idea"),
+    (917723, "Reliance Jio"),
+    (917724, "Reliance Jio"),
+    (917726, "Airtel"),
+    (917727, "Airtel"),
+    (917728, "Airtel"),
+    (917729, "Idea"),
+    (917730, "Idea"),
+    (917731, "Idea"),
+    (917732, "Idea"),
+    (917733, "Idea"),
+    (917734, "Idea"),
+    (917735, "Reliance Jio"),
+    (917736, "Tata Docomo"),
+    (917737, "Tata Docomo"),
+    (917738, "Airtel"),
+    (917739, "Airtel"),
+    (917742, "Airtel"),
+    (917760, "Airtel"),
+    (917780, "Reliance Jio"),
+    (917794, "Telewings"),
+    (917795, "Tata Docomo"),
+    (917796, "Tata Docomo"),
+    (917797, "Vodafone"),
+    (917798, "Vodafone"),
+    (917799, "Vodafone"),
+    (917800, "Vodafone"),
+    (917801, "Telewings"),
+    (917802, "Telewings"),
+    (917803, "Reliance Jio"),
+    (917804, "Reliance Jio"),
+    (917805, "Reliance Jio"),
+    (917806, "Reliance Jio"),
+    (917807, "Airtel"),
+    (917808, "Idea"),
+    (917809, "Aircel"),
+    (917810, "Reliance Jio"),
+    (917814, "Reliance Jio"),
+    (917815, "Reliance Jio"),
+    (917817, "Reliance Jio"),
+    (917818, "Reliance Jio"),
+    (917819, "Reliance Jio"),
+    (917820, "Reliance Jio"),
+    (917821, "Reliance Jio"),
+    (917822, "Reliance Jio"),
+    (917824, "Vodafone"),
+    (917825, "Vodafone"),
+    (917826, "Vodafone"),
+    (917827, "Reliance Jio"),
+    (917828, "Reliance Jio"),
+    (917829, "Vodafone"),
+    (917830, "Vodafone"),
+    (917834, "Idea"),
+    (917835, "Idea"),
+    (917836, "Idea"),
+    (917837, "Vodafone"),
+    (917838, "Vodafone"),
+    (917839, "BSNL MOBILE"),
+    (917841, "Telewings"),
+    (917842, "Tata Docomo"),
+    (917843, "Aircel"),
+    (917844, "Aircel"),
+    (917845, "Tata Docomo"),
+    (917846, "Reliance Jio"),
+    (917847, "Reliance Jio"),
+    (917848, "Reliance Jio"),
+    (917849, "Reliance Jio"),
+    (917860, "Idea"),
+    (917861, "Reliance Jio"),
+    (917862, "Reliance Jio"),
+    (917864, "Reliance Jio"),
+    (917865, "Reliance Jio"),
+    (917866, "Reliance Jio"),
+    (917867, "Vodafone"),
+    (917868, "Vodafone"),
+    (917869, "Airtel"),
+    (917870, "Telewings"),
+    (917871, "Idea"),
+    (917872, "Vodafone"),
+    (917873, "Vodafone"),
+    (917874, "Vodafone"),
+    (917875, "Vodafone"),
+    (917876, "Reliance Jio"),
+    (917877, "Reliance Jio"),
+    (917878, "Reliance Jio"),
+    (917879, "Reliance Jio"),
+    (917880, "Airtel"),
+    (917888, "Reliance Jio"),
+    (917889, "Reliance Jio"),
+    (917890, "Idea"),
+    (917891, "Idea"),
+    (917892, "Reliance Jio"),
+    (917900, "Idea"),
+    (917901, "BSNL MOBILE"),
+    (917902, "Idea"),
+    (917909, "Idea"),
+    (917970, "Airtel"),
+    (917972, "Reliance Jio"),
+    (917973, "Reliance Jio"),
+    (917974, "Reliance Jio"),
+    (917975, "Reliance Jio"),
+    (917976, "Reliance Jio"),
+    (917977, "Reliance Jio"),
+    (917978, "Reliance Jio"),
+    (917990, "Reliance Jio"),
+    (917991, "Airtel"),
+    (917993, "Airtel"),
+    (917994, "Airtel"),
+    (917995, "Airtel"),
+    (917996, "Idea"),
+    (917997, "Idea"),
+    (917998, "Aircel"),
+    (917999, "Reliance Jio"),
+    (918000, "Reliance Jio"),
+    (918001, "Vodafone"),
+    (918002, "Airtel"),
+    (918003, "Airtel"),
+    (918004, "BSNL MOBILE"),
+    (918006, "Vodafone"),
+    (918007, "Vodafone"),
+    (918008, "Airtel"),
+    (918009, "Airtel"),
+    (918010, "Reliance Jio"),
+    (918011, "Airtel"),
+    (918012, "Aircel"),
+    (918013, "Aircel"),
+    (918014, "Aircel"),
+    (918015, "Tata Docomo"),
+    (918016, "Airtel"),
+    (918017, "Vodafone"),
+    (918018, "Airtel"),
+    (918019, "Tata Docomo"),
+    (918050, "Tata Docomo"),
+    (918051, "Vodafone"),
+    (918052, "Vodafone"),
+    (918053, "Vodafone"),
+    (918054, "Vodafone"),
+    (918055, "Idea"),
+    (918056, "Airtel"),
+    (918057, "Idea"),
+    (918058, "Idea"),
+    (918059, "Idea"),
+    (918070, "Aircel"),
+    (918072, "Reliance Jio"),
+    (918073, "Reliance Jio"),
+    (918074, "Reliance Jio"),
+    (918075, "Reliance Jio"),
+    (918076, "Reliance Jio"),
+    (918077, "Reliance Jio"),
+    (918078, "BSNL MOBILE"),
+    (918080, "Reliance Jio"),
+    (918081, "Reliance Jio"),
+    (918082, "Airtel"),
+    (918083, "Aircel"),
+    (918084, "Airtel"),
+    (918085, "Airtel"),
+    (918086, "Vodafone"),
+    (918087, "Tata Docomo"),
+    (918088, "Reliance Jio"),
+    (918089, "Tata Docomo"),
+    (918090, "Tata Docomo"),
+    (918091, "Tata Docomo"),
+    (918092, "Tata Docomo"),
+    (918093, "Tata Docomo"),
+    (918094, "Vodafone"),
+    (918095, "Vodafone"),
+    (918096, "Idea"),
+    (918097, "Tata Docomo"),
+    (918098, "Vodafone"),
+    (918099, "Reliance Jio"),
+    (918100, "Reliance Jio"),
+    (918101, "Reliance Jio"),
+    (918102, "Reliance Jio"),
+    (918103, "Reliance Jio"),
+    (918104, "Reliance Jio"),
+    (918105, "Airtel"),
+    (918106, "Airtel"),
+    (918107, "Airtel"),
+    (918108, "Idea"),
+    (918109, "Tata Docomo"),
+    (918112, "Idea"),
+    (918114, "Airtel"),
+    (918116, "Airtel"),
+    (918120, "Idea"),
+    (918121, "Tata Docomo"),
+    (918122, "Tata Docomo"),
+    (918123, "Tata Docomo"),
+    (918124, "Idea"),
+    (918125, "Tata Docomo"),
+    (918126, "Airtel"),
+    (918127, "Airtel"),
+    (918128, "Airtel"),
+    (918129, "Airtel"),
+    (918130, "Airtel"),
+    (918140, "Idea"),
+    (918141, "Vodafone"),
+    (918142, "Vodafone"),
+    (918143, "Tata Docomo"),
+    (918144, "Reliance Jio"),
+    (918145, "Vodafone"),
+    (918146, "Airtel"),
+    (918147, "Tata Docomo"),
+    (918148, "Tata Docomo"),
+    (918149, "Tata Docomo"),
+    (918160, "Reliance Jio"),
+    (918168, "Reliance Jio"),
+    (918169, "Reliance Jio"),
+    (918171, "Airtel"),
+    (918178, "Reliance Jio"),
+    (918179, "Airtel"),
+    (918180, "Telewings"),
+    (918181, "Aircel"),
+    (918185, "Idea"),
+    (918186, "Idea"),
+    (918188, "Tata Docomo"),
+    (918189, "Tata Docomo"),
+    (918197, "Airtel"),
+    (918200, "Reliance Jio"),
+    (918208, "Reliance Jio"),
+    (918209, "Reliance Jio"),
+    (918210, "Reliance Jio"),
+    (918218, "Reliance Jio"),
+    (918219, "Reliance Jio"),
+    (918220, "Airtel"),
+    (918221, "Idea"),
+    (918222, "Idea"),
+    (918223, "Idea"),
+    (918224, "Idea"),
+    (918225, "Idea"),
+    (918226, "Idea"),
+    (918227, "Idea"),
+    (918228, "Idea"),
+    (918230, "Reliance Jio"),
+    (918231, "Reliance Jio"),
+    (918233, "Tata Docomo"),
+    (918234, "Reliance Jio"),
+    (918235, "Tata Docomo"),
+    (918236, "Reliance Jio"),
+    (918237, "Tata Docomo"),
+    (918238, "Vodafone"),
+    (918239, "Vodafone"),
+    (918240, "Reliance Jio"),
+    (918248, "Reliance Jio"),
+    (918249, "Reliance Jio"),
+    (918256, "Vodafone"),
+    (918257, "Vodafone"),
+    (918258, "Vodafone"),
+    (918259, "Vodafone"),
+    (918260, "Reliance Jio"),
+    (918261, "Reliance Jio"),
+    (918262, "Reliance Jio"),
+    (918263, "Reliance Jio"),
+    (918264, "Airtel"),
+    (918266, "Tata Docomo"),
+    (918267, "Tata Docomo"),
+    (918268, "Aircel"),
+    (918271, "Telewings"),
+    (918272, "Tata Docomo"),
+    (918273, "Telewings"),
+    (918274, "Tata Docomo"),
+    (918275, "BSNL MOBILE"),
+    (918276, "Tata Docomo"),
+    (918277, "BSNL MOBILE"),
+    (918278, "BSNL MOBILE"),
+    (918279, "Reliance Jio"),
+    (918280, "BSNL MOBILE"),
+    (918281, "BSNL MOBILE"),
+    (918282, "Tata Docomo"),
+    (918283, "Airtel"),
+    (918284, "Airtel"),
+    (918285, "Aircel"),
+    (918286, "Aircel"),
+    (918287, "Reliance Jio"),
+    (918288, "Airtel"),
+    (918290, "Airtel"),
+    (918292, "Airtel"),
+    (918293, "Tata Docomo"),
+    (918294, "Airtel"),
+    (918295, "Airtel"),
+    (918296, "Airtel"),
+    (918297, "Vodafone"),
+    (918298, "Vodafone"),
+    (918299, "Reliance Jio"),
+    (918300, "BSNL MOBILE"),
+    (918301, "BSNL MOBILE"),
+    (918302, "Reliance Jio"),
+    (918303, "Reliance Jio"),
+    (918304, "BSNL MOBILE"),
+    (918305, "Reliance Jio"),
+    (918306, "Airtel"),
+    (918307, "Reliance Jio"),
+    (918308, "Idea"),
+    (918309, "Reliance Jio"),
+    (918310, "Reliance Jio"),
+    (918318, "Reliance Jio"),
+    (918319, "Reliance Jio"),
+    (918320, "Reliance Jio"),
+    (918328, "Reliance Jio"),
+    (918329, "Reliance Jio"),
+    (918330, "BSNL MOBILE"),
+    (918331, "BSNL MOBILE"),
+    (918332, "BSNL MOBILE"),
+    (918333, "BSNL MOBILE"),
+    (918340, "Reliance Jio"),
+    (918341, "Telewings"),
+    (918342, "Vodafone"),
+    (918343, "Idea"),
+    (918344, "Aircel"),
+    (918345, "Idea"),
+    (918346, "Idea"),
+    (918347, "Idea"),
+    (918348, "Vodafone"),
+    (918349, "Airtel"),
+    (918350, "Idea"),
+    (918351, "Idea"),
+    (918352, "Idea"),
+    (918354, "Telewings"),
+    (918357, "Vodafone"),
+    (918358, "Vodafone"),
+    (918359, "Vodafone"),
+    (918360, "Reliance Jio"),
+    (918368, "Reliance Jio"),
+    (918369, "Reliance Jio"),
+    (918374, "Airtel"),
+    (918378, "Idea"),
+    (918379, "Idea"),
+    (918380, "Idea"),
+    (918382, "Telewings"),
+    (918384, "Aircel"),
+    (918385, "Aircel"),
+    (918386, "Aircel"),
+    (918387, "Aircel"),
+    (918388, "Reliance Jio"),
+    (918389, "Reliance Jio"),
+    (918390, "Vodafone"),
+    (918391, "Reliance Jio"),
+    (918392, "Vodafone"),
+    (918393, "Vodafone"),
+    (918394, "Vodafone"),
+    (918395, "Vodafone"),
+    (918397, "Vodafone"),
+    (918398, "Vodafone"),
+    (918399, "Vodafone"),
+    (918400, "Airtel"),
+    (918401, "Telewings"),
+    (918409, "Telewings"),
+    (918412, "Vodafone"),
+    (918413, "Airtel"),
+    (918414, "Airtel"),
+    (918415, "Airtel"),
+    (918416, "Idea"),
+    (918417, "Idea"),
+    (918418, "Idea"),
+    (918419, "Idea"),
+    (918420, "Airtel"),
+    (918421, "Telewings"),
+    (918422, "Idea"),
+    (918423, "Telewings"),
+    (918424, "Idea"),
+    (918425, "Idea"),
+    (918426, "Idea"),
+    (918427, "Airtel"),
+    (918428, "Idea"),
+    (918429, "Reliance Jio"),
+    (918430, "Vodafone"),
+    (918431, "Reliance Jio"),
+    (918432, "Idea"),
+    (918434, "Telewings"),
+    (918435, "Idea"),
+    (918436, "Uninor"),
+    (918437, "Idea"),
+    (918438, "Tata Docomo"),
+    (918439, "Tata Docomo"),
+    (918440, "Idea"),
+    (918441, "Idea"),
+    (918442, "Idea"),
+    (918443, "Reliance Jio"),
+    (918444, "Reliance Jio"),
+    (918445, "Telewings"),
+    (918446, "Tata Docomo"),
+    (918447, "Vodafone"),
+    (918448, "Reliance Jio"),
+    (918449, "Idea"),
+    (918451, "Airtel"),
+    (918452, "Airtel"),
+    (918454, "Airtel"),
+    (918455, "Airtel"),
+    (918456, "Airtel"),
+    (918457, "Airtel"),
+    (918458, "Idea"),
+    (918459, "Reliance Jio"),
+    (918460, "Tata Docomo"),
+    (918467, "Reliance Jio"),
+    (918468, "Reliance Jio"),
+    (918469, "Vodafone"),
+    (918470, "Reliance Jio"),
+    (918471, "Airtel"),
+    (918472, "Airtel"),
+    (918473, "Airtel"),
+    (918475, "Idea"),
+    (918476, "Idea"),
+    (918477, "Idea"),
+    (918478, "Idea"),
+    (918479, "Idea"),
+    (918480, "BSNL MOBILE"),
+    (918481, "Idea"),
+    (918483, "Telewings"),
+    (918484, "Telewings"),
+    (918485, "Telewings"),
+    (918486, "Vodafone"),
+    (918487, "Telewings"),
+    (918488, "Telewings"),
+    (918489, "Vodafone"),
+    (918490, "Telewings"),
+    (918491, "Airtel"),
+    (918492, "Airtel"),
+    (918493, "Airtel"),
+    (918500, "BSNL MOBILE"),
+    (918507, "Aircel"),
+    (918508, "Aircel"),
+    (918509, "Reliance Jio"),
+    (918510, "Idea"),
+    (918511, "Airtel"),
+    (918512, "Idea"),
+    (918513, "Idea"),
+    (918514, "Idea"),
+    (918515, "Idea"),
+    (918516, "Vodafone"),
+    (918517, "Vodafone"),
+    (918518, "Vodafone"),
+    (918520, "Telewings"),
+    (918521, "Airtel"),
+    (918522, "Telewings"),
+    (918523, "Telewings"),
+    (918524, "Vodafone"),
+    (918525, "Vodafone"),
+    (918526, "Aircel"),
+    (918527, "Airtel"),
+    (918532, "Telewings"),
+    (918533, "Telewings"),
+    (918534, "Telewings"),
+    (918535, "Idea"),
+    (918536, "Idea"),
+    (918537, "Idea"),
+    (918539, "Telewings"),
+    (918540, "Telewings"),
+    (918541, "Telewings"),
+    (918542, "Telewings"),
+    (918544, "BSNL MOBILE"),
+    (918545, "Telewings"),
+    (918546, "Idea"),
+    (918547, "BSNL MOBILE"),
+    (918548, "Idea"),
+    (918549, "Idea"),
+    (918551, "Vodafone"),
+    (918552, "Vodafone"),
+    (918553, "Aircel"),
+    (918554, "Vodafone"),
+    (918556, "Vodafone"),
+    (918557, "Vodafone"),
+    (918558, "Vodafone"),
+    (918560, "Aircel"),
+    (918561, "Aircel"),
+    (918562, "Aircel"),
+    (918563, "Aircel"),
+    (918564, "Aircel"),
+    (918565, "Aircel"),
+    (918566, "Aircel"),
+    (918567, "Aircel"),
+    (918568, "Aircel"),
+    (918570, "Airtel"),
+    (918571, "Airtel"),
+    (918573, "Idea"),
+    (918574, "Aircel"),
+    (918575, "Aircel"),
+    (918576, "Idea"),
+    (918577, "Idea"),
+    (918578, "Idea"),
+    (918579, "Idea"),
+    (918580, "BSNL MOBILE"),
+    (918581, "Idea"),
+    (918583, "Airtel"),
+    (918584, "Airtel"),
+    (918586, "Vodafone"),
+    (918587, "Vodafone"),
+    (918588, "Vodafone"),
+    (918589, "Vodafone"),
+    (918590, "Reliance Jio"),
+    (918591, "Reliance Jio"),
+    (918592, "Vodafone"),
+    (918593, "Vodafone"),
+    (918594, "Vodafone"),
+    (918595, "Reliance Jio"),
+    (918596, "Vodafone"),
+    (918597, "Reliance Jio"),
+    (918598, "Vodafone"),
+    (918600, "Airtel"),
+    (918601, "Vodafone"),
+    (918602, "Tata Docomo"),
+    (918603, "Tata Docomo"),
+    (918604, "Tata Docomo"),
+    (918605, "Idea"),
+    (918606, "Idea"),
+    (918607, "Idea"),
+    (918608, "Idea"),
+    (918609, "Idea"),
+    (918610, "Reliance Jio"),
+    (918618, "Reliance Jio"),
+    (918619, "Reliance Jio"),
+    (918620, "Aircel"),
+    (918621, "Aircel"),
+    (918622, "Aircel"),
+    (918623, "Telewings"),
+    (918624, "Telewings"),
+    (918625, "Telewings"),
+    (918626, "Airtel"),
+    (918627, "Airtel"),
+    (918628, "Airtel"),
+    (918630, "Reliance Jio"),
+    (918638, "Reliance Jio"),
+    (918639, "Reliance Jio"),
+    (918650, "Vodafone"),
+    (918651, "Idea"),
+    (918652, "Idea"),
+    (918653, "Tata Docomo"),
+    (918654, "Aircel"),
+    (918655, "Tata Docomo"),
+    (918656, "Aircel"),
+    (918657, "Vodafone"),
+    (918658, "Airtel"),
+    (918659, "Aircel"),
+    (918660, "Reliance Jio"),
+    (918668, "Reliance Jio"),
+    (918669, "Idea"),
+    (918670, "Airtel"),
+    (918671, "Aircel"),
+    (918672, "Aircel"),
+    (918673, "Aircel"),
+    (918674, "Aircel"),
+    (918675, "Aircel"),
+    (918676, "Vodafone"),
+    (918677, "Vodafone"),
+    (918679, "Aircel"),
+    (918686, "Aircel"),
+    (918687, "Reliance Jio"),
+    (918688, "Reliance Jio"),
+    (918690, "Airtel"),
+    (918691, "Idea"),
+    (918692, "Idea"),
+    (918695, "Vodafone"),
+    (918696, "Vodafone"),
+    (918697, "Vodafone"),
+    (918698, "Vodafone"),
+    (918699, "Tata Docomo"),
+    (918700, "Reliance Jio"),
+    (918708, "Reliance Jio"),
+    (918709, "Reliance Jio"),
+    (918712, "Tata Docomo"),
+    (918713, "Vodafone"),
+    (918714, "Tata Docomo"),
+    (918715, "Vodafone"),
+    (918716, "Vodafone"),
+    (918718, "Idea"),
+    (918719, "Idea"),
+    (918721, "Vodafone"),
+    (918722, "Idea"),
+    (918723, "Vodafone"),
+    (918724, "Vodafone"),
+    (918725, "Idea"),
+    (918726, "Idea"),
+    (918727, "Idea"),
+    (918728, "Idea"),
+    (918733, "Telewings"),
+    (918734, "Telewings"),
+    (918735, "Telewings"),
+    (918736, "Telewings"),
+    (918737, "Telewings"),
+    (918738, "Telewings"),
+    (918739, "Idea"),
+    (918740, "Idea"),
+    (918741, "Idea"),
+    (918742, "Idea"),
+    (918743, "Idea"),
+    (918744, "Idea"),
+    (918745, "Idea"),
+    (918746, "Idea"),
+    (918747, "Idea"),
+    (918748, "Idea"),
+    (918750, "Idea"),
+    (918751, "Aircel"),
+    (918752, "Aircel"),
+    (918754, "Airtel"),
+    (918755, "Airtel"),
+    (918756, "Airtel"),
+    (918757, "Airtel"),
+    (918758, "Vodafone"),
+    (918759, "Aircel"),
+    (918760, "Aircel"),
+    (918761, "Airtel"),
+    (918762, "BSNL MOBILE"),
+    (918763, "BSNL MOBILE"),
+    (918764, "BSNL MOBILE"),
+    (918765, "BSNL MOBILE"),
+    (918766, "Reliance Jio"),
+    (918767, "Reliance Jio"),
+    (918768, "Vodafone"),
+    (918769, "Airtel"),
+    (918770, "Reliance Jio"),
+    (918778, "Reliance Jio"),
+    (918779, "Reliance Jio"),
+    (918780, "Reliance Jio"),
+    (918788, "Reliance Jio"),
+    (918789, "Reliance Jio"),
+    (918790, "Airtel"),
+    (918791, "Tata Docomo"),
+    (918792, "Tata Docomo"),
+    (918793, "Tata Docomo"),
+    (918794, "Vodafone"),
+    (918795, "Vodafone"),
+    (918796, "Aircel"),
+    (918797, "Tata Docomo"),
+    (918798, "Reliance Jio"),
+    (918799, "Reliance Jio"),
+    (918800, "Airtel"),
+    (918801, "Aircel"),
+    (918802, "Aircel"),
+    (918803, "Aircel"),
+    (918804, "Aircel"),
+    (918805, "Idea"),
+    (918806, "Vodafone"),
+    (918807, "Tata Docomo"),
+    (918808, "Idea"),
+    (918809, "Airtel"),
+    (918810, "Reliance Jio"),
+    (918811, "Airtel"),
+    (918812, "Airtel"),
+    (918813, "Vodafone"),
+    (918814, "Vodafone"),
+    (918815, "Reliance Jio"),
+    (918816, "Vodafone"),
+    (918817, "Reliance Jio"),
+    (918818, "Vodafone"),
+    (918819, "Vodafone"),
+    (918820, "Reliance Jio"),
+    (918821, "Vodafone"),
+    (918822, "Reliance Jio"),
+    (918824, "Reliance Jio"),
+    (918825, "Reliance Jio"),
+    (918826, "Airtel"),
+    (918827, "Airtel"),
+    (918829, "Tata Docomo"),
+    (918830, "Reliance Jio"),
+    (918838, "Reliance Jio"),
+    (918839, "Reliance Jio"),
+    (918840, "Reliance Jio"),
+    (918848, "Reliance Jio"),
+    (918849, "Reliance Jio"),
+    (918850, "Reliance Jio"),
+    (918851, "Reliance Jio"),
+    (918852, "Tata Docomo"),
+    (918853, "Airtel"),
+    (918854, "Tata Docomo"),
+    (918855, "Telewings"),
+    (918856, "Telewings"),
+    (918857, "Telewings"),
+    (918858, "Telewings"),
+    (918859, "Vodafone"),
+    (918860, "Vodafone"),
+    (918861, "Airtel"),
+    (918866, "Tata Docomo"),
+    (918867, "Tata Docomo"),
+    (918870, "Airtel"),
+    (918871, "Tata Docomo"),
+    (918872, "Idea"),
+    (918873, "Idea"),
+    (918880, "Reliance Jio"),
+    (918881, "Vodafone"),
+    (918882, "Reliance Jio"),
+    (918883, "Aircel"),
+    (918884, "Vodafone"),
+    (918885, "Tata Docomo"),
+    (918886, "Vodafone"),
+    (918888, "Idea"),
+    (918889, "Idea"),
+    (918890, "Airtel"),
+    (918891, "Tata Docomo"),
+    (918892, "Aircel"),
+    (918893, "Reliance Jio"),
+    (918894, "Airtel"),
+    (918895, "BSNL MOBILE"),
+    (918896, "Aircel"),
+    (918897, "Airtel"),
+    (918898, "Aircel"),
+    (918899, "Airtel"),
+    (918900, "BSNL MOBILE"),
+    (918901, "BSNL MOBILE"),
+    (918902, "BSNL MOBILE"),
+    (918903, "BSNL MOBILE"),
+    (918904, "Tata Docomo"),
+    (918905, "Airtel"),
+    (918906, "Aircel"),
+    (918907, "Aircel"),
+    (918908, "Aircel"),
+    (918909, "Aircel"),
+    (918910, "Reliance Jio"),
+    (918912, "Airtel"),
+    (918918, "Reliance Jio"),
+    (918919, "Reliance Jio"),
+    (918920, "Reliance Jio"),
+    (918921, "Reliance Jio"),
+    (918922, "Telewings"),
+    (918923, "Telewings"),
+    (918924, "Telewings"),
+    (918927, "Reliance Jio"),
+    (918928, "Reliance Jio"),
+    (918929, "Vodafone"),
+    (918930, "Vodafone"),
+    (918931, "Telewings"),
+    (918932, "Idea"),
+    (918933, "Idea"),
+    (918934, "Idea"),
+    (918937, "Idea"),
+    (918939, "Vodafone"),
+    (918940, "Vodafone"),
+    (918941, "Idea"),
+    (918942, "Airtel"),
+    (918943, "Vodafone"),
+    (918944, "Airtel"),
+    (918945, "Airtel"),
+    (918946, "Aircel"),
+    (918947, "Aircel"),
+    (918948, "Vodafone"),
+    (918949, "Reliance Jio"),
+    (918950, "Tata Docomo"),
+    (918951, "Tata Docomo"),
+    (918952, "Aircel"),
+    (918953, "Airtel"),
+    (918954, "Vodafone"),
+    (918955, "Reliance Jio"),
+    (918956, "Airtel"),
+    (918957, "Reliance Jio"),
+    (918958, "Idea"),
+    (918959, "Idea"),
+    (918960, "Tata Docomo"),
+    (918961, "Tata Docomo"),
+    (918962, "Tata Docomo"),
+    (918964, "Idea"),
+    (918965, "Idea"),
+    (918966, "Idea"),
+    (918967, "Airtel"),
+    (918968, "Airtel"),
+    (918969, "Airtel"),
+    (918970, "Idea"),
+    (918971, "Airtel"),
+    (918972, "Airtel"),
+    (918973, "Aircel"),
+    (918974, "Airtel"),
+    (918975, "Idea"),
+    (918976, "Tata Docomo"),
+    (918977, "Tata Docomo"),
+    (918978, "Airtel"),
+    (918979, "Airtel"),
+    (918980, "Vodafone"),
+    (918981, "Tata Docomo"),
+    (918982, "Tata Docomo"),
+    (918983, "Tata Docomo"),
+    (918984, "Tata Docomo"),
+    (918985, "BSNL MOBILE"),
+    (918986, "BSNL MOBILE"),
+    (918987, "BSNL MOBILE"),
+    (918988, "BSNL MOBILE"),
+    (918989, "BSNL MOBILE"),
+    (918990, "Reliance Jio"),
+    (918997, "Airtel"),
+    (918998, "Airtel"),
+    (918999, "Reliance Jio"),
+    (919009, "Idea"),
+    (919010, "Idea"),
+    (919011, "Idea"),
+    (919012, "Idea"),
+    (919013, "MTNL"),
+    (919028, "Tata Docomo"),
+    (919029, "Tata Docomo"),
+    (919047, "Vodafone"),
+    (919048, "Vodafone"),
+    (919049, "Vodafone"),
+    (919050, "Vodafone"),
+    (919051, "Vodafone"),
+    (919052, "Vodafone"),
+    (919053, "Idea"),
+    (919055, "Idea"),
+    (919056, "Tata Docomo"),
+    (919057, "Idea"),
+    (919058, "Telewings"),
+    (919059, "Telewings"),
+    (919060, "Telenor"),
+    (919061, "Idea"),
+    (919062, "Idea"),
+    (919063, "Telewings"),
+    (919064, "Reliance Jio"),
+    (919065, "Airtel"),
+    (919066, "Aircel"),
+    (919067, "Idea"),
+    (919068, "Airtel"),
+    (919069, "Aircel"),
+    (919070, "Vodafone"),
+    (919071, "Idea"),
+    (919072, "Vodafone"),
+    (919073, "Vodafone"),
+    (919074, "Reliance Jio"),
+    (919075, "Idea"),
+    (919077, "Idea"),
+    (919078, "Airtel"),
+    (919079, "Reliance Jio"),
+    (919080, "Reliance Jio"),
+    (919082, "Reliance Jio"),
+    (919083, "Vodafone"),
+    (919084, "Telewings"),
+    (919090, "Idea"),
+    (919091, "Idea"),
+    (919092, "Idea"),
+    (919093, "Idea"),
+    (919094, "Aircel"),
+    (919095, "Aircel"),
+    (919096, "Airtel"),
+    (919097, "Aircel"),
+    (919098, "Reliance Jio"),
+    (919099, "Vodafone"),
+    (919100, "Airtel"),
+    (919101, "Reliance Jio"),
+    (919102, "Airtel"),
+    (919103, "Reliance Jio"),
+    (919104, "Telewings"),
+    (919105, "Idea"),
+    (919106, "Reliance Jio"),
+    (919107, "Aircel"),
+    (919108, "Airtel"),
+    (919109, "Airtel"),
+    (919110, "Reliance Jio"),
+    (919111, "Idea"),
+    (919112, "Idea"),
+    (919113, "Reliance Jio"),
+    (919114, "Idea"),
+    (919116, "Airtel"),
+    (919117, "Idea"),
+    (919118, "Telewings"),
+    (919119, "Airtel"),
+    (919120, "Telewings"),
+    (919121, "Airtel"),
+    (919122, "Telewings"),
+    (919123, "Reliance Jio"),
+    (919124, "Tata Docomo"),
+    (919125, "Telewings"),
+    (919126, "Idea"),
+    (919127, "Vodafone"),
+    (919128, "Idea"),
+    (919129, "S TEL"),
+    (919130, "Idea"),
+    (919131, "Reliance Jio"),
+    (919132, "Aircel"),
+    (919133, "Idea"),
+    (919134, "Idea"),
+    (919135, "Idea"),
+    (919136, "Airtel"),
+    (919137, "Reliance Jio"),
+    (919138, "Tata Docomo"),
+    (919139, "Aircel"),
+    (919140, "Reliance Jio"),
+    (919141, "BSNL MOBILE"),
+    (919144, "Idea"),
+    (919145, "Vodafone"),
+    (919146, "Idea"),
+    (919147, "Tata Docomo"),
+    (919148, "Airtel"),
+    (919149, "Reliance Jio"),
+    (919150, "Airtel"),
+    (919151, "Tata Docomo"),
+    (919154, "Telewings"),
+    (919155, "Telewings"),
+    (919156, "Telewings"),
+    (919157, "Telewings"),
+    (919158, "Vodafone"),
+    (919159, "Vodafone"),
+    (919160, "Vodafone"),
+    (919161, "Vodafone"),
+    (919162, "Airtel"),
+    (919163, "Airtel"),
+    (919164, "Idea"),
+    (919165, "Idea"),
+    (919166, "Airtel"),
+    (919167, "Vodafone"),
+    (919168, "Vodafone"),
+    (919169, "Aircel"),
+    (919170, "Telewings"),
+    (919171, "Idea"),
+    (919172, "Airtel"),
+    (919173, "Telewings"),
+    (919174, "Idea"),
+    (919175, "Telewings"),
+    (919176, "Vodafone"),
+    (919177, "Airtel"),
+    (919178, "Airtel"),
+    (919179, "Airtel"),
+    (919182, "Reliance Jio"),
+    (919190, "Reliance Jio"),
+    (919198, "Airtel"),
+    (919199, "Airtel"),
+    (919205, "Airtel"),
+    (919206, "Aircel"),
+    (919207, "Idea"),
+    (919262, "Airtel"),
+    (919285, "Idea"),
+    (919319, "Airtel"),
+    (919341, "Airtel"),
+    (919355, "Idea"),
+    (919358, "Airtel"),
+    (919371, "Idea"),
+    (919374, "Vodafone"),
+    (919375, "Vodafone"),
+    (919376, "Vodafone"),
+    (919384, "Airtel"),
+    (919385, "BSNL MOBILE"),
+    (919500, "Airtel"),
+    (919501, "Airtel"),
+    (919502, "Airtel"),
+    (919503, "Airtel"),
+    (919504, "Aircel"),
+    (919505, "Idea"),
+    (919506, "Idea"),
+    (919507, "Idea"),
+    (919508, "Reliance Jio"),
+    (919509, "Reliance Jio"),
+    (919510, "Reliance Jio"),
+    (919512, "Vodafone"),
+    (919513, "Vodafone"),
+    (919514, "Idea"),
+    (919515, "Airtel"),
+    (919516, "Idea"),
+    (919517, "Vodafone"),
+    (919518, "Reliance Jio"),
+    (919519, "Airtel"),
+    (919521, "Airtel"),
+    (919522, "Vodafone"),
+    (919523, "Airtel"),
+    (919524, "Aircel"),
+    (919525, "Idea"),
+    (919526, "Idea"),
+    (919527, "Idea"),
+    (919528, "Reliance Jio"),
+    (919529, "Reliance Jio"),
+    (919530, "BSNL MOBILE"),
+    (919531, "BSNL MOBILE"),
+    (919532, "BSNL MOBILE"),
+    (919533, "Reliance Jio"),
+    (919534, "Vodafone"),
+    (919535, "Airtel"),
+    (919536, "Vodafone"),
+    (919537, "Vodafone"),
+    (919538, "Vodafone"),
+    (919539, "Vodafone"),
+    (919540, "Idea"),
+    (919541, "Reliance Jio"),
+    (919542, "Idea"),
+    (919543, "Reliance Jio"),
+    (919544, "Idea"),
+    (919545, "Vodafone"),
+    (919546, "Airtel"),
+    (919547, "Airtel"),
+    (919548, "Reliance Jio"),
+    (919549, "Vodafone"),
+    (919550, "Airtel"),
+    (919551, "Aircel"),
+    (919552, "Idea"),
+    (919553, "Idea"),
+    (919554, "Vodafone"),
+    (919555, "Reliance Jio"),
+    (919556, "Airtel"),
+    (919557, "Airtel"),
+    (919558, "Airtel"),
+    (919559, "Airtel"),
+    (919560, "Airtel"),
+    (919561, "Airtel"),
+    (919562, "Idea"),
+    (919563, "Aircel"),
+    (919564, "Vodafone"),
+    (919565, "Vodafone"),
+    (919566, "Airtel"),
+    (919567, "Airtel"),
+    (919568, "Idea"),
+    (919569, "Reliance Jio"),
+    (919570, "Vodafone"),
+    (919571, "Airtel"),
+    (919572, "Airtel"),
+    (919573, "Airtel"),
+    (919574, "Idea"),
+    (919575, "Idea"),
+    (919576, "Idea"),
+    (919577, "Aircel"),
+    (919578, "Aircel"),
+    (919579, "Reliance Jio"),
+    (919580, "Reliance Jio"),
+    (919588, "Reliance Jio"),
+    (919589, "Airtel"),
+    (919590, "Reliance Jio"),
+    (919591, "Airtel"),
+    (919592, "Idea"),
+    (919593, "Vodafone"),
+    (919594, "Idea"),
+    (919595, "Reliance Jio"),
+    (919596, "Airtel"),
+    (919597, "Airtel"),
+    (919598, "Idea"),
+    (919599, "Airtel"),
+    (919600, "Airtel"),
+    (919601, "Airtel"),
+    (919602, "Airtel"),
+    (919603, "Idea"),
+    (919604, "Idea"),
+    (919605, "Idea"),
+    (919606, "Airtel"),
+    (919607, "Reliance Jio"),
+    (919608, "Reliance Jio"),
+    (919609, "Vodafone"),
+    (919610, "Vodafone"),
+    (919611, "Airtel"),
+    (919612, "Airtel"),
+    (919613, "Aircel"),
+    (919614, "Aircel"),
+    (919615, "Aircel"),
+    (919616, "Idea"),
+    (919617, "Idea"),
+    (919618, "Airtel"),
+    (919619, "Vodafone"),
+    (919620, "Vodafone"),
+    (919621, "Airtel"),
+    (919622, "Airtel"),
+    (919623, "Idea"),
+    (919624, "Idea"),
+    (919625, "Reliance Jio"),
+    (919626, "Vodafone"),
+    (919627, "Vodafone"),
+    (919628, "Vodafone"),
+    (919629, "Airtel"),
+    (919630, "Airtel"),
+    (919631, "Airtel"),
+    (919632, "Airtel"),
+    (919633, "Airtel"),
+    (919634, "Airtel"),
+    (919635, "Airtel"),
+    (919636, "Airtel"),
+    (919637, "Vodafone"),
+    (919639, "Idea"),
+    (919640, "Idea"),
+    (919641, "Reliance Jio"),
+    (919642, "Vodafone"),
+    (919644, "Idea"),
+    (919645, "Vodafone"),
+    (919646, "Vodafone"),
+    (919647, "Vodafone"),
+    (919648, "Vodafone"),
+    (919649, "Vodafone"),
+    (919650, "Airtel"),
+    (919651, "Airtel"),
+    (919652, "Airtel"),
+    (919653, "Reliance Jio"),
+    (919654, "Vodafone"),
+    (919655, "Vodafone"),
+    (919656, "Idea"),
+    (919657, "Idea"),
+    (919658, "Aircel"),
+    (919659, "Aircel"),
+    (919664, "Reliance Jio"),
+    (919666, "Idea"),
+    (919669, "Idea"),
+    (919676, "Airtel"),
+    (919677, "Airtel"),
+    (919678, "Airtel"),
+    (919679, "Airtel"),
+    (919680, "Airtel"),
+    (919681, "Reliance Jio"),
+    (919682, "Idea"),
+    (919683, "BSNL MOBILE"),
+    (919684, "BSNL MOBILE"),
+    (919685, "Airtel"),
+    (919686, "Airtel"),
+    (919687, "Vodafone"),
+    (919688, "Aircel"),
+    (919689, "Idea"),
+    (919690, "Idea"),
+    (919691, "Reliance Jio"),
+    (919692, "Reliance Jio"),
+    (919693, "Reliance Jio"),
+    (919694, "Idea"),
+    (919695, "Airtel"),
+    (919696, "Reliance Jio"),
+    (919697, "Aircel"),
+    (919698, "Aircel"),
+    (919699, "Reliance Jio"),
+    (919700, "Aircel"),
+    (919701, "Airtel"),
+    (919702, "Idea"),
+    (919703, "Vodafone"),
+    (919704, "Airtel"),
+    (919705, "Idea"),
+    (919706, "Vodafone"),
+    (919707, "Reliance Jio"),
+    (919708, "Idea"),
+    (919709, "Vodafone"),
+    (919710, "Aircel"),
+    (919711, "Vodafone"),
+    (919712, "Vodafone"),
+    (919713, "Vodafone"),
+    (919714, "Idea"),
+    (919715, "Aircel"),
+    (919716, "Aircel"),
+    (919717, "Airtel"),
+    (919718, "Idea"),
+    (919719, "Vodafone"),
+    (919720, "Vodafone"),
+    (919721, "Vodafone"),
+    (919722, "Aircel"),
+    (919723, "Idea"),
+    (919724, "Airtel"),
+    (919725, "Airtel"),
+    (919726, "Vodafone"),
+    (919727, "Vodafone"),
+    (919728, "Idea"),
+    (919729, "Airtel"),
+    (919730, "Airtel"),
+    (919731, "Airtel"),
+    (919737, "Idea"),
+    (919738, "Aircel"),
+    (919740, "Airtel"),
+    (919741, "Airtel"),
+    (919742, "Vodafone"),
+    (919743, "Idea"),
+    (919744, "Idea"),
+    (919745, "Vodafone"),
+    (919746, "Airtel"),
+    (919747, "Idea"),
+    (919748, "Airtel"),
+    (919749, "Reliance Jio"),
+    (919750, "Aircel"),
+    (919751, "Vodafone"),
+    (919752, "Airtel"),
+    (919753, "Idea"),
+    (919754, "Idea"),
+    (919755, "Airtel"),
+    (919756, "Idea"),
+    (919757, "MTNL"),
+    (919758, "Vodafone"),
+    (919759, "Vodafone"),
+    (919760, "Airtel"),
+    (919761, "Vodafone"),
+    (919762, "Aircel"),
+    (919763, "Idea"),
+    (919764, "Vodafone"),
+    (919765, "Vodafone"),
+    (919766, "Airtel"),
+    (919767, "Idea"),
+    (919768, "Aircel"),
+    (919769, "Vodafone"),
+    (919770, "Reliance Jio"),
+    (919771, "Airtel"),
+    (919772, "Vodafone"),
+    (919773, "Airtel"),
+    (919774, "Vodafone"),
+    (919775, "Vodafone"),
+    (919776, "Vodafone"),
+    (919777, "Airtel"),
+    (919778, "Reliance Jio"),
+    (919779, "Airtel"),
+    (919780, "Vodafone"),
+    (919781, "Idea"),
+    (919782, "Aircel"),
+    (919783, "Vodafone"),
+    (919784, "Airtel"),
+    (919785, "Idea"),
+    (919786, "Vodafone"),
+    (919787, "Vodafone"),
+    (919788, "Aircel"),
+    (919789, "Airtel"),
+    (919792, "Vodafone"),
+    (919795, "Idea"),
+    (919796, "Vodafone"),
+    (919798, "Reliance Jio"),
+    (919800, "Airtel"),
+    (919801, "Airtel"),
+    (919805, "Airtel"),
+    (919810, "Airtel"),
+    (919811, "Vodafone"),
+    (919812, "Idea"),
+    (919813, "Vodafone"),
+    (919814, "Idea"),
+    (919815, "Airtel"),
+    (919816, "Airtel"),
+    (919817, "Reliance Jio"),
+    (919818, "Airtel"),
+    (919819, "Vodafone"),
+    (919820, "Vodafone"),
+    (919821, "Airtel"),
+    (919822, "Idea"),
+    (919823, "Vodafone"),
+    (919824, "Idea"),
+    (919825, "Vodafone"),
+    (919826, "Idea"),
+    (919827, "Reliance Jio"),
+    (919828, "Vodafone"),
+    (919829, "Airtel"),
+    (919830, "Vodafone"),
+    (919831, "Airtel"),
+    (919832, "Reliance Jio"),
+    (919833, "Vodafone"),
+    (919834, "Reliance Jio"),
+    (919835, "Reliance Jio"),
+    (919836, "Vodafone"),
+    (919837, "Idea"),
+    (919838, "Vodafone"),
+    (919839, "Vodafone"),
+    (919840, "Airtel"),
+    (919841, "Aircel"),
+    (919842, "Aircel"),
+    (919843, "Vodafone"),
+    (919844, "Idea"),
+    (919845, "Airtel"),
+    (919846, "Vodafone"),
+    (919847, "Idea"),
+    (919848, "Idea"),
+    (919849, "Airtel"),
+    (919850, "Idea"),
+    (919855, "Idea"),
+    (919860, "Airtel"),
+    (919861, "Reliance Jio"),
+    (919862, "Airtel"),
+    (919863, "Reliance Jio"),
+    (919864, "Reliance Jio"),
+    (919865, "Aircel"),
+    (919866, "Airtel"),
+    (919867, "Airtel"),
+    (919868, "MTNL"),
+    (919869, "MTNL"),
+    (919871, "Airtel"),
+    (919872, "Airtel"),
+    (919873, "Vodafone"),
+    (919874, "Vodafone"),
+    (919876, "Airtel"),
+    (919877, "Reliance Jio"),
+    (919878, "Airtel"),
+    (919879, "Vodafone"),
+    (919880, "Airtel"),
+    (919881, "Idea"),
+    (919882, "Idea"),
+    (919883, "Reliance Jio"),
+    (919884, "Vodafone"),
+    (919885, "Vodafone"),
+    (919886, "Vodafone"),
+    (919887, "Idea"),
+    (919888, "Vodafone"),
+    (919889, "Idea"),
+    (919891, "Idea"),
+    (919899, "Vodafone"),
+    (919904, "Idea"),
+    (919905, "Reliance Jio"),
+    (919907, "Reliance Jio"),
+    (919909, "Vodafone"),
+    (919910, "Airtel"),
+    (919911, "Idea"),
+    (919912, "Idea"),
+    (919913, "Vodafone"),
+    (919914, "Idea"),
+    (919915, "Airtel"),
+    (919916, "Vodafone"),
+    (919917, "Idea"),
+    (919918, "Vodafone"),
+    (919919, "Vodafone"),
+    (919920, "Vodafone"),
+    (919921, "Idea"),
+    (919922, "Idea"),
+    (919923, "Vodafone"),
+    (919924, "Idea"),
+    (919925, "Vodafone"),
+    (919926, "Idea"),
+    (919927, "Idea"),
+    (919928, "Airtel"),
+    (919929, "Airtel"),
+    (919930, "Vodafone"),
+    (919940, "Airtel"),
+    (919941, "Aircel"),
+    (919942, "Aircel"),
+    (919943, "Vodafone"),
+    (919944, "Airtel"),
+    (919945, "Airtel"),
+    (919946, "Vodafone"),
+    (919947, "Idea"),
+    (919948, "Idea"),
+    (919949, "Airtel"),
+    (919951, "Idea"),
+    (919953, "Vodafone"),
+    (919960, "Airtel"),
+    (919961, "Idea"),
+    (919962, "Vodafone"),
+    (919963, "Airtel"),
+    (919964, "Idea"),
+    (919965, "Aircel"),
+    (919966, "Vodafone"),
+    (919967, "Airtel"),
+    (919968, "MTNL"),
+    (919969, "MTNL"),
+    (919976, "Aircel"),
+    (919977, "Idea"),
+    (919978, "Vodafone"),
+    (919979, "Vodafone"),
+    (919980, "Airtel"),
+    (919981, "Airtel"),
+    (919987, "Airtel"),
+    (919989, "Airtel"),
+    (919990, "Idea"),
+    (919991, "Vodafone"),
+    (919992, "Idea"),
+    (919999, "Vodafone"),
+    (961700, "Touch"),
+    (961701, "Alfa"),
+    (961702, "Alfa"),
+    (961703, "Alfa"),
+    (961704, "Alfa"),
+    (961705, "Alfa"),
+    (961706, "Touch"),
+    (961707, "Touch"),
+    (961708, "Touch"),
+    (961709, "Touch"),
+    (961710, "Alfa"),
+    (961711, "Touch"),
+    (961712, "Touch"),
+    (961713, "Touch"),
+    (961714, "Touch"),
+    (961715, "Touch"),
+    (961716, "Alfa"),
+    (961717, "Alfa"),
+    (961718, "Alfa"),
+    (961719, "Alfa"),
+    (961760, "Touch"),
+    (961761, "Alfa"),
+    (961763, "Alfa"),
+    (961764, "Alfa"),
+    (961765, "Alfa"),
+    (961766, "Touch"),
+    (961767, "Touch"),
+    (961768, "Touch"),
+    (961769, "Touch"),
+    (961787, "Touch"),
+    (961788, "Touch"),
+    (961789, "Touch"),
+    (961790, "Touch"),
+    (961791, "Alfa"),
+    (961793, "Alfa"),
+    (961794, "Alfa"),
+    (961810, "Touch"),
+    (961811, "Alfa"),
+    (961812, "Alfa"),
+    (961813, "Alfa"),
+    (961814, "Alfa"),
+    (961815, "Alfa"),
+    (961816, "Touch"),
+    (961817, "Touch"),
+    (961818, "Touch"),
+    (961819, "Touch"),
+    (966510, "Salam"),
+    (966511, "Salam"),
+    (966570, "Virgin"),
+    (966571, "Virgin"),
+    (966572, "Virgin"),
+    (966574, "Red Bull Mobile"),
+    (966575, "Red Bull Mobile"),
+    (966576, "Lebara"),
+    (966577, "Lebara"),
+    (966578, "Lebara"),
+    (966579, "Lebara"),
+    (968790, "Ooredoo"),
+    (968791, "Ooredoo"),
+    (968792, "Ooredoo"),
+    (968793, "Ooredoo"),
+    (968794, "Ooredoo"),
+    (968795, "Ooredoo"),
+    (968796, "Ooredoo"),
+    (968901, "Omantel"),
+    (968902, "Omantel"),
+    (968903, "Omantel"),
+    (968904, "Omantel"),
+    (968905, "Omantel"),
+    (968906, "Omantel"),
+    (968907, "Omantel"),
+    (968908, "Omantel"),
+    (968909, "Omantel"),
+    (972510, "Wecom"),
+    (972550, "Beezz"),
+    (972552, "019mobile"),
+    (972556, "Rami Levy"),
+    (972558, "Pelephone"),
+    (972559, "019mobile"),
+    (973385, "Telecommunications Regulatory Authority"),
+    (973666, "zain BH"),
+    (973669, "zain BH"),
+    (974310, "Vodafone"),
+    (974311, "Vodafone"),
+    (974312, "Vodafone"),
+    (974313, "Vodafone"),
+    (974314, "Vodafone"),
+    (974315, "Vodafone"),
+    (974316, "Vodafone"),
+    (974399, "ooredoo"),
+    (974599, "ooredoo"),
+    (974600, "ooredoo"),
+    (974720, "Vodafone"),
+    (974721, "Vodafone"),
+    (974722, "Vodafone"),
+    (974723, "Vodafone"),
+    (974724, "Vodafone"),
+    (974725, "Vodafone"),
+    (974726, "Vodafone"),
+    (977960, "STM Telecom"),
+    (977961, "Smart Telecom"),
+    (977962, "Smart Telecom"),
+    (977963, "NSTPL"),
+    (977970, "NCell"),
+    (977971, "NCell"),
+    (977972, "UTL"),
+    (977974, "NDCL"),
+    (977975, "NDCL"),
+    (977976, "Nepal Telecom"),
+    (977980, "NCell"),
+    (977981, "NCell"),
+    (977982, "NCell"),
+    (977984, "Nepal Telecom"),
+    (977985, "Nepal Telecom"),
+    (977986, "Nepal Telecom"),
+    (977988, "Smart Telecom"),
+    (989955, "Irancell"),
+    (989981, "Shatel Mobile"),
+    (989982, "Shatel Mobile"),
+    (989990, "IR-MCI"),
+    (989991, "Irancell"),
+    (989995, "IR-MCI"),
+    (989996, "IR-MCI"),
+    (989998, "Rightel"),
+    (992330, "ZET-MOBILE"),
+    (992333, "ZET-MOBILE"),
+    (992334, "ZET-MOBILE"),
+    (992335, "ZET-MOBILE"),
+    (992336, "ZET-MOBILE"),
+    (992337, "ZET-MOBILE"),
+    (992338, "ZET-MOBILE"),
+    (992339, "ZET-MOBILE"),
+    (992440, "ZET-MOBILE"),
+    (992442, "ZET-MOBILE"),
+    (992443, "ZET-MOBILE"),
+    (992444, "ZET-MOBILE"),
+    (992447, "ZET-MOBILE"),
+    (992449, "ZET-MOBILE"),
+    (995501, "Cellfie"),
+    (995502, "Silknet"),
+    (995504, "Cellfie"),
+    (995507, "Globalcell"),
+    (995510, "Silknet"),
+    (995514, "Silknet"),
+    (995515, "MagtiCom"),
+    (995517, "MagtiCom"),
+    (995520, "Silknet"),
+    (995525, "Globalcell"),
+    (995530, "Silknet"),
+    (995535, "Globalcell"),
+    (995540, "Silknet"),
+    (995545, "Globalcell"),
+    (995550, "MagtiCom"),
+    (995551, "MagtiCom"),
+    (995553, "Silknet"),
+    (995554, "Silknet"),
+    (995555, "Silknet"),
+    (995557, "Silknet"),
+    (995558, "Silknet"),
+    (995559, "Globalcell"),
+    (995570, "Silknet"),
+    (995571, "Cellfie"),
+    (995572, "Silknet"),
+    (995574, "Cellfie"),
+    (995577, "Silknet"),
+    (995579, "Cellfie"),
+    (995580, "Silknet"),
+    (995588, "Silknet"),
+    (995590, "Silknet"),
+    (995591, "MagtiCom"),
+    (995592, "Cellfie"),
+    (995593, "Silknet"),
+    (995595, "MagtiCom"),
+    (995596, "MagtiCom"),
+    (995597, "Cellfie"),
+    (995598, "MagtiCom"),
+    (995599, "MagtiCom"),
+    (996600, "Sky mobile"),
+    (998617, "MobiUZ"),
+    (998621, "MobiUZ"),
+    (998625, "MobiUZ"),
+    (998627, "MobiUZ"),
+    (998651, "MobiUZ"),
+    (998657, "MobiUZ"),
+    (998675, "MobiUZ"),
+    (998679, "MobiUZ"),
+    (998727, "MobiUZ"),
+    (998737, "MobiUZ"),
+    (998739, "MobiUZ"),
+    (998745, "MobiUZ"),
+    (998749, "MobiUZ"),
+    (998751, "MobiUZ"),
+    (998753, "MobiUZ"),
+    (998757, "MobiUZ"),
+    (998793, "MobiUZ"),
+    (998797, "MobiUZ"),
+    (1242357, "BaTelCo"),
+    (1242359, "BaTelCo"),
+    (1242375, "BaTelCo"),
+    (1242376, "BaTelCo"),
+    (1242395, "BaTelCo"),
+    (1242462, "BaTelCo"),
+    (1242463, "BaTelCo"),
+    (1242464, "BaTelCo"),
+    (1242465, "BaTelCo"),
+    (1242466, "BaTelCo"),
+    (1242467, "BaTelCo"),
+    (1242468, "BaTelCo"),
+    (1242646, "BaTelCo"),
+    (1246256, "Digicel"),
+    (1246257, "Digicel"),
+    (1246258, "Digicel"),
+    (1246259, "Digicel"),
+    (1246360, "Cable & Wireless"),
+    (1246361, "Cable & Wireless"),
+    (1246362, "Cable & Wireless"),
+    (1246363, "Cable & Wireless"),
+    (1246364, "Cable & Wireless"),
+    (1246365, "Cable & Wireless"),
+    (1246366, "Cable & Wireless"),
+    (1246446, "Neptune Communications"),
+    (1246522, "Ozone"),
+    (1264469, "Cable & Wireless"),
+    (1264729, "Cable & Wireless"),
+    (1268720, "Digicel"),
+    (1268721, "Digicel"),
+    (1268722, "Digicel"),
+    (1268724, "Digicel"),
+    (1268725, "Digicel"),
+    (1268726, "Digicel"),
+    (1268727, "APUA"),
+    (1268729, "APUA"),
+    (1268730, "APUA"),
+    (1268732, "Digicel"),
+    (1268734, "Digicel"),
+    (1268736, "Digicel"),
+    (1268773, "APUA"),
+    (1268774, "APUA"),
+    (1268775, "APUA"),
+    (1268780, "APUA"),
+    (1268781, "APUA"),
+    (1268783, "Digicel"),
+    (1268785, "Digicel"),
+    (1268787, "Cable & Wireless"),
+    (1268788, "Digicel"),
+    (1284300, "Digicel"),
+    (1284496, "CCT"),
+    (1284499, "CCT"),
+    (1284546, "Cable & Wireless"),
+    (1340423, "Vitelcom Cellular"),
+    (1340725, "Vitelcom Cellular"),
+    (1345649, "Digicel"),
+    (1345919, "Cable & Wireless"),
+    (1345930, "LIME"),
+    (1345936, "Cable & Wireless"),
+    (1345937, "Cable & Wireless"),
+    (1345938, "Cable & Wireless"),
+    (1345939, "Cable & Wireless"),
+    (1473402, "Affordable Island Communications"),
+    (1473449, "C&W"),
+    (1473456, "C&W"),
+    (1658295, "Cable & Wireless"),
+    (1659200, "Onvoy"),
+    (1659222, "Onvoy"),
+    (1659300, "Onvoy"),
+    (1659400, "Onvoy"),
+    (1659444, "Onvoy"),
+    (1659500, "Onvoy"),
+    (1659529, "Fractel"),
+    (1659600, "Onvoy"),
+    (1659666, "Onvoy"),
+    (1659766, "Fractel"),
+    (1659777, "Onvoy"),
+    (1659800, "Onvoy"),
+    (1659888, "Fractel"),
+    (1659900, "Onvoy"),
+    (1659999, "Onvoy"),
+    (1670284, "PTI PACIFICA"),
+    (1671480, "GTA"),
+    (1671482, "GTA"),
+    (1671483, "GTA"),
+    (1671485, "GTA"),
+    (1671486, "GTA"),
+    (1671487, "GTA"),
+    (1671488, "GTA"),
+    (1671489, "GTA"),
+    (1671864, "GTA"),
+    (1671867, "GTA"),
+    (1671868, "Choice Phone"),
+    (1758460, "Cable & Wireless"),
+    (1758461, "Cable & Wireless"),
+    (1758484, "Cable & Wireless"),
+    (1758485, "Cable & Wireless"),
+    (1758486, "Cable & Wireless"),
+    (1758487, "Cable & Wireless"),
+    (1758488, "Cable & Wireless"),
+    (1758489, "Cable & Wireless"),
+    (1767265, "Cable & Wireless"),
+    (1767704, "Digicel"),
+    (1767705, "Digicel"),
+    (1767706, "Digicel"),
+    (1784430, "AT&T"),
+    (1784431, "AT&T"),
+    (1784432, "AT&T"),
+    (1784433, "Digicel"),
+    (1784434, "Digicel"),
+    (1784435, "Digicel"),
+    (1784454, "Cable & Wireless"),
+    (1784455, "Cable & Wireless"),
+    (1784489, "Cable & Wireless"),
+    (1784490, "Cable & Wireless"),
+    (1784491, "Cable & Wireless"),
+    (1784492, "Cable & Wireless"),
+    (1784493, "Cable & Wireless"),
+    (1784494, "Cable & Wireless"),
+    (1784495, "Cable & Wireless"),
+    (1787203, "Claro"),
+    (1787210, "SunCom Wireless Puerto Rico"),
+    (1787212, "Claro"),
+    (1787213, "Claro"),
+    (1787214, "Claro"),
+    (1787215, "Claro"),
+    (1787216, "Claro"),
+    (1787217, "Claro"),
+    (1787218, "Claro"),
+    (1787219, "Claro"),
+    (1787220, "CENTENNIAL"),
+    (1787221, "CENTENNIAL"),
+    (1787222, "CENTENNIAL"),
+    (1787223, "CENTENNIAL"),
+    (1787224, "CENTENNIAL"),
+    (1787225, "SunCom Wireless Puerto Rico"),
+    (1787226, "SunCom Wireless Puerto Rico"),
+    (1787227, "CENTENNIAL"),
+    (1787229, "CENTENNIAL"),
+    (1787253, "Claro"),
+    (1787254, "Claro"),
+    (1787255, "Claro"),
+    (1787256, "Claro"),
+    (1787257, "Claro"),
+    (1787258, "Claro"),
+    (1787259, "Claro"),
+    (1787260, "Claro"),
+    (1787291, "CENTENNIAL"),
+    (1787299, "SunCom Wireless Puerto Rico"),
+    (1787300, "CENTENNIAL"),
+    (1787310, "SunCom Wireless Puerto Rico"),
+    (1787312, "Claro"),
+    (1787313, "Claro"),
+    (1787314, "Claro"),
+    (1787315, "Claro"),
+    (1787316, "Claro"),
+    (1787317, "Claro"),
+    (1787318, "Claro"),
+    (1787341, "SunCom Wireless Puerto Rico"),
+    (1787344, "SunCom Wireless Puerto Rico"),
+    (1787346, "SunCom Wireless Puerto Rico"),
+    (1787355, "CENTENNIAL"),
+    (1787357, "CENTENNIAL"),
+    (1787359, "SunCom Wireless Puerto Rico"),
+    (1787367, "SunCom Wireless Puerto Rico"),
+    (1787368, "SunCom Wireless Puerto Rico"),
+    (1787369, "CENTENNIAL"),
+    (1787371, "Claro"),
+    (1787372, "Claro"),
+    (1787374, "Claro"),
+    (1787375, "Claro"),
+    (1787376, "Claro"),
+    (1787380, "Claro"),
+    (1787381, "Claro"),
+    (1787382, "Claro"),
+    (1787383, "Claro"),
+    (1787384, "Claro"),
+    (1787385, "Claro"),
+    (1787389, "Claro"),
+    (1787390, "Claro"),
+    (1787391, "Claro"),
+    (1787392, "Claro"),
+    (1787400, "CENTENNIAL"),
+    (1787410, "SunCom Wireless Puerto Rico"),
+    (1787434, "CENTENNIAL"),
+    (1787447, "CENTENNIAL"),
+    (1787448, "CENTENNIAL"),
+    (1787449, "CENTENNIAL"),
+    (1787450, "Claro"),
+    (1787453, "Claro"),
+    (1787454, "SunCom Wireless Puerto Rico"),
+    (1787458, "SunCom Wireless Puerto Rico"),
+    (1787459, "SunCom Wireless Puerto Rico"),
+    (1787460, "SunCom Wireless Puerto Rico"),
+    (1787462, "SunCom Wireless Puerto Rico"),
+    (1787463, "SunCom Wireless Puerto Rico"),
+    (1787465, "CENTENNIAL"),
+    (1787466, "SunCom Wireless Puerto Rico"),
+    (1787471, "CENTENNIAL"),
+    (1787473, "CENTENNIAL"),
+    (1787474, "CENTENNIAL"),
+    (1787478, "SunCom Wireless Puerto Rico"),
+    (1787479, "CENTENNIAL"),
+    (1787481, "Claro"),
+    (1787484, "Claro"),
+    (1787485, "Claro"),
+    (1787486, "Claro"),
+    (1787487, "Claro"),
+    (1787513, "SunCom Wireless Puerto Rico"),
+    (1787514, "Claro"),
+    (1787515, "Claro"),
+    (1787516, "Claro"),
+    (1787517, "Claro"),
+    (1787518, "Claro"),
+    (1787519, "Claro"),
+    (1787520, "CENTENNIAL"),
+    (1787521, "CENTENNIAL"),
+    (1787522, "CENTENNIAL"),
+    (1787523, "CENTENNIAL"),
+    (1787528, "SunCom Wireless Puerto Rico"),
+    (1787534, "CENTENNIAL"),
+    (1787535, "CENTENNIAL"),
+    (1787537, "CENTENNIAL"),
+    (1787544, "CENTENNIAL"),
+    (1787545, "CENTENNIAL"),
+    (1787546, "SunCom Wireless Puerto Rico"),
+    (1787551, "CENTENNIAL"),
+    (1787553, "Claro"),
+    (1787561, "CENTENNIAL"),
+    (1787563, "CENTENNIAL"),
+    (1787568, "SunCom Wireless Puerto Rico"),
+    (1787569, "CENTENNIAL"),
+    (1787579, "Claro"),
+    (1787580, "CENTENNIAL"),
+    (1787585, "CENTENNIAL"),
+    (1787588, "CENTENNIAL"),
+    (1787589, "CENTENNIAL"),
+    (1787595, "SunCom Wireless Puerto Rico"),
+    (1787597, "SunCom Wireless Puerto Rico"),
+    (1787598, "SunCom Wireless Puerto Rico"),
+    (1787601, "SunCom Wireless Puerto Rico"),
+    (1787602, "CENTENNIAL"),
+    (1787604, "SunCom Wireless Puerto Rico"),
+    (1787605, "SunCom Wireless Puerto Rico"),
+    (1787607, "CENTENNIAL"),
+    (1787608, "CENTENNIAL"),
+    (1787609, "CENTENNIAL"),
+    (1787612, "Claro"),
+    (1787613, "Claro"),
+    (1787614, "Claro"),
+    (1787615, "Claro"),
+    (1787616, "Claro"),
+    (1787617, "Claro"),
+    (1787619, "SunCom Wireless Puerto Rico"),
+    (1787620, "CENTENNIAL"),
+    (1787621, "CENTENNIAL"),
+    (1787622, "CENTENNIAL"),
+    (1787623, "CENTENNIAL"),
+    (1787624, "CENTENNIAL"),
+    (1787625, "CENTENNIAL"),
+    (1787626, "CENTENNIAL"),
+    (1787628, "CENTENNIAL"),
+    (1787629, "SunCom Wireless Puerto Rico"),
+    (1787662, "SunCom Wireless Puerto Rico"),
+    (1787666, "SunCom Wireless Puerto Rico"),
+    (1787673, "SunCom Wireless Puerto Rico"),
+    (1787675, "CENTENNIAL"),
+    (1787678, "SunCom Wireless Puerto Rico"),
+    (1787686, "CENTENNIAL"),
+    (1787687, "CENTENNIAL"),
+    (1787689, "CENTENNIAL"),
+    (1787690, "CENTENNIAL"),
+    (1787692, "CENTENNIAL"),
+    (1787693, "CENTENNIAL"),
+    (1787695, "CENTENNIAL"),
+    (1787717, "CENTENNIAL"),
+    (1787719, "CENTENNIAL"),
+    (1787901, "SunCom Wireless Puerto Rico"),
+    (1787903, "CENTENNIAL"),
+    (1787904, "SunCom Wireless Puerto Rico"),
+    (1787908, "CENTENNIAL"),
+    (1787912, "CENTENNIAL"),
+    (1787915, "CENTENNIAL"),
+    (1787916, "CENTENNIAL"),
+    (1787917, "CENTENNIAL"),
+    (1787922, "SunCom Wireless Puerto Rico"),
+    (1787923, "SunCom Wireless Puerto Rico"),
+    (1787924, "CENTENNIAL"),
+    (1787926, "CENTENNIAL"),
+    (1787927, "CENTENNIAL"),
+    (1787928, "CENTENNIAL"),
+    (1787933, "CENTENNIAL"),
+    (1787935, "CENTENNIAL"),
+    (1787937, "CENTENNIAL"),
+    (1787940, "CENTENNIAL"),
+    (1787947, "CENTENNIAL"),
+    (1787949, "SunCom Wireless Puerto Rico"),
+    (1787952, "CENTENNIAL"),
+    (1787953, "CENTENNIAL"),
+    (1787954, "CENTENNIAL"),
+    (1787957, "CENTENNIAL"),
+    (1787961, "CENTENNIAL"),
+    (1787968, "CENTENNIAL"),
+    (1787969, "CENTENNIAL"),
+    (1787971, "CENTENNIAL"),
+    (1787975, "CENTENNIAL"),
+    (1787978, "CENTENNIAL"),
+    (1787992, "CENTENNIAL"),
+    (1787993, "CENTENNIAL"),
+    (1787998, "CENTENNIAL"),
+    (1787999, "CENTENNIAL"),
+    (1868620, "bmobile"),
+    (1868678, "bmobile"),
+    (1869660, "Cable & Wireless"),
+    (1869661, "Cable & Wireless"),
+    (1869662, "Cable & Wireless"),
+    (1869663, "Cable & Wireless"),
+    (1869664, "Cable & Wireless"),
+    (1869665, "Cable & Wireless"),
+    (1869667, "Cable & Wireless"),
+    (1869668, "Cable & Wireless"),
+    (1869669, "Cable & Wireless"),
+    (1869760, "Digicel"),
+    (1869762, "Digicel"),
+    (1869763, "Digicel"),
+    (1869764, "Digicel"),
+    (1869765, "Digicel"),
+    (1869766, "Digicel"),
+    (1876210, "Cable & Wireless"),
+    (1876275, "Digicel"),
+    (1876276, "Digicel"),
+    (1876277, "Digicel"),
+    (1876278, "Digicel"),
+    (1876279, "Digicel"),
+    (1876310, "Cable & Wireless"),
+    (1876312, "Cable & Wireless"),
+    (1876313, "Cable & Wireless"),
+    (1876314, "Cable & Wireless"),
+    (1876315, "Cable & Wireless"),
+    (1876316, "Cable & Wireless"),
+    (1876317, "Cable & Wireless"),
+    (1876318, "Cable & Wireless"),
+    (1876319, "Cable & Wireless"),
+    (1876440, "Digicel"),
+    (1876441, "Digicel"),
+    (1876442, "Digicel"),
+    (1876443, "Digicel"),
+    (1876445, "Digicel"),
+    (1876446, "Digicel"),
+    (1876447, "Digicel"),
+    (1876448, "Digicel"),
+    (1876449, "Digicel"),
+    (1876501, "Cable & Wireless"),
+    (1876502, "C&W"),
+    (1876515, "Cable & Wireless"),
+    (1876517, "Cable & Wireless"),
+    (1876519, "Cable & Wireless"),
+    (1876550, "Digicel"),
+    (1876551, "Digicel"),
+    (1876552, "Digicel"),
+    (1876553, "Digicel"),
+    (1876554, "Digicel"),
+    (1876556, "Digicel"),
+    (1876557, "Digicel"),
+    (1876558, "Digicel"),
+    (1876559, "Digicel"),
+    (1876563, "C&W"),
+    (1876648, "Digicel"),
+    (1876649, "Digicel"),
+    (1876666, "Digicel"),
+    (1876667, "Digicel"),
+    (1876700, "Cable & Wireless"),
+    (1876707, "Cable & Wireless"),
+    (1876781, "Cable & Wireless"),
+    (1876782, "Cable & Wireless"),
+    (1876783, "Cable & Wireless"),
+    (1876784, "Cable & Wireless"),
+    (1876787, "Cable & Wireless"),
+    (1876788, "Cable & Wireless"),
+    (1876789, "Cable & Wireless"),
+    (1876790, "Cable & Wireless"),
+    (1876791, "Cable & Wireless"),
+    (1876792, "Cable & Wireless"),
+    (1876793, "Cable & Wireless"),
+    (1876796, "Cable & Wireless"),
+    (1876797, "Cable & Wireless"),
+    (1876798, "Cable & Wireless"),
+    (1876799, "Cable & Wireless"),
+    (1876810, "Cable & Wireless"),
+    (1876812, "Cable & Wireless"),
+    (1876813, "Cable & Wireless"),
+    (1876814, "Cable & Wireless"),
+    (1876815, "Cable & Wireless"),
+    (1876816, "Cable & Wireless"),
+    (1876817, "Cable & Wireless"),
+    (1876818, "Cable & Wireless"),
+    (1876819, "Cable & Wireless"),
+    (1876909, "Cable & Wireless"),
+    (1876919, "Cable & Wireless"),
+    (1876990, "Cable & Wireless"),
+    (1876995, "Cable & Wireless"),
+    (1876997, "Cable & Wireless"),
+    (1876999, "Cable & Wireless"),
+    (1939201, "CENTENNIAL"),
+    (1939212, "CENTENNIAL"),
+    (1939214, "CENTENNIAL"),
+    (1939240, "SunCom Wireless Puerto Rico"),
+    (1939242, "Claro"),
+    (1939244, "Claro"),
+    (1939245, "Claro"),
+    (1939246, "Claro"),
+    (1939247, "Claro"),
+    (1939248, "Claro"),
+    (1939249, "Claro"),
+    (1939252, "CENTENNIAL"),
+    (1939307, "CENTENNIAL"),
+    (1939325, "SunCom Wireless Puerto Rico"),
+    (1939329, "CENTENNIAL"),
+    (1939334, "Claro"),
+    (1939339, "SunCom Wireless Puerto Rico"),
+    (1939394, "CENTENNIAL"),
+    (1939440, "CENTENNIAL"),
+    (1939628, "CENTENNIAL"),
+    (1939630, "CENTENNIAL"),
+    (1939639, "CENTENNIAL"),
+    (1939640, "CENTENNIAL"),
+    (1939642, "CENTENNIAL"),
+    (1939644, "CENTENNIAL"),
+    (1939645, "CENTENNIAL"),
+    (1939697, "CENTENNIAL"),
+    (1939717, "CENTENNIAL"),
+    (1939731, "CENTENNIAL"),
+    (1939777, "Claro"),
+    (1939865, "SunCom Wireless Puerto Rico"),
+    (1939891, "SunCom Wireless Puerto Rico"),
+    (1939910, "CENTENNIAL"),
+    (1939940, "CENTENNIAL"),
+    (1939969, "CENTENNIAL"),
+    (2126921, "Al Hourria Telecom"),
+    (2126922, "Al Hourria Telecom"),
+    (2232079, "Sotelma"),
+    (2290142, "MTN"),
+    (2290145, "Moov"),
+    (2290146, "MTN"),
+    (2290155, "Moov"),
+    (2290158, "Moov"),
+    (2290160, "Moov"),
+    (2290161, "MTN"),
+    (2290162, "MTN"),
+    (2290163, "Moov"),
+    (2290164, "Moov"),
+    (2290165, "Moov"),
+    (2290166, "MTN"),
+    (2290167, "MTN"),
+    (2290168, "Moov"),
+    (2290169, "MTN"),
+    (2290190, "MTN"),
+    (2290191, "MTN"),
+    (2290192, "Celtiis"),
+    (2290193, "Celtiis"),
+    (2290194, "Moov"),
+    (2290195, "Moov"),
+    (2290196, "MTN"),
+    (2290197, "MTN"),
+    (2290198, "Moov"),
+    (2290199, "Moov"),
+    (2305471, "Cellplus"),
+    (2305871, "MTML"),
+    (2305875, "Cellplus"),
+    (2305876, "Cellplus"),
+    (2305877, "Cellplus"),
+    (2305878, "Cellplus"),
+    (2347020, "Smile"),
+    (2347021, "Ntel"),
+    (2347022, "Ntel"),
+    (2347024, "Prestel"),
+    (2347025, "MTN"),
+    (2347026, "MTN"),
+    (2347027, "Multilinks"),
+    (2347028, "Starcomms"),
+    (2347029, "Starcomms"),
+    (2626392, "SFR"),
+    (2626394, "SFR"),
+    (2626920, "Orange"),
+    (2626922, "Orange"),
+    (2626923, "Orange"),
+    (2626934, "Telco OI"),
+    (2626938, "Telco OI"),
+    (2710492, "Vodacom"),
+    (2710493, "Vodacom"),
+    (2710494, "Vodacom"),
+    (2712492, "Vodacom"),
+    (2721492, "Vodacom"),
+    (2731492, "Vodacom"),
+    (2741492, "Vodacom"),
+    (2751492, "Vodacom"),
+    (2787086, "Vodacom"),
+    (2787087, "Vodacom"),
+    (2787158, "Vodacom"),
+    (2787285, "Vodacom"),
+    (2787286, "Vodacom"),
+    (2787287, "Vodacom"),
+    (2787288, "Vodacom"),
+    (2787289, "Vodacom"),
+    (2787310, "Vodacom"),
+    (3068519, "Cyta"),
+    (3069522, "Vodafone"),
+    (3069523, "Vodafone"),
+    (3069524, "BWS"),
+    (3069529, "BWS"),
+    (3069530, "Cyta"),
+    (3069540, "OTE"),
+    (3069541, "OTE"),
+    (3069542, "OTE"),
+    (3069543, "OTE"),
+    (3069601, "OTE"),
+    (3069900, "Wind"),
+    (3245001, "GATEWAY COMMUNICATIONS S.A."),
+    (3360040, "Zeop"),
+    (3360041, "Orange France"),
+    (3360042, "Digicel Antilles Francaises Guyane"),
+    (3360043, "Dauphin Telecom"),
+    (3360044, "OUTREMER TELECOM"),
+    (3360045, "UTS CARAIBES"),
+    (3360051, "Orange France"),
+    (3360052, "SFR"),
+    (3360053, "BJT"),
+    (3360054, "Only (Telco OI)"),
+    (3360055, "Only (Telco OI)"),
+    (3363800, "Globalstar Europe"),
+    (3363801, "Prixtel"),
+    (3363802, "Prixtel"),
+    (3363803, "Prixtel"),
+    (3363804, "Prixtel"),
+    (3363805, "Prixtel"),
+    (3363806, "IP Directions"),
+    (3363807, "Alphalink"),
+    (3363808, "Alphalink"),
+    (3363809, "Alphalink"),
+    (3364000, "Globalstar Europe"),
+    (3364001, "Globalstar Europe"),
+    (3364002, "Globalstar Europe"),
+    (3364003, "Globalstar Europe"),
+    (3364004, "Globalstar Europe"),
+    (3364005, "Coriolis Telecom"),
+    (3364006, "Coriolis Telecom"),
+    (3364007, "Coriolis Telecom"),
+    (3364008, "Coriolis Telecom"),
+    (3364009, "Coriolis Telecom"),
+    (3364160, "Euroinformation Telecom"),
+    (3364161, "Euroinformation Telecom"),
+    (3364162, "Mobiquithings"),
+    (3364163, "SCT"),
+    (3364164, "Legos"),
+    (3364165, "e*Message"),
+    (3364166, "SFR"),
+    (3364167, "SFR"),
+    (3364168, "SFR"),
+    (3364169, "SFR"),
+    (3364950, "Keyyo"),
+    (3364990, "Intercall"),
+    (3364991, "Intercall"),
+    (3364994, "e*Message"),
+    (3364995, "Prixtel"),
+    (3364996, "e*Message"),
+    (3364997, "e*Message"),
+    (3364998, "Prixtel"),
+    (3364999, "SFR"),
+    (3365660, "Mobiquithings"),
+    (3365661, "Airbus Defence and Space"),
+    (3365662, "Mobiquithings"),
+    (3365663, "Mobiquithings"),
+    (3365664, "Mobiquithings"),
+    (3365665, "Mobiquithings"),
+    (3365666, "Prixtel"),
+    (3365667, "Prixtel"),
+    (3365668, "Prixtel"),
+    (3365669, "Prixtel"),
+    (3375050, "Euroinformation Telecom"),
+    (3375051, "Euroinformation Telecom"),
+    (3375052, "Euroinformation Telecom"),
+    (3375053, "Euroinformation Telecom"),
+    (3375057, "Euroinformation Telecom"),
+    (3375058, "Euroinformation Telecom"),
+    (3375059, "Sewan communications"),
+    (3375060, "Euroinformation Telecom"),
+    (3375070, "Euroinformation Telecom"),
+    (3375071, "Netcom Group"),
+    (3375072, "Netcom Group"),
+    (3375073, "Alphalink"),
+    (3375074, "Alphalink"),
+    (3375075, "Alphalink"),
+    (3375076, "Globalstar Europe"),
+    (3375077, "Globalstar Europe"),
+    (3375078, "Bouygues"),
+    (3375079, "Bouygues"),
+    (3375202, "Prixtel"),
+    (3375203, "Prixtel"),
+    (3375204, "Prixtel"),
+    (3375205, "Prixtel"),
+    (3375206, "Prixtel"),
+    (3375207, "Prixtel"),
+    (3375208, "Prixtel"),
+    (3375209, "Prixtel"),
+    (3375430, "TDF"),
+    (3375431, "Legos"),
+    (3375432, "Euroinformation Telecom"),
+    (3375433, "SFR"),
+    (3375550, "Legos"),
+    (3375551, "Legos"),
+    (3375552, "Legos"),
+    (3375553, "Legos"),
+    (3375554, "Legos"),
+    (3375555, "Euroinformation Telecom"),
+    (3375556, "Intercall"),
+    (3375557, "Intercall"),
+    (3375558, "Sewan communications"),
+    (3375559, "Sewan communications"),
+    (3375560, "Prixtel"),
+    (3375561, "Prixtel"),
+    (3375562, "Prixtel"),
+    (3375563, "Prixtel"),
+    (3375564, "Prixtel"),
+    (3375565, "Sewan communications"),
+    (3375566, "Euroinformation Telecom"),
+    (3375567, "Euroinformation Telecom"),
+    (3375568, "Euroinformation Telecom"),
+    (3375569, "Axialys"),
+    (3375630, "Euroinformation Telecom"),
+    (3375631, "Euroinformation Telecom"),
+    (3375632, "Euroinformation Telecom"),
+    (3375633, "Euroinformation Telecom"),
+    (3375634, "Euroinformation Telecom"),
+    (3375644, "SFR"),
+    (3375645, "SFR"),
+    (3375648, "Bouygues"),
+    (3375700, "Sewan communications"),
+    (3375701, "Mobiweb telecom limited"),
+    (3375702, "Mobiweb telecom limited"),
+    (3375703, "Mobiweb telecom limited"),
+    (3375704, "Mobiweb telecom limited"),
+    (3375705, "Mobiweb telecom limited"),
+    (3375706, "Nordnet"),
+    (3375707, "Keyyo"),
+    (3375708, "SFR"),
+    (3375709, "SFR"),
+    (3375710, "SFR"),
+    (3375711, "SFR"),
+    (3375712, "SFR"),
+    (3375714, "SFR"),
+    (3375715, "AIF"),
+    (3375717, "Keyyo"),
+    (3375719, "Orange France"),
+    (3375757, "Euroinformation Telecom"),
+    (3375758, "Euroinformation Telecom"),
+    (3375759, "Twilio Ireland Limited"),
+    (3375760, "Twilio Ireland Limited"),
+    (3375761, "SFR"),
+    (3375763, "Euroinformation Telecom"),
+    (3375764, "Bouygues"),
+    (3375765, "Bouygues"),
+    (3375767, "Euroinformation Telecom"),
+    (3375770, "SFR"),
+    (3375771, "SFR"),
+    (3375772, "SFR"),
+    (3375773, "SFR"),
+    (3375774, "SFR"),
+    (3375777, "Euroinformation Telecom"),
+    (3375778, "SFR"),
+    (3375779, "Halys"),
+    (3375786, "Orange France"),
+    (3375787, "Euroinformation Telecom"),
+    (3375788, "BJT"),
+    (3375789, "BJT"),
+    (3377150, "SFR"),
+    (3377151, "SFR"),
+    (3377152, "SFR"),
+    (3377153, "SFR"),
+    (3377154, "SFR"),
+    (3377155, "Euroinformation Telecom"),
+    (3377156, "Euroinformation Telecom"),
+    (3377157, "Euroinformation Telecom"),
+    (3377158, "Euroinformation Telecom"),
+    (3377159, "Euroinformation Telecom"),
+    (3377190, "Euroinformation Telecom"),
+    (3377191, "Euroinformation Telecom"),
+    (3377192, "Euroinformation Telecom"),
+    (3377193, "Euroinformation Telecom"),
+    (3377194, "Euroinformation Telecom"),
+    (3460220, "Orange"),
+    (3460221, "Ion mobile"),
+    (3460222, "Vozelia"),
+    (3460223, "Orange"),
+    (3460224, "Oceans"),
+    (3460225, "VozTelecom"),
+    (3460226, "Orange"),
+    (3460227, "Orange"),
+    (3460228, "Orange"),
+    (3460229, "Boutique"),
+    (3460300, "Vodafone"),
+    (3460301, "Vodafone"),
+    (3460302, "Vodafone"),
+    (3460303, "Vodafone"),
+    (3460304, "Vodafone"),
+    (3460305, "Lebara"),
+    (3460306, "Lebara"),
+    (3460307, "Lebara"),
+    (3460308, "Lebara"),
+    (3460309, "Lebara"),
+    (3460529, "MasMovil"),
+    (3462529, "Yoigo"),
+    (3463529, "Yoigo"),
+    (3464529, "Yoigo"),
+    (3465229, "Yoigo"),
+    (3465329, "DIA"),
+    (3465429, "DIA"),
+    (3465529, "DIA"),
+    (3465729, "DIA"),
+    (3465829, "DIA"),
+    (3468440, "Eurona"),
+    (3468441, "Lemonvil"),
+    (3468442, "BluePhone"),
+    (3468443, "BT"),
+    (3468444, "BT"),
+    (3468445, "Aire Networks"),
+    (3468447, "Quattre"),
+    (3468448, "Nethits"),
+    (3468529, "Carrefour"),
+    (3468870, "OpenMovil"),
+    (3468883, "Sarenet"),
+    (3469190, "MasMovil"),
+    (3469198, "Carrefour"),
+    (3469199, "Carrefour"),
+    (3469229, "Carrefour"),
+    (3469300, "MasMovil"),
+    (3469301, "Yoigo"),
+    (3469302, "Yoigo"),
+    (3469303, "Yoigo"),
+    (3469304, "Yoigo"),
+    (3469305, "Yoigo"),
+    (3469306, "Yoigo"),
+    (3469310, "MasMovil"),
+    (3469320, "Carrefour"),
+    (3469321, "Carrefour"),
+    (3469329, "Orange"),
+    (3469336, "Yoigo"),
+    (3469337, "Yoigo"),
+    (3469340, "DIA"),
+    (3469341, "DIA"),
+    (3469342, "DIA"),
+    (3469343, "DIA"),
+    (3469344, "DIA"),
+    (3469345, "Yoigo"),
+    (3469346, "Yoigo"),
+    (3469347, "Yoigo"),
+    (3469348, "Yoigo"),
+    (3469349, "Yoigo"),
+    (3469360, "DIA"),
+    (3469361, "DIA"),
+    (3469362, "DIA"),
+    (3469363, "DIA"),
+    (3469364, "DIA"),
+    (3469365, "Carrefour"),
+    (3469366, "Carrefour"),
+    (3469367, "Yoigo"),
+    (3469368, "Yoigo"),
+    (3469369, "Yoigo"),
+    (3471770, "PepePhone"),
+    (3471771, "PepePhone"),
+    (3471777, "PepePhone"),
+    (3472260, "MasMovil"),
+    (3472261, "PepePhone"),
+    (3474442, "Deion"),
+    (3474443, "InfoVOIP"),
+    (3474447, "Jetnet"),
+    (3474448, "Aire Networks"),
+    (3474449, "Alai"),
+    (3516093, "NOS"),
+    (3516393, "NOS"),
+    (3516593, "NOS"),
+    (3516693, "NOS"),
+    (3519200, "Lycamobile"),
+    (3519201, "Lycamobile"),
+    (3519202, "Lycamobile"),
+    (3519203, "Lycamobile"),
+    (3519204, "Lycamobile"),
+    (3519205, "Lycamobile"),
+    (3519208, "Lycamobile"),
+    (3519220, "Vodafone"),
+    (3519221, "MEO"),
+    (3519222, "MEO"),
+    (3519231, "Vodafone"),
+    (3519232, "MEO"),
+    (3519233, "Digi Communications"),
+    (3519234, "G9 Telecom"),
+    (3519280, "MEO"),
+    (3519281, "MEO"),
+    (3519282, "Digi Communications"),
+    (3519283, "Digi Communications"),
+    (3519284, "Digi Communications"),
+    (3519285, "MEO"),
+    (3519290, "NOS"),
+    (3519291, "NOS"),
+    (3519292, "NOS"),
+    (3519293, "NOS"),
+    (3519294, "NOS"),
+    (3519295, "Sumamovil Portugal"),
+    (3538900, "Eircom"),
+    (3538928, "Tesco Mobile"),
+    (3538929, "Tesco Mobile"),
+    (3538960, "Virgin Media"),
+    (3538961, "Virgin Media"),
+    (3538962, "Virgin Media"),
+    (3538970, "Carphone Warehouse Ireland Mobile Limited"),
+    (3538971, "Carphone Warehouse Ireland Mobile Limited"),
+    (3538990, "Tesco Mobile"),
+    (3538991, "Tesco Mobile"),
+    (3538992, "Tesco Mobile"),
+    (3538993, "Tesco Mobile"),
+    (3538994, "Lycamobile"),
+    (3538995, "Lycamobile"),
+    (3538996, "Lycamobile"),
+    (3538997, "Lycamobile"),
+    (3538998, "Lycamobile"),
+    (3538999, "Tesco Mobile"),
+    (3569696, "epic"),
+    (3584320, "Cuuma"),
+    (3584321, "Cuuma"),
+    (3584322, "Benemen Oy"),
+    (3584323, "Top Connect OU"),
+    (3584324, "Nord Connect SIA"),
+    (3584325, "NETTIA"),
+    (3584326, "Lancelot"),
+    (3584540, "MobiWeb"),
+    (3584541, "AinaCom"),
+    (3584542, "Nokia"),
+    (3584543, "Nokia"),
+    (3584544, "Nokia"),
+    (3584545, "Interactive Digital Media"),
+    (3584546, "NextGen Mobile / CardBoardFish"),
+    (3584547, "SMS Provider Corp"),
+    (3584548, "Voxbone"),
+    (3584549, "Beepsend"),
+    (3584550, "Suomen Virveverkko"),
+    (3584552, "Suomen Virveverkko"),
+    (3584554, "Suomen Virveverkko"),
+    (3584555, "Nokia Solutions and Networks"),
+    (3584556, "Liikennevirasto"),
+    (3584557, "Compatel"),
+    (3584558, "Suomen Virveverkko"),
+    (3584559, "MI"),
+    (3584570, "AMT"),
+    (3584571, "Tismi"),
+    (3584572, "Telavox AB"),
+    (3584573, "AMT"),
+    (3584574, "DNA"),
+    (3584575, "AMT"),
+    (3584576, "DNA"),
+    (3584577, "DNA"),
+    (3584578, "DNA"),
+    (3584579, "DNA"),
+    (3599960, "A1"),
+    (3599961, "A1"),
+    (3599962, "A1"),
+    (3599964, "Telenor"),
+    (3599965, "Telenor"),
+    (3599966, "Telenor"),
+    (3599967, "Vivacom"),
+    (3599968, "Vivacom"),
+    (3599969, "Vivacom"),
+    (3599990, "A1"),
+    (3599991, "A1"),
+    (3599992, "A1"),
+    (3599993, "A1"),
+    (3599994, "Telenor"),
+    (3599995, "Telenor"),
+    (3599996, "Vivacom"),
+    (3599997, "Vivacom"),
+    (3599998, "Vivacom"),
+    (3599999, "Vivacom"),
+    (3631310, "One"),
+    (3631311, "One"),
+    (3631312, "One"),
+    (3631313, "One"),
+    (3631314, "One"),
+    (3631315, "One"),
+    (3631316, "One"),
+    (3631317, "One"),
+    (3631318, "One"),
+    (3631320, "One"),
+    (3631321, "One"),
+    (3631322, "One"),
+    (3631323, "One"),
+    (3631324, "One"),
+    (3631325, "One"),
+    (3631326, "One"),
+    (3631327, "One"),
+    (3631328, "One"),
+    (3631330, "One"),
+    (3631331, "One"),
+    (3631332, "One"),
+    (3631700, "One"),
+    (3631770, "One"),
+    (3631771, "One"),
+    (3631790, "One"),
+    (3706610, "Tele 2"),
+    (3706611, "BITĖ"),
+    (3706612, "BITĖ"),
+    (3706613, "BITĖ"),
+    (3706614, "BITĖ"),
+    (3706615, "BITĖ"),
+    (3706616, "BITĖ"),
+    (3706617, "BITĖ"),
+    (3706619, "BITĖ"),
+    (3706630, "Telia"),
+    (3706632, "Lancelot Telecom"),
+    (3706650, "Telia"),
+    (3706651, "Telia"),
+    (3706653, "Telia"),
+    (3706660, "BITĖ"),
+    (3706661, "BITĖ"),
+    (3706663, "Telia"),
+    (3706664, "Telia"),
+    (3706665, "BITĖ"),
+    (3706666, "Tele 2"),
+    (3706667, "BITĖ"),
+    (3706668, "BITĖ"),
+    (3706669, "BITĖ"),
+    (3706670, "BITĖ"),
+    (3706671, "BITĖ"),
+    (3706673, "BITĖ"),
+    (3706675, "Tele 2"),
+    (3706676, "BITĖ"),
+    (3706677, "BITĖ"),
+    (3706678, "BITĖ"),
+    (3706679, "BITĖ"),
+    (3706680, "Tele 2"),
+    (3706681, "Tele 2"),
+    (3706685, "Tele 2"),
+    (3706689, "Tele 2"),
+    (3706970, "Telia"),
+    (3706971, "Telia"),
+    (3706972, "Telia"),
+    (3706973, "Telia"),
+    (3706975, "Telia"),
+    (3706976, "Lancelot Telecom"),
+    (3706977, "Telia"),
+    (3706979, "Telia"),
+    (3712018, "Tele2"),
+    (3712089, "Tele2"),
+    (3712091, "Tele2"),
+    (3712092, "Tele2"),
+    (3712093, "Tele2"),
+    (3712094, "Triatel"),
+    (3712095, "Tele2"),
+    (3712096, "Tele2"),
+    (3712097, "Tele2"),
+    (3712200, "LMT"),
+    (3712201, "LMT"),
+    (3712202, "LMT"),
+    (3712203, "LMT"),
+    (3712204, "LMT"),
+    (3712205, "Bite Latvia"),
+    (3712206, "Bite Latvia"),
+    (3712207, "Bite Latvia"),
+    (3712208, "Bite Latvia"),
+    (3712209, "Bite Latvia"),
+    (3712239, "Bite Latvia"),
+    (3712266, "LMT"),
+    (3712267, "Tele2"),
+    (3712272, "Bite Latvia"),
+    (3712277, "LMT"),
+    (3712280, "Bite Latvia"),
+    (3712281, "Bite Latvia"),
+    (3712282, "Bite Latvia"),
+    (3712283, "Bite Latvia"),
+    (3712284, "Bite Latvia"),
+    (3712285, "UNISTARS"),
+    (3712286, "Triatel"),
+    (3712287, "Triatel"),
+    (3712288, "LMT"),
+    (3712299, "LMT"),
+    (3712311, "Bite Latvia"),
+    (3712317, "Bite Latvia"),
+    (3712320, "Bite Latvia"),
+    (3712322, "Bite Latvia"),
+    (3712323, "Tele2"),
+    (3712327, "Bite Latvia"),
+    (3712328, "LMT"),
+    (3712330, "Bite Latvia"),
+    (3712333, "Tele2"),
+    (3712337, "Bite Latvia"),
+    (3712347, "Bite Latvia"),
+    (3712355, "Bite Latvia"),
+    (3712357, "Bite Latvia"),
+    (3712366, "Bite Latvia"),
+    (3712377, "Bite Latvia"),
+    (3712380, "LMT"),
+    (3712381, "LMT"),
+    (3712382, "LMT"),
+    (3712383, "LMT"),
+    (3712384, "LMT"),
+    (3712388, "Bite Latvia"),
+    (3712399, "Bite Latvia"),
+    (3712400, "Bite Latvia"),
+    (3712411, "Bite Latvia"),
+    (3712420, "Bite Latvia"),
+    (3712422, "Bite Latvia"),
+    (3712424, "Bite Latvia"),
+    (3712433, "Bite Latvia"),
+    (3712440, "Bite Latvia"),
+    (3712442, "Bite Latvia"),
+    (3712444, "LMT"),
+    (3712450, "Bite Latvia"),
+    (3712455, "Bite Latvia"),
+    (3712460, "Bite Latvia"),
+    (3712466, "Bite Latvia"),
+    (3712477, "Bite Latvia"),
+    (3712478, "Tele2"),
+    (3712479, "Tele2"),
+    (3712488, "Bite Latvia"),
+    (3712499, "Bite Latvia"),
+    (3712500, "Bite Latvia"),
+    (3712556, "LMT"),
+    (3712557, "LMT"),
+    (3712558, "LMT"),
+    (3712559, "LMT"),
+    (3712585, "Bite Latvia"),
+    (3712586, "Bite Latvia"),
+    (3712587, "Bite Latvia"),
+    (3712588, "Bite Latvia"),
+    (3712720, "Bite Latvia"),
+    (3712721, "Bite Latvia"),
+    (3712722, "Bite Latvia"),
+    (3712723, "Bite Latvia"),
+    (3712724, "Bite Latvia"),
+    (3712725, "Bite Latvia"),
+    (3712726, "Tele2"),
+    (3712727, "Bite Latvia"),
+    (3712729, "LMT"),
+    (3712760, "Bite Latvia"),
+    (3712761, "Bite Latvia"),
+    (3712762, "Bite Latvia"),
+    (3712763, "Bite Latvia"),
+    (3712764, "Bite Latvia"),
+    (3712765, "Bite Latvia"),
+    (3712766, "Bite Latvia"),
+    (3712767, "Bite Latvia"),
+    (3712777, "LMT"),
+    (3712790, "LMT"),
+    (3712792, "Bite Latvia"),
+    (3712799, "Bite Latvia"),
+    (3712844, "Tele2"),
+    (3712845, "Tele2"),
+    (3712846, "Tele2"),
+    (3712847, "Tele2"),
+    (3712848, "Tele2"),
+    (3712849, "LMT"),
+    (3712855, "Bite Latvia"),
+    (3712900, "Bite Latvia"),
+    (3712902, "Bite Latvia"),
+    (3725461, "Elisa"),
+    (3725462, "Elisa"),
+    (3725463, "Elisa"),
+    (3728110, "Tele 2"),
+    (3728111, "Elisa"),
+    (3728123, "Elisa"),
+    (3728200, "Telia Eesti AS"),
+    (3728203, "Telia Eesti AS"),
+    (3728204, "Tele 2"),
+    (3728206, "Tele 2"),
+    (3728216, "Tele 2"),
+    (3728217, "Tele 2"),
+    (3728218, "Tele 2"),
+    (3728270, "Telia Eesti AS"),
+    (3728271, "Telia Eesti AS"),
+    (3728272, "Telia Eesti AS"),
+    (3728273, "Telia Eesti AS"),
+    (3728282, "Telia Eesti AS"),
+    (3728285, "Tele 2"),
+    (3728286, "Tele 2"),
+    (3728287, "Tele 2"),
+    (3859750, "Lancelot Telecom"),
+    (3859751, "Telefocus"),
+    (3859754, "Lancelot Telecom"),
+    (3859755, "BSG"),
+    (3859757, "Mobile One"),
+    (3866555, "Telekom Slovenije"),
+    (3866556, "Sloexport"),
+    (3866910, "Compatel"),
+    (3897421, "Mobik"),
+    (3897470, "T-Mobile"),
+    (3897471, "T-Mobile"),
+    (3897474, "T-Mobile"),
+    (3897475, "A1"),
+    (3897477, "A1"),
+    (3897478, "A1"),
+    (3897970, "T-Mobile"),
+    (3897971, "T-Mobile"),
+    (3897975, "A1"),
+    (4177310, "Sunrise"),
+    (4177311, "Sunrise"),
+    (4177312, "Sunrise"),
+    (4177313, "Sunrise"),
+    (4177314, "Sunrise"),
+    (4177315, "Sunrise"),
+    (4177316, "Sunrise"),
+    (4177357, "In&Phone"),
+    (4178460, "Tismi"),
+    (4178461, "Tismi"),
+    (4178462, "Tismi"),
+    (4178463, "Tismi"),
+    (4178480, "Nexphone"),
+    (4178481, "Nexphone"),
+    (4178482, "Nexphone"),
+    (4178490, "Telecom26 AG"),
+    (4179977, "Relario AG (Bebbicell)"),
+    (4179978, "Relario AG (Bebbicell)"),
+    (4179979, "Relario AG (Bebbicell)"),
+    (4207030, "T-Mobile"),
+    (4207031, "T-Mobile"),
+    (4207032, "T-Mobile"),
+    (4207033, "T-Mobile"),
+    (4207034, "T-Mobile"),
+    (4207035, "T-Mobile"),
+    (4207036, "T-Mobile"),
+    (4207038, "T-Mobile"),
+    (4207039, "T-Mobile"),
+    (4207040, "SAZKA sazkova kancelar, a.s"),
+    (4207041, "SAZKA sazkova kancelar, a.s"),
+    (4207042, "SAZKA sazkova kancelar, a.s"),
+    (4207043, "SAZKA sazkova kancelar, a.s"),
+    (4207044, "SAZKA sazkova kancelar, a.s"),
+    (4207045, "SAZKA sazkova kancelar, a.s"),
+    (4207047, "SAZKA sazkova kancelar, a.s"),
+    (4207050, "CEZ Group"),
+    (4207051, "CEZ Group"),
+    (4207052, "CEZ Group"),
+    (4207053, "CEZ Group"),
+    (4207054, "CEZ Group"),
+    (4207056, "T-Mobile"),
+    (4207057, "T-Mobile"),
+    (4207058, "T-Mobile"),
+    (4207059, "T-Mobile"),
+    (4207300, "T-Mobile"),
+    (4207301, "T-Mobile"),
+    (4207302, "T-Mobile"),
+    (4207305, "T-Mobile"),
+    (4207306, "T-Mobile"),
+    (4207308, "T-Mobile"),
+    (4207309, "T-Mobile"),
+    (4207700, "Vodafone"),
+    (4207701, "Vodafone"),
+    (4207702, "Vodafone"),
+    (4207703, "Vodafone"),
+    (4207704, "Vodafone"),
+    (4207705, "O2"),
+    (4207706, "Vodafone"),
+    (4207708, "Vodafone"),
+    (4207709, "Vodafone"),
+    (4207718, "Vodafone"),
+    (4207780, "Vodafone"),
+    (4207782, "Vodafone"),
+    (4207783, "Vodafone"),
+    (4207784, "Vodafone"),
+    (4207785, "Vodafone"),
+    (4207786, "Vodafone"),
+    (4207787, "Vodafone"),
+    (4207789, "Vodafone"),
+    (4207910, "TRAVEL TELEKOMMUNIKATION"),
+    (4207928, "Tesco Mobile CR"),
+    (4207929, "Tesco Mobile CR"),
+    (4207939, "T-Mobile"),
+    (4207940, "O2"),
+    (4207941, "O2"),
+    (4207942, "O2"),
+    (4207943, "O2"),
+    (4207944, "O2"),
+    (4207945, "O2"),
+    (4207946, "O2"),
+    (4207947, "O2"),
+    (4207948, "O2"),
+    (4207950, "Vectone Distribution Czech Republic s.r.o(Mundio)"),
+    (4207951, "Vectone Distribution Czech Republic s.r.o(Mundio)"),
+    (4207952, "O2"),
+    (4207953, "O2"),
+    (4207954, "Tesco Mobile CR"),
+    (4207976, "T-Mobile"),
+    (4207978, "T-Mobile"),
+    (4207990, "T-Mobile"),
+    (4207991, "T-Mobile"),
+    (4207998, "T-Mobile"),
+    (4207999, "T-Mobile"),
+    (4219091, "T-Mobile (Slovak Telekom)"),
+    (4219092, "T-Mobile (Slovak Telekom)"),
+    (4219093, "T-Mobile (Slovak Telekom)"),
+    (4219094, "T-Mobile (Slovak Telekom)"),
+    (4219095, "T-Mobile (Slovak Telekom)"),
+    (4219096, "T-Mobile (Slovak Telekom)"),
+    (4219097, "T-Mobile (Slovak Telekom)"),
+    (4219098, "T-Mobile (Slovak Telekom)"),
+    (4219099, "T-Mobile (Slovak Telekom)"),
+    (4219430, "BSG Estonia"),
+    (4219598, "Slovak Republic Railways (GSM-R)"),
+    (4236610, "Dimoco"),
+    (4236611, "FL1"),
+    (4368181, "A1 TA"),
+    (4368182, "A1 TA"),
+    (4368183, "Three"),
+    (4368184, "A1 TA"),
+    (4473566, "Wireless Logic"),
+    (4473568, "TATA Communications"),
+    (4473680, "TATA Communications"),
+    (4473682, "Sky"),
+    (4473683, "Sky"),
+    (4473684, "Sky"),
+    (4473685, "Sky"),
+    (4473686, "Sky"),
+    (4473690, "Telet Research"),
+    (4473691, "Telet Research"),
+    (4473692, "Sky"),
+    (4473697, "Wave Mobile"),
+    (4473699, "Anywhere Sim"),
+    (4473780, "Limitless"),
+    (4473800, "AMSUK"),
+    (4473890, "Three"),
+    (4473891, "Three"),
+    (4473892, "TalkTalk"),
+    (4473893, "TalkTalk"),
+    (4473894, "TalkTalk"),
+    (4473895, "TalkTalk"),
+    (4473896, "Hanhaa"),
+    (4473897, "Vodafone"),
+    (4473898, "Vodafone"),
+    (4473900, "Home Office"),
+    (4473970, "Three"),
+    (4473971, "Three"),
+    (4473972, "Three"),
+    (4473973, "Three"),
+    (4473975, "Three"),
+    (4473976, "Three"),
+    (4473977, "Three"),
+    (4473978, "Three"),
+    (4473979, "Three"),
+    (4474060, "Cheers"),
+    (4474061, "Cheers"),
+    (4474062, "Cheers"),
+    (4474065, "Telecom2"),
+    (4474066, "Sure"),
+    (4474067, "TGL"),
+    (4474068, "08Direct"),
+    (4474069, "CardBoardFish"),
+    (4474080, "Truphone"),
+    (4474081, "Truphone"),
+    (4474082, "Truphone"),
+    (4474086, "Truphone"),
+    (4474088, "Truphone"),
+    (4474089, "Truphone"),
+    (4474171, "CardBoardFish"),
+    (4474172, "Core Telecom"),
+    (4474173, "Lycamobile"),
+    (4474174, "Lycamobile"),
+    (4474175, "Lycamobile"),
+    (4474178, "Truphone"),
+    (4474179, "Core Telecom"),
+    (4474180, "Three"),
+    (4474181, "Bellingham"),
+    (4474182, "TGL"),
+    (4474183, "Tismi"),
+    (4474184, "Manx Telecom"),
+    (4474185, "Telna"),
+    (4474186, "Ace Call"),
+    (4474187, "TATA Communications"),
+    (4474189, "TATA Communications"),
+    (4474390, "TalkTalk"),
+    (4474391, "TalkTalk"),
+    (4474392, "TalkTalk"),
+    (4474393, "TalkTalk"),
+    (4474408, "Telecoms Cloud"),
+    (4474409, "Cloud9"),
+    (4474410, "Mediatel"),
+    (4474411, "Andrews & Arnold"),
+    (4474413, "Stour Marine"),
+    (4474414, "Tismi"),
+    (4474415, "Synectiv"),
+    (4474416, "Vodafone"),
+    (4474417, "Synectiv"),
+    (4474418, "Core Telecom"),
+    (4474419, "Voxbone"),
+    (4474512, "Tismi"),
+    (4474515, "Premium O"),
+    (4474516, "UK Broadband"),
+    (4474517, "UK Broadband"),
+    (4474527, "Three"),
+    (4474528, "Three"),
+    (4474529, "Three"),
+    (4474570, "Vectone Mobile"),
+    (4474571, "Vectone Mobile"),
+    (4474572, "Marathon Telecom"),
+    (4474573, "Vectone Mobile"),
+    (4474574, "Voicetec"),
+    (4474575, "Vectone Mobile"),
+    (4474576, "Sure"),
+    (4474577, "Spacetel"),
+    (4474578, "CardBoardFish"),
+    (4474579, "CardBoardFish"),
+    (4474580, "Gamma Telecom"),
+    (4474581, "Gamma Telecom"),
+    (4474582, "Lancelot Telecom"),
+    (4474583, "Virgin Mobile"),
+    (4474584, "Airwave"),
+    (4474585, "Marathon Telecom"),
+    (4474586, "Three"),
+    (4474587, "Limitless"),
+    (4474588, "Limitless"),
+    (4474589, "Three"),
+    (4474650, "Vectone Mobile"),
+    (4474651, "Vectone Mobile"),
+    (4474653, "Compatel"),
+    (4474655, "GlobalReach"),
+    (4474880, "Fogg"),
+    (4474881, "CESG"),
+    (4474882, "Sky"),
+    (4474883, "Sky"),
+    (4474884, "Three"),
+    (4474885, "Three"),
+    (4474886, "Lanonyx"),
+    (4474887, "Three"),
+    (4474888, "Ziron"),
+    (4474889, "Three"),
+    (4475090, "JT"),
+    (4475091, "JT"),
+    (4475092, "JT"),
+    (4475093, "JT"),
+    (4475094, "JT"),
+    (4475095, "JT"),
+    (4475096, "JT"),
+    (4475097, "JT"),
+    (4475200, "Simwood"),
+    (4475201, "BT OnePhone"),
+    (4475202, "Vectone Mobile"),
+    (4475204, "Core Communication"),
+    (4475205, "Esendex"),
+    (4475206, "Tismi"),
+    (4475207, "aql"),
+    (4475320, "Orange"),
+    (4475321, "Orange"),
+    (4475322, "Orange"),
+    (4475323, "Orange"),
+    (4475324, "Orange"),
+    (4475325, "SMSRelay AG"),
+    (4475326, "Three"),
+    (4475327, "Three"),
+    (4475328, "Three"),
+    (4475329, "Mobiweb"),
+    (4475370, "Wavecrest"),
+    (4475371, "Stour Marine"),
+    (4475373, "Swiftnet"),
+    (4475374, "Vodafone"),
+    (4475376, "Mediatel"),
+    (4475377, "CFL"),
+    (4475378, "Three"),
+    (4475379, "Three"),
+    (4475580, "Mobile FX Services Ltd"),
+    (4475588, "Cloud9"),
+    (4475590, "Mars"),
+    (4475591, "LegendTel"),
+    (4475592, "IPV6"),
+    (4475593, "Globecom"),
+    (4475594, "Truphone"),
+    (4475595, "Confabulate"),
+    (4475596, "Lleida.net"),
+    (4475597, "Core Telecom"),
+    (4475598, "Nodemax"),
+    (4475599, "Resilient"),
+    (4475710, "09 Mobile"),
+    (4475718, "Alliance"),
+    (4475890, "Yim Siam"),
+    (4475891, "Oxygen8"),
+    (4475892, "Oxygen8"),
+    (4475893, "Oxygen8"),
+    (4475894, "Vectone Mobile"),
+    (4475895, "Vectone Mobile"),
+    (4475896, "Vectone Mobile"),
+    (4475897, "Vectone Mobile"),
+    (4475898, "Test2date"),
+    (4476242, "Sure"),
+    (4477000, "Cloud9"),
+    (4477001, "Nationwide Telephone"),
+    (4477003, "Sure"),
+    (4477007, "Sure"),
+    (4477008, "Sure"),
+    (4477442, "Core Communication"),
+    (4477443, "Core Communication"),
+    (4477444, "Core Communication"),
+    (4477445, "Core Communication"),
+    (4477446, "Core Communication"),
+    (4477447, "Core Communication"),
+    (4477448, "Core Communication"),
+    (4477449, "Core Communication"),
+    (4477530, "Airwave"),
+    (4477552, "Core Communication"),
+    (4477553, "Core Communication"),
+    (4477554, "Core Communication"),
+    (4477555, "Core Communication"),
+    (4478220, "FleXtel"),
+    (4478221, "Swiftnet"),
+    (4478222, "TalkTalk"),
+    (4478224, "aql"),
+    (4478225, "Icron Network"),
+    (4478226, "aql"),
+    (4478227, "Cheers"),
+    (4478228, "Vodafone"),
+    (4478229, "Oxygen8"),
+    (4478297, "Airtel"),
+    (4478298, "Airtel"),
+    (4478299, "Airtel"),
+    (4478391, "Airtel"),
+    (4478392, "Airtel"),
+    (4478397, "Airtel"),
+    (4478398, "Sure"),
+    (4478640, "O2"),
+    (4478641, "O2"),
+    (4478642, "O2"),
+    (4478643, "O2"),
+    (4478645, "O2"),
+    (4478646, "O2"),
+    (4478647, "O2"),
+    (4478648, "O2"),
+    (4478649, "O2"),
+    (4478722, "Cloud9"),
+    (4478727, "Telecom 10"),
+    (4478730, "Telesign"),
+    (4478740, "O2"),
+    (4478741, "O2"),
+    (4478742, "O2"),
+    (4478743, "O2"),
+    (4478744, "Citrus"),
+    (4478746, "O2"),
+    (4478747, "O2"),
+    (4478748, "O2"),
+    (4478749, "O2"),
+    (4478920, "HSL"),
+    (4478921, "Vectone Mobile"),
+    (4478923, "O2"),
+    (4478924, "O2"),
+    (4478925, "FleXtel"),
+    (4478926, "O2"),
+    (4478927, "O2"),
+    (4478928, "O2"),
+    (4478929, "O2"),
+    (4478930, "Magrathea"),
+    (4478931, "Sure"),
+    (4478932, "O2"),
+    (4478933, "Yim Siam"),
+    (4478934, "O2"),
+    (4478935, "O2"),
+    (4478936, "O2"),
+    (4478937, "O2"),
+    (4478938, "aql"),
+    (4478939, "Citrus"),
+    (4479110, "Marathon Telecom"),
+    (4479111, "JT"),
+    (4479112, "Sure"),
+    (4479117, "JT"),
+    (4479118, "Sure"),
+    (4479245, "Cloud9"),
+    (4479781, "QX Telecom"),
+    (4479782, "Cloud9"),
+    (4479783, "Cloud9"),
+    (4479784, "Cheers"),
+    (4479785, "Icron Network"),
+    (4479786, "Oxygen8"),
+    (4479787, "TeleWare"),
+    (4479788, "Truphone"),
+    (4479789, "IV Response"),
+    (4523541, "telenor"),
+    (4523561, "telenor"),
+    (4525980, "uni-tel"),
+    (4525981, "mobiweb limited"),
+    (4525982, "jay.net"),
+    (4525983, "42 telecom ab"),
+    (4525984, "link mobile"),
+    (4525985, "42 telecom ab"),
+    (4525986, "42 telecom ab"),
+    (4525987, "netfors unified messaging"),
+    (4525988, "link mobile"),
+    (4525989, "ipnordic"),
+    (4531312, "mi carrier services"),
+    (4542900, "telenor"),
+    (4542901, "telenor"),
+    (4542902, "telenor"),
+    (4542903, "telenor"),
+    (4542904, "telenor"),
+    (4542905, "telenor"),
+    (4549109, "tdc"),
+    (4549130, "tdc"),
+    (4549131, "tdc"),
+    (4549132, "tdc"),
+    (4549133, "tdc"),
+    (4549134, "tdc"),
+    (4549135, "tdc"),
+    (4549136, "tdc"),
+    (4549138, "tdc"),
+    (4549139, "tdc"),
+    (4549150, "tdc"),
+    (4549151, "tdc"),
+    (4549155, "tdc"),
+    (4549156, "tdc"),
+    (4549157, "tdc"),
+    (4549158, "tdc"),
+    (4549159, "tdc"),
+    (4549160, "tdc"),
+    (4549161, "tdc"),
+    (4549162, "tdc"),
+    (4549163, "tdc"),
+    (4549168, "tdc"),
+    (4549169, "tdc"),
+    (4549180, "tdc"),
+    (4549181, "tdc"),
+    (4549184, "tdc"),
+    (4549185, "tdc"),
+    (4549187, "tdc"),
+    (4549188, "tdc"),
+    (4549189, "tdc"),
+    (4549200, "tdc"),
+    (4549201, "tdc"),
+    (4549202, "tdc"),
+    (4549203, "tdc"),
+    (4549220, "tdc"),
+    (4549221, "tdc"),
+    (4549222, "tdc"),
+    (4549223, "tdc"),
+    (4549224, "tdc"),
+    (4549225, "tdc"),
+    (4549226, "tdc"),
+    (4549250, "tdc"),
+    (4549251, "tdc"),
+    (4549252, "tdc"),
+    (4549253, "tdc"),
+    (4549255, "tdc"),
+    (4549256, "tdc"),
+    (4549258, "tdc"),
+    (4549259, "tdc"),
+    (4549260, "tdc"),
+    (4549261, "tdc"),
+    (4549262, "tdc"),
+    (4549263, "tdc"),
+    (4549264, "tdc"),
+    (4549265, "tdc"),
+    (4549266, "tdc"),
+    (4549295, "tdc"),
+    (4549298, "tdc"),
+    (4549299, "tdc"),
+    (4549700, "tdc"),
+    (4549701, "tdc"),
+    (4549702, "tdc"),
+    (4549703, "tdc"),
+    (4549704, "tdc"),
+    (4549707, "tdc"),
+    (4549708, "tdc"),
+    (4549709, "tdc"),
+    (4549750, "tdc"),
+    (4549751, "tdc"),
+    (4549752, "tdc"),
+    (4549753, "tdc"),
+    (4549754, "tdc"),
+    (4549755, "tdc"),
+    (4549758, "tdc"),
+    (4549759, "tdc"),
+    (4549760, "tdc"),
+    (4549761, "tdc"),
+    (4549762, "tdc"),
+    (4549763, "tdc"),
+    (4549765, "tdc"),
+    (4549766, "tdc"),
+    (4549767, "tdc"),
+    (4549780, "tdc"),
+    (4549789, "tdc"),
+    (4564212, "tdc"),
+    (4564215, "tdc"),
+    (4564222, "tdc"),
+    (4564281, "tdc"),
+    (4564292, "tdc"),
+    (4564400, "tdc"),
+    (4564401, "tdc"),
+    (4564402, "tdc"),
+    (4564403, "tdc"),
+    (4564404, "tdc"),
+    (4564406, "tdc"),
+    (4564421, "tdc"),
+    (4564422, "tdc"),
+    (4564423, "tdc"),
+    (4564431, "tdc"),
+    (4564432, "tdc"),
+    (4564433, "tdc"),
+    (4564441, "tdc"),
+    (4564442, "tdc"),
+    (4564451, "tdc"),
+    (4564457, "tdc"),
+    (4564458, "tdc"),
+    (4564459, "tdc"),
+    (4564460, "tdc"),
+    (4564461, "tdc"),
+    (4564462, "tdc"),
+    (4564471, "tdc"),
+    (4564472, "tdc"),
+    (4564473, "tdc"),
+    (4564474, "tdc"),
+    (4564481, "tdc"),
+    (4564491, "tdc"),
+    (4564492, "tdc"),
+    (4564505, "tdc"),
+    (4564721, "tdc"),
+    (4564722, "tdc"),
+    (4564723, "tdc"),
+    (4564731, "tdc"),
+    (4564732, "tdc"),
+    (4564733, "tdc"),
+    (4564741, "tdc"),
+    (4564742, "tdc"),
+    (4564746, "tdc"),
+    (4564747, "tdc"),
+    (4564751, "tdc"),
+    (4564752, "tdc"),
+    (4564761, "tdc"),
+    (4564762, "tdc"),
+    (4564763, "tdc"),
+    (4564764, "tdc"),
+    (4564771, "tdc"),
+    (4564781, "tdc"),
+    (4564787, "tdc"),
+    (4564788, "tdc"),
+    (4564789, "tdc"),
+    (4564790, "tdc"),
+    (4564791, "tdc"),
+    (4564792, "tdc"),
+    (4564801, "tdc"),
+    (4564804, "tdc"),
+    (4564805, "tdc"),
+    (4564806, "tdc"),
+    (4564811, "tdc"),
+    (4564812, "tdc"),
+    (4564813, "tdc"),
+    (4564814, "tdc"),
+    (4564820, "tdc"),
+    (4564821, "tdc"),
+    (4564822, "tdc"),
+    (4564823, "tdc"),
+    (4564824, "tdc"),
+    (4564825, "tdc"),
+    (4564826, "tdc"),
+    (4564827, "tdc"),
+    (4564828, "tdc"),
+    (4564831, "tdc"),
+    (4564841, "tdc"),
+    (4564842, "tdc"),
+    (4564851, "tdc"),
+    (4564852, "tdc"),
+    (4564861, "tdc"),
+    (4564871, "tdc"),
+    (4564872, "tdc"),
+    (4564881, "tdc"),
+    (4564882, "tdc"),
+    (4564891, "tdc"),
+    (4564892, "tdc"),
+    (4564893, "tdc"),
+    (4564897, "tdc"),
+    (4564898, "tdc"),
+    (4564899, "tdc"),
+    (4570160, "telenor"),
+    (4570161, "telenor"),
+    (4570180, "herobase"),
+    (4570181, "telenor"),
+    (4570300, "telia"),
+    (4570301, "telia"),
+    (4570302, "telia"),
+    (4570323, "telenor"),
+    (4570345, "telenor"),
+    (4570444, "telenor"),
+    (4570500, "telenor"),
+    (4570505, "telenor"),
+    (4570507, "telus aps"),
+    (4570555, "telenor"),
+    (4570666, "telenor"),
+    (4570770, "telenor"),
+    (4570776, "telenor"),
+    (4570777, "telenor"),
+    (4570778, "telenor"),
+    (4570810, "telenor"),
+    (4570811, "telenor"),
+    (4570812, "telenor"),
+    (4570813, "telenor"),
+    (4570814, "telenor"),
+    (4570815, "telenor"),
+    (4570816, "telenor"),
+    (4570817, "telenor"),
+    (4570818, "telenor"),
+    (4570828, "telenor"),
+    (4570838, "telenor"),
+    (4570848, "telenor"),
+    (4570858, "telenor"),
+    (4570868, "telenor"),
+    (4570900, "telenor"),
+    (4570907, "telus aps"),
+    (4570909, "telenor"),
+    (4570999, "telenor"),
+    (4571960, "tdc"),
+    (4581370, "telenor"),
+    (4581371, "clx networks ab"),
+    (4581372, "care solutions aka phone-it"),
+    (4581373, "tdc"),
+    (4581374, "mitto ag"),
+    (4581375, "monty uk global limited"),
+    (4581376, "icentrex lso(tdc)"),
+    (4581378, "mobiweb limited"),
+    (4581379, "telenor"),
+    (4591300, "maxtel.dk"),
+    (4591303, "maxtel.dk"),
+    (4670137, "42 Telecom AB"),
+    (4670189, "Alltele Sverige"),
+    (4670190, "Ventelo Sverige"),
+    (4670191, "Ventelo Sverige"),
+    (4670193, "Com Hem"),
+    (4670194, "Gotalandsnatet"),
+    (4670195, "Gotalandsnatet"),
+    (4670197, "Weblink IP Phone"),
+    (4670198, "IP-Only Telecommunication"),
+    (4672051, "WIFOG AB"),
+    (4672052, "WIFOG AB"),
+    (4672053, "WIFOG AB"),
+    (4672054, "WIFOG AB"),
+    (4672055, "Bahnhof AB"),
+    (4672056, "Bahnhof AB"),
+    (4672057, "WIFOG AB"),
+    (4672059, "Telenor Sverige"),
+    (4672081, "BM Sverige AB"),
+    (4672082, "Fibio Nordic AB"),
+    (4672083, "Tele2 Sverige"),
+    (4672084, "Tele2 Sverige"),
+    (4672085, "Tele2 Sverige"),
+    (4672087, "Telenor Sverige"),
+    (4672088, "Telenor Sverige"),
+    (4672092, "Telavox AB"),
+    (4672140, "Bredband 2"),
+    (4672141, "Tele2 Sverige"),
+    (4672142, "Tele2 Sverige"),
+    (4672143, "Tele2 Sverige"),
+    (4672144, "Tele2 Sverige"),
+    (4672145, "Tele2 Sverige"),
+    (4672146, "Tele2 Sverige"),
+    (4672147, "Tele2 Sverige"),
+    (4672148, "Tele2 Sverige"),
+    (4672341, "TELIGOO AB (Fello AB)"),
+    (4672342, "Telenor Sverige"),
+    (4672343, "MESSAGEBIRD B.V."),
+    (4672345, "42 Telecom AB"),
+    (4672347, "Benemen Oy"),
+    (4672348, "Benemen Oy"),
+    (4672401, "Telavox AB"),
+    (4672402, "Telavox AB"),
+    (4672409, "Telenor Sverige"),
+    (4672601, "Telavox AB"),
+    (4672603, "Tele2 Sverige"),
+    (4672604, "Tele2 Sverige"),
+    (4672605, "Tele2 Sverige"),
+    (4672606, "Tele2 Sverige"),
+    (4672607, "Tele2 Sverige"),
+    (4672608, "Tele2 Sverige"),
+    (4672640, "SPIRIUS AB"),
+    (4672641, "SPIRIUS AB"),
+    (4672644, "Telenor Sverige"),
+    (4672647, "Telenor Sverige"),
+    (4672648, "Telavox AB"),
+    (4672649, "Soatso AB"),
+    (4672660, "Telenor Sverige"),
+    (4672666, "Telenor Sverige"),
+    (4672667, "Telenor Sverige"),
+    (4672669, "Nortech"),
+    (4672695, "Telenor Sverige"),
+    (4672698, "SWEDFONENET AB"),
+    (4672753, "NETMORE GROUP AB"),
+    (4672754, "Telenor Sverige"),
+    (4672755, "FINK TELECOM SERVIC"),
+    (4672756, "FINK TELECOM SERVIC"),
+    (4672758, "Lancelot Telecom"),
+    (4672811, "Sappa"),
+    (4673120, "Telavox AB"),
+    (4673122, "EU Tel AB"),
+    (4673123, "42 Telecom AB"),
+    (4673125, "Telenor Sverige"),
+    (4673126, "Telenor Connexion"),
+    (4673127, "SWEDFONENET AB"),
+    (4673128, "SST Net Sverige AB"),
+    (4673129, "SPIRIUS AB"),
+    (4673171, "Ventelo Sverige"),
+    (4673173, "Svea Billing System"),
+    (4673174, "Svea Billing System"),
+    (4673175, "Svea Billing System"),
+    (4673176, "ID Mobile"),
+    (4673177, "SST Net Sverige AB"),
+    (4673178, "SST Net Sverige AB"),
+    (4673179, "SST Net Sverige AB"),
+    (4673450, "Telenor Sverige"),
+    (4673451, "CELLIP AB"),
+    (4673453, "TeliaSonera"),
+    (4673454, "Tele2 Sverige"),
+    (4673455, "Viatel Sweden"),
+    (4673456, "Svea Billing System"),
+    (4673457, "Telenor Sverige"),
+    (4673458, "Telenor Sverige"),
+    (4673459, "42 Telecom AB"),
+    (4673460, "Ventelo Sverige"),
+    (4673461, "Ventelo Sverige"),
+    (4673471, "Telenor Sverige"),
+    (4673472, "Telenor Sverige"),
+    (4673475, "Lycamobile Sweden"),
+    (4673476, "Lycamobile Sweden"),
+    (4673477, "Lycamobile Sweden"),
+    (4673478, "Lycamobile Sweden"),
+    (4673479, "Lycamobile Sweden"),
+    (4673860, "Telenor Sverige"),
+    (4673861, "Telenor Sverige"),
+    (4673862, "Telenor Sverige"),
+    (4673866, "Tele2 Sverige"),
+    (4673867, "Tele2 Sverige"),
+    (4673868, "Tele2 Sverige"),
+    (4676460, "Lycamobile Sweden"),
+    (4676461, "Lycamobile Sweden"),
+    (4676462, "Lycamobile Sweden"),
+    (4676463, "Lycamobile Sweden"),
+    (4676464, "Lycamobile Sweden"),
+    (4676466, "Gotalandsnatet"),
+    (4676467, "MERCURY INTERNATIONA"),
+    (4676468, "MERCURY INTERNATIONA"),
+    (4676469, "MERCURY INTERNATIONA"),
+    (4676478, "WIFOG AB"),
+    (4676479, "Beepsend"),
+    (4676492, "Telavox AB"),
+    (4676495, "Tele2 Sverige"),
+    (4676496, "Tele2 Sverige"),
+    (4676660, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676661, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676662, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676663, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676664, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676665, "NETETT SVERIGE AB (AINMT Sverige)"),
+    (4676666, "ÖRETEL AB"),
+    (4676667, "Unicorn Telecom"),
+    (4676668, "MERCURY INTERNATIONA"),
+    (4676670, "Svea Billing System"),
+    (4676671, "Svea Billing System"),
+    (4676672, "Svea Billing System"),
+    (4676673, "Svea Billing System"),
+    (4676674, "Svea Billing System"),
+    (4676677, "Telavox AB"),
+    (4676678, "SWEDFONENET AB"),
+    (4676692, "Voxbone"),
+    (4676694, "42 Telecom AB"),
+    (4676695, "Tele2 Sverige"),
+    (4676696, "Tele2 Sverige"),
+    (4676697, "Tele2 Sverige"),
+    (4676698, "Tele2 Sverige"),
+    (4676699, "Tele2 Sverige"),
+    (4676720, "Tele2 Sverige"),
+    (4676721, "Tele2 Sverige"),
+    (4676722, "Tele2 Sverige"),
+    (4676723, "Tele2 Sverige"),
+    (4676724, "Tele2 Sverige"),
+    (4676725, "Tele2 Sverige"),
+    (4676727, "Telenor Sverige"),
+    (4679000, "0700 LTD"),
+    (4679001, "EU Tel AB"),
+    (4679002, "0700 LTD"),
+    (4679003, "0700 LTD"),
+    (4679004, "0700 LTD"),
+    (4679006, "Telavox AB"),
+    (4679007, "FONIA AB"),
+    (4679008, "Voice Integrate"),
+    (4679009, "Soatso AB"),
+    (4679110, "Setera"),
+    (4679111, "Setera"),
+    (4679112, "Setera"),
+    (4679113, "Setera"),
+    (4679114, "Setera"),
+    (4679115, "Telavox AB"),
+    (4679550, "Soatso AB"),
+    (4679551, "Tele2 Sverige"),
+    (4679552, "Tele2 Sverige"),
+    (4679553, "Tele2 Sverige"),
+    (4679554, "Tele2 Sverige"),
+    (4679559, "Telenor Sverige"),
+    (4679580, "Borderlight"),
+    (4679581, "Borderlight"),
+    (4679585, "Telavox AB"),
+    (4679588, "Telenor Sverige"),
+    (4740820, "telia telecom solution"),
+    (4740821, "telia telecom solution"),
+    (4740822, "telia telecom solution"),
+    (4740823, "telia telecom solution"),
+    (4740824, "telia telecom solution"),
+    (4740920, "telia telecom solution"),
+    (4740921, "telia telecom solution"),
+    (4740922, "telia telecom solution"),
+    (4740923, "telia telecom solution"),
+    (4740924, "telia telecom solution"),
+    (4740925, "telenor norge"),
+    (4740926, "telenor norge"),
+    (4740927, "telenor norge"),
+    (4740928, "telenor norge"),
+    (4740929, "telenor norge"),
+    (4821130, "Plus"),
+    (4821131, "Polska Spoka Gazownictwa Sp"),
+    (4821132, "Polska Spoka Gazownictwa Sp"),
+    (4821133, "Polska Spoka Gazownictwa Sp"),
+    (4821134, "Polska Spoka Gazownictwa Sp"),
+    (4821135, "Polska Spoka Gazownictwa Sp"),
+    (4821136, "Plus"),
+    (4821137, "Plus"),
+    (4821138, "Plus"),
+    (4821139, "Plus"),
+    (4845910, "Play"),
+    (4845911, "Play"),
+    (4845912, "Play"),
+    (4845913, "Play"),
+    (4845914, "Play"),
+    (4845915, "Plus"),
+    (4845917, "Plus"),
+    (4845918, "Plus"),
+    (4845920, "SIA Ntel Solutions"),
+    (4845941, "Plus"),
+    (4845945, "Plus"),
+    (4845946, "Plus"),
+    (4845947, "Plus"),
+    (4845948, "Plus"),
+    (4845950, "SIA Ntel Solutions"),
+    (4845957, "BSG ESTONIA OU"),
+    (4857350, "Plus"),
+    (4857351, "UPC Polska"),
+    (4857352, "UPC Polska"),
+    (4857353, "UPC Polska"),
+    (4857354, "UPC Polska"),
+    (4857355, "UPC Polska"),
+    (4857356, "Plus"),
+    (4857357, "Orange"),
+    (4857358, "Plus"),
+    (4857359, "Plus"),
+    (4857940, "Play"),
+    (4857941, "Messagebird B.V."),
+    (4857942, "SIA NetBalt"),
+    (4857943, "Play"),
+    (4857944, "Play"),
+    (4857945, "Play"),
+    (4857946, "Plus"),
+    (4857947, "Plus"),
+    (4857948, "SIA Ntel Solutions"),
+    (4857949, "Plus"),
+    (4857950, "Plus"),
+    (4857953, "SIA NetBalt"),
+    (4857958, "NIMBUSFIVE GmbH"),
+    (4857970, "Play"),
+    (4857971, "UPC Polska"),
+    (4857972, "UPC Polska"),
+    (4857973, "UPC Polska"),
+    (4857974, "UPC Polska"),
+    (4857975, "UPC Polska"),
+    (4857976, "TELENABLER"),
+    (4857977, "IM Consulting Izabela"),
+    (4857978, "Plus"),
+    (4857979, "Plus"),
+    (4869901, "AMD Telecom S.A."),
+    (4869922, "Play"),
+    (4869950, "AMD Telecom S.A."),
+    (4869951, "Mobiledata Sp. z o.o."),
+    (4869952, "Mobiledata Sp. z o.o."),
+    (4869953, "Mobiledata Sp. z o.o."),
+    (4869954, "Mobiledata Sp. z o.o."),
+    (4869955, "Mobiledata Sp. z o.o."),
+    (4869956, "Twilio Ireland Limited"),
+    (4869957, "Softelnet S.A. Sp. k."),
+    (4869958, "IM Consulting Izabela"),
+    (4869959, "Move Telecom S.A."),
+    (4869960, "Play"),
+    (4869970, "Play"),
+    (4869974, "Compatel Limited"),
+    (4869978, "VOXBONE SA"),
+    (4869979, "Play"),
+    (4872970, "AMD Telecom S.A."),
+    (4872971, "Plus"),
+    (4872972, "Compatel Limited"),
+    (4872973, "Play"),
+    (4872974, "Play"),
+    (4872976, "Plus"),
+    (4872977, "Plus"),
+    (4872978, "Play"),
+    (4872979, "Play"),
+    (4872981, "Play"),
+    (4872982, "Play"),
+    (4872990, "TELCO LEADERS LTD"),
+    (4873930, "Play"),
+    (4873990, "Play"),
+    (4873991, "AGILE TELECOM POLAND"),
+    (4873993, "SIA NetBalt"),
+    (4873997, "Play"),
+    (4873998, "Play"),
+    (4873999, "Play"),
+    (4878020, "Plus"),
+    (4878025, "Interactive Digital Media GmbH"),
+    (4878026, "SIA NetBalt"),
+    (4878029, "SMSHIGHWAY LIMITED"),
+    (4878040, "Plus"),
+    (4878607, "Play"),
+    (4878608, "Play"),
+    (4878670, "Lancelot Telecom"),
+    (4878671, "Plus"),
+    (4878672, "Plus"),
+    (4878673, "Plus"),
+    (4878674, "Plus"),
+    (4878675, "Plus"),
+    (4878676, "Plus"),
+    (4878678, "Play"),
+    (4878679, "Plus"),
+    (4888441, "Plus"),
+    (4888442, "Plus"),
+    (4888443, "Plus"),
+    (4888444, "CARITAS"),
+    (4888446, "Plus"),
+    (4888447, "Plus"),
+    (4888448, "Plus"),
+    (4888449, "Plus"),
+    (4915019, "Tismi BV"),
+    (4915020, "Interactive digital media"),
+    (4915050, "NAKA AG"),
+    (4915080, "Easy World"),
+    (4915510, "LEBARA"),
+    (4915511, "LEBARA"),
+    (4915630, "Multiconnect"),
+    (4915678, "Argon Networks"),
+    (4915888, "TelcoVillage"),
+    (5023229, "Telgua"),
+    (5024476, "Tigo"),
+    (5024477, "Tigo"),
+    (5024478, "Tigo"),
+    (5024479, "Tigo"),
+    (5025310, "Telgua"),
+    (5025311, "Telgua"),
+    (5025312, "Movistar"),
+    (5025313, "Movistar"),
+    (5025518, "Movistar"),
+    (5025519, "Movistar"),
+    (5025531, "Telgua"),
+    (5025532, "Telgua"),
+    (5025533, "Telgua"),
+    (5025534, "Telgua"),
+    (5025535, "Telgua"),
+    (5025536, "Telgua"),
+    (5025537, "Telgua"),
+    (5025538, "Telgua"),
+    (5025539, "Telgua"),
+    (5025543, "Telgua"),
+    (5025544, "Telgua"),
+    (5025550, "Tigo"),
+    (5025551, "Tigo"),
+    (5025552, "Tigo"),
+    (5025553, "Tigo"),
+    (5025580, "Tigo"),
+    (5025581, "Tigo"),
+    (5025819, "Tigo"),
+    (5025915, "Movistar"),
+    (5025916, "Movistar"),
+    (5025917, "Movistar"),
+    (5025918, "Tigo"),
+    (5025919, "Tigo"),
+    (5035000, "Claro"),
+    (5035001, "Claro"),
+    (5035002, "Claro"),
+    (5035003, "Claro"),
+    (5035004, "Claro"),
+    (5035100, "Tigo"),
+    (5036310, "Claro"),
+    (5036311, "Claro"),
+    (5036312, "Claro"),
+    (5036313, "Claro"),
+    (5036314, "Claro"),
+    (5036315, "Claro"),
+    (5036316, "Claro"),
+    (5036430, "Movistar"),
+    (5036431, "Movistar"),
+    (5037072, "Digicel"),
+    (5037076, "SIGET"),
+    (5037077, "SIGET"),
+    (5037078, "SIGET"),
+    (5037079, "SIGET"),
+    (5037691, "Movistar"),
+    (5037692, "Movistar"),
+    (5037693, "Movistar"),
+    (5037694, "Movistar"),
+    (5037695, "Digicel"),
+    (5037696, "Digicel"),
+    (5037697, "Digicel"),
+    (5037698, "Digicel"),
+    (5037699, "Movistar"),
+    (5037780, "Movistar"),
+    (5037781, "Movistar"),
+    (5037782, "Movistar"),
+    (5037783, "Movistar"),
+    (5037784, "Movistar"),
+    (5037785, "Tigo"),
+    (5037786, "Tigo"),
+    (5037787, "Tigo"),
+    (5037788, "Tigo"),
+    (5037789, "Tigo"),
+    (5037790, "Movistar"),
+    (5037791, "Movistar"),
+    (5037792, "Movistar"),
+    (5037793, "Movistar"),
+    (5037794, "Movistar"),
+    (5037795, "Tigo"),
+    (5037796, "Tigo"),
+    (5037797, "Tigo"),
+    (5037798, "Tigo"),
+    (5037799, "Tigo"),
+    (5037800, "Movistar"),
+    (5037801, "Digicel"),
+    (5037803, "Claro"),
+    (5037805, "Claro"),
+    (5037806, "Claro"),
+    (5037807, "Claro"),
+    (5037808, "Claro"),
+    (5037809, "Claro"),
+    (5037840, "Claro"),
+    (5037841, "Claro"),
+    (5037842, "Claro"),
+    (5037843, "Claro"),
+    (5037844, "Claro"),
+    (5037845, "Movistar"),
+    (5037846, "Movistar"),
+    (5037847, "Movistar"),
+    (5037848, "Movistar"),
+    (5037849, "Movistar"),
+    (5037980, "Intelfon"),
+    (5037981, "Intelfon"),
+    (5037982, "Intelfon"),
+    (5037983, "Intelfon"),
+    (5037984, "Intelfon"),
+    (5037985, "Claro"),
+    (5037986, "Claro"),
+    (5037987, "Claro"),
+    (5037988, "Claro"),
+    (5037989, "Claro"),
+    (5067000, "Claro"),
+    (5067002, "Claro"),
+    (5067003, "Claro"),
+    (5067004, "Claro"),
+    (5067005, "Claro"),
+    (5067006, "Claro"),
+    (5067007, "Claro"),
+    (5067008, "Claro"),
+    (5067009, "Claro"),
+    (5067300, "Claro"),
+    (5067301, "Claro"),
+    (5191920, "Claro"),
+    (5191921, "Claro"),
+    (5191922, "Claro"),
+    (5191923, "Claro"),
+    (5191924, "Claro"),
+    (5191925, "Claro"),
+    (5191926, "Claro"),
+    (5191927, "Claro"),
+    (5192636, "Dolphin Telecom"),
+    (5192637, "Dolphin Telecom"),
+    (5192638, "Entel"),
+    (5192639, "Entel"),
+    (5193730, "Entel"),
+    (5193731, "Entel"),
+    (5193732, "Entel"),
+    (5193733, "Entel"),
+    (5194680, "Movistar"),
+    (5194681, "Movistar"),
+    (5194682, "Movistar"),
+    (5194683, "Movistar"),
+    (5194805, "Claro"),
+    (5194806, "Claro"),
+    (5194807, "Claro"),
+    (5194808, "Claro"),
+    (5194809, "Claro"),
+    (5194907, "Claro"),
+    (5194908, "Claro"),
+    (5194909, "Claro"),
+    (5194978, "Movistar"),
+    (5194979, "Movistar"),
+    (5194990, "Movistar"),
+    (5194991, "Movistar"),
+    (5194992, "Movistar"),
+    (5194993, "Movistar"),
+    (5194994, "Movistar"),
+    (5194995, "Movistar"),
+    (5194996, "Movistar"),
+    (5194997, "Movistar"),
+    (5194999, "Movistar"),
+    (5195010, "Entel"),
+    (5195210, "Entel"),
+    (5195270, "Claro"),
+    (5195271, "Claro"),
+    (5195272, "Claro"),
+    (5195310, "Entel"),
+    (5195420, "Claro"),
+    (5195430, "Claro"),
+    (5195805, "Claro"),
+    (5195806, "Claro"),
+    (5195807, "Claro"),
+    (5195808, "Claro"),
+    (5195809, "Claro"),
+    (5195847, "Claro"),
+    (5195848, "Claro"),
+    (5195849, "Claro"),
+    (5195895, "Claro"),
+    (5195896, "Claro"),
+    (5195897, "Claro"),
+    (5195898, "Claro"),
+    (5195899, "Claro"),
+    (5196004, "Claro"),
+    (5196005, "Claro"),
+    (5196006, "Claro"),
+    (5196007, "Claro"),
+    (5196008, "Claro"),
+    (5196009, "Claro"),
+    (5196350, "Movistar"),
+    (5196351, "Movistar"),
+    (5196352, "Movistar"),
+    (5196353, "Movistar"),
+    (5196354, "Movistar"),
+    (5196396, "Entel"),
+    (5196397, "Entel"),
+    (5196765, "Movistar"),
+    (5196766, "Movistar"),
+    (5196768, "Movistar"),
+    (5196769, "Movistar"),
+    (5196790, "Movistar"),
+    (5196791, "Movistar"),
+    (5196798, "Movistar"),
+    (5196799, "Movistar"),
+    (5196820, "Entel"),
+    (5196821, "Claro"),
+    (5197250, "Movistar"),
+    (5197251, "Movistar"),
+    (5197252, "Movistar"),
+    (5197410, "Entel"),
+    (5197487, "Movistar"),
+    (5197488, "Movistar"),
+    (5197489, "Movistar"),
+    (5197820, "Claro"),
+    (5197821, "Entel"),
+    (5198000, "Entel"),
+    (5198001, "Entel"),
+    (5198002, "Entel"),
+    (5198260, "Movistar"),
+    (5198261, "Movistar"),
+    (5198268, "Movistar"),
+    (5198298, "Movistar"),
+    (5511971, "Vivo"),
+    (5511972, "Vivo"),
+    (5511973, "Vivo"),
+    (5511974, "Vivo"),
+    (5511975, "Vivo"),
+    (5511976, "Claro"),
+    (5511981, "TIM"),
+    (5511982, "TIM"),
+    (5511983, "TIM"),
+    (5511984, "TIM"),
+    (5511985, "TIM"),
+    (5511986, "TIM"),
+    (5511987, "TIM"),
+    (5511988, "Claro"),
+    (5511989, "Claro"),
+    (5511991, "Claro"),
+    (5511992, "Claro"),
+    (5511993, "Claro"),
+    (5511994, "Claro"),
+    (5511995, "Vivo"),
+    (5511996, "Vivo"),
+    (5511997, "Vivo"),
+    (5511998, "Vivo"),
+    (5511999, "Vivo"),
+    (5512991, "Claro"),
+    (5512997, "Vivo"),
+    (5513991, "Claro"),
+    (5513997, "Vivo"),
+    (5514996, "Vivo"),
+    (5514997, "Vivo"),
+    (5515997, "Vivo"),
+    (5516991, "Claro"),
+    (5516992, "Claro"),
+    (5517991, "Claro"),
+    (5519981, "TIM"),
+    (5519991, "Claro"),
+    (5519992, "Claro"),
+    (5519993, "Claro"),
+    (5519994, "Claro"),
+    (5519998, "Vivo"),
+    (5521971, "Vivo"),
+    (5521972, "Vivo"),
+    (5521974, "Claro"),
+    (5521975, "Claro"),
+    (5521976, "Claro"),
+    (5521981, "TIM"),
+    (5521982, "TIM"),
+    (5521985, "Oi"),
+    (5521986, "Oi"),
+    (5521987, "Oi"),
+    (5521988, "Oi"),
+    (5521989, "Oi"),
+    (5521991, "Claro"),
+    (5521992, "Claro"),
+    (5521993, "Claro"),
+    (5521994, "Claro"),
+    (5521995, "Vivo"),
+    (5521996, "Vivo"),
+    (5521997, "Vivo"),
+    (5521998, "Vivo"),
+    (5521999, "Vivo"),
+    (5522985, "Oi"),
+    (5522986, "Oi"),
+    (5522987, "Oi"),
+    (5522988, "Oi"),
+    (5522989, "Oi"),
+    (5522998, "Vivo"),
+    (5522999, "Vivo"),
+    (5524985, "Oi"),
+    (5524986, "Oi"),
+    (5524987, "Oi"),
+    (5524988, "Oi"),
+    (5524989, "Oi"),
+    (5524997, "Vivo"),
+    (5524998, "Vivo"),
+    (5527985, "Oi"),
+    (5527986, "Oi"),
+    (5527987, "Oi"),
+    (5527988, "Oi"),
+    (5527989, "Oi"),
+    (5527997, "Vivo"),
+    (5527998, "Vivo"),
+    (5527999, "Vivo"),
+    (5528985, "Oi"),
+    (5528986, "Oi"),
+    (5528987, "Oi"),
+    (5528988, "Oi"),
+    (5528989, "Oi"),
+    (5531983, "Claro"),
+    (5531984, "Claro"),
+    (5531985, "Oi"),
+    (5531986, "Oi"),
+    (5531987, "Oi"),
+    (5531988, "Oi"),
+    (5531989, "Oi"),
+    (5531992, "TIM"),
+    (5531993, "TIM"),
+    (5531998, "Telemig Celular"),
+    (5532985, "Oi"),
+    (5532986, "Oi"),
+    (5532987, "Oi"),
+    (5532988, "Oi"),
+    (5532989, "Oi"),
+    (5533985, "Oi"),
+    (5533986, "Oi"),
+    (5533987, "Oi"),
+    (5533988, "Oi"),
+    (5533989, "Oi"),
+    (5534985, "Oi"),
+    (5534986, "Oi"),
+    (5534987, "Oi"),
+    (5534988, "Oi"),
+    (5534989, "Oi"),
+    (5535985, "Oi"),
+    (5535986, "Oi"),
+    (5535987, "Oi"),
+    (5535988, "Oi"),
+    (5535989, "Oi"),
+    (5537985, "Oi"),
+    (5537986, "Oi"),
+    (5537987, "Oi"),
+    (5537988, "Oi"),
+    (5537989, "Oi"),
+    (5538985, "Oi"),
+    (5538986, "Oi"),
+    (5538987, "Oi"),
+    (5538988, "Oi"),
+    (5538989, "Oi"),
+    (5541988, "Claro"),
+    (5541996, "TIM"),
+    (5541999, "TIM"),
+    (5544999, "TIM"),
+    (5547988, "Claro"),
+    (5547991, "Vivo"),
+    (5551981, "TIM"),
+    (5551984, "Brasil Telecom GSM"),
+    (5551985, "Brasil Telecom GSM"),
+    (5551991, "Claro"),
+    (5551992, "Claro"),
+    (5551993, "Claro"),
+    (5551996, "Vivo"),
+    (5551997, "Vivo"),
+    (5551998, "Vivo"),
+    (5551999, "Vivo"),
+    (5554991, "Claro"),
+    (5555991, "Claro"),
+    (5555996, "Vivo"),
+    (5561984, "Brasil Telecom GSM"),
+    (5562984, "Brasil Telecom GSM"),
+    (5562985, "Brasil Telecom GSM"),
+    (5566996, "Vivo"),
+    (5567996, "Vivo"),
+    (5567999, "Vivo"),
+    (5571981, "Claro"),
+    (5571982, "Claro"),
+    (5571985, "Oi"),
+    (5571986, "Oi"),
+    (5571987, "Oi"),
+    (5571988, "Oi"),
+    (5571989, "Oi"),
+    (5571991, "TIM"),
+    (5574985, "Oi"),
+    (5574986, "Oi"),
+    (5574987, "Oi"),
+    (5574988, "Oi"),
+    (5574989, "Oi"),
+    (5575981, "Claro"),
+    (5575985, "Oi"),
+    (5575986, "Oi"),
+    (5575987, "Oi"),
+    (5575988, "Oi"),
+    (5575989, "Oi"),
+    (5577985, "Oi"),
+    (5577986, "Oi"),
+    (5577987, "Oi"),
+    (5577988, "Oi"),
+    (5577989, "Oi"),
+    (5579985, "Oi"),
+    (5579986, "Oi"),
+    (5579987, "Oi"),
+    (5579988, "Oi"),
+    (5579989, "Oi"),
+    (5579998, "Vivo"),
+    (5579999, "Vivo"),
+    (5581981, "Vivo"),
+    (5581985, "Oi"),
+    (5581986, "Oi"),
+    (5581987, "Oi"),
+    (5581988, "Oi"),
+    (5581989, "Oi"),
+    (5581991, "Claro"),
+    (5581992, "Claro"),
+    (5581994, "Claro"),
+    (5581996, "TIM"),
+    (5581999, "TIM"),
+    (5582985, "Oi"),
+    (5582986, "Oi"),
+    (5582987, "Oi"),
+    (5582988, "Oi"),
+    (5582989, "Oi"),
+    (5582991, "Claro"),
+    (5583985, "Oi"),
+    (5583986, "Oi"),
+    (5583987, "Oi"),
+    (5583988, "Oi"),
+    (5583989, "Oi"),
+    (5583991, "Claro"),
+    (5584985, "Oi"),
+    (5584986, "Oi"),
+    (5584987, "Oi"),
+    (5584988, "Oi"),
+    (5584989, "Oi"),
+    (5584991, "Claro"),
+    (5584999, "TIM"),
+    (5585981, "Vivo"),
+    (5585985, "Oi"),
+    (5585986, "Oi"),
+    (5585987, "Oi"),
+    (5585988, "Oi"),
+    (5585989, "Oi"),
+    (5585991, "Claro"),
+    (5586985, "Oi"),
+    (5586986, "Oi"),
+    (5586987, "Oi"),
+    (5586988, "Oi"),
+    (5586989, "Oi"),
+    (5586994, "Claro"),
+    (5587985, "Oi"),
+    (5587986, "Oi"),
+    (5587987, "Oi"),
+    (5587988, "Oi"),
+    (5587989, "Oi"),
+    (5588985, "Oi"),
+    (5588986, "Oi"),
+    (5588987, "Oi"),
+    (5588988, "Oi"),
+    (5588989, "Oi"),
+    (5588992, "Claro"),
+    (5589985, "Oi"),
+    (5589986, "Oi"),
+    (5589987, "Oi"),
+    (5589988, "Oi"),
+    (5589989, "Oi"),
+    (5591981, "TIM"),
+    (5591985, "Oi"),
+    (5591986, "Oi"),
+    (5591987, "Oi"),
+    (5591988, "Oi"),
+    (5591989, "Oi"),
+    (5591991, "Vivo"),
+    (5592981, "TIM"),
+    (5592985, "Oi"),
+    (5592986, "Oi"),
+    (5592987, "Oi"),
+    (5592988, "Oi"),
+    (5592989, "Oi"),
+    (5592991, "Vivo"),
+    (5592992, "Vivo"),
+    (5593985, "Oi"),
+    (5593986, "Oi"),
+    (5593987, "Oi"),
+    (5593988, "Oi"),
+    (5593989, "Oi"),
+    (5594985, "Oi"),
+    (5594986, "Oi"),
+    (5594987, "Oi"),
+    (5594988, "Oi"),
+    (5594989, "Oi"),
+    (5594991, "Vivo"),
+    (5595985, "Oi"),
+    (5595986, "Oi"),
+    (5595987, "Oi"),
+    (5595988, "Oi"),
+    (5595989, "Oi"),
+    (5596985, "Oi"),
+    (5596986, "Oi"),
+    (5596987, "Oi"),
+    (5596988, "Oi"),
+    (5596989, "Oi"),
+    (5597985, "Oi"),
+    (5597986, "Oi"),
+    (5597987, "Oi"),
+    (5597988, "Oi"),
+    (5597989, "Oi"),
+    (5598985, "Oi"),
+    (5598986, "Oi"),
+    (5598987, "Oi"),
+    (5598988, "Oi"),
+    (5598989, "Oi"),
+    (5599985, "Oi"),
+    (5599986, "Oi"),
+    (5599987, "Oi"),
+    (5599988, "Oi"),
+    (5599989, "Oi"),
+    (5622290, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622291, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622292, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622293, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622294, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622295, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622296, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622297, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622299, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622300, "Entel"),
+    (5622301, "Entel"),
+    (5622302, "Entel"),
+    (5622303, "Entel"),
+    (5622304, "Cibeles Telecom"),
+    (5622306, "Gtd Manquehue S.A."),
+    (5622307, "Gtd Manquehue S.A."),
+    (5622308, "Entel"),
+    (5622323, "Vtr Banda Ancha (Chile) S.A."),
+    (5622324, "Vtr Banda Ancha (Chile) S.A."),
+    (5622325, "Vtr Banda Ancha (Chile) S.A."),
+    (5622326, "Vtr Banda Ancha (Chile) S.A."),
+    (5622356, "Vtr Banda Ancha (Chile) S.A."),
+    (5622357, "Vtr Banda Ancha (Chile) S.A."),
+    (5622358, "Vtr Banda Ancha (Chile) S.A."),
+    (5622359, "Vtr Banda Ancha (Chile) S.A."),
+    (5622400, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622401, "Claro"),
+    (5622402, "Claro"),
+    (5622403, "Claro"),
+    (5622404, "Claro"),
+    (5622405, "Claro"),
+    (5622407, "Entel"),
+    (5622408, "Entel"),
+    (5622409, "Entel"),
+    (5622410, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622411, "Gtd Telesat S.A."),
+    (5622412, "Gtd Telesat S.A."),
+    (5622413, "Gtd Telesat S.A."),
+    (5622414, "Telefonica Del Sur S.A."),
+    (5622415, "Vtr Banda Ancha (Chile) S.A."),
+    (5622416, "Vtr Banda Ancha (Chile) S.A."),
+    (5622417, "Vtr Banda Ancha (Chile) S.A."),
+    (5622418, "Vtr Banda Ancha (Chile) S.A."),
+    (5622419, "Vtr Banda Ancha (Chile) S.A."),
+    (5622450, "Entel"),
+    (5622451, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5622452, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5622453, "Vtr Banda Ancha (Chile) S.A."),
+    (5622454, "Vtr Banda Ancha (Chile) S.A."),
+    (5622455, "Vtr Banda Ancha (Chile) S.A."),
+    (5622457, "Vtr Banda Ancha (Chile) S.A."),
+    (5622458, "Vtr Banda Ancha (Chile) S.A."),
+    (5622459, "Vtr Banda Ancha (Chile) S.A."),
+    (5622474, "Vtr Banda Ancha (Chile) S.A."),
+    (5622475, "Vtr Banda Ancha (Chile) S.A."),
+    (5622480, "Entel"),
+    (5622481, "Entel"),
+    (5622491, "Vtr Banda Ancha (Chile) S.A."),
+    (5622492, "Vtr Banda Ancha (Chile) S.A."),
+    (5622493, "Vtr Banda Ancha (Chile) S.A."),
+    (5622494, "Vtr Banda Ancha (Chile) S.A."),
+    (5622500, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622501, "Vtr Banda Ancha (Chile) S.A."),
+    (5622502, "Vtr Banda Ancha (Chile) S.A."),
+    (5622503, "Vtr Banda Ancha (Chile) S.A."),
+    (5622504, "Vtr Banda Ancha (Chile) S.A."),
+    (5622505, "Claro"),
+    (5622506, "Claro"),
+    (5622508, "Vtr Banda Ancha (Chile) S.A."),
+    (5622509, "Vtr Banda Ancha (Chile) S.A."),
+    (5622530, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622531, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622532, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622533, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622534, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622535, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622537, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622538, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622539, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622560, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622561, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622562, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622563, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622564, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622565, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622566, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622568, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622569, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622570, "Claro"),
+    (5622571, "Claro"),
+    (5622572, "Will S.A."),
+    (5622587, "Gtd Telesat S.A."),
+    (5622588, "Chile.Com"),
+    (5622589, "Gtd Telesat S.A."),
+    (5622590, "Ifx Networks Chile S.A."),
+    (5622591, "Gtd Telesat S.A."),
+    (5622592, "Gtd Telesat S.A."),
+    (5622593, "Will S.A."),
+    (5622594, "Claro"),
+    (5622595, "Claro"),
+    (5622596, "Entel"),
+    (5622597, "Entel"),
+    (5622598, "Gtd Telesat S.A."),
+    (5622599, "Gtd Telesat S.A."),
+    (5622601, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622603, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622604, "Entel"),
+    (5622605, "Entel"),
+    (5622606, "Entel"),
+    (5622607, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622608, "Entel"),
+    (5622609, "Entel"),
+    (5622610, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622611, "Entel"),
+    (5622612, "Entel"),
+    (5622613, "Claro"),
+    (5622614, "Claro"),
+    (5622615, "Claro"),
+    (5622616, "Gtd Telesat S.A."),
+    (5622617, "Gtd Telesat S.A."),
+    (5622618, "Gtd Telesat S.A."),
+    (5622619, "Claro"),
+    (5622656, "Netline"),
+    (5622657, "Fullcom S.A."),
+    (5622658, "Fullcom S.A."),
+    (5622659, "Claro"),
+    (5622666, "Claro"),
+    (5622707, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622710, "Vtr Banda Ancha (Chile) S.A."),
+    (5622712, "Quantax"),
+    (5622713, "Gtd Telesat S.A."),
+    (5622714, "Claro"),
+    (5622715, "Gtd Telesat S.A."),
+    (5622716, "Vtr Banda Ancha (Chile) S.A."),
+    (5622717, "Vtr Banda Ancha (Chile) S.A."),
+    (5622718, "Claro"),
+    (5622719, "Claro"),
+    (5622720, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622721, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622722, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622723, "Vtr Banda Ancha (Chile) S.A."),
+    (5622724, "Vtr Banda Ancha (Chile) S.A."),
+    (5622725, "Vtr Banda Ancha (Chile) S.A."),
+    (5622726, "Gtd Telesat S.A."),
+    (5622727, "Vtr Banda Ancha (Chile) S.A."),
+    (5622728, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622729, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622760, "Convergia Telecom S.A."),
+    (5622761, "Vtr Banda Ancha (Chile) S.A."),
+    (5622769, "Vtr Banda Ancha (Chile) S.A."),
+    (5622784, "Entel"),
+    (5622785, "Vtr Banda Ancha (Chile) S.A."),
+    (5622788, "Will S.A."),
+    (5622789, "Vtr Banda Ancha (Chile) S.A."),
+    (5622790, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622791, "Vtr Banda Ancha (Chile) S.A."),
+    (5622792, "Vtr Banda Ancha (Chile) S.A."),
+    (5622793, "Vtr Banda Ancha (Chile) S.A."),
+    (5622794, "Rural Telecommunications Chile S.A."),
+    (5622795, "Entel"),
+    (5622796, "Entel"),
+    (5622797, "Gtd Telesat S.A."),
+    (5622798, "Entel"),
+    (5622799, "Entel"),
+    (5622800, "Entel"),
+    (5622807, "Claro"),
+    (5622808, "Claro"),
+    (5622809, "Claro"),
+    (5622813, "Vtr Banda Ancha (Chile) S.A."),
+    (5622816, "Entel"),
+    (5622820, "Entel"),
+    (5622821, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622822, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622823, "Vtr Banda Ancha (Chile) S.A."),
+    (5622824, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622825, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622826, "Gtd Telesat S.A."),
+    (5622827, "Gtd Telesat S.A."),
+    (5622828, "Gtd Telesat S.A."),
+    (5622829, "Gtd Telesat S.A."),
+    (5622830, "Claro"),
+    (5622833, "Vtr Banda Ancha (Chile) S.A."),
+    (5622839, "Vtr Banda Ancha (Chile) S.A."),
+    (5622840, "Telestar"),
+    (5622848, "Vtr Banda Ancha (Chile) S.A."),
+    (5622860, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622861, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622862, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622863, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622864, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622865, "Netel S.A."),
+    (5622866, "Claro"),
+    (5622867, "Claro"),
+    (5622868, "Will S.A."),
+    (5622869, "Netline"),
+    (5622877, "Vtr Banda Ancha (Chile) S.A."),
+    (5622878, "Vtr Banda Ancha (Chile) S.A."),
+    (5622880, "Vtr Banda Ancha (Chile) S.A."),
+    (5622881, "Vtr Banda Ancha (Chile) S.A."),
+    (5622882, "Pronto Ip Ltda."),
+    (5622883, "Gtd Manquehue S.A."),
+    (5622884, "Vtr Banda Ancha (Chile) S.A."),
+    (5622885, "Vtr Banda Ancha (Chile) S.A."),
+    (5622886, "Vtr Banda Ancha (Chile) S.A."),
+    (5622887, "Gtd Telesat S.A."),
+    (5622888, "Claro"),
+    (5622889, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622891, "Vtr Banda Ancha (Chile) S.A."),
+    (5622892, "Gtd Telesat S.A."),
+    (5622893, "Vtr Banda Ancha (Chile) S.A."),
+    (5622894, "Vtr Banda Ancha (Chile) S.A."),
+    (5622895, "Vtr Banda Ancha (Chile) S.A."),
+    (5622896, "Entel"),
+    (5622897, "Claro"),
+    (5622898, "Claro"),
+    (5622899, "Claro"),
+    (5622900, "Gtd Manquehue S.A."),
+    (5622901, "Gtd Telesat S.A."),
+    (5622902, "Claro"),
+    (5622903, "Claro"),
+    (5622904, "Claro"),
+    (5622905, "Vtr Banda Ancha (Chile) S.A."),
+    (5622906, "Vtr Banda Ancha (Chile) S.A."),
+    (5622907, "Vtr Banda Ancha (Chile) S.A."),
+    (5622908, "Vtr Banda Ancha (Chile) S.A."),
+    (5622909, "Vtr Banda Ancha (Chile) S.A."),
+    (5622910, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622912, "Entel"),
+    (5622913, "Entel"),
+    (5622914, "Movistar"),
+    (5622915, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622916, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622917, "Will S.A."),
+    (5622918, "Vtr Banda Ancha (Chile) S.A."),
+    (5622919, "Vtr Banda Ancha (Chile) S.A."),
+    (5622920, "Vtr Banda Ancha (Chile) S.A."),
+    (5622921, "Claro"),
+    (5622926, "Claro"),
+    (5622929, "Will S.A."),
+    (5622930, "Will S.A."),
+    (5622931, "Claro"),
+    (5622932, "Claro"),
+    (5622933, "Vtr Banda Ancha (Chile) S.A."),
+    (5622934, "Vtr Banda Ancha (Chile) S.A."),
+    (5622937, "Entel"),
+    (5622939, "Gtd Telesat S.A."),
+    (5622962, "Telefonica Del Sur S.A."),
+    (5622963, "Entel"),
+    (5622964, "Entel"),
+    (5622965, "Entel"),
+    (5622966, "Claro"),
+    (5622967, "Claro"),
+    (5622968, "Claro"),
+    (5622969, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622970, "Claro"),
+    (5622971, "Claro"),
+    (5622972, "Convergia Telecom S.A."),
+    (5622973, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622974, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622975, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622976, "Compania De Telecomunicaciones De Chile S.A."),
+    (5622977, "Claro"),
+    (5622978, "Claro"),
+    (5622979, "Claro"),
+    (5622987, "Claro"),
+    (5622988, "Gtd Telesat S.A."),
+    (5622989, "Gtd Telesat S.A."),
+    (5622993, "Will S.A."),
+    (5622994, "Telecomunicaciones Net Uno Ltda."),
+    (5622995, "Claro"),
+    (5622996, "Claro"),
+    (5622997, "Will S.A."),
+    (5622998, "Claro"),
+    (5622999, "Compania De Telecomunicaciones De Chile S.A."),
+    (5623210, "Redvoiss"),
+    (5623211, "Claro"),
+    (5623212, "Claro"),
+    (5623213, "Gtd Manquehue S.A."),
+    (5623215, "Cibeles Telecom"),
+    (5623216, "Entel"),
+    (5623217, "Entel"),
+    (5623218, "Entel"),
+    (5623219, "Entel"),
+    (5623236, "Servicios Internet Limitada"),
+    (5623237, "Claro"),
+    (5623238, "Claro"),
+    (5623239, "Claro"),
+    (5623240, "Claro"),
+    (5623241, "Claro"),
+    (5623260, "Entel"),
+    (5623261, "Entel"),
+    (5623262, "Gtd Manquehue S.A."),
+    (5623263, "Gtd Manquehue S.A."),
+    (5623270, "Vtr Comunicaciones Spa."),
+    (5623271, "Vtr Comunicaciones Spa."),
+    (5623272, "Optic Telecomunicaciones Ltda."),
+    (5623321, "Movistar"),
+    (5623322, "Movistar"),
+    (5623323, "Gtd Manquehue S.A."),
+    (5623324, "Gtd Manquehue S.A."),
+    (5623325, "Gtd Telesat S.A."),
+    (5623326, "Gtd Telesat S.A."),
+    (5623327, "Claro"),
+    (5623328, "Claro"),
+    (5623329, "Claro"),
+    (5623330, "Claro"),
+    (5623331, "Linksat Comunicaciones Spa."),
+    (5623332, "Linksat Comunicaciones Spa."),
+    (5623333, "Entel"),
+    (5623334, "Claro"),
+    (5623335, "Claro"),
+    (5623336, "Claro"),
+    (5623337, "Claro"),
+    (5623338, "Claro"),
+    (5623348, "Claro"),
+    (5623349, "Claro"),
+    (5623350, "Claro"),
+    (5623351, "Claro"),
+    (5623352, "Claro"),
+    (5623370, "Movistar"),
+    (5623371, "Movistar"),
+    (5623372, "Movistar"),
+    (5623373, "Movistar"),
+    (5623380, "Claro"),
+    (5623381, "Claro"),
+    (5623382, "Claro"),
+    (5623383, "Claro"),
+    (5632217, "Entel"),
+    (5632218, "Entel"),
+    (5632219, "Entel"),
+    (5632230, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632233, "Vtr Banda Ancha (Chile) S.A."),
+    (5632234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632235, "Gtd Telesat S.A."),
+    (5632237, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632238, "Gtd Telesat S.A."),
+    (5632239, "Vtr Banda Ancha (Chile) S.A."),
+    (5632240, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632241, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632242, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632243, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632244, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632245, "Entel"),
+    (5632246, "Entel"),
+    (5632247, "Vtr Banda Ancha (Chile) S.A."),
+    (5632248, "Vtr Banda Ancha (Chile) S.A."),
+    (5632249, "Vtr Banda Ancha (Chile) S.A."),
+    (5632252, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632254, "Entel"),
+    (5632255, "Entel"),
+    (5632271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632272, "Vtr Banda Ancha (Chile) S.A."),
+    (5632273, "Vtr Banda Ancha (Chile) S.A."),
+    (5632274, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632275, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632276, "Claro"),
+    (5632277, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632278, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632279, "Compania De Telecomunicaciones De Chile S.A."),
+    (5632290, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5632296, "Vtr Banda Ancha (Chile) S.A."),
+    (5632311, "Entel"),
+    (5632312, "Entel"),
+    (5632314, "Claro"),
+    (5632315, "Vtr Banda Ancha (Chile) S.A."),
+    (5632316, "Vtr Banda Ancha (Chile) S.A."),
+    (5632317, "Vtr Banda Ancha (Chile) S.A."),
+    (5632318, "Vtr Banda Ancha (Chile) S.A."),
+    (5632319, "Vtr Banda Ancha (Chile) S.A."),
+    (5632320, "Vtr Banda Ancha (Chile) S.A."),
+    (5632321, "Claro"),
+    (5632322, "Claro"),
+    (5632323, "Convergia Telecom S.A."),
+    (5632324, "Claro"),
+    (5632325, "Claro"),
+    (5632326, "Entel"),
+    (5632327, "Vtr Banda Ancha (Chile) S.A."),
+    (5632328, "Vtr Banda Ancha (Chile) S.A."),
+    (5632329, "Cibeles Telecom"),
+    (5632330, "Cibeles Telecom"),
+    (5632331, "Entel"),
+    (5632332, "Entel"),
+    (5632333, "Claro"),
+    (5632334, "Claro"),
+    (5632335, "Vtr Banda Ancha (Chile) S.A."),
+    (5632336, "Vtr Banda Ancha (Chile) S.A."),
+    (5632337, "Vtr Banda Ancha (Chile) S.A."),
+    (5632338, "Claro"),
+    (5632339, "Claro"),
+    (5632340, "Claro"),
+    (5632341, "Claro"),
+    (5632342, "Entel"),
+    (5632343, "Entel"),
+    (5632344, "Entel"),
+    (5632345, "Entel"),
+    (5632346, "Entel"),
+    (5632347, "Claro"),
+    (5632348, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (5632349, "Servicios Internet Limitada"),
+    (5632377, "Telestar"),
+    (5632380, "Movistar"),
+    (5632381, "Gtd Telesat S.A."),
+    (5632383, "Linksat Comunicaciones Spa."),
+    (5632384, "Linksat Comunicaciones Spa."),
+    (5632385, "Gtd Manquehue S.A."),
+    (5632386, "Gtd Manquehue S.A."),
+    (5633220, "Entel"),
+    (5633223, "Claro"),
+    (5633224, "Claro"),
+    (5633225, "Vtr Banda Ancha (Chile) S.A."),
+    (5633231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633233, "Entel"),
+    (5633234, "Entel"),
+    (5633235, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5633236, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5633237, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5633238, "Entel"),
+    (5633240, "Servicios Internet Limitada"),
+    (5633241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633242, "Vtr Banda Ancha (Chile) S.A."),
+    (5633244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633245, "Vtr Banda Ancha (Chile) S.A."),
+    (5633246, "Claro"),
+    (5633247, "Claro"),
+    (5633248, "Entel"),
+    (5633249, "Entel"),
+    (5633250, "Entel"),
+    (5633251, "Vtr Banda Ancha (Chile) S.A."),
+    (5633252, "Vtr Banda Ancha (Chile) S.A."),
+    (5633253, "Entel"),
+    (5633254, "Entel"),
+    (5633256, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633274, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633276, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633277, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633279, "Compania De Telecomunicaciones De Chile S.A."),
+    (5633281, "Telestar"),
+    (5634220, "Entel"),
+    (5634221, "Entel"),
+    (5634223, "Entel"),
+    (5634224, "Entel"),
+    (5634225, "Entel"),
+    (5634226, "Entel"),
+    (5634227, "Vtr Comunicaciones Spa."),
+    (5634228, "Vtr Banda Ancha (Chile) S.A."),
+    (5634229, "Vtr Banda Ancha (Chile) S.A."),
+    (5634231, "Vtr Banda Ancha (Chile) S.A."),
+    (5634232, "Vtr Banda Ancha (Chile) S.A."),
+    (5634234, "Entel"),
+    (5634235, "Entel"),
+    (5634236, "Claro"),
+    (5634237, "Claro"),
+    (5634238, "Claro"),
+    (5634239, "Entel"),
+    (5634240, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634241, "Claro"),
+    (5634242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634243, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634246, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634248, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634250, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634253, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634257, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634258, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634259, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634261, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634268, "Compania De Telecomunicaciones De Chile S.A."),
+    (5634277, "Telestar"),
+    (5634290, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5634291, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5635227, "Claro"),
+    (5635229, "Vtr Banda Ancha (Chile) S.A."),
+    (5635231, "Vtr Banda Ancha (Chile) S.A."),
+    (5635232, "Claro"),
+    (5635233, "Claro"),
+    (5635234, "Claro"),
+    (5635235, "Entel"),
+    (5635236, "Entel"),
+    (5635237, "Entel"),
+    (5635238, "Entel"),
+    (5635240, "Entel"),
+    (5635241, "Entel"),
+    (5635244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5635245, "Compania De Telecomunicaciones De Chile S.A."),
+    (5635247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5635248, "Compania De Telecomunicaciones De Chile S.A."),
+    (5635256, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5635257, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5635258, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5635277, "Telestar"),
+    (5635279, "Vtr Banda Ancha (Chile) S.A."),
+    (5635288, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641210, "Entel"),
+    (5641212, "Entel"),
+    (5641213, "Vtr Banda Ancha (Chile) S.A."),
+    (5641215, "Entel"),
+    (5641216, "Entel"),
+    (5641217, "Entel"),
+    (5641218, "Entel"),
+    (5641219, "Entel"),
+    (5641229, "Gtd Telesat S.A."),
+    (5641246, "Telefonica Del Sur S.A."),
+    (5641256, "Claro"),
+    (5641262, "Gtd Telesat S.A."),
+    (5641263, "Gtd Telesat S.A."),
+    (5641271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641272, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641273, "Telefonica Del Sur S.A."),
+    (5641274, "Telefonica Del Sur S.A."),
+    (5641276, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641277, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641279, "Telefonica Del Sur S.A."),
+    (5641281, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5641282, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5641283, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5641285, "Entel"),
+    (5641286, "Entel"),
+    (5641287, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641288, "Entel"),
+    (5641289, "Compania De Telecomunicaciones De Chile S.A."),
+    (5641290, "Claro"),
+    (5641291, "Entel"),
+    (5641292, "Entel"),
+    (5641296, "Claro"),
+    (5641311, "Claro"),
+    (5641312, "Claro"),
+    (5641313, "Vtr Banda Ancha (Chile) S.A."),
+    (5641314, "Vtr Banda Ancha (Chile) S.A."),
+    (5641316, "Telefonica Del Sur S.A."),
+    (5641317, "Vtr Banda Ancha (Chile) S.A."),
+    (5641318, "Vtr Banda Ancha (Chile) S.A."),
+    (5641319, "Claro"),
+    (5641320, "Convergia Telecom S.A."),
+    (5641321, "Claro"),
+    (5641322, "Vtr Banda Ancha (Chile) S.A."),
+    (5641323, "Vtr Banda Ancha (Chile) S.A."),
+    (5641324, "Vtr Banda Ancha (Chile) S.A."),
+    (5641325, "Vtr Banda Ancha (Chile) S.A."),
+    (5641327, "Entel"),
+    (5641328, "Entel"),
+    (5641331, "Movistar"),
+    (5641332, "Entel"),
+    (5641333, "Entel"),
+    (5641334, "Entel"),
+    (5641335, "Entel"),
+    (5641336, "Entel"),
+    (5641337, "Servicios Internet Limitada"),
+    (5641339, "Telefonica Del Sur S.A."),
+    (5641350, "Entel"),
+    (5641351, "Entel"),
+    (5641352, "Entel"),
+    (5641355, "Claro"),
+    (5641356, "Claro"),
+    (5641357, "Claro"),
+    (5641358, "Claro"),
+    (5641359, "Cibeles Telecom"),
+    (5641360, "Entel"),
+    (5641361, "Entel"),
+    (5641362, "Entel"),
+    (5641363, "Entel"),
+    (5641364, "Entel"),
+    (5641377, "Telestar"),
+    (5641383, "Telefonica Del Sur S.A."),
+    (5641397, "Vtr Banda Ancha (Chile) S.A."),
+    (5641398, "Vtr Banda Ancha (Chile) S.A."),
+    (5642225, "Telefonica Del Sur S.A."),
+    (5642231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642232, "Vtr Banda Ancha (Chile) S.A."),
+    (5642233, "Vtr Banda Ancha (Chile) S.A."),
+    (5642234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642236, "Claro"),
+    (5642238, "Claro"),
+    (5642241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642242, "Entel"),
+    (5642243, "Entel"),
+    (5642244, "Claro"),
+    (5642245, "Claro"),
+    (5642246, "Claro"),
+    (5642249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642250, "Entel"),
+    (5642252, "Entel"),
+    (5642253, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642254, "Entel"),
+    (5642255, "Entel"),
+    (5642258, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642259, "Entel"),
+    (5642261, "Vtr Banda Ancha (Chile) S.A."),
+    (5642262, "Vtr Banda Ancha (Chile) S.A."),
+    (5642263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642265, "Compania De Telecomunicaciones De Chile S.A."),
+    (5642277, "Telestar"),
+    (5642283, "Entel"),
+    (5642285, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5642287, "Entel"),
+    (5642296, "Telefonica Del Sur S.A."),
+    (5643221, "Telefonica Del Sur S.A."),
+    (5643223, "Vtr Banda Ancha (Chile) S.A."),
+    (5643225, "Vtr Banda Ancha (Chile) S.A."),
+    (5643226, "Vtr Banda Ancha (Chile) S.A."),
+    (5643227, "Vtr Banda Ancha (Chile) S.A."),
+    (5643228, "Vtr Banda Ancha (Chile) S.A."),
+    (5643229, "Entel"),
+    (5643230, "Entel"),
+    (5643231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643235, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643239, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643240, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643244, "Claro"),
+    (5643245, "Claro"),
+    (5643246, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643247, "Claro"),
+    (5643248, "Claro"),
+    (5643249, "Claro"),
+    (5643251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643252, "Entel"),
+    (5643253, "Entel"),
+    (5643254, "Entel"),
+    (5643256, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643257, "Entel"),
+    (5643263, "Entel"),
+    (5643264, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643265, "Entel"),
+    (5643266, "Entel"),
+    (5643269, "Compania De Telecomunicaciones De Chile S.A."),
+    (5643277, "Telestar"),
+    (5643287, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5643296, "Telefonica Del Sur S.A."),
+    (5645230, "Entel"),
+    (5645231, "Telefonica Del Sur S.A."),
+    (5645232, "Telefonica Del Sur S.A."),
+    (5645233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645237, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645238, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645240, "Telefonica Del Sur S.A."),
+    (5645241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645245, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645246, "Entel"),
+    (5645248, "Vtr Banda Ancha (Chile) S.A."),
+    (5645252, "Telefonica Del Sur S.A."),
+    (5645254, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645255, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645259, "Telefonica Del Sur S.A."),
+    (5645260, "Entel"),
+    (5645264, "Telefonica Del Sur S.A."),
+    (5645268, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645269, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645272, "Telefonica Del Sur S.A."),
+    (5645273, "Telefonica Del Sur S.A."),
+    (5645274, "Telefonica Del Sur S.A."),
+    (5645276, "Telestar"),
+    (5645280, "Chile.Com"),
+    (5645281, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645282, "Claro"),
+    (5645283, "Vtr Banda Ancha (Chile) S.A."),
+    (5645284, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645286, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645287, "Compania De Telecomunicaciones De Chile S.A."),
+    (5645290, "Movistar"),
+    (5645291, "Entel"),
+    (5645293, "Claro"),
+    (5645294, "Claro"),
+    (5645295, "Gtd Telesat S.A."),
+    (5645296, "Gtd Telesat S.A."),
+    (5645297, "Gtd Telesat S.A."),
+    (5645298, "Vtr Banda Ancha (Chile) S.A."),
+    (5645299, "Entel"),
+    (5651231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651235, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651238, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651239, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651240, "Entel"),
+    (5651241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651243, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651246, "Claro"),
+    (5651247, "Claro"),
+    (5651248, "Vtr Banda Ancha (Chile) S.A."),
+    (5651249, "Vtr Banda Ancha (Chile) S.A."),
+    (5651252, "Vtr Banda Ancha (Chile) S.A."),
+    (5651253, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651254, "Entel"),
+    (5651255, "Entel"),
+    (5651256, "Entel"),
+    (5651257, "Entel"),
+    (5651258, "Claro"),
+    (5651259, "Vtr Banda Ancha (Chile) S.A."),
+    (5651260, "Entel"),
+    (5651261, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651262, "Claro"),
+    (5651263, "Claro"),
+    (5651264, "Claro"),
+    (5651266, "Entel"),
+    (5651267, "Entel"),
+    (5651268, "Entel"),
+    (5651269, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651271, "Claro"),
+    (5651272, "Vtr Banda Ancha (Chile) S.A."),
+    (5651273, "Vtr Banda Ancha (Chile) S.A."),
+    (5651275, "Entel"),
+    (5651276, "Telestar"),
+    (5651277, "Compania De Telecomunicaciones De Chile S.A."),
+    (5651278, "Entel"),
+    (5651279, "Entel"),
+    (5652227, "Vtr Banda Ancha (Chile) S.A."),
+    (5652229, "Vtr Banda Ancha (Chile) S.A."),
+    (5652231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652233, "Claro"),
+    (5652234, "Claro"),
+    (5652235, "Claro"),
+    (5652236, "Vtr Banda Ancha (Chile) S.A."),
+    (5652237, "Vtr Banda Ancha (Chile) S.A."),
+    (5652238, "Vtr Banda Ancha (Chile) S.A."),
+    (5652240, "Entel"),
+    (5652241, "Claro"),
+    (5652242, "Entel"),
+    (5652243, "Entel"),
+    (5652244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652245, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652246, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652248, "Compania De Telecomunicaciones De Chile S.A."),
+    (5652250, "Entel"),
+    (5652251, "Entel"),
+    (5652252, "Entel"),
+    (5652253, "Entel"),
+    (5652254, "Entel"),
+    (5652256, "Entel"),
+    (5652257, "Entel"),
+    (5652258, "Movistar"),
+    (5652259, "Movistar"),
+    (5652260, "Movistar"),
+    (5652261, "Movistar"),
+    (5652262, "Movistar"),
+    (5652268, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653220, "Entel"),
+    (5653221, "Entel"),
+    (5653232, "Claro"),
+    (5653233, "Claro"),
+    (5653234, "Claro"),
+    (5653235, "Claro"),
+    (5653236, "Vtr Banda Ancha (Chile) S.A."),
+    (5653237, "Vtr Banda Ancha (Chile) S.A."),
+    (5653242, "Entel"),
+    (5653243, "Entel"),
+    (5653244, "Entel"),
+    (5653246, "Entel"),
+    (5653247, "Entel"),
+    (5653248, "Entel"),
+    (5653249, "Entel"),
+    (5653252, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653254, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653259, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653264, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653265, "Compania De Telecomunicaciones De Chile S.A."),
+    (5653266, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655235, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655237, "Vtr Banda Ancha (Chile) S.A."),
+    (5655238, "Vtr Banda Ancha (Chile) S.A."),
+    (5655239, "Gtd Telesat S.A."),
+    (5655240, "Vtr Banda Ancha (Chile) S.A."),
+    (5655241, "Entel"),
+    (5655242, "Entel"),
+    (5655243, "Entel"),
+    (5655244, "Entel"),
+    (5655245, "Gtd Telesat S.A."),
+    (5655246, "Gtd Telesat S.A."),
+    (5655247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655248, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655250, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655252, "Claro"),
+    (5655253, "Claro"),
+    (5655254, "Vtr Banda Ancha (Chile) S.A."),
+    (5655255, "Entel"),
+    (5655256, "Entel"),
+    (5655257, "Entel"),
+    (5655258, "Netglobalis Telecom S.A."),
+    (5655259, "Entel"),
+    (5655261, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655264, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655265, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655266, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655267, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655268, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655269, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655271, "Claro"),
+    (5655272, "Vtr Banda Ancha (Chile) S.A."),
+    (5655273, "Vtr Banda Ancha (Chile) S.A."),
+    (5655274, "Claro"),
+    (5655276, "Vtr Banda Ancha (Chile) S.A."),
+    (5655277, "Vtr Banda Ancha (Chile) S.A."),
+    (5655278, "Vtr Banda Ancha (Chile) S.A."),
+    (5655279, "Vtr Banda Ancha (Chile) S.A."),
+    (5655280, "Cibeles Telecom"),
+    (5655281, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655282, "Vtr Banda Ancha (Chile) S.A."),
+    (5655283, "Vtr Banda Ancha (Chile) S.A."),
+    (5655284, "Vtr Banda Ancha (Chile) S.A."),
+    (5655285, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655286, "Claro"),
+    (5655287, "Convergia Telecom S.A."),
+    (5655288, "Compania De Telecomunicaciones De Chile S.A."),
+    (5655289, "Entel"),
+    (5655290, "Movistar"),
+    (5655291, "Gtd Telesat S.A."),
+    (5655297, "Telestar"),
+    (5655298, "Entel"),
+    (5655334, "Movistar"),
+    (5655335, "Movistar"),
+    (5655336, "Movistar"),
+    (5655337, "Movistar"),
+    (5655338, "Movistar"),
+    (5657221, "Vtr Banda Ancha (Chile) S.A."),
+    (5657222, "Claro"),
+    (5657223, "Convergia Telecom S.A."),
+    (5657224, "Entel"),
+    (5657225, "Chile.Com"),
+    (5657226, "Entel"),
+    (5657227, "Entel"),
+    (5657228, "Vtr Banda Ancha (Chile) S.A."),
+    (5657229, "Vtr Banda Ancha (Chile) S.A."),
+    (5657231, "Vtr Banda Ancha (Chile) S.A."),
+    (5657232, "Vtr Banda Ancha (Chile) S.A."),
+    (5657233, "Vtr Banda Ancha (Chile) S.A."),
+    (5657234, "Vtr Banda Ancha (Chile) S.A."),
+    (5657235, "Claro"),
+    (5657236, "Claro"),
+    (5657237, "Gtd Telesat S.A."),
+    (5657238, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657239, "Gtd Telesat S.A."),
+    (5657250, "Movistar"),
+    (5657251, "Entel"),
+    (5657252, "Entel"),
+    (5657253, "Entel"),
+    (5657254, "Entel"),
+    (5657256, "Claro"),
+    (5657257, "Entel"),
+    (5657258, "Gtd Telesat S.A."),
+    (5657259, "Claro"),
+    (5657262, "Entel"),
+    (5657263, "Entel"),
+    (5657271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657272, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657273, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657276, "Vtr Banda Ancha (Chile) S.A."),
+    (5657277, "Telestar"),
+    (5657278, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657279, "Compania De Telecomunicaciones De Chile S.A."),
+    (5657281, "Gtd Telesat S.A."),
+    (5657283, "Movistar"),
+    (5657284, "Gtd Manquehue S.A."),
+    (5657285, "Entel"),
+    (5657286, "Entel"),
+    (5657287, "Entel"),
+    (5657288, "Entel"),
+    (5658231, "Vtr Banda Ancha (Chile) S.A."),
+    (5658232, "Vtr Banda Ancha (Chile) S.A."),
+    (5658233, "Claro"),
+    (5658234, "Claro"),
+    (5658235, "Claro"),
+    (5658236, "Vtr Banda Ancha (Chile) S.A."),
+    (5658237, "Compania De Telecomunicaciones De Chile S.A."),
+    (5658238, "Compania De Telecomunicaciones De Chile S.A."),
+    (5658240, "Entel"),
+    (5658241, "Vtr Banda Ancha (Chile) S.A."),
+    (5658242, "Claro"),
+    (5658243, "Claro"),
+    (5658246, "Convergia Telecom S.A."),
+    (5658247, "Vtr Banda Ancha (Chile) S.A."),
+    (5658248, "Chile.Com"),
+    (5658249, "Entel"),
+    (5658250, "Entel"),
+    (5658251, "Entel"),
+    (5658252, "Entel"),
+    (5658253, "Entel"),
+    (5658256, "Entel"),
+    (5658257, "Entel"),
+    (5658258, "Entel"),
+    (5658259, "Entel"),
+    (5658277, "Telestar"),
+    (5658283, "Movistar"),
+    (5658298, "Vtr Banda Ancha (Chile) S.A."),
+    (5661232, "Claro"),
+    (5661234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5661235, "Claro"),
+    (5661236, "Claro"),
+    (5661237, "Claro"),
+    (5661238, "Chile.Com"),
+    (5661239, "Claro"),
+    (5661241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5661242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5661245, "Entel"),
+    (5661261, "Entel"),
+    (5661262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5661263, "Entel"),
+    (5661264, "Entel"),
+    (5661269, "Compania De Telecomunicaciones De Chile S.A."),
+    (5661271, "Entel"),
+    (5661272, "Entel"),
+    (5661273, "Entel"),
+    (5661274, "Entel"),
+    (5663225, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663226, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663231, "Telefonica Del Sur S.A."),
+    (5663232, "Entel"),
+    (5663233, "Entel"),
+    (5663234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663235, "Claro"),
+    (5663236, "Claro"),
+    (5663238, "Claro"),
+    (5663243, "Vtr Banda Ancha (Chile) S.A."),
+    (5663245, "Telefonica Del Sur S.A."),
+    (5663251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663252, "Telefonica Del Sur S.A."),
+    (5663253, "Entel"),
+    (5663255, "Entel"),
+    (5663257, "Telefonica Del Sur S.A."),
+    (5663258, "Telefonica Del Sur S.A."),
+    (5663259, "Claro"),
+    (5663261, "Claro"),
+    (5663262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663264, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663265, "Compania De Telecomunicaciones De Chile S.A."),
+    (5663266, "Entel"),
+    (5663267, "Entel"),
+    (5663268, "Entel"),
+    (5663269, "Entel"),
+    (5663272, "Entel"),
+    (5663277, "Telestar"),
+    (5663296, "Telefonica Del Sur S.A."),
+    (5664220, "Telefonica Del Sur S.A."),
+    (5664221, "Telefonica Del Sur S.A."),
+    (5664222, "Telefonica Del Sur S.A."),
+    (5664223, "Telefonica Del Sur S.A."),
+    (5664224, "Telefonica Del Sur S.A."),
+    (5664225, "Telefonica Del Sur S.A."),
+    (5664226, "Telefonica Del Sur S.A."),
+    (5664227, "Vtr Banda Ancha (Chile) S.A."),
+    (5664231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664232, "Telefonica Del Sur S.A."),
+    (5664233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664234, "Telefonica Del Sur S.A."),
+    (5664236, "Telefonica Del Sur S.A."),
+    (5664238, "Telefonica Del Sur S.A."),
+    (5664241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664243, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664244, "Claro"),
+    (5664245, "Claro"),
+    (5664246, "Claro"),
+    (5664247, "Telefonica Del Sur S.A."),
+    (5664248, "Claro"),
+    (5664249, "Claro"),
+    (5664251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664252, "Telefonica Del Sur S.A."),
+    (5664253, "Compania De Telecomunicaciones De Chile S.A."),
+    (5664254, "Entel"),
+    (5664255, "Entel"),
+    (5664256, "Entel"),
+    (5664257, "Telefonica Del Sur S.A."),
+    (5664258, "Entel"),
+    (5664259, "Entel"),
+    (5664261, "Entel"),
+    (5664262, "Entel"),
+    (5664263, "Entel"),
+    (5664264, "Entel"),
+    (5664267, "Entel"),
+    (5664268, "Entel"),
+    (5664277, "Telestar"),
+    (5664296, "Telefonica Del Sur S.A."),
+    (5665220, "Telefonica Del Sur S.A."),
+    (5665221, "Telefonica Del Sur S.A."),
+    (5665222, "Telefonica Del Sur S.A."),
+    (5665223, "Telefonica Del Sur S.A."),
+    (5665225, "Telefonica Del Sur S.A."),
+    (5665226, "Telefonica Del Sur S.A."),
+    (5665227, "Telefonica Del Sur S.A."),
+    (5665228, "Telefonica Del Sur S.A."),
+    (5665229, "Telefonica Del Sur S.A."),
+    (5665231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665233, "Telefonica Del Sur S.A."),
+    (5665234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665235, "Entel"),
+    (5665236, "Entel"),
+    (5665238, "Entel"),
+    (5665239, "Claro"),
+    (5665241, "Claro"),
+    (5665243, "Telefonica Del Sur S.A."),
+    (5665246, "Telefonica Del Sur S.A."),
+    (5665247, "Telefonica Del Sur S.A."),
+    (5665248, "Telefonica Del Sur S.A."),
+    (5665249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665250, "Entel"),
+    (5665251, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665252, "Telefonica Del Sur S.A."),
+    (5665253, "Telefonica Del Sur S.A."),
+    (5665254, "Telefonica Del Sur S.A."),
+    (5665255, "Claro"),
+    (5665256, "Claro"),
+    (5665257, "Telefonica Del Sur S.A."),
+    (5665258, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665259, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665261, "Telefonica Del Sur S.A."),
+    (5665262, "Telefonica Del Sur S.A."),
+    (5665263, "Telefonica Del Sur S.A."),
+    (5665268, "Telefonica Del Sur S.A."),
+    (5665271, "Vtr Banda Ancha (Chile) S.A."),
+    (5665275, "Vtr Banda Ancha (Chile) S.A."),
+    (5665277, "Entel"),
+    (5665278, "Claro"),
+    (5665279, "Telestar"),
+    (5665280, "Entel"),
+    (5665281, "Convergia Telecom S.A."),
+    (5665282, "Compania De Telecomunicaciones De Chile S.A."),
+    (5665283, "Vtr Banda Ancha (Chile) S.A."),
+    (5665284, "Entel"),
+    (5665285, "Entel"),
+    (5665286, "Entel"),
+    (5665288, "Entel"),
+    (5665289, "Entel"),
+    (5665296, "Telefonica Del Sur S.A."),
+    (5667221, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667222, "Telefonica Del Sur S.A."),
+    (5667223, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667224, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667225, "Compania De Telecomunicaciones De Chile S.A."),
+    (5667226, "Compania De Telecomunicaciones De Chile S.A."),
+    (5667227, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667228, "Claro"),
+    (5667229, "Chile.Com"),
+    (5667231, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667232, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667234, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667235, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667237, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667239, "Entel"),
+    (5667241, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667244, "Claro"),
+    (5667245, "Claro"),
+    (5667246, "Claro"),
+    (5667252, "Compania De Teléfonos De Coyhaique S.A."),
+    (5667257, "Entel"),
+    (5667258, "Entel"),
+    (5667261, "Compania De Telecomunicaciones De Chile S.A."),
+    (5667262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5667263, "Entel"),
+    (5667267, "Entel"),
+    (5671231, "Vtr Banda Ancha (Chile) S.A."),
+    (5671232, "Claro"),
+    (5671233, "Claro"),
+    (5671234, "Claro"),
+    (5671235, "Claro"),
+    (5671238, "Entel"),
+    (5671240, "Entel"),
+    (5671241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671243, "Entel"),
+    (5671244, "Vtr Banda Ancha (Chile) S.A."),
+    (5671245, "Vtr Banda Ancha (Chile) S.A."),
+    (5671247, "Entel"),
+    (5671248, "Entel"),
+    (5671251, "Entel"),
+    (5671252, "Entel"),
+    (5671253, "Entel"),
+    (5671261, "Entel"),
+    (5671262, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671263, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671264, "Claro"),
+    (5671267, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671268, "Vtr Banda Ancha (Chile) S.A."),
+    (5671269, "Vtr Banda Ancha (Chile) S.A."),
+    (5671271, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5671273, "Entel"),
+    (5671274, "Entel"),
+    (5671277, "Telestar"),
+    (5671279, "Vtr Banda Ancha (Chile) S.A."),
+    (5671280, "Entel"),
+    (5671281, "Entel"),
+    (5671282, "Entel"),
+    (5671283, "Entel"),
+    (5671288, "Compania De Telecomunicaciones De Chile S.A."),
+    (5671298, "Telefonica Del Sur S.A."),
+    (5672231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672232, "Entel"),
+    (5672233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672234, "Entel"),
+    (5672235, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672240, "Chile.Com"),
+    (5672241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672242, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672243, "Vtr Banda Ancha (Chile) S.A."),
+    (5672244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672253, "Vtr Banda Ancha (Chile) S.A."),
+    (5672258, "Entel"),
+    (5672260, "Entel"),
+    (5672261, "Claro"),
+    (5672269, "Vtr Banda Ancha (Chile) S.A."),
+    (5672271, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672272, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672273, "Claro"),
+    (5672274, "Claro"),
+    (5672275, "Vtr Banda Ancha (Chile) S.A."),
+    (5672276, "Vtr Banda Ancha (Chile) S.A."),
+    (5672277, "Vtr Banda Ancha (Chile) S.A."),
+    (5672278, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672280, "Cibeles Telecom"),
+    (5672282, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672288, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672289, "Compania De Telecomunicaciones De Chile S.A."),
+    (5672290, "Entel"),
+    (5672291, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5672292, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5672293, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5672294, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5672295, "Entel"),
+    (5672296, "Claro"),
+    (5672297, "Entel"),
+    (5672298, "Entel"),
+    (5672299, "Telestar"),
+    (5673221, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673222, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673223, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673225, "Vtr Banda Ancha (Chile) S.A."),
+    (5673226, "Vtr Banda Ancha (Chile) S.A."),
+    (5673228, "Entel"),
+    (5673229, "Entel"),
+    (5673231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673233, "Claro"),
+    (5673243, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673244, "Claro"),
+    (5673245, "Claro"),
+    (5673246, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673247, "Claro"),
+    (5673248, "Entel"),
+    (5673249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673250, "Entel"),
+    (5673256, "Compania De Telecomunicaciones De Chile S.A."),
+    (5673261, "Entel"),
+    (5673262, "Entel"),
+    (5673263, "Entel"),
+    (5673265, "Entel"),
+    (5673273, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5675220, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675222, "Vtr Banda Ancha (Chile) S.A."),
+    (5675223, "Vtr Banda Ancha (Chile) S.A."),
+    (5675224, "Vtr Banda Ancha (Chile) S.A."),
+    (5675225, "Vtr Banda Ancha (Chile) S.A."),
+    (5675226, "Claro"),
+    (5675228, "Entel"),
+    (5675229, "Entel"),
+    (5675231, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675232, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675233, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675234, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675235, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675236, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675238, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675239, "Entel"),
+    (5675241, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675242, "Entel"),
+    (5675243, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675244, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675247, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675249, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675250, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5675252, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (5675254, "Entel"),
+    (5675255, "Entel"),
+    (5675256, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675257, "Entel"),
+    (5675259, "Entel"),
+    (5675260, "Entel"),
+    (5675261, "Entel"),
+    (5675273, "Claro"),
+    (5675274, "Claro"),
+    (5675275, "Claro"),
+    (5675276, "Telefonica Del Sur S.A."),
+    (5675277, "Telestar"),
+    (5675289, "Compania De Telecomunicaciones De Chile S.A."),
+    (5675298, "Vtr Banda Ancha (Chile) S.A."),
+    (5693242, "Entel"),
+    (5693243, "Entel"),
+    (5693244, "Entel"),
+    (5693245, "Entel"),
+    (5693246, "Entel"),
+    (5693247, "Entel"),
+    (5693248, "Entel"),
+    (5693249, "Entel"),
+    (5693600, "Pacifico Cable S.P.A."),
+    (5693602, "Belink Spa."),
+    (5693603, "Ingenium Outsourcing Services Chile Spa."),
+    (5693604, "Ingenium Outsourcing Services Chile Spa."),
+    (5693605, "Cellpluss Spa."),
+    (5693606, "Cellpluss Spa."),
+    (5693607, "Cellpluss Spa."),
+    (5693608, "Cellpluss Spa."),
+    (5693609, "Cellpluss Spa."),
+    (5694600, "Voip Analysis S.A"),
+    (5694601, "Voip Analysis S.A"),
+    (5694602, "Voip Analysis S.A"),
+    (5694603, "Voip Analysis S.A"),
+    (5694604, "Voip Analysis S.A"),
+    (5695550, "Movistar"),
+    (5695551, "Movistar"),
+    (5695552, "Movistar"),
+    (5695553, "Movistar"),
+    (5695554, "Movistar"),
+    (5695555, "Redvoiss"),
+    (5695556, "Redvoiss"),
+    (5695557, "Industel Chile Ltda"),
+    (5695558, "Industel Chile Ltda"),
+    (5695559, "Industel Chile Ltda"),
+    (5696760, "Movistar"),
+    (5696761, "Movistar"),
+    (5696762, "Movistar"),
+    (5696763, "Telefonica Del Sur S.A."),
+    (5696764, "Cibeles Telecom"),
+    (5696765, "Entel"),
+    (5696766, "Entel"),
+    (5696767, "Entel"),
+    (5696768, "Entel"),
+    (5696769, "Entel"),
+    (5696770, "Celupago"),
+    (5696774, "Telefonica Del Sur S.A."),
+    (5696775, "Entel"),
+    (5696776, "Entel"),
+    (5696777, "Entel"),
+    (5696778, "Entel"),
+    (5696779, "Entel"),
+    (5696780, "Mavi"),
+    (5696785, "Entel"),
+    (5696786, "Entel"),
+    (5696787, "Entel"),
+    (5696788, "Entel"),
+    (5696789, "Entel"),
+    (5696790, "Movistar"),
+    (5696795, "Entel"),
+    (5696796, "Entel"),
+    (5696797, "Entel"),
+    (5696798, "Entel"),
+    (5696799, "Entel"),
+    (5696890, "Netline"),
+    (5696891, "Netline"),
+    (5696892, "Netline"),
+    (5696893, "Netline"),
+    (5696894, "Netline"),
+    (5696895, "Entel"),
+    (5696896, "Entel"),
+    (5696897, "Entel"),
+    (5696898, "Entel"),
+    (5696899, "Entel"),
+    (5696910, "Sociedad Comercial y de IngenierÃ\u{ad}a Swedcom"),
+    (5696915, "Entel"),
+    (5696916, "Entel"),
+    (5696917, "Entel"),
+    (5696918, "Entel"),
+    (5696919, "Entel"),
+    (5696920, "Television Interactiva"),
+    (5696930, "Quantax"),
+    (5696940, "Telecomunicaciones Net Uno Ltda."),
+    (5696950, "Blue Two"),
+    (5696965, "Movistar"),
+    (5696970, "Vtr Banda Ancha (Chile) S.A."),
+    (5696990, "Telecomunicaciones Dotcom"),
+    (5697110, "Telecomunicaciones Max Ltda."),
+    (5698100, "Viva"),
+    (5698101, "Viva"),
+    (5698102, "Viva"),
+    (5698103, "Viva"),
+    (5698104, "Viva"),
+    (5698105, "WOM"),
+    (5698106, "WOM"),
+    (5698107, "WOM"),
+    (5698108, "WOM"),
+    (5698109, "WOM"),
+    (5699026, "Mobilink"),
+    (5699027, "Mobilink"),
+    (5699028, "WOM"),
+    (5699029, "WOM"),
+    (5699048, "WOM"),
+    (5699049, "WOM"),
+    (5699140, "Entel"),
+    (5699141, "Entel"),
+    (5699142, "Entel"),
+    (5699280, "Movistar"),
+    (5699281, "Movistar"),
+    (5699282, "Movistar"),
+    (5699283, "Movistar"),
+    (5699284, "Movistar"),
+    (5699286, "Movistar"),
+    (5699287, "Movistar"),
+    (5699289, "Entel"),
+    (5699380, "Movistar"),
+    (5699381, "Movistar"),
+    (5699382, "Entel"),
+    (5699383, "Entel"),
+    (5699385, "Claro"),
+    (5699386, "Claro"),
+    (5699387, "Claro"),
+    (5699388, "Claro"),
+    (5699389, "Movistar"),
+    (5699580, "Movistar"),
+    (5699581, "Movistar"),
+    (5699582, "Movistar"),
+    (5699583, "Movistar"),
+    (5699584, "Movistar"),
+    (5699585, "Claro"),
+    (5699586, "Claro"),
+    (5699587, "Claro"),
+    (5699588, "Claro"),
+    (5699589, "Claro"),
+    (5699600, "Movistar"),
+    (5699601, "Entel"),
+    (5699602, "Movistar"),
+    (5699603, "Movistar"),
+    (5699604, "Movistar"),
+    (5699606, "Movistar"),
+    (5699607, "Entel"),
+    (5699609, "Claro"),
+    (5699680, "Movistar"),
+    (5699681, "Movistar"),
+    (5699960, "Movistar"),
+    (5699961, "Movistar"),
+    (5699962, "Movistar"),
+    (5699963, "Movistar"),
+    (5699964, "Movistar"),
+    (5699965, "Entel"),
+    (5699966, "Entel"),
+    (5699967, "Entel"),
+    (5699968, "Entel"),
+    (5699969, "Entel"),
+    (5699970, "Movistar"),
+    (5699971, "Movistar"),
+    (5699972, "Movistar"),
+    (5699973, "Movistar"),
+    (5699974, "Movistar"),
+    (5699975, "Claro"),
+    (5699976, "Claro"),
+    (5699977, "Claro"),
+    (5699978, "Claro"),
+    (5699979, "Claro"),
+    (5699990, "Tesacom"),
+    (5730247, "Partners Telecom Colombia"),
+    (5730248, "Partners Telecom Colombia"),
+    (5730249, "Partners Telecom Colombia"),
+    (5730287, "Tigo"),
+    (5730288, "Tigo"),
+    (5730289, "Tigo"),
+    (5730410, "Tigo"),
+    (5730411, "Tigo"),
+    (5730412, "Tigo"),
+    (5730413, "Tigo"),
+    (5730414, "Tigo"),
+    (5730415, "Tigo"),
+    (5730416, "Tigo"),
+    (5730417, "Tigo"),
+    (5730418, "Tigo"),
+    (5730452, "Tigo"),
+    (5730453, "Tigo"),
+    (5730454, "Tigo"),
+    (5730455, "Tigo"),
+    (5730456, "Tigo"),
+    (5730457, "Tigo"),
+    (5730458, "Tigo"),
+    (5730459, "Tigo"),
+    (5730460, "Tigo"),
+    (5730461, "Tigo"),
+    (5730462, "Tigo"),
+    (5730463, "Tigo"),
+    (5730464, "Tigo"),
+    (5730465, "Tigo"),
+    (5730466, "Tigo"),
+    (5730467, "Tigo"),
+    (5730469, "Tigo"),
+    (5730470, "Tigo"),
+    (5730471, "Tigo"),
+    (5730472, "Tigo"),
+    (5730474, "Tigo"),
+    (5730475, "Tigo"),
+    (5730520, "Tigo"),
+    (5730522, "Tigo"),
+    (5730523, "Tigo"),
+    (5730524, "Tigo"),
+    (5730525, "Tigo"),
+    (5730527, "Tigo"),
+    (5730528, "Tigo"),
+    (5730529, "Tigo"),
+    (5730540, "Movil Exito"),
+    (5730541, "Movil Exito"),
+    (5730542, "Movil Exito"),
+    (5730543, "Movil Exito"),
+    (5730544, "Movil Exito"),
+    (5730545, "Movil Exito"),
+    (5730546, "Movil Exito"),
+    (5730547, "Movil Exito"),
+    (5730548, "Movil Exito"),
+    (5730550, "Tigo"),
+    (5730551, "Tigo"),
+    (5730552, "Tigo"),
+    (5730553, "Tigo"),
+    (5730554, "Tigo"),
+    (5730555, "Tigo"),
+    (5730556, "Tigo"),
+    (5730590, "Tigo"),
+    (5730591, "Tigo"),
+    (5730592, "Tigo"),
+    (5730593, "Tigo"),
+    (5730594, "Tigo"),
+    (5730595, "Tigo"),
+    (5731091, "Claro"),
+    (5731092, "Claro"),
+    (5731093, "Claro"),
+    (5733300, "Setroc Mobile"),
+    (5733301, "Setroc Mobile"),
+    (5733302, "Setroc Mobile"),
+    (5733303, "Setroc Mobile"),
+    (5733304, "Setroc Mobile"),
+    (5733305, "Tigo"),
+    (5733306, "Tigo"),
+    (5733307, "Tigo"),
+    (5733308, "Tigo"),
+    (5733310, "Libre Tecnologias"),
+    (5733311, "Libre Tecnologias"),
+    (5733312, "Libre Tecnologias"),
+    (5733313, "Libre Tecnologias"),
+    (5733314, "Libre Tecnologias"),
+    (5733315, "Libre Tecnologias"),
+    (5733316, "Libre Tecnologias"),
+    (5733320, "Tigo"),
+    (5733321, "Tigo"),
+    (5733330, "GoMobile"),
+    (5733332, "EZTALK Mobile"),
+    (5733333, "EZTALK Mobile"),
+    (5733334, "EZTALK Mobile"),
+    (5733335, "EZTALK Mobile"),
+    (5733340, "Comunicaciones Dime"),
+    (5733341, "Comunicaciones Dime"),
+    (5733342, "Comunicaciones Dime"),
+    (5733343, "Comunicaciones Dime"),
+    (5733344, "Comunicaciones Dime"),
+    (5733345, "Tigo"),
+    (5733346, "Tigo"),
+    (5733347, "Tigo"),
+    (5733348, "Tigo"),
+    (5733349, "Tigo"),
+    (5733360, "Suma Movil"),
+    (5733361, "Suma Movil"),
+    (5733362, "Suma Movil"),
+    (5733363, "Suma Movil"),
+    (5733364, "Suma Movil"),
+    (5733365, "Suma Movil"),
+    (5733366, "Suma Movil"),
+    (5733367, "Suma Movil"),
+    (5906901, "Digicel"),
+    (5906903, "Orange"),
+    (5906905, "Orange"),
+    (5906906, "Orange"),
+    (5906907, "Orange"),
+    (5906908, "Digicel"),
+    (5906909, "SFR/Rife"),
+    (5906912, "Free Caraïbe"),
+    (5917419, "Nuevatel"),
+    (5917429, "Nuevatel"),
+    (5917438, "Nuevatel"),
+    (5917439, "Nuevatel"),
+    (5917449, "Nuevatel"),
+    (5917459, "Nuevatel"),
+    (5917474, "Nuevatel"),
+    (5917479, "Nuevatel"),
+    (5917487, "Nuevatel"),
+    (5917488, "Nuevatel"),
+    (5917489, "Nuevatel"),
+    (5917497, "Nuevatel"),
+    (5917498, "Nuevatel"),
+    (5917499, "Nuevatel"),
+    (5939586, "Movistar"),
+    (5939587, "Movistar"),
+    (5939588, "Movistar"),
+    (5939589, "Movistar"),
+    (5939620, "CNT"),
+    (5939621, "CNT"),
+    (5939622, "CNT"),
+    (5939623, "CNT"),
+    (5939624, "CNT"),
+    (5939625, "Movistar"),
+    (5939626, "Movistar"),
+    (5939627, "Movistar"),
+    (5939628, "Movistar"),
+    (5939629, "Movistar"),
+    (5939690, "Movistar"),
+    (5939786, "Movistar"),
+    (5939787, "Movistar"),
+    (5939788, "Movistar"),
+    (5939789, "Movistar"),
+    (5939790, "Movistar"),
+    (5939791, "Movistar"),
+    (5939792, "Movistar"),
+    (5939793, "Movistar"),
+    (5939820, "CNT"),
+    (5939821, "CNT"),
+    (5939822, "CNT"),
+    (5939823, "CNT"),
+    (5939824, "CNT"),
+    (5939920, "Claro"),
+    (5939921, "Claro"),
+    (5939922, "Claro"),
+    (5939923, "Claro"),
+    (5939924, "Claro"),
+    (5939925, "Movistar"),
+    (5939926, "Movistar"),
+    (5939927, "Movistar"),
+    (5939928, "Movistar"),
+    (5939929, "Movistar"),
+    (5939990, "Movistar"),
+    (5939991, "Claro"),
+    (5939992, "Movistar"),
+    (5939993, "Claro"),
+    (5939994, "Claro"),
+    (5939995, "Claro"),
+    (5939996, "Claro"),
+    (5939997, "Movistar"),
+    (5939998, "Movistar"),
+    (5939999, "Movistar"),
+    (5946940, "SFR"),
+    (5946942, "Orange"),
+    (5946943, "Orange"),
+    (5946944, "Orange"),
+    (5946949, "Digicel"),
+    (5966960, "SFR/Rife"),
+    (5966962, "Orange"),
+    (5966963, "Orange"),
+    (5966964, "Orange"),
+    (5966967, "Digicel"),
+    (5966968, "Orange"),
+    (5966969, "Digicel"),
+    (5993181, "Telcell"),
+    (5993184, "Telcell"),
+    (5993185, "Telcell"),
+    (5993186, "Telcell"),
+    (5993187, "Telcell"),
+    (5993188, "Telcell"),
+    (5994161, "Telcell"),
+    (5994164, "WIC"),
+    (5994165, "WIC"),
+    (5994166, "WIC"),
+    (5994167, "WIC"),
+    (5994168, "WIC"),
+    (5994169, "Satel"),
+    (6011100, "Webe"),
+    (6011101, "Webe"),
+    (6011102, "Webe"),
+    (6011103, "Webe"),
+    (6011104, "Webe"),
+    (6011105, "red ONE"),
+    (6011106, "red ONE"),
+    (6011107, "red ONE"),
+    (6011108, "red ONE"),
+    (6011109, "red ONE"),
+    (6011130, "XOX"),
+    (6011131, "XOX"),
+    (6011132, "XOX"),
+    (6011133, "XOX"),
+    (6011134, "XOX"),
+    (6011135, "Baraka"),
+    (6011136, "Baraka"),
+    (6011137, "Baraka"),
+    (6011138, "Baraka"),
+    (6011139, "Baraka"),
+    (6011140, "Maxis"),
+    (6011141, "Maxis"),
+    (6011142, "Maxis"),
+    (6011143, "Maxis"),
+    (6011144, "Maxis"),
+    (6011145, "Celcom"),
+    (6011146, "Celcom"),
+    (6011147, "Celcom"),
+    (6011148, "Celcom"),
+    (6011149, "Celcom"),
+    (6011150, "Tune Talk"),
+    (6011151, "Tune Talk"),
+    (6011152, "Tune Talk"),
+    (6011153, "Tune Talk"),
+    (6011154, "Tune Talk"),
+    (6011155, "Celcom"),
+    (6011156, "Celcom"),
+    (6011157, "Celcom"),
+    (6011158, "Celcom"),
+    (6011159, "Celcom"),
+    (6011170, "YTL"),
+    (6011171, "YTL"),
+    (6011172, "YTL"),
+    (6011173, "YTL"),
+    (6011174, "YTL"),
+    (6011175, "Maxis"),
+    (6011176, "Maxis"),
+    (6011177, "Maxis"),
+    (6011178, "Maxis"),
+    (6011179, "Maxis"),
+    (6011180, "Telekom"),
+    (6011181, "Telekom"),
+    (6011182, "Telekom"),
+    (6011183, "Telekom"),
+    (6011184, "Telekom"),
+    (6011185, "Tune Talk"),
+    (6011186, "Tune Talk"),
+    (6011187, "Tune Talk"),
+    (6011188, "Tune Talk"),
+    (6011189, "Tune Talk"),
+    (6011200, "DiGi"),
+    (6011201, "DiGi"),
+    (6011202, "DiGi"),
+    (6011203, "DiGi"),
+    (6011204, "DiGi"),
+    (6011205, "XOX"),
+    (6011206, "XOX"),
+    (6011207, "XOX"),
+    (6011208, "XOX"),
+    (6011209, "XOX"),
+    (6011240, "Maxis"),
+    (6011241, "Maxis"),
+    (6011242, "Maxis"),
+    (6011243, "Maxis"),
+    (6011244, "Maxis"),
+    (6011245, "Celcom"),
+    (6011246, "Celcom"),
+    (6011247, "Celcom"),
+    (6011248, "Celcom"),
+    (6011249, "Celcom"),
+    (6011270, "U Mobile"),
+    (6011271, "U Mobile"),
+    (6011272, "U Mobile"),
+    (6011273, "U Mobile"),
+    (6011274, "U Mobile"),
+    (6011275, "Maxis"),
+    (6011276, "Maxis"),
+    (6011277, "Maxis"),
+    (6011278, "Maxis"),
+    (6011279, "Maxis"),
+    (6011328, "Altel"),
+    (6011560, "Celcom"),
+    (6011561, "Celcom"),
+    (6011562, "Celcom"),
+    (6011563, "Celcom"),
+    (6011564, "Celcom"),
+    (6011565, "XOX"),
+    (6011566, "XOX"),
+    (6011567, "XOX"),
+    (6011568, "XOX"),
+    (6011569, "XOX"),
+    (6011570, "YTL"),
+    (6011571, "YTL"),
+    (6011572, "YTL"),
+    (6011573, "YTL"),
+    (6011574, "YTL"),
+    (6011575, "XOX"),
+    (6011576, "XOX"),
+    (6011577, "XOX"),
+    (6011578, "XOX"),
+    (6011579, "XOX"),
+    (6011580, "YTL"),
+    (6011581, "YTL"),
+    (6011582, "YTL"),
+    (6011583, "YTL"),
+    (6011584, "YTL"),
+    (6011585, "XOX"),
+    (6011586, "XOX"),
+    (6011587, "XOX"),
+    (6011588, "XOX"),
+    (6011589, "XOX"),
+    (6011590, "DiGi"),
+    (6011591, "Webe"),
+    (6011592, "Webe"),
+    (6011593, "Webe"),
+    (6011594, "Webe"),
+    (6011595, "XOX"),
+    (6011596, "XOX"),
+    (6011597, "XOX"),
+    (6011598, "XOX"),
+    (6011599, "XOX"),
+    (6011620, "U Mobile"),
+    (6011621, "U Mobile"),
+    (6011622, "U Mobile"),
+    (6011623, "U Mobile"),
+    (6011624, "U Mobile"),
+    (6011625, "Webe"),
+    (6011626, "Webe"),
+    (6011627, "Webe"),
+    (6011628, "Webe"),
+    (6011629, "Webe"),
+    (6011636, "U Mobile"),
+    (6011637, "U Mobile"),
+    (6011638, "U Mobile"),
+    (6011639, "U Mobile"),
+    (6011646, "DiGi"),
+    (6011647, "DiGi"),
+    (6011648, "DiGi"),
+    (6011649, "Webe"),
+    (6011690, "Webe"),
+    (6011691, "Webe"),
+    (6011692, "DiGi"),
+    (6011700, "U Mobile"),
+    (6011701, "U Mobile"),
+    (6011702, "U Mobile"),
+    (6011710, "DiGi"),
+    (6011711, "DiGi"),
+    (6011720, "Celecom"),
+    (6011721, "Celecom"),
+    (6011722, "Maxis"),
+    (6011723, "Maxis"),
+    (6011724, "Maxis"),
+    (6011725, "Maxis"),
+    (6011726, "Maxis"),
+    (6011727, "YTL"),
+    (6011728, "YTL"),
+    (6011729, "YTL"),
+    (6011757, "U Mobile"),
+    (6018120, "U Mobile"),
+    (6018130, "U Mobile"),
+    (6018790, "U Mobile"),
+    (6018791, "U Mobile"),
+    (6018792, "U Mobile"),
+    (6018793, "U Mobile"),
+    (6018794, "U Mobile"),
+    (6018795, "YTL"),
+    (6018796, "YTL"),
+    (6018797, "YTL"),
+    (6018798, "YTL"),
+    (6018799, "YTL"),
+    (6142000, "Rail Corporation NSW"),
+    (6142001, "Rail Corporation NSW"),
+    (6142002, "Dialogue Communications"),
+    (6142003, "MessageBird"),
+    (6142004, "Symbio Networks"),
+    (6142010, "Pivotel Satellite"),
+    (6142011, "Compatel Limited"),
+    (6142012, "Soul Pattinson"),
+    (6148984, "Victorian Rail Track"),
+    (6148985, "MessageBird"),
+    (6661010, "True Move"),
+    (6661012, "True Move"),
+    (6661013, "True Move"),
+    (6661014, "True Move"),
+    (6661015, "True Move"),
+    (6661016, "True Move"),
+    (6661017, "True Move"),
+    (6661018, "AIS"),
+    (6661019, "AIS"),
+    (6661680, "AIS"),
+    (6661681, "AIS"),
+    (6661682, "AIS"),
+    (6661683, "AIS"),
+    (6662610, "AIS"),
+    (6662611, "AIS"),
+    (6662612, "AIS"),
+    (6662613, "AIS"),
+    (6663296, "DTAC"),
+    (6663297, "DTAC"),
+    (6663550, "DTAC"),
+    (6663555, "AIS"),
+    (6665730, "AIS"),
+    (6665731, "AIS"),
+    (6665732, "AIS"),
+    (6665733, "AIS"),
+    (6665734, "AIS"),
+    (6665735, "DTAC"),
+    (6665736, "DTAC"),
+    (6665737, "DTAC"),
+    (6665738, "DTAC"),
+    (6665739, "DTAC"),
+    (6665998, "DTAC"),
+    (6680330, "True Move"),
+    (6680331, "True Move"),
+    (6680332, "True Move"),
+    (6680333, "True Move"),
+    (6680334, "True Move"),
+    (6680335, "True Move"),
+    (6680336, "True Move"),
+    (6680337, "AIS"),
+    (6680338, "True Move"),
+    (6680398, "AIS"),
+    (6680895, "DTAC"),
+    (6680896, "DTAC"),
+    (6680897, "DTAC"),
+    (6680898, "DTAC"),
+    (6680899, "DTAC"),
+    (6681202, "True Move"),
+    (6681203, "True Move"),
+    (6681204, "True Move"),
+    (6681205, "DTAC"),
+    (6681206, "DTAC"),
+    (6681207, "DTAC"),
+    (6681208, "DTAC"),
+    (6681209, "DTAC"),
+    (6681230, "TOT Mobile"),
+    (6681240, "AIS"),
+    (6681241, "AIS"),
+    (6681242, "AIS"),
+    (6681243, "AIS"),
+    (6681244, "AIS"),
+    (6681245, "True Move"),
+    (6681246, "True Move"),
+    (6681247, "True Move"),
+    (6681248, "True Move"),
+    (6681249, "True Move"),
+    (6681417, "True Move"),
+    (6681418, "True Move"),
+    (6681419, "True Move"),
+    (6682601, "True Move"),
+    (6682602, "True Move"),
+    (6682603, "True Move"),
+    (6682604, "True Move"),
+    (6682605, "True Move"),
+    (6682606, "AIS"),
+    (6682607, "True Move"),
+    (6682608, "True Move"),
+    (6682609, "True Move"),
+    (6682667, "AIS"),
+    (6682668, "AIS"),
+    (6682930, "AIS"),
+    (6682931, "AIS"),
+    (6682932, "AIS"),
+    (6682994, "AIS"),
+    (6683145, "AIS"),
+    (6683146, "AIS"),
+    (6683147, "AIS"),
+    (6683148, "AIS"),
+    (6683149, "AIS"),
+    (6683150, "AIS"),
+    (6683151, "AIS"),
+    (6683152, "AIS"),
+    (6683153, "AIS"),
+    (6683154, "AIS"),
+    (6683256, "AIS"),
+    (6683257, "AIS"),
+    (6683258, "AIS"),
+    (6683259, "AIS"),
+    (6683478, "AIS"),
+    (6683479, "AIS"),
+    (6683589, "AIS"),
+    (6683599, "DTAC"),
+    (6688700, "True Move"),
+    (6688701, "True Move"),
+    (6688702, "True Move"),
+    (6688749, "True Move"),
+    (6688907, "AIS"),
+    (6688925, "AIS"),
+    (6688926, "AIS"),
+    (6688927, "AIS"),
+    (6688928, "AIS"),
+    (6688929, "AIS"),
+    (6688996, "AIS"),
+    (6688997, "AIS"),
+    (6688998, "AIS"),
+    (6688999, "AIS"),
+    (6689343, "AIS"),
+    (6689346, "AIS"),
+    (6689347, "AIS"),
+    (6689364, "AIS"),
+    (6689375, "AIS"),
+    (6689376, "AIS"),
+    (6689378, "AIS"),
+    (6689379, "AIS"),
+    (6689381, "AIS"),
+    (6689394, "AIS"),
+    (6689395, "AIS"),
+    (6689396, "AIS"),
+    (6689397, "AIS"),
+    (6689398, "AIS"),
+    (6690201, "True Move"),
+    (6690202, "AIS"),
+    (6690203, "AIS"),
+    (6690204, "AIS"),
+    (6690205, "AIS"),
+    (6690206, "AIS"),
+    (6690207, "AIS"),
+    (6690208, "AIS"),
+    (6690209, "True Move"),
+    (6690316, "True Move"),
+    (6690317, "True Move"),
+    (6690318, "True Move"),
+    (6690319, "True Move"),
+    (6690330, "True Move"),
+    (6690331, "True Move"),
+    (6690332, "True Move"),
+    (6690333, "True Move"),
+    (6690500, "AIS"),
+    (6690501, "AIS"),
+    (6690502, "AIS"),
+    (6690548, "DTAC"),
+    (6690549, "DTAC"),
+    (6690744, "AIS"),
+    (6690885, "True Move"),
+    (6690886, "True Move"),
+    (6690887, "True Move"),
+    (6690888, "True Move"),
+    (6690889, "True Move"),
+    (6691200, "DTAC"),
+    (6691201, "DTAC"),
+    (6691202, "DTAC"),
+    (6691235, "DTAC"),
+    (6691910, "DTAC"),
+    (6691988, "DTAC"),
+    (6691999, "DTAC"),
+    (6693796, "True Move"),
+    (6693797, "True Move"),
+    (6693798, "True Move"),
+    (6693799, "True Move"),
+    (6694240, "True Move"),
+    (6695130, "AIS"),
+    (6695131, "AIS"),
+    (6695132, "AIS"),
+    (6695149, "AIS"),
+    (6695200, "AIS"),
+    (6695201, "AIS"),
+    (6695202, "AIS"),
+    (6695244, "AIS"),
+    (6695245, "AIS"),
+    (6695377, "AIS"),
+    (6695378, "AIS"),
+    (6695379, "AIS"),
+    (6695380, "AIS"),
+    (6867311, "ATHKL"),
+    (6867312, "ATHKL"),
+    (6867313, "ATHKL"),
+    (6867314, "ATHKL"),
+    (6867315, "ATHKL"),
+    (6867316, "ATHKL"),
+    (6867317, "ATHKL"),
+    (6867318, "ATHKL"),
+    (6867319, "ATHKL"),
+    (6867500, "ATHKL"),
+    (6867501, "ATHKL"),
+    (6867503, "ATHKL"),
+    (6867504, "ATHKL"),
+    (6867505, "ATHKL"),
+    (6867506, "ATHKL"),
+    (6867507, "ATHKL"),
+    (6867508, "ATHKL"),
+    (6867509, "ATHKL"),
+    (6867510, "ATHKL"),
+    (6867511, "ATHKL"),
+    (6867513, "ATHKL"),
+    (6867514, "ATHKL"),
+    (6867515, "ATHKL"),
+    (6867516, "ATHKL"),
+    (6867517, "ATHKL"),
+    (6867518, "ATHKL"),
+    (6867519, "ATHKL"),
+    (6867520, "ATHKL"),
+    (6867521, "ATHKL"),
+    (6867523, "ATHKL"),
+    (6867524, "ATHKL"),
+    (6867525, "ATHKL"),
+    (6867526, "ATHKL"),
+    (6867527, "ATHKL"),
+    (6867528, "ATHKL"),
+    (6867529, "ATHKL"),
+    (6867531, "ATHKL"),
+    (6867532, "ATHKL"),
+    (6867533, "ATHKL"),
+    (6867534, "ATHKL"),
+    (6867535, "ATHKL"),
+    (6867536, "ATHKL"),
+    (6867537, "ATHKL"),
+    (6867539, "ATHKL"),
+    (6867541, "ATHKL"),
+    (6867542, "ATHKL"),
+    (6867543, "ATHKL"),
+    (6867544, "ATHKL"),
+    (6867545, "ATHKL"),
+    (6867546, "ATHKL"),
+    (6867547, "ATHKL"),
+    (6867549, "ATHKL"),
+    (6867551, "ATHKL"),
+    (6867552, "ATHKL"),
+    (6867553, "ATHKL"),
+    (6867554, "ATHKL"),
+    (6867555, "ATHKL"),
+    (6867556, "ATHKL"),
+    (6867557, "ATHKL"),
+    (6867558, "ATHKL"),
+    (6867559, "ATHKL"),
+    (7900197, "Motiv"),
+    (7900198, "Motiv"),
+    (7900199, "Motiv"),
+    (7900330, "Tele2"),
+    (7900331, "Tele2"),
+    (7900332, "Tele2"),
+    (7900333, "Tele2"),
+    (7900334, "Tele2"),
+    (7900335, "Beeline"),
+    (7900336, "Beeline"),
+    (7900337, "Beeline"),
+    (7900338, "Beeline"),
+    (7900339, "Beeline"),
+    (7900340, "Beeline"),
+    (7900341, "Beeline"),
+    (7900342, "Beeline"),
+    (7900343, "Beeline"),
+    (7900344, "Beeline"),
+    (7900345, "Tele2"),
+    (7900346, "Tele2"),
+    (7900347, "Tele2"),
+    (7900348, "Tele2"),
+    (7900349, "Tele2"),
+    (7900370, "Tele2"),
+    (7900371, "Tele2"),
+    (7900372, "Tele2"),
+    (7900373, "Tele2"),
+    (7900374, "Tele2"),
+    (7900375, "Motiv"),
+    (7900376, "Motiv"),
+    (7900377, "Motiv"),
+    (7900378, "Motiv"),
+    (7900379, "Motiv"),
+    (7900400, "Motiv"),
+    (7900401, "Motiv"),
+    (7900402, "Motiv"),
+    (7900403, "Motiv"),
+    (7900404, "Motiv"),
+    (7900405, "Tele2"),
+    (7900406, "Tele2"),
+    (7900407, "Tele2"),
+    (7900408, "Tele2"),
+    (7900409, "Tele2"),
+    (7900480, "Tele2"),
+    (7900481, "Tele2"),
+    (7900482, "Tele2"),
+    (7900483, "Tele2"),
+    (7900484, "Tele2"),
+    (7900485, "Tele2"),
+    (7900486, "Tele2"),
+    (7900487, "Tele2"),
+    (7900488, "Tele2"),
+    (7900900, "MegaFon"),
+    (7901010, "Tele2"),
+    (7901011, "Tele2"),
+    (7901012, "Tele2"),
+    (7901014, "Tele2"),
+    (7901015, "Tele2"),
+    (7901016, "Tele2"),
+    (7901017, "Tele2"),
+    (7901018, "Tele2"),
+    (7901019, "Tele2"),
+    (7901110, "Tele2"),
+    (7901111, "Tele2"),
+    (7901112, "Tele2"),
+    (7901113, "Tele2"),
+    (7901114, "Tele2"),
+    (7901115, "Tele2"),
+    (7901117, "Tele2"),
+    (7901118, "Tele2"),
+    (7901119, "Tele2"),
+    (7901120, "Tele2"),
+    (7901121, "Tele2"),
+    (7901122, "Tele2"),
+    (7901123, "Tele2"),
+    (7901125, "Tele2"),
+    (7901126, "Tele2"),
+    (7901127, "Tele2"),
+    (7901128, "Tele2"),
+    (7901129, "Multiregional Transit Telecom (MTT)"),
+    (7901130, "Tele2"),
+    (7901131, "Tele2"),
+    (7901134, "Tele2"),
+    (7901135, "Tele2"),
+    (7901136, "Tele2"),
+    (7901137, "Tele2"),
+    (7901138, "Tele2"),
+    (7901140, "Tele2"),
+    (7901141, "Tele2"),
+    (7901144, "Tele2"),
+    (7901145, "Tele2"),
+    (7901149, "Tele2"),
+    (7901150, "Tele2"),
+    (7901151, "Sberbank-Telecom"),
+    (7901152, "MTS"),
+    (7901155, "Tele2"),
+    (7901156, "Tele2"),
+    (7901157, "Tele2"),
+    (7901158, "Apex Telecom"),
+    (7901202, "Multiregional Transit Telecom (MTT)"),
+    (7901210, "Tele2"),
+    (7901220, "Tele2"),
+    (7901221, "Multiregional Transit Telecom (MTT)"),
+    (7901222, "Multiregional Transit Telecom (MTT)"),
+    (7901230, "Tele2"),
+    (7901234, "Tele2"),
+    (7901235, "Tele2"),
+    (7901236, "Tele2"),
+    (7901237, "Tele2"),
+    (7901238, "Tele2"),
+    (7901239, "Tele2"),
+    (7901240, "Tele2"),
+    (7901241, "Tele2"),
+    (7901242, "Tele2"),
+    (7901243, "Tele2"),
+    (7901410, "Tele2"),
+    (7901412, "Tele2"),
+    (7901413, "Tele2"),
+    (7901414, "Tele2"),
+    (7901415, "MTS"),
+    (7901416, "MTS"),
+    (7901418, "MTS"),
+    (7901419, "Tele2"),
+    (7901460, "Tele2"),
+    (7901461, "Tele2"),
+    (7901462, "Tele2"),
+    (7901463, "Tele2"),
+    (7901464, "Tele2"),
+    (7901465, "Tele2"),
+    (7901466, "Tele2"),
+    (7901470, "Tele2"),
+    (7901471, "Tele2"),
+    (7901475, "MTS"),
+    (7901476, "MTS"),
+    (7901477, "Multiregional Transit Telecom (MTT)"),
+    (7901478, "MTS"),
+    (7901479, "Tele2"),
+    (7901621, "Tele2"),
+    (7901623, "Tele2"),
+    (7901624, "Tele2"),
+    (7901625, "Tele2"),
+    (7901626, "Dani CALL"),
+    (7901627, "Dani CALL"),
+    (7901628, "Dani CALL"),
+    (7901630, "Tele2"),
+    (7901631, "Tele2"),
+    (7901632, "Tele2"),
+    (7901633, "Tele2"),
+    (7901634, "Tele2"),
+    (7901635, "Tele2"),
+    (7901640, "Tele2"),
+    (7901641, "Tele2"),
+    (7901642, "Tele2"),
+    (7901644, "Tele2"),
+    (7901645, "Tele2"),
+    (7901646, "Tele2"),
+    (7901647, "Tele2"),
+    (7901808, "Multiregional Transit Telecom (MTT)"),
+    (7901820, "Tele2"),
+    (7901821, "Tele2"),
+    (7901822, "Tele2"),
+    (7901847, "Tele2"),
+    (7901870, "Tele2"),
+    (7901871, "Tele2"),
+    (7902240, "MegaFon"),
+    (7902241, "MTS"),
+    (7902242, "MTS"),
+    (7902243, "MTS"),
+    (7902249, "MegaFon"),
+    (7902310, "Tele2"),
+    (7902311, "Tele2"),
+    (7902312, "Tele2"),
+    (7902313, "Tele2"),
+    (7902314, "Tele2"),
+    (7902315, "MTS"),
+    (7902316, "MTS"),
+    (7902317, "MTS"),
+    (7902318, "MTS"),
+    (7902319, "MTS"),
+    (7902325, "Tele2"),
+    (7902326, "Tele2"),
+    (7902329, "Tele2"),
+    (7902350, "MegaFon"),
+    (7902351, "MegaFon"),
+    (7902352, "MTS"),
+    (7902353, "MTS"),
+    (7902354, "MTS"),
+    (7902355, "Tele2"),
+    (7902356, "Tele2"),
+    (7902357, "Tele2"),
+    (7902358, "Tele2"),
+    (7902359, "MegaFon"),
+    (7902400, "Tele2"),
+    (7902403, "Tele2"),
+    (7902404, "Tele2"),
+    (7902405, "Tele2"),
+    (7902406, "Tele2"),
+    (7902407, "Tele2"),
+    (7902408, "Tele2"),
+    (7902409, "Tele2"),
+    (7902420, "Tele2"),
+    (7902421, "Tele2"),
+    (7902422, "Tele2"),
+    (7902423, "Tele2"),
+    (7902425, "Tele2"),
+    (7902426, "Tele2"),
+    (7902427, "Tele2"),
+    (7902428, "Tele2"),
+    (7902429, "Tele2"),
+    (7902461, "Tele2"),
+    (7902462, "Tele2"),
+    (7902463, "Tele2"),
+    (7902464, "Tele2"),
+    (7902465, "Tele2"),
+    (7902466, "Tele2"),
+    (7902467, "Tele2"),
+    (7902468, "Tele2"),
+    (7902660, "MegaFon"),
+    (7902661, "MegaFon"),
+    (7902662, "MegaFon"),
+    (7902663, "MegaFon"),
+    (7902665, "MegaFon"),
+    (7902710, "Beeline"),
+    (7902711, "TMT"),
+    (7902712, "MTS"),
+    (7902713, "MTS"),
+    (7902714, "MegaFon"),
+    (7902715, "TMT"),
+    (7902716, "MegaFon"),
+    (7902717, "Beeline"),
+    (7902718, "TMT"),
+    (7902719, "TMT"),
+    (7902746, "MTS"),
+    (7902747, "MTS"),
+    (7902748, "MegaFon"),
+    (7902749, "MegaFon"),
+    (7902953, "MegaFon"),
+    (7902954, "MegaFon"),
+    (7902955, "MegaFon"),
+    (7902993, "MegaFon"),
+    (7902994, "MegaFon"),
+    (7902995, "MegaFon"),
+    (7904708, "MegaFon"),
+    (7904709, "MegaFon"),
+    (7904710, "MegaFon"),
+    (7904711, "MegaFon"),
+    (7904724, "MTS"),
+    (7904725, "MTS"),
+    (7904726, "Beeline"),
+    (7904727, "Beeline"),
+    (7904730, "MegaFon"),
+    (7904731, "MegaFon"),
+    (7904732, "MegaFon"),
+    (7904733, "MegaFon"),
+    (7904734, "MegaFon"),
+    (7904735, "MTS"),
+    (7904736, "MTS"),
+    (7904737, "MTS"),
+    (7904738, "MTS"),
+    (7904739, "MTS"),
+    (7904955, "Tele2"),
+    (7904956, "Tele2"),
+    (7904957, "Tele2"),
+    (7904958, "Tele2"),
+    (7904959, "Tele2"),
+    (7908344, "MegaFon"),
+    (7908345, "MegaFon"),
+    (7908364, "Beeline"),
+    (7908370, "MTS"),
+    (7908371, "MTS"),
+    (7908372, "MTS"),
+    (7908373, "MegaFon"),
+    (7908374, "MegaFon"),
+    (7908375, "Beeline"),
+    (7908376, "Beeline"),
+    (7908377, "Beeline"),
+    (7908378, "Beeline"),
+    (7908379, "Beeline"),
+    (7908430, "MegaFon"),
+    (7908431, "MegaFon"),
+    (7908432, "MegaFon"),
+    (7908433, "MegaFon"),
+    (7908434, "MegaFon"),
+    (7908435, "Beeline"),
+    (7908436, "Beeline"),
+    (7908437, "Beeline"),
+    (7908438, "Beeline"),
+    (7908439, "Beeline"),
+    (7908460, "Beeline"),
+    (7908461, "Beeline"),
+    (7908462, "Beeline"),
+    (7908463, "Beeline"),
+    (7908464, "Beeline"),
+    (7908609, "Motiv"),
+    (7908850, "MegaFon"),
+    (7908851, "MegaFon"),
+    (7936110, "MegaFon"),
+    (7936111, "MegaFon"),
+    (7936222, "MegaFon"),
+    (7936333, "MegaFon"),
+    (7936555, "MegaFon"),
+    (7950665, "TMT"),
+    (7950666, "TMT"),
+    (7950667, "TMT"),
+    (7950668, "Beeline"),
+    (7950880, "Beeline"),
+    (7950888, "MTS"),
+    (7950889, "MTS"),
+    (7950940, "MTS"),
+    (7950941, "MTS"),
+    (7950942, "MTS"),
+    (7950943, "MTS"),
+    (7950944, "MTS"),
+    (7952725, "Motiv"),
+    (7952726, "Motiv"),
+    (7952727, "Motiv"),
+    (7952728, "Motiv"),
+    (7952729, "Motiv"),
+    (7952740, "Motiv"),
+    (7952741, "Motiv"),
+    (7952742, "Motiv"),
+    (7952743, "Motiv"),
+    (7952744, "Motiv"),
+    (7953039, "Motiv"),
+    (7958010, "Tele2"),
+    (7958011, "Tele2"),
+    (7958012, "Tele2"),
+    (7958013, "Tele2"),
+    (7958030, "Tele2"),
+    (7958031, "Tele2"),
+    (7958032, "Tele2"),
+    (7958033, "Tele2"),
+    (7958034, "Tele2"),
+    (7958035, "Tele2"),
+    (7958036, "Tele2"),
+    (7958037, "Tele2"),
+    (7958038, "Tele2"),
+    (7958040, "Tele2"),
+    (7958041, "Tele2"),
+    (7958190, "Tele2"),
+    (7958191, "Tele2"),
+    (7958192, "Tele2"),
+    (7958193, "Tele2"),
+    (7958195, "Tele2"),
+    (7958196, "Tele2"),
+    (7958198, "Tele2"),
+    (7958199, "Tele2"),
+    (7958510, "Tele2"),
+    (7958511, "Tele2"),
+    (7958512, "Tele2"),
+    (7958513, "Tele2"),
+    (7958514, "Tele2"),
+    (7958515, "Tele2"),
+    (7958516, "Tele2"),
+    (7958517, "Center 2M"),
+    (7958518, "Center 2M"),
+    (7958519, "Center 2M"),
+    (7958530, "Sonet"),
+    (7958531, "Sonet"),
+    (7958532, "Sonet"),
+    (7958533, "Sonet"),
+    (7958534, "Center 2M"),
+    (7958535, "Tele2"),
+    (7958541, "Tele2"),
+    (7958542, "Tele2"),
+    (7958543, "Tele2"),
+    (7958544, "Tele2"),
+    (7958545, "Tele2"),
+    (7958546, "Tele2"),
+    (7958547, "Tele2"),
+    (7958548, "Tele2"),
+    (7958549, "Tele2"),
+    (7958550, "Tele2"),
+    (7958551, "Tele2"),
+    (7958552, "Tele2"),
+    (7958553, "Tele2"),
+    (7958556, "Tele2"),
+    (7958557, "Tele2"),
+    (7958558, "Tele2"),
+    (7958559, "Tele2"),
+    (7958570, "Tele2"),
+    (7958571, "Tele2"),
+    (7958572, "Tele2"),
+    (7958573, "Tele2"),
+    (7958574, "Tele2"),
+    (7958575, "Tele2"),
+    (7958576, "Tele2"),
+    (7958577, "Sprint"),
+    (7958579, "Intersvyaz-2"),
+    (7958580, "Sprint"),
+    (7958581, "Sprint"),
+    (7958582, "Sprint"),
+    (7958583, "Sprint"),
+    (7958590, "Tele2"),
+    (7958592, "Tele2"),
+    (7958593, "Tele2"),
+    (7958594, "Tele2"),
+    (7958596, "Multiregional Transit Telecom (MTT)"),
+    (7958597, "Multiregional Transit Telecom (MTT)"),
+    (7958598, "Multiregional Transit Telecom (MTT)"),
+    (7958599, "Multiregional Transit Telecom (MTT)"),
+    (7958830, "MTS"),
+    (7958831, "MTS"),
+    (7958832, "MTS"),
+    (7958833, "MTS"),
+    (7958834, "MTS"),
+    (7958835, "Tele2"),
+    (7958836, "Tele2"),
+    (7958837, "Tele2"),
+    (7958838, "Tele2"),
+    (7958839, "Tele2"),
+    (7970881, "Tele2"),
+    (7978254, "Sevastopol TELECOM"),
+    (7978255, "MTS"),
+    (7978256, "MTS"),
+    (7978257, "MTS"),
+    (7978258, "MTS"),
+    (7978259, "MTS"),
+    (7980999, "Beeline"),
+    (7983888, "Beeline"),
+    (7983999, "Beeline"),
+    (7986666, "Beeline"),
+    (7991010, "Tele2"),
+    (7991011, "Tele2"),
+    (7991012, "Tele2"),
+    (7991013, "Tele2"),
+    (7991014, "Tele2"),
+    (7991015, "Tele2"),
+    (7991016, "Tele2"),
+    (7991017, "Tele2"),
+    (7991019, "Tele2"),
+    (7991315, "Tele2"),
+    (7991316, "Tele2"),
+    (7991317, "Tele2"),
+    (7991318, "Tele2"),
+    (7991319, "Tele2"),
+    (7991330, "Tele2"),
+    (7991331, "Tele2"),
+    (7991332, "Tele2"),
+    (7991342, "Tele2"),
+    (7991343, "Tele2"),
+    (7991344, "Tele2"),
+    (7991345, "Tele2"),
+    (7991346, "Tele2"),
+    (7991347, "Tele2"),
+    (7991348, "Tele2"),
+    (7991349, "Tele2"),
+    (7993030, "Tele2"),
+    (7993031, "Tele2"),
+    (7993032, "Tele2"),
+    (7993033, "Tele2"),
+    (7993034, "Tele2"),
+    (7993035, "Tele2"),
+    (7994250, "Tele2"),
+    (7994270, "Tele2"),
+    (7994271, "Tele2"),
+    (7995210, "Tele2"),
+    (7995211, "Tele2"),
+    (7995213, "Tele2"),
+    (7995770, "Sberbank-Telecom"),
+    (7995777, "Sberbank-Telecom"),
+    (7995990, "Sberbank-Telecom"),
+    (7995991, "Lyca Mobile"),
+    (7995992, "Lyca Mobile"),
+    (7995993, "Lyca Mobile"),
+    (7995994, "Lyca Mobile"),
+    (7995995, "Lyca Mobile"),
+    (7995996, "Tele2"),
+    (7995997, "Tele2"),
+    (7995998, "Tele2"),
+    (7995999, "Internod"),
+    (7996010, "Tele2"),
+    (7996013, "Tele2"),
+    (7996014, "Tele2"),
+    (7996015, "Tele2"),
+    (7996016, "Tele2"),
+    (7996017, "Tele2"),
+    (7996018, "Tele2"),
+    (7996019, "Tele2"),
+    (7996200, "Tele2"),
+    (7996201, "Tele2"),
+    (7996202, "Tele2"),
+    (7996203, "Tele2"),
+    (7996204, "Tele2"),
+    (7996205, "MegaFon"),
+    (7996206, "MegaFon"),
+    (7996207, "MegaFon"),
+    (7996208, "MegaFon"),
+    (7996209, "MegaFon"),
+    (7996299, "Tele2"),
+    (7996300, "Tele2"),
+    (7996301, "Tele2"),
+    (7996302, "Tele2"),
+    (7996699, "Tele2"),
+    (7996750, "Tele2"),
+    (7996751, "Tele2"),
+    (7996752, "Tele2"),
+    (7996755, "Tele2"),
+    (7996756, "Tele2"),
+    (7996757, "Tele2"),
+    (7996758, "Tele2"),
+    (7996759, "Tele2"),
+    (7996810, "Tele2"),
+    (7996811, "Tele2"),
+    (7996812, "Tele2"),
+    (7996813, "Tele2"),
+    (7996814, "Tele2"),
+    (7996849, "Tele2"),
+    (7996850, "Tele2"),
+    (7996851, "Tele2"),
+    (7996852, "Tele2"),
+    (7996853, "Tele2"),
+    (7996854, "Tele2"),
+    (7996855, "Tele2"),
+    (8160100, "Softbank"),
+    (8160110, "KDDI"),
+    (8160120, "Rakuten Communications"),
+    (8160130, "Okinawa Cellular"),
+    (8160140, "NTT Docomo"),
+    (8170250, "Softbank"),
+    (8170340, "Softbank"),
+    (8170341, "Softbank"),
+    (8170501, "Softbank"),
+    (8170502, "Softbank"),
+    (8170503, "Softbank"),
+    (8170504, "Softbank"),
+    (8170505, "Softbank"),
+    (8170506, "Softbank"),
+    (8170507, "Softbank"),
+    (8170508, "Softbank"),
+    (8170509, "Softbank"),
+    (8170581, "Softbank"),
+    (8170582, "KDDI"),
+    (8170583, "KDDI"),
+    (8170584, "KDDI"),
+    (8170585, "KDDI"),
+    (8170586, "KDDI"),
+    (8170587, "KDDI"),
+    (8170588, "KDDI"),
+    (8170589, "KDDI"),
+    (8170630, "Softbank"),
+    (8170631, "Softbank"),
+    (8170632, "Softbank"),
+    (8170633, "Softbank"),
+    (8170634, "Softbank"),
+    (8170635, "Softbank"),
+    (8170636, "Softbank"),
+    (8170680, "Softbank"),
+    (8170681, "Softbank"),
+    (8170682, "Softbank"),
+    (8170683, "Softbank"),
+    (8170684, "Softbank"),
+    (8170685, "Softbank"),
+    (8170810, "NTT Docomo"),
+    (8170811, "NTT Docomo"),
+    (8170812, "NTT Docomo"),
+    (8170817, "KDDI"),
+    (8170818, "KDDI"),
+    (8170819, "KDDI"),
+    (8180769, "NTT Docomo"),
+    (8180790, "NTT Docomo"),
+    (8180791, "NTT Docomo"),
+    (8180792, "NTT Docomo"),
+    (8190111, "NTT Docomo"),
+    (8190112, "NTT Docomo"),
+    (8190186, "NTT Docomo"),
+    (8190187, "NTT Docomo"),
+    (8190188, "NTT Docomo"),
+    (8190189, "NTT Docomo"),
+    (8190194, "Okinawa Cellular"),
+    (8190198, "NTT Docomo"),
+    (8190199, "NTT Docomo"),
+    (8190290, "KDDI"),
+    (8190291, "KDDI"),
+    (8190293, "KDDI"),
+    (8190294, "KDDI"),
+    (8190340, "NTT Docomo"),
+    (8190341, "Softbank"),
+    (8190342, "Softbank"),
+    (8190343, "KDDI"),
+    (8190344, "KDDI"),
+    (8190345, "KDDI"),
+    (8190346, "NTT Docomo"),
+    (8190347, "NTT Docomo"),
+    (8190348, "NTT Docomo"),
+    (8190349, "NTT Docomo"),
+    (8190398, "KDDI"),
+    (8190399, "KDDI"),
+    (8190410, "Softbank"),
+    (8190415, "KDDI"),
+    (8190416, "KDDI"),
+    (8190417, "KDDI"),
+    (8190418, "KDDI"),
+    (8190419, "KDDI"),
+    (8190426, "Softbank"),
+    (8190427, "Softbank"),
+    (8190428, "Softbank"),
+    (8190429, "Softbank"),
+    (8190669, "NTT Docomo"),
+    (8190676, "NTT Docomo"),
+    (8190677, "NTT Docomo"),
+    (8190678, "NTT Docomo"),
+    (8190679, "NTT Docomo"),
+    (8190683, "KDDI"),
+    (8190685, "KDDI"),
+    (8190686, "Okinawa Cellular"),
+    (8190687, "KDDI"),
+    (8190800, "NTT Docomo"),
+    (8190801, "NTT Docomo"),
+    (8190802, "NTT Docomo"),
+    (8190803, "NTT Docomo"),
+    (8190804, "KDDI"),
+    (8190805, "KDDI"),
+    (8190806, "KDDI"),
+    (8190807, "Softbank"),
+    (8190808, "KDDI"),
+    (8190809, "Softbank"),
+    (8190834, "Softbank"),
+    (8190835, "Softbank"),
+    (8190841, "NTT Docomo"),
+    (8190842, "NTT Docomo"),
+    (8190843, "KDDI"),
+    (8190844, "KDDI"),
+    (8190857, "NTT Docomo"),
+    (8190858, "NTT Docomo"),
+    (8190859, "NTT Docomo"),
+    (8190860, "Softbank"),
+    (8190861, "Softbank"),
+    (8190862, "Softbank"),
+    (8190965, "Softbank"),
+    (8190968, "Softbank"),
+    (8190969, "Softbank"),
+    (8210590, "SKTellink"),
+    (8210591, "SKTellink"),
+    (8210592, "SKTellink"),
+    (8210593, "SKTellink"),
+    (8210594, "SKTellink"),
+    (8210595, "SKTellink"),
+    (8524441, "Hutchison Telecom"),
+    (8524442, "Hutchison Telecom"),
+    (8524607, "Multibyte"),
+    (8524608, "HKT"),
+    (8524609, "HKT"),
+    (8524610, "HKT"),
+    (8524611, "HKT"),
+    (8524612, "Sun Mobile"),
+    (8524613, "HKT"),
+    (8524614, "HKT"),
+    (8524615, "China Unicom"),
+    (8524616, "China Unicom"),
+    (8524617, "China Mobile"),
+    (8524618, "China Mobile"),
+    (8524619, "China Telecom Global Limited"),
+    (8524620, "China Mobile"),
+    (8524621, "China Mobile"),
+    (8524622, "China Mobile"),
+    (8524623, "China Mobile"),
+    (8524624, "China Mobile"),
+    (8524625, "SmarTone"),
+    (8524626, "SmarTone"),
+    (8524627, "SmarTone"),
+    (8524628, "SmarTone"),
+    (8524629, "SmarTone"),
+    (8524640, "HKT"),
+    (8524641, "SmarTone"),
+    (8524642, "SmarTone"),
+    (8524643, "SmarTone"),
+    (8524644, "SmarTone"),
+    (8524645, "SmarTone"),
+    (8524647, "HKT"),
+    (8524648, "Multibyte"),
+    (8524649, "CITIC"),
+    (8524650, "China Unicom"),
+    (8524651, "China Unicom"),
+    (8524652, "China Unicom"),
+    (8524653, "China Unicom"),
+    (8524654, "HKT"),
+    (8524655, "HKT"),
+    (8524656, "HKT"),
+    (8524657, "HKT"),
+    (8524658, "HKT"),
+    (8524659, "China Mobile"),
+    (8524660, "China Mobile"),
+    (8524661, "China Mobile"),
+    (8524662, "China Mobile"),
+    (8524663, "China Mobile"),
+    (8524664, "China Mobile"),
+    (8524665, "HKT"),
+    (8524666, "HKT"),
+    (8524678, "HKT"),
+    (8524679, "HKT"),
+    (8524699, "South China Telecommunications"),
+    (8524726, "Hutchison Telecom"),
+    (8524727, "Hutchison Telecom"),
+    (8524728, "Hutchison Telecom"),
+    (8524729, "Hutchison Telecom"),
+    (8524730, "Hutchison Telecom"),
+    (8524740, "China Mobile"),
+    (8524741, "China Mobile"),
+    (8524742, "China Mobile"),
+    (8524743, "HKT"),
+    (8524744, "HKT"),
+    (8524748, "China Telecom Global Limited"),
+    (8525230, "HKT"),
+    (8525231, "HKT"),
+    (8525232, "HKT"),
+    (8525233, "HKT"),
+    (8525234, "Lycamobile"),
+    (8525235, "21Vianet"),
+    (8525236, "SmarTone"),
+    (8525237, "SmarTone"),
+    (8525238, "SmarTone"),
+    (8525239, "SmarTone"),
+    (8525280, "HKT"),
+    (8525281, "HKT"),
+    (8525282, "HKT"),
+    (8525283, "Multibyte"),
+    (8525284, "SmarTone"),
+    (8525285, "SmarTone"),
+    (8525286, "SmarTone"),
+    (8525287, "SmarTone"),
+    (8525288, "SmarTone"),
+    (8525289, "3"),
+    (8525540, "HKT"),
+    (8525541, "HKT"),
+    (8525542, "HKT"),
+    (8525543, "HKT"),
+    (8525544, "HKT"),
+    (8525545, "HKT"),
+    (8525546, "HKT"),
+    (8525547, "HKT"),
+    (8525548, "HKT"),
+    (8525700, "Multibyte"),
+    (8525701, "Multibyte"),
+    (8525702, "HKT"),
+    (8525703, "HKT"),
+    (8525704, "Multibyte"),
+    (8525705, "Multibyte"),
+    (8525706, "Hong Kong Broadband Network"),
+    (8525707, "China Mobile"),
+    (8525708, "China Mobile"),
+    (8525709, "CITIC"),
+    (8525718, "Lycamobile"),
+    (8525719, "Lycamobile"),
+    (8525740, "SmarTone"),
+    (8525741, "SmarTone"),
+    (8525742, "SmarTone"),
+    (8525743, "SmarTone"),
+    (8525744, "SmarTone"),
+    (8525745, "Multibyte"),
+    (8525746, "Multibyte"),
+    (8525747, "Multibyte"),
+    (8525748, "Multibyte"),
+    (8525749, "HKT"),
+    (8525768, "Lycamobile"),
+    (8525769, "Lycamobile"),
+    (8525906, "21Vianet"),
+    (8525907, "21Vianet"),
+    (8525908, "Lycamobile"),
+    (8525909, "Lycamobile"),
+    (8526260, "3"),
+    (8526261, "Webbing"),
+    (8526360, "HKT"),
+    (8526361, "China Unicom"),
+    (8526362, "HKT"),
+    (8526660, "China Unicom"),
+    (8526661, "China Unicom"),
+    (8526662, "China Unicom"),
+    (8526663, "China Unicom"),
+    (8526664, "HKT"),
+    (8526665, "HKT"),
+    (8527071, "SmarTone"),
+    (8527072, "SmarTone"),
+    (8527073, "SmarTone"),
+    (8527074, "SmarTone"),
+    (8527075, "SmarTone"),
+    (8527076, "China Mobile"),
+    (8527077, "Hutchison Telecom"),
+    (8527078, "Hutchison Telecom"),
+    (8527080, "HKT"),
+    (8527081, "HKT"),
+    (8527082, "HKT"),
+    (8527083, "HKT"),
+    (8527084, "HKT"),
+    (8527085, "SmarTone"),
+    (8527086, "SmarTone"),
+    (8527087, "SmarTone"),
+    (8527088, "SmarTone"),
+    (8528480, "HKT"),
+    (8528481, "HKT"),
+    (8528482, "HKT"),
+    (8528483, "HKT"),
+    (8528485, "SmarTone"),
+    (8528486, "SmarTone"),
+    (8528487, "SmarTone"),
+    (8528488, "SmarTone"),
+    (8528489, "SmarTone"),
+    (8529290, "Multibyte"),
+    (8529291, "HKT"),
+    (8529390, "HKT"),
+    (8529391, "HKT"),
+    (8536200, "SmarTone"),
+    (8536242, "CTM"),
+    (8536243, "CTM"),
+    (8536244, "SmarTone"),
+    (8536245, "SmarTone"),
+    (8536250, "CTM"),
+    (8536251, "CTM"),
+    (8536252, "CTM"),
+    (8536253, "CTM"),
+    (8536264, "CTM"),
+    (8536265, "CTM"),
+    (8536266, "CTM"),
+    (8536267, "CTM"),
+    (8536270, "SmarTone"),
+    (8536271, "SmarTone"),
+    (8536272, "CTM"),
+    (8536273, "CTM"),
+    (8536274, "CTM"),
+    (8536275, "CTM"),
+    (8536276, "3"),
+    (8536277, "3"),
+    (8536278, "3"),
+    (8536279, "3"),
+    (8536292, "3"),
+    (8536293, "3"),
+    (8536294, "3"),
+    (8536295, "3"),
+    (8536300, "CTM"),
+    (8536301, "CTM"),
+    (8536302, "CTM"),
+    (8536309, "CTM"),
+    (8536320, "3"),
+    (8536321, "3"),
+    (8536322, "China Telecom"),
+    (8536323, "China Telecom"),
+    (8536336, "3"),
+    (8536337, "3"),
+    (8536338, "3"),
+    (8536339, "3"),
+    (8536340, "China Telecom"),
+    (8536341, "China Telecom"),
+    (8536342, "China Telecom"),
+    (8536343, "China Telecom"),
+    (8536344, "3"),
+    (8536345, "CTM"),
+    (8536346, "CTM"),
+    (8536347, "CTM"),
+    (8536348, "CTM"),
+    (8536349, "CTM"),
+    (8536378, "3"),
+    (8536379, "3"),
+    (8536386, "China Telecom"),
+    (8536387, "China Telecom"),
+    (8536388, "China Telecom"),
+    (8536389, "China Telecom"),
+    (8536390, "China Telecom"),
+    (8536391, "China Telecom"),
+    (8536398, "3"),
+    (8536399, "3"),
+    (8536500, "3"),
+    (8536501, "3"),
+    (8536502, "3"),
+    (8536503, "3"),
+    (8536504, "3"),
+    (8536505, "CTM"),
+    (8536506, "CTM"),
+    (8536507, "CTM"),
+    (8536508, "CTM"),
+    (8536509, "CTM"),
+    (8536515, "CTM"),
+    (8536516, "CTM"),
+    (8536517, "CTM"),
+    (8536518, "CTM"),
+    (8536519, "CTM"),
+    (8536520, "China Telecom"),
+    (8536521, "China Telecom"),
+    (8536522, "China Telecom"),
+    (8536523, "China Telecom"),
+    (8536532, "3"),
+    (8536533, "3"),
+    (8536534, "3"),
+    (8536535, "3"),
+    (8536540, "3"),
+    (8536541, "3"),
+    (8536543, "China Telecom"),
+    (8536544, "China Telecom"),
+    (8536545, "CTM"),
+    (8536546, "CTM"),
+    (8536548, "SmarTone"),
+    (8536549, "SmarTone"),
+    (8536556, "China Telecom"),
+    (8536557, "China Telecom"),
+    (8536558, "China Telecom"),
+    (8536559, "China Telecom"),
+    (8536570, "China Telecom"),
+    (8536571, "China Telecom"),
+    (8536572, "China Telecom"),
+    (8536573, "China Telecom"),
+    (8536586, "CTM"),
+    (8536587, "CTM"),
+    (8536588, "CTM"),
+    (8536589, "CTM"),
+    (8536598, "China Telecom"),
+    (8536599, "China Telecom"),
+    (8536601, "CTM"),
+    (8536602, "SmarTone"),
+    (8536603, "3"),
+    (8536604, "SmarTone"),
+    (8536605, "China Telecom"),
+    (8536610, "3"),
+    (8536611, "3"),
+    (8536612, "CTM"),
+    (8536613, "CTM"),
+    (8536614, "SmarTone"),
+    (8536615, "SmarTone"),
+    (8536616, "3"),
+    (8536617, "3"),
+    (8536618, "CTM"),
+    (8536619, "CTM"),
+    (8536640, "SmarTone"),
+    (8536641, "SmarTone"),
+    (8536647, "CTM"),
+    (8536649, "China Telecom"),
+    (8536656, "3"),
+    (8536657, "3"),
+    (8536670, "China Telecom"),
+    (8536671, "China Telecom"),
+    (8536672, "CTM"),
+    (8536673, "SmarTone"),
+    (8536674, "3"),
+    (8536675, "CTM"),
+    (8536676, "3"),
+    (8536677, "CTM"),
+    (8536678, "SmarTone"),
+    (8536679, "CTM"),
+    (8536690, "Guangxing Communication Co"),
+    (8536691, "Guangxing Communication Co"),
+    (8536692, "CTM"),
+    (8536693, "CTM"),
+    (8536694, "3"),
+    (8536695, "3"),
+    (8536696, "CTM"),
+    (8536697, "3"),
+    (8536698, "CTM"),
+    (8536699, "China Telecom"),
+    (8536800, "CTM"),
+    (8536801, "CTM"),
+    (8536802, "CTM"),
+    (8536803, "CTM"),
+    (8536804, "CTM"),
+    (8536805, "3"),
+    (8536806, "3"),
+    (8536807, "3"),
+    (8536808, "3"),
+    (8536809, "3"),
+    (8536810, "CTM"),
+    (8536811, "CTM"),
+    (8536812, "CTM"),
+    (8536813, "CTM"),
+    (8536814, "CTM"),
+    (8536815, "SmarTone"),
+    (8536816, "SmarTone"),
+    (8536817, "SmarTone"),
+    (8536818, "SmarTone"),
+    (8536819, "SmarTone"),
+    (8536840, "3"),
+    (8536841, "3"),
+    (8536842, "3"),
+    (8536843, "3"),
+    (8536844, "3"),
+    (8536845, "CTM"),
+    (8536846, "CTM"),
+    (8536847, "CTM"),
+    (8536848, "CTM"),
+    (8536849, "CTM"),
+    (8536850, "3"),
+    (8536851, "3"),
+    (8536852, "3"),
+    (8536853, "3"),
+    (8536854, "3"),
+    (8536855, "CTM"),
+    (8536856, "CTM"),
+    (8536857, "CTM"),
+    (8536858, "CTM"),
+    (8536859, "CTM"),
+    (8536870, "SmarTone"),
+    (8536871, "SmarTone"),
+    (8536872, "SmarTone"),
+    (8536873, "SmarTone"),
+    (8536874, "SmarTone"),
+    (8536875, "3"),
+    (8536876, "3"),
+    (8536877, "3"),
+    (8536878, "3"),
+    (8536879, "3"),
+    (8536880, "CTM"),
+    (8536881, "CTM"),
+    (8536882, "CTM"),
+    (8536883, "CTM"),
+    (8536884, "CTM"),
+    (8536885, "China Telecom"),
+    (8536886, "China Telecom"),
+    (8536887, "China Telecom"),
+    (8536888, "China Telecom"),
+    (8536889, "China Telecom"),
+    (8553248, "Telecom Cambodia"),
+    (8553348, "Telecom Cambodia"),
+    (8553448, "Telecom Cambodia"),
+    (8553548, "Telecom Cambodia"),
+    (8553648, "Telecom Cambodia"),
+    (8554248, "Telecom Cambodia"),
+    (8554348, "Telecom Cambodia"),
+    (8554448, "Telecom Cambodia"),
+    (8555248, "Telecom Cambodia"),
+    (8555348, "Telecom Cambodia"),
+    (8555448, "Telecom Cambodia"),
+    (8555548, "Telecom Cambodia"),
+    (8556248, "Telecom Cambodia"),
+    (8556348, "Telecom Cambodia"),
+    (8556448, "Telecom Cambodia"),
+    (8556548, "Telecom Cambodia"),
+    (8557248, "Metfone"),
+    (8557348, "Metfone"),
+    (8557448, "Metfone"),
+    (8557548, "Metfone"),
+    (8864000, "Chunghwa Telecom"),
+    (8869006, "Chunghwa Telecom"),
+    (8869007, "Chunghwa Telecom"),
+    (8869008, "Chunghwa Telecom"),
+    (8869009, "Chunghwa Telecom"),
+    (8869010, "Taiwan Mobile"),
+    (8869011, "Taiwan Mobile"),
+    (8869012, "Taiwan Mobile"),
+    (8869013, "Taiwan Mobile"),
+    (8869014, "Taiwan Mobile"),
+    (8869020, "T Star"),
+    (8869021, "T Star"),
+    (8869022, "T Star"),
+    (8869023, "T Star"),
+    (8869024, "T Star"),
+    (8869025, "T Star"),
+    (8869026, "T Star"),
+    (8869040, "Taiwan Mobile"),
+    (8869041, "Taiwan Mobile"),
+    (8869042, "Taiwan Mobile"),
+    (8869140, "Taiwan Mobile"),
+    (8869141, "Taiwan Mobile"),
+    (8869142, "Taiwan Mobile"),
+    (8869240, "Taiwan Mobile"),
+    (8869241, "Taiwan Mobile"),
+    (8869310, "FarEasTone"),
+    (8869311, "FarEasTone"),
+    (8869312, "FarEasTone"),
+    (8869313, "FarEasTone"),
+    (8869540, "FarEasTone"),
+    (8869541, "FarEasTone"),
+    (8869600, "Taiwan Mobile"),
+    (8869601, "Taiwan Mobile"),
+    (8869602, "Taiwan Mobile"),
+    (8869603, "Taiwan Mobile"),
+    (8869604, "Taiwan Mobile"),
+    (8869605, "FarEasTone"),
+    (8869606, "FarEasTone"),
+    (8869607, "FarEasTone"),
+    (8869608, "FarEasTone"),
+    (8869609, "FarEasTone"),
+    (8869610, "Taiwan Mobile"),
+    (8869611, "Taiwan Mobile"),
+    (8869612, "Taiwan Mobile"),
+    (8869613, "Taiwan Mobile"),
+    (8869614, "Taiwan Mobile"),
+    (8869615, "Taiwan Mobile"),
+    (8869620, "FarEasTone"),
+    (8869650, "Chunghwa Telecom"),
+    (8869651, "Chunghwa Telecom"),
+    (8869652, "Chunghwa Telecom"),
+    (8869653, "Chunghwa Telecom"),
+    (8869654, "Chunghwa Telecom"),
+    (8869655, "Chunghwa Telecom"),
+    (8869656, "Chunghwa Telecom"),
+    (8869657, "Chunghwa Telecom"),
+    (8869658, "Chunghwa Telecom"),
+    (8869660, "Taiwan Mobile"),
+    (8869661, "Taiwan Mobile"),
+    (8869662, "Taiwan Mobile"),
+    (8869663, "Taiwan Mobile"),
+    (8869664, "Chunghwa Telecom"),
+    (8869665, "Chunghwa Telecom"),
+    (8869666, "Chunghwa Telecom"),
+    (8869667, "Chunghwa Telecom"),
+    (8869668, "FarEasTone"),
+    (8869669, "FarEasTone"),
+    (8869670, "FarEasTone"),
+    (8869671, "FarEasTone"),
+    (8869672, "FarEasTone"),
+    (8869673, "FarEasTone"),
+    (8869680, "FarEasTone"),
+    (8869681, "FarEasTone"),
+    (8869682, "Asia-Pacific Telecom"),
+    (8869683, "Asia-Pacific Telecom"),
+    (8869684, "Asia-Pacific Telecom"),
+    (8869685, "Asia-Pacific Telecom"),
+    (8869686, "T Star"),
+    (8869687, "T Star"),
+    (8869688, "T Star"),
+    (8869689, "T Star"),
+    (8869710, "Taiwan Mobile"),
+    (8869711, "Taiwan Mobile"),
+    (8869712, "T Star"),
+    (8869713, "T Star"),
+    (8869714, "T Star"),
+    (8869715, "T Star"),
+    (8869716, "T Star"),
+    (8869717, "T Star"),
+    (8869718, "T Star"),
+    (8869740, "Chunghwa Telecom"),
+    (8869741, "Chunghwa Telecom"),
+    (8869742, "FarEasTone"),
+    (8869743, "FarEasTone"),
+    (8869744, "Taiwan Mobile"),
+    (8869745, "Taiwan Mobile"),
+    (8869770, "Asia-Pacific Telecom"),
+    (8869771, "Asia-Pacific Telecom"),
+    (8869772, "Asia-Pacific Telecom"),
+    (8869773, "Asia-Pacific Telecom"),
+    (8869774, "Asia-Pacific Telecom"),
+    (8869775, "Asia-Pacific Telecom"),
+    (8869776, "Asia-Pacific Telecom"),
+    (8869777, "Asia-Pacific Telecom"),
+    (8869840, "FarEasTone"),
+    (8869841, "FarEasTone"),
+    (8869842, "Chunghwa Telecom"),
+    (8869843, "Chunghwa Telecom"),
+    (8869844, "Taiwan Mobile"),
+    (8869845, "Taiwan Mobile"),
+    (8869846, "T Star"),
+    (8869847, "T Star"),
+    (9051616, "Turkcell"),
+    (9053383, "Kuzey Kibris Turkcell"),
+    (9053384, "Kuzey Kibris Turkcell"),
+    (9053385, "Kuzey Kibris Turkcell"),
+    (9053386, "Kuzey Kibris Turkcell"),
+    (9053387, "Kuzey Kibris Turkcell"),
+    (9161279, "Idea"),
+    (9167479, "Idea"),
+    (9168284, "Vodafone"),
+    (9170489, "Reliance Jio"),
+    (9172199, "Aircel"),
+    (9172229, "Airtel"),
+    (9172290, "Airtel"),
+    (9172298, "Vodafone"),
+    (9172299, "Vodafone"),
+    (9172489, "Telewings"),
+    (9172499, "Vodafone"),
+    (9172570, "Idea"),
+    (9172578, "Airtel"),
+    (9172579, "Airtel"),
+    (9172610, "Airtel"),
+    (9172618, "Airtel"),
+    (9172619, "Vodafone"),
+    (9172690, "Telewings"),
+    (9172698, "Aircel"),
+    (9172699, "Aircel"),
+    (9172838, "Idea"),
+    (9172839, "Idea"),
+    (9172840, "Idea"),
+    (9172848, "Idea"),
+    (9172849, "Idea"),
+    (9172850, "Idea"),
+    (9172858, "Idea"),
+    (9172859, "Idea"),
+    (9172860, "Idea"),
+    (9172868, "Idea"),
+    (9172869, "Idea"),
+    (9172870, "Idea"),
+    (9172878, "Idea"),
+    (9172879, "Idea"),
+    (9172880, "Idea"),
+    (9172888, "Idea"),
+    (9172889, "Idea"),
+    (9172890, "Vodafone"),
+    (9172898, "Vodafone"),
+    (9172899, "Vodafone"),
+    (9172900, "Vodafone"),
+    (9172908, "Vodafone"),
+    (9172909, "Vodafone"),
+    (9172910, "Vodafone"),
+    (9172918, "Vodafone"),
+    (9172919, "Vodafone"),
+    (9172920, "Vodafone"),
+    (9172928, "Airtel"),
+    (9172929, "Airtel"),
+    (9172940, "Airtel"),
+    (9172941, "Airtel"),
+    (9172948, "Airtel"),
+    (9172949, "Airtel"),
+    (9172950, "Airtel"),
+    (9172958, "Airtel"),
+    (9172959, "Airtel"),
+    (9172960, "Airtel"),
+    (9172968, "Airtel"),
+    (9172969, "Airtel"),
+    (9172970, "Airtel"),
+    (9172978, "Airtel"),
+    (9172979, "Airtel"),
+    (9173100, "Vodafone"),
+    (9173101, "Vodafone"),
+    (9173102, "Vodafone"),
+    (9173103, "Vodafone"),
+    (9173104, "Vodafone"),
+    (9173105, "Airtel"),
+    (9173106, "Airtel"),
+    (9173107, "Airtel"),
+    (9173108, "Airtel"),
+    (9173109, "Airtel"),
+    (9173170, "Vodafone"),
+    (9173172, "Vodafone"),
+    (9173173, "Vodafone"),
+    (9173174, "Vodafone"),
+    (9173175, "Airtel"),
+    (9173176, "Airtel"),
+    (9173177, "Airtel"),
+    (9173178, "Airtel"),
+    (9173200, "Airtel"),
+    (9173208, "Airtel"),
+    (9173209, "Airtel"),
+    (9173210, "Airtel"),
+    (9173218, "Airtel"),
+    (9173219, "Airtel"),
+    (9173220, "Idea"),
+    (9173228, "Idea"),
+    (9173229, "Idea"),
+    (9173230, "Idea"),
+    (9173238, "Idea"),
+    (9173239, "Idea"),
+    (9173240, "Idea"),
+    (9173248, "Idea"),
+    (9173249, "Idea"),
+    (9173250, "Idea"),
+    (9173258, "Airtel"),
+    (9173259, "Airtel"),
+    (9173260, "Airtel"),
+    (9173268, "Airtel"),
+    (9173269, "Airtel"),
+    (9173270, "Airtel"),
+    (9173278, "Airtel"),
+    (9173279, "Airtel"),
+    (9173280, "Airtel"),
+    (9173288, "Airtel"),
+    (9173289, "Aircel"),
+    (9173290, "Aircel"),
+    (9173298, "Aircel"),
+    (9173299, "Aircel"),
+    (9173306, "Airtel"),
+    (9173307, "Airtel"),
+    (9173308, "Airtel"),
+    (9173309, "Airtel"),
+    (9173311, "Airtel"),
+    (9173370, "Airtel"),
+    (9173372, "Airtel"),
+    (9173373, "Airtel"),
+    (9173374, "Airtel"),
+    (9173375, "Airtel"),
+    (9173376, "Airtel"),
+    (9173377, "Airtel"),
+    (9173378, "Airtel"),
+    (9173470, "Airtel"),
+    (9173472, "Airtel"),
+    (9173473, "Airtel"),
+    (9173474, "Airtel"),
+    (9173475, "Airtel"),
+    (9173476, "Airtel"),
+    (9173477, "Idea"),
+    (9173478, "Idea"),
+    (9173488, "Airtel"),
+    (9173489, "Airtel"),
+    (9173498, "Vodafone"),
+    (9173499, "Vodafone"),
+    (9173558, "Airtel"),
+    (9173600, "Vodafone"),
+    (9173608, "Vodafone"),
+    (9173609, "Vodafone"),
+    (9173610, "Vodafone"),
+    (9173618, "Vodafone"),
+    (9173619, "Vodafone"),
+    (9173620, "Vodafone"),
+    (9173628, "Vodafone"),
+    (9173629, "Airtel"),
+    (9173630, "Airtel"),
+    (9173638, "Airtel"),
+    (9173639, "Airtel"),
+    (9173640, "Airtel"),
+    (9173648, "Airtel"),
+    (9173649, "Airtel"),
+    (9173650, "Airtel"),
+    (9173658, "Airtel"),
+    (9173659, "Airtel"),
+    (9173660, "Airtel"),
+    (9173668, "Airtel"),
+    (9173669, "Airtel"),
+    (9173670, "Airtel"),
+    (9173678, "Airtel"),
+    (9173679, "Airtel"),
+    (9173680, "Airtel"),
+    (9173688, "Airtel"),
+    (9173689, "Airtel"),
+    (9173690, "Airtel"),
+    (9173698, "Idea"),
+    (9173699, "Idea"),
+    (9173700, "Idea"),
+    (9173708, "Idea"),
+    (9173709, "Idea"),
+    (9173710, "Idea"),
+    (9173718, "Idea"),
+    (9173719, "Idea"),
+    (9173720, "Idea"),
+    (9173728, "Idea"),
+    (9173729, "Idea"),
+    (9173740, "Idea"),
+    (9173748, "Idea"),
+    (9173749, "Idea"),
+    (9173750, "Idea"),
+    (9173758, "Idea"),
+    (9173759, "Idea"),
+    (9173780, "Idea"),
+    (9173781, "Idea"),
+    (9173782, "Idea"),
+    (9173800, "BSNL MOBILE"),
+    (9173801, "BSNL MOBILE"),
+    (9173802, "BSNL MOBILE"),
+    (9173900, "Airtel"),
+    (9173908, "Airtel"),
+    (9173909, "Airtel"),
+    (9173910, "Vodafone"),
+    (9173918, "Vodafone"),
+    (9173919, "Vodafone"),
+    (9173920, "Telenor"),
+    (9173928, "Telenor"),
+    (9173929, "Telenor"),
+    (9173930, "Telenor"),
+    (9173938, "Telenor"),
+    (9173939, "Telenor"),
+    (9173940, "Telenor"),
+    (9173948, "Telenor"),
+    (9173949, "Telenor"),
+    (9173950, "Telenor"),
+    (9173958, "Airtel"),
+    (9173959, "Airtel"),
+    (9174000, "Airtel"),
+    (9174001, "Airtel"),
+    (9174002, "Airtel"),
+    (9174003, "Airtel"),
+    (9174004, "Airtel"),
+    (9174005, "Tata Docomo"),
+    (9174006, "Tata Docomo"),
+    (9174007, "Tata Docomo"),
+    (9174008, "Tata Docomo"),
+    (9174009, "Tata Docomo"),
+    (9174108, "Vodafone"),
+    (9174109, "Vodafone"),
+    (9174120, "Vodafone"),
+    (9174128, "Vodafone"),
+    (9174129, "Vodafone"),
+    (9174130, "Vodafone"),
+    (9174138, "Vodafone"),
+    (9174139, "Vodafone"),
+    (9174140, "Vodafone"),
+    (9174148, "Vodafone"),
+    (9174149, "Airtel"),
+    (9174200, "Airtel"),
+    (9174208, "Airtel"),
+    (9174209, "Airtel"),
+    (9174210, "Aircel"),
+    (9174218, "Aircel"),
+    (9174219, "Aircel"),
+    (9174220, "Aircel"),
+    (9174228, "Aircel"),
+    (9174229, "Aircel"),
+    (9174230, "Aircel"),
+    (9174238, "Aircel"),
+    (9174239, "Aircel"),
+    (9174240, "Aircel"),
+    (9174248, "Airtel"),
+    (9174249, "Airtel"),
+    (9174250, "Airtel"),
+    (9174258, "Airtel"),
+    (9174259, "Airtel"),
+    (9174260, "Airtel"),
+    (9174268, "Airtel"),
+    (9174269, "Airtel"),
+    (9174270, "Airtel"),
+    (9174278, "Airtel"),
+    (9174279, "Vodafone"),
+    (9174280, "Airtel"),
+    (9174281, "Airtel"),
+    (9174288, "Airtel"),
+    (9174289, "Airtel"),
+    (9174290, "Idea"),
+    (9174291, "Idea"),
+    (9174298, "Idea"),
+    (9174299, "Idea"),
+    (9174300, "Vodafone"),
+    (9174308, "Vodafone"),
+    (9174309, "Vodafone"),
+    (9174310, "Vodafone"),
+    (9174318, "Vodafone"),
+    (9174319, "Vodafone"),
+    (9174320, "Vodafone"),
+    (9174328, "Vodafone"),
+    (9174329, "Vodafone"),
+    (9174330, "Vodafone"),
+    (9174338, "Vodafone"),
+    (9174339, "Vodafone"),
+    (9174340, "Vodafone"),
+    (9174348, "Vodafone"),
+    (9174349, "Vodafone"),
+    (9174350, "Vodafone"),
+    (9174358, "Vodafone"),
+    (9174359, "Vodafone"),
+    (9174360, "Vodafone"),
+    (9174368, "Vodafone"),
+    (9174369, "Vodafone"),
+    (9174370, "Vodafone"),
+    (9174378, "Vodafone"),
+    (9174379, "Vodafone"),
+    (9174380, "Vodafone"),
+    (9174388, "Vodafone"),
+    (9174389, "Vodafone"),
+    (9174400, "Vodafone"),
+    (9174401, "Vodafone"),
+    (9174470, "Idea"),
+    (9174472, "Idea"),
+    (9174473, "Idea"),
+    (9174474, "Idea"),
+    (9174475, "Idea"),
+    (9174476, "Idea"),
+    (9174477, "Idea"),
+    (9174478, "Idea"),
+    (9174500, "Idea"),
+    (9174508, "Idea"),
+    (9174509, "Idea"),
+    (9174510, "Telenor"),
+    (9174518, "Telenor"),
+    (9174519, "Telenor"),
+    (9174520, "Telenor"),
+    (9174528, "Telenor"),
+    (9174529, "Telenor"),
+    (9174530, "Telenor"),
+    (9174538, "Telenor"),
+    (9174539, "Telenor"),
+    (9174540, "Telenor"),
+    (9174548, "Airtel"),
+    (9174549, "Airtel"),
+    (9174550, "Airtel"),
+    (9174558, "Airtel"),
+    (9174559, "Airtel"),
+    (9174560, "Airtel"),
+    (9174568, "Airtel"),
+    (9174569, "Airtel"),
+    (9174570, "Airtel"),
+    (9174578, "Airtel"),
+    (9174579, "Airtel"),
+    (9174580, "Airtel"),
+    (9174588, "Airtel"),
+    (9174589, "Airtel"),
+    (9174590, "Airtel"),
+    (9174598, "Airtel"),
+    (9174599, "Airtel"),
+    (9174600, "Airtel"),
+    (9174608, "Airtel"),
+    (9174609, "Airtel"),
+    (9174610, "Airtel"),
+    (9174618, "Airtel"),
+    (9174619, "Airtel"),
+    (9174620, "Airtel"),
+    (9174628, "Airtel"),
+    (9174629, "Airtel"),
+    (9174630, "Airtel"),
+    (9174638, "Airtel"),
+    (9174639, "Airtel"),
+    (9174640, "Airtel"),
+    (9174648, "Idea"),
+    (9174649, "Idea"),
+    (9174650, "Idea"),
+    (9174658, "Idea"),
+    (9174659, "Idea"),
+    (9174660, "Idea"),
+    (9174668, "Idea"),
+    (9174669, "Idea"),
+    (9174670, "Idea"),
+    (9174678, "Idea"),
+    (9174679, "Aircel"),
+    (9174680, "Aircel"),
+    (9174688, "Aircel"),
+    (9174689, "Aircel"),
+    (9174690, "Aircel"),
+    (9174698, "Aircel"),
+    (9174699, "Aircel"),
+    (9174700, "Aircel"),
+    (9174701, "Aircel"),
+    (9174702, "Aircel"),
+    (9174770, "Airtel"),
+    (9174772, "Airtel"),
+    (9174773, "Airtel"),
+    (9174774, "Airtel"),
+    (9174775, "Airtel"),
+    (9174776, "Airtel"),
+    (9174777, "Airtel"),
+    (9174778, "Airtel"),
+    (9174780, "Airtel"),
+    (9174781, "Airtel"),
+    (9174782, "Airtel"),
+    (9174783, "Airtel"),
+    (9174790, "Vodafone"),
+    (9174791, "Vodafone"),
+    (9174792, "Vodafone"),
+    (9174793, "Vodafone"),
+    (9174800, "Telenor"),
+    (9174808, "Telenor"),
+    (9174809, "Telenor"),
+    (9174810, "Telenor"),
+    (9174818, "Airtel"),
+    (9174819, "Airtel"),
+    (9174820, "Airtel"),
+    (9174828, "Airtel"),
+    (9174829, "Airtel"),
+    (9174840, "Airtel"),
+    (9174848, "Airtel"),
+    (9174849, "Airtel"),
+    (9174850, "Airtel"),
+    (9174858, "Airtel"),
+    (9174859, "Airtel"),
+    (9174860, "Airtel"),
+    (9174868, "Airtel"),
+    (9174869, "Airtel"),
+    (9174870, "Airtel"),
+    (9174878, "Airtel"),
+    (9174879, "Airtel"),
+    (9174900, "Airtel"),
+    (9174908, "Airtel"),
+    (9174909, "Airtel"),
+    (9174910, "Idea"),
+    (9174918, "Idea"),
+    (9174919, "Idea"),
+    (9174920, "Idea"),
+    (9174928, "Idea"),
+    (9174929, "Idea"),
+    (9174930, "Idea"),
+    (9174938, "Idea"),
+    (9174939, "Idea"),
+    (9174940, "Idea"),
+    (9174948, "Airtel"),
+    (9174949, "Airtel"),
+    (9174950, "Airtel"),
+    (9174958, "Tata Docomo"),
+    (9174959, "Tata Docomo"),
+    (9174960, "Airtel"),
+    (9174968, "Airtel"),
+    (9174969, "Airtel"),
+    (9174970, "Airtel"),
+    (9174978, "Airtel"),
+    (9174979, "Airtel"),
+    (9175100, "Airtel"),
+    (9175210, "Airtel"),
+    (9175218, "Airtel"),
+    (9175219, "Airtel"),
+    (9175220, "Airtel"),
+    (9175228, "Airtel"),
+    (9175229, "Idea"),
+    (9175260, "Airtel"),
+    (9175299, "Aircel"),
+    (9175300, "Airtel"),
+    (9175330, "Aircel"),
+    (9175338, "Idea"),
+    (9175339, "Idea"),
+    (9175360, "Idea"),
+    (9175368, "Idea"),
+    (9175369, "Aircel"),
+    (9175388, "Airtel"),
+    (9175399, "Airtel"),
+    (9175400, "Airtel"),
+    (9175440, "Airtel"),
+    (9175448, "Vodafone"),
+    (9175449, "Vodafone"),
+    (9175450, "Vodafone"),
+    (9175458, "Vodafone"),
+    (9175459, "Vodafone"),
+    (9175460, "Vodafone"),
+    (9175468, "Vodafone"),
+    (9175469, "Vodafone"),
+    (9175470, "Vodafone"),
+    (9175478, "Vodafone"),
+    (9175479, "Vodafone"),
+    (9175480, "Vodafone"),
+    (9175488, "Airtel"),
+    (9175489, "Airtel"),
+    (9175500, "Airtel"),
+    (9175501, "Airtel"),
+    (9175502, "Airtel"),
+    (9175503, "Airtel"),
+    (9175504, "Aircel"),
+    (9175505, "Aircel"),
+    (9175506, "Aircel"),
+    (9175507, "Aircel"),
+    (9175508, "Vodafone"),
+    (9175509, "Vodafone"),
+    (9175510, "Vodafone"),
+    (9175511, "Aircel"),
+    (9175518, "Vodafone"),
+    (9175519, "Vodafone"),
+    (9175570, "Vodafone"),
+    (9175572, "Aircel"),
+    (9175573, "Aircel"),
+    (9175574, "Aircel"),
+    (9175575, "Aircel"),
+    (9175576, "Aircel"),
+    (9175578, "Vodafone"),
+    (9175580, "Idea"),
+    (9175588, "Idea"),
+    (9175589, "Idea"),
+    (9175591, "Airtel"),
+    (9175592, "Airtel"),
+    (9175593, "Airtel"),
+    (9175594, "Airtel"),
+    (9175650, "Vodafone"),
+    (9175658, "Vodafone"),
+    (9175659, "Vodafone"),
+    (9175700, "Vodafone"),
+    (9175708, "Vodafone"),
+    (9175709, "Vodafone"),
+    (9175710, "Vodafone"),
+    (9175718, "Vodafone"),
+    (9175719, "Vodafone"),
+    (9175770, "Vodafone"),
+    (9175778, "Vodafone"),
+    (9175779, "Vodafone"),
+    (9175780, "Vodafone"),
+    (9175788, "Vodafone"),
+    (9175789, "Vodafone"),
+    (9175800, "Idea"),
+    (9175830, "Vodafone"),
+    (9175838, "Vodafone"),
+    (9175839, "Airtel"),
+    (9175896, "Reliance Jio"),
+    (9175897, "Reliance Jio"),
+    (9175898, "Reliance Jio"),
+    (9175899, "Reliance Jio"),
+    (9175900, "Idea"),
+    (9175908, "Idea"),
+    (9175909, "Idea"),
+    (9175910, "Idea"),
+    (9175918, "Idea"),
+    (9175919, "Vodafone"),
+    (9175920, "Vodafone"),
+    (9175928, "Vodafone"),
+    (9175929, "Vodafone"),
+    (9175930, "Vodafone"),
+    (9175938, "Vodafone"),
+    (9175939, "Vodafone"),
+    (9175940, "Vodafone"),
+    (9175948, "Vodafone"),
+    (9175949, "Vodafone"),
+    (9175950, "Airtel"),
+    (9175958, "Airtel"),
+    (9175959, "Airtel"),
+    (9175960, "Airtel"),
+    (9175968, "Airtel"),
+    (9175969, "Airtel"),
+    (9176030, "Airtel"),
+    (9176040, "Airtel"),
+    (9176050, "Airtel"),
+    (9176058, "Airtel"),
+    (9176059, "Airtel"),
+    (9176060, "Airtel"),
+    (9176068, "Airtel"),
+    (9176069, "Airtel"),
+    (9176080, "Airtel"),
+    (9176088, "Airtel"),
+    (9176089, "Airtel"),
+    (9176090, "Airtel"),
+    (9176098, "Airtel"),
+    (9176099, "Airtel"),
+    (9176100, "Aircel"),
+    (9176108, "Aircel"),
+    (9176109, "Aircel"),
+    (9176111, "Idea"),
+    (9176174, "Vodafone"),
+    (9176175, "Vodafone"),
+    (9176176, "Vodafone"),
+    (9176177, "Vodafone"),
+    (9176180, "Idea"),
+    (9176187, "Airtel"),
+    (9176188, "Idea"),
+    (9176189, "Idea"),
+    (9176190, "Idea"),
+    (9176197, "Aircel"),
+    (9176198, "Idea"),
+    (9176199, "Idea"),
+    (9176248, "Airtel"),
+    (9176249, "Airtel"),
+    (9176250, "Airtel"),
+    (9176258, "BSNL MOBILE"),
+    (9176259, "BSNL MOBILE"),
+    (9176260, "BSNL MOBILE"),
+    (9176268, "Airtel"),
+    (9176269, "Airtel"),
+    (9176270, "Reliance Jio"),
+    (9176278, "BSNL MOBILE"),
+    (9176279, "Airtel"),
+    (9176280, "Airtel"),
+    (9176288, "Airtel"),
+    (9176289, "Airtel"),
+    (9176290, "Airtel"),
+    (9176298, "Airtel"),
+    (9176299, "Airtel"),
+    (9176300, "Airtel"),
+    (9176308, "Airtel"),
+    (9176309, "Airtel"),
+    (9176320, "Airtel"),
+    (9176328, "Airtel"),
+    (9176329, "Airtel"),
+    (9176330, "Airtel"),
+    (9176338, "Airtel"),
+    (9176339, "Airtel"),
+    (9176340, "Airtel"),
+    (9176348, "Airtel"),
+    (9176349, "Airtel"),
+    (9176350, "Airtel"),
+    (9176358, "Airtel"),
+    (9176359, "Airtel"),
+    (9176360, "Airtel"),
+    (9176368, "Airtel"),
+    (9176369, "Airtel"),
+    (9176370, "Airtel"),
+    (9176378, "Airtel"),
+    (9176379, "Airtel"),
+    (9176380, "Airtel"),
+    (9176388, "Airtel"),
+    (9176389, "Vodafone"),
+    (9176400, "Vodafone"),
+    (9176408, "Vodafone"),
+    (9176409, "Vodafone"),
+    (9176410, "Vodafone"),
+    (9176418, "Vodafone"),
+    (9176419, "Vodafone"),
+    (9176420, "Vodafone"),
+    (9176428, "Vodafone"),
+    (9176429, "Vodafone"),
+    (9176430, "Telenor"),
+    (9176438, "Telenor"),
+    (9176439, "Telenor"),
+    (9176440, "Telenor"),
+    (9176448, "Telenor"),
+    (9176449, "Telenor"),
+    (9176450, "Telenor"),
+    (9176458, "Telenor"),
+    (9176459, "Telenor"),
+    (9176460, "Telenor"),
+    (9176468, "BSNL MOBILE"),
+    (9176469, "BSNL MOBILE"),
+    (9176470, "BSNL MOBILE"),
+    (9176478, "BSNL MOBILE"),
+    (9176479, "BSNL MOBILE"),
+    (9176480, "BSNL MOBILE"),
+    (9176488, "BSNL MOBILE"),
+    (9176489, "BSNL MOBILE"),
+    (9176490, "BSNL MOBILE"),
+    (9176498, "BSNL MOBILE"),
+    (9176499, "BSNL MOBILE"),
+    (9176500, "BSNL MOBILE"),
+    (9176508, "BSNL MOBILE"),
+    (9176509, "BSNL MOBILE"),
+    (9176510, "BSNL MOBILE"),
+    (9176518, "Reliance Jio"),
+    (9176519, "Reliance Jio"),
+    (9176520, "Reliance Jio"),
+    (9176528, "Airtel"),
+    (9176529, "Airtel"),
+    (9176530, "BSNL MOBILE"),
+    (9176538, "BSNL MOBILE"),
+    (9176539, "BSNL MOBILE"),
+    (9176550, "BSNL MOBILE"),
+    (9176558, "BSNL MOBILE"),
+    (9176559, "BSNL MOBILE"),
+    (9176560, "BSNL MOBILE"),
+    (9176568, "BSNL MOBILE"),
+    (9176569, "BSNL MOBILE"),
+    (9176570, "BSNL MOBILE"),
+    (9176578, "Tata Docomo"),
+    (9176579, "Tata Docomo"),
+    (9176580, "Tata Docomo"),
+    (9176588, "Tata Docomo"),
+    (9176700, "Idea"),
+    (9176708, "Reliance Jio"),
+    (9176738, "Reliance Jio"),
+    (9176739, "Airtel"),
+    (9176780, "Airtel"),
+    (9176781, "Reliance Jio"),
+    (9176782, "Reliance Jio"),
+    (9176783, "Reliance Jio"),
+    (9176784, "Reliance Jio"),
+    (9176785, "Reliance Jio"),
+    (9176786, "Reliance Jio"),
+    (9176788, "Idea"),
+    (9176789, "Idea"),
+    (9176819, "Tata Docomo"),
+    (9176830, "Reliance Jio"),
+    (9176880, "Aircel"),
+    (9176888, "Idea"),
+    (9176889, "Idea"),
+    (9177000, "Airtel"),
+    (9177008, "Airtel"),
+    (9177009, "Airtel"),
+    (9177010, "Tata Docomo"),
+    (9177018, "Reliance Jio"),
+    (9177019, "Reliance Jio"),
+    (9177030, "Vodafone"),
+    (9177038, "Reliance Jio"),
+    (9177039, "Reliance Jio"),
+    (9177040, "Vodafone"),
+    (9177048, "Vodafone"),
+    (9177049, "Vodafone"),
+    (9177050, "Vodafone"),
+    (9177058, "Vodafone"),
+    (9177059, "Vodafone"),
+    (9177060, "Vodafone"),
+    (9177068, "Vodafone"),
+    (9177069, "Vodafone"),
+    (9177070, "Idea"),
+    (9177078, "Idea"),
+    (9177079, "Idea"),
+    (9177100, "Airtel"),
+    (9177108, "Airtel"),
+    (9177109, "Airtel"),
+    (9177110, "Aircel"),
+    (9177118, "Aircel"),
+    (9177119, "Aircel"),
+    (9177120, "Aircel"),
+    (9177128, "Idea"),
+    (9177129, "Idea"),
+    (9177130, "Idea"),
+    (9177138, "Idea"),
+    (9177139, "Idea"),
+    (9177140, "Idea"),
+    (9177148, "Idea"),
+    (9177149, "Idea"),
+    (9177150, "Idea"),
+    (9177158, "Idea"),
+    (9177160, "Airtel"),
+    (9177168, "Airtel"),
+    (9177169, "Airtel"),
+    (9177170, "Airtel"),
+    (9177171, "Idea"),
+    (9177173, "Reliance Jio"),
+    (9177174, "Reliance Jio"),
+    (9177175, "Reliance Jio"),
+    (9177176, "Reliance Jio"),
+    (9177177, "Reliance Jio"),
+    (9177178, "Airtel"),
+    (9177179, "Airtel"),
+    (9177180, "Airtel"),
+    (9177188, "Airtel"),
+    (9177189, "Airtel"),
+    (9177190, "Idea"),
+    (9177191, "Vodafone"),
+    (9177192, "Vodafone"),
+    (9177193, "Vodafone"),
+    (9177194, "Reliance Jio"),
+    (9177195, "Reliance Jio"),
+    (9177196, "Reliance Jio"),
+    (9177197, "Reliance Jio"),
+    (9177198, "Idea"),
+    (9177199, "Idea"),
+    (9177220, "Idea"),
+    (9177228, "Reliance Jio"),
+    (9177229, "Reliance Jio"),
+    (9177250, "Reliance Jio"),
+    (9177258, "Reliance Jio"),
+    (9177259, "Airtel"),
+    (9177400, "Reliance Jio"),
+    (9177408, "Idea"),
+    (9177409, "Idea"),
+    (9177410, "Idea"),
+    (9177418, "Idea"),
+    (9177419, "Idea"),
+    (9177430, "Reliance Jio"),
+    (9177438, "Idea"),
+    (9177439, "Idea"),
+    (9177440, "Idea"),
+    (9177448, "Idea"),
+    (9177449, "Idea"),
+    (9177450, "Idea"),
+    (9177458, "Idea"),
+    (9177459, "Idea"),
+    (9177460, "Idea"),
+    (9177468, "Idea"),
+    (9177469, "Idea"),
+    (9177470, "Idea"),
+    (9177478, "Idea"),
+    (9177479, "Idea"),
+    (9177480, "Idea"),
+    (9177488, "Idea"),
+    (9177489, "Idea"),
+    (9177490, "Airtel"),
+    (9177498, "Airtel"),
+    (9177499, "Airtel"),
+    (9177500, "Airtel"),
+    (9177508, "Airtel"),
+    (9177509, "Airtel"),
+    (9177510, "Airtel"),
+    (9177518, "Airtel"),
+    (9177519, "Airtel"),
+    (9177520, "Airtel"),
+    (9177528, "Airtel"),
+    (9177529, "Airtel"),
+    (9177530, "Airtel"),
+    (9177538, "Airtel"),
+    (9177539, "Airtel"),
+    (9177540, "Airtel"),
+    (9177548, "Airtel"),
+    (9177549, "Airtel"),
+    (9177550, "Airtel"),
+    (9177558, "Airtel"),
+    (9177559, "Airtel"),
+    (9177560, "Airtel"),
+    (9177568, "Airtel"),
+    (9177569, "Airtel"),
+    (9177570, "Airtel"),
+    (9177578, "Airtel"),
+    (9177579, "Airtel"),
+    (9177580, "Airtel"),
+    (9177588, "Airtel"),
+    (9177589, "Airtel"),
+    (9177590, "Airtel"),
+    (9177598, "Airtel"),
+    (9177599, "Airtel"),
+    (9177610, "Airtel"),
+    (9177618, "Airtel"),
+    (9177619, "Airtel"),
+    (9177620, "Airtel"),
+    (9177628, "Airtel"),
+    (9177629, "Airtel"),
+    (9177630, "Airtel"),
+    (9177638, "Airtel"),
+    (9177639, "Airtel"),
+    (9177640, "Airtel"),
+    (9177648, "Airtel"),
+    (9177649, "Airtel"),
+    (9177650, "Airtel"),
+    (9177658, "Airtel"),
+    (9177659, "Airtel"),
+    (9177660, "Airtel"),
+    (9177668, "Airtel"),
+    (9177669, "Airtel"),
+    (9177670, "Vodafone"),
+    (9177678, "Vodafone"),
+    (9177679, "Vodafone"),
+    (9177680, "Vodafone"),
+    (9177688, "Vodafone"),
+    (9177689, "Vodafone"),
+    (9177690, "Vodafone"),
+    (9177698, "Vodafone"),
+    (9177699, "Vodafone"),
+    (9177700, "Vodafone"),
+    (9177708, "Idea"),
+    (9177709, "Idea"),
+    (9177710, "Idea"),
+    (9177718, "Idea"),
+    (9177719, "Idea"),
+    (9177720, "Idea"),
+    (9177728, "Idea"),
+    (9177729, "Idea"),
+    (9177730, "Idea"),
+    (9177738, "Idea"),
+    (9177739, "Idea"),
+    (9177740, "Idea"),
+    (9177748, "Idea"),
+    (9177749, "Idea"),
+    (9177750, "Idea"),
+    (9177758, "Idea"),
+    (9177759, "Idea"),
+    (9177760, "Idea"),
+    (9177768, "Idea"),
+    (9177769, "Idea"),
+    (9177770, "Airtel"),
+    (9177778, "Tata Docomo"),
+    (9177788, "Telewings"),
+    (9177798, "Airtel"),
+    (9177799, "Airtel"),
+    (9177800, "Idea"),
+    (9177810, "Airtel"),
+    (9177818, "Airtel"),
+    (9177819, "Airtel"),
+    (9177820, "Airtel"),
+    (9177828, "Airtel"),
+    (9177829, "Airtel"),
+    (9177830, "Airtel"),
+    (9177838, "Airtel"),
+    (9177839, "Aircel"),
+    (9177840, "Aircel"),
+    (9177848, "Aircel"),
+    (9177849, "Aircel"),
+    (9177850, "Aircel"),
+    (9177858, "Aircel"),
+    (9177859, "Aircel"),
+    (9177860, "Aircel"),
+    (9177868, "Aircel"),
+    (9177869, "Aircel"),
+    (9177870, "Idea"),
+    (9177878, "Idea"),
+    (9177879, "Idea"),
+    (9177880, "Idea"),
+    (9177888, "Idea"),
+    (9177889, "Idea"),
+    (9177890, "Idea"),
+    (9177898, "Idea"),
+    (9177899, "Idea"),
+    (9177900, "Idea"),
+    (9177908, "Aircel"),
+    (9177909, "Aircel"),
+    (9177910, "Aircel"),
+    (9177918, "Aircel"),
+    (9177919, "Aircel"),
+    (9177920, "Aircel"),
+    (9177928, "Aircel"),
+    (9177929, "Aircel"),
+    (9177930, "Aircel"),
+    (9177938, "Aircel"),
+    (9177939, "Telewings"),
+    (9177960, "Idea"),
+    (9177961, "Idea"),
+    (9177968, "Idea"),
+    (9177969, "Idea"),
+    (9178110, "Reliance Jio"),
+    (9178118, "Reliance Jio"),
+    (9178119, "Reliance Jio"),
+    (9178120, "Reliance Jio"),
+    (9178128, "Reliance Jio"),
+    (9178138, "Reliance Jio"),
+    (9178139, "Reliance Jio"),
+    (9178160, "Reliance Jio"),
+    (9178168, "Reliance Jio"),
+    (9178169, "Reliance Jio"),
+    (9178230, "Reliance Jio"),
+    (9178238, "Reliance Jio"),
+    (9178239, "Vodafone"),
+    (9178400, "Idea"),
+    (9178408, "Idea"),
+    (9178409, "Telewings"),
+    (9178430, "Telewings"),
+    (9178800, "Tata Docomo"),
+    (9178801, "Tata Docomo"),
+    (9178802, "Tata Docomo"),
+    (9178811, "Airtel"),
+    (9178870, "Airtel"),
+    (9178872, "Airtel"),
+    (9178873, "Idea"),
+    (9178874, "Idea"),
+    (9178875, "Idea"),
+    (9178876, "Idea"),
+    (9178877, "Idea"),
+    (9178878, "Idea"),
+    (9178879, "Idea"),
+    (9178880, "Idea"),
+    (9178881, "Idea"),
+    (9178882, "Idea"),
+    (9179000, "Airtel"),
+    (9179001, "Airtel"),
+    (9179018, "Airtel"),
+    (9179019, "Airtel"),
+    (9179313, "Reliance Jio"),
+    (9179700, "Idea"),
+    (9179701, "Idea"),
+    (9179702, "Idea"),
+    (9179790, "Reliance Jio"),
+    (9179797, "Reliance Jio"),
+    (9179798, "Reliance Jio"),
+    (9179799, "Reliance Jio"),
+    (9179911, "Reliance Jio"),
+    (9179920, "Airtel"),
+    (9179921, "Airtel"),
+    (9179922, "Reliance Jio"),
+    (9179923, "Reliance Jio"),
+    (9179924, "Reliance Jio"),
+    (9179925, "Idea"),
+    (9179926, "Idea"),
+    (9179927, "Idea"),
+    (9179928, "Idea"),
+    (9179929, "Idea"),
+    (9180050, "BSNL MOBILE"),
+    (9180051, "BSNL MOBILE"),
+    (9180052, "BSNL MOBILE"),
+    (9180053, "BSNL MOBILE"),
+    (9180054, "BSNL MOBILE"),
+    (9180055, "Reliance Jio"),
+    (9180056, "Reliance Jio"),
+    (9180057, "Reliance Jio"),
+    (9180058, "Reliance Jio"),
+    (9180059, "Reliance Jio"),
+    (9180786, "Reliance Jio"),
+    (9180790, "Reliance Jio"),
+    (9180794, "Aircel"),
+    (9180795, "Idea"),
+    (9180796, "BSNL MOBILE"),
+    (9180797, "Idea"),
+    (9180798, "Idea"),
+    (9180799, "Idea"),
+    (9180917, "Reliance Jio"),
+    (9181100, "Vodafone"),
+    (9181108, "Vodafone"),
+    (9181109, "Vodafone"),
+    (9181110, "Vodafone"),
+    (9181118, "Idea"),
+    (9181119, "Idea"),
+    (9181122, "Reliance Jio"),
+    (9181130, "Idea"),
+    (9181138, "Idea"),
+    (9181139, "Idea"),
+    (9181140, "Idea"),
+    (9181141, "Idea"),
+    (9181142, "Idea"),
+    (9181144, "Reliance Jio"),
+    (9181150, "Telewings"),
+    (9181170, "Airtel"),
+    (9181178, "Airtel"),
+    (9181179, "Airtel"),
+    (9181180, "Airtel"),
+    (9181188, "Reliance Jio"),
+    (9181189, "Airtel"),
+    (9181190, "Airtel"),
+    (9181198, "Airtel"),
+    (9181199, "Airtel"),
+    (9181310, "Airtel"),
+    (9181318, "Airtel"),
+    (9181319, "Airtel"),
+    (9181320, "Airtel"),
+    (9181328, "Airtel"),
+    (9181329, "Airtel"),
+    (9181330, "Airtel"),
+    (9181338, "Airtel"),
+    (9181339, "Airtel"),
+    (9181340, "Airtel"),
+    (9181348, "Airtel"),
+    (9181349, "Airtel"),
+    (9181350, "Airtel"),
+    (9181358, "Airtel"),
+    (9181359, "Airtel"),
+    (9181360, "Airtel"),
+    (9181368, "Airtel"),
+    (9181369, "Airtel"),
+    (9181370, "Airtel"),
+    (9181378, "Airtel"),
+    (9181379, "Airtel"),
+    (9181380, "Airtel"),
+    (9181388, "Airtel"),
+    (9181389, "Airtel"),
+    (9181390, "Airtel"),
+    (9181398, "Airtel"),
+    (9181399, "Idea"),
+    (9181500, "Idea"),
+    (9181508, "Idea"),
+    (9181509, "Idea"),
+    (9181510, "Idea"),
+    (9181518, "Idea"),
+    (9181519, "Idea"),
+    (9181520, "Idea"),
+    (9181528, "Idea"),
+    (9181529, "Idea"),
+    (9181530, "Idea"),
+    (9181538, "Idea"),
+    (9181539, "Idea"),
+    (9181540, "Idea"),
+    (9181548, "Idea"),
+    (9181549, "Idea"),
+    (9181550, "Idea"),
+    (9181558, "Idea"),
+    (9181559, "Idea"),
+    (9181560, "Idea"),
+    (9181568, "Idea"),
+    (9181569, "Idea"),
+    (9181570, "Idea"),
+    (9181578, "Idea"),
+    (9181579, "Idea"),
+    (9181580, "Airtel"),
+    (9181588, "Airtel"),
+    (9181589, "Airtel"),
+    (9181590, "Airtel"),
+    (9181598, "Airtel"),
+    (9181599, "Airtel"),
+    (9181670, "Airtel"),
+    (9181672, "Airtel"),
+    (9181673, "Airtel"),
+    (9181674, "Airtel"),
+    (9181675, "Airtel"),
+    (9181676, "Airtel"),
+    (9181677, "Airtel"),
+    (9181678, "Airtel"),
+    (9181700, "Airtel"),
+    (9181708, "Airtel"),
+    (9181709, "Airtel"),
+    (9181720, "Airtel"),
+    (9181728, "Airtel"),
+    (9181729, "Airtel"),
+    (9181730, "Airtel"),
+    (9181738, "Airtel"),
+    (9181739, "Airtel"),
+    (9181740, "Airtel"),
+    (9181748, "Airtel"),
+    (9181749, "Airtel"),
+    (9181750, "Airtel"),
+    (9181758, "Airtel"),
+    (9181759, "Tata Docomo"),
+    (9181760, "Tata Docomo"),
+    (9181768, "Tata Docomo"),
+    (9181769, "Tata Docomo"),
+    (9181770, "Tata Docomo"),
+    (9181778, "Telewings"),
+    (9181820, "Aircel"),
+    (9181828, "Aircel"),
+    (9181848, "Idea"),
+    (9181849, "Idea"),
+    (9181870, "Idea"),
+    (9181878, "Idea"),
+    (9181879, "Tata Docomo"),
+    (9181898, "Idea"),
+    (9181899, "Idea"),
+    (9182070, "Airtel"),
+    (9182072, "Airtel"),
+    (9182073, "Idea"),
+    (9182074, "Idea"),
+    (9182075, "Idea"),
+    (9182076, "Idea"),
+    (9182077, "Idea"),
+    (9182078, "Idea"),
+    (9182170, "Reliance Jio"),
+    (9182172, "Reliance Jio"),
+    (9182173, "Reliance Jio"),
+    (9182174, "Reliance Jio"),
+    (9182175, "Reliance Jio"),
+    (9182176, "Reliance Jio"),
+    (9182177, "Reliance Jio"),
+    (9182178, "Reliance Jio"),
+    (9182290, "Idea"),
+    (9182298, "Idea"),
+    (9182299, "Reliance Jio"),
+    (9182328, "Reliance Jio"),
+    (9182329, "Reliance Jio"),
+    (9182470, "Reliance Jio"),
+    (9182472, "Reliance Jio"),
+    (9182473, "Reliance Jio"),
+    (9182474, "Reliance Jio"),
+    (9182475, "Reliance Jio"),
+    (9182476, "Reliance Jio"),
+    (9182477, "Reliance Jio"),
+    (9182478, "Reliance Jio"),
+    (9182650, "Reliance Jio"),
+    (9182658, "Tata Docomo"),
+    (9182659, "Tata Docomo"),
+    (9182690, "Airtel"),
+    (9182691, "Airtel"),
+    (9182698, "Airtel"),
+    (9182699, "Airtel"),
+    (9182700, "Telewings"),
+    (9182701, "Telewings"),
+    (9182702, "Telewings"),
+    (9182703, "Telewings"),
+    (9182704, "Telewings"),
+    (9182705, "Vodafone"),
+    (9182706, "Vodafone"),
+    (9182707, "Vodafone"),
+    (9182708, "Vodafone"),
+    (9182709, "Vodafone"),
+    (9182788, "Reliance Jio"),
+    (9182790, "BSNL MOBILE"),
+    (9182791, "BSNL MOBILE"),
+    (9182890, "Airtel"),
+    (9182898, "BSNL MOBILE"),
+    (9182899, "BSNL MOBILE"),
+    (9183004, "Reliance Jio"),
+    (9183005, "Reliance Jio"),
+    (9183006, "Reliance Jio"),
+    (9183007, "Reliance Jio"),
+    (9183170, "Reliance Jio"),
+    (9183172, "Idea"),
+    (9183173, "Reliance Jio"),
+    (9183174, "Reliance Jio"),
+    (9183175, "Reliance Jio"),
+    (9183176, "Reliance Jio"),
+    (9183177, "Idea"),
+    (9183178, "Vodafone"),
+    (9183270, "Vodafone"),
+    (9183272, "Vodafone"),
+    (9183273, "Vodafone"),
+    (9183274, "Vodafone"),
+    (9183275, "Vodafone"),
+    (9183276, "Vodafone"),
+    (9183277, "Reliance Jio"),
+    (9183278, "Vodafone"),
+    (9183287, "Vodafone"),
+    (9183400, "Idea"),
+    (9183408, "Idea"),
+    (9183409, "Idea"),
+    (9183530, "Idea"),
+    (9183538, "Idea"),
+    (9183539, "Telewings"),
+    (9183558, "Reliance Jio"),
+    (9183559, "Reliance Jio"),
+    (9183560, "Reliance Jio"),
+    (9183568, "Reliance Jio"),
+    (9183569, "Reliance Jio"),
+    (9183670, "Idea"),
+    (9183672, "Idea"),
+    (9183673, "Idea"),
+    (9183674, "Idea"),
+    (9183675, "Idea"),
+    (9183676, "Idea"),
+    (9183677, "Idea"),
+    (9183678, "Vodafone"),
+    (9183810, "Idea"),
+    (9183818, "Telewings"),
+    (9183830, "Reliance Jio"),
+    (9183838, "Reliance Jio"),
+    (9183839, "Reliance Jio"),
+    (9183840, "Reliance Jio"),
+    (9183848, "Reliance Jio"),
+    (9183920, "Reliance Jio"),
+    (9183960, "Vodafone"),
+    (9183968, "Vodafone"),
+    (9183969, "Vodafone"),
+    (9184100, "Aircel"),
+    (9184101, "Aircel"),
+    (9184108, "Aircel"),
+    (9184109, "Aircel"),
+    (9184110, "Vodafone"),
+    (9184118, "Vodafone"),
+    (9184119, "Vodafone"),
+    (9184160, "Airtel"),
+    (9184290, "Airtel"),
+    (9184291, "Airtel"),
+    (9184298, "Airtel"),
+    (9184299, "Airtel"),
+    (9184330, "Reliance Jio"),
+    (9184331, "Reliance Jio"),
+    (9184332, "Reliance Jio"),
+    (9184333, "Idea"),
+    (9184334, "Reliance Jio"),
+    (9184335, "Airtel"),
+    (9184336, "Airtel"),
+    (9184337, "Airtel"),
+    (9184338, "Airtel"),
+    (9184339, "Airtel"),
+    (9184480, "Airtel"),
+    (9184481, "Airtel"),
+    (9184488, "Airtel"),
+    (9184489, "Airtel"),
+    (9184500, "Reliance Jio"),
+    (9184508, "Reliance Jio"),
+    (9184509, "Airtel"),
+    (9184580, "Airtel"),
+    (9184740, "Airtel"),
+    (9184748, "Airtel"),
+    (9184749, "Idea"),
+    (9184820, "Idea"),
+    (9184828, "Telewings"),
+    (9184859, "Airtel"),
+    (9184940, "Airtel"),
+    (9185190, "Vodafone"),
+    (9185198, "Telewings"),
+    (9185239, "Vodafone"),
+    (9185280, "Airtel"),
+    (9185281, "Airtel"),
+    (9185288, "Airtel"),
+    (9185289, "Airtel"),
+    (9185290, "Airtel"),
+    (9185291, "Airtel"),
+    (9185298, "Airtel"),
+    (9185299, "Airtel"),
+    (9185300, "Airtel"),
+    (9185301, "Airtel"),
+    (9185308, "Airtel"),
+    (9185309, "Airtel"),
+    (9185310, "Vodafone"),
+    (9185318, "Vodafone"),
+    (9185319, "Vodafone"),
+    (9185380, "Idea"),
+    (9185388, "Idea"),
+    (9185389, "Telewings"),
+    (9185438, "Telewings"),
+    (9185448, "Airtel"),
+    (9185449, "Airtel"),
+    (9185500, "Idea"),
+    (9185508, "Idea"),
+    (9185509, "Vodafone"),
+    (9185550, "Reliance Jio"),
+    (9185558, "Reliance Jio"),
+    (9185559, "Reliance Jio"),
+    (9185590, "Vodafone"),
+    (9185598, "Aircel"),
+    (9185599, "Aircel"),
+    (9185690, "Aircel"),
+    (9185698, "Airtel"),
+    (9185699, "Airtel"),
+    (9185720, "Airtel"),
+    (9185728, "Airtel"),
+    (9185729, "Idea"),
+    (9185808, "Reliance Jio"),
+    (9185809, "Reliance Jio"),
+    (9185820, "Idea"),
+    (9185828, "Airtel"),
+    (9185829, "Airtel"),
+    (9185850, "Airtel"),
+    (9185858, "Airtel"),
+    (9185859, "Vodafone"),
+    (9185970, "Airtel"),
+    (9185971, "Airtel"),
+    (9185978, "Airtel"),
+    (9185979, "Airtel"),
+    (9185990, "Vodafone"),
+    (9185998, "Vodafone"),
+    (9185999, "Aircel"),
+    (9186170, "Reliance Jio"),
+    (9186172, "Reliance Jio"),
+    (9186173, "Reliance Jio"),
+    (9186174, "Reliance Jio"),
+    (9186175, "Reliance Jio"),
+    (9186176, "Reliance Jio"),
+    (9186177, "Reliance Jio"),
+    (9186178, "Reliance Jio"),
+    (9186260, "Telewings"),
+    (9186290, "Airtel"),
+    (9186298, "Airtel"),
+    (9186370, "Reliance Jio"),
+    (9186372, "Reliance Jio"),
+    (9186373, "Reliance Jio"),
+    (9186374, "Reliance Jio"),
+    (9186375, "Reliance Jio"),
+    (9186376, "Reliance Jio"),
+    (9186377, "Idea"),
+    (9186378, "Reliance Jio"),
+    (9186499, "Aircel"),
+    (9186670, "Reliance Jio"),
+    (9186672, "Reliance Jio"),
+    (9186673, "Reliance Jio"),
+    (9186674, "Reliance Jio"),
+    (9186675, "Reliance Jio"),
+    (9186676, "Reliance Jio"),
+    (9186677, "Reliance Jio"),
+    (9186678, "Reliance Jio"),
+    (9186690, "Reliance Jio"),
+    (9186691, "Reliance Jio"),
+    (9186748, "Vodafone"),
+    (9186749, "Vodafone"),
+    (9186763, "Reliance Jio"),
+    (9186780, "Vodafone"),
+    (9186788, "Vodafone"),
+    (9186789, "Idea"),
+    (9186930, "Idea"),
+    (9186938, "Idea"),
+    (9187070, "Reliance Jio"),
+    (9187071, "Idea"),
+    (9187072, "Reliance Jio"),
+    (9187073, "Reliance Jio"),
+    (9187074, "Reliance Jio"),
+    (9187075, "Reliance Jio"),
+    (9187076, "Reliance Jio"),
+    (9187077, "Reliance Jio"),
+    (9187078, "Reliance Jio"),
+    (9187079, "Idea"),
+    (9187170, "Vodafone"),
+    (9187178, "Idea"),
+    (9187179, "Idea"),
+    (9187200, "Idea"),
+    (9187208, "Idea"),
+    (9187209, "Vodafone"),
+    (9187290, "Idea"),
+    (9187298, "Airtel"),
+    (9187299, "Airtel"),
+    (9187300, "Airtel"),
+    (9187308, "Airtel"),
+    (9187309, "Airtel"),
+    (9187310, "Airtel"),
+    (9187318, "Airtel"),
+    (9187319, "Airtel"),
+    (9187320, "Airtel"),
+    (9187328, "Airtel"),
+    (9187329, "Telewings"),
+    (9187490, "Idea"),
+    (9187498, "Aircel"),
+    (9187499, "Aircel"),
+    (9187530, "Aircel"),
+    (9187538, "Aircel"),
+    (9187539, "Airtel"),
+    (9187770, "Reliance Jio"),
+    (9187772, "Reliance Jio"),
+    (9187773, "Reliance Jio"),
+    (9187774, "Reliance Jio"),
+    (9187775, "Reliance Jio"),
+    (9187776, "Reliance Jio"),
+    (9187777, "Reliance Jio"),
+    (9187778, "Reliance Jio"),
+    (9187870, "Reliance Jio"),
+    (9187872, "Reliance Jio"),
+    (9187873, "Reliance Jio"),
+    (9187874, "Reliance Jio"),
+    (9187875, "Reliance Jio"),
+    (9187876, "Reliance Jio"),
+    (9187877, "Reliance Jio"),
+    (9187878, "Reliance Jio"),
+    (9188230, "Vodafone"),
+    (9188238, "Vodafone"),
+    (9188239, "Tata Docomo"),
+    (9188280, "Airtel"),
+    (9188281, "Airtel"),
+    (9188282, "Airtel"),
+    (9188283, "Airtel"),
+    (9188284, "Airtel"),
+    (9188285, "Idea"),
+    (9188286, "Idea"),
+    (9188287, "Idea"),
+    (9188288, "Idea"),
+    (9188289, "Idea"),
+    (9188370, "Reliance Jio"),
+    (9188372, "Reliance Jio"),
+    (9188373, "Reliance Jio"),
+    (9188374, "Reliance Jio"),
+    (9188375, "Reliance Jio"),
+    (9188376, "Reliance Jio"),
+    (9188377, "Reliance Jio"),
+    (9188378, "Reliance Jio"),
+    (9188470, "Reliance Jio"),
+    (9188472, "Reliance Jio"),
+    (9188473, "Reliance Jio"),
+    (9188474, "Reliance Jio"),
+    (9188475, "Reliance Jio"),
+    (9188476, "Reliance Jio"),
+    (9188477, "Idea"),
+    (9188478, "Reliance Jio"),
+    (9188870, "BSNL MOBILE"),
+    (9188871, "BSNL MOBILE"),
+    (9188872, "BSNL MOBILE"),
+    (9188873, "BSNL MOBILE"),
+    (9188874, "BSNL MOBILE"),
+    (9188875, "Reliance Jio"),
+    (9188876, "Reliance Jio"),
+    (9188877, "Reliance Jio"),
+    (9188878, "Reliance Jio"),
+    (9188879, "Reliance Jio"),
+    (9189170, "Idea"),
+    (9189172, "Reliance Jio"),
+    (9189173, "Reliance Jio"),
+    (9189174, "Reliance Jio"),
+    (9189175, "Reliance Jio"),
+    (9189176, "Reliance Jio"),
+    (9189177, "Idea"),
+    (9189178, "Idea"),
+    (9189250, "Airtel"),
+    (9189251, "Airtel"),
+    (9189258, "Airtel"),
+    (9189259, "Airtel"),
+    (9189350, "Idea"),
+    (9189358, "Telewings"),
+    (9189368, "Telewings"),
+    (9189369, "Idea"),
+    (9189380, "Idea"),
+    (9189388, "Idea"),
+    (9189389, "Idea"),
+    (9189460, "Reliance Jio"),
+    (9189630, "Aircel"),
+    (9189638, "Aircel"),
+    (9189639, "Idea"),
+    (9189911, "BSNL MOBILE"),
+    (9190572, "Reliance Jio"),
+    (9190575, "Reliance Jio"),
+    (9190760, "Idea"),
+    (9190761, "Idea"),
+    (9190762, "Idea"),
+    (9190763, "Idea"),
+    (9190764, "Idea"),
+    (9190765, "Airtel"),
+    (9190766, "Airtel"),
+    (9190767, "Airtel"),
+    (9190768, "Airtel"),
+    (9190769, "Airtel"),
+    (9190794, "Idea"),
+    (9190795, "Idea"),
+    (9191120, "Vodafone"),
+    (9191121, "Vodafone"),
+    (9191125, "Airtel"),
+    (9191190, "Telewings"),
+    (9191194, "Idea"),
+    (9191195, "Idea"),
+    (9191197, "Telewings"),
+    (9191458, "Airtel"),
+    (9191459, "Airtel"),
+    (9192641, "Airtel"),
+    (9192642, "Airtel"),
+    (9192644, "Airtel"),
+    (9192945, "Idea"),
+    (9192946, "Idea"),
+    (9192947, "Idea"),
+    (9192948, "Idea"),
+    (9193780, "Airtel"),
+    (9193781, "Airtel"),
+    (9193830, "Airtel"),
+    (9193831, "Airtel"),
+    (9193832, "Airtel"),
+    (9193833, "Airtel"),
+    (9195110, "Airtel"),
+    (9195111, "Airtel"),
+    (9195112, "Reliance Jio"),
+    (9195113, "Airtel"),
+    (9195114, "Airtel"),
+    (9195115, "Reliance Jio"),
+    (9195116, "Reliance Jio"),
+    (9195117, "Reliance Jio"),
+    (9195118, "Reliance Jio"),
+    (9195119, "Airtel"),
+    (9195200, "Telewings"),
+    (9195205, "Telewings"),
+    (9195206, "Telewings"),
+    (9195207, "Telewings"),
+    (9195208, "Tata Docomo"),
+    (9195209, "Tata Docomo"),
+    (9195219, "Aircel"),
+    (9196535, "Airtel"),
+    (9196537, "Airtel"),
+    (9196538, "Airtel"),
+    (9196539, "Airtel"),
+    (9196821, "Reliance Jio"),
+    (9196823, "Reliance Jio"),
+    (9196825, "Reliance Jio"),
+    (9196826, "Reliance Jio"),
+    (9197730, "Reliance Jio"),
+    (9197731, "Reliance Jio"),
+    (9197732, "Reliance Jio"),
+    (9197734, "Reliance Jio"),
+    (9198700, "Reliance Jio"),
+    (9198701, "Airtel"),
+    (9198702, "Airtel"),
+    (9198703, "Airtel"),
+    (9198704, "Airtel"),
+    (9198705, "Airtel"),
+    (9198706, "Reliance Jio"),
+    (9198707, "Reliance Jio"),
+    (9198708, "Reliance Jio"),
+    (9198709, "Reliance Jio"),
+    (9198753, "Reliance Jio"),
+    (9198754, "Reliance Jio"),
+    (9198755, "Reliance Jio"),
+    (9198756, "Reliance Jio"),
+    (9198757, "Airtel"),
+    (9198758, "Airtel"),
+    (9198759, "Airtel"),
+    (9647400, "Itisaluna"),
+    (9647401, "Itisaluna"),
+    (9647435, "Kalimat"),
+    (9647436, "Kalimat"),
+    (9647444, "Mobitel"),
+    (9647480, "ITC Fanoos"),
+    (9647481, "ITC Fanoos"),
+    (9647491, "ITPC"),
+    (9647494, "Imam Hussien Holy Shrine"),
+    (9647495, "IraqTel"),
+    (9647496, "IraqTel"),
+    (9665150, "Virgin"),
+    (9665151, "Virgin"),
+    (9665152, "Virgin"),
+    (9665153, "Virgin"),
+    (9665154, "Virgin"),
+    (9665730, "Virgin"),
+    (9665731, "Virgin"),
+    (9665732, "Virgin"),
+    (9665733, "Virgin"),
+    (9665734, "Virgin"),
+    (9665735, "STC"),
+    (9665736, "STC"),
+    (9665737, "STC"),
+    (9665738, "STC"),
+    (9725520, "BITIT"),
+    (9725521, "BITIT"),
+    (9725522, "Home Cellular"),
+    (9725523, "Home Cellular"),
+    (9725530, "019mobile"),
+    (9725531, "019mobile"),
+    (9725532, "Free Telecom"),
+    (9725533, "Free Telecom"),
+    (9725540, "Rami Levy"),
+    (9725541, "Merkaziya"),
+    (9725543, "Maskyoo"),
+    (9725544, "Cellran Cellular Communications"),
+    (9725545, "Maskyoo"),
+    (9725546, "Xphone"),
+    (9725550, "Annatel"),
+    (9725551, "Annatel"),
+    (9725552, "Annatel"),
+    (9725555, "Rami Levy"),
+    (9725557, "Rami Levy"),
+    (9725570, "Cellact"),
+    (9725571, "Cellact"),
+    (9725572, "Cellact"),
+    (9725577, "019mobile"),
+    (9736630, "zain BH"),
+    (9736633, "zain BH"),
+    (9736634, "zain BH"),
+    (9736635, "zain BH"),
+    (9736636, "zain BH"),
+    (9736637, "zain BH"),
+    (9736638, "zain BH"),
+    (9736639, "zain BH"),
+    (9736670, "Batelco"),
+    (9736671, "Batelco"),
+    (9736672, "Batelco"),
+    (9736673, "Batelco"),
+    (9736674, "Batelco"),
+    (9736675, "Batelco"),
+    (9736676, "Batelco"),
+    (9736678, "Batelco"),
+    (9736679, "Batelco"),
+    (9899996, "Rightel"),
+    (9899997, "Rightel"),
+    (9899998, "Rightel"),
+    (9899999, "Rightel"),
+    (9955002, "Silknet"),
+    (9955003, "Unicell Mobile"),
+    (9955004, "Silknet"),
+    (9955008, "Silknet"),
+    (9955050, "Silknet"),
+    (9955055, "MagtiCom"),
+    (9955111, "MagtiCom"),
+    (9955112, "MagtiCom"),
+    (9955113, "MagtiCom"),
+    (9955114, "MagtiCom"),
+    (9955117, "MagtiCom"),
+    (9955225, "Silknet"),
+    (9955333, "MagtiCom"),
+    (9955335, "Silknet"),
+    (9955440, "Cellfie"),
+    (9955441, "Cellfie"),
+    (9955442, "Cellfie"),
+    (9955443, "Cellfie"),
+    (9955445, "Silknet"),
+    (9955447, "Cellfie"),
+    (9955449, "Cellfie"),
+    (9955520, "Premium Net International SRL"),
+    (9955522, "Asanet"),
+    (9955750, "Asanet"),
+    (9955755, "Silknet"),
+    (9955855, "Globalcell"),
+    (9955858, "MagtiCom"),
+    (9986129, "MobiUZ"),
+    (9986135, "MobiUZ"),
+    (9986150, "MobiUZ"),
+    (9986161, "MobiUZ"),
+    (9986221, "MobiUZ"),
+    (9986229, "MobiUZ"),
+    (9986529, "MobiUZ"),
+    (9986530, "MobiUZ"),
+    (9986559, "MobiUZ"),
+    (9986637, "MobiUZ"),
+    (9986639, "MobiUZ"),
+    (9986670, "MobiUZ"),
+    (9986677, "MobiUZ"),
+    (9986690, "MobiUZ"),
+    (9986691, "MobiUZ"),
+    (9986692, "MobiUZ"),
+    (9986693, "MobiUZ"),
+    (9986770, "MobiUZ"),
+    (9986773, "MobiUZ"),
+    (9987229, "MobiUZ"),
+    (9987232, "MobiUZ"),
+    (9987236, "MobiUZ"),
+    (9987257, "MobiUZ"),
+    (9987333, "MobiUZ"),
+    (9987350, "MobiUZ"),
+    (9987359, "MobiUZ"),
+    (9987470, "MobiUZ"),
+    (9987520, "MobiUZ"),
+    (9987524, "MobiUZ"),
+    (9987529, "MobiUZ"),
+    (12465211, "Digicel"),
+    (12465214, "LIME"),
+    (12465217, "KW Telecommunications"),
+    (12844689, "CCT"),
+    (17873191, "Claro"),
+    (17873192, "Claro"),
+    (17873193, "Claro"),
+    (17873194, "Claro"),
+    (17873195, "Claro"),
+    (17873196, "Claro"),
+    (17873197, "Claro"),
+    (17873198, "Claro"),
+    (17873199, "Claro"),
+    (19392410, "Claro"),
+    (19392411, "Claro"),
+    (19392412, "Claro"),
+    (19392413, "Claro"),
+    (19392414, "Claro"),
+    (19392415, "Claro"),
+    (19392416, "Claro"),
+    (19392433, "Claro"),
+    (19392434, "Claro"),
+    (19392435, "Claro"),
+    (19392436, "Claro"),
+    (19392437, "Claro"),
+    (19392438, "Claro"),
+    (19392439, "Claro"),
+    (26263900, "Orange"),
+    (26263901, "Orange"),
+    (26263902, "Orange"),
+    (26263903, "Telco OI"),
+    (26263904, "Telco OI"),
+    (26263905, "Telco OI"),
+    (26263906, "Telco OI"),
+    (26263907, "Telco OI"),
+    (26263909, "SFR"),
+    (26263910, "SFR"),
+    (26263911, "SFR"),
+    (26263919, "Telco OI"),
+    (26263926, "Telco OI"),
+    (26263930, "BJT"),
+    (26263939, "Telco OI"),
+    (26263950, "BJT"),
+    (26263955, "Orange"),
+    (26263956, "Orange"),
+    (26263957, "Orange"),
+    (26263958, "Orange"),
+    (26263959, "Orange"),
+    (26263960, "Orange"),
+    (26263961, "Orange"),
+    (26263962, "Orange"),
+    (26263963, "Orange"),
+    (26263964, "Orange"),
+    (26263965, "SFR"),
+    (26263966, "SFR"),
+    (26263967, "SFR"),
+    (26263968, "SFR"),
+    (26263969, "SFR"),
+    (26263970, "BJT"),
+    (26263971, "Telco OI"),
+    (26263972, "Telco OI"),
+    (26263973, "Telco OI"),
+    (26263974, "Telco OI"),
+    (26263975, "Telco OI"),
+    (26263976, "Orange"),
+    (26263977, "Orange"),
+    (26263978, "Orange"),
+    (26263979, "Orange"),
+    (26263990, "BJT"),
+    (26263994, "Telco OI"),
+    (26263995, "Telco OI"),
+    (26263996, "Telco OI"),
+    (26263997, "Telco OI"),
+    (26263999, "Orange"),
+    (26269240, "Orange"),
+    (26269241, "Orange"),
+    (26269242, "Orange"),
+    (26269243, "Orange"),
+    (26269244, "Orange"),
+    (26269292, "Telco OI"),
+    (26269293, "Telco OI"),
+    (26269294, "Telco OI"),
+    (26269300, "Orange"),
+    (26269301, "SFR"),
+    (26269302, "SFR"),
+    (26269303, "SFR"),
+    (26269304, "SFR"),
+    (26269305, "ZEOP Mobile"),
+    (26269306, "Orange"),
+    (26269310, "SFR"),
+    (26269311, "Orange"),
+    (26269312, "ZEOP Mobile"),
+    (26269313, "SFR"),
+    (26269320, "SFR"),
+    (26269321, "Orange"),
+    (26269322, "Orange"),
+    (26269330, "Telco OI"),
+    (26269331, "Telco OI"),
+    (26269332, "Telco OI"),
+    (26269333, "Orange"),
+    (26269339, "Orange"),
+    (26269350, "Telco OI"),
+    (26269351, "Telco OI"),
+    (26269352, "Telco OI"),
+    (26269353, "Telco OI"),
+    (26269354, "Telco OI"),
+    (26269355, "Orange"),
+    (26269360, "Telco OI"),
+    (26269361, "ZEOP Mobile"),
+    (26269362, "ZEOP Mobile"),
+    (26269363, "ZEOP Mobile"),
+    (26269364, "ZEOP Mobile"),
+    (26269365, "ZEOP Mobile"),
+    (26269366, "Orange"),
+    (26269370, "Telco OI"),
+    (26269371, "Telco OI"),
+    (26269372, "Telco OI"),
+    (26269373, "Telco OI"),
+    (26269377, "Orange"),
+    (26269388, "Orange"),
+    (26269390, "Orange"),
+    (26269391, "Orange"),
+    (26269392, "Orange"),
+    (26269393, "Orange"),
+    (26269394, "SFR"),
+    (26269397, "SFR"),
+    (26269399, "Orange"),
+    (26270920, "SFR"),
+    (26270921, "Orange"),
+    (26270922, "Telco OI"),
+    (26270923, "ZEOP Mobile"),
+    (26270935, "SFR"),
+    (26270936, "Telco OI"),
+    (26270937, "Orange"),
+    (27134920, "Vodacom"),
+    (27134921, "Vodacom"),
+    (27134922, "Vodacom"),
+    (27134925, "Vodacom"),
+    (27144950, "Vodacom"),
+    (27144952, "Vodacom"),
+    (27144953, "Vodacom"),
+    (27144955, "Vodacom"),
+    (27154920, "Vodacom"),
+    (27154950, "Vodacom"),
+    (27154951, "Vodacom"),
+    (27164920, "Vodacom"),
+    (27174920, "Vodacom"),
+    (27184920, "Vodacom"),
+    (27224950, "Vodacom"),
+    (27274950, "Vodacom"),
+    (27284920, "Vodacom"),
+    (27324920, "Vodacom"),
+    (27334920, "Vodacom"),
+    (27344920, "Vodacom"),
+    (27354920, "Vodacom"),
+    (27364920, "Vodacom"),
+    (27394920, "Vodacom"),
+    (27404920, "Vodacom"),
+    (27424920, "Vodacom"),
+    (27434920, "Vodacom"),
+    (27434921, "Vodacom"),
+    (27444920, "Vodacom"),
+    (27444921, "Vodacom"),
+    (27454920, "Vodacom"),
+    (27464920, "Vodacom"),
+    (27474950, "Vodacom"),
+    (27484920, "Vodacom"),
+    (27494920, "Vodacom"),
+    (27544950, "Vodacom"),
+    (27564920, "Vodacom"),
+    (27574920, "Vodacom"),
+    (27584920, "Vodacom"),
+    (30685185, "Cyta"),
+    (30685500, "Cyta"),
+    (30685501, "BWS"),
+    (30685505, "Cyta"),
+    (30685550, "Cyta"),
+    (30685555, "Cyta"),
+    (30685585, "Cyta"),
+    (30687500, "BWS"),
+    (30688500, "BWS"),
+    (30689900, "OTEGlobe"),
+    (30689901, "M-STAT"),
+    (30690100, "MI Carrier Services"),
+    (30690199, "BWS"),
+    (30690200, "MI Carrier Services"),
+    (30690299, "BWS"),
+    (30690300, "MI Carrier Services"),
+    (30690399, "BWS"),
+    (30690400, "MI Carrier Services"),
+    (30690499, "BWS"),
+    (30690500, "MI Carrier Services"),
+    (30690555, "AMD Telecom"),
+    (30690574, "BWS"),
+    (30690575, "BWS"),
+    (30690588, "BWS"),
+    (30690599, "BWS"),
+    (30691000, "BWS"),
+    (30691234, "M-STAT"),
+    (30691345, "Forthnet"),
+    (30691400, "AMD Telecom"),
+    (30691600, "Compatel"),
+    (30691700, "Inter Telecom"),
+    (30691888, "OSE"),
+    (30692354, "Premium Net International"),
+    (30692356, "SIA NETBALT"),
+    (30692428, "Premium Net International"),
+    (30695200, "Vodafone"),
+    (30695201, "Vodafone"),
+    (30695202, "Vodafone"),
+    (30695203, "Vodafone"),
+    (30695310, "MI Carrier Services"),
+    (30695328, "Premium Net International"),
+    (30695330, "Apifon"),
+    (30695340, "AMD Telecom"),
+    (30695355, "Cyta"),
+    (30695456, "BWS"),
+    (30695490, "MI Carrier Services"),
+    (30695499, "M-STAT"),
+    (30699010, "BWS"),
+    (30699022, "Yuboto"),
+    (30699046, "Premium Net International"),
+    (30699048, "AMD Telecom"),
+    (30699099, "BWS"),
+    (32467306, "Telenet"),
+    (35160929, "NOS"),
+    (35163920, "Lycamobile"),
+    (35163924, "MEO"),
+    (35163929, "NOS"),
+    (35165920, "Lycamobile"),
+    (35165924, "MEO"),
+    (35165929, "NOS"),
+    (35166929, "NOS"),
+    (36312000, "Netfone Telecom"),
+    (36312001, "Netfone Telecom"),
+    (36313190, "One"),
+    (36313191, "One"),
+    (36313192, "One"),
+    (36313193, "One"),
+    (36313194, "One"),
+    (36313195, "One"),
+    (36313196, "One"),
+    (36313197, "One"),
+    (36313199, "One"),
+    (36313290, "One"),
+    (36313291, "One"),
+    (36313292, "One"),
+    (36313330, "Vidanet"),
+    (36313331, "Vidanet"),
+    (36313666, "One"),
+    (37066105, "BITĖ"),
+    (37066180, "BITĖ"),
+    (37066181, "BITĖ"),
+    (37066182, "BITĖ"),
+    (37066183, "BITĖ"),
+    (37066184, "BITĖ"),
+    (37066185, "BITĖ"),
+    (37066186, "Lancelot Telecom"),
+    (37066187, "BITĖ"),
+    (37066188, "BITĖ"),
+    (37066311, "Telia"),
+    (37066313, "BITĖ"),
+    (37066314, "BITĖ"),
+    (37066315, "BITĖ"),
+    (37066316, "BITĖ"),
+    (37066317, "BITĖ"),
+    (37066318, "BITĖ"),
+    (37066319, "BITĖ"),
+    (37066320, "BITĖ"),
+    (37066322, "Telia"),
+    (37066323, "BITĖ"),
+    (37066522, "Telia"),
+    (37066523, "Telia"),
+    (37066524, "Telia"),
+    (37066525, "Telia"),
+    (37066526, "Telia"),
+    (37066527, "Telia"),
+    (37066528, "Telia"),
+    (37066529, "Telia"),
+    (37066621, "Telia"),
+    (37066622, "BITĖ"),
+    (37066623, "BITĖ"),
+    (37066624, "BITĖ"),
+    (37066625, "BITĖ"),
+    (37066626, "BITĖ"),
+    (37066627, "BITĖ"),
+    (37066628, "BITĖ"),
+    (37066629, "BITĖ"),
+    (37066722, "Tele 2"),
+    (37066723, "Tele 2"),
+    (37066724, "Tele 2"),
+    (37066725, "Tele 2"),
+    (37066726, "Tele 2"),
+    (37066728, "BITĖ"),
+    (37066729, "BITĖ"),
+    (37066839, "Tele 2"),
+    (37066840, "Tele 2"),
+    (37066841, "Tele 2"),
+    (37066842, "Tele 2"),
+    (37066860, "Tele 2"),
+    (37066861, "Tele 2"),
+    (37066862, "Tele 2"),
+    (37066863, "Tele 2"),
+    (37066864, "Tele 2"),
+    (37066865, "Tele 2"),
+    (37066876, "BITĖ"),
+    (37066877, "BITĖ"),
+    (37069740, "Telia"),
+    (37069741, "Telia"),
+    (37069742, "BITĖ"),
+    (37069743, "BITĖ"),
+    (37069744, "Telia"),
+    (37069747, "Telia"),
+    (37069748, "Telia"),
+    (37069749, "Telia"),
+    (37123100, "Bite Latvia"),
+    (37123400, "Bite Latvia"),
+    (37123402, "Tele2"),
+    (37123444, "Bite Latvia"),
+    (37123456, "Tele2"),
+    (37123500, "Bite Latvia"),
+    (37254664, "Elisa"),
+    (37254665, "Elisa"),
+    (37254667, "Elisa"),
+    (37254668, "Elisa"),
+    (37254669, "Elisa"),
+    (37259120, "Tele 2"),
+    (37259121, "Tele 2"),
+    (37259140, "Tele 2"),
+    (37259144, "Tele 2"),
+    (37282056, "Tele 2"),
+    (37282057, "Tele 2"),
+    (37282058, "Tele 2"),
+    (37282059, "Tele 2"),
+    (37282199, "Tele 2"),
+    (37284510, "Telia Eesti AS"),
+    (37284511, "Telia Eesti AS"),
+    (37284512, "Telia Eesti AS"),
+    (38597595, "YATECO"),
+    (38597596, "Altavox"),
+    (38597597, "INNOVAC"),
+    (38597599, "Digicom"),
+    (38974774, "Telekabel"),
+    (42070301, "YATECO"),
+    (42070370, "FAYN Telecommunications"),
+    (42070373, "COMA"),
+    (42070376, "BSG"),
+    (42073030, "T-Mobile"),
+    (42073033, "Axfone"),
+    (42073035, "MATERNA Communications"),
+    (42073040, "Compatel"),
+    (42073041, "SMART Comp"),
+    (42073042, "SMART Comp"),
+    (42073043, "PODA a.s. (SkyNet)"),
+    (42073044, "Vodafone"),
+    (42073045, "Vodafone"),
+    (42073046, "Vodafone"),
+    (42073047, "Vodafone"),
+    (42073048, "Vodafone"),
+    (42073070, "T-Mobile"),
+    (42073071, "T-Mobile"),
+    (42073072, "T-Mobile"),
+    (42073073, "T-Mobile"),
+    (42073077, "T-Mobile"),
+    (42077050, "Compatel"),
+    (42077051, "3ton s.r.o."),
+    (42077052, "3ton s.r.o."),
+    (42077070, "O2"),
+    (42077071, "Cesky bezdrat"),
+    (42077072, "Cesky bezdrat"),
+    (42077073, "T-Mobile"),
+    (42077077, "T-Mobile"),
+    (42077078, "YATECO"),
+    (42077100, "TT Quality s.r.o."),
+    (42077111, "miniTEL"),
+    (42077177, "MONTYHO TECHNOLOGY s.r.o. (CANISTEC)"),
+    (42077200, "TT Quality s.r.o."),
+    (42077272, "IPEX"),
+    (42077273, "IPEX"),
+    (42077277, "Dragon Internet"),
+    (42077811, "Vodafone"),
+    (42077812, "Vodafone"),
+    (42077813, "Vodafone"),
+    (42077814, "Vodafone"),
+    (42077815, "Vodafone"),
+    (42077816, "Vodafone"),
+    (42077817, "Vodafone"),
+    (42077818, "Vodafone"),
+    (42077819, "Vodafone"),
+    (42077880, "ha-vel internet"),
+    (42077881, "Vodafone"),
+    (42077882, "Vodafone"),
+    (42077883, "Vodafone"),
+    (42077884, "Vodafone"),
+    (42077885, "Vodafone"),
+    (42077886, "Vodafone"),
+    (42077887, "Vodafone"),
+    (42077888, "Vodafone"),
+    (42077889, "Vodafone"),
+    (42077900, "TT Quality s.r.o."),
+    (42077977, "TT Quality s.r.o."),
+    (42077990, "ha-vel internet"),
+    (42077997, "Plus4U Mobile s.r.o."),
+    (42077999, "T-Mobile"),
+    (42079000, "Nordic Telecom s.r.o.(Air Telecom - MobilKom)"),
+    (42079058, "T-Mobile"),
+    (42079083, "T-Mobile"),
+    (42079191, "T-Mobile"),
+    (42079192, "3ton s.r.o."),
+    (42079193, "GOPE Systems a.s."),
+    (42079194, "O2"),
+    (42079195, "O2"),
+    (42079196, "O2"),
+    (42079197, "O2"),
+    (42079198, "O2"),
+    (42079199, "O2"),
+    (42079234, "Tesco Mobile CR"),
+    (42079235, "Tesco Mobile CR"),
+    (42079238, "Tesco Mobile CR"),
+    (42079240, "Tesco Mobile CR"),
+    (42079241, "Tesco Mobile CR"),
+    (42079242, "Tesco Mobile CR"),
+    (42079243, "Tesco Mobile CR"),
+    (42079244, "Tesco Mobile CR"),
+    (42079260, "SIA Net Balt"),
+    (42079750, "Dial Telecom"),
+    (42079770, "T-Mobile"),
+    (42079771, "T-Mobile"),
+    (42079772, "T-Mobile"),
+    (42079775, "T-Mobile"),
+    (42079777, "T-Mobile"),
+    (42079779, "T-Mobile"),
+    (42079797, "T-Mobile"),
+    (42079799, "T-Mobile"),
+    (42079920, "METRONET"),
+    (42079950, "TERMS"),
+    (42079951, "TERMS"),
+    (42079952, "TERMS"),
+    (42079953, "TERMS"),
+    (42079954, "T-Mobile"),
+    (42079955, "GoMobil"),
+    (42079956, "GoMobil"),
+    (42079957, "T-Mobile"),
+    (42079958, "GoMobil"),
+    (42079979, "miniTEL"),
+    (42194312, "Alternet, s.r.o."),
+    (42194333, "IPfon, s.r.o."),
+    (44762450, "BlueWave Communications"),
+    (44762456, "Sure"),
+    (45429060, "telenor"),
+    (45429061, "telenor"),
+    (45429062, "telenor"),
+    (45429063, "telenor"),
+    (45429064, "telenor"),
+    (45429065, "telenor"),
+    (45429066, "telenor"),
+    (45429067, "telenor"),
+    (45429068, "tdc"),
+    (45429084, "tdc"),
+    (45717409, "tdc"),
+    (45717429, "tdc"),
+    (45719649, "tdc"),
+    (45719689, "tdc"),
+    (46701332, "EU Tel AB"),
+    (46701334, "EU Tel AB"),
+    (46701335, "EU Tel AB"),
+    (46701336, "EU Tel AB"),
+    (46701338, "EU Tel AB"),
+    (46701339, "EU Tel AB"),
+    (46701341, "EU Tel AB"),
+    (46701342, "EU Tel AB"),
+    (46701346, "EU Tel AB"),
+    (46701347, "EU Tel AB"),
+    (46701348, "EU Tel AB"),
+    (46701349, "EU Tel AB"),
+    (46701350, "42 Telecom AB"),
+    (46701353, "EU Tel AB"),
+    (46701356, "EU Tel AB"),
+    (46701358, "EU Tel AB"),
+    (46701359, "EU Tel AB"),
+    (46701362, "EU Tel AB"),
+    (46701364, "42 Telecom AB"),
+    (46701365, "42 Telecom AB"),
+    (46701366, "42 Telecom AB"),
+    (46701367, "42 Telecom AB"),
+    (46701368, "42 Telecom AB"),
+    (46701369, "42 Telecom AB"),
+    (46701381, "42 Telecom AB"),
+    (46701383, "42 Telecom AB"),
+    (46701384, "42 Telecom AB"),
+    (46701385, "42 Telecom AB"),
+    (46701386, "42 Telecom AB"),
+    (46701388, "42 Telecom AB"),
+    (46701389, "42 Telecom AB"),
+    (46701390, "42 Telecom AB"),
+    (46701391, "42 Telecom AB"),
+    (46701392, "42 Telecom AB"),
+    (46701393, "42 Telecom AB"),
+    (46701394, "42 Telecom AB"),
+    (46701396, "42 Telecom AB"),
+    (46701397, "42 Telecom AB"),
+    (46701398, "42 Telecom AB"),
+    (46701399, "42 Telecom AB"),
+    (46701717, "42 Telecom AB"),
+    (46701741, "42 Telecom AB"),
+    (46701779, "EU Tel AB"),
+    (46701780, "42 Telecom AB"),
+    (46701781, "42 Telecom AB"),
+    (46701782, "42 Telecom AB"),
+    (46701783, "42 Telecom AB"),
+    (46701784, "42 Telecom AB"),
+    (46701785, "42 Telecom AB"),
+    (46701786, "42 Telecom AB"),
+    (46701788, "Ventelo Sverige"),
+    (46701790, "Svea Billing System"),
+    (46701791, "Svea Billing System"),
+    (46701792, "Svea Billing System"),
+    (46701793, "Svea Billing System"),
+    (46701794, "Svea Billing System"),
+    (46701795, "Svea Billing System"),
+    (46701796, "Svea Billing System"),
+    (46701797, "EU Tel AB"),
+    (46701798, "Gotalandsnatet"),
+    (46701897, "Gotalandsnatet"),
+    (46701920, "Viatel Sweden"),
+    (46701921, "Beepsend"),
+    (46701924, "Compatel Limited"),
+    (46701925, "Mobile Arts AB"),
+    (46701926, "Beepsend"),
+    (46701928, "HORISEN AG"),
+    (46701965, "42 Telecom AB"),
+    (46701966, "42 Telecom AB"),
+    (46701967, "42 Telecom AB"),
+    (46701968, "42 Telecom AB"),
+    (46701977, "42 Telecom AB"),
+    (46701978, "42 Telecom AB"),
+    (46701979, "42 Telecom AB"),
+    (46701990, "Telenor Sverige"),
+    (46701991, "Telenor Sverige"),
+    (46701992, "Telenor Sverige"),
+    (46701993, "Telenor Sverige"),
+    (46701994, "Telenor Sverige"),
+    (46701995, "Telenor Sverige"),
+    (46701997, "42 Telecom AB"),
+    (46701998, "MERCURY INTERNATIONA"),
+    (46701999, "42 Telecom AB"),
+    (46720501, "Generic Mobil Systems"),
+    (46720502, "Telavox AB"),
+    (46720503, "Telavox AB"),
+    (46720504, "Telavox AB"),
+    (46720505, "Telavox AB"),
+    (46720506, "Telavox AB"),
+    (46720507, "Telavox AB"),
+    (46720508, "Soatso AB"),
+    (46720509, "Telavox AB"),
+    (46720580, "MERCURY INTERNATIONA"),
+    (46720581, "Beepsend"),
+    (46720582, "iCentrex Sweden AB"),
+    (46720583, "iCentrex Sweden AB"),
+    (46720584, "iCentrex Sweden AB"),
+    (46720585, "iCentrex Sweden AB"),
+    (46720586, "iCentrex Sweden AB"),
+    (46720801, "Telavox AB"),
+    (46720802, "Telavox AB"),
+    (46720803, "Telavox AB"),
+    (46720806, "TeliaSonera"),
+    (46720807, "Telavox AB"),
+    (46720808, "Telavox AB"),
+    (46720893, "TeliaSonera"),
+    (46720894, "TeliaSonera"),
+    (46720895, "TeliaSonera"),
+    (46720896, "TeliaSonera"),
+    (46720898, "Xplora mobile"),
+    (46720899, "Soatso AB"),
+    (46720900, "Soatso AB"),
+    (46720902, "Telavox AB"),
+    (46720908, "Telavox AB"),
+    (46720995, "Telavox AB"),
+    (46720996, "Telavox AB"),
+    (46720999, "MOBIWEB LTD"),
+    (46721490, "Tele2 Sverige"),
+    (46721491, "Tele2 Sverige"),
+    (46721492, "Tele2 Sverige"),
+    (46721493, "Tele2 Sverige"),
+    (46721494, "Tele2 Sverige"),
+    (46721495, "Beepsend"),
+    (46721496, "Soatso AB"),
+    (46721497, "MONTY UK GLOBAL LIM"),
+    (46721498, "Beepsend"),
+    (46723401, "LOXYTEL AB"),
+    (46723402, "Soatso AB"),
+    (46723403, "Beepsend"),
+    (46723404, "LOXYTEL AB"),
+    (46723405, "LOXYTEL AB"),
+    (46723406, "LOXYTEL AB"),
+    (46723407, "LOXYTEL AB"),
+    (46723408, "ONOFF TELECOM SAS"),
+    (46723409, "ONOFF TELECOM SAS"),
+    (46723440, "Beepsend"),
+    (46723442, "Xplora mobile"),
+    (46723443, "Xplora mobile"),
+    (46723445, "Xplora mobile"),
+    (46723446, "Xplora mobile"),
+    (46723447, "Xplora mobile"),
+    (46723449, "Beepsend"),
+    (46723460, "Beepsend"),
+    (46723461, "Telenor Sverige"),
+    (46723464, "Telenor Sverige"),
+    (46723466, "Telenor Sverige"),
+    (46723468, "Telenor Sverige"),
+    (46723490, "Beepsend"),
+    (46723491, "Telenor Sverige"),
+    (46723499, "Beepsend"),
+    (46724000, "Telenor Sverige"),
+    (46724001, "Beepsend"),
+    (46724002, "Voice Integrate"),
+    (46724003, "Voice Integrate"),
+    (46724004, "Beepsend"),
+    (46724005, "Soatso AB"),
+    (46724008, "Telavox AB"),
+    (46726000, "Soatso AB"),
+    (46726001, "FINK TELECOM SERVIC"),
+    (46726003, "MOBIWEB LTD"),
+    (46726004, "Tele2 Sverige"),
+    (46726005, "Tele2 Sverige"),
+    (46726006, "Telavox AB"),
+    (46726008, "Global Telefoni Sve"),
+    (46726009, "Telenor Sverige"),
+    (46726421, "WARSIN HOLDING AB"),
+    (46726422, "Beepsend"),
+    (46726423, "Global Telefoni Sve"),
+    (46726424, "Global Telefoni Sve"),
+    (46726425, "Global Telefoni Sve"),
+    (46726426, "Global Telefoni Sve"),
+    (46726427, "Global Telefoni Sve"),
+    (46726428, "Global Telefoni Sve"),
+    (46726429, "Global Telefoni Sve"),
+    (46726990, "Gotalandsnatet"),
+    (46726991, "Fast Communication"),
+    (46726992, "Fast Communication"),
+    (46726993, "SWEDFONENET AB"),
+    (46726994, "SWEDFONENET AB"),
+    (46726995, "SWEDFONENET AB"),
+    (46726996, "Nortech"),
+    (46726997, "ONOFF TELECOM SAS"),
+    (46726998, "ONOFF TELECOM SAS"),
+    (46727501, "ONOFF TELECOM SAS"),
+    (46727502, "ONOFF TELECOM SAS"),
+    (46727503, "MINITEL AB"),
+    (46727504, "FINK TELECOM SERVIC"),
+    (46727505, "Soatso AB"),
+    (46727506, "FINK TELECOM SERVIC"),
+    (46727507, "FINK TELECOM SERVIC"),
+    (46727510, "ONOFF TELECOM SAS"),
+    (46727511, "ONOFF TELECOM SAS"),
+    (46727515, "FINK TELECOM SERVIC"),
+    (46727516, "FINK TELECOM SERVIC"),
+    (46728100, "Voice Integrate"),
+    (46728101, "Beepsend"),
+    (46728197, "Telenor Sverige"),
+    (46728198, "Telavox AB"),
+    (46728199, "Soatso AB"),
+    (46731214, "Voice Integrate"),
+    (46731215, "COOLTEL APS"),
+    (46731216, "HORISEN AG"),
+    (46731219, "CLX Networks AB"),
+    (46731245, "EU Tel AB"),
+    (46731247, "Beepsend"),
+    (46731248, "TELNESS AB"),
+    (46731706, "Soatso AB"),
+    (46731721, "REWICOM SCANDINAVIA"),
+    (46731723, "REWICOM SCANDINAVIA"),
+    (46731724, "REWICOM SCANDINAVIA"),
+    (46731725, "REWICOM SCANDINAVIA"),
+    (46731726, "REWICOM SCANDINAVIA"),
+    (46731727, "Beepsend"),
+    (46731728, "Beepsend"),
+    (46731729, "IPIFY LIMITED"),
+    (46734520, "Soatso AB"),
+    (46734521, "Soatso AB"),
+    (46734522, "Soatso AB"),
+    (46734523, "Soatso AB"),
+    (46734524, "Soatso AB"),
+    (46734525, "Soatso AB"),
+    (46734527, "Soatso AB"),
+    (46734528, "Soatso AB"),
+    (46734529, "Soatso AB"),
+    (46734600, "MERCURY INTERNATIONA"),
+    (46734601, "MERCURY INTERNATIONA"),
+    (46734700, "42 Telecom AB"),
+    (46734702, "MOBIWEB LTD"),
+    (46734703, "MOBIWEB LTD"),
+    (46734704, "MOBIWEB LTD"),
+    (46734705, "MOBIWEB LTD"),
+    (46734706, "MOBIWEB LTD"),
+    (46734707, "MOBIWEB LTD"),
+    (46734708, "MOBIWEB LTD"),
+    (46734709, "MOBIWEB LTD"),
+    (46734731, "MERCURY INTERNATIONA"),
+    (46734732, "MERCURY INTERNATIONA"),
+    (46734733, "MERCURY INTERNATIONA"),
+    (46734734, "MERCURY INTERNATIONA"),
+    (46734735, "MERCURY INTERNATIONA"),
+    (46734736, "MERCURY INTERNATIONA"),
+    (46734737, "MERCURY INTERNATIONA"),
+    (46734738, "MERCURY INTERNATIONA"),
+    (46734739, "MERCURY INTERNATIONA"),
+    (46734740, "Gotalandsnatet"),
+    (46734741, "Soatso AB"),
+    (46734743, "Soatso AB"),
+    (46734744, "Soatso AB"),
+    (46734745, "Beepsend"),
+    (46734747, "Telavox AB"),
+    (46738631, "Beepsend"),
+    (46738632, "Beepsend"),
+    (46738634, "MERCURY INTERNATIONA"),
+    (46738635, "MERCURY INTERNATIONA"),
+    (46738636, "MERCURY INTERNATIONA"),
+    (46738637, "MERCURY INTERNATIONA"),
+    (46738638, "MERCURY INTERNATIONA"),
+    (46738639, "MERCURY INTERNATIONA"),
+    (46738640, "EU Tel AB"),
+    (46738641, "iCentrex Sweden AB"),
+    (46738642, "42 Telecom AB"),
+    (46738643, "Beepsend"),
+    (46738644, "Beepsend"),
+    (46738645, "Beepsend"),
+    (46738647, "EU Tel AB"),
+    (46738651, "MERCURY INTERNATIONA"),
+    (46738652, "MERCURY INTERNATIONA"),
+    (46738653, "MERCURY INTERNATIONA"),
+    (46738654, "MERCURY INTERNATIONA"),
+    (46738655, "MERCURY INTERNATIONA"),
+    (46738656, "MERCURY INTERNATIONA"),
+    (46738657, "MERCURY INTERNATIONA"),
+    (46738658, "MERCURY INTERNATIONA"),
+    (46738659, "MERCURY INTERNATIONA"),
+    (46738691, "MERCURY INTERNATIONA"),
+    (46738692, "MERCURY INTERNATIONA"),
+    (46738693, "MERCURY INTERNATIONA"),
+    (46738694, "MERCURY INTERNATIONA"),
+    (46738695, "MERCURY INTERNATIONA"),
+    (46738696, "MERCURY INTERNATIONA"),
+    (46738697, "MERCURY INTERNATIONA"),
+    (46738698, "MERCURY INTERNATIONA"),
+    (46738699, "MERCURY INTERNATIONA"),
+    (46764651, "EU Tel AB"),
+    (46764652, "MERCURY INTERNATIONA"),
+    (46764653, "MERCURY INTERNATIONA"),
+    (46764654, "MERCURY INTERNATIONA"),
+    (46764655, "MERCURY INTERNATIONA"),
+    (46764656, "MERCURY INTERNATIONA"),
+    (46764657, "MERCURY INTERNATIONA"),
+    (46764658, "MERCURY INTERNATIONA"),
+    (46764659, "MERCURY INTERNATIONA"),
+    (46764901, "MERCURY INTERNATIONA"),
+    (46764902, "MERCURY INTERNATIONA"),
+    (46764903, "MERCURY INTERNATIONA"),
+    (46764904, "MERCURY INTERNATIONA"),
+    (46764905, "MERCURY INTERNATIONA"),
+    (46764906, "MERCURY INTERNATIONA"),
+    (46764907, "MERCURY INTERNATIONA"),
+    (46764908, "MERCURY INTERNATIONA"),
+    (46764909, "MERCURY INTERNATIONA"),
+    (46764940, "Tele2 Sverige"),
+    (46764942, "IPIFY LIMITED"),
+    (46764943, "IPIFY LIMITED"),
+    (46764944, "IPIFY LIMITED"),
+    (46764945, "IPIFY LIMITED"),
+    (46764946, "IPIFY LIMITED"),
+    (46764947, "IPIFY LIMITED"),
+    (46764948, "IPIFY LIMITED"),
+    (46764949, "IPIFY LIMITED"),
+    (46764981, "MERCURY INTERNATIONA"),
+    (46764982, "MERCURY INTERNATIONA"),
+    (46764983, "MERCURY INTERNATIONA"),
+    (46764984, "MERCURY INTERNATIONA"),
+    (46764985, "MERCURY INTERNATIONA"),
+    (46764986, "MERCURY INTERNATIONA"),
+    (46764987, "MERCURY INTERNATIONA"),
+    (46764988, "MERCURY INTERNATIONA"),
+    (46764989, "MERCURY INTERNATIONA"),
+    (46764990, "Gotalandsnatet"),
+    (46764991, "MERCURY INTERNATIONA"),
+    (46764992, "MERCURY INTERNATIONA"),
+    (46764993, "MERCURY INTERNATIONA"),
+    (46764994, "MERCURY INTERNATIONA"),
+    (46764995, "MERCURY INTERNATIONA"),
+    (46764996, "MERCURY INTERNATIONA"),
+    (46764997, "MERCURY INTERNATIONA"),
+    (46764998, "MERCURY INTERNATIONA"),
+    (46766696, "Telavox AB"),
+    (46766697, "Telavox AB"),
+    (46766698, "Telavox AB"),
+    (46766750, "42 Telecom AB"),
+    (46766753, "Beepsend"),
+    (46766754, "Beepsend"),
+    (46766760, "Voice Integrate"),
+    (46766791, "Beepsend"),
+    (46766798, "Beepsend"),
+    (46766799, "42 Telecom AB"),
+    (46766901, "MERCURY INTERNATIONA"),
+    (46766902, "MERCURY INTERNATIONA"),
+    (46766903, "MERCURY INTERNATIONA"),
+    (46766904, "MERCURY INTERNATIONA"),
+    (46766905, "MERCURY INTERNATIONA"),
+    (46766906, "MERCURY INTERNATIONA"),
+    (46766907, "MERCURY INTERNATIONA"),
+    (46766908, "MERCURY INTERNATIONA"),
+    (46766909, "MERCURY INTERNATIONA"),
+    (46766911, "MERCURY INTERNATIONA"),
+    (46766912, "MERCURY INTERNATIONA"),
+    (46766913, "MERCURY INTERNATIONA"),
+    (46766914, "MERCURY INTERNATIONA"),
+    (46766915, "MERCURY INTERNATIONA"),
+    (46766916, "MERCURY INTERNATIONA"),
+    (46766917, "MERCURY INTERNATIONA"),
+    (46766918, "MERCURY INTERNATIONA"),
+    (46766919, "MERCURY INTERNATIONA"),
+    (46766930, "MERCURY INTERNATIONA"),
+    (46766931, "Beepsend"),
+    (46766932, "IPIFY LIMITED"),
+    (46766933, "Connectel AB"),
+    (46766934, "IPIFY LIMITED"),
+    (46766935, "Beepsend"),
+    (46766936, "IPIFY LIMITED"),
+    (46766937, "IPIFY LIMITED"),
+    (46766938, "IPIFY LIMITED"),
+    (46766939, "Soatso AB"),
+    (46767260, "EU Tel AB"),
+    (46767261, "Beepsend"),
+    (46767262, "Beepsend"),
+    (46767265, "HORISEN AG"),
+    (46767266, "Beepsend"),
+    (46767268, "Rebtel Networks"),
+    (46790050, "Telenor Sverige"),
+    (46790051, "Telenor Sverige"),
+    (46790052, "Telenor Sverige"),
+    (46790053, "Telenor Sverige"),
+    (46790054, "Telenor Sverige"),
+    (46790055, "Telenor Sverige"),
+    (46790056, "Telenor Sverige"),
+    (46790057, "Telenor Sverige"),
+    (50363170, "Claro"),
+    (50363171, "Claro"),
+    (50363172, "Claro"),
+    (50363173, "Claro"),
+    (50363174, "Claro"),
+    (50366116, "Movistar"),
+    (50370700, "Claro"),
+    (50370701, "Tigo"),
+    (50370702, "Movistar"),
+    (50370703, "Claro"),
+    (50370704, "Claro"),
+    (50370705, "Claro"),
+    (50370706, "Tigo"),
+    (50370707, "Claro"),
+    (50370708, "Movistar"),
+    (50370709, "Tigo"),
+    (50370710, "Claro"),
+    (50370711, "Movistar"),
+    (50370712, "Claro"),
+    (50370713, "Tigo"),
+    (50370714, "Tigo"),
+    (50370715, "Tigo"),
+    (50370716, "Movistar"),
+    (50370717, "Claro"),
+    (50370718, "SIGET"),
+    (50370719, "Tigo"),
+    (50370730, "Digicel"),
+    (50370731, "Digicel"),
+    (50370732, "Digicel"),
+    (50370733, "Digicel"),
+    (50370734, "Digicel"),
+    (50370735, "Claro"),
+    (50370736, "Claro"),
+    (50370737, "Claro"),
+    (50370738, "Claro"),
+    (50370739, "Claro"),
+    (50370740, "Claro"),
+    (50370741, "Claro"),
+    (50370742, "Claro"),
+    (50370743, "Claro"),
+    (50370744, "Claro"),
+    (50370745, "Claro"),
+    (50370746, "Claro"),
+    (50376865, "Movistar"),
+    (50376866, "Movistar"),
+    (50376867, "Movistar"),
+    (50376868, "Movistar"),
+    (50376869, "Movistar"),
+    (50378020, "Digicel"),
+    (50378021, "Digicel"),
+    (50378022, "Digicel"),
+    (50378023, "Digicel"),
+    (50378024, "Digicel"),
+    (50378025, "Claro"),
+    (50378026, "Claro"),
+    (50378027, "Claro"),
+    (50378028, "Claro"),
+    (50378029, "Claro"),
+    (50670010, "Claro"),
+    (50670011, "Claro"),
+    (50670012, "Claro"),
+    (50670013, "Claro"),
+    (50670014, "Claro"),
+    (50870856, "SPM Telecom"),
+    (51949980, "Movistar"),
+    (51949981, "Movistar"),
+    (51949983, "Movistar"),
+    (51949984, "Movistar"),
+    (51949985, "Movistar"),
+    (51949986, "Movistar"),
+    (55119577, "Vivo"),
+    (55119617, "Claro"),
+    (55119618, "Vivo"),
+    (55119619, "Vivo"),
+    (55119630, "Claro"),
+    (55119631, "Claro"),
+    (55119632, "Claro"),
+    (55119633, "Claro"),
+    (55119637, "Vivo"),
+    (55119638, "Vivo"),
+    (55119639, "Vivo"),
+    (55119640, "Vivo"),
+    (55119641, "Vivo"),
+    (55119647, "Vivo"),
+    (55119648, "Vivo"),
+    (55119649, "Vivo"),
+    (55119657, "Claro"),
+    (55119658, "Claro"),
+    (55119659, "Claro"),
+    (55119660, "Claro"),
+    (55119661, "Claro"),
+    (55119662, "Claro"),
+    (55119663, "Claro"),
+    (55119664, "Claro"),
+    (55119684, "Vivo"),
+    (55119685, "Vivo"),
+    (55119690, "Vivo"),
+    (55119691, "Claro"),
+    (55119692, "Claro"),
+    (55119702, "TIM"),
+    (55119705, "Claro"),
+    (55119706, "Claro"),
+    (55119707, "Claro"),
+    (55119708, "Claro"),
+    (55119709, "Vivo"),
+    (55119797, "Oi"),
+    (55119798, "Oi"),
+    (55119803, "Oi"),
+    (55119804, "Oi"),
+    (55119805, "Oi"),
+    (55119806, "Oi"),
+    (55119807, "Oi"),
+    (55119808, "Oi"),
+    (55119809, "Oi"),
+    (55129881, "Oi"),
+    (55129920, "Claro"),
+    (55129921, "Claro"),
+    (55129922, "Claro"),
+    (55129923, "Claro"),
+    (55129960, "Vivo"),
+    (55129961, "Vivo"),
+    (55129962, "Vivo"),
+    (55139881, "Oi"),
+    (55139920, "Claro"),
+    (55139960, "Vivo"),
+    (55139961, "Vivo"),
+    (55139962, "Vivo"),
+    (55149980, "Vivo"),
+    (55149981, "Vivo"),
+    (55149982, "Vivo"),
+    (55159960, "Vivo"),
+    (55159961, "Vivo"),
+    (55159962, "Vivo"),
+    (55159963, "Vivo"),
+    (55159964, "Vivo"),
+    (55159965, "Vivo"),
+    (55159966, "Vivo"),
+    (55159967, "Vivo"),
+    (55159968, "Vivo"),
+    (55169881, "Oi"),
+    (55169930, "Claro"),
+    (55169931, "Claro"),
+    (55169932, "Claro"),
+    (55169933, "Claro"),
+    (55169934, "Claro"),
+    (55169935, "Claro"),
+    (55179920, "Claro"),
+    (55179921, "Claro"),
+    (55179922, "Claro"),
+    (55189910, "Claro"),
+    (55189911, "Claro"),
+    (55189912, "Claro"),
+    (55189913, "Claro"),
+    (55189914, "Claro"),
+    (55189915, "Claro"),
+    (55189916, "Claro"),
+    (55189917, "Claro"),
+    (55189978, "Vivo"),
+    (55199961, "Vivo"),
+    (55199970, "Vivo"),
+    (55199971, "Vivo"),
+    (55199972, "Vivo"),
+    (55199973, "Vivo"),
+    (55199974, "Vivo"),
+    (55199978, "Vivo"),
+    (55199979, "Vivo"),
+    (55219730, "Claro"),
+    (55219731, "Claro"),
+    (55219732, "Claro"),
+    (55219733, "Claro"),
+    (55219734, "Claro"),
+    (55219735, "Claro"),
+    (55219736, "Claro"),
+    (55229970, "Vivo"),
+    (55229971, "Vivo"),
+    (55229972, "Vivo"),
+    (55229973, "Vivo"),
+    (55229974, "Vivo"),
+    (55229975, "Vivo"),
+    (55249920, "Claro"),
+    (55249921, "Claro"),
+    (55249922, "Claro"),
+    (55249923, "Claro"),
+    (55249924, "Claro"),
+    (55249925, "Claro"),
+    (55249926, "Claro"),
+    (55249927, "Claro"),
+    (55249962, "Vivo"),
+    (55249963, "Vivo"),
+    (55249964, "Vivo"),
+    (55249965, "Vivo"),
+    (55249966, "Vivo"),
+    (55249967, "Vivo"),
+    (55249968, "Vivo"),
+    (55249969, "Vivo"),
+    (55249990, "Vivo"),
+    (55249991, "Vivo"),
+    (55249994, "Vivo"),
+    (55249995, "Vivo"),
+    (55249996, "Vivo"),
+    (55249997, "Vivo"),
+    (55249998, "Vivo"),
+    (55249999, "Vivo"),
+    (55279960, "Vivo"),
+    (55279961, "Vivo"),
+    (55279962, "Vivo"),
+    (55279963, "Vivo"),
+    (55279964, "Vivo"),
+    (55319820, "Claro"),
+    (55319821, "Claro"),
+    (55319822, "Claro"),
+    (55319823, "Claro"),
+    (55319911, "TIM"),
+    (55319912, "TIM"),
+    (55319913, "TIM"),
+    (55319914, "TIM"),
+    (55319915, "TIM"),
+    (55319995, "Telemig Celular"),
+    (55319996, "Telemig Celular"),
+    (55319997, "Telemig Celular"),
+    (55319998, "Telemig Celular"),
+    (55319999, "Telemig Celular"),
+    (55329840, "Claro"),
+    (55329841, "Claro"),
+    (55329842, "Claro"),
+    (55329843, "Claro"),
+    (55329844, "Claro"),
+    (55329845, "Claro"),
+    (55329846, "Claro"),
+    (55329847, "Claro"),
+    (55329996, "Telemig Celular"),
+    (55329998, "Telemig Celular"),
+    (55339998, "Telemig Celular"),
+    (55349840, "Claro"),
+    (55349841, "Claro"),
+    (55349911, "TIM"),
+    (55349912, "TIM"),
+    (55349913, "TIM"),
+    (55349914, "TIM"),
+    (55349915, "TIM"),
+    (55349916, "TIM"),
+    (55349917, "TIM"),
+    (55359996, "Telemig Celular"),
+    (55359997, "Telemig Celular"),
+    (55359998, "Telemig Celular"),
+    (55379998, "Telemig Celular"),
+    (55389991, "Telemig Celular"),
+    (55389992, "Telemig Celular"),
+    (55389993, "Telemig Celular"),
+    (55389994, "Telemig Celular"),
+    (55389995, "Telemig Celular"),
+    (55389996, "Telemig Celular"),
+    (55389997, "Telemig Celular"),
+    (55389998, "Telemig Celular"),
+    (55419870, "Claro"),
+    (55419871, "Claro"),
+    (55419872, "Claro"),
+    (55419873, "Claro"),
+    (55419874, "Claro"),
+    (55419910, "Vivo"),
+    (55429880, "Claro"),
+    (55429881, "Claro"),
+    (55429882, "Claro"),
+    (55429883, "Claro"),
+    (55429884, "Claro"),
+    (55429885, "Claro"),
+    (55429886, "Claro"),
+    (55429910, "Vivo"),
+    (55439880, "Claro"),
+    (55439881, "Claro"),
+    (55439882, "Claro"),
+    (55439883, "Claro"),
+    (55439884, "Claro"),
+    (55439910, "Vivo"),
+    (55439960, "TIM"),
+    (55439990, "TIM"),
+    (55439991, "TIM"),
+    (55439992, "TIM"),
+    (55439993, "TIM"),
+    (55439995, "TIM"),
+    (55439996, "TIM"),
+    (55439997, "TIM"),
+    (55439998, "TIM"),
+    (55449880, "Claro"),
+    (55449881, "Claro"),
+    (55449882, "Claro"),
+    (55449883, "Claro"),
+    (55449884, "Claro"),
+    (55449885, "Claro"),
+    (55449910, "Vivo"),
+    (55459910, "Vivo"),
+    (55469881, "Claro"),
+    (55469910, "Vivo"),
+    (55479920, "Vivo"),
+    (55479921, "Vivo"),
+    (55479922, "Vivo"),
+    (55479923, "Vivo"),
+    (55479998, "TIM"),
+    (55489880, "Claro"),
+    (55489881, "Claro"),
+    (55489882, "Claro"),
+    (55489883, "Claro"),
+    (55489884, "Claro"),
+    (55489885, "Claro"),
+    (55489910, "Vivo"),
+    (55489996, "TIM"),
+    (55489998, "TIM"),
+    (55499880, "Claro"),
+    (55499881, "Claro"),
+    (55499882, "Claro"),
+    (55499883, "Claro"),
+    (55499884, "Claro"),
+    (55499885, "Claro"),
+    (55499886, "Claro"),
+    (55499887, "Claro"),
+    (55499910, "Vivo"),
+    (55499998, "TIM"),
+    (55519940, "Claro"),
+    (55519941, "Claro"),
+    (55519942, "Claro"),
+    (55519943, "Claro"),
+    (55519944, "Claro"),
+    (55519950, "Vivo"),
+    (55519951, "Vivo"),
+    (55519952, "Vivo"),
+    (55519953, "Vivo"),
+    (55519954, "Vivo"),
+    (55519955, "Vivo"),
+    (55539910, "Claro"),
+    (55539911, "Claro"),
+    (55539912, "Claro"),
+    (55539913, "Claro"),
+    (55539914, "Claro"),
+    (55539915, "Claro"),
+    (55539916, "Claro"),
+    (55549920, "Claro"),
+    (55549921, "Claro"),
+    (55559990, "Vivo"),
+    (55559991, "Vivo"),
+    (55559992, "Vivo"),
+    (55619811, "TIM"),
+    (55619812, "TIM"),
+    (55619813, "TIM"),
+    (55619814, "TIM"),
+    (55619815, "TIM"),
+    (55619816, "TIM"),
+    (55619996, "Vivo"),
+    (55619997, "Vivo"),
+    (55619998, "Vivo"),
+    (55619999, "Vivo"),
+    (55629960, "Vivo"),
+    (55629961, "Vivo"),
+    (55629962, "Vivo"),
+    (55629963, "Vivo"),
+    (55629964, "Vivo"),
+    (55629965, "Vivo"),
+    (55629996, "Vivo"),
+    (55629997, "Vivo"),
+    (55629998, "Vivo"),
+    (55639997, "Vivo"),
+    (55639998, "Vivo"),
+    (55649997, "Vivo"),
+    (55649998, "Vivo"),
+    (55659996, "Vivo"),
+    (55659997, "Vivo"),
+    (55659998, "Vivo"),
+    (55669997, "Vivo"),
+    (55669998, "Vivo"),
+    (55679980, "Vivo"),
+    (55689997, "Vivo"),
+    (55689998, "Vivo"),
+    (55699996, "Vivo"),
+    (55699997, "Vivo"),
+    (55699998, "Vivo"),
+    (55719830, "Claro"),
+    (55719831, "Claro"),
+    (55719832, "Claro"),
+    (55719833, "Claro"),
+    (55719834, "Claro"),
+    (55719835, "Claro"),
+    (55719960, "Vivo"),
+    (55719961, "Vivo"),
+    (55719962, "Vivo"),
+    (55719963, "Vivo"),
+    (55719964, "Vivo"),
+    (55719965, "Vivo"),
+    (55719966, "Vivo"),
+    (55719967, "Vivo"),
+    (55719968, "Vivo"),
+    (55719996, "Vivo"),
+    (55739981, "Claro"),
+    (55739985, "Oi"),
+    (55739986, "Oi"),
+    (55739987, "Oi"),
+    (55739988, "Oi"),
+    (55739989, "Oi"),
+    (55739999, "Vivo"),
+    (55749810, "Claro"),
+    (55749811, "Claro"),
+    (55749812, "Claro"),
+    (55749995, "Vivo"),
+    (55749998, "Vivo"),
+    (55759820, "Claro"),
+    (55759821, "Claro"),
+    (55759822, "Claro"),
+    (55759823, "Claro"),
+    (55759824, "Claro"),
+    (55759825, "Claro"),
+    (55759826, "Claro"),
+    (55759981, "Vivo"),
+    (55759982, "Vivo"),
+    (55759983, "Vivo"),
+    (55759990, "Vivo"),
+    (55759991, "Vivo"),
+    (55759992, "Vivo"),
+    (55759993, "Vivo"),
+    (55759994, "Vivo"),
+    (55759995, "Vivo"),
+    (55779810, "Claro"),
+    (55779811, "Claro"),
+    (55779812, "Claro"),
+    (55779813, "Claro"),
+    (55779814, "Claro"),
+    (55779980, "Vivo"),
+    (55779990, "Vivo"),
+    (55779991, "Vivo"),
+    (55779992, "Vivo"),
+    (55779993, "Vivo"),
+    (55779994, "Vivo"),
+    (55779995, "Vivo"),
+    (55779998, "Vivo"),
+    (55799810, "Claro"),
+    (55799811, "Claro"),
+    (55799812, "Claro"),
+    (55799813, "Claro"),
+    (55799814, "Claro"),
+    (55799815, "Claro"),
+    (55799816, "Claro"),
+    (55819820, "Vivo"),
+    (55819821, "Vivo"),
+    (55819822, "Vivo"),
+    (55819823, "Vivo"),
+    (55819824, "Vivo"),
+    (55819825, "Vivo"),
+    (55819930, "Claro"),
+    (55819931, "Claro"),
+    (55819932, "Claro"),
+    (55819933, "Claro"),
+    (55819934, "Claro"),
+    (55819935, "Claro"),
+    (55819936, "Claro"),
+    (55819970, "TIM"),
+    (55819971, "TIM"),
+    (55819972, "TIM"),
+    (55829810, "Vivo"),
+    (55829811, "Vivo"),
+    (55829812, "Vivo"),
+    (55829813, "Vivo"),
+    (55829930, "Claro"),
+    (55829932, "Claro"),
+    (55829933, "Claro"),
+    (55829990, "TIM"),
+    (55829991, "TIM"),
+    (55829992, "TIM"),
+    (55829993, "TIM"),
+    (55829994, "TIM"),
+    (55829995, "TIM"),
+    (55829996, "TIM"),
+    (55829997, "TIM"),
+    (55829998, "TIM"),
+    (55839810, "Vivo"),
+    (55839811, "Vivo"),
+    (55839812, "Vivo"),
+    (55839813, "Vivo"),
+    (55839814, "Vivo"),
+    (55839815, "Vivo"),
+    (55839930, "Claro"),
+    (55839931, "Claro"),
+    (55839932, "Claro"),
+    (55839990, "TIM"),
+    (55839991, "TIM"),
+    (55839992, "TIM"),
+    (55839993, "TIM"),
+    (55839995, "TIM"),
+    (55839996, "TIM"),
+    (55839997, "TIM"),
+    (55839998, "TIM"),
+    (55839999, "TIM"),
+    (55849810, "Vivo"),
+    (55849811, "Vivo"),
+    (55849812, "Vivo"),
+    (55849813, "Vivo"),
+    (55849940, "Claro"),
+    (55849941, "Claro"),
+    (55849942, "Claro"),
+    (55849943, "Claro"),
+    (55849944, "Claro"),
+    (55849945, "Claro"),
+    (55849960, "TIM"),
+    (55859820, "Vivo"),
+    (55859920, "Claro"),
+    (55859921, "Claro"),
+    (55859922, "Claro"),
+    (55859923, "Claro"),
+    (55859924, "Claro"),
+    (55859999, "TIM"),
+    (55869810, "Vivo"),
+    (55869811, "Vivo"),
+    (55869812, "Vivo"),
+    (55869813, "Vivo"),
+    (55869950, "Claro"),
+    (55869992, "TIM"),
+    (55869993, "TIM"),
+    (55869994, "TIM"),
+    (55879810, "Vivo"),
+    (55879811, "Vivo"),
+    (55879910, "Claro"),
+    (55879911, "Claro"),
+    (55879912, "Claro"),
+    (55879913, "Claro"),
+    (55879914, "Claro"),
+    (55879915, "Claro"),
+    (55879916, "Claro"),
+    (55889810, "Vivo"),
+    (55889811, "Vivo"),
+    (55889812, "Vivo"),
+    (55889940, "Claro"),
+    (55889941, "Claro"),
+    (55889942, "Claro"),
+    (55889943, "Claro"),
+    (55889944, "Claro"),
+    (55889945, "Claro"),
+    (55889961, "TIM"),
+    (55899810, "Vivo"),
+    (55899811, "Vivo"),
+    (55899940, "Claro"),
+    (55899941, "Claro"),
+    (55899942, "Claro"),
+    (55899943, "Claro"),
+    (55919840, "Claro"),
+    (55919841, "Claro"),
+    (55919842, "Claro"),
+    (55919843, "Claro"),
+    (55919844, "Claro"),
+    (55919845, "Claro"),
+    (55919846, "Claro"),
+    (55919847, "Claro"),
+    (55919920, "Vivo"),
+    (55919921, "Vivo"),
+    (55919922, "Vivo"),
+    (55919923, "Vivo"),
+    (55919924, "Vivo"),
+    (55919925, "Vivo"),
+    (55919926, "Vivo"),
+    (55919927, "Vivo"),
+    (55919996, "Oi"),
+    (55919998, "Oi"),
+    (55929840, "Claro"),
+    (55929841, "Claro"),
+    (55929842, "Claro"),
+    (55929843, "Claro"),
+    (55929844, "Claro"),
+    (55929930, "Vivo"),
+    (55929931, "Vivo"),
+    (55929932, "Vivo"),
+    (55929933, "Vivo"),
+    (55929934, "Vivo"),
+    (55929935, "Vivo"),
+    (55929936, "Vivo"),
+    (55929937, "Vivo"),
+    (55929938, "Vivo"),
+    (55929998, "Oi"),
+    (55949920, "Vivo"),
+    (55949921, "Vivo"),
+    (55949922, "Vivo"),
+    (55959840, "Claro"),
+    (55969840, "Claro"),
+    (55979840, "Claro"),
+    (55989840, "Claro"),
+    (55989841, "Claro"),
+    (55989842, "Claro"),
+    (55989843, "Claro"),
+    (55989844, "Claro"),
+    (55989845, "Claro"),
+    (55999840, "Claro"),
+    (55999841, "Claro"),
+    (56222981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222987, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222988, "Compania De Telecomunicaciones De Chile S.A."),
+    (56222989, "Compania De Telecomunicaciones De Chile S.A."),
+    (56223050, "Vtr Banda Ancha (Chile) S.A."),
+    (56223051, "Vtr Banda Ancha (Chile) S.A."),
+    (56223052, "Vtr Banda Ancha (Chile) S.A."),
+    (56223053, "Vtr Banda Ancha (Chile) S.A."),
+    (56223093, "Entel"),
+    (56223099, "Gtd Telesat S.A."),
+    (56224060, "Claro"),
+    (56224061, "Claro"),
+    (56224062, "Claro"),
+    (56224063, "Claro"),
+    (56224064, "Claro"),
+    (56224065, "Entel"),
+    (56224066, "Entel"),
+    (56224067, "Entel"),
+    (56224068, "Entel"),
+    (56224069, "Entel"),
+    (56224565, "Claro"),
+    (56224566, "Claro"),
+    (56224567, "Claro"),
+    (56224568, "Claro"),
+    (56224569, "Claro"),
+    (56225070, "Claro"),
+    (56225071, "Claro"),
+    (56225072, "Claro"),
+    (56225073, "Claro"),
+    (56225074, "Claro"),
+    (56225360, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225361, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225362, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225363, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225364, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225365, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225366, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225367, "Compania De Telecomunicaciones De Chile S.A."),
+    (56225671, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56225672, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56225673, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56225675, "Vtr Banda Ancha (Chile) S.A."),
+    (56225676, "Vtr Banda Ancha (Chile) S.A."),
+    (56225677, "Vtr Banda Ancha (Chile) S.A."),
+    (56225678, "Vtr Banda Ancha (Chile) S.A."),
+    (56225679, "Vtr Banda Ancha (Chile) S.A."),
+    (56225730, "Stel Access S.A."),
+    (56225731, "Stel Access S.A."),
+    (56225732, "Stel Access S.A."),
+    (56225733, "Stel Access S.A."),
+    (56225734, "Stel Access S.A."),
+    (56225735, "Claro"),
+    (56225736, "Claro"),
+    (56225737, "Claro"),
+    (56225738, "Claro"),
+    (56225739, "Claro"),
+    (56226020, "Compania De Telecomunicaciones De Chile S.A."),
+    (56226021, "Compania De Telecomunicaciones De Chile S.A."),
+    (56226022, "Compania De Telecomunicaciones De Chile S.A."),
+    (56226023, "Compania De Telecomunicaciones De Chile S.A."),
+    (56226024, "Compania De Telecomunicaciones De Chile S.A."),
+    (56226025, "Claro"),
+    (56226026, "Claro"),
+    (56226027, "Claro"),
+    (56226028, "Claro"),
+    (56226029, "Claro"),
+    (56227115, "Vtr Banda Ancha (Chile) S.A."),
+    (56227116, "Vtr Banda Ancha (Chile) S.A."),
+    (56227117, "Vtr Banda Ancha (Chile) S.A."),
+    (56227118, "Vtr Banda Ancha (Chile) S.A."),
+    (56227119, "Vtr Banda Ancha (Chile) S.A."),
+    (56228900, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56228901, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56228902, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56228903, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56228904, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56228905, "Will S.A."),
+    (56228906, "Will S.A."),
+    (56228907, "Will S.A."),
+    (56228908, "Will S.A."),
+    (56228909, "Will S.A."),
+    (56229353, "Gtd Telesat S.A."),
+    (56229355, "Vtr Banda Ancha (Chile) S.A."),
+    (56229356, "Vtr Banda Ancha (Chile) S.A."),
+    (56229357, "Vtr Banda Ancha (Chile) S.A."),
+    (56229358, "Vtr Banda Ancha (Chile) S.A."),
+    (56229359, "Vtr Banda Ancha (Chile) S.A."),
+    (56229363, "Gtd Telesat S.A."),
+    (56229365, "Vtr Banda Ancha (Chile) S.A."),
+    (56229366, "Vtr Banda Ancha (Chile) S.A."),
+    (56229367, "Vtr Banda Ancha (Chile) S.A."),
+    (56229368, "Vtr Banda Ancha (Chile) S.A."),
+    (56229369, "Vtr Banda Ancha (Chile) S.A."),
+    (56229380, "Entel"),
+    (56229381, "Entel"),
+    (56229382, "Entel"),
+    (56229383, "Entel"),
+    (56229384, "Entel"),
+    (56229385, "Claro"),
+    (56229386, "Claro"),
+    (56229387, "Claro"),
+    (56229388, "Claro"),
+    (56229389, "Claro"),
+    (56232140, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56322162, "Entel"),
+    (56322163, "Entel"),
+    (56322164, "Entel"),
+    (56322165, "Entel"),
+    (56322166, "Entel"),
+    (56322167, "Entel"),
+    (56322168, "Entel"),
+    (56322169, "Entel"),
+    (56322360, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322361, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322362, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322363, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322364, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322365, "Vtr Banda Ancha (Chile) S.A."),
+    (56322366, "Vtr Banda Ancha (Chile) S.A."),
+    (56322367, "Vtr Banda Ancha (Chile) S.A."),
+    (56322368, "Vtr Banda Ancha (Chile) S.A."),
+    (56322369, "Vtr Banda Ancha (Chile) S.A."),
+    (56322510, "Gtd Telesat S.A."),
+    (56322511, "Gtd Telesat S.A."),
+    (56322512, "Gtd Telesat S.A."),
+    (56322513, "Gtd Telesat S.A."),
+    (56322514, "Gtd Telesat S.A."),
+    (56322515, "Claro"),
+    (56322516, "Claro"),
+    (56322517, "Claro"),
+    (56322518, "Claro"),
+    (56322519, "Claro"),
+    (56322700, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322701, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322702, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322703, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322704, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322707, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322708, "Compania De Telecomunicaciones De Chile S.A."),
+    (56322709, "Compania De Telecomunicaciones De Chile S.A."),
+    (56323130, "Entel"),
+    (56323132, "Entel"),
+    (56323133, "Chile.Com"),
+    (56323134, "Chile.Com"),
+    (56323135, "Chile.Com"),
+    (56323136, "Chile.Com"),
+    (56323137, "Chile.Com"),
+    (56323138, "Chile.Com"),
+    (56323139, "Chile.Com"),
+    (56323820, "Redvoiss"),
+    (56331980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56331986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56332390, "Claro"),
+    (56332391, "Claro"),
+    (56332392, "Claro"),
+    (56332393, "Claro"),
+    (56332394, "Claro"),
+    (56332395, "Claro"),
+    (56332433, "Entel"),
+    (56332434, "Chile.Com"),
+    (56332550, "Redvoiss"),
+    (56332781, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56341986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56342220, "Claro"),
+    (56342221, "Claro"),
+    (56342222, "Claro"),
+    (56342223, "Claro"),
+    (56342224, "Claro"),
+    (56342225, "Claro"),
+    (56342334, "Entel"),
+    (56342400, "Chile.Com"),
+    (56342520, "Redvoiss"),
+    (56351980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56351986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352390, "Claro"),
+    (56352391, "Claro"),
+    (56352392, "Claro"),
+    (56352393, "Claro"),
+    (56352394, "Claro"),
+    (56352395, "Claro"),
+    (56352420, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352421, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352422, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352425, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352429, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352430, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352431, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352432, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352436, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352437, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352438, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352439, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352460, "Chile.Com"),
+    (56352461, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352462, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352463, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352466, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352467, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352469, "Compania De Telecomunicaciones De Chile S.A."),
+    (56352490, "Redvoiss"),
+    (56352535, "Entel"),
+    (56411970, "Comunicacion Y Telefonia Rural S.A."),
+    (56411971, "Comunicacion Y Telefonia Rural S.A."),
+    (56411972, "Comunicacion Y Telefonia Rural S.A."),
+    (56411973, "Comunicacion Y Telefonia Rural S.A."),
+    (56411974, "Comunicacion Y Telefonia Rural S.A."),
+    (56412110, "Quantax"),
+    (56412111, "Quantax"),
+    (56412112, "Quantax"),
+    (56412113, "Quantax"),
+    (56412114, "Quantax"),
+    (56412140, "Entel"),
+    (56412141, "Entel"),
+    (56412751, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412752, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412753, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412754, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412755, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412756, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412757, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412758, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412759, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412780, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412781, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412782, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412783, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412784, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412785, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412786, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412787, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412788, "Compania De Telecomunicaciones De Chile S.A."),
+    (56412800, "Quantax"),
+    (56412801, "Quantax"),
+    (56412802, "Quantax"),
+    (56412803, "Quantax"),
+    (56412804, "Quantax"),
+    (56412840, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56412841, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56412842, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56412843, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56412844, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56412845, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56413150, "Comunicacion Y Telefonia Rural S.A."),
+    (56413154, "Chile.Com"),
+    (56413155, "Chile.Com"),
+    (56413156, "Chile.Com"),
+    (56413157, "Chile.Com"),
+    (56413158, "Chile.Com"),
+    (56413159, "Chile.Com"),
+    (56413260, "Claro"),
+    (56413261, "Claro"),
+    (56413262, "Claro"),
+    (56413263, "Claro"),
+    (56413264, "Claro"),
+    (56413265, "Claro"),
+    (56413290, "Redvoiss"),
+    (56413300, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56413302, "Gtd Telesat S.A."),
+    (56413380, "Compania De Telecomunicaciones De Chile S.A."),
+    (56413381, "Compania De Telecomunicaciones De Chile S.A."),
+    (56413382, "Compania De Telecomunicaciones De Chile S.A."),
+    (56413383, "Compania De Telecomunicaciones De Chile S.A."),
+    (56413384, "Compania De Telecomunicaciones De Chile S.A."),
+    (56413800, "Gtd Telesat S.A."),
+    (56413801, "Gtd Telesat S.A."),
+    (56413802, "Gtd Telesat S.A."),
+    (56413803, "Gtd Telesat S.A."),
+    (56413804, "Gtd Telesat S.A."),
+    (56421970, "Comunicacion Y Telefonia Rural S.A."),
+    (56421971, "Comunicacion Y Telefonia Rural S.A."),
+    (56421972, "Comunicacion Y Telefonia Rural S.A."),
+    (56421973, "Comunicacion Y Telefonia Rural S.A."),
+    (56421974, "Comunicacion Y Telefonia Rural S.A."),
+    (56421975, "Comunicacion Y Telefonia Rural S.A."),
+    (56421976, "Comunicacion Y Telefonia Rural S.A."),
+    (56421980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56421986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422351, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422354, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422370, "Comunicacion Y Telefonia Rural S.A."),
+    (56422371, "Comunicacion Y Telefonia Rural S.A."),
+    (56422372, "Comunicacion Y Telefonia Rural S.A."),
+    (56422373, "Comunicacion Y Telefonia Rural S.A."),
+    (56422374, "Comunicacion Y Telefonia Rural S.A."),
+    (56422375, "Comunicacion Y Telefonia Rural S.A."),
+    (56422378, "Chile.Com"),
+    (56422379, "Chile.Com"),
+    (56422390, "Gtd Telesat S.A."),
+    (56422391, "Gtd Telesat S.A."),
+    (56422400, "Claro"),
+    (56422401, "Claro"),
+    (56422402, "Claro"),
+    (56422403, "Claro"),
+    (56422471, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422481, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422510, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422511, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422561, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422565, "Redvoiss"),
+    (56422571, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422641, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422661, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422662, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422663, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422680, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422681, "Compania De Telecomunicaciones De Chile S.A."),
+    (56422842, "Entel"),
+    (56422970, "Comunicacion Y Telefonia Rural S.A."),
+    (56422971, "Comunicacion Y Telefonia Rural S.A."),
+    (56422972, "Comunicacion Y Telefonia Rural S.A."),
+    (56431970, "Comunicacion Y Telefonia Rural S.A."),
+    (56431971, "Comunicacion Y Telefonia Rural S.A."),
+    (56431972, "Comunicacion Y Telefonia Rural S.A."),
+    (56431973, "Comunicacion Y Telefonia Rural S.A."),
+    (56431974, "Comunicacion Y Telefonia Rural S.A."),
+    (56431975, "Comunicacion Y Telefonia Rural S.A."),
+    (56431980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56431986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432220, "Claro"),
+    (56432221, "Claro"),
+    (56432222, "Claro"),
+    (56432223, "Claro"),
+    (56432240, "Chile.Com"),
+    (56432241, "Chile.Com"),
+    (56432243, "Entel"),
+    (56432370, "Comunicacion Y Telefonia Rural S.A."),
+    (56432371, "Comunicacion Y Telefonia Rural S.A."),
+    (56432372, "Comunicacion Y Telefonia Rural S.A."),
+    (56432373, "Comunicacion Y Telefonia Rural S.A."),
+    (56432374, "Comunicacion Y Telefonia Rural S.A."),
+    (56432375, "Comunicacion Y Telefonia Rural S.A."),
+    (56432380, "Gtd Telesat S.A."),
+    (56432381, "Gtd Telesat S.A."),
+    (56432410, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432411, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432412, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432415, "Redvoiss"),
+    (56432419, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432431, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432432, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432439, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432551, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432559, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432581, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432588, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432589, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432591, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432599, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432611, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432619, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432621, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432629, "Compania De Telecomunicaciones De Chile S.A."),
+    (56432970, "Comunicacion Y Telefonia Rural S.A."),
+    (56432971, "Comunicacion Y Telefonia Rural S.A."),
+    (56432972, "Comunicacion Y Telefonia Rural S.A."),
+    (56451970, "Comunicacion Y Telefonia Rural S.A."),
+    (56451971, "Comunicacion Y Telefonia Rural S.A."),
+    (56451972, "Comunicacion Y Telefonia Rural S.A."),
+    (56451973, "Comunicacion Y Telefonia Rural S.A."),
+    (56451974, "Comunicacion Y Telefonia Rural S.A."),
+    (56451975, "Comunicacion Y Telefonia Rural S.A."),
+    (56451976, "Comunicacion Y Telefonia Rural S.A."),
+    (56451980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56451986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452320, "Entel"),
+    (56452321, "Entel"),
+    (56452322, "Entel"),
+    (56452323, "Entel"),
+    (56452350, "Comunicacion Y Telefonia Rural S.A."),
+    (56452351, "Comunicacion Y Telefonia Rural S.A."),
+    (56452352, "Comunicacion Y Telefonia Rural S.A."),
+    (56452353, "Comunicacion Y Telefonia Rural S.A."),
+    (56452354, "Comunicacion Y Telefonia Rural S.A."),
+    (56452355, "Comunicacion Y Telefonia Rural S.A."),
+    (56452390, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452391, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452392, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452393, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452394, "Claro"),
+    (56452395, "Claro"),
+    (56452396, "Claro"),
+    (56452399, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452430, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452431, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452432, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452433, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452435, "Claro"),
+    (56452436, "Claro"),
+    (56452437, "Claro"),
+    (56452438, "Claro"),
+    (56452439, "Claro"),
+    (56452471, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452472, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452473, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452474, "Servicios Internet Limitada"),
+    (56452475, "Servicios Internet Limitada"),
+    (56452476, "Servicios Internet Limitada"),
+    (56452477, "Servicios Internet Limitada"),
+    (56452478, "Servicios Internet Limitada"),
+    (56452479, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452490, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452491, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452492, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452495, "Claro"),
+    (56452496, "Claro"),
+    (56452497, "Claro"),
+    (56452498, "Claro"),
+    (56452499, "Claro"),
+    (56452503, "Entel"),
+    (56452504, "Entel"),
+    (56452505, "Entel"),
+    (56452506, "Entel"),
+    (56452507, "Entel"),
+    (56452508, "Entel"),
+    (56452509, "Entel"),
+    (56452513, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452515, "Entel"),
+    (56452516, "Entel"),
+    (56452517, "Entel"),
+    (56452518, "Entel"),
+    (56452519, "Entel"),
+    (56452531, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452532, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452533, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452534, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452535, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452537, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452539, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452562, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452566, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452568, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452573, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452574, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452578, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452581, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452583, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452585, "Claro"),
+    (56452586, "Claro"),
+    (56452587, "Claro"),
+    (56452588, "Claro"),
+    (56452589, "Claro"),
+    (56452611, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452612, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452613, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452614, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452615, "Claro"),
+    (56452616, "Claro"),
+    (56452617, "Claro"),
+    (56452618, "Claro"),
+    (56452619, "Claro"),
+    (56452620, "Vtr Banda Ancha (Chile) S.A."),
+    (56452621, "Vtr Banda Ancha (Chile) S.A."),
+    (56452622, "Vtr Banda Ancha (Chile) S.A."),
+    (56452623, "Vtr Banda Ancha (Chile) S.A."),
+    (56452624, "Vtr Banda Ancha (Chile) S.A."),
+    (56452625, "Entel"),
+    (56452626, "Entel"),
+    (56452627, "Entel"),
+    (56452628, "Entel"),
+    (56452629, "Entel"),
+    (56452631, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452634, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452635, "Entel"),
+    (56452636, "Entel"),
+    (56452637, "Entel"),
+    (56452638, "Entel"),
+    (56452639, "Entel"),
+    (56452651, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452652, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452655, "Entel"),
+    (56452656, "Entel"),
+    (56452657, "Entel"),
+    (56452658, "Entel"),
+    (56452659, "Entel"),
+    (56452661, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452664, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452665, "Entel"),
+    (56452666, "Entel"),
+    (56452667, "Entel"),
+    (56452668, "Entel"),
+    (56452669, "Entel"),
+    (56452671, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452673, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452675, "Entel"),
+    (56452676, "Entel"),
+    (56452677, "Entel"),
+    (56452678, "Entel"),
+    (56452679, "Entel"),
+    (56452753, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452755, "Telefonica Del Sur S.A."),
+    (56452756, "Telefonica Del Sur S.A."),
+    (56452757, "Telefonica Del Sur S.A."),
+    (56452758, "Telefonica Del Sur S.A."),
+    (56452759, "Telefonica Del Sur S.A."),
+    (56452770, "Claro"),
+    (56452771, "Claro"),
+    (56452772, "Claro"),
+    (56452774, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452775, "Entel"),
+    (56452776, "Entel"),
+    (56452777, "Entel"),
+    (56452778, "Entel"),
+    (56452779, "Entel"),
+    (56452781, "Redvoiss"),
+    (56452783, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452785, "Claro"),
+    (56452786, "Claro"),
+    (56452787, "Claro"),
+    (56452788, "Claro"),
+    (56452789, "Claro"),
+    (56452793, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452794, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452795, "Entel"),
+    (56452796, "Entel"),
+    (56452797, "Entel"),
+    (56452798, "Entel"),
+    (56452799, "Entel"),
+    (56452851, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452855, "Claro"),
+    (56452856, "Claro"),
+    (56452857, "Claro"),
+    (56452858, "Claro"),
+    (56452859, "Claro"),
+    (56452881, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452882, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452885, "Telefonica Del Sur S.A."),
+    (56452886, "Telefonica Del Sur S.A."),
+    (56452887, "Telefonica Del Sur S.A."),
+    (56452888, "Telefonica Del Sur S.A."),
+    (56452889, "Telefonica Del Sur S.A."),
+    (56452891, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452892, "Compania De Telecomunicaciones De Chile S.A."),
+    (56452895, "Entel"),
+    (56452896, "Entel"),
+    (56452897, "Entel"),
+    (56452898, "Entel"),
+    (56452899, "Entel"),
+    (56452920, "Comunicacion Y Telefonia Rural S.A."),
+    (56452921, "Comunicacion Y Telefonia Rural S.A."),
+    (56452922, "Comunicacion Y Telefonia Rural S.A."),
+    (56452923, "Comunicacion Y Telefonia Rural S.A."),
+    (56452924, "Comunicacion Y Telefonia Rural S.A."),
+    (56452925, "Gtd Telesat S.A."),
+    (56452926, "Gtd Telesat S.A."),
+    (56452927, "Gtd Telesat S.A."),
+    (56452928, "Gtd Telesat S.A."),
+    (56452929, "Gtd Telesat S.A."),
+    (56511980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56511986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56512451, "Compania De Telecomunicaciones De Chile S.A."),
+    (56512453, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56512455, "Claro"),
+    (56512456, "Claro"),
+    (56512457, "Claro"),
+    (56512458, "Claro"),
+    (56512459, "Claro"),
+    (56512505, "Redvoiss"),
+    (56512510, "Claro"),
+    (56512511, "Claro"),
+    (56512512, "Claro"),
+    (56512513, "Claro"),
+    (56512514, "Claro"),
+    (56512518, "Compania De Telecomunicaciones De Chile S.A."),
+    (56512519, "Compania De Telecomunicaciones De Chile S.A."),
+    (56512651, "Entel"),
+    (56512652, "Chile.Com"),
+    (56512653, "Chile.Com"),
+    (56512654, "Chile.Com"),
+    (56512655, "Chile.Com"),
+    (56512740, "Claro"),
+    (56512741, "Claro"),
+    (56512742, "Claro"),
+    (56512743, "Claro"),
+    (56512744, "Claro"),
+    (56512745, "Claro"),
+    (56521980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56521986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56522390, "Gtd Telesat S.A."),
+    (56522391, "Gtd Telesat S.A."),
+    (56522392, "Chile.Com"),
+    (56522393, "Chile.Com"),
+    (56522394, "Chile.Com"),
+    (56522395, "Chile.Com"),
+    (56522490, "Claro"),
+    (56522491, "Claro"),
+    (56522492, "Claro"),
+    (56522493, "Claro"),
+    (56522494, "Claro"),
+    (56522495, "Claro"),
+    (56522552, "Entel"),
+    (56522555, "Redvoiss"),
+    (56531980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56531986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532220, "Claro"),
+    (56532221, "Claro"),
+    (56532222, "Claro"),
+    (56532223, "Claro"),
+    (56532453, "Entel"),
+    (56532455, "Redvoiss"),
+    (56532531, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532536, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532538, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532539, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532551, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532552, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532553, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532558, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532681, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532686, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532691, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532693, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532696, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532711, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532712, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532718, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532721, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532726, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532728, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532731, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532738, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532741, "Compania De Telecomunicaciones De Chile S.A."),
+    (56532748, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56551986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552320, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552321, "Entel"),
+    (56552322, "Entel"),
+    (56552323, "Entel"),
+    (56552324, "Entel"),
+    (56552325, "Servicios Internet Limitada"),
+    (56552326, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552327, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552328, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552329, "Compania De Telecomunicaciones De Chile S.A."),
+    (56552601, "Movistar"),
+    (56552602, "Movistar"),
+    (56552603, "Movistar"),
+    (56552604, "Movistar"),
+    (56552605, "Movistar"),
+    (56552606, "Movistar"),
+    (56552607, "Movistar"),
+    (56552608, "Movistar"),
+    (56552609, "Movistar"),
+    (56552705, "Claro"),
+    (56552706, "Claro"),
+    (56552707, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56552750, "Vtr Banda Ancha (Chile) S.A."),
+    (56552751, "Vtr Banda Ancha (Chile) S.A."),
+    (56552752, "Vtr Banda Ancha (Chile) S.A."),
+    (56552753, "Vtr Banda Ancha (Chile) S.A."),
+    (56552754, "Vtr Banda Ancha (Chile) S.A."),
+    (56552755, "Entel"),
+    (56552756, "Claro"),
+    (56552757, "Claro"),
+    (56552758, "Claro"),
+    (56552759, "Claro"),
+    (56553280, "Redvoiss"),
+    (56553320, "Entel"),
+    (56572550, "Redvoiss"),
+    (56572557, "Entel"),
+    (56572610, "Claro"),
+    (56572611, "Claro"),
+    (56572612, "Claro"),
+    (56572613, "Claro"),
+    (56572741, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572742, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572743, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572744, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572747, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56572751, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572752, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572753, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572754, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572756, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572757, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572758, "Compania De Telecomunicaciones De Chile S.A."),
+    (56572759, "Compania De Telecomunicaciones De Chile S.A."),
+    (56582390, "Gtd Telesat S.A."),
+    (56582391, "Gtd Telesat S.A."),
+    (56582395, "Redvoiss"),
+    (56582440, "Claro"),
+    (56582441, "Claro"),
+    (56582442, "Claro"),
+    (56582443, "Claro"),
+    (56582458, "Entel"),
+    (56582747, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56582892, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56611986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56612311, "Compania De Telecomunicaciones De Chile S.A."),
+    (56612315, "Redvoiss"),
+    (56612331, "Compania De Telecomunicaciones De Chile S.A."),
+    (56612580, "Compania De Telecomunicaciones De Chile S.A."),
+    (56612581, "Compania De Telecomunicaciones De Chile S.A."),
+    (56612761, "Entel"),
+    (56631970, "Comunicacion Y Telefonia Rural S.A."),
+    (56631971, "Comunicacion Y Telefonia Rural S.A."),
+    (56631972, "Comunicacion Y Telefonia Rural S.A."),
+    (56631973, "Comunicacion Y Telefonia Rural S.A."),
+    (56631974, "Comunicacion Y Telefonia Rural S.A."),
+    (56631975, "Comunicacion Y Telefonia Rural S.A."),
+    (56632174, "Entel"),
+    (56632370, "Comunicacion Y Telefonia Rural S.A."),
+    (56632371, "Comunicacion Y Telefonia Rural S.A."),
+    (56632372, "Comunicacion Y Telefonia Rural S.A."),
+    (56632373, "Comunicacion Y Telefonia Rural S.A."),
+    (56632374, "Comunicacion Y Telefonia Rural S.A."),
+    (56632375, "Comunicacion Y Telefonia Rural S.A."),
+    (56632392, "Chile.Com"),
+    (56632393, "Chile.Com"),
+    (56632394, "Chile.Com"),
+    (56632395, "Chile.Com"),
+    (56632411, "Telefonica Del Sur S.A."),
+    (56632420, "Telefonica Del Sur S.A."),
+    (56632421, "Telefonica Del Sur S.A."),
+    (56632422, "Telefonica Del Sur S.A."),
+    (56632425, "Telefonica Del Sur S.A."),
+    (56632426, "Telefonica Del Sur S.A."),
+    (56632427, "Telefonica Del Sur S.A."),
+    (56632428, "Telefonica Del Sur S.A."),
+    (56632429, "Telefonica Del Sur S.A."),
+    (56632441, "Telefonica Del Sur S.A."),
+    (56632442, "Telefonica Del Sur S.A."),
+    (56632444, "Telefonica Del Sur S.A."),
+    (56632460, "Telefonica Del Sur S.A."),
+    (56632461, "Telefonica Del Sur S.A."),
+    (56632462, "Telefonica Del Sur S.A."),
+    (56632465, "Telefonica Del Sur S.A."),
+    (56632467, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56632471, "Telefonica Del Sur S.A."),
+    (56632480, "Telefonica Del Sur S.A."),
+    (56632481, "Telefonica Del Sur S.A."),
+    (56632482, "Telefonica Del Sur S.A."),
+    (56632491, "Telefonica Del Sur S.A."),
+    (56632540, "Vtr Banda Ancha (Chile) S.A."),
+    (56632541, "Vtr Banda Ancha (Chile) S.A."),
+    (56632542, "Vtr Banda Ancha (Chile) S.A."),
+    (56632543, "Vtr Banda Ancha (Chile) S.A."),
+    (56632544, "Vtr Banda Ancha (Chile) S.A."),
+    (56632563, "Entel"),
+    (56632600, "Claro"),
+    (56632601, "Claro"),
+    (56632602, "Claro"),
+    (56632603, "Claro"),
+    (56632710, "Entel"),
+    (56632711, "Entel"),
+    (56632712, "Entel"),
+    (56632713, "Entel"),
+    (56632715, "Entel"),
+    (56632716, "Entel"),
+    (56632717, "Entel"),
+    (56632718, "Entel"),
+    (56632719, "Entel"),
+    (56632730, "Redvoiss"),
+    (56632970, "Comunicacion Y Telefonia Rural S.A."),
+    (56632971, "Comunicacion Y Telefonia Rural S.A."),
+    (56632979, "Comunicacion Y Telefonia Rural S.A."),
+    (56641974, "Comunicacion Y Telefonia Rural S.A."),
+    (56641975, "Comunicacion Y Telefonia Rural S.A."),
+    (56641976, "Comunicacion Y Telefonia Rural S.A."),
+    (56641977, "Comunicacion Y Telefonia Rural S.A."),
+    (56641978, "Comunicacion Y Telefonia Rural S.A."),
+    (56642280, "Vtr Banda Ancha (Chile) S.A."),
+    (56642281, "Vtr Banda Ancha (Chile) S.A."),
+    (56642282, "Vtr Banda Ancha (Chile) S.A."),
+    (56642283, "Vtr Banda Ancha (Chile) S.A."),
+    (56642284, "Vtr Banda Ancha (Chile) S.A."),
+    (56642290, "Redvoiss"),
+    (56642294, "Telefonica Del Sur S.A."),
+    (56642295, "Telefonica Del Sur S.A."),
+    (56642296, "Telefonica Del Sur S.A."),
+    (56642350, "Telefonica Del Sur S.A."),
+    (56642351, "Telefonica Del Sur S.A."),
+    (56642352, "Telefonica Del Sur S.A."),
+    (56642353, "Telefonica Del Sur S.A."),
+    (56642354, "Telefonica Del Sur S.A."),
+    (56642355, "Telefonica Del Sur S.A."),
+    (56642370, "Telefonica Del Sur S.A."),
+    (56642371, "Telefonica Del Sur S.A."),
+    (56642372, "Telefonica Del Sur S.A."),
+    (56642374, "Comunicacion Y Telefonia Rural S.A."),
+    (56642375, "Comunicacion Y Telefonia Rural S.A."),
+    (56642376, "Comunicacion Y Telefonia Rural S.A."),
+    (56642377, "Comunicacion Y Telefonia Rural S.A."),
+    (56642378, "Comunicacion Y Telefonia Rural S.A."),
+    (56642379, "Comunicacion Y Telefonia Rural S.A."),
+    (56642391, "Telefonica Del Sur S.A."),
+    (56642392, "Chile.Com"),
+    (56642393, "Chile.Com"),
+    (56642394, "Chile.Com"),
+    (56642395, "Chile.Com"),
+    (56642396, "Telefonica Del Sur S.A."),
+    (56642400, "Claro"),
+    (56642401, "Claro"),
+    (56642402, "Claro"),
+    (56642403, "Claro"),
+    (56642664, "Entel"),
+    (56642970, "Comunicacion Y Telefonia Rural S.A."),
+    (56642971, "Comunicacion Y Telefonia Rural S.A."),
+    (56642979, "Comunicacion Y Telefonia Rural S.A."),
+    (56651970, "Comunicacion Y Telefonia Rural S.A."),
+    (56651971, "Comunicacion Y Telefonia Rural S.A."),
+    (56651972, "Comunicacion Y Telefonia Rural S.A."),
+    (56651973, "Comunicacion Y Telefonia Rural S.A."),
+    (56651979, "Comunicacion Y Telefonia Rural S.A."),
+    (56651980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56651986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56652240, "Telefonica Del Sur S.A."),
+    (56652241, "Telefonica Del Sur S.A."),
+    (56652242, "Telefonica Del Sur S.A."),
+    (56652243, "Telefonica Del Sur S.A."),
+    (56652244, "Telefonica Del Sur S.A."),
+    (56652245, "Telefonica Del Sur S.A."),
+    (56652246, "Telefonica Del Sur S.A."),
+    (56652370, "Comunicacion Y Telefonia Rural S.A."),
+    (56652371, "Comunicacion Y Telefonia Rural S.A."),
+    (56652372, "Comunicacion Y Telefonia Rural S.A."),
+    (56652373, "Comunicacion Y Telefonia Rural S.A."),
+    (56652374, "Comunicacion Y Telefonia Rural S.A."),
+    (56652400, "Claro"),
+    (56652401, "Claro"),
+    (56652402, "Claro"),
+    (56652403, "Claro"),
+    (56652404, "Claro"),
+    (56652405, "Claro"),
+    (56652420, "Telefonica Del Sur S.A."),
+    (56652421, "Telefonica Del Sur S.A."),
+    (56652422, "Telefonica Del Sur S.A."),
+    (56652423, "Telefonica Del Sur S.A."),
+    (56652424, "Telefonica Del Sur S.A."),
+    (56652425, "Telefonica Del Sur S.A."),
+    (56652426, "Telefonica Del Sur S.A."),
+    (56652440, "Telefonica Del Sur S.A."),
+    (56652441, "Telefonica Del Sur S.A."),
+    (56652442, "Telefonica Del Sur S.A."),
+    (56652443, "Telefonica Del Sur S.A."),
+    (56652444, "Telefonica Del Sur S.A."),
+    (56652445, "Telefonica Del Sur S.A."),
+    (56652446, "Telefonica Del Sur S.A."),
+    (56652451, "Telefonica Del Sur S.A."),
+    (56652456, "Telefonica Del Sur S.A."),
+    (56652640, "Telefonica Del Sur S.A."),
+    (56652641, "Telefonica Del Sur S.A."),
+    (56652642, "Telefonica Del Sur S.A."),
+    (56652643, "Telefonica Del Sur S.A."),
+    (56652651, "Telefonica Del Sur S.A."),
+    (56652661, "Telefonica Del Sur S.A."),
+    (56652667, "Telefonica Del Sur S.A."),
+    (56652671, "Telefonica Del Sur S.A."),
+    (56652672, "Telefonica Del Sur S.A."),
+    (56652673, "Telefonica Del Sur S.A."),
+    (56652677, "Telefonica Del Sur S.A."),
+    (56652691, "Telefonica Del Sur S.A."),
+    (56652696, "Telefonica Del Sur S.A."),
+    (56652721, "Telefonica Del Sur S.A."),
+    (56652725, "Chile.Com"),
+    (56652726, "Chile.Com"),
+    (56652731, "Telefonica Del Sur S.A."),
+    (56652741, "Telefonica Del Sur S.A."),
+    (56652765, "Entel"),
+    (56652870, "Redvoiss"),
+    (56652970, "Comunicacion Y Telefonia Rural S.A."),
+    (56652971, "Comunicacion Y Telefonia Rural S.A."),
+    (56652972, "Comunicacion Y Telefonia Rural S.A."),
+    (56671980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56671986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56672330, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672331, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672332, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672333, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672334, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672335, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672336, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672360, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672361, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672380, "Claro"),
+    (56672381, "Claro"),
+    (56672382, "Claro"),
+    (56672383, "Claro"),
+    (56672385, "Redvoiss"),
+    (56672423, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672430, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672431, "Compania De Teléfonos De Coyhaique S.A."),
+    (56672435, "Entel"),
+    (56672436, "Entel"),
+    (56672437, "Entel"),
+    (56672438, "Entel"),
+    (56672439, "Entel"),
+    (56672567, "Entel"),
+    (56711970, "Comunicacion Y Telefonia Rural S.A."),
+    (56711971, "Comunicacion Y Telefonia Rural S.A."),
+    (56711972, "Comunicacion Y Telefonia Rural S.A."),
+    (56711973, "Comunicacion Y Telefonia Rural S.A."),
+    (56711974, "Comunicacion Y Telefonia Rural S.A."),
+    (56711980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56711986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56712360, "Claro"),
+    (56712361, "Claro"),
+    (56712363, "Claro"),
+    (56712364, "Claro"),
+    (56712365, "Claro"),
+    (56712370, "Comunicacion Y Telefonia Rural S.A."),
+    (56712371, "Comunicacion Y Telefonia Rural S.A."),
+    (56712372, "Comunicacion Y Telefonia Rural S.A."),
+    (56712373, "Comunicacion Y Telefonia Rural S.A."),
+    (56712374, "Comunicacion Y Telefonia Rural S.A."),
+    (56712390, "Gtd Telesat S.A."),
+    (56712391, "Gtd Telesat S.A."),
+    (56712392, "Chile.Com"),
+    (56712393, "Chile.Com"),
+    (56712394, "Chile.Com"),
+    (56712395, "Chile.Com"),
+    (56712460, "Redvoiss"),
+    (56712571, "Entel"),
+    (56712651, "Compania De Telecomunicaciones De Chile S.A."),
+    (56712657, "Compania De Telecomunicaciones De Chile S.A."),
+    (56712720, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712721, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712722, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712723, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712724, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712725, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712726, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712727, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56712970, "Comunicacion Y Telefonia Rural S.A."),
+    (56712971, "Comunicacion Y Telefonia Rural S.A."),
+    (56712972, "Comunicacion Y Telefonia Rural S.A."),
+    (56721980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56721987, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722370, "Claro"),
+    (56722371, "Claro"),
+    (56722372, "Claro"),
+    (56722373, "Claro"),
+    (56722374, "Claro"),
+    (56722375, "Claro"),
+    (56722376, "Claro"),
+    (56722377, "Claro"),
+    (56722381, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722384, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722387, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722390, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722391, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722392, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722393, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722395, "Entel"),
+    (56722396, "Entel"),
+    (56722397, "Entel"),
+    (56722398, "Entel"),
+    (56722399, "Entel"),
+    (56722450, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722451, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722452, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722455, "Entel"),
+    (56722456, "Entel"),
+    (56722457, "Entel"),
+    (56722458, "Entel"),
+    (56722459, "Entel"),
+    (56722461, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722462, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722463, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722466, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722467, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722468, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722481, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722485, "Entel"),
+    (56722486, "Entel"),
+    (56722487, "Entel"),
+    (56722488, "Entel"),
+    (56722489, "Entel"),
+    (56722491, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722492, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722495, "Entel"),
+    (56722496, "Entel"),
+    (56722497, "Entel"),
+    (56722498, "Entel"),
+    (56722499, "Entel"),
+    (56722501, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722502, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722506, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722510, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722511, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722512, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722513, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722514, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722515, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722516, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722521, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722522, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722525, "Entel"),
+    (56722526, "Entel"),
+    (56722527, "Entel"),
+    (56722528, "Entel"),
+    (56722529, "Entel"),
+    (56722540, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722541, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722545, "Entel"),
+    (56722546, "Entel"),
+    (56722547, "Entel"),
+    (56722548, "Entel"),
+    (56722549, "Entel"),
+    (56722551, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722552, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722553, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722554, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722555, "Entel"),
+    (56722556, "Entel"),
+    (56722557, "Entel"),
+    (56722558, "Entel"),
+    (56722559, "Entel"),
+    (56722561, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722562, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722565, "Entel"),
+    (56722566, "Entel"),
+    (56722567, "Entel"),
+    (56722568, "Entel"),
+    (56722569, "Entel"),
+    (56722570, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722571, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722572, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722573, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722574, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722575, "Entel"),
+    (56722576, "Entel"),
+    (56722577, "Entel"),
+    (56722578, "Entel"),
+    (56722579, "Entel"),
+    (56722591, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722596, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722790, "Claro"),
+    (56722791, "Claro"),
+    (56722792, "Claro"),
+    (56722810, "Redvoiss"),
+    (56722817, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722831, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722833, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (56722835, "Entel"),
+    (56722836, "Entel"),
+    (56722837, "Entel"),
+    (56722838, "Entel"),
+    (56722839, "Entel"),
+    (56722841, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722842, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722843, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722845, "Telefonica Del Sur S.A."),
+    (56722846, "Telefonica Del Sur S.A."),
+    (56722847, "Telefonica Del Sur S.A."),
+    (56722848, "Telefonica Del Sur S.A."),
+    (56722849, "Telefonica Del Sur S.A."),
+    (56722851, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722856, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722857, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722858, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722859, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722861, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722865, "Telefonica Del Sur S.A."),
+    (56722866, "Telefonica Del Sur S.A."),
+    (56722867, "Telefonica Del Sur S.A."),
+    (56722868, "Telefonica Del Sur S.A."),
+    (56722869, "Telefonica Del Sur S.A."),
+    (56722871, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722874, "Compania De Telecomunicaciones De Chile S.A."),
+    (56722875, "Convergia Telecom S.A."),
+    (56722876, "Convergia Telecom S.A."),
+    (56722877, "Convergia Telecom S.A."),
+    (56722878, "Convergia Telecom S.A."),
+    (56722879, "Convergia Telecom S.A."),
+    (56731970, "Comunicacion Y Telefonia Rural S.A."),
+    (56731971, "Comunicacion Y Telefonia Rural S.A."),
+    (56731972, "Comunicacion Y Telefonia Rural S.A."),
+    (56731973, "Comunicacion Y Telefonia Rural S.A."),
+    (56731974, "Comunicacion Y Telefonia Rural S.A."),
+    (56731975, "Comunicacion Y Telefonia Rural S.A."),
+    (56731980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56731986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732240, "Claro"),
+    (56732241, "Claro"),
+    (56732242, "Claro"),
+    (56732243, "Claro"),
+    (56732270, "Chile.Com"),
+    (56732271, "Chile.Com"),
+    (56732272, "Chile.Com"),
+    (56732279, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732346, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732351, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732361, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732370, "Comunicacion Y Telefonia Rural S.A."),
+    (56732371, "Comunicacion Y Telefonia Rural S.A."),
+    (56732372, "Comunicacion Y Telefonia Rural S.A."),
+    (56732373, "Comunicacion Y Telefonia Rural S.A."),
+    (56732374, "Comunicacion Y Telefonia Rural S.A."),
+    (56732375, "Comunicacion Y Telefonia Rural S.A."),
+    (56732381, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732382, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732390, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732391, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732400, "Gtd Telesat S.A."),
+    (56732401, "Gtd Telesat S.A."),
+    (56732411, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732412, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732421, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732511, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732512, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732513, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732514, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732515, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732516, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732517, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732520, "Redvoiss"),
+    (56732541, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732551, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732556, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732641, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732646, "Compania De Telecomunicaciones De Chile S.A."),
+    (56732673, "Entel"),
+    (56732740, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732741, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732750, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732751, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732760, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732761, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732766, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732767, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732770, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732771, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56732970, "Comunicacion Y Telefonia Rural S.A."),
+    (56732971, "Comunicacion Y Telefonia Rural S.A."),
+    (56732979, "Comunicacion Y Telefonia Rural S.A."),
+    (56751975, "Comunicacion Y Telefonia Rural S.A."),
+    (56751976, "Comunicacion Y Telefonia Rural S.A."),
+    (56751977, "Comunicacion Y Telefonia Rural S.A."),
+    (56751978, "Comunicacion Y Telefonia Rural S.A."),
+    (56751979, "Comunicacion Y Telefonia Rural S.A."),
+    (56751980, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751981, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751982, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751983, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751984, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751985, "Compania De Telecomunicaciones De Chile S.A."),
+    (56751986, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752210, "Gtd Telesat S.A."),
+    (56752211, "Gtd Telesat S.A."),
+    (56752217, "Chile.Com"),
+    (56752218, "Chile.Com"),
+    (56752219, "Chile.Com"),
+    (56752275, "Entel"),
+    (56752371, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752379, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752400, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752405, "Redvoiss"),
+    (56752451, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752454, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752460, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752481, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752510, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752511, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752530, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752531, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752532, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752533, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752534, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752535, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752536, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752537, "Complejo Manufacturero De Equipos Telefonicos S.A.C.I."),
+    (56752580, "Claro"),
+    (56752581, "Claro"),
+    (56752582, "Claro"),
+    (56752583, "Claro"),
+    (56752661, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752690, "Compania De Telecomunicaciones De Chile S.A."),
+    (56752972, "Comunicacion Y Telefonia Rural S.A."),
+    (56752973, "Comunicacion Y Telefonia Rural S.A."),
+    (56752974, "Comunicacion Y Telefonia Rural S.A."),
+    (56752978, "Comunicacion Y Telefonia Rural S.A."),
+    (56752979, "Comunicacion Y Telefonia Rural S.A."),
+    (56946090, "Compatel Chile Limitada"),
+    (56946091, "Compatel Chile Limitada"),
+    (56946092, "Compatel Chile Limitada"),
+    (56946093, "Compatel Chile Limitada"),
+    (56946094, "Compatel Chile Limitada"),
+    (56996055, "Movistar"),
+    (56996056, "Movistar"),
+    (56996057, "Movistar"),
+    (56996058, "Movistar"),
+    (56996059, "Movistar"),
+    (56996080, "Entel"),
+    (56996081, "Entel"),
+    (56996082, "Entel"),
+    (56996083, "Entel"),
+    (56996084, "Entel"),
+    (56996085, "Movistar"),
+    (56996086, "Movistar"),
+    (56996087, "Movistar"),
+    (56996088, "Movistar"),
+    (56996089, "Movistar"),
+    (57304680, "Tigo"),
+    (57304681, "Tigo"),
+    (57304682, "Tigo"),
+    (57304683, "Tigo"),
+    (57304684, "Tigo"),
+    (57304686, "Tigo"),
+    (57304687, "Tigo"),
+    (57304688, "Tigo"),
+    (57304689, "Tigo"),
+    (57304730, "Tigo"),
+    (57304731, "Tigo"),
+    (57304732, "Tigo"),
+    (57304733, "Tigo"),
+    (57304734, "Tigo"),
+    (57304735, "Tigo"),
+    (57305210, "Tigo"),
+    (57305211, "Tigo"),
+    (57305212, "Tigo"),
+    (57305213, "Tigo"),
+    (57305214, "Tigo"),
+    (57305215, "Tigo"),
+    (57305216, "Tigo"),
+    (57305260, "Tigo"),
+    (57305261, "Tigo"),
+    (57305262, "Tigo"),
+    (57305263, "Tigo"),
+    (57305264, "Tigo"),
+    (57305265, "Tigo"),
+    (57305266, "Tigo"),
+    (57310990, "Claro"),
+    (57310991, "Claro"),
+    (57333170, "Libre Tecnologias"),
+    (57333171, "Libre Tecnologias"),
+    (59069000, "SFR/Rife"),
+    (59069005, "SFR/Rife"),
+    (59069006, "Digicel"),
+    (59069007, "Digicel"),
+    (59069008, "Digicel"),
+    (59069009, "Digicel"),
+    (59069010, "UTS"),
+    (59069020, "Digicel"),
+    (59069021, "Digicel"),
+    (59069022, "Dauphin Telecom"),
+    (59069023, "Digicel"),
+    (59069024, "Digicel"),
+    (59069025, "Digicel"),
+    (59069026, "Orange"),
+    (59069027, "Orange"),
+    (59069028, "Orange"),
+    (59069029, "Orange"),
+    (59069036, "Digicel"),
+    (59069040, "Orange"),
+    (59069041, "Orange"),
+    (59069042, "Digicel"),
+    (59069043, "Digicel"),
+    (59069044, "Digicel"),
+    (59069045, "Digicel"),
+    (59069046, "Digicel"),
+    (59069047, "Orange"),
+    (59069048, "Orange"),
+    (59069049, "Orange"),
+    (59069066, "Dauphin Telecom"),
+    (59069069, "Digicel"),
+    (59069077, "Dauphin Telecom"),
+    (59069078, "SFR/Rife"),
+    (59069079, "SFR/Rife"),
+    (59069087, "UTS"),
+    (59069088, "Dauphin Telecom"),
+    (59069089, "SFR/Rife"),
+    (59069122, "Dauphin Telecom"),
+    (59069129, "Digicel"),
+    (59069130, "Digicel"),
+    (59069131, "Orange"),
+    (59069132, "Orange"),
+    (59069133, "Orange"),
+    (59069134, "Orange"),
+    (59069135, "Orange"),
+    (59070900, "Free Caraïbe"),
+    (59070901, "Outremer Telecom"),
+    (59070902, "Digicel"),
+    (59070903, "Orange"),
+    (59070904, "Dauphin Telecom"),
+    (59469408, "Digicel"),
+    (59469409, "Digicel"),
+    (59469412, "Digicel"),
+    (59469413, "Digicel"),
+    (59469414, "Digicel"),
+    (59469415, "Digicel"),
+    (59469416, "Digicel"),
+    (59469417, "SFR"),
+    (59469418, "SFR"),
+    (59469419, "SFR"),
+    (59469435, "Free Caraibe"),
+    (59469436, "Free Caraibe"),
+    (59469437, "Free Caraibe"),
+    (59469446, "SFR"),
+    (59469447, "SFR"),
+    (59470930, "Free Caraibe"),
+    (59470932, "Digicel"),
+    (59470933, "Orange"),
+    (59669610, "Digicel"),
+    (59669611, "Digicel"),
+    (59669616, "Digicel"),
+    (59669617, "Digicel"),
+    (59669618, "Digicel"),
+    (59669619, "Digicel"),
+    (59669650, "Digicel"),
+    (59669651, "Digicel"),
+    (59669652, "Digicel"),
+    (59669653, "Digicel"),
+    (59669654, "Digicel"),
+    (59669655, "Orange"),
+    (59669656, "Orange"),
+    (59669660, "SFR/Rife"),
+    (59669661, "SFR/Rife"),
+    (59669662, "SFR/Rife"),
+    (59669663, "SFR/Rife"),
+    (59669664, "SFR/Rife"),
+    (59669665, "Free Caraïbe"),
+    (59669666, "Free Caraïbe"),
+    (59669667, "Free Caraïbe"),
+    (59669668, "Free Caraïbe"),
+    (59669669, "Free Caraïbe"),
+    (59669687, "SFR/Rife"),
+    (59669688, "SFR/Rife"),
+    (59669689, "SFR/Rife"),
+    (59669699, "Orange"),
+    (59669727, "Digicel"),
+    (59670910, "Free Caraïbe"),
+    (59670912, "Digicel"),
+    (59670913, "Orange"),
+    (60118886, "U Mobile"),
+    (60118888, "red ONE"),
+    (60118889, "U Mobile"),
+    (68673101, "ATHKL"),
+    (68673102, "ATHKL"),
+    (68673103, "ATHKL"),
+    (68673104, "ATHKL"),
+    (68673105, "ATHKL"),
+    (68673106, "ATHKL"),
+    (68673107, "ATHKL"),
+    (68673108, "ATHKL"),
+    (68673109, "ATHKL"),
+    (68675020, "ATHKL"),
+    (68675023, "ATHKL"),
+    (68675024, "ATHKL"),
+    (68675025, "ATHKL"),
+    (68675026, "ATHKL"),
+    (68675027, "ATHKL"),
+    (68675028, "ATHKL"),
+    (68675029, "ATHKL"),
+    (68675120, "ATHKL"),
+    (68675121, "ATHKL"),
+    (68675122, "ATHKL"),
+    (68675123, "ATHKL"),
+    (68675124, "ATHKL"),
+    (68675127, "ATHKL"),
+    (68675128, "ATHKL"),
+    (68675129, "ATHKL"),
+    (68675220, "ATHKL"),
+    (68675221, "ATHKL"),
+    (68675222, "ATHKL"),
+    (68675223, "ATHKL"),
+    (68675224, "ATHKL"),
+    (68675225, "ATHKL"),
+    (68675226, "ATHKL"),
+    (68675227, "ATHKL"),
+    (68675301, "ATHKL"),
+    (68675302, "ATHKL"),
+    (68675303, "ATHKL"),
+    (68675304, "ATHKL"),
+    (68675305, "ATHKL"),
+    (68675306, "ATHKL"),
+    (68675307, "ATHKL"),
+    (68675308, "ATHKL"),
+    (68675309, "ATHKL"),
+    (68675380, "ATHKL"),
+    (68675382, "ATHKL"),
+    (68675383, "ATHKL"),
+    (68675384, "ATHKL"),
+    (68675385, "ATHKL"),
+    (68675386, "ATHKL"),
+    (68675387, "ATHKL"),
+    (68675388, "ATHKL"),
+    (68675389, "ATHKL"),
+    (68675401, "ATHKL"),
+    (68675402, "ATHKL"),
+    (68675403, "ATHKL"),
+    (68675404, "ATHKL"),
+    (68675405, "ATHKL"),
+    (68675406, "ATHKL"),
+    (68675407, "ATHKL"),
+    (68675408, "ATHKL"),
+    (68675409, "ATHKL"),
+    (68675480, "ATHKL"),
+    (68675482, "ATHKL"),
+    (68675483, "ATHKL"),
+    (68675484, "ATHKL"),
+    (68675485, "ATHKL"),
+    (68675486, "ATHKL"),
+    (68675487, "ATHKL"),
+    (68675488, "ATHKL"),
+    (68675489, "ATHKL"),
+    (68675501, "ATHKL"),
+    (68675502, "ATHKL"),
+    (68675503, "ATHKL"),
+    (68675504, "ATHKL"),
+    (68675505, "ATHKL"),
+    (68675506, "ATHKL"),
+    (68675507, "ATHKL"),
+    (68675508, "ATHKL"),
+    (68675509, "ATHKL"),
+    (79004650, "Gazprom Telekom"),
+    (79004651, "Sim Telecom"),
+    (79004652, "Sim Telecom"),
+    (79004653, "Sim Telecom"),
+    (79004654, "Sim Telecom"),
+    (79010130, "Tele2"),
+    (79010131, "Tele2"),
+    (79010132, "Tele2"),
+    (79010133, "Tele2"),
+    (79010134, "Tele2"),
+    (79010135, "Tele2"),
+    (79010136, "Tele2"),
+    (79010138, "Multiregional Transit Telecom (MTT)"),
+    (79010139, "Multiregional Transit Telecom (MTT)"),
+    (85365420, "3"),
+    (85365421, "3"),
+    (85365422, "3"),
+    (85365423, "3"),
+    (85365424, "3"),
+    (85365425, "China Telecom"),
+    (85365426, "China Telecom"),
+    (85365427, "China Telecom"),
+    (85365428, "China Telecom"),
+    (85365429, "China Telecom"),
+    (85365470, "CTM"),
+    (85365471, "CTM"),
+    (85365472, "CTM"),
+    (85365473, "CTM"),
+    (85365474, "CTM"),
+    (85365475, "SmarTone"),
+    (85365476, "SmarTone"),
+    (85365477, "SmarTone"),
+    (85365478, "SmarTone"),
+    (85365479, "SmarTone"),
+    (85366001, "CTM"),
+    (90510210, "Pasifik Telekom"),
+    (90510220, "Netgsm"),
+    (90510221, "Netgsm"),
+    (90510222, "Netgsm"),
+    (90510223, "Netgsm"),
+    (90510224, "Netgsm"),
+    (90510227, "Netgsm"),
+    (90510232, "Foniv Telecommunications Services"),
+    (90510244, "Nida Telekomunikasyon"),
+    (90510255, "TTM"),
+    (90510266, "Medium Telecommunications Services"),
+    (90510300, "Mobilisim Mobil Bilisim"),
+    (90510310, "Assistant Telecom"),
+    (90510333, "Roitel"),
+    (90510343, "Isnet"),
+    (90510400, "Duru Telekom"),
+    (90510450, "Pelicell Telecommunications"),
+    (90510480, "Alfa iletisim"),
+    (90510499, "HATNET BILGI ILETISIM TEKNOLOJILERI"),
+    (97255440, "Merkaziya"),
+    (97255442, "Xphone"),
+    (97255443, "Yossi"),
+    (99436554, "Naxtel"),
+    (99550001, "Cellfie"),
+    (99550002, "Icell Telecom"),
+    (99550010, "Cellfie"),
+    (99550011, "Silknet"),
+    (99550012, "Silknet"),
+    (99550019, "Cellfie"),
+    (99550033, "Silknet"),
+    (99550050, "MagtiCom"),
+    (99550055, "Silknet"),
+    (99550070, "Cellfie"),
+    (99550077, "Silknet"),
+    (99550090, "Cellfie"),
+    (99550099, "Silknet"),
+    (99551100, "MagtiCom"),
+    (99551101, "Silknet"),
+    (99551107, "Silknet"),
+    (99551151, "Silknet"),
+    (99551152, "MagtiCom"),
+    (99551153, "MagtiCom"),
+    (99551154, "MagtiCom"),
+    (99551155, "MagtiCom"),
+    (99551156, "MagtiCom"),
+    (99552222, "MagtiCom"),
+    (99552225, "Silknet"),
+    (99554444, "MagtiCom"),
+    (99555000, "Silknet"),
+    (99555005, "Silknet"),
+    (99555050, "Silknet"),
+    (99555055, "Globalcell"),
+    (99557575, "Silknet"),
+    (99557577, "MagtiCom"),
+    (99557578, "MagtiCom"),
+    (99631258, "Sky mobile"),
+    (99861220, "MobiUZ"),
+    (99861221, "MobiUZ"),
+    (99862221, "MobiUZ"),
+    (99865227, "MobiUZ"),
+    (99866215, "MobiUZ"),
+    (99866216, "MobiUZ"),
+    (99866217, "MobiUZ"),
+    (99866218, "MobiUZ"),
+    (99866219, "MobiUZ"),
+    (99866220, "MobiUZ"),
+    (99866223, "MobiUZ"),
+    (99866226, "MobiUZ"),
+    (99866227, "MobiUZ"),
+    (99866238, "MobiUZ"),
+    (99866241, "MobiUZ"),
+    (99866252, "MobiUZ"),
+    (99866260, "MobiUZ"),
+    (99866456, "MobiUZ"),
+    (99866483, "MobiUZ"),
+    (99866710, "MobiUZ"),
+    (99866711, "MobiUZ"),
+    (99866717, "MobiUZ"),
+    (99866730, "MobiUZ"),
+    (99866737, "MobiUZ"),
+    (99866740, "MobiUZ"),
+    (99866744, "MobiUZ"),
+    (99866747, "MobiUZ"),
+    (99866750, "MobiUZ"),
+    (99866755, "MobiUZ"),
+    (99866757, "MobiUZ"),
+    (99866767, "MobiUZ"),
+    (99866780, "MobiUZ"),
+    (99866781, "MobiUZ"),
+    (99866787, "MobiUZ"),
+    (99866788, "MobiUZ"),
+    (99866797, "MobiUZ"),
+    (99866799, "MobiUZ"),
+    (99867224, "MobiUZ"),
+    (99867232, "MobiUZ"),
+    (99867233, "MobiUZ"),
+    (99867237, "MobiUZ"),
+    (99867245, "MobiUZ"),
+    (99867246, "MobiUZ"),
+    (99867247, "MobiUZ"),
+    (99867248, "MobiUZ"),
+    (99867249, "MobiUZ"),
+    (99867271, "MobiUZ"),
+    (99867275, "MobiUZ"),
+    (99867276, "MobiUZ"),
+    (99867277, "MobiUZ"),
+    (99867278, "MobiUZ"),
+    (99867790, "MobiUZ"),
+    (99867797, "MobiUZ"),
+    (99872227, "MobiUZ"),
+    (99872229, "MobiUZ"),
+    (99873210, "MobiUZ"),
+    (99873211, "MobiUZ"),
+    (99873212, "MobiUZ"),
+    (99873213, "MobiUZ"),
+    (99873214, "MobiUZ"),
+    (99873215, "MobiUZ"),
+    (99873216, "MobiUZ"),
+    (99873221, "MobiUZ"),
+    (99873234, "MobiUZ"),
+    (99873236, "MobiUZ"),
+    (99873239, "MobiUZ"),
+    (99873271, "MobiUZ"),
+    (99873275, "MobiUZ"),
+    (99873279, "MobiUZ"),
+    (99873555, "MobiUZ"),
+    (99873557, "MobiUZ"),
+    (99873559, "MobiUZ"),
+    (99874229, "MobiUZ"),
+    (99874250, "MobiUZ"),
+    (99874252, "MobiUZ"),
+    (99874255, "MobiUZ"),
+    (99874257, "MobiUZ"),
+    (99874260, "MobiUZ"),
+    (99874261, "MobiUZ"),
+    (99874262, "MobiUZ"),
+    (99874263, "MobiUZ"),
+    (99874264, "MobiUZ"),
+    (99874265, "MobiUZ"),
+    (99874266, "MobiUZ"),
+    (99874267, "MobiUZ"),
+    (99874271, "MobiUZ"),
+    (99874272, "MobiUZ"),
+    (99874273, "MobiUZ"),
+    (99874274, "MobiUZ"),
+    (99874275, "MobiUZ"),
+    (99874277, "MobiUZ"),
+    (99874710, "MobiUZ"),
+    (99874712, "MobiUZ"),
+    (99874714, "MobiUZ"),
+    (99874715, "MobiUZ"),
+    (99874718, "MobiUZ"),
+    (99874719, "MobiUZ"),
+    (99874720, "MobiUZ"),
+    (99874721, "MobiUZ"),
+    (99874722, "MobiUZ"),
+    (99874727, "MobiUZ"),
+    (99874730, "MobiUZ"),
+    (99874731, "MobiUZ"),
+    (99874733, "MobiUZ"),
+    (99874737, "MobiUZ"),
+    (99874740, "MobiUZ"),
+    (99874747, "MobiUZ"),
+    (99874750, "MobiUZ"),
+    (99874751, "MobiUZ"),
+    (99874760, "MobiUZ"),
+    (99874767, "MobiUZ"),
+    (99874775, "MobiUZ"),
+    (99874776, "MobiUZ"),
+    (99874777, "MobiUZ"),
+    (99874778, "MobiUZ"),
+    (99874779, "MobiUZ"),
+    (99874790, "MobiUZ"),
+    (99874797, "MobiUZ"),
+    (99874799, "MobiUZ"),
+    (99875222, "MobiUZ"),
+    (99875229, "MobiUZ"),
+    (99875526, "MobiUZ"),
+    (99875527, "MobiUZ"),
+    (99875528, "MobiUZ"),
+    (99875529, "MobiUZ"),
+    (99879221, "MobiUZ"),
+    (99879222, "MobiUZ"),
+    (99879228, "MobiUZ"),
+    (99879570, "MobiUZ"),
+    (99879572, "MobiUZ"),
+    (99879575, "MobiUZ"),
+    (99879576, "MobiUZ"),
+    (99879579, "MobiUZ"),
+    (193924199, "Claro"),
+    (351609230, "NOS"),
+    (351639230, "NOS"),
+    (351639233, "Digi Communications"),
+    (351639234, "G9 Telecom"),
+    (351659230, "NOS"),
+    (351659233, "Digi Communications"),
+    (351659234, "G9 Telecom"),
+    (351669230, "NOS"),
+    (372591410, "Tele 2"),
+    (372591411, "Tele 2"),
+    (372591412, "Tele 2"),
+    (372591413, "Tele 2"),
+    (519499822, "Movistar"),
+    (519499823, "Movistar"),
+    (519499824, "Movistar"),
+    (519499825, "Movistar"),
+    (519499826, "Movistar"),
+    (519499827, "Movistar"),
+    (519499828, "Movistar"),
+    (519499829, "Movistar"),
+    (519499875, "Movistar"),
+    (519499876, "Movistar"),
+    (519499877, "Movistar"),
+    (519499878, "Movistar"),
+    (519499879, "Movistar"),
+    (551195472, "Vivo"),
+    (551195473, "Vivo"),
+    (551195474, "Vivo"),
+    (551195769, "Vivo"),
+    (551195780, "Vivo"),
+    (551195781, "Vivo"),
+    (551195782, "Vivo"),
+    (551195783, "Vivo"),
+    (551195784, "Vivo"),
+    (551195785, "Vivo"),
+    (551195786, "Vivo"),
+    (551196057, "Vivo"),
+    (551196058, "Vivo"),
+    (551196059, "Vivo"),
+    (551196060, "Vivo"),
+    (551196168, "Claro"),
+    (551196169, "Claro"),
+    (551196180, "Claro"),
+    (551196181, "Claro"),
+    (551196650, "Claro"),
+    (551196860, "Vivo"),
+    (551196861, "Vivo"),
+    (551196862, "Vivo"),
+    (551196863, "Vivo"),
+    (551196864, "Vivo"),
+    (551196865, "Vivo"),
+    (551196866, "Vivo"),
+    (551196910, "Vivo"),
+    (551196911, "Vivo"),
+    (551196912, "Vivo"),
+    (551196913, "Vivo"),
+    (551196930, "Claro"),
+    (551196931, "Claro"),
+    (551197011, "TIM"),
+    (551197012, "TIM"),
+    (551197013, "TIM"),
+    (551197014, "TIM"),
+    (551197015, "TIM"),
+    (551197016, "TIM"),
+    (551197017, "TIM"),
+    (551197018, "TIM"),
+    (551197019, "TIM"),
+    (551197030, "TIM"),
+    (551197031, "TIM"),
+    (551197032, "TIM"),
+    (551197033, "TIM"),
+    (551197034, "TIM"),
+    (551197035, "TIM"),
+    (551197036, "TIM"),
+    (551197037, "TIM"),
+    (551197038, "TIM"),
+    (551197049, "TIM"),
+    (551197050, "TIM"),
+    (551197051, "TIM"),
+    (551197087, "Vivo"),
+    (551197088, "Vivo"),
+    (551197089, "Vivo"),
+    (551197968, "Claro"),
+    (551197969, "Claro"),
+    (551197970, "Claro"),
+    (551197990, "Oi"),
+    (551197991, "Oi"),
+    (551197992, "Oi"),
+    (551197993, "Oi"),
+    (551197994, "Oi"),
+    (551197995, "Oi"),
+    (551198023, "Oi"),
+    (551198024, "Oi"),
+    (551198025, "Oi"),
+    (551198026, "Oi"),
+    (551198027, "Oi"),
+    (551198028, "Oi"),
+    (551198029, "Oi"),
+    (551298111, "TIM"),
+    (551298112, "TIM"),
+    (551298113, "TIM"),
+    (551298114, "TIM"),
+    (551298115, "TIM"),
+    (551298116, "TIM"),
+    (551298117, "TIM"),
+    (551298118, "TIM"),
+    (551298119, "TIM"),
+    (551298121, "TIM"),
+    (551298122, "TIM"),
+    (551298123, "TIM"),
+    (551298124, "TIM"),
+    (551298125, "TIM"),
+    (551298126, "TIM"),
+    (551298127, "TIM"),
+    (551298128, "TIM"),
+    (551298129, "TIM"),
+    (551298131, "TIM"),
+    (551298132, "TIM"),
+    (551298133, "TIM"),
+    (551298134, "TIM"),
+    (551298135, "TIM"),
+    (551298136, "TIM"),
+    (551298137, "TIM"),
+    (551298138, "TIM"),
+    (551298139, "TIM"),
+    (551298141, "TIM"),
+    (551298142, "TIM"),
+    (551298143, "TIM"),
+    (551298144, "TIM"),
+    (551298145, "TIM"),
+    (551298146, "TIM"),
+    (551298147, "TIM"),
+    (551298148, "TIM"),
+    (551298149, "TIM"),
+    (551298151, "TIM"),
+    (551298152, "TIM"),
+    (551298153, "TIM"),
+    (551298154, "TIM"),
+    (551298155, "TIM"),
+    (551298156, "TIM"),
+    (551298157, "TIM"),
+    (551298158, "TIM"),
+    (551298159, "TIM"),
+    (551298161, "TIM"),
+    (551298162, "TIM"),
+    (551298163, "TIM"),
+    (551298164, "TIM"),
+    (551298165, "TIM"),
+    (551298166, "TIM"),
+    (551298167, "TIM"),
+    (551298168, "TIM"),
+    (551298169, "TIM"),
+    (551298171, "TIM"),
+    (551298172, "TIM"),
+    (551298173, "TIM"),
+    (551298174, "TIM"),
+    (551298175, "TIM"),
+    (551298176, "TIM"),
+    (551298177, "TIM"),
+    (551298178, "TIM"),
+    (551298179, "TIM"),
+    (551298181, "TIM"),
+    (551298182, "TIM"),
+    (551298808, "Oi"),
+    (551298809, "Oi"),
+    (551298820, "Oi"),
+    (551298821, "Oi"),
+    (551298822, "Oi"),
+    (551298823, "Oi"),
+    (551299240, "Claro"),
+    (551299241, "Claro"),
+    (551299242, "Claro"),
+    (551299243, "Claro"),
+    (551299244, "Claro"),
+    (551299245, "Claro"),
+    (551299630, "Vivo"),
+    (551299631, "Vivo"),
+    (551299632, "Vivo"),
+    (551398111, "TIM"),
+    (551398112, "TIM"),
+    (551398113, "TIM"),
+    (551398114, "TIM"),
+    (551398115, "TIM"),
+    (551398116, "TIM"),
+    (551398117, "TIM"),
+    (551398118, "TIM"),
+    (551398119, "TIM"),
+    (551398121, "TIM"),
+    (551398122, "TIM"),
+    (551398123, "TIM"),
+    (551398124, "TIM"),
+    (551398125, "TIM"),
+    (551398126, "TIM"),
+    (551398127, "TIM"),
+    (551398128, "TIM"),
+    (551398129, "TIM"),
+    (551398131, "TIM"),
+    (551398132, "TIM"),
+    (551398133, "TIM"),
+    (551398134, "TIM"),
+    (551398135, "TIM"),
+    (551398136, "TIM"),
+    (551398137, "TIM"),
+    (551398138, "TIM"),
+    (551398139, "TIM"),
+    (551398141, "TIM"),
+    (551398142, "TIM"),
+    (551398143, "TIM"),
+    (551398144, "TIM"),
+    (551398145, "TIM"),
+    (551398146, "TIM"),
+    (551398147, "TIM"),
+    (551398149, "TIM"),
+    (551398151, "TIM"),
+    (551398152, "TIM"),
+    (551398153, "TIM"),
+    (551398154, "TIM"),
+    (551398155, "TIM"),
+    (551398156, "TIM"),
+    (551398157, "TIM"),
+    (551398158, "TIM"),
+    (551398159, "TIM"),
+    (551398161, "TIM"),
+    (551398803, "Oi"),
+    (551398804, "Oi"),
+    (551398805, "Oi"),
+    (551398806, "Oi"),
+    (551398807, "Oi"),
+    (551398808, "Oi"),
+    (551398809, "Oi"),
+    (551398820, "Oi"),
+    (551399210, "Claro"),
+    (551399211, "Claro"),
+    (551399630, "Vivo"),
+    (551399631, "Vivo"),
+    (551399632, "Vivo"),
+    (551399633, "Vivo"),
+    (551399634, "Vivo"),
+    (551399635, "Vivo"),
+    (551399636, "Vivo"),
+    (551399637, "Vivo"),
+    (551498111, "TIM"),
+    (551498112, "TIM"),
+    (551498113, "TIM"),
+    (551498114, "TIM"),
+    (551498115, "TIM"),
+    (551498116, "TIM"),
+    (551498117, "TIM"),
+    (551498118, "TIM"),
+    (551498119, "TIM"),
+    (551498121, "TIM"),
+    (551498122, "TIM"),
+    (551498123, "TIM"),
+    (551498124, "TIM"),
+    (551498125, "TIM"),
+    (551498126, "TIM"),
+    (551498127, "TIM"),
+    (551498128, "TIM"),
+    (551498129, "TIM"),
+    (551498131, "TIM"),
+    (551498132, "TIM"),
+    (551498133, "TIM"),
+    (551498134, "TIM"),
+    (551498135, "TIM"),
+    (551498136, "TIM"),
+    (551498137, "TIM"),
+    (551498138, "TIM"),
+    (551498139, "TIM"),
+    (551498141, "TIM"),
+    (551498142, "TIM"),
+    (551498143, "TIM"),
+    (551498144, "TIM"),
+    (551498145, "TIM"),
+    (551498146, "TIM"),
+    (551498147, "TIM"),
+    (551498148, "TIM"),
+    (551498149, "TIM"),
+    (551498151, "TIM"),
+    (551498152, "TIM"),
+    (551498153, "TIM"),
+    (551498154, "TIM"),
+    (551498155, "TIM"),
+    (551498156, "TIM"),
+    (551498157, "TIM"),
+    (551498158, "TIM"),
+    (551498159, "TIM"),
+    (551498161, "TIM"),
+    (551498162, "TIM"),
+    (551498163, "TIM"),
+    (551498164, "TIM"),
+    (551498165, "TIM"),
+    (551498166, "TIM"),
+    (551498806, "Oi"),
+    (551498807, "Oi"),
+    (551498808, "Oi"),
+    (551498809, "Oi"),
+    (551498810, "Oi"),
+    (551498811, "Oi"),
+    (551498812, "Oi"),
+    (551498813, "Oi"),
+    (551498814, "Oi"),
+    (551499101, "Claro"),
+    (551499102, "Claro"),
+    (551499103, "Claro"),
+    (551499104, "Claro"),
+    (551499105, "Claro"),
+    (551499106, "Claro"),
+    (551499107, "Claro"),
+    (551499108, "Claro"),
+    (551499109, "Claro"),
+    (551499111, "Claro"),
+    (551499112, "Claro"),
+    (551499113, "Claro"),
+    (551499114, "Claro"),
+    (551499115, "Claro"),
+    (551499116, "Claro"),
+    (551499117, "Claro"),
+    (551499118, "Claro"),
+    (551499119, "Claro"),
+    (551499121, "Claro"),
+    (551499122, "Claro"),
+    (551499123, "Claro"),
+    (551499124, "Claro"),
+    (551499125, "Claro"),
+    (551499126, "Claro"),
+    (551499127, "Claro"),
+    (551499128, "Claro"),
+    (551499129, "Claro"),
+    (551499131, "Claro"),
+    (551499132, "Claro"),
+    (551499133, "Claro"),
+    (551499134, "Claro"),
+    (551499135, "Claro"),
+    (551499136, "Claro"),
+    (551499137, "Claro"),
+    (551499138, "Claro"),
+    (551499141, "Claro"),
+    (551499142, "Claro"),
+    (551499143, "Claro"),
+    (551499146, "Claro"),
+    (551499147, "Claro"),
+    (551499148, "Claro"),
+    (551499149, "Claro"),
+    (551499151, "Claro"),
+    (551499152, "Claro"),
+    (551499153, "Claro"),
+    (551499154, "Claro"),
+    (551499155, "Claro"),
+    (551499156, "Claro"),
+    (551499157, "Claro"),
+    (551499161, "Claro"),
+    (551499162, "Claro"),
+    (551499163, "Claro"),
+    (551499164, "Claro"),
+    (551499165, "Claro"),
+    (551499166, "Claro"),
+    (551499167, "Claro"),
+    (551499168, "Claro"),
+    (551499169, "Claro"),
+    (551499171, "Claro"),
+    (551499172, "Claro"),
+    (551499173, "Claro"),
+    (551499174, "Claro"),
+    (551499175, "Claro"),
+    (551499176, "Claro"),
+    (551499177, "Claro"),
+    (551499178, "Claro"),
+    (551499179, "Claro"),
+    (551499181, "Claro"),
+    (551499182, "Claro"),
+    (551499183, "Claro"),
+    (551499184, "Claro"),
+    (551499185, "Claro"),
+    (551499186, "Claro"),
+    (551499187, "Claro"),
+    (551499188, "Claro"),
+    (551499189, "Claro"),
+    (551499191, "Claro"),
+    (551499192, "Claro"),
+    (551499193, "Claro"),
+    (551499194, "Claro"),
+    (551499195, "Claro"),
+    (551499196, "Claro"),
+    (551499197, "Claro"),
+    (551499830, "Vivo"),
+    (551499831, "Vivo"),
+    (551499832, "Vivo"),
+    (551598111, "TIM"),
+    (551598112, "TIM"),
+    (551598113, "TIM"),
+    (551598114, "TIM"),
+    (551598115, "TIM"),
+    (551598116, "TIM"),
+    (551598117, "TIM"),
+    (551598118, "TIM"),
+    (551598119, "TIM"),
+    (551598121, "TIM"),
+    (551598122, "TIM"),
+    (551598123, "TIM"),
+    (551598124, "TIM"),
+    (551598125, "TIM"),
+    (551598126, "TIM"),
+    (551598127, "TIM"),
+    (551598128, "TIM"),
+    (551598129, "TIM"),
+    (551598131, "TIM"),
+    (551598132, "TIM"),
+    (551598133, "TIM"),
+    (551598134, "TIM"),
+    (551598135, "TIM"),
+    (551598136, "TIM"),
+    (551598138, "TIM"),
+    (551598139, "TIM"),
+    (551598141, "TIM"),
+    (551598804, "Oi"),
+    (551598805, "Oi"),
+    (551598806, "Oi"),
+    (551598807, "Oi"),
+    (551598808, "Oi"),
+    (551598809, "Oi"),
+    (551598810, "Oi"),
+    (551598813, "Oi"),
+    (551598814, "Oi"),
+    (551598815, "Oi"),
+    (551599101, "Claro"),
+    (551599102, "Claro"),
+    (551599103, "Claro"),
+    (551599104, "Claro"),
+    (551599105, "Claro"),
+    (551599106, "Claro"),
+    (551599107, "Claro"),
+    (551599108, "Claro"),
+    (551599109, "Claro"),
+    (551599111, "Claro"),
+    (551599112, "Claro"),
+    (551599113, "Claro"),
+    (551599114, "Claro"),
+    (551599115, "Claro"),
+    (551599116, "Claro"),
+    (551599117, "Claro"),
+    (551599118, "Claro"),
+    (551599119, "Claro"),
+    (551599121, "Claro"),
+    (551599122, "Claro"),
+    (551599123, "Claro"),
+    (551599124, "Claro"),
+    (551599125, "Claro"),
+    (551599126, "Claro"),
+    (551599127, "Claro"),
+    (551599128, "Claro"),
+    (551599129, "Claro"),
+    (551599131, "Claro"),
+    (551599132, "Claro"),
+    (551599133, "Claro"),
+    (551599134, "Claro"),
+    (551599135, "Claro"),
+    (551599136, "Claro"),
+    (551599137, "Claro"),
+    (551599138, "Claro"),
+    (551599139, "Claro"),
+    (551599141, "Claro"),
+    (551599142, "Claro"),
+    (551599143, "Claro"),
+    (551599144, "Claro"),
+    (551599145, "Claro"),
+    (551599146, "Claro"),
+    (551599147, "Claro"),
+    (551599148, "Claro"),
+    (551599149, "Claro"),
+    (551599151, "Claro"),
+    (551599152, "Claro"),
+    (551599153, "Claro"),
+    (551599154, "Claro"),
+    (551599155, "Claro"),
+    (551599156, "Claro"),
+    (551599157, "Claro"),
+    (551599158, "Claro"),
+    (551599159, "Claro"),
+    (551599161, "Claro"),
+    (551599162, "Claro"),
+    (551599163, "Claro"),
+    (551599164, "Claro"),
+    (551599165, "Claro"),
+    (551599166, "Claro"),
+    (551599167, "Claro"),
+    (551599168, "Claro"),
+    (551599169, "Claro"),
+    (551599171, "Claro"),
+    (551599172, "Claro"),
+    (551599173, "Claro"),
+    (551599174, "Claro"),
+    (551599175, "Claro"),
+    (551599176, "Claro"),
+    (551599177, "Claro"),
+    (551599178, "Claro"),
+    (551599179, "Claro"),
+    (551599181, "Claro"),
+    (551599182, "Claro"),
+    (551599183, "Claro"),
+    (551599184, "Claro"),
+    (551599185, "Claro"),
+    (551599186, "Claro"),
+    (551599187, "Claro"),
+    (551599188, "Claro"),
+    (551599201, "Claro"),
+    (551599690, "Vivo"),
+    (551599691, "Vivo"),
+    (551599692, "Vivo"),
+    (551599693, "Vivo"),
+    (551599694, "Vivo"),
+    (551599695, "Vivo"),
+    (551599696, "Vivo"),
+    (551599697, "Vivo"),
+    (551698111, "TIM"),
+    (551698112, "TIM"),
+    (551698113, "TIM"),
+    (551698114, "TIM"),
+    (551698115, "TIM"),
+    (551698116, "TIM"),
+    (551698117, "TIM"),
+    (551698118, "TIM"),
+    (551698119, "TIM"),
+    (551698121, "TIM"),
+    (551698122, "TIM"),
+    (551698123, "TIM"),
+    (551698124, "TIM"),
+    (551698125, "TIM"),
+    (551698126, "TIM"),
+    (551698127, "TIM"),
+    (551698128, "TIM"),
+    (551698129, "TIM"),
+    (551698131, "TIM"),
+    (551698132, "TIM"),
+    (551698133, "TIM"),
+    (551698134, "TIM"),
+    (551698135, "TIM"),
+    (551698136, "TIM"),
+    (551698137, "TIM"),
+    (551698138, "TIM"),
+    (551698139, "TIM"),
+    (551698141, "TIM"),
+    (551698142, "TIM"),
+    (551698143, "TIM"),
+    (551698144, "TIM"),
+    (551698145, "TIM"),
+    (551698146, "TIM"),
+    (551698147, "TIM"),
+    (551698148, "TIM"),
+    (551698149, "TIM"),
+    (551698151, "TIM"),
+    (551698152, "TIM"),
+    (551698153, "TIM"),
+    (551698154, "TIM"),
+    (551698155, "TIM"),
+    (551698156, "TIM"),
+    (551698157, "TIM"),
+    (551698158, "TIM"),
+    (551698159, "TIM"),
+    (551698161, "TIM"),
+    (551698162, "TIM"),
+    (551698163, "TIM"),
+    (551698164, "TIM"),
+    (551698165, "TIM"),
+    (551698166, "TIM"),
+    (551698167, "TIM"),
+    (551698168, "TIM"),
+    (551698169, "TIM"),
+    (551698171, "TIM"),
+    (551698172, "TIM"),
+    (551698173, "TIM"),
+    (551698174, "TIM"),
+    (551698175, "TIM"),
+    (551698176, "TIM"),
+    (551698177, "TIM"),
+    (551698178, "TIM"),
+    (551698179, "TIM"),
+    (551698181, "TIM"),
+    (551698182, "TIM"),
+    (551698183, "TIM"),
+    (551698184, "TIM"),
+    (551698803, "Oi"),
+    (551698804, "Oi"),
+    (551698805, "Oi"),
+    (551698806, "Oi"),
+    (551698807, "Oi"),
+    (551698808, "Oi"),
+    (551698809, "Oi"),
+    (551698820, "Oi"),
+    (551698821, "Oi"),
+    (551698822, "Oi"),
+    (551698823, "Oi"),
+    (551699360, "Claro"),
+    (551699361, "Claro"),
+    (551699362, "Claro"),
+    (551699363, "Claro"),
+    (551699364, "Claro"),
+    (551699601, "Vivo"),
+    (551699606, "Vivo"),
+    (551699607, "Vivo"),
+    (551699608, "Vivo"),
+    (551699609, "Vivo"),
+    (551699701, "Vivo"),
+    (551699702, "Vivo"),
+    (551699703, "Vivo"),
+    (551699704, "Vivo"),
+    (551699705, "Vivo"),
+    (551699706, "Vivo"),
+    (551699707, "Vivo"),
+    (551699708, "Vivo"),
+    (551699709, "Vivo"),
+    (551699711, "Vivo"),
+    (551699712, "Vivo"),
+    (551699713, "Vivo"),
+    (551699714, "Vivo"),
+    (551699715, "Vivo"),
+    (551699716, "Vivo"),
+    (551699717, "Vivo"),
+    (551699718, "Vivo"),
+    (551699719, "Vivo"),
+    (551699721, "Vivo"),
+    (551699722, "Vivo"),
+    (551699723, "Vivo"),
+    (551699724, "Vivo"),
+    (551699725, "Vivo"),
+    (551699726, "Vivo"),
+    (551699727, "Vivo"),
+    (551699728, "Vivo"),
+    (551699729, "Vivo"),
+    (551699731, "Vivo"),
+    (551699732, "Vivo"),
+    (551699733, "Vivo"),
+    (551699734, "Vivo"),
+    (551699735, "Vivo"),
+    (551699736, "Vivo"),
+    (551699737, "Vivo"),
+    (551699738, "Vivo"),
+    (551699739, "Vivo"),
+    (551699741, "Vivo"),
+    (551699742, "Vivo"),
+    (551699743, "Vivo"),
+    (551699744, "Vivo"),
+    (551699745, "Vivo"),
+    (551699746, "Vivo"),
+    (551699747, "Vivo"),
+    (551699748, "Vivo"),
+    (551699749, "Vivo"),
+    (551699751, "Vivo"),
+    (551699752, "Vivo"),
+    (551699753, "Vivo"),
+    (551699754, "Vivo"),
+    (551699755, "Vivo"),
+    (551699756, "Vivo"),
+    (551699757, "Vivo"),
+    (551699758, "Vivo"),
+    (551699759, "Vivo"),
+    (551699761, "Vivo"),
+    (551699762, "Vivo"),
+    (551699763, "Vivo"),
+    (551699764, "Vivo"),
+    (551699765, "Vivo"),
+    (551699766, "Vivo"),
+    (551699767, "Vivo"),
+    (551699768, "Vivo"),
+    (551699769, "Vivo"),
+    (551699770, "Vivo"),
+    (551699771, "Vivo"),
+    (551699772, "Vivo"),
+    (551699773, "Vivo"),
+    (551699774, "Vivo"),
+    (551699775, "Vivo"),
+    (551699776, "Vivo"),
+    (551699777, "Vivo"),
+    (551699778, "Vivo"),
+    (551699780, "Vivo"),
+    (551699781, "Vivo"),
+    (551699782, "Vivo"),
+    (551699783, "Vivo"),
+    (551699784, "Vivo"),
+    (551699785, "Vivo"),
+    (551699786, "Vivo"),
+    (551699787, "Vivo"),
+    (551699788, "Vivo"),
+    (551699791, "Vivo"),
+    (551699792, "Vivo"),
+    (551699793, "Vivo"),
+    (551699794, "Vivo"),
+    (551699796, "Vivo"),
+    (551699961, "Vivo"),
+    (551699962, "Vivo"),
+    (551699963, "Vivo"),
+    (551699964, "Vivo"),
+    (551699975, "Vivo"),
+    (551699991, "Vivo"),
+    (551699992, "Vivo"),
+    (551699993, "Vivo"),
+    (551699994, "Vivo"),
+    (551798111, "TIM"),
+    (551798112, "TIM"),
+    (551798113, "TIM"),
+    (551798114, "TIM"),
+    (551798115, "TIM"),
+    (551798116, "TIM"),
+    (551798117, "TIM"),
+    (551798118, "TIM"),
+    (551798119, "TIM"),
+    (551798121, "TIM"),
+    (551798122, "TIM"),
+    (551798123, "TIM"),
+    (551798124, "TIM"),
+    (551798125, "TIM"),
+    (551798126, "TIM"),
+    (551798127, "TIM"),
+    (551798128, "TIM"),
+    (551798129, "TIM"),
+    (551798131, "TIM"),
+    (551798132, "TIM"),
+    (551798133, "TIM"),
+    (551798134, "TIM"),
+    (551798135, "TIM"),
+    (551798136, "TIM"),
+    (551798137, "TIM"),
+    (551798138, "TIM"),
+    (551798139, "TIM"),
+    (551798141, "TIM"),
+    (551798142, "TIM"),
+    (551798143, "TIM"),
+    (551798144, "TIM"),
+    (551798145, "TIM"),
+    (551798146, "TIM"),
+    (551798147, "TIM"),
+    (551798148, "TIM"),
+    (551798149, "TIM"),
+    (551798151, "TIM"),
+    (551798152, "TIM"),
+    (551798153, "TIM"),
+    (551798154, "TIM"),
+    (551798155, "TIM"),
+    (551798156, "TIM"),
+    (551798803, "Oi"),
+    (551798804, "Oi"),
+    (551798805, "Oi"),
+    (551798806, "Oi"),
+    (551798807, "Oi"),
+    (551798808, "Oi"),
+    (551798809, "Oi"),
+    (551798810, "Oi"),
+    (551798811, "Oi"),
+    (551798812, "Oi"),
+    (551798813, "Oi"),
+    (551799230, "Claro"),
+    (551799231, "Claro"),
+    (551799232, "Claro"),
+    (551799233, "Claro"),
+    (551799234, "Claro"),
+    (551799235, "Claro"),
+    (551799236, "Claro"),
+    (551799601, "Vivo"),
+    (551799602, "Vivo"),
+    (551799603, "Vivo"),
+    (551799604, "Vivo"),
+    (551799605, "Vivo"),
+    (551799606, "Vivo"),
+    (551799607, "Vivo"),
+    (551799608, "Vivo"),
+    (551799609, "Vivo"),
+    (551799611, "Vivo"),
+    (551799612, "Vivo"),
+    (551799613, "Vivo"),
+    (551799614, "Vivo"),
+    (551799615, "Vivo"),
+    (551799616, "Vivo"),
+    (551799617, "Vivo"),
+    (551799618, "Vivo"),
+    (551799619, "Vivo"),
+    (551799621, "Vivo"),
+    (551799622, "Vivo"),
+    (551799623, "Vivo"),
+    (551799624, "Vivo"),
+    (551799625, "Vivo"),
+    (551799626, "Vivo"),
+    (551799627, "Vivo"),
+    (551799628, "Vivo"),
+    (551799629, "Vivo"),
+    (551799631, "Vivo"),
+    (551799632, "Vivo"),
+    (551799633, "Vivo"),
+    (551799634, "Vivo"),
+    (551799635, "Vivo"),
+    (551799636, "Vivo"),
+    (551799637, "Vivo"),
+    (551799638, "Vivo"),
+    (551799639, "Vivo"),
+    (551799641, "Vivo"),
+    (551799642, "Vivo"),
+    (551799643, "Vivo"),
+    (551799644, "Vivo"),
+    (551799645, "Vivo"),
+    (551799646, "Vivo"),
+    (551799701, "Vivo"),
+    (551799702, "Vivo"),
+    (551799703, "Vivo"),
+    (551799704, "Vivo"),
+    (551799705, "Vivo"),
+    (551799706, "Vivo"),
+    (551799707, "Vivo"),
+    (551799708, "Vivo"),
+    (551799709, "Vivo"),
+    (551799711, "Vivo"),
+    (551799712, "Vivo"),
+    (551799713, "Vivo"),
+    (551799714, "Vivo"),
+    (551799715, "Vivo"),
+    (551799716, "Vivo"),
+    (551799717, "Vivo"),
+    (551799718, "Vivo"),
+    (551799719, "Vivo"),
+    (551799721, "Vivo"),
+    (551799722, "Vivo"),
+    (551799723, "Vivo"),
+    (551799724, "Vivo"),
+    (551799725, "Vivo"),
+    (551799726, "Vivo"),
+    (551799727, "Vivo"),
+    (551799728, "Vivo"),
+    (551799729, "Vivo"),
+    (551799731, "Vivo"),
+    (551799732, "Vivo"),
+    (551799733, "Vivo"),
+    (551799734, "Vivo"),
+    (551799735, "Vivo"),
+    (551799736, "Vivo"),
+    (551799737, "Vivo"),
+    (551799738, "Vivo"),
+    (551799739, "Vivo"),
+    (551799741, "Vivo"),
+    (551799742, "Vivo"),
+    (551799743, "Vivo"),
+    (551799744, "Vivo"),
+    (551799745, "Vivo"),
+    (551799746, "Vivo"),
+    (551799747, "Vivo"),
+    (551799748, "Vivo"),
+    (551799749, "Vivo"),
+    (551799751, "Vivo"),
+    (551799752, "Vivo"),
+    (551799753, "Vivo"),
+    (551799754, "Vivo"),
+    (551799755, "Vivo"),
+    (551799756, "Vivo"),
+    (551799757, "Vivo"),
+    (551799758, "Vivo"),
+    (551799759, "Vivo"),
+    (551799761, "Vivo"),
+    (551799762, "Vivo"),
+    (551799763, "Vivo"),
+    (551799764, "Vivo"),
+    (551799765, "Vivo"),
+    (551799766, "Vivo"),
+    (551799767, "Vivo"),
+    (551799768, "Vivo"),
+    (551799769, "Vivo"),
+    (551799771, "Vivo"),
+    (551799772, "Vivo"),
+    (551799773, "Vivo"),
+    (551799774, "Vivo"),
+    (551799775, "Vivo"),
+    (551799776, "Vivo"),
+    (551799777, "Vivo"),
+    (551799778, "Vivo"),
+    (551799779, "Vivo"),
+    (551799780, "Vivo"),
+    (551799783, "Vivo"),
+    (551799784, "Vivo"),
+    (551799785, "Vivo"),
+    (551799791, "Vivo"),
+    (551898111, "TIM"),
+    (551898112, "TIM"),
+    (551898113, "TIM"),
+    (551898114, "TIM"),
+    (551898115, "TIM"),
+    (551898116, "TIM"),
+    (551898117, "TIM"),
+    (551898118, "TIM"),
+    (551898119, "TIM"),
+    (551898121, "TIM"),
+    (551898122, "TIM"),
+    (551898123, "TIM"),
+    (551898124, "TIM"),
+    (551898125, "TIM"),
+    (551898126, "TIM"),
+    (551898127, "TIM"),
+    (551898128, "TIM"),
+    (551898129, "TIM"),
+    (551898131, "TIM"),
+    (551898132, "TIM"),
+    (551898133, "TIM"),
+    (551898134, "TIM"),
+    (551898135, "TIM"),
+    (551898136, "TIM"),
+    (551898137, "TIM"),
+    (551898138, "TIM"),
+    (551898139, "TIM"),
+    (551898141, "TIM"),
+    (551898142, "TIM"),
+    (551898143, "TIM"),
+    (551898144, "TIM"),
+    (551898145, "TIM"),
+    (551898146, "TIM"),
+    (551898147, "TIM"),
+    (551898148, "TIM"),
+    (551898149, "TIM"),
+    (551898151, "TIM"),
+    (551898810, "Oi"),
+    (551898811, "Oi"),
+    (551899180, "Claro"),
+    (551899197, "Claro"),
+    (551899198, "Claro"),
+    (551899199, "Claro"),
+    (551899601, "Vivo"),
+    (551899602, "Vivo"),
+    (551899603, "Vivo"),
+    (551899604, "Vivo"),
+    (551899605, "Vivo"),
+    (551899606, "Vivo"),
+    (551899607, "Vivo"),
+    (551899608, "Vivo"),
+    (551899609, "Vivo"),
+    (551899611, "Vivo"),
+    (551899612, "Vivo"),
+    (551899613, "Vivo"),
+    (551899614, "Vivo"),
+    (551899615, "Vivo"),
+    (551899616, "Vivo"),
+    (551899617, "Vivo"),
+    (551899618, "Vivo"),
+    (551899621, "Vivo"),
+    (551899622, "Vivo"),
+    (551899623, "Vivo"),
+    (551899624, "Vivo"),
+    (551899625, "Vivo"),
+    (551899626, "Vivo"),
+    (551899627, "Vivo"),
+    (551899628, "Vivo"),
+    (551899629, "Vivo"),
+    (551899631, "Vivo"),
+    (551899632, "Vivo"),
+    (551899633, "Vivo"),
+    (551899634, "Vivo"),
+    (551899635, "Vivo"),
+    (551899636, "Vivo"),
+    (551899637, "Vivo"),
+    (551899638, "Vivo"),
+    (551899639, "Vivo"),
+    (551899641, "Vivo"),
+    (551899642, "Vivo"),
+    (551899643, "Vivo"),
+    (551899644, "Vivo"),
+    (551899645, "Vivo"),
+    (551899646, "Vivo"),
+    (551899647, "Vivo"),
+    (551899648, "Vivo"),
+    (551899649, "Vivo"),
+    (551899651, "Vivo"),
+    (551899652, "Vivo"),
+    (551899653, "Vivo"),
+    (551899654, "Vivo"),
+    (551899655, "Vivo"),
+    (551899656, "Vivo"),
+    (551899657, "Vivo"),
+    (551899658, "Vivo"),
+    (551899659, "Vivo"),
+    (551899661, "Vivo"),
+    (551899662, "Vivo"),
+    (551899663, "Vivo"),
+    (551899664, "Vivo"),
+    (551899665, "Vivo"),
+    (551899666, "Vivo"),
+    (551899667, "Vivo"),
+    (551899668, "Vivo"),
+    (551899669, "Vivo"),
+    (551899671, "Vivo"),
+    (551899672, "Vivo"),
+    (551899673, "Vivo"),
+    (551899674, "Vivo"),
+    (551899675, "Vivo"),
+    (551899676, "Vivo"),
+    (551899677, "Vivo"),
+    (551899678, "Vivo"),
+    (551899679, "Vivo"),
+    (551899681, "Vivo"),
+    (551899682, "Vivo"),
+    (551899683, "Vivo"),
+    (551899684, "Vivo"),
+    (551899685, "Vivo"),
+    (551899686, "Vivo"),
+    (551899687, "Vivo"),
+    (551899701, "Vivo"),
+    (551899702, "Vivo"),
+    (551899703, "Vivo"),
+    (551899704, "Vivo"),
+    (551899705, "Vivo"),
+    (551899706, "Vivo"),
+    (551899707, "Vivo"),
+    (551899708, "Vivo"),
+    (551899709, "Vivo"),
+    (551899711, "Vivo"),
+    (551899712, "Vivo"),
+    (551899713, "Vivo"),
+    (551899714, "Vivo"),
+    (551899715, "Vivo"),
+    (551899716, "Vivo"),
+    (551899717, "Vivo"),
+    (551899718, "Vivo"),
+    (551899719, "Vivo"),
+    (551899721, "Vivo"),
+    (551899722, "Vivo"),
+    (551899723, "Vivo"),
+    (551899724, "Vivo"),
+    (551899725, "Vivo"),
+    (551899726, "Vivo"),
+    (551899727, "Vivo"),
+    (551899728, "Vivo"),
+    (551899729, "Vivo"),
+    (551899731, "Vivo"),
+    (551899732, "Vivo"),
+    (551899733, "Vivo"),
+    (551899734, "Vivo"),
+    (551899735, "Vivo"),
+    (551899736, "Vivo"),
+    (551899737, "Vivo"),
+    (551899738, "Vivo"),
+    (551899739, "Vivo"),
+    (551899741, "Vivo"),
+    (551899742, "Vivo"),
+    (551899743, "Vivo"),
+    (551899744, "Vivo"),
+    (551899745, "Vivo"),
+    (551899746, "Vivo"),
+    (551899747, "Vivo"),
+    (551899748, "Vivo"),
+    (551899749, "Vivo"),
+    (551899751, "Vivo"),
+    (551899752, "Vivo"),
+    (551899753, "Vivo"),
+    (551899754, "Vivo"),
+    (551899755, "Vivo"),
+    (551899756, "Vivo"),
+    (551899757, "Vivo"),
+    (551899758, "Vivo"),
+    (551899759, "Vivo"),
+    (551899761, "Vivo"),
+    (551899762, "Vivo"),
+    (551899763, "Vivo"),
+    (551899764, "Vivo"),
+    (551899765, "Vivo"),
+    (551899766, "Vivo"),
+    (551899767, "Vivo"),
+    (551899768, "Vivo"),
+    (551899771, "Vivo"),
+    (551899772, "Vivo"),
+    (551899773, "Vivo"),
+    (551899774, "Vivo"),
+    (551899775, "Vivo"),
+    (551899776, "Vivo"),
+    (551899777, "Vivo"),
+    (551899778, "Vivo"),
+    (551899779, "Vivo"),
+    (551899791, "Vivo"),
+    (551899792, "Vivo"),
+    (551899793, "Vivo"),
+    (551899794, "Vivo"),
+    (551899795, "Vivo"),
+    (551899796, "Vivo"),
+    (551899797, "Vivo"),
+    (551899798, "Vivo"),
+    (551899799, "Vivo"),
+    (551998201, "TIM"),
+    (551998202, "TIM"),
+    (551998203, "TIM"),
+    (551998204, "TIM"),
+    (551998205, "TIM"),
+    (551998206, "TIM"),
+    (551998207, "TIM"),
+    (551998208, "TIM"),
+    (551998209, "TIM"),
+    (551998211, "TIM"),
+    (551998212, "TIM"),
+    (551998213, "TIM"),
+    (551998214, "TIM"),
+    (551998215, "TIM"),
+    (551998216, "TIM"),
+    (551998217, "TIM"),
+    (551998218, "TIM"),
+    (551998219, "TIM"),
+    (551998221, "TIM"),
+    (551998222, "TIM"),
+    (551998223, "TIM"),
+    (551998224, "TIM"),
+    (551998225, "TIM"),
+    (551998226, "TIM"),
+    (551998227, "TIM"),
+    (551998229, "TIM"),
+    (551999500, "Claro"),
+    (551999501, "Claro"),
+    (551999502, "Claro"),
+    (551999503, "Claro"),
+    (551999504, "Claro"),
+    (551999505, "Claro"),
+    (551999506, "Claro"),
+    (551999507, "Claro"),
+    (551999508, "Claro"),
+    (551999601, "Vivo"),
+    (551999602, "Vivo"),
+    (551999603, "Vivo"),
+    (551999604, "Vivo"),
+    (551999605, "Vivo"),
+    (551999606, "Vivo"),
+    (551999607, "Vivo"),
+    (551999608, "Vivo"),
+    (551999609, "Vivo"),
+    (551999621, "Vivo"),
+    (551999622, "Vivo"),
+    (551999623, "Vivo"),
+    (551999624, "Vivo"),
+    (551999625, "Vivo"),
+    (551999626, "Vivo"),
+    (551999627, "Vivo"),
+    (551999628, "Vivo"),
+    (551999629, "Vivo"),
+    (551999631, "Vivo"),
+    (551999632, "Vivo"),
+    (551999633, "Vivo"),
+    (551999634, "Vivo"),
+    (551999635, "Vivo"),
+    (551999636, "Vivo"),
+    (551999637, "Vivo"),
+    (551999638, "Vivo"),
+    (551999639, "Vivo"),
+    (551999641, "Vivo"),
+    (551999642, "Vivo"),
+    (551999643, "Vivo"),
+    (551999644, "Vivo"),
+    (551999645, "Vivo"),
+    (551999646, "Vivo"),
+    (551999647, "Vivo"),
+    (551999648, "Vivo"),
+    (551999649, "Vivo"),
+    (551999651, "Vivo"),
+    (551999652, "Vivo"),
+    (551999653, "Vivo"),
+    (551999654, "Vivo"),
+    (551999655, "Vivo"),
+    (551999656, "Vivo"),
+    (551999657, "Vivo"),
+    (551999658, "Vivo"),
+    (551999659, "Vivo"),
+    (551999661, "Vivo"),
+    (551999662, "Vivo"),
+    (551999663, "Vivo"),
+    (551999664, "Vivo"),
+    (551999665, "Vivo"),
+    (551999666, "Vivo"),
+    (551999667, "Vivo"),
+    (551999668, "Vivo"),
+    (551999669, "Vivo"),
+    (551999671, "Vivo"),
+    (551999672, "Vivo"),
+    (551999673, "Vivo"),
+    (551999674, "Vivo"),
+    (551999675, "Vivo"),
+    (551999676, "Vivo"),
+    (551999677, "Vivo"),
+    (551999678, "Vivo"),
+    (551999679, "Vivo"),
+    (551999681, "Vivo"),
+    (551999682, "Vivo"),
+    (551999683, "Vivo"),
+    (551999684, "Vivo"),
+    (551999685, "Vivo"),
+    (551999686, "Vivo"),
+    (551999687, "Vivo"),
+    (551999688, "Vivo"),
+    (551999689, "Vivo"),
+    (551999691, "Vivo"),
+    (551999692, "Vivo"),
+    (551999693, "Vivo"),
+    (551999694, "Vivo"),
+    (551999695, "Vivo"),
+    (551999696, "Vivo"),
+    (551999697, "Vivo"),
+    (551999698, "Vivo"),
+    (551999699, "Vivo"),
+    (551999751, "Vivo"),
+    (551999752, "Vivo"),
+    (551999753, "Vivo"),
+    (551999754, "Vivo"),
+    (551999755, "Vivo"),
+    (551999756, "Vivo"),
+    (551999757, "Vivo"),
+    (551999758, "Vivo"),
+    (551999759, "Vivo"),
+    (551999761, "Vivo"),
+    (551999762, "Vivo"),
+    (551999763, "Vivo"),
+    (551999764, "Vivo"),
+    (551999765, "Vivo"),
+    (551999766, "Vivo"),
+    (551999767, "Vivo"),
+    (551999768, "Vivo"),
+    (551999769, "Vivo"),
+    (551999771, "Vivo"),
+    (551999772, "Vivo"),
+    (551999773, "Vivo"),
+    (551999774, "Vivo"),
+    (551999775, "Vivo"),
+    (551999776, "Vivo"),
+    (551999777, "Vivo"),
+    (551999778, "Vivo"),
+    (551999779, "Vivo"),
+    (552197370, "Claro"),
+    (552197371, "Claro"),
+    (552197372, "Claro"),
+    (552197373, "Claro"),
+    (552198301, "TIM"),
+    (552198302, "TIM"),
+    (552198303, "TIM"),
+    (552198304, "TIM"),
+    (552198305, "TIM"),
+    (552198306, "TIM"),
+    (552198307, "TIM"),
+    (552198308, "TIM"),
+    (552198309, "TIM"),
+    (552198311, "TIM"),
+    (552198312, "TIM"),
+    (552198313, "TIM"),
+    (552198314, "TIM"),
+    (552198315, "TIM"),
+    (552198316, "TIM"),
+    (552198317, "TIM"),
+    (552198318, "TIM"),
+    (552198319, "TIM"),
+    (552198321, "TIM"),
+    (552198322, "TIM"),
+    (552198323, "TIM"),
+    (552198324, "TIM"),
+    (552198325, "TIM"),
+    (552198326, "TIM"),
+    (552198327, "TIM"),
+    (552198328, "TIM"),
+    (552198329, "TIM"),
+    (552198331, "TIM"),
+    (552198332, "TIM"),
+    (552198333, "TIM"),
+    (552198334, "TIM"),
+    (552198335, "TIM"),
+    (552198336, "TIM"),
+    (552198337, "TIM"),
+    (552198338, "TIM"),
+    (552198339, "TIM"),
+    (552198341, "TIM"),
+    (552198342, "TIM"),
+    (552198343, "TIM"),
+    (552198344, "TIM"),
+    (552198345, "TIM"),
+    (552198346, "TIM"),
+    (552198347, "TIM"),
+    (552198348, "TIM"),
+    (552198349, "TIM"),
+    (552198351, "TIM"),
+    (552198352, "TIM"),
+    (552198353, "TIM"),
+    (552198354, "TIM"),
+    (552198355, "TIM"),
+    (552198356, "TIM"),
+    (552198357, "TIM"),
+    (552198358, "TIM"),
+    (552198359, "TIM"),
+    (552198361, "TIM"),
+    (552198362, "TIM"),
+    (552198363, "TIM"),
+    (552198364, "TIM"),
+    (552198365, "TIM"),
+    (552198366, "TIM"),
+    (552198367, "TIM"),
+    (552198368, "TIM"),
+    (552198369, "TIM"),
+    (552198371, "TIM"),
+    (552198372, "TIM"),
+    (552198373, "TIM"),
+    (552198374, "TIM"),
+    (552198375, "TIM"),
+    (552198376, "TIM"),
+    (552198377, "TIM"),
+    (552198378, "TIM"),
+    (552198379, "TIM"),
+    (552198381, "TIM"),
+    (552198382, "TIM"),
+    (552198383, "TIM"),
+    (552198384, "TIM"),
+    (552198385, "TIM"),
+    (552198386, "TIM"),
+    (552198401, "Oi"),
+    (552198402, "Oi"),
+    (552198403, "Oi"),
+    (552198404, "Oi"),
+    (552198405, "Oi"),
+    (552198406, "Oi"),
+    (552198407, "Oi"),
+    (552198408, "Oi"),
+    (552198409, "Oi"),
+    (552198411, "Oi"),
+    (552198412, "Oi"),
+    (552198413, "Oi"),
+    (552198414, "Oi"),
+    (552198415, "Oi"),
+    (552198416, "Oi"),
+    (552198417, "Oi"),
+    (552198418, "Oi"),
+    (552198419, "Oi"),
+    (552298111, "TIM"),
+    (552298112, "TIM"),
+    (552298113, "TIM"),
+    (552298114, "TIM"),
+    (552298115, "TIM"),
+    (552298116, "TIM"),
+    (552298117, "TIM"),
+    (552298118, "TIM"),
+    (552298119, "TIM"),
+    (552298121, "TIM"),
+    (552298122, "TIM"),
+    (552298123, "TIM"),
+    (552298124, "TIM"),
+    (552298125, "TIM"),
+    (552298126, "TIM"),
+    (552298127, "TIM"),
+    (552298128, "TIM"),
+    (552298129, "TIM"),
+    (552298131, "TIM"),
+    (552298132, "TIM"),
+    (552298133, "TIM"),
+    (552298134, "TIM"),
+    (552298135, "TIM"),
+    (552298136, "TIM"),
+    (552298137, "TIM"),
+    (552298138, "TIM"),
+    (552298139, "TIM"),
+    (552298141, "TIM"),
+    (552298142, "TIM"),
+    (552298143, "TIM"),
+    (552298144, "TIM"),
+    (552298145, "TIM"),
+    (552298146, "TIM"),
+    (552298147, "TIM"),
+    (552298148, "TIM"),
+    (552298149, "TIM"),
+    (552298151, "TIM"),
+    (552299101, "Claro"),
+    (552299102, "Claro"),
+    (552299103, "Claro"),
+    (552299104, "Claro"),
+    (552299105, "Claro"),
+    (552299201, "Claro"),
+    (552299202, "Claro"),
+    (552299203, "Claro"),
+    (552299204, "Claro"),
+    (552299205, "Claro"),
+    (552299206, "Claro"),
+    (552299207, "Claro"),
+    (552299208, "Claro"),
+    (552299209, "Claro"),
+    (552299211, "Claro"),
+    (552299212, "Claro"),
+    (552299213, "Claro"),
+    (552299214, "Claro"),
+    (552299215, "Claro"),
+    (552299216, "Claro"),
+    (552299217, "Claro"),
+    (552299218, "Claro"),
+    (552299219, "Claro"),
+    (552299221, "Claro"),
+    (552299222, "Claro"),
+    (552299223, "Claro"),
+    (552299224, "Claro"),
+    (552299225, "Claro"),
+    (552299226, "Claro"),
+    (552299227, "Claro"),
+    (552299228, "Claro"),
+    (552299229, "Claro"),
+    (552299231, "Claro"),
+    (552299232, "Claro"),
+    (552299233, "Claro"),
+    (552299234, "Claro"),
+    (552299235, "Claro"),
+    (552299236, "Claro"),
+    (552299237, "Claro"),
+    (552299238, "Claro"),
+    (552299239, "Claro"),
+    (552299241, "Claro"),
+    (552299242, "Claro"),
+    (552299243, "Claro"),
+    (552299244, "Claro"),
+    (552299245, "Claro"),
+    (552299246, "Claro"),
+    (552299247, "Claro"),
+    (552299248, "Claro"),
+    (552299249, "Claro"),
+    (552299251, "Claro"),
+    (552299252, "Claro"),
+    (552299253, "Claro"),
+    (552299254, "Claro"),
+    (552299255, "Claro"),
+    (552299256, "Claro"),
+    (552299257, "Claro"),
+    (552299258, "Claro"),
+    (552299259, "Claro"),
+    (552299261, "Claro"),
+    (552299262, "Claro"),
+    (552299263, "Claro"),
+    (552299264, "Claro"),
+    (552299265, "Claro"),
+    (552299266, "Claro"),
+    (552299267, "Claro"),
+    (552299268, "Claro"),
+    (552299269, "Claro"),
+    (552299271, "Claro"),
+    (552299272, "Claro"),
+    (552299273, "Claro"),
+    (552299274, "Claro"),
+    (552299275, "Claro"),
+    (552299276, "Claro"),
+    (552299277, "Claro"),
+    (552299278, "Claro"),
+    (552299279, "Claro"),
+    (552299281, "Claro"),
+    (552299282, "Claro"),
+    (552299283, "Claro"),
+    (552299284, "Claro"),
+    (552299285, "Claro"),
+    (552299286, "Claro"),
+    (552299287, "Claro"),
+    (552299288, "Claro"),
+    (552299289, "Claro"),
+    (552299760, "Vivo"),
+    (552299761, "Vivo"),
+    (552299762, "Vivo"),
+    (552299763, "Vivo"),
+    (552299764, "Vivo"),
+    (552299765, "Vivo"),
+    (552299766, "Vivo"),
+    (552299767, "Vivo"),
+    (552498111, "TIM"),
+    (552498112, "TIM"),
+    (552498113, "TIM"),
+    (552498114, "TIM"),
+    (552498115, "TIM"),
+    (552498116, "TIM"),
+    (552498117, "TIM"),
+    (552498118, "TIM"),
+    (552498119, "TIM"),
+    (552498121, "TIM"),
+    (552498122, "TIM"),
+    (552498123, "TIM"),
+    (552498124, "TIM"),
+    (552498125, "TIM"),
+    (552498126, "TIM"),
+    (552498127, "TIM"),
+    (552498128, "TIM"),
+    (552498129, "TIM"),
+    (552498131, "TIM"),
+    (552498132, "TIM"),
+    (552498133, "TIM"),
+    (552498134, "TIM"),
+    (552498135, "TIM"),
+    (552498136, "TIM"),
+    (552498137, "TIM"),
+    (552498138, "TIM"),
+    (552498139, "TIM"),
+    (552498141, "TIM"),
+    (552498142, "TIM"),
+    (552498143, "TIM"),
+    (552498144, "TIM"),
+    (552498145, "TIM"),
+    (552498182, "TIM"),
+    (552499280, "Claro"),
+    (552499281, "Claro"),
+    (552499282, "Claro"),
+    (552499291, "Claro"),
+    (552499292, "Claro"),
+    (552499293, "Claro"),
+    (552499294, "Claro"),
+    (552499295, "Claro"),
+    (552499296, "Claro"),
+    (552499297, "Claro"),
+    (552499298, "Claro"),
+    (552499299, "Claro"),
+    (552499301, "Claro"),
+    (552499395, "Claro"),
+    (552499920, "Vivo"),
+    (552499921, "Vivo"),
+    (552499922, "Vivo"),
+    (552499923, "Vivo"),
+    (552499924, "Vivo"),
+    (552499925, "Vivo"),
+    (552798111, "TIM"),
+    (552798112, "TIM"),
+    (552798113, "TIM"),
+    (552798114, "TIM"),
+    (552798115, "TIM"),
+    (552798116, "TIM"),
+    (552798117, "TIM"),
+    (552798118, "TIM"),
+    (552798119, "TIM"),
+    (552798121, "TIM"),
+    (552798122, "TIM"),
+    (552798123, "TIM"),
+    (552798124, "TIM"),
+    (552798125, "TIM"),
+    (552798126, "TIM"),
+    (552798127, "TIM"),
+    (552798128, "TIM"),
+    (552798129, "TIM"),
+    (552798131, "TIM"),
+    (552798132, "TIM"),
+    (552798133, "TIM"),
+    (552798134, "TIM"),
+    (552798135, "TIM"),
+    (552798136, "TIM"),
+    (552798137, "TIM"),
+    (552798138, "TIM"),
+    (552798139, "TIM"),
+    (552798141, "TIM"),
+    (552798142, "TIM"),
+    (552798143, "TIM"),
+    (552798144, "TIM"),
+    (552798145, "TIM"),
+    (552798146, "TIM"),
+    (552798147, "TIM"),
+    (552798148, "TIM"),
+    (552798149, "TIM"),
+    (552798151, "TIM"),
+    (552798152, "TIM"),
+    (552798153, "TIM"),
+    (552798154, "TIM"),
+    (552798155, "TIM"),
+    (552798156, "TIM"),
+    (552798157, "TIM"),
+    (552798158, "TIM"),
+    (552798159, "TIM"),
+    (552798161, "TIM"),
+    (552798162, "TIM"),
+    (552798163, "TIM"),
+    (552798164, "TIM"),
+    (552798165, "TIM"),
+    (552798166, "TIM"),
+    (552798167, "TIM"),
+    (552798168, "TIM"),
+    (552798169, "TIM"),
+    (552798171, "TIM"),
+    (552798172, "TIM"),
+    (552798173, "TIM"),
+    (552798174, "TIM"),
+    (552798175, "TIM"),
+    (552798176, "TIM"),
+    (552798177, "TIM"),
+    (552798178, "TIM"),
+    (552798182, "TIM"),
+    (552799201, "Claro"),
+    (552799202, "Claro"),
+    (552799203, "Claro"),
+    (552799204, "Claro"),
+    (552799205, "Claro"),
+    (552799222, "Claro"),
+    (552799223, "Claro"),
+    (552799224, "Claro"),
+    (552799225, "Claro"),
+    (552799226, "Claro"),
+    (552799227, "Claro"),
+    (552799228, "Claro"),
+    (552799229, "Claro"),
+    (552799231, "Claro"),
+    (552799232, "Claro"),
+    (552799233, "Claro"),
+    (552799234, "Claro"),
+    (552799235, "Claro"),
+    (552799236, "Claro"),
+    (552799237, "Claro"),
+    (552799238, "Claro"),
+    (552799239, "Claro"),
+    (552799241, "Claro"),
+    (552799242, "Claro"),
+    (552799243, "Claro"),
+    (552799244, "Claro"),
+    (552799245, "Claro"),
+    (552799246, "Claro"),
+    (552799247, "Claro"),
+    (552799248, "Claro"),
+    (552799249, "Claro"),
+    (552799251, "Claro"),
+    (552799252, "Claro"),
+    (552799253, "Claro"),
+    (552799254, "Claro"),
+    (552799255, "Claro"),
+    (552799256, "Claro"),
+    (552799257, "Claro"),
+    (552799258, "Claro"),
+    (552799259, "Claro"),
+    (552799261, "Claro"),
+    (552799262, "Claro"),
+    (552799263, "Claro"),
+    (552799264, "Claro"),
+    (552799265, "Claro"),
+    (552799266, "Claro"),
+    (552799267, "Claro"),
+    (552799268, "Claro"),
+    (552799269, "Claro"),
+    (552799271, "Claro"),
+    (552799272, "Claro"),
+    (552799273, "Claro"),
+    (552799274, "Claro"),
+    (552799275, "Claro"),
+    (552799276, "Claro"),
+    (552799277, "Claro"),
+    (552799278, "Claro"),
+    (552799279, "Claro"),
+    (552799281, "Claro"),
+    (552799282, "Claro"),
+    (552799283, "Claro"),
+    (552799284, "Claro"),
+    (552799285, "Claro"),
+    (552799286, "Claro"),
+    (552799287, "Claro"),
+    (552799288, "Claro"),
+    (552799289, "Claro"),
+    (552799291, "Claro"),
+    (552799292, "Claro"),
+    (552799293, "Claro"),
+    (552799294, "Claro"),
+    (552799295, "Claro"),
+    (552799296, "Claro"),
+    (552799297, "Claro"),
+    (552799298, "Claro"),
+    (552799299, "Claro"),
+    (552799309, "Claro"),
+    (552799311, "Claro"),
+    (552799312, "Claro"),
+    (552799316, "Claro"),
+    (552799650, "Vivo"),
+    (552799651, "Vivo"),
+    (552799652, "Vivo"),
+    (552799653, "Vivo"),
+    (552898111, "TIM"),
+    (552898112, "TIM"),
+    (552898113, "TIM"),
+    (552898114, "TIM"),
+    (552898115, "TIM"),
+    (552898116, "TIM"),
+    (552898117, "TIM"),
+    (552898118, "TIM"),
+    (552898119, "TIM"),
+    (552899210, "Claro"),
+    (552899222, "Claro"),
+    (552899251, "Claro"),
+    (552899252, "Claro"),
+    (552899253, "Claro"),
+    (552899254, "Claro"),
+    (552899255, "Claro"),
+    (552899256, "Claro"),
+    (552899257, "Claro"),
+    (552899258, "Claro"),
+    (552899271, "Claro"),
+    (552899272, "Claro"),
+    (552899273, "Claro"),
+    (552899274, "Claro"),
+    (552899275, "Claro"),
+    (552899276, "Claro"),
+    (552899277, "Claro"),
+    (552899278, "Claro"),
+    (552899279, "Claro"),
+    (552899291, "Claro"),
+    (552899298, "Claro"),
+    (552899881, "Vivo"),
+    (552899882, "Vivo"),
+    (552899883, "Vivo"),
+    (552899884, "Vivo"),
+    (552899885, "Vivo"),
+    (552899886, "Vivo"),
+    (552899901, "Vivo"),
+    (552899902, "Vivo"),
+    (552899903, "Vivo"),
+    (552899904, "Vivo"),
+    (552899905, "Vivo"),
+    (552899915, "Vivo"),
+    (552899916, "Vivo"),
+    (552899917, "Vivo"),
+    (552899918, "Vivo"),
+    (552899919, "Vivo"),
+    (552899921, "Vivo"),
+    (552899922, "Vivo"),
+    (552899923, "Vivo"),
+    (552899924, "Vivo"),
+    (552899925, "Vivo"),
+    (552899926, "Vivo"),
+    (552899935, "Vivo"),
+    (552899938, "Vivo"),
+    (552899939, "Vivo"),
+    (552899945, "Vivo"),
+    (552899946, "Vivo"),
+    (552899951, "Vivo"),
+    (552899952, "Vivo"),
+    (552899953, "Vivo"),
+    (552899954, "Vivo"),
+    (552899955, "Vivo"),
+    (552899956, "Vivo"),
+    (552899957, "Vivo"),
+    (552899958, "Vivo"),
+    (552899959, "Vivo"),
+    (552899961, "Vivo"),
+    (552899962, "Vivo"),
+    (552899963, "Vivo"),
+    (552899964, "Vivo"),
+    (552899965, "Vivo"),
+    (552899966, "Vivo"),
+    (552899967, "Vivo"),
+    (552899968, "Vivo"),
+    (552899969, "Vivo"),
+    (552899971, "Vivo"),
+    (552899972, "Vivo"),
+    (552899973, "Vivo"),
+    (552899974, "Vivo"),
+    (552899975, "Vivo"),
+    (552899976, "Vivo"),
+    (552899977, "Vivo"),
+    (552899978, "Vivo"),
+    (552899979, "Vivo"),
+    (552899981, "Vivo"),
+    (552899982, "Vivo"),
+    (552899983, "Vivo"),
+    (552899984, "Vivo"),
+    (552899985, "Vivo"),
+    (552899986, "Vivo"),
+    (552899987, "Vivo"),
+    (552899988, "Vivo"),
+    (552899989, "Vivo"),
+    (552899991, "Vivo"),
+    (552899992, "Vivo"),
+    (552899993, "Vivo"),
+    (552899994, "Vivo"),
+    (552899995, "Vivo"),
+    (552899996, "Vivo"),
+    (552899997, "Vivo"),
+    (552899998, "Vivo"),
+    (553198240, "Claro"),
+    (553198241, "Claro"),
+    (553198242, "Claro"),
+    (553198243, "Claro"),
+    (553198244, "Claro"),
+    (553198245, "Claro"),
+    (553199101, "TIM"),
+    (553199102, "TIM"),
+    (553199103, "TIM"),
+    (553199104, "TIM"),
+    (553199105, "TIM"),
+    (553199106, "TIM"),
+    (553199107, "TIM"),
+    (553199108, "TIM"),
+    (553199109, "TIM"),
+    (553199161, "TIM"),
+    (553199162, "TIM"),
+    (553199163, "TIM"),
+    (553199164, "TIM"),
+    (553199165, "TIM"),
+    (553199166, "TIM"),
+    (553199167, "TIM"),
+    (553199168, "TIM"),
+    (553199169, "TIM"),
+    (553199171, "TIM"),
+    (553199172, "TIM"),
+    (553199173, "TIM"),
+    (553199174, "TIM"),
+    (553199175, "TIM"),
+    (553199176, "TIM"),
+    (553199177, "TIM"),
+    (553199178, "TIM"),
+    (553199179, "TIM"),
+    (553199181, "TIM"),
+    (553199182, "TIM"),
+    (553199183, "TIM"),
+    (553199184, "TIM"),
+    (553199185, "TIM"),
+    (553199186, "TIM"),
+    (553199187, "TIM"),
+    (553199188, "TIM"),
+    (553199189, "TIM"),
+    (553199191, "TIM"),
+    (553199192, "TIM"),
+    (553199193, "TIM"),
+    (553199194, "TIM"),
+    (553199195, "TIM"),
+    (553199196, "TIM"),
+    (553199197, "TIM"),
+    (553199198, "TIM"),
+    (553199199, "TIM"),
+    (553199401, "TIM"),
+    (553199402, "TIM"),
+    (553199403, "TIM"),
+    (553199404, "TIM"),
+    (553199405, "TIM"),
+    (553199406, "TIM"),
+    (553199407, "TIM"),
+    (553199408, "TIM"),
+    (553199409, "TIM"),
+    (553199411, "TIM"),
+    (553199412, "TIM"),
+    (553199413, "TIM"),
+    (553199414, "TIM"),
+    (553199415, "TIM"),
+    (553199416, "TIM"),
+    (553199601, "Telemig Celular"),
+    (553199602, "Telemig Celular"),
+    (553199603, "Telemig Celular"),
+    (553199604, "Telemig Celular"),
+    (553199605, "Telemig Celular"),
+    (553199606, "Telemig Celular"),
+    (553199607, "Telemig Celular"),
+    (553199608, "Telemig Celular"),
+    (553199609, "Telemig Celular"),
+    (553199611, "Telemig Celular"),
+    (553199612, "Telemig Celular"),
+    (553199613, "Telemig Celular"),
+    (553199614, "Telemig Celular"),
+    (553199615, "Telemig Celular"),
+    (553199616, "Telemig Celular"),
+    (553199617, "Telemig Celular"),
+    (553199618, "Telemig Celular"),
+    (553199619, "Telemig Celular"),
+    (553199621, "Telemig Celular"),
+    (553199622, "Telemig Celular"),
+    (553199624, "Telemig Celular"),
+    (553199625, "Telemig Celular"),
+    (553199626, "Telemig Celular"),
+    (553199627, "Telemig Celular"),
+    (553199628, "Telemig Celular"),
+    (553199629, "Telemig Celular"),
+    (553199631, "Telemig Celular"),
+    (553199632, "Telemig Celular"),
+    (553199633, "Telemig Celular"),
+    (553199634, "Telemig Celular"),
+    (553199635, "Telemig Celular"),
+    (553199636, "Telemig Celular"),
+    (553199637, "Telemig Celular"),
+    (553199638, "Telemig Celular"),
+    (553199639, "Telemig Celular"),
+    (553199641, "Telemig Celular"),
+    (553199642, "Telemig Celular"),
+    (553199643, "Telemig Celular"),
+    (553199644, "Telemig Celular"),
+    (553199645, "Telemig Celular"),
+    (553199646, "Telemig Celular"),
+    (553199647, "Telemig Celular"),
+    (553199648, "Telemig Celular"),
+    (553199649, "Telemig Celular"),
+    (553199651, "Telemig Celular"),
+    (553199652, "Telemig Celular"),
+    (553199653, "Telemig Celular"),
+    (553199654, "Telemig Celular"),
+    (553199655, "Telemig Celular"),
+    (553199656, "Telemig Celular"),
+    (553199657, "Telemig Celular"),
+    (553199658, "Telemig Celular"),
+    (553199659, "Telemig Celular"),
+    (553199661, "Telemig Celular"),
+    (553199662, "Telemig Celular"),
+    (553199663, "Telemig Celular"),
+    (553199664, "Telemig Celular"),
+    (553199665, "Telemig Celular"),
+    (553199666, "Telemig Celular"),
+    (553199667, "Telemig Celular"),
+    (553199668, "Telemig Celular"),
+    (553199669, "Telemig Celular"),
+    (553199671, "Telemig Celular"),
+    (553199672, "Telemig Celular"),
+    (553199673, "Telemig Celular"),
+    (553199674, "Telemig Celular"),
+    (553199675, "Telemig Celular"),
+    (553199676, "Telemig Celular"),
+    (553199677, "Telemig Celular"),
+    (553199678, "Telemig Celular"),
+    (553199679, "Telemig Celular"),
+    (553199681, "Telemig Celular"),
+    (553199682, "Telemig Celular"),
+    (553199683, "Telemig Celular"),
+    (553199684, "Telemig Celular"),
+    (553199685, "Telemig Celular"),
+    (553199686, "Telemig Celular"),
+    (553199687, "Telemig Celular"),
+    (553199688, "Telemig Celular"),
+    (553199689, "Telemig Celular"),
+    (553199691, "Telemig Celular"),
+    (553199692, "Telemig Celular"),
+    (553199693, "Telemig Celular"),
+    (553199694, "Telemig Celular"),
+    (553199695, "Telemig Celular"),
+    (553199696, "Telemig Celular"),
+    (553199697, "Telemig Celular"),
+    (553199698, "Telemig Celular"),
+    (553199699, "Telemig Celular"),
+    (553199701, "Telemig Celular"),
+    (553199702, "Telemig Celular"),
+    (553199703, "Telemig Celular"),
+    (553199704, "Telemig Celular"),
+    (553199705, "Telemig Celular"),
+    (553199706, "Telemig Celular"),
+    (553199707, "Telemig Celular"),
+    (553199708, "Telemig Celular"),
+    (553199709, "Telemig Celular"),
+    (553199711, "Telemig Celular"),
+    (553199712, "Telemig Celular"),
+    (553199713, "Telemig Celular"),
+    (553199714, "Telemig Celular"),
+    (553199715, "Telemig Celular"),
+    (553199717, "Telemig Celular"),
+    (553199718, "Telemig Celular"),
+    (553199719, "Telemig Celular"),
+    (553199721, "Telemig Celular"),
+    (553199722, "Telemig Celular"),
+    (553199723, "Telemig Celular"),
+    (553199724, "Telemig Celular"),
+    (553199725, "Telemig Celular"),
+    (553199726, "Telemig Celular"),
+    (553199728, "Telemig Celular"),
+    (553199729, "Telemig Celular"),
+    (553199731, "Telemig Celular"),
+    (553199732, "Telemig Celular"),
+    (553199733, "Telemig Celular"),
+    (553199734, "Telemig Celular"),
+    (553199735, "Telemig Celular"),
+    (553199736, "Telemig Celular"),
+    (553199737, "Telemig Celular"),
+    (553199738, "Telemig Celular"),
+    (553199739, "Telemig Celular"),
+    (553199741, "Telemig Celular"),
+    (553199742, "Telemig Celular"),
+    (553199743, "Telemig Celular"),
+    (553199744, "Telemig Celular"),
+    (553199745, "Telemig Celular"),
+    (553199746, "Telemig Celular"),
+    (553199747, "Telemig Celular"),
+    (553199748, "Telemig Celular"),
+    (553199749, "Telemig Celular"),
+    (553199751, "Telemig Celular"),
+    (553199752, "Telemig Celular"),
+    (553199753, "Telemig Celular"),
+    (553199755, "Telemig Celular"),
+    (553199756, "Telemig Celular"),
+    (553199757, "Telemig Celular"),
+    (553199758, "Telemig Celular"),
+    (553199759, "Telemig Celular"),
+    (553199761, "Telemig Celular"),
+    (553199762, "Telemig Celular"),
+    (553199763, "Telemig Celular"),
+    (553199764, "Telemig Celular"),
+    (553199765, "Telemig Celular"),
+    (553199766, "Telemig Celular"),
+    (553199767, "Telemig Celular"),
+    (553199768, "Telemig Celular"),
+    (553199769, "Telemig Celular"),
+    (553199771, "Telemig Celular"),
+    (553199772, "Telemig Celular"),
+    (553199773, "Telemig Celular"),
+    (553199774, "Telemig Celular"),
+    (553199775, "Telemig Celular"),
+    (553199776, "Telemig Celular"),
+    (553199777, "Telemig Celular"),
+    (553199778, "Telemig Celular"),
+    (553199779, "Telemig Celular"),
+    (553199781, "Telemig Celular"),
+    (553199782, "Telemig Celular"),
+    (553199783, "Telemig Celular"),
+    (553199784, "Telemig Celular"),
+    (553199785, "Telemig Celular"),
+    (553199786, "Telemig Celular"),
+    (553199787, "Telemig Celular"),
+    (553199788, "Telemig Celular"),
+    (553199789, "Telemig Celular"),
+    (553199791, "Telemig Celular"),
+    (553199792, "Telemig Celular"),
+    (553199793, "Telemig Celular"),
+    (553199794, "Telemig Celular"),
+    (553199795, "Telemig Celular"),
+    (553199796, "Telemig Celular"),
+    (553199797, "Telemig Celular"),
+    (553199798, "Telemig Celular"),
+    (553199799, "Telemig Celular"),
+    (553199800, "TIM"),
+    (553199810, "TIM"),
+    (553199820, "TIM"),
+    (553199830, "TIM"),
+    (553199840, "TIM"),
+    (553199850, "TIM"),
+    (553199860, "TIM"),
+    (553199870, "TIM"),
+    (553199880, "TIM"),
+    (553199890, "TIM"),
+    (553199901, "Telemig Celular"),
+    (553199902, "Telemig Celular"),
+    (553199903, "Telemig Celular"),
+    (553199904, "Telemig Celular"),
+    (553199905, "Telemig Celular"),
+    (553199906, "Telemig Celular"),
+    (553199907, "Telemig Celular"),
+    (553199908, "Telemig Celular"),
+    (553199909, "Telemig Celular"),
+    (553199911, "Telemig Celular"),
+    (553199912, "Telemig Celular"),
+    (553199913, "Telemig Celular"),
+    (553199914, "Telemig Celular"),
+    (553199915, "Telemig Celular"),
+    (553199916, "Telemig Celular"),
+    (553199917, "Telemig Celular"),
+    (553199918, "Telemig Celular"),
+    (553199919, "Telemig Celular"),
+    (553199921, "Telemig Celular"),
+    (553199922, "Telemig Celular"),
+    (553199923, "Telemig Celular"),
+    (553199924, "Telemig Celular"),
+    (553199925, "Telemig Celular"),
+    (553199926, "Telemig Celular"),
+    (553199927, "Telemig Celular"),
+    (553199928, "Telemig Celular"),
+    (553199929, "Telemig Celular"),
+    (553199931, "Telemig Celular"),
+    (553199932, "Telemig Celular"),
+    (553199933, "Telemig Celular"),
+    (553199934, "Telemig Celular"),
+    (553199935, "Telemig Celular"),
+    (553199936, "Telemig Celular"),
+    (553199937, "Telemig Celular"),
+    (553199938, "Telemig Celular"),
+    (553199939, "Telemig Celular"),
+    (553199941, "Telemig Celular"),
+    (553199942, "Telemig Celular"),
+    (553199943, "Telemig Celular"),
+    (553199944, "Telemig Celular"),
+    (553199945, "Telemig Celular"),
+    (553199946, "Telemig Celular"),
+    (553199947, "Telemig Celular"),
+    (553199948, "Telemig Celular"),
+    (553199949, "Telemig Celular"),
+    (553298480, "Claro"),
+    (553298481, "Claro"),
+    (553298482, "Claro"),
+    (553298483, "Claro"),
+    (553298484, "Claro"),
+    (553298485, "Claro"),
+    (553299101, "TIM"),
+    (553299102, "TIM"),
+    (553299103, "TIM"),
+    (553299104, "TIM"),
+    (553299105, "TIM"),
+    (553299106, "TIM"),
+    (553299107, "TIM"),
+    (553299108, "TIM"),
+    (553299109, "TIM"),
+    (553299111, "TIM"),
+    (553299112, "TIM"),
+    (553299113, "TIM"),
+    (553299114, "TIM"),
+    (553299115, "TIM"),
+    (553299116, "TIM"),
+    (553299117, "TIM"),
+    (553299118, "TIM"),
+    (553299119, "TIM"),
+    (553299121, "TIM"),
+    (553299122, "TIM"),
+    (553299123, "TIM"),
+    (553299124, "TIM"),
+    (553299125, "TIM"),
+    (553299126, "TIM"),
+    (553299127, "TIM"),
+    (553299128, "TIM"),
+    (553299129, "TIM"),
+    (553299131, "TIM"),
+    (553299132, "TIM"),
+    (553299133, "TIM"),
+    (553299134, "TIM"),
+    (553299135, "TIM"),
+    (553299136, "TIM"),
+    (553299137, "TIM"),
+    (553299138, "TIM"),
+    (553299139, "TIM"),
+    (553299141, "TIM"),
+    (553299142, "TIM"),
+    (553299143, "TIM"),
+    (553299144, "TIM"),
+    (553299145, "TIM"),
+    (553299146, "TIM"),
+    (553299193, "TIM"),
+    (553299194, "TIM"),
+    (553299195, "TIM"),
+    (553299197, "TIM"),
+    (553299198, "TIM"),
+    (553299199, "TIM"),
+    (553299901, "Telemig Celular"),
+    (553299902, "Telemig Celular"),
+    (553299903, "Telemig Celular"),
+    (553299904, "Telemig Celular"),
+    (553299905, "Telemig Celular"),
+    (553299906, "Telemig Celular"),
+    (553299907, "Telemig Celular"),
+    (553299908, "Telemig Celular"),
+    (553299909, "Telemig Celular"),
+    (553299911, "Telemig Celular"),
+    (553299912, "Telemig Celular"),
+    (553299913, "Telemig Celular"),
+    (553299914, "Telemig Celular"),
+    (553299917, "Telemig Celular"),
+    (553299918, "Telemig Celular"),
+    (553299919, "Telemig Celular"),
+    (553299921, "Telemig Celular"),
+    (553299922, "Telemig Celular"),
+    (553299923, "Telemig Celular"),
+    (553299924, "Telemig Celular"),
+    (553299925, "Telemig Celular"),
+    (553299931, "Telemig Celular"),
+    (553299932, "Telemig Celular"),
+    (553299933, "Telemig Celular"),
+    (553299934, "Telemig Celular"),
+    (553299935, "Telemig Celular"),
+    (553299936, "Telemig Celular"),
+    (553299937, "Telemig Celular"),
+    (553299938, "Telemig Celular"),
+    (553299939, "Telemig Celular"),
+    (553299941, "Telemig Celular"),
+    (553299942, "Telemig Celular"),
+    (553299943, "Telemig Celular"),
+    (553299944, "Telemig Celular"),
+    (553299945, "Telemig Celular"),
+    (553299946, "Telemig Celular"),
+    (553299947, "Telemig Celular"),
+    (553299948, "Telemig Celular"),
+    (553299949, "Telemig Celular"),
+    (553299951, "Telemig Celular"),
+    (553299952, "Telemig Celular"),
+    (553299953, "Telemig Celular"),
+    (553299954, "Telemig Celular"),
+    (553299955, "Telemig Celular"),
+    (553299956, "Telemig Celular"),
+    (553299957, "Telemig Celular"),
+    (553299958, "Telemig Celular"),
+    (553299959, "Telemig Celular"),
+    (553299971, "Telemig Celular"),
+    (553299972, "Telemig Celular"),
+    (553299973, "Telemig Celular"),
+    (553299974, "Telemig Celular"),
+    (553299975, "Telemig Celular"),
+    (553299976, "Telemig Celular"),
+    (553299977, "Telemig Celular"),
+    (553299979, "Telemig Celular"),
+    (553299991, "Telemig Celular"),
+    (553299992, "Telemig Celular"),
+    (553299993, "Telemig Celular"),
+    (553299994, "Telemig Celular"),
+    (553299995, "Telemig Celular"),
+    (553299996, "Telemig Celular"),
+    (553299997, "Telemig Celular"),
+    (553299998, "Telemig Celular"),
+    (553398401, "Claro"),
+    (553398402, "Claro"),
+    (553398403, "Claro"),
+    (553398404, "Claro"),
+    (553398405, "Claro"),
+    (553398406, "Claro"),
+    (553398407, "Claro"),
+    (553398408, "Claro"),
+    (553398409, "Claro"),
+    (553398411, "Claro"),
+    (553398412, "Claro"),
+    (553398413, "Claro"),
+    (553398414, "Claro"),
+    (553398415, "Claro"),
+    (553398416, "Claro"),
+    (553398417, "Claro"),
+    (553398418, "Claro"),
+    (553398419, "Claro"),
+    (553398421, "Claro"),
+    (553398422, "Claro"),
+    (553398423, "Claro"),
+    (553398424, "Claro"),
+    (553398425, "Claro"),
+    (553398426, "Claro"),
+    (553398427, "Claro"),
+    (553398428, "Claro"),
+    (553398429, "Claro"),
+    (553398431, "Claro"),
+    (553398432, "Claro"),
+    (553398433, "Claro"),
+    (553398434, "Claro"),
+    (553398435, "Claro"),
+    (553398436, "Claro"),
+    (553398437, "Claro"),
+    (553398438, "Claro"),
+    (553398439, "Claro"),
+    (553398441, "Claro"),
+    (553398442, "Claro"),
+    (553398443, "Claro"),
+    (553398444, "Claro"),
+    (553398445, "Claro"),
+    (553398446, "Claro"),
+    (553398447, "Claro"),
+    (553398448, "Claro"),
+    (553398449, "Claro"),
+    (553398451, "Claro"),
+    (553398452, "Claro"),
+    (553398453, "Claro"),
+    (553398454, "Claro"),
+    (553398455, "Claro"),
+    (553398456, "Claro"),
+    (553399101, "TIM"),
+    (553399102, "TIM"),
+    (553399103, "TIM"),
+    (553399104, "TIM"),
+    (553399105, "TIM"),
+    (553399106, "TIM"),
+    (553399107, "TIM"),
+    (553399108, "TIM"),
+    (553399109, "TIM"),
+    (553399111, "TIM"),
+    (553399112, "TIM"),
+    (553399113, "TIM"),
+    (553399114, "TIM"),
+    (553399115, "TIM"),
+    (553399116, "TIM"),
+    (553399117, "TIM"),
+    (553399118, "TIM"),
+    (553399119, "TIM"),
+    (553399121, "TIM"),
+    (553399122, "TIM"),
+    (553399123, "TIM"),
+    (553399124, "TIM"),
+    (553399125, "TIM"),
+    (553399126, "TIM"),
+    (553399127, "TIM"),
+    (553399128, "TIM"),
+    (553399129, "TIM"),
+    (553399136, "TIM"),
+    (553399137, "TIM"),
+    (553399138, "TIM"),
+    (553399139, "TIM"),
+    (553399168, "TIM"),
+    (553399191, "TIM"),
+    (553399193, "TIM"),
+    (553399197, "TIM"),
+    (553399198, "TIM"),
+    (553399199, "TIM"),
+    (553399901, "Telemig Celular"),
+    (553399902, "Telemig Celular"),
+    (553399903, "Telemig Celular"),
+    (553399904, "Telemig Celular"),
+    (553399905, "Telemig Celular"),
+    (553399906, "Telemig Celular"),
+    (553399907, "Telemig Celular"),
+    (553399908, "Telemig Celular"),
+    (553399909, "Telemig Celular"),
+    (553399911, "Telemig Celular"),
+    (553399912, "Telemig Celular"),
+    (553399913, "Telemig Celular"),
+    (553399914, "Telemig Celular"),
+    (553399915, "Telemig Celular"),
+    (553399916, "Telemig Celular"),
+    (553399917, "Telemig Celular"),
+    (553399918, "Telemig Celular"),
+    (553399919, "Telemig Celular"),
+    (553399921, "Telemig Celular"),
+    (553399922, "Telemig Celular"),
+    (553399933, "Telemig Celular"),
+    (553399951, "Telemig Celular"),
+    (553399952, "Telemig Celular"),
+    (553399953, "Telemig Celular"),
+    (553399954, "Telemig Celular"),
+    (553399955, "Telemig Celular"),
+    (553399956, "Telemig Celular"),
+    (553399957, "Telemig Celular"),
+    (553399958, "Telemig Celular"),
+    (553399959, "Telemig Celular"),
+    (553399961, "Telemig Celular"),
+    (553399962, "Telemig Celular"),
+    (553399963, "Telemig Celular"),
+    (553399964, "Telemig Celular"),
+    (553399965, "Telemig Celular"),
+    (553399966, "Telemig Celular"),
+    (553399967, "Telemig Celular"),
+    (553399968, "Telemig Celular"),
+    (553399969, "Telemig Celular"),
+    (553399971, "Telemig Celular"),
+    (553399972, "Telemig Celular"),
+    (553399973, "Telemig Celular"),
+    (553399974, "Telemig Celular"),
+    (553399975, "Telemig Celular"),
+    (553399976, "Telemig Celular"),
+    (553399977, "Telemig Celular"),
+    (553399978, "Telemig Celular"),
+    (553399979, "Telemig Celular"),
+    (553498420, "Claro"),
+    (553498421, "Claro"),
+    (553498422, "Claro"),
+    (553499101, "TIM"),
+    (553499102, "TIM"),
+    (553499103, "TIM"),
+    (553499104, "TIM"),
+    (553499105, "TIM"),
+    (553499106, "TIM"),
+    (553499107, "TIM"),
+    (553499108, "TIM"),
+    (553499109, "TIM"),
+    (553499181, "TIM"),
+    (553499182, "TIM"),
+    (553499183, "TIM"),
+    (553499184, "TIM"),
+    (553499185, "TIM"),
+    (553499186, "TIM"),
+    (553499187, "TIM"),
+    (553499188, "TIM"),
+    (553499189, "TIM"),
+    (553499191, "TIM"),
+    (553499192, "TIM"),
+    (553499193, "TIM"),
+    (553499194, "TIM"),
+    (553499195, "TIM"),
+    (553499196, "TIM"),
+    (553499197, "TIM"),
+    (553499198, "TIM"),
+    (553499199, "TIM"),
+    (553499202, "TIM"),
+    (553499203, "TIM"),
+    (553499204, "TIM"),
+    (553499205, "TIM"),
+    (553499206, "TIM"),
+    (553499207, "TIM"),
+    (553499208, "TIM"),
+    (553499209, "TIM"),
+    (553499211, "TIM"),
+    (553499212, "TIM"),
+    (553499213, "TIM"),
+    (553499214, "TIM"),
+    (553499215, "TIM"),
+    (553499216, "TIM"),
+    (553499217, "TIM"),
+    (553499218, "TIM"),
+    (553499229, "TIM"),
+    (553499801, "Telemig Celular"),
+    (553499802, "Telemig Celular"),
+    (553499803, "Telemig Celular"),
+    (553499804, "Telemig Celular"),
+    (553499805, "Telemig Celular"),
+    (553499806, "Telemig Celular"),
+    (553499807, "Telemig Celular"),
+    (553499808, "Telemig Celular"),
+    (553499809, "Telemig Celular"),
+    (553499811, "Telemig Celular"),
+    (553499812, "Telemig Celular"),
+    (553499813, "Telemig Celular"),
+    (553499814, "Telemig Celular"),
+    (553499815, "Telemig Celular"),
+    (553499816, "Telemig Celular"),
+    (553499817, "Telemig Celular"),
+    (553499821, "Telemig Celular"),
+    (553499822, "Telemig Celular"),
+    (553499823, "Telemig Celular"),
+    (553499824, "Telemig Celular"),
+    (553499825, "Telemig Celular"),
+    (553499901, "Telemig Celular"),
+    (553499902, "Telemig Celular"),
+    (553499903, "Telemig Celular"),
+    (553499904, "Telemig Celular"),
+    (553499905, "Telemig Celular"),
+    (553499906, "Telemig Celular"),
+    (553499907, "Telemig Celular"),
+    (553499908, "Telemig Celular"),
+    (553499909, "Telemig Celular"),
+    (553499911, "Telemig Celular"),
+    (553499912, "Telemig Celular"),
+    (553499913, "Telemig Celular"),
+    (553499914, "Telemig Celular"),
+    (553499915, "Telemig Celular"),
+    (553499916, "Telemig Celular"),
+    (553499917, "Telemig Celular"),
+    (553499918, "Telemig Celular"),
+    (553499919, "Telemig Celular"),
+    (553499921, "Telemig Celular"),
+    (553499922, "Telemig Celular"),
+    (553499923, "Telemig Celular"),
+    (553499924, "Telemig Celular"),
+    (553499925, "Telemig Celular"),
+    (553499926, "Telemig Celular"),
+    (553499927, "Telemig Celular"),
+    (553499928, "Telemig Celular"),
+    (553499929, "Telemig Celular"),
+    (553499931, "Telemig Celular"),
+    (553499932, "Telemig Celular"),
+    (553499933, "Telemig Celular"),
+    (553499934, "Telemig Celular"),
+    (553499935, "Telemig Celular"),
+    (553499936, "Telemig Celular"),
+    (553499937, "Telemig Celular"),
+    (553499938, "Telemig Celular"),
+    (553499939, "Telemig Celular"),
+    (553499941, "Telemig Celular"),
+    (553499942, "Telemig Celular"),
+    (553499943, "Telemig Celular"),
+    (553499944, "Telemig Celular"),
+    (553499945, "Telemig Celular"),
+    (553499946, "Telemig Celular"),
+    (553499947, "Telemig Celular"),
+    (553499948, "Telemig Celular"),
+    (553499949, "Telemig Celular"),
+    (553499951, "Telemig Celular"),
+    (553499952, "Telemig Celular"),
+    (553499953, "Telemig Celular"),
+    (553499954, "Telemig Celular"),
+    (553499955, "Telemig Celular"),
+    (553499956, "Telemig Celular"),
+    (553499957, "Telemig Celular"),
+    (553499958, "Telemig Celular"),
+    (553499959, "Telemig Celular"),
+    (553499981, "Telemig Celular"),
+    (553499982, "Telemig Celular"),
+    (553499983, "Telemig Celular"),
+    (553499984, "Telemig Celular"),
+    (553499985, "Telemig Celular"),
+    (553499986, "Telemig Celular"),
+    (553499987, "Telemig Celular"),
+    (553499988, "Telemig Celular"),
+    (553499989, "Telemig Celular"),
+    (553598401, "Claro"),
+    (553598402, "Claro"),
+    (553598403, "Claro"),
+    (553598404, "Claro"),
+    (553598405, "Claro"),
+    (553598406, "Claro"),
+    (553598407, "Claro"),
+    (553598408, "Claro"),
+    (553598409, "Claro"),
+    (553598411, "Claro"),
+    (553598412, "Claro"),
+    (553598413, "Claro"),
+    (553598414, "Claro"),
+    (553598415, "Claro"),
+    (553598416, "Claro"),
+    (553598417, "Claro"),
+    (553598418, "Claro"),
+    (553598419, "Claro"),
+    (553598421, "Claro"),
+    (553598422, "Claro"),
+    (553598423, "Claro"),
+    (553598424, "Claro"),
+    (553598425, "Claro"),
+    (553598426, "Claro"),
+    (553598427, "Claro"),
+    (553598428, "Claro"),
+    (553598429, "Claro"),
+    (553598431, "Claro"),
+    (553598432, "Claro"),
+    (553598433, "Claro"),
+    (553598434, "Claro"),
+    (553598435, "Claro"),
+    (553598436, "Claro"),
+    (553598437, "Claro"),
+    (553598438, "Claro"),
+    (553598439, "Claro"),
+    (553598441, "Claro"),
+    (553598442, "Claro"),
+    (553598443, "Claro"),
+    (553598444, "Claro"),
+    (553598445, "Claro"),
+    (553598446, "Claro"),
+    (553598447, "Claro"),
+    (553598448, "Claro"),
+    (553598449, "Claro"),
+    (553598451, "Claro"),
+    (553598452, "Claro"),
+    (553598453, "Claro"),
+    (553598454, "Claro"),
+    (553598455, "Claro"),
+    (553598456, "Claro"),
+    (553598457, "Claro"),
+    (553598458, "Claro"),
+    (553598459, "Claro"),
+    (553598461, "Claro"),
+    (553598462, "Claro"),
+    (553598463, "Claro"),
+    (553598464, "Claro"),
+    (553598465, "Claro"),
+    (553598466, "Claro"),
+    (553598467, "Claro"),
+    (553598468, "Claro"),
+    (553598469, "Claro"),
+    (553598471, "Claro"),
+    (553598472, "Claro"),
+    (553598473, "Claro"),
+    (553598474, "Claro"),
+    (553598475, "Claro"),
+    (553598476, "Claro"),
+    (553598477, "Claro"),
+    (553598478, "Claro"),
+    (553598479, "Claro"),
+    (553599101, "TIM"),
+    (553599102, "TIM"),
+    (553599103, "TIM"),
+    (553599104, "TIM"),
+    (553599105, "TIM"),
+    (553599106, "TIM"),
+    (553599107, "TIM"),
+    (553599108, "TIM"),
+    (553599109, "TIM"),
+    (553599111, "TIM"),
+    (553599112, "TIM"),
+    (553599113, "TIM"),
+    (553599114, "TIM"),
+    (553599115, "TIM"),
+    (553599116, "TIM"),
+    (553599117, "TIM"),
+    (553599118, "TIM"),
+    (553599119, "TIM"),
+    (553599121, "TIM"),
+    (553599122, "TIM"),
+    (553599123, "TIM"),
+    (553599124, "TIM"),
+    (553599125, "TIM"),
+    (553599126, "TIM"),
+    (553599127, "TIM"),
+    (553599128, "TIM"),
+    (553599129, "TIM"),
+    (553599131, "TIM"),
+    (553599132, "TIM"),
+    (553599133, "TIM"),
+    (553599134, "TIM"),
+    (553599135, "TIM"),
+    (553599136, "TIM"),
+    (553599137, "TIM"),
+    (553599138, "TIM"),
+    (553599139, "TIM"),
+    (553599141, "TIM"),
+    (553599142, "TIM"),
+    (553599143, "TIM"),
+    (553599144, "TIM"),
+    (553599145, "TIM"),
+    (553599146, "TIM"),
+    (553599147, "TIM"),
+    (553599148, "TIM"),
+    (553599149, "TIM"),
+    (553599151, "TIM"),
+    (553599152, "TIM"),
+    (553599153, "TIM"),
+    (553599154, "TIM"),
+    (553599155, "TIM"),
+    (553599156, "TIM"),
+    (553599157, "TIM"),
+    (553599158, "TIM"),
+    (553599159, "TIM"),
+    (553599161, "TIM"),
+    (553599162, "TIM"),
+    (553599163, "TIM"),
+    (553599164, "TIM"),
+    (553599165, "TIM"),
+    (553599166, "TIM"),
+    (553599167, "TIM"),
+    (553599168, "TIM"),
+    (553599169, "TIM"),
+    (553599171, "TIM"),
+    (553599172, "TIM"),
+    (553599173, "TIM"),
+    (553599174, "TIM"),
+    (553599175, "TIM"),
+    (553599176, "TIM"),
+    (553599177, "TIM"),
+    (553599178, "TIM"),
+    (553599179, "TIM"),
+    (553599181, "TIM"),
+    (553599187, "TIM"),
+    (553599188, "TIM"),
+    (553599191, "TIM"),
+    (553599192, "TIM"),
+    (553599193, "TIM"),
+    (553599197, "TIM"),
+    (553599198, "TIM"),
+    (553599199, "TIM"),
+    (553599801, "Telemig Celular"),
+    (553599802, "Telemig Celular"),
+    (553599803, "Telemig Celular"),
+    (553599804, "Telemig Celular"),
+    (553599805, "Telemig Celular"),
+    (553599806, "Telemig Celular"),
+    (553599807, "Telemig Celular"),
+    (553599808, "Telemig Celular"),
+    (553599809, "Telemig Celular"),
+    (553599811, "Telemig Celular"),
+    (553599812, "Telemig Celular"),
+    (553599813, "Telemig Celular"),
+    (553599814, "Telemig Celular"),
+    (553599815, "Telemig Celular"),
+    (553599816, "Telemig Celular"),
+    (553599817, "Telemig Celular"),
+    (553599818, "Telemig Celular"),
+    (553599819, "Telemig Celular"),
+    (553599821, "Telemig Celular"),
+    (553599822, "Telemig Celular"),
+    (553599823, "Telemig Celular"),
+    (553599824, "Telemig Celular"),
+    (553599825, "Telemig Celular"),
+    (553599826, "Telemig Celular"),
+    (553599827, "Telemig Celular"),
+    (553599828, "Telemig Celular"),
+    (553599829, "Telemig Celular"),
+    (553599831, "Telemig Celular"),
+    (553599832, "Telemig Celular"),
+    (553599833, "Telemig Celular"),
+    (553599834, "Telemig Celular"),
+    (553599835, "Telemig Celular"),
+    (553599836, "Telemig Celular"),
+    (553599837, "Telemig Celular"),
+    (553599838, "Telemig Celular"),
+    (553599839, "Telemig Celular"),
+    (553599841, "Telemig Celular"),
+    (553599842, "Telemig Celular"),
+    (553599843, "Telemig Celular"),
+    (553599844, "Telemig Celular"),
+    (553599845, "Telemig Celular"),
+    (553599846, "Telemig Celular"),
+    (553599901, "Telemig Celular"),
+    (553599902, "Telemig Celular"),
+    (553599903, "Telemig Celular"),
+    (553599904, "Telemig Celular"),
+    (553599905, "Telemig Celular"),
+    (553599906, "Telemig Celular"),
+    (553599907, "Telemig Celular"),
+    (553599908, "Telemig Celular"),
+    (553599911, "Telemig Celular"),
+    (553599912, "Telemig Celular"),
+    (553599913, "Telemig Celular"),
+    (553599914, "Telemig Celular"),
+    (553599915, "Telemig Celular"),
+    (553599916, "Telemig Celular"),
+    (553599917, "Telemig Celular"),
+    (553599918, "Telemig Celular"),
+    (553599919, "Telemig Celular"),
+    (553599921, "Telemig Celular"),
+    (553599922, "Telemig Celular"),
+    (553599923, "Telemig Celular"),
+    (553599924, "Telemig Celular"),
+    (553599925, "Telemig Celular"),
+    (553599926, "Telemig Celular"),
+    (553599927, "Telemig Celular"),
+    (553599928, "Telemig Celular"),
+    (553599929, "Telemig Celular"),
+    (553599931, "Telemig Celular"),
+    (553599932, "Telemig Celular"),
+    (553599933, "Telemig Celular"),
+    (553599934, "Telemig Celular"),
+    (553599935, "Telemig Celular"),
+    (553599936, "Telemig Celular"),
+    (553599937, "Telemig Celular"),
+    (553599938, "Telemig Celular"),
+    (553599939, "Telemig Celular"),
+    (553599941, "Telemig Celular"),
+    (553599942, "Telemig Celular"),
+    (553599943, "Telemig Celular"),
+    (553599944, "Telemig Celular"),
+    (553599945, "Telemig Celular"),
+    (553599946, "Telemig Celular"),
+    (553599947, "Telemig Celular"),
+    (553599948, "Telemig Celular"),
+    (553599949, "Telemig Celular"),
+    (553599951, "Telemig Celular"),
+    (553599952, "Telemig Celular"),
+    (553599953, "Telemig Celular"),
+    (553599954, "Telemig Celular"),
+    (553599955, "Telemig Celular"),
+    (553599956, "Telemig Celular"),
+    (553599957, "Telemig Celular"),
+    (553599958, "Telemig Celular"),
+    (553599959, "Telemig Celular"),
+    (553599992, "Telemig Celular"),
+    (553599993, "Telemig Celular"),
+    (553599994, "Telemig Celular"),
+    (553599995, "Telemig Celular"),
+    (553599996, "Telemig Celular"),
+    (553599997, "Telemig Celular"),
+    (553798401, "Claro"),
+    (553798402, "Claro"),
+    (553798403, "Claro"),
+    (553798404, "Claro"),
+    (553798405, "Claro"),
+    (553798406, "Claro"),
+    (553798407, "Claro"),
+    (553798408, "Claro"),
+    (553798409, "Claro"),
+    (553798411, "Claro"),
+    (553798412, "Claro"),
+    (553798413, "Claro"),
+    (553798414, "Claro"),
+    (553798415, "Claro"),
+    (553798416, "Claro"),
+    (553798417, "Claro"),
+    (553798418, "Claro"),
+    (553798419, "Claro"),
+    (553798421, "Claro"),
+    (553798422, "Claro"),
+    (553798423, "Claro"),
+    (553798424, "Claro"),
+    (553798425, "Claro"),
+    (553798426, "Claro"),
+    (553799101, "TIM"),
+    (553799102, "TIM"),
+    (553799103, "TIM"),
+    (553799104, "TIM"),
+    (553799105, "TIM"),
+    (553799106, "TIM"),
+    (553799107, "TIM"),
+    (553799108, "TIM"),
+    (553799109, "TIM"),
+    (553799111, "TIM"),
+    (553799112, "TIM"),
+    (553799113, "TIM"),
+    (553799114, "TIM"),
+    (553799115, "TIM"),
+    (553799116, "TIM"),
+    (553799117, "TIM"),
+    (553799118, "TIM"),
+    (553799119, "TIM"),
+    (553799121, "TIM"),
+    (553799122, "TIM"),
+    (553799123, "TIM"),
+    (553799124, "TIM"),
+    (553799125, "TIM"),
+    (553799126, "TIM"),
+    (553799127, "TIM"),
+    (553799128, "TIM"),
+    (553799129, "TIM"),
+    (553799131, "TIM"),
+    (553799132, "TIM"),
+    (553799133, "TIM"),
+    (553799134, "TIM"),
+    (553799135, "TIM"),
+    (553799136, "TIM"),
+    (553799137, "TIM"),
+    (553799138, "TIM"),
+    (553799139, "TIM"),
+    (553799141, "TIM"),
+    (553799143, "TIM"),
+    (553799144, "TIM"),
+    (553799145, "TIM"),
+    (553799146, "TIM"),
+    (553799147, "TIM"),
+    (553799154, "TIM"),
+    (553799186, "TIM"),
+    (553799191, "TIM"),
+    (553799192, "TIM"),
+    (553799193, "TIM"),
+    (553799194, "TIM"),
+    (553799197, "TIM"),
+    (553799198, "TIM"),
+    (553799199, "TIM"),
+    (553799801, "Telemig Celular"),
+    (553799802, "Telemig Celular"),
+    (553799803, "Telemig Celular"),
+    (553799804, "Telemig Celular"),
+    (553799805, "Telemig Celular"),
+    (553799901, "Telemig Celular"),
+    (553799902, "Telemig Celular"),
+    (553799903, "Telemig Celular"),
+    (553799904, "Telemig Celular"),
+    (553799905, "Telemig Celular"),
+    (553799906, "Telemig Celular"),
+    (553799907, "Telemig Celular"),
+    (553799908, "Telemig Celular"),
+    (553799909, "Telemig Celular"),
+    (553799911, "Telemig Celular"),
+    (553799912, "Telemig Celular"),
+    (553799913, "Telemig Celular"),
+    (553799914, "Telemig Celular"),
+    (553799915, "Telemig Celular"),
+    (553799916, "Telemig Celular"),
+    (553799917, "Telemig Celular"),
+    (553799918, "Telemig Celular"),
+    (553799919, "Telemig Celular"),
+    (553799921, "Telemig Celular"),
+    (553799922, "Telemig Celular"),
+    (553799923, "Telemig Celular"),
+    (553799924, "Telemig Celular"),
+    (553799925, "Telemig Celular"),
+    (553799926, "Telemig Celular"),
+    (553799927, "Telemig Celular"),
+    (553799928, "Telemig Celular"),
+    (553799929, "Telemig Celular"),
+    (553799931, "Telemig Celular"),
+    (553799932, "Telemig Celular"),
+    (553799933, "Telemig Celular"),
+    (553799934, "Telemig Celular"),
+    (553799935, "Telemig Celular"),
+    (553799936, "Telemig Celular"),
+    (553799937, "Telemig Celular"),
+    (553799938, "Telemig Celular"),
+    (553799939, "Telemig Celular"),
+    (553799941, "Telemig Celular"),
+    (553799942, "Telemig Celular"),
+    (553799943, "Telemig Celular"),
+    (553799944, "Telemig Celular"),
+    (553799945, "Telemig Celular"),
+    (553799946, "Telemig Celular"),
+    (553799947, "Telemig Celular"),
+    (553799948, "Telemig Celular"),
+    (553799949, "Telemig Celular"),
+    (553799951, "Telemig Celular"),
+    (553799952, "Telemig Celular"),
+    (553799953, "Telemig Celular"),
+    (553799954, "Telemig Celular"),
+    (553799955, "Telemig Celular"),
+    (553799956, "Telemig Celular"),
+    (553799957, "Telemig Celular"),
+    (553799958, "Telemig Celular"),
+    (553799959, "Telemig Celular"),
+    (553799961, "Telemig Celular"),
+    (553799962, "Telemig Celular"),
+    (553799963, "Telemig Celular"),
+    (553799964, "Telemig Celular"),
+    (553799965, "Telemig Celular"),
+    (553799966, "Telemig Celular"),
+    (553799967, "Telemig Celular"),
+    (553799968, "Telemig Celular"),
+    (553799969, "Telemig Celular"),
+    (553799991, "Telemig Celular"),
+    (553799992, "Telemig Celular"),
+    (553799993, "Telemig Celular"),
+    (553799994, "Telemig Celular"),
+    (553799995, "Telemig Celular"),
+    (553799996, "Telemig Celular"),
+    (553799997, "Telemig Celular"),
+    (553799998, "Telemig Celular"),
+    (553799999, "Telemig Celular"),
+    (553898401, "Claro"),
+    (553898402, "Claro"),
+    (553898403, "Claro"),
+    (553898404, "Claro"),
+    (553898405, "Claro"),
+    (553898406, "Claro"),
+    (553898407, "Claro"),
+    (553898408, "Claro"),
+    (553898409, "Claro"),
+    (553898411, "Claro"),
+    (553898412, "Claro"),
+    (553898413, "Claro"),
+    (553898414, "Claro"),
+    (553898415, "Claro"),
+    (553898416, "Claro"),
+    (553898417, "Claro"),
+    (553898418, "Claro"),
+    (553898419, "Claro"),
+    (553898421, "Claro"),
+    (553898422, "Claro"),
+    (553898423, "Claro"),
+    (553898424, "Claro"),
+    (553898425, "Claro"),
+    (553898426, "Claro"),
+    (553898427, "Claro"),
+    (553898428, "Claro"),
+    (553898429, "Claro"),
+    (553898431, "Claro"),
+    (553898432, "Claro"),
+    (553898433, "Claro"),
+    (553898434, "Claro"),
+    (553898435, "Claro"),
+    (553898436, "Claro"),
+    (553898437, "Claro"),
+    (553899101, "TIM"),
+    (553899102, "TIM"),
+    (553899103, "TIM"),
+    (553899104, "TIM"),
+    (553899105, "TIM"),
+    (553899106, "TIM"),
+    (553899107, "TIM"),
+    (553899108, "TIM"),
+    (553899109, "TIM"),
+    (553899111, "TIM"),
+    (553899112, "TIM"),
+    (553899113, "TIM"),
+    (553899114, "TIM"),
+    (553899115, "TIM"),
+    (553899116, "TIM"),
+    (553899117, "TIM"),
+    (553899118, "TIM"),
+    (553899119, "TIM"),
+    (553899121, "TIM"),
+    (553899122, "TIM"),
+    (553899123, "TIM"),
+    (553899124, "TIM"),
+    (553899125, "TIM"),
+    (553899126, "TIM"),
+    (553899127, "TIM"),
+    (553899128, "TIM"),
+    (553899129, "TIM"),
+    (553899131, "TIM"),
+    (553899132, "TIM"),
+    (553899133, "TIM"),
+    (553899134, "TIM"),
+    (553899135, "TIM"),
+    (553899136, "TIM"),
+    (553899137, "TIM"),
+    (553899138, "TIM"),
+    (553899139, "TIM"),
+    (553899141, "TIM"),
+    (553899142, "TIM"),
+    (553899143, "TIM"),
+    (553899144, "TIM"),
+    (553899145, "TIM"),
+    (553899146, "TIM"),
+    (553899147, "TIM"),
+    (553899148, "TIM"),
+    (553899149, "TIM"),
+    (553899151, "TIM"),
+    (553899152, "TIM"),
+    (553899153, "TIM"),
+    (553899154, "TIM"),
+    (553899155, "TIM"),
+    (553899156, "TIM"),
+    (553899157, "TIM"),
+    (553899158, "TIM"),
+    (553899159, "TIM"),
+    (553899161, "TIM"),
+    (553899162, "TIM"),
+    (553899163, "TIM"),
+    (553899164, "TIM"),
+    (553899165, "TIM"),
+    (553899166, "TIM"),
+    (553899167, "TIM"),
+    (553899168, "TIM"),
+    (553899169, "TIM"),
+    (553899171, "TIM"),
+    (553899172, "TIM"),
+    (553899173, "TIM"),
+    (553899174, "TIM"),
+    (553899175, "TIM"),
+    (553899176, "TIM"),
+    (553899177, "TIM"),
+    (553899178, "TIM"),
+    (553899179, "TIM"),
+    (553899181, "TIM"),
+    (553899182, "TIM"),
+    (553899183, "TIM"),
+    (553899184, "TIM"),
+    (553899191, "TIM"),
+    (553899192, "TIM"),
+    (553899193, "TIM"),
+    (553899194, "TIM"),
+    (553899195, "TIM"),
+    (553899196, "TIM"),
+    (553899197, "TIM"),
+    (553899801, "Telemig Celular"),
+    (553899802, "Telemig Celular"),
+    (553899803, "Telemig Celular"),
+    (553899804, "Telemig Celular"),
+    (553899805, "Telemig Celular"),
+    (553899806, "Telemig Celular"),
+    (553899807, "Telemig Celular"),
+    (553899808, "Telemig Celular"),
+    (553899809, "Telemig Celular"),
+    (553899810, "Telemig Celular"),
+    (553899901, "Telemig Celular"),
+    (553899902, "Telemig Celular"),
+    (553899903, "Telemig Celular"),
+    (553899904, "Telemig Celular"),
+    (553899905, "Telemig Celular"),
+    (553899906, "Telemig Celular"),
+    (553899907, "Telemig Celular"),
+    (553899908, "Telemig Celular"),
+    (553899909, "Telemig Celular"),
+    (553899990, "Telemig Celular"),
+    (553899991, "Telemig Celular"),
+    (553899992, "Telemig Celular"),
+    (553899993, "Telemig Celular"),
+    (553899995, "Telemig Celular"),
+    (553899996, "Telemig Celular"),
+    (553899997, "Telemig Celular"),
+    (553899998, "Telemig Celular"),
+    (553899999, "Telemig Celular"),
+    (554198401, "Brasil Telecom GSM"),
+    (554198402, "Brasil Telecom GSM"),
+    (554198403, "Brasil Telecom GSM"),
+    (554198404, "Brasil Telecom GSM"),
+    (554198405, "Brasil Telecom GSM"),
+    (554198406, "Brasil Telecom GSM"),
+    (554198407, "Brasil Telecom GSM"),
+    (554198408, "Brasil Telecom GSM"),
+    (554198409, "Brasil Telecom GSM"),
+    (554198411, "Brasil Telecom GSM"),
+    (554198412, "Brasil Telecom GSM"),
+    (554198413, "Brasil Telecom GSM"),
+    (554198414, "Brasil Telecom GSM"),
+    (554198415, "Brasil Telecom GSM"),
+    (554198416, "Brasil Telecom GSM"),
+    (554198417, "Brasil Telecom GSM"),
+    (554198418, "Brasil Telecom GSM"),
+    (554198419, "Brasil Telecom GSM"),
+    (554198421, "Brasil Telecom GSM"),
+    (554198422, "Brasil Telecom GSM"),
+    (554198423, "Brasil Telecom GSM"),
+    (554198424, "Brasil Telecom GSM"),
+    (554198425, "Brasil Telecom GSM"),
+    (554198426, "Brasil Telecom GSM"),
+    (554198427, "Brasil Telecom GSM"),
+    (554198428, "Brasil Telecom GSM"),
+    (554198429, "Brasil Telecom GSM"),
+    (554198431, "Brasil Telecom GSM"),
+    (554198432, "Brasil Telecom GSM"),
+    (554198433, "Brasil Telecom GSM"),
+    (554198434, "Brasil Telecom GSM"),
+    (554198435, "Brasil Telecom GSM"),
+    (554198436, "Brasil Telecom GSM"),
+    (554198437, "Brasil Telecom GSM"),
+    (554198438, "Brasil Telecom GSM"),
+    (554198439, "Brasil Telecom GSM"),
+    (554198441, "Brasil Telecom GSM"),
+    (554198442, "Brasil Telecom GSM"),
+    (554198443, "Brasil Telecom GSM"),
+    (554198444, "Brasil Telecom GSM"),
+    (554198445, "Brasil Telecom GSM"),
+    (554198446, "Brasil Telecom GSM"),
+    (554198447, "Brasil Telecom GSM"),
+    (554198448, "Brasil Telecom GSM"),
+    (554198449, "Brasil Telecom GSM"),
+    (554198451, "Brasil Telecom GSM"),
+    (554198452, "Brasil Telecom GSM"),
+    (554198453, "Brasil Telecom GSM"),
+    (554198454, "Brasil Telecom GSM"),
+    (554198455, "Brasil Telecom GSM"),
+    (554198456, "Brasil Telecom GSM"),
+    (554198457, "Brasil Telecom GSM"),
+    (554198458, "Brasil Telecom GSM"),
+    (554198459, "Brasil Telecom GSM"),
+    (554198461, "Brasil Telecom GSM"),
+    (554198462, "Brasil Telecom GSM"),
+    (554198463, "Brasil Telecom GSM"),
+    (554198464, "Brasil Telecom GSM"),
+    (554198465, "Brasil Telecom GSM"),
+    (554198466, "Brasil Telecom GSM"),
+    (554198467, "Brasil Telecom GSM"),
+    (554198468, "Brasil Telecom GSM"),
+    (554198469, "Brasil Telecom GSM"),
+    (554198471, "Brasil Telecom GSM"),
+    (554198472, "Brasil Telecom GSM"),
+    (554198473, "Brasil Telecom GSM"),
+    (554198474, "Brasil Telecom GSM"),
+    (554198475, "Brasil Telecom GSM"),
+    (554198476, "Brasil Telecom GSM"),
+    (554198477, "Brasil Telecom GSM"),
+    (554198478, "Brasil Telecom GSM"),
+    (554198750, "Claro"),
+    (554198751, "Claro"),
+    (554198752, "Claro"),
+    (554199111, "Vivo"),
+    (554199112, "Vivo"),
+    (554199113, "Vivo"),
+    (554199114, "Vivo"),
+    (554199115, "Vivo"),
+    (554199116, "Vivo"),
+    (554199117, "Vivo"),
+    (554199118, "Vivo"),
+    (554199119, "Vivo"),
+    (554199121, "Vivo"),
+    (554199122, "Vivo"),
+    (554199123, "Vivo"),
+    (554199124, "Vivo"),
+    (554199125, "Vivo"),
+    (554199126, "Vivo"),
+    (554199127, "Vivo"),
+    (554199128, "Vivo"),
+    (554199129, "Vivo"),
+    (554199131, "Vivo"),
+    (554199132, "Vivo"),
+    (554199133, "Vivo"),
+    (554199134, "Vivo"),
+    (554199135, "Vivo"),
+    (554199136, "Vivo"),
+    (554199137, "Vivo"),
+    (554199138, "Vivo"),
+    (554199139, "Vivo"),
+    (554199141, "Vivo"),
+    (554199142, "Vivo"),
+    (554199143, "Vivo"),
+    (554199144, "Vivo"),
+    (554199145, "Vivo"),
+    (554199146, "Vivo"),
+    (554199147, "Vivo"),
+    (554199148, "Vivo"),
+    (554199149, "Vivo"),
+    (554199151, "Vivo"),
+    (554199152, "Vivo"),
+    (554199153, "Vivo"),
+    (554199154, "Vivo"),
+    (554199155, "Vivo"),
+    (554199156, "Vivo"),
+    (554199157, "Vivo"),
+    (554199158, "Vivo"),
+    (554199159, "Vivo"),
+    (554199161, "Vivo"),
+    (554199162, "Vivo"),
+    (554199163, "Vivo"),
+    (554199164, "Vivo"),
+    (554199165, "Vivo"),
+    (554199166, "Vivo"),
+    (554199167, "Vivo"),
+    (554199168, "Vivo"),
+    (554199169, "Vivo"),
+    (554199171, "Vivo"),
+    (554199172, "Vivo"),
+    (554199173, "Vivo"),
+    (554199174, "Vivo"),
+    (554199175, "Vivo"),
+    (554199176, "Vivo"),
+    (554199177, "Vivo"),
+    (554199178, "Vivo"),
+    (554199179, "Vivo"),
+    (554199181, "Vivo"),
+    (554199182, "Vivo"),
+    (554199183, "Vivo"),
+    (554199184, "Vivo"),
+    (554199185, "Vivo"),
+    (554199186, "Vivo"),
+    (554199187, "Vivo"),
+    (554199188, "Vivo"),
+    (554199189, "Vivo"),
+    (554199191, "Vivo"),
+    (554199192, "Vivo"),
+    (554199193, "Vivo"),
+    (554199194, "Vivo"),
+    (554199195, "Vivo"),
+    (554199196, "Vivo"),
+    (554199197, "Vivo"),
+    (554199198, "Vivo"),
+    (554199199, "Vivo"),
+    (554199201, "Vivo"),
+    (554199202, "Vivo"),
+    (554199203, "Vivo"),
+    (554199204, "Vivo"),
+    (554199205, "Vivo"),
+    (554199206, "Vivo"),
+    (554199207, "Vivo"),
+    (554199208, "Vivo"),
+    (554199209, "Vivo"),
+    (554199211, "Vivo"),
+    (554199212, "Vivo"),
+    (554199213, "Vivo"),
+    (554199214, "Vivo"),
+    (554199215, "Vivo"),
+    (554199216, "Vivo"),
+    (554199217, "Vivo"),
+    (554199218, "Vivo"),
+    (554199219, "Vivo"),
+    (554199221, "Vivo"),
+    (554199222, "Vivo"),
+    (554199223, "Vivo"),
+    (554199224, "Vivo"),
+    (554199225, "Vivo"),
+    (554199226, "Vivo"),
+    (554199227, "Vivo"),
+    (554199228, "Vivo"),
+    (554199229, "Vivo"),
+    (554199231, "Vivo"),
+    (554199232, "Vivo"),
+    (554199233, "Vivo"),
+    (554199234, "Vivo"),
+    (554199235, "Vivo"),
+    (554199236, "Vivo"),
+    (554199237, "Vivo"),
+    (554199238, "Vivo"),
+    (554199239, "Vivo"),
+    (554199241, "Vivo"),
+    (554199242, "Vivo"),
+    (554199243, "Vivo"),
+    (554199244, "Vivo"),
+    (554199245, "Vivo"),
+    (554199246, "Vivo"),
+    (554199247, "Vivo"),
+    (554199248, "Vivo"),
+    (554199249, "Vivo"),
+    (554199251, "Vivo"),
+    (554199252, "Vivo"),
+    (554199253, "Vivo"),
+    (554199254, "Vivo"),
+    (554199255, "Vivo"),
+    (554199256, "Vivo"),
+    (554199257, "Vivo"),
+    (554199258, "Vivo"),
+    (554199259, "Vivo"),
+    (554199261, "Vivo"),
+    (554199262, "Vivo"),
+    (554199263, "Vivo"),
+    (554199264, "Vivo"),
+    (554199265, "Vivo"),
+    (554199266, "Vivo"),
+    (554199800, "TIM"),
+    (554199801, "TIM"),
+    (554199802, "TIM"),
+    (554199803, "TIM"),
+    (554199804, "TIM"),
+    (554298401, "Brasil Telecom GSM"),
+    (554298402, "Brasil Telecom GSM"),
+    (554298403, "Brasil Telecom GSM"),
+    (554298404, "Brasil Telecom GSM"),
+    (554298405, "Brasil Telecom GSM"),
+    (554298406, "Brasil Telecom GSM"),
+    (554298407, "Brasil Telecom GSM"),
+    (554298408, "Brasil Telecom GSM"),
+    (554298409, "Brasil Telecom GSM"),
+    (554298411, "Brasil Telecom GSM"),
+    (554298412, "Brasil Telecom GSM"),
+    (554298413, "Brasil Telecom GSM"),
+    (554298414, "Brasil Telecom GSM"),
+    (554298415, "Brasil Telecom GSM"),
+    (554298416, "Brasil Telecom GSM"),
+    (554298417, "Brasil Telecom GSM"),
+    (554298870, "Claro"),
+    (554298871, "Claro"),
+    (554298872, "Claro"),
+    (554298873, "Claro"),
+    (554298874, "Claro"),
+    (554299111, "Vivo"),
+    (554299112, "Vivo"),
+    (554299113, "Vivo"),
+    (554299114, "Vivo"),
+    (554299115, "Vivo"),
+    (554299116, "Vivo"),
+    (554299117, "Vivo"),
+    (554299118, "Vivo"),
+    (554299119, "Vivo"),
+    (554299121, "Vivo"),
+    (554299122, "Vivo"),
+    (554299123, "Vivo"),
+    (554299124, "Vivo"),
+    (554299125, "Vivo"),
+    (554299126, "Vivo"),
+    (554299127, "Vivo"),
+    (554299128, "Vivo"),
+    (554299129, "Vivo"),
+    (554299131, "Vivo"),
+    (554299132, "Vivo"),
+    (554299133, "Vivo"),
+    (554299134, "Vivo"),
+    (554299135, "Vivo"),
+    (554299136, "Vivo"),
+    (554299137, "Vivo"),
+    (554299138, "Vivo"),
+    (554299139, "Vivo"),
+    (554299141, "Vivo"),
+    (554299142, "Vivo"),
+    (554299143, "Vivo"),
+    (554299144, "Vivo"),
+    (554299145, "Vivo"),
+    (554299146, "Vivo"),
+    (554299147, "Vivo"),
+    (554299148, "Vivo"),
+    (554299149, "Vivo"),
+    (554299151, "Vivo"),
+    (554299152, "Vivo"),
+    (554299153, "Vivo"),
+    (554299154, "Vivo"),
+    (554299155, "Vivo"),
+    (554299156, "Vivo"),
+    (554299157, "Vivo"),
+    (554299158, "Vivo"),
+    (554299159, "Vivo"),
+    (554299161, "Vivo"),
+    (554299162, "Vivo"),
+    (554299163, "Vivo"),
+    (554299164, "Vivo"),
+    (554299165, "Vivo"),
+    (554299166, "Vivo"),
+    (554299901, "TIM"),
+    (554299902, "TIM"),
+    (554299903, "TIM"),
+    (554299904, "TIM"),
+    (554299905, "TIM"),
+    (554299906, "TIM"),
+    (554299907, "TIM"),
+    (554299908, "TIM"),
+    (554299911, "TIM"),
+    (554299912, "TIM"),
+    (554299913, "TIM"),
+    (554299914, "TIM"),
+    (554299915, "TIM"),
+    (554299916, "TIM"),
+    (554299917, "TIM"),
+    (554299918, "TIM"),
+    (554299919, "TIM"),
+    (554299921, "TIM"),
+    (554299922, "TIM"),
+    (554299923, "TIM"),
+    (554299924, "TIM"),
+    (554299925, "TIM"),
+    (554299926, "TIM"),
+    (554299927, "TIM"),
+    (554299928, "TIM"),
+    (554299929, "TIM"),
+    (554299931, "TIM"),
+    (554299932, "TIM"),
+    (554299933, "TIM"),
+    (554299934, "TIM"),
+    (554299935, "TIM"),
+    (554299936, "TIM"),
+    (554299937, "TIM"),
+    (554299938, "TIM"),
+    (554299939, "TIM"),
+    (554299941, "TIM"),
+    (554299942, "TIM"),
+    (554299943, "TIM"),
+    (554299944, "TIM"),
+    (554299945, "TIM"),
+    (554299946, "TIM"),
+    (554299947, "TIM"),
+    (554299948, "TIM"),
+    (554299949, "TIM"),
+    (554299961, "TIM"),
+    (554299962, "TIM"),
+    (554299963, "TIM"),
+    (554299964, "TIM"),
+    (554299965, "TIM"),
+    (554299966, "TIM"),
+    (554299967, "TIM"),
+    (554299969, "TIM"),
+    (554299971, "TIM"),
+    (554299972, "TIM"),
+    (554299973, "TIM"),
+    (554299974, "TIM"),
+    (554299975, "TIM"),
+    (554299976, "TIM"),
+    (554299977, "TIM"),
+    (554299978, "TIM"),
+    (554299979, "TIM"),
+    (554299981, "TIM"),
+    (554299982, "TIM"),
+    (554398111, "TIM"),
+    (554398401, "Brasil Telecom GSM"),
+    (554398402, "Brasil Telecom GSM"),
+    (554398403, "Brasil Telecom GSM"),
+    (554398404, "Brasil Telecom GSM"),
+    (554398405, "Brasil Telecom GSM"),
+    (554398406, "Brasil Telecom GSM"),
+    (554398407, "Brasil Telecom GSM"),
+    (554398408, "Brasil Telecom GSM"),
+    (554398409, "Brasil Telecom GSM"),
+    (554398411, "Brasil Telecom GSM"),
+    (554398412, "Brasil Telecom GSM"),
+    (554398413, "Brasil Telecom GSM"),
+    (554398414, "Brasil Telecom GSM"),
+    (554398415, "Brasil Telecom GSM"),
+    (554398416, "Brasil Telecom GSM"),
+    (554398417, "Brasil Telecom GSM"),
+    (554398418, "Brasil Telecom GSM"),
+    (554398419, "Brasil Telecom GSM"),
+    (554398421, "Brasil Telecom GSM"),
+    (554398422, "Brasil Telecom GSM"),
+    (554398423, "Brasil Telecom GSM"),
+    (554398424, "Brasil Telecom GSM"),
+    (554398425, "Brasil Telecom GSM"),
+    (554398426, "Brasil Telecom GSM"),
+    (554398427, "Brasil Telecom GSM"),
+    (554398428, "Brasil Telecom GSM"),
+    (554398429, "Brasil Telecom GSM"),
+    (554398431, "Brasil Telecom GSM"),
+    (554398432, "Brasil Telecom GSM"),
+    (554398433, "Brasil Telecom GSM"),
+    (554398434, "Brasil Telecom GSM"),
+    (554398435, "Brasil Telecom GSM"),
+    (554398850, "Claro"),
+    (554398851, "Claro"),
+    (554398852, "Claro"),
+    (554399111, "Vivo"),
+    (554399112, "Vivo"),
+    (554399113, "Vivo"),
+    (554399114, "Vivo"),
+    (554399115, "Vivo"),
+    (554399116, "Vivo"),
+    (554399117, "Vivo"),
+    (554399118, "Vivo"),
+    (554399119, "Vivo"),
+    (554399121, "Vivo"),
+    (554399122, "Vivo"),
+    (554399123, "Vivo"),
+    (554399124, "Vivo"),
+    (554399125, "Vivo"),
+    (554399126, "Vivo"),
+    (554399127, "Vivo"),
+    (554399128, "Vivo"),
+    (554399129, "Vivo"),
+    (554399131, "Vivo"),
+    (554399132, "Vivo"),
+    (554399133, "Vivo"),
+    (554399134, "Vivo"),
+    (554399135, "Vivo"),
+    (554399136, "Vivo"),
+    (554399137, "Vivo"),
+    (554399138, "Vivo"),
+    (554399139, "Vivo"),
+    (554399141, "Vivo"),
+    (554399142, "Vivo"),
+    (554399143, "Vivo"),
+    (554399144, "Vivo"),
+    (554399145, "Vivo"),
+    (554399146, "Vivo"),
+    (554399147, "Vivo"),
+    (554399148, "Vivo"),
+    (554399149, "Vivo"),
+    (554399151, "Vivo"),
+    (554399152, "Vivo"),
+    (554399153, "Vivo"),
+    (554399154, "Vivo"),
+    (554399155, "Vivo"),
+    (554399156, "Vivo"),
+    (554399157, "Vivo"),
+    (554399158, "Vivo"),
+    (554399159, "Vivo"),
+    (554399161, "Vivo"),
+    (554399162, "Vivo"),
+    (554399163, "Vivo"),
+    (554399164, "Vivo"),
+    (554399165, "Vivo"),
+    (554399166, "Vivo"),
+    (554399167, "Vivo"),
+    (554399168, "Vivo"),
+    (554399169, "Vivo"),
+    (554399171, "Vivo"),
+    (554399172, "Vivo"),
+    (554399173, "Vivo"),
+    (554399174, "Vivo"),
+    (554399175, "Vivo"),
+    (554399176, "Vivo"),
+    (554399177, "Vivo"),
+    (554399178, "Vivo"),
+    (554399179, "Vivo"),
+    (554399181, "Vivo"),
+    (554399182, "Vivo"),
+    (554399183, "Vivo"),
+    (554399184, "Vivo"),
+    (554399185, "Vivo"),
+    (554399186, "Vivo"),
+    (554399187, "Vivo"),
+    (554399188, "Vivo"),
+    (554399189, "Vivo"),
+    (554399191, "Vivo"),
+    (554399192, "Vivo"),
+    (554399193, "Vivo"),
+    (554399194, "Vivo"),
+    (554399195, "Vivo"),
+    (554399610, "TIM"),
+    (554399611, "TIM"),
+    (554399612, "TIM"),
+    (554498401, "Brasil Telecom GSM"),
+    (554498402, "Brasil Telecom GSM"),
+    (554498403, "Brasil Telecom GSM"),
+    (554498404, "Brasil Telecom GSM"),
+    (554498405, "Brasil Telecom GSM"),
+    (554498406, "Brasil Telecom GSM"),
+    (554498407, "Brasil Telecom GSM"),
+    (554498408, "Brasil Telecom GSM"),
+    (554498409, "Brasil Telecom GSM"),
+    (554498411, "Brasil Telecom GSM"),
+    (554498412, "Brasil Telecom GSM"),
+    (554498413, "Brasil Telecom GSM"),
+    (554498414, "Brasil Telecom GSM"),
+    (554498415, "Brasil Telecom GSM"),
+    (554498416, "Brasil Telecom GSM"),
+    (554498417, "Brasil Telecom GSM"),
+    (554498418, "Brasil Telecom GSM"),
+    (554498419, "Brasil Telecom GSM"),
+    (554498421, "Brasil Telecom GSM"),
+    (554498422, "Brasil Telecom GSM"),
+    (554498423, "Brasil Telecom GSM"),
+    (554498424, "Brasil Telecom GSM"),
+    (554498425, "Brasil Telecom GSM"),
+    (554498426, "Brasil Telecom GSM"),
+    (554498427, "Brasil Telecom GSM"),
+    (554498428, "Brasil Telecom GSM"),
+    (554498429, "Brasil Telecom GSM"),
+    (554498860, "Claro"),
+    (554499111, "Vivo"),
+    (554499112, "Vivo"),
+    (554499113, "Vivo"),
+    (554499114, "Vivo"),
+    (554499115, "Vivo"),
+    (554499116, "Vivo"),
+    (554499117, "Vivo"),
+    (554499118, "Vivo"),
+    (554499119, "Vivo"),
+    (554499121, "Vivo"),
+    (554499122, "Vivo"),
+    (554499123, "Vivo"),
+    (554499124, "Vivo"),
+    (554499125, "Vivo"),
+    (554499126, "Vivo"),
+    (554499127, "Vivo"),
+    (554499128, "Vivo"),
+    (554499129, "Vivo"),
+    (554499131, "Vivo"),
+    (554499132, "Vivo"),
+    (554499133, "Vivo"),
+    (554499134, "Vivo"),
+    (554499135, "Vivo"),
+    (554499136, "Vivo"),
+    (554499137, "Vivo"),
+    (554499138, "Vivo"),
+    (554499139, "Vivo"),
+    (554499141, "Vivo"),
+    (554499142, "Vivo"),
+    (554499143, "Vivo"),
+    (554499144, "Vivo"),
+    (554499145, "Vivo"),
+    (554499146, "Vivo"),
+    (554499147, "Vivo"),
+    (554499148, "Vivo"),
+    (554499149, "Vivo"),
+    (554499151, "Vivo"),
+    (554499152, "Vivo"),
+    (554499153, "Vivo"),
+    (554499154, "Vivo"),
+    (554499155, "Vivo"),
+    (554499156, "Vivo"),
+    (554499157, "Vivo"),
+    (554499158, "Vivo"),
+    (554499159, "Vivo"),
+    (554499161, "Vivo"),
+    (554499162, "Vivo"),
+    (554499163, "Vivo"),
+    (554499164, "Vivo"),
+    (554499165, "Vivo"),
+    (554499166, "Vivo"),
+    (554499167, "Vivo"),
+    (554499168, "Vivo"),
+    (554499169, "Vivo"),
+    (554499171, "Vivo"),
+    (554499172, "Vivo"),
+    (554499173, "Vivo"),
+    (554499174, "Vivo"),
+    (554598401, "Brasil Telecom GSM"),
+    (554598402, "Brasil Telecom GSM"),
+    (554598403, "Brasil Telecom GSM"),
+    (554598404, "Brasil Telecom GSM"),
+    (554598405, "Brasil Telecom GSM"),
+    (554598406, "Brasil Telecom GSM"),
+    (554598407, "Brasil Telecom GSM"),
+    (554598408, "Brasil Telecom GSM"),
+    (554598409, "Brasil Telecom GSM"),
+    (554598411, "Brasil Telecom GSM"),
+    (554598412, "Brasil Telecom GSM"),
+    (554598413, "Brasil Telecom GSM"),
+    (554598414, "Brasil Telecom GSM"),
+    (554598415, "Brasil Telecom GSM"),
+    (554598416, "Brasil Telecom GSM"),
+    (554598417, "Brasil Telecom GSM"),
+    (554598418, "Brasil Telecom GSM"),
+    (554598801, "Claro"),
+    (554598802, "Claro"),
+    (554598803, "Claro"),
+    (554598804, "Claro"),
+    (554598805, "Claro"),
+    (554598806, "Claro"),
+    (554598807, "Claro"),
+    (554598808, "Claro"),
+    (554598809, "Claro"),
+    (554598811, "Claro"),
+    (554598812, "Claro"),
+    (554598813, "Claro"),
+    (554598814, "Claro"),
+    (554598815, "Claro"),
+    (554598816, "Claro"),
+    (554598817, "Claro"),
+    (554598818, "Claro"),
+    (554598819, "Claro"),
+    (554598821, "Claro"),
+    (554598822, "Claro"),
+    (554598823, "Claro"),
+    (554598824, "Claro"),
+    (554598825, "Claro"),
+    (554598826, "Claro"),
+    (554598827, "Claro"),
+    (554598828, "Claro"),
+    (554598829, "Claro"),
+    (554598831, "Claro"),
+    (554598832, "Claro"),
+    (554598833, "Claro"),
+    (554598834, "Claro"),
+    (554598835, "Claro"),
+    (554598836, "Claro"),
+    (554598837, "Claro"),
+    (554598838, "Claro"),
+    (554598839, "Claro"),
+    (554598841, "Claro"),
+    (554598842, "Claro"),
+    (554599111, "Vivo"),
+    (554599112, "Vivo"),
+    (554599113, "Vivo"),
+    (554599114, "Vivo"),
+    (554599115, "Vivo"),
+    (554599116, "Vivo"),
+    (554599117, "Vivo"),
+    (554599118, "Vivo"),
+    (554599119, "Vivo"),
+    (554599121, "Vivo"),
+    (554599122, "Vivo"),
+    (554599123, "Vivo"),
+    (554599124, "Vivo"),
+    (554599125, "Vivo"),
+    (554599126, "Vivo"),
+    (554599127, "Vivo"),
+    (554599128, "Vivo"),
+    (554599129, "Vivo"),
+    (554599131, "Vivo"),
+    (554599132, "Vivo"),
+    (554599133, "Vivo"),
+    (554599134, "Vivo"),
+    (554599135, "Vivo"),
+    (554599136, "Vivo"),
+    (554599137, "Vivo"),
+    (554599138, "Vivo"),
+    (554599139, "Vivo"),
+    (554599141, "Vivo"),
+    (554599142, "Vivo"),
+    (554599143, "Vivo"),
+    (554599144, "Vivo"),
+    (554599145, "Vivo"),
+    (554599146, "Vivo"),
+    (554599147, "Vivo"),
+    (554599148, "Vivo"),
+    (554599149, "Vivo"),
+    (554599151, "Vivo"),
+    (554599152, "Vivo"),
+    (554599153, "Vivo"),
+    (554599154, "Vivo"),
+    (554599155, "Vivo"),
+    (554599156, "Vivo"),
+    (554599157, "Vivo"),
+    (554599158, "Vivo"),
+    (554599911, "TIM"),
+    (554599912, "TIM"),
+    (554599913, "TIM"),
+    (554599914, "TIM"),
+    (554599915, "TIM"),
+    (554599916, "TIM"),
+    (554599917, "TIM"),
+    (554599918, "TIM"),
+    (554599919, "TIM"),
+    (554599921, "TIM"),
+    (554599922, "TIM"),
+    (554599923, "TIM"),
+    (554599924, "TIM"),
+    (554599925, "TIM"),
+    (554599926, "TIM"),
+    (554599927, "TIM"),
+    (554599928, "TIM"),
+    (554599929, "TIM"),
+    (554599931, "TIM"),
+    (554599932, "TIM"),
+    (554599933, "TIM"),
+    (554599934, "TIM"),
+    (554599935, "TIM"),
+    (554599936, "TIM"),
+    (554599937, "TIM"),
+    (554599938, "TIM"),
+    (554599939, "TIM"),
+    (554599941, "TIM"),
+    (554599942, "TIM"),
+    (554599943, "TIM"),
+    (554599944, "TIM"),
+    (554599945, "TIM"),
+    (554599946, "TIM"),
+    (554599947, "TIM"),
+    (554599948, "TIM"),
+    (554599949, "TIM"),
+    (554599951, "TIM"),
+    (554599952, "TIM"),
+    (554599953, "TIM"),
+    (554599954, "TIM"),
+    (554599961, "TIM"),
+    (554599962, "TIM"),
+    (554599963, "TIM"),
+    (554599964, "TIM"),
+    (554599965, "TIM"),
+    (554599966, "TIM"),
+    (554599967, "TIM"),
+    (554599968, "TIM"),
+    (554599969, "TIM"),
+    (554599971, "TIM"),
+    (554599972, "TIM"),
+    (554599973, "TIM"),
+    (554599974, "TIM"),
+    (554599975, "TIM"),
+    (554599976, "TIM"),
+    (554599977, "TIM"),
+    (554599978, "TIM"),
+    (554599979, "TIM"),
+    (554599981, "TIM"),
+    (554599982, "TIM"),
+    (554599983, "TIM"),
+    (554599984, "TIM"),
+    (554698401, "Brasil Telecom GSM"),
+    (554698402, "Brasil Telecom GSM"),
+    (554698403, "Brasil Telecom GSM"),
+    (554698404, "Brasil Telecom GSM"),
+    (554698405, "Brasil Telecom GSM"),
+    (554698406, "Brasil Telecom GSM"),
+    (554698407, "Brasil Telecom GSM"),
+    (554698801, "Claro"),
+    (554698802, "Claro"),
+    (554698803, "Claro"),
+    (554698804, "Claro"),
+    (554698805, "Claro"),
+    (554698806, "Claro"),
+    (554698807, "Claro"),
+    (554698808, "Claro"),
+    (554698809, "Claro"),
+    (554698821, "Claro"),
+    (554698822, "Claro"),
+    (554698823, "Claro"),
+    (554698824, "Claro"),
+    (554698825, "Claro"),
+    (554698826, "Claro"),
+    (554698827, "Claro"),
+    (554699111, "Vivo"),
+    (554699112, "Vivo"),
+    (554699113, "Vivo"),
+    (554699114, "Vivo"),
+    (554699115, "Vivo"),
+    (554699116, "Vivo"),
+    (554699117, "Vivo"),
+    (554699118, "Vivo"),
+    (554699119, "Vivo"),
+    (554699121, "Vivo"),
+    (554699122, "Vivo"),
+    (554699123, "Vivo"),
+    (554699124, "Vivo"),
+    (554699125, "Vivo"),
+    (554699126, "Vivo"),
+    (554699127, "Vivo"),
+    (554699128, "Vivo"),
+    (554699129, "Vivo"),
+    (554699131, "Vivo"),
+    (554699132, "Vivo"),
+    (554699911, "TIM"),
+    (554699912, "TIM"),
+    (554699913, "TIM"),
+    (554699914, "TIM"),
+    (554699915, "TIM"),
+    (554699916, "TIM"),
+    (554699917, "TIM"),
+    (554699918, "TIM"),
+    (554699919, "TIM"),
+    (554699921, "TIM"),
+    (554699922, "TIM"),
+    (554699923, "TIM"),
+    (554699939, "TIM"),
+    (554699971, "TIM"),
+    (554699972, "TIM"),
+    (554699973, "TIM"),
+    (554699974, "TIM"),
+    (554699975, "TIM"),
+    (554699976, "TIM"),
+    (554699978, "TIM"),
+    (554798401, "Brasil Telecom GSM"),
+    (554798402, "Brasil Telecom GSM"),
+    (554798403, "Brasil Telecom GSM"),
+    (554798404, "Brasil Telecom GSM"),
+    (554798405, "Brasil Telecom GSM"),
+    (554798406, "Brasil Telecom GSM"),
+    (554798407, "Brasil Telecom GSM"),
+    (554798408, "Brasil Telecom GSM"),
+    (554798409, "Brasil Telecom GSM"),
+    (554798411, "Brasil Telecom GSM"),
+    (554798412, "Brasil Telecom GSM"),
+    (554798413, "Brasil Telecom GSM"),
+    (554798414, "Brasil Telecom GSM"),
+    (554798415, "Brasil Telecom GSM"),
+    (554798416, "Brasil Telecom GSM"),
+    (554798417, "Brasil Telecom GSM"),
+    (554798418, "Brasil Telecom GSM"),
+    (554798419, "Brasil Telecom GSM"),
+    (554798421, "Brasil Telecom GSM"),
+    (554798422, "Brasil Telecom GSM"),
+    (554798423, "Brasil Telecom GSM"),
+    (554798424, "Brasil Telecom GSM"),
+    (554798425, "Brasil Telecom GSM"),
+    (554798426, "Brasil Telecom GSM"),
+    (554798427, "Brasil Telecom GSM"),
+    (554798428, "Brasil Telecom GSM"),
+    (554798429, "Brasil Telecom GSM"),
+    (554798431, "Brasil Telecom GSM"),
+    (554798432, "Brasil Telecom GSM"),
+    (554798433, "Brasil Telecom GSM"),
+    (554798434, "Brasil Telecom GSM"),
+    (554798435, "Brasil Telecom GSM"),
+    (554798436, "Brasil Telecom GSM"),
+    (554798437, "Brasil Telecom GSM"),
+    (554798438, "Brasil Telecom GSM"),
+    (554798439, "Brasil Telecom GSM"),
+    (554798441, "Brasil Telecom GSM"),
+    (554798442, "Brasil Telecom GSM"),
+    (554798443, "Brasil Telecom GSM"),
+    (554798444, "Brasil Telecom GSM"),
+    (554798445, "Brasil Telecom GSM"),
+    (554798446, "Brasil Telecom GSM"),
+    (554798447, "Brasil Telecom GSM"),
+    (554798448, "Brasil Telecom GSM"),
+    (554798449, "Brasil Telecom GSM"),
+    (554798451, "Brasil Telecom GSM"),
+    (554798452, "Brasil Telecom GSM"),
+    (554798453, "Brasil Telecom GSM"),
+    (554798454, "Brasil Telecom GSM"),
+    (554798455, "Brasil Telecom GSM"),
+    (554798456, "Brasil Telecom GSM"),
+    (554798457, "Brasil Telecom GSM"),
+    (554798900, "Claro"),
+    (554798901, "Claro"),
+    (554799240, "Vivo"),
+    (554799241, "Vivo"),
+    (554799242, "Vivo"),
+    (554799243, "Vivo"),
+    (554799244, "Vivo"),
+    (554799245, "Vivo"),
+    (554799246, "Vivo"),
+    (554799601, "TIM"),
+    (554799602, "TIM"),
+    (554799603, "TIM"),
+    (554799604, "TIM"),
+    (554799605, "TIM"),
+    (554799606, "TIM"),
+    (554799607, "TIM"),
+    (554799608, "TIM"),
+    (554799609, "TIM"),
+    (554799611, "TIM"),
+    (554799612, "TIM"),
+    (554799613, "TIM"),
+    (554799614, "TIM"),
+    (554799615, "TIM"),
+    (554799616, "TIM"),
+    (554799617, "TIM"),
+    (554799618, "TIM"),
+    (554799619, "TIM"),
+    (554799621, "TIM"),
+    (554799622, "TIM"),
+    (554799623, "TIM"),
+    (554799624, "TIM"),
+    (554799625, "TIM"),
+    (554799626, "TIM"),
+    (554799627, "TIM"),
+    (554799628, "TIM"),
+    (554799629, "TIM"),
+    (554799631, "TIM"),
+    (554799632, "TIM"),
+    (554799633, "TIM"),
+    (554799634, "TIM"),
+    (554799635, "TIM"),
+    (554799636, "TIM"),
+    (554799637, "TIM"),
+    (554799638, "TIM"),
+    (554799651, "TIM"),
+    (554799652, "TIM"),
+    (554799653, "TIM"),
+    (554799654, "TIM"),
+    (554799655, "TIM"),
+    (554799656, "TIM"),
+    (554799657, "TIM"),
+    (554799658, "TIM"),
+    (554799901, "TIM"),
+    (554799902, "TIM"),
+    (554799903, "TIM"),
+    (554799904, "TIM"),
+    (554799905, "TIM"),
+    (554799906, "TIM"),
+    (554799907, "TIM"),
+    (554799909, "TIM"),
+    (554799911, "TIM"),
+    (554799912, "TIM"),
+    (554799913, "TIM"),
+    (554799914, "TIM"),
+    (554799915, "TIM"),
+    (554799916, "TIM"),
+    (554799917, "TIM"),
+    (554799918, "TIM"),
+    (554799919, "TIM"),
+    (554799921, "TIM"),
+    (554799922, "TIM"),
+    (554799923, "TIM"),
+    (554799924, "TIM"),
+    (554799925, "TIM"),
+    (554799926, "TIM"),
+    (554799927, "TIM"),
+    (554799928, "TIM"),
+    (554799929, "TIM"),
+    (554799931, "TIM"),
+    (554799932, "TIM"),
+    (554799933, "TIM"),
+    (554799934, "TIM"),
+    (554799935, "TIM"),
+    (554799936, "TIM"),
+    (554799937, "TIM"),
+    (554799938, "TIM"),
+    (554799939, "TIM"),
+    (554799941, "TIM"),
+    (554799942, "TIM"),
+    (554799943, "TIM"),
+    (554799944, "TIM"),
+    (554799945, "TIM"),
+    (554799946, "TIM"),
+    (554799947, "TIM"),
+    (554799948, "TIM"),
+    (554799949, "TIM"),
+    (554799951, "TIM"),
+    (554799952, "TIM"),
+    (554799953, "TIM"),
+    (554799954, "TIM"),
+    (554799955, "TIM"),
+    (554799956, "TIM"),
+    (554799957, "TIM"),
+    (554799958, "TIM"),
+    (554799959, "TIM"),
+    (554799961, "TIM"),
+    (554799962, "TIM"),
+    (554799963, "TIM"),
+    (554799964, "TIM"),
+    (554799965, "TIM"),
+    (554799966, "TIM"),
+    (554799967, "TIM"),
+    (554799968, "TIM"),
+    (554799969, "TIM"),
+    (554799971, "TIM"),
+    (554799972, "TIM"),
+    (554799973, "TIM"),
+    (554799974, "TIM"),
+    (554799975, "TIM"),
+    (554799976, "TIM"),
+    (554799977, "TIM"),
+    (554799978, "TIM"),
+    (554799979, "TIM"),
+    (554799991, "TIM"),
+    (554799992, "TIM"),
+    (554799993, "TIM"),
+    (554799994, "TIM"),
+    (554799995, "TIM"),
+    (554799996, "TIM"),
+    (554799997, "TIM"),
+    (554799998, "TIM"),
+    (554898401, "Brasil Telecom GSM"),
+    (554898402, "Brasil Telecom GSM"),
+    (554898403, "Brasil Telecom GSM"),
+    (554898404, "Brasil Telecom GSM"),
+    (554898405, "Brasil Telecom GSM"),
+    (554898406, "Brasil Telecom GSM"),
+    (554898407, "Brasil Telecom GSM"),
+    (554898408, "Brasil Telecom GSM"),
+    (554898409, "Brasil Telecom GSM"),
+    (554898411, "Brasil Telecom GSM"),
+    (554898412, "Brasil Telecom GSM"),
+    (554898413, "Brasil Telecom GSM"),
+    (554898414, "Brasil Telecom GSM"),
+    (554898415, "Brasil Telecom GSM"),
+    (554898416, "Brasil Telecom GSM"),
+    (554898417, "Brasil Telecom GSM"),
+    (554898418, "Brasil Telecom GSM"),
+    (554898419, "Brasil Telecom GSM"),
+    (554898421, "Brasil Telecom GSM"),
+    (554898422, "Brasil Telecom GSM"),
+    (554898423, "Brasil Telecom GSM"),
+    (554898424, "Brasil Telecom GSM"),
+    (554898425, "Brasil Telecom GSM"),
+    (554898426, "Brasil Telecom GSM"),
+    (554898427, "Brasil Telecom GSM"),
+    (554898428, "Brasil Telecom GSM"),
+    (554898429, "Brasil Telecom GSM"),
+    (554898431, "Brasil Telecom GSM"),
+    (554898432, "Brasil Telecom GSM"),
+    (554898433, "Brasil Telecom GSM"),
+    (554898434, "Brasil Telecom GSM"),
+    (554898435, "Brasil Telecom GSM"),
+    (554898436, "Brasil Telecom GSM"),
+    (554898437, "Brasil Telecom GSM"),
+    (554898438, "Brasil Telecom GSM"),
+    (554898439, "Brasil Telecom GSM"),
+    (554898441, "Brasil Telecom GSM"),
+    (554898442, "Brasil Telecom GSM"),
+    (554898443, "Brasil Telecom GSM"),
+    (554898444, "Brasil Telecom GSM"),
+    (554898445, "Brasil Telecom GSM"),
+    (554898446, "Brasil Telecom GSM"),
+    (554898447, "Brasil Telecom GSM"),
+    (554898448, "Brasil Telecom GSM"),
+    (554898449, "Brasil Telecom GSM"),
+    (554898451, "Brasil Telecom GSM"),
+    (554898452, "Brasil Telecom GSM"),
+    (554898860, "Claro"),
+    (554898861, "Claro"),
+    (554898862, "Claro"),
+    (554898863, "Claro"),
+    (554898864, "Claro"),
+    (554898865, "Claro"),
+    (554898866, "Claro"),
+    (554899111, "Vivo"),
+    (554899112, "Vivo"),
+    (554899113, "Vivo"),
+    (554899114, "Vivo"),
+    (554899115, "Vivo"),
+    (554899116, "Vivo"),
+    (554899117, "Vivo"),
+    (554899118, "Vivo"),
+    (554899119, "Vivo"),
+    (554899121, "Vivo"),
+    (554899122, "Vivo"),
+    (554899123, "Vivo"),
+    (554899124, "Vivo"),
+    (554899125, "Vivo"),
+    (554899126, "Vivo"),
+    (554899127, "Vivo"),
+    (554899128, "Vivo"),
+    (554899129, "Vivo"),
+    (554899131, "Vivo"),
+    (554899132, "Vivo"),
+    (554899133, "Vivo"),
+    (554899134, "Vivo"),
+    (554899135, "Vivo"),
+    (554899136, "Vivo"),
+    (554899137, "Vivo"),
+    (554899138, "Vivo"),
+    (554899139, "Vivo"),
+    (554899141, "Vivo"),
+    (554899142, "Vivo"),
+    (554899143, "Vivo"),
+    (554899144, "Vivo"),
+    (554899145, "Vivo"),
+    (554899146, "Vivo"),
+    (554899147, "Vivo"),
+    (554899148, "Vivo"),
+    (554899149, "Vivo"),
+    (554899151, "Vivo"),
+    (554899152, "Vivo"),
+    (554899153, "Vivo"),
+    (554899154, "Vivo"),
+    (554899155, "Vivo"),
+    (554899156, "Vivo"),
+    (554899157, "Vivo"),
+    (554899158, "Vivo"),
+    (554899159, "Vivo"),
+    (554899161, "Vivo"),
+    (554899162, "Vivo"),
+    (554899163, "Vivo"),
+    (554899164, "Vivo"),
+    (554899165, "Vivo"),
+    (554899166, "Vivo"),
+    (554899167, "Vivo"),
+    (554899168, "Vivo"),
+    (554899169, "Vivo"),
+    (554899171, "Vivo"),
+    (554899172, "Vivo"),
+    (554899173, "Vivo"),
+    (554899174, "Vivo"),
+    (554899175, "Vivo"),
+    (554899176, "Vivo"),
+    (554899177, "Vivo"),
+    (554899178, "Vivo"),
+    (554899179, "Vivo"),
+    (554899181, "Vivo"),
+    (554899182, "Vivo"),
+    (554899183, "Vivo"),
+    (554899184, "Vivo"),
+    (554899185, "Vivo"),
+    (554899186, "Vivo"),
+    (554899601, "TIM"),
+    (554899602, "TIM"),
+    (554899603, "TIM"),
+    (554899604, "TIM"),
+    (554899606, "TIM"),
+    (554899607, "TIM"),
+    (554899608, "TIM"),
+    (554899609, "TIM"),
+    (554899611, "TIM"),
+    (554899612, "TIM"),
+    (554899613, "TIM"),
+    (554899614, "TIM"),
+    (554899615, "TIM"),
+    (554899616, "TIM"),
+    (554899617, "TIM"),
+    (554899618, "TIM"),
+    (554899619, "TIM"),
+    (554899621, "TIM"),
+    (554899622, "TIM"),
+    (554899623, "TIM"),
+    (554899624, "TIM"),
+    (554899625, "TIM"),
+    (554899626, "TIM"),
+    (554899627, "TIM"),
+    (554899628, "TIM"),
+    (554899629, "TIM"),
+    (554899631, "TIM"),
+    (554899632, "TIM"),
+    (554899633, "TIM"),
+    (554899634, "TIM"),
+    (554899635, "TIM"),
+    (554899636, "TIM"),
+    (554899637, "TIM"),
+    (554899638, "TIM"),
+    (554899639, "TIM"),
+    (554899641, "TIM"),
+    (554899642, "TIM"),
+    (554899643, "TIM"),
+    (554899901, "TIM"),
+    (554899902, "TIM"),
+    (554899903, "TIM"),
+    (554899904, "TIM"),
+    (554899905, "TIM"),
+    (554899906, "TIM"),
+    (554899907, "TIM"),
+    (554899908, "TIM"),
+    (554899909, "TIM"),
+    (554899911, "TIM"),
+    (554899912, "TIM"),
+    (554899913, "TIM"),
+    (554899914, "TIM"),
+    (554899915, "TIM"),
+    (554899916, "TIM"),
+    (554899917, "TIM"),
+    (554899918, "TIM"),
+    (554899919, "TIM"),
+    (554899921, "TIM"),
+    (554899922, "TIM"),
+    (554899923, "TIM"),
+    (554899924, "TIM"),
+    (554899925, "TIM"),
+    (554899926, "TIM"),
+    (554899927, "TIM"),
+    (554899928, "TIM"),
+    (554899929, "TIM"),
+    (554899931, "TIM"),
+    (554899932, "TIM"),
+    (554899933, "TIM"),
+    (554899934, "TIM"),
+    (554899935, "TIM"),
+    (554899936, "TIM"),
+    (554899937, "TIM"),
+    (554899938, "TIM"),
+    (554899939, "TIM"),
+    (554899941, "TIM"),
+    (554899942, "TIM"),
+    (554899943, "TIM"),
+    (554899944, "TIM"),
+    (554899945, "TIM"),
+    (554899946, "TIM"),
+    (554899947, "TIM"),
+    (554899948, "TIM"),
+    (554899949, "TIM"),
+    (554899951, "TIM"),
+    (554899952, "TIM"),
+    (554899953, "TIM"),
+    (554899954, "TIM"),
+    (554899955, "TIM"),
+    (554899956, "TIM"),
+    (554899957, "TIM"),
+    (554899958, "TIM"),
+    (554899959, "TIM"),
+    (554899971, "TIM"),
+    (554899972, "TIM"),
+    (554899973, "TIM"),
+    (554899974, "TIM"),
+    (554899975, "TIM"),
+    (554899976, "TIM"),
+    (554899977, "TIM"),
+    (554899978, "TIM"),
+    (554899979, "TIM"),
+    (554899991, "TIM"),
+    (554899992, "TIM"),
+    (554899993, "TIM"),
+    (554899994, "TIM"),
+    (554899995, "TIM"),
+    (554899996, "TIM"),
+    (554899997, "TIM"),
+    (554899998, "TIM"),
+    (554998401, "Brasil Telecom GSM"),
+    (554998402, "Brasil Telecom GSM"),
+    (554998403, "Brasil Telecom GSM"),
+    (554998404, "Brasil Telecom GSM"),
+    (554998405, "Brasil Telecom GSM"),
+    (554998406, "Brasil Telecom GSM"),
+    (554998407, "Brasil Telecom GSM"),
+    (554998408, "Brasil Telecom GSM"),
+    (554998409, "Brasil Telecom GSM"),
+    (554998411, "Brasil Telecom GSM"),
+    (554998412, "Brasil Telecom GSM"),
+    (554998413, "Brasil Telecom GSM"),
+    (554998414, "Brasil Telecom GSM"),
+    (554998415, "Brasil Telecom GSM"),
+    (554998416, "Brasil Telecom GSM"),
+    (554998417, "Brasil Telecom GSM"),
+    (554998418, "Brasil Telecom GSM"),
+    (554998419, "Brasil Telecom GSM"),
+    (554998880, "Claro"),
+    (554998881, "Claro"),
+    (554998882, "Claro"),
+    (554999111, "Vivo"),
+    (554999112, "Vivo"),
+    (554999113, "Vivo"),
+    (554999114, "Vivo"),
+    (554999115, "Vivo"),
+    (554999116, "Vivo"),
+    (554999117, "Vivo"),
+    (554999118, "Vivo"),
+    (554999119, "Vivo"),
+    (554999121, "Vivo"),
+    (554999122, "Vivo"),
+    (554999123, "Vivo"),
+    (554999124, "Vivo"),
+    (554999125, "Vivo"),
+    (554999126, "Vivo"),
+    (554999127, "Vivo"),
+    (554999128, "Vivo"),
+    (554999129, "Vivo"),
+    (554999131, "Vivo"),
+    (554999132, "Vivo"),
+    (554999133, "Vivo"),
+    (554999134, "Vivo"),
+    (554999135, "Vivo"),
+    (554999136, "Vivo"),
+    (554999137, "Vivo"),
+    (554999138, "Vivo"),
+    (554999139, "Vivo"),
+    (554999141, "Vivo"),
+    (554999142, "Vivo"),
+    (554999143, "Vivo"),
+    (554999144, "Vivo"),
+    (554999145, "Vivo"),
+    (554999146, "Vivo"),
+    (554999147, "Vivo"),
+    (554999148, "Vivo"),
+    (554999149, "Vivo"),
+    (554999151, "Vivo"),
+    (554999152, "Vivo"),
+    (554999153, "Vivo"),
+    (554999154, "Vivo"),
+    (554999155, "Vivo"),
+    (554999156, "Vivo"),
+    (554999157, "Vivo"),
+    (554999158, "Vivo"),
+    (554999159, "Vivo"),
+    (554999161, "Vivo"),
+    (554999162, "Vivo"),
+    (554999163, "Vivo"),
+    (554999164, "Vivo"),
+    (554999165, "Vivo"),
+    (554999166, "Vivo"),
+    (554999167, "Vivo"),
+    (554999168, "Vivo"),
+    (554999169, "Vivo"),
+    (554999171, "Vivo"),
+    (554999172, "Vivo"),
+    (554999173, "Vivo"),
+    (554999174, "Vivo"),
+    (554999175, "Vivo"),
+    (554999176, "Vivo"),
+    (554999177, "Vivo"),
+    (554999911, "TIM"),
+    (554999912, "TIM"),
+    (554999913, "TIM"),
+    (554999914, "TIM"),
+    (554999915, "TIM"),
+    (554999916, "TIM"),
+    (554999917, "TIM"),
+    (554999918, "TIM"),
+    (554999919, "TIM"),
+    (554999921, "TIM"),
+    (554999922, "TIM"),
+    (554999923, "TIM"),
+    (554999924, "TIM"),
+    (554999925, "TIM"),
+    (554999926, "TIM"),
+    (554999927, "TIM"),
+    (554999928, "TIM"),
+    (554999929, "TIM"),
+    (554999931, "TIM"),
+    (554999932, "TIM"),
+    (554999939, "TIM"),
+    (554999951, "TIM"),
+    (554999955, "TIM"),
+    (554999963, "TIM"),
+    (554999964, "TIM"),
+    (554999965, "TIM"),
+    (554999966, "TIM"),
+    (554999967, "TIM"),
+    (554999968, "TIM"),
+    (554999969, "TIM"),
+    (554999971, "TIM"),
+    (554999972, "TIM"),
+    (554999973, "TIM"),
+    (554999974, "TIM"),
+    (554999975, "TIM"),
+    (554999976, "TIM"),
+    (554999977, "TIM"),
+    (554999978, "TIM"),
+    (554999979, "TIM"),
+    (554999991, "TIM"),
+    (554999992, "TIM"),
+    (554999993, "TIM"),
+    (554999994, "TIM"),
+    (554999995, "TIM"),
+    (554999996, "TIM"),
+    (554999997, "TIM"),
+    (554999998, "TIM"),
+    (555198201, "TIM"),
+    (555198202, "TIM"),
+    (555198203, "TIM"),
+    (555198204, "TIM"),
+    (555198205, "TIM"),
+    (555198206, "TIM"),
+    (555198207, "TIM"),
+    (555198208, "TIM"),
+    (555198209, "TIM"),
+    (555198211, "TIM"),
+    (555198212, "TIM"),
+    (555198213, "TIM"),
+    (555198214, "TIM"),
+    (555198215, "TIM"),
+    (555198216, "TIM"),
+    (555198217, "TIM"),
+    (555198218, "TIM"),
+    (555198226, "TIM"),
+    (555198227, "TIM"),
+    (555199450, "Claro"),
+    (555199560, "Vivo"),
+    (555199561, "Vivo"),
+    (555199562, "Vivo"),
+    (555199563, "Vivo"),
+    (555398111, "TIM"),
+    (555398112, "TIM"),
+    (555398113, "TIM"),
+    (555398114, "TIM"),
+    (555398115, "TIM"),
+    (555398116, "TIM"),
+    (555398117, "TIM"),
+    (555398118, "TIM"),
+    (555398119, "TIM"),
+    (555398121, "TIM"),
+    (555398122, "TIM"),
+    (555398123, "TIM"),
+    (555398124, "TIM"),
+    (555398125, "TIM"),
+    (555398126, "TIM"),
+    (555398127, "TIM"),
+    (555398128, "TIM"),
+    (555398129, "TIM"),
+    (555398131, "TIM"),
+    (555398132, "TIM"),
+    (555398133, "TIM"),
+    (555398134, "TIM"),
+    (555398135, "TIM"),
+    (555398136, "TIM"),
+    (555398137, "TIM"),
+    (555398138, "TIM"),
+    (555398139, "TIM"),
+    (555398141, "TIM"),
+    (555398401, "Brasil Telecom GSM"),
+    (555398402, "Brasil Telecom GSM"),
+    (555398403, "Brasil Telecom GSM"),
+    (555398404, "Brasil Telecom GSM"),
+    (555398405, "Brasil Telecom GSM"),
+    (555398406, "Brasil Telecom GSM"),
+    (555398407, "Brasil Telecom GSM"),
+    (555398408, "Brasil Telecom GSM"),
+    (555398409, "Brasil Telecom GSM"),
+    (555398411, "Brasil Telecom GSM"),
+    (555398412, "Brasil Telecom GSM"),
+    (555398413, "Brasil Telecom GSM"),
+    (555398414, "Brasil Telecom GSM"),
+    (555398415, "Brasil Telecom GSM"),
+    (555398416, "Brasil Telecom GSM"),
+    (555398417, "Brasil Telecom GSM"),
+    (555398418, "Brasil Telecom GSM"),
+    (555398419, "Brasil Telecom GSM"),
+    (555398421, "Brasil Telecom GSM"),
+    (555398422, "Brasil Telecom GSM"),
+    (555398423, "Brasil Telecom GSM"),
+    (555398424, "Brasil Telecom GSM"),
+    (555398425, "Brasil Telecom GSM"),
+    (555398426, "Brasil Telecom GSM"),
+    (555398427, "Brasil Telecom GSM"),
+    (555398428, "Brasil Telecom GSM"),
+    (555398429, "Brasil Telecom GSM"),
+    (555398431, "Brasil Telecom GSM"),
+    (555398432, "Brasil Telecom GSM"),
+    (555398433, "Brasil Telecom GSM"),
+    (555398434, "Brasil Telecom GSM"),
+    (555398435, "Brasil Telecom GSM"),
+    (555398436, "Brasil Telecom GSM"),
+    (555398437, "Brasil Telecom GSM"),
+    (555399170, "Claro"),
+    (555399171, "Claro"),
+    (555399172, "Claro"),
+    (555399173, "Claro"),
+    (555399174, "Claro"),
+    (555399175, "Claro"),
+    (555399176, "Claro"),
+    (555399177, "Claro"),
+    (555399241, "Claro"),
+    (555399911, "TIM"),
+    (555399913, "TIM"),
+    (555399927, "Vivo"),
+    (555399928, "Vivo"),
+    (555399929, "Vivo"),
+    (555399931, "Vivo"),
+    (555399932, "Vivo"),
+    (555399933, "Vivo"),
+    (555399934, "Vivo"),
+    (555399935, "Vivo"),
+    (555399936, "Vivo"),
+    (555399937, "Vivo"),
+    (555399938, "Vivo"),
+    (555399939, "TIM"),
+    (555399941, "Vivo"),
+    (555399942, "Vivo"),
+    (555399943, "Vivo"),
+    (555399944, "Vivo"),
+    (555399945, "Vivo"),
+    (555399946, "Vivo"),
+    (555399947, "Vivo"),
+    (555399948, "Vivo"),
+    (555399949, "Vivo"),
+    (555399951, "Vivo"),
+    (555399952, "Vivo"),
+    (555399953, "Vivo"),
+    (555399954, "Vivo"),
+    (555399955, "Vivo"),
+    (555399956, "Vivo"),
+    (555399957, "Vivo"),
+    (555399958, "Vivo"),
+    (555399959, "Vivo"),
+    (555399961, "Vivo"),
+    (555399962, "Vivo"),
+    (555399963, "Vivo"),
+    (555399964, "Vivo"),
+    (555399965, "Vivo"),
+    (555399966, "Vivo"),
+    (555399967, "Vivo"),
+    (555399968, "Vivo"),
+    (555399969, "Vivo"),
+    (555399971, "Vivo"),
+    (555399972, "Vivo"),
+    (555399973, "Vivo"),
+    (555399974, "Vivo"),
+    (555399975, "Vivo"),
+    (555399976, "Vivo"),
+    (555399977, "Vivo"),
+    (555399978, "Vivo"),
+    (555399979, "Vivo"),
+    (555399981, "TIM"),
+    (555399982, "TIM"),
+    (555399983, "TIM"),
+    (555399985, "TIM"),
+    (555399986, "TIM"),
+    (555399987, "TIM"),
+    (555399988, "TIM"),
+    (555399989, "TIM"),
+    (555399991, "Vivo"),
+    (555399992, "Vivo"),
+    (555399993, "Vivo"),
+    (555399994, "Vivo"),
+    (555399995, "Vivo"),
+    (555399996, "Vivo"),
+    (555399997, "Vivo"),
+    (555399998, "Vivo"),
+    (555399999, "Vivo"),
+    (555498111, "TIM"),
+    (555498112, "TIM"),
+    (555498113, "TIM"),
+    (555498114, "TIM"),
+    (555498115, "TIM"),
+    (555498116, "TIM"),
+    (555498117, "TIM"),
+    (555498118, "TIM"),
+    (555498119, "TIM"),
+    (555498121, "TIM"),
+    (555498122, "TIM"),
+    (555498123, "TIM"),
+    (555498124, "TIM"),
+    (555498125, "TIM"),
+    (555498126, "TIM"),
+    (555498127, "TIM"),
+    (555498128, "TIM"),
+    (555498129, "TIM"),
+    (555498131, "TIM"),
+    (555498132, "TIM"),
+    (555498133, "TIM"),
+    (555498134, "TIM"),
+    (555498135, "TIM"),
+    (555498136, "TIM"),
+    (555498137, "TIM"),
+    (555498138, "TIM"),
+    (555498139, "TIM"),
+    (555498141, "TIM"),
+    (555498142, "TIM"),
+    (555498143, "TIM"),
+    (555498144, "TIM"),
+    (555498145, "TIM"),
+    (555498146, "TIM"),
+    (555498147, "TIM"),
+    (555498148, "TIM"),
+    (555498149, "TIM"),
+    (555498401, "Brasil Telecom GSM"),
+    (555498402, "Brasil Telecom GSM"),
+    (555498403, "Brasil Telecom GSM"),
+    (555498404, "Brasil Telecom GSM"),
+    (555498405, "Brasil Telecom GSM"),
+    (555498406, "Brasil Telecom GSM"),
+    (555498407, "Brasil Telecom GSM"),
+    (555498408, "Brasil Telecom GSM"),
+    (555498409, "Brasil Telecom GSM"),
+    (555498411, "Brasil Telecom GSM"),
+    (555498412, "Brasil Telecom GSM"),
+    (555498413, "Brasil Telecom GSM"),
+    (555498414, "Brasil Telecom GSM"),
+    (555498415, "Brasil Telecom GSM"),
+    (555498416, "Brasil Telecom GSM"),
+    (555498417, "Brasil Telecom GSM"),
+    (555498418, "Brasil Telecom GSM"),
+    (555499220, "Claro"),
+    (555499221, "Claro"),
+    (555499601, "Vivo"),
+    (555499602, "Vivo"),
+    (555499603, "Vivo"),
+    (555499604, "Vivo"),
+    (555499605, "Vivo"),
+    (555499606, "Vivo"),
+    (555499607, "Vivo"),
+    (555499608, "Vivo"),
+    (555499609, "Vivo"),
+    (555499611, "Vivo"),
+    (555499612, "Vivo"),
+    (555499613, "Vivo"),
+    (555499614, "Vivo"),
+    (555499615, "Vivo"),
+    (555499616, "Vivo"),
+    (555499617, "Vivo"),
+    (555499618, "Vivo"),
+    (555499619, "Vivo"),
+    (555499621, "Vivo"),
+    (555499622, "Vivo"),
+    (555499623, "Vivo"),
+    (555499624, "Vivo"),
+    (555499625, "Vivo"),
+    (555499626, "Vivo"),
+    (555499627, "Vivo"),
+    (555499628, "Vivo"),
+    (555499629, "Vivo"),
+    (555499631, "Vivo"),
+    (555499632, "Vivo"),
+    (555499633, "Vivo"),
+    (555499634, "Vivo"),
+    (555499635, "Vivo"),
+    (555499636, "Vivo"),
+    (555499637, "Vivo"),
+    (555499638, "Vivo"),
+    (555499639, "Vivo"),
+    (555499641, "Vivo"),
+    (555499642, "Vivo"),
+    (555499643, "Vivo"),
+    (555499644, "Vivo"),
+    (555499645, "Vivo"),
+    (555499646, "Vivo"),
+    (555499647, "Vivo"),
+    (555499648, "Vivo"),
+    (555499649, "Vivo"),
+    (555499651, "Vivo"),
+    (555499652, "Vivo"),
+    (555499653, "Vivo"),
+    (555499654, "Vivo"),
+    (555499655, "Vivo"),
+    (555499656, "Vivo"),
+    (555499657, "Vivo"),
+    (555499658, "Vivo"),
+    (555499659, "Vivo"),
+    (555499661, "Vivo"),
+    (555499662, "Vivo"),
+    (555499663, "Vivo"),
+    (555499664, "Vivo"),
+    (555499665, "Vivo"),
+    (555499666, "Vivo"),
+    (555499667, "Vivo"),
+    (555499668, "Vivo"),
+    (555499669, "Vivo"),
+    (555499671, "Vivo"),
+    (555499672, "Vivo"),
+    (555499673, "Vivo"),
+    (555499674, "Vivo"),
+    (555499675, "Vivo"),
+    (555499676, "Vivo"),
+    (555499677, "Vivo"),
+    (555499678, "Vivo"),
+    (555499679, "Vivo"),
+    (555499681, "Vivo"),
+    (555499682, "Vivo"),
+    (555499683, "Vivo"),
+    (555499684, "Vivo"),
+    (555499901, "Vivo"),
+    (555499902, "Vivo"),
+    (555499903, "Vivo"),
+    (555499904, "Vivo"),
+    (555499905, "Vivo"),
+    (555499906, "Vivo"),
+    (555499907, "Vivo"),
+    (555499908, "Vivo"),
+    (555499909, "Vivo"),
+    (555499911, "Vivo"),
+    (555499912, "Vivo"),
+    (555499913, "Vivo"),
+    (555499914, "Vivo"),
+    (555499915, "Vivo"),
+    (555499916, "Vivo"),
+    (555499917, "Vivo"),
+    (555499918, "Vivo"),
+    (555499919, "Vivo"),
+    (555499921, "Vivo"),
+    (555499922, "Vivo"),
+    (555499923, "Vivo"),
+    (555499924, "Vivo"),
+    (555499925, "Vivo"),
+    (555499926, "Vivo"),
+    (555499927, "Vivo"),
+    (555499928, "Vivo"),
+    (555499929, "Vivo"),
+    (555499931, "Vivo"),
+    (555499932, "Vivo"),
+    (555499933, "Vivo"),
+    (555499934, "Vivo"),
+    (555499935, "Vivo"),
+    (555499936, "Vivo"),
+    (555499937, "Vivo"),
+    (555499938, "Vivo"),
+    (555499939, "Vivo"),
+    (555499941, "Vivo"),
+    (555499942, "Vivo"),
+    (555499943, "Vivo"),
+    (555499944, "Vivo"),
+    (555499945, "Vivo"),
+    (555499946, "Vivo"),
+    (555499947, "Vivo"),
+    (555499948, "Vivo"),
+    (555499949, "Vivo"),
+    (555499951, "Vivo"),
+    (555499952, "Vivo"),
+    (555499953, "Vivo"),
+    (555499954, "Vivo"),
+    (555499955, "Vivo"),
+    (555499956, "Vivo"),
+    (555499957, "Vivo"),
+    (555499958, "Vivo"),
+    (555499959, "Vivo"),
+    (555499961, "Vivo"),
+    (555499962, "Vivo"),
+    (555499963, "Vivo"),
+    (555499964, "Vivo"),
+    (555499965, "Vivo"),
+    (555499966, "Vivo"),
+    (555499967, "Vivo"),
+    (555499968, "Vivo"),
+    (555499969, "Vivo"),
+    (555499971, "Vivo"),
+    (555499972, "Vivo"),
+    (555499973, "Vivo"),
+    (555499974, "Vivo"),
+    (555499975, "Vivo"),
+    (555499976, "Vivo"),
+    (555499977, "Vivo"),
+    (555499978, "Vivo"),
+    (555499979, "Vivo"),
+    (555499981, "Vivo"),
+    (555499982, "Vivo"),
+    (555499983, "Vivo"),
+    (555499984, "Vivo"),
+    (555499985, "Vivo"),
+    (555499986, "Vivo"),
+    (555499987, "Vivo"),
+    (555499988, "Vivo"),
+    (555499989, "Vivo"),
+    (555499991, "Vivo"),
+    (555499992, "Vivo"),
+    (555499993, "Vivo"),
+    (555499994, "Vivo"),
+    (555499995, "Vivo"),
+    (555499996, "Vivo"),
+    (555499997, "Vivo"),
+    (555499998, "Vivo"),
+    (555499999, "Vivo"),
+    (555598111, "TIM"),
+    (555598112, "TIM"),
+    (555598113, "TIM"),
+    (555598114, "TIM"),
+    (555598115, "TIM"),
+    (555598116, "TIM"),
+    (555598117, "TIM"),
+    (555598118, "TIM"),
+    (555598119, "TIM"),
+    (555598121, "TIM"),
+    (555598122, "TIM"),
+    (555598123, "TIM"),
+    (555598124, "TIM"),
+    (555598125, "TIM"),
+    (555598126, "TIM"),
+    (555598127, "TIM"),
+    (555598128, "TIM"),
+    (555598129, "TIM"),
+    (555598131, "TIM"),
+    (555598132, "TIM"),
+    (555598133, "TIM"),
+    (555598134, "TIM"),
+    (555598135, "TIM"),
+    (555598136, "TIM"),
+    (555598137, "TIM"),
+    (555598138, "TIM"),
+    (555598139, "TIM"),
+    (555598141, "TIM"),
+    (555598142, "TIM"),
+    (555598143, "TIM"),
+    (555598401, "Brasil Telecom GSM"),
+    (555598402, "Brasil Telecom GSM"),
+    (555598403, "Brasil Telecom GSM"),
+    (555598404, "Brasil Telecom GSM"),
+    (555598405, "Brasil Telecom GSM"),
+    (555598406, "Brasil Telecom GSM"),
+    (555598407, "Brasil Telecom GSM"),
+    (555598408, "Brasil Telecom GSM"),
+    (555598409, "Brasil Telecom GSM"),
+    (555598411, "Brasil Telecom GSM"),
+    (555598412, "Brasil Telecom GSM"),
+    (555598413, "Brasil Telecom GSM"),
+    (555598414, "Brasil Telecom GSM"),
+    (555598415, "Brasil Telecom GSM"),
+    (555598416, "Brasil Telecom GSM"),
+    (555598417, "Brasil Telecom GSM"),
+    (555598418, "Brasil Telecom GSM"),
+    (555598419, "Brasil Telecom GSM"),
+    (555598421, "Brasil Telecom GSM"),
+    (555598422, "Brasil Telecom GSM"),
+    (555598423, "Brasil Telecom GSM"),
+    (555598424, "Brasil Telecom GSM"),
+    (555598425, "Brasil Telecom GSM"),
+    (555598426, "Brasil Telecom GSM"),
+    (555598427, "Brasil Telecom GSM"),
+    (555599931, "Vivo"),
+    (555599932, "Vivo"),
+    (555599933, "Vivo"),
+    (555599934, "Vivo"),
+    (555599935, "Vivo"),
+    (555599936, "Vivo"),
+    (555599937, "Vivo"),
+    (555599938, "Vivo"),
+    (555599939, "Vivo"),
+    (555599941, "Vivo"),
+    (555599942, "Vivo"),
+    (555599943, "Vivo"),
+    (555599944, "Vivo"),
+    (555599945, "Vivo"),
+    (555599946, "Vivo"),
+    (555599947, "Vivo"),
+    (555599948, "Vivo"),
+    (555599949, "Vivo"),
+    (555599951, "Vivo"),
+    (555599952, "Vivo"),
+    (555599953, "Vivo"),
+    (555599954, "Vivo"),
+    (555599955, "Vivo"),
+    (555599956, "Vivo"),
+    (555599957, "Vivo"),
+    (555599958, "Vivo"),
+    (555599959, "Vivo"),
+    (555599961, "Vivo"),
+    (555599962, "Vivo"),
+    (555599963, "Vivo"),
+    (555599964, "Vivo"),
+    (555599965, "Vivo"),
+    (555599966, "Vivo"),
+    (555599967, "Vivo"),
+    (555599968, "Vivo"),
+    (555599969, "Vivo"),
+    (555599971, "Vivo"),
+    (555599972, "Vivo"),
+    (555599973, "Vivo"),
+    (555599974, "Vivo"),
+    (555599975, "Vivo"),
+    (555599976, "Vivo"),
+    (555599977, "Vivo"),
+    (555599978, "Vivo"),
+    (555599979, "Vivo"),
+    (555599981, "Vivo"),
+    (555599982, "Vivo"),
+    (555599983, "Vivo"),
+    (555599984, "Vivo"),
+    (555599985, "Vivo"),
+    (555599986, "Vivo"),
+    (555599987, "Vivo"),
+    (555599988, "Vivo"),
+    (555599989, "Vivo"),
+    (555599991, "Vivo"),
+    (555599992, "Vivo"),
+    (555599993, "Vivo"),
+    (555599994, "Vivo"),
+    (555599995, "Vivo"),
+    (555599996, "Vivo"),
+    (555599997, "Vivo"),
+    (555599998, "Vivo"),
+    (555599999, "Vivo"),
+    (556198101, "TIM"),
+    (556198102, "TIM"),
+    (556198103, "TIM"),
+    (556198104, "TIM"),
+    (556198105, "TIM"),
+    (556198106, "TIM"),
+    (556198107, "TIM"),
+    (556198108, "TIM"),
+    (556198109, "TIM"),
+    (556198171, "TIM"),
+    (556198172, "TIM"),
+    (556198173, "TIM"),
+    (556198174, "TIM"),
+    (556198175, "TIM"),
+    (556198176, "TIM"),
+    (556198177, "TIM"),
+    (556198178, "TIM"),
+    (556198179, "TIM"),
+    (556198181, "TIM"),
+    (556198182, "TIM"),
+    (556198183, "TIM"),
+    (556198184, "TIM"),
+    (556198185, "TIM"),
+    (556198186, "TIM"),
+    (556198187, "TIM"),
+    (556198188, "TIM"),
+    (556198189, "TIM"),
+    (556198191, "TIM"),
+    (556198192, "TIM"),
+    (556198193, "TIM"),
+    (556198194, "TIM"),
+    (556198195, "TIM"),
+    (556198196, "TIM"),
+    (556198197, "TIM"),
+    (556198198, "TIM"),
+    (556198199, "TIM"),
+    (556198501, "Brasil Telecom GSM"),
+    (556198502, "Brasil Telecom GSM"),
+    (556198503, "Brasil Telecom GSM"),
+    (556198504, "Brasil Telecom GSM"),
+    (556198505, "Brasil Telecom GSM"),
+    (556198506, "Brasil Telecom GSM"),
+    (556198507, "Brasil Telecom GSM"),
+    (556198508, "Brasil Telecom GSM"),
+    (556198509, "Brasil Telecom GSM"),
+    (556198511, "Brasil Telecom GSM"),
+    (556198512, "Brasil Telecom GSM"),
+    (556198513, "Brasil Telecom GSM"),
+    (556198514, "Brasil Telecom GSM"),
+    (556198515, "Brasil Telecom GSM"),
+    (556198516, "Brasil Telecom GSM"),
+    (556198517, "Brasil Telecom GSM"),
+    (556198518, "Brasil Telecom GSM"),
+    (556198519, "Brasil Telecom GSM"),
+    (556198521, "Brasil Telecom GSM"),
+    (556198522, "Brasil Telecom GSM"),
+    (556198523, "Brasil Telecom GSM"),
+    (556198524, "Brasil Telecom GSM"),
+    (556198525, "Brasil Telecom GSM"),
+    (556198526, "Brasil Telecom GSM"),
+    (556198527, "Brasil Telecom GSM"),
+    (556198528, "Brasil Telecom GSM"),
+    (556198529, "Brasil Telecom GSM"),
+    (556198531, "Brasil Telecom GSM"),
+    (556198532, "Brasil Telecom GSM"),
+    (556198533, "Brasil Telecom GSM"),
+    (556198534, "Brasil Telecom GSM"),
+    (556198535, "Brasil Telecom GSM"),
+    (556198536, "Brasil Telecom GSM"),
+    (556198537, "Brasil Telecom GSM"),
+    (556198538, "Brasil Telecom GSM"),
+    (556198539, "Brasil Telecom GSM"),
+    (556198541, "Brasil Telecom GSM"),
+    (556198542, "Brasil Telecom GSM"),
+    (556198543, "Brasil Telecom GSM"),
+    (556198544, "Brasil Telecom GSM"),
+    (556198545, "Brasil Telecom GSM"),
+    (556198546, "Brasil Telecom GSM"),
+    (556198547, "Brasil Telecom GSM"),
+    (556198548, "Brasil Telecom GSM"),
+    (556198549, "Brasil Telecom GSM"),
+    (556198551, "Brasil Telecom GSM"),
+    (556198552, "Brasil Telecom GSM"),
+    (556198553, "Brasil Telecom GSM"),
+    (556198554, "Brasil Telecom GSM"),
+    (556198555, "Brasil Telecom GSM"),
+    (556198556, "Brasil Telecom GSM"),
+    (556198557, "Brasil Telecom GSM"),
+    (556198558, "Brasil Telecom GSM"),
+    (556198559, "Brasil Telecom GSM"),
+    (556198561, "Brasil Telecom GSM"),
+    (556198562, "Brasil Telecom GSM"),
+    (556198563, "Brasil Telecom GSM"),
+    (556198564, "Brasil Telecom GSM"),
+    (556198565, "Brasil Telecom GSM"),
+    (556198566, "Brasil Telecom GSM"),
+    (556198567, "Brasil Telecom GSM"),
+    (556198568, "Brasil Telecom GSM"),
+    (556198569, "Brasil Telecom GSM"),
+    (556198571, "Brasil Telecom GSM"),
+    (556198572, "Brasil Telecom GSM"),
+    (556198573, "Brasil Telecom GSM"),
+    (556198574, "Brasil Telecom GSM"),
+    (556198576, "Brasil Telecom GSM"),
+    (556198577, "Brasil Telecom GSM"),
+    (556198579, "Brasil Telecom GSM"),
+    (556198581, "Brasil Telecom GSM"),
+    (556198582, "Brasil Telecom GSM"),
+    (556199601, "Vivo"),
+    (556199602, "Vivo"),
+    (556199603, "Vivo"),
+    (556199604, "Vivo"),
+    (556199605, "Vivo"),
+    (556199606, "Vivo"),
+    (556199607, "Vivo"),
+    (556199608, "Vivo"),
+    (556199609, "Vivo"),
+    (556199611, "Vivo"),
+    (556199612, "Vivo"),
+    (556199613, "Vivo"),
+    (556199614, "Vivo"),
+    (556199615, "Vivo"),
+    (556199616, "Vivo"),
+    (556199617, "Vivo"),
+    (556199618, "Vivo"),
+    (556199619, "Vivo"),
+    (556199621, "Vivo"),
+    (556199622, "Vivo"),
+    (556199623, "Vivo"),
+    (556199624, "Vivo"),
+    (556199625, "Vivo"),
+    (556199626, "Vivo"),
+    (556199627, "Vivo"),
+    (556199628, "Vivo"),
+    (556199629, "Vivo"),
+    (556199631, "Vivo"),
+    (556199632, "Vivo"),
+    (556199633, "Vivo"),
+    (556199634, "Vivo"),
+    (556199635, "Vivo"),
+    (556199636, "Vivo"),
+    (556199637, "Vivo"),
+    (556199638, "Vivo"),
+    (556199639, "Vivo"),
+    (556199641, "Vivo"),
+    (556199642, "Vivo"),
+    (556199643, "Vivo"),
+    (556199644, "Vivo"),
+    (556199645, "Vivo"),
+    (556199646, "Vivo"),
+    (556199647, "Vivo"),
+    (556199648, "Vivo"),
+    (556199649, "Vivo"),
+    (556199651, "Vivo"),
+    (556199652, "Vivo"),
+    (556199653, "Vivo"),
+    (556199654, "Vivo"),
+    (556199655, "Vivo"),
+    (556199656, "Vivo"),
+    (556199657, "Vivo"),
+    (556199658, "Vivo"),
+    (556199659, "Vivo"),
+    (556199661, "Vivo"),
+    (556199662, "Vivo"),
+    (556199663, "Vivo"),
+    (556199664, "Vivo"),
+    (556199665, "Vivo"),
+    (556199666, "Vivo"),
+    (556199667, "Vivo"),
+    (556199668, "Vivo"),
+    (556199669, "Vivo"),
+    (556199671, "Vivo"),
+    (556199672, "Vivo"),
+    (556199673, "Vivo"),
+    (556199674, "Vivo"),
+    (556199675, "Vivo"),
+    (556199676, "Vivo"),
+    (556199677, "Vivo"),
+    (556199678, "Vivo"),
+    (556199679, "Vivo"),
+    (556199681, "Vivo"),
+    (556199682, "Vivo"),
+    (556199683, "Vivo"),
+    (556199684, "Vivo"),
+    (556199685, "Vivo"),
+    (556199686, "Vivo"),
+    (556199687, "Vivo"),
+    (556199688, "Vivo"),
+    (556199689, "Vivo"),
+    (556199691, "Vivo"),
+    (556199692, "Vivo"),
+    (556199693, "Vivo"),
+    (556199694, "Vivo"),
+    (556199695, "Vivo"),
+    (556199696, "Vivo"),
+    (556199697, "Vivo"),
+    (556199698, "Vivo"),
+    (556199699, "Vivo"),
+    (556199801, "Vivo"),
+    (556199802, "Vivo"),
+    (556199803, "Vivo"),
+    (556199804, "Vivo"),
+    (556199805, "Vivo"),
+    (556199806, "Vivo"),
+    (556199807, "Vivo"),
+    (556199808, "Vivo"),
+    (556199809, "Vivo"),
+    (556199811, "Vivo"),
+    (556199812, "Vivo"),
+    (556199813, "Vivo"),
+    (556199814, "Vivo"),
+    (556199815, "Vivo"),
+    (556199816, "Vivo"),
+    (556199817, "Vivo"),
+    (556199818, "Vivo"),
+    (556199819, "Vivo"),
+    (556199821, "Vivo"),
+    (556199822, "Vivo"),
+    (556199823, "Vivo"),
+    (556199824, "Vivo"),
+    (556199825, "Vivo"),
+    (556199826, "Vivo"),
+    (556199827, "Vivo"),
+    (556199828, "Vivo"),
+    (556199829, "Vivo"),
+    (556199831, "Vivo"),
+    (556199832, "Vivo"),
+    (556199838, "Vivo"),
+    (556199839, "Vivo"),
+    (556199841, "Vivo"),
+    (556199842, "Vivo"),
+    (556199843, "Vivo"),
+    (556199844, "Vivo"),
+    (556199901, "Vivo"),
+    (556199902, "Vivo"),
+    (556199903, "Vivo"),
+    (556199904, "Vivo"),
+    (556199905, "Vivo"),
+    (556199906, "Vivo"),
+    (556199907, "Vivo"),
+    (556199908, "Vivo"),
+    (556199909, "Vivo"),
+    (556199911, "Vivo"),
+    (556199912, "Vivo"),
+    (556199913, "Vivo"),
+    (556199914, "Vivo"),
+    (556199915, "Vivo"),
+    (556199916, "Vivo"),
+    (556199917, "Vivo"),
+    (556199918, "Vivo"),
+    (556199919, "Vivo"),
+    (556199921, "Vivo"),
+    (556199922, "Vivo"),
+    (556199923, "Vivo"),
+    (556199924, "Vivo"),
+    (556199925, "Vivo"),
+    (556199926, "Vivo"),
+    (556199927, "Vivo"),
+    (556199928, "Vivo"),
+    (556199929, "Vivo"),
+    (556199931, "Vivo"),
+    (556199932, "Vivo"),
+    (556199933, "Vivo"),
+    (556199934, "Vivo"),
+    (556199935, "Vivo"),
+    (556199936, "Vivo"),
+    (556199937, "Vivo"),
+    (556199938, "Vivo"),
+    (556199939, "Vivo"),
+    (556199941, "Vivo"),
+    (556199942, "Vivo"),
+    (556199943, "Vivo"),
+    (556199944, "Vivo"),
+    (556199945, "Vivo"),
+    (556199946, "Vivo"),
+    (556199947, "Vivo"),
+    (556199948, "Vivo"),
+    (556199949, "Vivo"),
+    (556199951, "Vivo"),
+    (556199952, "Vivo"),
+    (556199953, "Vivo"),
+    (556199954, "Vivo"),
+    (556199955, "Vivo"),
+    (556199956, "Vivo"),
+    (556199957, "Vivo"),
+    (556199958, "Vivo"),
+    (556199959, "Vivo"),
+    (556298101, "TIM"),
+    (556298102, "TIM"),
+    (556298103, "TIM"),
+    (556298104, "TIM"),
+    (556298111, "TIM"),
+    (556298112, "TIM"),
+    (556298113, "TIM"),
+    (556298114, "TIM"),
+    (556298115, "TIM"),
+    (556298116, "TIM"),
+    (556298117, "TIM"),
+    (556298118, "TIM"),
+    (556298119, "TIM"),
+    (556298121, "TIM"),
+    (556298122, "TIM"),
+    (556298123, "TIM"),
+    (556298124, "TIM"),
+    (556298125, "TIM"),
+    (556298126, "TIM"),
+    (556298127, "TIM"),
+    (556298128, "TIM"),
+    (556298129, "TIM"),
+    (556298131, "TIM"),
+    (556298132, "TIM"),
+    (556298133, "TIM"),
+    (556298134, "TIM"),
+    (556298135, "TIM"),
+    (556298136, "TIM"),
+    (556298137, "TIM"),
+    (556298138, "TIM"),
+    (556298139, "TIM"),
+    (556298141, "TIM"),
+    (556298142, "TIM"),
+    (556298143, "TIM"),
+    (556298144, "TIM"),
+    (556298145, "TIM"),
+    (556298146, "TIM"),
+    (556298147, "TIM"),
+    (556298148, "TIM"),
+    (556298149, "TIM"),
+    (556298151, "TIM"),
+    (556298152, "TIM"),
+    (556298153, "TIM"),
+    (556298154, "TIM"),
+    (556298155, "TIM"),
+    (556298156, "TIM"),
+    (556298157, "TIM"),
+    (556298158, "TIM"),
+    (556298159, "TIM"),
+    (556298161, "TIM"),
+    (556298162, "TIM"),
+    (556298163, "TIM"),
+    (556298164, "TIM"),
+    (556298165, "TIM"),
+    (556298166, "TIM"),
+    (556298167, "TIM"),
+    (556298168, "TIM"),
+    (556298169, "TIM"),
+    (556298171, "TIM"),
+    (556298172, "TIM"),
+    (556298173, "TIM"),
+    (556298174, "TIM"),
+    (556298175, "TIM"),
+    (556298176, "TIM"),
+    (556298177, "TIM"),
+    (556298178, "TIM"),
+    (556298179, "TIM"),
+    (556298181, "TIM"),
+    (556298182, "TIM"),
+    (556298183, "TIM"),
+    (556298184, "TIM"),
+    (556298185, "TIM"),
+    (556298186, "TIM"),
+    (556298187, "TIM"),
+    (556298188, "TIM"),
+    (556298189, "TIM"),
+    (556298191, "TIM"),
+    (556298192, "TIM"),
+    (556298193, "TIM"),
+    (556298194, "TIM"),
+    (556298195, "TIM"),
+    (556298196, "TIM"),
+    (556298197, "TIM"),
+    (556298198, "TIM"),
+    (556298199, "TIM"),
+    (556299661, "Vivo"),
+    (556299662, "Vivo"),
+    (556299663, "Vivo"),
+    (556299664, "Vivo"),
+    (556299665, "Vivo"),
+    (556299666, "Vivo"),
+    (556299667, "Vivo"),
+    (556299668, "Vivo"),
+    (556299669, "Vivo"),
+    (556299671, "Vivo"),
+    (556299672, "Vivo"),
+    (556299673, "Vivo"),
+    (556299674, "Vivo"),
+    (556299675, "Vivo"),
+    (556299676, "Vivo"),
+    (556299677, "Vivo"),
+    (556299678, "Vivo"),
+    (556299679, "Vivo"),
+    (556299681, "Vivo"),
+    (556299682, "Vivo"),
+    (556299683, "Vivo"),
+    (556299684, "Vivo"),
+    (556299685, "Vivo"),
+    (556299686, "Vivo"),
+    (556299687, "Vivo"),
+    (556299688, "Vivo"),
+    (556299689, "Vivo"),
+    (556299691, "Vivo"),
+    (556299692, "Vivo"),
+    (556299693, "Vivo"),
+    (556299694, "Vivo"),
+    (556299695, "Vivo"),
+    (556299696, "Vivo"),
+    (556299697, "Vivo"),
+    (556299698, "Vivo"),
+    (556299699, "Vivo"),
+    (556299801, "Vivo"),
+    (556299802, "Vivo"),
+    (556299803, "Vivo"),
+    (556299804, "Vivo"),
+    (556299805, "Vivo"),
+    (556299806, "Vivo"),
+    (556299807, "Vivo"),
+    (556299808, "Vivo"),
+    (556299809, "Vivo"),
+    (556299811, "Vivo"),
+    (556299812, "Vivo"),
+    (556299813, "Vivo"),
+    (556299814, "Vivo"),
+    (556299815, "Vivo"),
+    (556299816, "Vivo"),
+    (556299817, "Vivo"),
+    (556299818, "Vivo"),
+    (556299901, "Vivo"),
+    (556299902, "Vivo"),
+    (556299903, "Vivo"),
+    (556299904, "Vivo"),
+    (556299905, "Vivo"),
+    (556299906, "Vivo"),
+    (556299907, "Vivo"),
+    (556299908, "Vivo"),
+    (556299909, "Vivo"),
+    (556299911, "Vivo"),
+    (556299912, "Vivo"),
+    (556299913, "Vivo"),
+    (556299914, "Vivo"),
+    (556299915, "Vivo"),
+    (556299916, "Vivo"),
+    (556299917, "Vivo"),
+    (556299918, "Vivo"),
+    (556299919, "Vivo"),
+    (556299921, "Vivo"),
+    (556299922, "Vivo"),
+    (556299923, "Vivo"),
+    (556299924, "Vivo"),
+    (556299925, "Vivo"),
+    (556299926, "Vivo"),
+    (556299927, "Vivo"),
+    (556299928, "Vivo"),
+    (556299929, "Vivo"),
+    (556299931, "Vivo"),
+    (556299932, "Vivo"),
+    (556299933, "Vivo"),
+    (556299934, "Vivo"),
+    (556299935, "Vivo"),
+    (556299936, "Vivo"),
+    (556299937, "Vivo"),
+    (556299938, "Vivo"),
+    (556299939, "Vivo"),
+    (556299941, "Vivo"),
+    (556299942, "Vivo"),
+    (556299943, "Vivo"),
+    (556299944, "Vivo"),
+    (556299945, "Vivo"),
+    (556299946, "Vivo"),
+    (556299947, "Vivo"),
+    (556299948, "Vivo"),
+    (556299949, "Vivo"),
+    (556299951, "Vivo"),
+    (556299952, "Vivo"),
+    (556299953, "Vivo"),
+    (556299954, "Vivo"),
+    (556299955, "Vivo"),
+    (556299956, "Vivo"),
+    (556299957, "Vivo"),
+    (556299958, "Vivo"),
+    (556299959, "Vivo"),
+    (556299991, "Vivo"),
+    (556299992, "Vivo"),
+    (556299993, "Vivo"),
+    (556299994, "Vivo"),
+    (556299995, "Vivo"),
+    (556299996, "Vivo"),
+    (556299997, "Vivo"),
+    (556299998, "Vivo"),
+    (556299999, "Vivo"),
+    (556398111, "TIM"),
+    (556398112, "TIM"),
+    (556398113, "TIM"),
+    (556398114, "TIM"),
+    (556398115, "TIM"),
+    (556398116, "TIM"),
+    (556398117, "TIM"),
+    (556398118, "TIM"),
+    (556398119, "TIM"),
+    (556398121, "TIM"),
+    (556398122, "TIM"),
+    (556398123, "TIM"),
+    (556398124, "TIM"),
+    (556398125, "TIM"),
+    (556398126, "TIM"),
+    (556398127, "TIM"),
+    (556398128, "TIM"),
+    (556398129, "TIM"),
+    (556398131, "TIM"),
+    (556398132, "TIM"),
+    (556398133, "TIM"),
+    (556398401, "Brasil Telecom GSM"),
+    (556398402, "Brasil Telecom GSM"),
+    (556398403, "Brasil Telecom GSM"),
+    (556398404, "Brasil Telecom GSM"),
+    (556398405, "Brasil Telecom GSM"),
+    (556398406, "Brasil Telecom GSM"),
+    (556398407, "Brasil Telecom GSM"),
+    (556398408, "Brasil Telecom GSM"),
+    (556398409, "Brasil Telecom GSM"),
+    (556398411, "Brasil Telecom GSM"),
+    (556398412, "Brasil Telecom GSM"),
+    (556398413, "Brasil Telecom GSM"),
+    (556398414, "Brasil Telecom GSM"),
+    (556398415, "Brasil Telecom GSM"),
+    (556398416, "Brasil Telecom GSM"),
+    (556398417, "Brasil Telecom GSM"),
+    (556398418, "Brasil Telecom GSM"),
+    (556398419, "Brasil Telecom GSM"),
+    (556398421, "Brasil Telecom GSM"),
+    (556398422, "Brasil Telecom GSM"),
+    (556398423, "Brasil Telecom GSM"),
+    (556398424, "Brasil Telecom GSM"),
+    (556398425, "Brasil Telecom GSM"),
+    (556398426, "Brasil Telecom GSM"),
+    (556398427, "Brasil Telecom GSM"),
+    (556398428, "Brasil Telecom GSM"),
+    (556398429, "Brasil Telecom GSM"),
+    (556398431, "Brasil Telecom GSM"),
+    (556398432, "Brasil Telecom GSM"),
+    (556398433, "Brasil Telecom GSM"),
+    (556398434, "Brasil Telecom GSM"),
+    (556398435, "Brasil Telecom GSM"),
+    (556398436, "Brasil Telecom GSM"),
+    (556398437, "Brasil Telecom GSM"),
+    (556398438, "Brasil Telecom GSM"),
+    (556398439, "Brasil Telecom GSM"),
+    (556398441, "Brasil Telecom GSM"),
+    (556398442, "Brasil Telecom GSM"),
+    (556398443, "Brasil Telecom GSM"),
+    (556399911, "Vivo"),
+    (556399941, "Vivo"),
+    (556399942, "Vivo"),
+    (556399943, "Vivo"),
+    (556399944, "Vivo"),
+    (556399945, "Vivo"),
+    (556399946, "Vivo"),
+    (556399947, "Vivo"),
+    (556399948, "Vivo"),
+    (556399949, "Vivo"),
+    (556399951, "Vivo"),
+    (556399952, "Vivo"),
+    (556399953, "Vivo"),
+    (556399954, "Vivo"),
+    (556399955, "Vivo"),
+    (556399956, "Vivo"),
+    (556399957, "Vivo"),
+    (556399958, "Vivo"),
+    (556399959, "Vivo"),
+    (556399961, "Vivo"),
+    (556399962, "Vivo"),
+    (556399963, "Vivo"),
+    (556399964, "Vivo"),
+    (556399965, "Vivo"),
+    (556399966, "Vivo"),
+    (556399967, "Vivo"),
+    (556399968, "Vivo"),
+    (556399969, "Vivo"),
+    (556399991, "Vivo"),
+    (556399992, "Vivo"),
+    (556399993, "Vivo"),
+    (556399994, "Vivo"),
+    (556399995, "Vivo"),
+    (556399996, "Vivo"),
+    (556399997, "Vivo"),
+    (556399998, "Vivo"),
+    (556399999, "Vivo"),
+    (556498111, "TIM"),
+    (556498112, "TIM"),
+    (556498113, "TIM"),
+    (556498114, "TIM"),
+    (556498115, "TIM"),
+    (556498116, "TIM"),
+    (556498117, "TIM"),
+    (556498118, "TIM"),
+    (556498119, "TIM"),
+    (556498121, "TIM"),
+    (556498122, "TIM"),
+    (556498123, "TIM"),
+    (556498124, "TIM"),
+    (556498125, "TIM"),
+    (556498126, "TIM"),
+    (556498127, "TIM"),
+    (556498128, "TIM"),
+    (556498129, "TIM"),
+    (556498131, "TIM"),
+    (556498132, "TIM"),
+    (556498133, "TIM"),
+    (556498134, "TIM"),
+    (556498135, "TIM"),
+    (556498136, "TIM"),
+    (556498137, "TIM"),
+    (556498138, "TIM"),
+    (556498139, "TIM"),
+    (556498141, "TIM"),
+    (556498401, "Brasil Telecom GSM"),
+    (556498402, "Brasil Telecom GSM"),
+    (556498403, "Brasil Telecom GSM"),
+    (556498404, "Brasil Telecom GSM"),
+    (556498405, "Brasil Telecom GSM"),
+    (556498406, "Brasil Telecom GSM"),
+    (556498407, "Brasil Telecom GSM"),
+    (556498408, "Brasil Telecom GSM"),
+    (556498409, "Brasil Telecom GSM"),
+    (556498411, "Brasil Telecom GSM"),
+    (556498412, "Brasil Telecom GSM"),
+    (556498413, "Brasil Telecom GSM"),
+    (556498414, "Brasil Telecom GSM"),
+    (556498415, "Brasil Telecom GSM"),
+    (556498416, "Brasil Telecom GSM"),
+    (556498417, "Brasil Telecom GSM"),
+    (556498418, "Brasil Telecom GSM"),
+    (556498419, "Brasil Telecom GSM"),
+    (556498421, "Brasil Telecom GSM"),
+    (556498422, "Brasil Telecom GSM"),
+    (556498423, "Brasil Telecom GSM"),
+    (556498424, "Brasil Telecom GSM"),
+    (556498425, "Brasil Telecom GSM"),
+    (556498426, "Brasil Telecom GSM"),
+    (556498427, "Brasil Telecom GSM"),
+    (556498428, "Brasil Telecom GSM"),
+    (556498429, "Brasil Telecom GSM"),
+    (556498431, "Brasil Telecom GSM"),
+    (556498432, "Brasil Telecom GSM"),
+    (556498433, "Brasil Telecom GSM"),
+    (556498434, "Brasil Telecom GSM"),
+    (556498435, "Brasil Telecom GSM"),
+    (556498436, "Brasil Telecom GSM"),
+    (556498437, "Brasil Telecom GSM"),
+    (556498438, "Brasil Telecom GSM"),
+    (556498439, "Brasil Telecom GSM"),
+    (556498441, "Brasil Telecom GSM"),
+    (556499606, "Vivo"),
+    (556499607, "Vivo"),
+    (556499611, "Vivo"),
+    (556499618, "Vivo"),
+    (556499623, "Vivo"),
+    (556499624, "Vivo"),
+    (556499625, "Vivo"),
+    (556499626, "Vivo"),
+    (556499627, "Vivo"),
+    (556499641, "Vivo"),
+    (556499642, "Vivo"),
+    (556499643, "Vivo"),
+    (556499644, "Vivo"),
+    (556499645, "Vivo"),
+    (556499646, "Vivo"),
+    (556499647, "Vivo"),
+    (556499648, "Vivo"),
+    (556499652, "Vivo"),
+    (556499653, "Vivo"),
+    (556499654, "Vivo"),
+    (556499655, "Vivo"),
+    (556499658, "Vivo"),
+    (556499671, "Vivo"),
+    (556499675, "Vivo"),
+    (556499676, "Vivo"),
+    (556499695, "Vivo"),
+    (556499699, "Vivo"),
+    (556499902, "Vivo"),
+    (556499905, "Vivo"),
+    (556499906, "Vivo"),
+    (556499907, "Vivo"),
+    (556499911, "Vivo"),
+    (556499931, "Vivo"),
+    (556499935, "Vivo"),
+    (556499937, "Vivo"),
+    (556499938, "Vivo"),
+    (556499939, "Vivo"),
+    (556499941, "Vivo"),
+    (556499942, "Vivo"),
+    (556499943, "Vivo"),
+    (556499944, "Vivo"),
+    (556499945, "Vivo"),
+    (556499946, "Vivo"),
+    (556499947, "Vivo"),
+    (556499948, "Vivo"),
+    (556499949, "Vivo"),
+    (556499951, "Vivo"),
+    (556499952, "Vivo"),
+    (556499953, "Vivo"),
+    (556499954, "Vivo"),
+    (556499955, "Vivo"),
+    (556499956, "Vivo"),
+    (556499957, "Vivo"),
+    (556499958, "Vivo"),
+    (556499959, "Vivo"),
+    (556499961, "Vivo"),
+    (556499962, "Vivo"),
+    (556499963, "Vivo"),
+    (556499964, "Vivo"),
+    (556499965, "Vivo"),
+    (556499966, "Vivo"),
+    (556499967, "Vivo"),
+    (556499968, "Vivo"),
+    (556499969, "Vivo"),
+    (556499991, "Vivo"),
+    (556499994, "Vivo"),
+    (556499995, "Vivo"),
+    (556499996, "Vivo"),
+    (556499997, "Vivo"),
+    (556499998, "Vivo"),
+    (556598111, "TIM"),
+    (556598112, "TIM"),
+    (556598113, "TIM"),
+    (556598114, "TIM"),
+    (556598115, "TIM"),
+    (556598116, "TIM"),
+    (556598117, "TIM"),
+    (556598118, "TIM"),
+    (556598119, "TIM"),
+    (556598121, "TIM"),
+    (556598122, "TIM"),
+    (556598123, "TIM"),
+    (556598124, "TIM"),
+    (556598125, "TIM"),
+    (556598126, "TIM"),
+    (556598127, "TIM"),
+    (556598128, "TIM"),
+    (556598129, "TIM"),
+    (556598131, "TIM"),
+    (556598132, "TIM"),
+    (556598133, "TIM"),
+    (556598134, "TIM"),
+    (556598135, "TIM"),
+    (556598136, "TIM"),
+    (556598137, "TIM"),
+    (556598138, "TIM"),
+    (556598139, "TIM"),
+    (556598141, "TIM"),
+    (556598142, "TIM"),
+    (556598401, "Brasil Telecom GSM"),
+    (556598402, "Brasil Telecom GSM"),
+    (556598403, "Brasil Telecom GSM"),
+    (556598404, "Brasil Telecom GSM"),
+    (556598405, "Brasil Telecom GSM"),
+    (556598406, "Brasil Telecom GSM"),
+    (556598407, "Brasil Telecom GSM"),
+    (556598408, "Brasil Telecom GSM"),
+    (556598409, "Brasil Telecom GSM"),
+    (556598411, "Brasil Telecom GSM"),
+    (556598412, "Brasil Telecom GSM"),
+    (556598413, "Brasil Telecom GSM"),
+    (556598414, "Brasil Telecom GSM"),
+    (556598415, "Brasil Telecom GSM"),
+    (556598416, "Brasil Telecom GSM"),
+    (556598417, "Brasil Telecom GSM"),
+    (556598418, "Brasil Telecom GSM"),
+    (556598419, "Brasil Telecom GSM"),
+    (556598421, "Brasil Telecom GSM"),
+    (556598422, "Brasil Telecom GSM"),
+    (556598423, "Brasil Telecom GSM"),
+    (556598424, "Brasil Telecom GSM"),
+    (556598425, "Brasil Telecom GSM"),
+    (556598426, "Brasil Telecom GSM"),
+    (556598427, "Brasil Telecom GSM"),
+    (556598428, "Brasil Telecom GSM"),
+    (556598429, "Brasil Telecom GSM"),
+    (556598431, "Brasil Telecom GSM"),
+    (556598432, "Brasil Telecom GSM"),
+    (556598433, "Brasil Telecom GSM"),
+    (556598434, "Brasil Telecom GSM"),
+    (556598435, "Brasil Telecom GSM"),
+    (556598436, "Brasil Telecom GSM"),
+    (556598437, "Brasil Telecom GSM"),
+    (556598438, "Brasil Telecom GSM"),
+    (556598439, "Brasil Telecom GSM"),
+    (556598441, "Brasil Telecom GSM"),
+    (556598442, "Brasil Telecom GSM"),
+    (556598443, "Brasil Telecom GSM"),
+    (556598444, "Brasil Telecom GSM"),
+    (556598445, "Brasil Telecom GSM"),
+    (556598446, "Brasil Telecom GSM"),
+    (556598447, "Brasil Telecom GSM"),
+    (556598448, "Brasil Telecom GSM"),
+    (556598449, "Brasil Telecom GSM"),
+    (556598451, "Brasil Telecom GSM"),
+    (556598452, "Brasil Telecom GSM"),
+    (556598453, "Brasil Telecom GSM"),
+    (556598454, "Brasil Telecom GSM"),
+    (556598455, "Brasil Telecom GSM"),
+    (556598456, "Brasil Telecom GSM"),
+    (556598457, "Brasil Telecom GSM"),
+    (556599601, "Vivo"),
+    (556599602, "Vivo"),
+    (556599603, "Vivo"),
+    (556599604, "Vivo"),
+    (556599605, "Vivo"),
+    (556599606, "Vivo"),
+    (556599607, "Vivo"),
+    (556599608, "Vivo"),
+    (556599609, "Vivo"),
+    (556599611, "Vivo"),
+    (556599612, "Vivo"),
+    (556599613, "Vivo"),
+    (556599614, "Vivo"),
+    (556599615, "Vivo"),
+    (556599616, "Vivo"),
+    (556599617, "Vivo"),
+    (556599618, "Vivo"),
+    (556599619, "Vivo"),
+    (556599621, "Vivo"),
+    (556599622, "Vivo"),
+    (556599623, "Vivo"),
+    (556599624, "Vivo"),
+    (556599625, "Vivo"),
+    (556599626, "Vivo"),
+    (556599627, "Vivo"),
+    (556599628, "Vivo"),
+    (556599629, "Vivo"),
+    (556599631, "Vivo"),
+    (556599632, "Vivo"),
+    (556599633, "Vivo"),
+    (556599634, "Vivo"),
+    (556599635, "Vivo"),
+    (556599636, "Vivo"),
+    (556599637, "Vivo"),
+    (556599638, "Vivo"),
+    (556599639, "Vivo"),
+    (556599641, "Vivo"),
+    (556599642, "Vivo"),
+    (556599643, "Vivo"),
+    (556599644, "Vivo"),
+    (556599645, "Vivo"),
+    (556599646, "Vivo"),
+    (556599647, "Vivo"),
+    (556599648, "Vivo"),
+    (556599649, "Vivo"),
+    (556599651, "Vivo"),
+    (556599652, "Vivo"),
+    (556599653, "Vivo"),
+    (556599654, "Vivo"),
+    (556599655, "Vivo"),
+    (556599656, "Vivo"),
+    (556599657, "Vivo"),
+    (556599658, "Vivo"),
+    (556599659, "Vivo"),
+    (556599661, "Vivo"),
+    (556599662, "Vivo"),
+    (556599663, "Vivo"),
+    (556599664, "Vivo"),
+    (556599665, "Vivo"),
+    (556599666, "Vivo"),
+    (556599667, "Vivo"),
+    (556599668, "Vivo"),
+    (556599669, "Vivo"),
+    (556599671, "Vivo"),
+    (556599672, "Vivo"),
+    (556599673, "Vivo"),
+    (556599674, "Vivo"),
+    (556599675, "Vivo"),
+    (556599676, "Vivo"),
+    (556599677, "Vivo"),
+    (556599901, "Vivo"),
+    (556599902, "Vivo"),
+    (556599903, "Vivo"),
+    (556599904, "Vivo"),
+    (556599905, "Vivo"),
+    (556599906, "Vivo"),
+    (556599907, "Vivo"),
+    (556599908, "Vivo"),
+    (556599909, "Vivo"),
+    (556599911, "Vivo"),
+    (556599912, "Vivo"),
+    (556599913, "Vivo"),
+    (556599914, "Vivo"),
+    (556599915, "Vivo"),
+    (556599916, "Vivo"),
+    (556599917, "Vivo"),
+    (556599918, "Vivo"),
+    (556599919, "Vivo"),
+    (556599921, "Vivo"),
+    (556599922, "Vivo"),
+    (556599923, "Vivo"),
+    (556599924, "Vivo"),
+    (556599925, "Vivo"),
+    (556599926, "Vivo"),
+    (556599927, "Vivo"),
+    (556599928, "Vivo"),
+    (556599929, "Vivo"),
+    (556599931, "Vivo"),
+    (556599932, "Vivo"),
+    (556599933, "Vivo"),
+    (556599934, "Vivo"),
+    (556599935, "Vivo"),
+    (556599936, "Vivo"),
+    (556599937, "Vivo"),
+    (556599938, "Vivo"),
+    (556599939, "Vivo"),
+    (556599941, "Vivo"),
+    (556599942, "Vivo"),
+    (556599943, "Vivo"),
+    (556599944, "Vivo"),
+    (556599945, "Vivo"),
+    (556599946, "Vivo"),
+    (556599947, "Vivo"),
+    (556599948, "Vivo"),
+    (556599949, "Vivo"),
+    (556599951, "Vivo"),
+    (556599952, "Vivo"),
+    (556599953, "Vivo"),
+    (556599954, "Vivo"),
+    (556599955, "Vivo"),
+    (556599956, "Vivo"),
+    (556599957, "Vivo"),
+    (556599958, "Vivo"),
+    (556599959, "Vivo"),
+    (556599991, "Vivo"),
+    (556599992, "Vivo"),
+    (556599993, "Vivo"),
+    (556599994, "Vivo"),
+    (556599995, "Vivo"),
+    (556599996, "Vivo"),
+    (556599997, "Vivo"),
+    (556599998, "Vivo"),
+    (556599999, "Vivo"),
+    (556698111, "TIM"),
+    (556698112, "TIM"),
+    (556698113, "TIM"),
+    (556698114, "TIM"),
+    (556698115, "TIM"),
+    (556698116, "TIM"),
+    (556698117, "TIM"),
+    (556698118, "TIM"),
+    (556698119, "TIM"),
+    (556698121, "TIM"),
+    (556698122, "TIM"),
+    (556698123, "TIM"),
+    (556698124, "TIM"),
+    (556698125, "TIM"),
+    (556698126, "TIM"),
+    (556698127, "TIM"),
+    (556698128, "TIM"),
+    (556698129, "TIM"),
+    (556698131, "TIM"),
+    (556698132, "TIM"),
+    (556698401, "Brasil Telecom GSM"),
+    (556698402, "Brasil Telecom GSM"),
+    (556698403, "Brasil Telecom GSM"),
+    (556698404, "Brasil Telecom GSM"),
+    (556698405, "Brasil Telecom GSM"),
+    (556698406, "Brasil Telecom GSM"),
+    (556698407, "Brasil Telecom GSM"),
+    (556698408, "Brasil Telecom GSM"),
+    (556698409, "Brasil Telecom GSM"),
+    (556698411, "Brasil Telecom GSM"),
+    (556698412, "Brasil Telecom GSM"),
+    (556698413, "Brasil Telecom GSM"),
+    (556698414, "Brasil Telecom GSM"),
+    (556698415, "Brasil Telecom GSM"),
+    (556698416, "Brasil Telecom GSM"),
+    (556698417, "Brasil Telecom GSM"),
+    (556698418, "Brasil Telecom GSM"),
+    (556698419, "Brasil Telecom GSM"),
+    (556698421, "Brasil Telecom GSM"),
+    (556698422, "Brasil Telecom GSM"),
+    (556698423, "Brasil Telecom GSM"),
+    (556698424, "Brasil Telecom GSM"),
+    (556698425, "Brasil Telecom GSM"),
+    (556698426, "Brasil Telecom GSM"),
+    (556698427, "Brasil Telecom GSM"),
+    (556698428, "Brasil Telecom GSM"),
+    (556699901, "Vivo"),
+    (556699902, "Vivo"),
+    (556699903, "Vivo"),
+    (556699904, "Vivo"),
+    (556699905, "Vivo"),
+    (556699906, "Vivo"),
+    (556699907, "Vivo"),
+    (556699908, "Vivo"),
+    (556699909, "Vivo"),
+    (556699911, "Vivo"),
+    (556699912, "Vivo"),
+    (556699913, "Vivo"),
+    (556699951, "Vivo"),
+    (556699952, "Vivo"),
+    (556699953, "Vivo"),
+    (556699954, "Vivo"),
+    (556699955, "Vivo"),
+    (556699956, "Vivo"),
+    (556699957, "Vivo"),
+    (556699958, "Vivo"),
+    (556699959, "Vivo"),
+    (556699961, "Vivo"),
+    (556699962, "Vivo"),
+    (556699963, "Vivo"),
+    (556699964, "Vivo"),
+    (556699965, "Vivo"),
+    (556699966, "Vivo"),
+    (556699967, "Vivo"),
+    (556699968, "Vivo"),
+    (556699969, "Vivo"),
+    (556699991, "Vivo"),
+    (556699992, "Vivo"),
+    (556699993, "Vivo"),
+    (556699994, "Vivo"),
+    (556699995, "Vivo"),
+    (556699996, "Vivo"),
+    (556699997, "Vivo"),
+    (556699998, "Vivo"),
+    (556699999, "Vivo"),
+    (556798111, "TIM"),
+    (556798112, "TIM"),
+    (556798113, "TIM"),
+    (556798114, "TIM"),
+    (556798115, "TIM"),
+    (556798116, "TIM"),
+    (556798117, "TIM"),
+    (556798118, "TIM"),
+    (556798119, "TIM"),
+    (556798121, "TIM"),
+    (556798122, "TIM"),
+    (556798123, "TIM"),
+    (556798124, "TIM"),
+    (556798125, "TIM"),
+    (556798126, "TIM"),
+    (556798127, "TIM"),
+    (556798128, "TIM"),
+    (556798129, "TIM"),
+    (556798131, "TIM"),
+    (556798132, "TIM"),
+    (556798133, "TIM"),
+    (556798134, "TIM"),
+    (556798135, "TIM"),
+    (556798136, "TIM"),
+    (556798137, "TIM"),
+    (556798138, "TIM"),
+    (556798139, "TIM"),
+    (556798141, "TIM"),
+    (556798142, "TIM"),
+    (556798143, "TIM"),
+    (556798144, "TIM"),
+    (556798145, "TIM"),
+    (556798146, "TIM"),
+    (556798147, "TIM"),
+    (556798148, "TIM"),
+    (556798149, "TIM"),
+    (556798151, "TIM"),
+    (556798152, "TIM"),
+    (556798153, "TIM"),
+    (556798154, "TIM"),
+    (556798155, "TIM"),
+    (556798156, "TIM"),
+    (556798157, "TIM"),
+    (556798158, "TIM"),
+    (556798159, "TIM"),
+    (556798161, "TIM"),
+    (556798162, "TIM"),
+    (556798163, "TIM"),
+    (556798164, "TIM"),
+    (556798167, "TIM"),
+    (556798401, "Brasil Telecom GSM"),
+    (556798402, "Brasil Telecom GSM"),
+    (556798403, "Brasil Telecom GSM"),
+    (556798404, "Brasil Telecom GSM"),
+    (556798405, "Brasil Telecom GSM"),
+    (556798406, "Brasil Telecom GSM"),
+    (556798407, "Brasil Telecom GSM"),
+    (556798408, "Brasil Telecom GSM"),
+    (556798409, "Brasil Telecom GSM"),
+    (556798411, "Brasil Telecom GSM"),
+    (556798412, "Brasil Telecom GSM"),
+    (556798413, "Brasil Telecom GSM"),
+    (556798414, "Brasil Telecom GSM"),
+    (556798415, "Brasil Telecom GSM"),
+    (556798416, "Brasil Telecom GSM"),
+    (556798417, "Brasil Telecom GSM"),
+    (556798418, "Brasil Telecom GSM"),
+    (556798419, "Brasil Telecom GSM"),
+    (556798421, "Brasil Telecom GSM"),
+    (556798422, "Brasil Telecom GSM"),
+    (556798423, "Brasil Telecom GSM"),
+    (556798424, "Brasil Telecom GSM"),
+    (556798425, "Brasil Telecom GSM"),
+    (556798426, "Brasil Telecom GSM"),
+    (556798427, "Brasil Telecom GSM"),
+    (556798428, "Brasil Telecom GSM"),
+    (556798429, "Brasil Telecom GSM"),
+    (556798431, "Brasil Telecom GSM"),
+    (556798432, "Brasil Telecom GSM"),
+    (556798433, "Brasil Telecom GSM"),
+    (556798434, "Brasil Telecom GSM"),
+    (556798435, "Brasil Telecom GSM"),
+    (556798436, "Brasil Telecom GSM"),
+    (556798437, "Brasil Telecom GSM"),
+    (556798438, "Brasil Telecom GSM"),
+    (556798439, "Brasil Telecom GSM"),
+    (556798441, "Brasil Telecom GSM"),
+    (556798442, "Brasil Telecom GSM"),
+    (556798443, "Brasil Telecom GSM"),
+    (556798444, "Brasil Telecom GSM"),
+    (556798445, "Brasil Telecom GSM"),
+    (556798446, "Brasil Telecom GSM"),
+    (556798447, "Brasil Telecom GSM"),
+    (556798448, "Brasil Telecom GSM"),
+    (556798449, "Brasil Telecom GSM"),
+    (556798451, "Brasil Telecom GSM"),
+    (556798452, "Brasil Telecom GSM"),
+    (556798453, "Brasil Telecom GSM"),
+    (556798454, "Brasil Telecom GSM"),
+    (556799810, "Vivo"),
+    (556799811, "Vivo"),
+    (556799812, "Vivo"),
+    (556799813, "Vivo"),
+    (556799814, "Vivo"),
+    (556898111, "TIM"),
+    (556898112, "TIM"),
+    (556898113, "TIM"),
+    (556898114, "TIM"),
+    (556898115, "TIM"),
+    (556898117, "TIM"),
+    (556898118, "TIM"),
+    (556898119, "TIM"),
+    (556898121, "TIM"),
+    (556898401, "Brasil Telecom GSM"),
+    (556898402, "Brasil Telecom GSM"),
+    (556898403, "Brasil Telecom GSM"),
+    (556898404, "Brasil Telecom GSM"),
+    (556898405, "Brasil Telecom GSM"),
+    (556898406, "Brasil Telecom GSM"),
+    (556898407, "Brasil Telecom GSM"),
+    (556898408, "Brasil Telecom GSM"),
+    (556898409, "Brasil Telecom GSM"),
+    (556898411, "Brasil Telecom GSM"),
+    (556898412, "Brasil Telecom GSM"),
+    (556898413, "Brasil Telecom GSM"),
+    (556898414, "Brasil Telecom GSM"),
+    (556898415, "Brasil Telecom GSM"),
+    (556898416, "Brasil Telecom GSM"),
+    (556898417, "Brasil Telecom GSM"),
+    (556898418, "Brasil Telecom GSM"),
+    (556899911, "Vivo"),
+    (556899931, "Vivo"),
+    (556899932, "Vivo"),
+    (556899933, "Vivo"),
+    (556899934, "Vivo"),
+    (556899935, "Vivo"),
+    (556899936, "Vivo"),
+    (556899937, "Vivo"),
+    (556899938, "Vivo"),
+    (556899939, "Vivo"),
+    (556899941, "Vivo"),
+    (556899942, "Vivo"),
+    (556899943, "Vivo"),
+    (556899944, "Vivo"),
+    (556899945, "Vivo"),
+    (556899946, "Vivo"),
+    (556899947, "Vivo"),
+    (556899948, "Vivo"),
+    (556899949, "Vivo"),
+    (556899951, "Vivo"),
+    (556899952, "Vivo"),
+    (556899953, "Vivo"),
+    (556899954, "Vivo"),
+    (556899955, "Vivo"),
+    (556899956, "Vivo"),
+    (556899957, "Vivo"),
+    (556899958, "Vivo"),
+    (556899959, "Vivo"),
+    (556899961, "Vivo"),
+    (556899962, "Vivo"),
+    (556899963, "Vivo"),
+    (556899964, "Vivo"),
+    (556899965, "Vivo"),
+    (556899966, "Vivo"),
+    (556899967, "Vivo"),
+    (556899968, "Vivo"),
+    (556899969, "Vivo"),
+    (556899991, "Vivo"),
+    (556899992, "Vivo"),
+    (556899993, "Vivo"),
+    (556899994, "Vivo"),
+    (556899995, "Vivo"),
+    (556899996, "Vivo"),
+    (556899997, "Vivo"),
+    (556899998, "Vivo"),
+    (556899999, "Vivo"),
+    (556998111, "TIM"),
+    (556998112, "TIM"),
+    (556998113, "TIM"),
+    (556998114, "TIM"),
+    (556998115, "TIM"),
+    (556998116, "TIM"),
+    (556998117, "TIM"),
+    (556998118, "TIM"),
+    (556998119, "TIM"),
+    (556998121, "TIM"),
+    (556998122, "TIM"),
+    (556998123, "TIM"),
+    (556998124, "TIM"),
+    (556998125, "TIM"),
+    (556998126, "TIM"),
+    (556998127, "TIM"),
+    (556998128, "TIM"),
+    (556998401, "Brasil Telecom GSM"),
+    (556998402, "Brasil Telecom GSM"),
+    (556998403, "Brasil Telecom GSM"),
+    (556998404, "Brasil Telecom GSM"),
+    (556998405, "Brasil Telecom GSM"),
+    (556998406, "Brasil Telecom GSM"),
+    (556998407, "Brasil Telecom GSM"),
+    (556998408, "Brasil Telecom GSM"),
+    (556998409, "Brasil Telecom GSM"),
+    (556998411, "Brasil Telecom GSM"),
+    (556998412, "Brasil Telecom GSM"),
+    (556998413, "Brasil Telecom GSM"),
+    (556998414, "Brasil Telecom GSM"),
+    (556998415, "Brasil Telecom GSM"),
+    (556998416, "Brasil Telecom GSM"),
+    (556998417, "Brasil Telecom GSM"),
+    (556998418, "Brasil Telecom GSM"),
+    (556998419, "Brasil Telecom GSM"),
+    (556998421, "Brasil Telecom GSM"),
+    (556998422, "Brasil Telecom GSM"),
+    (556998423, "Brasil Telecom GSM"),
+    (556998424, "Brasil Telecom GSM"),
+    (556998425, "Brasil Telecom GSM"),
+    (556998426, "Brasil Telecom GSM"),
+    (556998427, "Brasil Telecom GSM"),
+    (556998428, "Brasil Telecom GSM"),
+    (556998429, "Brasil Telecom GSM"),
+    (556998431, "Brasil Telecom GSM"),
+    (556998432, "Brasil Telecom GSM"),
+    (556998433, "Brasil Telecom GSM"),
+    (556998434, "Brasil Telecom GSM"),
+    (556998435, "Brasil Telecom GSM"),
+    (556998436, "Brasil Telecom GSM"),
+    (556998437, "Brasil Telecom GSM"),
+    (556998438, "Brasil Telecom GSM"),
+    (556998439, "Brasil Telecom GSM"),
+    (556998441, "Brasil Telecom GSM"),
+    (556998442, "Brasil Telecom GSM"),
+    (556998443, "Brasil Telecom GSM"),
+    (556998444, "Brasil Telecom GSM"),
+    (556998445, "Brasil Telecom GSM"),
+    (556998446, "Brasil Telecom GSM"),
+    (556998447, "Brasil Telecom GSM"),
+    (556998448, "Brasil Telecom GSM"),
+    (556998449, "Brasil Telecom GSM"),
+    (556998451, "Brasil Telecom GSM"),
+    (556998452, "Brasil Telecom GSM"),
+    (556998453, "Brasil Telecom GSM"),
+    (556998454, "Brasil Telecom GSM"),
+    (556998455, "Brasil Telecom GSM"),
+    (556998456, "Brasil Telecom GSM"),
+    (556998457, "Brasil Telecom GSM"),
+    (556998458, "Brasil Telecom GSM"),
+    (556998459, "Brasil Telecom GSM"),
+    (556998461, "Brasil Telecom GSM"),
+    (556998462, "Brasil Telecom GSM"),
+    (556998463, "Brasil Telecom GSM"),
+    (556998465, "Brasil Telecom GSM"),
+    (556998466, "Brasil Telecom GSM"),
+    (556998467, "Brasil Telecom GSM"),
+    (556999901, "Vivo"),
+    (556999902, "Vivo"),
+    (556999903, "Vivo"),
+    (556999904, "Vivo"),
+    (556999905, "Vivo"),
+    (556999906, "Vivo"),
+    (556999907, "Vivo"),
+    (556999908, "Vivo"),
+    (556999909, "Vivo"),
+    (556999911, "Vivo"),
+    (556999912, "Vivo"),
+    (556999913, "Vivo"),
+    (556999914, "Vivo"),
+    (556999915, "Vivo"),
+    (556999916, "Vivo"),
+    (556999917, "Vivo"),
+    (556999918, "Vivo"),
+    (556999919, "Vivo"),
+    (556999921, "Vivo"),
+    (556999922, "Vivo"),
+    (556999923, "Vivo"),
+    (556999924, "Vivo"),
+    (556999925, "Vivo"),
+    (556999926, "Vivo"),
+    (556999927, "Vivo"),
+    (556999928, "Vivo"),
+    (556999929, "Vivo"),
+    (556999931, "Vivo"),
+    (556999932, "Vivo"),
+    (556999951, "Vivo"),
+    (556999952, "Vivo"),
+    (556999953, "Vivo"),
+    (556999954, "Vivo"),
+    (556999955, "Vivo"),
+    (556999956, "Vivo"),
+    (556999957, "Vivo"),
+    (556999958, "Vivo"),
+    (556999959, "Vivo"),
+    (556999991, "Vivo"),
+    (556999992, "Vivo"),
+    (556999993, "Vivo"),
+    (556999994, "Vivo"),
+    (556999995, "Vivo"),
+    (556999996, "Vivo"),
+    (556999997, "Vivo"),
+    (556999998, "Vivo"),
+    (556999999, "Vivo"),
+    (557198360, "Claro"),
+    (557198361, "Claro"),
+    (557198362, "Claro"),
+    (557199201, "TIM"),
+    (557199202, "TIM"),
+    (557199203, "TIM"),
+    (557199204, "TIM"),
+    (557199205, "TIM"),
+    (557199206, "TIM"),
+    (557199207, "TIM"),
+    (557199208, "TIM"),
+    (557199209, "TIM"),
+    (557199211, "TIM"),
+    (557199212, "TIM"),
+    (557199213, "TIM"),
+    (557199214, "TIM"),
+    (557199215, "TIM"),
+    (557199216, "TIM"),
+    (557199217, "TIM"),
+    (557199218, "TIM"),
+    (557199219, "TIM"),
+    (557199221, "TIM"),
+    (557199222, "TIM"),
+    (557199223, "TIM"),
+    (557199224, "TIM"),
+    (557199225, "TIM"),
+    (557199226, "TIM"),
+    (557199227, "TIM"),
+    (557199228, "TIM"),
+    (557199229, "TIM"),
+    (557199231, "TIM"),
+    (557199232, "TIM"),
+    (557199233, "TIM"),
+    (557199234, "TIM"),
+    (557199235, "TIM"),
+    (557199236, "TIM"),
+    (557199237, "TIM"),
+    (557199238, "TIM"),
+    (557199239, "TIM"),
+    (557199241, "TIM"),
+    (557199242, "TIM"),
+    (557199243, "TIM"),
+    (557199244, "TIM"),
+    (557199245, "TIM"),
+    (557199246, "TIM"),
+    (557199247, "TIM"),
+    (557199248, "TIM"),
+    (557199249, "TIM"),
+    (557199251, "TIM"),
+    (557199252, "TIM"),
+    (557199253, "TIM"),
+    (557199254, "TIM"),
+    (557199255, "TIM"),
+    (557199256, "TIM"),
+    (557199257, "TIM"),
+    (557199258, "TIM"),
+    (557199259, "TIM"),
+    (557199261, "TIM"),
+    (557199262, "TIM"),
+    (557199263, "TIM"),
+    (557199264, "TIM"),
+    (557199265, "TIM"),
+    (557199266, "TIM"),
+    (557199267, "TIM"),
+    (557199268, "TIM"),
+    (557199269, "TIM"),
+    (557199271, "TIM"),
+    (557199272, "TIM"),
+    (557199273, "TIM"),
+    (557199274, "TIM"),
+    (557199275, "TIM"),
+    (557199276, "TIM"),
+    (557199277, "TIM"),
+    (557199278, "TIM"),
+    (557199279, "TIM"),
+    (557199287, "TIM"),
+    (557199690, "Vivo"),
+    (557199691, "Vivo"),
+    (557199692, "Vivo"),
+    (557199901, "Vivo"),
+    (557199902, "Vivo"),
+    (557199903, "Vivo"),
+    (557199904, "Vivo"),
+    (557199905, "Vivo"),
+    (557199906, "Vivo"),
+    (557199907, "Vivo"),
+    (557199908, "Vivo"),
+    (557199909, "Vivo"),
+    (557199911, "Vivo"),
+    (557199912, "Vivo"),
+    (557199913, "Vivo"),
+    (557199914, "Vivo"),
+    (557199915, "Vivo"),
+    (557199916, "Vivo"),
+    (557199917, "Vivo"),
+    (557199918, "Vivo"),
+    (557199919, "Vivo"),
+    (557199921, "Vivo"),
+    (557199922, "Vivo"),
+    (557199923, "Vivo"),
+    (557199924, "Vivo"),
+    (557199925, "Vivo"),
+    (557199926, "Vivo"),
+    (557199927, "Vivo"),
+    (557199928, "Vivo"),
+    (557199929, "Vivo"),
+    (557199931, "Vivo"),
+    (557199932, "Vivo"),
+    (557199933, "Vivo"),
+    (557199934, "Vivo"),
+    (557199935, "Vivo"),
+    (557199936, "Vivo"),
+    (557199937, "Vivo"),
+    (557199938, "Vivo"),
+    (557199939, "Vivo"),
+    (557199941, "Vivo"),
+    (557199942, "Vivo"),
+    (557199943, "Vivo"),
+    (557199944, "Vivo"),
+    (557199945, "Vivo"),
+    (557199946, "Vivo"),
+    (557199947, "Vivo"),
+    (557199948, "Vivo"),
+    (557199949, "Vivo"),
+    (557199951, "Vivo"),
+    (557199952, "Vivo"),
+    (557199953, "Vivo"),
+    (557199954, "Vivo"),
+    (557199955, "Vivo"),
+    (557199956, "Vivo"),
+    (557199957, "Vivo"),
+    (557199958, "Vivo"),
+    (557199959, "Vivo"),
+    (557199971, "Vivo"),
+    (557199972, "Vivo"),
+    (557199973, "Vivo"),
+    (557199974, "Vivo"),
+    (557199975, "Vivo"),
+    (557199976, "Vivo"),
+    (557199977, "Vivo"),
+    (557199978, "Vivo"),
+    (557199979, "Vivo"),
+    (557199981, "Vivo"),
+    (557199982, "Vivo"),
+    (557199983, "Vivo"),
+    (557199984, "Vivo"),
+    (557199985, "Vivo"),
+    (557199986, "Vivo"),
+    (557199987, "Vivo"),
+    (557199988, "Vivo"),
+    (557199989, "Vivo"),
+    (557199991, "Vivo"),
+    (557199992, "Vivo"),
+    (557199993, "Vivo"),
+    (557199994, "Vivo"),
+    (557199995, "Vivo"),
+    (557199996, "Vivo"),
+    (557199997, "Vivo"),
+    (557199998, "Vivo"),
+    (557199999, "Vivo"),
+    (557399911, "TIM"),
+    (557399912, "TIM"),
+    (557399913, "TIM"),
+    (557399914, "TIM"),
+    (557399915, "TIM"),
+    (557399919, "TIM"),
+    (557399980, "Vivo"),
+    (557498130, "Claro"),
+    (557498131, "Claro"),
+    (557499115, "TIM"),
+    (557499116, "TIM"),
+    (557499121, "TIM"),
+    (557499122, "TIM"),
+    (557499123, "TIM"),
+    (557499124, "TIM"),
+    (557499125, "TIM"),
+    (557499135, "TIM"),
+    (557499147, "TIM"),
+    (557499148, "TIM"),
+    (557499149, "TIM"),
+    (557499188, "TIM"),
+    (557499189, "TIM"),
+    (557499191, "TIM"),
+    (557499193, "TIM"),
+    (557499194, "TIM"),
+    (557499195, "TIM"),
+    (557499198, "TIM"),
+    (557499199, "TIM"),
+    (557499941, "Vivo"),
+    (557499942, "Vivo"),
+    (557499943, "Vivo"),
+    (557499944, "Vivo"),
+    (557499945, "Vivo"),
+    (557499946, "Vivo"),
+    (557499947, "Vivo"),
+    (557499948, "Vivo"),
+    (557499949, "Vivo"),
+    (557499961, "Vivo"),
+    (557499962, "Vivo"),
+    (557499963, "Vivo"),
+    (557499964, "Vivo"),
+    (557499965, "Vivo"),
+    (557499967, "Vivo"),
+    (557499968, "Vivo"),
+    (557499969, "Vivo"),
+    (557499970, "Vivo"),
+    (557499971, "Vivo"),
+    (557499972, "Vivo"),
+    (557499973, "Vivo"),
+    (557499975, "Vivo"),
+    (557499976, "Vivo"),
+    (557499977, "Vivo"),
+    (557499978, "Vivo"),
+    (557499979, "Vivo"),
+    (557499991, "Vivo"),
+    (557499995, "Vivo"),
+    (557499996, "Vivo"),
+    (557499998, "Vivo"),
+    (557499999, "Vivo"),
+    (557598270, "Claro"),
+    (557598271, "Claro"),
+    (557598272, "Claro"),
+    (557598273, "Claro"),
+    (557598274, "Claro"),
+    (557598275, "Claro"),
+    (557598276, "Claro"),
+    (557599111, "TIM"),
+    (557599112, "TIM"),
+    (557599113, "TIM"),
+    (557599114, "TIM"),
+    (557599115, "TIM"),
+    (557599116, "TIM"),
+    (557599117, "TIM"),
+    (557599118, "TIM"),
+    (557599119, "TIM"),
+    (557599121, "TIM"),
+    (557599122, "TIM"),
+    (557599123, "TIM"),
+    (557599124, "TIM"),
+    (557599125, "TIM"),
+    (557599126, "TIM"),
+    (557599127, "TIM"),
+    (557599129, "TIM"),
+    (557599131, "TIM"),
+    (557599132, "TIM"),
+    (557599133, "TIM"),
+    (557599134, "TIM"),
+    (557599135, "TIM"),
+    (557599136, "TIM"),
+    (557599137, "TIM"),
+    (557599138, "TIM"),
+    (557599139, "TIM"),
+    (557599141, "TIM"),
+    (557599142, "TIM"),
+    (557599143, "TIM"),
+    (557599144, "TIM"),
+    (557599145, "TIM"),
+    (557599146, "TIM"),
+    (557599147, "TIM"),
+    (557599148, "TIM"),
+    (557599149, "TIM"),
+    (557599165, "TIM"),
+    (557599168, "TIM"),
+    (557599169, "TIM"),
+    (557599172, "TIM"),
+    (557599173, "TIM"),
+    (557599174, "TIM"),
+    (557599175, "TIM"),
+    (557599176, "TIM"),
+    (557599177, "TIM"),
+    (557599178, "TIM"),
+    (557599179, "TIM"),
+    (557599181, "TIM"),
+    (557599182, "TIM"),
+    (557599183, "TIM"),
+    (557599191, "TIM"),
+    (557599192, "TIM"),
+    (557599193, "TIM"),
+    (557599194, "TIM"),
+    (557599198, "TIM"),
+    (557599199, "TIM"),
+    (557599801, "Vivo"),
+    (557599802, "Vivo"),
+    (557599803, "Vivo"),
+    (557599804, "Vivo"),
+    (557599805, "Vivo"),
+    (557599806, "Vivo"),
+    (557599807, "Vivo"),
+    (557599808, "Vivo"),
+    (557599809, "Vivo"),
+    (557599840, "Vivo"),
+    (557599841, "Vivo"),
+    (557599842, "Vivo"),
+    (557599843, "Vivo"),
+    (557599961, "Vivo"),
+    (557599962, "Vivo"),
+    (557599963, "Vivo"),
+    (557599964, "Vivo"),
+    (557599965, "Vivo"),
+    (557599966, "Vivo"),
+    (557599967, "Vivo"),
+    (557599970, "Vivo"),
+    (557599972, "Vivo"),
+    (557599973, "Vivo"),
+    (557599975, "Vivo"),
+    (557599976, "Vivo"),
+    (557599977, "Vivo"),
+    (557599978, "Vivo"),
+    (557599979, "Vivo"),
+    (557599980, "Vivo"),
+    (557599981, "Vivo"),
+    (557599982, "Vivo"),
+    (557599983, "Vivo"),
+    (557599985, "Vivo"),
+    (557599986, "Vivo"),
+    (557599987, "Vivo"),
+    (557599988, "Vivo"),
+    (557599990, "Vivo"),
+    (557599992, "Vivo"),
+    (557599993, "Vivo"),
+    (557599994, "Vivo"),
+    (557599996, "Vivo"),
+    (557599997, "Vivo"),
+    (557599998, "Vivo"),
+    (557599999, "Vivo"),
+    (557798150, "Claro"),
+    (557798151, "Claro"),
+    (557798152, "Claro"),
+    (557798153, "Claro"),
+    (557799115, "TIM"),
+    (557799116, "TIM"),
+    (557799117, "TIM"),
+    (557799118, "TIM"),
+    (557799119, "TIM"),
+    (557799121, "TIM"),
+    (557799125, "TIM"),
+    (557799127, "TIM"),
+    (557799128, "TIM"),
+    (557799129, "TIM"),
+    (557799131, "TIM"),
+    (557799135, "TIM"),
+    (557799136, "TIM"),
+    (557799148, "TIM"),
+    (557799149, "TIM"),
+    (557799191, "TIM"),
+    (557799193, "TIM"),
+    (557799194, "TIM"),
+    (557799198, "TIM"),
+    (557799199, "TIM"),
+    (557799810, "Vivo"),
+    (557799811, "Vivo"),
+    (557799812, "Vivo"),
+    (557799813, "Vivo"),
+    (557799814, "Vivo"),
+    (557799815, "Vivo"),
+    (557799961, "Vivo"),
+    (557799962, "Vivo"),
+    (557799963, "Vivo"),
+    (557799964, "Vivo"),
+    (557799966, "Vivo"),
+    (557799967, "Vivo"),
+    (557799968, "Vivo"),
+    (557799969, "Vivo"),
+    (557799970, "Vivo"),
+    (557799971, "Vivo"),
+    (557799972, "Vivo"),
+    (557799973, "Vivo"),
+    (557799974, "Vivo"),
+    (557799975, "Vivo"),
+    (557799976, "Vivo"),
+    (557799977, "Vivo"),
+    (557799978, "Vivo"),
+    (557799990, "Vivo"),
+    (557799992, "Vivo"),
+    (557799993, "Vivo"),
+    (557799999, "Vivo"),
+    (557998171, "Claro"),
+    (557998172, "Claro"),
+    (557999116, "TIM"),
+    (557999121, "TIM"),
+    (557999124, "TIM"),
+    (557999131, "TIM"),
+    (557999132, "TIM"),
+    (557999133, "TIM"),
+    (557999134, "TIM"),
+    (557999135, "TIM"),
+    (557999136, "TIM"),
+    (557999137, "TIM"),
+    (557999138, "TIM"),
+    (557999139, "TIM"),
+    (557999141, "TIM"),
+    (557999142, "TIM"),
+    (557999143, "TIM"),
+    (557999145, "TIM"),
+    (557999147, "TIM"),
+    (557999148, "TIM"),
+    (557999149, "TIM"),
+    (557999151, "TIM"),
+    (557999152, "TIM"),
+    (557999153, "TIM"),
+    (557999154, "TIM"),
+    (557999159, "TIM"),
+    (557999191, "TIM"),
+    (557999192, "TIM"),
+    (557999193, "TIM"),
+    (557999198, "TIM"),
+    (557999199, "TIM"),
+    (557999600, "Vivo"),
+    (557999601, "Vivo"),
+    (557999602, "Vivo"),
+    (557999603, "Vivo"),
+    (557999604, "Vivo"),
+    (557999605, "Vivo"),
+    (557999606, "Vivo"),
+    (557999607, "Vivo"),
+    (558198260, "Vivo"),
+    (558198261, "Vivo"),
+    (558198262, "Vivo"),
+    (558198263, "Vivo"),
+    (558199370, "Claro"),
+    (558199371, "Claro"),
+    (558199372, "Claro"),
+    (558199373, "Claro"),
+    (558199374, "Claro"),
+    (558199375, "Claro"),
+    (558199730, "TIM"),
+    (558199731, "TIM"),
+    (558199732, "TIM"),
+    (558199733, "TIM"),
+    (558199734, "TIM"),
+    (558199735, "TIM"),
+    (558199736, "TIM"),
+    (558199737, "TIM"),
+    (558199738, "TIM"),
+    (558199747, "TIM"),
+    (558199748, "TIM"),
+    (558199749, "TIM"),
+    (558199750, "TIM"),
+    (558298140, "Vivo"),
+    (558298141, "Vivo"),
+    (558298142, "Vivo"),
+    (558298143, "Vivo"),
+    (558299310, "Claro"),
+    (558299311, "Claro"),
+    (558299312, "Claro"),
+    (558299313, "Claro"),
+    (558299314, "Claro"),
+    (558299315, "Claro"),
+    (558299316, "Claro"),
+    (558299317, "Claro"),
+    (558299318, "Claro"),
+    (558299340, "Claro"),
+    (558299341, "Claro"),
+    (558299342, "Claro"),
+    (558299343, "Claro"),
+    (558299351, "Claro"),
+    (558299361, "Claro"),
+    (558299371, "Claro"),
+    (558299381, "Claro"),
+    (558299444, "Claro"),
+    (558299600, "TIM"),
+    (558299601, "TIM"),
+    (558299602, "TIM"),
+    (558299603, "TIM"),
+    (558299604, "TIM"),
+    (558299605, "TIM"),
+    (558299606, "TIM"),
+    (558299607, "TIM"),
+    (558299608, "TIM"),
+    (558299621, "TIM"),
+    (558299622, "TIM"),
+    (558299623, "TIM"),
+    (558299624, "TIM"),
+    (558299625, "TIM"),
+    (558299627, "TIM"),
+    (558299628, "TIM"),
+    (558299629, "TIM"),
+    (558299631, "TIM"),
+    (558299991, "TIM"),
+    (558299992, "TIM"),
+    (558299993, "TIM"),
+    (558299994, "TIM"),
+    (558299995, "TIM"),
+    (558299996, "TIM"),
+    (558299997, "TIM"),
+    (558299998, "TIM"),
+    (558299999, "TIM"),
+    (558398160, "Vivo"),
+    (558398161, "Vivo"),
+    (558398162, "Vivo"),
+    (558398163, "Vivo"),
+    (558398164, "Vivo"),
+    (558398165, "Vivo"),
+    (558399330, "Claro"),
+    (558399331, "Claro"),
+    (558399332, "Claro"),
+    (558399333, "Claro"),
+    (558399334, "Claro"),
+    (558399342, "Claro"),
+    (558399352, "Claro"),
+    (558399362, "Claro"),
+    (558399372, "Claro"),
+    (558399382, "Claro"),
+    (558399444, "Claro"),
+    (558399600, "TIM"),
+    (558399601, "TIM"),
+    (558399940, "TIM"),
+    (558399941, "TIM"),
+    (558399942, "TIM"),
+    (558399943, "TIM"),
+    (558399944, "TIM"),
+    (558399945, "TIM"),
+    (558399946, "TIM"),
+    (558399947, "TIM"),
+    (558399948, "TIM"),
+    (558498140, "Vivo"),
+    (558498141, "Vivo"),
+    (558498142, "Vivo"),
+    (558498143, "Vivo"),
+    (558498144, "Vivo"),
+    (558498145, "Vivo"),
+    (558498146, "Vivo"),
+    (558498147, "Vivo"),
+    (558499461, "Claro"),
+    (558499462, "Claro"),
+    (558499463, "Claro"),
+    (558499464, "Claro"),
+    (558499465, "Claro"),
+    (558499466, "Claro"),
+    (558499467, "Claro"),
+    (558499468, "Claro"),
+    (558499471, "Claro"),
+    (558499481, "Claro"),
+    (558499610, "TIM"),
+    (558499611, "TIM"),
+    (558499612, "TIM"),
+    (558499615, "TIM"),
+    (558499616, "TIM"),
+    (558499617, "TIM"),
+    (558499618, "TIM"),
+    (558499619, "TIM"),
+    (558499620, "TIM"),
+    (558598210, "Vivo"),
+    (558598211, "Vivo"),
+    (558598212, "Vivo"),
+    (558598213, "Vivo"),
+    (558598214, "Vivo"),
+    (558598215, "Vivo"),
+    (558598216, "Vivo"),
+    (558598217, "Vivo"),
+    (558599401, "Claro"),
+    (558599402, "Claro"),
+    (558599403, "Claro"),
+    (558599404, "Claro"),
+    (558599444, "Claro"),
+    (558599601, "TIM"),
+    (558599602, "TIM"),
+    (558599603, "TIM"),
+    (558599604, "TIM"),
+    (558599605, "TIM"),
+    (558599606, "TIM"),
+    (558599607, "TIM"),
+    (558599608, "TIM"),
+    (558599609, "TIM"),
+    (558599611, "TIM"),
+    (558599612, "TIM"),
+    (558599613, "TIM"),
+    (558599614, "TIM"),
+    (558599615, "TIM"),
+    (558599616, "TIM"),
+    (558599617, "TIM"),
+    (558599618, "TIM"),
+    (558599619, "TIM"),
+    (558599621, "TIM"),
+    (558599622, "TIM"),
+    (558599623, "TIM"),
+    (558599624, "TIM"),
+    (558599625, "TIM"),
+    (558599626, "TIM"),
+    (558599627, "TIM"),
+    (558599628, "TIM"),
+    (558599629, "TIM"),
+    (558599631, "TIM"),
+    (558599632, "TIM"),
+    (558599633, "TIM"),
+    (558599634, "TIM"),
+    (558599635, "TIM"),
+    (558599636, "TIM"),
+    (558599637, "TIM"),
+    (558599638, "TIM"),
+    (558599639, "TIM"),
+    (558599641, "TIM"),
+    (558599642, "TIM"),
+    (558599643, "TIM"),
+    (558599644, "TIM"),
+    (558599645, "TIM"),
+    (558599646, "TIM"),
+    (558599647, "TIM"),
+    (558599648, "TIM"),
+    (558599649, "TIM"),
+    (558599651, "TIM"),
+    (558599652, "TIM"),
+    (558599653, "TIM"),
+    (558599654, "TIM"),
+    (558599655, "TIM"),
+    (558599656, "TIM"),
+    (558599657, "TIM"),
+    (558599658, "TIM"),
+    (558599659, "TIM"),
+    (558599661, "TIM"),
+    (558599662, "TIM"),
+    (558599663, "TIM"),
+    (558599664, "TIM"),
+    (558599665, "TIM"),
+    (558599666, "TIM"),
+    (558599667, "TIM"),
+    (558599668, "TIM"),
+    (558599669, "TIM"),
+    (558599671, "TIM"),
+    (558599672, "TIM"),
+    (558599673, "TIM"),
+    (558599674, "TIM"),
+    (558599675, "TIM"),
+    (558599676, "TIM"),
+    (558599677, "TIM"),
+    (558599901, "TIM"),
+    (558599902, "TIM"),
+    (558599903, "TIM"),
+    (558599904, "TIM"),
+    (558599905, "TIM"),
+    (558599906, "TIM"),
+    (558599907, "TIM"),
+    (558599908, "TIM"),
+    (558599909, "TIM"),
+    (558599911, "TIM"),
+    (558599912, "TIM"),
+    (558599913, "TIM"),
+    (558599914, "TIM"),
+    (558599915, "TIM"),
+    (558599916, "TIM"),
+    (558599917, "TIM"),
+    (558599918, "TIM"),
+    (558599919, "TIM"),
+    (558599921, "TIM"),
+    (558599922, "TIM"),
+    (558599923, "TIM"),
+    (558599924, "TIM"),
+    (558599925, "TIM"),
+    (558599926, "TIM"),
+    (558599927, "TIM"),
+    (558599928, "TIM"),
+    (558599929, "TIM"),
+    (558599931, "TIM"),
+    (558599932, "TIM"),
+    (558599933, "TIM"),
+    (558599934, "TIM"),
+    (558599935, "TIM"),
+    (558599936, "TIM"),
+    (558599937, "TIM"),
+    (558599938, "TIM"),
+    (558599939, "TIM"),
+    (558599941, "TIM"),
+    (558599942, "TIM"),
+    (558599943, "TIM"),
+    (558599944, "TIM"),
+    (558599945, "TIM"),
+    (558599946, "TIM"),
+    (558599947, "TIM"),
+    (558599948, "TIM"),
+    (558599949, "TIM"),
+    (558599951, "TIM"),
+    (558599952, "TIM"),
+    (558599953, "TIM"),
+    (558599954, "TIM"),
+    (558599955, "TIM"),
+    (558599956, "TIM"),
+    (558599957, "TIM"),
+    (558599958, "TIM"),
+    (558599959, "TIM"),
+    (558599961, "TIM"),
+    (558599962, "TIM"),
+    (558599963, "TIM"),
+    (558599969, "TIM"),
+    (558599971, "TIM"),
+    (558599972, "TIM"),
+    (558599973, "TIM"),
+    (558599974, "TIM"),
+    (558599975, "TIM"),
+    (558599976, "TIM"),
+    (558599977, "TIM"),
+    (558599978, "TIM"),
+    (558599979, "TIM"),
+    (558599981, "TIM"),
+    (558599982, "TIM"),
+    (558599983, "TIM"),
+    (558599984, "TIM"),
+    (558599985, "TIM"),
+    (558599986, "TIM"),
+    (558599987, "TIM"),
+    (558599988, "TIM"),
+    (558599989, "TIM"),
+    (558698140, "Vivo"),
+    (558698141, "Vivo"),
+    (558698142, "Vivo"),
+    (558698143, "Vivo"),
+    (558698144, "Vivo"),
+    (558699510, "Claro"),
+    (558699511, "Claro"),
+    (558699512, "Claro"),
+    (558699513, "Claro"),
+    (558699514, "Claro"),
+    (558699515, "Claro"),
+    (558699516, "Claro"),
+    (558699517, "Claro"),
+    (558699950, "TIM"),
+    (558699951, "TIM"),
+    (558699971, "TIM"),
+    (558699972, "TIM"),
+    (558699973, "TIM"),
+    (558699974, "TIM"),
+    (558699975, "TIM"),
+    (558699976, "TIM"),
+    (558699977, "TIM"),
+    (558699978, "TIM"),
+    (558699979, "TIM"),
+    (558699981, "TIM"),
+    (558699982, "TIM"),
+    (558699983, "TIM"),
+    (558699984, "TIM"),
+    (558699985, "TIM"),
+    (558699986, "TIM"),
+    (558699987, "TIM"),
+    (558699988, "TIM"),
+    (558699989, "TIM"),
+    (558699991, "TIM"),
+    (558699992, "TIM"),
+    (558699993, "TIM"),
+    (558699994, "TIM"),
+    (558699995, "TIM"),
+    (558699996, "TIM"),
+    (558699997, "TIM"),
+    (558699998, "TIM"),
+    (558699999, "TIM"),
+    (558798120, "Vivo"),
+    (558798121, "Vivo"),
+    (558798122, "Vivo"),
+    (558798123, "Vivo"),
+    (558798124, "Vivo"),
+    (558798125, "Vivo"),
+    (558799170, "Claro"),
+    (558799171, "Claro"),
+    (558799172, "Claro"),
+    (558799173, "Claro"),
+    (558799174, "Claro"),
+    (558799175, "Claro"),
+    (558799243, "Claro"),
+    (558799253, "Claro"),
+    (558799601, "TIM"),
+    (558799602, "TIM"),
+    (558799603, "TIM"),
+    (558799604, "TIM"),
+    (558799605, "TIM"),
+    (558799606, "TIM"),
+    (558799607, "TIM"),
+    (558799608, "TIM"),
+    (558799609, "TIM"),
+    (558799611, "TIM"),
+    (558799612, "TIM"),
+    (558799613, "TIM"),
+    (558799614, "TIM"),
+    (558799615, "TIM"),
+    (558799616, "TIM"),
+    (558799617, "TIM"),
+    (558799618, "TIM"),
+    (558799619, "TIM"),
+    (558799621, "TIM"),
+    (558799622, "TIM"),
+    (558799623, "TIM"),
+    (558799624, "TIM"),
+    (558799625, "TIM"),
+    (558799626, "TIM"),
+    (558799627, "TIM"),
+    (558799628, "TIM"),
+    (558799629, "TIM"),
+    (558799631, "TIM"),
+    (558799632, "TIM"),
+    (558799633, "TIM"),
+    (558799634, "TIM"),
+    (558799635, "TIM"),
+    (558799636, "TIM"),
+    (558799637, "TIM"),
+    (558799638, "TIM"),
+    (558799639, "TIM"),
+    (558799901, "TIM"),
+    (558799902, "TIM"),
+    (558799911, "TIM"),
+    (558799912, "TIM"),
+    (558799913, "TIM"),
+    (558799914, "TIM"),
+    (558799915, "TIM"),
+    (558799916, "TIM"),
+    (558799917, "TIM"),
+    (558799918, "TIM"),
+    (558799919, "TIM"),
+    (558799921, "TIM"),
+    (558799922, "TIM"),
+    (558799923, "TIM"),
+    (558799924, "TIM"),
+    (558799925, "TIM"),
+    (558799926, "TIM"),
+    (558799927, "TIM"),
+    (558799928, "TIM"),
+    (558799929, "TIM"),
+    (558799931, "TIM"),
+    (558799932, "TIM"),
+    (558799933, "TIM"),
+    (558799934, "TIM"),
+    (558799935, "TIM"),
+    (558799936, "TIM"),
+    (558799937, "TIM"),
+    (558799938, "TIM"),
+    (558799939, "TIM"),
+    (558799988, "TIM"),
+    (558799991, "TIM"),
+    (558799992, "TIM"),
+    (558799993, "TIM"),
+    (558799994, "TIM"),
+    (558799995, "TIM"),
+    (558799996, "TIM"),
+    (558799997, "TIM"),
+    (558799998, "TIM"),
+    (558799999, "TIM"),
+    (558898130, "Vivo"),
+    (558899460, "Claro"),
+    (558899461, "Claro"),
+    (558899462, "Claro"),
+    (558899463, "Claro"),
+    (558899464, "Claro"),
+    (558899601, "TIM"),
+    (558899602, "TIM"),
+    (558899603, "TIM"),
+    (558899604, "TIM"),
+    (558899605, "TIM"),
+    (558899606, "TIM"),
+    (558899607, "TIM"),
+    (558899608, "TIM"),
+    (558899609, "TIM"),
+    (558899621, "TIM"),
+    (558899622, "TIM"),
+    (558899623, "TIM"),
+    (558899624, "TIM"),
+    (558899625, "TIM"),
+    (558899626, "TIM"),
+    (558899627, "TIM"),
+    (558899628, "TIM"),
+    (558899630, "TIM"),
+    (558899634, "TIM"),
+    (558899635, "TIM"),
+    (558899636, "TIM"),
+    (558899640, "TIM"),
+    (558899650, "TIM"),
+    (558899901, "TIM"),
+    (558899902, "TIM"),
+    (558899903, "TIM"),
+    (558899904, "TIM"),
+    (558899905, "TIM"),
+    (558899906, "TIM"),
+    (558899907, "TIM"),
+    (558899908, "TIM"),
+    (558899909, "TIM"),
+    (558899911, "TIM"),
+    (558899912, "TIM"),
+    (558899913, "TIM"),
+    (558899914, "TIM"),
+    (558899915, "TIM"),
+    (558899916, "TIM"),
+    (558899917, "TIM"),
+    (558899918, "TIM"),
+    (558899919, "TIM"),
+    (558899921, "TIM"),
+    (558899922, "TIM"),
+    (558899923, "TIM"),
+    (558899924, "TIM"),
+    (558899925, "TIM"),
+    (558899926, "TIM"),
+    (558899927, "TIM"),
+    (558899928, "TIM"),
+    (558899929, "TIM"),
+    (558899931, "TIM"),
+    (558899932, "TIM"),
+    (558899933, "TIM"),
+    (558899934, "TIM"),
+    (558899935, "TIM"),
+    (558899936, "TIM"),
+    (558899937, "TIM"),
+    (558899938, "TIM"),
+    (558899939, "TIM"),
+    (558899941, "TIM"),
+    (558899942, "TIM"),
+    (558899943, "TIM"),
+    (558899944, "TIM"),
+    (558899945, "TIM"),
+    (558899946, "TIM"),
+    (558899947, "TIM"),
+    (558899951, "TIM"),
+    (558899952, "TIM"),
+    (558899953, "TIM"),
+    (558899954, "TIM"),
+    (558899955, "TIM"),
+    (558899956, "TIM"),
+    (558899957, "TIM"),
+    (558899958, "TIM"),
+    (558899959, "TIM"),
+    (558899961, "TIM"),
+    (558899962, "TIM"),
+    (558899963, "TIM"),
+    (558899964, "TIM"),
+    (558899965, "TIM"),
+    (558899966, "TIM"),
+    (558899967, "TIM"),
+    (558899968, "TIM"),
+    (558899969, "TIM"),
+    (558998120, "Vivo"),
+    (558999443, "Claro"),
+    (558999444, "Claro"),
+    (558999445, "Claro"),
+    (558999446, "Claro"),
+    (558999921, "TIM"),
+    (558999922, "TIM"),
+    (558999924, "TIM"),
+    (558999925, "TIM"),
+    (558999926, "TIM"),
+    (558999927, "TIM"),
+    (558999928, "TIM"),
+    (558999929, "TIM"),
+    (558999971, "TIM"),
+    (558999972, "TIM"),
+    (558999973, "TIM"),
+    (558999974, "TIM"),
+    (558999975, "TIM"),
+    (558999976, "TIM"),
+    (558999978, "TIM"),
+    (558999979, "TIM"),
+    (558999984, "TIM"),
+    (558999985, "TIM"),
+    (558999997, "TIM"),
+    (559198201, "TIM"),
+    (559198202, "TIM"),
+    (559198203, "TIM"),
+    (559198204, "TIM"),
+    (559198205, "TIM"),
+    (559198206, "TIM"),
+    (559198207, "TIM"),
+    (559198208, "TIM"),
+    (559198209, "TIM"),
+    (559198211, "TIM"),
+    (559198212, "TIM"),
+    (559198213, "TIM"),
+    (559198214, "TIM"),
+    (559198215, "TIM"),
+    (559198216, "TIM"),
+    (559198217, "TIM"),
+    (559198218, "TIM"),
+    (559198219, "TIM"),
+    (559198221, "TIM"),
+    (559198222, "TIM"),
+    (559198223, "TIM"),
+    (559198224, "TIM"),
+    (559198225, "TIM"),
+    (559198226, "TIM"),
+    (559198227, "TIM"),
+    (559198228, "TIM"),
+    (559198229, "TIM"),
+    (559198231, "TIM"),
+    (559198232, "TIM"),
+    (559198233, "TIM"),
+    (559198234, "TIM"),
+    (559198235, "TIM"),
+    (559198236, "TIM"),
+    (559198237, "TIM"),
+    (559198238, "TIM"),
+    (559198239, "TIM"),
+    (559198241, "TIM"),
+    (559198242, "TIM"),
+    (559198243, "TIM"),
+    (559198244, "TIM"),
+    (559198245, "TIM"),
+    (559198246, "TIM"),
+    (559198480, "Claro"),
+    (559198481, "Claro"),
+    (559198482, "Claro"),
+    (559198483, "Claro"),
+    (559198484, "Claro"),
+    (559198485, "Claro"),
+    (559198486, "Claro"),
+    (559199280, "Vivo"),
+    (559199281, "Vivo"),
+    (559199282, "Vivo"),
+    (559199283, "Vivo"),
+    (559199284, "Vivo"),
+    (559199285, "Vivo"),
+    (559199286, "Vivo"),
+    (559199601, "Oi"),
+    (559199602, "Oi"),
+    (559199603, "Oi"),
+    (559199604, "Oi"),
+    (559199605, "Oi"),
+    (559199606, "Oi"),
+    (559199607, "Oi"),
+    (559199608, "Oi"),
+    (559199609, "Oi"),
+    (559199611, "Oi"),
+    (559199612, "Oi"),
+    (559199613, "Oi"),
+    (559199614, "Oi"),
+    (559199615, "Oi"),
+    (559199616, "Oi"),
+    (559199617, "Oi"),
+    (559199618, "Oi"),
+    (559199619, "Oi"),
+    (559199621, "Oi"),
+    (559199622, "Oi"),
+    (559199623, "Oi"),
+    (559199624, "Oi"),
+    (559199625, "Oi"),
+    (559199626, "Oi"),
+    (559199627, "Oi"),
+    (559199628, "Oi"),
+    (559199629, "Oi"),
+    (559199631, "Oi"),
+    (559199632, "Oi"),
+    (559199633, "Oi"),
+    (559199634, "Oi"),
+    (559199635, "Oi"),
+    (559199636, "Oi"),
+    (559199637, "Oi"),
+    (559199638, "Oi"),
+    (559199639, "Oi"),
+    (559199641, "Oi"),
+    (559199642, "Oi"),
+    (559199901, "Oi"),
+    (559199902, "Oi"),
+    (559199903, "Oi"),
+    (559199904, "Oi"),
+    (559199905, "Oi"),
+    (559199906, "Oi"),
+    (559199907, "Oi"),
+    (559199908, "Oi"),
+    (559199912, "Oi"),
+    (559199913, "Oi"),
+    (559199914, "Oi"),
+    (559199915, "Oi"),
+    (559199916, "Oi"),
+    (559199917, "Oi"),
+    (559199918, "Oi"),
+    (559199919, "Oi"),
+    (559199921, "Oi"),
+    (559199922, "Oi"),
+    (559199923, "Oi"),
+    (559199924, "Oi"),
+    (559199925, "Oi"),
+    (559199926, "Oi"),
+    (559199933, "Oi"),
+    (559199940, "Oi"),
+    (559199941, "Oi"),
+    (559199942, "Oi"),
+    (559199943, "Oi"),
+    (559199944, "Oi"),
+    (559199949, "Oi"),
+    (559199971, "Oi"),
+    (559199975, "Oi"),
+    (559199977, "Oi"),
+    (559199978, "Oi"),
+    (559199979, "Oi"),
+    (559298211, "TIM"),
+    (559298450, "Claro"),
+    (559298451, "Claro"),
+    (559299390, "Vivo"),
+    (559299391, "Vivo"),
+    (559299392, "Vivo"),
+    (559299393, "Vivo"),
+    (559299394, "Vivo"),
+    (559299395, "Vivo"),
+    (559299631, "Oi"),
+    (559299901, "Oi"),
+    (559299902, "Oi"),
+    (559299903, "Oi"),
+    (559299904, "Oi"),
+    (559299905, "Oi"),
+    (559299906, "Oi"),
+    (559299907, "Oi"),
+    (559299908, "Oi"),
+    (559299909, "Oi"),
+    (559299911, "Oi"),
+    (559299912, "Oi"),
+    (559299913, "Oi"),
+    (559299933, "Oi"),
+    (559299944, "Oi"),
+    (559299951, "Oi"),
+    (559299961, "Oi"),
+    (559299962, "Oi"),
+    (559299963, "Oi"),
+    (559299964, "Oi"),
+    (559299965, "Oi"),
+    (559299966, "Oi"),
+    (559299967, "Oi"),
+    (559299968, "Oi"),
+    (559299969, "Oi"),
+    (559299971, "Oi"),
+    (559299972, "Oi"),
+    (559299973, "Oi"),
+    (559299974, "Oi"),
+    (559299975, "Oi"),
+    (559299976, "Oi"),
+    (559299977, "Oi"),
+    (559299978, "Oi"),
+    (559299991, "Oi"),
+    (559299994, "Oi"),
+    (559299995, "Oi"),
+    (559299996, "Oi"),
+    (559299997, "Oi"),
+    (559299998, "Oi"),
+    (559299999, "Oi"),
+    (559398111, "TIM"),
+    (559398112, "TIM"),
+    (559398113, "TIM"),
+    (559398114, "TIM"),
+    (559398115, "TIM"),
+    (559398116, "TIM"),
+    (559398117, "TIM"),
+    (559398118, "TIM"),
+    (559398119, "TIM"),
+    (559398121, "TIM"),
+    (559398122, "TIM"),
+    (559398123, "TIM"),
+    (559398124, "TIM"),
+    (559398125, "TIM"),
+    (559398401, "Claro"),
+    (559398402, "Claro"),
+    (559398403, "Claro"),
+    (559398404, "Claro"),
+    (559398405, "Claro"),
+    (559398406, "Claro"),
+    (559398407, "Claro"),
+    (559398408, "Claro"),
+    (559398409, "Claro"),
+    (559398411, "Claro"),
+    (559398412, "Claro"),
+    (559398413, "Claro"),
+    (559398414, "Claro"),
+    (559398415, "Claro"),
+    (559398416, "Claro"),
+    (559399101, "Vivo"),
+    (559399102, "Vivo"),
+    (559399103, "Vivo"),
+    (559399104, "Vivo"),
+    (559399105, "Vivo"),
+    (559399106, "Vivo"),
+    (559399107, "Vivo"),
+    (559399111, "Vivo"),
+    (559399112, "Vivo"),
+    (559399113, "Vivo"),
+    (559399114, "Vivo"),
+    (559399115, "Vivo"),
+    (559399116, "Vivo"),
+    (559399117, "Vivo"),
+    (559399118, "Vivo"),
+    (559399119, "Vivo"),
+    (559399121, "Vivo"),
+    (559399122, "Vivo"),
+    (559399123, "Vivo"),
+    (559399124, "Vivo"),
+    (559399125, "Vivo"),
+    (559399126, "Vivo"),
+    (559399127, "Vivo"),
+    (559399128, "Vivo"),
+    (559399129, "Vivo"),
+    (559399131, "Vivo"),
+    (559399132, "Vivo"),
+    (559399133, "Vivo"),
+    (559399134, "Vivo"),
+    (559399135, "Vivo"),
+    (559399136, "Vivo"),
+    (559399137, "Vivo"),
+    (559399138, "Vivo"),
+    (559399139, "Vivo"),
+    (559399141, "Vivo"),
+    (559399142, "Vivo"),
+    (559399143, "Vivo"),
+    (559399144, "Vivo"),
+    (559399145, "Vivo"),
+    (559399146, "Vivo"),
+    (559399147, "Vivo"),
+    (559399148, "Vivo"),
+    (559399149, "Vivo"),
+    (559399151, "Vivo"),
+    (559399152, "Vivo"),
+    (559399153, "Vivo"),
+    (559399154, "Vivo"),
+    (559399155, "Vivo"),
+    (559399156, "Vivo"),
+    (559399157, "Vivo"),
+    (559399158, "Vivo"),
+    (559399159, "Vivo"),
+    (559399161, "Vivo"),
+    (559399162, "Vivo"),
+    (559399163, "Vivo"),
+    (559399164, "Vivo"),
+    (559399165, "Vivo"),
+    (559399166, "Vivo"),
+    (559399167, "Vivo"),
+    (559399168, "Vivo"),
+    (559399169, "Vivo"),
+    (559399171, "Vivo"),
+    (559399172, "Vivo"),
+    (559399173, "Vivo"),
+    (559399174, "Vivo"),
+    (559399175, "Vivo"),
+    (559399176, "Vivo"),
+    (559399177, "Vivo"),
+    (559399178, "Vivo"),
+    (559399179, "Vivo"),
+    (559399181, "Vivo"),
+    (559399182, "Vivo"),
+    (559399183, "Vivo"),
+    (559399184, "Vivo"),
+    (559399185, "Vivo"),
+    (559399186, "Vivo"),
+    (559399187, "Vivo"),
+    (559399188, "Vivo"),
+    (559399189, "Vivo"),
+    (559399191, "Vivo"),
+    (559399192, "Vivo"),
+    (559399193, "Vivo"),
+    (559399194, "Vivo"),
+    (559399195, "Vivo"),
+    (559399196, "Vivo"),
+    (559399197, "Vivo"),
+    (559399198, "Vivo"),
+    (559399199, "Vivo"),
+    (559399651, "Oi"),
+    (559399652, "Oi"),
+    (559399653, "Oi"),
+    (559399654, "Oi"),
+    (559399655, "Oi"),
+    (559399656, "Oi"),
+    (559399657, "Oi"),
+    (559399658, "Oi"),
+    (559399659, "Oi"),
+    (559399901, "Oi"),
+    (559399902, "Oi"),
+    (559399903, "Oi"),
+    (559399904, "Oi"),
+    (559399908, "Oi"),
+    (559399933, "Oi"),
+    (559399951, "Oi"),
+    (559399952, "Oi"),
+    (559399954, "Oi"),
+    (559399970, "Oi"),
+    (559399973, "Oi"),
+    (559399974, "Oi"),
+    (559399975, "Oi"),
+    (559399976, "Oi"),
+    (559399977, "Oi"),
+    (559399978, "Oi"),
+    (559399979, "Oi"),
+    (559498111, "TIM"),
+    (559498112, "TIM"),
+    (559498113, "TIM"),
+    (559498114, "TIM"),
+    (559498115, "TIM"),
+    (559498116, "TIM"),
+    (559498117, "TIM"),
+    (559498118, "TIM"),
+    (559498119, "TIM"),
+    (559498121, "TIM"),
+    (559498122, "TIM"),
+    (559498123, "TIM"),
+    (559498124, "TIM"),
+    (559498125, "TIM"),
+    (559498126, "TIM"),
+    (559498127, "TIM"),
+    (559498128, "TIM"),
+    (559498129, "TIM"),
+    (559498131, "TIM"),
+    (559498132, "TIM"),
+    (559498133, "TIM"),
+    (559498134, "TIM"),
+    (559498135, "TIM"),
+    (559498136, "TIM"),
+    (559498137, "TIM"),
+    (559498138, "TIM"),
+    (559498139, "TIM"),
+    (559498141, "TIM"),
+    (559498142, "TIM"),
+    (559498143, "TIM"),
+    (559498144, "TIM"),
+    (559498145, "TIM"),
+    (559498146, "TIM"),
+    (559498147, "TIM"),
+    (559498148, "TIM"),
+    (559498149, "TIM"),
+    (559498154, "TIM"),
+    (559498155, "TIM"),
+    (559498156, "TIM"),
+    (559498157, "TIM"),
+    (559498401, "Claro"),
+    (559498402, "Claro"),
+    (559498403, "Claro"),
+    (559498404, "Claro"),
+    (559498405, "Claro"),
+    (559498406, "Claro"),
+    (559498407, "Claro"),
+    (559498408, "Claro"),
+    (559498409, "Claro"),
+    (559498411, "Claro"),
+    (559498412, "Claro"),
+    (559498413, "Claro"),
+    (559498414, "Claro"),
+    (559499230, "Vivo"),
+    (559499231, "Vivo"),
+    (559499232, "Vivo"),
+    (559499233, "Vivo"),
+    (559499661, "Oi"),
+    (559499662, "Oi"),
+    (559499663, "Oi"),
+    (559499664, "Oi"),
+    (559499665, "Oi"),
+    (559499666, "Oi"),
+    (559499667, "Oi"),
+    (559499668, "Oi"),
+    (559499901, "Oi"),
+    (559499903, "Oi"),
+    (559499904, "Oi"),
+    (559499908, "Oi"),
+    (559499909, "Oi"),
+    (559499933, "Oi"),
+    (559499934, "Oi"),
+    (559499944, "Oi"),
+    (559499953, "Oi"),
+    (559499955, "Oi"),
+    (559499969, "Oi"),
+    (559499970, "Oi"),
+    (559499972, "Oi"),
+    (559499973, "Oi"),
+    (559499974, "Oi"),
+    (559499975, "Oi"),
+    (559499977, "Oi"),
+    (559499979, "Oi"),
+    (559598111, "TIM"),
+    (559598112, "TIM"),
+    (559598113, "TIM"),
+    (559598114, "TIM"),
+    (559598115, "TIM"),
+    (559598116, "TIM"),
+    (559598117, "TIM"),
+    (559598118, "TIM"),
+    (559598119, "TIM"),
+    (559598121, "TIM"),
+    (559598122, "TIM"),
+    (559598123, "TIM"),
+    (559598410, "Claro"),
+    (559598411, "Claro"),
+    (559598412, "Claro"),
+    (559599111, "Vivo"),
+    (559599112, "Vivo"),
+    (559599113, "Vivo"),
+    (559599114, "Vivo"),
+    (559599115, "Vivo"),
+    (559599116, "Vivo"),
+    (559599117, "Vivo"),
+    (559599118, "Vivo"),
+    (559599119, "Vivo"),
+    (559599121, "Vivo"),
+    (559599122, "Vivo"),
+    (559599123, "Vivo"),
+    (559599124, "Vivo"),
+    (559599125, "Vivo"),
+    (559599126, "Vivo"),
+    (559599127, "Vivo"),
+    (559599128, "Vivo"),
+    (559599129, "Vivo"),
+    (559599131, "Vivo"),
+    (559599132, "Vivo"),
+    (559599133, "Vivo"),
+    (559599134, "Vivo"),
+    (559599135, "Vivo"),
+    (559599136, "Vivo"),
+    (559599137, "Vivo"),
+    (559599138, "Vivo"),
+    (559599139, "Vivo"),
+    (559599141, "Vivo"),
+    (559599142, "Vivo"),
+    (559599143, "Vivo"),
+    (559599144, "Vivo"),
+    (559599145, "Vivo"),
+    (559599146, "Vivo"),
+    (559599147, "Vivo"),
+    (559599148, "Vivo"),
+    (559599149, "Vivo"),
+    (559599151, "Vivo"),
+    (559599152, "Vivo"),
+    (559599153, "Vivo"),
+    (559599154, "Vivo"),
+    (559599155, "Vivo"),
+    (559599156, "Vivo"),
+    (559599157, "Vivo"),
+    (559599158, "Vivo"),
+    (559599159, "Vivo"),
+    (559599161, "Vivo"),
+    (559599162, "Vivo"),
+    (559599163, "Vivo"),
+    (559599164, "Vivo"),
+    (559599165, "Vivo"),
+    (559599166, "Vivo"),
+    (559599167, "Vivo"),
+    (559599168, "Vivo"),
+    (559599901, "Oi"),
+    (559599902, "Oi"),
+    (559599903, "Oi"),
+    (559599904, "Oi"),
+    (559599905, "Oi"),
+    (559599933, "Oi"),
+    (559599959, "Oi"),
+    (559599961, "Oi"),
+    (559599962, "Oi"),
+    (559599963, "Oi"),
+    (559599964, "Oi"),
+    (559599965, "Oi"),
+    (559599967, "Oi"),
+    (559599970, "Oi"),
+    (559599971, "Oi"),
+    (559599972, "Oi"),
+    (559599974, "Oi"),
+    (559599976, "Oi"),
+    (559599977, "Oi"),
+    (559599981, "Oi"),
+    (559698111, "TIM"),
+    (559698112, "TIM"),
+    (559698113, "TIM"),
+    (559698114, "TIM"),
+    (559698115, "TIM"),
+    (559698116, "TIM"),
+    (559698117, "TIM"),
+    (559698118, "TIM"),
+    (559698119, "TIM"),
+    (559698121, "TIM"),
+    (559698122, "TIM"),
+    (559698123, "TIM"),
+    (559698124, "TIM"),
+    (559698125, "TIM"),
+    (559698126, "TIM"),
+    (559698127, "TIM"),
+    (559698128, "TIM"),
+    (559698129, "TIM"),
+    (559698131, "TIM"),
+    (559698132, "TIM"),
+    (559698133, "TIM"),
+    (559698134, "TIM"),
+    (559698135, "TIM"),
+    (559698410, "Claro"),
+    (559698411, "Claro"),
+    (559698412, "Claro"),
+    (559698413, "Claro"),
+    (559699111, "Vivo"),
+    (559699112, "Vivo"),
+    (559699113, "Vivo"),
+    (559699114, "Vivo"),
+    (559699115, "Vivo"),
+    (559699116, "Vivo"),
+    (559699117, "Vivo"),
+    (559699118, "Vivo"),
+    (559699119, "Vivo"),
+    (559699121, "Vivo"),
+    (559699122, "Vivo"),
+    (559699123, "Vivo"),
+    (559699124, "Vivo"),
+    (559699125, "Vivo"),
+    (559699126, "Vivo"),
+    (559699127, "Vivo"),
+    (559699128, "Vivo"),
+    (559699129, "Vivo"),
+    (559699131, "Vivo"),
+    (559699132, "Vivo"),
+    (559699133, "Vivo"),
+    (559699134, "Vivo"),
+    (559699135, "Vivo"),
+    (559699136, "Vivo"),
+    (559699137, "Vivo"),
+    (559699138, "Vivo"),
+    (559699139, "Vivo"),
+    (559699141, "Vivo"),
+    (559699142, "Vivo"),
+    (559699143, "Vivo"),
+    (559699144, "Vivo"),
+    (559699145, "Vivo"),
+    (559699146, "Vivo"),
+    (559699147, "Vivo"),
+    (559699148, "Vivo"),
+    (559699149, "Vivo"),
+    (559699151, "Vivo"),
+    (559699152, "Vivo"),
+    (559699153, "Vivo"),
+    (559699154, "Vivo"),
+    (559699155, "Vivo"),
+    (559699156, "Vivo"),
+    (559699157, "Vivo"),
+    (559699158, "Vivo"),
+    (559699159, "Vivo"),
+    (559699161, "Vivo"),
+    (559699162, "Vivo"),
+    (559699163, "Vivo"),
+    (559699164, "Vivo"),
+    (559699165, "Vivo"),
+    (559699166, "Vivo"),
+    (559699167, "Vivo"),
+    (559699168, "Vivo"),
+    (559699169, "Vivo"),
+    (559699171, "Vivo"),
+    (559699172, "Vivo"),
+    (559699173, "Vivo"),
+    (559699174, "Vivo"),
+    (559699175, "Vivo"),
+    (559699176, "Vivo"),
+    (559699177, "Vivo"),
+    (559699178, "Vivo"),
+    (559699179, "Vivo"),
+    (559699181, "Vivo"),
+    (559699901, "Oi"),
+    (559699902, "Oi"),
+    (559699903, "Oi"),
+    (559699904, "Oi"),
+    (559699905, "Oi"),
+    (559699906, "Oi"),
+    (559699907, "Oi"),
+    (559699908, "Oi"),
+    (559699909, "Oi"),
+    (559699911, "Oi"),
+    (559699912, "Oi"),
+    (559699913, "Oi"),
+    (559699914, "Oi"),
+    (559699915, "Oi"),
+    (559699916, "Oi"),
+    (559699917, "Oi"),
+    (559699918, "Oi"),
+    (559699933, "Oi"),
+    (559699961, "Oi"),
+    (559699962, "Oi"),
+    (559699963, "Oi"),
+    (559699964, "Oi"),
+    (559699965, "Oi"),
+    (559699966, "Oi"),
+    (559699967, "Oi"),
+    (559699968, "Oi"),
+    (559699970, "Oi"),
+    (559699971, "Oi"),
+    (559699972, "Oi"),
+    (559699973, "Oi"),
+    (559699974, "Oi"),
+    (559699975, "Oi"),
+    (559699976, "Oi"),
+    (559699981, "Oi"),
+    (559798111, "TIM"),
+    (559798113, "TIM"),
+    (559798114, "TIM"),
+    (559798115, "TIM"),
+    (559798116, "TIM"),
+    (559798117, "TIM"),
+    (559798118, "TIM"),
+    (559798119, "TIM"),
+    (559798121, "TIM"),
+    (559798410, "Claro"),
+    (559798411, "Claro"),
+    (559799151, "Vivo"),
+    (559799152, "Vivo"),
+    (559799153, "Vivo"),
+    (559799154, "Vivo"),
+    (559799155, "Vivo"),
+    (559799156, "Vivo"),
+    (559799157, "Vivo"),
+    (559799158, "Vivo"),
+    (559799159, "Vivo"),
+    (559799161, "Vivo"),
+    (559799162, "Vivo"),
+    (559799163, "Vivo"),
+    (559799164, "Vivo"),
+    (559799165, "Vivo"),
+    (559799166, "Vivo"),
+    (559799167, "Vivo"),
+    (559799168, "Vivo"),
+    (559799169, "Vivo"),
+    (559799171, "Vivo"),
+    (559799172, "Vivo"),
+    (559799173, "Vivo"),
+    (559799174, "Vivo"),
+    (559799175, "Vivo"),
+    (559799176, "Vivo"),
+    (559799177, "Vivo"),
+    (559799178, "Vivo"),
+    (559799179, "Vivo"),
+    (559799181, "Vivo"),
+    (559799182, "Vivo"),
+    (559799183, "Vivo"),
+    (559799184, "Vivo"),
+    (559799185, "Vivo"),
+    (559799186, "Vivo"),
+    (559799187, "Vivo"),
+    (559799188, "Vivo"),
+    (559799611, "Oi"),
+    (559799612, "Oi"),
+    (559799613, "Oi"),
+    (559799901, "Oi"),
+    (559799902, "Oi"),
+    (559799903, "Oi"),
+    (559799933, "Oi"),
+    (559799953, "Oi"),
+    (559799957, "Oi"),
+    (559799958, "Oi"),
+    (559799959, "Oi"),
+    (559799978, "Oi"),
+    (559799979, "Oi"),
+    (559898111, "TIM"),
+    (559898112, "TIM"),
+    (559898113, "TIM"),
+    (559898114, "TIM"),
+    (559898115, "TIM"),
+    (559898116, "TIM"),
+    (559898117, "TIM"),
+    (559898118, "TIM"),
+    (559898119, "TIM"),
+    (559898121, "TIM"),
+    (559898122, "TIM"),
+    (559898123, "TIM"),
+    (559898124, "TIM"),
+    (559898125, "TIM"),
+    (559898126, "TIM"),
+    (559898127, "TIM"),
+    (559898128, "TIM"),
+    (559898129, "TIM"),
+    (559898131, "TIM"),
+    (559898132, "TIM"),
+    (559898133, "TIM"),
+    (559898134, "TIM"),
+    (559898135, "TIM"),
+    (559898136, "TIM"),
+    (559898137, "TIM"),
+    (559898138, "TIM"),
+    (559898139, "TIM"),
+    (559898141, "TIM"),
+    (559898142, "TIM"),
+    (559898143, "TIM"),
+    (559898144, "TIM"),
+    (559898145, "TIM"),
+    (559898146, "TIM"),
+    (559898147, "TIM"),
+    (559898148, "TIM"),
+    (559898149, "TIM"),
+    (559898151, "TIM"),
+    (559898152, "TIM"),
+    (559898153, "TIM"),
+    (559898154, "TIM"),
+    (559898155, "TIM"),
+    (559898156, "TIM"),
+    (559898157, "TIM"),
+    (559898158, "TIM"),
+    (559898159, "TIM"),
+    (559898161, "TIM"),
+    (559898162, "TIM"),
+    (559898163, "TIM"),
+    (559898164, "TIM"),
+    (559898165, "TIM"),
+    (559898166, "TIM"),
+    (559898167, "TIM"),
+    (559898168, "TIM"),
+    (559898169, "TIM"),
+    (559898171, "TIM"),
+    (559898172, "TIM"),
+    (559898173, "TIM"),
+    (559898174, "TIM"),
+    (559898175, "TIM"),
+    (559898176, "TIM"),
+    (559898177, "TIM"),
+    (559898178, "TIM"),
+    (559898179, "TIM"),
+    (559898181, "TIM"),
+    (559898182, "TIM"),
+    (559898183, "TIM"),
+    (559898184, "TIM"),
+    (559898185, "TIM"),
+    (559898460, "Claro"),
+    (559898461, "Claro"),
+    (559899101, "Vivo"),
+    (559899102, "Vivo"),
+    (559899103, "Vivo"),
+    (559899104, "Vivo"),
+    (559899105, "Vivo"),
+    (559899106, "Vivo"),
+    (559899107, "Vivo"),
+    (559899108, "Vivo"),
+    (559899111, "Vivo"),
+    (559899112, "Vivo"),
+    (559899113, "Vivo"),
+    (559899114, "Vivo"),
+    (559899115, "Vivo"),
+    (559899116, "Vivo"),
+    (559899117, "Vivo"),
+    (559899118, "Vivo"),
+    (559899119, "Vivo"),
+    (559899121, "Vivo"),
+    (559899122, "Vivo"),
+    (559899123, "Vivo"),
+    (559899124, "Vivo"),
+    (559899125, "Vivo"),
+    (559899126, "Vivo"),
+    (559899127, "Vivo"),
+    (559899128, "Vivo"),
+    (559899129, "Vivo"),
+    (559899131, "Vivo"),
+    (559899132, "Vivo"),
+    (559899133, "Vivo"),
+    (559899134, "Vivo"),
+    (559899135, "Vivo"),
+    (559899136, "Vivo"),
+    (559899137, "Vivo"),
+    (559899138, "Vivo"),
+    (559899139, "Vivo"),
+    (559899141, "Vivo"),
+    (559899142, "Vivo"),
+    (559899143, "Vivo"),
+    (559899144, "Vivo"),
+    (559899145, "Vivo"),
+    (559899146, "Vivo"),
+    (559899147, "Vivo"),
+    (559899148, "Vivo"),
+    (559899149, "Vivo"),
+    (559899151, "Vivo"),
+    (559899152, "Vivo"),
+    (559899153, "Vivo"),
+    (559899154, "Vivo"),
+    (559899155, "Vivo"),
+    (559899156, "Vivo"),
+    (559899157, "Vivo"),
+    (559899158, "Vivo"),
+    (559899159, "Vivo"),
+    (559899161, "Vivo"),
+    (559899162, "Vivo"),
+    (559899163, "Vivo"),
+    (559899164, "Vivo"),
+    (559899165, "Vivo"),
+    (559899166, "Vivo"),
+    (559899167, "Vivo"),
+    (559899168, "Vivo"),
+    (559899169, "Vivo"),
+    (559899171, "Vivo"),
+    (559899172, "Vivo"),
+    (559899173, "Vivo"),
+    (559899174, "Vivo"),
+    (559899175, "Vivo"),
+    (559899176, "Vivo"),
+    (559899177, "Vivo"),
+    (559899178, "Vivo"),
+    (559899179, "Vivo"),
+    (559899181, "Vivo"),
+    (559899182, "Vivo"),
+    (559899183, "Vivo"),
+    (559899184, "Vivo"),
+    (559899185, "Vivo"),
+    (559899186, "Vivo"),
+    (559899187, "Vivo"),
+    (559899188, "Vivo"),
+    (559899189, "Vivo"),
+    (559899191, "Vivo"),
+    (559899192, "Vivo"),
+    (559899193, "Vivo"),
+    (559899194, "Vivo"),
+    (559899195, "Vivo"),
+    (559899196, "Vivo"),
+    (559899197, "Vivo"),
+    (559899198, "Vivo"),
+    (559899199, "Vivo"),
+    (559899601, "Oi"),
+    (559899602, "Oi"),
+    (559899603, "Oi"),
+    (559899604, "Oi"),
+    (559899605, "Oi"),
+    (559899606, "Oi"),
+    (559899607, "Oi"),
+    (559899608, "Oi"),
+    (559899609, "Oi"),
+    (559899611, "Oi"),
+    (559899612, "Oi"),
+    (559899613, "Oi"),
+    (559899614, "Oi"),
+    (559899615, "Oi"),
+    (559899616, "Oi"),
+    (559899617, "Oi"),
+    (559899618, "Oi"),
+    (559899619, "Oi"),
+    (559899621, "Oi"),
+    (559899901, "Oi"),
+    (559899902, "Oi"),
+    (559899903, "Oi"),
+    (559899904, "Oi"),
+    (559899905, "Oi"),
+    (559899906, "Oi"),
+    (559899907, "Oi"),
+    (559899908, "Oi"),
+    (559899909, "Oi"),
+    (559899911, "Oi"),
+    (559899912, "Oi"),
+    (559899913, "Oi"),
+    (559899933, "Oi"),
+    (559899934, "Oi"),
+    (559899944, "Oi"),
+    (559899961, "Oi"),
+    (559899962, "Oi"),
+    (559899963, "Oi"),
+    (559899964, "Oi"),
+    (559899965, "Oi"),
+    (559899966, "Oi"),
+    (559899967, "Oi"),
+    (559899968, "Oi"),
+    (559899969, "Oi"),
+    (559899970, "Oi"),
+    (559899971, "Oi"),
+    (559899972, "Oi"),
+    (559899973, "Oi"),
+    (559899974, "Oi"),
+    (559899975, "Oi"),
+    (559899976, "Oi"),
+    (559899981, "Oi"),
+    (559899982, "Oi"),
+    (559899983, "Oi"),
+    (559899984, "Oi"),
+    (559899985, "Oi"),
+    (559899988, "Oi"),
+    (559899991, "Oi"),
+    (559899992, "Oi"),
+    (559899993, "Oi"),
+    (559899994, "Oi"),
+    (559899995, "Oi"),
+    (559998111, "TIM"),
+    (559998112, "TIM"),
+    (559998113, "TIM"),
+    (559998114, "TIM"),
+    (559998115, "TIM"),
+    (559998116, "TIM"),
+    (559998117, "TIM"),
+    (559998118, "TIM"),
+    (559998119, "TIM"),
+    (559998121, "TIM"),
+    (559998122, "TIM"),
+    (559998123, "TIM"),
+    (559998124, "TIM"),
+    (559998125, "TIM"),
+    (559998126, "TIM"),
+    (559998127, "TIM"),
+    (559998128, "TIM"),
+    (559998129, "TIM"),
+    (559998131, "TIM"),
+    (559998132, "TIM"),
+    (559998133, "TIM"),
+    (559998134, "TIM"),
+    (559998135, "TIM"),
+    (559998136, "TIM"),
+    (559998137, "TIM"),
+    (559998138, "TIM"),
+    (559998139, "TIM"),
+    (559998141, "TIM"),
+    (559998142, "TIM"),
+    (559998143, "TIM"),
+    (559998144, "TIM"),
+    (559998145, "TIM"),
+    (559998146, "TIM"),
+    (559998147, "TIM"),
+    (559998148, "TIM"),
+    (559998149, "TIM"),
+    (559998151, "TIM"),
+    (559998152, "TIM"),
+    (559998153, "TIM"),
+    (559998420, "Claro"),
+    (559998421, "Claro"),
+    (559998422, "Claro"),
+    (559999101, "Vivo"),
+    (559999102, "Vivo"),
+    (559999103, "Vivo"),
+    (559999104, "Vivo"),
+    (559999105, "Vivo"),
+    (559999106, "Vivo"),
+    (559999107, "Vivo"),
+    (559999108, "Vivo"),
+    (559999109, "Vivo"),
+    (559999111, "Vivo"),
+    (559999112, "Vivo"),
+    (559999113, "Vivo"),
+    (559999114, "Vivo"),
+    (559999115, "Vivo"),
+    (559999121, "Vivo"),
+    (559999122, "Vivo"),
+    (559999123, "Vivo"),
+    (559999124, "Vivo"),
+    (559999125, "Vivo"),
+    (559999126, "Vivo"),
+    (559999127, "Vivo"),
+    (559999128, "Vivo"),
+    (559999129, "Vivo"),
+    (559999131, "Vivo"),
+    (559999132, "Vivo"),
+    (559999133, "Vivo"),
+    (559999134, "Vivo"),
+    (559999135, "Vivo"),
+    (559999136, "Vivo"),
+    (559999137, "Vivo"),
+    (559999138, "Vivo"),
+    (559999139, "Vivo"),
+    (559999141, "Vivo"),
+    (559999142, "Vivo"),
+    (559999143, "Vivo"),
+    (559999144, "Vivo"),
+    (559999145, "Vivo"),
+    (559999146, "Vivo"),
+    (559999147, "Vivo"),
+    (559999148, "Vivo"),
+    (559999149, "Vivo"),
+    (559999151, "Vivo"),
+    (559999152, "Vivo"),
+    (559999153, "Vivo"),
+    (559999154, "Vivo"),
+    (559999155, "Vivo"),
+    (559999156, "Vivo"),
+    (559999157, "Vivo"),
+    (559999158, "Vivo"),
+    (559999159, "Vivo"),
+    (559999161, "Vivo"),
+    (559999162, "Vivo"),
+    (559999163, "Vivo"),
+    (559999164, "Vivo"),
+    (559999165, "Vivo"),
+    (559999166, "Vivo"),
+    (559999167, "Vivo"),
+    (559999168, "Vivo"),
+    (559999169, "Vivo"),
+    (559999171, "Vivo"),
+    (559999172, "Vivo"),
+    (559999173, "Vivo"),
+    (559999174, "Vivo"),
+    (559999175, "Vivo"),
+    (559999176, "Vivo"),
+    (559999177, "Vivo"),
+    (559999178, "Vivo"),
+    (559999179, "Vivo"),
+    (559999181, "Vivo"),
+    (559999182, "Vivo"),
+    (559999183, "Vivo"),
+    (559999184, "Vivo"),
+    (559999185, "Vivo"),
+    (559999186, "Vivo"),
+    (559999187, "Vivo"),
+    (559999188, "Vivo"),
+    (559999189, "Vivo"),
+    (559999191, "Vivo"),
+    (559999192, "Vivo"),
+    (559999193, "Vivo"),
+    (559999194, "Vivo"),
+    (559999195, "Vivo"),
+    (559999196, "Vivo"),
+    (559999197, "Vivo"),
+    (559999198, "Vivo"),
+    (559999631, "Oi"),
+    (559999641, "Oi"),
+    (559999642, "Oi"),
+    (559999643, "Oi"),
+    (559999644, "Oi"),
+    (559999645, "Oi"),
+    (559999646, "Oi"),
+    (559999647, "Oi"),
+    (559999649, "Oi"),
+    (559999651, "Oi"),
+    (559999901, "Oi"),
+    (559999902, "Oi"),
+    (559999903, "Oi"),
+    (559999904, "Oi"),
+    (559999905, "Oi"),
+    (559999933, "Oi"),
+    (559999934, "Oi"),
+    (559999935, "Oi"),
+    (559999951, "Oi"),
+    (559999952, "Oi"),
+    (559999953, "Oi"),
+    (559999954, "Oi"),
+    (559999955, "Oi"),
+    (559999970, "Oi"),
+    (559999977, "Oi"),
+    (559999978, "Oi"),
+    (559999979, "Oi"),
+    (559999984, "Oi"),
+    (559999985, "Oi"),
+    (559999986, "Oi"),
+    (559999987, "Oi"),
+    (559999989, "Oi"),
+    (563324320, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563324321, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563324322, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563324323, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563324324, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563423330, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563423331, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563423332, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563423333, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563423334, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563524270, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563524271, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563524272, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563524273, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (563524274, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564223570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564223571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564223572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564223573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564223574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564324370, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564324371, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564324372, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564324373, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564324374, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564523570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564523571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564523572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564523573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (564523574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565225570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565225571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565225572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565225573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565225574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565325570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565325571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565325572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565325573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (565325574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566123330, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566123331, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566123332, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566123333, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566123334, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566423570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566423571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566423572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566423573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566423574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566524270, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566524271, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566524272, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566524273, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566524274, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566724270, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566724271, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566724272, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566724273, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (566724274, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567126550, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567126551, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567126552, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567126553, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567126554, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567323570, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567323571, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567323572, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567323573, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567323574, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567524550, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567524551, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567524552, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567524553, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (567524554, "Compania Chilena De Comunicaciones Parallel S.A."),
+    (573046851, "Tigo"),
+    (573046852, "Tigo"),
+    (573046853, "Tigo"),
+    (573046854, "Tigo"),
+    (573046855, "Tigo"),
+    (573046856, "Tigo"),
+    (573046857, "Tigo"),
+    (573046858, "Tigo"),
+    (573046859, "Tigo"),
+    (573052670, "Tigo"),
+    (573052671, "Tigo"),
+    (995500050, "Silknet"),
+    (995500051, "Silknet"),
+    (995500052, "Silknet"),
+    (995500053, "Silknet"),
+    (995500054, "Silknet"),
+    (995500055, "Cellfie"),
+    (995500056, "Cellfie"),
+    (995500057, "Cellfie"),
+    (995500058, "Cellfie"),
+    (995500059, "Cellfie"),
+    (995559995, "DataHouse Global"),
+    (995559996, "DataHouse Global"),
+    (995559997, "DataHouse Global"),
+    (995559998, "DataHouse Global"),
+    (995559999, "DataHouse Global"),
+    (996312973, "Nur Telecom"),
+];
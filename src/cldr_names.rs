@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+
+use crate::locale::{locale_fallbacks, FALLBACK_LOCALE};
+use crate::TerritoryCode;
+
+mod data;
+
+/// The territory names of a locale, indexed like [`TerritoryCode::all`]
+struct LocaleNames {
+    locale: &'static str,
+    names: &'static [&'static str],
+    sort_keys: &'static [u16],
+}
+
+///
+/// The name of a territory in some language, from the Unicode CLDR
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocalizedName {
+    name: &'static str,
+    locale: &'static str,
+    sort_key: u16,
+}
+
+impl LocalizedName {
+    /// The territory name, suitable for display
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The locale the name was found in, after falling back from the requested one
+    pub fn locale(&self) -> &'static str {
+        self.locale
+    }
+
+    /// The position of this name among the names of all the territories in the requested locale,
+    /// English ones included, once sorted following the collation rules of that locale. Country
+    /// pickers can sort on this key.
+    pub fn sort_key(&self) -> u16 {
+        self.sort_key
+    }
+}
+
+impl Display for LocalizedName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl TerritoryCode {
+    /// Gets the name of this territory in the language of a BCP 47 locale, like `de` or `pt-BR`.
+    /// Locales without names of their own fall back to their parents, e.g. `pt-BR` to `pt` and
+    /// `es-MX` to `es-419`, and ultimately to [`TerritoryCode::english_name`].
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// let name = TerritoryCode::DE.localized_name("pt-BR");
+    /// assert_eq!("Alemanha", name.name());
+    /// assert_eq!("pt", name.locale());
+    ///
+    /// let mut territories = vec![TerritoryCode::AT, TerritoryCode::ZW, TerritoryCode::SE];
+    /// territories.sort_by_key(|territory| territory.localized_name("sv").sort_key());
+    /// assert_eq!(
+    ///     vec![TerritoryCode::SE, TerritoryCode::ZW, TerritoryCode::AT],
+    ///     territories,
+    ///     "Sverige, Zimbabwe, Österrike: Ö sorts after Z in Swedish"
+    /// );
+    /// ```
+    pub fn localized_name(&self, locale: &str) -> LocalizedName {
+        let index = *self as usize;
+        let names = locale_fallbacks(locale)
+            .iter()
+            .find_map(|locale| data::LOCALES.iter().find(|names| names.locale == *locale))
+            .expect("the fallback locale has names for all territories");
+        // Sort keys always come from the requested locale, whose table sorts the English names
+        // of the territories it has no names of
+        let (name, locale) = match names.names.get(index) {
+            Some(&name) if !name.is_empty() => (name, names.locale),
+            _ => (self.english_name(), FALLBACK_LOCALE),
+        };
+        LocalizedName {
+            name,
+            locale,
+            sort_key: names.sort_keys[index],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn locale_data_is_complete() {
+        for names in &data::LOCALES {
+            assert!(names.names.is_empty() || names.names.len() == TerritoryCode::COUNT);
+            assert_eq!(TerritoryCode::COUNT, names.sort_keys.len());
+            let sort_keys: HashSet<_> = names.sort_keys.iter().collect();
+            assert_eq!(TerritoryCode::COUNT, sort_keys.len(), "{}", names.locale);
+        }
+        assert!(data::LOCALES
+            .iter()
+            .any(|names| names.locale == FALLBACK_LOCALE));
+    }
+
+    #[test]
+    fn sort_keys_are_unique_per_locale() {
+        let locales = data::LOCALES.iter().map(|names| names.locale);
+        for locale in locales.chain(["pt-BR", "es-MX", "zh-TW", "tlh"]) {
+            let sort_keys: HashSet<_> = TerritoryCode::all()
+                .iter()
+                .map(|territory| territory.localized_name(locale).sort_key())
+                .collect();
+            assert_eq!(TerritoryCode::COUNT, sort_keys.len(), "{}", locale);
+        }
+        let fr = |territory: TerritoryCode| territory.localized_name("fr").sort_key();
+        assert!(fr(TerritoryCode::FR) < fr(TerritoryCode::XT));
+        assert!(fr(TerritoryCode::XT) < fr(TerritoryCode::IT));
+    }
+
+    #[test]
+    fn localized_names() {
+        let name = |territory: TerritoryCode, locale| {
+            let name = territory.localized_name(locale);
+            (name.name(), name.locale())
+        };
+        assert_eq!(
+            ("Vereinigtes Königreich", "de"),
+            name(TerritoryCode::GB, "de-AT")
+        );
+        assert_eq!(("Reino Unido", "es-419"), name(TerritoryCode::GB, "es-AR"));
+        assert_eq!(("英國", "zh-Hant"), name(TerritoryCode::GB, "zh-HK"));
+        assert_eq!(("United Kingdom", "en"), name(TerritoryCode::GB, "tlh"));
+        assert_eq!(
+            ("International Freephone", "en"),
+            name(TerritoryCode::XT, "fr")
+        );
+        assert_eq!(
+            ("Democratic Republic of the Congo", "en"),
+            name(TerritoryCode::CD, "en-GB")
+        );
+    }
+}
@@ -0,0 +1,2031 @@
+// Generated by `cargo run -p metadata-gen -- cldr-names path/to/cldr-localenames-full/main`.
+// Do not edit.
+// Territory display names from CLDR 46, in the order of `TerritoryCode::all`. Territories
+// unknown to CLDR have an empty name. English names are those of `TerritoryCode::english_name`.
+// Sort keys are the positions of the names once sorted with the CLDR collation of each locale,
+// English names included.
+
+use super::LocaleNames;
+
+#[rustfmt::skip]
+pub(super) static LOCALES: [LocaleNames; 35] = [
+    LocaleNames {
+        locale: "ar",
+        names: &[
+            "الولايات المتحدة", "كندا", "جزيرة أسينشيون", "أندورا", "الإمارات العربية المتحدة",
+            "أفغانستان", "أنتيغوا وبربودا", "أنغويلا", "ألبانيا", "أرمينيا", "أنغولا", "أنتاركتيكا",
+            "الأرجنتين", "ساموا الأمريكية", "النمسا", "أستراليا", "أروبا", "جزر آلاند", "أذربيجان",
+            "البوسنة والهرسك", "بربادوس", "بنغلاديش", "بلجيكا", "بوركينا فاسو", "بلغاريا",
+            "البحرين", "بوروندي", "بنين", "برمودا", "بروناي", "بوليفيا", "هولندا الكاريبية",
+            "البرازيل", "جزر البهاما", "بوتان", "بوتسوانا", "بيلاروس", "بليز", "جزر كوكوس (كيلينغ)",
+            "الكونغو - كينشاسا", "جمهورية أفريقيا الوسطى", "الكونغو - برازافيل", "سويسرا",
+            "ساحل العاج", "جزر كوك", "تشيلي", "الكاميرون", "الصين", "كولومبيا", "كوستاريكا", "كوبا",
+            "الرأس الأخضر", "كوراساو", "جزيرة كريسماس", "قبرص", "التشيك", "ألمانيا", "جيبوتي",
+            "الدانمرك", "دومينيكا", "جمهورية الدومينيكان", "الجزائر", "الإكوادور", "إستونيا", "مصر",
+            "إريتريا", "إسبانيا", "إثيوبيا", "فنلندا", "فيجي", "جزر فوكلاند", "ميكرونيزيا",
+            "جزر فارو", "فرنسا", "الغابون", "المملكة المتحدة", "غرينادا", "جورجيا",
+            "غويانا الفرنسية", "غيرنزي", "غانا", "جبل طارق", "غرينلاند", "غامبيا", "غينيا",
+            "غوادلوب", "غينيا الاستوائية", "اليونان", "غواتيمالا", "غوام", "غينيا بيساو", "غيانا",
+            "هونغ كونغ الصينية (منطقة إدارية خاصة)", "هندوراس", "كرواتيا", "هايتي", "هنغاريا",
+            "إندونيسيا", "أيرلندا", "إسرائيل", "جزيرة مان", "الهند",
+            "الإقليم البريطاني في المحيط الهندي", "العراق", "إيران", "آيسلندا", "إيطاليا", "جيرسي",
+            "جامايكا", "الأردن", "اليابان", "كينيا", "قيرغيزستان", "كمبوديا", "كيريباتي",
+            "جزر القمر", "سانت كيتس ونيفيس", "كوريا الشمالية", "كوريا الجنوبية", "الكويت",
+            "جزر كايمان", "كازاخستان", "لاوس", "لبنان", "سانت لوسيا", "ليختنشتاين", "سريلانكا",
+            "ليبيريا", "ليسوتو", "ليتوانيا", "لوكسمبورغ", "لاتفيا", "ليبيا", "المغرب", "موناكو",
+            "مولدوفا", "الجبل الأسود", "مدغشقر", "جزر مارشال", "مقدونيا الشمالية", "مالي",
+            "ميانمار (بورما)", "منغوليا", "منطقة ماكاو الإدارية الخاصة", "جزر ماريانا الشمالية",
+            "جزر المارتينيك", "موريتانيا", "مونتسرات", "مالطا", "موريشيوس", "جزر المالديف", "ملاوي",
+            "المكسيك", "ماليزيا", "موزمبيق", "ناميبيا", "كاليدونيا الجديدة", "النيجر",
+            "جزيرة نورفولك", "نيجيريا", "نيكاراغوا", "هولندا", "النرويج", "نيبال", "ناورو", "نيوي",
+            "نيوزيلندا", "عُمان", "بنما", "بيرو", "بولينيزيا الفرنسية", "بابوا غينيا الجديدة",
+            "الفلبين", "باكستان", "بولندا", "سان بيير ومكويلون", "بورتوريكو", "الأراضي الفلسطينية",
+            "البرتغال", "بالاو", "باراغواي", "قطر", "روينيون", "رومانيا", "صربيا", "روسيا",
+            "رواندا", "المملكة العربية السعودية", "جزر سليمان", "سيشل", "السودان", "السويد",
+            "سنغافورة", "سانت هيلينا", "سلوفينيا", "سفالبارد وجان ماين", "سلوفاكيا", "سيراليون",
+            "سان مارينو", "السنغال", "الصومال", "سورينام", "جنوب السودان", "ساو تومي وبرينسيبي",
+            "السلفادور", "سانت مارتن", "سوريا", "إسواتيني", "جزر توركس وكايكوس", "تشاد", "توغو",
+            "تايلاند", "طاجيكستان", "توكيلاو", "تيمور - ليشتي", "تركمانستان", "تونس", "تونغا",
+            "تركيا", "ترينيداد وتوباغو", "توفالو", "تايوان", "تنزانيا", "أوكرانيا", "أوغندا",
+            "أورغواي", "أوزبكستان", "الفاتيكان", "سانت فنسنت وجزر غرينادين", "فنزويلا",
+            "جزر فيرجن البريطانية", "جزر فيرجن الأمريكية", "فيتنام", "فانواتو", "جزر والس وفوتونا",
+            "ساموا", "", "", "", "", "", "", "اليمن", "مايوت", "جنوب أفريقيا", "زامبيا", "زيمبابوي",
+        ],
+        sort_keys: &[
+            51, 192, 124, 57, 17, 11, 56, 60, 18, 3, 59, 55, 13, 144, 48, 7, 4, 107, 2, 22, 72, 78,
+            75, 84, 76, 19, 85, 80, 73, 74, 87, 239, 20, 108, 81, 82, 90, 77, 120, 40, 128, 39, 160,
+            142, 119, 97, 38, 33, 198, 197, 193, 27, 194, 125, 185, 23, 42, 133, 26, 135, 129, 25,
+            16, 8, 215, 5, 6, 1, 182, 184, 115, 227, 114, 180, 35, 46, 168, 132, 173, 175, 167, 106,
+            169, 166, 176, 171, 177, 54, 170, 172, 178, 174, 240, 236, 190, 235, 237, 58, 66, 9,
+            126, 50, 15, 34, 65, 0, 67, 134, 105, 14, 52, 200, 187, 191, 199, 109, 148, 196, 195,
+            41, 118, 188, 202, 203, 149, 208, 153, 206, 209, 207, 204, 201, 205, 43, 224, 223, 24,
+            214, 121, 216, 211, 226, 219, 218, 122, 110, 220, 225, 210, 221, 111, 217, 44, 212, 222,
+            228, 189, 49, 127, 231, 232, 238, 47, 230, 229, 234, 233, 165, 79, 89, 88, 68, 37, 70,
+            86, 145, 83, 12, 21, 71, 69, 186, 139, 138, 163, 137, 136, 45, 113, 162, 30, 31, 157,
+            151, 156, 154, 155, 161, 146, 29, 32, 159, 131, 152, 28, 150, 158, 10, 112, 96, 99, 91,
+            164, 101, 104, 93, 102, 103, 94, 95, 100, 92, 98, 64, 63, 61, 62, 36, 147, 181, 117,
+            116, 183, 179, 123, 143, 241, 242, 246, 245, 243, 244, 53, 213, 130, 140, 141,
+        ],
+    },
+    LocaleNames {
+        locale: "bn",
+        names: &[
+            "মার্কিন যুক্তরাষ্ট্র", "কানাডা", "অ্যাসেনশন দ্বীপপুঞ্জ", "আন্ডোরা",
+            "সংযুক্ত আরব আমিরাত", "আফগানিস্তান", "অ্যান্টিগুয়া ও বারবুডা", "অ্যাঙ্গুইলা",
+            "আলবেনিয়া", "আর্মেনিয়া", "অ্যাঙ্গোলা", "অ্যান্টার্কটিকা", "আর্জেন্টিনা",
+            "আমেরিকান সামোয়া", "অস্ট্রিয়া", "অস্ট্রেলিয়া", "আরুবা", "অলান্ড দ্বীপপুঞ্জ",
+            "আজারবাইজান", "বসনিয়া ও হার্জেগোভিনা", "বার্বাডোজ", "বাংলাদেশ", "বেলজিয়াম",
+            "বুরকিনা ফাসো", "বুলগেরিয়া", "বাহারিন", "বুরুন্ডি", "বেনিন", "বারমুডা", "ব্রুনেই",
+            "বলিভিয়া", "ক্যারিবিয়ান নেদারল্যান্ডস", "ব্রাজিল", "বাহামা দ্বীপপুঞ্জ", "ভুটান",
+            "বতসোয়ানা", "বেলারুশ", "বেলিজ", "কোকোস (কিলিং) দ্বীপপুঞ্জ", "কঙ্গো-কিনশাসা",
+            "মধ্য আফ্রিকার প্রজাতন্ত্র", "কঙ্গো - ব্রাজাভিল", "সুইজারল্যান্ড", "কোট ডি‘আইভোর",
+            "কুক দ্বীপপুঞ্জ", "চিলি", "ক্যামেরুন", "চীন", "কলম্বিয়া", "কোস্টারিকা", "কিউবা",
+            "কেপ ভার্দে", "কুরাসাও", "ক্রিসমাস দ্বীপ", "সাইপ্রাস", "চেকিয়া", "জার্মানি", "জিবুতি",
+            "ডেনমার্ক", "ডোমিনিকা", "ডোমেনিকান প্রজাতন্ত্র", "আলজেরিয়া", "ইকুয়েডর", "এস্তোনিয়া",
+            "মিশর", "ইরিত্রিয়া", "স্পেন", "ইথিওপিয়া", "ফিনল্যান্ড", "ফিজি",
+            "ফকল্যান্ড দ্বীপপুঞ্জ", "মাইক্রোনেশিয়া", "ফ্যারো দ্বীপপুঞ্জ", "ফ্রান্স", "গ্যাবন",
+            "যুক্তরাজ্য", "গ্রেনাডা", "জর্জিয়া", "ফরাসী গায়ানা", "গার্নসি", "ঘানা", "জিব্রাল্টার",
+            "গ্রীনল্যান্ড", "গাম্বিয়া", "গিনি", "গুয়াদেলৌপ", "নিরক্ষীয় গিনি", "গ্রীস",
+            "গুয়াতেমালা", "গুয়াম", "গিনি-বিসাউ", "গিয়ানা", "হংকং এসএআর চীনা", "হন্ডুরাস",
+            "ক্রোয়েশিয়া", "হাইতি", "হাঙ্গেরি", "ইন্দোনেশিয়া", "আয়ারল্যান্ড", "ইজরায়েল",
+            "আইল অফ ম্যান", "ভারত", "ব্রিটিশ ভারত মহাসাগরীয় অঞ্চল", "ইরাক", "ইরান", "আইসল্যান্ড",
+            "ইতালি", "জার্সি", "জামাইকা", "জর্ডন", "জাপান", "কেনিয়া", "কিরগিজিস্তান", "কম্বোডিয়া",
+            "কিরিবাতি", "কমোরোস", "সেন্ট কিটস ও নেভিস", "উত্তর কোরিয়া", "দক্ষিণ কোরিয়া", "কুয়েত",
+            "কেম্যান দ্বীপপুঞ্জ", "কাজাখস্তান", "লাওস", "লেবানন", "সেন্ট লুসিয়া", "লিচেনস্টেইন",
+            "শ্রীলঙ্কা", "লাইবেরিয়া", "লেসোথো", "লিথুয়ানিয়া", "লাক্সেমবার্গ", "লাটভিয়া",
+            "লিবিয়া", "মোরক্কো", "মোনাকো", "মলডোভা", "মন্টিনিগ্রো", "মাদাগাস্কার",
+            "মার্শাল দ্বীপপুঞ্জ", "উত্তর ম্যাসেডোনিয়া", "মালি", "মায়ানমার (বার্মা)", "মঙ্গোলিয়া",
+            "ম্যাকাও এসএআর চীন", "উত্তরাঞ্চলীয় মারিয়ানা দ্বীপপুঞ্জ", "মার্টিনিক", "মরিতানিয়া",
+            "মন্টসেরাট", "মাল্টা", "মরিশাস", "মালদ্বীপ", "মালাউই", "মেক্সিকো", "মালয়েশিয়া",
+            "মোজাম্বিক", "নামিবিয়া", "নিউ ক্যালেডোনিয়া", "নাইজার", "নরফোক দ্বীপ", "নাইজেরিয়া",
+            "নিকারাগুয়া", "নেদারল্যান্ডস", "নরওয়ে", "নেপাল", "নাউরু", "নিউয়ে", "নিউজিল্যান্ড",
+            "ওমান", "পানামা", "পেরু", "ফরাসী পলিনেশিয়া", "পাপুয়া নিউ গিনি", "ফিলিপাইন",
+            "পাকিস্তান", "পোল্যান্ড", "সেন্ট পিয়ের ও মিকুয়েলন", "পুয়ের্তো রিকো",
+            "প্যালেস্টাইন ভূখণ্ড", "পর্তুগাল", "পালাউ", "প্যারাগুয়ে", "কাতার", "রিইউনিয়ন",
+            "রোমানিয়া", "সার্বিয়া", "রাশিয়া", "রুয়ান্ডা", "সৌদি আরব", "সলোমন দ্বীপপুঞ্জ",
+            "সিসিলি", "সুদান", "সুইডেন", "সিঙ্গাপুর", "সেন্ট হেলেনা", "স্লোভানিয়া",
+            "স্বালবার্ড ও জান মেয়েন", "স্লোভাকিয়া", "সিয়েরা লিওন", "সান মারিনো", "সেনেগাল",
+            "সোমালিয়া", "সুরিনাম", "দক্ষিণ সুদান", "সাওটোমা ও প্রিন্সিপি", "এল সালভেদর",
+            "সিন্ট মার্টেন", "সিরিয়া", "ইসওয়াতিনি", "তুর্কস ও কাইকোস দ্বীপপুঞ্জ", "চাদ", "টোগো",
+            "থাইল্যান্ড", "তাজিকিস্তান", "টোকেলাউ", "তিমুর-লেস্তে", "তুর্কমেনিস্তান", "তিউনিসিয়া",
+            "টোঙ্গা", "তুরস্ক", "ত্রিনিনাদ ও টোব্যাগো", "টুভালু", "তাইওয়ান", "তাঞ্জানিয়া",
+            "ইউক্রেন", "উগান্ডা", "উরুগুয়ে", "উজবেকিস্তান", "ভ্যাটিকান সিটি",
+            "সেন্ট ভিনসেন্ট ও গ্রেনাডিনস", "ভেনেজুয়েলা", "ব্রিটিশ ভার্জিন দ্বীপপুঞ্জ",
+            "মার্কিন যুক্তরাষ্ট্রীয় ভার্জিন দ্বীপপুঞ্জ", "ভিয়েতনাম", "ভানুয়াটু",
+            "ওয়ালিস ও ফুটুনা", "সামোয়া", "", "", "", "", "", "", "ইয়েমেন", "মায়োত্তে",
+            "দক্ষিণ আফ্রিকা", "জাম্বিয়া", "জিম্বাবোয়ে",
+        ],
+        sort_keys: &[
+            179, 48, 7, 11, 209, 12, 6, 3, 19, 17, 4, 5, 16, 13, 1, 2, 15, 0, 10, 145, 148, 146,
+            155, 151, 153, 150, 152, 154, 147, 161, 144, 62, 158, 149, 165, 143, 156, 157, 58, 42,
+            169, 41, 221, 59, 52, 79, 61, 80, 45, 60, 49, 56, 54, 63, 211, 81, 87, 89, 96, 97, 98,
+            18, 21, 38, 188, 29, 233, 24, 139, 138, 135, 175, 141, 142, 73, 194, 76, 82, 136, 66,
+            77, 90, 74, 65, 67, 71, 122, 75, 70, 72, 68, 69, 237, 238, 64, 239, 240, 25, 14, 22, 8,
+            163, 159, 27, 28, 9, 23, 88, 85, 83, 84, 55, 50, 44, 51, 43, 226, 33, 110, 53, 57, 46,
+            200, 206, 229, 203, 208, 199, 207, 204, 201, 202, 205, 192, 191, 174, 171, 176, 182, 34,
+            186, 177, 168, 193, 35, 181, 172, 170, 187, 173, 183, 185, 189, 184, 190, 117, 118, 114,
+            113, 115, 121, 123, 112, 124, 116, 120, 119, 39, 127, 131, 137, 128, 140, 126, 132, 227,
+            130, 134, 125, 129, 133, 47, 196, 198, 215, 195, 197, 232, 210, 220, 223, 222, 216, 230,
+            236, 234, 235, 218, 213, 225, 231, 224, 111, 212, 37, 217, 219, 30, 106, 78, 94, 108,
+            100, 93, 103, 105, 102, 95, 104, 107, 92, 99, 101, 20, 31, 36, 32, 167, 228, 166, 160,
+            180, 164, 162, 40, 214, 241, 242, 246, 245, 243, 244, 26, 178, 109, 86, 91,
+        ],
+    },
+    LocaleNames {
+        locale: "cs",
+        names: &[
+            "Spojené státy", "Kanada", "Ascension", "Andorra", "Spojené arabské emiráty",
+            "Afghánistán", "Antigua a Barbuda", "Anguilla", "Albánie", "Arménie", "Angola",
+            "Antarktida", "Argentina", "Americká Samoa", "Rakousko", "Austrálie", "Aruba", "Ålandy",
+            "Ázerbájdžán", "Bosna a Hercegovina", "Barbados", "Bangladéš", "Belgie", "Burkina Faso",
+            "Bulharsko", "Bahrajn", "Burundi", "Benin", "Bermudy", "Brunej", "Bolívie",
+            "Karibské Nizozemsko", "Brazílie", "Bahamy", "Bhútán", "Botswana", "Bělorusko",
+            "Belize", "Kokosové ostrovy", "Kongo – Kinshasa", "Středoafrická republika",
+            "Kongo – Brazzaville", "Švýcarsko", "Pobřeží slonoviny", "Cookovy ostrovy", "Chile",
+            "Kamerun", "Čína", "Kolumbie", "Kostarika", "Kuba", "Kapverdy", "Curaçao",
+            "Vánoční ostrov", "Kypr", "Česko", "Německo", "Džibutsko", "Dánsko", "Dominika",
+            "Dominikánská republika", "Alžírsko", "Ekvádor", "Estonsko", "Egypt", "Eritrea",
+            "Španělsko", "Etiopie", "Finsko", "Fidži", "Falklandské ostrovy", "Mikronésie",
+            "Faerské ostrovy", "Francie", "Gabon", "Spojené království", "Grenada", "Gruzie",
+            "Francouzská Guyana", "Guernsey", "Ghana", "Gibraltar", "Grónsko", "Gambie", "Guinea",
+            "Guadeloupe", "Rovníková Guinea", "Řecko", "Guatemala", "Guam", "Guinea-Bissau",
+            "Guyana", "Hongkong – ZAO Číny", "Honduras", "Chorvatsko", "Haiti", "Maďarsko",
+            "Indonésie", "Irsko", "Izrael", "Ostrov Man", "Indie", "Britské indickooceánské území",
+            "Irák", "Írán", "Island", "Itálie", "Jersey", "Jamajka", "Jordánsko", "Japonsko",
+            "Keňa", "Kyrgyzstán", "Kambodža", "Kiribati", "Komory", "Svatý Kryštof a Nevis",
+            "Severní Korea", "Jižní Korea", "Kuvajt", "Kajmanské ostrovy", "Kazachstán", "Laos",
+            "Libanon", "Svatá Lucie", "Lichtenštejnsko", "Srí Lanka", "Libérie", "Lesotho", "Litva",
+            "Lucembursko", "Lotyšsko", "Libye", "Maroko", "Monako", "Moldavsko", "Černá Hora",
+            "Madagaskar", "Marshallovy ostrovy", "Severní Makedonie", "Mali", "Myanmar (Barma)",
+            "Mongolsko", "Macao – ZAO Číny", "Severní Mariany", "Martinik", "Mauritánie",
+            "Montserrat", "Malta", "Mauricius", "Maledivy", "Malawi", "Mexiko", "Malajsie",
+            "Mosambik", "Namibie", "Nová Kaledonie", "Niger", "Norfolk", "Nigérie", "Nikaragua",
+            "Nizozemsko", "Norsko", "Nepál", "Nauru", "Niue", "Nový Zéland", "Omán", "Panama",
+            "Peru", "Francouzská Polynésie", "Papua-Nová Guinea", "Filipíny", "Pákistán", "Polsko",
+            "Saint-Pierre a Miquelon", "Portoriko", "Palestinská území", "Portugalsko", "Palau",
+            "Paraguay", "Katar", "Réunion", "Rumunsko", "Srbsko", "Rusko", "Rwanda",
+            "Saúdská Arábie", "Šalamounovy ostrovy", "Seychely", "Súdán", "Švédsko", "Singapur",
+            "Svatá Helena", "Slovinsko", "Špicberky a Jan Mayen", "Slovensko", "Sierra Leone",
+            "San Marino", "Senegal", "Somálsko", "Surinam", "Jižní Súdán",
+            "Svatý Tomáš a Princův ostrov", "Salvador", "Svatý Martin (Nizozemsko)", "Sýrie",
+            "Eswatini", "Turks a Caicos", "Čad", "Togo", "Thajsko", "Tádžikistán", "Tokelau",
+            "Východní Timor", "Turkmenistán", "Tunisko", "Tonga", "Turecko", "Trinidad a Tobago",
+            "Tuvalu", "Tchaj-wan", "Tanzanie", "Ukrajina", "Uganda", "Uruguay", "Uzbekistán",
+            "Vatikán", "Svatý Vincenc a Grenadiny", "Venezuela", "Britské Panenské ostrovy",
+            "Americké Panenské ostrovy", "Vietnam", "Vanuatu", "Wallis a Futuna", "Samoa", "", "",
+            "", "", "", "", "Jemen", "Mayotte", "Jihoafrická republika", "Zambie", "Zimbabwe",
+        ],
+        sort_keys: &[
+            202, 104, 14, 6, 200, 0, 10, 8, 2, 12, 7, 9, 11, 4, 178, 15, 13, 1, 16, 28, 20, 19, 21,
+            35, 34, 18, 36, 24, 25, 33, 27, 106, 30, 17, 26, 29, 23, 22, 111, 115, 205, 114, 219,
+            174, 37, 79, 103, 42, 112, 116, 117, 105, 38, 238, 119, 41, 154, 46, 43, 44, 45, 3, 48,
+            50, 47, 49, 216, 52, 57, 55, 54, 145, 53, 58, 61, 201, 66, 68, 59, 72, 63, 64, 67, 62,
+            73, 69, 180, 184, 71, 70, 74, 75, 78, 77, 80, 76, 132, 82, 89, 92, 166, 81, 31, 87, 88,
+            90, 91, 96, 93, 100, 94, 109, 120, 102, 110, 113, 210, 191, 98, 118, 101, 108, 121, 123,
+            209, 126, 204, 124, 122, 127, 129, 128, 125, 138, 147, 146, 40, 131, 139, 192, 136, 151,
+            148, 130, 193, 140, 142, 149, 137, 141, 135, 134, 144, 133, 150, 152, 163, 156, 161,
+            157, 158, 160, 162, 155, 153, 159, 164, 165, 170, 173, 60, 171, 56, 167, 175, 185, 176,
+            169, 177, 168, 172, 107, 179, 181, 203, 182, 183, 189, 215, 194, 206, 218, 196, 208,
+            198, 217, 197, 195, 188, 190, 199, 207, 99, 212, 186, 211, 214, 51, 231, 39, 224, 222,
+            220, 225, 243, 230, 228, 226, 229, 227, 232, 223, 221, 234, 233, 236, 237, 240, 213,
+            241, 32, 5, 242, 239, 244, 187, 65, 83, 235, 86, 84, 85, 95, 143, 97, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "da",
+        names: &[
+            "USA", "Canada", "Ascensionøen", "Andorra", "De Forenede Arabiske Emirater",
+            "Afghanistan", "Antigua og Barbuda", "Anguilla", "Albanien", "Armenien", "Angola",
+            "Antarktis", "Argentina", "Amerikansk Samoa", "Østrig", "Australien", "Aruba", "Åland",
+            "Aserbajdsjan", "Bosnien-Hercegovina", "Barbados", "Bangladesh", "Belgien",
+            "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "Bermuda", "Brunei",
+            "Bolivia", "De tidligere Nederlandske Antiller", "Brasilien", "Bahamas", "Bhutan",
+            "Botswana", "Belarus", "Belize", "Cocosøerne", "Congo-Kinshasa",
+            "Den Centralafrikanske Republik", "Congo-Brazzaville", "Schweiz", "Elfenbenskysten",
+            "Cookøerne", "Chile", "Cameroun", "Kina", "Colombia", "Costa Rica", "Cuba", "Kap Verde",
+            "Curaçao", "Juleøen", "Cypern", "Tjekkiet", "Tyskland", "Djibouti", "Danmark",
+            "Dominica", "Den Dominikanske Republik", "Algeriet", "Ecuador", "Estland", "Egypten",
+            "Eritrea", "Spanien", "Etiopien", "Finland", "Fiji", "Falklandsøerne", "Mikronesien",
+            "Færøerne", "Frankrig", "Gabon", "Storbritannien", "Grenada", "Georgien",
+            "Fransk Guyana", "Guernsey", "Ghana", "Gibraltar", "Grønland", "Gambia", "Guinea",
+            "Guadeloupe", "Ækvatorialguinea", "Grækenland", "Guatemala", "Guam", "Guinea-Bissau",
+            "Guyana", "SAR Hongkong", "Honduras", "Kroatien", "Haiti", "Ungarn", "Indonesien",
+            "Irland", "Israel", "Isle of Man", "Indien",
+            "Det Britiske Territorium i Det Indiske Ocean", "Irak", "Iran", "Island", "Italien",
+            "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kirgisistan", "Cambodja", "Kiribati",
+            "Comorerne", "Saint Kitts og Nevis", "Nordkorea", "Sydkorea", "Kuwait", "Caymanøerne",
+            "Kasakhstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Litauen", "Luxembourg", "Letland", "Libyen", "Marokko", "Monaco", "Moldova",
+            "Montenegro", "Madagaskar", "Marshalløerne", "Nordmakedonien", "Mali",
+            "Myanmar (Burma)", "Mongoliet", "SAR Macao", "Nordmarianerne", "Martinique",
+            "Mauretanien", "Montserrat", "Malta", "Mauritius", "Maldiverne", "Malawi", "Mexico",
+            "Malaysia", "Mozambique", "Namibia", "Ny Kaledonien", "Niger", "Norfolk Island",
+            "Nigeria", "Nicaragua", "Nederlandene", "Norge", "Nepal", "Nauru", "Niue",
+            "New Zealand", "Oman", "Panama", "Peru", "Fransk Polynesien", "Papua Ny Guinea",
+            "Filippinerne", "Pakistan", "Polen", "Saint Pierre og Miquelon", "Puerto Rico",
+            "De palæstinensiske områder", "Portugal", "Palau", "Paraguay", "Qatar", "Réunion",
+            "Rumænien", "Serbien", "Rusland", "Rwanda", "Saudi-Arabien", "Salomonøerne",
+            "Seychellerne", "Sudan", "Sverige", "Singapore", "St. Helena", "Slovenien",
+            "Svalbard og Jan Mayen", "Slovakiet", "Sierra Leone", "San Marino", "Senegal",
+            "Somalia", "Surinam", "Sydsudan", "São Tomé og Príncipe", "El Salvador", "Sint Maarten",
+            "Syrien", "Eswatini", "Turks- og Caicosøerne", "Tchad", "Togo", "Thailand",
+            "Tadsjikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunesien", "Tonga",
+            "Tyrkiet", "Trinidad og Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda",
+            "Uruguay", "Usbekistan", "Vatikanstaten", "Saint Vincent og Grenadinerne", "Venezuela",
+            "De Britiske Jomfruøer", "De Amerikanske Jomfruøer", "Vietnam", "Vanuatu",
+            "Wallis og Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte", "Sydafrika",
+            "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            234, 35, 12, 4, 51, 0, 8, 6, 1, 10, 5, 7, 9, 3, 245, 14, 11, 246, 13, 26, 18, 17, 20,
+            31, 30, 16, 32, 22, 23, 29, 25, 53, 28, 15, 24, 27, 19, 21, 38, 42, 54, 41, 190, 62, 43,
+            37, 34, 114, 39, 44, 45, 111, 46, 110, 47, 218, 228, 57, 48, 58, 55, 2, 59, 64, 60, 63,
+            200, 66, 70, 68, 67, 141, 74, 71, 75, 203, 81, 77, 72, 87, 78, 79, 83, 76, 88, 84, 244,
+            82, 86, 85, 89, 90, 187, 92, 117, 91, 231, 94, 101, 104, 103, 93, 56, 99, 100, 102, 105,
+            108, 106, 109, 107, 113, 115, 33, 116, 40, 179, 158, 209, 118, 36, 112, 119, 122, 180,
+            125, 201, 123, 120, 126, 127, 121, 124, 134, 143, 142, 145, 128, 135, 159, 132, 148,
+            144, 188, 160, 136, 137, 146, 133, 138, 131, 129, 140, 130, 147, 149, 163, 155, 161,
+            156, 154, 151, 162, 152, 150, 157, 153, 164, 167, 170, 73, 168, 69, 165, 171, 181, 173,
+            52, 172, 166, 169, 174, 175, 176, 192, 177, 178, 189, 183, 193, 204, 207, 195, 202, 198,
+            206, 197, 194, 185, 191, 199, 205, 210, 186, 61, 196, 211, 65, 225, 215, 219, 216, 212,
+            220, 217, 224, 223, 221, 227, 222, 226, 213, 214, 230, 229, 233, 235, 237, 182, 238, 50,
+            49, 239, 236, 240, 184, 80, 95, 232, 98, 96, 97, 241, 139, 208, 242, 243,
+        ],
+    },
+    LocaleNames {
+        locale: "de",
+        names: &[
+            "Vereinigte Staaten", "Kanada", "Ascension", "Andorra", "Vereinigte Arabische Emirate",
+            "Afghanistan", "Antigua und Barbuda", "Anguilla", "Albanien", "Armenien", "Angola",
+            "Antarktis", "Argentinien", "Amerikanisch-Samoa", "Österreich", "Australien", "Aruba",
+            "Ålandinseln", "Aserbaidschan", "Bosnien und Herzegowina", "Barbados", "Bangladesch",
+            "Belgien", "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "Bermuda",
+            "Brunei Darussalam", "Bolivien", "Karibische Niederlande", "Brasilien", "Bahamas",
+            "Bhutan", "Botsuana", "Belarus", "Belize", "Kokosinseln", "Kongo-Kinshasa",
+            "Zentralafrikanische Republik", "Kongo-Brazzaville", "Schweiz", "Côte d’Ivoire",
+            "Cookinseln", "Chile", "Kamerun", "China", "Kolumbien", "Costa Rica", "Kuba",
+            "Cabo Verde", "Curaçao", "Weihnachtsinsel", "Zypern", "Tschechien", "Deutschland",
+            "Dschibuti", "Dänemark", "Dominica", "Dominikanische Republik", "Algerien", "Ecuador",
+            "Estland", "Ägypten", "Eritrea", "Spanien", "Äthiopien", "Finnland", "Fidschi",
+            "Falklandinseln", "Mikronesien", "Färöer", "Frankreich", "Gabun",
+            "Vereinigtes Königreich", "Grenada", "Georgien", "Französisch-Guayana", "Guernsey",
+            "Ghana", "Gibraltar", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Äquatorialguinea",
+            "Griechenland", "Guatemala", "Guam", "Guinea-Bissau", "Guyana",
+            "Sonderverwaltungsregion Hongkong", "Honduras", "Kroatien", "Haiti", "Ungarn",
+            "Indonesien", "Irland", "Israel", "Isle of Man", "Indien",
+            "Britisches Territorium im Indischen Ozean", "Irak", "Iran", "Island", "Italien",
+            "Jersey", "Jamaika", "Jordanien", "Japan", "Kenia", "Kirgisistan", "Kambodscha",
+            "Kiribati", "Komoren", "St. Kitts und Nevis", "Nordkorea", "Südkorea", "Kuwait",
+            "Kaimaninseln", "Kasachstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein",
+            "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxemburg", "Lettland", "Libyen",
+            "Marokko", "Monaco", "Republik Moldau", "Montenegro", "Madagaskar", "Marshallinseln",
+            "Nordmazedonien", "Mali", "Myanmar", "Mongolei", "Sonderverwaltungsregion Macau",
+            "Nördliche Marianen", "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius",
+            "Malediven", "Malawi", "Mexiko", "Malaysia", "Mosambik", "Namibia", "Neukaledonien",
+            "Niger", "Norfolkinsel", "Nigeria", "Nicaragua", "Niederlande", "Norwegen", "Nepal",
+            "Nauru", "Niue", "Neuseeland", "Oman", "Panama", "Peru", "Französisch-Polynesien",
+            "Papua-Neuguinea", "Philippinen", "Pakistan", "Polen", "St. Pierre und Miquelon",
+            "Puerto Rico", "Palästinensische Autonomiegebiete", "Portugal", "Palau", "Paraguay",
+            "Katar", "Réunion", "Rumänien", "Serbien", "Russland", "Ruanda", "Saudi-Arabien",
+            "Salomonen", "Seychellen", "Sudan", "Schweden", "Singapur", "St. Helena", "Slowenien",
+            "Spitzbergen und Jan Mayen", "Slowakei", "Sierra Leone", "San Marino", "Senegal",
+            "Somalia", "Suriname", "Südsudan", "São Tomé und Príncipe", "El Salvador",
+            "Sint Maarten", "Syrien", "Eswatini", "Turks- und Caicosinseln", "Tschad", "Togo",
+            "Thailand", "Tadschikistan", "Tokelau", "Timor-Leste", "Turkmenistan", "Tunesien",
+            "Tonga", "Türkei", "Trinidad und Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukraine",
+            "Uganda", "Uruguay", "Usbekistan", "Vatikanstadt", "St. Vincent und die Grenadinen",
+            "Venezuela", "Britische Jungferninseln", "Amerikanische Jungferninseln", "Vietnam",
+            "Vanuatu", "Wallis und Futuna", "Samoa", "", "", "", "", "", "", "Jemen", "Mayotte",
+            "Südafrika", "Sambia", "Simbabwe",
+        ],
+        sort_keys: &[
+            240, 103, 16, 7, 239, 0, 11, 9, 3, 14, 8, 10, 13, 5, 163, 19, 15, 2, 17, 31, 23, 22, 25,
+            38, 37, 21, 39, 27, 28, 36, 30, 104, 33, 20, 29, 32, 24, 26, 110, 114, 245, 113, 187,
+            45, 43, 41, 102, 42, 111, 44, 116, 40, 46, 244, 246, 224, 48, 51, 47, 49, 50, 4, 52, 55,
+            1, 54, 200, 18, 60, 59, 57, 140, 58, 61, 64, 241, 70, 66, 62, 76, 67, 68, 72, 65, 77,
+            73, 12, 71, 75, 74, 78, 79, 198, 81, 115, 80, 232, 83, 90, 93, 92, 82, 35, 88, 89, 91,
+            94, 98, 95, 99, 96, 107, 108, 101, 109, 112, 204, 157, 210, 117, 100, 105, 118, 121,
+            205, 124, 202, 122, 119, 125, 126, 120, 123, 133, 141, 175, 143, 127, 134, 159, 131,
+            146, 142, 199, 158, 135, 136, 144, 132, 137, 130, 128, 139, 129, 145, 147, 150, 154,
+            160, 155, 152, 153, 161, 149, 148, 156, 151, 162, 167, 170, 63, 168, 171, 164, 172, 206,
+            174, 165, 173, 166, 169, 106, 176, 178, 189, 179, 177, 185, 180, 190, 209, 186, 193,
+            203, 196, 201, 195, 191, 183, 188, 197, 212, 211, 184, 53, 194, 213, 56, 228, 223, 219,
+            217, 214, 220, 218, 227, 225, 221, 226, 222, 229, 215, 216, 231, 230, 234, 235, 237,
+            207, 238, 34, 6, 242, 236, 243, 182, 69, 84, 233, 87, 85, 86, 97, 138, 208, 181, 192,
+        ],
+    },
+    LocaleNames {
+        locale: "el",
+        names: &[
+            "Ηνωμένες Πολιτείες", "Καναδάς", "Νήσος Ασενσιόν", "Ανδόρα", "Ηνωμένα Αραβικά Εμιράτα",
+            "Αφγανιστάν", "Αντίγκουα και Μπαρμπούντα", "Ανγκουίλα", "Αλβανία", "Αρμενία", "Αγκόλα",
+            "Ανταρκτική", "Αργεντινή", "Αμερικανική Σαμόα", "Αυστρία", "Αυστραλία", "Αρούμπα",
+            "Νήσοι Όλαντ", "Αζερμπαϊτζάν", "Βοσνία - Ερζεγοβίνη", "Μπαρμπέιντος", "Μπανγκλαντές",
+            "Βέλγιο", "Μπουρκίνα Φάσο", "Βουλγαρία", "Μπαχρέιν", "Μπουρούντι", "Μπενίν",
+            "Βερμούδες", "Μπρουνέι", "Βολιβία", "Ολλανδία Καραϊβικής", "Βραζιλία", "Μπαχάμες",
+            "Μπουτάν", "Μποτσουάνα", "Λευκορωσία", "Μπελίζ", "Νήσοι Κόκος (Κίλινγκ)",
+            "Κονγκό - Κινσάσα", "Κεντροαφρικανική Δημοκρατία", "Κονγκό - Μπραζαβίλ", "Ελβετία",
+            "Ακτή Ελεφαντοστού", "Νήσοι Κουκ", "Χιλή", "Καμερούν", "Κίνα", "Κολομβία", "Κόστα Ρίκα",
+            "Κούβα", "Πράσινο Ακρωτήριο", "Κουρασάο", "Νήσος των Χριστουγέννων", "Κύπρος", "Τσεχία",
+            "Γερμανία", "Τζιμπουτί", "Δανία", "Ντομίνικα", "Δομινικανή Δημοκρατία", "Αλγερία",
+            "Ισημερινός", "Εσθονία", "Αίγυπτος", "Ερυθραία", "Ισπανία", "Αιθιοπία", "Φινλανδία",
+            "Φίτζι", "Νήσοι Φόκλαντ", "Μικρονησία", "Νήσοι Φερόες", "Γαλλία", "Γκαμπόν",
+            "Ηνωμένο Βασίλειο", "Γρενάδα", "Γεωργία", "Γαλλική Γουιάνα", "Γκέρνζι", "Γκάνα",
+            "Γιβραλτάρ", "Γροιλανδία", "Γκάμπια", "Γουινέα", "Γουαδελούπη", "Ισημερινή Γουινέα",
+            "Ελλάδα", "Γουατεμάλα", "Γκουάμ", "Γουινέα Μπισάου", "Γουιάνα", "Χονγκ Κονγκ ΕΔΠ Κίνας",
+            "Ονδούρα", "Κροατία", "Αϊτή", "Ουγγαρία", "Ινδονησία", "Ιρλανδία", "Ισραήλ",
+            "Νήσος του Μαν", "Ινδία", "Βρετανικά Εδάφη Ινδικού Ωκεανού", "Ιράκ", "Ιράν", "Ισλανδία",
+            "Ιταλία", "Τζέρζι", "Τζαμάικα", "Ιορδανία", "Ιαπωνία", "Κένυα", "Κιργιστάν", "Καμπότζη",
+            "Κιριμπάτι", "Κομόρες", "Σεν Κιτς και Νέβις", "Βόρεια Κορέα", "Νότια Κορέα", "Κουβέιτ",
+            "Νήσοι Κέιμαν", "Καζακστάν", "Λάος", "Λίβανος", "Αγία Λουκία", "Λιχτενστάιν",
+            "Σρι Λάνκα", "Λιβερία", "Λεσότο", "Λιθουανία", "Λουξεμβούργο", "Λετονία", "Λιβύη",
+            "Μαρόκο", "Μονακό", "Μολδαβία", "Μαυροβούνιο", "Μαδαγασκάρη", "Νήσοι Μάρσαλ",
+            "Βόρεια Μακεδονία", "Μάλι", "Μιανμάρ (Βιρμανία)", "Μογγολία", "Μακάο ΕΔΠ Κίνας",
+            "Νήσοι Βόρειες Μαριάνες", "Μαρτινίκα", "Μαυριτανία", "Μονσεράτ", "Μάλτα", "Μαυρίκιος",
+            "Μαλδίβες", "Μαλάουι", "Μεξικό", "Μαλαισία", "Μοζαμβίκη", "Ναμίμπια", "Νέα Καληδονία",
+            "Νίγηρας", "Νήσος Νόρφολκ", "Νιγηρία", "Νικαράγουα", "Κάτω Χώρες", "Νορβηγία", "Νεπάλ",
+            "Ναουρού", "Νιούε", "Νέα Ζηλανδία", "Ομάν", "Παναμάς", "Περού", "Γαλλική Πολυνησία",
+            "Παπούα Νέα Γουινέα", "Φιλιππίνες", "Πακιστάν", "Πολωνία", "Σεν Πιερ και Μικελόν",
+            "Πουέρτο Ρίκο", "Παλαιστινιακά Εδάφη", "Πορτογαλία", "Παλάου", "Παραγουάη", "Κατάρ",
+            "Ρεϊνιόν", "Ρουμανία", "Σερβία", "Ρωσία", "Ρουάντα", "Σαουδική Αραβία",
+            "Νήσοι Σολομώντος", "Σεϋχέλλες", "Σουδάν", "Σουηδία", "Σιγκαπούρη", "Αγία Ελένη",
+            "Σλοβενία", "Σβάλμπαρντ και Γιαν Μαγιέν", "Σλοβακία", "Σιέρα Λεόνε", "Άγιος Μαρίνος",
+            "Σενεγάλη", "Σομαλία", "Σουρινάμ", "Νότιο Σουδάν", "Σάο Τομέ και Πρίνσιπε",
+            "Ελ Σαλβαδόρ", "Άγιος Μαρτίνος (Ολλανδικό τμήμα)", "Συρία", "Εσουατίνι",
+            "Νήσοι Τερκς και Κάικος", "Τσαντ", "Τόγκο", "Ταϊλάνδη", "Τατζικιστάν", "Τοκελάου",
+            "Τιμόρ-Λέστε", "Τουρκμενιστάν", "Τυνησία", "Τόνγκα", "Τουρκία",
+            "Τρινιντάντ και Τομπάγκο", "Τουβαλού", "Ταϊβάν", "Τανζανία", "Ουκρανία", "Ουγκάντα",
+            "Ουρουγουάη", "Ουζμπεκιστάν", "Βατικανό", "Άγιος Βικέντιος και Γρεναδίνες",
+            "Βενεζουέλα", "Βρετανικές Παρθένες Νήσοι", "Αμερικανικές Παρθένες Νήσοι", "Βιετνάμ",
+            "Βανουάτου", "Γουάλις και Φουτούνα", "Σαμόα", "", "", "", "", "", "", "Υεμένη",
+            "Μαγιότ", "Νότια Αφρική", "Ζάμπια", "Ζιμπάμπουε",
+        ],
+        sort_keys: &[
+            69, 87, 162, 16, 68, 24, 18, 15, 11, 20, 5, 17, 19, 14, 23, 22, 21, 157, 6, 34, 137,
+            136, 27, 143, 35, 139, 144, 141, 29, 146, 31, 175, 36, 138, 145, 142, 108, 140, 154, 97,
+            90, 98, 61, 10, 155, 239, 85, 92, 95, 99, 100, 193, 102, 165, 104, 233, 42, 223, 58,
+            174, 59, 12, 79, 64, 7, 63, 81, 8, 237, 238, 161, 130, 160, 39, 46, 70, 56, 43, 40, 48,
+            47, 44, 57, 45, 54, 50, 78, 62, 52, 49, 55, 53, 240, 177, 103, 9, 178, 73, 77, 82, 164,
+            72, 37, 75, 76, 80, 83, 222, 221, 74, 71, 91, 93, 86, 94, 96, 202, 32, 172, 101, 153,
+            84, 105, 109, 1, 113, 215, 110, 106, 112, 114, 107, 111, 123, 134, 133, 127, 116, 156,
+            33, 121, 129, 131, 117, 152, 124, 126, 135, 122, 125, 120, 119, 128, 118, 132, 147, 150,
+            166, 163, 167, 168, 89, 170, 151, 148, 169, 149, 176, 186, 189, 41, 187, 236, 183, 190,
+            203, 192, 184, 191, 185, 188, 88, 194, 196, 205, 197, 195, 200, 158, 206, 212, 213, 207,
+            0, 210, 201, 209, 208, 3, 204, 211, 214, 173, 199, 60, 4, 216, 65, 159, 232, 225, 218,
+            220, 226, 224, 230, 234, 227, 229, 231, 228, 217, 219, 181, 179, 182, 180, 26, 2, 28,
+            38, 13, 30, 25, 51, 198, 241, 242, 246, 245, 243, 244, 235, 115, 171, 66, 67,
+        ],
+    },
+    LocaleNames {
+        locale: "en",
+        names: &[],
+        sort_keys: &[
+            235, 39, 13, 5, 233, 0, 9, 7, 2, 11, 6, 8, 10, 4, 15, 14, 12, 1, 16, 28, 20, 19, 22, 35,
+            34, 18, 36, 24, 25, 33, 27, 41, 30, 17, 26, 29, 21, 23, 48, 59, 43, 181, 214, 53, 51,
+            45, 38, 46, 49, 52, 55, 40, 56, 47, 57, 58, 82, 61, 60, 62, 63, 3, 64, 69, 65, 68, 208,
+            71, 75, 74, 72, 144, 73, 76, 79, 234, 88, 81, 77, 92, 83, 84, 87, 80, 93, 89, 67, 86,
+            91, 90, 94, 95, 98, 97, 54, 96, 99, 102, 109, 111, 110, 101, 31, 108, 107, 100, 112,
+            115, 113, 116, 114, 118, 121, 37, 119, 50, 187, 164, 206, 120, 42, 117, 122, 124, 188,
+            128, 209, 126, 125, 129, 130, 123, 127, 150, 146, 145, 148, 132, 138, 165, 136, 152,
+            147, 131, 166, 139, 140, 149, 137, 141, 135, 133, 143, 134, 151, 153, 157, 160, 163,
+            161, 159, 156, 167, 155, 154, 162, 158, 168, 172, 175, 78, 173, 176, 169, 177, 189, 179,
+            171, 178, 170, 174, 180, 182, 183, 196, 184, 185, 194, 203, 197, 210, 213, 199, 186,
+            202, 212, 201, 198, 192, 195, 204, 211, 207, 193, 66, 200, 215, 70, 228, 44, 221, 219,
+            217, 222, 220, 227, 225, 223, 226, 224, 229, 216, 218, 232, 231, 237, 238, 240, 190,
+            241, 32, 230, 242, 239, 243, 191, 85, 103, 236, 106, 104, 105, 244, 142, 205, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "es",
+        names: &[
+            "Estados Unidos", "Canadá", "Isla de la Ascensión", "Andorra", "Emiratos Árabes Unidos",
+            "Afganistán", "Antigua y Barbuda", "Anguila", "Albania", "Armenia", "Angola",
+            "Antártida", "Argentina", "Samoa Americana", "Austria", "Australia", "Aruba",
+            "Islas Aland", "Azerbaiyán", "Bosnia y Herzegovina", "Barbados", "Bangladés", "Bélgica",
+            "Burkina Faso", "Bulgaria", "Baréin", "Burundi", "Benín", "Bermudas", "Brunéi",
+            "Bolivia", "Caribe neerlandés", "Brasil", "Bahamas", "Bután", "Botsuana", "Bielorrusia",
+            "Belice", "Islas Cocos", "República Democrática del Congo", "República Centroafricana",
+            "Congo", "Suiza", "Côte d’Ivoire", "Islas Cook", "Chile", "Camerún", "China",
+            "Colombia", "Costa Rica", "Cuba", "Cabo Verde", "Curazao", "Isla de Navidad", "Chipre",
+            "Chequia", "Alemania", "Yibuti", "Dinamarca", "Dominica", "República Dominicana",
+            "Argelia", "Ecuador", "Estonia", "Egipto", "Eritrea", "España", "Etiopía", "Finlandia",
+            "Fiyi", "Islas Malvinas", "Micronesia", "Islas Feroe", "Francia", "Gabón",
+            "Reino Unido", "Granada", "Georgia", "Guayana Francesa", "Guernesey", "Ghana",
+            "Gibraltar", "Groenlandia", "Gambia", "Guinea", "Guadalupe", "Guinea Ecuatorial",
+            "Grecia", "Guatemala", "Guam", "Guinea-Bisáu", "Guyana", "RAE de Hong Kong (China)",
+            "Honduras", "Croacia", "Haití", "Hungría", "Indonesia", "Irlanda", "Israel",
+            "Isla de Man", "India", "Territorio Británico del Océano Índico", "Irak", "Irán",
+            "Islandia", "Italia", "Jersey", "Jamaica", "Jordania", "Japón", "Kenia", "Kirguistán",
+            "Camboya", "Kiribati", "Comoras", "San Cristóbal y Nieves", "Corea del Norte",
+            "Corea del Sur", "Kuwait", "Islas Caimán", "Kazajistán", "Laos", "Líbano",
+            "Santa Lucía", "Liechtenstein", "Sri Lanka", "Liberia", "Lesoto", "Lituania",
+            "Luxemburgo", "Letonia", "Libia", "Marruecos", "Mónaco", "Moldavia", "Montenegro",
+            "Madagascar", "Islas Marshall", "Macedonia del Norte", "Mali", "Myanmar (Birmania)",
+            "Mongolia", "RAE de Macao (China)", "Islas Marianas del Norte", "Martinica",
+            "Mauritania", "Montserrat", "Malta", "Mauricio", "Maldivas", "Malaui", "México",
+            "Malasia", "Mozambique", "Namibia", "Nueva Caledonia", "Níger", "Isla Norfolk",
+            "Nigeria", "Nicaragua", "Países Bajos", "Noruega", "Nepal", "Nauru", "Niue",
+            "Nueva Zelanda", "Omán", "Panamá", "Perú", "Polinesia Francesa", "Papúa Nueva Guinea",
+            "Filipinas", "Pakistán", "Polonia", "San Pedro y Miquelón", "Puerto Rico",
+            "Territorios Palestinos", "Portugal", "Palaos", "Paraguay", "Catar", "Reunión",
+            "Rumanía", "Serbia", "Rusia", "Ruanda", "Arabia Saudí", "Islas Salomón", "Seychelles",
+            "Sudán", "Suecia", "Singapur", "Santa Elena", "Eslovenia", "Svalbard y Jan Mayen",
+            "Eslovaquia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam",
+            "Sudán del Sur", "Santo Tomé y Príncipe", "El Salvador", "Sint Maarten", "Siria",
+            "Esuatini", "Islas Turcas y Caicos", "Chad", "Togo", "Tailandia", "Tayikistán",
+            "Tokelau", "Timor-Leste", "Turkmenistán", "Túnez", "Tonga", "Turquía",
+            "Trinidad y Tobago", "Tuvalu", "Taiwán", "Tanzania", "Ucrania", "Uganda", "Uruguay",
+            "Uzbekistán", "Ciudad del Vaticano", "San Vicente y las Granadinas", "Venezuela",
+            "Islas Vírgenes Británicas", "Islas Vírgenes de EE. UU.", "Vietnam", "Vanuatu",
+            "Wallis y Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte", "Sudáfrica",
+            "Zambia", "Zimbabue",
+        ],
+        sort_keys: &[
+            66, 37, 104, 3, 61, 0, 7, 5, 1, 11, 4, 6, 10, 195, 14, 13, 12, 109, 15, 26, 18, 17, 20,
+            31, 30, 19, 32, 22, 23, 29, 25, 38, 28, 16, 33, 27, 24, 21, 111, 188, 187, 48, 216, 52,
+            112, 42, 36, 43, 46, 51, 54, 34, 55, 106, 44, 41, 2, 244, 56, 57, 189, 9, 58, 67, 59,
+            62, 65, 69, 71, 72, 114, 154, 113, 73, 74, 186, 80, 76, 86, 87, 77, 78, 82, 75, 88, 83,
+            89, 81, 85, 84, 90, 91, 184, 93, 53, 92, 94, 96, 103, 121, 105, 95, 223, 101, 102, 108,
+            122, 125, 123, 126, 124, 128, 129, 35, 130, 47, 196, 49, 50, 131, 110, 127, 132, 135,
+            201, 138, 211, 136, 133, 139, 140, 134, 137, 148, 156, 155, 158, 142, 116, 141, 146,
+            161, 157, 185, 115, 149, 151, 159, 147, 150, 145, 144, 153, 143, 160, 162, 170, 166,
+            107, 167, 165, 173, 169, 164, 163, 168, 171, 172, 176, 179, 180, 177, 70, 174, 181, 198,
+            183, 224, 182, 175, 178, 39, 190, 192, 204, 193, 191, 8, 117, 205, 213, 215, 207, 200,
+            64, 218, 63, 206, 197, 203, 210, 217, 214, 202, 60, 208, 209, 68, 118, 40, 226, 219,
+            222, 227, 225, 231, 230, 228, 232, 229, 233, 220, 221, 234, 235, 237, 238, 45, 199, 240,
+            119, 120, 241, 239, 242, 194, 79, 97, 236, 100, 98, 99, 243, 152, 212, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "es-419",
+        names: &[
+            "Estados Unidos", "Canadá", "Isla Ascensión", "Andorra", "Emiratos Árabes Unidos",
+            "Afganistán", "Antigua y Barbuda", "Anguila", "Albania", "Armenia", "Angola",
+            "Antártida", "Argentina", "Samoa Americana", "Austria", "Australia", "Aruba",
+            "Islas Åland", "Azerbaiyán", "Bosnia-Herzegovina", "Barbados", "Bangladés", "Bélgica",
+            "Burkina Faso", "Bulgaria", "Baréin", "Burundi", "Benín", "Bermudas", "Brunéi",
+            "Bolivia", "Caribe neerlandés", "Brasil", "Bahamas", "Bután", "Botsuana", "Bielorrusia",
+            "Belice", "Islas Cocos", "República Democrática del Congo", "República Centroafricana",
+            "República del Congo", "Suiza", "Costa de Marfil", "Islas Cook", "Chile", "Camerún",
+            "China", "Colombia", "Costa Rica", "Cuba", "Cabo Verde", "Curazao", "Isla de Navidad",
+            "Chipre", "Chequia", "Alemania", "Yibuti", "Dinamarca", "Dominica",
+            "República Dominicana", "Argelia", "Ecuador", "Estonia", "Egipto", "Eritrea", "España",
+            "Etiopía", "Finlandia", "Fiyi", "Islas Malvinas", "Micronesia", "Islas Feroe",
+            "Francia", "Gabón", "Reino Unido", "Granada", "Georgia", "Guayana Francesa",
+            "Guernesey", "Ghana", "Gibraltar", "Groenlandia", "Gambia", "Guinea", "Guadalupe",
+            "Guinea Ecuatorial", "Grecia", "Guatemala", "Guam", "Guinea-Bisáu", "Guyana",
+            "RAE de Hong Kong (China)", "Honduras", "Croacia", "Haití", "Hungría", "Indonesia",
+            "Irlanda", "Israel", "Isla de Man", "India", "Territorio Británico del Océano Índico",
+            "Irak", "Irán", "Islandia", "Italia", "Jersey", "Jamaica", "Jordania", "Japón", "Kenia",
+            "Kirguistán", "Camboya", "Kiribati", "Comoras", "San Cristóbal y Nieves",
+            "Corea del Norte", "Corea del Sur", "Kuwait", "Islas Caimán", "Kazajistán", "Laos",
+            "Líbano", "Santa Lucía", "Liechtenstein", "Sri Lanka", "Liberia", "Lesoto", "Lituania",
+            "Luxemburgo", "Letonia", "Libia", "Marruecos", "Mónaco", "Moldavia", "Montenegro",
+            "Madagascar", "Islas Marshall", "Macedonia del Norte", "Mali", "Myanmar (Birmania)",
+            "Mongolia", "RAE de Macao (China)", "Islas Marianas del Norte", "Martinica",
+            "Mauritania", "Montserrat", "Malta", "Mauricio", "Maldivas", "Malaui", "México",
+            "Malasia", "Mozambique", "Namibia", "Nueva Caledonia", "Níger", "Isla Norfolk",
+            "Nigeria", "Nicaragua", "Países Bajos", "Noruega", "Nepal", "Nauru", "Niue",
+            "Nueva Zelanda", "Omán", "Panamá", "Perú", "Polinesia Francesa", "Papúa Nueva Guinea",
+            "Filipinas", "Pakistán", "Polonia", "San Pedro y Miquelón", "Puerto Rico",
+            "Territorios Palestinos", "Portugal", "Palaos", "Paraguay", "Catar", "Reunión",
+            "Rumania", "Serbia", "Rusia", "Ruanda", "Arabia Saudita", "Islas Salomón", "Seychelles",
+            "Sudán", "Suecia", "Singapur", "Santa Elena", "Eslovenia", "Svalbard y Jan Mayen",
+            "Eslovaquia", "Sierra Leona", "San Marino", "Senegal", "Somalia", "Surinam",
+            "Sudán del Sur", "Santo Tomé y Príncipe", "El Salvador", "Sint Maarten", "Siria",
+            "Esuatini", "Islas Turcas y Caicos", "Chad", "Togo", "Tailandia", "Tayikistán",
+            "Tokelau", "Timor Oriental", "Turkmenistán", "Túnez", "Tonga", "Turquía",
+            "Trinidad y Tobago", "Tuvalu", "Taiwán", "Tanzania", "Ucrania", "Uganda", "Uruguay",
+            "Uzbekistán", "Ciudad del Vaticano", "San Vicente y las Granadinas", "Venezuela",
+            "Islas Vírgenes Británicas", "Islas Vírgenes de EE. UU.", "Vietnam", "Vanuatu",
+            "Wallis y Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte", "Sudáfrica",
+            "Zambia", "Zimbabue",
+        ],
+        sort_keys: &[
+            65, 37, 103, 3, 60, 0, 7, 5, 1, 11, 4, 6, 10, 195, 14, 13, 12, 108, 15, 26, 18, 17, 20,
+            31, 30, 19, 32, 22, 23, 29, 25, 38, 28, 16, 33, 27, 24, 21, 110, 188, 186, 187, 216, 50,
+            111, 42, 36, 43, 46, 51, 53, 34, 54, 105, 44, 41, 2, 244, 55, 56, 189, 9, 57, 66, 58,
+            61, 64, 68, 70, 71, 113, 153, 112, 72, 73, 185, 79, 75, 85, 86, 76, 77, 81, 74, 87, 82,
+            88, 80, 84, 83, 89, 90, 183, 92, 52, 91, 93, 95, 102, 120, 104, 94, 223, 100, 101, 107,
+            121, 124, 122, 125, 123, 127, 128, 35, 129, 47, 196, 48, 49, 130, 109, 126, 131, 134,
+            201, 137, 211, 135, 132, 138, 139, 133, 136, 147, 155, 154, 157, 141, 115, 140, 145,
+            160, 156, 184, 114, 148, 150, 158, 146, 149, 144, 143, 152, 142, 159, 161, 169, 165,
+            106, 166, 164, 172, 168, 163, 162, 167, 170, 171, 175, 178, 179, 176, 69, 173, 180, 198,
+            182, 224, 181, 174, 177, 39, 190, 192, 204, 193, 191, 8, 116, 205, 213, 215, 207, 200,
+            63, 218, 62, 206, 197, 203, 210, 217, 214, 202, 59, 208, 209, 67, 117, 40, 226, 219,
+            222, 227, 225, 231, 230, 228, 232, 229, 233, 220, 221, 234, 235, 237, 238, 45, 199, 240,
+            118, 119, 241, 239, 242, 194, 78, 96, 236, 99, 97, 98, 243, 151, 212, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "fa",
+        names: &[
+            "ایالات متحده", "کانادا", "جزایر آسنسیون", "آندورا", "امارات متحدهٔ عربی", "افغانستان",
+            "آنتیگوا و باربودا", "آنگویلا", "آلبانی", "ارمنستان", "آنگولا", "جنوبگان", "آرژانتین",
+            "ساموآی امریکا", "اتریش", "استرالیا", "آروبا", "جزایر آلاند", "جمهوری آذربایجان",
+            "بوسنی و هرزگوین", "باربادوس", "بنگلادش", "بلژیک", "بورکینافاسو", "بلغارستان", "بحرین",
+            "بوروندی", "بنین", "برمودا", "برونئی", "بولیوی", "جزایر کارائیب هلند", "برزیل",
+            "باهاما", "بوتان", "بوتسوانا", "بلاروس", "بلیز", "جزایر کوکوس", "کنگو - کینشاسا",
+            "جمهوری افریقای مرکزی", "کنگو - برازویل", "سوئیس", "ساحل عاج", "جزایر کوک", "شیلی",
+            "کامرون", "چین", "کلمبیا", "کاستاریکا", "کوبا", "کیپ‌ورد", "کوراسائو", "جزیرهٔ کریسمس",
+            "قبرس", "چک", "آلمان", "جیبوتی", "دانمارک", "دومینیکا", "جمهوری دومینیکن", "الجزایر",
+            "اکوادور", "استونی", "مصر", "اریتره", "اسپانیا", "اتیوپی", "فنلاند", "فیجی",
+            "جزایر فالکلند", "میکرونزی", "جزایر فارو", "فرانسه", "گابن", "بریتانیا", "گرنادا",
+            "گرجستان", "گویان فرانسه", "گرنزی", "غنا", "جبل‌الطارق", "گرینلند", "گامبیا", "گینه",
+            "گوادلوپ", "گینهٔ استوایی", "یونان", "گواتمالا", "گوام", "گینهٔ بیسائو", "گویان",
+            "هنگ‌کنگ، منطقهٔ ویژهٔ اداری چین", "هندوراس", "کرواسی", "هائیتی", "مجارستان", "اندونزی",
+            "ایرلند", "اسرائیل", "جزیرهٔ من", "هند", "قلمرو بریتانیا در اقیانوس هند", "عراق",
+            "ایران", "ایسلند", "ایتالیا", "جرزی", "جامائیکا", "اردن", "ژاپن", "کنیا", "قرقیزستان",
+            "کامبوج", "کیریباتی", "کومور", "سنت کیتس و نویس", "کرهٔ شمالی", "کرهٔ جنوبی", "کویت",
+            "جزایر کِیمن", "قزاقستان", "لائوس", "لبنان", "سنت لوسیا", "لیختن‌اشتاین", "سری‌لانکا",
+            "لیبریا", "لسوتو", "لیتوانی", "لوکزامبورگ", "لتونی", "لیبی", "مراکش", "موناکو",
+            "مولداوی", "مونته‌نگرو", "ماداگاسکار", "جزایر مارشال", "مقدونیهٔ شمالی", "مالی",
+            "میانمار (برمه)", "مغولستان", "ماکائو، منطقهٔ ویژهٔ اداری چین", "جزایر ماریانای شمالی",
+            "مارتینیک", "موریتانی", "مونت‌سرات", "مالت", "موریس", "مالدیو", "مالاوی", "مکزیک",
+            "مالزی", "موزامبیک", "نامیبیا", "کالدونیای جدید", "نیجر", "جزیرهٔ نورفولک", "نیجریه",
+            "نیکاراگوئه", "هلند", "نروژ", "نپال", "نائورو", "نیوئه", "نیوزیلند", "عمان", "پاناما",
+            "پرو", "پلی‌نزی فرانسه", "پاپوا گینهٔ نو", "فیلیپین", "پاکستان", "لهستان",
+            "سن پیر و میکلن", "پورتوریکو", "سرزمین‌های فلسطینی", "پرتغال", "پالائو", "پاراگوئه",
+            "قطر", "رئونیون", "رومانی", "صربستان", "روسیه", "رواندا", "عربستان سعودی",
+            "جزایر سلیمان", "سیشل", "سودان", "سوئد", "سنگاپور", "سنت هلن", "اسلوونی",
+            "سوالبارد و یان ماین", "اسلواکی", "سیرالئون", "سان‌مارینو", "سنگال", "سومالی",
+            "سورینام", "سودان جنوبی", "سائوتومه و پرینسیپ", "السالوادور", "سنت مارتن", "سوریه",
+            "اسواتینی", "جزایر تورکس و کایکوس", "چاد", "توگو", "تایلند", "تاجیکستان", "توکلائو",
+            "تیمور-لسته", "ترکمنستان", "تونس", "تونگا", "ترکیه", "ترینیداد و توباگو", "تووالو",
+            "تایوان", "تانزانیا", "اوکراین", "اوگاندا", "اروگوئه", "ازبکستان", "واتیکان",
+            "سنت وینسنت و گرنادین", "ونزوئلا", "جزایر ویرجین بریتانیا", "جزایر ویرجین ایالات متحده",
+            "ویتنام", "وانواتو", "والیس و فوتونا", "ساموآ", "", "", "", "", "", "", "یمن", "مایوت",
+            "افریقای جنوبی", "زامبیا", "زیمبابوه",
+        ],
+        sort_keys: &[
+            31, 158, 80, 5, 27, 23, 4, 7, 2, 11, 6, 100, 0, 117, 8, 16, 1, 81, 97, 53, 36, 47, 44,
+            51, 45, 38, 52, 48, 40, 41, 54, 86, 39, 37, 49, 50, 43, 46, 88, 164, 98, 163, 131, 115,
+            87, 139, 157, 104, 162, 154, 166, 170, 167, 94, 149, 103, 3, 101, 105, 106, 99, 25, 24,
+            17, 207, 13, 15, 9, 146, 147, 85, 219, 84, 145, 172, 42, 175, 174, 182, 176, 144, 78,
+            177, 173, 183, 179, 184, 240, 178, 180, 185, 181, 238, 237, 159, 234, 205, 28, 34, 18,
+            95, 236, 153, 141, 33, 35, 32, 79, 77, 10, 113, 165, 150, 156, 171, 168, 122, 161, 160,
+            169, 89, 151, 186, 187, 123, 195, 120, 192, 189, 194, 190, 188, 193, 206, 215, 214, 217,
+            196, 90, 209, 203, 218, 208, 198, 91, 197, 211, 216, 200, 212, 201, 199, 210, 202, 213,
+            221, 155, 224, 96, 225, 226, 235, 223, 222, 220, 227, 228, 143, 59, 61, 62, 55, 148, 57,
+            191, 121, 63, 119, 60, 58, 56, 152, 107, 110, 140, 109, 108, 142, 83, 138, 132, 130,
+            127, 126, 20, 129, 19, 137, 118, 128, 136, 134, 133, 114, 26, 124, 135, 21, 82, 102, 72,
+            66, 64, 71, 76, 68, 73, 74, 69, 70, 75, 67, 65, 29, 30, 12, 14, 229, 125, 232, 93, 92,
+            233, 231, 230, 116, 241, 242, 246, 245, 243, 244, 239, 204, 22, 111, 112,
+        ],
+    },
+    LocaleNames {
+        locale: "fi",
+        names: &[
+            "Yhdysvallat", "Kanada", "Ascension-saari", "Andorra", "Arabiemiirikunnat",
+            "Afganistan", "Antigua ja Barbuda", "Anguilla", "Albania", "Armenia", "Angola",
+            "Antarktis", "Argentiina", "Amerikan Samoa", "Itävalta", "Australia", "Aruba",
+            "Ahvenanmaa", "Azerbaidžan", "Bosnia ja Hertsegovina", "Barbados", "Bangladesh",
+            "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Bermuda",
+            "Brunei", "Bolivia", "Karibian Alankomaat", "Brasilia", "Bahama", "Bhutan", "Botswana",
+            "Valko-Venäjä", "Belize", "Kookossaaret (Keelingsaaret)",
+            "Kongon demokraattinen tasavalta", "Keski-Afrikan tasavalta", "Kongon tasavalta",
+            "Sveitsi", "Norsunluurannikko", "Cookinsaaret", "Chile", "Kamerun", "Kiina", "Kolumbia",
+            "Costa Rica", "Kuuba", "Kap Verde", "Curaçao", "Joulusaari", "Kypros", "Tšekki",
+            "Saksa", "Djibouti", "Tanska", "Dominica", "Dominikaaninen tasavalta", "Algeria",
+            "Ecuador", "Viro", "Egypti", "Eritrea", "Espanja", "Etiopia", "Suomi", "Fidži",
+            "Falklandinsaaret", "Mikronesia", "Färsaaret", "Ranska", "Gabon", "Iso-Britannia",
+            "Grenada", "Georgia", "Ranskan Guayana", "Guernsey", "Ghana", "Gibraltar", "Grönlanti",
+            "Gambia", "Guinea", "Guadeloupe", "Päiväntasaajan Guinea", "Kreikka", "Guatemala",
+            "Guam", "Guinea-Bissau", "Guyana", "Hongkong – Kiinan erityishallintoalue", "Honduras",
+            "Kroatia", "Haiti", "Unkari", "Indonesia", "Irlanti", "Israel", "Mansaari", "Intia",
+            "Brittiläinen Intian valtameren alue", "Irak", "Iran", "Islanti", "Italia", "Jersey",
+            "Jamaika", "Jordania", "Japani", "Kenia", "Kirgisia", "Kambodža", "Kiribati", "Komorit",
+            "Saint Kitts ja Nevis", "Pohjois-Korea", "Etelä-Korea", "Kuwait", "Caymansaaret",
+            "Kazakstan", "Laos", "Libanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Liettua", "Luxemburg", "Latvia", "Libya", "Marokko", "Monaco", "Moldova",
+            "Montenegro", "Madagaskar", "Marshallinsaaret", "Pohjois-Makedonia", "Mali",
+            "Myanmar (Burma)", "Mongolia", "Macao – Kiinan erityishallintoalue",
+            "Pohjois-Mariaanit", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius",
+            "Malediivit", "Malawi", "Meksiko", "Malesia", "Mosambik", "Namibia", "Uusi-Kaledonia",
+            "Niger", "Norfolkinsaari", "Nigeria", "Nicaragua", "Alankomaat", "Norja", "Nepal",
+            "Nauru", "Niue", "Uusi-Seelanti", "Oman", "Panama", "Peru", "Ranskan Polynesia",
+            "Papua-Uusi-Guinea", "Filippiinit", "Pakistan", "Puola", "Saint-Pierre ja Miquelon",
+            "Puerto Rico", "Palestiinalaisalue", "Portugali", "Palau", "Paraguay", "Qatar",
+            "Réunion", "Romania", "Serbia", "Venäjä", "Ruanda", "Saudi-Arabia", "Salomonsaaret",
+            "Seychellit", "Sudan", "Ruotsi", "Singapore", "Saint Helena", "Slovenia",
+            "Huippuvuoret ja Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal",
+            "Somalia", "Suriname", "Etelä-Sudan", "São Tomé ja Príncipe", "El Salvador",
+            "Sint Maarten", "Syyria", "Eswatini", "Turks- ja Caicossaaret", "Tšad", "Togo",
+            "Thaimaa", "Tadžikistan", "Tokelau", "Itä-Timor", "Turkmenistan", "Tunisia", "Tonga",
+            "Turkki", "Trinidad ja Tobago", "Tuvalu", "Taiwan", "Tansania", "Ukraina", "Uganda",
+            "Uruguay", "Uzbekistan", "Vatikaani", "Saint Vincent ja Grenadiinit", "Venezuela",
+            "Brittiläiset Neitsytsaaret", "Yhdysvaltain Neitsytsaaret", "Vietnam", "Vanuatu",
+            "Wallis ja Futuna", "Samoa", "", "", "", "", "", "", "Jemen", "Mayotte",
+            "Etelä-Afrikka", "Sambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            244, 101, 15, 6, 11, 0, 10, 8, 3, 13, 7, 9, 12, 5, 92, 16, 14, 1, 17, 28, 21, 20, 22,
+            35, 34, 19, 36, 24, 25, 33, 27, 103, 30, 18, 26, 29, 236, 23, 114, 112, 106, 113, 210,
+            161, 39, 38, 100, 107, 110, 40, 117, 102, 41, 98, 119, 222, 190, 42, 215, 43, 44, 4, 45,
+            242, 46, 48, 49, 54, 208, 56, 55, 144, 58, 178, 59, 88, 65, 61, 179, 70, 62, 63, 66, 60,
+            71, 67, 176, 115, 69, 68, 72, 73, 76, 75, 116, 74, 231, 78, 86, 89, 136, 83, 31, 84, 85,
+            87, 90, 96, 93, 97, 94, 105, 108, 99, 109, 111, 186, 170, 52, 118, 37, 104, 120, 123,
+            187, 126, 206, 124, 122, 127, 128, 121, 125, 137, 146, 145, 148, 130, 138, 171, 134,
+            151, 147, 129, 172, 139, 140, 149, 135, 141, 132, 131, 143, 133, 150, 152, 233, 156,
+            159, 157, 155, 2, 160, 154, 153, 158, 234, 162, 166, 169, 180, 167, 57, 163, 175, 189,
+            174, 165, 173, 164, 168, 177, 181, 182, 198, 240, 183, 196, 191, 199, 207, 184, 201,
+            185, 204, 77, 203, 200, 194, 197, 205, 209, 53, 195, 47, 202, 211, 50, 226, 221, 217,
+            216, 212, 218, 91, 225, 223, 219, 224, 220, 227, 213, 214, 229, 228, 232, 235, 238, 188,
+            239, 32, 245, 241, 237, 243, 193, 64, 79, 230, 82, 80, 81, 95, 142, 51, 192, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "fr",
+        names: &[
+            "États-Unis", "Canada", "Île de l’Ascension", "Andorre", "Émirats arabes unis",
+            "Afghanistan", "Antigua-et-Barbuda", "Anguilla", "Albanie", "Arménie", "Angola",
+            "Antarctique", "Argentine", "Samoa américaines", "Autriche", "Australie", "Aruba",
+            "Îles Åland", "Azerbaïdjan", "Bosnie-Herzégovine", "Barbade", "Bangladesh", "Belgique",
+            "Burkina Faso", "Bulgarie", "Bahreïn", "Burundi", "Bénin", "Bermudes", "Brunei",
+            "Bolivie", "Pays-Bas caribéens", "Brésil", "Bahamas", "Bhoutan", "Botswana",
+            "Biélorussie", "Belize", "Îles Cocos", "Congo-Kinshasa", "République centrafricaine",
+            "Congo-Brazzaville", "Suisse", "Côte d’Ivoire", "Îles Cook", "Chili", "Cameroun",
+            "Chine", "Colombie", "Costa Rica", "Cuba", "Cap-Vert", "Curaçao", "Île Christmas",
+            "Chypre", "Tchéquie", "Allemagne", "Djibouti", "Danemark", "Dominique",
+            "République dominicaine", "Algérie", "Équateur", "Estonie", "Égypte", "Érythrée",
+            "Espagne", "Éthiopie", "Finlande", "Fidji", "Îles Malouines", "Micronésie",
+            "Îles Féroé", "France", "Gabon", "Royaume-Uni", "Grenade", "Géorgie",
+            "Guyane française", "Guernesey", "Ghana", "Gibraltar", "Groenland", "Gambie", "Guinée",
+            "Guadeloupe", "Guinée équatoriale", "Grèce", "Guatemala", "Guam", "Guinée-Bissau",
+            "Guyana", "R.A.S. chinoise de Hong Kong", "Honduras", "Croatie", "Haïti", "Hongrie",
+            "Indonésie", "Irlande", "Israël", "Île de Man", "Inde",
+            "Territoire britannique de l’océan Indien", "Irak", "Iran", "Islande", "Italie",
+            "Jersey", "Jamaïque", "Jordanie", "Japon", "Kenya", "Kirghizstan", "Cambodge",
+            "Kiribati", "Comores", "Saint-Christophe-et-Niévès", "Corée du Nord", "Corée du Sud",
+            "Koweït", "Îles Caïmans", "Kazakhstan", "Laos", "Liban", "Sainte-Lucie",
+            "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituanie", "Luxembourg",
+            "Lettonie", "Libye", "Maroc", "Monaco", "Moldavie", "Monténégro", "Madagascar",
+            "Îles Marshall", "Macédoine du Nord", "Mali", "Myanmar (Birmanie)", "Mongolie",
+            "R.A.S. chinoise de Macao", "Îles Mariannes du Nord", "Martinique", "Mauritanie",
+            "Montserrat", "Malte", "Maurice", "Maldives", "Malawi", "Mexique", "Malaisie",
+            "Mozambique", "Namibie", "Nouvelle-Calédonie", "Niger", "Île Norfolk", "Nigeria",
+            "Nicaragua", "Pays-Bas", "Norvège", "Népal", "Nauru", "Niue", "Nouvelle-Zélande",
+            "Oman", "Panama", "Pérou", "Polynésie française", "Papouasie-Nouvelle-Guinée",
+            "Philippines", "Pakistan", "Pologne", "Saint-Pierre-et-Miquelon", "Porto Rico",
+            "Territoires palestiniens", "Portugal", "Palaos", "Paraguay", "Qatar", "La Réunion",
+            "Roumanie", "Serbie", "Russie", "Rwanda", "Arabie saoudite", "Îles Salomon",
+            "Seychelles", "Soudan", "Suède", "Singapour", "Sainte-Hélène", "Slovénie",
+            "Svalbard et Jan Mayen", "Slovaquie", "Sierra Leone", "Saint-Marin", "Sénégal",
+            "Somalie", "Suriname", "Soudan du Sud", "Sao Tomé-et-Principe", "Salvador",
+            "Saint-Martin (partie néerlandaise)", "Syrie", "Eswatini", "Îles Turques-et-Caïques",
+            "Tchad", "Togo", "Thaïlande", "Tadjikistan", "Tokelau", "Timor oriental",
+            "Turkménistan", "Tunisie", "Tonga", "Turquie", "Trinité-et-Tobago", "Tuvalu", "Taïwan",
+            "Tanzanie", "Ukraine", "Ouganda", "Uruguay", "Ouzbékistan",
+            "État de la Cité du Vatican", "Saint-Vincent-et-les Grenadines", "Venezuela",
+            "Îles Vierges britanniques", "Îles Vierges des États-Unis", "Viêt Nam", "Vanuatu",
+            "Wallis-et-Futuna", "Samoa", "", "", "", "", "", "", "Yémen", "Mayotte",
+            "Afrique du Sud", "Zambie", "Zimbabwe",
+        ],
+        sort_keys: &[
+            64, 37, 91, 5, 57, 0, 9, 7, 2, 12, 6, 8, 11, 202, 15, 14, 13, 94, 16, 28, 20, 19, 21,
+            33, 32, 18, 34, 23, 24, 31, 27, 177, 30, 17, 25, 29, 26, 22, 96, 45, 187, 44, 216, 49,
+            97, 39, 36, 40, 42, 48, 51, 38, 52, 90, 41, 224, 4, 54, 53, 55, 188, 3, 58, 61, 56, 59,
+            60, 65, 67, 66, 99, 150, 98, 68, 69, 190, 76, 71, 86, 81, 72, 73, 77, 70, 82, 78, 83,
+            75, 80, 79, 84, 85, 185, 88, 50, 87, 89, 107, 114, 116, 92, 106, 225, 112, 113, 115,
+            117, 120, 118, 121, 119, 123, 124, 35, 125, 43, 193, 46, 47, 126, 95, 122, 128, 131,
+            199, 134, 214, 132, 129, 135, 136, 130, 133, 144, 152, 151, 154, 138, 101, 137, 142,
+            157, 153, 186, 100, 145, 147, 155, 143, 146, 141, 140, 149, 139, 156, 158, 166, 162, 93,
+            163, 161, 176, 165, 160, 159, 164, 167, 168, 173, 178, 181, 174, 179, 171, 180, 196,
+            182, 226, 183, 172, 175, 184, 127, 189, 205, 191, 192, 10, 102, 206, 212, 215, 208, 198,
+            210, 218, 209, 207, 194, 204, 211, 217, 213, 203, 200, 195, 219, 62, 103, 223, 229, 227,
+            220, 230, 228, 234, 233, 231, 235, 232, 236, 221, 222, 237, 169, 239, 170, 63, 197, 241,
+            104, 105, 242, 240, 243, 201, 74, 108, 238, 111, 109, 110, 244, 148, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "he",
+        names: &[
+            "ארצות הברית", "קנדה", "האי אסנשן", "אנדורה", "איחוד האמירויות הערביות", "אפגניסטן",
+            "אנטיגואה וברבודה", "אנגווילה", "אלבניה", "ארמניה", "אנגולה", "אנטארקטיקה", "ארגנטינה",
+            "סמואה האמריקנית", "אוסטריה", "אוסטרליה", "ארובה", "איי אולנד", "אזרבייג׳ן",
+            "בוסניה והרצגובינה", "ברבדוס", "בנגלדש", "בלגיה", "בורקינה פאסו", "בולגריה", "בחריין",
+            "בורונדי", "בנין", "ברמודה", "ברוניי", "בוליביה", "האיים הקריביים ההולנדיים", "ברזיל",
+            "איי בהאמה", "בהוטן", "בוטסואנה", "בלארוס", "בליז", "איי קוקוס (קילינג)",
+            "קונגו - קינשאסה", "הרפובליקה המרכז-אפריקאית", "קונגו - ברזאויל", "שווייץ", "חוף השנהב",
+            "איי קוק", "צ׳ילה", "קמרון", "סין", "קולומביה", "קוסטה ריקה", "קובה", "כף ורדה",
+            "קוראסאו", "אי חג המולד", "קפריסין", "צ׳כיה", "גרמניה", "ג׳יבוטי", "דנמרק", "דומיניקה",
+            "הרפובליקה הדומיניקנית", "אלג׳יריה", "אקוודור", "אסטוניה", "מצרים", "אריתריאה", "ספרד",
+            "אתיופיה", "פינלנד", "פיג׳י", "איי פוקלנד", "מיקרונזיה", "איי פארו", "צרפת", "גבון",
+            "בריטניה", "גרנדה", "גאורגיה", "גיאנה הצרפתית", "גרנזי", "גאנה", "גיברלטר", "גרינלנד",
+            "גמביה", "גינאה", "גוואדלופ", "גינאה המשוונית", "יוון", "גואטמלה", "גואם",
+            "גינאה-ביסאו", "גיאנה", "הונג קונג (אזור מנהלי מיוחד של סין)", "הונדורס", "קרואטיה",
+            "האיטי", "הונגריה", "אינדונזיה", "אירלנד", "ישראל", "האי מאן", "הודו",
+            "הטריטוריה הבריטית באוקיינוס ההודי", "עיראק", "איראן", "איסלנד", "איטליה", "ג׳רזי",
+            "ג׳מייקה", "ירדן", "יפן", "קניה", "קירגיזסטן", "קמבודיה", "קיריבאטי", "קומורו",
+            "סנט קיטס ונוויס", "קוריאה הצפונית", "קוריאה הדרומית", "כווית", "איי קיימן", "קזחסטן",
+            "לאוס", "לבנון", "סנט לוסיה", "ליכטנשטיין", "סרי לנקה", "ליבריה", "לסוטו", "ליטא",
+            "לוקסמבורג", "לטביה", "לוב", "מרוקו", "מונקו", "מולדובה", "מונטנגרו", "מדגסקר",
+            "איי מרשל", "מקדוניה הצפונית", "מאלי", "מיאנמר (בורמה)", "מונגוליה",
+            "מקאו (אזור מנהלי מיוחד של סין)", "איי מריאנה הצפוניים", "מרטיניק", "מאוריטניה",
+            "מונסראט", "מלטה", "מאוריציוס", "האיים המלדיביים", "מלאווי", "מקסיקו", "מלזיה",
+            "מוזמביק", "נמיביה", "קלדוניה החדשה", "ניז׳ר", "האי נורפוק", "ניגריה", "ניקרגואה",
+            "הולנד", "נורווגיה", "נפאל", "נאורו", "ניווה", "ניו זילנד", "עומאן", "פנמה", "פרו",
+            "פולינזיה הצרפתית", "פפואה גינאה החדשה", "הפיליפינים", "פקיסטן", "פולין",
+            "סנט פייר ומיקלון", "פוארטו ריקו", "השטחים הפלסטיניים", "פורטוגל", "פלאו", "פרגוואי",
+            "קטאר", "ראוניון", "רומניה", "סרביה", "רוסיה", "רואנדה", "ערב הסעודית", "איי שלמה",
+            "איי סיישל", "סודן", "שוודיה", "סינגפור", "סנט הלנה", "סלובניה", "סבאלברד ויאן מאיין",
+            "סלובקיה", "סיירה לאון", "סן מרינו", "סנגל", "סומליה", "סורינאם", "דרום סודן",
+            "סאו טומה ופרינסיפה", "אל סלבדור", "סנט מארטן", "סוריה", "אסוואטיני",
+            "איי טרקס וקייקוס", "צ׳אד", "טוגו", "תאילנד", "טג׳יקיסטן", "טוקלאו", "טימור-לסטה",
+            "טורקמניסטן", "תוניסיה", "טונגה", "טורקיה", "טרינידד וטובגו", "טובאלו", "טייוואן",
+            "טנזניה", "אוקראינה", "אוגנדה", "אורוגוואי", "אוזבקיסטן", "הוותיקן",
+            "סנט וינסנט והגרנדינים", "ונצואלה", "איי הבתולה הבריטיים", "איי הבתולה של ארצות הברית",
+            "וייטנאם", "ונואטו", "איי ווליס ופוטונה", "סמואה", "", "", "", "", "", "", "תימן",
+            "מאיוט", "דרום אפריקה", "זמביה", "זימבבואה",
+        ],
+        sort_keys: &[
+            45, 228, 89, 34, 8, 39, 36, 32, 30, 44, 33, 35, 41, 181, 2, 3, 42, 10, 6, 51, 60, 58,
+            56, 53, 49, 54, 52, 59, 64, 61, 50, 94, 62, 11, 47, 48, 55, 57, 22, 216, 104, 215, 237,
+            111, 21, 209, 227, 176, 213, 217, 212, 128, 218, 7, 230, 210, 82, 65, 86, 85, 103, 31,
+            40, 38, 154, 43, 190, 46, 201, 200, 20, 150, 19, 211, 70, 63, 83, 68, 75, 84, 69, 76,
+            81, 80, 77, 73, 78, 123, 71, 72, 79, 74, 98, 100, 231, 92, 99, 25, 28, 126, 90, 95, 101,
+            194, 27, 26, 9, 67, 66, 125, 124, 229, 223, 226, 224, 214, 189, 220, 219, 127, 23, 221,
+            129, 130, 186, 136, 192, 134, 137, 135, 132, 133, 131, 158, 148, 144, 146, 142, 17, 156,
+            141, 149, 145, 155, 16, 159, 138, 147, 153, 139, 93, 151, 157, 152, 143, 167, 225, 165,
+            91, 162, 166, 97, 161, 168, 160, 164, 163, 193, 203, 207, 198, 204, 102, 205, 197, 188,
+            196, 105, 199, 202, 206, 222, 232, 234, 191, 235, 233, 195, 24, 18, 171, 236, 177, 184,
+            178, 170, 179, 175, 182, 183, 172, 174, 88, 169, 29, 187, 173, 37, 15, 208, 114, 238,
+            112, 116, 120, 118, 239, 115, 117, 122, 113, 119, 121, 4, 0, 5, 1, 96, 185, 108, 12, 13,
+            106, 107, 14, 180, 241, 242, 246, 245, 243, 244, 240, 140, 87, 110, 109,
+        ],
+    },
+    LocaleNames {
+        locale: "hi",
+        names: &[
+            "संयुक्त राज्य", "कनाडा", "असेंशन द्वीप", "एंडोरा", "संयुक्त अरब अमीरात",
+            "अफ़गानिस्तान", "एंटिगुआ और बरबुडा", "एंग्विला", "अल्बानिया", "आर्मेनिया", "अंगोला",
+            "अंटार्कटिका", "अर्जेंटीना", "अमेरिकी समोआ", "ऑस्ट्रिया", "ऑस्ट्रेलिया", "अरूबा",
+            "एलैंड द्वीपसमूह", "अज़रबैजान", "बोस्निया और हर्ज़ेगोविना", "बारबाडोस", "बांग्लादेश",
+            "बेल्जियम", "बुर्किना फ़ासो", "बुल्गारिया", "बहरीन", "बुरुंडी", "बेनिन", "बरमूडा",
+            "ब्रूनेई", "बोलीविया", "कैरिबियन नीदरलैंड", "ब्राज़ील", "बहामास", "भूटान", "बोत्स्वाना",
+            "बेलारूस", "बेलीज़", "कोकोस (कीलिंग) द्वीपसमूह", "कांगो - किंशासा",
+            "मध्य अफ़्रीकी गणराज्य", "कांगो – ब्राज़ाविल", "स्विट्ज़रलैंड", "कोत दिवुआर",
+            "कुक द्वीपसमूह", "चिली", "कैमरून", "चीन", "कोलंबिया", "कोस्टारिका", "क्यूबा",
+            "केप वर्ड", "कुरासाओ", "क्रिसमस द्वीप", "साइप्रस", "चेकिया", "जर्मनी", "जिबूती",
+            "डेनमार्क", "डोमिनिका", "डोमिनिकन गणराज्य", "अल्जीरिया", "इक्वाडोर", "एस्टोनिया",
+            "मिस्र", "इरिट्रिया", "स्पेन", "इथियोपिया", "फ़िनलैंड", "फ़िजी", "फ़ॉकलैंड द्वीपसमूह",
+            "माइक्रोनेशिया", "फ़ेरो द्वीपसमूह", "फ़्रांस", "गैबॉन", "यूनाइटेड किंगडम", "ग्रेनाडा",
+            "जॉर्जिया", "फ़्रेंच गुयाना", "गर्नसी", "घाना", "जिब्राल्टर", "ग्रीनलैंड", "गाम्बिया",
+            "गिनी", "ग्वाडेलूप", "इक्वेटोरियल गिनी", "यूनान", "ग्वाटेमाला", "गुआम", "गिनी-बिसाउ",
+            "गुयाना", "हाँग काँग (चीन विशेष प्रशासनिक क्षेत्र)", "होंडूरास", "क्रोएशिया", "हैती",
+            "हंगरी", "इंडोनेशिया", "आयरलैंड", "इज़राइल", "आइल ऑफ़ मैन", "भारत",
+            "ब्रिटिश हिंद महासागरीय क्षेत्र", "इराक", "ईरान", "आइसलैंड", "इटली", "जर्सी", "जमैका",
+            "जॉर्डन", "जापान", "केन्या", "किर्गिज़स्तान", "कंबोडिया", "किरिबाती", "कोमोरोस",
+            "सेंट किट्स और नेविस", "उत्तर कोरिया", "दक्षिण कोरिया", "कुवैत", "कैमेन द्वीपसमूह",
+            "कज़ाखस्तान", "लाओस", "लेबनान", "सेंट लूसिया", "लिचेंस्टीन", "श्रीलंका", "लाइबेरिया",
+            "लेसोथो", "लिथुआनिया", "लग्ज़मबर्ग", "लातविया", "लीबिया", "मोरक्को", "मोनाको",
+            "मॉल्डोवा", "मोंटेनेग्रो", "मेडागास्कर", "मार्शल द्वीपसमूह", "उत्तरी मकदूनिया", "माली",
+            "म्यांमार (बर्मा)", "मंगोलिया", "मकाऊ (विशेष प्रशासनिक क्षेत्र चीन)",
+            "उत्तरी मारियाना द्वीपसमूह", "मार्टीनिक", "मॉरिटानिया", "मोंटसेरात", "माल्टा", "मॉरीशस",
+            "मालदीव", "मलावी", "मैक्सिको", "मलेशिया", "मोज़ांबिक", "नामीबिया", "न्यू कैलेडोनिया",
+            "नाइजर", "नॉरफ़ॉक द्वीप", "नाइजीरिया", "निकारागुआ", "नीदरलैंड", "नॉर्वे", "नेपाल",
+            "नाउरु", "नीयू", "न्यूज़ीलैंड", "ओमान", "पनामा", "पेरू", "फ़्रेंच पोलिनेशिया",
+            "पापुआ न्यू गिनी", "फ़िलिपींस", "पाकिस्तान", "पोलैंड", "सेंट पिएरे और मिक्वेलान",
+            "पोर्टो रिको", "फ़िलिस्तीनी क्षेत्र", "पुर्तगाल", "पलाऊ", "पराग्वे", "क़तर", "रियूनियन",
+            "रोमानिया", "सर्बिया", "रूस", "रवांडा", "सऊदी अरब", "सोलोमन द्वीपसमूह", "सेशेल्स",
+            "सूडान", "स्वीडन", "सिंगापुर", "सेंट हेलेना", "स्लोवेनिया", "स्वालबार्ड और जान मायेन",
+            "स्लोवाकिया", "सिएरा लियोन", "सैन मेरीनो", "सेनेगल", "सोमालिया", "सूरीनाम",
+            "दक्षिण सूडान", "साओ टोम और प्रिंसिपे", "अल सल्वाडोर", "सिंट मार्टिन", "सीरिया",
+            "एस्वाटिनी", "तुर्क और कैकोज़ द्वीपसमूह", "चाड", "टोगो", "थाईलैंड", "ताजिकिस्तान",
+            "तोकेलाउ", "तिमोर-लेस्त", "तुर्कमेनिस्तान", "ट्यूनीशिया", "टोंगा", "तुर्किये",
+            "त्रिनिदाद और टोबैगो", "तुवालू", "ताइवान", "तंज़ानिया", "यूक्रेन", "युगांडा", "उरूग्वे",
+            "उज़्बेकिस्तान", "वेटिकन सिटी", "सेंट विंसेंट और ग्रेनाडाइंस", "वेनेज़ुएला",
+            "ब्रिटिश वर्जिन द्वीपसमूह", "यू॰एस॰ वर्जिन द्वीपसमूह", "वियतनाम", "वनुआतू",
+            "वालिस और फ़्यूचूना", "समोआ", "", "", "", "", "", "", "यमन", "मायोते",
+            "दक्षिण अफ़्रीका", "ज़ाम्बिया", "ज़िम्बाब्वे",
+        ],
+        sort_keys: &[
+            209, 41, 10, 31, 208, 3, 30, 29, 9, 14, 0, 1, 6, 4, 35, 36, 5, 32, 2, 152, 142, 141,
+            149, 144, 145, 139, 143, 146, 138, 156, 151, 53, 153, 140, 158, 150, 147, 148, 54, 42,
+            161, 43, 235, 55, 46, 75, 51, 76, 57, 58, 59, 50, 47, 60, 213, 77, 79, 83, 91, 93, 92,
+            8, 16, 33, 171, 22, 231, 20, 130, 129, 134, 164, 133, 135, 68, 187, 70, 86, 136, 62, 73,
+            84, 69, 63, 64, 72, 17, 188, 71, 66, 65, 67, 238, 240, 61, 239, 237, 15, 13, 18, 11,
+            157, 155, 21, 23, 12, 19, 80, 78, 87, 81, 49, 45, 38, 44, 56, 221, 25, 106, 48, 52, 39,
+            195, 200, 223, 197, 207, 194, 201, 198, 193, 196, 199, 181, 180, 176, 178, 172, 167, 26,
+            169, 182, 159, 160, 27, 166, 174, 177, 170, 175, 168, 162, 173, 163, 179, 111, 118, 108,
+            116, 109, 112, 113, 117, 115, 110, 114, 119, 37, 120, 126, 137, 124, 131, 123, 128, 222,
+            127, 132, 125, 122, 121, 40, 190, 192, 212, 191, 189, 210, 230, 227, 219, 236, 215, 225,
+            233, 234, 232, 217, 228, 226, 229, 220, 107, 214, 7, 216, 218, 34, 98, 74, 89, 104, 96,
+            102, 97, 99, 90, 88, 100, 103, 101, 95, 94, 186, 184, 28, 24, 205, 224, 206, 154, 185,
+            204, 202, 203, 211, 241, 242, 246, 245, 243, 244, 183, 165, 105, 82, 85,
+        ],
+    },
+    LocaleNames {
+        locale: "hu",
+        names: &[
+            "Egyesült Államok", "Kanada", "Ascension-sziget", "Andorra", "Egyesült Arab Emírségek",
+            "Afganisztán", "Antigua és Barbuda", "Anguilla", "Albánia", "Örményország", "Angola",
+            "Antarktisz", "Argentína", "Amerikai Szamoa", "Ausztria", "Ausztrália", "Aruba",
+            "Åland-szigetek", "Azerbajdzsán", "Bosznia-Hercegovina", "Barbados", "Banglades",
+            "Belgium", "Burkina Faso", "Bulgária", "Bahrein", "Burundi", "Benin", "Bermuda",
+            "Brunei", "Bolívia", "Holland Karib-térség", "Brazília", "Bahama-szigetek", "Bhután",
+            "Botswana", "Belarusz", "Belize", "Kókusz (Keeling)-szigetek", "Kongó – Kinshasa",
+            "Közép-afrikai Köztársaság", "Kongó – Brazzaville", "Svájc", "Elefántcsontpart",
+            "Cook-szigetek", "Chile", "Kamerun", "Kína", "Kolumbia", "Costa Rica", "Kuba",
+            "Zöld-foki Köztársaság", "Curaçao", "Karácsony-sziget", "Ciprus", "Csehország",
+            "Németország", "Dzsibuti", "Dánia", "Dominika", "Dominikai Köztársaság", "Algéria",
+            "Ecuador", "Észtország", "Egyiptom", "Eritrea", "Spanyolország", "Etiópia",
+            "Finnország", "Fidzsi", "Falkland-szigetek", "Mikronézia", "Feröer szigetek",
+            "Franciaország", "Gabon", "Egyesült Királyság", "Grenada", "Grúzia", "Francia Guyana",
+            "Guernsey", "Ghána", "Gibraltár", "Grönland", "Gambia", "Guinea", "Guadeloupe",
+            "Egyenlítői-Guinea", "Görögország", "Guatemala", "Guam", "Bissau-Guinea", "Guyana",
+            "Hongkong KKT", "Honduras", "Horvátország", "Haiti", "Magyarország", "Indonézia",
+            "Írország", "Izrael", "Man-sziget", "India", "Brit Indiai-óceáni Terület", "Irak",
+            "Irán", "Izland", "Olaszország", "Jersey", "Jamaica", "Jordánia", "Japán", "Kenya",
+            "Kirgizisztán", "Kambodzsa", "Kiribati", "Comore-szigetek", "Saint Kitts és Nevis",
+            "Észak-Korea", "Dél-Korea", "Kuvait", "Kajmán-szigetek", "Kazahsztán", "Laosz",
+            "Libanon", "Saint Lucia", "Liechtenstein", "Srí Lanka", "Libéria", "Lesotho",
+            "Litvánia", "Luxemburg", "Lettország", "Líbia", "Marokkó", "Monaco", "Moldova",
+            "Montenegró", "Madagaszkár", "Marshall-szigetek", "Észak-Macedónia", "Mali", "Mianmar",
+            "Mongólia", "Makaó KKT", "Északi Mariana-szigetek", "Martinique", "Mauritánia",
+            "Montserrat", "Málta", "Mauritius", "Maldív-szigetek", "Malawi", "Mexikó", "Malajzia",
+            "Mozambik", "Namíbia", "Új-Kaledónia", "Niger", "Norfolk-sziget", "Nigéria",
+            "Nicaragua", "Hollandia", "Norvégia", "Nepál", "Nauru", "Niue", "Új-Zéland", "Omán",
+            "Panama", "Peru", "Francia Polinézia", "Pápua Új-Guinea", "Fülöp-szigetek", "Pakisztán",
+            "Lengyelország", "Saint-Pierre és Miquelon", "Puerto Rico", "Palesztin Autonómia",
+            "Portugália", "Palau", "Paraguay", "Katar", "Réunion", "Románia", "Szerbia",
+            "Oroszország", "Ruanda", "Szaúd-Arábia", "Salamon-szigetek", "Seychelle-szigetek",
+            "Szudán", "Svédország", "Szingapúr", "Szent Ilona", "Szlovénia",
+            "Svalbard és Jan Mayen", "Szlovákia", "Sierra Leone", "San Marino", "Szenegál",
+            "Szomália", "Suriname", "Dél-Szudán", "São Tomé és Príncipe", "Salvador",
+            "Sint Maarten", "Szíria", "Szváziföld", "Turks- és Caicos-szigetek", "Csád", "Togo",
+            "Thaiföld", "Tádzsikisztán", "Tokelau", "Kelet-Timor", "Türkmenisztán", "Tunézia",
+            "Tonga", "Törökország", "Trinidad és Tobago", "Tuvalu", "Tajvan", "Tanzánia", "Ukrajna",
+            "Uganda", "Uruguay", "Üzbegisztán", "Vatikán", "Saint Vincent és a Grenadine-szigetek",
+            "Venezuela", "Brit Virgin-szigetek", "Amerikai Virgin-szigetek", "Vietnám", "Vanuatu",
+            "Wallis és Futuna", "Szamoa", "", "", "", "", "", "", "Jemen", "Mayotte",
+            "Dél-afrikai Köztársaság", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            55, 114, 13, 6, 56, 0, 10, 8, 2, 177, 7, 9, 11, 4, 15, 14, 12, 1, 16, 29, 20, 19, 22,
+            36, 35, 18, 37, 24, 25, 34, 28, 90, 31, 17, 26, 30, 21, 23, 123, 126, 127, 125, 204, 59,
+            41, 38, 113, 120, 124, 42, 128, 246, 43, 115, 39, 45, 166, 52, 46, 50, 51, 3, 53, 64,
+            58, 60, 201, 65, 69, 68, 66, 157, 67, 72, 74, 57, 80, 82, 70, 86, 76, 77, 81, 75, 87,
+            83, 54, 79, 85, 84, 27, 88, 93, 92, 94, 89, 141, 96, 103, 105, 148, 95, 32, 101, 102,
+            104, 174, 109, 106, 110, 107, 119, 121, 112, 122, 40, 190, 61, 48, 129, 111, 117, 130,
+            134, 191, 137, 202, 135, 132, 138, 139, 133, 136, 149, 159, 158, 161, 140, 150, 62, 146,
+            156, 160, 142, 63, 151, 152, 162, 147, 153, 145, 144, 155, 143, 163, 164, 233, 169, 172,
+            170, 168, 91, 173, 167, 165, 171, 234, 175, 181, 184, 71, 182, 73, 178, 131, 193, 186,
+            180, 185, 179, 183, 116, 187, 188, 211, 176, 189, 208, 194, 198, 217, 206, 212, 210,
+            215, 205, 214, 199, 196, 209, 216, 203, 49, 197, 195, 200, 213, 218, 229, 44, 223, 222,
+            219, 224, 118, 231, 228, 225, 226, 227, 230, 220, 221, 235, 232, 237, 238, 240, 192,
+            241, 33, 5, 242, 239, 243, 207, 78, 97, 236, 100, 98, 99, 108, 154, 47, 244, 245,
+        ],
+    },
+    LocaleNames {
+        locale: "id",
+        names: &[
+            "Amerika Serikat", "Kanada", "Pulau Ascension", "Andorra", "Uni Emirat Arab",
+            "Afganistan", "Antigua dan Barbuda", "Anguilla", "Albania", "Armenia", "Angola",
+            "Antarktika", "Argentina", "Samoa Amerika", "Austria", "Australia", "Aruba",
+            "Kepulauan Aland", "Azerbaijan", "Bosnia dan Herzegovina", "Barbados", "Bangladesh",
+            "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Bermuda",
+            "Brunei", "Bolivia", "Belanda Karibia", "Brasil", "Bahama", "Bhutan", "Botswana",
+            "Belarus", "Belize", "Kepulauan Cocos (Keeling)", "Kongo - Kinshasa",
+            "Republik Afrika Tengah", "Kongo - Brazzaville", "Swiss", "Côte d’Ivoire",
+            "Kepulauan Cook", "Cile", "Kamerun", "Tiongkok", "Kolombia", "Kosta Rika", "Kuba",
+            "Tanjung Verde", "Curaçao", "Pulau Natal", "Siprus", "Ceko", "Jerman", "Jibuti",
+            "Denmark", "Dominika", "Republik Dominika", "Aljazair", "Ekuador", "Estonia", "Mesir",
+            "Eritrea", "Spanyol", "Etiopia", "Finlandia", "Fiji", "Kepulauan Falkland",
+            "Mikronesia", "Kepulauan Faroe", "Prancis", "Gabon", "Inggris Raya", "Grenada",
+            "Georgia", "Guyana Prancis", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia",
+            "Guinea", "Guadeloupe", "Guinea Ekuatorial", "Yunani", "Guatemala", "Guam",
+            "Guinea-Bissau", "Guyana", "Hong Kong DAK Tiongkok", "Honduras", "Kroasia", "Haiti",
+            "Hungaria", "Indonesia", "Irlandia", "Israel", "Pulau Man", "India",
+            "Wilayah Inggris di Samudra Hindia", "Irak", "Iran", "Islandia", "Italia", "Jersey",
+            "Jamaika", "Yordania", "Jepang", "Kenya", "Kirgizstan", "Kamboja", "Kiribati", "Komoro",
+            "Saint Kitts dan Nevis", "Korea Utara", "Korea Selatan", "Kuwait", "Kepulauan Cayman",
+            "Kazakhstan", "Laos", "Lebanon", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Lituania", "Luksemburg", "Latvia", "Libya", "Maroko", "Monako", "Moldova",
+            "Montenegro", "Madagaskar", "Kepulauan Marshall", "Makedonia Utara", "Mali",
+            "Myanmar (Burma)", "Mongolia", "Makau DAK Tiongkok", "Kepulauan Mariana Utara",
+            "Martinik", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maladewa", "Malawi",
+            "Meksiko", "Malaysia", "Mozambik", "Namibia", "Kaledonia Baru", "Niger",
+            "Kepulauan Norfolk", "Nigeria", "Nikaragua", "Belanda", "Norwegia", "Nepal", "Nauru",
+            "Niue", "Selandia Baru", "Oman", "Panama", "Peru", "Polinesia Prancis", "Papua Nugini",
+            "Filipina", "Pakistan", "Polandia", "Saint Pierre dan Miquelon", "Puerto Riko",
+            "Wilayah Palestina", "Portugal", "Palau", "Paraguay", "Qatar", "Réunion", "Rumania",
+            "Serbia", "Rusia", "Rwanda", "Arab Saudi", "Kepulauan Solomon", "Seychelles", "Sudan",
+            "Swedia", "Singapura", "Saint Helena", "Slovenia", "Kepulauan Svalbard dan Jan Mayen",
+            "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname",
+            "Sudan Selatan", "Sao Tome dan Principe", "El Salvador", "Sint Maarten", "Suriah",
+            "eSwatini", "Kepulauan Turks dan Caicos", "Chad", "Togo", "Thailand", "Tajikistan",
+            "Tokelau", "Timor Leste", "Turkmenistan", "Tunisia", "Tonga", "Turki",
+            "Trinidad dan Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "Uruguay",
+            "Uzbekistan", "Vatikan", "Saint Vincent dan Grenadine", "Venezuela",
+            "Kepulauan Virgin Britania Raya", "Kepulauan Virgin Amerika Serikat", "Vietnam",
+            "Vanuatu", "Kepulauan Wallis dan Futuna", "Samoa", "", "", "", "", "", "", "Yaman",
+            "Mayotte", "Afrika Selatan", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            4, 95, 177, 5, 232, 0, 9, 7, 2, 12, 6, 8, 11, 193, 15, 14, 13, 98, 16, 30, 20, 19, 24,
+            35, 34, 18, 36, 26, 27, 33, 29, 22, 32, 17, 28, 31, 23, 25, 100, 118, 181, 117, 214, 40,
+            101, 39, 94, 221, 115, 121, 123, 217, 41, 179, 203, 37, 89, 91, 42, 43, 182, 3, 44, 47,
+            148, 46, 207, 49, 52, 50, 102, 149, 103, 175, 53, 76, 60, 55, 69, 64, 56, 57, 59, 54,
+            65, 61, 66, 244, 63, 62, 67, 68, 72, 71, 122, 70, 73, 75, 83, 85, 178, 74, 240, 81, 82,
+            84, 86, 90, 87, 243, 88, 97, 113, 93, 114, 116, 188, 120, 119, 124, 99, 96, 125, 127,
+            189, 131, 208, 129, 128, 132, 133, 126, 130, 142, 151, 150, 153, 134, 105, 136, 140,
+            156, 152, 135, 104, 143, 144, 154, 141, 145, 137, 138, 147, 139, 155, 157, 92, 160, 106,
+            161, 162, 21, 164, 159, 158, 163, 196, 165, 168, 171, 173, 169, 51, 166, 172, 190, 176,
+            241, 174, 167, 170, 180, 183, 184, 198, 185, 186, 10, 107, 199, 209, 213, 201, 187, 205,
+            108, 204, 200, 194, 197, 206, 212, 210, 195, 45, 202, 211, 48, 109, 38, 222, 219, 216,
+            223, 220, 228, 226, 224, 227, 225, 229, 215, 218, 231, 230, 234, 235, 237, 191, 238,
+            111, 110, 239, 236, 112, 192, 58, 77, 233, 80, 78, 79, 242, 146, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "it",
+        names: &[
+            "Stati Uniti", "Canada", "Isola Ascensione", "Andorra", "Emirati Arabi Uniti",
+            "Afghanistan", "Antigua e Barbuda", "Anguilla", "Albania", "Armenia", "Angola",
+            "Antartide", "Argentina", "Samoa Americane", "Austria", "Australia", "Aruba",
+            "Isole Åland", "Azerbaigian", "Bosnia ed Erzegovina", "Barbados", "Bangladesh",
+            "Belgio", "Burkina Faso", "Bulgaria", "Bahrein", "Burundi", "Benin", "Bermuda",
+            "Brunei", "Bolivia", "Caraibi Olandesi", "Brasile", "Bahamas", "Bhutan", "Botswana",
+            "Bielorussia", "Belize", "Isole Cocos (Keeling)", "Congo - Kinshasa",
+            "Repubblica Centrafricana", "Congo-Brazzaville", "Svizzera", "Costa d’Avorio",
+            "Isole Cook", "Cile", "Camerun", "Cina", "Colombia", "Costa Rica", "Cuba", "Capo Verde",
+            "Curaçao", "Isola Christmas", "Cipro", "Cechia", "Germania", "Gibuti", "Danimarca",
+            "Dominica", "Repubblica Dominicana", "Algeria", "Ecuador", "Estonia", "Egitto",
+            "Eritrea", "Spagna", "Etiopia", "Finlandia", "Figi", "Isole Falkland", "Micronesia",
+            "Isole Fær Øer", "Francia", "Gabon", "Regno Unito", "Grenada", "Georgia",
+            "Guyana Francese", "Guernsey", "Ghana", "Gibilterra", "Groenlandia", "Gambia", "Guinea",
+            "Guadalupa", "Guinea Equatoriale", "Grecia", "Guatemala", "Guam", "Guinea-Bissau",
+            "Guyana", "RAS di Hong Kong", "Honduras", "Croazia", "Haiti", "Ungheria", "Indonesia",
+            "Irlanda", "Israele", "Isola di Man", "India",
+            "Territorio Britannico dell’Oceano Indiano", "Iraq", "Iran", "Islanda", "Italia",
+            "Jersey", "Giamaica", "Giordania", "Giappone", "Kenya", "Kirghizistan", "Cambogia",
+            "Kiribati", "Comore", "Saint Kitts e Nevis", "Corea del Nord", "Corea del Sud",
+            "Kuwait", "Isole Cayman", "Kazakistan", "Laos", "Libano", "Saint Lucia",
+            "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Lussemburgo",
+            "Lettonia", "Libia", "Marocco", "Monaco", "Moldavia", "Montenegro", "Madagascar",
+            "Isole Marshall", "Macedonia del Nord", "Mali", "Myanmar (Birmania)", "Mongolia",
+            "RAS di Macao", "Isole Marianne Settentrionali", "Martinica", "Mauritania",
+            "Montserrat", "Malta", "Mauritius", "Maldive", "Malawi", "Messico", "Malaysia",
+            "Mozambico", "Namibia", "Nuova Caledonia", "Niger", "Isola Norfolk", "Nigeria",
+            "Nicaragua", "Paesi Bassi", "Norvegia", "Nepal", "Nauru", "Niue", "Nuova Zelanda",
+            "Oman", "Panama", "Perù", "Polinesia Francese", "Papua Nuova Guinea", "Filippine",
+            "Pakistan", "Polonia", "Saint-Pierre e Miquelon", "Portorico", "Territori Palestinesi",
+            "Portogallo", "Palau", "Paraguay", "Qatar", "Riunione", "Romania", "Serbia", "Russia",
+            "Ruanda", "Arabia Saudita", "Isole Salomone", "Seychelles", "Sudan", "Svezia",
+            "Singapore", "Sant’Elena", "Slovenia", "Svalbard e Jan Mayen", "Slovacchia",
+            "Sierra Leone", "San Marino", "Senegal", "Somalia", "Suriname", "Sud Sudan",
+            "São Tomé e Príncipe", "El Salvador", "Sint Maarten", "Siria", "Eswatini",
+            "Isole Turks e Caicos", "Ciad", "Togo", "Thailandia", "Tagikistan", "Tokelau",
+            "Timor Est", "Turkmenistan", "Tunisia", "Tonga", "Turchia", "Trinidad e Tobago",
+            "Tuvalu", "Taiwan", "Tanzania", "Ucraina", "Uganda", "Uruguay", "Uzbekistan",
+            "Città del Vaticano", "Saint Vincent e Grenadine", "Venezuela",
+            "Isole Vergini Britanniche", "Isole Vergini Americane", "Vietnam", "Vanuatu",
+            "Wallis e Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte", "Sudafrica",
+            "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            211, 35, 104, 3, 60, 0, 7, 5, 1, 10, 4, 6, 9, 195, 13, 12, 11, 108, 14, 26, 18, 17, 19,
+            31, 30, 16, 32, 21, 22, 29, 25, 37, 28, 15, 23, 27, 24, 20, 110, 46, 184, 47, 218, 50,
+            111, 40, 34, 41, 44, 51, 53, 36, 54, 105, 42, 38, 72, 77, 55, 56, 185, 2, 57, 62, 58,
+            61, 209, 64, 67, 65, 113, 150, 112, 68, 69, 183, 81, 71, 91, 86, 73, 76, 82, 70, 87, 83,
+            88, 80, 85, 84, 89, 90, 181, 93, 52, 92, 236, 95, 102, 120, 106, 94, 223, 101, 100, 103,
+            121, 122, 74, 78, 75, 124, 125, 33, 126, 45, 190, 48, 49, 127, 109, 123, 128, 131, 191,
+            134, 210, 132, 129, 135, 136, 130, 133, 144, 152, 151, 154, 138, 115, 137, 142, 157,
+            153, 182, 114, 145, 146, 155, 143, 147, 141, 139, 149, 140, 156, 158, 166, 162, 107,
+            163, 161, 169, 165, 160, 159, 164, 167, 168, 172, 175, 176, 173, 66, 170, 177, 193, 179,
+            222, 178, 171, 174, 180, 186, 187, 200, 189, 188, 8, 116, 201, 214, 217, 203, 197, 207,
+            216, 206, 202, 196, 199, 208, 215, 212, 198, 59, 204, 205, 63, 117, 39, 226, 224, 219,
+            227, 225, 232, 230, 228, 231, 229, 233, 220, 221, 234, 235, 238, 239, 43, 192, 241, 119,
+            118, 242, 240, 243, 194, 79, 96, 237, 99, 97, 98, 244, 148, 213, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "ja",
+        names: &[
+            "アメリカ合衆国", "カナダ", "アセンション島", "アンドラ", "アラブ首長国連邦", "アフガニスタン", "アンティグア・バーブーダ", "アンギラ",
+            "アルバニア", "アルメニア", "アンゴラ", "南極", "アルゼンチン", "米領サモア", "オーストリア", "オーストラリア", "アルバ",
+            "オーランド諸島", "アゼルバイジャン", "ボスニア・ヘルツェゴビナ", "バルバドス", "バングラデシュ", "ベルギー", "ブルキナファソ", "ブルガリア",
+            "バーレーン", "ブルンジ", "ベナン", "バミューダ", "ブルネイ", "ボリビア", "オランダ領カリブ", "ブラジル", "バハマ", "ブータン",
+            "ボツワナ", "ベラルーシ", "ベリーズ", "ココス(キーリング)諸島", "コンゴ民主共和国(キンシャサ)", "中央アフリカ共和国",
+            "コンゴ共和国(ブラザビル)", "スイス", "コートジボワール", "クック諸島", "チリ", "カメルーン", "中国", "コロンビア", "コスタリカ",
+            "キューバ", "カーボベルデ", "キュラソー", "クリスマス島", "キプロス", "チェコ", "ドイツ", "ジブチ", "デンマーク", "ドミニカ国",
+            "ドミニカ共和国", "アルジェリア", "エクアドル", "エストニア", "エジプト", "エリトリア", "スペイン", "エチオピア", "フィンランド",
+            "フィジー", "フォークランド諸島", "ミクロネシア連邦", "フェロー諸島", "フランス", "ガボン", "イギリス", "グレナダ", "ジョージア",
+            "仏領ギアナ", "ガーンジー", "ガーナ", "ジブラルタル", "グリーンランド", "ガンビア", "ギニア", "グアドループ", "赤道ギニア", "ギリシャ",
+            "グアテマラ", "グアム", "ギニアビサウ", "ガイアナ", "中華人民共和国香港特別行政区", "ホンジュラス", "クロアチア", "ハイチ", "ハンガリー",
+            "インドネシア", "アイルランド", "イスラエル", "マン島", "インド", "英領インド洋地域", "イラク", "イラン", "アイスランド", "イタリア",
+            "ジャージー", "ジャマイカ", "ヨルダン", "日本", "ケニア", "キルギス", "カンボジア", "キリバス", "コモロ",
+            "セントクリストファー・ネーヴィス", "北朝鮮", "韓国", "クウェート", "ケイマン諸島", "カザフスタン", "ラオス", "レバノン", "セントルシア",
+            "リヒテンシュタイン", "スリランカ", "リベリア", "レソト", "リトアニア", "ルクセンブルク", "ラトビア", "リビア", "モロッコ", "モナコ",
+            "モルドバ", "モンテネグロ", "マダガスカル", "マーシャル諸島", "北マケドニア", "マリ", "ミャンマー (ビルマ)", "モンゴル",
+            "中華人民共和国マカオ特別行政区", "北マリアナ諸島", "マルティニーク", "モーリタニア", "モントセラト", "マルタ", "モーリシャス", "モルディブ",
+            "マラウイ", "メキシコ", "マレーシア", "モザンビーク", "ナミビア", "ニューカレドニア", "ニジェール", "ノーフォーク島", "ナイジェリア",
+            "ニカラグア", "オランダ", "ノルウェー", "ネパール", "ナウル", "ニウエ", "ニュージーランド", "オマーン", "パナマ", "ペルー",
+            "仏領ポリネシア", "パプアニューギニア", "フィリピン", "パキスタン", "ポーランド", "サンピエール島・ミクロン島", "プエルトリコ",
+            "パレスチナ自治区", "ポルトガル", "パラオ", "パラグアイ", "カタール", "レユニオン", "ルーマニア", "セルビア", "ロシア", "ルワンダ",
+            "サウジアラビア", "ソロモン諸島", "セーシェル", "スーダン", "スウェーデン", "シンガポール", "セントヘレナ", "スロベニア",
+            "スバールバル諸島・ヤンマイエン島", "スロバキア", "シエラレオネ", "サンマリノ", "セネガル", "ソマリア", "スリナム", "南スーダン",
+            "サントメ・プリンシペ", "エルサルバドル", "シント・マールテン", "シリア", "エスワティニ", "タークス・カイコス諸島", "チャド", "トーゴ",
+            "タイ", "タジキスタン", "トケラウ", "東ティモール", "トルクメニスタン", "チュニジア", "トンガ", "トルコ", "トリニダード・トバゴ",
+            "ツバル", "台湾", "タンザニア", "ウクライナ", "ウガンダ", "ウルグアイ", "ウズベキスタン", "バチカン市国",
+            "セントビンセント及びグレナディーン諸島", "ベネズエラ", "英領ヴァージン諸島", "米領ヴァージン諸島", "ベトナム", "バヌアツ", "ウォリス・フツナ",
+            "サモア", "", "", "", "", "", "", "イエメン", "マヨット", "南アフリカ", "ザンビア", "ジンバブエ",
+        ],
+        sort_keys: &[
+            11, 54, 9, 21, 12, 10, 20, 18, 16, 17, 19, 238, 14, 243, 43, 42, 15, 44, 8, 185, 160,
+            163, 183, 174, 173, 149, 176, 178, 157, 175, 187, 47, 171, 155, 167, 186, 180, 181, 79,
+            84, 231, 83, 101, 78, 71, 126, 56, 234, 82, 80, 62, 49, 63, 73, 61, 123, 129, 92, 128,
+            133, 132, 13, 35, 37, 36, 40, 105, 39, 166, 164, 170, 199, 169, 172, 55, 23, 74, 96,
+            240, 50, 48, 93, 72, 57, 59, 68, 229, 64, 67, 69, 60, 51, 233, 189, 75, 150, 162, 29, 7,
+            24, 198, 28, 226, 26, 27, 6, 25, 94, 95, 212, 239, 77, 66, 58, 65, 81, 113, 246, 228,
+            70, 76, 52, 213, 223, 116, 217, 107, 218, 222, 215, 220, 214, 216, 208, 205, 207, 210,
+            191, 190, 244, 194, 200, 209, 232, 245, 196, 203, 211, 195, 202, 206, 193, 201, 197,
+            204, 140, 144, 143, 147, 138, 142, 46, 148, 146, 139, 141, 145, 45, 153, 182, 241, 156,
+            165, 151, 184, 89, 168, 161, 188, 158, 159, 53, 224, 219, 112, 225, 221, 85, 118, 110,
+            103, 102, 98, 115, 109, 104, 108, 91, 90, 111, 117, 106, 237, 87, 41, 99, 97, 38, 119,
+            124, 130, 120, 121, 131, 235, 135, 125, 137, 136, 134, 127, 230, 122, 32, 31, 34, 33,
+            152, 114, 179, 227, 242, 177, 154, 30, 86, 0, 1, 5, 4, 2, 3, 22, 192, 236, 88, 100,
+        ],
+    },
+    LocaleNames {
+        locale: "ko",
+        names: &[
+            "미국", "캐나다", "어센션섬", "안도라", "아랍에미리트", "아프가니스탄", "앤티가 바부다", "앵귈라", "알바니아", "아르메니아",
+            "앙골라", "남극 대륙", "아르헨티나", "아메리칸 사모아", "오스트리아", "오스트레일리아", "아루바", "올란드 제도", "아제르바이잔",
+            "보스니아 헤르체고비나", "바베이도스", "방글라데시", "벨기에", "부르키나파소", "불가리아", "바레인", "부룬디", "베냉", "버뮤다",
+            "브루나이", "볼리비아", "네덜란드령 카리브", "브라질", "바하마", "부탄", "보츠와나", "벨라루스", "벨리즈", "코코스 제도",
+            "콩고-킨샤사", "중앙 아프리카 공화국", "콩고-브라자빌", "스위스", "코트디부아르", "쿡 제도", "칠레", "카메룬", "중국", "콜롬비아",
+            "코스타리카", "쿠바", "카보베르데", "퀴라소", "크리스마스섬", "키프로스", "체코", "독일", "지부티", "덴마크", "도미니카",
+            "도미니카 공화국", "알제리", "에콰도르", "에스토니아", "이집트", "에리트리아", "스페인", "에티오피아", "핀란드", "피지",
+            "포클랜드 제도", "미크로네시아", "페로 제도", "프랑스", "가봉", "영국", "그레나다", "조지아", "프랑스령 기아나", "건지", "가나",
+            "지브롤터", "그린란드", "감비아", "기니", "과들루프", "적도 기니", "그리스", "과테말라", "괌", "기니비사우", "가이아나",
+            "홍콩(중국 특별행정구)", "온두라스", "크로아티아", "아이티", "헝가리", "인도네시아", "아일랜드", "이스라엘", "맨섬", "인도",
+            "영국령 인도양 지역", "이라크", "이란", "아이슬란드", "이탈리아", "저지", "자메이카", "요르단", "일본", "케냐", "키르기스스탄",
+            "캄보디아", "키리바시", "코모로", "세인트키츠 네비스", "북한", "대한민국", "쿠웨이트", "케이맨 제도", "카자흐스탄", "라오스",
+            "레바논", "세인트루시아", "리히텐슈타인", "스리랑카", "라이베리아", "레소토", "리투아니아", "룩셈부르크", "라트비아", "리비아",
+            "모로코", "모나코", "몰도바", "몬테네그로", "마다가스카르", "마셜 제도", "북마케도니아", "말리", "미얀마", "몽골",
+            "마카오(중국 특별행정구)", "북마리아나제도", "마르티니크", "모리타니", "몬트세라트", "몰타", "모리셔스", "몰디브", "말라위", "멕시코",
+            "말레이시아", "모잠비크", "나미비아", "뉴칼레도니아", "니제르", "노퍽섬", "나이지리아", "니카라과", "네덜란드", "노르웨이", "네팔",
+            "나우루", "니우에", "뉴질랜드", "오만", "파나마", "페루", "프랑스령 폴리네시아", "파푸아뉴기니", "필리핀", "파키스탄", "폴란드",
+            "생피에르 미클롱", "푸에르토리코", "팔레스타인 지구", "포르투갈", "팔라우", "파라과이", "카타르", "레위니옹", "루마니아", "세르비아",
+            "러시아", "르완다", "사우디아라비아", "솔로몬 제도", "세이셸", "수단", "스웨덴", "싱가포르", "세인트헬레나", "슬로베니아",
+            "스발바르제도-얀마웬섬", "슬로바키아", "시에라리온", "산마리노", "세네갈", "소말리아", "수리남", "남수단", "상투메 프린시페",
+            "엘살바도르", "신트마르턴", "시리아", "에스와티니", "터크스 케이커스 제도", "차드", "토고", "태국", "타지키스탄", "토켈라우",
+            "동티모르", "투르크메니스탄", "튀니지", "통가", "튀르키예", "트리니다드 토바고", "투발루", "대만", "탄자니아", "우크라이나",
+            "우간다", "우루과이", "우즈베키스탄", "바티칸 시국", "세인트빈센트그레나딘", "베네수엘라", "영국령 버진아일랜드", "미국령 버진아일랜드",
+            "베트남", "바누아투", "왈리스-푸투나 제도", "사모아", "", "", "", "", "", "", "예멘", "마요트", "남아프리카", "잠비아",
+            "짐바브웨",
+        ],
+        sort_keys: &[
+            70, 190, 142, 136, 126, 135, 140, 141, 137, 128, 139, 16, 129, 130, 155, 154, 127, 157,
+            134, 87, 76, 79, 84, 91, 96, 75, 90, 81, 80, 98, 89, 20, 97, 78, 92, 88, 85, 86, 195,
+            199, 178, 198, 118, 196, 202, 184, 185, 177, 197, 194, 200, 186, 203, 205, 208, 183, 34,
+            179, 31, 32, 33, 138, 146, 145, 167, 143, 119, 147, 237, 236, 230, 73, 227, 233, 1, 149,
+            8, 176, 234, 4, 0, 180, 10, 3, 11, 5, 175, 9, 6, 7, 12, 2, 240, 156, 204, 132, 239, 170,
+            133, 166, 57, 169, 151, 164, 165, 131, 168, 174, 172, 159, 171, 191, 206, 189, 207, 193,
+            109, 95, 30, 201, 192, 187, 36, 40, 107, 48, 115, 37, 41, 47, 44, 38, 46, 60, 59, 66,
+            64, 49, 51, 94, 56, 72, 69, 53, 93, 50, 62, 65, 68, 61, 67, 54, 58, 55, 63, 13, 25, 27,
+            23, 15, 28, 19, 22, 21, 14, 26, 24, 153, 221, 228, 235, 224, 238, 223, 231, 103, 232,
+            226, 229, 225, 222, 188, 42, 43, 105, 39, 45, 100, 112, 106, 113, 117, 125, 110, 121,
+            116, 120, 123, 101, 104, 111, 114, 17, 102, 148, 124, 122, 144, 212, 182, 213, 211, 209,
+            214, 35, 216, 218, 215, 219, 220, 217, 29, 210, 163, 160, 161, 162, 77, 108, 82, 150,
+            71, 83, 74, 158, 99, 241, 242, 246, 245, 243, 244, 152, 52, 18, 173, 181,
+        ],
+    },
+    LocaleNames {
+        locale: "ms",
+        names: &[
+            "Amerika Syarikat", "Kanada", "Pulau Ascension", "Andorra", "Emiriah Arab Bersatu",
+            "Afghanistan", "Antigua dan Barbuda", "Anguilla", "Albania", "Armenia", "Angola",
+            "Antartika", "Argentina", "Samoa Amerika", "Austria", "Australia", "Aruba",
+            "Kepulauan Aland", "Azerbaijan", "Bosnia dan Herzegovina", "Barbados", "Bangladesh",
+            "Belgium", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Bermuda",
+            "Brunei", "Bolivia", "Belanda Caribbean", "Brazil", "Bahamas", "Bhutan", "Botswana",
+            "Belarus", "Belize", "Kepulauan Cocos (Keeling)", "Congo - Kinshasa",
+            "Republik Afrika Tengah", "Congo - Brazzaville", "Switzerland", "Cote d’Ivoire",
+            "Kepulauan Cook", "Chile", "Cameroon", "China", "Colombia", "Costa Rica", "Cuba",
+            "Cape Verde", "Curacao", "Pulau Krismas", "Cyprus", "Czechia", "Jerman", "Djibouti",
+            "Denmark", "Dominica", "Republik Dominica", "Algeria", "Ecuador", "Estonia", "Mesir",
+            "Eritrea", "Sepanyol", "Ethiopia", "Finland", "Fiji", "Kepulauan Falkland",
+            "Micronesia", "Kepulauan Faroe", "Perancis", "Gabon", "United Kingdom", "Grenada",
+            "Georgia", "Guiana Perancis", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia",
+            "Guinea", "Guadeloupe", "Guinea Khatulistiwa", "Greece", "Guatemala", "Guam",
+            "Guinea Bissau", "Guyana", "Hong Kong SAR China", "Honduras", "Croatia", "Haiti",
+            "Hungary", "Indonesia", "Ireland", "Israel", "Isle of Man", "India",
+            "Wilayah Lautan Hindi British", "Iraq", "Iran", "Iceland", "Itali", "Jersey", "Jamaica",
+            "Jordan", "Jepun", "Kenya", "Kyrgyzstan", "Kemboja", "Kiribati", "Comoros",
+            "Saint Kitts dan Nevis", "Korea Utara", "Korea Selatan", "Kuwait", "Kepulauan Cayman",
+            "Kazakhstan", "Laos", "Lubnan", "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Lithuania", "Luxembourg", "Latvia", "Libya", "Maghribi", "Monaco",
+            "Moldova", "Montenegro", "Madagaskar", "Kepulauan Marshall", "Macedonia Utara", "Mali",
+            "Myanmar (Burma)", "Mongolia", "Macau SAR China", "Kepulauan Mariana Utara",
+            "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldives", "Malawi",
+            "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia", "Niger",
+            "Pulau Norfolk", "Nigeria", "Nicaragua", "Belanda", "Norway", "Nepal", "Nauru", "Niue",
+            "New Zealand", "Oman", "Panama", "Peru", "Polinesia Perancis", "Papua New Guinea",
+            "Filipina", "Pakistan", "Poland", "Saint Pierre dan Miquelon", "Puerto Rico",
+            "Wilayah Palestin", "Portugal", "Palau", "Paraguay", "Qatar", "Reunion", "Romania",
+            "Serbia", "Rusia", "Rwanda", "Arab Saudi", "Kepulauan Solomon", "Seychelles", "Sudan",
+            "Sweden", "Singapura", "Saint Helena", "Slovenia", "Svalbard dan Jan Mayen", "Slovakia",
+            "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sudan Selatan",
+            "Sao Tome dan Principe", "El Salvador", "Sint Maarten", "Syria", "Eswatini",
+            "Kepulauan Turks dan Caicos", "Chad", "Togo", "Thailand", "Tajikistan", "Tokelau",
+            "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Turkiye", "Trinidad dan Tobago",
+            "Tuvalu", "Taiwan", "Tanzania", "Ukraine", "Uganda", "Uruguay", "Uzbekistan",
+            "Kota Vatican", "Saint Vincent dan Grenadines", "Venezuela", "Kepulauan Virgin British",
+            "Kepulauan Virgin A.S.", "Vietnam", "Vanuatu", "Wallis dan Futuna", "Samoa", "", "", "",
+            "", "", "", "Yaman", "Mayotte", "Afrika Selatan", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            4, 106, 182, 5, 58, 0, 9, 7, 2, 12, 6, 8, 11, 198, 15, 14, 13, 110, 16, 30, 20, 19, 24,
+            35, 34, 18, 36, 26, 27, 33, 29, 22, 32, 17, 28, 31, 23, 25, 112, 45, 186, 44, 217, 47,
+            113, 40, 37, 41, 42, 46, 49, 38, 50, 183, 51, 52, 103, 54, 53, 55, 187, 3, 56, 60, 150,
+            59, 202, 62, 65, 63, 114, 152, 115, 176, 66, 234, 74, 68, 79, 78, 69, 70, 73, 67, 80,
+            75, 82, 72, 77, 76, 81, 83, 86, 85, 48, 84, 87, 90, 97, 99, 98, 89, 242, 96, 95, 88,
+            100, 104, 101, 105, 102, 109, 127, 108, 122, 43, 193, 124, 123, 126, 111, 107, 128, 135,
+            194, 133, 211, 131, 130, 134, 136, 129, 132, 140, 154, 153, 156, 139, 117, 138, 144,
+            159, 155, 137, 116, 146, 147, 157, 145, 148, 143, 141, 151, 142, 158, 160, 163, 166,
+            184, 167, 165, 21, 169, 162, 161, 168, 164, 170, 173, 177, 179, 174, 64, 171, 178, 195,
+            181, 243, 180, 172, 175, 185, 188, 189, 203, 190, 191, 10, 118, 204, 212, 216, 206, 192,
+            209, 215, 208, 205, 199, 201, 210, 214, 213, 200, 57, 207, 218, 61, 119, 39, 224, 222,
+            220, 225, 223, 230, 228, 226, 229, 227, 231, 219, 221, 233, 232, 236, 237, 125, 196,
+            239, 121, 120, 240, 238, 241, 197, 71, 91, 235, 94, 92, 93, 244, 149, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "nl",
+        names: &[
+            "Verenigde Staten", "Canada", "Ascension", "Andorra", "Verenigde Arabische Emiraten",
+            "Afghanistan", "Antigua en Barbuda", "Anguilla", "Albanië", "Armenië", "Angola",
+            "Antarctica", "Argentinië", "Amerikaans-Samoa", "Oostenrijk", "Australië", "Aruba",
+            "Åland", "Azerbeidzjan", "Bosnië en Herzegovina", "Barbados", "Bangladesh", "België",
+            "Burkina Faso", "Bulgarije", "Bahrein", "Burundi", "Benin", "Bermuda", "Brunei",
+            "Bolivia", "Caribisch Nederland", "Brazilië", "Bahama’s", "Bhutan", "Botswana",
+            "Belarus", "Belize", "Cocoseilanden", "Congo-Kinshasa", "Centraal-Afrikaanse Republiek",
+            "Congo-Brazzaville", "Zwitserland", "Ivoorkust", "Cookeilanden", "Chili", "Kameroen",
+            "China", "Colombia", "Costa Rica", "Cuba", "Kaapverdië", "Curaçao", "Christmaseiland",
+            "Cyprus", "Tsjechië", "Duitsland", "Djibouti", "Denemarken", "Dominica",
+            "Dominicaanse Republiek", "Algerije", "Ecuador", "Estland", "Egypte", "Eritrea",
+            "Spanje", "Ethiopië", "Finland", "Fiji", "Falklandeilanden", "Micronesia", "Faeröer",
+            "Frankrijk", "Gabon", "Verenigd Koninkrijk", "Grenada", "Georgië", "Frans-Guyana",
+            "Guernsey", "Ghana", "Gibraltar", "Groenland", "Gambia", "Guinee", "Guadeloupe",
+            "Equatoriaal-Guinea", "Griekenland", "Guatemala", "Guam", "Guinee-Bissau", "Guyana",
+            "Hongkong SAR van China", "Honduras", "Kroatië", "Haïti", "Hongarije", "Indonesië",
+            "Ierland", "Israël", "Isle of Man", "India", "Brits Indische Oceaanterritorium", "Irak",
+            "Iran", "IJsland", "Italië", "Jersey", "Jamaica", "Jordanië", "Japan", "Kenia",
+            "Kirgizië", "Cambodja", "Kiribati", "Comoren", "Saint Kitts en Nevis", "Noord-Korea",
+            "Zuid-Korea", "Koeweit", "Kaaimaneilanden", "Kazachstan", "Laos", "Libanon",
+            "Saint Lucia", "Liechtenstein", "Sri Lanka", "Liberia", "Lesotho", "Litouwen",
+            "Luxemburg", "Letland", "Libië", "Marokko", "Monaco", "Moldavië", "Montenegro",
+            "Madagaskar", "Marshalleilanden", "Noord-Macedonië", "Mali", "Myanmar (Birma)",
+            "Mongolië", "Macau SAR van China", "Noordelijke Marianen", "Martinique", "Mauritanië",
+            "Montserrat", "Malta", "Mauritius", "Maldiven", "Malawi", "Mexico", "Maleisië",
+            "Mozambique", "Namibië", "Nieuw-Caledonië", "Niger", "Norfolk", "Nigeria", "Nicaragua",
+            "Nederland", "Noorwegen", "Nepal", "Nauru", "Niue", "Nieuw-Zeeland", "Oman", "Panama",
+            "Peru", "Frans-Polynesië", "Papoea-Nieuw-Guinea", "Filipijnen", "Pakistan", "Polen",
+            "Saint-Pierre en Miquelon", "Puerto Rico", "Palestijnse gebieden", "Portugal", "Palau",
+            "Paraguay", "Qatar", "Réunion", "Roemenië", "Servië", "Rusland", "Rwanda",
+            "Saoedi-Arabië", "Salomonseilanden", "Seychellen", "Soedan", "Zweden", "Singapore",
+            "Sint-Helena", "Slovenië", "Spitsbergen en Jan Mayen", "Slowakije", "Sierra Leone",
+            "San Marino", "Senegal", "Somalië", "Suriname", "Zuid-Soedan", "Sao Tomé en Principe",
+            "El Salvador", "Sint-Maarten", "Syrië", "Eswatini", "Turks- en Caicoseilanden",
+            "Tsjaad", "Togo", "Thailand", "Tadzjikistan", "Tokelau", "Oost-Timor", "Turkmenistan",
+            "Tunesië", "Tonga", "Turkije", "Trinidad en Tobago", "Tuvalu", "Taiwan", "Tanzania",
+            "Oekraïne", "Oeganda", "Uruguay", "Oezbekistan", "Vaticaanstad",
+            "Saint Vincent en de Grenadines", "Venezuela", "Britse Maagdeneilanden",
+            "Amerikaanse Maagdeneilanden", "Vietnam", "Vanuatu", "Wallis en Futuna", "Samoa", "",
+            "", "", "", "", "", "Jemen", "Mayotte", "Zuid-Afrika", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            237, 38, 14, 6, 236, 0, 10, 8, 2, 12, 7, 9, 11, 4, 174, 15, 13, 1, 16, 28, 20, 19, 22,
+            35, 34, 18, 36, 24, 25, 33, 27, 39, 30, 17, 26, 29, 21, 23, 44, 48, 40, 47, 246, 108,
+            49, 41, 116, 42, 45, 50, 51, 115, 52, 43, 53, 224, 58, 55, 54, 56, 57, 3, 59, 64, 60,
+            63, 210, 66, 71, 69, 68, 146, 67, 72, 75, 235, 81, 77, 73, 87, 78, 79, 83, 76, 88, 84,
+            62, 82, 86, 85, 89, 90, 94, 92, 122, 91, 93, 98, 95, 106, 105, 97, 31, 103, 104, 96,
+            107, 112, 109, 113, 110, 118, 119, 37, 120, 46, 190, 164, 243, 121, 114, 117, 123, 126,
+            191, 129, 212, 127, 124, 130, 131, 125, 128, 139, 148, 147, 150, 133, 140, 165, 137,
+            153, 149, 132, 166, 141, 142, 151, 138, 143, 135, 134, 145, 136, 152, 154, 159, 161,
+            168, 162, 158, 156, 167, 157, 155, 163, 160, 172, 178, 181, 74, 179, 70, 175, 182, 193,
+            184, 177, 183, 176, 180, 185, 186, 187, 200, 188, 189, 198, 194, 201, 208, 245, 203,
+            204, 206, 211, 207, 202, 196, 199, 209, 213, 244, 197, 61, 205, 214, 65, 228, 223, 219,
+            218, 215, 220, 173, 227, 225, 221, 226, 222, 229, 216, 217, 170, 169, 231, 171, 233,
+            192, 234, 32, 5, 238, 232, 239, 195, 80, 99, 230, 102, 100, 101, 111, 144, 242, 240,
+            241,
+        ],
+    },
+    LocaleNames {
+        locale: "no",
+        names: &[
+            "USA", "Canada", "Ascension", "Andorra", "De forente arabiske emirater", "Afghanistan",
+            "Antigua og Barbuda", "Anguilla", "Albania", "Armenia", "Angola", "Antarktis",
+            "Argentina", "Amerikansk Samoa", "Østerrike", "Australia", "Aruba", "Åland",
+            "Aserbajdsjan", "Bosnia-Hercegovina", "Barbados", "Bangladesh", "Belgia",
+            "Burkina Faso", "Bulgaria", "Bahrain", "Burundi", "Benin", "Bermuda", "Brunei",
+            "Bolivia", "Karibisk Nederland", "Brasil", "Bahamas", "Bhutan", "Botswana", "Belarus",
+            "Belize", "Kokosøyene", "Kongo", "Den sentralafrikanske republikk", "Kongo-Brazzaville",
+            "Sveits", "Elfenbenskysten", "Cookøyene", "Chile", "Kamerun", "Kina", "Colombia",
+            "Costa Rica", "Cuba", "Kapp Verde", "Curaçao", "Christmasøya", "Kypros", "Tsjekkia",
+            "Tyskland", "Djibouti", "Danmark", "Dominica", "Den dominikanske republikk", "Algerie",
+            "Ecuador", "Estland", "Egypt", "Eritrea", "Spania", "Etiopia", "Finland", "Fiji",
+            "Falklandsøyene", "Mikronesiaføderasjonen", "Færøyene", "Frankrike", "Gabon",
+            "Storbritannia", "Grenada", "Georgia", "Fransk Guyana", "Guernsey", "Ghana",
+            "Gibraltar", "Grønland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorial-Guinea",
+            "Hellas", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong SAR Kina",
+            "Honduras", "Kroatia", "Haiti", "Ungarn", "Indonesia", "Irland", "Israel", "Man",
+            "India", "Det britiske territoriet i Indiahavet", "Irak", "Iran", "Island", "Italia",
+            "Jersey", "Jamaica", "Jordan", "Japan", "Kenya", "Kirgisistan", "Kambodsja", "Kiribati",
+            "Komorene", "Saint Kitts og Nevis", "Nord-Korea", "Sør-Korea", "Kuwait", "Caymanøyene",
+            "Kasakhstan", "Laos", "Libanon", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Litauen", "Luxemburg", "Latvia", "Libya", "Marokko", "Monaco", "Moldova",
+            "Montenegro", "Madagaskar", "Marshalløyene", "Nord-Makedonia", "Mali",
+            "Myanmar (Burma)", "Mongolia", "Macao SAR Kina", "Nord-Marianene", "Martinique",
+            "Mauritania", "Montserrat", "Malta", "Mauritius", "Maldivene", "Malawi", "Mexico",
+            "Malaysia", "Mosambik", "Namibia", "Ny-Caledonia", "Niger", "Norfolkøya", "Nigeria",
+            "Nicaragua", "Nederland", "Norge", "Nepal", "Nauru", "Niue", "New Zealand", "Oman",
+            "Panama", "Peru", "Fransk Polynesia", "Papua Ny-Guinea", "Filippinene", "Pakistan",
+            "Polen", "Saint-Pierre-et-Miquelon", "Puerto Rico", "Det palestinske området",
+            "Portugal", "Palau", "Paraguay", "Qatar", "Réunion", "Romania", "Serbia", "Russland",
+            "Rwanda", "Saudi-Arabia", "Salomonøyene", "Seychellene", "Sudan", "Sverige",
+            "Singapore", "St. Helena", "Slovenia", "Svalbard og Jan Mayen", "Slovakia",
+            "Sierra Leone", "San Marino", "Senegal", "Somalia", "Surinam", "Sør-Sudan",
+            "São Tomé og Príncipe", "El Salvador", "Sint Maarten", "Syria", "Eswatini",
+            "Turks- og Caicosøyene", "Tsjad", "Togo", "Thailand", "Tadsjikistan", "Tokelau",
+            "Øst-Timor", "Turkmenistan", "Tunisia", "Tonga", "Tyrkia", "Trinidad og Tobago",
+            "Tuvalu", "Taiwan", "Tanzania", "Ukraina", "Uganda", "Uruguay", "Usbekistan",
+            "Vatikanstaten", "St. Vincent og Grenadinene", "Venezuela", "De britiske jomfruøyene",
+            "De amerikanske jomfruøyene", "Vietnam", "Vanuatu", "Wallis og Futuna", "Samoa", "", "",
+            "", "", "", "", "Jemen", "Mayotte", "Sør-Afrika", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            235, 33, 12, 4, 45, 0, 8, 6, 1, 10, 5, 7, 9, 3, 245, 14, 11, 246, 13, 26, 18, 17, 20,
+            31, 30, 16, 32, 22, 23, 29, 25, 108, 28, 15, 24, 27, 19, 21, 114, 116, 47, 117, 208, 56,
+            38, 35, 106, 111, 37, 39, 40, 107, 41, 36, 120, 223, 229, 50, 42, 51, 46, 2, 52, 58, 53,
+            57, 199, 60, 64, 62, 61, 145, 68, 65, 69, 204, 75, 71, 66, 80, 72, 73, 76, 70, 81, 77,
+            54, 85, 79, 78, 82, 83, 87, 86, 118, 84, 232, 89, 96, 98, 137, 88, 48, 94, 95, 97, 99,
+            103, 100, 104, 101, 110, 112, 105, 113, 115, 183, 162, 212, 119, 34, 109, 121, 124, 202,
+            127, 200, 125, 123, 128, 129, 122, 126, 138, 147, 146, 149, 131, 139, 163, 135, 152,
+            148, 130, 164, 140, 141, 150, 136, 142, 134, 132, 144, 133, 151, 153, 167, 159, 165,
+            160, 158, 155, 166, 156, 154, 161, 157, 168, 171, 174, 67, 172, 63, 169, 175, 184, 177,
+            49, 176, 170, 173, 178, 179, 180, 191, 181, 182, 189, 185, 192, 205, 209, 194, 201, 197,
+            207, 196, 193, 187, 190, 198, 206, 213, 188, 55, 195, 210, 59, 226, 222, 218, 217, 214,
+            219, 244, 225, 224, 220, 228, 221, 227, 215, 216, 231, 230, 234, 236, 238, 203, 239, 44,
+            43, 240, 237, 241, 186, 74, 90, 233, 93, 91, 92, 102, 143, 211, 242, 243,
+        ],
+    },
+    LocaleNames {
+        locale: "pl",
+        names: &[
+            "Stany Zjednoczone", "Kanada", "Wyspa Wniebowstąpienia", "Andora",
+            "Zjednoczone Emiraty Arabskie", "Afganistan", "Antigua i Barbuda", "Anguilla",
+            "Albania", "Armenia", "Angola", "Antarktyda", "Argentyna", "Samoa Amerykańskie",
+            "Austria", "Australia", "Aruba", "Wyspy Alandzkie", "Azerbejdżan",
+            "Bośnia i Hercegowina", "Barbados", "Bangladesz", "Belgia", "Burkina Faso", "Bułgaria",
+            "Bahrajn", "Burundi", "Benin", "Bermudy", "Brunei", "Boliwia", "Niderlandy Karaibskie",
+            "Brazylia", "Bahamy", "Bhutan", "Botswana", "Białoruś", "Belize", "Wyspy Kokosowe",
+            "Demokratyczna Republika Konga", "Republika Środkowoafrykańska", "Kongo", "Szwajcaria",
+            "Côte d’Ivoire", "Wyspy Cooka", "Chile", "Kamerun", "Chiny", "Kolumbia", "Kostaryka",
+            "Kuba", "Republika Zielonego Przylądka", "Curaçao", "Wyspa Bożego Narodzenia", "Cypr",
+            "Czechy", "Niemcy", "Dżibuti", "Dania", "Dominika", "Dominikana", "Algieria", "Ekwador",
+            "Estonia", "Egipt", "Erytrea", "Hiszpania", "Etiopia", "Finlandia", "Fidżi",
+            "Falklandy", "Mikronezja", "Wyspy Owcze", "Francja", "Gabon", "Wielka Brytania",
+            "Grenada", "Gruzja", "Gujana Francuska", "Guernsey", "Ghana", "Gibraltar", "Grenlandia",
+            "Gambia", "Gwinea", "Gwadelupa", "Gwinea Równikowa", "Grecja", "Gwatemala", "Guam",
+            "Gwinea Bissau", "Gujana", "SRA Hongkong (Chiny)", "Honduras", "Chorwacja", "Haiti",
+            "Węgry", "Indonezja", "Irlandia", "Izrael", "Wyspa Man", "Indie",
+            "Brytyjskie Terytorium Oceanu Indyjskiego", "Irak", "Iran", "Islandia", "Włochy",
+            "Jersey", "Jamajka", "Jordania", "Japonia", "Kenia", "Kirgistan", "Kambodża",
+            "Kiribati", "Komory", "Saint Kitts i Nevis", "Korea Północna", "Korea Południowa",
+            "Kuwejt", "Kajmany", "Kazachstan", "Laos", "Liban", "Saint Lucia", "Liechtenstein",
+            "Sri Lanka", "Liberia", "Lesotho", "Litwa", "Luksemburg", "Łotwa", "Libia", "Maroko",
+            "Monako", "Mołdawia", "Czarnogóra", "Madagaskar", "Wyspy Marshalla",
+            "Macedonia Północna", "Mali", "Mjanma (Birma)", "Mongolia", "SRA Makau (Chiny)",
+            "Mariany Północne", "Martynika", "Mauretania", "Montserrat", "Malta", "Mauritius",
+            "Malediwy", "Malawi", "Meksyk", "Malezja", "Mozambik", "Namibia", "Nowa Kaledonia",
+            "Niger", "Norfolk", "Nigeria", "Nikaragua", "Holandia", "Norwegia", "Nepal", "Nauru",
+            "Niue", "Nowa Zelandia", "Oman", "Panama", "Peru", "Polinezja Francuska",
+            "Papua-Nowa Gwinea", "Filipiny", "Pakistan", "Polska", "Saint-Pierre i Miquelon",
+            "Portoryko", "Terytoria Palestyńskie", "Portugalia", "Palau", "Paragwaj", "Katar",
+            "Reunion", "Rumunia", "Serbia", "Rosja", "Rwanda", "Arabia Saudyjska", "Wyspy Salomona",
+            "Seszele", "Sudan", "Szwecja", "Singapur", "Wyspa Świętej Heleny", "Słowenia",
+            "Svalbard i Jan Mayen", "Słowacja", "Sierra Leone", "San Marino", "Senegal", "Somalia",
+            "Surinam", "Sudan Południowy", "Wyspy Świętego Tomasza i Książęca", "Salwador",
+            "Sint Maarten", "Syria", "Eswatini", "Turks i Caicos", "Czad", "Togo", "Tajlandia",
+            "Tadżykistan", "Tokelau", "Timor Wschodni", "Turkmenistan", "Tunezja", "Tonga",
+            "Turcja", "Trynidad i Tobago", "Tuvalu", "Tajwan", "Tanzania", "Ukraina", "Uganda",
+            "Urugwaj", "Uzbekistan", "Watykan", "Saint Vincent i Grenadyny", "Wenezuela",
+            "Brytyjskie Wyspy Dziewicze", "Wyspy Dziewicze Stanów Zjednoczonych", "Wietnam",
+            "Vanuatu", "Wallis i Futuna", "Samoa", "", "", "", "", "", "", "Jemen", "Majotta",
+            "Republika Południowej Afryki", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            196, 101, 235, 3, 246, 0, 7, 5, 1, 10, 4, 6, 9, 182, 13, 12, 11, 236, 14, 26, 18, 17,
+            19, 33, 32, 16, 34, 21, 22, 29, 25, 148, 28, 15, 23, 27, 24, 20, 239, 45, 170, 109, 202,
+            38, 237, 35, 100, 36, 107, 112, 113, 171, 39, 232, 40, 43, 149, 48, 44, 46, 47, 2, 50,
+            52, 49, 51, 79, 54, 58, 56, 55, 138, 241, 59, 60, 229, 66, 68, 72, 70, 62, 63, 67, 61,
+            75, 73, 77, 65, 74, 69, 76, 71, 193, 81, 37, 78, 228, 83, 90, 92, 233, 82, 30, 88, 89,
+            91, 231, 96, 93, 97, 94, 104, 105, 99, 106, 108, 176, 111, 110, 114, 98, 103, 115, 117,
+            177, 120, 195, 118, 116, 121, 122, 123, 119, 133, 141, 140, 42, 125, 240, 124, 130, 139,
+            142, 194, 132, 134, 135, 143, 131, 136, 128, 127, 137, 129, 144, 145, 156, 150, 154,
+            151, 152, 80, 155, 147, 146, 153, 157, 158, 161, 164, 165, 162, 57, 159, 166, 179, 167,
+            208, 168, 160, 163, 102, 172, 174, 185, 173, 175, 8, 242, 186, 197, 203, 188, 234, 191,
+            200, 190, 187, 183, 184, 192, 199, 198, 243, 180, 189, 201, 53, 217, 41, 210, 205, 204,
+            211, 209, 216, 214, 212, 215, 213, 218, 206, 207, 220, 219, 222, 223, 226, 178, 227, 31,
+            238, 230, 224, 225, 181, 64, 84, 221, 87, 85, 86, 95, 126, 169, 244, 245,
+        ],
+    },
+    LocaleNames {
+        locale: "pt",
+        names: &[
+            "Estados Unidos", "Canadá", "Ilha de Ascensão", "Andorra", "Emirados Árabes Unidos",
+            "Afeganistão", "Antígua e Barbuda", "Anguila", "Albânia", "Armênia", "Angola",
+            "Antártida", "Argentina", "Samoa Americana", "Áustria", "Austrália", "Aruba",
+            "Ilhas Aland", "Azerbaijão", "Bósnia e Herzegovina", "Barbados", "Bangladesh",
+            "Bélgica", "Burquina Faso", "Bulgária", "Barein", "Burundi", "Benin", "Bermudas",
+            "Brunei", "Bolívia", "Países Baixos Caribenhos", "Brasil", "Bahamas", "Butão",
+            "Botsuana", "Bielorrússia", "Belize", "Ilhas Cocos (Keeling)", "Congo - Kinshasa",
+            "República Centro-Africana", "República do Congo", "Suíça", "Costa do Marfim",
+            "Ilhas Cook", "Chile", "Camarões", "China", "Colômbia", "Costa Rica", "Cuba",
+            "Cabo Verde", "Curaçao", "Ilha Christmas", "Chipre", "Tchéquia", "Alemanha", "Djibuti",
+            "Dinamarca", "Dominica", "República Dominicana", "Argélia", "Equador", "Estônia",
+            "Egito", "Eritreia", "Espanha", "Etiópia", "Finlândia", "Fiji", "Ilhas Malvinas",
+            "Micronésia", "Ilhas Faroé", "França", "Gabão", "Reino Unido", "Granada", "Geórgia",
+            "Guiana Francesa", "Guernsey", "Gana", "Gibraltar", "Groenlândia", "Gâmbia", "Guiné",
+            "Guadalupe", "Guiné Equatorial", "Grécia", "Guatemala", "Guam", "Guiné-Bissau",
+            "Guiana", "Hong Kong, RAE da China", "Honduras", "Croácia", "Haiti", "Hungria",
+            "Indonésia", "Irlanda", "Israel", "Ilha de Man", "Índia",
+            "Território Britânico do Oceano Índico", "Iraque", "Irã", "Islândia", "Itália",
+            "Jersey", "Jamaica", "Jordânia", "Japão", "Quênia", "Quirguistão", "Camboja",
+            "Quiribati", "Comores", "São Cristóvão e Névis", "Coreia do Norte", "Coreia do Sul",
+            "Kuwait", "Ilhas Cayman", "Cazaquistão", "Laos", "Líbano", "Santa Lúcia",
+            "Liechtenstein", "Sri Lanka", "Libéria", "Lesoto", "Lituânia", "Luxemburgo", "Letônia",
+            "Líbia", "Marrocos", "Mônaco", "Moldávia", "Montenegro", "Madagascar", "Ilhas Marshall",
+            "Macedônia do Norte", "Mali", "Mianmar (Birmânia)", "Mongólia", "Macau, RAE da China",
+            "Ilhas Marianas do Norte", "Martinica", "Mauritânia", "Montserrat", "Malta", "Maurício",
+            "Maldivas", "Malaui", "México", "Malásia", "Moçambique", "Namíbia", "Nova Caledônia",
+            "Níger", "Ilha Norfolk", "Nigéria", "Nicarágua", "Países Baixos", "Noruega", "Nepal",
+            "Nauru", "Niue", "Nova Zelândia", "Omã", "Panamá", "Peru", "Polinésia Francesa",
+            "Papua-Nova Guiné", "Filipinas", "Paquistão", "Polônia", "São Pedro e Miquelão",
+            "Porto Rico", "Territórios palestinos", "Portugal", "Palau", "Paraguai", "Catar",
+            "Reunião", "Romênia", "Sérvia", "Rússia", "Ruanda", "Arábia Saudita", "Ilhas Salomão",
+            "Seicheles", "Sudão", "Suécia", "Singapura", "Santa Helena", "Eslovênia",
+            "Svalbard e Jan Mayen", "Eslováquia", "Serra Leoa", "San Marino", "Senegal", "Somália",
+            "Suriname", "Sudão do Sul", "São Tomé e Príncipe", "El Salvador", "Sint Maarten",
+            "Síria", "Essuatíni", "Ilhas Turcas e Caicos", "Chade", "Togo", "Tailândia",
+            "Tadjiquistão", "Tokelau", "Timor-Leste", "Turcomenistão", "Tunísia", "Tonga",
+            "Turquia", "Trinidad e Tobago", "Tuvalu", "Taiwan", "Tanzânia", "Ucrânia", "Uganda",
+            "Uruguai", "Uzbequistão", "Cidade do Vaticano", "São Vicente e Granadinas", "Venezuela",
+            "Ilhas Virgens Britânicas", "Ilhas Virgens Americanas", "Vietnã", "Vanuatu",
+            "Wallis e Futuna", "Samoa", "", "", "", "", "", "", "Iêmen", "Mayotte", "África do Sul",
+            "Zâmbia", "Zimbábue",
+        ],
+        sort_keys: &[
+            68, 38, 99, 4, 61, 0, 8, 6, 2, 12, 5, 7, 11, 197, 15, 14, 13, 102, 16, 27, 19, 18, 21,
+            32, 31, 20, 33, 23, 24, 30, 26, 174, 29, 17, 34, 28, 25, 22, 104, 48, 189, 190, 217, 51,
+            105, 42, 36, 43, 46, 52, 54, 35, 55, 98, 44, 224, 3, 57, 56, 58, 191, 10, 62, 69, 59,
+            63, 66, 70, 73, 71, 107, 155, 106, 74, 75, 188, 81, 78, 89, 87, 77, 79, 83, 76, 90, 84,
+            91, 82, 86, 85, 92, 88, 95, 94, 53, 93, 96, 115, 122, 124, 100, 114, 225, 121, 120, 123,
+            125, 128, 126, 129, 127, 185, 186, 37, 187, 47, 201, 49, 50, 130, 103, 40, 131, 134,
+            200, 137, 213, 135, 132, 138, 139, 133, 136, 148, 158, 157, 160, 142, 109, 141, 146,
+            154, 159, 140, 108, 149, 151, 161, 147, 150, 145, 144, 153, 143, 156, 162, 170, 166,
+            101, 167, 165, 173, 169, 164, 163, 168, 171, 172, 176, 180, 181, 177, 72, 178, 182, 202,
+            183, 226, 184, 175, 179, 39, 192, 193, 208, 195, 194, 9, 110, 205, 214, 216, 209, 199,
+            65, 219, 64, 207, 198, 206, 212, 218, 215, 203, 60, 210, 211, 67, 111, 41, 228, 221,
+            220, 229, 227, 233, 232, 230, 234, 231, 235, 222, 223, 236, 237, 239, 240, 45, 204, 242,
+            113, 112, 243, 241, 244, 196, 80, 116, 238, 119, 117, 118, 97, 152, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "pt-PT",
+        names: &[
+            "Estados Unidos", "Canadá", "Ilha de Ascensão", "Andorra", "Emirados Árabes Unidos",
+            "Afeganistão", "Antígua e Barbuda", "Anguila", "Albânia", "Arménia", "Angola",
+            "Antártida", "Argentina", "Samoa Americana", "Áustria", "Austrália", "Aruba", "Alanda",
+            "Azerbaijão", "Bósnia e Herzegovina", "Barbados", "Bangladeche", "Bélgica",
+            "Burquina Faso", "Bulgária", "Barém", "Burundi", "Benim", "Bermudas", "Brunei",
+            "Bolívia", "Países Baixos Caribenhos", "Brasil", "Baamas", "Butão", "Botsuana",
+            "Bielorrússia", "Belize", "Ilhas dos Cocos (Keeling)", "Congo-Kinshasa",
+            "República Centro-Africana", "Congo-Brazzaville", "Suíça",
+            "Côte d’Ivoire (Costa do Marfim)", "Ilhas Cook", "Chile", "Camarões", "China",
+            "Colômbia", "Costa Rica", "Cuba", "Cabo Verde", "Curaçau", "Ilha do Natal", "Chipre",
+            "Chéquia", "Alemanha", "Jibuti", "Dinamarca", "Domínica", "República Dominicana",
+            "Argélia", "Equador", "Estónia", "Egito", "Eritreia", "Espanha", "Etiópia", "Finlândia",
+            "Fiji", "Ilhas Falkland", "Micronésia", "Ilhas Faroé", "França", "Gabão", "Reino Unido",
+            "Granada", "Geórgia", "Guiana Francesa", "Guernesey", "Gana", "Gibraltar",
+            "Gronelândia", "Gâmbia", "Guiné", "Guadalupe", "Guiné Equatorial", "Grécia",
+            "Guatemala", "Guame", "Guiné-Bissau", "Guiana", "Hong Kong, RAE da China", "Honduras",
+            "Croácia", "Haiti", "Hungria", "Indonésia", "Irlanda", "Israel", "Ilha de Man", "Índia",
+            "Território Britânico do Oceano Índico", "Iraque", "Irão", "Islândia", "Itália",
+            "Jersey", "Jamaica", "Jordânia", "Japão", "Quénia", "Quirguistão", "Camboja",
+            "Quiribáti", "Comores", "São Cristóvão e Neves", "Coreia do Norte", "Coreia do Sul",
+            "Koweit", "Ilhas Caimão", "Cazaquistão", "Laos", "Líbano", "Santa Lúcia",
+            "Listenstaine", "Sri Lanca", "Libéria", "Lesoto", "Lituânia", "Luxemburgo", "Letónia",
+            "Líbia", "Marrocos", "Mónaco", "Moldávia", "Montenegro", "Madagáscar", "Ilhas Marshall",
+            "Macedónia do Norte", "Mali", "Mianmar (Birmânia)", "Mongólia", "Macau, RAE da China",
+            "Ilhas Marianas do Norte", "Martinica", "Mauritânia", "Monserrate", "Malta", "Maurícia",
+            "Maldivas", "Maláui", "México", "Malásia", "Moçambique", "Namíbia", "Nova Caledónia",
+            "Níger", "Ilha Norfolk", "Nigéria", "Nicarágua", "Países Baixos", "Noruega", "Nepal",
+            "Nauru", "Niuê", "Nova Zelândia", "Omã", "Panamá", "Peru", "Polinésia Francesa",
+            "Papua-Nova Guiné", "Filipinas", "Paquistão", "Polónia", "São Pedro e Miquelão",
+            "Porto Rico", "Territórios palestinianos", "Portugal", "Palau", "Paraguai", "Catar",
+            "Reunião", "Roménia", "Sérvia", "Rússia", "Ruanda", "Arábia Saudita", "Ilhas Salomão",
+            "Seicheles", "Sudão", "Suécia", "Singapura", "Santa Helena", "Eslovénia",
+            "Svalbard e Jan Mayen", "Eslováquia", "Serra Leoa", "São Marinho", "Senegal", "Somália",
+            "Suriname", "Sudão do Sul", "São Tomé e Príncipe", "Salvador",
+            "São Martinho (Sint Maarten)", "Síria", "Essuatíni", "Ilhas Turcas e Caicos", "Chade",
+            "Togo", "Tailândia", "Tajiquistão", "Toquelau", "Timor-Leste", "Turquemenistão",
+            "Tunísia", "Tonga", "Turquia", "Trindade e Tobago", "Tuvalu", "Taiwan", "Tanzânia",
+            "Ucrânia", "Uganda", "Uruguai", "Usbequistão", "Cidade do Vaticano",
+            "São Vicente e Granadinas", "Venezuela", "Ilhas Virgens Britânicas",
+            "Ilhas Virgens dos EUA", "Vietname", "Vanuatu", "Wallis e Futuna", "Samoa", "", "", "",
+            "", "", "", "Iémen", "Maiote", "África do Sul", "Zâmbia", "Zimbabué",
+        ],
+        sort_keys: &[
+            69, 39, 99, 5, 62, 0, 9, 7, 3, 13, 6, 8, 12, 198, 16, 15, 14, 2, 17, 28, 20, 19, 22, 33,
+            32, 21, 34, 24, 25, 31, 27, 175, 30, 18, 35, 29, 26, 23, 105, 51, 190, 50, 218, 55, 104,
+            44, 37, 45, 48, 54, 57, 36, 58, 101, 46, 43, 4, 129, 59, 60, 191, 11, 63, 70, 61, 64,
+            67, 71, 74, 72, 106, 156, 107, 75, 76, 189, 82, 79, 90, 88, 78, 80, 84, 77, 91, 85, 92,
+            83, 87, 86, 93, 89, 96, 95, 56, 94, 97, 115, 122, 124, 100, 114, 225, 121, 120, 123,
+            125, 128, 126, 130, 127, 186, 187, 38, 188, 49, 201, 52, 53, 131, 103, 41, 132, 135,
+            200, 138, 214, 136, 133, 139, 140, 134, 137, 150, 159, 158, 162, 143, 109, 142, 148,
+            155, 160, 141, 108, 151, 153, 161, 149, 152, 147, 146, 154, 145, 157, 163, 171, 167,
+            102, 168, 166, 174, 170, 165, 164, 169, 172, 173, 177, 181, 182, 178, 73, 179, 183, 204,
+            184, 226, 185, 176, 180, 40, 192, 193, 210, 195, 194, 10, 110, 207, 215, 217, 211, 199,
+            66, 220, 65, 209, 202, 208, 213, 219, 216, 205, 196, 203, 212, 68, 111, 42, 228, 221,
+            223, 230, 227, 233, 232, 229, 234, 231, 235, 222, 224, 236, 237, 239, 240, 47, 206, 242,
+            112, 113, 243, 241, 244, 197, 81, 116, 238, 119, 117, 118, 98, 144, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "ro",
+        names: &[
+            "Statele Unite ale Americii", "Canada", "Insula Ascension", "Andorra",
+            "Emiratele Arabe Unite", "Afganistan", "Antigua și Barbuda", "Anguilla", "Albania",
+            "Armenia", "Angola", "Antarctica", "Argentina", "Samoa Americană", "Austria",
+            "Australia", "Aruba", "Insulele Åland", "Azerbaidjan", "Bosnia și Herțegovina",
+            "Barbados", "Bangladesh", "Belgia", "Burkina Faso", "Bulgaria", "Bahrain", "Burundi",
+            "Benin", "Bermuda", "Brunei", "Bolivia", "Insulele Caraibe Olandeze", "Brazilia",
+            "Bahamas", "Bhutan", "Botswana", "Belarus", "Belize", "Insulele Cocos (Keeling)",
+            "Congo - Kinshasa", "Republica Centrafricană", "Congo - Brazzaville", "Elveția",
+            "Côte d’Ivoire", "Insulele Cook", "Chile", "Camerun", "China", "Columbia", "Costa Rica",
+            "Cuba", "Capul Verde", "Curaçao", "Insula Christmas", "Cipru", "Cehia", "Germania",
+            "Djibouti", "Danemarca", "Dominica", "Republica Dominicană", "Algeria", "Ecuador",
+            "Estonia", "Egipt", "Eritreea", "Spania", "Etiopia", "Finlanda", "Fiji",
+            "Insulele Falkland", "Micronezia", "Insulele Feroe", "Franța", "Gabon", "Regatul Unit",
+            "Grenada", "Georgia", "Guyana Franceză", "Guernsey", "Ghana", "Gibraltar", "Groenlanda",
+            "Gambia", "Guineea", "Guadelupa", "Guineea Ecuatorială", "Grecia", "Guatemala", "Guam",
+            "Guineea-Bissau", "Guyana", "R.A.S. Hong Kong, China", "Honduras", "Croația", "Haiti",
+            "Ungaria", "Indonezia", "Irlanda", "Israel", "Insula Man", "India",
+            "Teritoriul Britanic din Oceanul Indian", "Irak", "Iran", "Islanda", "Italia", "Jersey",
+            "Jamaica", "Iordania", "Japonia", "Kenya", "Kârgâzstan", "Cambodgia", "Kiribati",
+            "Comore", "Saint Kitts și Nevis", "Coreea de Nord", "Coreea de Sud", "Kuweit",
+            "Insulele Cayman", "Kazahstan", "Laos", "Liban", "Sfânta Lucia", "Liechtenstein",
+            "Sri Lanka", "Liberia", "Lesotho", "Lituania", "Luxemburg", "Letonia", "Libia", "Maroc",
+            "Monaco", "Republica Moldova", "Muntenegru", "Madagascar", "Insulele Marshall",
+            "Macedonia de Nord", "Mali", "Myanmar (Birmania)", "Mongolia", "R.A.S. Macao, China",
+            "Insulele Mariane de Nord", "Martinica", "Mauritania", "Montserrat", "Malta",
+            "Mauritius", "Maldive", "Malawi", "Mexic", "Malaysia", "Mozambic", "Namibia",
+            "Noua Caledonie", "Niger", "Insula Norfolk", "Nigeria", "Nicaragua", "Țările de Jos",
+            "Norvegia", "Nepal", "Nauru", "Niue", "Noua Zeelandă", "Oman", "Panama", "Peru",
+            "Polinezia Franceză", "Papua-Noua Guinee", "Filipine", "Pakistan", "Polonia",
+            "Saint-Pierre și Miquelon", "Puerto Rico", "Teritoriile Palestiniene", "Portugalia",
+            "Palau", "Paraguay", "Qatar", "Réunion", "România", "Serbia", "Rusia", "Rwanda",
+            "Arabia Saudită", "Insulele Solomon", "Seychelles", "Sudan", "Suedia", "Singapore",
+            "Sfânta Elena", "Slovenia", "Svalbard și Jan Mayen", "Slovacia", "Sierra Leone",
+            "San Marino", "Senegal", "Somalia", "Suriname", "Sudanul de Sud",
+            "São Tomé și Príncipe", "El Salvador", "Sint-Maarten", "Siria", "Eswatini",
+            "Insulele Turks și Caicos", "Ciad", "Togo", "Thailanda", "Tadjikistan", "Tokelau",
+            "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Turcia", "Trinidad și Tobago",
+            "Tuvalu", "Taiwan", "Tanzania", "Ucraina", "Uganda", "Uruguay", "Uzbekistan",
+            "Statul Cetății Vaticanului", "Saint Vincent și Grenadinele", "Venezuela",
+            "Insulele Virgine Britanice", "Insulele Virgine Americane", "Vietnam", "Vanuatu",
+            "Wallis și Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte",
+            "Africa de Sud", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            211, 36, 94, 4, 61, 0, 8, 6, 2, 11, 5, 7, 10, 194, 14, 13, 12, 98, 15, 27, 19, 18, 21,
+            32, 31, 17, 33, 23, 24, 30, 26, 99, 29, 16, 25, 28, 20, 22, 101, 46, 183, 45, 60, 50,
+            102, 39, 35, 40, 43, 49, 52, 37, 53, 95, 42, 38, 73, 55, 54, 56, 184, 3, 57, 63, 58, 62,
+            209, 65, 68, 66, 103, 151, 104, 69, 70, 182, 78, 72, 88, 83, 74, 75, 79, 71, 84, 80, 85,
+            77, 82, 81, 86, 87, 180, 90, 51, 89, 236, 92, 117, 119, 96, 91, 222, 115, 116, 118, 120,
+            123, 121, 114, 122, 126, 125, 34, 127, 44, 190, 47, 48, 128, 100, 124, 129, 132, 201,
+            135, 210, 133, 130, 136, 137, 131, 134, 145, 152, 185, 156, 139, 106, 138, 143, 157,
+            153, 181, 105, 146, 147, 154, 144, 148, 142, 140, 150, 141, 155, 158, 166, 162, 97, 163,
+            161, 233, 165, 160, 159, 164, 167, 168, 171, 174, 175, 172, 67, 169, 176, 192, 178, 221,
+            177, 170, 173, 179, 186, 187, 198, 188, 189, 9, 107, 199, 213, 215, 203, 200, 207, 217,
+            206, 202, 195, 197, 208, 216, 214, 196, 59, 204, 205, 64, 108, 41, 225, 223, 218, 226,
+            224, 231, 229, 227, 230, 228, 232, 219, 220, 234, 235, 238, 239, 212, 191, 241, 110,
+            109, 242, 240, 243, 193, 76, 93, 237, 113, 111, 112, 244, 149, 1, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "ru",
+        names: &[
+            "Соединенные Штаты", "Канада", "о-в Вознесения", "Андорра", "ОАЭ", "Афганистан",
+            "Антигуа и Барбуда", "Ангилья", "Албания", "Армения", "Ангола", "Антарктида",
+            "Аргентина", "Американское Самоа", "Австрия", "Австралия", "Аруба", "Аландские о-ва",
+            "Азербайджан", "Босния и Герцеговина", "Барбадос", "Бангладеш", "Бельгия",
+            "Буркина-Фасо", "Болгария", "Бахрейн", "Бурунди", "Бенин", "Бермудские о-ва", "Бруней",
+            "Боливия", "Бонэйр, Синт-Эстатиус и Саба", "Бразилия", "Багамы", "Бутан", "Ботсвана",
+            "Беларусь", "Белиз", "Кокосовые о-ва", "Конго - Киншаса",
+            "Центрально-Африканская Республика", "Конго - Браззавиль", "Швейцария", "Кот-д’Ивуар",
+            "о-ва Кука", "Чили", "Камерун", "Китай", "Колумбия", "Коста-Рика", "Куба", "Кабо-Верде",
+            "Кюрасао", "о-в Рождества", "Кипр", "Чехия", "Германия", "Джибути", "Дания", "Доминика",
+            "Доминиканская Республика", "Алжир", "Эквадор", "Эстония", "Египет", "Эритрея",
+            "Испания", "Эфиопия", "Финляндия", "Фиджи", "Фолклендские о-ва",
+            "Федеративные Штаты Микронезии", "Фарерские о-ва", "Франция", "Габон", "Великобритания",
+            "Гренада", "Грузия", "Французская Гвиана", "Гернси", "Гана", "Гибралтар", "Гренландия",
+            "Гамбия", "Гвинея", "Гваделупа", "Экваториальная Гвинея", "Греция", "Гватемала", "Гуам",
+            "Гвинея-Бисау", "Гайана", "Гонконг (САР)", "Гондурас", "Хорватия", "Гаити", "Венгрия",
+            "Индонезия", "Ирландия", "Израиль", "о-в Мэн", "Индия",
+            "Британская территория в Индийском океане", "Ирак", "Иран", "Исландия", "Италия",
+            "Джерси", "Ямайка", "Иордания", "Япония", "Кения", "Киргизия", "Камбоджа", "Кирибати",
+            "Коморы", "Сент-Китс и Невис", "КНДР", "Республика Корея", "Кувейт", "о-ва Кайман",
+            "Казахстан", "Лаос", "Ливан", "Сент-Люсия", "Лихтенштейн", "Шри-Ланка", "Либерия",
+            "Лесото", "Литва", "Люксембург", "Латвия", "Ливия", "Марокко", "Монако", "Молдова",
+            "Черногория", "Мадагаскар", "Маршалловы о-ва", "Северная Македония", "Мали",
+            "Мьянма (Бирма)", "Монголия", "Макао (САР)", "Северные Марианские о-ва", "Мартиника",
+            "Мавритания", "Монтсеррат", "Мальта", "Маврикий", "Мальдивы", "Малави", "Мексика",
+            "Малайзия", "Мозамбик", "Намибия", "Новая Каледония", "Нигер", "о-в Норфолк", "Нигерия",
+            "Никарагуа", "Нидерланды", "Норвегия", "Непал", "Науру", "Ниуэ", "Новая Зеландия",
+            "Оман", "Панама", "Перу", "Французская Полинезия", "Папуа — Новая Гвинея", "Филиппины",
+            "Пакистан", "Польша", "Сен-Пьер и Микелон", "Пуэрто-Рико", "Палестинские территории",
+            "Португалия", "Палау", "Парагвай", "Катар", "Реюньон", "Румыния", "Сербия", "Россия",
+            "Руанда", "Саудовская Аравия", "Соломоновы о-ва", "Сейшельские о-ва", "Судан", "Швеция",
+            "Сингапур", "о-в Св. Елены", "Словения", "Шпицберген и Ян-Майен", "Словакия",
+            "Сьерра-Леоне", "Сан-Марино", "Сенегал", "Сомали", "Суринам", "Южный Судан",
+            "Сан-Томе и Принсипи", "Сальвадор", "Синт-Мартен", "Сирия", "Эсватини",
+            "Тёркс и Кайкос", "Чад", "Того", "Таиланд", "Таджикистан", "Токелау", "Восточный Тимор",
+            "Туркменистан", "Тунис", "Тонга", "Турция", "Тринидад и Тобаго", "Тувалу", "Тайвань",
+            "Танзания", "Украина", "Уганда", "Уругвай", "Узбекистан", "Ватикан",
+            "Сент-Винсент и Гренадины", "Венесуэла", "Виргинские о-ва (Великобритания)",
+            "Виргинские о-ва (США)", "Вьетнам", "Вануату", "Уоллис и Футуна", "Самоа", "", "", "",
+            "", "", "", "Йемен", "Майотта", "Южно-Африканская Республика", "Замбия", "Зимбабве",
+        ],
+        sort_keys: &[
+            188, 87, 145, 9, 152, 15, 11, 7, 4, 13, 8, 10, 12, 6, 1, 0, 14, 3, 2, 28, 18, 17, 22,
+            33, 25, 19, 34, 23, 24, 32, 26, 27, 30, 16, 35, 29, 20, 21, 95, 99, 222, 98, 227, 101,
+            151, 226, 86, 93, 96, 100, 102, 83, 104, 148, 90, 225, 54, 66, 64, 67, 68, 5, 231, 235,
+            69, 233, 80, 236, 216, 214, 217, 213, 212, 218, 45, 38, 59, 62, 219, 55, 49, 56, 60, 48,
+            52, 50, 232, 61, 51, 63, 53, 47, 58, 57, 221, 46, 39, 74, 78, 72, 146, 73, 31, 76, 77,
+            79, 81, 65, 239, 75, 240, 89, 91, 85, 92, 97, 180, 94, 164, 103, 150, 84, 105, 109, 181,
+            112, 230, 108, 107, 111, 113, 106, 110, 124, 130, 129, 224, 116, 126, 174, 121, 133,
+            131, 118, 175, 125, 115, 132, 123, 114, 122, 119, 127, 120, 128, 134, 143, 137, 147,
+            138, 140, 139, 144, 136, 135, 141, 142, 153, 157, 160, 220, 158, 215, 154, 161, 177,
+            163, 156, 162, 155, 159, 88, 165, 168, 182, 166, 167, 173, 189, 176, 191, 228, 183, 149,
+            187, 229, 186, 193, 171, 178, 190, 192, 238, 172, 169, 184, 185, 234, 198, 223, 199,
+            195, 194, 200, 43, 205, 204, 201, 206, 202, 203, 196, 197, 209, 207, 211, 208, 37, 179,
+            40, 41, 42, 44, 36, 210, 170, 241, 242, 246, 245, 243, 244, 82, 117, 237, 70, 71,
+        ],
+    },
+    LocaleNames {
+        locale: "sk",
+        names: &[
+            "Spojené štáty", "Kanada", "Ascension", "Andorra", "Spojené arabské emiráty",
+            "Afganistan", "Antigua a Barbuda", "Anguilla", "Albánsko", "Arménsko", "Angola",
+            "Antarktída", "Argentína", "Americká Samoa", "Rakúsko", "Austrália", "Aruba", "Alandy",
+            "Azerbajdžan", "Bosna a Hercegovina", "Barbados", "Bangladéš", "Belgicko",
+            "Burkina Faso", "Bulharsko", "Bahrajn", "Burundi", "Benin", "Bermudy", "Brunej",
+            "Bolívia", "Karibské Holandsko", "Brazília", "Bahamy", "Bhután", "Botswana",
+            "Bielorusko", "Belize", "Kokosové ostrovy", "Konžská demokratická republika",
+            "Stredoafrická republika", "Konžská republika", "Švajčiarsko", "Pobrežie Slonoviny",
+            "Cookove ostrovy", "Čile", "Kamerun", "Čína", "Kolumbia", "Kostarika", "Kuba",
+            "Kapverdy", "Curaçao", "Vianočný ostrov", "Cyprus", "Česko", "Nemecko", "Džibutsko",
+            "Dánsko", "Dominika", "Dominikánska republika", "Alžírsko", "Ekvádor", "Estónsko",
+            "Egypt", "Eritrea", "Španielsko", "Etiópia", "Fínsko", "Fidži", "Falklandy",
+            "Mikronézia", "Faerské ostrovy", "Francúzsko", "Gabon", "Spojené kráľovstvo", "Grenada",
+            "Gruzínsko", "Francúzska Guyana", "Guernsey", "Ghana", "Gibraltár", "Grónsko", "Gambia",
+            "Guinea", "Guadeloupe", "Rovníková Guinea", "Grécko", "Guatemala", "Guam",
+            "Guinea-Bissau", "Guyana", "Hongkong – OAO Číny", "Honduras", "Chorvátsko", "Haiti",
+            "Maďarsko", "Indonézia", "Írsko", "Izrael", "Ostrov Man", "India",
+            "Britské indickooceánske územie", "Irak", "Irán", "Island", "Taliansko", "Jersey",
+            "Jamajka", "Jordánsko", "Japonsko", "Keňa", "Kirgizsko", "Kambodža", "Kiribati",
+            "Komory", "Svätý Krištof a Nevis", "Severná Kórea", "Južná Kórea", "Kuvajt",
+            "Kajmanie ostrovy", "Kazachstan", "Laos", "Libanon", "Svätá Lucia", "Lichtenštajnsko",
+            "Srí Lanka", "Libéria", "Lesotho", "Litva", "Luxembursko", "Lotyšsko", "Líbya",
+            "Maroko", "Monako", "Moldavsko", "Čierna Hora", "Madagaskar", "Marshallove ostrovy",
+            "Severné Macedónsko", "Mali", "Mjanmarsko", "Mongolsko", "Macao – OAO Číny",
+            "Severné Mariány", "Martinik", "Mauritánia", "Montserrat", "Malta", "Maurícius",
+            "Maldivy", "Malawi", "Mexiko", "Malajzia", "Mozambik", "Namíbia", "Nová Kaledónia",
+            "Niger", "Norfolk", "Nigéria", "Nikaragua", "Holandsko", "Nórsko", "Nepál", "Nauru",
+            "Niue", "Nový Zéland", "Omán", "Panama", "Peru", "Francúzska Polynézia",
+            "Papua-Nová Guinea", "Filipíny", "Pakistan", "Poľsko", "Saint Pierre a Miquelon",
+            "Portoriko", "Palestínske územia", "Portugalsko", "Palau", "Paraguaj", "Katar",
+            "Réunion", "Rumunsko", "Srbsko", "Rusko", "Rwanda", "Saudská Arábia",
+            "Šalamúnove ostrovy", "Seychely", "Sudán", "Švédsko", "Singapur", "Svätá Helena",
+            "Slovinsko", "Svalbard a Jan Mayen", "Slovensko", "Sierra Leone", "San Maríno",
+            "Senegal", "Somálsko", "Surinam", "Južný Sudán", "Svätý Tomáš a Princov ostrov",
+            "Salvádor", "Svätý Martin (hol.)", "Sýria", "Eswatini", "Turks a Caicos", "Čad", "Togo",
+            "Thajsko", "Tadžikistan", "Tokelau", "Východný Timor", "Turkménsko", "Tunisko", "Tonga",
+            "Turecko", "Trinidad a Tobago", "Tuvalu", "Taiwan", "Tanzánia", "Ukrajina", "Uganda",
+            "Uruguaj", "Uzbekistan", "Vatikán", "Svätý Vincent a Grenadíny", "Venezuela",
+            "Britské Panenské ostrovy", "Americké Panenské ostrovy", "Vietnam", "Vanuatu",
+            "Wallis a Futuna", "Samoa", "", "", "", "", "", "", "Jemen", "Mayotte", "Južná Afrika",
+            "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            201, 106, 14, 6, 199, 0, 10, 8, 2, 12, 7, 9, 11, 4, 178, 15, 13, 1, 16, 28, 20, 19, 21,
+            35, 34, 18, 36, 23, 24, 33, 27, 108, 30, 17, 25, 29, 26, 22, 114, 117, 204, 118, 217,
+            174, 37, 43, 105, 44, 115, 119, 120, 107, 38, 241, 39, 41, 155, 48, 45, 46, 47, 3, 50,
+            52, 49, 51, 216, 54, 59, 57, 56, 146, 55, 62, 63, 200, 69, 71, 60, 75, 65, 66, 70, 64,
+            76, 72, 180, 68, 74, 73, 77, 78, 82, 81, 83, 79, 133, 85, 92, 94, 166, 84, 31, 90, 91,
+            93, 221, 98, 95, 99, 96, 111, 112, 104, 113, 116, 210, 190, 101, 121, 103, 110, 122,
+            124, 209, 127, 203, 125, 123, 128, 130, 129, 126, 139, 149, 148, 42, 132, 140, 191, 137,
+            147, 150, 131, 192, 141, 143, 151, 138, 142, 136, 135, 145, 134, 152, 153, 163, 157,
+            161, 158, 159, 80, 162, 156, 154, 160, 164, 165, 170, 173, 61, 171, 58, 167, 175, 184,
+            176, 169, 177, 168, 172, 109, 179, 181, 202, 182, 183, 188, 215, 193, 205, 218, 195,
+            208, 197, 207, 196, 194, 187, 189, 198, 206, 102, 212, 185, 211, 214, 53, 231, 40, 224,
+            223, 219, 225, 243, 230, 228, 226, 229, 227, 232, 220, 222, 234, 233, 236, 237, 239,
+            213, 240, 32, 5, 242, 238, 244, 186, 67, 86, 235, 89, 87, 88, 97, 144, 100, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "sv",
+        names: &[
+            "USA", "Kanada", "Ascension", "Andorra", "Förenade Arabemiraten", "Afghanistan",
+            "Antigua och Barbuda", "Anguilla", "Albanien", "Armenien", "Angola", "Antarktis",
+            "Argentina", "Amerikanska Samoa", "Österrike", "Australien", "Aruba", "Åland",
+            "Azerbajdzjan", "Bosnien och Hercegovina", "Barbados", "Bangladesh", "Belgien",
+            "Burkina Faso", "Bulgarien", "Bahrain", "Burundi", "Benin", "Bermuda", "Brunei",
+            "Bolivia", "Karibiska Nederländerna", "Brasilien", "Bahamas", "Bhutan", "Botswana",
+            "Belarus", "Belize", "Kokosöarna", "Kongo-Kinshasa", "Centralafrikanska republiken",
+            "Kongo-Brazzaville", "Schweiz", "Elfenbenskusten", "Cooköarna", "Chile", "Kamerun",
+            "Kina", "Colombia", "Costa Rica", "Kuba", "Kap Verde", "Curaçao", "Julön", "Cypern",
+            "Tjeckien", "Tyskland", "Djibouti", "Danmark", "Dominica", "Dominikanska republiken",
+            "Algeriet", "Ecuador", "Estland", "Egypten", "Eritrea", "Spanien", "Etiopien",
+            "Finland", "Fiji", "Falklandsöarna", "Mikronesien", "Färöarna", "Frankrike", "Gabon",
+            "Storbritannien", "Grenada", "Georgien", "Franska Guyana", "Guernsey", "Ghana",
+            "Gibraltar", "Grönland", "Gambia", "Guinea", "Guadeloupe", "Ekvatorialguinea",
+            "Grekland", "Guatemala", "Guam", "Guinea-Bissau", "Guyana", "Hongkong SAR", "Honduras",
+            "Kroatien", "Haiti", "Ungern", "Indonesien", "Irland", "Israel", "Isle of Man",
+            "Indien", "Brittiska territoriet i Indiska oceanen", "Irak", "Iran", "Island",
+            "Italien", "Jersey", "Jamaica", "Jordanien", "Japan", "Kenya", "Kirgizistan",
+            "Kambodja", "Kiribati", "Komorerna", "S:t Kitts och Nevis", "Nordkorea", "Sydkorea",
+            "Kuwait", "Caymanöarna", "Kazakstan", "Laos", "Libanon", "S:t Lucia", "Liechtenstein",
+            "Sri Lanka", "Liberia", "Lesotho", "Litauen", "Luxemburg", "Lettland", "Libyen",
+            "Marocko", "Monaco", "Moldavien", "Montenegro", "Madagaskar", "Marshallöarna",
+            "Nordmakedonien", "Mali", "Myanmar (Burma)", "Mongoliet", "Macao SAR", "Nordmarianerna",
+            "Martinique", "Mauretanien", "Montserrat", "Malta", "Mauritius", "Maldiverna", "Malawi",
+            "Mexiko", "Malaysia", "Moçambique", "Namibia", "Nya Kaledonien", "Niger", "Norfolkön",
+            "Nigeria", "Nicaragua", "Nederländerna", "Norge", "Nepal", "Nauru", "Niue",
+            "Nya Zeeland", "Oman", "Panama", "Peru", "Franska Polynesien", "Papua Nya Guinea",
+            "Filippinerna", "Pakistan", "Polen", "S:t Pierre och Miquelon", "Puerto Rico",
+            "Palestinska territorierna", "Portugal", "Palau", "Paraguay", "Qatar", "Réunion",
+            "Rumänien", "Serbien", "Ryssland", "Rwanda", "Saudiarabien", "Salomonöarna",
+            "Seychellerna", "Sudan", "Sverige", "Singapore", "S:t Helena", "Slovenien",
+            "Svalbard och Jan Mayen", "Slovakien", "Sierra Leone", "San Marino", "Senegal",
+            "Somalia", "Surinam", "Sydsudan", "São Tomé och Príncipe", "El Salvador",
+            "Sint Maarten", "Syrien", "Eswatini", "Turks- och Caicosöarna", "Tchad", "Togo",
+            "Thailand", "Tadzjikistan", "Tokelauöarna", "Östtimor", "Turkmenistan", "Tunisien",
+            "Tonga", "Turkiet", "Trinidad och Tobago", "Tuvalu", "Taiwan", "Tanzania", "Ukraina",
+            "Uganda", "Uruguay", "Uzbekistan", "Vatikanstaten", "S:t Vincent och Grenadinerna",
+            "Venezuela", "Brittiska Jungfruöarna", "Amerikanska Jungfruöarna", "Vietnam", "Vanuatu",
+            "Wallis- och Futunaöarna", "Samoa", "", "", "", "", "", "", "Jemen", "Mayotte",
+            "Sydafrika", "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            235, 106, 13, 5, 65, 0, 9, 7, 1, 11, 6, 8, 10, 4, 245, 14, 12, 244, 15, 27, 19, 18, 21,
+            34, 33, 17, 35, 23, 24, 32, 26, 108, 29, 16, 25, 28, 20, 22, 114, 117, 37, 116, 193, 52,
+            40, 38, 105, 111, 39, 41, 119, 107, 42, 103, 43, 219, 229, 45, 44, 46, 47, 2, 48, 54,
+            49, 53, 203, 56, 60, 58, 57, 144, 64, 61, 66, 205, 73, 68, 62, 78, 69, 70, 74, 67, 79,
+            75, 50, 72, 77, 76, 80, 81, 84, 83, 118, 82, 232, 86, 93, 96, 95, 85, 31, 91, 92, 94,
+            97, 101, 98, 102, 99, 110, 112, 104, 113, 115, 184, 160, 211, 120, 36, 109, 121, 124,
+            185, 127, 204, 125, 122, 128, 129, 123, 126, 137, 147, 146, 149, 131, 138, 161, 135,
+            151, 148, 130, 162, 139, 140, 150, 136, 141, 134, 132, 143, 133, 145, 152, 165, 157,
+            163, 158, 156, 154, 164, 155, 153, 159, 166, 167, 171, 174, 63, 172, 59, 168, 175, 186,
+            177, 170, 176, 169, 173, 178, 179, 180, 195, 182, 181, 192, 188, 196, 206, 209, 198,
+            183, 201, 208, 200, 197, 190, 194, 202, 207, 212, 191, 51, 199, 213, 55, 227, 217, 220,
+            218, 214, 221, 246, 226, 224, 222, 225, 223, 228, 215, 216, 231, 230, 234, 236, 238,
+            187, 239, 30, 3, 240, 237, 241, 189, 71, 87, 233, 90, 88, 89, 100, 142, 210, 242, 243,
+        ],
+    },
+    LocaleNames {
+        locale: "th",
+        names: &[
+            "สหรัฐอเมริกา", "แคนาดา", "เกาะแอสเซนชัน", "อันดอร์รา", "สหรัฐอาหรับเอมิเรตส์",
+            "อัฟกานิสถาน", "แอนติกาและบาร์บูดา", "แองกวิลลา", "แอลเบเนีย", "อาร์เมเนีย", "แองโกลา",
+            "แอนตาร์กติกา", "อาร์เจนตินา", "อเมริกันซามัว", "ออสเตรีย", "ออสเตรเลีย", "อารูบา",
+            "หมู่เกาะโอลันด์", "อาเซอร์ไบจาน", "บอสเนียและเฮอร์เซโกวีนา", "บาร์เบโดส", "บังกลาเทศ",
+            "เบลเยียม", "บูร์กินาฟาโซ", "บัลแกเรีย", "บาห์เรน", "บุรุนดี", "เบนิน", "เบอร์มิวดา",
+            "บรูไน", "โบลิเวีย", "เนเธอร์แลนด์แคริบเบียน", "บราซิล", "บาฮามาส", "ภูฏาน", "บอตสวานา",
+            "เบลารุส", "เบลีซ", "หมู่เกาะโคโคส (คีลิง)", "คองโก - กินชาซา", "สาธารณรัฐแอฟริกากลาง",
+            "คองโก - บราซซาวิล", "สวิตเซอร์แลนด์", "โกตดิวัวร์", "หมู่เกาะคุก", "ชิลี", "แคเมอรูน",
+            "จีน", "โคลอมเบีย", "คอสตาริกา", "คิวบา", "เคปเวิร์ด", "คูราเซา", "เกาะคริสต์มาส",
+            "ไซปรัส", "เช็ก", "เยอรมนี", "จิบูตี", "เดนมาร์ก", "โดมินิกา", "สาธารณรัฐโดมินิกัน",
+            "แอลจีเรีย", "เอกวาดอร์", "เอสโตเนีย", "อียิปต์", "เอริเทรีย", "สเปน", "เอธิโอเปีย",
+            "ฟินแลนด์", "ฟิจิ", "หมู่เกาะฟอล์กแลนด์", "ไมโครนีเซีย", "หมู่เกาะแฟโร", "ฝรั่งเศส",
+            "กาบอง", "สหราชอาณาจักร", "เกรเนดา", "จอร์เจีย", "เฟรนช์เกียนา", "เกิร์นซีย์", "กานา",
+            "ยิบรอลตาร์", "กรีนแลนด์", "แกมเบีย", "กินี", "กวาเดอลูป", "อิเควทอเรียลกินี", "กรีซ",
+            "กัวเตมาลา", "กวม", "กินี-บิสเซา", "กายอานา",
+            "เขตปกครองพิเศษฮ่องกงแห่งสาธารณรัฐประชาชนจีน", "ฮอนดูรัส", "โครเอเชีย", "เฮติ",
+            "ฮังการี", "อินโดนีเซีย", "ไอร์แลนด์", "อิสราเอล", "เกาะแมน", "อินเดีย",
+            "บริติชอินเดียนโอเชียนเทร์ริทอรี", "อิรัก", "อิหร่าน", "ไอซ์แลนด์", "อิตาลี",
+            "เจอร์ซีย์", "จาเมกา", "จอร์แดน", "ญี่ปุ่น", "เคนยา", "คีร์กีซสถาน", "กัมพูชา",
+            "คิริบาส", "คอโมโรส", "เซนต์คิตส์และเนวิส", "เกาหลีเหนือ", "เกาหลีใต้", "คูเวต",
+            "หมู่เกาะเคย์แมน", "คาซัคสถาน", "ลาว", "เลบานอน", "เซนต์ลูเซีย", "ลิกเตนสไตน์",
+            "ศรีลังกา", "ไลบีเรีย", "เลโซโท", "ลิทัวเนีย", "ลักเซมเบิร์ก", "ลัตเวีย", "ลิเบีย",
+            "โมร็อกโก", "โมนาโก", "มอลโดวา", "มอนเตเนโกร", "มาดากัสการ์", "หมู่เกาะมาร์แชลล์",
+            "มาซิโดเนียเหนือ", "มาลี", "เมียนมา (พม่า)", "มองโกเลีย",
+            "เขตปกครองพิเศษมาเก๊าแห่งสาธารณรัฐประชาชนจีน", "หมู่เกาะนอร์เทิร์นมาเรียนา",
+            "มาร์ตินีก", "มอริเตเนีย", "มอนต์เซอร์รัต", "มอลตา", "มอริเชียส", "มัลดีฟส์", "มาลาวี",
+            "เม็กซิโก", "มาเลเซีย", "โมซัมบิก", "นามิเบีย", "นิวแคลิโดเนีย", "ไนเจอร์",
+            "เกาะนอร์ฟอล์ก", "ไนจีเรีย", "นิการากัว", "เนเธอร์แลนด์", "นอร์เวย์", "เนปาล", "นาอูรู",
+            "นีอูเอ", "นิวซีแลนด์", "โอมาน", "ปานามา", "เปรู", "เฟรนช์โปลินีเซีย", "ปาปัวนิวกินี",
+            "ฟิลิปปินส์", "ปากีสถาน", "โปแลนด์", "แซงปีแยร์และมีเกอลง", "เปอร์โตริโก",
+            "ดินแดนปาเลสไตน์", "โปรตุเกส", "ปาเลา", "ปารากวัย", "กาตาร์", "เรอูนียง", "โรมาเนีย",
+            "เซอร์เบีย", "รัสเซีย", "รวันดา", "ซาอุดีอาระเบีย", "หมู่เกาะโซโลมอน", "เซเชลส์",
+            "ซูดาน", "สวีเดน", "สิงคโปร์", "เซนต์เฮเลนา", "สโลวีเนีย", "สฟาลบาร์และยานไมเอน",
+            "สโลวะเกีย", "เซียร์ราลีโอน", "ซานมาริโน", "เซเนกัล", "โซมาเลีย", "ซูรินาเม",
+            "ซูดานใต้", "เซาตูเมและปรินซิปี", "เอลซัลวาดอร์", "ซินต์มาร์เทน", "ซีเรีย", "เอสวาตีนี",
+            "หมู่เกาะเติกส์และหมู่เกาะเคคอส", "ชาด", "โตโก", "ไทย", "ทาจิกิสถาน", "โตเกเลา",
+            "ติมอร์-เลสเต", "เติร์กเมนิสถาน", "ตูนิเซีย", "ตองกา", "ตุรกี", "ตรินิแดดและโตเบโก",
+            "ตูวาลู", "ไต้หวัน", "แทนซาเนีย", "ยูเครน", "ยูกันดา", "อุรุกวัย", "อุซเบกิสถาน",
+            "นครวาติกัน", "เซนต์วินเซนต์และเกรนาดีนส์", "เวเนซุเอลา", "หมู่เกาะบริติชเวอร์จิน",
+            "หมู่เกาะเวอร์จินของสหรัฐอเมริกา", "เวียดนาม", "วานูอาตู", "วาลลิสและฟุตูนา", "ซามัว",
+            "", "", "", "", "", "", "เยเมน", "มายอต", "แอฟริกาใต้", "แซมเบีย", "ซิมบับเว",
+        ],
+        sort_keys: &[
+            185, 36, 18, 206, 186, 207, 231, 228, 234, 210, 229, 230, 209, 203, 205, 204, 211, 202,
+            208, 105, 108, 106, 114, 112, 107, 109, 111, 113, 117, 103, 118, 97, 101, 110, 134, 104,
+            115, 116, 193, 24, 189, 25, 183, 21, 191, 47, 37, 44, 39, 27, 30, 35, 32, 15, 70, 48,
+            160, 43, 73, 74, 188, 233, 222, 226, 219, 224, 179, 223, 130, 129, 198, 155, 199, 128,
+            8, 187, 12, 40, 132, 19, 7, 156, 1, 20, 10, 3, 212, 0, 5, 2, 11, 9, 23, 238, 38, 240,
+            239, 215, 237, 217, 17, 214, 102, 216, 218, 236, 213, 45, 42, 41, 71, 34, 31, 4, 29, 26,
+            59, 14, 13, 33, 192, 28, 167, 172, 60, 168, 178, 173, 171, 169, 165, 166, 170, 154, 153,
+            140, 137, 144, 200, 143, 148, 151, 135, 22, 196, 146, 139, 136, 141, 138, 142, 147, 150,
+            149, 152, 90, 93, 100, 16, 99, 92, 96, 89, 98, 91, 95, 94, 235, 120, 124, 133, 121, 131,
+            119, 127, 67, 125, 72, 126, 123, 122, 6, 163, 164, 64, 162, 161, 51, 194, 58, 55, 184,
+            190, 62, 182, 180, 181, 66, 49, 63, 69, 57, 56, 65, 225, 52, 54, 227, 195, 46, 83, 87,
+            85, 82, 77, 81, 79, 76, 78, 75, 80, 84, 86, 158, 157, 221, 220, 88, 61, 176, 197, 201,
+            177, 174, 175, 50, 241, 242, 246, 245, 243, 244, 159, 145, 232, 68, 53,
+        ],
+    },
+    LocaleNames {
+        locale: "tr",
+        names: &[
+            "Amerika Birleşik Devletleri", "Kanada", "Ascension Adası", "Andorra",
+            "Birleşik Arap Emirlikleri", "Afganistan", "Antigua ve Barbuda", "Anguilla",
+            "Arnavutluk", "Ermenistan", "Angola", "Antarktika", "Arjantin", "Amerikan Samoası",
+            "Avusturya", "Avustralya", "Aruba", "Åland Adaları", "Azerbaycan", "Bosna-Hersek",
+            "Barbados", "Bangladeş", "Belçika", "Burkina Faso", "Bulgaristan", "Bahreyn", "Burundi",
+            "Benin", "Bermuda", "Brunei", "Bolivya", "Karayip Hollandası", "Brezilya", "Bahamalar",
+            "Butan", "Botsvana", "Belarus", "Belize", "Cocos (Keeling) Adaları", "Kongo - Kinşasa",
+            "Orta Afrika Cumhuriyeti", "Kongo - Brazavil", "İsviçre", "Côte d’Ivoire",
+            "Cook Adaları", "Şili", "Kamerun", "Çin", "Kolombiya", "Kosta Rika", "Küba",
+            "Cabo Verde", "Curaçao", "Christmas Adası", "Kıbrıs", "Çekya", "Almanya", "Cibuti",
+            "Danimarka", "Dominika", "Dominik Cumhuriyeti", "Cezayir", "Ekvador", "Estonya",
+            "Mısır", "Eritre", "İspanya", "Etiyopya", "Finlandiya", "Fiji", "Falkland Adaları",
+            "Mikronezya", "Faroe Adaları", "Fransa", "Gabon", "Birleşik Krallık", "Grenada",
+            "Gürcistan", "Fransız Guyanası", "Guernsey", "Gana", "Cebelitarık", "Grönland",
+            "Gambiya", "Gine", "Guadeloupe", "Ekvator Ginesi", "Yunanistan", "Guatemala", "Guam",
+            "Gine-Bissau", "Guyana", "Çin Hong Kong ÖİB", "Honduras", "Hırvatistan", "Haiti",
+            "Macaristan", "Endonezya", "İrlanda", "İsrail", "Man Adası", "Hindistan",
+            "Britanya Hint Okyanusu Toprakları", "Irak", "İran", "İzlanda", "İtalya", "Jersey",
+            "Jamaika", "Ürdün", "Japonya", "Kenya", "Kırgızistan", "Kamboçya", "Kiribati",
+            "Komorlar", "Saint Kitts ve Nevis", "Kuzey Kore", "Güney Kore", "Kuveyt",
+            "Cayman Adaları", "Kazakistan", "Laos", "Lübnan", "Saint Lucia", "Liechtenstein",
+            "Sri Lanka", "Liberya", "Lesotho", "Litvanya", "Lüksemburg", "Letonya", "Libya", "Fas",
+            "Monako", "Moldova", "Karadağ", "Madagaskar", "Marshall Adaları", "Kuzey Makedonya",
+            "Mali", "Myanmar (Burma)", "Moğolistan", "Çin Makao ÖİB", "Kuzey Mariana Adaları",
+            "Martinik", "Moritanya", "Montserrat", "Malta", "Mauritius", "Maldivler", "Malavi",
+            "Meksika", "Malezya", "Mozambik", "Namibya", "Yeni Kaledonya", "Nijer", "Norfolk Adası",
+            "Nijerya", "Nikaragua", "Hollanda", "Norveç", "Nepal", "Nauru", "Niue", "Yeni Zelanda",
+            "Umman", "Panama", "Peru", "Fransız Polinezyası", "Papua Yeni Gine", "Filipinler",
+            "Pakistan", "Polonya", "Saint Pierre ve Miquelon", "Porto Riko", "Filistin Bölgeleri",
+            "Portekiz", "Palau", "Paraguay", "Katar", "Reunion", "Romanya", "Sırbistan", "Rusya",
+            "Ruanda", "Suudi Arabistan", "Solomon Adaları", "Seyşeller", "Sudan", "İsveç",
+            "Singapur", "Saint Helena", "Slovenya", "Svalbard ve Jan Mayen", "Slovakya",
+            "Sierra Leone", "San Marino", "Senegal", "Somali", "Surinam", "Güney Sudan",
+            "Sao Tome ve Principe", "El Salvador", "Sint Maarten", "Suriye", "Esvatini",
+            "Turks ve Caicos Adaları", "Çad", "Togo", "Tayland", "Tacikistan", "Tokelau",
+            "Timor-Leste", "Türkmenistan", "Tunus", "Tonga", "Türkiye", "Trinidad ve Tobago",
+            "Tuvalu", "Tayvan", "Tanzanya", "Ukrayna", "Uganda", "Uruguay", "Özbekistan", "Vatikan",
+            "Saint Vincent ve Grenadinler", "Venezuela", "Britanya Virjin Adaları",
+            "ABD Virjin Adaları", "Vietnam", "Vanuatu", "Wallis ve Futuna", "Samoa", "", "", "", "",
+            "", "", "Yemen", "Mayotte", "Güney Afrika", "Zambiya", "Zimbabve",
+        ],
+        sort_keys: &[
+            4, 117, 14, 6, 27, 1, 10, 8, 12, 63, 7, 9, 11, 5, 16, 15, 13, 2, 17, 30, 21, 20, 23, 37,
+            36, 19, 38, 25, 26, 35, 29, 119, 32, 18, 39, 31, 22, 24, 46, 129, 176, 128, 109, 48, 47,
+            215, 116, 52, 126, 130, 135, 40, 49, 44, 123, 51, 3, 45, 55, 57, 56, 43, 58, 64, 158,
+            62, 106, 66, 73, 70, 67, 159, 68, 74, 77, 28, 83, 93, 75, 88, 79, 42, 84, 78, 80, 85,
+            59, 244, 87, 86, 81, 89, 53, 98, 95, 94, 145, 61, 105, 107, 152, 96, 33, 103, 104, 111,
+            110, 114, 112, 235, 113, 122, 124, 115, 125, 127, 192, 132, 91, 131, 41, 121, 136, 143,
+            193, 141, 209, 139, 137, 142, 144, 138, 140, 69, 162, 161, 118, 146, 153, 133, 150, 166,
+            160, 54, 134, 154, 164, 163, 151, 155, 148, 147, 157, 149, 165, 167, 242, 170, 174, 171,
+            172, 97, 175, 169, 168, 173, 243, 232, 180, 183, 76, 181, 71, 178, 184, 194, 186, 72,
+            185, 179, 182, 120, 187, 188, 201, 190, 189, 213, 207, 200, 210, 108, 203, 191, 206,
+            214, 205, 202, 197, 199, 208, 211, 92, 198, 60, 204, 212, 65, 226, 50, 221, 218, 216,
+            222, 220, 229, 225, 223, 228, 224, 227, 219, 217, 231, 230, 234, 177, 237, 195, 238, 34,
+            0, 239, 236, 240, 196, 82, 99, 233, 102, 100, 101, 241, 156, 90, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "uk",
+        names: &[
+            "Сполучені Штати", "Канада", "Острів Вознесіння", "Андорра",
+            "Обʼєднані Арабські Емірати", "Афганістан", "Антигуа і Барбуда", "Ангілья", "Албанія",
+            "Вірменія", "Ангола", "Антарктика", "Аргентина", "Американське Самоа", "Австрія",
+            "Австралія", "Аруба", "Аландські Острови", "Азербайджан", "Боснія і Герцеговина",
+            "Барбадос", "Бангладеш", "Бельгія", "Буркіна-Фасо", "Болгарія", "Бахрейн", "Бурунді",
+            "Бенін", "Бермудські Острови", "Бруней", "Болівія", "Карибські Нідерланди", "Бразилія",
+            "Багамські Острови", "Бутан", "Ботсвана", "Білорусь", "Беліз",
+            "Кокосові (Кілінг) Острови", "Конго – Кіншаса", "Центральноафриканська Республіка",
+            "Конго – Браззавіль", "Швейцарія", "Кот-дʼІвуар", "Острови Кука", "Чилі", "Камерун",
+            "Китай", "Колумбія", "Коста-Рика", "Куба", "Кабо-Верде", "Кюрасао", "Острів Різдва",
+            "Кіпр", "Чехія", "Німеччина", "Джибуті", "Данія", "Домініка",
+            "Домініканська Республіка", "Алжир", "Еквадор", "Естонія", "Єгипет", "Еритрея",
+            "Іспанія", "Ефіопія", "Фінляндія", "Фіджі", "Фолклендські Острови", "Мікронезія",
+            "Фарерські Острови", "Франція", "Габон", "Велика Британія", "Гренада", "Грузія",
+            "Французька Гвіана", "Гернсі", "Гана", "Гібралтар", "Гренландія", "Гамбія", "Гвінея",
+            "Гваделупа", "Екваторіальна Гвінея", "Греція", "Гватемала", "Гуам", "Гвінея-Бісау",
+            "Гаяна", "Гонконг, ОАР Китаю", "Гондурас", "Хорватія", "Гаїті", "Угорщина", "Індонезія",
+            "Ірландія", "Ізраїль", "Острів Мен", "Індія",
+            "Британська територія в Індійському океані", "Ірак", "Іран", "Ісландія", "Італія",
+            "Джерсі", "Ямайка", "Йорданія", "Японія", "Кенія", "Киргизстан", "Камбоджа", "Кірибаті",
+            "Комори", "Сент-Кітс і Невіс", "Північна Корея", "Південна Корея", "Кувейт",
+            "Кайманові Острови", "Казахстан", "Лаос", "Ліван", "Сент-Люсія", "Ліхтенштейн",
+            "Шрі-Ланка", "Ліберія", "Лесото", "Литва", "Люксембург", "Латвія", "Лівія", "Марокко",
+            "Монако", "Молдова", "Чорногорія", "Мадагаскар", "Маршаллові Острови",
+            "Північна Македонія", "Малі", "Мʼянма (Бірма)", "Монголія", "Макао, ОАР Китаю",
+            "Північні Маріанські Острови", "Мартиніка", "Мавританія", "Монтсеррат", "Мальта",
+            "Маврикій", "Мальдіви", "Малаві", "Мексика", "Малайзія", "Мозамбік", "Намібія",
+            "Нова Каледонія", "Нігер", "Острів Норфолк", "Нігерія", "Нікарагуа", "Нідерланди",
+            "Норвегія", "Непал", "Науру", "Ніуе", "Нова Зеландія", "Оман", "Панама", "Перу",
+            "Французька Полінезія", "Папуа-Нова Гвінея", "Філіппіни", "Пакистан", "Польща",
+            "Сен-Пʼєр і Мікелон", "Пуерто-Рико", "Палестинські території", "Португалія", "Палау",
+            "Парагвай", "Катар", "Реюньйон", "Румунія", "Сербія", "Росія", "Руанда",
+            "Саудівська Аравія", "Соломонові Острови", "Сейшельські Острови", "Судан", "Швеція",
+            "Сінгапур", "Острів Святої Єлени", "Словенія", "Шпіцберген та Ян-Маєн", "Словаччина",
+            "Сьєрра-Леоне", "Сан-Марино", "Сенегал", "Сомалі", "Суринам", "Південний Судан",
+            "Сан-Томе і Принсіпі", "Сальвадор", "Сінт-Мартен", "Сирія", "Есватіні",
+            "Острови Теркс і Кайкос", "Чад", "Того", "Таїланд", "Таджикистан", "Токелау",
+            "Тимор-Лешті", "Туркменістан", "Туніс", "Тонга", "Туреччина", "Тринідад і Тобаго",
+            "Тувалу", "Тайвань", "Танзанія", "Україна", "Уганда", "Уругвай", "Узбекистан",
+            "Ватикан", "Сент-Вінсент і Гренадіни", "Венесуела", "Британські Віргінські острови",
+            "Віргінські Острови (США)", "Вʼєтнам", "Вануату", "Уолліс і Футуна", "Самоа", "", "",
+            "", "", "", "", "Ємен", "Майотта", "Південно-Африканська Республіка", "Замбія",
+            "Зімбабве",
+        ],
+        sort_keys: &[
+            198, 90, 152, 9, 150, 14, 11, 7, 4, 40, 8, 10, 12, 6, 1, 0, 13, 3, 2, 26, 17, 16, 20,
+            32, 24, 18, 33, 21, 22, 31, 25, 91, 28, 15, 34, 27, 23, 19, 98, 102, 230, 101, 235, 104,
+            157, 233, 89, 95, 99, 103, 105, 85, 107, 155, 96, 232, 145, 62, 60, 63, 64, 5, 65, 69,
+            71, 67, 82, 70, 224, 222, 225, 131, 221, 226, 42, 37, 55, 58, 227, 51, 45, 52, 56, 44,
+            49, 47, 66, 57, 48, 59, 50, 46, 54, 53, 229, 43, 216, 77, 80, 75, 153, 76, 29, 78, 79,
+            81, 83, 61, 239, 84, 240, 93, 94, 88, 97, 100, 188, 169, 166, 106, 87, 86, 108, 113,
+            189, 115, 238, 112, 110, 111, 116, 109, 114, 127, 134, 133, 234, 119, 129, 170, 124,
+            137, 135, 121, 171, 128, 118, 136, 126, 117, 125, 122, 130, 123, 132, 138, 148, 141,
+            154, 142, 144, 143, 149, 140, 139, 146, 147, 151, 162, 165, 228, 163, 223, 159, 172,
+            185, 174, 161, 173, 160, 164, 92, 175, 178, 190, 176, 177, 183, 196, 184, 199, 236, 192,
+            156, 195, 237, 194, 201, 181, 186, 197, 200, 167, 182, 179, 193, 191, 68, 158, 231, 207,
+            203, 202, 208, 206, 214, 212, 209, 213, 210, 211, 204, 205, 218, 215, 220, 217, 36, 187,
+            38, 30, 39, 41, 35, 219, 180, 241, 242, 246, 245, 243, 244, 72, 120, 168, 73, 74,
+        ],
+    },
+    LocaleNames {
+        locale: "vi",
+        names: &[
+            "Hoa Kỳ", "Canada", "Đảo Ascension", "Andorra", "Các Tiểu Vương quốc Ả Rập Thống nhất",
+            "Afghanistan", "Antigua và Barbuda", "Anguilla", "Albania", "Armenia", "Angola",
+            "Nam Cực", "Argentina", "Samoa thuộc Mỹ", "Áo", "Australia", "Aruba", "Quần đảo Åland",
+            "Azerbaijan", "Bosnia và Herzegovina", "Barbados", "Bangladesh", "Bỉ", "Burkina Faso",
+            "Bulgaria", "Bahrain", "Burundi", "Benin", "Bermuda", "Brunei", "Bolivia",
+            "Ca-ri-bê Hà Lan", "Brazil", "Bahamas", "Bhutan", "Botswana", "Belarus", "Belize",
+            "Quần đảo Cocos (Keeling)", "Congo - Kinshasa", "Cộng hòa Trung Phi",
+            "Congo - Brazzaville", "Thụy Sĩ", "Côte d’Ivoire", "Quần đảo Cook", "Chile", "Cameroon",
+            "Trung Quốc", "Colombia", "Costa Rica", "Cuba", "Cape Verde", "Curaçao",
+            "Đảo Giáng Sinh", "Síp", "Séc", "Đức", "Djibouti", "Đan Mạch", "Dominica",
+            "Cộng hòa Dominica", "Algeria", "Ecuador", "Estonia", "Ai Cập", "Eritrea",
+            "Tây Ban Nha", "Ethiopia", "Phần Lan", "Fiji", "Quần đảo Falkland", "Micronesia",
+            "Quần đảo Faroe", "Pháp", "Gabon", "Vương quốc Anh", "Grenada", "Georgia",
+            "Guiana thuộc Pháp", "Guernsey", "Ghana", "Gibraltar", "Greenland", "Gambia", "Guinea",
+            "Guadeloupe", "Guinea Xích Đạo", "Hy Lạp", "Guatemala", "Guam", "Guinea-Bissau",
+            "Guyana", "Đặc khu Hành chính Hồng Kông, Trung Quốc", "Honduras", "Croatia", "Haiti",
+            "Hungary", "Indonesia", "Ireland", "Israel", "Đảo Man", "Ấn Độ",
+            "Lãnh thổ Ấn Độ Dương thuộc Anh", "Iraq", "Iran", "Iceland", "Italy", "Jersey",
+            "Jamaica", "Jordan", "Nhật Bản", "Kenya", "Kyrgyzstan", "Campuchia", "Kiribati",
+            "Comoros", "St. Kitts và Nevis", "Triều Tiên", "Hàn Quốc", "Kuwait", "Quần đảo Cayman",
+            "Kazakhstan", "Lào", "Li-băng", "St. Lucia", "Liechtenstein", "Sri Lanka", "Liberia",
+            "Lesotho", "Litva", "Luxembourg", "Latvia", "Libya", "Ma-rốc", "Monaco", "Moldova",
+            "Montenegro", "Madagascar", "Quần đảo Marshall", "Bắc Macedonia", "Mali",
+            "Myanmar (Miến Điện)", "Mông Cổ", "Đặc khu Hành chính Macao, Trung Quốc",
+            "Quần đảo Bắc Mariana", "Martinique", "Mauritania", "Montserrat", "Malta", "Mauritius",
+            "Maldives", "Malawi", "Mexico", "Malaysia", "Mozambique", "Namibia", "New Caledonia",
+            "Niger", "Đảo Norfolk", "Nigeria", "Nicaragua", "Hà Lan", "Na Uy", "Nepal", "Nauru",
+            "Niue", "New Zealand", "Oman", "Panama", "Peru", "Polynesia thuộc Pháp",
+            "Papua New Guinea", "Philippines", "Pakistan", "Ba Lan", "Saint Pierre và Miquelon",
+            "Puerto Rico", "Lãnh thổ Palestine", "Bồ Đào Nha", "Palau", "Paraguay", "Qatar",
+            "Réunion", "Romania", "Serbia", "Nga", "Rwanda", "Ả Rập Xê-út", "Quần đảo Solomon",
+            "Seychelles", "Sudan", "Thụy Điển", "Singapore", "St. Helena", "Slovenia",
+            "Svalbard và Jan Mayen", "Slovakia", "Sierra Leone", "San Marino", "Senegal", "Somalia",
+            "Suriname", "Nam Sudan", "São Tomé và Príncipe", "El Salvador", "Sint Maarten", "Syria",
+            "Eswatini", "Quần đảo Turks và Caicos", "Chad", "Togo", "Thái Lan", "Tajikistan",
+            "Tokelau", "Timor-Leste", "Turkmenistan", "Tunisia", "Tonga", "Thổ Nhĩ Kỳ",
+            "Trinidad và Tobago", "Tuvalu", "Đài Loan", "Tanzania", "Ukraina", "Uganda", "Uruguay",
+            "Uzbekistan", "Thành Vatican", "St. Vincent và Grenadines", "Venezuela",
+            "Quần đảo Virgin thuộc Anh", "Quần đảo Virgin thuộc Hoa Kỳ", "Việt Nam", "Vanuatu",
+            "Wallis và Futuna", "Samoa", "", "", "", "", "", "", "Yemen", "Mayotte", "Nam Phi",
+            "Zambia", "Zimbabwe",
+        ],
+        sort_keys: &[
+            94, 41, 60, 5, 38, 1, 8, 7, 3, 11, 6, 149, 10, 193, 9, 13, 12, 176, 14, 29, 20, 19, 27,
+            35, 34, 18, 36, 24, 25, 33, 28, 37, 32, 17, 26, 30, 22, 23, 179, 48, 51, 47, 223, 52,
+            180, 44, 39, 230, 45, 49, 54, 42, 55, 61, 203, 196, 66, 56, 59, 57, 50, 4, 67, 70, 2,
+            69, 218, 72, 171, 73, 181, 140, 182, 170, 74, 242, 81, 76, 86, 85, 77, 78, 80, 75, 87,
+            82, 88, 97, 84, 83, 89, 90, 64, 95, 53, 92, 96, 99, 106, 107, 62, 15, 117, 105, 104, 98,
+            108, 110, 109, 111, 158, 113, 116, 40, 114, 46, 209, 228, 93, 115, 178, 112, 119, 122,
+            210, 125, 207, 123, 121, 126, 127, 120, 124, 128, 142, 141, 143, 129, 183, 21, 133, 147,
+            146, 65, 177, 135, 136, 144, 134, 137, 132, 130, 139, 131, 145, 152, 155, 160, 63, 161,
+            159, 91, 148, 154, 153, 162, 156, 163, 166, 169, 173, 167, 172, 164, 16, 191, 174, 118,
+            31, 165, 168, 175, 188, 189, 198, 157, 190, 0, 184, 199, 212, 222, 201, 208, 205, 214,
+            204, 200, 194, 197, 206, 213, 151, 195, 68, 202, 215, 71, 185, 43, 225, 219, 216, 226,
+            224, 232, 231, 227, 221, 229, 233, 58, 217, 235, 234, 237, 238, 220, 211, 240, 186, 187,
+            241, 239, 243, 192, 79, 100, 236, 103, 101, 102, 244, 138, 150, 245, 246,
+        ],
+    },
+    LocaleNames {
+        locale: "zh",
+        names: &[
+            "美国", "加拿大", "阿森松岛", "安道尔", "阿拉伯联合酋长国", "阿富汗", "安提瓜和巴布达", "安圭拉", "阿尔巴尼亚", "亚美尼亚", "安哥拉",
+            "南极洲", "阿根廷", "美属萨摩亚", "奥地利", "澳大利亚", "阿鲁巴", "奥兰群岛", "阿塞拜疆", "波斯尼亚和黑塞哥维那", "巴巴多斯",
+            "孟加拉国", "比利时", "布基纳法索", "保加利亚", "巴林", "布隆迪", "贝宁", "百慕大", "文莱", "玻利维亚", "荷属加勒比区", "巴西",
+            "巴哈马", "不丹", "博茨瓦纳", "白俄罗斯", "伯利兹", "科科斯（基林）群岛", "刚果（金）", "中非共和国", "刚果（布）", "瑞士",
+            "科特迪瓦", "库克群岛", "智利", "喀麦隆", "中国", "哥伦比亚", "哥斯达黎加", "古巴", "佛得角", "库拉索", "圣诞岛", "塞浦路斯",
+            "捷克", "德国", "吉布提", "丹麦", "多米尼克", "多米尼加共和国", "阿尔及利亚", "厄瓜多尔", "爱沙尼亚", "埃及", "厄立特里亚",
+            "西班牙", "埃塞俄比亚", "芬兰", "斐济", "福克兰群岛", "密克罗尼西亚", "法罗群岛", "法国", "加蓬", "英国", "格林纳达", "格鲁吉亚",
+            "法属圭亚那", "根西岛", "加纳", "直布罗陀", "格陵兰", "冈比亚", "几内亚", "瓜德罗普", "赤道几内亚", "希腊", "危地马拉", "关岛",
+            "几内亚比绍", "圭亚那", "中国香港特别行政区", "洪都拉斯", "克罗地亚", "海地", "匈牙利", "印度尼西亚", "爱尔兰", "以色列", "马恩岛",
+            "印度", "英属印度洋领地", "伊拉克", "伊朗", "冰岛", "意大利", "泽西岛", "牙买加", "约旦", "日本", "肯尼亚", "吉尔吉斯斯坦",
+            "柬埔寨", "基里巴斯", "科摩罗", "圣基茨和尼维斯", "朝鲜", "韩国", "科威特", "开曼群岛", "哈萨克斯坦", "老挝", "黎巴嫩",
+            "圣卢西亚", "列支敦士登", "斯里兰卡", "利比里亚", "莱索托", "立陶宛", "卢森堡", "拉脱维亚", "利比亚", "摩洛哥", "摩纳哥",
+            "摩尔多瓦", "黑山", "马达加斯加", "马绍尔群岛", "北马其顿", "马里", "缅甸", "蒙古", "中国澳门特别行政区", "北马里亚纳群岛",
+            "马提尼克", "毛里塔尼亚", "蒙特塞拉特", "马耳他", "毛里求斯", "马尔代夫", "马拉维", "墨西哥", "马来西亚", "莫桑比克", "纳米比亚",
+            "新喀里多尼亚", "尼日尔", "诺福克岛", "尼日利亚", "尼加拉瓜", "荷兰", "挪威", "尼泊尔", "瑙鲁", "纽埃", "新西兰", "阿曼",
+            "巴拿马", "秘鲁", "法属波利尼西亚", "巴布亚新几内亚", "菲律宾", "巴基斯坦", "波兰", "圣皮埃尔和密克隆群岛", "波多黎各", "巴勒斯坦领土",
+            "葡萄牙", "帕劳", "巴拉圭", "卡塔尔", "留尼汪", "罗马尼亚", "塞尔维亚", "俄罗斯", "卢旺达", "沙特阿拉伯", "所罗门群岛", "塞舌尔",
+            "苏丹", "瑞典", "新加坡", "圣赫勒拿", "斯洛文尼亚", "斯瓦尔巴和扬马延", "斯洛伐克", "塞拉利昂", "圣马力诺", "塞内加尔", "索马里",
+            "苏里南", "南苏丹", "圣多美和普林西比", "萨尔瓦多", "荷属圣马丁", "叙利亚", "斯威士兰", "特克斯和凯科斯群岛", "乍得", "多哥", "泰国",
+            "塔吉克斯坦", "托克劳", "东帝汶", "土库曼斯坦", "突尼斯", "汤加", "土耳其", "特立尼达和多巴哥", "图瓦卢", "台湾", "坦桑尼亚",
+            "乌克兰", "乌干达", "乌拉圭", "乌兹别克斯坦", "梵蒂冈", "圣文森特和格林纳丁斯", "委内瑞拉", "英属维尔京群岛", "美属维尔京群岛", "越南",
+            "瓦努阿图", "瓦利斯和富图纳", "萨摩亚", "", "", "", "", "", "", "也门", "马约特", "南非", "赞比亚", "津巴布韦",
+        ],
+        sort_keys: &[
+            134, 93, 8, 13, 4, 2, 16, 15, 0, 219, 14, 150, 3, 135, 17, 19, 5, 18, 7, 39, 20, 139,
+            35, 44, 31, 26, 45, 34, 30, 206, 40, 84, 28, 22, 43, 42, 29, 41, 102, 69, 237, 68, 164,
+            104, 108, 236, 99, 238, 70, 71, 76, 65, 109, 173, 170, 97, 49, 89, 48, 53, 52, 1, 55,
+            12, 9, 56, 211, 10, 64, 63, 66, 141, 58, 57, 95, 227, 72, 74, 60, 75, 94, 235, 73, 67,
+            91, 77, 47, 212, 204, 78, 92, 79, 240, 87, 106, 81, 216, 226, 11, 223, 123, 225, 229,
+            221, 222, 36, 224, 233, 218, 230, 162, 107, 90, 96, 88, 103, 176, 46, 82, 105, 101, 80,
+            112, 113, 177, 117, 181, 115, 111, 114, 119, 110, 116, 144, 145, 143, 86, 122, 129, 33,
+            128, 142, 137, 239, 32, 130, 133, 138, 125, 132, 124, 126, 147, 127, 146, 148, 214, 155,
+            159, 156, 153, 83, 158, 154, 152, 157, 215, 6, 27, 140, 59, 21, 62, 23, 38, 179, 37, 25,
+            161, 160, 24, 100, 118, 121, 167, 54, 120, 172, 188, 171, 186, 163, 213, 175, 183, 184,
+            182, 168, 178, 169, 189, 187, 151, 174, 165, 85, 217, 185, 195, 234, 51, 192, 190, 201,
+            50, 200, 197, 194, 199, 196, 198, 191, 193, 208, 207, 209, 210, 61, 180, 205, 228, 136,
+            231, 203, 202, 166, 241, 242, 246, 245, 243, 244, 220, 131, 149, 232, 98,
+        ],
+    },
+    LocaleNames {
+        locale: "zh-Hant",
+        names: &[
+            "美國", "加拿大", "阿森松島", "安道爾", "阿拉伯聯合大公國", "阿富汗", "安地卡及巴布達", "安奎拉", "阿爾巴尼亞", "亞美尼亞", "安哥拉",
+            "南極洲", "阿根廷", "美屬薩摩亞", "奧地利", "澳洲", "荷屬阿魯巴", "奧蘭群島", "亞塞拜然", "波士尼亞與赫塞哥維納", "巴貝多", "孟加拉",
+            "比利時", "布吉納法索", "保加利亞", "巴林", "蒲隆地", "貝南", "百慕達", "汶萊", "玻利維亞", "荷蘭加勒比區", "巴西", "巴哈馬",
+            "不丹", "波札那", "白俄羅斯", "貝里斯", "科克斯（基靈）群島", "剛果（金夏沙）", "中非共和國", "剛果（布拉薩）", "瑞士", "象牙海岸",
+            "庫克群島", "智利", "喀麥隆", "中國", "哥倫比亞", "哥斯大黎加", "古巴", "維德角", "庫拉索", "聖誕島", "賽普勒斯", "捷克",
+            "德國", "吉布地", "丹麥", "多米尼克", "多明尼加共和國", "阿爾及利亞", "厄瓜多", "愛沙尼亞", "埃及", "厄利垂亞", "西班牙",
+            "衣索比亞", "芬蘭", "斐濟", "福克蘭群島", "密克羅尼西亞", "法羅群島", "法國", "加彭", "英國", "格瑞那達", "喬治亞", "法屬圭亞那",
+            "根息", "迦納", "直布羅陀", "格陵蘭", "甘比亞", "幾內亞", "瓜地洛普", "赤道幾內亞", "希臘", "瓜地馬拉", "關島", "幾內亞比索",
+            "蓋亞那", "中國香港特別行政區", "宏都拉斯", "克羅埃西亞", "海地", "匈牙利", "印尼", "愛爾蘭", "以色列", "曼島", "印度",
+            "英屬印度洋領地", "伊拉克", "伊朗", "冰島", "義大利", "澤西島", "牙買加", "約旦", "日本", "肯亞", "吉爾吉斯", "柬埔寨",
+            "吉里巴斯", "葛摩", "聖克里斯多福及尼維斯", "北韓", "南韓", "科威特", "開曼群島", "哈薩克", "寮國", "黎巴嫩", "聖露西亞",
+            "列支敦斯登", "斯里蘭卡", "賴比瑞亞", "賴索托", "立陶宛", "盧森堡", "拉脫維亞", "利比亞", "摩洛哥", "摩納哥", "摩爾多瓦",
+            "蒙特內哥羅", "馬達加斯加", "馬紹爾群島", "北馬其頓", "馬利", "緬甸", "蒙古", "中國澳門特別行政區", "北馬利安納群島", "馬丁尼克",
+            "茅利塔尼亞", "蒙哲臘", "馬爾他", "模里西斯", "馬爾地夫", "馬拉威", "墨西哥", "馬來西亞", "莫三比克", "納米比亞", "新喀里多尼亞",
+            "尼日", "諾福克島", "奈及利亞", "尼加拉瓜", "荷蘭", "挪威", "尼泊爾", "諾魯", "紐埃島", "紐西蘭", "阿曼", "巴拿馬", "秘魯",
+            "法屬玻里尼西亞", "巴布亞紐幾內亞", "菲律賓", "巴基斯坦", "波蘭", "聖皮埃與密克隆群島", "波多黎各", "巴勒斯坦自治區", "葡萄牙", "帛琉",
+            "巴拉圭", "卡達", "留尼旺", "羅馬尼亞", "塞爾維亞", "俄羅斯", "盧安達", "沙烏地阿拉伯", "索羅門群島", "塞席爾", "蘇丹", "瑞典",
+            "新加坡", "聖赫勒拿島", "斯洛維尼亞", "挪威屬斯瓦巴及尖棉", "斯洛伐克", "獅子山", "聖馬利諾", "塞內加爾", "索馬利亞", "蘇利南",
+            "南蘇丹", "聖多美普林西比", "薩爾瓦多", "荷屬聖馬丁", "敘利亞", "史瓦帝尼", "土克斯及開科斯群島", "查德", "多哥", "泰國", "塔吉克",
+            "托克勞群島", "東帝汶", "土庫曼", "突尼西亞", "東加", "土耳其", "千里達及托巴哥", "吐瓦魯", "台灣", "坦尚尼亞", "烏克蘭",
+            "烏干達", "烏拉圭", "烏茲別克", "梵蒂岡", "聖文森及格瑞那丁", "委內瑞拉", "英屬維京群島", "美屬維京群島", "越南", "萬那杜",
+            "瓦利斯群島和富圖那群島", "薩摩亞", "", "", "", "", "", "", "葉門", "馬約特島", "南非", "尚比亞", "辛巴威",
+        ],
+        sort_keys: &[
+            128, 91, 6, 10, 4, 2, 11, 13, 0, 219, 12, 148, 3, 129, 14, 16, 83, 15, 220, 38, 17, 134,
+            34, 43, 28, 23, 160, 33, 27, 205, 40, 82, 25, 19, 42, 39, 26, 32, 99, 68, 237, 67, 165,
+            212, 103, 236, 96, 238, 69, 70, 75, 203, 104, 174, 171, 95, 47, 86, 46, 51, 52, 1, 54,
+            9, 7, 55, 210, 224, 63, 62, 64, 136, 57, 56, 92, 229, 72, 162, 59, 74, 93, 235, 71, 66,
+            89, 76, 45, 211, 77, 78, 90, 65, 240, 85, 101, 80, 216, 228, 8, 225, 126, 227, 231, 222,
+            223, 35, 226, 234, 218, 232, 163, 102, 87, 94, 88, 73, 177, 29, 147, 100, 98, 79, 111,
+            108, 178, 112, 184, 106, 107, 109, 115, 105, 110, 140, 141, 139, 132, 117, 124, 31, 123,
+            137, 131, 239, 30, 118, 127, 133, 120, 138, 119, 121, 143, 122, 142, 144, 215, 152, 157,
+            145, 150, 81, 155, 151, 158, 153, 154, 5, 24, 135, 58, 18, 61, 20, 37, 180, 36, 22, 159,
+            41, 21, 97, 113, 116, 168, 53, 114, 172, 189, 170, 187, 164, 214, 176, 186, 156, 185,
+            182, 179, 169, 190, 188, 149, 175, 166, 84, 217, 183, 197, 44, 50, 193, 191, 200, 48,
+            198, 195, 49, 196, 161, 199, 192, 194, 207, 206, 208, 209, 60, 181, 204, 230, 130, 233,
+            202, 201, 167, 241, 242, 246, 245, 243, 244, 221, 125, 146, 173, 213,
+        ],
+    },
+];
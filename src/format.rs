@@ -0,0 +1,332 @@
+use std::sync::OnceLock;
+
+use crate::pattern::Pattern;
+use crate::{country_calling_code, PhoneNumber, TerritoryCode};
+
+mod data;
+
+/// A way of grouping the digits of a national significant number, from libphonenumber
+struct NumberFormat {
+    /// The pattern the national significant number must match, with a group per block of digits
+    pattern: &'static str,
+    /// A pattern which must match the start of the national significant number
+    leading_digits: &'static str,
+    /// How to write the groups within the territory, e.g. `$1 $2`
+    national: &'static str,
+    /// How to write the groups after the calling code, when the format is usable internationally
+    international: Option<&'static str>,
+    /// How to write the first group together with the national prefix, e.g. `0$FG`
+    national_prefix_rule: Option<&'static str>,
+}
+
+/// The compiled patterns of a [`NumberFormat`]
+struct CompiledFormat {
+    pattern: Pattern,
+    leading_digits: Pattern,
+}
+
+impl CompiledFormat {
+    fn new(format: &NumberFormat) -> Self {
+        Self {
+            pattern: Pattern::new(format.pattern),
+            leading_digits: Pattern::new(format.leading_digits),
+        }
+    }
+}
+
+/// The patterns of the formats of [`data::FORMATS`], compiled on first use of each calling code
+static COMPILED: [OnceLock<Vec<CompiledFormat>>; data::FORMATS_LEN] =
+    [const { OnceLock::new() }; data::FORMATS_LEN];
+
+///
+/// The ways of writing a phone number
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FormatStyle {
+    /// All the digits following a `+`, e.g. `+442079460958`
+    E164,
+    /// Digits grouped as in the territory, following the calling code, e.g. `+44 20 7946 0958`
+    International,
+    /// Digits grouped as dialed within the territory, with its national prefix, e.g. `020 7946 0958`
+    National,
+    /// A `tel` URI, as defined by RFC 3966, e.g. `tel:+44-20-7946-0958`
+    Rfc3966,
+}
+
+impl PhoneNumber {
+    /// Writes this phone number in the given style, grouping digits as in its territory
+    /// ```
+    /// use e164_phones_countries::{FormatStyle, PhoneNumber};
+    /// let phone: PhoneNumber = "+442079460958".parse().unwrap();
+    /// assert_eq!("+442079460958", phone.format(FormatStyle::E164));
+    /// assert_eq!("+44 20 7946 0958", phone.format(FormatStyle::International));
+    /// assert_eq!("020 7946 0958", phone.format(FormatStyle::National));
+    /// assert_eq!("tel:+44-20-7946-0958", phone.format(FormatStyle::Rfc3966));
+    /// ```
+    pub fn format(&self, style: FormatStyle) -> String {
+        let national_number = self.national_number_digits();
+        match style {
+            FormatStyle::E164 => self.to_string(),
+            FormatStyle::International => self.format_international(&national_number),
+            FormatStyle::National => self.group_national(&national_number),
+            FormatStyle::Rfc3966 => {
+                let mut uri = String::from("tel:");
+                let mut separated = false;
+                for ch in self.format_international(&national_number).chars() {
+                    if ch == '+' || ch.is_ascii_digit() {
+                        if separated {
+                            uri.push('-');
+                            separated = false;
+                        }
+                        uri.push(ch);
+                    } else {
+                        separated = true;
+                    }
+                }
+                uri
+            }
+        }
+    }
+
+    /// Writes this phone number as dialed from a territory: in national format within the
+    /// territory or another one sharing its calling code, and otherwise following the
+    /// international prefix of the territory, see [`TerritoryCode::international_prefix`]. Within
+    /// the +1 numbering plan, numbers are dialed with a leading `1` instead. When the territory
+    /// has no preferred international prefix, the number is written in international format.
+    /// ```
+    /// use e164_phones_countries::{PhoneNumber, TerritoryCode};
+    /// let phone: PhoneNumber = "+41446681800".parse().unwrap();
+    /// assert_eq!("011 41 44 668 18 00", phone.format_for_dialing_from(TerritoryCode::US));
+    /// assert_eq!("00 41 44 668 18 00", phone.format_for_dialing_from(TerritoryCode::DE));
+    /// assert_eq!("044 668 18 00", phone.format_for_dialing_from(TerritoryCode::CH));
+    ///
+    /// let phone: PhoneNumber = "+16135550123".parse().unwrap();
+    /// assert_eq!(TerritoryCode::CA, phone.territory());
+    /// assert_eq!("1 (613) 555-0123", phone.format_for_dialing_from(TerritoryCode::US));
+    /// ```
+    pub fn format_for_dialing_from(&self, territory: TerritoryCode) -> String {
+        let national_number = self.national_number_digits();
+        let calling_code = self.calling_code().get();
+        let from_calling_code = country_calling_code(territory.calling_codes().primary());
+        if calling_code == from_calling_code {
+            let national = self.group_national(&national_number);
+            return match calling_code {
+                1 => format!("1 {national}"),
+                _ => national,
+            };
+        }
+        match territory.international_prefix() {
+            Some(prefix) => format!(
+                "{prefix} {calling_code} {}",
+                self.group_international(&national_number)
+            ),
+            None => self.format_international(&national_number),
+        }
+    }
+
+    fn format_international(&self, national_number: &str) -> String {
+        format!(
+            "+{} {}",
+            self.calling_code(),
+            self.group_international(national_number)
+        )
+    }
+
+    /// Groups the digits of the national significant number as written after the calling code
+    fn group_international(&self, national_number: &str) -> String {
+        self.find_format(national_number, |format| format.international)
+            .map(|(format, groups)| fill(format.international.unwrap_or_default(), &groups))
+            .unwrap_or_else(|| national_number.into())
+    }
+
+    /// Groups the digits of the national significant number as dialed within the territory
+    fn group_national(&self, national_number: &str) -> String {
+        self.find_format(national_number, |format| Some(format.national))
+            .map(|(format, groups)| {
+                let template = match format.national_prefix_rule {
+                    Some(rule) => with_national_prefix(format.national, rule),
+                    None => format.national.into(),
+                };
+                fill(&template, &groups)
+            })
+            .unwrap_or_else(|| national_number.into())
+    }
+
+    /// Finds the first format of the calling code which applies to the national significant
+    /// number and has a template for the style
+    fn find_format<'a>(
+        &self,
+        national_number: &'a str,
+        template: impl Fn(&NumberFormat) -> Option<&'static str>,
+    ) -> Option<(&'static NumberFormat, Vec<&'a str>)> {
+        let index = data::FORMATS
+            .binary_search_by_key(&self.calling_code().get(), |(code, _)| *code)
+            .ok()?;
+        let formats = data::FORMATS[index].1;
+        let compiled =
+            COMPILED[index].get_or_init(|| formats.iter().map(CompiledFormat::new).collect());
+        formats
+            .iter()
+            .zip(compiled)
+            .filter(|(format, _)| template(format).is_some())
+            .filter(|(_, compiled)| compiled.leading_digits.matches_start(national_number))
+            .find_map(|(format, compiled)| {
+                let groups = compiled.pattern.captures(national_number)?;
+                Some((format, groups))
+            })
+    }
+}
+
+/// Replaces the first group of a template by the national prefix formatting rule, in which `$FG`
+/// stands for that group
+fn with_national_prefix(template: &str, rule: &str) -> String {
+    match template.find('$') {
+        Some(start) => {
+            let first_group = &template[start..start + 2];
+            let rule = rule.replacen("$FG", first_group, 1);
+            template.replacen(first_group, &rule, 1)
+        }
+        None => template.into(),
+    }
+}
+
+/// Replaces the `$1` to `$9` placeholders of a template by groups of digits
+fn fill(template: &str, groups: &[&str]) -> String {
+    let mut filled = String::with_capacity(template.len() + 16);
+    let mut chars = template.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '$' => {
+                let group = chars.next().and_then(|index| index.to_digit(10));
+                let group = group.and_then(|index| groups.get((index as usize).checked_sub(1)?));
+                if let Some(group) = group {
+                    filled.push_str(group);
+                }
+            }
+            ch => filled.push(ch),
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn format_data_is_valid() {
+        let mut codes = HashSet::new();
+        for (code, formats) in &data::FORMATS {
+            assert!(codes.insert(code), "{}", code);
+            for format in *formats {
+                CompiledFormat::new(format);
+                let groups = format.pattern.matches('(').count();
+                let templates = [Some(format.national), format.international];
+                for template in templates.iter().flatten() {
+                    let placeholders = template.split('$').skip(1);
+                    for placeholder in placeholders {
+                        let group = placeholder[..1].parse::<usize>().unwrap();
+                        assert!((1..=groups).contains(&group), "{}: {}", code, template);
+                    }
+                }
+            }
+        }
+        assert!(data::FORMATS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn format_in_all_styles() {
+        for (input, international, national, rfc3966) in [
+            (
+                "+12069359290",
+                "+1 206-935-9290",
+                "(206) 935-9290",
+                "tel:+1-206-935-9290",
+            ),
+            (
+                "+390612345678",
+                "+39 06 1234 5678",
+                "06 1234 5678",
+                "tel:+39-06-1234-5678",
+            ),
+            (
+                "+33612345678",
+                "+33 6 12 34 56 78",
+                "06 12 34 56 78",
+                "tel:+33-6-12-34-56-78",
+            ),
+            (
+                "+74951234567",
+                "+7 495 123-45-67",
+                "8 (495) 123-45-67",
+                "tel:+7-495-123-45-67",
+            ),
+            (
+                "+5491123456789",
+                "+54 9 11 2345-6789",
+                "011 15-2345-6789",
+                "tel:+54-9-11-2345-6789",
+            ),
+            (
+                "+80012345678",
+                "+800 1234 5678",
+                "1234 5678",
+                "tel:+800-1234-5678",
+            ),
+        ] {
+            let phone: PhoneNumber = input.parse().unwrap();
+            assert_eq!(input, phone.format(FormatStyle::E164));
+            assert_eq!(international, phone.format(FormatStyle::International));
+            assert_eq!(national, phone.format(FormatStyle::National));
+            assert_eq!(rfc3966, phone.format(FormatStyle::Rfc3966));
+        }
+    }
+
+    #[test]
+    fn format_for_dialing_from_territories() {
+        let phone: PhoneNumber = "+41446681800".parse().unwrap();
+        for (territory, expected) in [
+            (TerritoryCode::US, "011 41 44 668 18 00"),
+            (TerritoryCode::CA, "011 41 44 668 18 00"),
+            (TerritoryCode::DE, "00 41 44 668 18 00"),
+            (TerritoryCode::RU, "810 41 44 668 18 00"),
+            (TerritoryCode::AU, "0011 41 44 668 18 00"),
+            (TerritoryCode::BR, "+41 44 668 18 00"),
+            (TerritoryCode::CH, "044 668 18 00"),
+            (TerritoryCode::LI, "00 41 44 668 18 00"),
+        ] {
+            assert_eq!(
+                expected,
+                phone.format_for_dialing_from(territory),
+                "{territory}"
+            );
+        }
+
+        let phone: PhoneNumber = "+77172123456".parse().unwrap();
+        assert_eq!(TerritoryCode::KZ, phone.territory());
+        assert_eq!(
+            "8 (7172) 12 34 56",
+            phone.format_for_dialing_from(TerritoryCode::RU)
+        );
+        let phone: PhoneNumber = "+441481123456".parse().unwrap();
+        assert_eq!(
+            "01481 123456",
+            phone.format_for_dialing_from(TerritoryCode::GB)
+        );
+        let phone: PhoneNumber = "+12069359290".parse().unwrap();
+        assert_eq!(
+            "1 (206) 935-9290",
+            phone.format_for_dialing_from(TerritoryCode::JM)
+        );
+    }
+
+    #[test]
+    fn format_without_matching_pattern() {
+        use std::convert::TryFrom;
+        let phone = PhoneNumber::try_from(4420794).unwrap();
+        assert_eq!("+44 20794", phone.format(FormatStyle::International));
+        assert_eq!("20794", phone.format(FormatStyle::National));
+        assert_eq!("tel:+44-20794", phone.format(FormatStyle::Rfc3966));
+    }
+}
@@ -0,0 +1,6377 @@
+// Generated by `cargo run -p metadata-gen -- PhoneNumberMetadata.xml`. Do not edit.
+// Number formats of libphonenumber's PhoneNumberMetadata.xml, keyed by country calling code and
+// taken from the main territory of each code. National prefix formatting rules have `$NP`
+// replaced by the national prefix. Formats which must not be used internationally have no
+// `international` format.
+
+use super::NumberFormat;
+
+/// The number of calling codes with formats
+pub(super) const FORMATS_LEN: usize = 210;
+
+#[rustfmt::skip]
+pub(super) static FORMATS: [(u32, &[NumberFormat]); FORMATS_LEN] = [
+    (
+        1,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"310",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[24-9]|3(?:[02-9]|1[1-9])",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "($1) $2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        7,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[0-79]",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"7(?:1(?:[0-356]2|4[29]|7|8[27])|2(?:13[03-69]|62[013-9]))|72[1-57-9]2",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("8 ($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d)(\d{2})(\d{2})",
+                leading_digits: r"7(?:1(?:0(?:[356]|4[023])|[18]|2(?:3[013-9]|5)|3[45]|43[013-79]|5(?:3[1-8]|4[1-7]|5)|6(?:3[0-35-9]|[4-6]))|2(?:1(?:3[178]|[45])|[24-689]|3[35]|7[457]))|7(?:14|23)4[0-8]|71(?:33|45)[1-79]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("8 ($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("8 ($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[349]|8(?:[02-7]|1[1-8])",
+                national: "$1 $2-$3-$4",
+                international: Some("$1 $2-$3-$4"),
+                national_prefix_rule: Some("8 ($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("8 ($FG)"),
+            },
+        ],
+    ),
+    (
+        20,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{7,8})",
+                leading_digits: r"[23]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6,7})",
+                leading_digits: r"1[35]|[4-6]|8[2468]|9[235-7]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{8})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        27,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})",
+                leading_digits: r"8[1-4]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2,3})",
+                leading_digits: r"8[1-4]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"860",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        30,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"21|7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})",
+                leading_digits: r"2(?:2|3[2-57-9]|4[2-469]|5[2-59]|6[2-9]|7[2-69]|8[2-49])|5",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[2689]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})(\d{5})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        31,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"1[238]|[34]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})",
+                leading_digits: r"14",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"1",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4,7})",
+                leading_digits: r"[89]0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"66",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{8})",
+                leading_digits: r"6",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"1[16-8]|2[259]|3[124]|4[17-9]|5[124679]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[1-578]|91",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{5})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        32,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"(?:80|9)0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[239]|4[23]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[15-8]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"4",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        33,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"10",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0 $FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[1-79]",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        34,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"905",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"[79]9",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[89]00",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        36,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(06 $FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[27][2-9]|3[2-7]|4[24-9]|5[2-79]|6|8[2-57-9]|9[2-69]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(06 $FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("06 $FG"),
+            },
+        ],
+    ),
+    (
+        39,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4,5})",
+                leading_digits: r"1(?:0|9(?:2[2-9]|[46]))",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"1(?:1|92)",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,6})",
+                leading_digits: r"0[26]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,6})",
+                leading_digits: r"0[13-57-9][0159]|8(?:03|4[17]|9(?:2|3[04]|[45][0-4]))",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2,6})",
+                leading_digits: r"0(?:[13-579][2-46-8]|8[236-8])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"894",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})(\d{4})",
+                leading_digits: r"0[26]|5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"1(?:44|[679])|[378]|43",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})(\d{4})",
+                leading_digits: r"0[13-57-9][0159]|14",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{5})",
+                leading_digits: r"0[26]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4,5})",
+                leading_digits: r"[03]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        40,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"2[3-6]\d9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})",
+                leading_digits: r"219|31",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[23]1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[236-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        41,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"8[047]|90",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[2-79]|81",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        43,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"14",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3,12})",
+                leading_digits: r"1(?:11|[2-9])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})",
+                leading_digits: r"517",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,5})",
+                leading_digits: r"5[079]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"[18]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,10})",
+                leading_digits: r"(?:31|4)6|51|6(?:485|5[0-3579]|[6-9])|7(?:20|32|8)|[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,9})",
+                leading_digits: r"[2-467]|5[2-6]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4,7})",
+                leading_digits: r"5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        44,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"8001111",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"845464",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"800",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4,5})",
+                leading_digits: r"1(?:3873|5(?:242|39[4-6])|(?:697|768)[347]|9467)",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5,6})",
+                leading_digits: r"1(?:[2-69][02-9]|[78])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"[25]|7(?:0|6(?:[03-9]|2[356]))",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[1389]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        45,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        46,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2,3})(\d{2})",
+                leading_digits: r"20",
+                national: "$1-$2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"9(?:00|39|44|9)",
+                national: "$1-$2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})",
+                leading_digits: r"[12][136]|3[356]|4[0246]|6[03]|90[1-9]",
+                national: "$1-$2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{2,3})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2,3})(\d{2})",
+                leading_digits: r"1[2457]|2(?:[247-9]|5[0138])|3[0247-9]|4[1357-9]|5[0-35-9]|6(?:[125689]|4[02-57]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])",
+                national: "$1-$2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2,3})(\d{3})",
+                leading_digits: r"9(?:00|39|44)",
+                national: "$1-$2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2,3})(\d{2})(\d{2})",
+                leading_digits: r"1[13689]|2[0136]|3[1356]|4[0246]|54|6[03]|90[1-9]",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"10|7",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})(\d{2})",
+                leading_digits: r"8",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[13-5]|2(?:[247-9]|5[0138])|6(?:[124-689]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"9",
+                national: "$1-$2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[26]",
+                national: "$1-$2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        47,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[2-79]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        48,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"19",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"11|20|64",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])19",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2,3})",
+                leading_digits: r"64",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"21|39|45|5[0137]|6[0469]|7[02389]|8(?:0[14]|8)",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"1[2-8]|[2-7]|8[1-79]|9[145]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        49,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,13})",
+                leading_digits: r"3[02]|40|[68]9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"2277",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,12})",
+                leading_digits: r"2(?:0[1-389]|12[0-8])|3(?:[35-9][15]|4[015])|906|2(?:[13][14]|2[18])|(?:2[4-9]|4[2-9]|[579][1-9]|[68][1-8])1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2,11})",
+                leading_digits: r"[24-6]|3(?:3(?:0[1-467]|2[127-9]|3[124578]|7[1257-9]|8[1256]|9[145])|4(?:2[135]|4[13578]|9[1346])|5(?:0[14]|2[1-3589]|6[1-4]|7[13468]|8[13568])|6(?:2[1-489]|3[124-6]|6[13]|7[12579]|8[1-356]|9[135])|7(?:2[1-7]|4[145]|6[1-5]|7[1-4])|8(?:21|3[1468]|6|7[1467]|8[136])|9(?:0[12479]|2[1358]|4[134679]|6[1-9]|7[136]|8[147]|9[1468]))|70[2-8]|8(?:0[2-9]|[1-8])|90[7-9]|[79][1-9]|3[68]4[1347]|3(?:47|60)[1356]|3(?:3[46]|46|5[49])[1246]|3[4579]3[1357]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"138",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{2,10})",
+                leading_digits: r"3",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,11})",
+                leading_digits: r"181",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{4,10})",
+                leading_digits: r"1(?:3|80)|9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7,8})",
+                leading_digits: r"1[67]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7,12})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{6})",
+                leading_digits: r"18500",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{7})",
+                leading_digits: r"18[68]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{7})",
+                leading_digits: r"15[1279]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{6})",
+                leading_digits: r"15(?:[0568]|3[13])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{8})",
+                leading_digits: r"18",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{7,8})",
+                leading_digits: r"1(?:6[023]|7)",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{7})",
+                leading_digits: r"15[279]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{8})",
+                leading_digits: r"15",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        51,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"80",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{7})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"[4-8]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        52,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"53",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"33|5[56]|81",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        53,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,6})",
+                leading_digits: r"2[1-4]|[34]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{6,7})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{7})",
+                leading_digits: r"[56]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        54,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})",
+                leading_digits: r"0|1(?:0[0-35-7]|1[02-5]|2[015]|3[47]|4[478])|911",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})",
+                leading_digits: r"[1-9]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[1-8]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{4})",
+                leading_digits: r"2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|58|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|54(?:4|5[13-7]|6[89])|86[3-6]))|2(?:2[24-9]|3[1-59]|47)|38(?:[58][78]|7[378])|3(?:454|85[56])[46]|3(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[68]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[23]",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{2})(\d{4})",
+                leading_digits: r"9(?:2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|5(?:4[46]|8)|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|5(?:4(?:4|5[13-7]|6[89])|[56][46]|[78])|7[378]|8(?:6[3-6]|[78]))))|92(?:2[24-9]|3[1-59]|47)|93(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]",
+                national: "$2 15-$3-$4",
+                international: Some("$1 $2 $3-$4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"91",
+                national: "$2 15-$3-$4",
+                international: Some("$1 $2 $3-$4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{5})",
+                leading_digits: r"8",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"9",
+                national: "$2 15-$3-$4",
+                international: Some("$1 $2 $3-$4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        55,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3,6})",
+                leading_digits: r"1(?:1[25-8]|2[357-9]|3[02-68]|4[12568]|5|6[0-8]|8[015]|9[0-47-9])|321|610",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"300|4(?:0(?:0|20)|370|864)",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2357]|4(?:[0-24-9]|3(?:[0-689]|7[1-9]))",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2,3})(\d{4})",
+                leading_digits: r"(?:[358]|90)0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4})",
+                leading_digits: r"9",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])[2-57]",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})(\d{4})",
+                leading_digits: r"[16][1-9]|[2-57-9]",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("($FG)"),
+            },
+        ],
+    ),
+    (
+        56,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"1(?:[03-589]|21)|[29]0|78",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4})",
+                leading_digits: r"2196",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"60|809",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"44",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"2[1-36]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"9(?:10|[2-9])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"3[2-5]|[47]|5[1-3578]|6[13-57]|8(?:0[1-8]|[1-9])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"60|8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"60",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        57,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"46",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"6|90",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("($FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"3[0-357]|9[14]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{7})",
+                leading_digits: r"1",
+                national: "$1-$2-$3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        58,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"[24-689]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        60,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[4-79]",
+                national: "$1-$2 $3",
+                international: Some("$1-$2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"1(?:[02469]|[37][1-9]|53|8(?:[1-46-9]|5[7-9]))|8",
+                national: "$1-$2 $3",
+                international: Some("$1-$2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"3",
+                national: "$1-$2 $3",
+                international: Some("$1-$2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{2})(\d{4})",
+                leading_digits: r"1(?:[367]|80)",
+                national: "$1-$2-$3-$4",
+                international: Some("$1-$2-$3-$4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"15",
+                national: "$1-$2 $3",
+                international: Some("$1-$2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"1",
+                national: "$1-$2 $3",
+                international: Some("$1-$2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        61,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})",
+                leading_digits: r"16",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"13",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"19",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"1802",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,4})",
+                leading_digits: r"19",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2,4})",
+                leading_digits: r"16",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"14|4",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"[2378]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1(?:30|[89])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})(\d{4})",
+                leading_digits: r"130",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        62,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"15",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5,9})",
+                leading_digits: r"2[124]|[36]1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,7})",
+                leading_digits: r"800",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,8})",
+                leading_digits: r"[2-79]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})(\d{3})",
+                leading_digits: r"8[1-35-9]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6,8})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"804",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{3})(\d{3})",
+                leading_digits: r"80",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4,5})",
+                leading_digits: r"8",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{2,8})",
+                leading_digits: r"001",
+                national: "$1 $2 $3 $4",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        63,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{5})",
+                leading_digits: r"2",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4,6})",
+                leading_digits: r"3(?:230|397|461)|4(?:2(?:35|[46]4|51)|396|4(?:22|63)|59[347]|76[15])|5(?:221|446)|642[23]|8(?:622|8(?:[24]2|5[13]))",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4})",
+                leading_digits: r"3469|4(?:279|9(?:30|56))|8834",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[3-7]|8[2-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{1,2})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        64,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,8})",
+                leading_digits: r"8[1-79]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2,3})",
+                leading_digits: r"50(?:[0367]|88)|8|90",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"24|[346]|7[2-57-9]|9[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"2(?:10|74)|[589]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})(\d{4})",
+                leading_digits: r"1|2[028]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,5})",
+                leading_digits: r"2(?:[169]|7[0-35-9])|7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        65,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4,5})",
+                leading_digits: r"1(?:[013-8]|9(?:0[1-9]|[1-9]))|77",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[369]|8(?:0[1-9]|[1-9])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})(\d{3})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        66,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[13-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        81,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"00777[01]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{8,10})",
+                leading_digits: r"000",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"(?:12|57|99)0",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d)(\d{4})",
+                leading_digits: r"1(?:267|3(?:7[247]|9[278])|466|5(?:47|58|64)|6(?:3[245]|48|5[4-68]))|499[2468]|5(?:769|979[2-69])|7468|8(?:3(?:8[7-9]|96[2457-9])|477|51[2-9])|9(?:802|9(?:1[23]|69))|1(?:45|58)[67]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"60",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"3|4(?:2(?:0|9[02-69])|7(?:0[019]|1))|6[1-9]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"1(?:1|5(?:4[018]|5[017])|77|88|9[69])|2(?:2[127]|3[0-269]|4[59]|5(?:[1-3]|5[0-69]|9(?:17|99))|6(?:2|4[016-9])|7(?:[1-35]|8[0189])|8(?:[16]|3[0134]|9[0-5])|9(?:[028]|17))|4(?:2(?:[13-79]|8[014-6])|3[0-57]|[45]|6[248]|7[2-47]|9[29])|5(?:2|3(?:[045]|9(?:[0-58]|6[4-9]|7[0-35689]))|4[0-369]|5[29]|8[02389]|9[0-3])|7(?:2[02-46-9]|34|[58]|6[0249]|7[57]|9(?:[23]|4[0-59]|5[01569]|6[0167]))|8(?:2(?:[1258]|4[0-39]|9[0169])|3(?:[29]|60|7(?:[017-9]|6[6-8]))|49|51|6(?:[0-24]|36[2-57-9]|5(?:[0-389]|5[23])|6(?:[01]|9[178])|7(?:2[2-468]|3[78])|9[0145])|7[0-468]|8[68])|9(?:4[15]|5[138]|7[156]|8[189]|9(?:[1289]|3(?:31|4[357])|4[0178]))|(?:8294|96)[1-3]|2(?:57|93)[015-9]|(?:223|8699)[014-9]|(?:25[0468]|422|838)[01]|(?:48|8292|9[23])[1-9]|(?:47[59]|59[89]|8(?:68|9))[019]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{4})",
+                leading_digits: r"[14]|[289][2-9]|5[3-9]|7[2-4679]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{3,4})",
+                leading_digits: r"0077",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{4})",
+                leading_digits: r"008",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"800",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"[25-9]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3,4})",
+                leading_digits: r"0",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})(\d{4,5})",
+                leading_digits: r"0",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})(\d{5,6})",
+                leading_digits: r"0",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})(\d{6,7})",
+                leading_digits: r"0",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        82,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"1[016-9]114",
+                national: "$1",
+                international: None,
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})",
+                leading_digits: r"(?:3[1-3]|[46][1-4]|5[1-5])1",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"1",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3,4})(\d{4})",
+                leading_digits: r"2",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[36]0|8",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})(\d{4})",
+                leading_digits: r"[1346]|5[1-5]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"[57]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{3})(\d{3})",
+                leading_digits: r"0030",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})(\d{4})",
+                leading_digits: r"5",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{3,4})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        84,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[17]99",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"80",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4,5})",
+                leading_digits: r"69",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4,6})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"6",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[357-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"2[48]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{3})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        86,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5,6})",
+                leading_digits: r"1(?:00|2(?:1|395))|9[56]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{5,6})",
+                leading_digits: r"1(?:0|23(?:[0-8]|9[0-46-9]))|78123|[1-9]123",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5,6})",
+                leading_digits: r"10(?:1(?:0|23)|9[56])|2[0-57-9](?:1(?:00|23)|9[56])|(?:3(?:[157]\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\d|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))123",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"1(?:0(?:[02-8]|1(?:[013-9]|2[0-24-9])|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[02-8]|1(?:0[1-9]|[13-9]|2[0-24-9])|9[0-47-9])|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|1[03]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|8[1-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:0(?:[0-689]|7[0-79])|1[01459]|2[0-489]|[46]|50|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9]|78|94)|(?:3(?:[157]\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\d|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-9]|1(?:[013-9]|2[0-24-9]))",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"16[08]",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,6})",
+                leading_digits: r"85[23](?:100|95)|(?:3(?:[157]\d|35|49|9[1-68])|4(?:[17]\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\d|4[13]|5[1-5]))(?:100|9[56])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"1(?:0[02-8]|[1-9])|2(?:[0-57-9][0-8]|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:[046]|1[01459]|2[0-489]|5(?:0|[23](?:[02-8]|1[1-9]|9[0-46-9]))|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9])|(?:10|2[0-57-9])9[0-47-9]|(?:101|58|85[23]10)[1-9]|(?:3(?:[157]\d|35|49|9[1-68])|4(?:[17]\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\d|4[13]|5[1-5]))(?:[02-8]|1(?:0[1-9]|[1-9])|9[0-47-9])",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"(?:4|80)0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"10[0-79]|2(?:[02-57-9]|1[1-79])|(?:10|21)8(?:0[1-9]|[1-9])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"3(?:[3-59]|7[02-68])|4(?:[26-8]|3[3-9]|5[2-9])|5(?:3[03-9]|[468]|7[028]|9[2-46-9])|6|7(?:[0-247]|3[04-9]|5[0-4689]|6[2368])|8(?:[1-358]|9[1-7])|9(?:[013479]|5[1-5])|(?:[34]1|55|79|87)[02-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7,8})",
+                leading_digits: r"9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"80",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"[3-578]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"1[3-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[12]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        90,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{3})",
+                leading_digits: r"444",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"512|8[01589]|90",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"5(?:[0-59]|61[06]1)",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[24][1-8]|3[1-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{6,7})",
+                leading_digits: r"80",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        91,
+        &[
+            NumberFormat {
+                pattern: r"(\d{7})",
+                leading_digits: r"575",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{8})",
+                leading_digits: r"5(?:0|2(?:21|3)|3(?:0|3[23])|616|717|8888)",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4,5})",
+                leading_digits: r"1800",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"140",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"11|2[02]|33|4[04]|79(?:[124-6]|3(?:[02-9]|1[0-24-9])|7(?:1|9[1-6]))|80(?:[2-4]|6[0-589])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"1(?:2[0-24]|3[0-25]|4[145]|[59][14]|6[1-9]|7[1257]|8[1-57-9])|2(?:1[257]|3[013]|4[01]|5[0137]|6[058]|78|8[1568]|9[14])|3(?:26|4[1-3]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[0-24-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|6(?:12(?:[2-6]|7[0-8])|74[2-7])|7(?:(?:2[14]|5[15])[2-6]|3171|61[346]|88(?:[2-7]|82))|8(?:70[2-6]|84(?:[2356]|7[19])|91(?:[3-6]|7[19]))|73[134][2-6]|(?:74[47]|8(?:16|2[014]|3[126]|6[136]|7[78]|83))(?:[2-6]|7[19])|(?:1(?:29|60|8[06])|261|552|6(?:[2-4]1|5[17]|6[13]|7(?:1|4[0189])|80)|7(?:12|88[01]))[2-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1(?:[2-479]|5(?:[0236-9]|5[013-9]))|[2-5]|6(?:2(?:84|95)|355|8(?:28[235-7]|3))|73179|807(?:1|9[1-3])|(?:1552|6(?:(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578]|7[235689])\d|8(?:[14-6]\d|2[0-79]))|7(?:1(?:[013-8]\d|9[6-9])|28[6-8]|3(?:2[0-49]|9[2-57])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]\d|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4\d|5[0-367])|70[13-7]))[2-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{5})",
+                leading_digits: r"16|[6-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2,4})(\d{4})",
+                leading_digits: r"18[06]0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"18",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        92,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2,7})",
+                leading_digits: r"[89]0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6,7})",
+                leading_digits: r"9(?:2[3-8]|98)|(?:2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:22|3[27-9]|4[2-6]|6[3569]|9[25-7]))[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7,8})",
+                leading_digits: r"(?:2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91)[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{5})",
+                leading_digits: r"58",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"3",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[24-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+        ],
+    ),
+    (
+        93,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[2-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        94,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[1-689]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        95,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{3})",
+                leading_digits: r"16|2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"4(?:[2-46]|5[3-5])|5|6(?:[1-689]|7[235-7])|7(?:[0-4]|5[2-7])|8[1-5]|(?:60|86)[23]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3,4})",
+                leading_digits: r"[12]|452|6788|86",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[4-7]|8[1-35]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4,6})",
+                leading_digits: r"9(?:2[0-4]|[35-9]|4[137-9])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"92",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{5})(\d{4})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        98,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4,5})",
+                leading_digits: r"96",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,5})",
+                leading_digits: r"(?:1[137]|2[13-68]|3[1458]|4[145]|5[1468]|6[16]|7[1467]|8[13467])[12689]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"[1-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        211,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[19]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        212,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"5[45]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"5(?:[19]|2[2-46-9]|3[3-9])|8(?:0[89]|92)",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"8",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"[5-7]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        213,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[1-4]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"9",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-8]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        216,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[2-57-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        218,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"[2-9]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        220,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        221,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[379]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        222,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[2-48]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        223,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})",
+                leading_digits: r"67(?:0[09]|[59]9|77|8[89])|74(?:0[02]|44|55)",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[24-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        224,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"3",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[67]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        225,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d)(\d{5})",
+                leading_digits: r"2",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        226,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[024-7]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        227,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"08",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[089]|2[013]|7[0467]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        228,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[279]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        229,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        230,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-46]|8[013]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[57]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{5})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        231,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"4[67]|[56]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[2-578]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        232,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"[236-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+        ],
+    ),
+    (
+        233,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[237]|8[0-2]",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[235]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        234,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"[7-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"20[129]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4,5})",
+                leading_digits: r"[78]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})(\d{5,6})",
+                leading_digits: r"[78]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        235,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[236-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        236,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[278]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        237,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"88",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[26]|88",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        238,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[2-589]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        239,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[29]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        240,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[235]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        241,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[2-7]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"11|[67]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        242,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[02]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        243,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"88",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"[1-6]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"5",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        244,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[29]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        245,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"40",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[49]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        246,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"3",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        248,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"[246]|9[57]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        249,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[19]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        250,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[7-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        251,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[1-579]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        252,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})",
+                leading_digits: r"8[125]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"[134]",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{6})",
+                leading_digits: r"[15]|2[0-79]|3[0-46-8]|4[0-7]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{7})",
+                leading_digits: r"(?:2|90)4|[67]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[348]|64|79|90",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5,7})",
+                leading_digits: r"1|28|6[0-35-9]|7[67]|9[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        253,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[27]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        254,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5,7})",
+                leading_digits: r"[24-6]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"[17]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        255,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[24]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"5",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[67]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        256,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"2024",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"[27-9]|4(?:6[45]|[7-9])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"[34]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        257,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[2367]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        258,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"2|8[2-79]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        260,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[28]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"[579]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        261,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})(\d{2})",
+                leading_digits: r"[23]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        262,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[26-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        263,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,5})",
+                leading_digits: r"2(?:0[45]|2[278]|[49]8)|3(?:[09]8|17)|6(?:[29]8|37|75)|[23][78]|(?:33|5[15]|6[68])[78]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{2,4})",
+                leading_digits: r"[49]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"80",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"2(?:02[014]|4|[56]20|[79]2)|392|5(?:42|525)|6(?:[16-8]21|52[013])|8[13-59]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"2(?:1[39]|2[0157]|[378]|[56][14])|3(?:123|29)",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,5})",
+                leading_digits: r"1|2(?:0[0-36-9]|12|29|[56])|3(?:1[0-689]|[24-6])|5(?:[0236-9]|1[2-4])|6(?:[013-59]|7[0-46-9])|(?:33|55|6[68])[0-69]|(?:29|3[09]|62)[0-79]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"29[013-9]|39|54",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,5})",
+                leading_digits: r"258|5483",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        264,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"88",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"87",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        265,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"1[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[137-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        266,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2568]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        267,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"90",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[24-6]|3[15-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[37]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        268,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[0237]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4})",
+                leading_digits: r"9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        269,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[3478]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        291,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"[178]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        297,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[25-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        298,
+        &[
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"[2-9]",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        299,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"19|[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        350,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"2",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        351,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"2[12]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"16|[236-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        352,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})",
+                leading_digits: r"2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"20[2-689]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{1,2})",
+                leading_digits: r"2(?:[0367]|4[3-8])",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"80[01]|90[015]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"20",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})(\d{1,2})",
+                leading_digits: r"2(?:[0367]|4[3-8])",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{1,5})",
+                leading_digits: r"[3-57]|8[13-9]|9(?:0[89]|[2-579])|(?:2|80)[2-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        353,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"2[24-9]|47|58|6[237-9]|9[35-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[45]0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3,4})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[2569]|4[1-69]|7[14]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"70",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"81",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[78]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"4",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d)(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        354,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[4-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"3",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        355,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})",
+                leading_digits: r"80|9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"4[2-6]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[2358][2-5]|4",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[23578]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        356,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2357-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        357,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"[257-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        358,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"75[12]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"20[2-59]",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"11",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,7})",
+                leading_digits: r"(?:[1-3]0|[68])0|70[07-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,8})",
+                leading_digits: r"[14]|2[09]|50|7[135]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6,10})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4,9})",
+                leading_digits: r"(?:19|[2568])[1-8]|3(?:0[1-9]|[1-9])|9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        359,
+        &[
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"1",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d)(\d{2})(\d{2})",
+                leading_digits: r"2",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"43[1-6]|70[1-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3,4})",
+                leading_digits: r"2",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2,3})",
+                leading_digits: r"[356]|4[124-7]|7[1-9]|8[1-6]|9[1-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"(?:70|8)0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})",
+                leading_digits: r"43[1-7]|7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[48]|9[08]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        370,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"52[0-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0-$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"[7-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0 $FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"37|4(?:[15]|6[1-8])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0-$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[3-6]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0-$FG)"),
+            },
+        ],
+    ),
+    (
+        371,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[2679]|8[01]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        372,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[369]|4[3-8]|5(?:[02]|1(?:[0-8]|95)|5[0-478]|6(?:4[0-4]|5[1-589]))|7[1-9]|88",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,4})",
+                leading_digits: r"[45]|8(?:00[1-9]|[1-49])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        373,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"22|3",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"[25-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        374,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})",
+                leading_digits: r"[89]0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0 $FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"2|3[12]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"1|47",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"[3-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        375,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"800",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("8 $FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2,4})",
+                leading_digits: r"800",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("8 $FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{3})",
+                leading_digits: r"1(?:5[169]|6(?:3[1-3]|4|5[125])|7(?:1[3-9]|7[0-24-6]|9[2-7]))|2(?:1[35]|2[34]|3[3-5])",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("8 0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"1(?:[56]|7[467])|2[1-3]",
+                national: "$1 $2-$3-$4",
+                international: Some("$1 $2-$3-$4"),
+                national_prefix_rule: Some("8 0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[1-4]",
+                national: "$1 $2-$3-$4",
+                international: Some("$1 $2-$3-$4"),
+                national_prefix_rule: Some("8 0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("8 $FG"),
+            },
+        ],
+    ),
+    (
+        376,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"[135-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        377,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2})",
+                leading_digits: r"87",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"4",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[389]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[67]",
+                national: "$1 $2 $3 $4 $5",
+                international: Some("$1 $2 $3 $4 $5"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        378,
+        &[
+            NumberFormat {
+                pattern: r"(\d{6})",
+                leading_digits: r"[89]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-7]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})",
+                leading_digits: r"0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        380,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"6[12][29]|(?:35|4[1378]|5[12457]|6[49])2|(?:56|65)[24]|(?:3[1-46-8]|46)2[013-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"3[1-8]|4(?:[1367]|[45][6-9]|8[4-6])|5(?:[1-5]|6(?:[015689]|3[02389])|7[4-6])|6(?:[12][3-7]|[459])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[3-7]|89|9[1-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3,4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        381,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,9})",
+                leading_digits: r"(?:2[389]|39)0|[7-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5,10})",
+                leading_digits: r"[1-36]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        382,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        383,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[2-4]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"2|39",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7,10})",
+                leading_digits: r"3",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        385,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"6[01]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2,3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"6|7[245]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[2-57]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        386,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,6})",
+                leading_digits: r"8[09]|9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"59|8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[37][01]|4[0139]|51|6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[1-57]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+        ],
+    ),
+    (
+        387,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"[2-9]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"6[1-3]|[7-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[3-5]|6[56]",
+                national: "$1 $2-$3",
+                international: Some("$1 $2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"6",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        389,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"2|34[47]|4(?:[37]7|5[47]|64)",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[347]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{2})(\d{2})",
+                leading_digits: r"[58]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        420,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[2-8]|9[015-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})(\d{2})",
+                leading_digits: r"96",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        421,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{2})(\d{3,4})",
+                leading_digits: r"21",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2,3})",
+                leading_digits: r"[3-5][1-8]1[67]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})",
+                leading_digits: r"9090",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})(\d{2})",
+                leading_digits: r"2",
+                national: "$1/$2 $3 $4",
+                international: Some("$1/$2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[689]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[3-5]",
+                national: "$1/$2 $3 $4",
+                international: Some("$1/$2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        423,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[2379]|8(?:0(?:02|9)|7)",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"69",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        501,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-8]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})(\d{3})",
+                leading_digits: r"0",
+                national: "$1-$2-$3-$4",
+                international: Some("$1-$2-$3-$4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        502,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2-8]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        503,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[25-7]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        504,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[237-9]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        505,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[125-8]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        506,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2-7]|8[3-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        507,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[1-57-9]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[68]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        508,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[45]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        509,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"[2-589]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        590,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-79]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        591,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{7})",
+                leading_digits: r"[235]|4[46]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{8})",
+                leading_digits: r"[67]",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        592,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        593,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-7]",
+                national: "$1-$2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[2-7]",
+                national: "$1 $2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3,4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        594,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-7]|9[47]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        595,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,6})",
+                leading_digits: r"[2-9]0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{7})",
+                leading_digits: r"[15]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"[26]1|3[289]|4[1246-8]|7[1-3]|8[1-36]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4,5})",
+                leading_digits: r"2[279]|3[13-5]|4[359]|5|6(?:[34]|7[1-46-8])|7[46-8]|85",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"2[14-68]|3[26-9]|4[1246-8]|6(?:1|75)|7[1-35]|8[1-36]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"87",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{6})",
+                leading_digits: r"9(?:[5-79]|8[1-7])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[2-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        596,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[5-79]|8(?:0[6-9]|[36])",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        597,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"56",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"[2-5]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[6-9]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        598,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4,5})",
+                leading_digits: r"21",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,4})",
+                leading_digits: r"0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[49]0|8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[124]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2,4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{2,4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        599,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[3467]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"9[4-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        670,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-489]|70",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        672,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})",
+                leading_digits: r"1[0-3]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{5})",
+                leading_digits: r"[13]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        673,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-578]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        674,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[24-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        675,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"18|[2-69]|85",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[78]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        676,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})",
+                leading_digits: r"[2-4]|50|6[09]|7[0-24-69]|8[05]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})",
+                leading_digits: r"0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[5-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        677,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"6[89]|7|8[4-9]|9(?:[1-8]|9[0-8])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        678,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[57-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        679,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[235-9]|45",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{4})",
+                leading_digits: r"0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        680,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        681,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[47-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        682,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})",
+                leading_digits: r"[2-578]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        683,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        685,
+        &[
+            NumberFormat {
+                pattern: r"(\d{5})",
+                leading_digits: r"[2-5]|6[1-9]",
+                national: "$1",
+                international: Some("$1"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,7})",
+                leading_digits: r"[68]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        687,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})",
+                leading_digits: r"5[6-8]",
+                national: "$1",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[02-57-9]",
+                national: "$1.$2.$3",
+                international: Some("$1.$2.$3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        688,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})",
+                leading_digits: r"2",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})",
+                leading_digits: r"90",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"7",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        689,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"44",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"4|8[7-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        691,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[389]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        692,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[2-6]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        800,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"\d",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        808,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        850,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[2-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        852,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2,5})",
+                leading_digits: r"9003",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[2-7]|8[1-4]|9(?:0[1-9]|[1-8])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"9",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        853,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})",
+                leading_digits: r"0",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[268]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        855,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        856,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"2[13]|3[14]|[4-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{3})",
+                leading_digits: r"3",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[23]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        870,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[235-7]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        878,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})(\d{5})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        880,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,6})",
+                leading_digits: r"31[5-8]|[459]1",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3,7})",
+                leading_digits: r"3(?:[67]|8[013-9])|4(?:6[168]|7|[89][18])|5(?:6[128]|9)|6(?:[15]|28|4[14])|7[2-589]|8(?:0[014-9]|[12])|9[358]|(?:3[2-5]|4[235]|5[2-578]|6[0389]|76|8[3-7]|9[24])1|(?:44|66)[01346-9]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,6})",
+                leading_digits: r"[13-9]|2[23]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{7,8})",
+                leading_digits: r"2",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        881,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{5})",
+                leading_digits: r"[0-37-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{5,6})",
+                leading_digits: r"6",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        882,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{5})",
+                leading_digits: r"16|342",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"49",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"1[36]|9",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{3})",
+                leading_digits: r"3[23]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3,4})(\d{4})",
+                leading_digits: r"16",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4})",
+                leading_digits: r"10|23|3(?:[15]|4[57])|4|51",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})(\d{4})",
+                leading_digits: r"34",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4,5})(\d{5})",
+                leading_digits: r"[1-35]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        883,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{2,8})",
+                leading_digits: r"[14]|2[24-689]|3[02-689]|51[24-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"510",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"21",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})(\d{4})",
+                leading_digits: r"51[13]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[235]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        886,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d)(\d{4})",
+                leading_digits: r"202",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[258]0",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3,4})(\d{4})",
+                leading_digits: r"[23568]|4(?:0[2-48]|[1-47-9])|(?:400|7)[1-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[49]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{4})(\d{4,5})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        888,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{5})",
+                leading_digits: r"",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        960,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"[34679]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"[89]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        961,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"[13-69]|7(?:[2-57]|62|8[0-6]|9[04-9])|8[02-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3})",
+                leading_digits: r"[27-9]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        962,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[2356]|87",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,6})",
+                leading_digits: r"[89]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{7})",
+                leading_digits: r"70",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"[47]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        963,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[1-4]|5[1-3]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[59]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        964,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{3,4})",
+                leading_digits: r"[2-6]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        965,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3,4})",
+                leading_digits: r"[169]|2(?:[235]|4[1-35-9])|52",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5})",
+                leading_digits: r"[245]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        966,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"9",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{4})",
+                leading_digits: r"8",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        967,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3,4})",
+                leading_digits: r"[1-6]|7(?:[24-6]|8[0-7])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        968,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4,6})",
+                leading_digits: r"[58]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"2",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[179]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        970,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[2489]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        971,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2,9})",
+                leading_digits: r"60|8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[236]|[479][2-8]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{5})",
+                leading_digits: r"[479]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"5",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        972,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})",
+                leading_digits: r"125",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{2})",
+                leading_digits: r"121",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{4})",
+                leading_digits: r"[2-489]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"[57]",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{3})(\d{3})",
+                leading_digits: r"12",
+                national: "$1-$2-$3",
+                international: Some("$1-$2-$3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{6})",
+                leading_digits: r"159",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"1[7-9]",
+                national: "$1-$2-$3-$4",
+                international: Some("$1-$2-$3-$4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{1,2})(\d{3})(\d{4})",
+                leading_digits: r"15",
+                national: "$1-$2 $3-$4",
+                international: Some("$1-$2 $3-$4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        973,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[13679]|8[02-4679]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        974,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{4})",
+                leading_digits: r"2[136]|8",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[3-7]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        975,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})",
+                leading_digits: r"[2-7]",
+                national: "$1 $2",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d)(\d{3})(\d{3})",
+                leading_digits: r"[2-6]|7[246]|8[2-4]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"1[67]|[78]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        976,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{4})",
+                leading_digits: r"[12]1",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{4})",
+                leading_digits: r"[5-9]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{5,6})",
+                leading_digits: r"[12]2[1-3]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5,6})",
+                leading_digits: r"[12](?:27|3[2-8]|4[2-68]|5[1-4689])[0-3]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{5})(\d{4,5})",
+                leading_digits: r"[12]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        977,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{7})",
+                leading_digits: r"1[2-6]",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"1[01]|[2-8]|9(?:[1-59]|[67][2-6])",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{7})",
+                leading_digits: r"9",
+                national: "$1-$2",
+                international: Some("$1-$2"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d{2})(\d{5})",
+                leading_digits: r"1",
+                national: "$1-$2-$3",
+                international: None,
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        979,
+        &[
+            NumberFormat {
+                pattern: r"(\d)(\d{4})(\d{4})",
+                leading_digits: r"[1359]",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        992,
+        &[
+            NumberFormat {
+                pattern: r"(\d{6})(\d)(\d{2})",
+                leading_digits: r"3317",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{4})",
+                leading_digits: r"44[02-479]|[34]7",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{4})(\d)(\d{4})",
+                leading_digits: r"3(?:[1245]|3[12])",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{4})",
+                leading_digits: r"\d",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+    (
+        993,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"12",
+                national: "$1 $2-$3-$4",
+                international: Some("$1 $2-$3-$4"),
+                national_prefix_rule: Some("(8 $FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d)(\d{2})(\d{2})",
+                leading_digits: r"[1-5]",
+                national: "$1 $2-$3-$4",
+                international: Some("$1 $2-$3-$4"),
+                national_prefix_rule: Some("(8 $FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{6})",
+                leading_digits: r"[67]",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("8 $FG"),
+            },
+        ],
+    ),
+    (
+        994,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[1-9]",
+                national: "$1 $2 $3",
+                international: None,
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"90",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"1[28]|2|365(?:4|5[02])|46",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("(0$FG)"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[13-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        995,
+        &[
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"70",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"32",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[57]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{2})(\d{2})(\d{2})",
+                leading_digits: r"[348]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        996,
+        &[
+            NumberFormat {
+                pattern: r"(\d{4})(\d{5})",
+                leading_digits: r"3(?:1[346]|[24-79])",
+                national: "$1 $2",
+                international: Some("$1 $2"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d{3})",
+                leading_digits: r"[235-79]|88",
+                national: "$1 $2 $3",
+                international: Some("$1 $2 $3"),
+                national_prefix_rule: Some("0$FG"),
+            },
+            NumberFormat {
+                pattern: r"(\d{3})(\d{3})(\d)(\d{2,3})",
+                leading_digits: r"8",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: Some("0$FG"),
+            },
+        ],
+    ),
+    (
+        998,
+        &[
+            NumberFormat {
+                pattern: r"(\d{2})(\d{3})(\d{2})(\d{2})",
+                leading_digits: r"[235-9]",
+                national: "$1 $2 $3 $4",
+                international: Some("$1 $2 $3 $4"),
+                national_prefix_rule: None,
+            },
+        ],
+    ),
+];
@@ -0,0 +1,110 @@
+use crate::locale::{locale_fallbacks, FALLBACK_LOCALE};
+use crate::prefix::{longest_prefix, Prefixed};
+use crate::{NumberType, PhoneNumber};
+
+mod data;
+
+/// The geographic area of the phone numbers starting with a prefix
+struct Area {
+    /// The leading digits of the international phone numbers, including the calling code, like
+    /// the prefixes of the lookup table
+    prefix: u64,
+    /// The locales and descriptions of the area, English first
+    descriptions: &'static [(&'static str, &'static str)],
+}
+
+impl Prefixed for Area {
+    fn prefix(&self) -> u64 {
+        self.prefix
+    }
+}
+
+impl PhoneNumber {
+    /// Describes the geographic area of this phone number in English, like
+    /// [`PhoneNumber::localized_location`] does.
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+49 30 12345678".parse().unwrap();
+    /// assert_eq!(Some("Berlin"), phone.location());
+    /// let phone: PhoneNumber = "+1 212 555 0123".parse().unwrap();
+    /// assert_eq!(Some("New York, NY"), phone.location());
+    /// ```
+    pub fn location(&self) -> Option<&'static str> {
+        self.localized_location(FALLBACK_LOCALE)
+    }
+
+    /// Describes the geographic area of this phone number in the language of a BCP 47 locale,
+    /// like `de` or `fr-CA`. The description is that of the longest known prefix of the number,
+    /// in the locale or the nearest of its parents, and ultimately in English. Phone numbers of
+    /// the United States and Canada without a known prefix are described by the state or province
+    /// of their area code, whose name is in English in every locale.
+    ///
+    /// There is none for numbers which are not fixed-line, like mobile or toll-free numbers, since
+    /// they are not tied to an area.
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+32 2 555 12 12".parse().unwrap();
+    /// assert_eq!(Some("Bruxelles"), phone.localized_location("fr-BE"));
+    /// assert_eq!(Some("Brussels"), phone.localized_location("pt"));
+    /// let phone: PhoneNumber = "+1 418 555 0123".parse().unwrap();
+    /// assert_eq!(Some("Quebec"), phone.localized_location("fr-CA"));
+    /// let mobile: PhoneNumber = "+49 151 23456789".parse().unwrap();
+    /// assert_eq!(None, mobile.localized_location("de"));
+    /// ```
+    pub fn localized_location(&self, locale: &str) -> Option<&'static str> {
+        if !matches!(
+            self.number_type(),
+            NumberType::FixedLine | NumberType::FixedLineOrMobile
+        ) {
+            return None;
+        }
+        let area = match longest_prefix(&data::AREAS, self) {
+            Some(area) => area,
+            None => return self.subdivision().map(|subdivision| subdivision.name()),
+        };
+        let description = locale_fallbacks(locale).into_iter().find_map(|locale| {
+            area.descriptions
+                .iter()
+                .find(|(known, _)| *known == locale)
+                .map(|&(_, description)| description)
+        });
+        Some(description.unwrap_or(area.descriptions[0].1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prefix::tests::check_table;
+
+    #[test]
+    fn areas_extend_the_lookup_table() {
+        check_table(&data::AREAS);
+        for area in &data::AREAS {
+            assert_eq!(FALLBACK_LOCALE, area.descriptions[0].0, "{}", area.prefix);
+        }
+    }
+
+    #[test]
+    fn describe_locations() {
+        for (phone, locale, location) in [
+            ("+49 30 12345678", "en", Some("Berlin")),
+            ("+49 89 12345678", "de-AT", Some("München")),
+            ("+49 89 12345678", "en-GB", Some("Munich")),
+            ("+81 3 1234 5678", "ja", Some("東京")),
+            ("+81 3 1234 5678", "ko", Some("Tokyo")),
+            ("+39 06 1234 5678", "it", Some("Roma")),
+            ("+44 20 7946 0958", "en", Some("London")),
+            ("+1 212 555 0123", "en", Some("New York, NY")),
+            ("+1 514 555 0123", "fr-CA", Some("Quebec")),
+            ("+1 718 555 0123", "en", Some("New York")),
+            ("+1 905 555 0123", "fr", Some("Ontario")),
+            ("+1 876 555 0123", "en", None),
+            ("+33 6 12 34 56 78", "fr", None),
+            ("+1 800 555 0123", "en", None),
+        ] {
+            let phone: PhoneNumber = phone.parse().unwrap();
+            assert_eq!(location, phone.localized_location(locale), "{}", phone);
+        }
+    }
+}
@@ -0,0 +1,6293 @@
+// Generated by `cargo run -p metadata-gen` from `data/geocoding.csv`. Do not edit.
+
+use super::Area;
+
+/// All the areas, sorted by prefix
+#[rustfmt::skip]
+pub(super) static AREAS: [Area; 6285] = [
+    Area { prefix: 202, descriptions: &[("en", "Cairo/Giza/Qalyubia")] },
+    Area { prefix: 203, descriptions: &[("en", "Alexandria")] },
+    Area { prefix: 322, descriptions: &[("en", "Brussels"), ("de", "Brüssel"), ("fr", "Bruxelles"), ("nl", "Brussel")] },
+    Area { prefix: 323, descriptions: &[("en", "Antwerp"), ("de", "Antwerpen"), ("fr", "Anvers"), ("nl", "Antwerpen")] },
+    Area { prefix: 329, descriptions: &[("en", "Ghent"), ("de", "Gent"), ("fr", "Gand"), ("nl", "Gent")] },
+    Area { prefix: 361, descriptions: &[("en", "Budapest")] },
+    Area { prefix: 468, descriptions: &[("en", "Stockholm")] },
+    Area { prefix: 511, descriptions: &[("en", "Lima/Callao")] },
+    Area { prefix: 537, descriptions: &[("en", "Havana City")] },
+    Area { prefix: 541, descriptions: &[("en", "Buenos Aires")] },
+    Area { prefix: 551, descriptions: &[("en", "São Paulo")] },
+    Area { prefix: 662, descriptions: &[("en", "Bangkok/Nonthaburi/Pathum Thani/Samut Prakan"), ("th", "กรุงเทพ/นนทบุรี/ปทุมธานี/สมุทรปราการ")] },
+    Area { prefix: 736, descriptions: &[("en", "Simferopol"), ("ru", "Республика Крым")] },
+    Area { prefix: 740, descriptions: &[("en", "Kaliningrad"), ("ru", "Калининградская обл.")] },
+    Area { prefix: 813, descriptions: &[("en", "Tokyo"), ("ja", "東京")] },
+    Area { prefix: 822, descriptions: &[("en", "Seoul"), ("ko", "서울")] },
+    Area { prefix: 1201, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1202, descriptions: &[("en", "Washington D.C.")] },
+    Area { prefix: 1204, descriptions: &[("en", "Manitoba")] },
+    Area { prefix: 1205, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1206, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1207, descriptions: &[("en", "Maine")] },
+    Area { prefix: 1208, descriptions: &[("en", "Idaho")] },
+    Area { prefix: 1209, descriptions: &[("en", "California")] },
+    Area { prefix: 1210, descriptions: &[("en", "San Antonio, TX")] },
+    Area { prefix: 1212, descriptions: &[("en", "New York, NY")] },
+    Area { prefix: 1213, descriptions: &[("en", "Los Angeles, CA")] },
+    Area { prefix: 1214, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1215, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1216, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1217, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1218, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1219, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1220, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1223, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1224, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1225, descriptions: &[("en", "Louisiana")] },
+    Area { prefix: 1226, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1227, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1228, descriptions: &[("en", "Mississippi")] },
+    Area { prefix: 1229, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1231, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1234, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1235, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1236, descriptions: &[("en", "British Columbia")] },
+    Area { prefix: 1239, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1240, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1248, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1249, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1250, descriptions: &[("en", "British Columbia")] },
+    Area { prefix: 1251, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1252, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1253, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1254, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1256, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1257, descriptions: &[("en", "British Colombia")] },
+    Area { prefix: 1260, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1262, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1263, descriptions: &[("en", "Montreal, QC")] },
+    Area { prefix: 1267, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1269, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1270, descriptions: &[("en", "Kentucky")] },
+    Area { prefix: 1272, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1274, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1276, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1279, descriptions: &[("en", "California")] },
+    Area { prefix: 1281, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1283, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1289, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1301, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1302, descriptions: &[("en", "Delaware")] },
+    Area { prefix: 1303, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1304, descriptions: &[("en", "West Virginia")] },
+    Area { prefix: 1306, descriptions: &[("en", "Saskatchewan")] },
+    Area { prefix: 1307, descriptions: &[("en", "Wyoming")] },
+    Area { prefix: 1308, descriptions: &[("en", "Nebraska")] },
+    Area { prefix: 1309, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1312, descriptions: &[("en", "Chicago, IL")] },
+    Area { prefix: 1313, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1314, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1315, descriptions: &[("en", "New York")] },
+    Area { prefix: 1316, descriptions: &[("en", "Kansas")] },
+    Area { prefix: 1317, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1318, descriptions: &[("en", "Louisiana")] },
+    Area { prefix: 1319, descriptions: &[("en", "Iowa")] },
+    Area { prefix: 1320, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1321, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1323, descriptions: &[("en", "California")] },
+    Area { prefix: 1324, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1325, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1326, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1327, descriptions: &[("en", "Arkansas")] },
+    Area { prefix: 1329, descriptions: &[("en", "New York")] },
+    Area { prefix: 1330, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1331, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1332, descriptions: &[("en", "New York, NY")] },
+    Area { prefix: 1334, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1336, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1337, descriptions: &[("en", "Louisiana")] },
+    Area { prefix: 1339, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1341, descriptions: &[("en", "California")] },
+    Area { prefix: 1343, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1346, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1347, descriptions: &[("en", "New York")] },
+    Area { prefix: 1350, descriptions: &[("en", "California")] },
+    Area { prefix: 1351, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1352, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1353, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1354, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1360, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1361, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1363, descriptions: &[("en", "New York")] },
+    Area { prefix: 1364, descriptions: &[("en", "Kentucky")] },
+    Area { prefix: 1365, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1367, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1368, descriptions: &[("en", "Alberta")] },
+    Area { prefix: 1369, descriptions: &[("en", "California")] },
+    Area { prefix: 1380, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1382, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1385, descriptions: &[("en", "Utah")] },
+    Area { prefix: 1386, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1401, descriptions: &[("en", "Rhode Island")] },
+    Area { prefix: 1402, descriptions: &[("en", "Nebraska")] },
+    Area { prefix: 1403, descriptions: &[("en", "Alberta")] },
+    Area { prefix: 1404, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1405, descriptions: &[("en", "Oklahoma")] },
+    Area { prefix: 1406, descriptions: &[("en", "Montana")] },
+    Area { prefix: 1407, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1408, descriptions: &[("en", "California")] },
+    Area { prefix: 1409, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1410, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1412, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1413, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1414, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1415, descriptions: &[("en", "California")] },
+    Area { prefix: 1416, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1417, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1418, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1419, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1423, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1424, descriptions: &[("en", "California")] },
+    Area { prefix: 1425, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1428, descriptions: &[("en", "New Brunswick")] },
+    Area { prefix: 1430, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1431, descriptions: &[("en", "Manitoba")] },
+    Area { prefix: 1432, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1434, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1435, descriptions: &[("en", "Utah")] },
+    Area { prefix: 1437, descriptions: &[("en", "Toronto")] },
+    Area { prefix: 1438, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1440, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1442, descriptions: &[("en", "California")] },
+    Area { prefix: 1443, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1445, descriptions: &[("en", "Philadelphia")] },
+    Area { prefix: 1447, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1448, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1450, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1458, descriptions: &[("en", "Oregon")] },
+    Area { prefix: 1463, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1464, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1468, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1469, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1470, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1472, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1474, descriptions: &[("en", "Saskatchewan")] },
+    Area { prefix: 1475, descriptions: &[("en", "Connecticut")] },
+    Area { prefix: 1478, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1479, descriptions: &[("en", "Arkansas")] },
+    Area { prefix: 1480, descriptions: &[("en", "Arizona")] },
+    Area { prefix: 1484, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1501, descriptions: &[("en", "Arkansas")] },
+    Area { prefix: 1502, descriptions: &[("en", "Kentucky")] },
+    Area { prefix: 1503, descriptions: &[("en", "Oregon")] },
+    Area { prefix: 1504, descriptions: &[("en", "Louisiana")] },
+    Area { prefix: 1505, descriptions: &[("en", "New Mexico")] },
+    Area { prefix: 1506, descriptions: &[("en", "New Brunswick")] },
+    Area { prefix: 1507, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1508, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1509, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1510, descriptions: &[("en", "California")] },
+    Area { prefix: 1512, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1513, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1514, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1515, descriptions: &[("en", "Iowa")] },
+    Area { prefix: 1516, descriptions: &[("en", "New York")] },
+    Area { prefix: 1517, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1518, descriptions: &[("en", "New York")] },
+    Area { prefix: 1519, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1520, descriptions: &[("en", "Arizona")] },
+    Area { prefix: 1530, descriptions: &[("en", "California")] },
+    Area { prefix: 1531, descriptions: &[("en", "Nebraska")] },
+    Area { prefix: 1534, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1539, descriptions: &[("en", "Oklahoma")] },
+    Area { prefix: 1540, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1541, descriptions: &[("en", "Oregon")] },
+    Area { prefix: 1548, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1551, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1557, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1559, descriptions: &[("en", "California")] },
+    Area { prefix: 1561, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1562, descriptions: &[("en", "California")] },
+    Area { prefix: 1563, descriptions: &[("en", "Iowa")] },
+    Area { prefix: 1564, descriptions: &[("en", "Washington State")] },
+    Area { prefix: 1567, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1570, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1571, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1572, descriptions: &[("en", "Oklahoma")] },
+    Area { prefix: 1573, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1574, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1575, descriptions: &[("en", "New Mexico")] },
+    Area { prefix: 1579, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1580, descriptions: &[("en", "Oklahoma")] },
+    Area { prefix: 1581, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1582, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1584, descriptions: &[("en", "Manitoba")] },
+    Area { prefix: 1585, descriptions: &[("en", "New York")] },
+    Area { prefix: 1586, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1587, descriptions: &[("en", "Alberta")] },
+    Area { prefix: 1601, descriptions: &[("en", "Mississippi")] },
+    Area { prefix: 1602, descriptions: &[("en", "Arizona")] },
+    Area { prefix: 1603, descriptions: &[("en", "New Hampshire")] },
+    Area { prefix: 1604, descriptions: &[("en", "British Columbia")] },
+    Area { prefix: 1605, descriptions: &[("en", "South Dakota")] },
+    Area { prefix: 1606, descriptions: &[("en", "Kentucky")] },
+    Area { prefix: 1607, descriptions: &[("en", "New York")] },
+    Area { prefix: 1608, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1609, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1610, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1612, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1613, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1614, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1615, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1616, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1617, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1618, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1619, descriptions: &[("en", "California")] },
+    Area { prefix: 1620, descriptions: &[("en", "Kansas")] },
+    Area { prefix: 1623, descriptions: &[("en", "Arizona")] },
+    Area { prefix: 1626, descriptions: &[("en", "California")] },
+    Area { prefix: 1628, descriptions: &[("en", "California")] },
+    Area { prefix: 1629, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1630, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1631, descriptions: &[("en", "New York")] },
+    Area { prefix: 1636, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1639, descriptions: &[("en", "Saskatchewan")] },
+    Area { prefix: 1640, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1641, descriptions: &[("en", "Iowa")] },
+    Area { prefix: 1645, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1646, descriptions: &[("en", "New York")] },
+    Area { prefix: 1647, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1650, descriptions: &[("en", "California")] },
+    Area { prefix: 1651, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1656, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1657, descriptions: &[("en", "California")] },
+    Area { prefix: 1659, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1660, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1661, descriptions: &[("en", "California")] },
+    Area { prefix: 1662, descriptions: &[("en", "Mississippi")] },
+    Area { prefix: 1667, descriptions: &[("en", "Maryland")] },
+    Area { prefix: 1669, descriptions: &[("en", "California")] },
+    Area { prefix: 1672, descriptions: &[("en", "British Columbia")] },
+    Area { prefix: 1678, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1680, descriptions: &[("en", "New York")] },
+    Area { prefix: 1681, descriptions: &[("en", "West Virginia")] },
+    Area { prefix: 1682, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1683, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1686, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1689, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1701, descriptions: &[("en", "North Dakota")] },
+    Area { prefix: 1702, descriptions: &[("en", "Nevada")] },
+    Area { prefix: 1703, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1704, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1705, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1706, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1707, descriptions: &[("en", "California")] },
+    Area { prefix: 1708, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1709, descriptions: &[("en", "Newfoundland and Labrador")] },
+    Area { prefix: 1712, descriptions: &[("en", "Iowa")] },
+    Area { prefix: 1714, descriptions: &[("en", "California")] },
+    Area { prefix: 1715, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1716, descriptions: &[("en", "New York")] },
+    Area { prefix: 1717, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1719, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1720, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1724, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1725, descriptions: &[("en", "Nevada")] },
+    Area { prefix: 1726, descriptions: &[("en", "San Antonio, TX")] },
+    Area { prefix: 1727, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1730, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1731, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1732, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1734, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1737, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1740, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1742, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1743, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1747, descriptions: &[("en", "California")] },
+    Area { prefix: 1748, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1753, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1754, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1757, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1760, descriptions: &[("en", "California")] },
+    Area { prefix: 1762, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1763, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1765, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1769, descriptions: &[("en", "Mississippi")] },
+    Area { prefix: 1770, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1771, descriptions: &[("en", "Washington D.C.")] },
+    Area { prefix: 1772, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1773, descriptions: &[("en", "Chicago, IL")] },
+    Area { prefix: 1774, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1775, descriptions: &[("en", "Nevada")] },
+    Area { prefix: 1778, descriptions: &[("en", "British Columbia")] },
+    Area { prefix: 1779, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1780, descriptions: &[("en", "Alberta")] },
+    Area { prefix: 1781, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1782, descriptions: &[("en", "Nova Scotia/Prince Edward Island")] },
+    Area { prefix: 1785, descriptions: &[("en", "Kansas")] },
+    Area { prefix: 1786, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1801, descriptions: &[("en", "Utah")] },
+    Area { prefix: 1802, descriptions: &[("en", "Vermont")] },
+    Area { prefix: 1803, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1804, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1805, descriptions: &[("en", "California")] },
+    Area { prefix: 1806, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1807, descriptions: &[("en", "Ontario")] },
+    Area { prefix: 1808, descriptions: &[("en", "Hawaii")] },
+    Area { prefix: 1810, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1812, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1813, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1814, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1815, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1816, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1817, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1818, descriptions: &[("en", "California")] },
+    Area { prefix: 1819, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1820, descriptions: &[("en", "California")] },
+    Area { prefix: 1821, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1825, descriptions: &[("en", "Alberta")] },
+    Area { prefix: 1826, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1828, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1830, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1831, descriptions: &[("en", "California")] },
+    Area { prefix: 1832, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1835, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1838, descriptions: &[("en", "New York")] },
+    Area { prefix: 1839, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1840, descriptions: &[("en", "California")] },
+    Area { prefix: 1843, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1845, descriptions: &[("en", "New York")] },
+    Area { prefix: 1847, descriptions: &[("en", "Illinois")] },
+    Area { prefix: 1848, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1850, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1854, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1856, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1857, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1858, descriptions: &[("en", "California")] },
+    Area { prefix: 1859, descriptions: &[("en", "Kentucky")] },
+    Area { prefix: 1860, descriptions: &[("en", "Connecticut")] },
+    Area { prefix: 1862, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1863, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1864, descriptions: &[("en", "South Carolina")] },
+    Area { prefix: 1865, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1867, descriptions: &[("en", "Northwest Territories/Nunavut/Yukon")] },
+    Area { prefix: 1870, descriptions: &[("en", "Arkansas")] },
+    Area { prefix: 1872, descriptions: &[("en", "Chicago, IL")] },
+    Area { prefix: 1873, descriptions: &[("en", "Quebec")] },
+    Area { prefix: 1878, descriptions: &[("en", "Pennsylvania")] },
+    Area { prefix: 1879, descriptions: &[("en", "Newfoundland and Labrador")] },
+    Area { prefix: 1901, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1902, descriptions: &[("en", "Nova Scotia/Prince Edward Island")] },
+    Area { prefix: 1903, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1904, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1906, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1907, descriptions: &[("en", "Alaska")] },
+    Area { prefix: 1908, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1909, descriptions: &[("en", "California")] },
+    Area { prefix: 1910, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1912, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1913, descriptions: &[("en", "Kansas")] },
+    Area { prefix: 1914, descriptions: &[("en", "New York")] },
+    Area { prefix: 1915, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1916, descriptions: &[("en", "California")] },
+    Area { prefix: 1917, descriptions: &[("en", "New York")] },
+    Area { prefix: 1918, descriptions: &[("en", "Oklahoma")] },
+    Area { prefix: 1919, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1920, descriptions: &[("en", "Wisconsin")] },
+    Area { prefix: 1925, descriptions: &[("en", "California")] },
+    Area { prefix: 1928, descriptions: &[("en", "Arizona")] },
+    Area { prefix: 1929, descriptions: &[("en", "New York")] },
+    Area { prefix: 1930, descriptions: &[("en", "Indiana")] },
+    Area { prefix: 1931, descriptions: &[("en", "Tennessee")] },
+    Area { prefix: 1934, descriptions: &[("en", "New York, NY")] },
+    Area { prefix: 1936, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1937, descriptions: &[("en", "Ohio")] },
+    Area { prefix: 1938, descriptions: &[("en", "Alabama")] },
+    Area { prefix: 1940, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1941, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1942, descriptions: &[("en", "Toronto, ON")] },
+    Area { prefix: 1943, descriptions: &[("en", "Georgia")] },
+    Area { prefix: 1945, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1947, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 1948, descriptions: &[("en", "Virginia")] },
+    Area { prefix: 1949, descriptions: &[("en", "California")] },
+    Area { prefix: 1951, descriptions: &[("en", "California")] },
+    Area { prefix: 1952, descriptions: &[("en", "Minnesota")] },
+    Area { prefix: 1954, descriptions: &[("en", "Florida")] },
+    Area { prefix: 1956, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1959, descriptions: &[("en", "Connecticut")] },
+    Area { prefix: 1970, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1971, descriptions: &[("en", "Oregon")] },
+    Area { prefix: 1972, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1973, descriptions: &[("en", "New Jersey")] },
+    Area { prefix: 1975, descriptions: &[("en", "Missouri")] },
+    Area { prefix: 1978, descriptions: &[("en", "Massachusetts")] },
+    Area { prefix: 1979, descriptions: &[("en", "Texas")] },
+    Area { prefix: 1980, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1983, descriptions: &[("en", "Colorado")] },
+    Area { prefix: 1984, descriptions: &[("en", "North Carolina")] },
+    Area { prefix: 1985, descriptions: &[("en", "Louisiana")] },
+    Area { prefix: 1986, descriptions: &[("en", "Idaho")] },
+    Area { prefix: 1989, descriptions: &[("en", "Michigan")] },
+    Area { prefix: 2013, descriptions: &[("en", "Banha")] },
+    Area { prefix: 2015, descriptions: &[("en", "10th of Ramadan")] },
+    Area { prefix: 2040, descriptions: &[("en", "Tanta")] },
+    Area { prefix: 2045, descriptions: &[("en", "Damanhur")] },
+    Area { prefix: 2046, descriptions: &[("en", "Marsa Matruh")] },
+    Area { prefix: 2047, descriptions: &[("en", "Kafr El-Sheikh")] },
+    Area { prefix: 2048, descriptions: &[("en", "Monufia")] },
+    Area { prefix: 2050, descriptions: &[("en", "Mansoura")] },
+    Area { prefix: 2055, descriptions: &[("en", "Zagazig")] },
+    Area { prefix: 2057, descriptions: &[("en", "Damietta")] },
+    Area { prefix: 2062, descriptions: &[("en", "Suez")] },
+    Area { prefix: 2064, descriptions: &[("en", "Ismailia")] },
+    Area { prefix: 2065, descriptions: &[("en", "Red Sea")] },
+    Area { prefix: 2066, descriptions: &[("en", "Port Said")] },
+    Area { prefix: 2068, descriptions: &[("en", "El-Arish")] },
+    Area { prefix: 2069, descriptions: &[("en", "El-Tor")] },
+    Area { prefix: 2082, descriptions: &[("en", "Beni Suef")] },
+    Area { prefix: 2084, descriptions: &[("en", "Fayoum")] },
+    Area { prefix: 2086, descriptions: &[("en", "Minia")] },
+    Area { prefix: 2088, descriptions: &[("en", "Assiout")] },
+    Area { prefix: 2092, descriptions: &[("en", "Wadi El-Gedid")] },
+    Area { prefix: 2093, descriptions: &[("en", "Sohag")] },
+    Area { prefix: 2095, descriptions: &[("en", "Luxor")] },
+    Area { prefix: 2096, descriptions: &[("en", "Qena")] },
+    Area { prefix: 2097, descriptions: &[("en", "Aswan")] },
+    Area { prefix: 2304, descriptions: &[("en", "Central Region"), ("es", "Región Central"), ("fr", "Région Centrale")] },
+    Area { prefix: 2306, descriptions: &[("en", "South Region"), ("es", "Región Sur"), ("fr", "Région Sud")] },
+    Area { prefix: 2362, descriptions: &[("en", "Bangui")] },
+    Area { prefix: 2431, descriptions: &[("en", "Kinshasa")] },
+    Area { prefix: 2432, descriptions: &[("en", "Katanga")] },
+    Area { prefix: 2433, descriptions: &[("en", "Bas-Congo/Bandundu")] },
+    Area { prefix: 2434, descriptions: &[("en", "Kasai-Oriental/Kasai-Occidental")] },
+    Area { prefix: 2435, descriptions: &[("en", "Oriental Province (Kisanga/Mbandaka)"), ("fr", "Province Orientale (Kisanga/Mbandaka)")] },
+    Area { prefix: 2436, descriptions: &[("en", "North Kivu/South Kivu/Maniema"), ("fr", "Nord-Kivu/Sud-Kivu/Maniema")] },
+    Area { prefix: 2521, descriptions: &[("en", "Mogadishu")] },
+    Area { prefix: 2523, descriptions: &[("en", "Hargeisa")] },
+    Area { prefix: 2524, descriptions: &[("en", "Garowe")] },
+    Area { prefix: 2542, descriptions: &[("en", "Nairobi")] },
+    Area { prefix: 2634, descriptions: &[("en", "Harare")] },
+    Area { prefix: 2639, descriptions: &[("en", "Bulawayo")] },
+    Area { prefix: 2710, descriptions: &[("en", "Johannesburg")] },
+    Area { prefix: 2711, descriptions: &[("en", "Johannesburg")] },
+    Area { prefix: 2712, descriptions: &[("en", "Brits/Tshwane")] },
+    Area { prefix: 2713, descriptions: &[("en", "Bronkhorstspruit/Eastern Gauteng/Middelburg/Nelspruit/Northern and Western Mpumalanga/Witbank")] },
+    Area { prefix: 2714, descriptions: &[("en", "Modimolle/Northern North West and Southwestern Limpopo/Rustenburg")] },
+    Area { prefix: 2715, descriptions: &[("en", "Northern and Eastern Limpopo/Polokwane")] },
+    Area { prefix: 2716, descriptions: &[("en", "Vaal Triangle")] },
+    Area { prefix: 2717, descriptions: &[("en", "Ermelo/Secunda/Southern Mpumalanga")] },
+    Area { prefix: 2718, descriptions: &[("en", "Klerksdorp/Lichtenburg/Potchefstroom")] },
+    Area { prefix: 2721, descriptions: &[("en", "Cape Town/Gordons Bay/Somerset West/Stellenbosch")] },
+    Area { prefix: 2722, descriptions: &[("en", "Boland/Malmesbury/Vredenburg/Western coast of Western Cape")] },
+    Area { prefix: 2723, descriptions: &[("en", "Beaufort West/Karoo/Robertson/Worcester")] },
+    Area { prefix: 2727, descriptions: &[("en", "Alexander Bay/Calvinia/Clanwilliam/Namaqualand/Port Nolloth/Springbok/Vredendal")] },
+    Area { prefix: 2728, descriptions: &[("en", "Caledon/Hermanus/Southern coast of Western Cape/Swellendam")] },
+    Area { prefix: 2731, descriptions: &[("en", "Durban")] },
+    Area { prefix: 2732, descriptions: &[("en", "Ballito/KwaZulu Natal coast/Stanger/Tongaat/Verulam")] },
+    Area { prefix: 2733, descriptions: &[("en", "KwaZulu Natal Midlands/Pietermaritzburg")] },
+    Area { prefix: 2734, descriptions: &[("en", "Newcastle/Northern KwaZulu Natal/Vryheid")] },
+    Area { prefix: 2735, descriptions: &[("en", "Richards Bay/St. Lucia/Ulundi/Zululand")] },
+    Area { prefix: 2736, descriptions: &[("en", "Drakensberg/Ladysmith")] },
+    Area { prefix: 2739, descriptions: &[("en", "Eastern Pondoland/Port Shepstone/Southern coast of KwaZulu Natal")] },
+    Area { prefix: 2740, descriptions: &[("en", "Alice/Bhisho")] },
+    Area { prefix: 2741, descriptions: &[("en", "Port Elizabeth/Uitenhage")] },
+    Area { prefix: 2742, descriptions: &[("en", "Jeffreys Bay/Humansdorp/Southern and central Eastern Cape")] },
+    Area { prefix: 2743, descriptions: &[("en", "East London")] },
+    Area { prefix: 2744, descriptions: &[("en", "Garden Route/George/Knysna/Mossel Bay/Oudtshoorn/Plettenberg Bay")] },
+    Area { prefix: 2745, descriptions: &[("en", "Northern and eastern parts of Eastern Cape/Queenstown")] },
+    Area { prefix: 2746, descriptions: &[("en", "Bathurst/Southern and eastern parts of Eastern Cape/Grahamstown/Kenton-on-Sea/Port Alfred")] },
+    Area { prefix: 2747, descriptions: &[("en", "Butterworth/Eastern part of Eastern Cape/Mthatha")] },
+    Area { prefix: 2748, descriptions: &[("en", "Cradock/Northern part of Eastern Cape/Steynsburg")] },
+    Area { prefix: 2749, descriptions: &[("en", "Graaff-Reinet/Western part of Eastern Cape")] },
+    Area { prefix: 2751, descriptions: &[("en", "Aliwal North/Bloemfontein/Far eastern part of Eastern Cape/Southern and Central Free State")] },
+    Area { prefix: 2753, descriptions: &[("en", "Eastern part of Northern Cape/Far western part of North West/Kimberley/Kuruman")] },
+    Area { prefix: 2754, descriptions: &[("en", "Upington/Gordonia")] },
+    Area { prefix: 2756, descriptions: &[("en", "Kroonstad/Parys/Northern Free State")] },
+    Area { prefix: 2757, descriptions: &[("en", "Northern Free State Goldfields/Welkom")] },
+    Area { prefix: 2758, descriptions: &[("en", "Bethlehem/Eastern Free State")] },
+    Area { prefix: 2908, descriptions: &[("en", "Tristan da Cunha")] },
+    Area { prefix: 3021, descriptions: &[("en", "Athens/Piraeus/Salamina"), ("el", "Αθήνα/Πειραιάς/Σαλαμίνα")] },
+    Area { prefix: 3110, descriptions: &[("en", "Rotterdam")] },
+    Area { prefix: 3113, descriptions: &[("en", "Tilburg")] },
+    Area { prefix: 3115, descriptions: &[("en", "Delft")] },
+    Area { prefix: 3120, descriptions: &[("en", "Amsterdam")] },
+    Area { prefix: 3123, descriptions: &[("en", "Haarlem")] },
+    Area { prefix: 3124, descriptions: &[("en", "Nijmegen")] },
+    Area { prefix: 3126, descriptions: &[("en", "Arnhem")] },
+    Area { prefix: 3130, descriptions: &[("en", "Utrecht")] },
+    Area { prefix: 3133, descriptions: &[("en", "Amersfoort")] },
+    Area { prefix: 3135, descriptions: &[("en", "Hilversum")] },
+    Area { prefix: 3136, descriptions: &[("en", "Almere")] },
+    Area { prefix: 3138, descriptions: &[("en", "Zwolle")] },
+    Area { prefix: 3140, descriptions: &[("en", "Eindhoven")] },
+    Area { prefix: 3143, descriptions: &[("en", "Maastricht")] },
+    Area { prefix: 3145, descriptions: &[("en", "Heerlen")] },
+    Area { prefix: 3146, descriptions: &[("en", "Sittard")] },
+    Area { prefix: 3150, descriptions: &[("en", "Groningen")] },
+    Area { prefix: 3153, descriptions: &[("en", "Enschede")] },
+    Area { prefix: 3155, descriptions: &[("en", "Apeldoorn")] },
+    Area { prefix: 3158, descriptions: &[("en", "Leeuwarden")] },
+    Area { prefix: 3170, descriptions: &[("en", "The Hague"), ("nl", "Den Haag")] },
+    Area { prefix: 3171, descriptions: &[("en", "Leiden")] },
+    Area { prefix: 3172, descriptions: &[("en", "Alkmaar")] },
+    Area { prefix: 3173, descriptions: &[("en", "'s-Hertogenbosch")] },
+    Area { prefix: 3174, descriptions: &[("en", "Hengelo")] },
+    Area { prefix: 3175, descriptions: &[("en", "Zaandam")] },
+    Area { prefix: 3176, descriptions: &[("en", "Breda")] },
+    Area { prefix: 3177, descriptions: &[("en", "Venlo")] },
+    Area { prefix: 3178, descriptions: &[("en", "Dordrecht")] },
+    Area { prefix: 3179, descriptions: &[("en", "Zoetermeer")] },
+    Area { prefix: 3210, descriptions: &[("en", "Wavre"), ("nl", "Waver")] },
+    Area { prefix: 3211, descriptions: &[("en", "Hasselt")] },
+    Area { prefix: 3212, descriptions: &[("en", "Tongeren"), ("de", "Tongern"), ("fr", "Tongres")] },
+    Area { prefix: 3213, descriptions: &[("en", "Diest")] },
+    Area { prefix: 3214, descriptions: &[("en", "Herentals")] },
+    Area { prefix: 3215, descriptions: &[("en", "Mechelen"), ("de", "Mecheln"), ("fr", "Malines")] },
+    Area { prefix: 3216, descriptions: &[("en", "Leuven"), ("de", "Löwen"), ("fr", "Louvain")] },
+    Area { prefix: 3219, descriptions: &[("en", "Waremme"), ("nl", "Borgworm")] },
+    Area { prefix: 3242, descriptions: &[("en", "Liège"), ("de", "Lüttich"), ("nl", "Luik")] },
+    Area { prefix: 3243, descriptions: &[("en", "Liège"), ("de", "Lüttich"), ("nl", "Luik")] },
+    Area { prefix: 3250, descriptions: &[("en", "Bruges"), ("de", "Brügge"), ("nl", "Brugge")] },
+    Area { prefix: 3251, descriptions: &[("en", "Roeselare"), ("fr", "Roulers")] },
+    Area { prefix: 3252, descriptions: &[("en", "Dendermonde"), ("fr", "Termonde")] },
+    Area { prefix: 3253, descriptions: &[("en", "Aalst"), ("fr", "Alost")] },
+    Area { prefix: 3254, descriptions: &[("en", "Ninove")] },
+    Area { prefix: 3255, descriptions: &[("en", "Ronse"), ("fr", "Renaix")] },
+    Area { prefix: 3256, descriptions: &[("en", "Kortrijk"), ("fr", "Courtrai")] },
+    Area { prefix: 3257, descriptions: &[("en", "Ypres"), ("de", "Ypern"), ("nl", "Ieper")] },
+    Area { prefix: 3258, descriptions: &[("en", "Veurne"), ("fr", "Furnes")] },
+    Area { prefix: 3259, descriptions: &[("en", "Ostend"), ("de", "Ostende"), ("fr", "Ostende"), ("nl", "Oostende")] },
+    Area { prefix: 3260, descriptions: &[("en", "Chimay")] },
+    Area { prefix: 3261, descriptions: &[("en", "Libramont-Chevigny")] },
+    Area { prefix: 3263, descriptions: &[("en", "Arlon"), ("de", "Arel"), ("nl", "Aarlen")] },
+    Area { prefix: 3264, descriptions: &[("en", "La Louvière")] },
+    Area { prefix: 3265, descriptions: &[("en", "Mons"), ("de", "Bergen"), ("nl", "Bergen")] },
+    Area { prefix: 3267, descriptions: &[("en", "Nivelles"), ("nl", "Nijvel")] },
+    Area { prefix: 3268, descriptions: &[("en", "Ath"), ("nl", "Aat")] },
+    Area { prefix: 3269, descriptions: &[("en", "Tournai"), ("nl", "Doornik")] },
+    Area { prefix: 3271, descriptions: &[("en", "Charleroi")] },
+    Area { prefix: 3280, descriptions: &[("en", "Stavelot"), ("de", "Stablo")] },
+    Area { prefix: 3281, descriptions: &[("en", "Namur"), ("de", "Namür"), ("nl", "Namen")] },
+    Area { prefix: 3282, descriptions: &[("en", "Dinant")] },
+    Area { prefix: 3283, descriptions: &[("en", "Ciney")] },
+    Area { prefix: 3284, descriptions: &[("en", "Marche-en-Famenne")] },
+    Area { prefix: 3285, descriptions: &[("en", "Huy"), ("nl", "Hoei")] },
+    Area { prefix: 3286, descriptions: &[("en", "Durbuy")] },
+    Area { prefix: 3287, descriptions: &[("en", "Verviers")] },
+    Area { prefix: 3289, descriptions: &[("en", "Genk")] },
+    Area { prefix: 3481, descriptions: &[("en", "Madrid")] },
+    Area { prefix: 3483, descriptions: &[("en", "Barcelona")] },
+    Area { prefix: 3491, descriptions: &[("en", "Madrid")] },
+    Area { prefix: 3493, descriptions: &[("en", "Barcelona")] },
+    Area { prefix: 3531, descriptions: &[("en", "Dublin")] },
+    Area { prefix: 3545, descriptions: &[("en", "Reykjavík")] },
+    Area { prefix: 3589, descriptions: &[("en", "Helsinki"), ("sv", "Helsingfors")] },
+    Area { prefix: 3592, descriptions: &[("en", "Sofia"), ("bg", "София")] },
+    Area { prefix: 3622, descriptions: &[("en", "Székesfehérvár")] },
+    Area { prefix: 3623, descriptions: &[("en", "Biatorbágy")] },
+    Area { prefix: 3624, descriptions: &[("en", "Szigetszentmiklós")] },
+    Area { prefix: 3625, descriptions: &[("en", "Dunaujvaros"), ("hu", "Dunaújváros")] },
+    Area { prefix: 3626, descriptions: &[("en", "Szentendre")] },
+    Area { prefix: 3627, descriptions: &[("en", "Vac"), ("hu", "Vác")] },
+    Area { prefix: 3628, descriptions: &[("en", "Godollo"), ("hu", "Gödöllő")] },
+    Area { prefix: 3629, descriptions: &[("en", "Monor")] },
+    Area { prefix: 3632, descriptions: &[("en", "Salgotarjan"), ("hu", "Salgótarján")] },
+    Area { prefix: 3633, descriptions: &[("en", "Esztergom")] },
+    Area { prefix: 3634, descriptions: &[("en", "Tatabanya"), ("hu", "Tatabánya")] },
+    Area { prefix: 3635, descriptions: &[("en", "Balassagyarmat")] },
+    Area { prefix: 3636, descriptions: &[("en", "Eger")] },
+    Area { prefix: 3637, descriptions: &[("en", "Gyongyos"), ("hu", "Gyöngyös")] },
+    Area { prefix: 3642, descriptions: &[("en", "Nyiregyhaza"), ("hu", "Nyíregyháza")] },
+    Area { prefix: 3644, descriptions: &[("en", "Mátészalka")] },
+    Area { prefix: 3645, descriptions: &[("en", "Kisvarda"), ("hu", "Kisvárda")] },
+    Area { prefix: 3646, descriptions: &[("en", "Miskolc")] },
+    Area { prefix: 3647, descriptions: &[("en", "Szerencs")] },
+    Area { prefix: 3648, descriptions: &[("en", "Ozd"), ("hu", "Ózd")] },
+    Area { prefix: 3649, descriptions: &[("en", "Mezokovesd"), ("hu", "Mezőkövesd")] },
+    Area { prefix: 3652, descriptions: &[("en", "Debrecen")] },
+    Area { prefix: 3653, descriptions: &[("en", "Cegled"), ("hu", "Cegléd")] },
+    Area { prefix: 3654, descriptions: &[("en", "Berettyoujfalu"), ("hu", "Berettyóújfalu")] },
+    Area { prefix: 3656, descriptions: &[("en", "Szolnok")] },
+    Area { prefix: 3657, descriptions: &[("en", "Jaszbereny"), ("hu", "Jászberény")] },
+    Area { prefix: 3659, descriptions: &[("en", "Karcag")] },
+    Area { prefix: 3662, descriptions: &[("en", "Szeged")] },
+    Area { prefix: 3663, descriptions: &[("en", "Szentes")] },
+    Area { prefix: 3666, descriptions: &[("en", "Bekescsaba"), ("hu", "Békéscsaba")] },
+    Area { prefix: 3668, descriptions: &[("en", "Oroshaza"), ("hu", "Orosháza")] },
+    Area { prefix: 3669, descriptions: &[("en", "Mohacs"), ("hu", "Mohács")] },
+    Area { prefix: 3672, descriptions: &[("en", "Pecs"), ("hu", "Pécs")] },
+    Area { prefix: 3673, descriptions: &[("en", "Szigetvar"), ("hu", "Szigetvár")] },
+    Area { prefix: 3674, descriptions: &[("en", "Szekszard"), ("hu", "Szekszárd")] },
+    Area { prefix: 3675, descriptions: &[("en", "Paks")] },
+    Area { prefix: 3676, descriptions: &[("en", "Kecskemet"), ("hu", "Kecskemét")] },
+    Area { prefix: 3677, descriptions: &[("en", "Kiskunhalas")] },
+    Area { prefix: 3678, descriptions: &[("en", "Kiskoros"), ("hu", "Kiskőrös")] },
+    Area { prefix: 3679, descriptions: &[("en", "Baja")] },
+    Area { prefix: 3682, descriptions: &[("en", "Kaposvar"), ("hu", "Kaposvár")] },
+    Area { prefix: 3683, descriptions: &[("en", "Keszthely")] },
+    Area { prefix: 3684, descriptions: &[("en", "Siofok"), ("hu", "Siófok")] },
+    Area { prefix: 3685, descriptions: &[("en", "Marcali")] },
+    Area { prefix: 3687, descriptions: &[("en", "Tapolca")] },
+    Area { prefix: 3688, descriptions: &[("en", "Veszprem"), ("hu", "Veszprém")] },
+    Area { prefix: 3689, descriptions: &[("en", "Papa"), ("hu", "Pápa")] },
+    Area { prefix: 3692, descriptions: &[("en", "Zalaegerszeg")] },
+    Area { prefix: 3693, descriptions: &[("en", "Nagykanizsa")] },
+    Area { prefix: 3694, descriptions: &[("en", "Szombathely")] },
+    Area { prefix: 3695, descriptions: &[("en", "Sarvar"), ("hu", "Sárvár")] },
+    Area { prefix: 3696, descriptions: &[("en", "Gyor"), ("hu", "Győr")] },
+    Area { prefix: 3699, descriptions: &[("en", "Sopron")] },
+    Area { prefix: 3822, descriptions: &[("en", "Danilovgad/Kolasin/Podgorica")] },
+    Area { prefix: 3851, descriptions: &[("en", "Zagreb")] },
+    Area { prefix: 3861, descriptions: &[("en", "Ljubljana")] },
+    Area { prefix: 3862, descriptions: &[("en", "Maribor/Ravne na Koroškem/Murska Sobota")] },
+    Area { prefix: 3874, descriptions: &[("en", "Brčko District"), ("bs", "Brčko Distrikt"), ("sr", "Брчко Дистрикт")] },
+    Area { prefix: 3892, descriptions: &[("en", "Skopje")] },
+    Area { prefix: 3902, descriptions: &[("en", "Milan"), ("it", "Milano")] },
+    Area { prefix: 3906, descriptions: &[("en", "Rome"), ("it", "Roma")] },
+    Area { prefix: 4021, descriptions: &[("en", "Bucharest and Ilfov County"), ("ro", "București și județul Ilfov")] },
+    Area { prefix: 4031, descriptions: &[("en", "Bucharest and Ilfov County"), ("ro", "București și județul Ilfov")] },
+    Area { prefix: 4121, descriptions: &[("en", "Lausanne"), ("it", "Losanna")] },
+    Area { prefix: 4122, descriptions: &[("en", "Geneva"), ("de", "Genf"), ("fr", "Genève"), ("it", "Ginevra")] },
+    Area { prefix: 4124, descriptions: &[("en", "Yverdon/Aigle")] },
+    Area { prefix: 4126, descriptions: &[("en", "Fribourg"), ("de", "Freiburg"), ("it", "Friburgo")] },
+    Area { prefix: 4127, descriptions: &[("en", "Sion"), ("de", "Sitten")] },
+    Area { prefix: 4131, descriptions: &[("en", "Berne"), ("de", "Bern"), ("it", "Berna")] },
+    Area { prefix: 4132, descriptions: &[("en", "Bienne/Neuchâtel/Soleure/Jura"), ("de", "Biel/Neuenburg/Solothurn/Jura"), ("it", "Bienne/Neuchâtel/Soletta/Giura")] },
+    Area { prefix: 4133, descriptions: &[("en", "Thun"), ("fr", "Thoune")] },
+    Area { prefix: 4134, descriptions: &[("en", "Burgdorf/Langnau i.E.")] },
+    Area { prefix: 4141, descriptions: &[("en", "Lucerne"), ("de", "Luzern"), ("it", "Lucerna")] },
+    Area { prefix: 4143, descriptions: &[("en", "Zurich"), ("de", "Zürich"), ("it", "Zurigo")] },
+    Area { prefix: 4144, descriptions: &[("en", "Zurich"), ("de", "Zürich"), ("it", "Zurigo")] },
+    Area { prefix: 4152, descriptions: &[("en", "Winterthur"), ("fr", "Winterthour")] },
+    Area { prefix: 4155, descriptions: &[("en", "Rapperswil")] },
+    Area { prefix: 4156, descriptions: &[("en", "Baden")] },
+    Area { prefix: 4161, descriptions: &[("en", "Basel"), ("fr", "Bâle"), ("it", "Basilea")] },
+    Area { prefix: 4162, descriptions: &[("en", "Olten")] },
+    Area { prefix: 4171, descriptions: &[("en", "St. Gallen"), ("fr", "St. Gall"), ("it", "San Gallo")] },
+    Area { prefix: 4181, descriptions: &[("en", "Chur"), ("fr", "Coire"), ("it", "Coira")] },
+    Area { prefix: 4191, descriptions: &[("en", "Bellinzona")] },
+    Area { prefix: 4202, descriptions: &[("en", "Prague")] },
+    Area { prefix: 4212, descriptions: &[("en", "Bratislava")] },
+    Area { prefix: 4312, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4313, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4314, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4315, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4316, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4317, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4318, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4319, descriptions: &[("en", "Vienna"), ("de", "Wien")] },
+    Area { prefix: 4346, descriptions: &[("en", "Klagenfurt")] },
+    Area { prefix: 4420, descriptions: &[("en", "London")] },
+    Area { prefix: 4611, descriptions: &[("en", "Norrköping")] },
+    Area { prefix: 4613, descriptions: &[("en", "Linköping")] },
+    Area { prefix: 4616, descriptions: &[("en", "Eskilstuna-Torshälla")] },
+    Area { prefix: 4618, descriptions: &[("en", "Uppsala")] },
+    Area { prefix: 4619, descriptions: &[("en", "Örebro-Kumla")] },
+    Area { prefix: 4621, descriptions: &[("en", "Västerås")] },
+    Area { prefix: 4623, descriptions: &[("en", "Falun")] },
+    Area { prefix: 4626, descriptions: &[("en", "Gävle-Sandviken")] },
+    Area { prefix: 4631, descriptions: &[("en", "Gothenburg")] },
+    Area { prefix: 4633, descriptions: &[("en", "Borås")] },
+    Area { prefix: 4635, descriptions: &[("en", "Halmstad")] },
+    Area { prefix: 4636, descriptions: &[("en", "Jönköping-Huskvarna")] },
+    Area { prefix: 4640, descriptions: &[("en", "Malmö")] },
+    Area { prefix: 4642, descriptions: &[("en", "Helsingborg-Höganäs")] },
+    Area { prefix: 4644, descriptions: &[("en", "Kristianstad")] },
+    Area { prefix: 4646, descriptions: &[("en", "Lund")] },
+    Area { prefix: 4654, descriptions: &[("en", "Karlstad")] },
+    Area { prefix: 4660, descriptions: &[("en", "Sundsvall-Timrå")] },
+    Area { prefix: 4663, descriptions: &[("en", "Östersund")] },
+    Area { prefix: 4722, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 4731, descriptions: &[("en", "Buskerud")] },
+    Area { prefix: 4732, descriptions: &[("en", "Buskerud")] },
+    Area { prefix: 4733, descriptions: &[("en", "Vestfold")] },
+    Area { prefix: 4735, descriptions: &[("en", "Telemark")] },
+    Area { prefix: 4737, descriptions: &[("en", "Aust-Agder")] },
+    Area { prefix: 4738, descriptions: &[("en", "Vest-Agder")] },
+    Area { prefix: 4751, descriptions: &[("en", "Rogaland")] },
+    Area { prefix: 4752, descriptions: &[("en", "Rogaland")] },
+    Area { prefix: 4753, descriptions: &[("en", "Hordaland")] },
+    Area { prefix: 4755, descriptions: &[("en", "Hordaland")] },
+    Area { prefix: 4756, descriptions: &[("en", "Hordaland")] },
+    Area { prefix: 4757, descriptions: &[("en", "Sogn og Fjordane")] },
+    Area { prefix: 4761, descriptions: &[("en", "Oppland")] },
+    Area { prefix: 4762, descriptions: &[("en", "Hedmark")] },
+    Area { prefix: 4763, descriptions: &[("en", "Akershus")] },
+    Area { prefix: 4764, descriptions: &[("en", "Akershus")] },
+    Area { prefix: 4766, descriptions: &[("en", "Akershus")] },
+    Area { prefix: 4767, descriptions: &[("en", "Akershus")] },
+    Area { prefix: 4769, descriptions: &[("en", "Østfold")] },
+    Area { prefix: 4770, descriptions: &[("en", "Møre og Romsdal")] },
+    Area { prefix: 4771, descriptions: &[("en", "Møre og Romsdal")] },
+    Area { prefix: 4772, descriptions: &[("en", "Sør-Trøndelag")] },
+    Area { prefix: 4773, descriptions: &[("en", "Sør-Trøndelag")] },
+    Area { prefix: 4774, descriptions: &[("en", "Nord-Trøndelag")] },
+    Area { prefix: 4775, descriptions: &[("en", "Nordland")] },
+    Area { prefix: 4776, descriptions: &[("en", "Nordland")] },
+    Area { prefix: 4777, descriptions: &[("en", "Troms")] },
+    Area { prefix: 4778, descriptions: &[("en", "Finnmark")] },
+    Area { prefix: 4779, descriptions: &[("en", "Svalbard & Jan Mayen")] },
+    Area { prefix: 4812, descriptions: &[("en", "Kraków")] },
+    Area { prefix: 4813, descriptions: &[("en", "Krosno")] },
+    Area { prefix: 4814, descriptions: &[("en", "Tarnów")] },
+    Area { prefix: 4815, descriptions: &[("en", "Tarnobrzeg")] },
+    Area { prefix: 4816, descriptions: &[("en", "Przemyśl")] },
+    Area { prefix: 4817, descriptions: &[("en", "Rzeszów")] },
+    Area { prefix: 4818, descriptions: &[("en", "Nowy Sącz")] },
+    Area { prefix: 4822, descriptions: &[("en", "Warsaw"), ("pl", "Warszawa")] },
+    Area { prefix: 4823, descriptions: &[("en", "Ciechanów")] },
+    Area { prefix: 4824, descriptions: &[("en", "Plock"), ("pl", "Płock")] },
+    Area { prefix: 4825, descriptions: &[("en", "Siedlce")] },
+    Area { prefix: 4829, descriptions: &[("en", "Ostrolęka"), ("pl", "Ostrołęka")] },
+    Area { prefix: 4832, descriptions: &[("en", "Katowice")] },
+    Area { prefix: 4833, descriptions: &[("en", "Bielsko-Biala"), ("pl", "Bielsko-Biała")] },
+    Area { prefix: 4834, descriptions: &[("en", "Częstochowa")] },
+    Area { prefix: 4841, descriptions: &[("en", "Kielce")] },
+    Area { prefix: 4842, descriptions: &[("en", "Lódź"), ("pl", "Łódź")] },
+    Area { prefix: 4843, descriptions: &[("en", "Sieradz")] },
+    Area { prefix: 4844, descriptions: &[("en", "Piotrków Trybunalski")] },
+    Area { prefix: 4846, descriptions: &[("en", "Skierniewice")] },
+    Area { prefix: 4848, descriptions: &[("en", "Radom")] },
+    Area { prefix: 4852, descriptions: &[("en", "Bydgoszcz")] },
+    Area { prefix: 4854, descriptions: &[("en", "Wloclawek"), ("pl", "Włocławek")] },
+    Area { prefix: 4855, descriptions: &[("en", "Elbląg")] },
+    Area { prefix: 4856, descriptions: &[("en", "Toruń")] },
+    Area { prefix: 4858, descriptions: &[("en", "Gdańsk")] },
+    Area { prefix: 4859, descriptions: &[("en", "Slupsk"), ("pl", "Słupsk")] },
+    Area { prefix: 4861, descriptions: &[("en", "Poznań")] },
+    Area { prefix: 4862, descriptions: &[("en", "Kalisz")] },
+    Area { prefix: 4863, descriptions: &[("en", "Konin")] },
+    Area { prefix: 4865, descriptions: &[("en", "Leszno")] },
+    Area { prefix: 4867, descriptions: &[("en", "Pila"), ("pl", "Piła")] },
+    Area { prefix: 4868, descriptions: &[("en", "Zielona Góra")] },
+    Area { prefix: 4871, descriptions: &[("en", "Wroclaw"), ("pl", "Wrocław")] },
+    Area { prefix: 4874, descriptions: &[("en", "Walbrzych"), ("pl", "Wałbrzych")] },
+    Area { prefix: 4875, descriptions: &[("en", "Jelenia Góra")] },
+    Area { prefix: 4876, descriptions: &[("en", "Legnica")] },
+    Area { prefix: 4877, descriptions: &[("en", "Opole")] },
+    Area { prefix: 4881, descriptions: &[("en", "Lublin")] },
+    Area { prefix: 4882, descriptions: &[("en", "Chelm"), ("pl", "Chełm")] },
+    Area { prefix: 4883, descriptions: &[("en", "Biala Podlaska"), ("pl", "Biała Podlaska")] },
+    Area { prefix: 4884, descriptions: &[("en", "Zamość")] },
+    Area { prefix: 4885, descriptions: &[("en", "Bialystok"), ("pl", "Białystok")] },
+    Area { prefix: 4886, descriptions: &[("en", "Lomża"), ("pl", "Łomża")] },
+    Area { prefix: 4887, descriptions: &[("en", "Suwalki"), ("pl", "Suwałki")] },
+    Area { prefix: 4889, descriptions: &[("en", "Olsztyn")] },
+    Area { prefix: 4891, descriptions: &[("en", "Szczecin")] },
+    Area { prefix: 4894, descriptions: &[("en", "Koszalin")] },
+    Area { prefix: 4895, descriptions: &[("en", "Gorzów Wielkopolski")] },
+    Area { prefix: 4930, descriptions: &[("en", "Berlin")] },
+    Area { prefix: 4940, descriptions: &[("en", "Hamburg")] },
+    Area { prefix: 4969, descriptions: &[("en", "Frankfurt am Main")] },
+    Area { prefix: 4989, descriptions: &[("en", "Munich"), ("de", "München")] },
+    Area { prefix: 5012, descriptions: &[("en", "Belize District")] },
+    Area { prefix: 5013, descriptions: &[("en", "Orange Walk District")] },
+    Area { prefix: 5014, descriptions: &[("en", "Corozal District")] },
+    Area { prefix: 5015, descriptions: &[("en", "Stann Creek District")] },
+    Area { prefix: 5017, descriptions: &[("en", "Toledo District")] },
+    Area { prefix: 5018, descriptions: &[("en", "Cayo District")] },
+    Area { prefix: 5141, descriptions: &[("en", "Amazonas")] },
+    Area { prefix: 5142, descriptions: &[("en", "San Martín")] },
+    Area { prefix: 5143, descriptions: &[("en", "Ancash")] },
+    Area { prefix: 5144, descriptions: &[("en", "La Libertad")] },
+    Area { prefix: 5151, descriptions: &[("en", "Puno")] },
+    Area { prefix: 5152, descriptions: &[("en", "Tacna")] },
+    Area { prefix: 5153, descriptions: &[("en", "Moquegua")] },
+    Area { prefix: 5154, descriptions: &[("en", "Arequipa")] },
+    Area { prefix: 5156, descriptions: &[("en", "Ica")] },
+    Area { prefix: 5161, descriptions: &[("en", "Ucayali")] },
+    Area { prefix: 5162, descriptions: &[("en", "Huánuco")] },
+    Area { prefix: 5163, descriptions: &[("en", "Pasco")] },
+    Area { prefix: 5164, descriptions: &[("en", "Junín")] },
+    Area { prefix: 5165, descriptions: &[("en", "Loreto")] },
+    Area { prefix: 5166, descriptions: &[("en", "Ayacucho")] },
+    Area { prefix: 5167, descriptions: &[("en", "Huancavelica")] },
+    Area { prefix: 5168, descriptions: &[("en", "Lima")] },
+    Area { prefix: 5172, descriptions: &[("en", "Tumbes")] },
+    Area { prefix: 5173, descriptions: &[("en", "Piura")] },
+    Area { prefix: 5174, descriptions: &[("en", "Lambayeque")] },
+    Area { prefix: 5175, descriptions: &[("en", "Lima")] },
+    Area { prefix: 5176, descriptions: &[("en", "Cajamarca")] },
+    Area { prefix: 5182, descriptions: &[("en", "Madre de Dios")] },
+    Area { prefix: 5183, descriptions: &[("en", "Apurímac")] },
+    Area { prefix: 5184, descriptions: &[("en", "Cusco")] },
+    Area { prefix: 5233, descriptions: &[("en", "Guadalajara, JAL")] },
+    Area { prefix: 5237, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 5248, descriptions: &[("en", "San Luis Potosi"), ("es", "San Luis Potosí")] },
+    Area { prefix: 5255, descriptions: &[("en", "Mexico City, FD"), ("es", "Ciudad de México, CDMX")] },
+    Area { prefix: 5258, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 5269, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 5281, descriptions: &[("en", "Monterrey, NL")] },
+    Area { prefix: 5295, descriptions: &[("en", "Oaxaca")] },
+    Area { prefix: 5296, descriptions: &[("en", "Chiapas")] },
+    Area { prefix: 5297, descriptions: &[("en", "Oaxaca")] },
+    Area { prefix: 5321, descriptions: &[("en", "Guantánamo Province")] },
+    Area { prefix: 5322, descriptions: &[("en", "Santiago de Cuba Province")] },
+    Area { prefix: 5323, descriptions: &[("en", "Granma Province")] },
+    Area { prefix: 5324, descriptions: &[("en", "Holguín Province")] },
+    Area { prefix: 5331, descriptions: &[("en", "Las Tunas Province")] },
+    Area { prefix: 5332, descriptions: &[("en", "Camagüey Province")] },
+    Area { prefix: 5333, descriptions: &[("en", "Ciego de Ávila Province")] },
+    Area { prefix: 5341, descriptions: &[("en", "Sancti Spíritus Province")] },
+    Area { prefix: 5342, descriptions: &[("en", "Villa Clara Province")] },
+    Area { prefix: 5343, descriptions: &[("en", "Cienfuegos Province")] },
+    Area { prefix: 5345, descriptions: &[("en", "Matanzas Province")] },
+    Area { prefix: 5346, descriptions: &[("en", "Isle of Youth")] },
+    Area { prefix: 5347, descriptions: &[("en", "Mayabeque and Artemisa")] },
+    Area { prefix: 5348, descriptions: &[("en", "Pinar del Río Province")] },
+    Area { prefix: 5349, descriptions: &[("en", "Artemisa Province")] },
+    Area { prefix: 5428, descriptions: &[("en", "Trelew/Rawson, Chubut")] },
+    Area { prefix: 5521, descriptions: &[("en", "Rio de Janeiro")] },
+    Area { prefix: 5522, descriptions: &[("en", "Rio de Janeiro")] },
+    Area { prefix: 5524, descriptions: &[("en", "Rio de Janeiro")] },
+    Area { prefix: 5527, descriptions: &[("en", "Espirito Santo")] },
+    Area { prefix: 5528, descriptions: &[("en", "Espirito Santo")] },
+    Area { prefix: 5532, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5533, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5534, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5535, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5537, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5538, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 5541, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5542, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5543, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5544, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5545, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5546, descriptions: &[("en", "Paraná")] },
+    Area { prefix: 5547, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 5549, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 5553, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 5554, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 5555, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 5561, descriptions: &[("en", "Federal District"), ("pt", "Distrito Federal")] },
+    Area { prefix: 5562, descriptions: &[("en", "Goiás")] },
+    Area { prefix: 5563, descriptions: &[("en", "Tocantins")] },
+    Area { prefix: 5564, descriptions: &[("en", "Goiás")] },
+    Area { prefix: 5565, descriptions: &[("en", "Mato Grosso")] },
+    Area { prefix: 5566, descriptions: &[("en", "Mato Grosso")] },
+    Area { prefix: 5567, descriptions: &[("en", "Mato Grosso do Sul")] },
+    Area { prefix: 5568, descriptions: &[("en", "Acre")] },
+    Area { prefix: 5569, descriptions: &[("en", "Rondônia")] },
+    Area { prefix: 5571, descriptions: &[("en", "Bahia")] },
+    Area { prefix: 5573, descriptions: &[("en", "Bahia")] },
+    Area { prefix: 5574, descriptions: &[("en", "Bahia")] },
+    Area { prefix: 5575, descriptions: &[("en", "Bahia")] },
+    Area { prefix: 5577, descriptions: &[("en", "Bahia")] },
+    Area { prefix: 5579, descriptions: &[("en", "Sergipe")] },
+    Area { prefix: 5581, descriptions: &[("en", "Pernambuco")] },
+    Area { prefix: 5582, descriptions: &[("en", "Alagoas")] },
+    Area { prefix: 5583, descriptions: &[("en", "Paraiba")] },
+    Area { prefix: 5584, descriptions: &[("en", "Rio Grande do Norte")] },
+    Area { prefix: 5585, descriptions: &[("en", "Ceará")] },
+    Area { prefix: 5586, descriptions: &[("en", "Piauí")] },
+    Area { prefix: 5587, descriptions: &[("en", "Pernambuco")] },
+    Area { prefix: 5588, descriptions: &[("en", "Ceará")] },
+    Area { prefix: 5589, descriptions: &[("en", "Piauí")] },
+    Area { prefix: 5591, descriptions: &[("en", "Pará")] },
+    Area { prefix: 5592, descriptions: &[("en", "Amazonas")] },
+    Area { prefix: 5593, descriptions: &[("en", "Pará")] },
+    Area { prefix: 5594, descriptions: &[("en", "Pará")] },
+    Area { prefix: 5595, descriptions: &[("en", "Roraima")] },
+    Area { prefix: 5596, descriptions: &[("en", "Amapá")] },
+    Area { prefix: 5597, descriptions: &[("en", "Amazonas")] },
+    Area { prefix: 5598, descriptions: &[("en", "Maranhão")] },
+    Area { prefix: 5599, descriptions: &[("en", "Maranhão")] },
+    Area { prefix: 5622, descriptions: &[("en", "Santiago, Metropolitan Region"), ("es", "Santiago, Región Metropolitana")] },
+    Area { prefix: 5623, descriptions: &[("en", "Santiago, Metropolitan Region"), ("es", "Santiago, Región Metropolitana")] },
+    Area { prefix: 5626, descriptions: &[("en", "Santiago, Metropolitan Region"), ("es", "Santiago, Región Metropolitana")] },
+    Area { prefix: 5632, descriptions: &[("en", "Valparaíso")] },
+    Area { prefix: 5633, descriptions: &[("en", "Quillota, Valparaíso")] },
+    Area { prefix: 5634, descriptions: &[("en", "San Felipe, Valparaíso")] },
+    Area { prefix: 5635, descriptions: &[("en", "San Antonio, Valparaíso")] },
+    Area { prefix: 5641, descriptions: &[("en", "Concepción, Biobío")] },
+    Area { prefix: 5642, descriptions: &[("en", "Chillán, Biobío")] },
+    Area { prefix: 5643, descriptions: &[("en", "Los Angeles, Biobío")] },
+    Area { prefix: 5645, descriptions: &[("en", "Temuco, Araucanía")] },
+    Area { prefix: 5651, descriptions: &[("en", "La Serena, Coquimbo")] },
+    Area { prefix: 5652, descriptions: &[("en", "Copiapó, Atacama")] },
+    Area { prefix: 5655, descriptions: &[("en", "Antofagasta")] },
+    Area { prefix: 5657, descriptions: &[("en", "Iquique, Tarapacá")] },
+    Area { prefix: 5658, descriptions: &[("en", "Arica, Arica and Parinacota"), ("es", "Arica, Arica y Parinacota")] },
+    Area { prefix: 5661, descriptions: &[("en", "Punta Arenas, Magallanes and Antártica Chilena"), ("es", "Punta Arenas, Magallanes")] },
+    Area { prefix: 5663, descriptions: &[("en", "Valdivia, Los Ríos")] },
+    Area { prefix: 5664, descriptions: &[("en", "Osorno, Los Lagos")] },
+    Area { prefix: 5665, descriptions: &[("en", "Puerto Montt, Los Lagos")] },
+    Area { prefix: 5667, descriptions: &[("en", "Coyhaique, Aisén"), ("es", "Coihaique, Aysén")] },
+    Area { prefix: 5671, descriptions: &[("en", "Talca, Maule")] },
+    Area { prefix: 5672, descriptions: &[("en", "Rancagua, O'Higgins")] },
+    Area { prefix: 5673, descriptions: &[("en", "Linares, Maule")] },
+    Area { prefix: 5675, descriptions: &[("en", "Curicó, Maule")] },
+    Area { prefix: 5821, descriptions: &[("en", "Caracas/Miranda/Vargas"), ("es", "Distrito Capital/Miranda/Vargas")] },
+    Area { prefix: 5982, descriptions: &[("en", "Montevideo")] },
+    Area { prefix: 6120, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 6121, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 6122, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 6221, descriptions: &[("en", "Greater Jakarta"), ("id", "Jabodetabek")] },
+    Area { prefix: 6222, descriptions: &[("en", "Bandung/Cimahi")] },
+    Area { prefix: 6224, descriptions: &[("en", "Semarang/Demak")] },
+    Area { prefix: 6231, descriptions: &[("en", "Surabaya")] },
+    Area { prefix: 6244, descriptions: &[("en", "Marisa")] },
+    Area { prefix: 6261, descriptions: &[("en", "Medan")] },
+    Area { prefix: 6270, descriptions: &[("en", "Tebing Tinggi")] },
+    Area { prefix: 6332, descriptions: &[("en", "Cebu")] },
+    Area { prefix: 6333, descriptions: &[("en", "Iloilo")] },
+    Area { prefix: 6335, descriptions: &[("en", "Negros Oriental")] },
+    Area { prefix: 6336, descriptions: &[("en", "Antique/Aklan/Capiz")] },
+    Area { prefix: 6338, descriptions: &[("en", "Bohol")] },
+    Area { prefix: 6346, descriptions: &[("en", "Cavite")] },
+    Area { prefix: 6348, descriptions: &[("en", "Palawan")] },
+    Area { prefix: 6353, descriptions: &[("en", "Leyte")] },
+    Area { prefix: 6355, descriptions: &[("en", "Western Samar")] },
+    Area { prefix: 6356, descriptions: &[("en", "Sorsogon/Masbate")] },
+    Area { prefix: 6362, descriptions: &[("en", "Zamboanga del Sur")] },
+    Area { prefix: 6363, descriptions: &[("en", "Lanao del Norte/Lanao del Sur")] },
+    Area { prefix: 6365, descriptions: &[("en", "Zamboanga del Norte/Zamboanga del Sur")] },
+    Area { prefix: 6372, descriptions: &[("en", "La Union")] },
+    Area { prefix: 6374, descriptions: &[("en", "Abra/Benguet/Kalinga-Apayao/Ifugao/Mountain Province")] },
+    Area { prefix: 6375, descriptions: &[("en", "Pangasinan")] },
+    Area { prefix: 6377, descriptions: &[("en", "Ilocos Sur/Ilocos Norte")] },
+    Area { prefix: 6378, descriptions: &[("en", "Isabela/Quirino/Batanes/Nueva Vizcaya/Cagayan Valley")] },
+    Area { prefix: 6382, descriptions: &[("en", "Davao del Sur/Davao")] },
+    Area { prefix: 6383, descriptions: &[("en", "South Cotabato")] },
+    Area { prefix: 6384, descriptions: &[("en", "Davao del Norte")] },
+    Area { prefix: 6385, descriptions: &[("en", "Agusan del Sur/Agusan del Norte")] },
+    Area { prefix: 6387, descriptions: &[("en", "Davao Oriental")] },
+    Area { prefix: 6424, descriptions: &[("en", "Scott Base")] },
+    Area { prefix: 6435, descriptions: &[("en", "South Island")] },
+    Area { prefix: 6443, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 6444, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 6445, descriptions: &[("en", "Wellington/Hutt Valley")] },
+    Area { prefix: 6449, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 6492, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 6495, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 6496, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 6498, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 6499, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 6610, descriptions: &[("en", "Bangkok/Nonthaburi/Pathum Thani/Samut Prakan"), ("th", "กรุงเทพ/นนทบุรี/ปทุมธานี/สมุทรปราการ")] },
+    Area { prefix: 6616, descriptions: &[("en", "Bangkok/Nonthaburi/Pathum Thani/Samut Prakan"), ("th", "กรุงเทพ/นนทบุรี/ปทุมธานี/สมุทรปราการ")] },
+    Area { prefix: 6618, descriptions: &[("en", "Bangkok/Nonthaburi/Pathum Thani/Samut Prakan"), ("th", "กรุงเทพ/นนทบุรี/ปทุมธานี/สมุทรปราการ")] },
+    Area { prefix: 6619, descriptions: &[("en", "Bangkok/Nonthaburi/Pathum Thani/Samut Prakan"), ("th", "กรุงเทพ/นนทบุรี/ปทุมธานี/สมุทรปราการ")] },
+    Area { prefix: 6632, descriptions: &[("en", "Phetchaburi/Prachuap Khiri Khan/Ratchaburi"), ("th", "เพชรบุรี/ประจวบคีรีขันธ์/ราชบุรี")] },
+    Area { prefix: 6633, descriptions: &[("en", "Chachoengsao/Chon Buri/Rayong"), ("th", "ฉะเชิงเทรา/ชลบุรี/ระยอง")] },
+    Area { prefix: 6634, descriptions: &[("en", "Kanchanaburi/Nakhon Pathom/Samut Sakhon/Samut Songkhram"), ("th", "กาญจนบุรี/นครปฐม/สมุทรสาคร/สมุทรสงคราม")] },
+    Area { prefix: 6635, descriptions: &[("en", "Ang Thong/Phra Nakhon Si Ayutthaya/Suphan Buri"), ("th", "อ่างทอง/พระนครศรีอยุธยา/สุพรรณบุรี")] },
+    Area { prefix: 6636, descriptions: &[("en", "Lop Buri/Saraburi/Sing Buri"), ("th", "ลพบุรี/สระบุรี/สิงห์บุรี")] },
+    Area { prefix: 6637, descriptions: &[("en", "Nakhon Nayok/Prachin Buri/Sa Kaeo"), ("th", "นครนายก/ปราจีนบุรี/สระแก้ว")] },
+    Area { prefix: 6638, descriptions: &[("en", "Chachoengsao/Chon Buri/Rayong"), ("th", "ฉะเชิงเทรา/ชลบุรี/ระยอง")] },
+    Area { prefix: 6639, descriptions: &[("en", "Chanthaburi/Trat"), ("th", "จันทบุรี/ตราด")] },
+    Area { prefix: 6642, descriptions: &[("en", "Loei/Mukdahan/Nakhon Phanom/Nong Khai/Sakon Nakhon/Udon Thani"), ("th", "เลย/มุกดาหาร/นครพนม/หนองคาย/สกลนคร/อุดรธานี")] },
+    Area { prefix: 6643, descriptions: &[("en", "Kalasin/Khon Kaen/Maha Sarakham/Roi Et"), ("th", "กาฬสินธุ์/ขอนแก่น/มหาสารคาม/ร้อยเอ็ด")] },
+    Area { prefix: 6644, descriptions: &[("en", "Buri Ram/Chaiyaphum/Nakhon Ratchasima/Surin"), ("th", "บุรีรัมย์/ชัยภูมิ/นครราชสีมา/สุรินทร์")] },
+    Area { prefix: 6645, descriptions: &[("en", "Amnat Charoen/Si Sa Ket/Ubon Ratchathani/Yasothon"), ("th", "อำนาจเจริญ/ศรีสะเกษ/อุบลราชธานี/ยโสธร")] },
+    Area { prefix: 6652, descriptions: &[("en", "Chiang Mai/Chiang Rai/Lamphun/Mae Hong Son"), ("th", "เชียงใหม่/เชียงราย/ลำพูน/แม่ฮ่องสอน")] },
+    Area { prefix: 6653, descriptions: &[("en", "Chiang Mai/Chiang Rai/Lamphun/Mae Hong Son"), ("th", "เชียงใหม่/เชียงราย/ลำพูน/แม่ฮ่องสอน")] },
+    Area { prefix: 6654, descriptions: &[("en", "Lampang/Nan/Phayao/Phrae"), ("th", "ลำปาง/น่าน/พะเยา/แพร่")] },
+    Area { prefix: 6655, descriptions: &[("en", "Kamphaeng Phet/Phitsanulok/Sukhothai/Tak/Uttaradit"), ("th", "กำแพงเพชร/พิษณุโลก/สุโขทัย/ตาก/อุตรดิตถ์")] },
+    Area { prefix: 6656, descriptions: &[("en", "Chai Nat/Nakhon Sawan/Phetchabun/Phichit/Uthai Thani"), ("th", "ชัยนาท/นครสวรรค์/เพชรบูรณ์/พิจิตร/อุทัยธานี")] },
+    Area { prefix: 6673, descriptions: &[("en", "Narathiwat/Pattani/Yala"), ("th", "นราธิวาส/ปัตตานี/ยะลา")] },
+    Area { prefix: 6674, descriptions: &[("en", "Phatthalung/Satun/Songkhla"), ("th", "พัทลุง/สตูล/สงขลา")] },
+    Area { prefix: 6675, descriptions: &[("en", "Krabi/Nakhon Si Thammarat/Trang"), ("th", "กระบี่/นครศรีธรรมราช/ตรัง")] },
+    Area { prefix: 6676, descriptions: &[("en", "Phang Nga/Phuket"), ("th", "พังงา/ภูเก็ต")] },
+    Area { prefix: 6677, descriptions: &[("en", "Chumphon/Ranong/Surat Thani"), ("th", "ชุมพร/ระนอง/สุราษฎร์ธานี")] },
+    Area { prefix: 6733, descriptions: &[("en", "Beliat")] },
+    Area { prefix: 6734, descriptions: &[("en", "Tutong")] },
+    Area { prefix: 6753, descriptions: &[("en", "NCD")] },
+    Area { prefix: 6755, descriptions: &[("en", "Highlands")] },
+    Area { prefix: 6756, descriptions: &[("en", "MP/Gulf/Tabubil/Kiunga")] },
+    Area { prefix: 6759, descriptions: &[("en", "Islands")] },
+    Area { prefix: 6762, descriptions: &[("en", "Nuku'alofa")] },
+    Area { prefix: 6784, descriptions: &[("en", "Malampa")] },
+    Area { prefix: 6793, descriptions: &[("en", "Suva City/Nausori/Korovou")] },
+    Area { prefix: 6803, descriptions: &[("en", "Peleliu State")] },
+    Area { prefix: 6822, descriptions: &[("en", "Rarotonga")] },
+    Area { prefix: 6852, descriptions: &[("en", "Apia")] },
+    Area { prefix: 6853, descriptions: &[("en", "Apia")] },
+    Area { prefix: 6854, descriptions: &[("en", "Upolu Rural")] },
+    Area { prefix: 6855, descriptions: &[("en", "Savaii")] },
+    Area { prefix: 6902, descriptions: &[("en", "Atafu Atoll")] },
+    Area { prefix: 6903, descriptions: &[("en", "Fakaofo Atoll")] },
+    Area { prefix: 6904, descriptions: &[("en", "Nakunonu Atoll")] },
+    Area { prefix: 7301, descriptions: &[("en", "Republic of Buryatia"), ("ru", "Республика Бурятия")] },
+    Area { prefix: 7302, descriptions: &[("en", "Chita"), ("ru", "Забайкальский край")] },
+    Area { prefix: 7341, descriptions: &[("en", "Udmurtian Republic"), ("ru", "Республика Удмуртская")] },
+    Area { prefix: 7342, descriptions: &[("en", "Perm"), ("ru", "Пермский край")] },
+    Area { prefix: 7343, descriptions: &[("en", "Ekaterinburg"), ("ru", "Свердловская обл")] },
+    Area { prefix: 7345, descriptions: &[("en", "Tyumen"), ("ru", "Тюменская обл.")] },
+    Area { prefix: 7346, descriptions: &[("en", "Surgut"), ("ru", "Ханты - Мансийский - Югра АО")] },
+    Area { prefix: 7347, descriptions: &[("en", "Republic of Bashkortostan"), ("ru", "Республика Башкортостан")] },
+    Area { prefix: 7349, descriptions: &[("en", "Yamalo-Nenets Autonomous District"), ("ru", "Ямало-Ненецкий АО")] },
+    Area { prefix: 7351, descriptions: &[("en", "Chelyabinsk"), ("ru", "Челябинская обл.")] },
+    Area { prefix: 7352, descriptions: &[("en", "Kurgan"), ("ru", "Курганская обл.")] },
+    Area { prefix: 7353, descriptions: &[("en", "Orenburg"), ("ru", "Оренбургская обл.")] },
+    Area { prefix: 7381, descriptions: &[("en", "Omsk"), ("ru", "Омская обл.")] },
+    Area { prefix: 7382, descriptions: &[("en", "Tomsk"), ("ru", "Томская обл.")] },
+    Area { prefix: 7383, descriptions: &[("en", "Novosibirsk"), ("ru", "Новосибирская обл.")] },
+    Area { prefix: 7384, descriptions: &[("en", "Kemerovo"), ("ru", "Кемеровская обл.")] },
+    Area { prefix: 7385, descriptions: &[("en", "Altai Territory"), ("ru", "Алтайский край")] },
+    Area { prefix: 7388, descriptions: &[("en", "Republic of Altai"), ("ru", "Республика Алтай")] },
+    Area { prefix: 7390, descriptions: &[("en", "Republic of Khakassia"), ("ru", "Республика Хакасия")] },
+    Area { prefix: 7391, descriptions: &[("en", "Krasnoyarsk Territory"), ("ru", "Красноярский край")] },
+    Area { prefix: 7394, descriptions: &[("en", "Republic of Tuva"), ("ru", "Республика Тыва")] },
+    Area { prefix: 7395, descriptions: &[("en", "Irkutsk"), ("ru", "Иркутская обл.")] },
+    Area { prefix: 7411, descriptions: &[("en", "Republic of Sakha"), ("ru", "Республика Саха /Якутия/")] },
+    Area { prefix: 7413, descriptions: &[("en", "Magadan"), ("ru", "Магаданская обл.")] },
+    Area { prefix: 7415, descriptions: &[("en", "Kamchatka Region"), ("ru", "Камчатский край")] },
+    Area { prefix: 7416, descriptions: &[("en", "Amur Region"), ("ru", "Амурская обл.")] },
+    Area { prefix: 7421, descriptions: &[("en", "Khabarovsk Territory"), ("ru", "Хабаровский край")] },
+    Area { prefix: 7423, descriptions: &[("en", "Primorie territory"), ("ru", "Приморский край")] },
+    Area { prefix: 7424, descriptions: &[("en", "Sakhalin Region"), ("ru", "Сахалинская обл.")] },
+    Area { prefix: 7426, descriptions: &[("en", "Jewish Autonomous Region"), ("ru", "Еврейская автономная обл.")] },
+    Area { prefix: 7427, descriptions: &[("en", "Chukotka Autonomous District"), ("ru", "Чукотский АО")] },
+    Area { prefix: 7471, descriptions: &[("en", "Kursk"), ("ru", "Курская обл.")] },
+    Area { prefix: 7472, descriptions: &[("en", "Belgorod"), ("ru", "Белгородская обл.")] },
+    Area { prefix: 7473, descriptions: &[("en", "Voronezh"), ("ru", "Воронежская обл.")] },
+    Area { prefix: 7474, descriptions: &[("en", "Lipetsk"), ("ru", "Липецкая обл.")] },
+    Area { prefix: 7475, descriptions: &[("en", "Tambov"), ("ru", "Тамбовская обл.")] },
+    Area { prefix: 7481, descriptions: &[("en", "Smolensk"), ("ru", "Смоленская обл.")] },
+    Area { prefix: 7482, descriptions: &[("en", "Tver"), ("ru", "Тверская обл.")] },
+    Area { prefix: 7483, descriptions: &[("en", "Bryansk"), ("ru", "Брянская обл.")] },
+    Area { prefix: 7484, descriptions: &[("en", "Kaluga"), ("ru", "Калужская обл.")] },
+    Area { prefix: 7485, descriptions: &[("en", "Yaroslavl"), ("ru", "Ярославская обл.")] },
+    Area { prefix: 7486, descriptions: &[("en", "Orel"), ("ru", "Орловская обл.")] },
+    Area { prefix: 7487, descriptions: &[("en", "Tula"), ("ru", "Тульская обл.")] },
+    Area { prefix: 7491, descriptions: &[("en", "Ryazan"), ("ru", "Рязанская обл.")] },
+    Area { prefix: 7492, descriptions: &[("en", "Vladimir"), ("ru", "Владимирская обл.")] },
+    Area { prefix: 7494, descriptions: &[("en", "Kostroma"), ("ru", "Костромская обл.")] },
+    Area { prefix: 7495, descriptions: &[("en", "Moscow"), ("ru", "Московская обл.")] },
+    Area { prefix: 7496, descriptions: &[("en", "Moscow"), ("ru", "г. Москва")] },
+    Area { prefix: 7498, descriptions: &[("en", "Moscow"), ("ru", "Московская обл.")] },
+    Area { prefix: 7499, descriptions: &[("en", "Moscow"), ("ru", "г. Москва")] },
+    Area { prefix: 7717, descriptions: &[("en", "Astana"), ("ru", "Астана")] },
+    Area { prefix: 7811, descriptions: &[("en", "Pskov"), ("ru", "Псковская обл.")] },
+    Area { prefix: 7812, descriptions: &[("en", "St Petersburg"), ("ru", "г. Санкт-Петербург")] },
+    Area { prefix: 7813, descriptions: &[("en", "Leningrad region"), ("ru", "Ленинградская обл.")] },
+    Area { prefix: 7814, descriptions: &[("en", "Republic of Karelia"), ("ru", "Республика Карелия")] },
+    Area { prefix: 7815, descriptions: &[("en", "Murmansk"), ("ru", "Мурманская обл.")] },
+    Area { prefix: 7816, descriptions: &[("en", "Veliky Novgorod"), ("ru", "Новгородская обл.")] },
+    Area { prefix: 7817, descriptions: &[("en", "Vologda"), ("ru", "Вологодская обл.")] },
+    Area { prefix: 7818, descriptions: &[("en", "Arkhangelsk"), ("ru", "Архангельская обл.")] },
+    Area { prefix: 7820, descriptions: &[("en", "Cherepovets"), ("ru", "Вологодская обл.")] },
+    Area { prefix: 7821, descriptions: &[("en", "Komi Republic"), ("ru", "Республика Коми")] },
+    Area { prefix: 7831, descriptions: &[("en", "Nizhni Novgorod"), ("ru", "Нижегородская обл.")] },
+    Area { prefix: 7833, descriptions: &[("en", "Kirov"), ("ru", "Кировская обл.")] },
+    Area { prefix: 7834, descriptions: &[("en", "Republic of Mordovia"), ("ru", "Республика Мордовия")] },
+    Area { prefix: 7835, descriptions: &[("en", "Chuvashi Republic"), ("ru", "Чувашская Республика - Чувашия")] },
+    Area { prefix: 7836, descriptions: &[("en", "Republic of Marij El"), ("ru", "Республика Марий Эл")] },
+    Area { prefix: 7841, descriptions: &[("en", "Penza"), ("ru", "Пензенская обл.")] },
+    Area { prefix: 7842, descriptions: &[("en", "Ulyanovsk"), ("ru", "Ульяновская обл.")] },
+    Area { prefix: 7843, descriptions: &[("en", "Republic of Tatarstan"), ("ru", "Республика Татарстан")] },
+    Area { prefix: 7844, descriptions: &[("en", "Volgograd"), ("ru", "Волгоградская обл.")] },
+    Area { prefix: 7845, descriptions: &[("en", "Saratov"), ("ru", "Саратовская обл.")] },
+    Area { prefix: 7846, descriptions: &[("en", "Samara"), ("ru", "Самарская обл.")] },
+    Area { prefix: 7847, descriptions: &[("en", "Republic of Kalmykia"), ("ru", "Республика Калмыкия")] },
+    Area { prefix: 7848, descriptions: &[("en", "Tolyatti"), ("ru", "Самарская обл.")] },
+    Area { prefix: 7851, descriptions: &[("en", "Astrakhan"), ("ru", "Астраханская обл.")] },
+    Area { prefix: 7855, descriptions: &[("en", "Naberezhnye Chelny"), ("ru", "Республика Татарстан")] },
+    Area { prefix: 7856, descriptions: &[("en", "Donetsk People's Republic"), ("ru", "Донецкая Народная Республика")] },
+    Area { prefix: 7857, descriptions: &[("en", "Luhansk People's Republic"), ("ru", "Луга́нская Наро́дная Респу́блика")] },
+    Area { prefix: 7860, descriptions: &[("en", "Kherson Region"), ("ru", "Херсонская область")] },
+    Area { prefix: 7861, descriptions: &[("en", "Krasnodar Territory"), ("ru", "Краснодарский край")] },
+    Area { prefix: 7862, descriptions: &[("en", "Sochi"), ("ru", "Краснодарский край")] },
+    Area { prefix: 7863, descriptions: &[("en", "Rostov"), ("ru", "Ростовская обл.")] },
+    Area { prefix: 7865, descriptions: &[("en", "Stavropol territory"), ("ru", "Ставропольский край")] },
+    Area { prefix: 7866, descriptions: &[("en", "Kabardino-Balkarian Republic"), ("ru", "Республика Кабардино-Балкарская")] },
+    Area { prefix: 7867, descriptions: &[("en", "Republic of North Ossetia"), ("ru", "Республика Северная Осетия - Алания")] },
+    Area { prefix: 7869, descriptions: &[("en", "Sevastopol"), ("ru", "г. Севастополь")] },
+    Area { prefix: 7871, descriptions: &[("en", "Chechen Republic"), ("ru", "Республика Чеченская")] },
+    Area { prefix: 7872, descriptions: &[("en", "Republic of Daghestan"), ("ru", "Республика Дагестан")] },
+    Area { prefix: 7873, descriptions: &[("en", "Ingushi Republic"), ("ru", "Республика Ингушетия")] },
+    Area { prefix: 7877, descriptions: &[("en", "Republic of Adygeya"), ("ru", "Республика Адыгея")] },
+    Area { prefix: 7878, descriptions: &[("en", "Karachayevo-Cherkessian Republic"), ("ru", "Республика Карачаево-Черкесская")] },
+    Area { prefix: 7879, descriptions: &[("en", "Mineranye Vody"), ("ru", "Ставропольский край")] },
+    Area { prefix: 8111, descriptions: &[("en", "Sapporo, Hokkaido"), ("ja", "札幌")] },
+    Area { prefix: 8144, descriptions: &[("en", "Kawasaki, Kanagawa"), ("ja", "川崎")] },
+    Area { prefix: 8145, descriptions: &[("en", "Yokohama, Kanagawa"), ("ja", "横浜")] },
+    Area { prefix: 8152, descriptions: &[("en", "Nagoya, Aichi"), ("ja", "名古屋")] },
+    Area { prefix: 8161, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8162, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8163, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8164, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8165, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8166, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8167, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8168, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8169, descriptions: &[("en", "Osaka, Osaka"), ("ja", "大阪")] },
+    Area { prefix: 8175, descriptions: &[("en", "Kyoto, Kyoto"), ("ja", "京都")] },
+    Area { prefix: 8178, descriptions: &[("en", "Kobe, Hyogo"), ("ja", "神戸")] },
+    Area { prefix: 8231, descriptions: &[("en", "Gyeonggi"), ("ko", "경기")] },
+    Area { prefix: 8232, descriptions: &[("en", "Incheon"), ("ko", "인천")] },
+    Area { prefix: 8233, descriptions: &[("en", "Gangwon"), ("ko", "강원")] },
+    Area { prefix: 8241, descriptions: &[("en", "Chungnam"), ("ko", "충남")] },
+    Area { prefix: 8242, descriptions: &[("en", "Daejeon"), ("ko", "대전")] },
+    Area { prefix: 8243, descriptions: &[("en", "Chungbuk"), ("ko", "충북")] },
+    Area { prefix: 8244, descriptions: &[("en", "Sejong City"), ("ko", "세종")] },
+    Area { prefix: 8251, descriptions: &[("en", "Busan"), ("ko", "부산")] },
+    Area { prefix: 8252, descriptions: &[("en", "Ulsan"), ("ko", "울산")] },
+    Area { prefix: 8253, descriptions: &[("en", "Daegu"), ("ko", "대구")] },
+    Area { prefix: 8254, descriptions: &[("en", "Gyeongbuk"), ("ko", "경북")] },
+    Area { prefix: 8255, descriptions: &[("en", "Gyeongnam"), ("ko", "경남")] },
+    Area { prefix: 8261, descriptions: &[("en", "Jeonnam"), ("ko", "전남")] },
+    Area { prefix: 8262, descriptions: &[("en", "Gwangju"), ("ko", "광주")] },
+    Area { prefix: 8263, descriptions: &[("en", "Jeonbuk"), ("ko", "전북")] },
+    Area { prefix: 8264, descriptions: &[("en", "Jeju"), ("ko", "제주")] },
+    Area { prefix: 8424, descriptions: &[("en", "Hanoi City"), ("vi", "Thủ đô Hà Nội")] },
+    Area { prefix: 8428, descriptions: &[("en", "Ho Chi Minh City"), ("vi", "Thành phố Hồ Chí Minh")] },
+    Area { prefix: 8508, descriptions: &[("en", "Rason")] },
+    Area { prefix: 8610, descriptions: &[("en", "Beijing"), ("zh", "北京市")] },
+    Area { prefix: 8620, descriptions: &[("en", "Guangzhou, Guangdong"), ("zh", "广东省广州市")] },
+    Area { prefix: 8621, descriptions: &[("en", "Shanghai"), ("zh", "上海市")] },
+    Area { prefix: 8622, descriptions: &[("en", "Tianjin"), ("zh", "天津市")] },
+    Area { prefix: 8623, descriptions: &[("en", "Chongqing"), ("zh", "重庆市")] },
+    Area { prefix: 8624, descriptions: &[("en", "Shenyang/Tieling/Fushun, Liaoning"), ("zh", "辽宁省沈阳市、铁岭市、抚顺市")] },
+    Area { prefix: 8625, descriptions: &[("en", "Nanjing, Jiangsu"), ("zh", "江苏省南京市")] },
+    Area { prefix: 8627, descriptions: &[("en", "Wuhan, Hubei"), ("zh", "湖北省武汉市")] },
+    Area { prefix: 8628, descriptions: &[("en", "Chengdu/Ziyang/Meishan, Sichuan"), ("zh", "四川省成都市、资阳市、眉山市")] },
+    Area { prefix: 8629, descriptions: &[("en", "XiAn/Xianyang, Shaanxi"), ("zh", "陕西省西安市、咸阳市")] },
+    Area { prefix: 8633, descriptions: &[("en", "Qinhuangdao, Hebei"), ("zh", "河北省秦皇岛市")] },
+    Area { prefix: 8634, descriptions: &[("en", "Shuozhou, Shanxi"), ("zh", "山西省朔州市")] },
+    Area { prefix: 8658, descriptions: &[("en", "Zhoushan, Zhejiang"), ("zh", "浙江省舟山市")] },
+    Area { prefix: 8670, descriptions: &[("en", "Yingtan, Jiangxi"), ("zh", "江西省鹰潭市")] },
+    Area { prefix: 8862, descriptions: &[("en", "Taipei"), ("zh", "台北"), ("zh-Hant", "臺北")] },
+    Area { prefix: 8863, descriptions: &[("en", "Taoyuan/Hsinchu/Yilan/Hualien"), ("zh", "桃园、新竹、花莲、宜兰"), ("zh-Hant", "桃園、新竹、花蓮、宜蘭")] },
+    Area { prefix: 8866, descriptions: &[("en", "Tainan/Penghu"), ("zh", "台南、澎湖"), ("zh-Hant", "臺南、澎湖")] },
+    Area { prefix: 9039, descriptions: &[("en", "Northern Cyprus"), ("tr", "Kuzey Kıbrıs")] },
+    Area { prefix: 9111, descriptions: &[("en", "New Delhi")] },
+    Area { prefix: 9133, descriptions: &[("en", "Kolkata, West Bengal")] },
+    Area { prefix: 9140, descriptions: &[("en", "Hyderabad Local, Andhra Pradesh")] },
+    Area { prefix: 9144, descriptions: &[("en", "Chennai, Tamil Nadu")] },
+    Area { prefix: 9180, descriptions: &[("en", "Bangalore, Karnataka")] },
+    Area { prefix: 9258, descriptions: &[("en", "AJK/FATA")] },
+    Area { prefix: 9320, descriptions: &[("en", "Kabul"), ("fa", "کابل")] },
+    Area { prefix: 9321, descriptions: &[("en", "Parwan"), ("fa", "پروان")] },
+    Area { prefix: 9322, descriptions: &[("en", "Kapisa"), ("fa", "کاپیسا")] },
+    Area { prefix: 9323, descriptions: &[("en", "Bamian"), ("fa", "بامیان")] },
+    Area { prefix: 9324, descriptions: &[("en", "Wardak"), ("fa", "وردک")] },
+    Area { prefix: 9325, descriptions: &[("en", "Logar"), ("fa", "لوگر")] },
+    Area { prefix: 9326, descriptions: &[("en", "Dorkondi"), ("fa", "دایکندی")] },
+    Area { prefix: 9327, descriptions: &[("en", "Khost"), ("fa", "خوست")] },
+    Area { prefix: 9328, descriptions: &[("en", "Panjshar"), ("fa", "پنجشیر")] },
+    Area { prefix: 9330, descriptions: &[("en", "Kandahar"), ("fa", "قندهار")] },
+    Area { prefix: 9331, descriptions: &[("en", "Ghazni"), ("fa", "غزنی")] },
+    Area { prefix: 9332, descriptions: &[("en", "Uruzgan"), ("fa", "ارزگان")] },
+    Area { prefix: 9333, descriptions: &[("en", "Zabol"), ("fa", "زابل")] },
+    Area { prefix: 9334, descriptions: &[("en", "Helmand"), ("fa", "هلمند")] },
+    Area { prefix: 9340, descriptions: &[("en", "Heart"), ("fa", "هرات")] },
+    Area { prefix: 9341, descriptions: &[("en", "Badghis"), ("fa", "بادغیس")] },
+    Area { prefix: 9342, descriptions: &[("en", "Ghowr"), ("fa", "غور")] },
+    Area { prefix: 9343, descriptions: &[("en", "Farah"), ("fa", "فراه")] },
+    Area { prefix: 9344, descriptions: &[("en", "Nimruz"), ("fa", "نیمروز")] },
+    Area { prefix: 9350, descriptions: &[("en", "Balkh"), ("fa", "بلخ")] },
+    Area { prefix: 9351, descriptions: &[("en", "Kunduz"), ("fa", "قندوز")] },
+    Area { prefix: 9352, descriptions: &[("en", "Badkhshan"), ("fa", "بدخشان")] },
+    Area { prefix: 9353, descriptions: &[("en", "Takhar"), ("fa", "تخار")] },
+    Area { prefix: 9354, descriptions: &[("en", "Jowzjan"), ("fa", "جوزجان")] },
+    Area { prefix: 9355, descriptions: &[("en", "Samangan"), ("fa", "سمنگان")] },
+    Area { prefix: 9356, descriptions: &[("en", "Sar-E Pol"), ("fa", "سر پل")] },
+    Area { prefix: 9357, descriptions: &[("en", "Faryab"), ("fa", "فاریاب")] },
+    Area { prefix: 9358, descriptions: &[("en", "Baghlan"), ("fa", "بغلان")] },
+    Area { prefix: 9360, descriptions: &[("en", "Nangarhar"), ("fa", "ننگرهار")] },
+    Area { prefix: 9361, descriptions: &[("en", "Nurestan"), ("fa", "نورستان")] },
+    Area { prefix: 9362, descriptions: &[("en", "Kunarha"), ("fa", "کنرها")] },
+    Area { prefix: 9363, descriptions: &[("en", "Laghman"), ("fa", "لغمان")] },
+    Area { prefix: 9364, descriptions: &[("en", "Paktia"), ("fa", "پکتیا")] },
+    Area { prefix: 9365, descriptions: &[("en", "Paktika"), ("fa", "پکتیکا")] },
+    Area { prefix: 9411, descriptions: &[("en", "Colombo")] },
+    Area { prefix: 9421, descriptions: &[("en", "Jaffna")] },
+    Area { prefix: 9423, descriptions: &[("en", "Mannar")] },
+    Area { prefix: 9424, descriptions: &[("en", "Vavuniya")] },
+    Area { prefix: 9425, descriptions: &[("en", "Anuradhapura")] },
+    Area { prefix: 9426, descriptions: &[("en", "Trincomalee")] },
+    Area { prefix: 9427, descriptions: &[("en", "Polonnaruwa")] },
+    Area { prefix: 9431, descriptions: &[("en", "Negombo, Gampaha")] },
+    Area { prefix: 9432, descriptions: &[("en", "Chilaw, Puttalam")] },
+    Area { prefix: 9433, descriptions: &[("en", "Gampaha")] },
+    Area { prefix: 9434, descriptions: &[("en", "Kalutara")] },
+    Area { prefix: 9435, descriptions: &[("en", "Kegalle")] },
+    Area { prefix: 9436, descriptions: &[("en", "Avissawella, Colombo")] },
+    Area { prefix: 9437, descriptions: &[("en", "Kurunegala")] },
+    Area { prefix: 9438, descriptions: &[("en", "Panadura, Kalutara")] },
+    Area { prefix: 9441, descriptions: &[("en", "Matara")] },
+    Area { prefix: 9445, descriptions: &[("en", "Ratnapura")] },
+    Area { prefix: 9447, descriptions: &[("en", "Hambantota")] },
+    Area { prefix: 9451, descriptions: &[("en", "Hatton, Nuwara Eliya")] },
+    Area { prefix: 9452, descriptions: &[("en", "Nuwara Eliya")] },
+    Area { prefix: 9454, descriptions: &[("en", "Nawalapitiya, Kandy")] },
+    Area { prefix: 9455, descriptions: &[("en", "Badulla")] },
+    Area { prefix: 9457, descriptions: &[("en", "Bandarawela, Badulla")] },
+    Area { prefix: 9463, descriptions: &[("en", "Ampara")] },
+    Area { prefix: 9465, descriptions: &[("en", "Batticaloa")] },
+    Area { prefix: 9466, descriptions: &[("en", "Matale")] },
+    Area { prefix: 9467, descriptions: &[("en", "Kalmunai, Ampara")] },
+    Area { prefix: 9482, descriptions: &[("en", "Kandy")] },
+    Area { prefix: 9491, descriptions: &[("en", "Galle")] },
+    Area { prefix: 9511, descriptions: &[("en", "Yangon")] },
+    Area { prefix: 9671, descriptions: &[("en", "Sanaa")] },
+    Area { prefix: 9673, descriptions: &[("en", "Hodaidah")] },
+    Area { prefix: 9712, descriptions: &[("en", "Abu dhabi")] },
+    Area { prefix: 9713, descriptions: &[("en", "Al Ain")] },
+    Area { prefix: 9722, descriptions: &[("en", "Jerusalem"), ("he", "ירושלים")] },
+    Area { prefix: 9723, descriptions: &[("en", "Tel Aviv"), ("he", "תל אביב-יפו והמרכז")] },
+    Area { prefix: 9724, descriptions: &[("en", "Haifa and North Regions"), ("he", "חיפה והצפון")] },
+    Area { prefix: 9728, descriptions: &[("en", "Hashfela and South Regions"), ("he", "השפלה והדרום")] },
+    Area { prefix: 9729, descriptions: &[("en", "Hasharon"), ("he", "השרון")] },
+    Area { prefix: 9752, descriptions: &[("en", "Thimphu")] },
+    Area { prefix: 9753, descriptions: &[("en", "Trongsa")] },
+    Area { prefix: 9754, descriptions: &[("en", "Trashigang")] },
+    Area { prefix: 9755, descriptions: &[("en", "Phuentsholing")] },
+    Area { prefix: 9756, descriptions: &[("en", "Gelephu")] },
+    Area { prefix: 9811, descriptions: &[("en", "Mazandaran"), ("fa", "مازندران")] },
+    Area { prefix: 9813, descriptions: &[("en", "Gilan"), ("fa", "گیلان")] },
+    Area { prefix: 9817, descriptions: &[("en", "Golestan"), ("fa", "گلستان")] },
+    Area { prefix: 9821, descriptions: &[("en", "Tehran province"), ("fa", "استان تهران")] },
+    Area { prefix: 9823, descriptions: &[("en", "Semnan province"), ("fa", "استان سمنان")] },
+    Area { prefix: 9824, descriptions: &[("en", "Zanjan province"), ("fa", "استان زنجان")] },
+    Area { prefix: 9825, descriptions: &[("en", "Qom province"), ("fa", "استان قم")] },
+    Area { prefix: 9826, descriptions: &[("en", "Alborz"), ("fa", "البرز")] },
+    Area { prefix: 9828, descriptions: &[("en", "Qazvin province"), ("fa", "استان قزوین")] },
+    Area { prefix: 9831, descriptions: &[("en", "Isfahan province"), ("fa", "استان اصفهان")] },
+    Area { prefix: 9834, descriptions: &[("en", "Kerman province"), ("fa", "استان کرمان")] },
+    Area { prefix: 9835, descriptions: &[("en", "Yazd province"), ("fa", "استان یزد")] },
+    Area { prefix: 9838, descriptions: &[("en", "Chahar-mahal and Bakhtiari"), ("fa", "چهارمحال و بختیاری")] },
+    Area { prefix: 9841, descriptions: &[("en", "East Azarbaijan"), ("fa", "آذربایجان شرقی")] },
+    Area { prefix: 9844, descriptions: &[("en", "West Azarbaijan"), ("fa", "آذربایجان غربی")] },
+    Area { prefix: 9845, descriptions: &[("en", "Ardabil province"), ("fa", "استان اردبیل")] },
+    Area { prefix: 9851, descriptions: &[("en", "Razavi Khorasan"), ("fa", "خراسان رضوی")] },
+    Area { prefix: 9854, descriptions: &[("en", "Sistan and Baluchestan"), ("fa", "سیستان و بلوچستان")] },
+    Area { prefix: 9856, descriptions: &[("en", "South Khorasan"), ("fa", "خراسان جنوبی")] },
+    Area { prefix: 9858, descriptions: &[("en", "North Khorasan"), ("fa", "خراسان شمالی")] },
+    Area { prefix: 9861, descriptions: &[("en", "Khuzestan"), ("fa", "خوزستان")] },
+    Area { prefix: 9866, descriptions: &[("en", "Lorestan"), ("fa", "لرستان")] },
+    Area { prefix: 9871, descriptions: &[("en", "Fars"), ("fa", "فارس")] },
+    Area { prefix: 9874, descriptions: &[("en", "Kohgiluyeh and Boyer-Ahmad"), ("fa", "کهگیلویه و بویراحمد")] },
+    Area { prefix: 9876, descriptions: &[("en", "Hormozgan"), ("fa", "هرمزگان")] },
+    Area { prefix: 9877, descriptions: &[("en", "Bushehr province"), ("fa", "استان بوشهر")] },
+    Area { prefix: 9881, descriptions: &[("en", "Hamadan province"), ("fa", "استان همدان")] },
+    Area { prefix: 9883, descriptions: &[("en", "Kermanshah province"), ("fa", "استان کرمانشاه")] },
+    Area { prefix: 9884, descriptions: &[("en", "Ilam province"), ("fa", "استان ایلام")] },
+    Area { prefix: 9886, descriptions: &[("en", "Markazi"), ("fa", "مرکزی")] },
+    Area { prefix: 9887, descriptions: &[("en", "Kurdistan"), ("fa", "کردستان")] },
+    Area { prefix: 9931, descriptions: &[("en", "Ahal")] },
+    Area { prefix: 9932, descriptions: &[("en", "Balkan")] },
+    Area { prefix: 9933, descriptions: &[("en", "Daşoguz")] },
+    Area { prefix: 9934, descriptions: &[("en", "Lebap")] },
+    Area { prefix: 9935, descriptions: &[("en", "Mary")] },
+    Area { prefix: 20554, descriptions: &[("en", "10th of Ramadan")] },
+    Area { prefix: 21251, descriptions: &[("en", "Rabat/Tanger/Fès/Meknès")] },
+    Area { prefix: 21321, descriptions: &[("en", "Algiers")] },
+    Area { prefix: 21327, descriptions: &[("en", "Chlef")] },
+    Area { prefix: 21329, descriptions: &[("en", "Ghardaia/Illizi/Tamanrasset")] },
+    Area { prefix: 21331, descriptions: &[("en", "Constantine")] },
+    Area { prefix: 21332, descriptions: &[("en", "El Oued")] },
+    Area { prefix: 21333, descriptions: &[("en", "Batna/Beskra")] },
+    Area { prefix: 21334, descriptions: &[("en", "Béjaïa/Jijel")] },
+    Area { prefix: 21335, descriptions: &[("en", "Bordj Bou Arreridj")] },
+    Area { prefix: 21337, descriptions: &[("en", "Tebessa")] },
+    Area { prefix: 21338, descriptions: &[("en", "Annaba/Skikda")] },
+    Area { prefix: 21341, descriptions: &[("en", "Oran")] },
+    Area { prefix: 21343, descriptions: &[("en", "Tlemcen")] },
+    Area { prefix: 21344, descriptions: &[("en", "Blida")] },
+    Area { prefix: 21349, descriptions: &[("en", "Adrar/Béchar/Tindouf")] },
+    Area { prefix: 21670, descriptions: &[("en", "Ben Arous")] },
+    Area { prefix: 21671, descriptions: &[("en", "Ariana/Ben Arous/Carthage/Tunis")] },
+    Area { prefix: 21672, descriptions: &[("en", "Bizerte/Nabeul/Zaghouan")] },
+    Area { prefix: 21673, descriptions: &[("en", "Chebba/Hamman-Sousse/Khenis/Mahdia/Monastir/Sousse")] },
+    Area { prefix: 21674, descriptions: &[("en", "Agareb/Sfax")] },
+    Area { prefix: 21675, descriptions: &[("en", "Gabes/Kebili/Medenine/Tataouine")] },
+    Area { prefix: 21676, descriptions: &[("en", "Gafsa/Sidi Bouzid/Tozeur")] },
+    Area { prefix: 21677, descriptions: &[("en", "Haffouz/Kairouan/Kasserine")] },
+    Area { prefix: 21678, descriptions: &[("en", "Beja/Jendouba/Kef/La Kef/Siliana/Tabarka")] },
+    Area { prefix: 21679, descriptions: &[("en", "Ariana/Ben Arous/Manouba/Tunis")] },
+    Area { prefix: 21821, descriptions: &[("en", "Tripoli")] },
+    Area { prefix: 21822, descriptions: &[("en", "Ben Gashir")] },
+    Area { prefix: 21823, descriptions: &[("en", "Zawia")] },
+    Area { prefix: 21824, descriptions: &[("en", "Sabratha")] },
+    Area { prefix: 21825, descriptions: &[("en", "Zuara")] },
+    Area { prefix: 21826, descriptions: &[("en", "Taigura")] },
+    Area { prefix: 21831, descriptions: &[("en", "Khums")] },
+    Area { prefix: 21841, descriptions: &[("en", "Garian")] },
+    Area { prefix: 21847, descriptions: &[("en", "Nalut")] },
+    Area { prefix: 21851, descriptions: &[("en", "Misratah")] },
+    Area { prefix: 21854, descriptions: &[("en", "Sirt")] },
+    Area { prefix: 21857, descriptions: &[("en", "Hun")] },
+    Area { prefix: 21861, descriptions: &[("en", "Benghazi")] },
+    Area { prefix: 21863, descriptions: &[("en", "Benina")] },
+    Area { prefix: 21867, descriptions: &[("en", "Elmareg")] },
+    Area { prefix: 21871, descriptions: &[("en", "Sebha")] },
+    Area { prefix: 21873, descriptions: &[("en", "Ubary")] },
+    Area { prefix: 21881, descriptions: &[("en", "Derna")] },
+    Area { prefix: 21882, descriptions: &[("en", "Haraua")] },
+    Area { prefix: 21884, descriptions: &[("en", "El Beida")] },
+    Area { prefix: 21888, descriptions: &[("en", "Jaghbub")] },
+    Area { prefix: 22042, descriptions: &[("en", "Banjul")] },
+    Area { prefix: 22043, descriptions: &[("en", "Bundung/Serekunda")] },
+    Area { prefix: 22245, descriptions: &[("en", "Nouakchott")] },
+    Area { prefix: 22822, descriptions: &[("en", "Lome"), ("es", "Lomé"), ("fr", "Lomé")] },
+    Area { prefix: 22823, descriptions: &[("en", "Maritime region"), ("es", "Región Marítima"), ("fr", "Région Maritime")] },
+    Area { prefix: 22824, descriptions: &[("en", "Plateaux region"), ("es", "Región Plateaux"), ("fr", "Région des Plateaux")] },
+    Area { prefix: 22825, descriptions: &[("en", "Central region"), ("es", "Región Central"), ("fr", "Région Centrale")] },
+    Area { prefix: 22826, descriptions: &[("en", "Kara region"), ("es", "Región de Kara"), ("fr", "Région de la Kara")] },
+    Area { prefix: 22827, descriptions: &[("en", "Savannah region"), ("es", "Región de Savannah"), ("fr", "Région des Savanes")] },
+    Area { prefix: 23020, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23023, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23024, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23026, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23027, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23028, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 23081, descriptions: &[("en", "Agalega")] },
+    Area { prefix: 23083, descriptions: &[("en", "Rodrigues")] },
+    Area { prefix: 23222, descriptions: &[("en", "Freetown")] },
+    Area { prefix: 24422, descriptions: &[("en", "Luanda")] },
+    Area { prefix: 24762, descriptions: &[("en", "US Base")] },
+    Area { prefix: 24763, descriptions: &[("en", "Travellers Hill & Airhead")] },
+    Area { prefix: 24764, descriptions: &[("en", "Two Boats")] },
+    Area { prefix: 24766, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 24767, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 25440, descriptions: &[("en", "Kwale/Ukunda/Msambweni/Lungalunga")] },
+    Area { prefix: 25441, descriptions: &[("en", "Mombasa/Mariakani/Kilifi")] },
+    Area { prefix: 25442, descriptions: &[("en", "Malindi/Lamu/Garsen")] },
+    Area { prefix: 25443, descriptions: &[("en", "Voi/Wundanyi/Mwatate/Taveta")] },
+    Area { prefix: 25444, descriptions: &[("en", "Machakos/Makueni/Mwingi/Kitui")] },
+    Area { prefix: 25445, descriptions: &[("en", "Kajiado/Ngong/Loitokitok/Athi River")] },
+    Area { prefix: 25446, descriptions: &[("en", "Garissa/Hola/Wajir/Mandera")] },
+    Area { prefix: 25450, descriptions: &[("en", "Naivasha/Narok/Gilgil")] },
+    Area { prefix: 25451, descriptions: &[("en", "Nakuru/Njoro/Molo")] },
+    Area { prefix: 25452, descriptions: &[("en", "Kericho/Bomet")] },
+    Area { prefix: 25453, descriptions: &[("en", "Eldoret/Turbo/Kapsabet/Iten/Kabarnet")] },
+    Area { prefix: 25454, descriptions: &[("en", "Kitale/Moi's Bridge/Kapenguria/Lodwar")] },
+    Area { prefix: 25455, descriptions: &[("en", "Bungoma/Busia")] },
+    Area { prefix: 25456, descriptions: &[("en", "Kakamega/Mbale/Butere/Mumias/Vihiga")] },
+    Area { prefix: 25457, descriptions: &[("en", "Kisumu/Siaya/Maseno")] },
+    Area { prefix: 25458, descriptions: &[("en", "Kisii/Kilgoris/Oyugis/Nyamira")] },
+    Area { prefix: 25459, descriptions: &[("en", "Homabay/Migori")] },
+    Area { prefix: 25460, descriptions: &[("en", "Muranga/Kerugoya")] },
+    Area { prefix: 25461, descriptions: &[("en", "Nyeri/Karatina")] },
+    Area { prefix: 25462, descriptions: &[("en", "Nanyuki")] },
+    Area { prefix: 25464, descriptions: &[("en", "Meru/Maua/Chuka")] },
+    Area { prefix: 25465, descriptions: &[("en", "Nyahururu/Maralal")] },
+    Area { prefix: 25466, descriptions: &[("en", "Thika/Ruiru")] },
+    Area { prefix: 25467, descriptions: &[("en", "Kiambu/Kikuyu")] },
+    Area { prefix: 25468, descriptions: &[("en", "Embu")] },
+    Area { prefix: 25469, descriptions: &[("en", "Marsabit/Moyale")] },
+    Area { prefix: 25522, descriptions: &[("en", "Dar-Es-Salaam")] },
+    Area { prefix: 25523, descriptions: &[("en", "Coast/Morogoro/Lindi/Mtwara")] },
+    Area { prefix: 25524, descriptions: &[("en", "Zanzibar")] },
+    Area { prefix: 25525, descriptions: &[("en", "Mbeya/Songwe/Ruvuma/Katavi/Rukwa")] },
+    Area { prefix: 25526, descriptions: &[("en", "Dodoma/Iringa/Njombe/Singida/Tabora")] },
+    Area { prefix: 25527, descriptions: &[("en", "Arusha/Manyara/Kilimanjaro/Tanga")] },
+    Area { prefix: 25528, descriptions: &[("en", "Mwanza/Shinyanga/Mara/Geita/Simiyu/Kagera/Kigoma")] },
+    Area { prefix: 25641, descriptions: &[("en", "Kampala")] },
+    Area { prefix: 25643, descriptions: &[("en", "Jinja")] },
+    Area { prefix: 25645, descriptions: &[("en", "Mbale")] },
+    Area { prefix: 25646, descriptions: &[("en", "Mityana")] },
+    Area { prefix: 25821, descriptions: &[("en", "Maputo")] },
+    Area { prefix: 25823, descriptions: &[("en", "Beira")] },
+    Area { prefix: 25824, descriptions: &[("en", "Quelimane")] },
+    Area { prefix: 25826, descriptions: &[("en", "Nampula")] },
+    Area { prefix: 25829, descriptions: &[("en", "Inhambane")] },
+    Area { prefix: 26313, descriptions: &[("en", "Victoria Falls")] },
+    Area { prefix: 26314, descriptions: &[("en", "Rutenga")] },
+    Area { prefix: 26315, descriptions: &[("en", "Binga")] },
+    Area { prefix: 26316, descriptions: &[("en", "West Nicholson")] },
+    Area { prefix: 26317, descriptions: &[("en", "Filabusi")] },
+    Area { prefix: 26318, descriptions: &[("en", "Dete")] },
+    Area { prefix: 26319, descriptions: &[("en", "Plumtree")] },
+    Area { prefix: 26323, descriptions: &[("en", "Chiredzi")] },
+    Area { prefix: 26325, descriptions: &[("en", "Rusape")] },
+    Area { prefix: 26326, descriptions: &[("en", "Chimanimani")] },
+    Area { prefix: 26329, descriptions: &[("en", "Bulawayo")] },
+    Area { prefix: 26330, descriptions: &[("en", "Gutu")] },
+    Area { prefix: 26331, descriptions: &[("en", "Chiredzi")] },
+    Area { prefix: 26332, descriptions: &[("en", "Mvuma")] },
+    Area { prefix: 26333, descriptions: &[("en", "Triangle")] },
+    Area { prefix: 26334, descriptions: &[("en", "Jerera")] },
+    Area { prefix: 26335, descriptions: &[("en", "Mashava")] },
+    Area { prefix: 26336, descriptions: &[("en", "Ngundu")] },
+    Area { prefix: 26339, descriptions: &[("en", "Masvingo")] },
+    Area { prefix: 26350, descriptions: &[("en", "Shanagani")] },
+    Area { prefix: 26352, descriptions: &[("en", "Shurugwi")] },
+    Area { prefix: 26353, descriptions: &[("en", "Chegutu")] },
+    Area { prefix: 26354, descriptions: &[("en", "Gweru")] },
+    Area { prefix: 26355, descriptions: &[("en", "Kwekwe")] },
+    Area { prefix: 26356, descriptions: &[("en", "Chivhu")] },
+    Area { prefix: 26357, descriptions: &[("en", "Centenary")] },
+    Area { prefix: 26358, descriptions: &[("en", "Guruve")] },
+    Area { prefix: 26359, descriptions: &[("en", "Gokwe")] },
+    Area { prefix: 26360, descriptions: &[("en", "Mhangura")] },
+    Area { prefix: 26361, descriptions: &[("en", "Kariba")] },
+    Area { prefix: 26362, descriptions: &[("en", "Norton")] },
+    Area { prefix: 26363, descriptions: &[("en", "Makuti")] },
+    Area { prefix: 26364, descriptions: &[("en", "Karoi")] },
+    Area { prefix: 26365, descriptions: &[("en", "Beatrice")] },
+    Area { prefix: 26366, descriptions: &[("en", "Banket")] },
+    Area { prefix: 26367, descriptions: &[("en", "Chinhoyi")] },
+    Area { prefix: 26368, descriptions: &[("en", "Kadoma")] },
+    Area { prefix: 26369, descriptions: &[("en", "Darwendale")] },
+    Area { prefix: 26383, descriptions: &[("en", "Victoria Falls")] },
+    Area { prefix: 26385, descriptions: &[("en", "BeitBridge")] },
+    Area { prefix: 26461, descriptions: &[("en", "Windhoek")] },
+    Area { prefix: 26622, descriptions: &[("en", "Maseru")] },
+    Area { prefix: 26724, descriptions: &[("en", "Francistown")] },
+    Area { prefix: 26726, descriptions: &[("en", "Selebi-Phikwe")] },
+    Area { prefix: 26729, descriptions: &[("en", "Letlhakane/Orapa")] },
+    Area { prefix: 26735, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 26736, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 26738, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 26746, descriptions: &[("en", "Serowe")] },
+    Area { prefix: 26747, descriptions: &[("en", "Mahalapye")] },
+    Area { prefix: 26749, descriptions: &[("en", "Palapye")] },
+    Area { prefix: 26754, descriptions: &[("en", "Barolong/Ngwaketse")] },
+    Area { prefix: 26757, descriptions: &[("en", "Mochudi")] },
+    Area { prefix: 26758, descriptions: &[("en", "Jwaneng")] },
+    Area { prefix: 26759, descriptions: &[("en", "Molepolole")] },
+    Area { prefix: 26762, descriptions: &[("en", "Kasane")] },
+    Area { prefix: 26768, descriptions: &[("en", "Maun")] },
+    Area { prefix: 26832, descriptions: &[("en", "Shiselweni")] },
+    Area { prefix: 26833, descriptions: &[("en", "Lubombo")] },
+    Area { prefix: 26834, descriptions: &[("en", "Hhohho")] },
+    Area { prefix: 26835, descriptions: &[("en", "Manzini")] },
+    Area { prefix: 29022, descriptions: &[("en", "Jamestown")] },
+    Area { prefix: 29023, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 29024, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 29027, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 29931, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29932, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29933, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29934, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29935, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29936, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29937, descriptions: &[("en", "Nuuk")] },
+    Area { prefix: 29961, descriptions: &[("en", "Nanortalik")] },
+    Area { prefix: 29964, descriptions: &[("en", "Qaqortoq")] },
+    Area { prefix: 29966, descriptions: &[("en", "Narsaq")] },
+    Area { prefix: 29968, descriptions: &[("en", "Paamiut")] },
+    Area { prefix: 29981, descriptions: &[("en", "Maniitsoq")] },
+    Area { prefix: 29984, descriptions: &[("en", "Kangerlussuaq")] },
+    Area { prefix: 29985, descriptions: &[("en", "Sisimiut")] },
+    Area { prefix: 29986, descriptions: &[("en", "Sisimiut")] },
+    Area { prefix: 29987, descriptions: &[("en", "Kangaatsiaq")] },
+    Area { prefix: 29989, descriptions: &[("en", "Aasiaat")] },
+    Area { prefix: 29991, descriptions: &[("en", "Qasigannguit")] },
+    Area { prefix: 29992, descriptions: &[("en", "Qeqertasuaq")] },
+    Area { prefix: 29994, descriptions: &[("en", "Ilulissat")] },
+    Area { prefix: 29995, descriptions: &[("en", "Uummannaq")] },
+    Area { prefix: 29996, descriptions: &[("en", "Upernavik")] },
+    Area { prefix: 29997, descriptions: &[("en", "Qaanaaq")] },
+    Area { prefix: 29998, descriptions: &[("en", "Tasiilaq")] },
+    Area { prefix: 29999, descriptions: &[("en", "Ittoqqortoormiit")] },
+    Area { prefix: 30231, descriptions: &[("en", "Thessaloniki"), ("el", "Θεσσαλονίκη")] },
+    Area { prefix: 30241, descriptions: &[("en", "Larissa"), ("el", "Λάρισα")] },
+    Area { prefix: 30251, descriptions: &[("en", "Kavala"), ("el", "Καβάλα")] },
+    Area { prefix: 30261, descriptions: &[("en", "Patras"), ("el", "Πάτρα")] },
+    Area { prefix: 30271, descriptions: &[("en", "Tripoli"), ("el", "Τρίπολη")] },
+    Area { prefix: 30281, descriptions: &[("en", "Heraklion"), ("el", "Ηράκλειο")] },
+    Area { prefix: 31111, descriptions: &[("en", "Zierikzee")] },
+    Area { prefix: 31113, descriptions: &[("en", "Goes")] },
+    Area { prefix: 31114, descriptions: &[("en", "Hulst")] },
+    Area { prefix: 31115, descriptions: &[("en", "Terneuzen")] },
+    Area { prefix: 31117, descriptions: &[("en", "Oostburg")] },
+    Area { prefix: 31118, descriptions: &[("en", "Middelburg")] },
+    Area { prefix: 31161, descriptions: &[("en", "Rijen")] },
+    Area { prefix: 31162, descriptions: &[("en", "Oosterhout")] },
+    Area { prefix: 31164, descriptions: &[("en", "Bergen op Zoom")] },
+    Area { prefix: 31165, descriptions: &[("en", "Roosendaal")] },
+    Area { prefix: 31166, descriptions: &[("en", "Tholen")] },
+    Area { prefix: 31167, descriptions: &[("en", "Steenbergen")] },
+    Area { prefix: 31168, descriptions: &[("en", "Zevenbergen")] },
+    Area { prefix: 31172, descriptions: &[("en", "Alphen aan den Rijn")] },
+    Area { prefix: 31174, descriptions: &[("en", "Naaldwijk")] },
+    Area { prefix: 31180, descriptions: &[("en", "Barendrecht")] },
+    Area { prefix: 31181, descriptions: &[("en", "Spijkenisse")] },
+    Area { prefix: 31182, descriptions: &[("en", "Gouda")] },
+    Area { prefix: 31183, descriptions: &[("en", "Gorinchem")] },
+    Area { prefix: 31184, descriptions: &[("en", "Sliedrecht")] },
+    Area { prefix: 31186, descriptions: &[("en", "Oud-Beijerland")] },
+    Area { prefix: 31187, descriptions: &[("en", "Middelharnis")] },
+    Area { prefix: 31222, descriptions: &[("en", "Den Burg")] },
+    Area { prefix: 31223, descriptions: &[("en", "Den Helder")] },
+    Area { prefix: 31224, descriptions: &[("en", "Schagen")] },
+    Area { prefix: 31226, descriptions: &[("en", "Noord Scharwoude")] },
+    Area { prefix: 31227, descriptions: &[("en", "Medemblik")] },
+    Area { prefix: 31228, descriptions: &[("en", "Enkhuizen")] },
+    Area { prefix: 31229, descriptions: &[("en", "Horn"), ("nl", "Hoorn")] },
+    Area { prefix: 31251, descriptions: &[("en", "Beverwijk")] },
+    Area { prefix: 31252, descriptions: &[("en", "Nieuw-Vennep")] },
+    Area { prefix: 31255, descriptions: &[("en", "IJmuiden")] },
+    Area { prefix: 31294, descriptions: &[("en", "Weesp")] },
+    Area { prefix: 31297, descriptions: &[("en", "Aalsmeer")] },
+    Area { prefix: 31299, descriptions: &[("en", "Purmerend")] },
+    Area { prefix: 31313, descriptions: &[("en", "Dieren")] },
+    Area { prefix: 31314, descriptions: &[("en", "Doetinchem")] },
+    Area { prefix: 31315, descriptions: &[("en", "Terborg")] },
+    Area { prefix: 31316, descriptions: &[("en", "Zevenaar")] },
+    Area { prefix: 31317, descriptions: &[("en", "Wageningen")] },
+    Area { prefix: 31318, descriptions: &[("en", "Veenendaal")] },
+    Area { prefix: 31320, descriptions: &[("en", "Lelystad")] },
+    Area { prefix: 31321, descriptions: &[("en", "Dronten")] },
+    Area { prefix: 31341, descriptions: &[("en", "Harderwijk")] },
+    Area { prefix: 31342, descriptions: &[("en", "Barneveld")] },
+    Area { prefix: 31343, descriptions: &[("en", "Driebergen-Rijsenburg")] },
+    Area { prefix: 31344, descriptions: &[("en", "Tiel")] },
+    Area { prefix: 31345, descriptions: &[("en", "Culemborg")] },
+    Area { prefix: 31346, descriptions: &[("en", "Maarssen")] },
+    Area { prefix: 31347, descriptions: &[("en", "Vianen")] },
+    Area { prefix: 31348, descriptions: &[("en", "Woerden")] },
+    Area { prefix: 31411, descriptions: &[("en", "Boxtel")] },
+    Area { prefix: 31412, descriptions: &[("en", "Oss")] },
+    Area { prefix: 31413, descriptions: &[("en", "Uden")] },
+    Area { prefix: 31416, descriptions: &[("en", "Waalwijk")] },
+    Area { prefix: 31418, descriptions: &[("en", "Zaltbommel")] },
+    Area { prefix: 31475, descriptions: &[("en", "Roermond")] },
+    Area { prefix: 31478, descriptions: &[("en", "Venray")] },
+    Area { prefix: 31481, descriptions: &[("en", "Bemmel"), ("nl", "Elst")] },
+    Area { prefix: 31485, descriptions: &[("en", "Cuyk")] },
+    Area { prefix: 31486, descriptions: &[("en", "Grave"), ("nl", "Schaijk")] },
+    Area { prefix: 31487, descriptions: &[("en", "Druten")] },
+    Area { prefix: 31488, descriptions: &[("en", "Zetten")] },
+    Area { prefix: 31492, descriptions: &[("en", "Helmond")] },
+    Area { prefix: 31493, descriptions: &[("en", "Deurne")] },
+    Area { prefix: 31495, descriptions: &[("en", "Weert")] },
+    Area { prefix: 31497, descriptions: &[("en", "Eersel")] },
+    Area { prefix: 31499, descriptions: &[("en", "Best")] },
+    Area { prefix: 31511, descriptions: &[("en", "Veenwouden")] },
+    Area { prefix: 31512, descriptions: &[("en", "Drachten")] },
+    Area { prefix: 31513, descriptions: &[("en", "Heerenveen")] },
+    Area { prefix: 31514, descriptions: &[("en", "Lemmer")] },
+    Area { prefix: 31515, descriptions: &[("en", "Sneek")] },
+    Area { prefix: 31516, descriptions: &[("en", "Oosterwolde")] },
+    Area { prefix: 31517, descriptions: &[("en", "Harlingen")] },
+    Area { prefix: 31518, descriptions: &[("en", "St. Annaparochie")] },
+    Area { prefix: 31519, descriptions: &[("en", "Dokkum")] },
+    Area { prefix: 31521, descriptions: &[("en", "Steenwijk")] },
+    Area { prefix: 31522, descriptions: &[("en", "Meppel")] },
+    Area { prefix: 31523, descriptions: &[("en", "Hardenberg")] },
+    Area { prefix: 31524, descriptions: &[("en", "Coevorden")] },
+    Area { prefix: 31525, descriptions: &[("en", "Elburg")] },
+    Area { prefix: 31527, descriptions: &[("en", "Emmeloord")] },
+    Area { prefix: 31528, descriptions: &[("en", "Hoogeveen")] },
+    Area { prefix: 31529, descriptions: &[("en", "Dalfsen")] },
+    Area { prefix: 31541, descriptions: &[("en", "Oldenzaal")] },
+    Area { prefix: 31543, descriptions: &[("en", "Winterswijk")] },
+    Area { prefix: 31544, descriptions: &[("en", "Lichtenvoorde")] },
+    Area { prefix: 31545, descriptions: &[("en", "Eibergen")] },
+    Area { prefix: 31546, descriptions: &[("en", "Almelo")] },
+    Area { prefix: 31547, descriptions: &[("en", "Goor")] },
+    Area { prefix: 31548, descriptions: &[("en", "Rijssen")] },
+    Area { prefix: 31561, descriptions: &[("en", "Wolvega")] },
+    Area { prefix: 31562, descriptions: &[("en", "West-Terschelling")] },
+    Area { prefix: 31566, descriptions: &[("en", "Grou")] },
+    Area { prefix: 31570, descriptions: &[("en", "Deventer")] },
+    Area { prefix: 31571, descriptions: &[("en", "Twello")] },
+    Area { prefix: 31572, descriptions: &[("en", "Raalte")] },
+    Area { prefix: 31573, descriptions: &[("en", "Lochem")] },
+    Area { prefix: 31575, descriptions: &[("en", "Zutphen")] },
+    Area { prefix: 31577, descriptions: &[("en", "Elspeet")] },
+    Area { prefix: 31578, descriptions: &[("en", "Epe")] },
+    Area { prefix: 31591, descriptions: &[("en", "Emmen")] },
+    Area { prefix: 31592, descriptions: &[("en", "Assen")] },
+    Area { prefix: 31593, descriptions: &[("en", "Beilen")] },
+    Area { prefix: 31594, descriptions: &[("en", "Zuidhorn")] },
+    Area { prefix: 31595, descriptions: &[("en", "Warffum")] },
+    Area { prefix: 31596, descriptions: &[("en", "Delfzijl")] },
+    Area { prefix: 31597, descriptions: &[("en", "Winschoten")] },
+    Area { prefix: 31598, descriptions: &[("en", "Veendam")] },
+    Area { prefix: 31599, descriptions: &[("en", "Stadskanaal")] },
+    Area { prefix: 34820, descriptions: &[("en", "Ávila")] },
+    Area { prefix: 34821, descriptions: &[("en", "Segovia")] },
+    Area { prefix: 34822, descriptions: &[("en", "Tenerife")] },
+    Area { prefix: 34823, descriptions: &[("en", "Salamanca")] },
+    Area { prefix: 34824, descriptions: &[("en", "Badajoz")] },
+    Area { prefix: 34825, descriptions: &[("en", "Toledo")] },
+    Area { prefix: 34826, descriptions: &[("en", "Ciudad Real")] },
+    Area { prefix: 34827, descriptions: &[("en", "Cáceres")] },
+    Area { prefix: 34828, descriptions: &[("en", "Las Palmas")] },
+    Area { prefix: 34841, descriptions: &[("en", "La Rioja")] },
+    Area { prefix: 34842, descriptions: &[("en", "Cantabria")] },
+    Area { prefix: 34843, descriptions: &[("en", "Guipúzcoa")] },
+    Area { prefix: 34844, descriptions: &[("en", "Bizkaia"), ("es", "Vizcaya")] },
+    Area { prefix: 34845, descriptions: &[("en", "Araba"), ("es", "Álava")] },
+    Area { prefix: 34846, descriptions: &[("en", "Bizkaia"), ("es", "Vizcaya")] },
+    Area { prefix: 34847, descriptions: &[("en", "Burgos")] },
+    Area { prefix: 34848, descriptions: &[("en", "Navarre"), ("es", "Navarra")] },
+    Area { prefix: 34849, descriptions: &[("en", "Guadalajara")] },
+    Area { prefix: 34850, descriptions: &[("en", "Almería"), ("es", "Álmería")] },
+    Area { prefix: 34851, descriptions: &[("en", "Málaga")] },
+    Area { prefix: 34852, descriptions: &[("en", "Málaga")] },
+    Area { prefix: 34853, descriptions: &[("en", "Jaén")] },
+    Area { prefix: 34854, descriptions: &[("en", "Seville"), ("es", "Sevilla")] },
+    Area { prefix: 34855, descriptions: &[("en", "Seville")] },
+    Area { prefix: 34856, descriptions: &[("en", "Cádiz")] },
+    Area { prefix: 34857, descriptions: &[("en", "Cordova"), ("es", "Córdoba")] },
+    Area { prefix: 34858, descriptions: &[("en", "Granada")] },
+    Area { prefix: 34859, descriptions: &[("en", "Huelva")] },
+    Area { prefix: 34860, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34861, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34862, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34863, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34864, descriptions: &[("en", "Castellón")] },
+    Area { prefix: 34865, descriptions: &[("en", "Alicante")] },
+    Area { prefix: 34866, descriptions: &[("en", "Alicante")] },
+    Area { prefix: 34867, descriptions: &[("en", "Albacete")] },
+    Area { prefix: 34868, descriptions: &[("en", "Murcia")] },
+    Area { prefix: 34869, descriptions: &[("en", "Cuenca")] },
+    Area { prefix: 34871, descriptions: &[("en", "Balearic Islands"), ("es", "Baleares")] },
+    Area { prefix: 34872, descriptions: &[("en", "Girona"), ("es", "Gerona")] },
+    Area { prefix: 34873, descriptions: &[("en", "Lleida"), ("es", "Lérida")] },
+    Area { prefix: 34874, descriptions: &[("en", "Huesca")] },
+    Area { prefix: 34875, descriptions: &[("en", "Soria")] },
+    Area { prefix: 34876, descriptions: &[("en", "Zaragoza")] },
+    Area { prefix: 34877, descriptions: &[("en", "Tarragona")] },
+    Area { prefix: 34878, descriptions: &[("en", "Teruel")] },
+    Area { prefix: 34879, descriptions: &[("en", "Palencia")] },
+    Area { prefix: 34880, descriptions: &[("en", "Zamora")] },
+    Area { prefix: 34881, descriptions: &[("en", "A Coruña")] },
+    Area { prefix: 34882, descriptions: &[("en", "Lugo")] },
+    Area { prefix: 34883, descriptions: &[("en", "Valladolid")] },
+    Area { prefix: 34884, descriptions: &[("en", "Asturias")] },
+    Area { prefix: 34885, descriptions: &[("en", "Asturias")] },
+    Area { prefix: 34886, descriptions: &[("en", "Pontevedra")] },
+    Area { prefix: 34887, descriptions: &[("en", "León")] },
+    Area { prefix: 34888, descriptions: &[("en", "Ourense"), ("es", "Orense")] },
+    Area { prefix: 34920, descriptions: &[("en", "Ávila")] },
+    Area { prefix: 34921, descriptions: &[("en", "Segovia")] },
+    Area { prefix: 34922, descriptions: &[("en", "Tenerife")] },
+    Area { prefix: 34923, descriptions: &[("en", "Salamanca")] },
+    Area { prefix: 34924, descriptions: &[("en", "Badajoz")] },
+    Area { prefix: 34925, descriptions: &[("en", "Toledo")] },
+    Area { prefix: 34926, descriptions: &[("en", "Ciudad Real")] },
+    Area { prefix: 34927, descriptions: &[("en", "Cáceres")] },
+    Area { prefix: 34928, descriptions: &[("en", "Las Palmas")] },
+    Area { prefix: 34941, descriptions: &[("en", "La Rioja")] },
+    Area { prefix: 34942, descriptions: &[("en", "Cantabria")] },
+    Area { prefix: 34943, descriptions: &[("en", "Guipúzcoa")] },
+    Area { prefix: 34944, descriptions: &[("en", "Bizkaia"), ("es", "Vizcaya")] },
+    Area { prefix: 34945, descriptions: &[("en", "Araba"), ("es", "Álava")] },
+    Area { prefix: 34946, descriptions: &[("en", "Bizkaia"), ("es", "Vizcaya")] },
+    Area { prefix: 34947, descriptions: &[("en", "Burgos")] },
+    Area { prefix: 34948, descriptions: &[("en", "Navarre"), ("es", "Navarra")] },
+    Area { prefix: 34949, descriptions: &[("en", "Guadalajara")] },
+    Area { prefix: 34950, descriptions: &[("en", "Almería")] },
+    Area { prefix: 34951, descriptions: &[("en", "Málaga")] },
+    Area { prefix: 34952, descriptions: &[("en", "Málaga")] },
+    Area { prefix: 34953, descriptions: &[("en", "Jaén")] },
+    Area { prefix: 34954, descriptions: &[("en", "Seville"), ("es", "Sevilla")] },
+    Area { prefix: 34955, descriptions: &[("en", "Seville"), ("es", "Sevilla")] },
+    Area { prefix: 34956, descriptions: &[("en", "Cádiz")] },
+    Area { prefix: 34957, descriptions: &[("en", "Cordova"), ("es", "Córdoba")] },
+    Area { prefix: 34958, descriptions: &[("en", "Granada")] },
+    Area { prefix: 34959, descriptions: &[("en", "Huelva")] },
+    Area { prefix: 34960, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34961, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34962, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34963, descriptions: &[("en", "Valencia")] },
+    Area { prefix: 34964, descriptions: &[("en", "Castellón")] },
+    Area { prefix: 34965, descriptions: &[("en", "Alicante")] },
+    Area { prefix: 34966, descriptions: &[("en", "Alicante")] },
+    Area { prefix: 34967, descriptions: &[("en", "Albacete")] },
+    Area { prefix: 34968, descriptions: &[("en", "Murcia")] },
+    Area { prefix: 34971, descriptions: &[("en", "Balearic Islands"), ("es", "Baleares")] },
+    Area { prefix: 34972, descriptions: &[("en", "Girona"), ("es", "Gerona")] },
+    Area { prefix: 34974, descriptions: &[("en", "Huesca")] },
+    Area { prefix: 34975, descriptions: &[("en", "Soria")] },
+    Area { prefix: 34976, descriptions: &[("en", "Zaragoza")] },
+    Area { prefix: 34977, descriptions: &[("en", "Tarragona")] },
+    Area { prefix: 34978, descriptions: &[("en", "Teruel")] },
+    Area { prefix: 34979, descriptions: &[("en", "Palencia")] },
+    Area { prefix: 34980, descriptions: &[("en", "Zamora")] },
+    Area { prefix: 34981, descriptions: &[("en", "A Coruña")] },
+    Area { prefix: 34982, descriptions: &[("en", "Lugo")] },
+    Area { prefix: 34983, descriptions: &[("en", "Valladolid")] },
+    Area { prefix: 34984, descriptions: &[("en", "Asturias")] },
+    Area { prefix: 34985, descriptions: &[("en", "Asturias")] },
+    Area { prefix: 34986, descriptions: &[("en", "Pontevedra")] },
+    Area { prefix: 34987, descriptions: &[("en", "León")] },
+    Area { prefix: 34988, descriptions: &[("en", "Ourense"), ("es", "Orense")] },
+    Area { prefix: 35121, descriptions: &[("en", "Lisbon"), ("pt", "Lisboa")] },
+    Area { prefix: 35122, descriptions: &[("en", "Porto")] },
+    Area { prefix: 35222, descriptions: &[("en", "Luxembourg City"), ("de", "Luxemburg"), ("fr", "Luxembourg-Ville")] },
+    Area { prefix: 35223, descriptions: &[("en", "Mondorf-les-Bains/Bascharage/Noerdange/Remich"), ("de", "Bad Mondorf")] },
+    Area { prefix: 35225, descriptions: &[("en", "Luxembourg"), ("de", "Luxemburg")] },
+    Area { prefix: 35228, descriptions: &[("en", "Luxembourg City"), ("de", "Luxemburg"), ("fr", "Luxembourg-Ville")] },
+    Area { prefix: 35229, descriptions: &[("en", "Luxembourg/Kockelscheuer"), ("de", "Luxemburg")] },
+    Area { prefix: 35230, descriptions: &[("en", "Capellen/Kehlen"), ("de", "Kanton Capellen/Kehlen")] },
+    Area { prefix: 35231, descriptions: &[("en", "Bertrange/Mamer/Munsbach/Strassen"), ("de", "Bartringen")] },
+    Area { prefix: 35232, descriptions: &[("en", "Mersch"), ("de", "Kanton Mersch")] },
+    Area { prefix: 35233, descriptions: &[("en", "Walferdange"), ("de", "Walferdingen")] },
+    Area { prefix: 35234, descriptions: &[("en", "Rameldange/Senningerberg"), ("de", "Rammeldingen/Senningerberg")] },
+    Area { prefix: 35235, descriptions: &[("en", "Sandweiler/Moutfort/Roodt-sur-Syre"), ("de", "Sandweiler/Mutfort/Roodt-sur-Syre")] },
+    Area { prefix: 35236, descriptions: &[("en", "Hesperange/Kockelscheuer/Roeser"), ("de", "Hesperingen/Kockelscheuer/Roeser")] },
+    Area { prefix: 35237, descriptions: &[("en", "Leudelange/Ehlange/Mondercange"), ("de", "Leudelingen/Ehlingen/Monnerich")] },
+    Area { prefix: 35239, descriptions: &[("en", "Windhof/Steinfort")] },
+    Area { prefix: 35240, descriptions: &[("en", "Howald")] },
+    Area { prefix: 35241, descriptions: &[("en", "Luxembourg City"), ("de", "Luxemburg"), ("fr", "Luxembourg-Ville")] },
+    Area { prefix: 35242, descriptions: &[("en", "Plateau de Kirchberg")] },
+    Area { prefix: 35243, descriptions: &[("en", "Findel/Kirchberg")] },
+    Area { prefix: 35244, descriptions: &[("en", "Luxembourg City"), ("de", "Luxemburg"), ("fr", "Luxembourg-Ville")] },
+    Area { prefix: 35245, descriptions: &[("en", "Diedrich")] },
+    Area { prefix: 35246, descriptions: &[("en", "Luxembourg City"), ("de", "Luxemburg"), ("fr", "Luxembourg-Ville")] },
+    Area { prefix: 35247, descriptions: &[("en", "Lintgen")] },
+    Area { prefix: 35248, descriptions: &[("en", "Contern/Foetz")] },
+    Area { prefix: 35249, descriptions: &[("en", "Howald")] },
+    Area { prefix: 35250, descriptions: &[("en", "Bascharage/Petange/Rodange"), ("de", "Bascharage/Petingen/Rodingen")] },
+    Area { prefix: 35251, descriptions: &[("en", "Dudelange/Bettembourg/Livange"), ("de", "Düdelingen/Bettemburg/Livingen")] },
+    Area { prefix: 35252, descriptions: &[("en", "Dudelange"), ("de", "Düdelingen")] },
+    Area { prefix: 35253, descriptions: &[("en", "Esch-sur-Alzette")] },
+    Area { prefix: 35254, descriptions: &[("en", "Esch-sur-Alzette")] },
+    Area { prefix: 35255, descriptions: &[("en", "Esch-sur-Alzette/Mondercange"), ("de", "Esch-sur-Alzette/Monnerich")] },
+    Area { prefix: 35256, descriptions: &[("en", "Rumelange"), ("de", "Rümelingen")] },
+    Area { prefix: 35257, descriptions: &[("en", "Esch-sur-Alzette/Schifflange"), ("de", "Esch-sur-Alzette/Schifflingen")] },
+    Area { prefix: 35258, descriptions: &[("en", "Differdange"), ("de", "Differdingen")] },
+    Area { prefix: 35259, descriptions: &[("en", "Soleuvre")] },
+    Area { prefix: 35271, descriptions: &[("en", "Betzdorf")] },
+    Area { prefix: 35272, descriptions: &[("en", "Echternach")] },
+    Area { prefix: 35273, descriptions: &[("en", "Rosport")] },
+    Area { prefix: 35274, descriptions: &[("en", "Wasserbillig")] },
+    Area { prefix: 35275, descriptions: &[("en", "Grevenmacher"), ("de", "Distrikt Grevenmacher")] },
+    Area { prefix: 35276, descriptions: &[("en", "Wormeldange"), ("de", "Wormeldingen")] },
+    Area { prefix: 35278, descriptions: &[("en", "Junglinster")] },
+    Area { prefix: 35279, descriptions: &[("en", "Berdorf/Consdorf")] },
+    Area { prefix: 35280, descriptions: &[("en", "Diekirch")] },
+    Area { prefix: 35281, descriptions: &[("en", "Ettelbruck"), ("de", "Ettelbrück")] },
+    Area { prefix: 35283, descriptions: &[("en", "Vianden")] },
+    Area { prefix: 35284, descriptions: &[("en", "Han/Lesse")] },
+    Area { prefix: 35285, descriptions: &[("en", "Bissen/Roost")] },
+    Area { prefix: 35287, descriptions: &[("en", "Larochette"), ("de", "Fels")] },
+    Area { prefix: 35288, descriptions: &[("en", "Mertzig/Wahl")] },
+    Area { prefix: 35292, descriptions: &[("en", "Clervaux/Fischbach/Hosingen"), ("de", "Kanton Clerf/Fischbach/Hosingen")] },
+    Area { prefix: 35295, descriptions: &[("en", "Wiltz")] },
+    Area { prefix: 35297, descriptions: &[("en", "Huldange"), ("de", "Huldingen")] },
+    Area { prefix: 35299, descriptions: &[("en", "Troisvierges"), ("de", "Ulflingen")] },
+    Area { prefix: 35321, descriptions: &[("en", "Cork")] },
+    Area { prefix: 35322, descriptions: &[("en", "Mallow")] },
+    Area { prefix: 35323, descriptions: &[("en", "Bandon")] },
+    Area { prefix: 35324, descriptions: &[("en", "Youghal")] },
+    Area { prefix: 35325, descriptions: &[("en", "Fermoy")] },
+    Area { prefix: 35326, descriptions: &[("en", "Macroom")] },
+    Area { prefix: 35327, descriptions: &[("en", "Bantry")] },
+    Area { prefix: 35328, descriptions: &[("en", "Skibbereen")] },
+    Area { prefix: 35329, descriptions: &[("en", "Kanturk")] },
+    Area { prefix: 35341, descriptions: &[("en", "Drogheda")] },
+    Area { prefix: 35343, descriptions: &[("en", "Longford/Granard")] },
+    Area { prefix: 35344, descriptions: &[("en", "Mullingar")] },
+    Area { prefix: 35351, descriptions: &[("en", "Waterford")] },
+    Area { prefix: 35352, descriptions: &[("en", "Clonmel/Cahir/Killenaule")] },
+    Area { prefix: 35353, descriptions: &[("en", "Wexford/Enniscorthy/Ferns/Gorey")] },
+    Area { prefix: 35356, descriptions: &[("en", "Kilkenny/Castlecomer/Freshford")] },
+    Area { prefix: 35357, descriptions: &[("en", "Portlaoise/Abbeyleix/Tullamore/Birr")] },
+    Area { prefix: 35358, descriptions: &[("en", "Dungarvan")] },
+    Area { prefix: 35359, descriptions: &[("en", "Carlow/Muine Bheag/Athy/Baltinglass")] },
+    Area { prefix: 35361, descriptions: &[("en", "Limerick")] },
+    Area { prefix: 35363, descriptions: &[("en", "Rathluirc")] },
+    Area { prefix: 35364, descriptions: &[("en", "Killarney/Rathmore")] },
+    Area { prefix: 35366, descriptions: &[("en", "Tralee")] },
+    Area { prefix: 35367, descriptions: &[("en", "Nenagh")] },
+    Area { prefix: 35368, descriptions: &[("en", "Listowel")] },
+    Area { prefix: 35369, descriptions: &[("en", "Newcastle West")] },
+    Area { prefix: 35371, descriptions: &[("en", "Sligo/Manorhamilton/Carrick-on-Shannon")] },
+    Area { prefix: 35374, descriptions: &[("en", "Letterkenny/Donegal/Dungloe/Buncrana")] },
+    Area { prefix: 35390, descriptions: &[("en", "Athlone/Ballinasloe/Portumna/Roscommon")] },
+    Area { prefix: 35391, descriptions: &[("en", "Galway")] },
+    Area { prefix: 35393, descriptions: &[("en", "Tuam")] },
+    Area { prefix: 35394, descriptions: &[("en", "Castlebar/Claremorris/Castlerea/Ballinrobe")] },
+    Area { prefix: 35395, descriptions: &[("en", "Clifden")] },
+    Area { prefix: 35396, descriptions: &[("en", "Ballina")] },
+    Area { prefix: 35397, descriptions: &[("en", "Belmullet")] },
+    Area { prefix: 35398, descriptions: &[("en", "Westport")] },
+    Area { prefix: 35399, descriptions: &[("en", "Kilronan")] },
+    Area { prefix: 35442, descriptions: &[("en", "Keflavík")] },
+    Area { prefix: 35446, descriptions: &[("en", "Akureyri")] },
+    Area { prefix: 35455, descriptions: &[("en", "Reykjavík/Vesturbær/Miðbærinn")] },
+    Area { prefix: 35456, descriptions: &[("en", "Reykjavík/Vesturbær/Miðbærinn")] },
+    Area { prefix: 35522, descriptions: &[("en", "Shkodër")] },
+    Area { prefix: 35524, descriptions: &[("en", "Kukës")] },
+    Area { prefix: 35532, descriptions: &[("en", "Berat")] },
+    Area { prefix: 35533, descriptions: &[("en", "Vlorë")] },
+    Area { prefix: 35534, descriptions: &[("en", "Fier")] },
+    Area { prefix: 35535, descriptions: &[("en", "Lushnje")] },
+    Area { prefix: 35542, descriptions: &[("en", "Tirana")] },
+    Area { prefix: 35543, descriptions: &[("en", "Tirana")] },
+    Area { prefix: 35544, descriptions: &[("en", "Tirana")] },
+    Area { prefix: 35545, descriptions: &[("en", "Tirana")] },
+    Area { prefix: 35546, descriptions: &[("en", "Tirana")] },
+    Area { prefix: 35547, descriptions: &[("en", "Kamëz/Vorë/Paskuqan/Zall-Herr/Burxullë/Prezë, Tiranë")] },
+    Area { prefix: 35548, descriptions: &[("en", "Kashar/Vaqar/Ndroq/Pezë/Farkë/Dajt, Tiranë")] },
+    Area { prefix: 35549, descriptions: &[("en", "Petrelë/Baldushk/Bërzhitë/Krrabë/Shengjergj/Zall-Bastar, Tiranë")] },
+    Area { prefix: 35552, descriptions: &[("en", "Durrës")] },
+    Area { prefix: 35553, descriptions: &[("en", "Laç, Kurbin")] },
+    Area { prefix: 35554, descriptions: &[("en", "Elbasan")] },
+    Area { prefix: 35555, descriptions: &[("en", "Kavajë")] },
+    Area { prefix: 35582, descriptions: &[("en", "Korçë")] },
+    Area { prefix: 35583, descriptions: &[("en", "Pogradec")] },
+    Area { prefix: 35584, descriptions: &[("en", "Gjirokastër")] },
+    Area { prefix: 35585, descriptions: &[("en", "Sarandë")] },
+    Area { prefix: 35813, descriptions: &[("en", "North Karelia"), ("fi", "Pohjois-Karjala"), ("sv", "Norra Karelen")] },
+    Area { prefix: 35814, descriptions: &[("en", "Central Finland"), ("fi", "Keski-Suomi"), ("sv", "Mellersta Finland")] },
+    Area { prefix: 35815, descriptions: &[("en", "Mikkeli"), ("sv", "St Michel")] },
+    Area { prefix: 35816, descriptions: &[("en", "Lapland"), ("fi", "Lappi"), ("sv", "Lappland")] },
+    Area { prefix: 35817, descriptions: &[("en", "Kuopio")] },
+    Area { prefix: 35819, descriptions: &[("en", "Uusimaa"), ("sv", "Nyland")] },
+    Area { prefix: 35821, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35822, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35823, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35824, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35825, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35826, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35827, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35828, descriptions: &[("en", "Turku/Pori"), ("sv", "Åbo/Björneborg")] },
+    Area { prefix: 35831, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35832, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35833, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35834, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35835, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35836, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35837, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35838, descriptions: &[("en", "Häme"), ("sv", "Tavastland")] },
+    Area { prefix: 35851, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35852, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35853, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35854, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35855, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35856, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35857, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35858, descriptions: &[("en", "Kymi"), ("sv", "Kymmene")] },
+    Area { prefix: 35861, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35862, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35863, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35864, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35865, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35866, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35867, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35868, descriptions: &[("en", "Vaasa"), ("sv", "Vasa")] },
+    Area { prefix: 35881, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35882, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35883, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35884, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35885, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35886, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35887, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35888, descriptions: &[("en", "Oulu"), ("sv", "Uleåborg")] },
+    Area { prefix: 35890, descriptions: &[("en", "Uusimaa"), ("sv", "Nyland")] },
+    Area { prefix: 35932, descriptions: &[("en", "Plovdiv"), ("bg", "Пловдив")] },
+    Area { prefix: 35934, descriptions: &[("en", "Pazardzhik"), ("bg", "Пазарджик")] },
+    Area { prefix: 35938, descriptions: &[("en", "Haskovo"), ("bg", "Хасково")] },
+    Area { prefix: 35942, descriptions: &[("en", "Stara Zagora"), ("bg", "Стара Загора")] },
+    Area { prefix: 35944, descriptions: &[("en", "Sliven"), ("bg", "Сливен")] },
+    Area { prefix: 35946, descriptions: &[("en", "Yambol"), ("bg", "Ямбол")] },
+    Area { prefix: 35952, descriptions: &[("en", "Varna"), ("bg", "Варна")] },
+    Area { prefix: 35954, descriptions: &[("en", "Shumen"), ("bg", "Шумен")] },
+    Area { prefix: 35956, descriptions: &[("en", "Burgas"), ("bg", "Бургас")] },
+    Area { prefix: 35958, descriptions: &[("en", "Dobrich"), ("bg", "Добрич")] },
+    Area { prefix: 35962, descriptions: &[("en", "Veliko Tarnovo"), ("bg", "Велико Търново")] },
+    Area { prefix: 35964, descriptions: &[("en", "Pleven"), ("bg", "Плевен")] },
+    Area { prefix: 35966, descriptions: &[("en", "Gabrovo"), ("bg", "Габрово")] },
+    Area { prefix: 35968, descriptions: &[("en", "Lovech"), ("bg", "Ловеч")] },
+    Area { prefix: 35973, descriptions: &[("en", "Blagoevgrad"), ("bg", "Благоевград")] },
+    Area { prefix: 35976, descriptions: &[("en", "Pernik"), ("bg", "Перник")] },
+    Area { prefix: 35978, descriptions: &[("en", "Kyustendil"), ("bg", "Кюстендил")] },
+    Area { prefix: 35982, descriptions: &[("en", "Ruse"), ("bg", "Русе")] },
+    Area { prefix: 35984, descriptions: &[("en", "Razgrad"), ("bg", "Разград")] },
+    Area { prefix: 35992, descriptions: &[("en", "Vratsa"), ("bg", "Враца")] },
+    Area { prefix: 35994, descriptions: &[("en", "Vidin"), ("bg", "Видин")] },
+    Area { prefix: 35996, descriptions: &[("en", "Montana"), ("bg", "Монтана")] },
+    Area { prefix: 37037, descriptions: &[("en", "Kaunas")] },
+    Area { prefix: 37041, descriptions: &[("en", "Šiauliai")] },
+    Area { prefix: 37045, descriptions: &[("en", "Panevėžys")] },
+    Area { prefix: 37046, descriptions: &[("en", "Klaipėda")] },
+    Area { prefix: 37322, descriptions: &[("en", "Chisinau"), ("ro", "Chişinău"), ("ru", "Кишинэу")] },
+    Area { prefix: 37353, descriptions: &[("en", "Tiraspol"), ("ru", "Тираспол")] },
+    Area { prefix: 37410, descriptions: &[("en", "Yerevan/Jrvezh"), ("hy", "Երևան/Ջրվեժ"), ("ru", "Ереван/Джрвеж")] },
+    Area { prefix: 37411, descriptions: &[("en", "Yerevan"), ("hy", "Երևան"), ("ru", "Ереван")] },
+    Area { prefix: 37412, descriptions: &[("en", "Yerevan"), ("hy", "Երևան"), ("ru", "Ереван")] },
+    Area { prefix: 37415, descriptions: &[("en", "Yerevan"), ("hy", "Երևան"), ("ru", "Ереван")] },
+    Area { prefix: 37517, descriptions: &[("en", "Minsk"), ("be", "Мінск"), ("ru", "Минск")] },
+    Area { prefix: 38031, descriptions: &[("en", "Zakarpattia"), ("uk", "Закарпатська")] },
+    Area { prefix: 38033, descriptions: &[("en", "Volyn"), ("uk", "Волинська")] },
+    Area { prefix: 38034, descriptions: &[("en", "Ivano-Frankivsk"), ("uk", "Івано-Франківська")] },
+    Area { prefix: 38035, descriptions: &[("en", "Ternopil"), ("uk", "Тернопіль")] },
+    Area { prefix: 38036, descriptions: &[("en", "Rivne"), ("uk", "Рівне")] },
+    Area { prefix: 38037, descriptions: &[("en", "Chernivtsi"), ("uk", "Чернівці")] },
+    Area { prefix: 38038, descriptions: &[("en", "Khmelnytskyi"), ("uk", "Хмельницький")] },
+    Area { prefix: 38041, descriptions: &[("en", "Zhytomyr"), ("uk", "Житомир")] },
+    Area { prefix: 38043, descriptions: &[("en", "Vinnytsia"), ("uk", "Вінниця")] },
+    Area { prefix: 38044, descriptions: &[("en", "Kyiv city"), ("uk", "м. Київ")] },
+    Area { prefix: 38045, descriptions: &[("en", "Kyiv"), ("uk", "Київ")] },
+    Area { prefix: 38046, descriptions: &[("en", "Chernihiv"), ("uk", "Чернігів")] },
+    Area { prefix: 38047, descriptions: &[("en", "Cherkasy"), ("uk", "Черкаси")] },
+    Area { prefix: 38048, descriptions: &[("en", "Odesa"), ("uk", "Одеса")] },
+    Area { prefix: 38051, descriptions: &[("en", "Mykolayiv"), ("uk", "Миколаїв")] },
+    Area { prefix: 38052, descriptions: &[("en", "Kirovohrad"), ("uk", "Кіровоград")] },
+    Area { prefix: 38053, descriptions: &[("en", "Poltava"), ("uk", "Полтава")] },
+    Area { prefix: 38054, descriptions: &[("en", "Sumy"), ("uk", "Суми")] },
+    Area { prefix: 38055, descriptions: &[("en", "Kherson"), ("uk", "Херсон")] },
+    Area { prefix: 38057, descriptions: &[("en", "Kharkiv"), ("uk", "Харків")] },
+    Area { prefix: 38061, descriptions: &[("en", "Zaporizhzhia"), ("uk", "Запоріжжя/Запорізька")] },
+    Area { prefix: 38065, descriptions: &[("en", "Crimea"), ("uk", "Крим")] },
+    Area { prefix: 38069, descriptions: &[("en", "Sevastopol city"), ("uk", "Севастополь")] },
+    Area { prefix: 38110, descriptions: &[("en", "Pirot"), ("sr", "Пирот")] },
+    Area { prefix: 38111, descriptions: &[("en", "Belgrade"), ("sr", "Београд")] },
+    Area { prefix: 38112, descriptions: &[("en", "Pozarevac"), ("sr", "Пожаревац")] },
+    Area { prefix: 38113, descriptions: &[("en", "Pancevo"), ("sr", "Панчево")] },
+    Area { prefix: 38114, descriptions: &[("en", "Valjevo"), ("sr", "Ваљево")] },
+    Area { prefix: 38115, descriptions: &[("en", "Sabac"), ("sr", "Шабац")] },
+    Area { prefix: 38116, descriptions: &[("en", "Leskovac"), ("sr", "Лесковац")] },
+    Area { prefix: 38117, descriptions: &[("en", "Vranje"), ("sr", "Врање")] },
+    Area { prefix: 38118, descriptions: &[("en", "Nis"), ("sr", "Ниш")] },
+    Area { prefix: 38119, descriptions: &[("en", "Zajecar"), ("sr", "Зајечар")] },
+    Area { prefix: 38120, descriptions: &[("en", "Novi Pazar"), ("sr", "Нови Пазар")] },
+    Area { prefix: 38121, descriptions: &[("en", "Novi Sad"), ("sr", "Нови Сад")] },
+    Area { prefix: 38122, descriptions: &[("en", "Sremska Mitrovica"), ("sr", "Сремска Митровица")] },
+    Area { prefix: 38123, descriptions: &[("en", "Zrenjanin"), ("sr", "Зрењанин")] },
+    Area { prefix: 38124, descriptions: &[("en", "Subotica"), ("sr", "Суботица")] },
+    Area { prefix: 38125, descriptions: &[("en", "Sombor"), ("sr", "Сомбор")] },
+    Area { prefix: 38126, descriptions: &[("en", "Smederevo"), ("sr", "Смедерево")] },
+    Area { prefix: 38127, descriptions: &[("en", "Prokuplje"), ("sr", "Прокупље")] },
+    Area { prefix: 38128, descriptions: &[("en", "Kosovska Mitrovica"), ("sr", "Косовска Митровица")] },
+    Area { prefix: 38129, descriptions: &[("en", "Prizren"), ("sr", "Призрен")] },
+    Area { prefix: 38130, descriptions: &[("en", "Bor"), ("sr", "Бор")] },
+    Area { prefix: 38131, descriptions: &[("en", "Uzice"), ("sr", "Ужице")] },
+    Area { prefix: 38132, descriptions: &[("en", "Cacak"), ("sr", "Чачак")] },
+    Area { prefix: 38133, descriptions: &[("en", "Prijepolje"), ("sr", "Пријепоље")] },
+    Area { prefix: 38134, descriptions: &[("en", "Kragujevac"), ("sr", "Крагујевац")] },
+    Area { prefix: 38135, descriptions: &[("en", "Jagodina"), ("sr", "Јагодина")] },
+    Area { prefix: 38136, descriptions: &[("en", "Kraljevo"), ("sr", "Краљево")] },
+    Area { prefix: 38137, descriptions: &[("en", "Krusevac"), ("sr", "Крушевац")] },
+    Area { prefix: 38138, descriptions: &[("en", "Pristina"), ("sr", "Приштина")] },
+    Area { prefix: 38139, descriptions: &[("en", "Pec"), ("sr", "Пећ")] },
+    Area { prefix: 38230, descriptions: &[("en", "Bar/Ulcinj")] },
+    Area { prefix: 38231, descriptions: &[("en", "Herceg Novi")] },
+    Area { prefix: 38232, descriptions: &[("en", "Kotor/Tivat")] },
+    Area { prefix: 38233, descriptions: &[("en", "Budva")] },
+    Area { prefix: 38240, descriptions: &[("en", "Niksic/Pluzine/Savnik")] },
+    Area { prefix: 38241, descriptions: &[("en", "Cetinje")] },
+    Area { prefix: 38250, descriptions: &[("en", "Bijelo Polje/Mojkovac")] },
+    Area { prefix: 38251, descriptions: &[("en", "Andrijevica/Berane/Blue/Gusinje/Petnitsa/Rožaje")] },
+    Area { prefix: 38252, descriptions: &[("en", "Pljevlja/Zabljak")] },
+    Area { prefix: 38520, descriptions: &[("en", "Dubrovnik-Neretva")] },
+    Area { prefix: 38521, descriptions: &[("en", "Split-Dalmatia")] },
+    Area { prefix: 38522, descriptions: &[("en", "Šibenik-Knin")] },
+    Area { prefix: 38523, descriptions: &[("en", "Zadar")] },
+    Area { prefix: 38531, descriptions: &[("en", "Osijek-Baranja")] },
+    Area { prefix: 38532, descriptions: &[("en", "Vukovar-Srijem")] },
+    Area { prefix: 38533, descriptions: &[("en", "Virovitica-Podravina")] },
+    Area { prefix: 38534, descriptions: &[("en", "Požega-Slavonia")] },
+    Area { prefix: 38535, descriptions: &[("en", "Brod-Posavina")] },
+    Area { prefix: 38540, descriptions: &[("en", "Međimurje")] },
+    Area { prefix: 38542, descriptions: &[("en", "Varaždin")] },
+    Area { prefix: 38543, descriptions: &[("en", "Bjelovar-Bilogora")] },
+    Area { prefix: 38544, descriptions: &[("en", "Sisak-Moslavina")] },
+    Area { prefix: 38547, descriptions: &[("en", "Karlovac")] },
+    Area { prefix: 38548, descriptions: &[("en", "Koprivnica-Križevci")] },
+    Area { prefix: 38549, descriptions: &[("en", "Krapina-Zagorje")] },
+    Area { prefix: 38551, descriptions: &[("en", "Primorsko-goranska")] },
+    Area { prefix: 38552, descriptions: &[("en", "Istra")] },
+    Area { prefix: 38553, descriptions: &[("en", "Lika-Senj")] },
+    Area { prefix: 38632, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38633, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38634, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38635, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38636, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38637, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38638, descriptions: &[("en", "Celje/Trbovlje")] },
+    Area { prefix: 38642, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38644, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38645, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38646, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38647, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38648, descriptions: &[("en", "Kranj")] },
+    Area { prefix: 38652, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38653, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38654, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38655, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38656, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38657, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38658, descriptions: &[("en", "Gorica/Koper/Postojna")] },
+    Area { prefix: 38672, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38673, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38674, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38675, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38676, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38677, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38678, descriptions: &[("en", "Novo Mesto/Krško")] },
+    Area { prefix: 38730, descriptions: &[("en", "Central Bosnia Canton"), ("bs", "Srednjobosanski kanton"), ("hr", "Županija Središnja Bosna"), ("sr", "Средњoбосански кантон")] },
+    Area { prefix: 38731, descriptions: &[("en", "Posavina Canton"), ("bs", "Posavski kanton"), ("hr", "Županija Posavska"), ("sr", "Посавски кантон")] },
+    Area { prefix: 38732, descriptions: &[("en", "Zenica-Doboj Canton"), ("bs", "Zeničko-dobojski kanton"), ("hr", "Zeničko-dobojska županija"), ("sr", "Зеничко-добојски кантон")] },
+    Area { prefix: 38733, descriptions: &[("en", "Sarajevo Canton"), ("bs", "Kanton Sarajevo"), ("hr", "Sarajevska županija"), ("sr", "Сарајевски кантон")] },
+    Area { prefix: 38734, descriptions: &[("en", "Canton 10"), ("bs", "kanton 10"), ("hr", "Hercegbosanska županija"), ("sr", "Кантон 10")] },
+    Area { prefix: 38735, descriptions: &[("en", "Tuzla Canton"), ("bs", "Tuzlanski kanton"), ("hr", "Tuzlanska županija"), ("sr", "Тузлански кантон")] },
+    Area { prefix: 38736, descriptions: &[("en", "Herzegovina-Neretva Canton"), ("bs", "Hercegovačko-neretvanski kanton"), ("hr", "Hercegovačko-neretvanska županija")] },
+    Area { prefix: 38737, descriptions: &[("en", "Una-Sana Canton"), ("bs", "Unsko-sanski kanton"), ("hr", "Unsko-sanska županija"), ("sr", "Унско-сански кантон")] },
+    Area { prefix: 38738, descriptions: &[("en", "Bosnian-Podrinje Canton Goražde"), ("bs", "Bosansko-podrinjski kanton Goražde"), ("hr", "Bosansko-podrinjska županija Goražde"), ("sr", "Босанско-подрињски кантон Горажде")] },
+    Area { prefix: 38739, descriptions: &[("en", "West Herzegovina Canton"), ("bs", "Zapadnohercegovački kanton"), ("hr", "Županija Zapadnohercegovačka")] },
+    Area { prefix: 38750, descriptions: &[("en", "Mrkonjić Grad"), ("sr", "Мркоњић Град")] },
+    Area { prefix: 38751, descriptions: &[("en", "Banja Luka"), ("sr", "Бања Лука")] },
+    Area { prefix: 38752, descriptions: &[("en", "Prijedor"), ("sr", "Приједор")] },
+    Area { prefix: 38753, descriptions: &[("en", "Doboj"), ("sr", "Добој")] },
+    Area { prefix: 38754, descriptions: &[("en", "Šamac"), ("sr", "Шамац")] },
+    Area { prefix: 38755, descriptions: &[("en", "Bijeljina"), ("sr", "Бијељина")] },
+    Area { prefix: 38756, descriptions: &[("en", "Zvornik"), ("sr", "Зворник")] },
+    Area { prefix: 38757, descriptions: &[("en", "East Sarajevo"), ("bs", "Istočno Sarajevo"), ("sr", "Источно Сарајево")] },
+    Area { prefix: 38758, descriptions: &[("en", "Foča"), ("sr", "Фоча")] },
+    Area { prefix: 38759, descriptions: &[("en", "Trebinje"), ("sr", "Требиње")] },
+    Area { prefix: 38931, descriptions: &[("en", "Kumanovo/Kriva Palanka/Kratovo")] },
+    Area { prefix: 38932, descriptions: &[("en", "Stip/Probistip/Sveti Nikole/Radovis")] },
+    Area { prefix: 38933, descriptions: &[("en", "Kocani/Berovo/Delcevo/Vinica")] },
+    Area { prefix: 38934, descriptions: &[("en", "Gevgelija/Valandovo/Strumica/Dojran")] },
+    Area { prefix: 38942, descriptions: &[("en", "Gostivar")] },
+    Area { prefix: 38943, descriptions: &[("en", "Veles/Kavadarci/Negotino")] },
+    Area { prefix: 38944, descriptions: &[("en", "Tetovo")] },
+    Area { prefix: 38945, descriptions: &[("en", "Kicevo/Makedonski Brod")] },
+    Area { prefix: 38946, descriptions: &[("en", "Ohrid/Struga/Debar")] },
+    Area { prefix: 39010, descriptions: &[("en", "Genoa"), ("it", "Genova")] },
+    Area { prefix: 39011, descriptions: &[("en", "Turin"), ("it", "Torino")] },
+    Area { prefix: 39013, descriptions: &[("en", "Alessandria")] },
+    Area { prefix: 39015, descriptions: &[("en", "Biella")] },
+    Area { prefix: 39030, descriptions: &[("en", "Brescia")] },
+    Area { prefix: 39031, descriptions: &[("en", "Como")] },
+    Area { prefix: 39033, descriptions: &[("en", "Varese")] },
+    Area { prefix: 39035, descriptions: &[("en", "Bergamo")] },
+    Area { prefix: 39039, descriptions: &[("en", "Monza")] },
+    Area { prefix: 39040, descriptions: &[("en", "Trieste")] },
+    Area { prefix: 39041, descriptions: &[("en", "Venice"), ("it", "Venezia")] },
+    Area { prefix: 39045, descriptions: &[("en", "Verona")] },
+    Area { prefix: 39048, descriptions: &[("en", "Gorizia")] },
+    Area { prefix: 39049, descriptions: &[("en", "Padova")] },
+    Area { prefix: 39050, descriptions: &[("en", "Pisa")] },
+    Area { prefix: 39051, descriptions: &[("en", "Bologna")] },
+    Area { prefix: 39055, descriptions: &[("en", "Florence"), ("it", "Firenze")] },
+    Area { prefix: 39059, descriptions: &[("en", "Modena")] },
+    Area { prefix: 39070, descriptions: &[("en", "Cagliari")] },
+    Area { prefix: 39071, descriptions: &[("en", "Ancona")] },
+    Area { prefix: 39075, descriptions: &[("en", "Perugia")] },
+    Area { prefix: 39079, descriptions: &[("en", "Sassari")] },
+    Area { prefix: 39080, descriptions: &[("en", "Bari")] },
+    Area { prefix: 39081, descriptions: &[("en", "Naples"), ("it", "Napoli")] },
+    Area { prefix: 39085, descriptions: &[("en", "Pescara")] },
+    Area { prefix: 39089, descriptions: &[("en", "Salerno")] },
+    Area { prefix: 39090, descriptions: &[("en", "Messina")] },
+    Area { prefix: 39091, descriptions: &[("en", "Palermo")] },
+    Area { prefix: 39095, descriptions: &[("en", "Catania")] },
+    Area { prefix: 39099, descriptions: &[("en", "Taranto")] },
+    Area { prefix: 40230, descriptions: &[("en", "Suceava")] },
+    Area { prefix: 40231, descriptions: &[("en", "Botoșani")] },
+    Area { prefix: 40232, descriptions: &[("en", "Iași")] },
+    Area { prefix: 40233, descriptions: &[("en", "Neamț")] },
+    Area { prefix: 40234, descriptions: &[("en", "Bacău")] },
+    Area { prefix: 40235, descriptions: &[("en", "Vaslui")] },
+    Area { prefix: 40236, descriptions: &[("en", "Galați")] },
+    Area { prefix: 40237, descriptions: &[("en", "Vrancea")] },
+    Area { prefix: 40238, descriptions: &[("en", "Buzău")] },
+    Area { prefix: 40239, descriptions: &[("en", "Brăila")] },
+    Area { prefix: 40240, descriptions: &[("en", "Tulcea")] },
+    Area { prefix: 40241, descriptions: &[("en", "Constanța")] },
+    Area { prefix: 40242, descriptions: &[("en", "Călărași")] },
+    Area { prefix: 40243, descriptions: &[("en", "Ialomița")] },
+    Area { prefix: 40244, descriptions: &[("en", "Prahova")] },
+    Area { prefix: 40245, descriptions: &[("en", "Dâmbovița")] },
+    Area { prefix: 40246, descriptions: &[("en", "Giurgiu")] },
+    Area { prefix: 40247, descriptions: &[("en", "Teleorman")] },
+    Area { prefix: 40248, descriptions: &[("en", "Argeș")] },
+    Area { prefix: 40249, descriptions: &[("en", "Olt")] },
+    Area { prefix: 40250, descriptions: &[("en", "Vâlcea")] },
+    Area { prefix: 40251, descriptions: &[("en", "Dolj")] },
+    Area { prefix: 40252, descriptions: &[("en", "Mehedinți")] },
+    Area { prefix: 40253, descriptions: &[("en", "Gorj")] },
+    Area { prefix: 40254, descriptions: &[("en", "Hunedoara")] },
+    Area { prefix: 40255, descriptions: &[("en", "Caraș-Severin")] },
+    Area { prefix: 40256, descriptions: &[("en", "Timiș")] },
+    Area { prefix: 40257, descriptions: &[("en", "Arad")] },
+    Area { prefix: 40258, descriptions: &[("en", "Alba")] },
+    Area { prefix: 40259, descriptions: &[("en", "Bihor")] },
+    Area { prefix: 40260, descriptions: &[("en", "Sălaj")] },
+    Area { prefix: 40261, descriptions: &[("en", "Satu Mare")] },
+    Area { prefix: 40262, descriptions: &[("en", "Maramureș")] },
+    Area { prefix: 40263, descriptions: &[("en", "Bistrița-Năsăud")] },
+    Area { prefix: 40264, descriptions: &[("en", "Cluj")] },
+    Area { prefix: 40265, descriptions: &[("en", "Mureș")] },
+    Area { prefix: 40266, descriptions: &[("en", "Harghita")] },
+    Area { prefix: 40267, descriptions: &[("en", "Covasna")] },
+    Area { prefix: 40268, descriptions: &[("en", "Brașov")] },
+    Area { prefix: 40269, descriptions: &[("en", "Sibiu")] },
+    Area { prefix: 40330, descriptions: &[("en", "Suceava")] },
+    Area { prefix: 40331, descriptions: &[("en", "Botoșani")] },
+    Area { prefix: 40332, descriptions: &[("en", "Iași")] },
+    Area { prefix: 40333, descriptions: &[("en", "Neamț")] },
+    Area { prefix: 40334, descriptions: &[("en", "Bacău")] },
+    Area { prefix: 40335, descriptions: &[("en", "Vaslui")] },
+    Area { prefix: 40336, descriptions: &[("en", "Galați")] },
+    Area { prefix: 40337, descriptions: &[("en", "Vrancea")] },
+    Area { prefix: 40338, descriptions: &[("en", "Buzău")] },
+    Area { prefix: 40339, descriptions: &[("en", "Brăila")] },
+    Area { prefix: 40340, descriptions: &[("en", "Tulcea")] },
+    Area { prefix: 40341, descriptions: &[("en", "Constanța")] },
+    Area { prefix: 40342, descriptions: &[("en", "Călărași")] },
+    Area { prefix: 40343, descriptions: &[("en", "Ialomița")] },
+    Area { prefix: 40344, descriptions: &[("en", "Prahova")] },
+    Area { prefix: 40345, descriptions: &[("en", "Dâmbovița")] },
+    Area { prefix: 40346, descriptions: &[("en", "Giurgiu")] },
+    Area { prefix: 40347, descriptions: &[("en", "Teleorman")] },
+    Area { prefix: 40348, descriptions: &[("en", "Argeș")] },
+    Area { prefix: 40349, descriptions: &[("en", "Olt")] },
+    Area { prefix: 40350, descriptions: &[("en", "Vâlcea")] },
+    Area { prefix: 40351, descriptions: &[("en", "Dolj")] },
+    Area { prefix: 40352, descriptions: &[("en", "Mehedinți")] },
+    Area { prefix: 40353, descriptions: &[("en", "Gorj")] },
+    Area { prefix: 40354, descriptions: &[("en", "Hunedoara")] },
+    Area { prefix: 40355, descriptions: &[("en", "Caraș-Severin")] },
+    Area { prefix: 40356, descriptions: &[("en", "Timiș")] },
+    Area { prefix: 40357, descriptions: &[("en", "Arad")] },
+    Area { prefix: 40358, descriptions: &[("en", "Alba")] },
+    Area { prefix: 40359, descriptions: &[("en", "Bihor")] },
+    Area { prefix: 40360, descriptions: &[("en", "Sălaj")] },
+    Area { prefix: 40361, descriptions: &[("en", "Satu Mare")] },
+    Area { prefix: 40362, descriptions: &[("en", "Maramureș")] },
+    Area { prefix: 40363, descriptions: &[("en", "Bistrița-Năsăud")] },
+    Area { prefix: 40364, descriptions: &[("en", "Cluj")] },
+    Area { prefix: 40365, descriptions: &[("en", "Mureș")] },
+    Area { prefix: 40366, descriptions: &[("en", "Harghita")] },
+    Area { prefix: 40367, descriptions: &[("en", "Covasna")] },
+    Area { prefix: 40368, descriptions: &[("en", "Brașov")] },
+    Area { prefix: 40369, descriptions: &[("en", "Sibiu")] },
+    Area { prefix: 42031, descriptions: &[("en", "Central Bohemian Region")] },
+    Area { prefix: 42032, descriptions: &[("en", "Central Bohemian Region")] },
+    Area { prefix: 42035, descriptions: &[("en", "Karlovy Vary Region")] },
+    Area { prefix: 42037, descriptions: &[("en", "Plzeň Region")] },
+    Area { prefix: 42038, descriptions: &[("en", "South Bohemian Region")] },
+    Area { prefix: 42039, descriptions: &[("en", "South Bohemian Region")] },
+    Area { prefix: 42041, descriptions: &[("en", "Ústí nad Labem Region")] },
+    Area { prefix: 42046, descriptions: &[("en", "Pardubice Region")] },
+    Area { prefix: 42047, descriptions: &[("en", "Ústí nad Labem Region")] },
+    Area { prefix: 42048, descriptions: &[("en", "Liberec Region")] },
+    Area { prefix: 42049, descriptions: &[("en", "Hradec Králové Region")] },
+    Area { prefix: 42051, descriptions: &[("en", "South Moravian Region")] },
+    Area { prefix: 42053, descriptions: &[("en", "South Moravian Region")] },
+    Area { prefix: 42054, descriptions: &[("en", "South Moravian Region")] },
+    Area { prefix: 42055, descriptions: &[("en", "Moravian-Silesian Region")] },
+    Area { prefix: 42056, descriptions: &[("en", "Vysočina Region")] },
+    Area { prefix: 42057, descriptions: &[("en", "Zlín Region")] },
+    Area { prefix: 42058, descriptions: &[("en", "Olomouc Region")] },
+    Area { prefix: 42059, descriptions: &[("en", "Moravian-Silesian Region")] },
+    Area { prefix: 42131, descriptions: &[("en", "Dunajska Streda")] },
+    Area { prefix: 42132, descriptions: &[("en", "Trencin")] },
+    Area { prefix: 42133, descriptions: &[("en", "Trnava")] },
+    Area { prefix: 42134, descriptions: &[("en", "Senica")] },
+    Area { prefix: 42135, descriptions: &[("en", "Nove Zamky")] },
+    Area { prefix: 42136, descriptions: &[("en", "Levice")] },
+    Area { prefix: 42137, descriptions: &[("en", "Nitra")] },
+    Area { prefix: 42138, descriptions: &[("en", "Topolcany")] },
+    Area { prefix: 42141, descriptions: &[("en", "Zilina")] },
+    Area { prefix: 42142, descriptions: &[("en", "Povazska Bystrica")] },
+    Area { prefix: 42143, descriptions: &[("en", "Martin")] },
+    Area { prefix: 42144, descriptions: &[("en", "Liptovsky Mikulas")] },
+    Area { prefix: 42145, descriptions: &[("en", "Zvolen")] },
+    Area { prefix: 42146, descriptions: &[("en", "Prievidza")] },
+    Area { prefix: 42147, descriptions: &[("en", "Lucenec")] },
+    Area { prefix: 42148, descriptions: &[("en", "Banska Bystrica")] },
+    Area { prefix: 42151, descriptions: &[("en", "Presov")] },
+    Area { prefix: 42152, descriptions: &[("en", "Poprad")] },
+    Area { prefix: 42153, descriptions: &[("en", "Spisska Nova Ves")] },
+    Area { prefix: 42154, descriptions: &[("en", "Bardejov")] },
+    Area { prefix: 42155, descriptions: &[("en", "Kosice")] },
+    Area { prefix: 42156, descriptions: &[("en", "Michalovce")] },
+    Area { prefix: 42157, descriptions: &[("en", "Humenne")] },
+    Area { prefix: 42158, descriptions: &[("en", "Roznava")] },
+    Area { prefix: 43316, descriptions: &[("en", "Graz")] },
+    Area { prefix: 43512, descriptions: &[("en", "Innsbruck")] },
+    Area { prefix: 43662, descriptions: &[("en", "Salzburg")] },
+    Area { prefix: 43732, descriptions: &[("en", "Linz")] },
+    Area { prefix: 44113, descriptions: &[("en", "Leeds")] },
+    Area { prefix: 44115, descriptions: &[("en", "Nottingham")] },
+    Area { prefix: 44116, descriptions: &[("en", "Leicester")] },
+    Area { prefix: 44117, descriptions: &[("en", "Bristol")] },
+    Area { prefix: 44118, descriptions: &[("en", "Reading")] },
+    Area { prefix: 44121, descriptions: &[("en", "Birmingham")] },
+    Area { prefix: 44131, descriptions: &[("en", "Edinburgh")] },
+    Area { prefix: 44141, descriptions: &[("en", "Glasgow")] },
+    Area { prefix: 44151, descriptions: &[("en", "Liverpool")] },
+    Area { prefix: 44161, descriptions: &[("en", "Manchester")] },
+    Area { prefix: 44238, descriptions: &[("en", "Southampton")] },
+    Area { prefix: 44239, descriptions: &[("en", "Portsmouth")] },
+    Area { prefix: 44241, descriptions: &[("en", "Coventry")] },
+    Area { prefix: 44247, descriptions: &[("en", "Coventry")] },
+    Area { prefix: 44280, descriptions: &[("en", "Northern Ireland")] },
+    Area { prefix: 44281, descriptions: &[("en", "Northern Ireland")] },
+    Area { prefix: 44283, descriptions: &[("en", "Northern Ireland")] },
+    Area { prefix: 44286, descriptions: &[("en", "Northern Ireland")] },
+    Area { prefix: 44287, descriptions: &[("en", "Northern Ireland")] },
+    Area { prefix: 44291, descriptions: &[("en", "Cardiff")] },
+    Area { prefix: 44292, descriptions: &[("en", "Cardiff")] },
+    Area { prefix: 46120, descriptions: &[("en", "Åtvidaberg")] },
+    Area { prefix: 46121, descriptions: &[("en", "Söderköping")] },
+    Area { prefix: 46122, descriptions: &[("en", "Finspång")] },
+    Area { prefix: 46123, descriptions: &[("en", "Valdemarsvik")] },
+    Area { prefix: 46125, descriptions: &[("en", "Vikbolandet")] },
+    Area { prefix: 46140, descriptions: &[("en", "Tranås")] },
+    Area { prefix: 46141, descriptions: &[("en", "Motala")] },
+    Area { prefix: 46142, descriptions: &[("en", "Mjölby-Skänninge-Boxholm")] },
+    Area { prefix: 46143, descriptions: &[("en", "Vadstena")] },
+    Area { prefix: 46144, descriptions: &[("en", "Ödeshög")] },
+    Area { prefix: 46150, descriptions: &[("en", "Katrineholm")] },
+    Area { prefix: 46151, descriptions: &[("en", "Vingåker")] },
+    Area { prefix: 46152, descriptions: &[("en", "Strängnäs")] },
+    Area { prefix: 46155, descriptions: &[("en", "Nyköping-Oxelösund")] },
+    Area { prefix: 46156, descriptions: &[("en", "Trosa-Vagnhärad")] },
+    Area { prefix: 46157, descriptions: &[("en", "Flen-Malmköping")] },
+    Area { prefix: 46158, descriptions: &[("en", "Gnesta")] },
+    Area { prefix: 46159, descriptions: &[("en", "Mariefred")] },
+    Area { prefix: 46171, descriptions: &[("en", "Enköping")] },
+    Area { prefix: 46173, descriptions: &[("en", "Öregrund-Östhammar")] },
+    Area { prefix: 46174, descriptions: &[("en", "Alunda")] },
+    Area { prefix: 46175, descriptions: &[("en", "Hallstavik-Rimbo")] },
+    Area { prefix: 46176, descriptions: &[("en", "Norrtälje")] },
+    Area { prefix: 46220, descriptions: &[("en", "Hallstahammar-Surahammar")] },
+    Area { prefix: 46221, descriptions: &[("en", "Köping")] },
+    Area { prefix: 46222, descriptions: &[("en", "Skinnskatteberg")] },
+    Area { prefix: 46223, descriptions: &[("en", "Fagersta-Norberg")] },
+    Area { prefix: 46224, descriptions: &[("en", "Sala-Heby")] },
+    Area { prefix: 46225, descriptions: &[("en", "Hedemora-Säter")] },
+    Area { prefix: 46226, descriptions: &[("en", "Avesta-Krylbo")] },
+    Area { prefix: 46227, descriptions: &[("en", "Kungsör")] },
+    Area { prefix: 46240, descriptions: &[("en", "Ludvika-Smedjebacken")] },
+    Area { prefix: 46241, descriptions: &[("en", "Gagnef-Floda")] },
+    Area { prefix: 46243, descriptions: &[("en", "Borlänge")] },
+    Area { prefix: 46246, descriptions: &[("en", "Svärdsjö-Enviken")] },
+    Area { prefix: 46247, descriptions: &[("en", "Leksand-Insjön")] },
+    Area { prefix: 46248, descriptions: &[("en", "Rättvik")] },
+    Area { prefix: 46250, descriptions: &[("en", "Mora-Orsa")] },
+    Area { prefix: 46251, descriptions: &[("en", "Älvdalen")] },
+    Area { prefix: 46253, descriptions: &[("en", "Idre-Särna")] },
+    Area { prefix: 46258, descriptions: &[("en", "Furudal")] },
+    Area { prefix: 46270, descriptions: &[("en", "Söderhamn")] },
+    Area { prefix: 46271, descriptions: &[("en", "Alfta-Edsbyn")] },
+    Area { prefix: 46278, descriptions: &[("en", "Bollnäs")] },
+    Area { prefix: 46280, descriptions: &[("en", "Malung")] },
+    Area { prefix: 46281, descriptions: &[("en", "Vansbro")] },
+    Area { prefix: 46290, descriptions: &[("en", "Hofors-Storvik")] },
+    Area { prefix: 46291, descriptions: &[("en", "Hedesunda-Österfärnebo")] },
+    Area { prefix: 46292, descriptions: &[("en", "Tärnsjö-Östervåla")] },
+    Area { prefix: 46293, descriptions: &[("en", "Tierp-Söderfors")] },
+    Area { prefix: 46294, descriptions: &[("en", "Karlholmsbruk-Skärplinge")] },
+    Area { prefix: 46295, descriptions: &[("en", "Örbyhus-Dannemora")] },
+    Area { prefix: 46297, descriptions: &[("en", "Ockelbo-Hamrånge")] },
+    Area { prefix: 46300, descriptions: &[("en", "Kungsbacka")] },
+    Area { prefix: 46301, descriptions: &[("en", "Hindås")] },
+    Area { prefix: 46302, descriptions: &[("en", "Lerum")] },
+    Area { prefix: 46303, descriptions: &[("en", "Kungälv")] },
+    Area { prefix: 46304, descriptions: &[("en", "Orust-Tjörn")] },
+    Area { prefix: 46320, descriptions: &[("en", "Kinna")] },
+    Area { prefix: 46321, descriptions: &[("en", "Ulricehamn")] },
+    Area { prefix: 46322, descriptions: &[("en", "Alingsås-Vårgårda")] },
+    Area { prefix: 46325, descriptions: &[("en", "Svenljunga-Tranemo")] },
+    Area { prefix: 46340, descriptions: &[("en", "Varberg")] },
+    Area { prefix: 46345, descriptions: &[("en", "Hyltebruk-Torup")] },
+    Area { prefix: 46346, descriptions: &[("en", "Falkenberg")] },
+    Area { prefix: 46370, descriptions: &[("en", "Värnamo")] },
+    Area { prefix: 46371, descriptions: &[("en", "Gislaved-Anderstorp")] },
+    Area { prefix: 46372, descriptions: &[("en", "Ljungby")] },
+    Area { prefix: 46380, descriptions: &[("en", "Nässjö")] },
+    Area { prefix: 46381, descriptions: &[("en", "Eksjö")] },
+    Area { prefix: 46382, descriptions: &[("en", "Sävsjö")] },
+    Area { prefix: 46383, descriptions: &[("en", "Vetlanda")] },
+    Area { prefix: 46390, descriptions: &[("en", "Gränna")] },
+    Area { prefix: 46392, descriptions: &[("en", "Mullsjö")] },
+    Area { prefix: 46393, descriptions: &[("en", "Vaggeryd")] },
+    Area { prefix: 46410, descriptions: &[("en", "Trelleborg")] },
+    Area { prefix: 46411, descriptions: &[("en", "Ystad")] },
+    Area { prefix: 46413, descriptions: &[("en", "Eslöv-Höör")] },
+    Area { prefix: 46414, descriptions: &[("en", "Simrishamn")] },
+    Area { prefix: 46415, descriptions: &[("en", "Hörby")] },
+    Area { prefix: 46416, descriptions: &[("en", "Sjöbo")] },
+    Area { prefix: 46417, descriptions: &[("en", "Tomelilla")] },
+    Area { prefix: 46418, descriptions: &[("en", "Landskrona-Svalöv")] },
+    Area { prefix: 46430, descriptions: &[("en", "Laholm")] },
+    Area { prefix: 46431, descriptions: &[("en", "Ängelholm-Båstad")] },
+    Area { prefix: 46433, descriptions: &[("en", "Markaryd-Strömsnäsbruk")] },
+    Area { prefix: 46435, descriptions: &[("en", "Klippan-Perstorp")] },
+    Area { prefix: 46451, descriptions: &[("en", "Hässleholm")] },
+    Area { prefix: 46454, descriptions: &[("en", "Karlshamn-Olofström")] },
+    Area { prefix: 46455, descriptions: &[("en", "Karlskrona")] },
+    Area { prefix: 46456, descriptions: &[("en", "Sölvesborg-Bromölla")] },
+    Area { prefix: 46457, descriptions: &[("en", "Ronneby")] },
+    Area { prefix: 46459, descriptions: &[("en", "Ryd")] },
+    Area { prefix: 46470, descriptions: &[("en", "Växjö")] },
+    Area { prefix: 46471, descriptions: &[("en", "Emmaboda")] },
+    Area { prefix: 46472, descriptions: &[("en", "Alvesta-Rydaholm")] },
+    Area { prefix: 46474, descriptions: &[("en", "Åseda-Lenhovda")] },
+    Area { prefix: 46476, descriptions: &[("en", "Älmhult")] },
+    Area { prefix: 46477, descriptions: &[("en", "Tingsryd")] },
+    Area { prefix: 46478, descriptions: &[("en", "Lessebo")] },
+    Area { prefix: 46479, descriptions: &[("en", "Osby")] },
+    Area { prefix: 46480, descriptions: &[("en", "Kalmar")] },
+    Area { prefix: 46481, descriptions: &[("en", "Nybro")] },
+    Area { prefix: 46485, descriptions: &[("en", "Öland")] },
+    Area { prefix: 46486, descriptions: &[("en", "Torsås")] },
+    Area { prefix: 46490, descriptions: &[("en", "Västervik")] },
+    Area { prefix: 46491, descriptions: &[("en", "Oskarshamn-Högsby")] },
+    Area { prefix: 46492, descriptions: &[("en", "Vimmerby")] },
+    Area { prefix: 46493, descriptions: &[("en", "Gamleby")] },
+    Area { prefix: 46494, descriptions: &[("en", "Kisa")] },
+    Area { prefix: 46495, descriptions: &[("en", "Hultsfred-Virserum")] },
+    Area { prefix: 46496, descriptions: &[("en", "Mariannelund")] },
+    Area { prefix: 46498, descriptions: &[("en", "Gotland")] },
+    Area { prefix: 46499, descriptions: &[("en", "Mönsterås")] },
+    Area { prefix: 46500, descriptions: &[("en", "Skövde")] },
+    Area { prefix: 46501, descriptions: &[("en", "Mariestad")] },
+    Area { prefix: 46502, descriptions: &[("en", "Tidaholm")] },
+    Area { prefix: 46503, descriptions: &[("en", "Hjo")] },
+    Area { prefix: 46504, descriptions: &[("en", "Tibro")] },
+    Area { prefix: 46505, descriptions: &[("en", "Karlsborg")] },
+    Area { prefix: 46506, descriptions: &[("en", "Töreboda-Hova")] },
+    Area { prefix: 46510, descriptions: &[("en", "Lidköping")] },
+    Area { prefix: 46511, descriptions: &[("en", "Skara-Götene")] },
+    Area { prefix: 46512, descriptions: &[("en", "Vara-Nossebro")] },
+    Area { prefix: 46513, descriptions: &[("en", "Herrljunga")] },
+    Area { prefix: 46514, descriptions: &[("en", "Grästorp")] },
+    Area { prefix: 46515, descriptions: &[("en", "Falköping")] },
+    Area { prefix: 46520, descriptions: &[("en", "Trollhättan")] },
+    Area { prefix: 46521, descriptions: &[("en", "Vänersborg")] },
+    Area { prefix: 46522, descriptions: &[("en", "Uddevalla")] },
+    Area { prefix: 46523, descriptions: &[("en", "Lysekil")] },
+    Area { prefix: 46524, descriptions: &[("en", "Munkedal")] },
+    Area { prefix: 46525, descriptions: &[("en", "Grebbestad")] },
+    Area { prefix: 46526, descriptions: &[("en", "Strömstad")] },
+    Area { prefix: 46528, descriptions: &[("en", "Färgelanda")] },
+    Area { prefix: 46530, descriptions: &[("en", "Mellerud")] },
+    Area { prefix: 46531, descriptions: &[("en", "Bengtsfors")] },
+    Area { prefix: 46532, descriptions: &[("en", "Åmål")] },
+    Area { prefix: 46533, descriptions: &[("en", "Säffle")] },
+    Area { prefix: 46534, descriptions: &[("en", "Ed")] },
+    Area { prefix: 46550, descriptions: &[("en", "Kristinehamn")] },
+    Area { prefix: 46551, descriptions: &[("en", "Gullspång")] },
+    Area { prefix: 46552, descriptions: &[("en", "Deje")] },
+    Area { prefix: 46553, descriptions: &[("en", "Molkom")] },
+    Area { prefix: 46554, descriptions: &[("en", "Kil")] },
+    Area { prefix: 46555, descriptions: &[("en", "Grums")] },
+    Area { prefix: 46560, descriptions: &[("en", "Torsby")] },
+    Area { prefix: 46563, descriptions: &[("en", "Hagfors-Munkfors")] },
+    Area { prefix: 46564, descriptions: &[("en", "Sysslebäck")] },
+    Area { prefix: 46565, descriptions: &[("en", "Sunne")] },
+    Area { prefix: 46570, descriptions: &[("en", "Arvika")] },
+    Area { prefix: 46571, descriptions: &[("en", "Charlottenberg-Åmotfors")] },
+    Area { prefix: 46573, descriptions: &[("en", "Årjäng")] },
+    Area { prefix: 46580, descriptions: &[("en", "Kopparberg")] },
+    Area { prefix: 46581, descriptions: &[("en", "Lindesberg")] },
+    Area { prefix: 46582, descriptions: &[("en", "Hallsberg")] },
+    Area { prefix: 46583, descriptions: &[("en", "Askersund")] },
+    Area { prefix: 46584, descriptions: &[("en", "Laxå")] },
+    Area { prefix: 46585, descriptions: &[("en", "Fjugesta-Svartå")] },
+    Area { prefix: 46586, descriptions: &[("en", "Karlskoga-Degerfors")] },
+    Area { prefix: 46587, descriptions: &[("en", "Nora")] },
+    Area { prefix: 46589, descriptions: &[("en", "Arboga")] },
+    Area { prefix: 46590, descriptions: &[("en", "Filipstad")] },
+    Area { prefix: 46591, descriptions: &[("en", "Hällefors-Grythyttan")] },
+    Area { prefix: 46611, descriptions: &[("en", "Härnösand")] },
+    Area { prefix: 46612, descriptions: &[("en", "Kramfors")] },
+    Area { prefix: 46613, descriptions: &[("en", "Ullånger")] },
+    Area { prefix: 46620, descriptions: &[("en", "Sollefteå")] },
+    Area { prefix: 46621, descriptions: &[("en", "Junsele")] },
+    Area { prefix: 46622, descriptions: &[("en", "Näsåker")] },
+    Area { prefix: 46623, descriptions: &[("en", "Ramsele")] },
+    Area { prefix: 46624, descriptions: &[("en", "Backe")] },
+    Area { prefix: 46640, descriptions: &[("en", "Krokom")] },
+    Area { prefix: 46642, descriptions: &[("en", "Lit")] },
+    Area { prefix: 46643, descriptions: &[("en", "Hallen-Oviken")] },
+    Area { prefix: 46644, descriptions: &[("en", "Hammerdal")] },
+    Area { prefix: 46645, descriptions: &[("en", "Föllinge")] },
+    Area { prefix: 46647, descriptions: &[("en", "Åre-Järpen")] },
+    Area { prefix: 46650, descriptions: &[("en", "Hudiksvall")] },
+    Area { prefix: 46651, descriptions: &[("en", "Ljusdal")] },
+    Area { prefix: 46652, descriptions: &[("en", "Bergsjö")] },
+    Area { prefix: 46653, descriptions: &[("en", "Delsbo")] },
+    Area { prefix: 46657, descriptions: &[("en", "Los")] },
+    Area { prefix: 46660, descriptions: &[("en", "Örnsköldsvik")] },
+    Area { prefix: 46661, descriptions: &[("en", "Bredbyn")] },
+    Area { prefix: 46662, descriptions: &[("en", "Björna")] },
+    Area { prefix: 46663, descriptions: &[("en", "Husum")] },
+    Area { prefix: 46670, descriptions: &[("en", "Strömsund")] },
+    Area { prefix: 46671, descriptions: &[("en", "Hoting")] },
+    Area { prefix: 46672, descriptions: &[("en", "Gäddede")] },
+    Area { prefix: 46680, descriptions: &[("en", "Sveg")] },
+    Area { prefix: 46682, descriptions: &[("en", "Rätan")] },
+    Area { prefix: 46684, descriptions: &[("en", "Hede-Funäsdalen")] },
+    Area { prefix: 46687, descriptions: &[("en", "Svenstavik")] },
+    Area { prefix: 46690, descriptions: &[("en", "Ånge")] },
+    Area { prefix: 46691, descriptions: &[("en", "Torpshammar")] },
+    Area { prefix: 46692, descriptions: &[("en", "Liden")] },
+    Area { prefix: 46693, descriptions: &[("en", "Bräcke-Gällö")] },
+    Area { prefix: 46695, descriptions: &[("en", "Stugun")] },
+    Area { prefix: 46696, descriptions: &[("en", "Hammarstrand")] },
+    Area { prefix: 46901, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46902, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46903, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46904, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46905, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46906, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46907, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46908, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46909, descriptions: &[("en", "Umeå")] },
+    Area { prefix: 46910, descriptions: &[("en", "Skellefteå")] },
+    Area { prefix: 46911, descriptions: &[("en", "Piteå")] },
+    Area { prefix: 46912, descriptions: &[("en", "Byske")] },
+    Area { prefix: 46913, descriptions: &[("en", "Lövånger")] },
+    Area { prefix: 46914, descriptions: &[("en", "Burträsk")] },
+    Area { prefix: 46915, descriptions: &[("en", "Bastuträsk")] },
+    Area { prefix: 46916, descriptions: &[("en", "Jörn")] },
+    Area { prefix: 46918, descriptions: &[("en", "Norsjö")] },
+    Area { prefix: 46920, descriptions: &[("en", "Luleå")] },
+    Area { prefix: 46921, descriptions: &[("en", "Boden")] },
+    Area { prefix: 46922, descriptions: &[("en", "Haparanda")] },
+    Area { prefix: 46923, descriptions: &[("en", "Kalix")] },
+    Area { prefix: 46924, descriptions: &[("en", "Råneå")] },
+    Area { prefix: 46925, descriptions: &[("en", "Lakaträsk")] },
+    Area { prefix: 46926, descriptions: &[("en", "Överkalix")] },
+    Area { prefix: 46927, descriptions: &[("en", "Övertorneå")] },
+    Area { prefix: 46928, descriptions: &[("en", "Harads")] },
+    Area { prefix: 46929, descriptions: &[("en", "Älvsbyn")] },
+    Area { prefix: 46930, descriptions: &[("en", "Nordmaling")] },
+    Area { prefix: 46932, descriptions: &[("en", "Bjurholm")] },
+    Area { prefix: 46933, descriptions: &[("en", "Vindeln")] },
+    Area { prefix: 46934, descriptions: &[("en", "Robertsfors")] },
+    Area { prefix: 46935, descriptions: &[("en", "Vännäs")] },
+    Area { prefix: 46940, descriptions: &[("en", "Vilhelmina")] },
+    Area { prefix: 46941, descriptions: &[("en", "Åsele")] },
+    Area { prefix: 46942, descriptions: &[("en", "Dorotea")] },
+    Area { prefix: 46943, descriptions: &[("en", "Fredrika")] },
+    Area { prefix: 46950, descriptions: &[("en", "Lycksele")] },
+    Area { prefix: 46951, descriptions: &[("en", "Storuman")] },
+    Area { prefix: 46952, descriptions: &[("en", "Sorsele")] },
+    Area { prefix: 46953, descriptions: &[("en", "Malå")] },
+    Area { prefix: 46954, descriptions: &[("en", "Tärnaby")] },
+    Area { prefix: 46960, descriptions: &[("en", "Arvidsjaur")] },
+    Area { prefix: 46961, descriptions: &[("en", "Arjeplog")] },
+    Area { prefix: 46970, descriptions: &[("en", "Gällivare")] },
+    Area { prefix: 46971, descriptions: &[("en", "Jokkmokk")] },
+    Area { prefix: 46973, descriptions: &[("en", "Porjus")] },
+    Area { prefix: 46975, descriptions: &[("en", "Hakkas")] },
+    Area { prefix: 46976, descriptions: &[("en", "Vuollerim")] },
+    Area { prefix: 46977, descriptions: &[("en", "Korpilombolo")] },
+    Area { prefix: 46978, descriptions: &[("en", "Pajala")] },
+    Area { prefix: 46980, descriptions: &[("en", "Kiruna")] },
+    Area { prefix: 46981, descriptions: &[("en", "Vittangi")] },
+    Area { prefix: 47210, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47211, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47212, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47215, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47216, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47217, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47218, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47230, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47231, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47232, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47233, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47234, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47235, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47237, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47239, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47240, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47243, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47244, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47245, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47246, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47247, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47248, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 47249, descriptions: &[("en", "Oslo")] },
+    Area { prefix: 49201, descriptions: &[("en", "Essen")] },
+    Area { prefix: 49202, descriptions: &[("en", "Wuppertal")] },
+    Area { prefix: 49203, descriptions: &[("en", "Duisburg")] },
+    Area { prefix: 49208, descriptions: &[("en", "Oberhausen Rheinland")] },
+    Area { prefix: 49209, descriptions: &[("en", "Gelsenkirchen")] },
+    Area { prefix: 49211, descriptions: &[("en", "Düsseldorf")] },
+    Area { prefix: 49212, descriptions: &[("en", "Solingen")] },
+    Area { prefix: 49214, descriptions: &[("en", "Leverkusen")] },
+    Area { prefix: 49221, descriptions: &[("en", "Cologne"), ("de", "Köln")] },
+    Area { prefix: 49228, descriptions: &[("en", "Bonn")] },
+    Area { prefix: 49231, descriptions: &[("en", "Dortmund")] },
+    Area { prefix: 49234, descriptions: &[("en", "Bochum")] },
+    Area { prefix: 49241, descriptions: &[("en", "Aachen")] },
+    Area { prefix: 49251, descriptions: &[("en", "Münster")] },
+    Area { prefix: 49261, descriptions: &[("en", "Koblenz am Rhein")] },
+    Area { prefix: 49271, descriptions: &[("en", "Siegen")] },
+    Area { prefix: 49281, descriptions: &[("en", "Wesel")] },
+    Area { prefix: 49291, descriptions: &[("en", "Meschede")] },
+    Area { prefix: 49331, descriptions: &[("en", "Potsdam")] },
+    Area { prefix: 49335, descriptions: &[("en", "Frankfurt (Oder)")] },
+    Area { prefix: 49340, descriptions: &[("en", "Dessau Anh")] },
+    Area { prefix: 49341, descriptions: &[("en", "Leipzig")] },
+    Area { prefix: 49345, descriptions: &[("en", "Halle Saale")] },
+    Area { prefix: 49351, descriptions: &[("en", "Dresden")] },
+    Area { prefix: 49355, descriptions: &[("en", "Cottbus")] },
+    Area { prefix: 49361, descriptions: &[("en", "Erfurt")] },
+    Area { prefix: 49365, descriptions: &[("en", "Gera")] },
+    Area { prefix: 49371, descriptions: &[("en", "Chemnitz Sachsen")] },
+    Area { prefix: 49375, descriptions: &[("en", "Zwickau")] },
+    Area { prefix: 49381, descriptions: &[("en", "Rostock")] },
+    Area { prefix: 49385, descriptions: &[("en", "Schwerin")] },
+    Area { prefix: 49391, descriptions: &[("en", "Magdeburg")] },
+    Area { prefix: 49395, descriptions: &[("en", "Neubrandenburg")] },
+    Area { prefix: 49421, descriptions: &[("en", "Bremen")] },
+    Area { prefix: 49431, descriptions: &[("en", "Kiel")] },
+    Area { prefix: 49441, descriptions: &[("en", "Oldenburg")] },
+    Area { prefix: 49451, descriptions: &[("en", "Lübeck")] },
+    Area { prefix: 49461, descriptions: &[("en", "Flensburg")] },
+    Area { prefix: 49465, descriptions: &[("en", "Sylt")] },
+    Area { prefix: 49471, descriptions: &[("en", "Bremerhaven")] },
+    Area { prefix: 49481, descriptions: &[("en", "Heide Holstein")] },
+    Area { prefix: 49491, descriptions: &[("en", "Leer Ostfriesland")] },
+    Area { prefix: 49511, descriptions: &[("en", "Hannover")] },
+    Area { prefix: 49521, descriptions: &[("en", "Bielefeld")] },
+    Area { prefix: 49531, descriptions: &[("en", "Braunschweig")] },
+    Area { prefix: 49541, descriptions: &[("en", "Osnabrück")] },
+    Area { prefix: 49551, descriptions: &[("en", "Göttingen")] },
+    Area { prefix: 49561, descriptions: &[("en", "Kassel")] },
+    Area { prefix: 49571, descriptions: &[("en", "Minden Westfalen")] },
+    Area { prefix: 49581, descriptions: &[("en", "Uelzen")] },
+    Area { prefix: 49591, descriptions: &[("en", "Lingen (Ems)")] },
+    Area { prefix: 49611, descriptions: &[("en", "Wiesbaden")] },
+    Area { prefix: 49621, descriptions: &[("en", "Mannheim")] },
+    Area { prefix: 49631, descriptions: &[("en", "Kaiserslautern")] },
+    Area { prefix: 49641, descriptions: &[("en", "Giessen")] },
+    Area { prefix: 49651, descriptions: &[("en", "Trier")] },
+    Area { prefix: 49661, descriptions: &[("en", "Fulda")] },
+    Area { prefix: 49671, descriptions: &[("en", "Bad Kreuznach")] },
+    Area { prefix: 49681, descriptions: &[("en", "Saarbrücken")] },
+    Area { prefix: 49711, descriptions: &[("en", "Stuttgart")] },
+    Area { prefix: 49721, descriptions: &[("en", "Karlsruhe")] },
+    Area { prefix: 49731, descriptions: &[("en", "Ulm Donau")] },
+    Area { prefix: 49741, descriptions: &[("en", "Rottweil")] },
+    Area { prefix: 49751, descriptions: &[("en", "Ravensburg")] },
+    Area { prefix: 49760, descriptions: &[("en", "Oberried Breisgau")] },
+    Area { prefix: 49761, descriptions: &[("en", "Freiburg im Breisgau")] },
+    Area { prefix: 49771, descriptions: &[("en", "Donaueschingen")] },
+    Area { prefix: 49781, descriptions: &[("en", "Offenburg")] },
+    Area { prefix: 49791, descriptions: &[("en", "Schwäbisch Hall")] },
+    Area { prefix: 49811, descriptions: &[("en", "Hallbergmoos")] },
+    Area { prefix: 49821, descriptions: &[("en", "Augsburg")] },
+    Area { prefix: 49831, descriptions: &[("en", "Kempten Allgäu")] },
+    Area { prefix: 49841, descriptions: &[("en", "Ingolstadt Donau")] },
+    Area { prefix: 49851, descriptions: &[("en", "Passau")] },
+    Area { prefix: 49861, descriptions: &[("en", "Traunstein")] },
+    Area { prefix: 49871, descriptions: &[("en", "Landshut")] },
+    Area { prefix: 49881, descriptions: &[("en", "Weilheim in Oberbayern")] },
+    Area { prefix: 49906, descriptions: &[("en", "Donauwörth")] },
+    Area { prefix: 49911, descriptions: &[("en", "Nuremberg"), ("de", "Nürnberg")] },
+    Area { prefix: 49921, descriptions: &[("en", "Bayreuth")] },
+    Area { prefix: 49931, descriptions: &[("en", "Würzburg")] },
+    Area { prefix: 49941, descriptions: &[("en", "Regensburg")] },
+    Area { prefix: 49951, descriptions: &[("en", "Bamberg")] },
+    Area { prefix: 49961, descriptions: &[("en", "Weiden in der Oberpfalz")] },
+    Area { prefix: 49971, descriptions: &[("en", "Bad Kissingen")] },
+    Area { prefix: 49981, descriptions: &[("en", "Ansbach")] },
+    Area { prefix: 49991, descriptions: &[("en", "Deggendorf")] },
+    Area { prefix: 52220, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52221, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52222, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52223, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52224, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52225, descriptions: &[("en", "Tlapacoyan, VER")] },
+    Area { prefix: 52226, descriptions: &[("en", "Altotonga/Jalacingo, VER")] },
+    Area { prefix: 52227, descriptions: &[("en", "Huejotzingo/San Buenaventura Nealtican, PUE")] },
+    Area { prefix: 52228, descriptions: &[("en", "Jalapa/Tuzamapan, VER")] },
+    Area { prefix: 52229, descriptions: &[("en", "Veracruz, VER")] },
+    Area { prefix: 52231, descriptions: &[("en", "Teteles/Teziutlan, PUE"), ("es", "Teteles/Teziutlán, PUE")] },
+    Area { prefix: 52232, descriptions: &[("en", "La Vigueta/Martinez de la Torre, VER"), ("es", "La Vigueta/Martínez de la Torre, VER")] },
+    Area { prefix: 52233, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52235, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52236, descriptions: &[("en", "Oaxaca/Puebla")] },
+    Area { prefix: 52237, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52238, descriptions: &[("en", "Santiago Miahuatlan/Tehuacan, PUE"), ("es", "Santiago Miahuatlán/Tehuacán, PUE")] },
+    Area { prefix: 52241, descriptions: &[("en", "Tlaxcala")] },
+    Area { prefix: 52243, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52244, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52245, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52246, descriptions: &[("en", "Tlaxcala")] },
+    Area { prefix: 52247, descriptions: &[("en", "Huamantla/San Cosme Xalostoc, TLAX")] },
+    Area { prefix: 52248, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52249, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52271, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52272, descriptions: &[("en", "Maltrata/Orizaba, VER")] },
+    Area { prefix: 52273, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52274, descriptions: &[("en", "Oaxaca")] },
+    Area { prefix: 52275, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52276, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52278, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52279, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52281, descriptions: &[("en", "Loma Bonita, OAX")] },
+    Area { prefix: 52282, descriptions: &[("en", "Puebla/Veracruz")] },
+    Area { prefix: 52283, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52284, descriptions: &[("en", "Angel Rosario Cabada/Lerdo de Tejada, VER"), ("es", "Ángel Rosario Cabada/Lerdo de Tejada, VER")] },
+    Area { prefix: 52285, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52287, descriptions: &[("en", "Oaxaca")] },
+    Area { prefix: 52288, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52294, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52296, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52297, descriptions: &[("en", "Alvarado, VER")] },
+    Area { prefix: 52311, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52312, descriptions: &[("en", "Colima/Los Tepames, COL")] },
+    Area { prefix: 52313, descriptions: &[("en", "Colima")] },
+    Area { prefix: 52314, descriptions: &[("en", "Manzanillo/Pena Colorada, COL"), ("es", "Manzanillo/Peña Colorada, COL")] },
+    Area { prefix: 52315, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52316, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52317, descriptions: &[("en", "Autlan/El Chante, JAL"), ("es", "Autlán/El Chante, JAL")] },
+    Area { prefix: 52319, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52321, descriptions: &[("en", "El Grullo/El Limon, JAL")] },
+    Area { prefix: 52322, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52323, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52324, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52325, descriptions: &[("en", "Acaponeta, NAY")] },
+    Area { prefix: 52326, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52327, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52328, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52329, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52341, descriptions: &[("en", "Ciudad Guzman, JAL"), ("es", "Ciudad Guzmán, JAL")] },
+    Area { prefix: 52342, descriptions: &[("en", "Gomez Farias/Sayula, JAL"), ("es", "Gómez Farías/Sayula, JAL")] },
+    Area { prefix: 52343, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52344, descriptions: &[("en", "Mexticacan/Yahualica, JAL")] },
+    Area { prefix: 52345, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52346, descriptions: &[("en", "Jalisco/Zacatecas")] },
+    Area { prefix: 52347, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52348, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52349, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52351, descriptions: &[("en", "Ario de Rayon/Zamora, MICH"), ("es", "Ario de Rayón/Zamora, MICH")] },
+    Area { prefix: 52352, descriptions: &[("en", "La Piedad, MICH")] },
+    Area { prefix: 52353, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52354, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52355, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52356, descriptions: &[("en", "Tanhuato/Yurecuaro, MICH"), ("es", "Tanhuato/Yurécuaro, MICH")] },
+    Area { prefix: 52357, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52358, descriptions: &[("en", "Tamazula/Zapoltitic, JAL"), ("es", "Tamazula/Zapotiltic, JAL")] },
+    Area { prefix: 52375, descriptions: &[("en", "Ameca, JAL")] },
+    Area { prefix: 52377, descriptions: &[("en", "Cocula/Estipac, JAL")] },
+    Area { prefix: 52381, descriptions: &[("en", "Cojumatlan/San Jose de Gracia, MICH")] },
+    Area { prefix: 52382, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52383, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52384, descriptions: &[("en", "Tala/Teuchitlan, JAL")] },
+    Area { prefix: 52385, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52386, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52387, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52388, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52389, descriptions: &[("en", "Nayarit")] },
+    Area { prefix: 52391, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52392, descriptions: &[("en", "Jamay/Ocotlan, JAL"), ("es", "Jamay/Ocotlán, JAL")] },
+    Area { prefix: 52393, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52394, descriptions: &[("en", "Cotija de la Paz, MICH")] },
+    Area { prefix: 52395, descriptions: &[("en", "Jalisco")] },
+    Area { prefix: 52411, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52412, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52413, descriptions: &[("en", "Apaseo el Alto/Apaseo el Grande, GTO")] },
+    Area { prefix: 52414, descriptions: &[("en", "Tequisquiapan, QRO")] },
+    Area { prefix: 52415, descriptions: &[("en", "San Miguel Allende, GTO")] },
+    Area { prefix: 52417, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52418, descriptions: &[("en", "Dolores Hidalgo/San Diego de la Union, GTO"), ("es", "Dolores Hidalgo/San Diego de la Unión, GTO")] },
+    Area { prefix: 52419, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52421, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52422, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52423, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52424, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52425, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52426, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52427, descriptions: &[("en", "Mexico/Quintana Roo"), ("es", "México/Quintana Roo")] },
+    Area { prefix: 52428, descriptions: &[("en", "Ocampo/San Felipe, GTO")] },
+    Area { prefix: 52429, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52431, descriptions: &[("en", "Jalostotitlan/Villa Obregon, JAL"), ("es", "Jalostotitlán/Villa Obregón, JAL")] },
+    Area { prefix: 52432, descriptions: &[("en", "Ciudad Manuel Doblado/Romita, GTO")] },
+    Area { prefix: 52433, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52434, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52435, descriptions: &[("en", "Huetamo/San Lucas, MICH")] },
+    Area { prefix: 52436, descriptions: &[("en", "Zacapu, MICH")] },
+    Area { prefix: 52437, descriptions: &[("en", "Jalisco/Zacatecas")] },
+    Area { prefix: 52438, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52441, descriptions: &[("en", "Queretaro"), ("es", "Querétaro")] },
+    Area { prefix: 52442, descriptions: &[("en", "Queretaro"), ("es", "Querétaro")] },
+    Area { prefix: 52443, descriptions: &[("en", "Morelia/Tarimbaro, MICH"), ("es", "Morelia/Tarímbaro, MICH")] },
+    Area { prefix: 52444, descriptions: &[("en", "San Luis Potosi, SLP"), ("es", "San Luis Potosí, SLP")] },
+    Area { prefix: 52445, descriptions: &[("en", "Moroleon, GTO")] },
+    Area { prefix: 52447, descriptions: &[("en", "Contepec/Maravatio, MICH"), ("es", "Contepec/Maravatío, MICH")] },
+    Area { prefix: 52448, descriptions: &[("en", "Queretaro"), ("es", "Querétaro")] },
+    Area { prefix: 52449, descriptions: &[("en", "Aguascalientes/Jesus Maria, AGS"), ("es", "Aguascalientes/Jesús María, AGS")] },
+    Area { prefix: 52450, descriptions: &[("en", "Morelia")] },
+    Area { prefix: 52451, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52452, descriptions: &[("en", "Nuevo San Juan Parangaricutiro/Uruapan, MICH")] },
+    Area { prefix: 52453, descriptions: &[("en", "Apatzingan, MICH"), ("es", "Apatzingán, MICH")] },
+    Area { prefix: 52454, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52455, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52456, descriptions: &[("en", "Valle de Santiago, GTO")] },
+    Area { prefix: 52457, descriptions: &[("en", "Jalisco/Zacatecas")] },
+    Area { prefix: 52458, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52459, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52461, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52462, descriptions: &[("en", "Irapuato, GTO")] },
+    Area { prefix: 52463, descriptions: &[("en", "Jalpa/Tabasco, ZAC")] },
+    Area { prefix: 52464, descriptions: &[("en", "Salamanca, GTO")] },
+    Area { prefix: 52465, descriptions: &[("en", "Aguascalientes")] },
+    Area { prefix: 52466, descriptions: &[("en", "Guanajuato")] },
+    Area { prefix: 52467, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52468, descriptions: &[("en", "San Luis de la Paz, GTO")] },
+    Area { prefix: 52469, descriptions: &[("en", "Buenavista de Cortez/Penjamo, GTO"), ("es", "Buenavista de Cortés/Pénjamo, GTO")] },
+    Area { prefix: 52471, descriptions: &[("en", "Purepero/Tlazazalca, MICH")] },
+    Area { prefix: 52472, descriptions: &[("en", "Silao, GTO")] },
+    Area { prefix: 52473, descriptions: &[("en", "Guanajuato, GTO")] },
+    Area { prefix: 52474, descriptions: &[("en", "Lagos de Moreno/Paso de Cuarenta, JAL")] },
+    Area { prefix: 52475, descriptions: &[("en", "Bajio de San Jose/Encarnacion de Diaz, JAL"), ("es", "Bajío de San José/Encarnación de Diaz, JAL")] },
+    Area { prefix: 52476, descriptions: &[("en", "San Francisco Del Rincon, GTO"), ("es", "San Francisco del Rincón, GTO")] },
+    Area { prefix: 52477, descriptions: &[("en", "Leon, GTO"), ("es", "León, GTO")] },
+    Area { prefix: 52478, descriptions: &[("en", "Calera Victor Rosales, ZAC")] },
+    Area { prefix: 52481, descriptions: &[("en", "Ciudad Valles, SLP")] },
+    Area { prefix: 52492, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52493, descriptions: &[("en", "Fresnillo, ZAC")] },
+    Area { prefix: 52494, descriptions: &[("en", "Jerez de Garcia Salinas, ZAC"), ("es", "Jerez de García Salinas, ZAC")] },
+    Area { prefix: 52495, descriptions: &[("en", "Aguascalientes/Jalisco")] },
+    Area { prefix: 52496, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52498, descriptions: &[("en", "Zacatecas")] },
+    Area { prefix: 52499, descriptions: &[("en", "Jalisco/Zacatecas")] },
+    Area { prefix: 52591, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52592, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52593, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52594, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52595, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52596, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52599, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52612, descriptions: &[("en", "La Paz/Todos Santos, BCS")] },
+    Area { prefix: 52613, descriptions: &[("en", "Baja California Sur")] },
+    Area { prefix: 52614, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52615, descriptions: &[("en", "Baja California Sur")] },
+    Area { prefix: 52616, descriptions: &[("en", "Baja California")] },
+    Area { prefix: 52618, descriptions: &[("en", "Colonia Hidalgo/Durango, DGO")] },
+    Area { prefix: 52621, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52622, descriptions: &[("en", "Guaymas/San Carlos, SON")] },
+    Area { prefix: 52623, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52624, descriptions: &[("en", "Baja California Sur")] },
+    Area { prefix: 52625, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52626, descriptions: &[("en", "Ojinaga, CHIH")] },
+    Area { prefix: 52627, descriptions: &[("en", "Parral, CHIH")] },
+    Area { prefix: 52628, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52629, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52631, descriptions: &[("en", "Nogales, SON")] },
+    Area { prefix: 52632, descriptions: &[("en", "Imuris/Magdalena, SON"), ("es", "Ímuris/Magdalena, SON")] },
+    Area { prefix: 52633, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52634, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52635, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52636, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52637, descriptions: &[("en", "Altar/Caborca, SON")] },
+    Area { prefix: 52638, descriptions: &[("en", "Puerto Penasco, SON")] },
+    Area { prefix: 52639, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52641, descriptions: &[("en", "Benjamin Hill/Santa Ana, SON"), ("es", "Benjamín Hill/Santa Ana, SON")] },
+    Area { prefix: 52642, descriptions: &[("en", "Navojoa/Pueblo Mayo, SON")] },
+    Area { prefix: 52643, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52644, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52645, descriptions: &[("en", "Cananea, SON")] },
+    Area { prefix: 52646, descriptions: &[("en", "Baja California")] },
+    Area { prefix: 52647, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52648, descriptions: &[("en", "Boquilla/Ciudad Camargo, CHIH")] },
+    Area { prefix: 52649, descriptions: &[("en", "Chihuahua/Durango")] },
+    Area { prefix: 52651, descriptions: &[("en", "Sonoita, SON")] },
+    Area { prefix: 52652, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52653, descriptions: &[("en", "Luis B. Sanchez/San Luis Rio Colorado, SON"), ("es", "Luis B. Sánchez/San Luis Río Colorado, SON")] },
+    Area { prefix: 52656, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52657, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52658, descriptions: &[("en", "Baja California")] },
+    Area { prefix: 52659, descriptions: &[("en", "Chihuahua")] },
+    Area { prefix: 52660, descriptions: &[("en", "Culiacan"), ("es", "Culiacán")] },
+    Area { prefix: 52661, descriptions: &[("en", "Primo Tapia/Rosarito, BCN")] },
+    Area { prefix: 52662, descriptions: &[("en", "Sonora")] },
+    Area { prefix: 52665, descriptions: &[("en", "Tecate, BCN")] },
+    Area { prefix: 52667, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52668, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52669, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52671, descriptions: &[("en", "Durango")] },
+    Area { prefix: 52672, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52673, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52674, descriptions: &[("en", "Durango")] },
+    Area { prefix: 52675, descriptions: &[("en", "Durango")] },
+    Area { prefix: 52676, descriptions: &[("en", "Durango")] },
+    Area { prefix: 52677, descriptions: &[("en", "Durango")] },
+    Area { prefix: 52686, descriptions: &[("en", "Baja California")] },
+    Area { prefix: 52687, descriptions: &[("en", "Sinaloa")] },
+    Area { prefix: 52711, descriptions: &[("en", "Mexico/Michoacan"), ("es", "México/Michoacán")] },
+    Area { prefix: 52712, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52713, descriptions: &[("en", "Santiago Tianguistenco, MEX")] },
+    Area { prefix: 52714, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52715, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52716, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52717, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52718, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52719, descriptions: &[("en", "San Francisco Xonacatlan/Temoaya, MEX"), ("es", "San Francisco Xonacatlán/Temoaya, MEX")] },
+    Area { prefix: 52720, descriptions: &[("en", "Toluca")] },
+    Area { prefix: 52721, descriptions: &[("en", "Ixtapan de la Sal, MEX")] },
+    Area { prefix: 52722, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52723, descriptions: &[("en", "Coatepec Harinas, MEX")] },
+    Area { prefix: 52724, descriptions: &[("en", "Luvianos/Tejupilco de Hidalgo, MEX")] },
+    Area { prefix: 52725, descriptions: &[("en", "Almoloya de Juarez/Santa Maria Del Monte, MEX"), ("es", "Almoloya de Juárez/Santa María del Monte, MEX")] },
+    Area { prefix: 52726, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52727, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52728, descriptions: &[("en", "Lerma/Santa Maria Atarasquillo, MEX"), ("es", "Lerma/Santa María Atarasquillo, MEX")] },
+    Area { prefix: 52729, descriptions: &[("en", "Estado de Mexico"), ("es", "Estado de México")] },
+    Area { prefix: 52731, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52732, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52733, descriptions: &[("en", "Iguala, GRO")] },
+    Area { prefix: 52734, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52735, descriptions: &[("en", "Cuautla/Jonacatepec, MOR")] },
+    Area { prefix: 52736, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52737, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52738, descriptions: &[("en", "Mixquiahuala/Tepatepec, HGO")] },
+    Area { prefix: 52739, descriptions: &[("en", "Huitzilac/Tepoztlan, MOR")] },
+    Area { prefix: 52741, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52742, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52743, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52744, descriptions: &[("en", "Acapulco/Xaltianguis, GRO")] },
+    Area { prefix: 52745, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52746, descriptions: &[("en", "Puebla/Veracruz")] },
+    Area { prefix: 52747, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52748, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52749, descriptions: &[("en", "Calpulalpan, TLAX")] },
+    Area { prefix: 52751, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52753, descriptions: &[("en", "Michoacan"), ("es", "Michoacán")] },
+    Area { prefix: 52754, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52755, descriptions: &[("en", "Ixtapa/Zihuatanejo, GRO")] },
+    Area { prefix: 52756, descriptions: &[("en", "Chilapa/Olinala, GRO"), ("es", "Chilapa/Olinalá, GRO")] },
+    Area { prefix: 52757, descriptions: &[("en", "Huamuxtitlan/Tlapa de Comonfort, GRO")] },
+    Area { prefix: 52758, descriptions: &[("en", "Petatlan/San Jeronimito, GRO")] },
+    Area { prefix: 52759, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52761, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52762, descriptions: &[("en", "Taxco, GRO")] },
+    Area { prefix: 52763, descriptions: &[("en", "Tezontepec de Aldama/Tlahuelilpan, HGO")] },
+    Area { prefix: 52764, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52765, descriptions: &[("en", "Alamo Temapache/Alazan/Potrero Del Llano, VER"), ("es", "Álamo Temapache/Alazán/Potrero del Llano, VER")] },
+    Area { prefix: 52766, descriptions: &[("en", "Gutierrez Zamora/Tecolutla, VER"), ("es", "Gutiérrez Zamora/Tecolutla, VER")] },
+    Area { prefix: 52767, descriptions: &[("en", "Guerrero")] },
+    Area { prefix: 52768, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52769, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52770, descriptions: &[("en", "Cuernavaca/Emiliano Zapata/Temixco/Xochitepec/Jiutepec")] },
+    Area { prefix: 52771, descriptions: &[("en", "Pachuca/Real Del Monte, HGO"), ("es", "Pachuca/Real del Monte, HGO")] },
+    Area { prefix: 52772, descriptions: &[("en", "Actopan, HGO")] },
+    Area { prefix: 52773, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52774, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52775, descriptions: &[("en", "Tulancingo, HGO")] },
+    Area { prefix: 52776, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52777, descriptions: &[("en", "Morelos")] },
+    Area { prefix: 52778, descriptions: &[("en", "Hidalgo")] },
+    Area { prefix: 52779, descriptions: &[("en", "Tizayuca, HGO")] },
+    Area { prefix: 52781, descriptions: &[("en", "Coyuca de Benitez/San Jeronimo de Juarez, GRO"), ("es", "Coyuca de Benítez/San Jerónimo de Juárez, GRO")] },
+    Area { prefix: 52782, descriptions: &[("en", "Poza Rica, VER")] },
+    Area { prefix: 52783, descriptions: &[("en", "Tuxpan, VER")] },
+    Area { prefix: 52784, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52785, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52786, descriptions: &[("en", "Ciudad Hidalgo/Tuxpan, MICH")] },
+    Area { prefix: 52789, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52791, descriptions: &[("en", "Ciudad Sahagun, HGO"), ("es", "Ciudad Sahagún, HGO")] },
+    Area { prefix: 52797, descriptions: &[("en", "Puebla")] },
+    Area { prefix: 52821, descriptions: &[("en", "Hualahuises/Linares, NL")] },
+    Area { prefix: 52823, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52824, descriptions: &[("en", "Sabinas Hidalgo, NL")] },
+    Area { prefix: 52825, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52826, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52828, descriptions: &[("en", "Cadereyta, NL")] },
+    Area { prefix: 52829, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52831, descriptions: &[("en", "Ciudad Mante/Los Aztecas, TAMPS")] },
+    Area { prefix: 52832, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52833, descriptions: &[("en", "Tampico, TAMPS")] },
+    Area { prefix: 52834, descriptions: &[("en", "Ciudad Victoria, TAMPS")] },
+    Area { prefix: 52835, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52836, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52841, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52842, descriptions: &[("en", "Coahuila")] },
+    Area { prefix: 52844, descriptions: &[("en", "Saltillo, COAH")] },
+    Area { prefix: 52845, descriptions: &[("en", "Ebano/Ponciano Arriaga, SLP")] },
+    Area { prefix: 52846, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52861, descriptions: &[("en", "Nueva Rosita/Sabinas, COAH")] },
+    Area { prefix: 52862, descriptions: &[("en", "Coahuila")] },
+    Area { prefix: 52864, descriptions: &[("en", "Coahuila")] },
+    Area { prefix: 52866, descriptions: &[("en", "Castanos/Monclova, COAH"), ("es", "Castaños/Monclova, COAH")] },
+    Area { prefix: 52867, descriptions: &[("en", "Nuevo Laredo/Tamaulipas"), ("es", "Nuevo León/Tamaulipas")] },
+    Area { prefix: 52868, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52869, descriptions: &[("en", "Cuatro Cienegas/San Buenaventura, COAH"), ("es", "Cuatro Ciénegas/San Buenaventura, COAH")] },
+    Area { prefix: 52870, descriptions: &[("en", "Coahuila/Durango")] },
+    Area { prefix: 52871, descriptions: &[("en", "Coahuila")] },
+    Area { prefix: 52872, descriptions: &[("en", "Coahuila/Durango")] },
+    Area { prefix: 52873, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52877, descriptions: &[("en", "Ciudad Acuna, COAH"), ("es", "Ciudad Acuña, COAH")] },
+    Area { prefix: 52878, descriptions: &[("en", "Piedras Negras, COAH")] },
+    Area { prefix: 52891, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52892, descriptions: &[("en", "Nuevo Leon"), ("es", "Nuevo León")] },
+    Area { prefix: 52894, descriptions: &[("en", "Santa Apolonia/Valle Hermoso, TAMPS")] },
+    Area { prefix: 52897, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52899, descriptions: &[("en", "Tamaulipas")] },
+    Area { prefix: 52913, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52914, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52916, descriptions: &[("en", "Chiapas")] },
+    Area { prefix: 52917, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52918, descriptions: &[("en", "Chiapas")] },
+    Area { prefix: 52919, descriptions: &[("en", "Chiapas")] },
+    Area { prefix: 52921, descriptions: &[("en", "Coatzacoalcos/Ixhuatlan Del Sureste, VER"), ("es", "Coatzacoalcos/Ixhuatlán del Sureste, VER")] },
+    Area { prefix: 52922, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52923, descriptions: &[("en", "Tabasco/Veracruz")] },
+    Area { prefix: 52924, descriptions: &[("en", "Veracruz")] },
+    Area { prefix: 52932, descriptions: &[("en", "Chiapas/Tabasco")] },
+    Area { prefix: 52933, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52934, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52936, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52937, descriptions: &[("en", "Cardenas, TAB"), ("es", "Cárdenas, TAB")] },
+    Area { prefix: 52938, descriptions: &[("en", "Ciudad Del Carmen, CAMP"), ("es", "Ciudad del Carmen, CAMP")] },
+    Area { prefix: 52960, descriptions: &[("en", "Tuxtla Gutierrez")] },
+    Area { prefix: 52966, descriptions: &[("en", "Arriaga/Tonala, CHIS"), ("es", "Arriaga/Tonalá, CHIS")] },
+    Area { prefix: 52967, descriptions: &[("en", "San Cristobal de las Casas, CHIS"), ("es", "San Cristóbal de las Casas, CHIS")] },
+    Area { prefix: 52969, descriptions: &[("en", "Flamboyanes/Yucalpeten, YUC")] },
+    Area { prefix: 52981, descriptions: &[("en", "Campeche, CAMP")] },
+    Area { prefix: 52982, descriptions: &[("en", "Campeche")] },
+    Area { prefix: 52983, descriptions: &[("en", "Quintana Roo")] },
+    Area { prefix: 52984, descriptions: &[("en", "Quintana Roo")] },
+    Area { prefix: 52985, descriptions: &[("en", "Yucatan"), ("es", "Yucatán")] },
+    Area { prefix: 52986, descriptions: &[("en", "Yucatan"), ("es", "Yucatán")] },
+    Area { prefix: 52987, descriptions: &[("en", "Cozumel, QRO")] },
+    Area { prefix: 52988, descriptions: &[("en", "Yucatan"), ("es", "Yucatán")] },
+    Area { prefix: 52990, descriptions: &[("en", "Merida")] },
+    Area { prefix: 52991, descriptions: &[("en", "Yucatan"), ("es", "Yucatán")] },
+    Area { prefix: 52992, descriptions: &[("en", "Chiapas")] },
+    Area { prefix: 52993, descriptions: &[("en", "Tabasco")] },
+    Area { prefix: 52994, descriptions: &[("en", "Oaxaca")] },
+    Area { prefix: 52995, descriptions: &[("en", "Magdalena Tequisistlan/Santa Maria Jalapa Del Marquez, OAX"), ("es", "Magdalena Tequisistlán/Santa Maria Jalapa del Marqués, OAX")] },
+    Area { prefix: 52996, descriptions: &[("en", "Campeche")] },
+    Area { prefix: 52997, descriptions: &[("en", "Yucatan"), ("es", "Yucatán")] },
+    Area { prefix: 52998, descriptions: &[("en", "Quintana Roo")] },
+    Area { prefix: 52999, descriptions: &[("en", "Conkal/Merida, YUC"), ("es", "Conkal/Mérida, YUC")] },
+    Area { prefix: 54221, descriptions: &[("en", "La Plata, Buenos Aires")] },
+    Area { prefix: 54223, descriptions: &[("en", "Mar del Plata, Buenos Aires")] },
+    Area { prefix: 54236, descriptions: &[("en", "Junín, Buenos Aires")] },
+    Area { prefix: 54237, descriptions: &[("en", "Moreno, Buenos Aires")] },
+    Area { prefix: 54249, descriptions: &[("en", "Tandil, Buenos Aires")] },
+    Area { prefix: 54260, descriptions: &[("en", "San Rafael, Mendoza")] },
+    Area { prefix: 54261, descriptions: &[("en", "Mendoza, Mendoza")] },
+    Area { prefix: 54263, descriptions: &[("en", "San Martín, Mendoza")] },
+    Area { prefix: 54266, descriptions: &[("en", "San Luis, San Luis")] },
+    Area { prefix: 54291, descriptions: &[("en", "Bahía Blanca, Buenos Aires")] },
+    Area { prefix: 54299, descriptions: &[("en", "Neuquén, Neuquén")] },
+    Area { prefix: 54336, descriptions: &[("en", "San Nicolás, Buenos Aires")] },
+    Area { prefix: 54341, descriptions: &[("en", "Rosario, Santa Fe")] },
+    Area { prefix: 54342, descriptions: &[("en", "Santa Fe, Santa Fe")] },
+    Area { prefix: 54351, descriptions: &[("en", "Córdoba, Córdoba")] },
+    Area { prefix: 54362, descriptions: &[("en", "Resistencia, Chaco")] },
+    Area { prefix: 54364, descriptions: &[("en", "Presidencia Roque Sáenz Peña, Chaco")] },
+    Area { prefix: 54370, descriptions: &[("en", "Formosa, Formosa")] },
+    Area { prefix: 54376, descriptions: &[("en", "Posadas, Misiones")] },
+    Area { prefix: 54379, descriptions: &[("en", "Corrientes, Corrientes")] },
+    Area { prefix: 54380, descriptions: &[("en", "La Rioja, La Rioja")] },
+    Area { prefix: 54381, descriptions: &[("en", "San Miguel de Tucumán, Tucumán")] },
+    Area { prefix: 55312, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 55314, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 55315, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 55317, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 55319, descriptions: &[("en", "Minas Gerais")] },
+    Area { prefix: 55482, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55483, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55484, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55485, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55487, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55489, descriptions: &[("en", "Santa Catarina")] },
+    Area { prefix: 55512, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 55514, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 55515, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 55517, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 55519, descriptions: &[("en", "Rio Grande do Sul")] },
+    Area { prefix: 56211, descriptions: &[("en", "Santiago, Metropolitan Region"), ("es", "Santiago, Región Metropolitana")] },
+    Area { prefix: 56530, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56531, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56533, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56534, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56535, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56536, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56537, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56538, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 56539, descriptions: &[("en", "Ovalle, Coquimbo")] },
+    Area { prefix: 58234, descriptions: &[("en", "Miranda")] },
+    Area { prefix: 58235, descriptions: &[("en", "Anzoátegui/Bolívar/Guárico")] },
+    Area { prefix: 58237, descriptions: &[("en", "Federal Dependencies"), ("es", "Dependencias Federales")] },
+    Area { prefix: 58238, descriptions: &[("en", "Guárico")] },
+    Area { prefix: 58239, descriptions: &[("en", "Miranda")] },
+    Area { prefix: 58240, descriptions: &[("en", "Apure/Barinas")] },
+    Area { prefix: 58241, descriptions: &[("en", "Carabobo")] },
+    Area { prefix: 58242, descriptions: &[("en", "Carabobo")] },
+    Area { prefix: 58243, descriptions: &[("en", "Aragua/Carabobo")] },
+    Area { prefix: 58244, descriptions: &[("en", "Aragua")] },
+    Area { prefix: 58245, descriptions: &[("en", "Carabobo")] },
+    Area { prefix: 58246, descriptions: &[("en", "Aragua/Guárico")] },
+    Area { prefix: 58247, descriptions: &[("en", "Apure/Barinas/Guárico")] },
+    Area { prefix: 58248, descriptions: &[("en", "Amazonas")] },
+    Area { prefix: 58249, descriptions: &[("en", "Carabobo")] },
+    Area { prefix: 58251, descriptions: &[("en", "Lara/Yaracuy")] },
+    Area { prefix: 58252, descriptions: &[("en", "Lara")] },
+    Area { prefix: 58253, descriptions: &[("en", "Lara/Yaracuy")] },
+    Area { prefix: 58254, descriptions: &[("en", "Yaracuy")] },
+    Area { prefix: 58255, descriptions: &[("en", "Portuguesa")] },
+    Area { prefix: 58256, descriptions: &[("en", "Portuguesa")] },
+    Area { prefix: 58257, descriptions: &[("en", "Portuguesa")] },
+    Area { prefix: 58258, descriptions: &[("en", "Cojedes")] },
+    Area { prefix: 58259, descriptions: &[("en", "Falcón")] },
+    Area { prefix: 58261, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58262, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58263, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58264, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58265, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58266, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58267, descriptions: &[("en", "Zulia")] },
+    Area { prefix: 58268, descriptions: &[("en", "Falcón")] },
+    Area { prefix: 58269, descriptions: &[("en", "Falcón")] },
+    Area { prefix: 58271, descriptions: &[("en", "Mérida/Trujillo/Zulia")] },
+    Area { prefix: 58272, descriptions: &[("en", "Trujillo")] },
+    Area { prefix: 58273, descriptions: &[("en", "Barinas")] },
+    Area { prefix: 58274, descriptions: &[("en", "Mérida")] },
+    Area { prefix: 58275, descriptions: &[("en", "Táchira/Mérida/Zulia"), ("es", "Mérida/Táchira/Zulia")] },
+    Area { prefix: 58276, descriptions: &[("en", "Táchira")] },
+    Area { prefix: 58277, descriptions: &[("en", "Táchira/Mérida"), ("es", "Mérida/Táchira")] },
+    Area { prefix: 58278, descriptions: &[("en", "Apure/Barinas")] },
+    Area { prefix: 58279, descriptions: &[("en", "Falcón")] },
+    Area { prefix: 58281, descriptions: &[("en", "Anzoátegui")] },
+    Area { prefix: 58282, descriptions: &[("en", "Anzoátegui")] },
+    Area { prefix: 58283, descriptions: &[("en", "Anzoátegui")] },
+    Area { prefix: 58284, descriptions: &[("en", "Bolívar")] },
+    Area { prefix: 58285, descriptions: &[("en", "Anzoátegui/Bolívar")] },
+    Area { prefix: 58286, descriptions: &[("en", "Anzoátegui/Bolívar")] },
+    Area { prefix: 58287, descriptions: &[("en", "Delta Amacuro/Monagas")] },
+    Area { prefix: 58288, descriptions: &[("en", "Bolívar")] },
+    Area { prefix: 58289, descriptions: &[("en", "Bolívar")] },
+    Area { prefix: 58291, descriptions: &[("en", "Monagas")] },
+    Area { prefix: 58292, descriptions: &[("en", "Anzoátegui/Monagas")] },
+    Area { prefix: 58293, descriptions: &[("en", "Sucre")] },
+    Area { prefix: 58294, descriptions: &[("en", "Sucre")] },
+    Area { prefix: 58295, descriptions: &[("en", "Nueva Esparta")] },
+    Area { prefix: 58296, descriptions: &[("en", "Amazonas")] },
+    Area { prefix: 59322, descriptions: &[("en", "Pichincha")] },
+    Area { prefix: 59323, descriptions: &[("en", "Cotopaxi/Tungurahua/Chimborazo/Bolívar/Pastaza")] },
+    Area { prefix: 59326, descriptions: &[("en", "Carchi/Imbabura/Esmeraldas/Sucumbíos/Napo/Orellana")] },
+    Area { prefix: 59327, descriptions: &[("en", "Azuay/Cañar/Morona Santiago")] },
+    Area { prefix: 59344, descriptions: &[("en", "Guayas")] },
+    Area { prefix: 59345, descriptions: &[("en", "Manabí/Los Ríos/Galápagos")] },
+    Area { prefix: 59347, descriptions: &[("en", "Loja/El Oro/Zamora Chinchipe")] },
+    Area { prefix: 59521, descriptions: &[("en", "Fernando De La Mora, Lambare, Limpio, Luque, Mariano Roque Alonso, San Antonio, Valle Pucu and Villa Elisa")] },
+    Area { prefix: 59524, descriptions: &[("en", "Ita")] },
+    Area { prefix: 59525, descriptions: &[("en", "Villeta")] },
+    Area { prefix: 59526, descriptions: &[("en", "Villa Hayes")] },
+    Area { prefix: 59528, descriptions: &[("en", "Capiata")] },
+    Area { prefix: 59531, descriptions: &[("en", "Concepcion")] },
+    Area { prefix: 59532, descriptions: &[("en", "Horqueta")] },
+    Area { prefix: 59533, descriptions: &[("en", "Loreto")] },
+    Area { prefix: 59535, descriptions: &[("en", "Valle Mi")] },
+    Area { prefix: 59536, descriptions: &[("en", "Pedro Juan Caballero")] },
+    Area { prefix: 59537, descriptions: &[("en", "Capitan Bado")] },
+    Area { prefix: 59538, descriptions: &[("en", "Bella Vista Norte")] },
+    Area { prefix: 59539, descriptions: &[("en", "Yby Ja'U")] },
+    Area { prefix: 59541, descriptions: &[("en", "Itacurubi Del Rosario")] },
+    Area { prefix: 59542, descriptions: &[("en", "San Pedro Del Ycua Mandyju")] },
+    Area { prefix: 59543, descriptions: &[("en", "San Estanislao")] },
+    Area { prefix: 59544, descriptions: &[("en", "Villa Del Rosario")] },
+    Area { prefix: 59546, descriptions: &[("en", "Salto Del Guaira")] },
+    Area { prefix: 59547, descriptions: &[("en", "Puente Kyha")] },
+    Area { prefix: 59548, descriptions: &[("en", "Curuguaty")] },
+    Area { prefix: 59561, descriptions: &[("en", "Presidente Franco")] },
+    Area { prefix: 59564, descriptions: &[("en", "Cargil")] },
+    Area { prefix: 59571, descriptions: &[("en", "Capitan Miranda")] },
+    Area { prefix: 59572, descriptions: &[("en", "Ayolas")] },
+    Area { prefix: 59573, descriptions: &[("en", "San Cosme")] },
+    Area { prefix: 59575, descriptions: &[("en", "Hoenau")] },
+    Area { prefix: 59581, descriptions: &[("en", "San Juan Bautista / Misiones")] },
+    Area { prefix: 59582, descriptions: &[("en", "San Ignacio / Misiones")] },
+    Area { prefix: 59583, descriptions: &[("en", "Villa Florida")] },
+    Area { prefix: 59585, descriptions: &[("en", "Santa Rosa / Misiones")] },
+    Area { prefix: 59586, descriptions: &[("en", "Pilar")] },
+    Area { prefix: 59842, descriptions: &[("en", "San Carlos")] },
+    Area { prefix: 59971, descriptions: &[("en", "Bonaire")] },
+    Area { prefix: 59972, descriptions: &[("en", "Bonaire")] },
+    Area { prefix: 59975, descriptions: &[("en", "Bonaire")] },
+    Area { prefix: 61230, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61231, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61232, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61233, descriptions: &[("en", "Gosford")] },
+    Area { prefix: 61234, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61235, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61236, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61237, descriptions: &[("en", "New South Wales")] },
+    Area { prefix: 61241, descriptions: &[("en", "Clarence Town/Dungog/Eccleston/East Gresford/Maitland/Mulbring/Karuah/Laguna/Wards River/Wootton/Tea Gardens/Swansea/Newcastle/Nelson Bay/Raymond Terrace/Stroud/Newcastle")] },
+    Area { prefix: 61261, descriptions: &[("en", "Canberra")] },
+    Area { prefix: 61262, descriptions: &[("en", "Canberra")] },
+    Area { prefix: 61272, descriptions: &[("en", "Sydney")] },
+    Area { prefix: 61290, descriptions: &[("en", "Sydney")] },
+    Area { prefix: 61292, descriptions: &[("en", "Sydney")] },
+    Area { prefix: 61294, descriptions: &[("en", "Sydney")] },
+    Area { prefix: 61299, descriptions: &[("en", "Sydney")] },
+    Area { prefix: 61349, descriptions: &[("en", "Cowes/Cranbourne/Emerald/Healesville/Koo Wee Rup/Marysville/Mornington/Pakenham/Red Hill/Rosebud/Tankerton/Warburton")] },
+    Area { prefix: 61390, descriptions: &[("en", "Melbourne")] },
+    Area { prefix: 61855, descriptions: &[("en", "Bakers Hill/Bindoon/Bullsbrook East/Byford/Dwellingup/Gidgegannup/Gingin/Guilderton/Mandurah/Mount Helena/Mount Wells/Pinjarra/Rockingham/Toodyay/Yanchep")] },
+    Area { prefix: 62231, descriptions: &[("en", "Cirebon")] },
+    Area { prefix: 62232, descriptions: &[("en", "Kuningan")] },
+    Area { prefix: 62233, descriptions: &[("en", "Majalengka")] },
+    Area { prefix: 62234, descriptions: &[("en", "Indramayu")] },
+    Area { prefix: 62251, descriptions: &[("en", "Bogor")] },
+    Area { prefix: 62252, descriptions: &[("en", "Rangkasbitung")] },
+    Area { prefix: 62253, descriptions: &[("en", "Pandeglang")] },
+    Area { prefix: 62254, descriptions: &[("en", "Serang/Merak")] },
+    Area { prefix: 62260, descriptions: &[("en", "Subang")] },
+    Area { prefix: 62261, descriptions: &[("en", "Sumedang")] },
+    Area { prefix: 62262, descriptions: &[("en", "Garut")] },
+    Area { prefix: 62263, descriptions: &[("en", "Cianjur")] },
+    Area { prefix: 62264, descriptions: &[("en", "Purwakarta/Cikampek")] },
+    Area { prefix: 62265, descriptions: &[("en", "Tasikmalaya/Banjar/Ciamis")] },
+    Area { prefix: 62266, descriptions: &[("en", "Sukabumi")] },
+    Area { prefix: 62267, descriptions: &[("en", "Karawang")] },
+    Area { prefix: 62271, descriptions: &[("en", "Surakarta/Sukoharjo/Karanganyar/Sragen")] },
+    Area { prefix: 62272, descriptions: &[("en", "Klaten")] },
+    Area { prefix: 62273, descriptions: &[("en", "Wonogiri")] },
+    Area { prefix: 62274, descriptions: &[("en", "Yogyakarta")] },
+    Area { prefix: 62275, descriptions: &[("en", "Purworejo")] },
+    Area { prefix: 62276, descriptions: &[("en", "Boyolali")] },
+    Area { prefix: 62280, descriptions: &[("en", "West Cilacap"), ("id", "Cilacap Barat")] },
+    Area { prefix: 62281, descriptions: &[("en", "Banyumas/Purbalingga")] },
+    Area { prefix: 62282, descriptions: &[("en", "East Cilacap"), ("id", "Cilacap Timur")] },
+    Area { prefix: 62283, descriptions: &[("en", "Tegal/Brebes")] },
+    Area { prefix: 62284, descriptions: &[("en", "Pemalang")] },
+    Area { prefix: 62285, descriptions: &[("en", "Pekalongan/Batang/Comal")] },
+    Area { prefix: 62286, descriptions: &[("en", "Banjarnegara/Wonosobo")] },
+    Area { prefix: 62287, descriptions: &[("en", "Kebumen/Karanganyar")] },
+    Area { prefix: 62289, descriptions: &[("en", "Bumiayu")] },
+    Area { prefix: 62291, descriptions: &[("en", "Demak/Jepara/Kudus")] },
+    Area { prefix: 62292, descriptions: &[("en", "Purwodadi")] },
+    Area { prefix: 62293, descriptions: &[("en", "Magelang/Mungkid/Temanggung")] },
+    Area { prefix: 62294, descriptions: &[("en", "Kendal")] },
+    Area { prefix: 62295, descriptions: &[("en", "Pati/Rembang")] },
+    Area { prefix: 62296, descriptions: &[("en", "Blora")] },
+    Area { prefix: 62297, descriptions: &[("en", "Karimun Jawa")] },
+    Area { prefix: 62298, descriptions: &[("en", "Salatiga/Ambarawa")] },
+    Area { prefix: 62321, descriptions: &[("en", "Mojokerto/Jombang")] },
+    Area { prefix: 62322, descriptions: &[("en", "Lamongan")] },
+    Area { prefix: 62323, descriptions: &[("en", "Sampang")] },
+    Area { prefix: 62324, descriptions: &[("en", "Pamekasan")] },
+    Area { prefix: 62325, descriptions: &[("en", "Sangkapura")] },
+    Area { prefix: 62326, descriptions: &[("en", "Masalembu Islands")] },
+    Area { prefix: 62327, descriptions: &[("en", "Kangean/Masalembu")] },
+    Area { prefix: 62328, descriptions: &[("en", "Sumenep")] },
+    Area { prefix: 62331, descriptions: &[("en", "Jember")] },
+    Area { prefix: 62332, descriptions: &[("en", "Bondowoso")] },
+    Area { prefix: 62333, descriptions: &[("en", "Banyuwangi")] },
+    Area { prefix: 62334, descriptions: &[("en", "Lumajang")] },
+    Area { prefix: 62335, descriptions: &[("en", "Probolinggo")] },
+    Area { prefix: 62336, descriptions: &[("en", "Jember")] },
+    Area { prefix: 62338, descriptions: &[("en", "Situbondo")] },
+    Area { prefix: 62341, descriptions: &[("en", "Malang/Batu")] },
+    Area { prefix: 62342, descriptions: &[("en", "Blitar")] },
+    Area { prefix: 62343, descriptions: &[("en", "Pasuruan")] },
+    Area { prefix: 62351, descriptions: &[("en", "Madiun/Magetan/Ngawi")] },
+    Area { prefix: 62352, descriptions: &[("en", "Ponorogo")] },
+    Area { prefix: 62353, descriptions: &[("en", "Bojonegoro")] },
+    Area { prefix: 62354, descriptions: &[("en", "Kediri")] },
+    Area { prefix: 62355, descriptions: &[("en", "Tulungagung/Trenggalek")] },
+    Area { prefix: 62356, descriptions: &[("en", "Rembang/Tuban")] },
+    Area { prefix: 62357, descriptions: &[("en", "Pacitan")] },
+    Area { prefix: 62358, descriptions: &[("en", "Nganjuk")] },
+    Area { prefix: 62361, descriptions: &[("en", "Denpasar")] },
+    Area { prefix: 62362, descriptions: &[("en", "Singaraja")] },
+    Area { prefix: 62363, descriptions: &[("en", "Amlapura")] },
+    Area { prefix: 62365, descriptions: &[("en", "Negara/Gilimanuk")] },
+    Area { prefix: 62366, descriptions: &[("en", "Klungkung/Bangli")] },
+    Area { prefix: 62368, descriptions: &[("en", "Baturiti")] },
+    Area { prefix: 62370, descriptions: &[("en", "Mataram/Praya")] },
+    Area { prefix: 62371, descriptions: &[("en", "Sumbawa")] },
+    Area { prefix: 62372, descriptions: &[("en", "Alas/Taliwang")] },
+    Area { prefix: 62373, descriptions: &[("en", "Dompu")] },
+    Area { prefix: 62374, descriptions: &[("en", "Bima")] },
+    Area { prefix: 62376, descriptions: &[("en", "Selong")] },
+    Area { prefix: 62380, descriptions: &[("en", "Kupang")] },
+    Area { prefix: 62381, descriptions: &[("en", "Ende")] },
+    Area { prefix: 62382, descriptions: &[("en", "Maumere")] },
+    Area { prefix: 62383, descriptions: &[("en", "Larantuka")] },
+    Area { prefix: 62384, descriptions: &[("en", "Bajawa")] },
+    Area { prefix: 62385, descriptions: &[("en", "Labuhanbajo/Ruteng")] },
+    Area { prefix: 62386, descriptions: &[("en", "Kalabahi")] },
+    Area { prefix: 62387, descriptions: &[("en", "Waingapu/Waikabubak")] },
+    Area { prefix: 62388, descriptions: &[("en", "Kefamenanu/Soe")] },
+    Area { prefix: 62389, descriptions: &[("en", "Atambua")] },
+    Area { prefix: 62401, descriptions: &[("en", "Kendari")] },
+    Area { prefix: 62402, descriptions: &[("en", "Baubau")] },
+    Area { prefix: 62403, descriptions: &[("en", "Raha")] },
+    Area { prefix: 62404, descriptions: &[("en", "Wanci")] },
+    Area { prefix: 62405, descriptions: &[("en", "Kolaka")] },
+    Area { prefix: 62408, descriptions: &[("en", "Unaaha")] },
+    Area { prefix: 62410, descriptions: &[("en", "Pangkep")] },
+    Area { prefix: 62411, descriptions: &[("en", "Makassar/Maros/Sungguminasa")] },
+    Area { prefix: 62413, descriptions: &[("en", "Bulukumba/Bantaeng")] },
+    Area { prefix: 62414, descriptions: &[("en", "Kepulauan Selayar")] },
+    Area { prefix: 62417, descriptions: &[("en", "Malino")] },
+    Area { prefix: 62418, descriptions: &[("en", "Takalar")] },
+    Area { prefix: 62419, descriptions: &[("en", "Jeneponto")] },
+    Area { prefix: 62420, descriptions: &[("en", "Enrekang")] },
+    Area { prefix: 62421, descriptions: &[("en", "Parepare/Pinrang")] },
+    Area { prefix: 62422, descriptions: &[("en", "Majene")] },
+    Area { prefix: 62423, descriptions: &[("en", "Makale/Rantepao")] },
+    Area { prefix: 62426, descriptions: &[("en", "Mamuju")] },
+    Area { prefix: 62427, descriptions: &[("en", "Barru")] },
+    Area { prefix: 62428, descriptions: &[("en", "Polewali")] },
+    Area { prefix: 62430, descriptions: &[("en", "Amurang")] },
+    Area { prefix: 62431, descriptions: &[("en", "Manado/Tomohon/Tondano")] },
+    Area { prefix: 62432, descriptions: &[("en", "Tahuna")] },
+    Area { prefix: 62434, descriptions: &[("en", "Kotamobagu")] },
+    Area { prefix: 62435, descriptions: &[("en", "Gorontalo")] },
+    Area { prefix: 62438, descriptions: &[("en", "Bitung")] },
+    Area { prefix: 62451, descriptions: &[("en", "Palu")] },
+    Area { prefix: 62452, descriptions: &[("en", "Poso")] },
+    Area { prefix: 62453, descriptions: &[("en", "Tolitoli")] },
+    Area { prefix: 62457, descriptions: &[("en", "Donggala")] },
+    Area { prefix: 62458, descriptions: &[("en", "Tentena")] },
+    Area { prefix: 62461, descriptions: &[("en", "Luwuk")] },
+    Area { prefix: 62462, descriptions: &[("en", "Banggai")] },
+    Area { prefix: 62463, descriptions: &[("en", "Bunta")] },
+    Area { prefix: 62464, descriptions: &[("en", "Ampana")] },
+    Area { prefix: 62465, descriptions: &[("en", "Kolonedale")] },
+    Area { prefix: 62471, descriptions: &[("en", "Palopo")] },
+    Area { prefix: 62473, descriptions: &[("en", "Masamba")] },
+    Area { prefix: 62474, descriptions: &[("en", "Malili")] },
+    Area { prefix: 62481, descriptions: &[("en", "Watampone")] },
+    Area { prefix: 62482, descriptions: &[("en", "Sinjai")] },
+    Area { prefix: 62484, descriptions: &[("en", "Watansoppeng")] },
+    Area { prefix: 62485, descriptions: &[("en", "Sengkang")] },
+    Area { prefix: 62511, descriptions: &[("en", "Banjarmasin")] },
+    Area { prefix: 62512, descriptions: &[("en", "Pelaihari")] },
+    Area { prefix: 62513, descriptions: &[("en", "Muara Teweh")] },
+    Area { prefix: 62517, descriptions: &[("en", "Kandangan/Barabai/Rantau/Negara")] },
+    Area { prefix: 62518, descriptions: &[("en", "Kotabaru/Batulicin")] },
+    Area { prefix: 62522, descriptions: &[("en", "Ampah")] },
+    Area { prefix: 62525, descriptions: &[("en", "Buntok")] },
+    Area { prefix: 62526, descriptions: &[("en", "Tamiang Layang/Tanjung")] },
+    Area { prefix: 62527, descriptions: &[("en", "Amuntai")] },
+    Area { prefix: 62528, descriptions: &[("en", "Purukcahu")] },
+    Area { prefix: 62531, descriptions: &[("en", "Sampit")] },
+    Area { prefix: 62532, descriptions: &[("en", "Pangkalan Bun")] },
+    Area { prefix: 62534, descriptions: &[("en", "Ketapang")] },
+    Area { prefix: 62536, descriptions: &[("en", "Palangkaraya/Kasongan")] },
+    Area { prefix: 62537, descriptions: &[("en", "Kuala Kurun")] },
+    Area { prefix: 62538, descriptions: &[("en", "Kuala Pembuang")] },
+    Area { prefix: 62539, descriptions: &[("en", "Kuala Kuayan")] },
+    Area { prefix: 62541, descriptions: &[("en", "Samarinda/Tenggarong")] },
+    Area { prefix: 62542, descriptions: &[("en", "Balikpapan")] },
+    Area { prefix: 62543, descriptions: &[("en", "Tanah Grogot")] },
+    Area { prefix: 62545, descriptions: &[("en", "Melak")] },
+    Area { prefix: 62548, descriptions: &[("en", "Bontang")] },
+    Area { prefix: 62549, descriptions: &[("en", "Sangatta")] },
+    Area { prefix: 62551, descriptions: &[("en", "Tarakan")] },
+    Area { prefix: 62552, descriptions: &[("en", "Tanjungselor")] },
+    Area { prefix: 62553, descriptions: &[("en", "Malinau")] },
+    Area { prefix: 62554, descriptions: &[("en", "Tanjung Redeb")] },
+    Area { prefix: 62556, descriptions: &[("en", "Nunukan")] },
+    Area { prefix: 62561, descriptions: &[("en", "Pontianak/Mempawah")] },
+    Area { prefix: 62562, descriptions: &[("en", "Singkawang/Sambas/Bengkayang")] },
+    Area { prefix: 62563, descriptions: &[("en", "Ngabang")] },
+    Area { prefix: 62564, descriptions: &[("en", "Sanggau")] },
+    Area { prefix: 62565, descriptions: &[("en", "Sintang")] },
+    Area { prefix: 62567, descriptions: &[("en", "Putussibau")] },
+    Area { prefix: 62568, descriptions: &[("en", "Nanga Pinoh")] },
+    Area { prefix: 62620, descriptions: &[("en", "Pangkalan Brandan")] },
+    Area { prefix: 62621, descriptions: &[("en", "Tebing Tinggi/Sei Rampah")] },
+    Area { prefix: 62622, descriptions: &[("en", "Pematangsiantar/Pematang Raya/Limapuluh")] },
+    Area { prefix: 62623, descriptions: &[("en", "Kisaran/Tanjung Balai")] },
+    Area { prefix: 62624, descriptions: &[("en", "Panipahan/Labuhanbatu")] },
+    Area { prefix: 62625, descriptions: &[("en", "Parapat/Ajibata/Simanindo")] },
+    Area { prefix: 62626, descriptions: &[("en", "Pangururan")] },
+    Area { prefix: 62627, descriptions: &[("en", "Subulussalam/Sidikalang/Salak")] },
+    Area { prefix: 62628, descriptions: &[("en", "Kabanjahe/Sibolangit")] },
+    Area { prefix: 62629, descriptions: &[("en", "Kutacane")] },
+    Area { prefix: 62631, descriptions: &[("en", "Sibolga/Pandan")] },
+    Area { prefix: 62632, descriptions: &[("en", "Balige")] },
+    Area { prefix: 62633, descriptions: &[("en", "Tarutung/Dolok Sanggul")] },
+    Area { prefix: 62634, descriptions: &[("en", "Padang Sidempuan/Sipirok")] },
+    Area { prefix: 62635, descriptions: &[("en", "Gunung Tua")] },
+    Area { prefix: 62636, descriptions: &[("en", "Panyabungan/Sibuhuan")] },
+    Area { prefix: 62639, descriptions: &[("en", "Gunung Sitoli")] },
+    Area { prefix: 62641, descriptions: &[("en", "Langsa")] },
+    Area { prefix: 62642, descriptions: &[("en", "Blang Kejeren")] },
+    Area { prefix: 62643, descriptions: &[("en", "Takengon")] },
+    Area { prefix: 62644, descriptions: &[("en", "Bireuen")] },
+    Area { prefix: 62645, descriptions: &[("en", "Lhokseumawe")] },
+    Area { prefix: 62646, descriptions: &[("en", "Idi")] },
+    Area { prefix: 62650, descriptions: &[("en", "Sinabang")] },
+    Area { prefix: 62651, descriptions: &[("en", "Banda Aceh/Jantho/Lamno")] },
+    Area { prefix: 62652, descriptions: &[("en", "Sabang")] },
+    Area { prefix: 62653, descriptions: &[("en", "Sigli")] },
+    Area { prefix: 62654, descriptions: &[("en", "Calang")] },
+    Area { prefix: 62655, descriptions: &[("en", "Meulaboh")] },
+    Area { prefix: 62656, descriptions: &[("en", "Tapaktuan")] },
+    Area { prefix: 62657, descriptions: &[("en", "Bakongan")] },
+    Area { prefix: 62658, descriptions: &[("en", "Singkil")] },
+    Area { prefix: 62659, descriptions: &[("en", "Blangpidie")] },
+    Area { prefix: 62711, descriptions: &[("en", "Palembang")] },
+    Area { prefix: 62712, descriptions: &[("en", "Kayu Agung/Tanjung Raja")] },
+    Area { prefix: 62713, descriptions: &[("en", "Prabumulih/Talang Ubi")] },
+    Area { prefix: 62714, descriptions: &[("en", "Sekayu")] },
+    Area { prefix: 62715, descriptions: &[("en", "Belinyu")] },
+    Area { prefix: 62716, descriptions: &[("en", "Muntok")] },
+    Area { prefix: 62717, descriptions: &[("en", "Pangkal Pinang/Sungailiat")] },
+    Area { prefix: 62718, descriptions: &[("en", "Koba/Toboali")] },
+    Area { prefix: 62719, descriptions: &[("en", "Manggar/Tanjung Pandan")] },
+    Area { prefix: 62721, descriptions: &[("en", "Bandar Lampung")] },
+    Area { prefix: 62722, descriptions: &[("en", "Tanggamus")] },
+    Area { prefix: 62723, descriptions: &[("en", "Blambangan Umpu")] },
+    Area { prefix: 62724, descriptions: &[("en", "Kotabumi")] },
+    Area { prefix: 62725, descriptions: &[("en", "Metro")] },
+    Area { prefix: 62726, descriptions: &[("en", "Menggala")] },
+    Area { prefix: 62727, descriptions: &[("en", "Kalianda")] },
+    Area { prefix: 62728, descriptions: &[("en", "Liwa")] },
+    Area { prefix: 62729, descriptions: &[("en", "Pringsewu")] },
+    Area { prefix: 62730, descriptions: &[("en", "Pagar Alam/Kota Agung")] },
+    Area { prefix: 62731, descriptions: &[("en", "Lahat")] },
+    Area { prefix: 62732, descriptions: &[("en", "Curup")] },
+    Area { prefix: 62733, descriptions: &[("en", "Lubuklinggau/Muara Beliti")] },
+    Area { prefix: 62734, descriptions: &[("en", "Muara Enim")] },
+    Area { prefix: 62735, descriptions: &[("en", "Baturaja/Martapura/Muaradua")] },
+    Area { prefix: 62736, descriptions: &[("en", "Bengkulu City"), ("id", "Kota Bengkulu")] },
+    Area { prefix: 62737, descriptions: &[("en", "Arga Makmur/Mukomuko")] },
+    Area { prefix: 62738, descriptions: &[("en", "Muara Aman")] },
+    Area { prefix: 62739, descriptions: &[("en", "Bintuhan/Manna")] },
+    Area { prefix: 62741, descriptions: &[("en", "Jambi City"), ("id", "Kota Jambi")] },
+    Area { prefix: 62742, descriptions: &[("en", "Kualatungkal/Tebing Tinggi")] },
+    Area { prefix: 62743, descriptions: &[("en", "Muara Bulian")] },
+    Area { prefix: 62744, descriptions: &[("en", "Muara Tebo")] },
+    Area { prefix: 62745, descriptions: &[("en", "Sarolangun")] },
+    Area { prefix: 62746, descriptions: &[("en", "Bangko")] },
+    Area { prefix: 62747, descriptions: &[("en", "Muarabungo")] },
+    Area { prefix: 62748, descriptions: &[("en", "Sungai Penuh/Kerinci")] },
+    Area { prefix: 62751, descriptions: &[("en", "Padang/Pariaman")] },
+    Area { prefix: 62752, descriptions: &[("en", "Bukittinggi/Padang Panjang/Payakumbuh/Batusangkar")] },
+    Area { prefix: 62753, descriptions: &[("en", "Lubuk Sikaping")] },
+    Area { prefix: 62754, descriptions: &[("en", "Sijunjung")] },
+    Area { prefix: 62755, descriptions: &[("en", "Solok")] },
+    Area { prefix: 62756, descriptions: &[("en", "Painan")] },
+    Area { prefix: 62757, descriptions: &[("en", "Balai Selasa")] },
+    Area { prefix: 62760, descriptions: &[("en", "Teluk Kuantan")] },
+    Area { prefix: 62761, descriptions: &[("en", "Pekanbaru")] },
+    Area { prefix: 62762, descriptions: &[("en", "Bangkinang/Pasir Pengaraian")] },
+    Area { prefix: 62763, descriptions: &[("en", "Selatpanjang")] },
+    Area { prefix: 62764, descriptions: &[("en", "Siak Sri Indrapura")] },
+    Area { prefix: 62765, descriptions: &[("en", "Dumai/Duri/Bagan Batu/Ujung Tanjung")] },
+    Area { prefix: 62766, descriptions: &[("en", "Bengkalis")] },
+    Area { prefix: 62767, descriptions: &[("en", "Bagansiapiapi")] },
+    Area { prefix: 62768, descriptions: &[("en", "Tembilahan")] },
+    Area { prefix: 62769, descriptions: &[("en", "Rengat/Air Molek")] },
+    Area { prefix: 62771, descriptions: &[("en", "Tanjung Pinang")] },
+    Area { prefix: 62772, descriptions: &[("en", "Tarempa")] },
+    Area { prefix: 62773, descriptions: &[("en", "Ranai")] },
+    Area { prefix: 62776, descriptions: &[("en", "Dabosingkep")] },
+    Area { prefix: 62777, descriptions: &[("en", "Karimun")] },
+    Area { prefix: 62778, descriptions: &[("en", "Batam")] },
+    Area { prefix: 62779, descriptions: &[("en", "Tanjungbatu")] },
+    Area { prefix: 62901, descriptions: &[("en", "Timika")] },
+    Area { prefix: 62902, descriptions: &[("en", "Agats")] },
+    Area { prefix: 62910, descriptions: &[("en", "Bandanaira")] },
+    Area { prefix: 62911, descriptions: &[("en", "Ambon")] },
+    Area { prefix: 62913, descriptions: &[("en", "Namlea")] },
+    Area { prefix: 62914, descriptions: &[("en", "Masohi")] },
+    Area { prefix: 62915, descriptions: &[("en", "Bula")] },
+    Area { prefix: 62916, descriptions: &[("en", "Tual")] },
+    Area { prefix: 62917, descriptions: &[("en", "Dobo")] },
+    Area { prefix: 62918, descriptions: &[("en", "Saumlaku")] },
+    Area { prefix: 62921, descriptions: &[("en", "Soasiu")] },
+    Area { prefix: 62922, descriptions: &[("en", "Jailolo")] },
+    Area { prefix: 62923, descriptions: &[("en", "Morotai")] },
+    Area { prefix: 62924, descriptions: &[("en", "Tobelo")] },
+    Area { prefix: 62927, descriptions: &[("en", "Labuha")] },
+    Area { prefix: 62929, descriptions: &[("en", "Sanana")] },
+    Area { prefix: 62951, descriptions: &[("en", "Sorong")] },
+    Area { prefix: 62952, descriptions: &[("en", "Teminabuan")] },
+    Area { prefix: 62955, descriptions: &[("en", "Bintuni")] },
+    Area { prefix: 62956, descriptions: &[("en", "Fakfak")] },
+    Area { prefix: 62957, descriptions: &[("en", "Kaimana")] },
+    Area { prefix: 62966, descriptions: &[("en", "Sarmi")] },
+    Area { prefix: 62967, descriptions: &[("en", "Jayapura")] },
+    Area { prefix: 62969, descriptions: &[("en", "Wamena")] },
+    Area { prefix: 62971, descriptions: &[("en", "Merauke")] },
+    Area { prefix: 62975, descriptions: &[("en", "Tanahmerah")] },
+    Area { prefix: 62980, descriptions: &[("en", "Ransiki")] },
+    Area { prefix: 62981, descriptions: &[("en", "Biak")] },
+    Area { prefix: 62983, descriptions: &[("en", "Serui")] },
+    Area { prefix: 62984, descriptions: &[("en", "Nabire")] },
+    Area { prefix: 62986, descriptions: &[("en", "Manokwari")] },
+    Area { prefix: 64320, descriptions: &[("en", "Gore")] },
+    Area { prefix: 64325, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64328, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64329, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64330, descriptions: &[("en", "Ashburton/Akaroa/Chatham Islands")] },
+    Area { prefix: 64331, descriptions: &[("en", "Rangiora/Amberley/Culverden/Darfield/Cheviot/Kaikoura")] },
+    Area { prefix: 64332, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64334, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64335, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64336, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64337, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64338, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64340, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64343, descriptions: &[("en", "Oamaru")] },
+    Area { prefix: 64347, descriptions: &[("en", "Dunedin")] },
+    Area { prefix: 64348, descriptions: &[("en", "Dunedin")] },
+    Area { prefix: 64349, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64354, descriptions: &[("en", "Nelson")] },
+    Area { prefix: 64357, descriptions: &[("en", "Blenheim")] },
+    Area { prefix: 64360, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64361, descriptions: &[("en", "Timaru")] },
+    Area { prefix: 64362, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64363, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64364, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64367, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64368, descriptions: &[("en", "Timaru")] },
+    Area { prefix: 64369, descriptions: &[("en", "Geraldine")] },
+    Area { prefix: 64370, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64371, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64372, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64373, descriptions: &[("en", "Greymouth")] },
+    Area { prefix: 64376, descriptions: &[("en", "Greymouth")] },
+    Area { prefix: 64377, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64378, descriptions: &[("en", "Westport")] },
+    Area { prefix: 64379, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64391, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64396, descriptions: &[("en", "Christchurch")] },
+    Area { prefix: 64399, descriptions: &[("en", "South Island")] },
+    Area { prefix: 64423, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 64429, descriptions: &[("en", "Paraparaumu")] },
+    Area { prefix: 64452, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 64456, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 64480, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 64483, descriptions: &[("en", "Wellington")] },
+    Area { prefix: 64627, descriptions: &[("en", "Hawera")] },
+    Area { prefix: 64630, descriptions: &[("en", "Featherston")] },
+    Area { prefix: 64632, descriptions: &[("en", "Palmerston North")] },
+    Area { prefix: 64634, descriptions: &[("en", "Wanganui")] },
+    Area { prefix: 64635, descriptions: &[("en", "Palmerston North")] },
+    Area { prefix: 64636, descriptions: &[("en", "Levin")] },
+    Area { prefix: 64637, descriptions: &[("en", "Masterton")] },
+    Area { prefix: 64638, descriptions: &[("en", "Taihape/Ohakune/Waiouru")] },
+    Area { prefix: 64675, descriptions: &[("en", "New Plymouth")] },
+    Area { prefix: 64683, descriptions: &[("en", "Napier/Hastings")] },
+    Area { prefix: 64685, descriptions: &[("en", "Waipukurau")] },
+    Area { prefix: 64686, descriptions: &[("en", "Gisborne")] },
+    Area { prefix: 64687, descriptions: &[("en", "Napier/Hastings")] },
+    Area { prefix: 64694, descriptions: &[("en", "Masterton/Levin")] },
+    Area { prefix: 64697, descriptions: &[("en", "Napier")] },
+    Area { prefix: 64698, descriptions: &[("en", "Gisborne")] },
+    Area { prefix: 64730, descriptions: &[("en", "Whakatane")] },
+    Area { prefix: 64731, descriptions: &[("en", "Whakatane/Opotiki")] },
+    Area { prefix: 64732, descriptions: &[("en", "Whakatane")] },
+    Area { prefix: 64733, descriptions: &[("en", "Rotorua/Taupo")] },
+    Area { prefix: 64734, descriptions: &[("en", "Rotorua")] },
+    Area { prefix: 64735, descriptions: &[("en", "Rotorua")] },
+    Area { prefix: 64736, descriptions: &[("en", "Rotorua")] },
+    Area { prefix: 64737, descriptions: &[("en", "Taupo")] },
+    Area { prefix: 64738, descriptions: &[("en", "Taupo")] },
+    Area { prefix: 64754, descriptions: &[("en", "Tauranga")] },
+    Area { prefix: 64757, descriptions: &[("en", "Tauranga")] },
+    Area { prefix: 64783, descriptions: &[("en", "Hamilton")] },
+    Area { prefix: 64784, descriptions: &[("en", "Hamilton")] },
+    Area { prefix: 64785, descriptions: &[("en", "Hamilton")] },
+    Area { prefix: 64789, descriptions: &[("en", "Taumarunui")] },
+    Area { prefix: 64790, descriptions: &[("en", "Taupo")] },
+    Area { prefix: 64793, descriptions: &[("en", "Tauranga")] },
+    Area { prefix: 64795, descriptions: &[("en", "Hamilton")] },
+    Area { prefix: 64796, descriptions: &[("en", "Hamilton")] },
+    Area { prefix: 64923, descriptions: &[("en", "Pukekohe")] },
+    Area { prefix: 64930, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64931, descriptions: &[("en", "Auckland/Waiheke Island")] },
+    Area { prefix: 64932, descriptions: &[("en", "Auckland/Waiheke Island")] },
+    Area { prefix: 64933, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64934, descriptions: &[("en", "Auckland/Waiheke Island")] },
+    Area { prefix: 64935, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64936, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64937, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64938, descriptions: &[("en", "Auckland/Waiheke Island")] },
+    Area { prefix: 64941, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64943, descriptions: &[("en", "Whangarei")] },
+    Area { prefix: 64944, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64947, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64948, descriptions: &[("en", "Auckland")] },
+    Area { prefix: 64998, descriptions: &[("en", "Whangarei")] },
+    Area { prefix: 67021, descriptions: &[("en", "Manufahi")] },
+    Area { prefix: 67022, descriptions: &[("en", "Cova Lima")] },
+    Area { prefix: 67023, descriptions: &[("en", "Bobonaro")] },
+    Area { prefix: 67024, descriptions: &[("en", "Ainaro")] },
+    Area { prefix: 67025, descriptions: &[("en", "Dekuse")] },
+    Area { prefix: 67031, descriptions: &[("en", "Dili")] },
+    Area { prefix: 67032, descriptions: &[("en", "Dili")] },
+    Area { prefix: 67033, descriptions: &[("en", "Dili")] },
+    Area { prefix: 67036, descriptions: &[("en", "Liquica")] },
+    Area { prefix: 67037, descriptions: &[("en", "Aileu")] },
+    Area { prefix: 67038, descriptions: &[("en", "Ermera")] },
+    Area { prefix: 67039, descriptions: &[("en", "Oekusi")] },
+    Area { prefix: 67041, descriptions: &[("en", "Baucau")] },
+    Area { prefix: 67042, descriptions: &[("en", "Manatuto")] },
+    Area { prefix: 67043, descriptions: &[("en", "Viqueque")] },
+    Area { prefix: 67044, descriptions: &[("en", "Lautem")] },
+    Area { prefix: 67210, descriptions: &[("en", "Davis")] },
+    Area { prefix: 67211, descriptions: &[("en", "Mawson")] },
+    Area { prefix: 67212, descriptions: &[("en", "Casey")] },
+    Area { prefix: 67213, descriptions: &[("en", "Macquarie Island")] },
+    Area { prefix: 67320, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67321, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67323, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67324, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67325, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67326, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67327, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67328, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67329, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 67350, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67351, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67352, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67355, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67356, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67357, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67358, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67359, descriptions: &[("en", "Temburong")] },
+    Area { prefix: 67542, descriptions: &[("en", "Madang")] },
+    Area { prefix: 67545, descriptions: &[("en", "Sepik")] },
+    Area { prefix: 67547, descriptions: &[("en", "Morobe")] },
+    Area { prefix: 67629, descriptions: &[("en", "Pea")] },
+    Area { prefix: 67630, descriptions: &[("en", "Pea")] },
+    Area { prefix: 67631, descriptions: &[("en", "Muʻa")] },
+    Area { prefix: 67632, descriptions: &[("en", "Muʻa")] },
+    Area { prefix: 67633, descriptions: &[("en", "Kolonga")] },
+    Area { prefix: 67634, descriptions: &[("en", "Kolonga")] },
+    Area { prefix: 67635, descriptions: &[("en", "Nakolo")] },
+    Area { prefix: 67636, descriptions: &[("en", "Nakolo")] },
+    Area { prefix: 67637, descriptions: &[("en", "Vaini")] },
+    Area { prefix: 67638, descriptions: &[("en", "Vaini")] },
+    Area { prefix: 67640, descriptions: &[("en", "Kolovai")] },
+    Area { prefix: 67641, descriptions: &[("en", "Masilamea")] },
+    Area { prefix: 67642, descriptions: &[("en", "Masilamea")] },
+    Area { prefix: 67643, descriptions: &[("en", "Matangiake")] },
+    Area { prefix: 67650, descriptions: &[("en", "‘Eua")] },
+    Area { prefix: 67660, descriptions: &[("en", "Ha’apai")] },
+    Area { prefix: 67669, descriptions: &[("en", "Ha’apai")] },
+    Area { prefix: 67670, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67671, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67672, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67674, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67675, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67676, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67679, descriptions: &[("en", "Vava’u")] },
+    Area { prefix: 67680, descriptions: &[("en", "Niuas")] },
+    Area { prefix: 67685, descriptions: &[("en", "Niuas")] },
+    Area { prefix: 67822, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67823, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67824, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67825, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67826, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67827, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67828, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67829, descriptions: &[("en", "Port Vila, Shefa")] },
+    Area { prefix: 67836, descriptions: &[("en", "Sanma")] },
+    Area { prefix: 67837, descriptions: &[("en", "Luganville")] },
+    Area { prefix: 67838, descriptions: &[("en", "Penama/Torba")] },
+    Area { prefix: 67888, descriptions: &[("en", "Tafea")] },
+    Area { prefix: 67965, descriptions: &[("en", "Coral Coast/Sigatoka")] },
+    Area { prefix: 67966, descriptions: &[("en", "Lautoka/Ba/Vatukoula/Tavua/Rakiraki")] },
+    Area { prefix: 67967, descriptions: &[("en", "Nadi")] },
+    Area { prefix: 67985, descriptions: &[("en", "Vanua Levu")] },
+    Area { prefix: 67988, descriptions: &[("en", "Vanua Levu")] },
+    Area { prefix: 68025, descriptions: &[("en", "Sonsorol State and Hatohobei State")] },
+    Area { prefix: 68027, descriptions: &[("en", "Angaur State")] },
+    Area { prefix: 68048, descriptions: &[("en", "Koror State")] },
+    Area { prefix: 68053, descriptions: &[("en", "Ngatpang State")] },
+    Area { prefix: 68054, descriptions: &[("en", "Aimeliik State")] },
+    Area { prefix: 68058, descriptions: &[("en", "Airai State")] },
+    Area { prefix: 68065, descriptions: &[("en", "Melekeok State")] },
+    Area { prefix: 68067, descriptions: &[("en", "Ngiwal State")] },
+    Area { prefix: 68073, descriptions: &[("en", "Ngaremlengui State")] },
+    Area { prefix: 68074, descriptions: &[("en", "Ngardmau State")] },
+    Area { prefix: 68082, descriptions: &[("en", "Ngaraard State")] },
+    Area { prefix: 68085, descriptions: &[("en", "Ngarchelong State")] },
+    Area { prefix: 68087, descriptions: &[("en", "Kayangel State")] },
+    Area { prefix: 68231, descriptions: &[("en", "Aitutaki")] },
+    Area { prefix: 68233, descriptions: &[("en", "Atiu")] },
+    Area { prefix: 68234, descriptions: &[("en", "Mangaia")] },
+    Area { prefix: 68235, descriptions: &[("en", "Mauke")] },
+    Area { prefix: 68236, descriptions: &[("en", "Mitiaro")] },
+    Area { prefix: 68237, descriptions: &[("en", "Palmerston")] },
+    Area { prefix: 68241, descriptions: &[("en", "Pukapuka")] },
+    Area { prefix: 68242, descriptions: &[("en", "Penrhyn")] },
+    Area { prefix: 68243, descriptions: &[("en", "Manihiki")] },
+    Area { prefix: 68244, descriptions: &[("en", "Rakahanga")] },
+    Area { prefix: 68245, descriptions: &[("en", "Nassau")] },
+    Area { prefix: 68561, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68562, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68563, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68564, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68565, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68566, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68567, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68568, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68569, descriptions: &[("en", "Apia")] },
+    Area { prefix: 68621, descriptions: &[("en", "Bairiki")] },
+    Area { prefix: 68622, descriptions: &[("en", "Bairiki")] },
+    Area { prefix: 68623, descriptions: &[("en", "Bairiki")] },
+    Area { prefix: 68624, descriptions: &[("en", "Bairiki")] },
+    Area { prefix: 68625, descriptions: &[("en", "Betio")] },
+    Area { prefix: 68626, descriptions: &[("en", "Betio")] },
+    Area { prefix: 68627, descriptions: &[("en", "Tarawa")] },
+    Area { prefix: 68628, descriptions: &[("en", "Bikenibeu")] },
+    Area { prefix: 68629, descriptions: &[("en", "Bikenibeu")] },
+    Area { prefix: 68631, descriptions: &[("en", "North Tarawa")] },
+    Area { prefix: 68632, descriptions: &[("en", "North Tarawa")] },
+    Area { prefix: 68633, descriptions: &[("en", "Abaiang")] },
+    Area { prefix: 68634, descriptions: &[("en", "Marakei")] },
+    Area { prefix: 68635, descriptions: &[("en", "Butaritari")] },
+    Area { prefix: 68636, descriptions: &[("en", "Makin")] },
+    Area { prefix: 68637, descriptions: &[("en", "Banaba")] },
+    Area { prefix: 68638, descriptions: &[("en", "Maiana")] },
+    Area { prefix: 68639, descriptions: &[("en", "Kuria")] },
+    Area { prefix: 68640, descriptions: &[("en", "Aranuka")] },
+    Area { prefix: 68641, descriptions: &[("en", "Abemama")] },
+    Area { prefix: 68642, descriptions: &[("en", "Nonouti")] },
+    Area { prefix: 68643, descriptions: &[("en", "Tabiteuea North")] },
+    Area { prefix: 68644, descriptions: &[("en", "Tabiteuea South")] },
+    Area { prefix: 68645, descriptions: &[("en", "Onotoa")] },
+    Area { prefix: 68646, descriptions: &[("en", "Beru")] },
+    Area { prefix: 68647, descriptions: &[("en", "Nikunau")] },
+    Area { prefix: 68648, descriptions: &[("en", "Tamana")] },
+    Area { prefix: 68649, descriptions: &[("en", "Arorae")] },
+    Area { prefix: 68681, descriptions: &[("en", "Kiritimati")] },
+    Area { prefix: 68682, descriptions: &[("en", "Kiritimati")] },
+    Area { prefix: 68683, descriptions: &[("en", "Fanning")] },
+    Area { prefix: 68684, descriptions: &[("en", "Washington")] },
+    Area { prefix: 68685, descriptions: &[("en", "Kanton")] },
+    Area { prefix: 68820, descriptions: &[("en", "Funafuti")] },
+    Area { prefix: 68822, descriptions: &[("en", "Niulakita")] },
+    Area { prefix: 68823, descriptions: &[("en", "Nui")] },
+    Area { prefix: 68824, descriptions: &[("en", "Nukufetau")] },
+    Area { prefix: 68825, descriptions: &[("en", "Nukulaelae")] },
+    Area { prefix: 68826, descriptions: &[("en", "Nanumea")] },
+    Area { prefix: 68827, descriptions: &[("en", "Nanumaga")] },
+    Area { prefix: 68828, descriptions: &[("en", "Niutao")] },
+    Area { prefix: 68829, descriptions: &[("en", "Vaitupu")] },
+    Area { prefix: 81124, descriptions: &[("en", "Ashibetsu, Hokkaido"), ("ja", "芦別")] },
+    Area { prefix: 81125, descriptions: &[("en", "Takikawa, Hokkaido"), ("ja", "滝川")] },
+    Area { prefix: 81126, descriptions: &[("en", "Iwamizawa, Hokkaido"), ("ja", "岩見沢")] },
+    Area { prefix: 81134, descriptions: &[("en", "Otaru, Hokkaido"), ("ja", "小樽")] },
+    Area { prefix: 81138, descriptions: &[("en", "Hakodate, Hokkaido"), ("ja", "函館")] },
+    Area { prefix: 81142, descriptions: &[("en", "Date, Hokkaido"), ("ja", "伊達")] },
+    Area { prefix: 81143, descriptions: &[("en", "Muroran, Hokkaido"), ("ja", "室蘭")] },
+    Area { prefix: 81144, descriptions: &[("en", "Tomakomai, Hokkaido"), ("ja", "苫小牧")] },
+    Area { prefix: 81157, descriptions: &[("en", "Kitami, Hokkaido"), ("ja", "北見")] },
+    Area { prefix: 81162, descriptions: &[("en", "Wakkanai, Hokkaido"), ("ja", "稚内")] },
+    Area { prefix: 81166, descriptions: &[("en", "Asahikawa, Hokkaido"), ("ja", "旭川")] },
+    Area { prefix: 81167, descriptions: &[("en", "Furano, Hokkaido"), ("ja", "富良野")] },
+    Area { prefix: 81172, descriptions: &[("en", "Hirosaki, Aomori"), ("ja", "弘前")] },
+    Area { prefix: 81174, descriptions: &[("en", "Kanita, Aomori"), ("ja", "蟹田")] },
+    Area { prefix: 81176, descriptions: &[("en", "Towada, Aomori"), ("ja", "十和田")] },
+    Area { prefix: 81177, descriptions: &[("en", "Aomori, Aomori"), ("ja", "青森")] },
+    Area { prefix: 81178, descriptions: &[("en", "Hachinohe, Aomori"), ("ja", "八戸")] },
+    Area { prefix: 81179, descriptions: &[("en", "Sannohe, Aomori"), ("ja", "三戸")] },
+    Area { prefix: 81182, descriptions: &[("en", "Yokote, Akita"), ("ja", "横手")] },
+    Area { prefix: 81183, descriptions: &[("en", "Yuzawa, Akita"), ("ja", "湯沢")] },
+    Area { prefix: 81184, descriptions: &[("en", "Yurihonjo, Akita"), ("ja", "本荘")] },
+    Area { prefix: 81188, descriptions: &[("en", "Akita, Akita"), ("ja", "秋田")] },
+    Area { prefix: 81191, descriptions: &[("en", "Ichinoseki, Iwate"), ("ja", "一関")] },
+    Area { prefix: 81192, descriptions: &[("en", "Ofunato, Iwate"), ("ja", "大船渡")] },
+    Area { prefix: 81196, descriptions: &[("en", "Morioka, Iwate"), ("ja", "盛岡")] },
+    Area { prefix: 81199, descriptions: &[("en", "Morioka, Iwate"), ("ja", "盛岡")] },
+    Area { prefix: 81222, descriptions: &[("en", "Sendai, Miyagi"), ("ja", "仙台")] },
+    Area { prefix: 81225, descriptions: &[("en", "Ishinomaki, Miyagi"), ("ja", "石巻")] },
+    Area { prefix: 81226, descriptions: &[("en", "Kesennuma, Miyagi"), ("ja", "気仙沼")] },
+    Area { prefix: 81227, descriptions: &[("en", "Sendai, Miyagi"), ("ja", "仙台")] },
+    Area { prefix: 81233, descriptions: &[("en", "Shinjo, Yamagata"), ("ja", "新庄")] },
+    Area { prefix: 81234, descriptions: &[("en", "Sakata, Yamagata"), ("ja", "酒田")] },
+    Area { prefix: 81235, descriptions: &[("en", "Tsuruoka, Yamagata"), ("ja", "鶴岡")] },
+    Area { prefix: 81236, descriptions: &[("en", "Yamagata, Yamagata"), ("ja", "山形")] },
+    Area { prefix: 81242, descriptions: &[("en", "Aizuwakamatsu, Fukushima"), ("ja", "会津若松")] },
+    Area { prefix: 81243, descriptions: &[("en", "Nihonmatsu, Fukushima"), ("ja", "二本松")] },
+    Area { prefix: 81244, descriptions: &[("en", "Hobara, Fukushima"), ("ja", "原町")] },
+    Area { prefix: 81245, descriptions: &[("en", "Fukushima, Fukushima"), ("ja", "福島")] },
+    Area { prefix: 81246, descriptions: &[("en", "Iwaki, Fukushima"), ("ja", "いわき")] },
+    Area { prefix: 81249, descriptions: &[("en", "Koriyama, Fukushima"), ("ja", "郡山")] },
+    Area { prefix: 81250, descriptions: &[("en", "Niitsu, Niigata"), ("ja", "新津")] },
+    Area { prefix: 81252, descriptions: &[("en", "Niigata, Niigata"), ("ja", "新潟")] },
+    Area { prefix: 81253, descriptions: &[("en", "Niigata, Niigata"), ("ja", "新潟")] },
+    Area { prefix: 81259, descriptions: &[("en", "Sado, Niigata"), ("ja", "佐渡")] },
+    Area { prefix: 81260, descriptions: &[("en", "Anan, Nagano"), ("ja", "阿南町")] },
+    Area { prefix: 81262, descriptions: &[("en", "Nagano, Nagano"), ("ja", "長野")] },
+    Area { prefix: 81263, descriptions: &[("en", "Matsumoto, Nagano"), ("ja", "松本")] },
+    Area { prefix: 81266, descriptions: &[("en", "Suwa, Nagano"), ("ja", "諏訪")] },
+    Area { prefix: 81268, descriptions: &[("en", "Ueda, Nagano"), ("ja", "上田")] },
+    Area { prefix: 81270, descriptions: &[("en", "Isesaki, Gunma"), ("ja", "伊勢崎")] },
+    Area { prefix: 81272, descriptions: &[("en", "Maebashi, Gunma"), ("ja", "前橋")] },
+    Area { prefix: 81273, descriptions: &[("en", "Takasaki, Gunma"), ("ja", "高崎")] },
+    Area { prefix: 81276, descriptions: &[("en", "Ota, Gunma"), ("ja", "太田")] },
+    Area { prefix: 81277, descriptions: &[("en", "Kiryu, Gunma"), ("ja", "桐生")] },
+    Area { prefix: 81280, descriptions: &[("en", "Koga, Ibaraki"), ("ja", "古河")] },
+    Area { prefix: 81281, descriptions: &[("en", "Utsunomiya, Tochigi"), ("ja", "宇都宮")] },
+    Area { prefix: 81282, descriptions: &[("en", "Tochigi, Tochigi"), ("ja", "栃木")] },
+    Area { prefix: 81284, descriptions: &[("en", "Ashikaga, Tochigi"), ("ja", "足利")] },
+    Area { prefix: 81286, descriptions: &[("en", "Utsunomiya, Tochigi"), ("ja", "宇都宮")] },
+    Area { prefix: 81288, descriptions: &[("en", "Imabari, Ehime"), ("ja", "今市")] },
+    Area { prefix: 81292, descriptions: &[("en", "Mito, Ibaraki"), ("ja", "水戸")] },
+    Area { prefix: 81294, descriptions: &[("en", "Hitachiota, Ibaraki"), ("ja", "常陸太田")] },
+    Area { prefix: 81298, descriptions: &[("en", "Tsuchiura, Ibaraki"), ("ja", "土浦")] },
+    Area { prefix: 81420, descriptions: &[("en", "Tokorozawa, Saitama"), ("ja", "所沢")] },
+    Area { prefix: 81423, descriptions: &[("en", "Kokubunji, Tokyo"), ("ja", "国分寺")] },
+    Area { prefix: 81425, descriptions: &[("en", "Tachikawa, Tokyo"), ("ja", "立川")] },
+    Area { prefix: 81426, descriptions: &[("en", "Hachioji, Tokyo"), ("ja", "八王子")] },
+    Area { prefix: 81427, descriptions: &[("en", "Sagamihara, Kanagawa"), ("ja", "相模原")] },
+    Area { prefix: 81429, descriptions: &[("en", "Tokorozawa, Saitama"), ("ja", "所沢")] },
+    Area { prefix: 81432, descriptions: &[("en", "Chiba, Chiba"), ("ja", "千葉")] },
+    Area { prefix: 81433, descriptions: &[("en", "Chiba, Chiba"), ("ja", "千葉")] },
+    Area { prefix: 81434, descriptions: &[("en", "Chiba, Chiba"), ("ja", "千葉")] },
+    Area { prefix: 81436, descriptions: &[("en", "Ichihara, Chiba"), ("ja", "市原")] },
+    Area { prefix: 81438, descriptions: &[("en", "Kisarazu, Chiba"), ("ja", "木更津")] },
+    Area { prefix: 81439, descriptions: &[("en", "Kisarazu, Chiba"), ("ja", "木更津")] },
+    Area { prefix: 81460, descriptions: &[("en", "Odawara, Kanagawa"), ("ja", "小田原")] },
+    Area { prefix: 81462, descriptions: &[("en", "Atsugi, Kanagawa"), ("ja", "厚木")] },
+    Area { prefix: 81463, descriptions: &[("en", "Hiratsuka, Kanagawa"), ("ja", "平塚")] },
+    Area { prefix: 81464, descriptions: &[("en", "Atsugi, Kanagawa"), ("ja", "厚木")] },
+    Area { prefix: 81465, descriptions: &[("en", "Odawara, Kanagawa"), ("ja", "小田原")] },
+    Area { prefix: 81466, descriptions: &[("en", "Fujisawa, Kanagawa"), ("ja", "藤沢")] },
+    Area { prefix: 81467, descriptions: &[("en", "Fujisawa, Kanagawa"), ("ja", "藤沢")] },
+    Area { prefix: 81468, descriptions: &[("en", "Yokosuka, Kanagawa"), ("ja", "横須賀")] },
+    Area { prefix: 81471, descriptions: &[("en", "Kashiwa, Chiba"), ("ja", "柏")] },
+    Area { prefix: 81473, descriptions: &[("en", "Ichikawa, Chiba"), ("ja", "市川")] },
+    Area { prefix: 81474, descriptions: &[("en", "Funabashi, Chiba"), ("ja", "船橋")] },
+    Area { prefix: 81476, descriptions: &[("en", "Narita, Chiba"), ("ja", "成田")] },
+    Area { prefix: 81478, descriptions: &[("en", "Sawara, Chiba"), ("ja", "佐原")] },
+    Area { prefix: 81480, descriptions: &[("en", "Kuki, Saitama"), ("ja", "久喜")] },
+    Area { prefix: 81482, descriptions: &[("en", "Kawaguchi, Saitama"), ("ja", "川口")] },
+    Area { prefix: 81484, descriptions: &[("en", "Kawaguchi, Saitama"), ("ja", "川口")] },
+    Area { prefix: 81485, descriptions: &[("en", "Kumagaya, Saitama"), ("ja", "熊谷")] },
+    Area { prefix: 81486, descriptions: &[("en", "Urawa, Saitama"), ("ja", "浦和")] },
+    Area { prefix: 81487, descriptions: &[("en", "Urawa, Saitama"), ("ja", "浦和")] },
+    Area { prefix: 81488, descriptions: &[("en", "Urawa, Saitama"), ("ja", "浦和")] },
+    Area { prefix: 81489, descriptions: &[("en", "Soka, Saitama"), ("ja", "草加")] },
+    Area { prefix: 81492, descriptions: &[("en", "Kawagoe, Saitama"), ("ja", "川越")] },
+    Area { prefix: 81493, descriptions: &[("en", "Higashimatsuyama, Saitama"), ("ja", "東松山")] },
+    Area { prefix: 81494, descriptions: &[("en", "Chichibu, Saitama"), ("ja", "秩父")] },
+    Area { prefix: 81495, descriptions: &[("en", "Honjo, Saitama"), ("ja", "本庄")] },
+    Area { prefix: 81531, descriptions: &[("en", "Tahara, Aichi"), ("ja", "田原")] },
+    Area { prefix: 81532, descriptions: &[("en", "Toyohashi, Aichi"), ("ja", "豊橋")] },
+    Area { prefix: 81533, descriptions: &[("en", "Toyohashi, Aichi"), ("ja", "豊橋")] },
+    Area { prefix: 81534, descriptions: &[("en", "Hamamatsu, Shizuoka"), ("ja", "浜松")] },
+    Area { prefix: 81535, descriptions: &[("en", "Hamamatsu, Shizuoka"), ("ja", "浜松")] },
+    Area { prefix: 81537, descriptions: &[("en", "Kakegawa, Shizuoka"), ("ja", "掛川")] },
+    Area { prefix: 81538, descriptions: &[("en", "Iwata, Shizuoka"), ("ja", "磐田")] },
+    Area { prefix: 81542, descriptions: &[("en", "Shizuoka, Shizuoka"), ("ja", "静岡")] },
+    Area { prefix: 81543, descriptions: &[("en", "Shizuoka, Shizuoka"), ("ja", "静岡")] },
+    Area { prefix: 81544, descriptions: &[("en", "Fujinomiya, Shizuoka"), ("ja", "富士宮")] },
+    Area { prefix: 81545, descriptions: &[("en", "Fuji, Shizuoka"), ("ja", "富士")] },
+    Area { prefix: 81546, descriptions: &[("en", "Shizuoka, Shizuoka"), ("ja", "静岡")] },
+    Area { prefix: 81547, descriptions: &[("en", "Shimada, Shizuoka"), ("ja", "島田")] },
+    Area { prefix: 81548, descriptions: &[("en", "Haibara, Shizuoka"), ("ja", "榛原")] },
+    Area { prefix: 81549, descriptions: &[("en", "Shizuoka, Shizuoka"), ("ja", "静岡")] },
+    Area { prefix: 81550, descriptions: &[("en", "Gotenba, Shizuoka"), ("ja", "御殿場")] },
+    Area { prefix: 81551, descriptions: &[("en", "Nirasaki, Yamanashi"), ("ja", "韮崎")] },
+    Area { prefix: 81552, descriptions: &[("en", "Kofu, Yamanashi"), ("ja", "甲府")] },
+    Area { prefix: 81553, descriptions: &[("en", "Yamanashi, Yamanashi"), ("ja", "山梨")] },
+    Area { prefix: 81554, descriptions: &[("en", "Otsuki, Yamanashi"), ("ja", "大月")] },
+    Area { prefix: 81555, descriptions: &[("en", "Fujiyoshida, Yamanashi"), ("ja", "吉田")] },
+    Area { prefix: 81557, descriptions: &[("en", "Ito, Shizuoka"), ("ja", "伊東")] },
+    Area { prefix: 81559, descriptions: &[("en", "Numazu, Shizuoka"), ("ja", "沼津")] },
+    Area { prefix: 81561, descriptions: &[("en", "Seto, Aichi"), ("ja", "瀬戸")] },
+    Area { prefix: 81563, descriptions: &[("en", "Nishio, Aichi"), ("ja", "西尾")] },
+    Area { prefix: 81564, descriptions: &[("en", "Okazaki, Aichi"), ("ja", "岡崎")] },
+    Area { prefix: 81565, descriptions: &[("en", "Toyota, Aichi"), ("ja", "豊田")] },
+    Area { prefix: 81566, descriptions: &[("en", "Kariya, Aichi"), ("ja", "刈谷")] },
+    Area { prefix: 81567, descriptions: &[("en", "Tsushima, Aichi"), ("ja", "津島")] },
+    Area { prefix: 81568, descriptions: &[("en", "Kasugai, Aichi"), ("ja", "春日井")] },
+    Area { prefix: 81569, descriptions: &[("en", "Handa, Aichi"), ("ja", "半田")] },
+    Area { prefix: 81572, descriptions: &[("en", "Tajimi, Gifu"), ("ja", "多治見")] },
+    Area { prefix: 81577, descriptions: &[("en", "Takayama, Gifu"), ("ja", "高山")] },
+    Area { prefix: 81578, descriptions: &[("en", "Kamioka, Akita"), ("ja", "神岡")] },
+    Area { prefix: 81582, descriptions: &[("en", "Gifu, Gifu"), ("ja", "岐阜")] },
+    Area { prefix: 81583, descriptions: &[("en", "Gifu, Gifu"), ("ja", "岐阜")] },
+    Area { prefix: 81584, descriptions: &[("en", "Ogaki, Gifu"), ("ja", "大垣")] },
+    Area { prefix: 81585, descriptions: &[("en", "Ibigawa, Gifu"), ("ja", "揖斐川")] },
+    Area { prefix: 81586, descriptions: &[("en", "Ichinomiya, Aichi"), ("ja", "一宮")] },
+    Area { prefix: 81587, descriptions: &[("en", "Ichinomiya, Aichi"), ("ja", "一宮")] },
+    Area { prefix: 81591, descriptions: &[("en", "Tsu, Mie"), ("ja", "津")] },
+    Area { prefix: 81592, descriptions: &[("en", "Tsu, Mie"), ("ja", "津")] },
+    Area { prefix: 81593, descriptions: &[("en", "Yokkaichi, Mie"), ("ja", "四日市")] },
+    Area { prefix: 81594, descriptions: &[("en", "Kuwana, Mie"), ("ja", "桑名")] },
+    Area { prefix: 81596, descriptions: &[("en", "Ise, Mie"), ("ja", "伊勢")] },
+    Area { prefix: 81721, descriptions: &[("en", "Tondabayashi, Osaka"), ("ja", "富田林")] },
+    Area { prefix: 81722, descriptions: &[("en", "Sakai, Osaka"), ("ja", "堺")] },
+    Area { prefix: 81723, descriptions: &[("en", "Sakai, Osaka"), ("ja", "堺")] },
+    Area { prefix: 81725, descriptions: &[("en", "Izumi, Osaka"), ("ja", "和泉")] },
+    Area { prefix: 81726, descriptions: &[("en", "Ibaraki, Osaka"), ("ja", "茨木")] },
+    Area { prefix: 81727, descriptions: &[("en", "Ikeda, Osaka"), ("ja", "池田")] },
+    Area { prefix: 81728, descriptions: &[("en", "Neyagawa, Osaka"), ("ja", "寝屋川")] },
+    Area { prefix: 81729, descriptions: &[("en", "Yao, Osaka"), ("ja", "八尾")] },
+    Area { prefix: 81734, descriptions: &[("en", "Wakayama, Wakayama"), ("ja", "和歌山")] },
+    Area { prefix: 81737, descriptions: &[("en", "Yuasa, Wakayama"), ("ja", "湯浅")] },
+    Area { prefix: 81738, descriptions: &[("en", "Gobo, Wakayama"), ("ja", "御坊")] },
+    Area { prefix: 81739, descriptions: &[("en", "Tanabe, Wakayama"), ("ja", "田辺")] },
+    Area { prefix: 81740, descriptions: &[("en", "Imazu, Shiga"), ("ja", "今津")] },
+    Area { prefix: 81742, descriptions: &[("en", "Nara, Nara"), ("ja", "奈良")] },
+    Area { prefix: 81743, descriptions: &[("en", "Nara, Nara"), ("ja", "奈良")] },
+    Area { prefix: 81744, descriptions: &[("en", "Yamatotakada, Nara"), ("ja", "大和高田")] },
+    Area { prefix: 81762, descriptions: &[("en", "Kanazawa, Ishikawa"), ("ja", "金沢")] },
+    Area { prefix: 81763, descriptions: &[("en", "Fukuno, Toyama"), ("ja", "福野")] },
+    Area { prefix: 81764, descriptions: &[("en", "Toyama, Toyama"), ("ja", "富山")] },
+    Area { prefix: 81765, descriptions: &[("en", "Uozu, Toyama"), ("ja", "魚津")] },
+    Area { prefix: 81766, descriptions: &[("en", "Takaoka, Toyama"), ("ja", "高岡")] },
+    Area { prefix: 81774, descriptions: &[("en", "Uji, Kyoto"), ("ja", "宇治")] },
+    Area { prefix: 81775, descriptions: &[("en", "Otsu, Shiga"), ("ja", "大津")] },
+    Area { prefix: 81776, descriptions: &[("en", "Fukui, Fukui"), ("ja", "福井")] },
+    Area { prefix: 81778, descriptions: &[("en", "Takefu, Fukui"), ("ja", "武生")] },
+    Area { prefix: 81779, descriptions: &[("en", "Ono, Gifu"), ("ja", "大野")] },
+    Area { prefix: 81792, descriptions: &[("en", "Himeji, Hyogo"), ("ja", "姫路")] },
+    Area { prefix: 81793, descriptions: &[("en", "Himeji, Hyogo"), ("ja", "姫路")] },
+    Area { prefix: 81797, descriptions: &[("en", "Nishinomiya, Hyogo"), ("ja", "西宮")] },
+    Area { prefix: 81798, descriptions: &[("en", "Nishinomiya, Hyogo"), ("ja", "西宮")] },
+    Area { prefix: 81822, descriptions: &[("en", "Hiroshima, Hiroshima"), ("ja", "広島")] },
+    Area { prefix: 81823, descriptions: &[("en", "Kure, Hiroshima"), ("ja", "呉")] },
+    Area { prefix: 81825, descriptions: &[("en", "Hiroshima, Hiroshima"), ("ja", "広島")] },
+    Area { prefix: 81827, descriptions: &[("en", "Iwakuni, Yamaguchi"), ("ja", "岩国")] },
+    Area { prefix: 81828, descriptions: &[("en", "Hiroshima, Hiroshima"), ("ja", "広島")] },
+    Area { prefix: 81832, descriptions: &[("en", "Shimonoseki, Yamaguchi"), ("ja", "下関")] },
+    Area { prefix: 81833, descriptions: &[("en", "Kudamatsu, Yamaguchi"), ("ja", "下松")] },
+    Area { prefix: 81834, descriptions: &[("en", "Tokuyama, Yamaguchi"), ("ja", "徳山")] },
+    Area { prefix: 81835, descriptions: &[("en", "Hofu, Yamaguchi"), ("ja", "防府")] },
+    Area { prefix: 81839, descriptions: &[("en", "Yamaguchi, Yamaguchi"), ("ja", "山口")] },
+    Area { prefix: 81845, descriptions: &[("en", "Innoshima, Hiroshima"), ("ja", "因島")] },
+    Area { prefix: 81848, descriptions: &[("en", "Onomichi, Hiroshima"), ("ja", "尾道")] },
+    Area { prefix: 81849, descriptions: &[("en", "Fukuyama, Hiroshima"), ("ja", "福山")] },
+    Area { prefix: 81852, descriptions: &[("en", "Matsue, Shimane"), ("ja", "松江")] },
+    Area { prefix: 81853, descriptions: &[("en", "Izumo, Shimane"), ("ja", "出雲")] },
+    Area { prefix: 81857, descriptions: &[("en", "Tottori, Tottori"), ("ja", "鳥取")] },
+    Area { prefix: 81862, descriptions: &[("en", "Okayama, Okayama"), ("ja", "岡山")] },
+    Area { prefix: 81863, descriptions: &[("en", "Tamano, Okayama"), ("ja", "玉野")] },
+    Area { prefix: 81864, descriptions: &[("en", "Kurashiki, Okayama"), ("ja", "倉敷")] },
+    Area { prefix: 81875, descriptions: &[("en", "Kan'onji, Kagawa"), ("ja", "観音寺")] },
+    Area { prefix: 81877, descriptions: &[("en", "Marugame, Kagawa"), ("ja", "丸亀")] },
+    Area { prefix: 81878, descriptions: &[("en", "Takamatsu, Kagawa"), ("ja", "高松")] },
+    Area { prefix: 81885, descriptions: &[("en", "Komatsushima, Tokushima"), ("ja", "小松島")] },
+    Area { prefix: 81886, descriptions: &[("en", "Tokushima, Tokushima"), ("ja", "徳島")] },
+    Area { prefix: 81888, descriptions: &[("en", "Kochi, Kochi"), ("ja", "高知")] },
+    Area { prefix: 81892, descriptions: &[("en", "Kumakogen, Ehime"), ("ja", "久万")] },
+    Area { prefix: 81893, descriptions: &[("en", "Ozu, Ehime"), ("ja", "大洲")] },
+    Area { prefix: 81896, descriptions: &[("en", "Iyomishima, Ehime"), ("ja", "伊予三島")] },
+    Area { prefix: 81898, descriptions: &[("en", "Imabari, Ehime"), ("ja", "今治")] },
+    Area { prefix: 81899, descriptions: &[("en", "Matsuyama, Ehime"), ("ja", "松山")] },
+    Area { prefix: 81922, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81923, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81924, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81925, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81926, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81927, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81928, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81929, descriptions: &[("en", "Fukuoka, Fukuoka"), ("ja", "福岡")] },
+    Area { prefix: 81930, descriptions: &[("en", "Yukuhashi, Fukuoka"), ("ja", "行橋")] },
+    Area { prefix: 81932, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81933, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81934, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81935, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81936, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81937, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81938, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81939, descriptions: &[("en", "Kitakyushu, Fukuoka"), ("ja", "北九州")] },
+    Area { prefix: 81940, descriptions: &[("en", "Munakata, Fukuoka"), ("ja", "宗像")] },
+    Area { prefix: 81942, descriptions: &[("en", "Kurume, Fukuoka"), ("ja", "久留米")] },
+    Area { prefix: 81944, descriptions: &[("en", "Setaka, Fukuoka"), ("ja", "瀬高")] },
+    Area { prefix: 81946, descriptions: &[("en", "Amagi, Fukuoka"), ("ja", "甘木")] },
+    Area { prefix: 81947, descriptions: &[("en", "Tagawa, Fukuoka"), ("ja", "田川")] },
+    Area { prefix: 81948, descriptions: &[("en", "Iizuka, Fukuoka"), ("ja", "飯塚")] },
+    Area { prefix: 81949, descriptions: &[("en", "Nogata, Fukuoka"), ("ja", "直方")] },
+    Area { prefix: 81950, descriptions: &[("en", "Hirado, Nagasaki"), ("ja", "平戸")] },
+    Area { prefix: 81952, descriptions: &[("en", "Saga, Saga"), ("ja", "佐賀")] },
+    Area { prefix: 81956, descriptions: &[("en", "Sasebo, Nagasaki"), ("ja", "佐世保")] },
+    Area { prefix: 81958, descriptions: &[("en", "Nagasaki, Nagasaki"), ("ja", "長崎")] },
+    Area { prefix: 81962, descriptions: &[("en", "Kumamoto, Kumamoto"), ("ja", "熊本")] },
+    Area { prefix: 81963, descriptions: &[("en", "Kumamoto, Kumamoto"), ("ja", "熊本")] },
+    Area { prefix: 81965, descriptions: &[("en", "Yatsushiro, Kumamoto"), ("ja", "八代")] },
+    Area { prefix: 81969, descriptions: &[("en", "Amakusa, Kumamoto"), ("ja", "天草")] },
+    Area { prefix: 81975, descriptions: &[("en", "Oita, Oita"), ("ja", "大分")] },
+    Area { prefix: 81977, descriptions: &[("en", "Beppu, Oita"), ("ja", "別府")] },
+    Area { prefix: 81979, descriptions: &[("en", "Nakatsu, Oita"), ("ja", "中津")] },
+    Area { prefix: 81983, descriptions: &[("en", "Takanabe, Miyazaki"), ("ja", "高鍋")] },
+    Area { prefix: 81984, descriptions: &[("en", "Kobayashi, Miyazaki"), ("ja", "小林")] },
+    Area { prefix: 81985, descriptions: &[("en", "Miyazaki, Miyazaki"), ("ja", "宮崎")] },
+    Area { prefix: 81986, descriptions: &[("en", "Miyakonojo, Miyazaki"), ("ja", "都城")] },
+    Area { prefix: 81987, descriptions: &[("en", "Nichinan, Miyazaki"), ("ja", "日南")] },
+    Area { prefix: 81988, descriptions: &[("en", "Naha, Okinawa"), ("ja", "那覇")] },
+    Area { prefix: 81989, descriptions: &[("en", "Naha, Okinawa"), ("ja", "那覇")] },
+    Area { prefix: 81992, descriptions: &[("en", "Kagoshima, Kagoshima"), ("ja", "鹿児島")] },
+    Area { prefix: 81998, descriptions: &[("en", "Kagoshima, Kagoshima"), ("ja", "鹿児島")] },
+    Area { prefix: 84203, descriptions: &[("en", "Quang Ninh province"), ("vi", "Quảng Ninh")] },
+    Area { prefix: 84204, descriptions: &[("en", "Bac Giang province"), ("vi", "Bắc Giang")] },
+    Area { prefix: 84205, descriptions: &[("en", "Lang Son province"), ("vi", "Lạng Sơn")] },
+    Area { prefix: 84206, descriptions: &[("en", "Cao Bang province"), ("vi", "Cao Bằng")] },
+    Area { prefix: 84207, descriptions: &[("en", "Tuyen Quang province"), ("vi", "Tuyên Quang")] },
+    Area { prefix: 84208, descriptions: &[("en", "Thai Nguyen province"), ("vi", "Thái Nguyên")] },
+    Area { prefix: 84209, descriptions: &[("en", "Bac Can province"), ("vi", "Tỉnh Bắc Kạn")] },
+    Area { prefix: 84210, descriptions: &[("en", "Phu Tho province"), ("vi", "Phú Thọ")] },
+    Area { prefix: 84211, descriptions: &[("en", "Vinh Phuc province"), ("vi", "Vĩnh Phúc")] },
+    Area { prefix: 84212, descriptions: &[("en", "Son La province"), ("vi", "Sơn La")] },
+    Area { prefix: 84213, descriptions: &[("en", "Lai Chau province"), ("vi", "Lai Châu")] },
+    Area { prefix: 84214, descriptions: &[("en", "Lao Cai province"), ("vi", "Lào Cai")] },
+    Area { prefix: 84215, descriptions: &[("en", "Dien Bien province"), ("vi", "Điện Biên")] },
+    Area { prefix: 84216, descriptions: &[("en", "Yen Bai province"), ("vi", "Yên Bái")] },
+    Area { prefix: 84218, descriptions: &[("en", "Hoa Binh province"), ("vi", "Hòa Bình")] },
+    Area { prefix: 84219, descriptions: &[("en", "Ha Giang province"), ("vi", "Hà Giang")] },
+    Area { prefix: 84220, descriptions: &[("en", "Hai Duong province"), ("vi", "Hải Dương")] },
+    Area { prefix: 84221, descriptions: &[("en", "Hung Yen province"), ("vi", "Hưng Yên")] },
+    Area { prefix: 84222, descriptions: &[("en", "Bac Ninh province"), ("vi", "Bắc Ninh")] },
+    Area { prefix: 84225, descriptions: &[("en", "Hai Phong City"), ("vi", "Thành phố Hải Phòng")] },
+    Area { prefix: 84226, descriptions: &[("en", "Ha Nam province"), ("vi", "Hà Nam")] },
+    Area { prefix: 84227, descriptions: &[("en", "Thai Binh province"), ("vi", "Thái Bình")] },
+    Area { prefix: 84228, descriptions: &[("en", "Nam Dinh province"), ("vi", "Nam Định")] },
+    Area { prefix: 84229, descriptions: &[("en", "Ninh Binh province"), ("vi", "Ninh Bình")] },
+    Area { prefix: 84232, descriptions: &[("en", "Quang Binh province"), ("vi", "Quảng Bình")] },
+    Area { prefix: 84233, descriptions: &[("en", "Quang Tri province"), ("vi", "Quảng Trị")] },
+    Area { prefix: 84234, descriptions: &[("en", "Thua Thien-Hue province"), ("vi", "Thừa Thiên-Huế")] },
+    Area { prefix: 84235, descriptions: &[("en", "Quang Nam province"), ("vi", "Quảng Nam")] },
+    Area { prefix: 84236, descriptions: &[("en", "Da Nang"), ("vi", "TP Đà Nẵng")] },
+    Area { prefix: 84237, descriptions: &[("en", "Thanh Hoa province"), ("vi", "Thanh Hóa")] },
+    Area { prefix: 84238, descriptions: &[("en", "Nghe An province"), ("vi", "Nghệ An")] },
+    Area { prefix: 84239, descriptions: &[("en", "Ha Tinh province"), ("vi", "Hà Tĩnh")] },
+    Area { prefix: 84251, descriptions: &[("en", "Dong Nai province"), ("vi", "Đồng Nai")] },
+    Area { prefix: 84252, descriptions: &[("en", "Binh Thuan province"), ("vi", "Bình Thuận")] },
+    Area { prefix: 84254, descriptions: &[("en", "Ba Ria Vung Tau province"), ("vi", "Bà Rịa-Vũng Tàu")] },
+    Area { prefix: 84255, descriptions: &[("en", "Quang Ngai province"), ("vi", "Quảng Ngãi")] },
+    Area { prefix: 84256, descriptions: &[("en", "Binh Dinh province"), ("vi", "Bình Định")] },
+    Area { prefix: 84257, descriptions: &[("en", "Phu Yen province"), ("vi", "Phú Yên")] },
+    Area { prefix: 84258, descriptions: &[("en", "Khanh Hoa province"), ("vi", "Khánh Hòa")] },
+    Area { prefix: 84259, descriptions: &[("en", "Ninh Thuan province"), ("vi", "Ninh Thuận")] },
+    Area { prefix: 84260, descriptions: &[("en", "Kon Tum province"), ("vi", "Kon Tum")] },
+    Area { prefix: 84261, descriptions: &[("en", "Dak Nong province"), ("vi", "Đăk Nông")] },
+    Area { prefix: 84262, descriptions: &[("en", "Dak Lak province"), ("vi", "Đăk Lăk")] },
+    Area { prefix: 84263, descriptions: &[("en", "Lam Dong province"), ("vi", "Lâm Đồng")] },
+    Area { prefix: 84269, descriptions: &[("en", "Gia Lai province"), ("vi", "Gia Lai")] },
+    Area { prefix: 84270, descriptions: &[("en", "Ving Long province"), ("vi", "Vĩnh Long")] },
+    Area { prefix: 84271, descriptions: &[("en", "Binh Phuoc province"), ("vi", "Bình Phước")] },
+    Area { prefix: 84272, descriptions: &[("en", "Long An province"), ("vi", "Long An")] },
+    Area { prefix: 84273, descriptions: &[("en", "Tien Giang province"), ("vi", "Tiền Giang")] },
+    Area { prefix: 84274, descriptions: &[("en", "Binh Duong province"), ("vi", "Bình Dương")] },
+    Area { prefix: 84275, descriptions: &[("en", "Ben Tre province"), ("vi", "Bến Tre")] },
+    Area { prefix: 84276, descriptions: &[("en", "Tay Ninh province"), ("vi", "Tây Ninh")] },
+    Area { prefix: 84277, descriptions: &[("en", "Dong Thap province"), ("vi", "Đồng Tháp")] },
+    Area { prefix: 84290, descriptions: &[("en", "Ca Mau province"), ("vi", "Cà Mau")] },
+    Area { prefix: 84291, descriptions: &[("en", "Bac Lieu province"), ("vi", "Bạc Liêu")] },
+    Area { prefix: 84292, descriptions: &[("en", "Can Tho City"), ("vi", "Thành phố Cần Thơ")] },
+    Area { prefix: 84293, descriptions: &[("en", "Hau Giang province"), ("vi", "Hậu Giang")] },
+    Area { prefix: 84294, descriptions: &[("en", "Tra Vinh province"), ("vi", "Trà Vinh")] },
+    Area { prefix: 84296, descriptions: &[("en", "An Giang province"), ("vi", "An Giang")] },
+    Area { prefix: 84297, descriptions: &[("en", "Kien Giang province"), ("vi", "Kiên Giang")] },
+    Area { prefix: 84299, descriptions: &[("en", "Soc Trang province"), ("vi", "Sóc Trăng")] },
+    Area { prefix: 85021, descriptions: &[("en", "Pyongyang")] },
+    Area { prefix: 85027, descriptions: &[("en", "Pyongyang")] },
+    Area { prefix: 85028, descriptions: &[("en", "Pyongyang")] },
+    Area { prefix: 85031, descriptions: &[("en", "Pyongyang")] },
+    Area { prefix: 85039, descriptions: &[("en", "Nampo")] },
+    Area { prefix: 85041, descriptions: &[("en", "Sariwon")] },
+    Area { prefix: 85045, descriptions: &[("en", "Haeju")] },
+    Area { prefix: 85049, descriptions: &[("en", "Kaesong")] },
+    Area { prefix: 85053, descriptions: &[("en", "Hamhung")] },
+    Area { prefix: 85057, descriptions: &[("en", "Wonsan")] },
+    Area { prefix: 85061, descriptions: &[("en", "Sinuiju")] },
+    Area { prefix: 85067, descriptions: &[("en", "Kanggye")] },
+    Area { prefix: 85073, descriptions: &[("en", "Chongjin")] },
+    Area { prefix: 85079, descriptions: &[("en", "Hyesan")] },
+    Area { prefix: 86310, descriptions: &[("en", "Handan, Hebei"), ("zh", "河北省邯郸市")] },
+    Area { prefix: 86311, descriptions: &[("en", "Shijiazhuang, Hebei"), ("zh", "河北省石家庄市")] },
+    Area { prefix: 86312, descriptions: &[("en", "Baoding, Hebei"), ("zh", "河北省保定市")] },
+    Area { prefix: 86313, descriptions: &[("en", "Zhangjiakou, Hebei"), ("zh", "河北省张家口市")] },
+    Area { prefix: 86314, descriptions: &[("en", "Chengde, Hebei"), ("zh", "河北省承德市")] },
+    Area { prefix: 86315, descriptions: &[("en", "Tangshan, Hebei"), ("zh", "河北省唐山市")] },
+    Area { prefix: 86316, descriptions: &[("en", "Langfang, Hebei"), ("zh", "河北省廊坊市")] },
+    Area { prefix: 86317, descriptions: &[("en", "Cangzhou, Hebei"), ("zh", "河北省沧州市")] },
+    Area { prefix: 86318, descriptions: &[("en", "Hengshui, Hebei"), ("zh", "河北省衡水市")] },
+    Area { prefix: 86319, descriptions: &[("en", "Xingtai, Hebei"), ("zh", "河北省邢台市")] },
+    Area { prefix: 86350, descriptions: &[("en", "Xinzhou, Shanxi"), ("zh", "山西省忻州市")] },
+    Area { prefix: 86351, descriptions: &[("en", "Taiyuan, Shanxi"), ("zh", "山西省太原市")] },
+    Area { prefix: 86352, descriptions: &[("en", "Datong, Shanxi"), ("zh", "山西省大同市")] },
+    Area { prefix: 86353, descriptions: &[("en", "Yangquan, Shanxi"), ("zh", "山西省阳泉市")] },
+    Area { prefix: 86354, descriptions: &[("en", "Yuci, Shanxi"), ("zh", "山西省晋中市")] },
+    Area { prefix: 86355, descriptions: &[("en", "Changzhi, Shanxi"), ("zh", "山西省长治市")] },
+    Area { prefix: 86356, descriptions: &[("en", "Jincheng, Shanxi"), ("zh", "山西省晋城市")] },
+    Area { prefix: 86357, descriptions: &[("en", "Linfen, Shanxi"), ("zh", "山西省临汾市")] },
+    Area { prefix: 86358, descriptions: &[("en", "Lishi, Shanxi"), ("zh", "山西省吕梁市")] },
+    Area { prefix: 86359, descriptions: &[("en", "Yuncheng, Shanxi"), ("zh", "山西省运城市")] },
+    Area { prefix: 86370, descriptions: &[("en", "Shangqiu, Henan"), ("zh", "河南省商丘市")] },
+    Area { prefix: 86371, descriptions: &[("en", "Zhengzhou, Henan"), ("zh", "河南省郑州市")] },
+    Area { prefix: 86372, descriptions: &[("en", "Anyang, Henan"), ("zh", "河南省安阳市")] },
+    Area { prefix: 86373, descriptions: &[("en", "Xinxiang, Henan"), ("zh", "河南省新乡市")] },
+    Area { prefix: 86374, descriptions: &[("en", "Xuchang, Henan"), ("zh", "河南省许昌市")] },
+    Area { prefix: 86375, descriptions: &[("en", "Pingdingshan, Henan"), ("zh", "河南省平顶山市")] },
+    Area { prefix: 86376, descriptions: &[("en", "Xinyang, Henan"), ("zh", "河南省信阳市")] },
+    Area { prefix: 86377, descriptions: &[("en", "Nanyang, Henan"), ("zh", "河南省南阳市")] },
+    Area { prefix: 86378, descriptions: &[("en", "Kaifeng, Henan"), ("zh", "河南省开封市")] },
+    Area { prefix: 86379, descriptions: &[("en", "Luoyang, Henan"), ("zh", "河南省洛阳市")] },
+    Area { prefix: 86391, descriptions: &[("en", "Jiaozuo, Henan"), ("zh", "河南省焦作市")] },
+    Area { prefix: 86392, descriptions: &[("en", "Hebi, Henan"), ("zh", "河南省鹤壁市")] },
+    Area { prefix: 86393, descriptions: &[("en", "Puyang, Henan"), ("zh", "河南省濮阳市")] },
+    Area { prefix: 86394, descriptions: &[("en", "Zhoukou, Henan"), ("zh", "河南省周口市")] },
+    Area { prefix: 86395, descriptions: &[("en", "Luohe, Henan"), ("zh", "河南省漯河市")] },
+    Area { prefix: 86396, descriptions: &[("en", "Zhumadian, Henan"), ("zh", "河南省驻马店市")] },
+    Area { prefix: 86398, descriptions: &[("en", "Sanmenxia, Henan"), ("zh", "河南省三门峡市")] },
+    Area { prefix: 86410, descriptions: &[("en", "Tieling, Liaoning"), ("zh", "辽宁省铁岭市")] },
+    Area { prefix: 86411, descriptions: &[("en", "Dalian, Liaoning"), ("zh", "辽宁省大连市")] },
+    Area { prefix: 86412, descriptions: &[("en", "Anshan, Liaoning"), ("zh", "辽宁省鞍山市")] },
+    Area { prefix: 86413, descriptions: &[("en", "Fushun, Liaoning"), ("zh", "辽宁省抚顺市")] },
+    Area { prefix: 86414, descriptions: &[("en", "Benxi, Liaoning"), ("zh", "辽宁省本溪市")] },
+    Area { prefix: 86415, descriptions: &[("en", "Dandong, Liaoning"), ("zh", "辽宁省丹东市")] },
+    Area { prefix: 86416, descriptions: &[("en", "Jinzhou, Liaoning"), ("zh", "辽宁省锦州市")] },
+    Area { prefix: 86417, descriptions: &[("en", "Yingkou, Liaoning"), ("zh", "辽宁省营口市")] },
+    Area { prefix: 86418, descriptions: &[("en", "Fuxin, Liaoning"), ("zh", "辽宁省阜新市")] },
+    Area { prefix: 86419, descriptions: &[("en", "Liaoyang, Liaoning"), ("zh", "辽宁省辽阳市")] },
+    Area { prefix: 86421, descriptions: &[("en", "Chaoyang, Liaoning"), ("zh", "辽宁省朝阳市")] },
+    Area { prefix: 86427, descriptions: &[("en", "Panjin, Liaoning"), ("zh", "辽宁省盘锦市")] },
+    Area { prefix: 86429, descriptions: &[("en", "Huludao, Liaoning"), ("zh", "辽宁省葫芦岛市")] },
+    Area { prefix: 86431, descriptions: &[("en", "Changchun, Jilin"), ("zh", "吉林省长春市")] },
+    Area { prefix: 86432, descriptions: &[("en", "Jilin, Jilin"), ("zh", "吉林省吉林市")] },
+    Area { prefix: 86433, descriptions: &[("en", "Yanbian Zhou/Hunchun/Yanji, Jilin"), ("zh", "吉林省延边朝鲜族自治州、珲春市、延吉市")] },
+    Area { prefix: 86434, descriptions: &[("en", "Siping, Jilin"), ("zh", "吉林省四平市")] },
+    Area { prefix: 86435, descriptions: &[("en", "Tonghua/Meihekou, Jilin"), ("zh", "吉林省通化市、梅河口市")] },
+    Area { prefix: 86436, descriptions: &[("en", "Baicheng, Jilin"), ("zh", "吉林省白城市")] },
+    Area { prefix: 86437, descriptions: &[("en", "Liaoyuan, Jilin"), ("zh", "吉林省辽源市")] },
+    Area { prefix: 86438, descriptions: &[("en", "Songyuan, Jilin"), ("zh", "吉林省松原市")] },
+    Area { prefix: 86439, descriptions: &[("en", "Baishan, Jilin"), ("zh", "吉林省白山市")] },
+    Area { prefix: 86451, descriptions: &[("en", "Harbin, Heilongjiang"), ("zh", "黑龙江省哈尔滨市")] },
+    Area { prefix: 86452, descriptions: &[("en", "Qiqihar, Heilongjiang"), ("zh", "黑龙江省齐齐哈尔市")] },
+    Area { prefix: 86453, descriptions: &[("en", "Mudanjiang, Heilongjiang"), ("zh", "黑龙江省牡丹江市")] },
+    Area { prefix: 86454, descriptions: &[("en", "Jiamusi, Heilongjiang"), ("zh", "黑龙江省佳木斯市")] },
+    Area { prefix: 86455, descriptions: &[("en", "Suihua, Heilongjiang"), ("zh", "黑龙江省绥化市")] },
+    Area { prefix: 86456, descriptions: &[("en", "Heihe, Heilongjiang"), ("zh", "黑龙江省黑河市")] },
+    Area { prefix: 86457, descriptions: &[("en", "Jiagedaqi, Heilongjiang"), ("zh", "黑龙江省大兴安岭地区")] },
+    Area { prefix: 86458, descriptions: &[("en", "Yichun, Heilongjiang"), ("zh", "黑龙江省伊春市")] },
+    Area { prefix: 86459, descriptions: &[("en", "Daqing, Heilongjiang"), ("zh", "黑龙江省大庆市")] },
+    Area { prefix: 86464, descriptions: &[("en", "Qitaihe, Heilongjiang"), ("zh", "黑龙江省七台河市")] },
+    Area { prefix: 86467, descriptions: &[("en", "Jixi, Heilongjiang"), ("zh", "黑龙江省鸡西市")] },
+    Area { prefix: 86468, descriptions: &[("en", "Hegang, Heilongjiang"), ("zh", "黑龙江省鹤岗市")] },
+    Area { prefix: 86469, descriptions: &[("en", "Shuangyashan, Heilongjiang"), ("zh", "黑龙江省双鸭山市")] },
+    Area { prefix: 86470, descriptions: &[("en", "Hailaer, Inner Mongolia"), ("zh", "内蒙古呼伦贝尔市")] },
+    Area { prefix: 86471, descriptions: &[("en", "Huhehaote, Inner Mongolia"), ("zh", "内蒙古呼和浩特市")] },
+    Area { prefix: 86472, descriptions: &[("en", "Baotou, Inner Mongolia"), ("zh", "内蒙古包头市")] },
+    Area { prefix: 86473, descriptions: &[("en", "Wuhai, Inner Mongolia"), ("zh", "内蒙古乌海市")] },
+    Area { prefix: 86474, descriptions: &[("en", "Jining, Inner Mongolia"), ("zh", "内蒙古乌兰察布市")] },
+    Area { prefix: 86475, descriptions: &[("en", "Tongliao, Inner Mongolia"), ("zh", "内蒙古通辽市")] },
+    Area { prefix: 86476, descriptions: &[("en", "Chifeng, Inner Mongolia"), ("zh", "内蒙古赤峰市")] },
+    Area { prefix: 86477, descriptions: &[("en", "Dongsheng, Inner Mongolia"), ("zh", "内蒙古鄂尔多斯市")] },
+    Area { prefix: 86478, descriptions: &[("en", "Linhe, Inner Mongolia"), ("zh", "内蒙古巴彦淖尔市")] },
+    Area { prefix: 86479, descriptions: &[("en", "Xilinhaote, Inner Mongolia"), ("zh", "内蒙古锡林郭勒盟")] },
+    Area { prefix: 86482, descriptions: &[("en", "Wulanhaote, Inner Mongolia"), ("zh", "内蒙古兴安盟")] },
+    Area { prefix: 86483, descriptions: &[("en", "Alashanzuoqi, Inner Mongolia"), ("zh", "内蒙古阿拉善盟")] },
+    Area { prefix: 86510, descriptions: &[("en", "Wuxi, Jiangsu"), ("zh", "江苏省无锡市")] },
+    Area { prefix: 86511, descriptions: &[("en", "Zhenjiang, Jiangsu"), ("zh", "江苏省镇江市")] },
+    Area { prefix: 86512, descriptions: &[("en", "Suzhou, Jiangsu"), ("zh", "江苏省苏州市")] },
+    Area { prefix: 86513, descriptions: &[("en", "Nantong, Jiangsu"), ("zh", "江苏省南通市")] },
+    Area { prefix: 86514, descriptions: &[("en", "Yangzhou, Jiangsu"), ("zh", "江苏省扬州市")] },
+    Area { prefix: 86515, descriptions: &[("en", "Yancheng, Jiangsu"), ("zh", "江苏省盐城市")] },
+    Area { prefix: 86516, descriptions: &[("en", "Xuzhou, Jiangsu"), ("zh", "江苏省徐州市")] },
+    Area { prefix: 86517, descriptions: &[("en", "Huaian, Jiangsu"), ("zh", "江苏省淮安市")] },
+    Area { prefix: 86518, descriptions: &[("en", "Lianyungang, Jiangsu"), ("zh", "江苏省连云港市")] },
+    Area { prefix: 86519, descriptions: &[("en", "Changzhou, Jiangsu"), ("zh", "江苏省常州市")] },
+    Area { prefix: 86523, descriptions: &[("en", "Taizhou, Jiangsu"), ("zh", "江苏省泰州市")] },
+    Area { prefix: 86527, descriptions: &[("en", "Suqian, Jiangsu"), ("zh", "江苏省宿迁市")] },
+    Area { prefix: 86530, descriptions: &[("en", "Heze, Shandong"), ("zh", "山东省菏泽市")] },
+    Area { prefix: 86531, descriptions: &[("en", "Jinan, Shandong"), ("zh", "山东省济南市")] },
+    Area { prefix: 86532, descriptions: &[("en", "Qingdao, Shandong"), ("zh", "山东省青岛市")] },
+    Area { prefix: 86533, descriptions: &[("en", "Zibo, Shandong"), ("zh", "山东省淄博市")] },
+    Area { prefix: 86534, descriptions: &[("en", "Dezhou, Shandong"), ("zh", "山东省德州市")] },
+    Area { prefix: 86535, descriptions: &[("en", "Yantai, Shandong"), ("zh", "山东省烟台市")] },
+    Area { prefix: 86536, descriptions: &[("en", "Weifang, Shandong"), ("zh", "山东省潍坊市")] },
+    Area { prefix: 86537, descriptions: &[("en", "Jining, Shandong"), ("zh", "山东省济宁市")] },
+    Area { prefix: 86538, descriptions: &[("en", "TaiAn, Shandong"), ("zh", "山东省泰安市")] },
+    Area { prefix: 86539, descriptions: &[("en", "Linyi, Shandong"), ("zh", "山东省临沂市")] },
+    Area { prefix: 86543, descriptions: &[("en", "Binzhou, Shandong"), ("zh", "山东省滨州市")] },
+    Area { prefix: 86546, descriptions: &[("en", "Dongying, Shandong"), ("zh", "山东省东营市")] },
+    Area { prefix: 86550, descriptions: &[("en", "Chuzhou, Anhui"), ("zh", "安徽省滁州市")] },
+    Area { prefix: 86551, descriptions: &[("en", "Hefei/Chaohu, Anhui"), ("zh", "安徽省合肥市、巢湖市")] },
+    Area { prefix: 86552, descriptions: &[("en", "Bengbu, Anhui"), ("zh", "安徽省蚌埠市")] },
+    Area { prefix: 86553, descriptions: &[("en", "Wuhu, Anhui"), ("zh", "安徽省芜湖市")] },
+    Area { prefix: 86554, descriptions: &[("en", "Huainan, Anhui"), ("zh", "安徽省淮南市")] },
+    Area { prefix: 86555, descriptions: &[("en", "MaAnshan, Anhui"), ("zh", "安徽省马鞍山市")] },
+    Area { prefix: 86556, descriptions: &[("en", "Anqing, Anhui"), ("zh", "安徽省安庆市")] },
+    Area { prefix: 86557, descriptions: &[("en", "Suzhou, Anhui"), ("zh", "安徽省宿州市")] },
+    Area { prefix: 86558, descriptions: &[("en", "Fuyang, Anhui"), ("zh", "安徽省阜阳市")] },
+    Area { prefix: 86559, descriptions: &[("en", "Huangshan, Anhui"), ("zh", "安徽省黄山市")] },
+    Area { prefix: 86561, descriptions: &[("en", "Huaibei, Anhui"), ("zh", "安徽省淮北市")] },
+    Area { prefix: 86562, descriptions: &[("en", "Tongling, Anhui"), ("zh", "安徽省铜陵市")] },
+    Area { prefix: 86563, descriptions: &[("en", "Xuancheng, Anhui"), ("zh", "安徽省宣城市")] },
+    Area { prefix: 86564, descriptions: &[("en", "LiuAn, Anhui"), ("zh", "安徽省六安市")] },
+    Area { prefix: 86566, descriptions: &[("en", "Chizhou, Anhui"), ("zh", "安徽省池州市")] },
+    Area { prefix: 86570, descriptions: &[("en", "Quzhou, Zhejiang"), ("zh", "浙江省衢州市")] },
+    Area { prefix: 86571, descriptions: &[("en", "Hangzhou, Zhejiang"), ("zh", "浙江省杭州市")] },
+    Area { prefix: 86572, descriptions: &[("en", "Huzhou, Zhejiang"), ("zh", "浙江省湖州市")] },
+    Area { prefix: 86573, descriptions: &[("en", "Jiaxing, Zhejiang"), ("zh", "浙江省嘉兴市")] },
+    Area { prefix: 86574, descriptions: &[("en", "Ningbo, Zhejiang"), ("zh", "浙江省宁波市")] },
+    Area { prefix: 86575, descriptions: &[("en", "Shaoxing, Zhejiang"), ("zh", "浙江省绍兴市")] },
+    Area { prefix: 86576, descriptions: &[("en", "Taizhou, Zhejiang"), ("zh", "浙江省台州市")] },
+    Area { prefix: 86577, descriptions: &[("en", "Wenzhou, Zhejiang"), ("zh", "浙江省温州市")] },
+    Area { prefix: 86578, descriptions: &[("en", "Lishui, Zhejiang"), ("zh", "浙江省丽水市")] },
+    Area { prefix: 86579, descriptions: &[("en", "Jinhua, Zhejiang"), ("zh", "浙江省金华市")] },
+    Area { prefix: 86591, descriptions: &[("en", "Fuzhou, Fujian"), ("zh", "福建省福州市")] },
+    Area { prefix: 86592, descriptions: &[("en", "Xiamen, Fujian"), ("zh", "福建省厦门市")] },
+    Area { prefix: 86593, descriptions: &[("en", "Ningde, Fujian"), ("zh", "福建省宁德市")] },
+    Area { prefix: 86594, descriptions: &[("en", "Putian, Fujian"), ("zh", "福建省莆田市")] },
+    Area { prefix: 86595, descriptions: &[("en", "Quanzhou, Fujian"), ("zh", "福建省泉州市")] },
+    Area { prefix: 86596, descriptions: &[("en", "Zhangzhou, Fujian"), ("zh", "福建省漳州市")] },
+    Area { prefix: 86597, descriptions: &[("en", "Longyan, Fujian"), ("zh", "福建省龙岩市")] },
+    Area { prefix: 86598, descriptions: &[("en", "Sanming, Fujian"), ("zh", "福建省三明市")] },
+    Area { prefix: 86599, descriptions: &[("en", "Nanping, Fujian"), ("zh", "福建省南平市")] },
+    Area { prefix: 86631, descriptions: &[("en", "Weihai, Shandong"), ("zh", "山东省威海市")] },
+    Area { prefix: 86632, descriptions: &[("en", "Zaozhuang, Shandong"), ("zh", "山东省枣庄市")] },
+    Area { prefix: 86633, descriptions: &[("en", "Rizhao, Shandong"), ("zh", "山东省日照市")] },
+    Area { prefix: 86634, descriptions: &[("en", "Laiwu, Shandong"), ("zh", "山东省莱芜市")] },
+    Area { prefix: 86635, descriptions: &[("en", "Liaocheng, Shandong"), ("zh", "山东省聊城市")] },
+    Area { prefix: 86660, descriptions: &[("en", "Shanwei, Guangdong"), ("zh", "广东省汕尾市")] },
+    Area { prefix: 86662, descriptions: &[("en", "Yangjiang, Guangdong"), ("zh", "广东省阳江市")] },
+    Area { prefix: 86663, descriptions: &[("en", "Jieyang, Guangdong"), ("zh", "广东省揭阳市")] },
+    Area { prefix: 86668, descriptions: &[("en", "Maoming, Guangdong"), ("zh", "广东省茂名市")] },
+    Area { prefix: 86691, descriptions: &[("en", "Jinghong, Yunnan"), ("zh", "云南省西双版纳傣族自治州")] },
+    Area { prefix: 86692, descriptions: &[("en", "Luxi, Yunnan"), ("zh", "云南省德宏傣族景颇族自治州")] },
+    Area { prefix: 86710, descriptions: &[("en", "Xiangyang/Xiangfan, Hubei"), ("zh", "湖北省襄樊市、襄樊市")] },
+    Area { prefix: 86711, descriptions: &[("en", "Ezhou, Hubei"), ("zh", "湖北省鄂州市")] },
+    Area { prefix: 86712, descriptions: &[("en", "Xiaogan, Hubei"), ("zh", "湖北省孝感市")] },
+    Area { prefix: 86713, descriptions: &[("en", "Huanggang, Hubei"), ("zh", "湖北省黄冈市")] },
+    Area { prefix: 86714, descriptions: &[("en", "Huangshi, Hubei"), ("zh", "湖北省黄石市")] },
+    Area { prefix: 86715, descriptions: &[("en", "Xianning, Hubei"), ("zh", "湖北省咸宁市")] },
+    Area { prefix: 86716, descriptions: &[("en", "Jingzhou, Hubei"), ("zh", "湖北省荆州市")] },
+    Area { prefix: 86717, descriptions: &[("en", "Yichang, Hubei"), ("zh", "湖北省宜昌市")] },
+    Area { prefix: 86718, descriptions: &[("en", "Enshi, Hubei"), ("zh", "湖北省恩施土家族苗族自治州")] },
+    Area { prefix: 86719, descriptions: &[("en", "Shiyan, Hubei"), ("zh", "湖北省十堰市")] },
+    Area { prefix: 86722, descriptions: &[("en", "Suizhou, Hubei"), ("zh", "湖北省随州市")] },
+    Area { prefix: 86724, descriptions: &[("en", "Jingmen, Hubei"), ("zh", "湖北省荆门市")] },
+    Area { prefix: 86728, descriptions: &[("en", "Xiantao, Hubei"), ("zh", "湖北省仙桃市")] },
+    Area { prefix: 86730, descriptions: &[("en", "Yueyang, Hunan"), ("zh", "湖南省岳阳市")] },
+    Area { prefix: 86731, descriptions: &[("en", "Zhuzhou/Changsha/Xiangtan, Hunan"), ("zh", "湖南省长沙市、湘潭市、株州市")] },
+    Area { prefix: 86734, descriptions: &[("en", "Hengyang, Hunan"), ("zh", "湖南省衡阳市")] },
+    Area { prefix: 86735, descriptions: &[("en", "Chenzhou, Hunan"), ("zh", "湖南省郴州市")] },
+    Area { prefix: 86736, descriptions: &[("en", "Changde, Hunan"), ("zh", "湖南省常德市")] },
+    Area { prefix: 86737, descriptions: &[("en", "Yiyang, Hunan"), ("zh", "湖南省益阳市")] },
+    Area { prefix: 86738, descriptions: &[("en", "Loudi, Hunan"), ("zh", "湖南省娄底市")] },
+    Area { prefix: 86739, descriptions: &[("en", "Shaoyang, Hunan"), ("zh", "湖南省邵阳市")] },
+    Area { prefix: 86743, descriptions: &[("en", "Jishou, Hunan"), ("zh", "湖南省湘西土家族苗族自治州")] },
+    Area { prefix: 86744, descriptions: &[("en", "Zhangjiajie, Hunan"), ("zh", "湖南省张家界市")] },
+    Area { prefix: 86745, descriptions: &[("en", "Huaihua, Hunan"), ("zh", "湖南省怀化市")] },
+    Area { prefix: 86746, descriptions: &[("en", "Yongzhou, Hunan"), ("zh", "湖南省永州市")] },
+    Area { prefix: 86750, descriptions: &[("en", "Jiangmen, Guangdong"), ("zh", "广东省江门市")] },
+    Area { prefix: 86751, descriptions: &[("en", "Shaoguan, Guangdong"), ("zh", "广东省韶关市")] },
+    Area { prefix: 86752, descriptions: &[("en", "Huizhou, Guangdong"), ("zh", "广东省惠州市")] },
+    Area { prefix: 86753, descriptions: &[("en", "Meizhou, Guangdong"), ("zh", "广东省梅州市")] },
+    Area { prefix: 86754, descriptions: &[("en", "Shantou/Chaoyang, Guangdong"), ("zh", "广东省汕头市、潮阳市")] },
+    Area { prefix: 86755, descriptions: &[("en", "Shenzhen, Guangdong"), ("zh", "广东省深圳市")] },
+    Area { prefix: 86756, descriptions: &[("en", "Zhuhai, Guangdong"), ("zh", "广东省珠海市")] },
+    Area { prefix: 86757, descriptions: &[("en", "Shunde/Foshan, Guangdong"), ("zh", "广东省佛山市、顺德市")] },
+    Area { prefix: 86758, descriptions: &[("en", "Zhaoqing, Guangdong"), ("zh", "广东省肇庆市")] },
+    Area { prefix: 86759, descriptions: &[("en", "Zhanjiang, Guangdong"), ("zh", "广东省湛江市")] },
+    Area { prefix: 86760, descriptions: &[("en", "Zhongshan, Guangdong"), ("zh", "广东省中山市")] },
+    Area { prefix: 86762, descriptions: &[("en", "Heyuan, Guangdong"), ("zh", "广东省河源市")] },
+    Area { prefix: 86763, descriptions: &[("en", "Qingyuan, Guangdong"), ("zh", "广东省清远市")] },
+    Area { prefix: 86766, descriptions: &[("en", "Yunfu, Guangdong"), ("zh", "广东省云浮市")] },
+    Area { prefix: 86768, descriptions: &[("en", "Chaozhou, Guangdong"), ("zh", "广东省潮州市")] },
+    Area { prefix: 86769, descriptions: &[("en", "Dongguan, Guangdong"), ("zh", "广东省东莞市")] },
+    Area { prefix: 86770, descriptions: &[("en", "Fangchenggang, Guangxi"), ("zh", "广西防城港市")] },
+    Area { prefix: 86771, descriptions: &[("en", "Nanning, Guangxi"), ("zh", "广西南宁市")] },
+    Area { prefix: 86772, descriptions: &[("en", "Liuzhou, Guangxi"), ("zh", "广西柳州市")] },
+    Area { prefix: 86773, descriptions: &[("en", "Guilin, Guangxi"), ("zh", "广西桂林市")] },
+    Area { prefix: 86774, descriptions: &[("en", "Wuzhou, Guangxi"), ("zh", "广西梧州市")] },
+    Area { prefix: 86775, descriptions: &[("en", "Yulin, Guangxi"), ("zh", "广西玉林市")] },
+    Area { prefix: 86776, descriptions: &[("en", "Baise, Guangxi"), ("zh", "广西百色市")] },
+    Area { prefix: 86777, descriptions: &[("en", "Qinzhou, Guangxi"), ("zh", "广西钦州市")] },
+    Area { prefix: 86778, descriptions: &[("en", "Hechi, Guangxi"), ("zh", "广西河池市")] },
+    Area { prefix: 86779, descriptions: &[("en", "Beihai, Guangxi"), ("zh", "广西北海市")] },
+    Area { prefix: 86790, descriptions: &[("en", "Xinyu, Jiangxi"), ("zh", "江西省新余市")] },
+    Area { prefix: 86791, descriptions: &[("en", "Nanchang, Jiangxi"), ("zh", "江西省南昌市")] },
+    Area { prefix: 86792, descriptions: &[("en", "Jiujiang, Jiangxi"), ("zh", "江西省九江市")] },
+    Area { prefix: 86793, descriptions: &[("en", "Shangrao, Jiangxi"), ("zh", "江西省上饶市")] },
+    Area { prefix: 86794, descriptions: &[("en", "Fuzhou, Jiangxi"), ("zh", "江西省抚州市")] },
+    Area { prefix: 86795, descriptions: &[("en", "Yichun, Jiangxi"), ("zh", "江西省宜春市")] },
+    Area { prefix: 86796, descriptions: &[("en", "JiAn, Jiangxi"), ("zh", "江西省吉安市")] },
+    Area { prefix: 86797, descriptions: &[("en", "Ganzhou, Jiangxi"), ("zh", "江西省赣州市")] },
+    Area { prefix: 86798, descriptions: &[("en", "Jingdezhen, Jiangxi"), ("zh", "江西省景德镇市")] },
+    Area { prefix: 86799, descriptions: &[("en", "Pingxiang, Jiangxi"), ("zh", "江西省萍乡市")] },
+    Area { prefix: 86807, descriptions: &[("en", "Suoxian, Tibet"), ("zh", "西藏索县")] },
+    Area { prefix: 86812, descriptions: &[("en", "Panzhihua, Sichuan"), ("zh", "四川省攀枝花市")] },
+    Area { prefix: 86813, descriptions: &[("en", "Zigong, Sichuan"), ("zh", "四川省自贡市")] },
+    Area { prefix: 86816, descriptions: &[("en", "Mianyang, Sichuan"), ("zh", "四川省绵阳市")] },
+    Area { prefix: 86817, descriptions: &[("en", "Nanchong, Sichuan"), ("zh", "四川省南充市")] },
+    Area { prefix: 86818, descriptions: &[("en", "Dazhou, Sichuan"), ("zh", "四川省达州市")] },
+    Area { prefix: 86825, descriptions: &[("en", "Suining, Sichuan"), ("zh", "四川省遂宁市")] },
+    Area { prefix: 86826, descriptions: &[("en", "GuangAn, Sichuan"), ("zh", "四川省广安市")] },
+    Area { prefix: 86827, descriptions: &[("en", "Bazhong, Sichuan"), ("zh", "四川省巴中市")] },
+    Area { prefix: 86830, descriptions: &[("en", "Luzhou, Sichuan"), ("zh", "四川省泸州市")] },
+    Area { prefix: 86831, descriptions: &[("en", "Yibin, Sichuan"), ("zh", "四川省宜宾市")] },
+    Area { prefix: 86832, descriptions: &[("en", "Ziyang/Neijiang, Sichuan"), ("zh", "四川省内江市、资阳市")] },
+    Area { prefix: 86833, descriptions: &[("en", "Leshan, Sichuan"), ("zh", "四川省乐山市")] },
+    Area { prefix: 86834, descriptions: &[("en", "Xichang, Sichuan"), ("zh", "四川省凉山彝族自治州")] },
+    Area { prefix: 86835, descriptions: &[("en", "YaAn, Sichuan"), ("zh", "四川省雅安市")] },
+    Area { prefix: 86836, descriptions: &[("en", "Kangding, Sichuan"), ("zh", "四川省甘孜藏族自治州")] },
+    Area { prefix: 86837, descriptions: &[("en", "MaErkang, Sichuan"), ("zh", "四川省阿坝藏族羌族自治州")] },
+    Area { prefix: 86838, descriptions: &[("en", "Deyang, Sichuan"), ("zh", "四川省德阳市")] },
+    Area { prefix: 86839, descriptions: &[("en", "Guangyuan, Sichuan"), ("zh", "四川省广元市")] },
+    Area { prefix: 86851, descriptions: &[("en", "Guiyang/Zunyi/Anshun, Guizhou"), ("zh", "贵州省贵阳市、遵义市、安顺市")] },
+    Area { prefix: 86852, descriptions: &[("en", "Zunyi, Guizhou"), ("zh", "贵州省遵义市")] },
+    Area { prefix: 86853, descriptions: &[("en", "Anshun, Guizhou"), ("zh", "贵州省安顺市")] },
+    Area { prefix: 86854, descriptions: &[("en", "Duyun, Guizhou"), ("zh", "贵州省黔南布依族苗族自治州")] },
+    Area { prefix: 86855, descriptions: &[("en", "Kaili, Guizhou"), ("zh", "贵州省黔东南苗族侗族自治州")] },
+    Area { prefix: 86856, descriptions: &[("en", "Tongren, Guizhou"), ("zh", "贵州省铜仁地区")] },
+    Area { prefix: 86857, descriptions: &[("en", "Bijie, Guizhou"), ("zh", "贵州省毕节地区")] },
+    Area { prefix: 86858, descriptions: &[("en", "Liupanshui, Guizhou"), ("zh", "贵州省六盘水市")] },
+    Area { prefix: 86859, descriptions: &[("en", "Xingyi, Guizhou"), ("zh", "贵州省黔西南布依族苗族自治州")] },
+    Area { prefix: 86870, descriptions: &[("en", "Zhaotong, Yunnan"), ("zh", "云南省昭通市")] },
+    Area { prefix: 86871, descriptions: &[("en", "Kunming, Yunnan"), ("zh", "云南省昆明市")] },
+    Area { prefix: 86872, descriptions: &[("en", "Dali, Yunnan"), ("zh", "云南省大理白族自治州")] },
+    Area { prefix: 86873, descriptions: &[("en", "Gejiu, Yunnan"), ("zh", "云南省红河哈尼族彝族自治州")] },
+    Area { prefix: 86874, descriptions: &[("en", "Qujing, Yunnan"), ("zh", "云南省曲靖市")] },
+    Area { prefix: 86875, descriptions: &[("en", "Baoshan, Yunnan"), ("zh", "云南省保山市")] },
+    Area { prefix: 86876, descriptions: &[("en", "Wenshan, Yunnan"), ("zh", "云南省文山壮族苗族自治州")] },
+    Area { prefix: 86877, descriptions: &[("en", "Yuxi, Yunnan"), ("zh", "云南省玉溪市")] },
+    Area { prefix: 86878, descriptions: &[("en", "Chuxiong, Yunnan"), ("zh", "云南省楚雄彝族自治州")] },
+    Area { prefix: 86879, descriptions: &[("en", "Simao, Yunnan"), ("zh", "云南省普洱市")] },
+    Area { prefix: 86883, descriptions: &[("en", "Lincang, Yunnan"), ("zh", "云南省临沧市")] },
+    Area { prefix: 86886, descriptions: &[("en", "Liuku, Yunnan"), ("zh", "云南省怒江傈僳族自治州")] },
+    Area { prefix: 86887, descriptions: &[("en", "Zhongdian, Yunnan"), ("zh", "云南省迪庆藏族自治州")] },
+    Area { prefix: 86888, descriptions: &[("en", "Lijiang, Yunnan"), ("zh", "云南省丽江市")] },
+    Area { prefix: 86891, descriptions: &[("en", "Lhasa, Tibet"), ("zh", "西藏拉萨市")] },
+    Area { prefix: 86892, descriptions: &[("en", "Rikaze/Zhongba, Tibet"), ("zh", "西藏日喀则地区、仲巴县")] },
+    Area { prefix: 86893, descriptions: &[("en", "Naidong, Tibet"), ("zh", "西藏山南地区")] },
+    Area { prefix: 86894, descriptions: &[("en", "Linzhi, Tibet"), ("zh", "西藏林芝地区")] },
+    Area { prefix: 86895, descriptions: &[("en", "Changdu, Tibet"), ("zh", "西藏昌都地区")] },
+    Area { prefix: 86896, descriptions: &[("en", "Naqu/Jiali/Nierong/Shenzha/Shuanghu/Bange/Nima, Tibet"), ("zh", "西藏那曲地区、嘉黎县、聂荣县、申扎县、双湖、班戈县、尼玛县")] },
+    Area { prefix: 86897, descriptions: &[("en", "GeEr/Pulan/Zhada, Tibet"), ("zh", "西藏阿里地区、普兰县、扎达县")] },
+    Area { prefix: 86898, descriptions: &[("en", "Haikou, Hainan"), ("zh", "海南省海口市")] },
+    Area { prefix: 86901, descriptions: &[("en", "Tacheng, Xinjiang"), ("zh", "新疆塔城地区")] },
+    Area { prefix: 86902, descriptions: &[("en", "Hami, Xinjiang"), ("zh", "新疆哈密地区")] },
+    Area { prefix: 86903, descriptions: &[("en", "Hetian, Xinjiang"), ("zh", "新疆和田地区")] },
+    Area { prefix: 86906, descriptions: &[("en", "Aletai, Xinjiang"), ("zh", "新疆阿勒泰地区")] },
+    Area { prefix: 86908, descriptions: &[("en", "Atushi, Xinjiang"), ("zh", "新疆克孜勒苏柯尔克孜自治州")] },
+    Area { prefix: 86909, descriptions: &[("en", "Bole, Xinjiang"), ("zh", "新疆博尔塔拉蒙古自治州")] },
+    Area { prefix: 86911, descriptions: &[("en", "YanAn, Shaanxi"), ("zh", "陕西省延安市")] },
+    Area { prefix: 86912, descriptions: &[("en", "Yulin, Shaanxi"), ("zh", "陕西省榆林市")] },
+    Area { prefix: 86913, descriptions: &[("en", "Weinan, Shaanxi"), ("zh", "陕西省渭南市")] },
+    Area { prefix: 86914, descriptions: &[("en", "Shangzhou, Shaanxi"), ("zh", "陕西省商洛市")] },
+    Area { prefix: 86915, descriptions: &[("en", "Ankang, Shaanxi"), ("zh", "陕西省安康市")] },
+    Area { prefix: 86916, descriptions: &[("en", "Hanzhong, Shaanxi"), ("zh", "陕西省汉中市")] },
+    Area { prefix: 86917, descriptions: &[("en", "Baoji, Shaanxi"), ("zh", "陕西省宝鸡市")] },
+    Area { prefix: 86919, descriptions: &[("en", "Tongchuan, Shaanxi"), ("zh", "陕西省铜川市")] },
+    Area { prefix: 86930, descriptions: &[("en", "Linxia, Gansu"), ("zh", "甘肃省临夏回族自治州")] },
+    Area { prefix: 86931, descriptions: &[("en", "Lanzhou, Gansu"), ("zh", "甘肃省兰州市")] },
+    Area { prefix: 86932, descriptions: &[("en", "Dingxi, Gansu"), ("zh", "甘肃省定西市")] },
+    Area { prefix: 86933, descriptions: &[("en", "Pingliang, Gansu"), ("zh", "甘肃省平凉市")] },
+    Area { prefix: 86934, descriptions: &[("en", "Xifeng, Gansu"), ("zh", "甘肃省庆阳市")] },
+    Area { prefix: 86935, descriptions: &[("en", "Jinchang, Gansu"), ("zh", "甘肃省武威市")] },
+    Area { prefix: 86936, descriptions: &[("en", "Zhangye, Gansu"), ("zh", "甘肃省张掖市")] },
+    Area { prefix: 86937, descriptions: &[("en", "Jiuquan, Gansu"), ("zh", "甘肃省酒泉市")] },
+    Area { prefix: 86938, descriptions: &[("en", "Tianshui, Gansu"), ("zh", "甘肃省天水市")] },
+    Area { prefix: 86939, descriptions: &[("en", "Wudu, Gansu"), ("zh", "甘肃省陇南市")] },
+    Area { prefix: 86941, descriptions: &[("en", "Hezuo, Gansu"), ("zh", "甘肃省甘南藏族自治州")] },
+    Area { prefix: 86943, descriptions: &[("en", "Baiyin, Gansu"), ("zh", "甘肃省白银市")] },
+    Area { prefix: 86951, descriptions: &[("en", "Yinchuan, Ningxia"), ("zh", "宁夏银川市")] },
+    Area { prefix: 86952, descriptions: &[("en", "Shizuishan, Ningxia"), ("zh", "宁夏石嘴山市")] },
+    Area { prefix: 86953, descriptions: &[("en", "Wuzhong, Ningxia"), ("zh", "宁夏吴忠市")] },
+    Area { prefix: 86954, descriptions: &[("en", "Guyuan, Ningxia"), ("zh", "宁夏固原市")] },
+    Area { prefix: 86955, descriptions: &[("en", "Zhongwei, Ningxia"), ("zh", "宁夏中卫市")] },
+    Area { prefix: 86970, descriptions: &[("en", "Haiyan, Qinghai"), ("zh", "青海省海北藏族自治州")] },
+    Area { prefix: 86971, descriptions: &[("en", "Xining, Qinghai"), ("zh", "青海省西宁市")] },
+    Area { prefix: 86972, descriptions: &[("en", "PingAn, Qinghai"), ("zh", "青海省海东地区")] },
+    Area { prefix: 86973, descriptions: &[("en", "Tongren, Qinghai"), ("zh", "青海省黄南藏族自治州")] },
+    Area { prefix: 86974, descriptions: &[("en", "Gonghe, Qinghai"), ("zh", "青海省海南藏族自治州")] },
+    Area { prefix: 86975, descriptions: &[("en", "Maqin, Qinghai"), ("zh", "青海省果洛藏族自治州")] },
+    Area { prefix: 86976, descriptions: &[("en", "Yushu, Qinghai"), ("zh", "青海省玉树藏族自治州")] },
+    Area { prefix: 86977, descriptions: &[("en", "Delingha, Qinghai"), ("zh", "青海省海西蒙古族藏族自治州")] },
+    Area { prefix: 86979, descriptions: &[("en", "GeErmu, Qinghai"), ("zh", "青海省海西蒙古族藏族自治州")] },
+    Area { prefix: 86990, descriptions: &[("en", "Kalamayi, Xinjiang"), ("zh", "新疆克拉玛依市")] },
+    Area { prefix: 86991, descriptions: &[("en", "Urumchi, Xinjiang"), ("zh", "新疆乌鲁木齐市")] },
+    Area { prefix: 86992, descriptions: &[("en", "Kuitun, Xinjiang"), ("zh", "新疆伊犁哈萨克自治州")] },
+    Area { prefix: 86993, descriptions: &[("en", "Shihezi, Xinjiang"), ("zh", "新疆石河子市")] },
+    Area { prefix: 86994, descriptions: &[("en", "Changji, Xinjiang"), ("zh", "新疆昌吉回族自治州")] },
+    Area { prefix: 86995, descriptions: &[("en", "Tulufan, Xinjiang"), ("zh", "新疆吐鲁番地区")] },
+    Area { prefix: 86996, descriptions: &[("en", "KuErle, Xinjiang"), ("zh", "新疆巴音郭楞蒙古自治州")] },
+    Area { prefix: 86997, descriptions: &[("en", "Akesu, Xinjiang"), ("zh", "新疆阿克苏地区")] },
+    Area { prefix: 86998, descriptions: &[("en", "Kashi, Xinjiang"), ("zh", "新疆喀什地区")] },
+    Area { prefix: 86999, descriptions: &[("en", "Yining, Xinjiang"), ("zh", "新疆伊犁哈萨克自治州")] },
+    Area { prefix: 88024, descriptions: &[("en", "Dhaka")] },
+    Area { prefix: 88025, descriptions: &[("en", "Dhaka")] },
+    Area { prefix: 88027, descriptions: &[("en", "Dhaka")] },
+    Area { prefix: 88028, descriptions: &[("en", "Dhaka")] },
+    Area { prefix: 88029, descriptions: &[("en", "Dhaka")] },
+    Area { prefix: 88036, descriptions: &[("en", "Bandarban")] },
+    Area { prefix: 88041, descriptions: &[("en", "Khulna")] },
+    Area { prefix: 88047, descriptions: &[("en", "Satkhira")] },
+    Area { prefix: 88051, descriptions: &[("en", "Bogra/Gabtali/Nandigram/Sherpur")] },
+    Area { prefix: 88059, descriptions: &[("en", "Lalmonirhat")] },
+    Area { prefix: 88070, descriptions: &[("en", "Bheramara")] },
+    Area { prefix: 88071, descriptions: &[("en", "Kushtia")] },
+    Area { prefix: 88079, descriptions: &[("en", "Meherpur")] },
+    Area { prefix: 88081, descriptions: &[("en", "Homna/Comilla")] },
+    Area { prefix: 88091, descriptions: &[("en", "Mymensingh")] },
+    Area { prefix: 88093, descriptions: &[("en", "Nalitabari/Nakla/Sherpur")] },
+    Area { prefix: 88095, descriptions: &[("en", "Netrokona")] },
+    Area { prefix: 88098, descriptions: &[("en", "Jamalpur/Islampur/Dewanganj")] },
+    Area { prefix: 88637, descriptions: &[("en", "Miaoli"), ("zh", "苗栗"), ("zh-Hant", "苗栗")] },
+    Area { prefix: 88641, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 88642, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化"), ("zh-Hant", "臺中、彰化")] },
+    Area { prefix: 88643, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化"), ("zh-Hant", "臺中、彰化")] },
+    Area { prefix: 88644, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 88647, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化"), ("zh-Hant", "臺中、彰化")] },
+    Area { prefix: 88648, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化"), ("zh-Hant", "臺中、彰化")] },
+    Area { prefix: 88649, descriptions: &[("en", "Nantou"), ("zh", "南投"), ("zh-Hant", "南投")] },
+    Area { prefix: 88652, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88653, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88654, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88655, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88656, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88657, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88658, descriptions: &[("en", "Chiayi/Yunlin"), ("zh", "嘉义、云林")] },
+    Area { prefix: 88671, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88672, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88673, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88674, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88675, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88676, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88677, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88678, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88679, descriptions: &[("en", "Kaohsiung"), ("zh", "高雄")] },
+    Area { prefix: 88680, descriptions: &[("en", "Pingtung"), ("zh", "屏东"), ("zh-Hant", "屏東")] },
+    Area { prefix: 88683, descriptions: &[("en", "Matsu"), ("zh", "马祖"), ("zh-Hant", "馬祖")] },
+    Area { prefix: 88684, descriptions: &[("en", "Pingtung"), ("zh", "屏东")] },
+    Area { prefix: 88687, descriptions: &[("en", "Pingtung"), ("zh", "屏东"), ("zh-Hant", "屏東")] },
+    Area { prefix: 88688, descriptions: &[("en", "Pingtung"), ("zh", "屏东"), ("zh-Hant", "屏東")] },
+    Area { prefix: 88689, descriptions: &[("en", "Taitung"), ("zh", "台东"), ("zh-Hant", "臺東")] },
+    Area { prefix: 90212, descriptions: &[("en", "Istanbul (Europe)"), ("tr", "Istanbul (Avrupa)")] },
+    Area { prefix: 90216, descriptions: &[("en", "Istanbul (Anatolia)")] },
+    Area { prefix: 90222, descriptions: &[("en", "Esksehir"), ("tr", "Eskisehir")] },
+    Area { prefix: 90224, descriptions: &[("en", "Bursa")] },
+    Area { prefix: 90226, descriptions: &[("en", "Yalova")] },
+    Area { prefix: 90228, descriptions: &[("en", "Bilecik")] },
+    Area { prefix: 90232, descriptions: &[("en", "Izmir"), ("tr", "İzmir")] },
+    Area { prefix: 90236, descriptions: &[("en", "Manisa")] },
+    Area { prefix: 90242, descriptions: &[("en", "Antalya")] },
+    Area { prefix: 90246, descriptions: &[("en", "Isparta")] },
+    Area { prefix: 90248, descriptions: &[("en", "Burdur")] },
+    Area { prefix: 90252, descriptions: &[("en", "Mugla"), ("tr", "Muğla")] },
+    Area { prefix: 90256, descriptions: &[("en", "Aydin"), ("tr", "Aydın")] },
+    Area { prefix: 90258, descriptions: &[("en", "Denizli")] },
+    Area { prefix: 90262, descriptions: &[("en", "Kocaeli"), ("tr", "Kocaeli (İzmit)")] },
+    Area { prefix: 90264, descriptions: &[("en", "Sakarya"), ("tr", "Sakarya (Adapazarı)")] },
+    Area { prefix: 90266, descriptions: &[("en", "Balikesir"), ("tr", "Balıkesir")] },
+    Area { prefix: 90272, descriptions: &[("en", "Afyon")] },
+    Area { prefix: 90274, descriptions: &[("en", "Kutahya"), ("tr", "Kütahya")] },
+    Area { prefix: 90276, descriptions: &[("en", "Usak"), ("tr", "Uşak")] },
+    Area { prefix: 90282, descriptions: &[("en", "Tekirdag"), ("tr", "Tekirdağ")] },
+    Area { prefix: 90284, descriptions: &[("en", "Edirne")] },
+    Area { prefix: 90286, descriptions: &[("en", "Canakkale"), ("tr", "Çanakkale")] },
+    Area { prefix: 90288, descriptions: &[("en", "Kirklareli"), ("tr", "Kırklareli")] },
+    Area { prefix: 90312, descriptions: &[("en", "Ankara")] },
+    Area { prefix: 90318, descriptions: &[("en", "Kirikkale"), ("tr", "Kırıkkale")] },
+    Area { prefix: 90322, descriptions: &[("en", "Adana")] },
+    Area { prefix: 90324, descriptions: &[("en", "Icel"), ("tr", "Mersin")] },
+    Area { prefix: 90326, descriptions: &[("en", "Hatay")] },
+    Area { prefix: 90328, descriptions: &[("en", "Osmaniye")] },
+    Area { prefix: 90332, descriptions: &[("en", "Konya")] },
+    Area { prefix: 90338, descriptions: &[("en", "Karaman")] },
+    Area { prefix: 90342, descriptions: &[("en", "Gaziantep")] },
+    Area { prefix: 90344, descriptions: &[("en", "K. Maras"), ("tr", "Kahramanmaraş")] },
+    Area { prefix: 90346, descriptions: &[("en", "Sivas")] },
+    Area { prefix: 90348, descriptions: &[("en", "Kilis")] },
+    Area { prefix: 90352, descriptions: &[("en", "Kayseri")] },
+    Area { prefix: 90354, descriptions: &[("en", "Yozgat")] },
+    Area { prefix: 90356, descriptions: &[("en", "Tokat")] },
+    Area { prefix: 90358, descriptions: &[("en", "Amasya")] },
+    Area { prefix: 90362, descriptions: &[("en", "Samsun")] },
+    Area { prefix: 90364, descriptions: &[("en", "Corum"), ("tr", "Çorum")] },
+    Area { prefix: 90366, descriptions: &[("en", "Kastamonu")] },
+    Area { prefix: 90368, descriptions: &[("en", "Sinop")] },
+    Area { prefix: 90370, descriptions: &[("en", "Karabuk"), ("tr", "Karabük")] },
+    Area { prefix: 90372, descriptions: &[("en", "Zongdulak"), ("tr", "Zonguldak")] },
+    Area { prefix: 90374, descriptions: &[("en", "Bolu")] },
+    Area { prefix: 90376, descriptions: &[("en", "Cankiri"), ("tr", "Çankırı")] },
+    Area { prefix: 90378, descriptions: &[("en", "Bartin"), ("tr", "Bartın")] },
+    Area { prefix: 90380, descriptions: &[("en", "Duzce"), ("tr", "Düzce")] },
+    Area { prefix: 90382, descriptions: &[("en", "Aksaray")] },
+    Area { prefix: 90384, descriptions: &[("en", "Nevsehir"), ("tr", "Nevşehir")] },
+    Area { prefix: 90386, descriptions: &[("en", "Kirsehir"), ("tr", "Kırşehir")] },
+    Area { prefix: 90388, descriptions: &[("en", "Nigde"), ("tr", "Niğde")] },
+    Area { prefix: 90412, descriptions: &[("en", "Diyarbakir"), ("tr", "Diyarbakır")] },
+    Area { prefix: 90414, descriptions: &[("en", "Sanliurfa"), ("tr", "Şanlıurfa")] },
+    Area { prefix: 90416, descriptions: &[("en", "Adiyaman"), ("tr", "Adıyaman")] },
+    Area { prefix: 90422, descriptions: &[("en", "Malatya")] },
+    Area { prefix: 90424, descriptions: &[("en", "Elazig"), ("tr", "Elazığ")] },
+    Area { prefix: 90426, descriptions: &[("en", "Bingol"), ("tr", "Bingöl")] },
+    Area { prefix: 90428, descriptions: &[("en", "Tuniceli"), ("tr", "Tunceli")] },
+    Area { prefix: 90432, descriptions: &[("en", "Van")] },
+    Area { prefix: 90434, descriptions: &[("en", "Bitlis")] },
+    Area { prefix: 90436, descriptions: &[("en", "Mus"), ("tr", "Muş")] },
+    Area { prefix: 90438, descriptions: &[("en", "Hakkari")] },
+    Area { prefix: 90442, descriptions: &[("en", "Erzurum")] },
+    Area { prefix: 90446, descriptions: &[("en", "Erzincan")] },
+    Area { prefix: 90452, descriptions: &[("en", "Ordu")] },
+    Area { prefix: 90454, descriptions: &[("en", "Giresun")] },
+    Area { prefix: 90456, descriptions: &[("en", "Gumushane"), ("tr", "Gümüşhane")] },
+    Area { prefix: 90458, descriptions: &[("en", "Bayburt")] },
+    Area { prefix: 90462, descriptions: &[("en", "Trabzon")] },
+    Area { prefix: 90464, descriptions: &[("en", "Rize")] },
+    Area { prefix: 90466, descriptions: &[("en", "Artvin")] },
+    Area { prefix: 90472, descriptions: &[("en", "Agri"), ("tr", "Ağrı")] },
+    Area { prefix: 90474, descriptions: &[("en", "Kars")] },
+    Area { prefix: 90476, descriptions: &[("en", "Igdir"), ("tr", "Iğdır")] },
+    Area { prefix: 90478, descriptions: &[("en", "Ardahan")] },
+    Area { prefix: 90482, descriptions: &[("en", "Mardin")] },
+    Area { prefix: 90484, descriptions: &[("en", "Stirt"), ("tr", "Siirt")] },
+    Area { prefix: 90486, descriptions: &[("en", "Sirnak"), ("tr", "Şırnak")] },
+    Area { prefix: 90488, descriptions: &[("en", "Batman")] },
+    Area { prefix: 91120, descriptions: &[("en", "Ghaziabad/Dadri, Uttar Pradesh")] },
+    Area { prefix: 91121, descriptions: &[("en", "Meerut, Uttar Pradesh")] },
+    Area { prefix: 91122, descriptions: &[("en", "Hapur, Uttar Pradesh")] },
+    Area { prefix: 91124, descriptions: &[("en", "Gurgaon, Haryana")] },
+    Area { prefix: 91129, descriptions: &[("en", "Faridabad, Haryana")] },
+    Area { prefix: 91130, descriptions: &[("en", "Sonipat, Haryana")] },
+    Area { prefix: 91131, descriptions: &[("en", "Muzaffarnagar, Uttar Pradesh")] },
+    Area { prefix: 91132, descriptions: &[("en", "Saharanpur, Uttar Pradesh")] },
+    Area { prefix: 91135, descriptions: &[("en", "Dehradun, Uttarakhand")] },
+    Area { prefix: 91141, descriptions: &[("en", "Jaipur, Rajasthan")] },
+    Area { prefix: 91144, descriptions: &[("en", "Alwar, Rajasthan")] },
+    Area { prefix: 91145, descriptions: &[("en", "Ajmer, Rajasthan")] },
+    Area { prefix: 91151, descriptions: &[("en", "Bikaner, Rajasthan")] },
+    Area { prefix: 91154, descriptions: &[("en", "Sriganganagar, Rajasthan")] },
+    Area { prefix: 91161, descriptions: &[("en", "Ludhiana, Punjab")] },
+    Area { prefix: 91164, descriptions: &[("en", "Bhatinda, Punjab")] },
+    Area { prefix: 91171, descriptions: &[("en", "Ambala, Haryana")] },
+    Area { prefix: 91172, descriptions: &[("en", "Chandigarh, Punjab")] },
+    Area { prefix: 91175, descriptions: &[("en", "Patiala, Punjab")] },
+    Area { prefix: 91177, descriptions: &[("en", "Shimla, Himachal Pradesh")] },
+    Area { prefix: 91181, descriptions: &[("en", "Jallandhar, Punjab")] },
+    Area { prefix: 91183, descriptions: &[("en", "Amritsar, Punjab")] },
+    Area { prefix: 91184, descriptions: &[("en", "Karnal, Haryana")] },
+    Area { prefix: 91186, descriptions: &[("en", "Pathankot, Punjab")] },
+    Area { prefix: 91191, descriptions: &[("en", "Jammu, Jammu And Kashmir")] },
+    Area { prefix: 91194, descriptions: &[("en", "Srinagar, Jammu And Kashmir")] },
+    Area { prefix: 91202, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91203, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91204, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91205, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91206, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91207, descriptions: &[("en", "Pune, Maharashtra")] },
+    Area { prefix: 91212, descriptions: &[("en", "Chinchwad, Maharashtra")] },
+    Area { prefix: 91215, descriptions: &[("en", "Navi Mumbai/Turbhe, Maharashtra")] },
+    Area { prefix: 91217, descriptions: &[("en", "Sholapur, Maharashtra")] },
+    Area { prefix: 91222, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91223, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91224, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91225, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91226, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91227, descriptions: &[("en", "Mumbai")] },
+    Area { prefix: 91230, descriptions: &[("en", "Khadakwasala, Maharashtra")] },
+    Area { prefix: 91231, descriptions: &[("en", "Kolhapur, Maharashtra")] },
+    Area { prefix: 91233, descriptions: &[("en", "Sangli, Maharashtra")] },
+    Area { prefix: 91241, descriptions: &[("en", "Ahmednagar, Maharashtra")] },
+    Area { prefix: 91250, descriptions: &[("en", "Bassein, Maharashtra")] },
+    Area { prefix: 91251, descriptions: &[("en", "Kalyan, Maharashtra")] },
+    Area { prefix: 91253, descriptions: &[("en", "Nasik City, Maharashtra")] },
+    Area { prefix: 91257, descriptions: &[("en", "Jalgaon, Maharashtra")] },
+    Area { prefix: 91260, descriptions: &[("en", "Vapi, Gujarat")] },
+    Area { prefix: 91261, descriptions: &[("en", "Surat, Gujarat")] },
+    Area { prefix: 91265, descriptions: &[("en", "Vadodara, Gujarat")] },
+    Area { prefix: 91268, descriptions: &[("en", "Nadiad, Gujarat")] },
+    Area { prefix: 91278, descriptions: &[("en", "Bhavnagar, Gujarat")] },
+    Area { prefix: 91281, descriptions: &[("en", "Rajkot, Gujarat")] },
+    Area { prefix: 91285, descriptions: &[("en", "Junagarh, Gujarat")] },
+    Area { prefix: 91286, descriptions: &[("en", "Porbander, Gujarat")] },
+    Area { prefix: 91288, descriptions: &[("en", "Jamnagar, Gujarat")] },
+    Area { prefix: 91291, descriptions: &[("en", "Jodhpur, Rajasthan")] },
+    Area { prefix: 91294, descriptions: &[("en", "Udaipur Girwa/Udaipur, Rajasthan")] },
+    Area { prefix: 91326, descriptions: &[("en", "Dhanbad, Bihar")] },
+    Area { prefix: 91341, descriptions: &[("en", "Asansol, West Bengal")] },
+    Area { prefix: 91342, descriptions: &[("en", "Burdwan, West Bengal")] },
+    Area { prefix: 91343, descriptions: &[("en", "Durgapur, West Bengal")] },
+    Area { prefix: 91353, descriptions: &[("en", "Siliguri, West Bengal")] },
+    Area { prefix: 91354, descriptions: &[("en", "Darjeeling, West Bengal")] },
+    Area { prefix: 91360, descriptions: &[("en", "Itanagar/Ziro, Arunachal Pradesh")] },
+    Area { prefix: 91361, descriptions: &[("en", "Guwahati, Assam")] },
+    Area { prefix: 91364, descriptions: &[("en", "Shillong, Meghalaya")] },
+    Area { prefix: 91368, descriptions: &[("en", "Passighat, Arunachal Pradesh")] },
+    Area { prefix: 91369, descriptions: &[("en", "Mokokchung, Nagaland")] },
+    Area { prefix: 91370, descriptions: &[("en", "Kohima, Nagaland")] },
+    Area { prefix: 91372, descriptions: &[("en", "Lungleh, Mizoram")] },
+    Area { prefix: 91373, descriptions: &[("en", "Dibrugarh, Assam")] },
+    Area { prefix: 91374, descriptions: &[("en", "Tinsukhia, Assam")] },
+    Area { prefix: 91376, descriptions: &[("en", "Jorhat, Assam")] },
+    Area { prefix: 91381, descriptions: &[("en", "Agartala, Tripura")] },
+    Area { prefix: 91385, descriptions: &[("en", "Imphal, Manipur")] },
+    Area { prefix: 91389, descriptions: &[("en", "Aizawal, Mizoram")] },
+    Area { prefix: 91413, descriptions: &[("en", "Pondicherry, Tamil Nadu")] },
+    Area { prefix: 91416, descriptions: &[("en", "Vellore, Tamil Nadu")] },
+    Area { prefix: 91421, descriptions: &[("en", "Tirupur, Tamil Nadu")] },
+    Area { prefix: 91422, descriptions: &[("en", "Coimbatore, Tamil Nadu")] },
+    Area { prefix: 91423, descriptions: &[("en", "Udhagamandalam, Tamil Nadu")] },
+    Area { prefix: 91424, descriptions: &[("en", "Erode, Tamil Nadu")] },
+    Area { prefix: 91427, descriptions: &[("en", "Salem, Tamil Nadu")] },
+    Area { prefix: 91431, descriptions: &[("en", "Tiruchchirappalli, Tamil Nadu")] },
+    Area { prefix: 91435, descriptions: &[("en", "Kumbakonam, Tamil Nadu")] },
+    Area { prefix: 91451, descriptions: &[("en", "Dindigul, Tamil Nadu")] },
+    Area { prefix: 91452, descriptions: &[("en", "Madurai, Tamil Nadu")] },
+    Area { prefix: 91460, descriptions: &[("en", "Taliparamba, Kerala")] },
+    Area { prefix: 91461, descriptions: &[("en", "Thoothukudi, Tamil Nadu")] },
+    Area { prefix: 91462, descriptions: &[("en", "Tirunelvelli, Tamil Nadu")] },
+    Area { prefix: 91469, descriptions: &[("en", "Tiruvalla, Kerala")] },
+    Area { prefix: 91470, descriptions: &[("en", "Attingal, Kerala")] },
+    Area { prefix: 91471, descriptions: &[("en", "Thiruvananthapuram, Kerala")] },
+    Area { prefix: 91474, descriptions: &[("en", "Kollam, Kerala")] },
+    Area { prefix: 91475, descriptions: &[("en", "Punalur, Kerala")] },
+    Area { prefix: 91476, descriptions: &[("en", "Karunagapally, Kerala")] },
+    Area { prefix: 91477, descriptions: &[("en", "Alappuzha, Kerala")] },
+    Area { prefix: 91478, descriptions: &[("en", "Cherthala, Kerala")] },
+    Area { prefix: 91479, descriptions: &[("en", "Mavelikkara, Kerala")] },
+    Area { prefix: 91480, descriptions: &[("en", "Irinjalakuda, Kerala")] },
+    Area { prefix: 91481, descriptions: &[("en", "Kottayam, Kerala")] },
+    Area { prefix: 91483, descriptions: &[("en", "Manjeri, Kerala")] },
+    Area { prefix: 91484, descriptions: &[("en", "Ernakulam, Kerala")] },
+    Area { prefix: 91485, descriptions: &[("en", "Muvattupuzha, Kerala")] },
+    Area { prefix: 91487, descriptions: &[("en", "Thrissur, Kerala")] },
+    Area { prefix: 91490, descriptions: &[("en", "Tellicherry, Kerala")] },
+    Area { prefix: 91491, descriptions: &[("en", "Palakkad, Kerala")] },
+    Area { prefix: 91494, descriptions: &[("en", "Tirur, Kerala")] },
+    Area { prefix: 91495, descriptions: &[("en", "Kozhikode, Kerala")] },
+    Area { prefix: 91496, descriptions: &[("en", "Vatakara, Kerala")] },
+    Area { prefix: 91497, descriptions: &[("en", "Kannur, Kerala")] },
+    Area { prefix: 91512, descriptions: &[("en", "Kanpur, Uttar Pradesh")] },
+    Area { prefix: 91515, descriptions: &[("en", "Unnao, Uttar Pradesh")] },
+    Area { prefix: 91522, descriptions: &[("en", "Lucknow, Uttar Pradesh")] },
+    Area { prefix: 91532, descriptions: &[("en", "Allahabad, Uttar Pradesh")] },
+    Area { prefix: 91535, descriptions: &[("en", "Raibareli, Uttar Pradesh")] },
+    Area { prefix: 91542, descriptions: &[("en", "Varansi, Uttar Pradesh")] },
+    Area { prefix: 91548, descriptions: &[("en", "Ghazipur, Uttar Pradesh")] },
+    Area { prefix: 91551, descriptions: &[("en", "Gorakhpur, Uttar Pradesh")] },
+    Area { prefix: 91562, descriptions: &[("en", "Agra, Uttar Pradesh")] },
+    Area { prefix: 91565, descriptions: &[("en", "Mathura, Uttar Pradesh")] },
+    Area { prefix: 91571, descriptions: &[("en", "Aligarh, Uttar Pradesh")] },
+    Area { prefix: 91581, descriptions: &[("en", "Bareilly, Uttar Pradesh")] },
+    Area { prefix: 91591, descriptions: &[("en", "Moradabad, Uttar Pradesh")] },
+    Area { prefix: 91595, descriptions: &[("en", "Rampur, Uttar Pradesh")] },
+    Area { prefix: 91721, descriptions: &[("en", "Amravati, Maharashtra")] },
+    Area { prefix: 91724, descriptions: &[("en", "Akola, Maharashtra")] },
+    Area { prefix: 91731, descriptions: &[("en", "Indore, Madhya Pradesh")] },
+    Area { prefix: 91733, descriptions: &[("en", "Khandwa, Madhya Pradesh")] },
+    Area { prefix: 91734, descriptions: &[("en", "Ujjain, Madhya Pradesh")] },
+    Area { prefix: 91755, descriptions: &[("en", "Bhopal, Madhya Pradesh")] },
+    Area { prefix: 91761, descriptions: &[("en", "Jabalpur, Madhya Pradesh")] },
+    Area { prefix: 91771, descriptions: &[("en", "Raipur, Madhya Pradesh")] },
+    Area { prefix: 91788, descriptions: &[("en", "Durg, Madhya Pradesh")] },
+    Area { prefix: 91790, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91792, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91793, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91794, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91795, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91796, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91797, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91798, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91799, descriptions: &[("en", "Ahmedabad Local, Gujarat")] },
+    Area { prefix: 91816, descriptions: &[("en", "Tumkur, Karnataka")] },
+    Area { prefix: 92212, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92213, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92214, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92215, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92216, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92217, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92218, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92219, descriptions: &[("en", "Karachi")] },
+    Area { prefix: 92222, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92223, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92224, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92225, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92226, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92227, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92228, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92229, descriptions: &[("en", "Hyderabad")] },
+    Area { prefix: 92252, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92253, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92254, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92255, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92256, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92257, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92258, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92259, descriptions: &[("en", "Dadu")] },
+    Area { prefix: 92402, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92403, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92404, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92405, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92406, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92407, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92408, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92409, descriptions: &[("en", "Sahiwal")] },
+    Area { prefix: 92412, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92413, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92414, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92415, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92416, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92417, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92418, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92419, descriptions: &[("en", "Faisalabad")] },
+    Area { prefix: 92422, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92423, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92424, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92425, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92426, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92427, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92428, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92429, descriptions: &[("en", "Lahore")] },
+    Area { prefix: 92442, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92443, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92444, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92445, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92446, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92447, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92448, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92449, descriptions: &[("en", "Okara")] },
+    Area { prefix: 92462, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92463, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92464, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92465, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92466, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92467, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92468, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92469, descriptions: &[("en", "Toba Tek Singh")] },
+    Area { prefix: 92472, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92473, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92474, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92475, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92476, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92477, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92478, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92479, descriptions: &[("en", "Jhang")] },
+    Area { prefix: 92482, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92483, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92484, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92485, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92486, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92487, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92488, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92489, descriptions: &[("en", "Sargodha")] },
+    Area { prefix: 92492, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92493, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92494, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92495, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92496, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92497, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92498, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92499, descriptions: &[("en", "Kasur")] },
+    Area { prefix: 92512, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92513, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92514, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92515, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92516, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92517, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92518, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92519, descriptions: &[("en", "Islamabad/Rawalpindi")] },
+    Area { prefix: 92522, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92523, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92524, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92525, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92526, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92527, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92528, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92529, descriptions: &[("en", "Sialkot")] },
+    Area { prefix: 92532, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92533, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92534, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92535, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92536, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92537, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92538, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92539, descriptions: &[("en", "Gujrat")] },
+    Area { prefix: 92552, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92553, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92554, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92555, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92556, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92557, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92558, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92559, descriptions: &[("en", "Gujranwala")] },
+    Area { prefix: 92562, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92563, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92564, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92565, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92566, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92567, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92568, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92569, descriptions: &[("en", "Sheikhupura")] },
+    Area { prefix: 92572, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92573, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92574, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92575, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92576, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92577, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92578, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92579, descriptions: &[("en", "Attock")] },
+    Area { prefix: 92612, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92613, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92614, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92615, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92616, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92617, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92618, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92619, descriptions: &[("en", "Multan")] },
+    Area { prefix: 92622, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92623, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92624, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92625, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92626, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92627, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92628, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92629, descriptions: &[("en", "Bahawalpur")] },
+    Area { prefix: 92632, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92633, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92634, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92635, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92636, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92637, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92638, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92639, descriptions: &[("en", "Bahawalnagar")] },
+    Area { prefix: 92642, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92643, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92644, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92645, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92646, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92647, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92648, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92649, descriptions: &[("en", "Dera Ghazi Khan")] },
+    Area { prefix: 92652, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92653, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92654, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92655, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92656, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92657, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92658, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92659, descriptions: &[("en", "Khanewal")] },
+    Area { prefix: 92662, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92663, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92664, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92665, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92666, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92667, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92668, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92669, descriptions: &[("en", "Muzaffargarh")] },
+    Area { prefix: 92672, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92673, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92674, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92675, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92676, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92677, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92678, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92679, descriptions: &[("en", "Vehari")] },
+    Area { prefix: 92682, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92683, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92684, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92685, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92686, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92687, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92688, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92689, descriptions: &[("en", "Rahim Yar Khan")] },
+    Area { prefix: 92712, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92713, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92714, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92715, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92716, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92717, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92718, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92719, descriptions: &[("en", "Sukkur")] },
+    Area { prefix: 92742, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92743, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92744, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92745, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92746, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92747, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92748, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92749, descriptions: &[("en", "Larkana")] },
+    Area { prefix: 92812, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92813, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92814, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92815, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92816, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92817, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92818, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92819, descriptions: &[("en", "Quetta")] },
+    Area { prefix: 92862, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92863, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92864, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92865, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92866, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92867, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92868, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92869, descriptions: &[("en", "Gwadar")] },
+    Area { prefix: 92912, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92913, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92914, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92915, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92916, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92917, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92918, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92919, descriptions: &[("en", "Peshawar/Charsadda")] },
+    Area { prefix: 92923, descriptions: &[("en", "Nowshera")] },
+    Area { prefix: 92924, descriptions: &[("en", "Khyber/Mohmand Agy")] },
+    Area { prefix: 92925, descriptions: &[("en", "Hangu/Orakzai Agy")] },
+    Area { prefix: 92926, descriptions: &[("en", "Kurram Agency")] },
+    Area { prefix: 92927, descriptions: &[("en", "Karak")] },
+    Area { prefix: 92928, descriptions: &[("en", "Bannu/N. Waziristan")] },
+    Area { prefix: 92998, descriptions: &[("en", "Kohistan")] },
+    Area { prefix: 95256, descriptions: &[("en", "Amarapura")] },
+    Area { prefix: 96121, descriptions: &[("en", "Beirut")] },
+    Area { prefix: 96124, descriptions: &[("en", "Metn")] },
+    Area { prefix: 96125, descriptions: &[("en", "Chouf")] },
+    Area { prefix: 96126, descriptions: &[("en", "North Lebanon")] },
+    Area { prefix: 96127, descriptions: &[("en", "South Lebanon")] },
+    Area { prefix: 96128, descriptions: &[("en", "Bekaa")] },
+    Area { prefix: 96129, descriptions: &[("en", "Jbeil & Keserwan")] },
+    Area { prefix: 96227, descriptions: &[("en", "Irbid")] },
+    Area { prefix: 96263, descriptions: &[("en", "Amman")] },
+    Area { prefix: 96264, descriptions: &[("en", "Amman")] },
+    Area { prefix: 96265, descriptions: &[("en", "Amman")] },
+    Area { prefix: 96311, descriptions: &[("en", "Damascus and rural areas")] },
+    Area { prefix: 96312, descriptions: &[("en", "Al-Nebek")] },
+    Area { prefix: 96313, descriptions: &[("en", "Al-Zabadani")] },
+    Area { prefix: 96314, descriptions: &[("en", "Al-Quneitra")] },
+    Area { prefix: 96315, descriptions: &[("en", "Dara")] },
+    Area { prefix: 96316, descriptions: &[("en", "Al-Swedaa")] },
+    Area { prefix: 96321, descriptions: &[("en", "Aleppo")] },
+    Area { prefix: 96322, descriptions: &[("en", "Al-Rakkah")] },
+    Area { prefix: 96323, descriptions: &[("en", "Edleb")] },
+    Area { prefix: 96325, descriptions: &[("en", "Menbej")] },
+    Area { prefix: 96331, descriptions: &[("en", "Homs")] },
+    Area { prefix: 96333, descriptions: &[("en", "Hamah")] },
+    Area { prefix: 96334, descriptions: &[("en", "Palmyra")] },
+    Area { prefix: 96341, descriptions: &[("en", "Lattakia")] },
+    Area { prefix: 96343, descriptions: &[("en", "Tartous")] },
+    Area { prefix: 96344, descriptions: &[("en", "Hamah")] },
+    Area { prefix: 96351, descriptions: &[("en", "Deir Ezzour")] },
+    Area { prefix: 96352, descriptions: &[("en", "Alhasakah")] },
+    Area { prefix: 96353, descriptions: &[("en", "Al-Kameshli")] },
+    Area { prefix: 96611, descriptions: &[("en", "Riyadh/Kharj"), ("ar", "الرياض/الخرج")] },
+    Area { prefix: 96612, descriptions: &[("en", "Makkah/Jeddah"), ("ar", "مكة/جدة")] },
+    Area { prefix: 96613, descriptions: &[("en", "Dammam/Khobar/Dahran"), ("ar", "الدمام/الخبر/الظهران")] },
+    Area { prefix: 96614, descriptions: &[("en", "Madenah/Arar/Tabuk/Yanbu"), ("ar", "المدينة المنورة/عرعر/تبوك/ينبع البحر")] },
+    Area { prefix: 96616, descriptions: &[("en", "Hail/Qasim"), ("ar", "حائل/القصيم")] },
+    Area { prefix: 96617, descriptions: &[("en", "Abha/Najran/Jezan"), ("ar", "أبها/نجران/جازان")] },
+    Area { prefix: 96722, descriptions: &[("en", "Aden")] },
+    Area { prefix: 96723, descriptions: &[("en", "Aden")] },
+    Area { prefix: 96724, descriptions: &[("en", "Dhalea")] },
+    Area { prefix: 96725, descriptions: &[("en", "Lahj")] },
+    Area { prefix: 96726, descriptions: &[("en", "Abyan")] },
+    Area { prefix: 96742, descriptions: &[("en", "Taiz")] },
+    Area { prefix: 96743, descriptions: &[("en", "Taiz")] },
+    Area { prefix: 96744, descriptions: &[("en", "Ibb")] },
+    Area { prefix: 96745, descriptions: &[("en", "Ibb")] },
+    Area { prefix: 96752, descriptions: &[("en", "Shabwah")] },
+    Area { prefix: 96753, descriptions: &[("en", "Hadhrmout")] },
+    Area { prefix: 96754, descriptions: &[("en", "Hadhrmout")] },
+    Area { prefix: 96755, descriptions: &[("en", "Hadhrmout")] },
+    Area { prefix: 96764, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 96772, descriptions: &[("en", "Hajjah")] },
+    Area { prefix: 96774, descriptions: &[("en", "Al Mahweet")] },
+    Area { prefix: 96775, descriptions: &[("en", "Saadah")] },
+    Area { prefix: 96776, descriptions: &[("en", "Amran")] },
+    Area { prefix: 96823, descriptions: &[("en", "Dhofar & Al Wusta")] },
+    Area { prefix: 96824, descriptions: &[("en", "Muscat")] },
+    Area { prefix: 96825, descriptions: &[("en", "A’Dakhliyah, Al Sharqiya & A’Dhahira")] },
+    Area { prefix: 96826, descriptions: &[("en", "Al Batinah & Musandam")] },
+    Area { prefix: 97142, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97143, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97144, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97145, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97146, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97147, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97148, descriptions: &[("en", "Dubai")] },
+    Area { prefix: 97162, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97163, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97164, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97165, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97166, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97167, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97168, descriptions: &[("en", "Sharjah, Ajman, Umm Al-Qaiwain")] },
+    Area { prefix: 97172, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97173, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97174, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97175, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97176, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97177, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97178, descriptions: &[("en", "Ras Alkhaimah")] },
+    Area { prefix: 97192, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97193, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97194, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97195, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97196, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97197, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97198, descriptions: &[("en", "Fujairah")] },
+    Area { prefix: 97572, descriptions: &[("en", "Samdrup Jongkhar")] },
+    Area { prefix: 97574, descriptions: &[("en", "Samdrup Jongkhar")] },
+    Area { prefix: 97576, descriptions: &[("en", "Samdrup Jongkhar")] },
+    Area { prefix: 97582, descriptions: &[("en", "Paro")] },
+    Area { prefix: 97583, descriptions: &[("en", "Paro")] },
+    Area { prefix: 97584, descriptions: &[("en", "Paro")] },
+    Area { prefix: 97611, descriptions: &[("en", "Ulaanbaatar")] },
+    Area { prefix: 97621, descriptions: &[("en", "Ulaanbaatar")] },
+    Area { prefix: 99237, descriptions: &[("en", "Dushanbe")] },
+    Area { prefix: 99412, descriptions: &[("en", "Baku")] },
+    Area { prefix: 99418, descriptions: &[("en", "Sumgayit")] },
+    Area { prefix: 99532, descriptions: &[("en", "Tbilisi")] },
+    Area { prefix: 212520, descriptions: &[("en", "Casablanca")] },
+    Area { prefix: 212521, descriptions: &[("en", "Casablanca/Central Morocco"), ("fr", "Casablanca/Maroc Central")] },
+    Area { prefix: 212525, descriptions: &[("en", "Southern Morocco"), ("fr", "Maroc Sud")] },
+    Area { prefix: 212529, descriptions: &[("en", "Casablanca")] },
+    Area { prefix: 212530, descriptions: &[("en", "Rabat/Kènitra"), ("fr", "Rabat/Kénitra")] },
+    Area { prefix: 212531, descriptions: &[("en", "Tangier/Al Hoceima/Larache/Tètouan/Chefchaouen"), ("fr", "Tanger/Tétouan/Larache/Al Hoceima/Cherfchaouen")] },
+    Area { prefix: 212532, descriptions: &[("en", "Fès/Errachidia/Meknès/Nador/Oujda/Taza"), ("fr", "Fès/Oujda/Meknès/Taza/Nador/Errachidia")] },
+    Area { prefix: 212549, descriptions: &[("en", "Casablanca/Marrakech/Agadir")] },
+    Area { prefix: 218205, descriptions: &[("en", "Sidiessaiah")] },
+    Area { prefix: 218206, descriptions: &[("en", "Suk Elkhamis")] },
+    Area { prefix: 218224, descriptions: &[("en", "Swajni")] },
+    Area { prefix: 218252, descriptions: &[("en", "Zahra")] },
+    Area { prefix: 218271, descriptions: &[("en", "Hashan")] },
+    Area { prefix: 218272, descriptions: &[("en", "Azizia")] },
+    Area { prefix: 218274, descriptions: &[("en", "Abu Issa")] },
+    Area { prefix: 218275, descriptions: &[("en", "Matred")] },
+    Area { prefix: 218277, descriptions: &[("en", "Mamura")] },
+    Area { prefix: 218279, descriptions: &[("en", "Elmaya")] },
+    Area { prefix: 218281, descriptions: &[("en", "Jmail")] },
+    Area { prefix: 218282, descriptions: &[("en", "Agelat, Ajalat")] },
+    Area { prefix: 218284, descriptions: &[("en", "Hugialin")] },
+    Area { prefix: 218322, descriptions: &[("en", "Bani Walid")] },
+    Area { prefix: 218323, descriptions: &[("en", "Wadi Keam")] },
+    Area { prefix: 218325, descriptions: &[("en", "Tarhuna")] },
+    Area { prefix: 218326, descriptions: &[("en", "Kussabat")] },
+    Area { prefix: 218421, descriptions: &[("en", "Yefren")] },
+    Area { prefix: 218422, descriptions: &[("en", "Mizda")] },
+    Area { prefix: 218423, descriptions: &[("en", "Guassem")] },
+    Area { prefix: 218425, descriptions: &[("en", "Buzayan")] },
+    Area { prefix: 218427, descriptions: &[("en", "Kikla")] },
+    Area { prefix: 218452, descriptions: &[("en", "Rujban")] },
+    Area { prefix: 218453, descriptions: &[("en", "Reyana")] },
+    Area { prefix: 218454, descriptions: &[("en", "Al Josh")] },
+    Area { prefix: 218481, descriptions: &[("en", "Kabaw")] },
+    Area { prefix: 218482, descriptions: &[("en", "Tigi")] },
+    Area { prefix: 218484, descriptions: &[("en", "Ghadames")] },
+    Area { prefix: 218521, descriptions: &[("en", "Zliten")] },
+    Area { prefix: 218522, descriptions: &[("en", "Tawergha")] },
+    Area { prefix: 218523, descriptions: &[("en", "Dafnia")] },
+    Area { prefix: 218524, descriptions: &[("en", "Kasarahmad")] },
+    Area { prefix: 218526, descriptions: &[("en", "Zawyat Elmahjub")] },
+    Area { prefix: 218529, descriptions: &[("en", "Bugrain")] },
+    Area { prefix: 218551, descriptions: &[("en", "Sirt")] },
+    Area { prefix: 218553, descriptions: &[("en", "Abuhadi")] },
+    Area { prefix: 218554, descriptions: &[("en", "Wadi Jeref")] },
+    Area { prefix: 218555, descriptions: &[("en", "Noflia")] },
+    Area { prefix: 218581, descriptions: &[("en", "Wodan")] },
+    Area { prefix: 218582, descriptions: &[("en", "Sokna")] },
+    Area { prefix: 218583, descriptions: &[("en", "Soussa")] },
+    Area { prefix: 218584, descriptions: &[("en", "Zella")] },
+    Area { prefix: 218623, descriptions: &[("en", "Gmines")] },
+    Area { prefix: 218624, descriptions: &[("en", "Elkuwaifia")] },
+    Area { prefix: 218625, descriptions: &[("en", "Deriana")] },
+    Area { prefix: 218626, descriptions: &[("en", "Kaalifa")] },
+    Area { prefix: 218627, descriptions: &[("en", "Jerdina")] },
+    Area { prefix: 218628, descriptions: &[("en", "Seluk")] },
+    Area { prefix: 218629, descriptions: &[("en", "Elmagrun")] },
+    Area { prefix: 218652, descriptions: &[("en", "Kofra")] },
+    Area { prefix: 218653, descriptions: &[("en", "Ojla")] },
+    Area { prefix: 218654, descriptions: &[("en", "Sidi Sultan Sultan")] },
+    Area { prefix: 218655, descriptions: &[("en", "Bisher")] },
+    Area { prefix: 218657, descriptions: &[("en", "Jalo")] },
+    Area { prefix: 218681, descriptions: &[("en", "Tolmitha")] },
+    Area { prefix: 218682, descriptions: &[("en", "Jardas")] },
+    Area { prefix: 218683, descriptions: &[("en", "Taknes")] },
+    Area { prefix: 218684, descriptions: &[("en", "Elbayada")] },
+    Area { prefix: 218685, descriptions: &[("en", "Tomina")] },
+    Area { prefix: 218721, descriptions: &[("en", "Brak")] },
+    Area { prefix: 218723, descriptions: &[("en", "Edry")] },
+    Area { prefix: 218724, descriptions: &[("en", "Ghat")] },
+    Area { prefix: 218725, descriptions: &[("en", "Murzuk")] },
+    Area { prefix: 218726, descriptions: &[("en", "Um Laranib")] },
+    Area { prefix: 218727, descriptions: &[("en", "Zawaya")] },
+    Area { prefix: 218729, descriptions: &[("en", "Ghrefa")] },
+    Area { prefix: 218731, descriptions: &[("en", "Wadi Atba")] },
+    Area { prefix: 218732, descriptions: &[("en", "Bergen")] },
+    Area { prefix: 218733, descriptions: &[("en", "Garda")] },
+    Area { prefix: 218734, descriptions: &[("en", "Traghen")] },
+    Area { prefix: 218821, descriptions: &[("en", "Gubba")] },
+    Area { prefix: 218851, descriptions: &[("en", "Shahat")] },
+    Area { prefix: 218852, descriptions: &[("en", "Massa")] },
+    Area { prefix: 218854, descriptions: &[("en", "Slenta")] },
+    Area { prefix: 220446, descriptions: &[("en", "Kotu/Senegambia")] },
+    Area { prefix: 220447, descriptions: &[("en", "Yundum")] },
+    Area { prefix: 220449, descriptions: &[("en", "Bakau")] },
+    Area { prefix: 220553, descriptions: &[("en", "Soma")] },
+    Area { prefix: 220566, descriptions: &[("en", "Baja Kunda/Basse/Fatoto/Gambisara/Garawol/Misera/Sambakunda/Sudowol")] },
+    Area { prefix: 220567, descriptions: &[("en", "Sotuma")] },
+    Area { prefix: 220574, descriptions: &[("en", "Kaur")] },
+    Area { prefix: 221338, descriptions: &[("en", "Dakar")] },
+    Area { prefix: 223202, descriptions: &[("en", "Bamako")] },
+    Area { prefix: 223212, descriptions: &[("en", "Koulikoro")] },
+    Area { prefix: 223214, descriptions: &[("en", "Mopti")] },
+    Area { prefix: 223215, descriptions: &[("en", "Kayes")] },
+    Area { prefix: 223216, descriptions: &[("en", "Sikasso")] },
+    Area { prefix: 223218, descriptions: &[("en", "Gao/Kidal")] },
+    Area { prefix: 223219, descriptions: &[("en", "Tombouctou")] },
+    Area { prefix: 223442, descriptions: &[("en", "Bamako")] },
+    Area { prefix: 223443, descriptions: &[("en", "Bamako")] },
+    Area { prefix: 223449, descriptions: &[("en", "Bamako")] },
+    Area { prefix: 224302, descriptions: &[("en", "Fria")] },
+    Area { prefix: 224307, descriptions: &[("en", "Kankan")] },
+    Area { prefix: 224308, descriptions: &[("en", "Faranah")] },
+    Area { prefix: 226204, descriptions: &[("en", "Kaya")] },
+    Area { prefix: 226253, descriptions: &[("en", "Ouagadougou")] },
+    Area { prefix: 226254, descriptions: &[("en", "Ouagadougou")] },
+    Area { prefix: 227202, descriptions: &[("en", "Niamey")] },
+    Area { prefix: 227203, descriptions: &[("en", "Niamey")] },
+    Area { prefix: 230210, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230211, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230212, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230213, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230214, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230215, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230216, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230217, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Région Nord")] },
+    Area { prefix: 230218, descriptions: &[("en", "North Region"), ("es", "Región Norte"), ("fr", "Región Nord")] },
+    Area { prefix: 233302, descriptions: &[("en", "Accra")] },
+    Area { prefix: 233303, descriptions: &[("en", "Tema")] },
+    Area { prefix: 233307, descriptions: &[("en", "Greater Accra Region")] },
+    Area { prefix: 233308, descriptions: &[("en", "Greater Accra Region")] },
+    Area { prefix: 233317, descriptions: &[("en", "Western Region")] },
+    Area { prefix: 233318, descriptions: &[("en", "Western Region")] },
+    Area { prefix: 233327, descriptions: &[("en", "Ashanti Region")] },
+    Area { prefix: 233328, descriptions: &[("en", "Ashanti Region")] },
+    Area { prefix: 233337, descriptions: &[("en", "Central Region")] },
+    Area { prefix: 233338, descriptions: &[("en", "Central Region")] },
+    Area { prefix: 233347, descriptions: &[("en", "Eastern Region")] },
+    Area { prefix: 233348, descriptions: &[("en", "Eastern Region")] },
+    Area { prefix: 233357, descriptions: &[("en", "Brong-Ahafo Region")] },
+    Area { prefix: 233358, descriptions: &[("en", "Brong-Ahafo Region")] },
+    Area { prefix: 233367, descriptions: &[("en", "Volta Region")] },
+    Area { prefix: 233368, descriptions: &[("en", "Volta Region")] },
+    Area { prefix: 233377, descriptions: &[("en", "Northern Region")] },
+    Area { prefix: 233378, descriptions: &[("en", "Northern Region")] },
+    Area { prefix: 233387, descriptions: &[("en", "Upper East Region")] },
+    Area { prefix: 233388, descriptions: &[("en", "Upper East Region")] },
+    Area { prefix: 233392, descriptions: &[("en", "Wa")] },
+    Area { prefix: 233397, descriptions: &[("en", "Upper West Region")] },
+    Area { prefix: 233398, descriptions: &[("en", "Upper West Region")] },
+    Area { prefix: 234201, descriptions: &[("en", "Lagos")] },
+    Area { prefix: 234202, descriptions: &[("en", "Ibadan")] },
+    Area { prefix: 234209, descriptions: &[("en", "Abuja")] },
+    Area { prefix: 238221, descriptions: &[("en", "Ribeira Grande, Santo Antão")] },
+    Area { prefix: 238222, descriptions: &[("en", "Porto Novo, Santo Antão")] },
+    Area { prefix: 238223, descriptions: &[("en", "Paúl, Santo Antão")] },
+    Area { prefix: 238224, descriptions: &[("en", "Cocoli, Santo Antão")] },
+    Area { prefix: 238225, descriptions: &[("en", "Ponta do Sol, Santo Antão")] },
+    Area { prefix: 238226, descriptions: &[("en", "Manta Velha/Chã de Igreja  (Santo Antão Island)")] },
+    Area { prefix: 238227, descriptions: &[("en", "Lajedos/Alto Mira (Santo Antão Island)")] },
+    Area { prefix: 238230, descriptions: &[("en", "Mindelo, São Vicente")] },
+    Area { prefix: 238231, descriptions: &[("en", "Mindelo, São Vicente")] },
+    Area { prefix: 238232, descriptions: &[("en", "Mindelo, São Vicente")] },
+    Area { prefix: 238235, descriptions: &[("en", "Ribeira Brava, São Nicolau")] },
+    Area { prefix: 238236, descriptions: &[("en", "Tarrafal de São Nicolau, São Nicolau")] },
+    Area { prefix: 238237, descriptions: &[("en", "Fajã, São Nicolau")] },
+    Area { prefix: 238238, descriptions: &[("en", "Praia Branca, São Nicolau")] },
+    Area { prefix: 238241, descriptions: &[("en", "Espargos, Sal")] },
+    Area { prefix: 238242, descriptions: &[("en", "Santa Maria, Sal")] },
+    Area { prefix: 238251, descriptions: &[("en", "Sal Rei, Boa Vista")] },
+    Area { prefix: 238252, descriptions: &[("en", "Funda das Figueiras, Boa Vista")] },
+    Area { prefix: 238255, descriptions: &[("en", "Vila do Maio, Maio")] },
+    Area { prefix: 238256, descriptions: &[("en", "Calheta, Maio")] },
+    Area { prefix: 238260, descriptions: &[("en", "Praia, Santiago")] },
+    Area { prefix: 238261, descriptions: &[("en", "Praia, Santiago")] },
+    Area { prefix: 238262, descriptions: &[("en", "Praia, Santiago")] },
+    Area { prefix: 238263, descriptions: &[("en", "Praia, Santiago")] },
+    Area { prefix: 238264, descriptions: &[("en", "Praia, Santiago")] },
+    Area { prefix: 238265, descriptions: &[("en", "Santa Catarina, Santiago")] },
+    Area { prefix: 238266, descriptions: &[("en", "Tarrafal, Santiago")] },
+    Area { prefix: 238267, descriptions: &[("en", "Cidade Velha, Santiago")] },
+    Area { prefix: 238268, descriptions: &[("en", "São Domingos, Santiago")] },
+    Area { prefix: 238269, descriptions: &[("en", "Pedra Badejo, Santiago")] },
+    Area { prefix: 238271, descriptions: &[("en", "Orgão/São Jorge (Santiago Island)")] },
+    Area { prefix: 238272, descriptions: &[("en", "Picos, Santiago")] },
+    Area { prefix: 238273, descriptions: &[("en", "Calheta de São Miguel, Santiago")] },
+    Area { prefix: 238281, descriptions: &[("en", "São Filipe, Fogo")] },
+    Area { prefix: 238282, descriptions: &[("en", "Cova Figueira, Fogo")] },
+    Area { prefix: 238283, descriptions: &[("en", "Mosteiros, Fogo")] },
+    Area { prefix: 238284, descriptions: &[("en", "São Jorge, Fogo")] },
+    Area { prefix: 238285, descriptions: &[("en", "Nova Sintra, Brava")] },
+    Area { prefix: 239224, descriptions: &[("en", "Água Grande")] },
+    Area { prefix: 239228, descriptions: &[("en", "Água Grande")] },
+    Area { prefix: 239229, descriptions: &[("en", "Água Grande")] },
+    Area { prefix: 241017, descriptions: &[("en", "Libreville")] },
+    Area { prefix: 241117, descriptions: &[("en", "Libreville")] },
+    Area { prefix: 244231, descriptions: &[("en", "Cabinda")] },
+    Area { prefix: 244232, descriptions: &[("en", "Zaire")] },
+    Area { prefix: 244233, descriptions: &[("en", "Uige"), ("pt", "Uíge")] },
+    Area { prefix: 244235, descriptions: &[("en", "Cuanza Norte"), ("pt", "Kwanza-Norte")] },
+    Area { prefix: 244236, descriptions: &[("en", "Cuanza Sul"), ("pt", "Kwanza-Sul")] },
+    Area { prefix: 244241, descriptions: &[("en", "Huambo")] },
+    Area { prefix: 244248, descriptions: &[("en", "Bie"), ("pt", "Bié")] },
+    Area { prefix: 244249, descriptions: &[("en", "Cuando Cubango"), ("pt", "Cuando-Cubango")] },
+    Area { prefix: 244251, descriptions: &[("en", "Malange"), ("pt", "Malanje")] },
+    Area { prefix: 244252, descriptions: &[("en", "Lunda Norte"), ("pt", "Lunda-Norte")] },
+    Area { prefix: 244264, descriptions: &[("en", "Namibe")] },
+    Area { prefix: 244265, descriptions: &[("en", "Cunene")] },
+    Area { prefix: 244272, descriptions: &[("en", "Benguela")] },
+    Area { prefix: 249153, descriptions: &[("en", "Khartoum")] },
+    Area { prefix: 249155, descriptions: &[("en", "Khartoum North")] },
+    Area { prefix: 249156, descriptions: &[("en", "Khartoum Rural")] },
+    Area { prefix: 249157, descriptions: &[("en", "Omdurman")] },
+    Area { prefix: 249183, descriptions: &[("en", "Khartoum")] },
+    Area { prefix: 249185, descriptions: &[("en", "Khartoum North")] },
+    Area { prefix: 249186, descriptions: &[("en", "Khartoum Rural")] },
+    Area { prefix: 249187, descriptions: &[("en", "Omdurman")] },
+    Area { prefix: 251115, descriptions: &[("en", "Addis Ababa")] },
+    Area { prefix: 251258, descriptions: &[("en", "Kelafo, East Region")] },
+    Area { prefix: 251461, descriptions: &[("en", "Shasemene")] },
+    Area { prefix: 251466, descriptions: &[("en", "Kebado, South Region")] },
+    Area { prefix: 251572, descriptions: &[("en", "Ghedo, West Region")] },
+    Area { prefix: 251585, descriptions: &[("en", "Pawe, North-West Region")] },
+    Area { prefix: 256464, descriptions: &[("en", "Mubende")] },
+    Area { prefix: 256465, descriptions: &[("en", "Masindi")] },
+    Area { prefix: 256471, descriptions: &[("en", "Gulu")] },
+    Area { prefix: 256473, descriptions: &[("en", "Lira")] },
+    Area { prefix: 256476, descriptions: &[("en", "Arua")] },
+    Area { prefix: 256481, descriptions: &[("en", "Masaka")] },
+    Area { prefix: 256483, descriptions: &[("en", "Fort Portal")] },
+    Area { prefix: 256485, descriptions: &[("en", "Mbarara")] },
+    Area { prefix: 256486, descriptions: &[("en", "Kabale/Rukungiri/Kisoro")] },
+    Area { prefix: 258251, descriptions: &[("en", "Manica")] },
+    Area { prefix: 258252, descriptions: &[("en", "Tete")] },
+    Area { prefix: 258271, descriptions: &[("en", "Lichinga")] },
+    Area { prefix: 258272, descriptions: &[("en", "Pemba")] },
+    Area { prefix: 258281, descriptions: &[("en", "Chokwe"), ("pt", "Chokwé")] },
+    Area { prefix: 258282, descriptions: &[("en", "Xai-Xai")] },
+    Area { prefix: 260211, descriptions: &[("en", "Lusaka Province")] },
+    Area { prefix: 260212, descriptions: &[("en", "Ndola/Copperbelt and Luapula Provinces")] },
+    Area { prefix: 260213, descriptions: &[("en", "Livingstone/Southern Province")] },
+    Area { prefix: 260214, descriptions: &[("en", "Kasama/Northern Province")] },
+    Area { prefix: 260215, descriptions: &[("en", "Kabwe/Central Province")] },
+    Area { prefix: 260216, descriptions: &[("en", "Chipata/Eastern Province")] },
+    Area { prefix: 260217, descriptions: &[("en", "Solwezi/Western Province")] },
+    Area { prefix: 260218, descriptions: &[("en", "Mongu/North-Western Province")] },
+    Area { prefix: 263204, descriptions: &[("en", "Odzi")] },
+    Area { prefix: 263205, descriptions: &[("en", "Pengalonga")] },
+    Area { prefix: 263206, descriptions: &[("en", "Mutare")] },
+    Area { prefix: 263212, descriptions: &[("en", "Murambinda")] },
+    Area { prefix: 263213, descriptions: &[("en", "Victoria Falls")] },
+    Area { prefix: 263219, descriptions: &[("en", "Plumtree")] },
+    Area { prefix: 263221, descriptions: &[("en", "Murambinda")] },
+    Area { prefix: 263222, descriptions: &[("en", "Wedza")] },
+    Area { prefix: 263225, descriptions: &[("en", "Rusape")] },
+    Area { prefix: 263227, descriptions: &[("en", "Chipinge")] },
+    Area { prefix: 263228, descriptions: &[("en", "Hauna")] },
+    Area { prefix: 263229, descriptions: &[("en", "Juliasdale")] },
+    Area { prefix: 263242, descriptions: &[("en", "Harare")] },
+    Area { prefix: 263248, descriptions: &[("en", "Birchenough Bridge")] },
+    Area { prefix: 263251, descriptions: &[("en", "Zvishavane")] },
+    Area { prefix: 263254, descriptions: &[("en", "Gweru")] },
+    Area { prefix: 263261, descriptions: &[("en", "Kariba")] },
+    Area { prefix: 263264, descriptions: &[("en", "Karoi")] },
+    Area { prefix: 263270, descriptions: &[("en", "Chitungwiza")] },
+    Area { prefix: 263271, descriptions: &[("en", "Bindura")] },
+    Area { prefix: 263272, descriptions: &[("en", "Mutoko")] },
+    Area { prefix: 263273, descriptions: &[("en", "Ruwa")] },
+    Area { prefix: 263274, descriptions: &[("en", "Arcturus")] },
+    Area { prefix: 263277, descriptions: &[("en", "Mvurwi")] },
+    Area { prefix: 263278, descriptions: &[("en", "Murewa")] },
+    Area { prefix: 263279, descriptions: &[("en", "Marondera")] },
+    Area { prefix: 263281, descriptions: &[("en", "Hwange")] },
+    Area { prefix: 263282, descriptions: &[("en", "Kezi")] },
+    Area { prefix: 263283, descriptions: &[("en", "Figtree")] },
+    Area { prefix: 263284, descriptions: &[("en", "Gwanda")] },
+    Area { prefix: 263285, descriptions: &[("en", "Turkmine")] },
+    Area { prefix: 263286, descriptions: &[("en", "Beitbridge")] },
+    Area { prefix: 263287, descriptions: &[("en", "Tsholotsho")] },
+    Area { prefix: 263288, descriptions: &[("en", "Esigodini")] },
+    Area { prefix: 263289, descriptions: &[("en", "Jotsholo")] },
+    Area { prefix: 263308, descriptions: &[("en", "Chatsworth")] },
+    Area { prefix: 263317, descriptions: &[("en", "Checheche")] },
+    Area { prefix: 263329, descriptions: &[("en", "Nyanga")] },
+    Area { prefix: 263337, descriptions: &[("en", "Nyaningwe")] },
+    Area { prefix: 263338, descriptions: &[("en", "Nyika")] },
+    Area { prefix: 263371, descriptions: &[("en", "Shamva")] },
+    Area { prefix: 263375, descriptions: &[("en", "Concession")] },
+    Area { prefix: 263376, descriptions: &[("en", "Glendale")] },
+    Area { prefix: 263379, descriptions: &[("en", "Macheke")] },
+    Area { prefix: 263383, descriptions: &[("en", "Matopose")] },
+    Area { prefix: 263387, descriptions: &[("en", "Nyamandhlovu")] },
+    Area { prefix: 263398, descriptions: &[("en", "Lupane")] },
+    Area { prefix: 263512, descriptions: &[("en", "Zvishavane")] },
+    Area { prefix: 263513, descriptions: &[("en", "Zvishavane")] },
+    Area { prefix: 263514, descriptions: &[("en", "Zvishavane")] },
+    Area { prefix: 263517, descriptions: &[("en", "Mataga")] },
+    Area { prefix: 263518, descriptions: &[("en", "Mberengwa")] },
+    Area { prefix: 263557, descriptions: &[("en", "Munyati")] },
+    Area { prefix: 263558, descriptions: &[("en", "Nkayi")] },
+    Area { prefix: 263628, descriptions: &[("en", "Selous")] },
+    Area { prefix: 263637, descriptions: &[("en", "Chirundu")] },
+    Area { prefix: 263667, descriptions: &[("en", "Raffingora")] },
+    Area { prefix: 263668, descriptions: &[("en", "Mutorashanga")] },
+    Area { prefix: 263675, descriptions: &[("en", "Murombedzi")] },
+    Area { prefix: 263687, descriptions: &[("en", "Sanyati")] },
+    Area { prefix: 263688, descriptions: &[("en", "Chakari")] },
+    Area { prefix: 263698, descriptions: &[("en", "Trelawney")] },
+    Area { prefix: 263920, descriptions: &[("en", "Northend")] },
+    Area { prefix: 263921, descriptions: &[("en", "Northend")] },
+    Area { prefix: 263924, descriptions: &[("en", "Hillside")] },
+    Area { prefix: 263929, descriptions: &[("en", "Killarney")] },
+    Area { prefix: 263940, descriptions: &[("en", "Mabutewni")] },
+    Area { prefix: 263941, descriptions: &[("en", "Mabutewni")] },
+    Area { prefix: 263942, descriptions: &[("en", "Mabutewni")] },
+    Area { prefix: 263943, descriptions: &[("en", "Mabutewni")] },
+    Area { prefix: 263946, descriptions: &[("en", "Bellevue")] },
+    Area { prefix: 263947, descriptions: &[("en", "Bellevue")] },
+    Area { prefix: 263948, descriptions: &[("en", "Nkulumane")] },
+    Area { prefix: 263949, descriptions: &[("en", "Nkulumane")] },
+    Area { prefix: 263952, descriptions: &[("en", "Luveve")] },
+    Area { prefix: 263956, descriptions: &[("en", "Luveve")] },
+    Area { prefix: 267310, descriptions: &[("en", "Gaborone (outer)")] },
+    Area { prefix: 267312, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267313, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267315, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267316, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267317, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267318, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267319, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267370, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267371, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267390, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267391, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267392, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267393, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267394, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267395, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267397, descriptions: &[("en", "Gaborone")] },
+    Area { prefix: 267530, descriptions: &[("en", "Lobatse")] },
+    Area { prefix: 267533, descriptions: &[("en", "Lobatse")] },
+    Area { prefix: 267534, descriptions: &[("en", "Lobatse")] },
+    Area { prefix: 267538, descriptions: &[("en", "Ramotswa")] },
+    Area { prefix: 267539, descriptions: &[("en", "Ramotswa")] },
+    Area { prefix: 267651, descriptions: &[("en", "Kgalagadi")] },
+    Area { prefix: 267654, descriptions: &[("en", "Kgalagadi")] },
+    Area { prefix: 267659, descriptions: &[("en", "Gantsi")] },
+    Area { prefix: 269760, descriptions: &[("en", "Domoni")] },
+    Area { prefix: 269761, descriptions: &[("en", "Mutsamudu")] },
+    Area { prefix: 269762, descriptions: &[("en", "Mohéli")] },
+    Area { prefix: 269763, descriptions: &[("en", "Moroni")] },
+    Area { prefix: 269767, descriptions: &[("en", "Mbéni")] },
+    Area { prefix: 269768, descriptions: &[("en", "Mitsamiouli")] },
+    Area { prefix: 269769, descriptions: &[("en", "Foumbouni")] },
+    Area { prefix: 269770, descriptions: &[("en", "Domoni")] },
+    Area { prefix: 269771, descriptions: &[("en", "Mutsamudu")] },
+    Area { prefix: 269772, descriptions: &[("en", "Mohéli")] },
+    Area { prefix: 269773, descriptions: &[("en", "Moroni")] },
+    Area { prefix: 269774, descriptions: &[("en", "Moroni")] },
+    Area { prefix: 269775, descriptions: &[("en", "Moroni")] },
+    Area { prefix: 269777, descriptions: &[("en", "Mbéni")] },
+    Area { prefix: 269778, descriptions: &[("en", "Mitsamiouli")] },
+    Area { prefix: 269779, descriptions: &[("en", "Foumbouni")] },
+    Area { prefix: 290264, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 290265, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 290266, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 290267, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 290268, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 290269, descriptions: &[("en", "St. Helena"), ("fr", "Sainte-Hélène")] },
+    Area { prefix: 299691, descriptions: &[("en", "Ivittuut")] },
+    Area { prefix: 351231, descriptions: &[("en", "Mealhada")] },
+    Area { prefix: 351232, descriptions: &[("en", "Viseu")] },
+    Area { prefix: 351233, descriptions: &[("en", "Figueira da Foz")] },
+    Area { prefix: 351234, descriptions: &[("en", "Aveiro")] },
+    Area { prefix: 351235, descriptions: &[("en", "Arganil")] },
+    Area { prefix: 351236, descriptions: &[("en", "Pombal")] },
+    Area { prefix: 351238, descriptions: &[("en", "Seia")] },
+    Area { prefix: 351239, descriptions: &[("en", "Coimbra")] },
+    Area { prefix: 351241, descriptions: &[("en", "Abrantes")] },
+    Area { prefix: 351242, descriptions: &[("en", "Ponte de Sôr")] },
+    Area { prefix: 351243, descriptions: &[("en", "Santarém")] },
+    Area { prefix: 351244, descriptions: &[("en", "Leiria")] },
+    Area { prefix: 351245, descriptions: &[("en", "Portalegre")] },
+    Area { prefix: 351249, descriptions: &[("en", "Torres Novas")] },
+    Area { prefix: 351251, descriptions: &[("en", "Valença")] },
+    Area { prefix: 351252, descriptions: &[("en", "V. N. de Famalicão")] },
+    Area { prefix: 351253, descriptions: &[("en", "Braga")] },
+    Area { prefix: 351254, descriptions: &[("en", "Peso da Régua")] },
+    Area { prefix: 351255, descriptions: &[("en", "Penafiel")] },
+    Area { prefix: 351256, descriptions: &[("en", "S. João da Madeira")] },
+    Area { prefix: 351257, descriptions: &[("en", "Braga")] },
+    Area { prefix: 351258, descriptions: &[("en", "Viana do Castelo")] },
+    Area { prefix: 351259, descriptions: &[("en", "Vila Real")] },
+    Area { prefix: 351261, descriptions: &[("en", "Torres Vedras")] },
+    Area { prefix: 351262, descriptions: &[("en", "Caldas da Rainha")] },
+    Area { prefix: 351263, descriptions: &[("en", "Vila Franca de Xira")] },
+    Area { prefix: 351265, descriptions: &[("en", "Setúbal")] },
+    Area { prefix: 351266, descriptions: &[("en", "Évora")] },
+    Area { prefix: 351268, descriptions: &[("en", "Estremoz")] },
+    Area { prefix: 351269, descriptions: &[("en", "Santiago do Cacém")] },
+    Area { prefix: 351271, descriptions: &[("en", "Guarda")] },
+    Area { prefix: 351272, descriptions: &[("en", "Castelo Branco")] },
+    Area { prefix: 351273, descriptions: &[("en", "Bragança")] },
+    Area { prefix: 351274, descriptions: &[("en", "Proença-a-Nova")] },
+    Area { prefix: 351275, descriptions: &[("en", "Covilhã")] },
+    Area { prefix: 351276, descriptions: &[("en", "Chaves")] },
+    Area { prefix: 351277, descriptions: &[("en", "Idanha-a-Nova")] },
+    Area { prefix: 351278, descriptions: &[("en", "Mirandela")] },
+    Area { prefix: 351279, descriptions: &[("en", "Moncorvo")] },
+    Area { prefix: 351281, descriptions: &[("en", "Tavira")] },
+    Area { prefix: 351282, descriptions: &[("en", "Portimão")] },
+    Area { prefix: 351283, descriptions: &[("en", "Odemira")] },
+    Area { prefix: 351284, descriptions: &[("en", "Beja")] },
+    Area { prefix: 351285, descriptions: &[("en", "Moura")] },
+    Area { prefix: 351286, descriptions: &[("en", "Castro Verde")] },
+    Area { prefix: 351289, descriptions: &[("en", "Faro")] },
+    Area { prefix: 351291, descriptions: &[("en", "Funchal")] },
+    Area { prefix: 351292, descriptions: &[("en", "Horta")] },
+    Area { prefix: 351295, descriptions: &[("en", "Angra do Heroísmo")] },
+    Area { prefix: 351296, descriptions: &[("en", "Ponta Delgada")] },
+    Area { prefix: 352240, descriptions: &[("en", "Luxembourg"), ("de", "Luxemburg")] },
+    Area { prefix: 352241, descriptions: &[("en", "Luxembourg"), ("de", "Luxemburg")] },
+    Area { prefix: 352246, descriptions: &[("en", "Luxembourg"), ("de", "Luxemburg")] },
+    Area { prefix: 352249, descriptions: &[("en", "Luxembourg"), ("de", "Luxemburg")] },
+    Area { prefix: 353217, descriptions: &[("en", "Coachford")] },
+    Area { prefix: 353218, descriptions: &[("en", "Cork/Kinsale/Coachford")] },
+    Area { prefix: 353402, descriptions: &[("en", "Arklow")] },
+    Area { prefix: 353404, descriptions: &[("en", "Wicklow")] },
+    Area { prefix: 353416, descriptions: &[("en", "Ardee")] },
+    Area { prefix: 353420, descriptions: &[("en", "Dundalk/Carrickmacross/Castleblaney")] },
+    Area { prefix: 353421, descriptions: &[("en", "Dundalk/Carrickmacross/Castleblaney")] },
+    Area { prefix: 353422, descriptions: &[("en", "Dundalk")] },
+    Area { prefix: 353423, descriptions: &[("en", "Dundalk/Carrickmacross/Castleblaney")] },
+    Area { prefix: 353424, descriptions: &[("en", "Carrickmacross")] },
+    Area { prefix: 353425, descriptions: &[("en", "Castleblaney")] },
+    Area { prefix: 353426, descriptions: &[("en", "Dundalk")] },
+    Area { prefix: 353427, descriptions: &[("en", "Dundalk")] },
+    Area { prefix: 353428, descriptions: &[("en", "Dundalk")] },
+    Area { prefix: 353432, descriptions: &[("en", "Longford")] },
+    Area { prefix: 353437, descriptions: &[("en", "Granard")] },
+    Area { prefix: 353438, descriptions: &[("en", "Granard")] },
+    Area { prefix: 353443, descriptions: &[("en", "Mullingar/Castlepollard/Tyrrellspass")] },
+    Area { prefix: 353447, descriptions: &[("en", "Castlepollard")] },
+    Area { prefix: 353448, descriptions: &[("en", "Tyrellspass")] },
+    Area { prefix: 353450, descriptions: &[("en", "Naas/Kildare/Curragh")] },
+    Area { prefix: 353451, descriptions: &[("en", "Naas/Kildare/Curragh")] },
+    Area { prefix: 353452, descriptions: &[("en", "Kildare")] },
+    Area { prefix: 353453, descriptions: &[("en", "The Curragh")] },
+    Area { prefix: 353454, descriptions: &[("en", "The Curragh")] },
+    Area { prefix: 353455, descriptions: &[("en", "Kildare")] },
+    Area { prefix: 353456, descriptions: &[("en", "Naas")] },
+    Area { prefix: 353457, descriptions: &[("en", "Naas")] },
+    Area { prefix: 353458, descriptions: &[("en", "Naas")] },
+    Area { prefix: 353459, descriptions: &[("en", "Naas")] },
+    Area { prefix: 353460, descriptions: &[("en", "Navan")] },
+    Area { prefix: 353461, descriptions: &[("en", "Navan")] },
+    Area { prefix: 353462, descriptions: &[("en", "Kells")] },
+    Area { prefix: 353463, descriptions: &[("en", "Navan/Kells/Trim/Edenderry/Enfield")] },
+    Area { prefix: 353464, descriptions: &[("en", "Trim")] },
+    Area { prefix: 353465, descriptions: &[("en", "Enfield")] },
+    Area { prefix: 353466, descriptions: &[("en", "Edenderry")] },
+    Area { prefix: 353467, descriptions: &[("en", "Navan")] },
+    Area { prefix: 353468, descriptions: &[("en", "Navan")] },
+    Area { prefix: 353470, descriptions: &[("en", "Monaghan/Clones")] },
+    Area { prefix: 353471, descriptions: &[("en", "Monaghan/Clones")] },
+    Area { prefix: 353472, descriptions: &[("en", "Clones")] },
+    Area { prefix: 353473, descriptions: &[("en", "Monaghan")] },
+    Area { prefix: 353474, descriptions: &[("en", "Clones")] },
+    Area { prefix: 353475, descriptions: &[("en", "Clones")] },
+    Area { prefix: 353476, descriptions: &[("en", "Monaghan")] },
+    Area { prefix: 353477, descriptions: &[("en", "Monaghan")] },
+    Area { prefix: 353478, descriptions: &[("en", "Monaghan")] },
+    Area { prefix: 353479, descriptions: &[("en", "Monaghan")] },
+    Area { prefix: 353490, descriptions: &[("en", "Cavan/Cootehill/Oldcastle/Belturbet")] },
+    Area { prefix: 353491, descriptions: &[("en", "Cavan/Cootehill/Oldcastle/Belturbet")] },
+    Area { prefix: 353492, descriptions: &[("en", "Cootehill")] },
+    Area { prefix: 353493, descriptions: &[("en", "Belturbet")] },
+    Area { prefix: 353494, descriptions: &[("en", "Cavan")] },
+    Area { prefix: 353495, descriptions: &[("en", "Cootehill")] },
+    Area { prefix: 353496, descriptions: &[("en", "Cavan")] },
+    Area { prefix: 353497, descriptions: &[("en", "Cavan")] },
+    Area { prefix: 353498, descriptions: &[("en", "Oldcastle")] },
+    Area { prefix: 353499, descriptions: &[("en", "Belturbet")] },
+    Area { prefix: 353504, descriptions: &[("en", "Thurles")] },
+    Area { prefix: 353505, descriptions: &[("en", "Roscrea")] },
+    Area { prefix: 353512, descriptions: &[("en", "Kilmacthomas")] },
+    Area { prefix: 353514, descriptions: &[("en", "New Ross")] },
+    Area { prefix: 353516, descriptions: &[("en", "Carrick-on-Suir")] },
+    Area { prefix: 353530, descriptions: &[("en", "Wexford")] },
+    Area { prefix: 353531, descriptions: &[("en", "Wexford")] },
+    Area { prefix: 353560, descriptions: &[("en", "Kilkenny")] },
+    Area { prefix: 353561, descriptions: &[("en", "Kilkenny")] },
+    Area { prefix: 353570, descriptions: &[("en", "Portlaoise")] },
+    Area { prefix: 353571, descriptions: &[("en", "Portlaoise")] },
+    Area { prefix: 353616, descriptions: &[("en", "Scariff")] },
+    Area { prefix: 353619, descriptions: &[("en", "Scariff")] },
+    Area { prefix: 353620, descriptions: &[("en", "Tipperary/Cashel")] },
+    Area { prefix: 353621, descriptions: &[("en", "Tipperary/Cashel")] },
+    Area { prefix: 353622, descriptions: &[("en", "Cashel")] },
+    Area { prefix: 353623, descriptions: &[("en", "Tipperary")] },
+    Area { prefix: 353624, descriptions: &[("en", "Tipperary")] },
+    Area { prefix: 353625, descriptions: &[("en", "Tipperary")] },
+    Area { prefix: 353626, descriptions: &[("en", "Cashel")] },
+    Area { prefix: 353627, descriptions: &[("en", "Cashel")] },
+    Area { prefix: 353628, descriptions: &[("en", "Tipperary")] },
+    Area { prefix: 353629, descriptions: &[("en", "Cashel")] },
+    Area { prefix: 353650, descriptions: &[("en", "Ennis/Ennistymon/Kilrush")] },
+    Area { prefix: 353651, descriptions: &[("en", "Ennis/Ennistymon/Kilrush")] },
+    Area { prefix: 353652, descriptions: &[("en", "Ennis")] },
+    Area { prefix: 353653, descriptions: &[("en", "Ennis")] },
+    Area { prefix: 353654, descriptions: &[("en", "Ennis")] },
+    Area { prefix: 353655, descriptions: &[("en", "Ennis")] },
+    Area { prefix: 353656, descriptions: &[("en", "Ennis")] },
+    Area { prefix: 353657, descriptions: &[("en", "Ennistymon")] },
+    Area { prefix: 353658, descriptions: &[("en", "Kilrush")] },
+    Area { prefix: 353659, descriptions: &[("en", "Kilrush")] },
+    Area { prefix: 353668, descriptions: &[("en", "Tralee/Dingle/Killorglin/Cahersiveen")] },
+    Area { prefix: 353710, descriptions: &[("en", "Sligo")] },
+    Area { prefix: 353711, descriptions: &[("en", "Sligo")] },
+    Area { prefix: 353740, descriptions: &[("en", "Letterkenny")] },
+    Area { prefix: 353741, descriptions: &[("en", "Letterkenny")] },
+    Area { prefix: 353900, descriptions: &[("en", "Athlone")] },
+    Area { prefix: 353901, descriptions: &[("en", "Athlone")] },
+    Area { prefix: 353912, descriptions: &[("en", "Gort")] },
+    Area { prefix: 353916, descriptions: &[("en", "Gort")] },
+    Area { prefix: 353918, descriptions: &[("en", "Loughrea")] },
+    Area { prefix: 355211, descriptions: &[("en", "Koplik")] },
+    Area { prefix: 355212, descriptions: &[("en", "Pukë")] },
+    Area { prefix: 355213, descriptions: &[("en", "Bajram Curri")] },
+    Area { prefix: 355214, descriptions: &[("en", "Krumë")] },
+    Area { prefix: 355215, descriptions: &[("en", "Lezhë")] },
+    Area { prefix: 355216, descriptions: &[("en", "Rrëshen")] },
+    Area { prefix: 355217, descriptions: &[("en", "Burrel")] },
+    Area { prefix: 355218, descriptions: &[("en", "Peshkopi")] },
+    Area { prefix: 355219, descriptions: &[("en", "Bulqizë")] },
+    Area { prefix: 355261, descriptions: &[("en", "Vau-Dejës")] },
+    Area { prefix: 355262, descriptions: &[("en", "Rrethinat/Ana-Malit, Shkodër")] },
+    Area { prefix: 355263, descriptions: &[("en", "Pult/Shalë/Shosh/Temal/Shllak, Shkodër")] },
+    Area { prefix: 355264, descriptions: &[("en", "Postribë/Gur i Zi")] },
+    Area { prefix: 355265, descriptions: &[("en", "Vig-Mnelë/Hajmel, Shkodër")] },
+    Area { prefix: 355266, descriptions: &[("en", "Bushat/Bërdicë, Shkodër")] },
+    Area { prefix: 355267, descriptions: &[("en", "Dajç/Velipojë, Shkodër")] },
+    Area { prefix: 355268, descriptions: &[("en", "Qendër/Gruemirë, Malësi e Madhe")] },
+    Area { prefix: 355269, descriptions: &[("en", "Kastrat/Shkrel/Kelmend, Malësi e Madhe")] },
+    Area { prefix: 355270, descriptions: &[("en", "Kolsh/Surroj/Arren/Malzi, Kukës")] },
+    Area { prefix: 355271, descriptions: &[("en", "Fushë-Arrëz/Rrapë, Pukë")] },
+    Area { prefix: 355272, descriptions: &[("en", "Qerret/Qelëz/Gjegjan, Pukë")] },
+    Area { prefix: 355273, descriptions: &[("en", "Iballë/Fierzë/Blerim/Qafë-Mali, Pukë")] },
+    Area { prefix: 355274, descriptions: &[("en", "Tropojë/Llugaj/Margegaj, Tropojë")] },
+    Area { prefix: 355275, descriptions: &[("en", "Bujan/Fierzë/Bytyc/Lekbiba, Tropojë")] },
+    Area { prefix: 355276, descriptions: &[("en", "Fajza/Golaj/Gjinaj, Has")] },
+    Area { prefix: 355277, descriptions: &[("en", "Shtiqen/Tërthore/Zapod, Kukës")] },
+    Area { prefix: 355278, descriptions: &[("en", "Bicaj/Topojan/Shishtavec, Kukës")] },
+    Area { prefix: 355279, descriptions: &[("en", "Gryk-Çajë/Ujmisht/Bushtrice/Kalis, Kukës")] },
+    Area { prefix: 355281, descriptions: &[("en", "Shëngjin/Balldre, Lezhë")] },
+    Area { prefix: 355282, descriptions: &[("en", "Kallmet/Blinisht/Dajç/Ungrej, Lezhë")] },
+    Area { prefix: 355283, descriptions: &[("en", "Kolsh/Zejmen/Shënkoll, Lezhë")] },
+    Area { prefix: 355284, descriptions: &[("en", "Rubik, Mirditë")] },
+    Area { prefix: 355285, descriptions: &[("en", "Kthjellë/Selitë, Mirditë")] },
+    Area { prefix: 355286, descriptions: &[("en", "Kaçinar/Orosh/Fan, Mirditë")] },
+    Area { prefix: 355287, descriptions: &[("en", "Klos/Suç/Lis, Mat")] },
+    Area { prefix: 355288, descriptions: &[("en", "Baz/Komsi/Gurrë/Xibër, Mat")] },
+    Area { prefix: 355289, descriptions: &[("en", "Ulëz/Rukaj/Derjan/Macukull, Mat")] },
+    Area { prefix: 355291, descriptions: &[("en", "Tomin/Luzni, Dibër")] },
+    Area { prefix: 355292, descriptions: &[("en", "Maqellarë/Melan, Dibër")] },
+    Area { prefix: 355293, descriptions: &[("en", "Kastriot/Muhur/Selishtë, Dibër")] },
+    Area { prefix: 355294, descriptions: &[("en", "Arras/Fushë-Çidhën/Lurë, Dibër")] },
+    Area { prefix: 355295, descriptions: &[("en", "Sllovë/Zall-Dardhë/Zall-Reç/Kala e Dodes, Dibër")] },
+    Area { prefix: 355296, descriptions: &[("en", "Fushë-Bulqizë/Shupenzë/Zerqan, Bulqizë")] },
+    Area { prefix: 355297, descriptions: &[("en", "Gjorice/Ostren/Trebisht/Martanesh, Bulqizë")] },
+    Area { prefix: 355311, descriptions: &[("en", "Kuçovë")] },
+    Area { prefix: 355312, descriptions: &[("en", "Çorovodë, Skrapar")] },
+    Area { prefix: 355313, descriptions: &[("en", "Ballsh, Mallakastër")] },
+    Area { prefix: 355360, descriptions: &[("en", "Leshnje/Potom/Çepan/Gjerbës/Zhepë, Skrapar")] },
+    Area { prefix: 355361, descriptions: &[("en", "Ura Vajgurore, Berat")] },
+    Area { prefix: 355362, descriptions: &[("en", "Velabisht/Roshnik, Berat")] },
+    Area { prefix: 355363, descriptions: &[("en", "Otllak/Lumas, Berat")] },
+    Area { prefix: 355364, descriptions: &[("en", "Vërtop/Terpan, Berat")] },
+    Area { prefix: 355365, descriptions: &[("en", "Sinjë/Cukalat, Berat")] },
+    Area { prefix: 355366, descriptions: &[("en", "Poshnjë/Kutalli, Berat")] },
+    Area { prefix: 355367, descriptions: &[("en", "Perondi/Kozarë, Kuçovë")] },
+    Area { prefix: 355368, descriptions: &[("en", "Poliçan/Bogovë, Skrapar")] },
+    Area { prefix: 355369, descriptions: &[("en", "Qendër/Vendreshë, Skrapar")] },
+    Area { prefix: 355371, descriptions: &[("en", "Divjakë, Lushnjë")] },
+    Area { prefix: 355372, descriptions: &[("en", "Karbunarë/Fier-Shegan/Hysgjokaj/Ballagat, Lushnjë")] },
+    Area { prefix: 355373, descriptions: &[("en", "Krutje/Bubullimë/Allkaj, Lushnjë")] },
+    Area { prefix: 355374, descriptions: &[("en", "Gradishtë/Kolonjë, Lushnjë")] },
+    Area { prefix: 355375, descriptions: &[("en", "Golem/Grabian/Remas, Lushnjë")] },
+    Area { prefix: 355376, descriptions: &[("en", "Dushk/Tërbuf, Lushnjë")] },
+    Area { prefix: 355377, descriptions: &[("en", "Qendër/Greshicë/Hekal, Mallakastër")] },
+    Area { prefix: 355378, descriptions: &[("en", "Aranitas/Ngracan/Selitë/Fratar/Kutë, Mallakastër")] },
+    Area { prefix: 355381, descriptions: &[("en", "Patos, Fier")] },
+    Area { prefix: 355382, descriptions: &[("en", "Roskovec, Fier")] },
+    Area { prefix: 355383, descriptions: &[("en", "Qendër, Fier")] },
+    Area { prefix: 355384, descriptions: &[("en", "Mbrostar Ura/LIibofshë, Fier")] },
+    Area { prefix: 355385, descriptions: &[("en", "Portëz/Zharëz, Fier")] },
+    Area { prefix: 355386, descriptions: &[("en", "Kuman/Kurjan/Strum/Ruzhdie, Fier")] },
+    Area { prefix: 355387, descriptions: &[("en", "Cakran/Frakull, Fier")] },
+    Area { prefix: 355388, descriptions: &[("en", "Levan, Fier")] },
+    Area { prefix: 355389, descriptions: &[("en", "Dermenas/Topojë, Fier")] },
+    Area { prefix: 355391, descriptions: &[("en", "Orikum, Vlorë")] },
+    Area { prefix: 355392, descriptions: &[("en", "Selenicë, Vlorë")] },
+    Area { prefix: 355393, descriptions: &[("en", "Himarë, Vlorë")] },
+    Area { prefix: 355394, descriptions: &[("en", "Qendër, Vlorë")] },
+    Area { prefix: 355395, descriptions: &[("en", "Novoselë, Vlorë")] },
+    Area { prefix: 355396, descriptions: &[("en", "Shushicë/Armen, Vlorë")] },
+    Area { prefix: 355397, descriptions: &[("en", "Vllahinë/Kote, Vlorë")] },
+    Area { prefix: 355398, descriptions: &[("en", "Sevaster/Brataj/Hore-Vranisht, Vlorë")] },
+    Area { prefix: 355511, descriptions: &[("en", "Kruje")] },
+    Area { prefix: 355512, descriptions: &[("en", "Peqin")] },
+    Area { prefix: 355513, descriptions: &[("en", "Gramsh")] },
+    Area { prefix: 355514, descriptions: &[("en", "Librazhd")] },
+    Area { prefix: 355561, descriptions: &[("en", "Mamurras, Kurbin")] },
+    Area { prefix: 355562, descriptions: &[("en", "Milot/Fushe-Kuqe, Kurbin")] },
+    Area { prefix: 355563, descriptions: &[("en", "Fushë-Krujë")] },
+    Area { prefix: 355564, descriptions: &[("en", "Nikël/Bubq, Kruje")] },
+    Area { prefix: 355565, descriptions: &[("en", "Koder-Thumane/Cudhi, Kruje")] },
+    Area { prefix: 355570, descriptions: &[("en", "Gosë/Lekaj/Sinaballaj, Kavajë")] },
+    Area { prefix: 355571, descriptions: &[("en", "Shijak, Durrës")] },
+    Area { prefix: 355572, descriptions: &[("en", "Manëz, Durrës")] },
+    Area { prefix: 355573, descriptions: &[("en", "Sukth, Durrës")] },
+    Area { prefix: 355574, descriptions: &[("en", "Rashbull/Gjepalaj, Durrës")] },
+    Area { prefix: 355575, descriptions: &[("en", "Xhafzotaj/Maminas, Durrës")] },
+    Area { prefix: 355576, descriptions: &[("en", "Katund i Ri/Ishem, Durrës")] },
+    Area { prefix: 355577, descriptions: &[("en", "Rrogozhinë, Kavajë")] },
+    Area { prefix: 355578, descriptions: &[("en", "Synej/Golem, Kavajë")] },
+    Area { prefix: 355579, descriptions: &[("en", "Luz i Vogël/Kryevidh/Helmës, Kavajë")] },
+    Area { prefix: 355580, descriptions: &[("en", "Përparim/Pajovë, Peqin")] },
+    Area { prefix: 355581, descriptions: &[("en", "Cërrik, Elbasan")] },
+    Area { prefix: 355582, descriptions: &[("en", "Belsh, Elbasan")] },
+    Area { prefix: 355583, descriptions: &[("en", "Bradashesh/Shirgjan, Elbasan")] },
+    Area { prefix: 355584, descriptions: &[("en", "Labinot-Fushë/Labinot-Mal/Funarë/Gracen, Elbasan")] },
+    Area { prefix: 355585, descriptions: &[("en", "Shushicë/Tregan/Gjinar/Zavalinë, Elbasan")] },
+    Area { prefix: 355586, descriptions: &[("en", "Gjergjan/Papër/Shalës, Elbasan")] },
+    Area { prefix: 355587, descriptions: &[("en", "Gostime/Klos/Mollas, Elbasan")] },
+    Area { prefix: 355588, descriptions: &[("en", "Rrasë/Fierzë/Kajan/Grekan, Elbasan")] },
+    Area { prefix: 355589, descriptions: &[("en", "Karinë/Gjocaj/Shezë, Peqin")] },
+    Area { prefix: 355591, descriptions: &[("en", "Përrenjas, Librazhd")] },
+    Area { prefix: 355592, descriptions: &[("en", "Qendër, Librazhd")] },
+    Area { prefix: 355593, descriptions: &[("en", "Lunik/Orenjë/Stebleve, Librazhd")] },
+    Area { prefix: 355594, descriptions: &[("en", "Hotolisht/Polis/Stravaj, Librazhd")] },
+    Area { prefix: 355595, descriptions: &[("en", "Qukës/Rajcë, Librazhd")] },
+    Area { prefix: 355596, descriptions: &[("en", "Pishaj/Sult/Tunjë/Kushovë/Skënderbegas, Gramsh")] },
+    Area { prefix: 355597, descriptions: &[("en", "Kodovjat/Poroçan/Kukur/Lenie, Gramsh")] },
+    Area { prefix: 355811, descriptions: &[("en", "Bilisht, Devoll")] },
+    Area { prefix: 355812, descriptions: &[("en", "Ersekë, Kolonjë")] },
+    Area { prefix: 355813, descriptions: &[("en", "Përmet")] },
+    Area { prefix: 355814, descriptions: &[("en", "Tepelenë")] },
+    Area { prefix: 355815, descriptions: &[("en", "Delvinë")] },
+    Area { prefix: 355860, descriptions: &[("en", "Trebinjë/Proptisht/Velçan, Pogradec")] },
+    Area { prefix: 355861, descriptions: &[("en", "Maliq, Korçë")] },
+    Area { prefix: 355862, descriptions: &[("en", "Qendër, Korçë")] },
+    Area { prefix: 355863, descriptions: &[("en", "Drenovë/Mollaj, Korçë")] },
+    Area { prefix: 355864, descriptions: &[("en", "Voskop/Voskopojë/Vithkuq/Lekas, Korçë")] },
+    Area { prefix: 355865, descriptions: &[("en", "Gorë/Pirg/Moglicë, Korçë")] },
+    Area { prefix: 355866, descriptions: &[("en", "Libonik/Vreshtaz, Korçë")] },
+    Area { prefix: 355867, descriptions: &[("en", "Pojan/Liqenas, Korçë")] },
+    Area { prefix: 355868, descriptions: &[("en", "Buçimas/Udenisht, Pogradec")] },
+    Area { prefix: 355869, descriptions: &[("en", "Çëravë/Dardhas, Pogradec")] },
+    Area { prefix: 355871, descriptions: &[("en", "Leskovik/Barmash/Novoselë, Kolonjë")] },
+    Area { prefix: 355872, descriptions: &[("en", "Qendër Ersekë/Mollas/Çlirim, Kolonjë")] },
+    Area { prefix: 355873, descriptions: &[("en", "Qendër Bilisht/Progër, Devoll")] },
+    Area { prefix: 355874, descriptions: &[("en", "Hoçisht/Miras, Devoll")] },
+    Area { prefix: 355875, descriptions: &[("en", "Këlcyrë, Përmet")] },
+    Area { prefix: 355876, descriptions: &[("en", "Qendër/Frashër/Petran/Çarshovë, Përmet")] },
+    Area { prefix: 355877, descriptions: &[("en", "Dishnicë/Sukë/Ballaban, Përmet")] },
+    Area { prefix: 355881, descriptions: &[("en", "Libohovë/Qendër, Gjirokastër")] },
+    Area { prefix: 355882, descriptions: &[("en", "Cepo/Picar/Lazarat/Atigon, Gjirokastër")] },
+    Area { prefix: 355883, descriptions: &[("en", "Lunxheri/Odrie/Zagorie/Pogon, Gjirokastër")] },
+    Area { prefix: 355884, descriptions: &[("en", "Dropull i Poshtëm/Dropull i Sipërm, Gjirokastër")] },
+    Area { prefix: 355885, descriptions: &[("en", "Memaliaj, Tepelenë")] },
+    Area { prefix: 355886, descriptions: &[("en", "Qendër/Kurvelesh/Lopëz, Tepelenë")] },
+    Area { prefix: 355887, descriptions: &[("en", "Qesarat/Krahës/Luftinje/Buz, Tepelenë")] },
+    Area { prefix: 355891, descriptions: &[("en", "Konispol/Xare/Markat, Sarandë")] },
+    Area { prefix: 355892, descriptions: &[("en", "Aliko/Lukovë, Sarandë")] },
+    Area { prefix: 355893, descriptions: &[("en", "Ksamil, Sarandë")] },
+    Area { prefix: 355894, descriptions: &[("en", "Livadhja/Dhivër, Sarandë")] },
+    Area { prefix: 355895, descriptions: &[("en", "Finiq/Mesopotam/Vergo, Delvinë")] },
+    Area { prefix: 359301, descriptions: &[("en", "Smolyan"), ("bg", "Смолян")] },
+    Area { prefix: 359306, descriptions: &[("en", "Rudozem"), ("bg", "Рудозем")] },
+    Area { prefix: 359308, descriptions: &[("en", "Madan, Smol."), ("bg", "Мадан, Смол.")] },
+    Area { prefix: 359309, descriptions: &[("en", "Pamporovo"), ("bg", "Пампорово")] },
+    Area { prefix: 359318, descriptions: &[("en", "Saedinenie, Plovdiv"), ("bg", "Съединение, Пловдив")] },
+    Area { prefix: 359331, descriptions: &[("en", "Asenovgrad"), ("bg", "Асеновград")] },
+    Area { prefix: 359335, descriptions: &[("en", "Karlovo"), ("bg", "Карлово")] },
+    Area { prefix: 359336, descriptions: &[("en", "Parvomay, Plovdiv"), ("bg", "Първомай, Пловдив")] },
+    Area { prefix: 359337, descriptions: &[("en", "Hisarya"), ("bg", "Хисаря")] },
+    Area { prefix: 359339, descriptions: &[("en", "Stamboliyski, Plovdiv"), ("bg", "Стамболийски, Пловдив")] },
+    Area { prefix: 359350, descriptions: &[("en", "Peshtera, Pazardzhik"), ("bg", "Пещера, Пазарджик")] },
+    Area { prefix: 359357, descriptions: &[("en", "Panagyurishte"), ("bg", "Панагюрище")] },
+    Area { prefix: 359359, descriptions: &[("en", "Velingrad"), ("bg", "Велинград")] },
+    Area { prefix: 359361, descriptions: &[("en", "Kardzhali"), ("bg", "Кърджали")] },
+    Area { prefix: 359373, descriptions: &[("en", "Harmanli"), ("bg", "Харманли")] },
+    Area { prefix: 359379, descriptions: &[("en", "Svilengrad"), ("bg", "Свиленград")] },
+    Area { prefix: 359391, descriptions: &[("en", "Dimitrovgrad"), ("bg", "Димитровград")] },
+    Area { prefix: 359416, descriptions: &[("en", "Chirpan"), ("bg", "Чирпан")] },
+    Area { prefix: 359417, descriptions: &[("en", "Radnevo"), ("bg", "Раднево")] },
+    Area { prefix: 359418, descriptions: &[("en", "Galabovo, St. Zagora"), ("bg", "Гълъбово, Ст. Загора")] },
+    Area { prefix: 359431, descriptions: &[("en", "Kazanlak"), ("bg", "Казанлък")] },
+    Area { prefix: 359453, descriptions: &[("en", "Kotel"), ("bg", "Котел")] },
+    Area { prefix: 359454, descriptions: &[("en", "Tvarditsa, Sliven"), ("bg", "Твърдица, Сливен")] },
+    Area { prefix: 359457, descriptions: &[("en", "Nova Zagora"), ("bg", "Нова Загора")] },
+    Area { prefix: 359470, descriptions: &[("en", "Topolovgrad"), ("bg", "Тополовград")] },
+    Area { prefix: 359478, descriptions: &[("en", "Elhovo, Yambol"), ("bg", "Елхово, Ямбол")] },
+    Area { prefix: 359517, descriptions: &[("en", "Dalgopol"), ("bg", "Дългопол")] },
+    Area { prefix: 359518, descriptions: &[("en", "Provadia"), ("bg", "Провадия")] },
+    Area { prefix: 359519, descriptions: &[("en", "Devnya"), ("bg", "Девня")] },
+    Area { prefix: 359537, descriptions: &[("en", "Novi pazar, Shumen"), ("bg", "Нови пазар, Шумен")] },
+    Area { prefix: 359538, descriptions: &[("en", "Veliki Preslav"), ("bg", "Велики Преслав")] },
+    Area { prefix: 359550, descriptions: &[("en", "Sozopol"), ("bg", "Созопол")] },
+    Area { prefix: 359554, descriptions: &[("en", "Sunny Beach"), ("bg", "Слънчев бряг")] },
+    Area { prefix: 359556, descriptions: &[("en", "Obzor"), ("bg", "Обзор")] },
+    Area { prefix: 359558, descriptions: &[("en", "Aytos"), ("bg", "Айтос")] },
+    Area { prefix: 359559, descriptions: &[("en", "Karnobat"), ("bg", "Карнобат")] },
+    Area { prefix: 359570, descriptions: &[("en", "Kavarna"), ("bg", "Каварна")] },
+    Area { prefix: 359579, descriptions: &[("en", "Albena"), ("bg", "Албена")] },
+    Area { prefix: 359590, descriptions: &[("en", "Tsarevo"), ("bg", "Царево")] },
+    Area { prefix: 359596, descriptions: &[("en", "Pomorie"), ("bg", "Поморие")] },
+    Area { prefix: 359601, descriptions: &[("en", "Targovishte"), ("bg", "Търговище")] },
+    Area { prefix: 359605, descriptions: &[("en", "Omurtag"), ("bg", "Омуртаг")] },
+    Area { prefix: 359608, descriptions: &[("en", "Popovo, Targ."), ("bg", "Попово, Търг.")] },
+    Area { prefix: 359610, descriptions: &[("en", "Pavlikeni, V. Tarnovo"), ("bg", "Павликени, В. Търново")] },
+    Area { prefix: 359618, descriptions: &[("en", "Gorna Oryahovitsa"), ("bg", "Горна Оряховица")] },
+    Area { prefix: 359619, descriptions: &[("en", "Lyaskovets, V. Tarnovo"), ("bg", "Лясковец, В. Търново")] },
+    Area { prefix: 359631, descriptions: &[("en", "Svishtov"), ("bg", "Свищов")] },
+    Area { prefix: 359650, descriptions: &[("en", "Levski, Pleven"), ("bg", "Левски, Плевен")] },
+    Area { prefix: 359659, descriptions: &[("en", "Cherven bryag"), ("bg", "Червен бряг")] },
+    Area { prefix: 359670, descriptions: &[("en", "Troyan, Lovech"), ("bg", "Троян, Ловеч")] },
+    Area { prefix: 359675, descriptions: &[("en", "Sevlievo"), ("bg", "Севлиево")] },
+    Area { prefix: 359676, descriptions: &[("en", "Dryanovo, Gabr."), ("bg", "Дряново, Габр.")] },
+    Area { prefix: 359677, descriptions: &[("en", "Tryavna"), ("bg", "Трявна")] },
+    Area { prefix: 359678, descriptions: &[("en", "Teteven"), ("bg", "Тетевен")] },
+    Area { prefix: 359697, descriptions: &[("en", "Lukovit"), ("bg", "Луковит")] },
+    Area { prefix: 359701, descriptions: &[("en", "Dupnitsa"), ("bg", "Дупница")] },
+    Area { prefix: 359702, descriptions: &[("en", "Bobov dol"), ("bg", "Бобов дол")] },
+    Area { prefix: 359707, descriptions: &[("en", "Sapareva banya"), ("bg", "Сапарева баня")] },
+    Area { prefix: 359720, descriptions: &[("en", "Etropole"), ("bg", "Етрополе")] },
+    Area { prefix: 359721, descriptions: &[("en", "Kostinbrod"), ("bg", "Костинброд")] },
+    Area { prefix: 359722, descriptions: &[("en", "Samokov"), ("bg", "Самоков")] },
+    Area { prefix: 359723, descriptions: &[("en", "Botevgrad"), ("bg", "Ботевград")] },
+    Area { prefix: 359724, descriptions: &[("en", "Ihtiman"), ("bg", "Ихтиман")] },
+    Area { prefix: 359725, descriptions: &[("en", "Elin Pelin"), ("bg", "Елин Пелин")] },
+    Area { prefix: 359726, descriptions: &[("en", "Svoge"), ("bg", "Своге")] },
+    Area { prefix: 359727, descriptions: &[("en", "Slivnitsa, Sofia"), ("bg", "Сливница, София")] },
+    Area { prefix: 359728, descriptions: &[("en", "Zlatitsa"), ("bg", "Златица")] },
+    Area { prefix: 359729, descriptions: &[("en", "Godech"), ("bg", "Годеч")] },
+    Area { prefix: 359745, descriptions: &[("en", "Petrich, Blag."), ("bg", "Петрич, Благ.")] },
+    Area { prefix: 359746, descriptions: &[("en", "Sandanski"), ("bg", "Сандански")] },
+    Area { prefix: 359747, descriptions: &[("en", "Razlog"), ("bg", "Разлог")] },
+    Area { prefix: 359748, descriptions: &[("en", "Simitli"), ("bg", "Симитли")] },
+    Area { prefix: 359749, descriptions: &[("en", "Bansko"), ("bg", "Банско")] },
+    Area { prefix: 359750, descriptions: &[("en", "Borovets, Sofia"), ("bg", "Боровец, София")] },
+    Area { prefix: 359751, descriptions: &[("en", "Gotse Delchev"), ("bg", "Гоце Делчев")] },
+    Area { prefix: 359777, descriptions: &[("en", "Radomir"), ("bg", "Радомир")] },
+    Area { prefix: 359817, descriptions: &[("en", "Byala, Ruse"), ("bg", "Бяла, Русе")] },
+    Area { prefix: 359848, descriptions: &[("en", "Kubrat"), ("bg", "Кубрат")] },
+    Area { prefix: 359860, descriptions: &[("en", "Silistra"), ("bg", "Силистра")] },
+    Area { prefix: 359861, descriptions: &[("en", "Silistra"), ("bg", "Силистра")] },
+    Area { prefix: 359865, descriptions: &[("en", "Silistra"), ("bg", "Силистра")] },
+    Area { prefix: 359868, descriptions: &[("en", "Silistra"), ("bg", "Силистра")] },
+    Area { prefix: 359910, descriptions: &[("en", "Mezdra"), ("bg", "Мездра")] },
+    Area { prefix: 359915, descriptions: &[("en", "Byala Slatina"), ("bg", "Бяла Слатина")] },
+    Area { prefix: 359936, descriptions: &[("en", "Belogradchik"), ("bg", "Белоградчик")] },
+    Area { prefix: 359938, descriptions: &[("en", "Kula"), ("bg", "Кула")] },
+    Area { prefix: 359953, descriptions: &[("en", "Berkovitsa"), ("bg", "Берковица")] },
+    Area { prefix: 359971, descriptions: &[("en", "Lom"), ("bg", "Лом")] },
+    Area { prefix: 359973, descriptions: &[("en", "Kozloduy"), ("bg", "Козлодуй")] },
+    Area { prefix: 370310, descriptions: &[("en", "Varėna")] },
+    Area { prefix: 370313, descriptions: &[("en", "Druskininkai")] },
+    Area { prefix: 370315, descriptions: &[("en", "Alytus")] },
+    Area { prefix: 370318, descriptions: &[("en", "Lazdijai")] },
+    Area { prefix: 370319, descriptions: &[("en", "Birštonas/Prienai")] },
+    Area { prefix: 370340, descriptions: &[("en", "Ukmergė")] },
+    Area { prefix: 370342, descriptions: &[("en", "Vilkaviškis")] },
+    Area { prefix: 370343, descriptions: &[("en", "Marijampolė")] },
+    Area { prefix: 370345, descriptions: &[("en", "Šakiai")] },
+    Area { prefix: 370346, descriptions: &[("en", "Kaišiadorys")] },
+    Area { prefix: 370347, descriptions: &[("en", "Kėdainiai")] },
+    Area { prefix: 370349, descriptions: &[("en", "Jonava")] },
+    Area { prefix: 370380, descriptions: &[("en", "Šalčininkai")] },
+    Area { prefix: 370381, descriptions: &[("en", "Anykščiai")] },
+    Area { prefix: 370382, descriptions: &[("en", "Širvintos")] },
+    Area { prefix: 370383, descriptions: &[("en", "Molėtai")] },
+    Area { prefix: 370385, descriptions: &[("en", "Zarasai")] },
+    Area { prefix: 370386, descriptions: &[("en", "Ignalina/Visaginas")] },
+    Area { prefix: 370387, descriptions: &[("en", "Švenčionys")] },
+    Area { prefix: 370389, descriptions: &[("en", "Utena")] },
+    Area { prefix: 370421, descriptions: &[("en", "Pakruojis")] },
+    Area { prefix: 370422, descriptions: &[("en", "Radviliškis")] },
+    Area { prefix: 370425, descriptions: &[("en", "Akmenė")] },
+    Area { prefix: 370426, descriptions: &[("en", "Joniškis")] },
+    Area { prefix: 370427, descriptions: &[("en", "Kelmė")] },
+    Area { prefix: 370428, descriptions: &[("en", "Raseiniai")] },
+    Area { prefix: 370440, descriptions: &[("en", "Skuodas")] },
+    Area { prefix: 370441, descriptions: &[("en", "Šilutė")] },
+    Area { prefix: 370443, descriptions: &[("en", "Mažeikiai")] },
+    Area { prefix: 370444, descriptions: &[("en", "Telšiai")] },
+    Area { prefix: 370445, descriptions: &[("en", "Kretinga")] },
+    Area { prefix: 370446, descriptions: &[("en", "Tauragė")] },
+    Area { prefix: 370447, descriptions: &[("en", "Jurbarkas")] },
+    Area { prefix: 370448, descriptions: &[("en", "Plungė")] },
+    Area { prefix: 370449, descriptions: &[("en", "Šilalė")] },
+    Area { prefix: 370450, descriptions: &[("en", "Biržai")] },
+    Area { prefix: 370451, descriptions: &[("en", "Pasvalys")] },
+    Area { prefix: 370458, descriptions: &[("en", "Rokiškis")] },
+    Area { prefix: 370459, descriptions: &[("en", "Kupiškis")] },
+    Area { prefix: 370460, descriptions: &[("en", "Palanga")] },
+    Area { prefix: 370469, descriptions: &[("en", "Neringa")] },
+    Area { prefix: 370520, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370521, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370522, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370523, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370524, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370525, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370526, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370527, descriptions: &[("en", "Vilnius")] },
+    Area { prefix: 370528, descriptions: &[("en", "Trakai")] },
+    Area { prefix: 373210, descriptions: &[("en", "Grigoriopol"), ("ru", "Григориополь")] },
+    Area { prefix: 373215, descriptions: &[("en", "Dubasari"), ("ro", "Dubăsari"), ("ru", "Дубэсарь")] },
+    Area { prefix: 373216, descriptions: &[("en", "Camenca"), ("ru", "Каменка")] },
+    Area { prefix: 373219, descriptions: &[("en", "Dnestrovsk"), ("ru", "Днестровск")] },
+    Area { prefix: 373230, descriptions: &[("en", "Soroca"), ("ru", "Сорока")] },
+    Area { prefix: 373231, descriptions: &[("en", "Balţi"), ("ro", "Bălţi"), ("ru", "Бэлць")] },
+    Area { prefix: 373235, descriptions: &[("en", "Orhei"), ("ru", "Орхей")] },
+    Area { prefix: 373236, descriptions: &[("en", "Ungheni"), ("ru", "Унгень")] },
+    Area { prefix: 373237, descriptions: &[("en", "Straseni"), ("ro", "Străşeni"), ("ru", "Стрэшень")] },
+    Area { prefix: 373241, descriptions: &[("en", "Cimislia"), ("ro", "Cimişlia"), ("ru", "Чимишлия")] },
+    Area { prefix: 373242, descriptions: &[("en", "Stefan Voda"), ("ro", "Ştefan Vodă"), ("ru", "Штефан Водэ")] },
+    Area { prefix: 373243, descriptions: &[("en", "Causeni"), ("ro", "Căuşeni"), ("ru", "Кэушень")] },
+    Area { prefix: 373244, descriptions: &[("en", "Calarasi"), ("ro", "Călăraşi"), ("ru", "Кэлэрашь")] },
+    Area { prefix: 373246, descriptions: &[("en", "Edineţ"), ("ru", "Единец")] },
+    Area { prefix: 373247, descriptions: &[("en", "Briceni"), ("ru", "Бричень")] },
+    Area { prefix: 373248, descriptions: &[("en", "Criuleni"), ("ru", "Криулень")] },
+    Area { prefix: 373249, descriptions: &[("en", "Glodeni"), ("ru", "Глодень")] },
+    Area { prefix: 373250, descriptions: &[("en", "Floresti"), ("ro", "Floreşti"), ("ru", "Флорешть")] },
+    Area { prefix: 373251, descriptions: &[("en", "Donduseni"), ("ro", "Donduşeni"), ("ru", "Дондушень")] },
+    Area { prefix: 373252, descriptions: &[("en", "Drochia"), ("ru", "Дрокия")] },
+    Area { prefix: 373254, descriptions: &[("en", "Rezina"), ("ru", "Резина")] },
+    Area { prefix: 373256, descriptions: &[("en", "Riscani"), ("ro", "Rîşcani"), ("ru", "Рышкань")] },
+    Area { prefix: 373258, descriptions: &[("en", "Telenesti"), ("ro", "Teleneşti"), ("ru", "Теленешть")] },
+    Area { prefix: 373259, descriptions: &[("en", "Falesti"), ("ro", "Făleşti"), ("ru", "Фэлешть")] },
+    Area { prefix: 373262, descriptions: &[("en", "Singerei"), ("ro", "Sîngerei"), ("ru", "Сынжерей")] },
+    Area { prefix: 373263, descriptions: &[("en", "Leova"), ("ru", "Леова")] },
+    Area { prefix: 373264, descriptions: &[("en", "Nisporeni"), ("ru", "Ниспорень")] },
+    Area { prefix: 373265, descriptions: &[("en", "Anenii Noi"), ("ru", "Анений Ной")] },
+    Area { prefix: 373268, descriptions: &[("en", "Ialoveni"), ("ru", "Яловень")] },
+    Area { prefix: 373269, descriptions: &[("en", "Hincesti"), ("ro", "Hînceşti"), ("ru", "Хынчешть")] },
+    Area { prefix: 373271, descriptions: &[("en", "Ocniţa"), ("ru", "Окница")] },
+    Area { prefix: 373272, descriptions: &[("en", "Soldanesti"), ("ro", "Şoldăneşti"), ("ru", "Шолдэнешть")] },
+    Area { prefix: 373273, descriptions: &[("en", "Cantemir"), ("ru", "Кантемир")] },
+    Area { prefix: 373291, descriptions: &[("en", "Ceadir Lunga"), ("ro", "Ceadîr Lunga"), ("ru", "Чадыр-Лунга")] },
+    Area { prefix: 373293, descriptions: &[("en", "Vulcanesti"), ("ro", "Vulcăneşti"), ("ru", "Вулкэнешть")] },
+    Area { prefix: 373294, descriptions: &[("en", "Taraclia"), ("ru", "Тараклия")] },
+    Area { prefix: 373297, descriptions: &[("en", "Basarabeasca"), ("ru", "Басарабяска")] },
+    Area { prefix: 373298, descriptions: &[("en", "Comrat"), ("ru", "Комрат")] },
+    Area { prefix: 373299, descriptions: &[("en", "Cahul"), ("ru", "Кагул")] },
+    Area { prefix: 373552, descriptions: &[("en", "Bender"), ("ru", "Бендер")] },
+    Area { prefix: 373555, descriptions: &[("en", "Ribnita"), ("ro", "Rîbniţa"), ("ru", "Рыбница")] },
+    Area { prefix: 373557, descriptions: &[("en", "Slobozia"), ("ru", "Слобозия")] },
+    Area { prefix: 374226, descriptions: &[("en", "Charentsavan, Kotayk"), ("hy", "Չարենցավան, Կոտայք"), ("ru", "Чаренцаван, Котайк")] },
+    Area { prefix: 374234, descriptions: &[("en", "Ararat/Vedi, Ararat"), ("hy", "Արարատ/Վեդի, Արարատ"), ("ru", "Арарат/Веди, Арарат")] },
+    Area { prefix: 374238, descriptions: &[("en", "Ararat/Avshar/Surenavan/Yeraskh"), ("hy", "Արարատ/Ավշար/Սուրենավան/Երասխ"), ("ru", "Арарат/Авшар/Суренаван/Ерасх")] },
+    Area { prefix: 374245, descriptions: &[("en", "Ashotsk, Shirak"), ("hy", "Աշոցք, Շիրակ"), ("ru", "Ашоцк, Ширакский")] },
+    Area { prefix: 374246, descriptions: &[("en", "Amasia, Shirak"), ("hy", "Ամասիա, Շիրակ"), ("ru", "Амасия, Ширакский")] },
+    Area { prefix: 374249, descriptions: &[("en", "Talin, Aragatsotn"), ("hy", "Թալին, Արագածոտն"), ("ru", "Талин, Арагацо́тн")] },
+    Area { prefix: 374252, descriptions: &[("en", "Aparan, Aragatsotn"), ("hy", "Ապարան, Արագածոտն"), ("ru", "Апаран, Арагацо́тн")] },
+    Area { prefix: 374254, descriptions: &[("en", "Tashir, Lori"), ("hy", "Տաշիր, Լոռի"), ("ru", "Ташир, Лори")] },
+    Area { prefix: 374255, descriptions: &[("en", "Spitak, Lori"), ("hy", "Սպիտակ, Լոռի"), ("ru", "Спитак, Лори")] },
+    Area { prefix: 374257, descriptions: &[("en", "Aragats, Aragatsotn"), ("hy", "Արագած, Արագածոտն"), ("ru", "Арагац, Арагацо́тн")] },
+    Area { prefix: 374261, descriptions: &[("en", "Sevan, Gegharkunik"), ("hy", "Սևան, Գեղարքունիք"), ("ru", "Севан, Гегаркуникский")] },
+    Area { prefix: 374262, descriptions: &[("en", "Martuni, Gegharkunik"), ("hy", "Մարտունի, Գեղարքունիք"), ("ru", "Мартуни, Гегаркуникский")] },
+    Area { prefix: 374264, descriptions: &[("en", "Gavar, Gegharkunik"), ("hy", "Գավառ, Գեղարքունիք"), ("ru", "Гавар, Гегаркуникский")] },
+    Area { prefix: 374265, descriptions: &[("en", "Tchambarak, Gegharkunik"), ("hy", "Ճամբարակ, Գեղարքունիք"), ("ru", "Чамбарак, Гегаркуникский")] },
+    Area { prefix: 374269, descriptions: &[("en", "Vardenis, Gegharkunik"), ("hy", "Վարդենիս, Գեղարքունիք"), ("ru", "Варденис, Гегаркуникский")] },
+    Area { prefix: 374282, descriptions: &[("en", "Vayk, Vayots dzor"), ("hy", "Վայք, Վայոց Ձոր"), ("ru", "Вайк, ВАЙОЦДЗОРСКИЙ")] },
+    Area { prefix: 374284, descriptions: &[("en", "Goris, Syunik"), ("hy", "Գորիս, Սյունիք"), ("ru", "Горис, Сюника")] },
+    Area { prefix: 374287, descriptions: &[("en", "Jermuk, Vayots dzor"), ("hy", "Ջերմուկ, Վայոց Ձոր"), ("ru", "Джермук, ВАЙОЦДЗОРСКИЙ")] },
+    Area { prefix: 374312, descriptions: &[("en", "Gyumri, Shirak"), ("hy", "Գյումրի, Շիրակ"), ("ru", "Гюмри, Ширакский")] },
+    Area { prefix: 374322, descriptions: &[("en", "Vanadzor, Lori"), ("hy", "Վանաձոր, Լոռի"), ("ru", "Ванадзор, Лори")] },
+    Area { prefix: 374470, descriptions: &[("en", "Nagorno-Karabakh"), ("hy", "Լեռնային Ղարաբաղ"), ("ru", "Нагорный Карабах")] },
+    Area { prefix: 374471, descriptions: &[("en", "Stepanakert"), ("hy", "Ստեփանակերտ"), ("ru", "Степанакерт")] },
+    Area { prefix: 374472, descriptions: &[("en", "Nagorno-Karabakh"), ("hy", "Լեռնային Ղարաբաղ"), ("ru", "Нагорный Карабах")] },
+    Area { prefix: 374473, descriptions: &[("en", "Nagorno-Karabakh"), ("hy", "Լեռնային Ղարաբաղ"), ("ru", "Нагорный Карабах")] },
+    Area { prefix: 374474, descriptions: &[("en", "Martakert"), ("hy", "Մարտակերտ"), ("ru", "Мартакерт")] },
+    Area { prefix: 374475, descriptions: &[("en", "Hadrut"), ("hy", "Հադրութ"), ("ru", "Гадрут")] },
+    Area { prefix: 374476, descriptions: &[("en", "Askeran"), ("hy", "Ասկերան"), ("ru", "Аскеран")] },
+    Area { prefix: 374477, descriptions: &[("en", "Shushi"), ("hy", "Շուշի"), ("ru", "Шуши")] },
+    Area { prefix: 374478, descriptions: &[("en", "Martuni"), ("hy", "Մարտունի"), ("ru", "Мартуни")] },
+    Area { prefix: 374479, descriptions: &[("en", "Stepanakert"), ("hy", "Ստեփանակերտ"), ("ru", "Степанакерт")] },
+    Area { prefix: 375152, descriptions: &[("en", "Grodno"), ("be", "Гродна"), ("ru", "Гродно")] },
+    Area { prefix: 375154, descriptions: &[("en", "Lida"), ("be", "Ліда"), ("ru", "Лида")] },
+    Area { prefix: 375162, descriptions: &[("en", "Brest"), ("be", "Брэст"), ("ru", "Брест")] },
+    Area { prefix: 375163, descriptions: &[("en", "Baranovichi"), ("be", "Баранавічы"), ("ru", "Барановичи")] },
+    Area { prefix: 375165, descriptions: &[("en", "Pinsk"), ("be", "Пінск"), ("ru", "Пинск")] },
+    Area { prefix: 375174, descriptions: &[("en", "Soligorsk"), ("be", "Салігорск"), ("ru", "Солигорск")] },
+    Area { prefix: 375176, descriptions: &[("en", "Molodechno"), ("be", "Маладзечна"), ("ru", "Молодечно")] },
+    Area { prefix: 375177, descriptions: &[("en", "Borisov"), ("be", "Барысаў"), ("ru", "Борисов")] },
+    Area { prefix: 375212, descriptions: &[("en", "Vitebsk"), ("be", "Віцебск"), ("ru", "Витебск")] },
+    Area { prefix: 375214, descriptions: &[("en", "Polotsk/Navapolatsk"), ("be", "Полацк/Наваполацк"), ("ru", "Полоцк/Новополоцк")] },
+    Area { prefix: 375216, descriptions: &[("en", "Orsha"), ("be", "Орша"), ("ru", "Орша")] },
+    Area { prefix: 375222, descriptions: &[("en", "Mogilev"), ("be", "Магілёў"), ("ru", "Могилев")] },
+    Area { prefix: 375225, descriptions: &[("en", "Babruysk"), ("be", "Бабруйск"), ("ru", "Бобруйск")] },
+    Area { prefix: 375232, descriptions: &[("en", "Gomel"), ("be", "Гомель"), ("ru", "Гомель")] },
+    Area { prefix: 375236, descriptions: &[("en", "Mozyr"), ("be", "Мазыр"), ("ru", "Мозырь")] },
+    Area { prefix: 380312, descriptions: &[("en", "Uzhgorod/Chop, Zakarpattia"), ("uk", "Ужгород/Чоп, Закарпатська")] },
+    Area { prefix: 380320, descriptions: &[("en", "Lviv"), ("uk", "Львів/Львівська")] },
+    Area { prefix: 380321, descriptions: &[("en", "Lviv"), ("uk", "Львів/Львівська")] },
+    Area { prefix: 380322, descriptions: &[("en", "Bryukhovichi/Lviv, Lviv"), ("uk", "Брюховичі/Львів, Львівська")] },
+    Area { prefix: 380327, descriptions: &[("en", "Lviv"), ("uk", "Львів/Львівська")] },
+    Area { prefix: 380328, descriptions: &[("en", "Lviv"), ("uk", "Львів/Львівська")] },
+    Area { prefix: 380329, descriptions: &[("en", "Lviv"), ("uk", "Львів/Львівська")] },
+    Area { prefix: 380332, descriptions: &[("en", "Lutsk, Volyn"), ("uk", "Луцьк, Волинська")] },
+    Area { prefix: 380522, descriptions: &[("en", "Kropyvnytskyi, Kirovohrad"), ("uk", "Кропивницький, Кіровоград")] },
+    Area { prefix: 380560, descriptions: &[("en", "Dnipropetrovsk/Dnipro"), ("uk", "Дніпропетро́вськ/Дніпро")] },
+    Area { prefix: 380561, descriptions: &[("en", "Dnipropetrovsk/Dnipro"), ("uk", "Дніпропетро́вськ/Дніпро")] },
+    Area { prefix: 380562, descriptions: &[("en", "Dnipropetrovsk/Dnipro"), ("uk", "Дніпропетровськ, Дніпро")] },
+    Area { prefix: 380564, descriptions: &[("en", "Krivoy Rog, Dnipro"), ("uk", "Кривий Ріг, Дніпро")] },
+    Area { prefix: 380567, descriptions: &[("en", "Dnipropetrovsk/Dnipro"), ("uk", "Дніпропетро́вськ/Дніпро")] },
+    Area { prefix: 380568, descriptions: &[("en", "Dnipropetrovsk/Dnipro"), ("uk", "Дніпропетро́вськ/Дніпро")] },
+    Area { prefix: 380569, descriptions: &[("en", "Dneprodzerzhinsk/Novomoskovsk, Dnipro"), ("uk", "Дніпродзержинськ/Новомосковськ, Дніпро")] },
+    Area { prefix: 380572, descriptions: &[("en", "Merefa/Kharkiv, Kharkiv"), ("uk", "Мерефа/Харків, Харків")] },
+    Area { prefix: 380612, descriptions: &[("en", "Zaporozhye, Zaporizhzhia"), ("uk", "Запоріжжя, Запорізька")] },
+    Area { prefix: 380619, descriptions: &[("en", "Melitopol, Zaporizhzhia"), ("uk", "Мелітополь, Запорізька")] },
+    Area { prefix: 380620, descriptions: &[("en", "Donetsk"), ("uk", "Донецьк")] },
+    Area { prefix: 380621, descriptions: &[("en", "Donetsk"), ("uk", "Донецьк")] },
+    Area { prefix: 380622, descriptions: &[("en", "Donetsk"), ("uk", "Донецьк")] },
+    Area { prefix: 380623, descriptions: &[("en", "Krasnoarmeysk/Makeyevka, Donetsk"), ("uk", "Красноармійськ/Макіївка, Донецьк")] },
+    Area { prefix: 380628, descriptions: &[("en", "Donetsk"), ("uk", "Донецьк")] },
+    Area { prefix: 380629, descriptions: &[("en", "Mariupol, Donetsk"), ("uk", "Маріуполь, Донецьк")] },
+    Area { prefix: 380640, descriptions: &[("en", "Luhansk"), ("uk", "Луганськ")] },
+    Area { prefix: 380641, descriptions: &[("en", "Luhansk"), ("uk", "Луганськ")] },
+    Area { prefix: 380642, descriptions: &[("en", "Luhansk/Oleksandrivsk, Luhansk"), ("uk", "Луганськ/Олександрівськ, Луганськ")] },
+    Area { prefix: 380647, descriptions: &[("en", "Luhansk"), ("uk", "Луганськ")] },
+    Area { prefix: 380648, descriptions: &[("en", "Luhansk"), ("uk", "Луганськ")] },
+    Area { prefix: 380649, descriptions: &[("en", "Luhansk"), ("uk", "Луганськ")] },
+    Area { prefix: 381230, descriptions: &[("en", "Kikinda"), ("sr", "Кикинда")] },
+    Area { prefix: 381280, descriptions: &[("en", "Gnjilane"), ("sr", "Гњилане")] },
+    Area { prefix: 381290, descriptions: &[("en", "Urosevac"), ("sr", "Урошевац")] },
+    Area { prefix: 381390, descriptions: &[("en", "Dakovica"), ("sr", "Ђаковица")] },
+    Area { prefix: 389472, descriptions: &[("en", "Bitola/Demir Hisar/Resen")] },
+    Area { prefix: 389474, descriptions: &[("en", "Bitola/Demir Hisar/Resen")] },
+    Area { prefix: 389475, descriptions: &[("en", "Bitola/Demir Hisar/Resen")] },
+    Area { prefix: 389477, descriptions: &[("en", "Bitola/Demir Hisar/Resen")] },
+    Area { prefix: 389478, descriptions: &[("en", "Bitola/Demir Hisar/Resen")] },
+    Area { prefix: 389484, descriptions: &[("en", "Prilep/Krusevo")] },
+    Area { prefix: 389485, descriptions: &[("en", "Prilep/Krusevo")] },
+    Area { prefix: 389488, descriptions: &[("en", "Prilep/Krusevo")] },
+    Area { prefix: 421601, descriptions: &[("en", "Roznava")] },
+    Area { prefix: 504261, descriptions: &[("en", "Choloma, Cortés")] },
+    Area { prefix: 504268, descriptions: &[("en", "La Lima")] },
+    Area { prefix: 504270, descriptions: &[("en", "Olancho")] },
+    Area { prefix: 504287, descriptions: &[("en", "Choluteca")] },
+    Area { prefix: 592216, descriptions: &[("en", "Diamond/Grove")] },
+    Area { prefix: 592217, descriptions: &[("en", "Mocha")] },
+    Area { prefix: 592218, descriptions: &[("en", "Georgetown (S/R/Veldt)")] },
+    Area { prefix: 592219, descriptions: &[("en", "Georgetown,Sophia")] },
+    Area { prefix: 592220, descriptions: &[("en", "B/V Central")] },
+    Area { prefix: 592221, descriptions: &[("en", "Mahaicony")] },
+    Area { prefix: 592222, descriptions: &[("en", "B/V West")] },
+    Area { prefix: 592223, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 592225, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 592226, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 592227, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 592228, descriptions: &[("en", "Mahaica/Belmont")] },
+    Area { prefix: 592229, descriptions: &[("en", "Enterprise/Cove & John")] },
+    Area { prefix: 592231, descriptions: &[("en", "Georgetown")] },
+    Area { prefix: 592232, descriptions: &[("en", "Novar/Catherine/Belladrum/Bush Lot")] },
+    Area { prefix: 592233, descriptions: &[("en", "Agricola/Houston/Eccles/Nandy Park")] },
+    Area { prefix: 592234, descriptions: &[("en", "B/V Central")] },
+    Area { prefix: 592253, descriptions: &[("en", "La Grange/Goed Fortuin")] },
+    Area { prefix: 592254, descriptions: &[("en", "New Road/Best")] },
+    Area { prefix: 592255, descriptions: &[("en", "Paradise/Golden Grove/Haslington")] },
+    Area { prefix: 592256, descriptions: &[("en", "Victoria/Hope West")] },
+    Area { prefix: 592257, descriptions: &[("en", "Cane Grove/Strangroen")] },
+    Area { prefix: 592258, descriptions: &[("en", "Planters Hall/Mortice")] },
+    Area { prefix: 592259, descriptions: &[("en", "Clonbrook/Unity")] },
+    Area { prefix: 592260, descriptions: &[("en", "Tuschen/Parika")] },
+    Area { prefix: 592261, descriptions: &[("en", "Timehri/Long Creek/Soesdyke")] },
+    Area { prefix: 592262, descriptions: &[("en", "Parika")] },
+    Area { prefix: 592264, descriptions: &[("en", "Vreed-en-Hoop")] },
+    Area { prefix: 592265, descriptions: &[("en", "Diamond")] },
+    Area { prefix: 592266, descriptions: &[("en", "New Hope/Friendship/Grove/Land of Canaan")] },
+    Area { prefix: 592267, descriptions: &[("en", "Wales")] },
+    Area { prefix: 592268, descriptions: &[("en", "Leonora")] },
+    Area { prefix: 592269, descriptions: &[("en", "Windsor Forest")] },
+    Area { prefix: 592270, descriptions: &[("en", "Melanie/Non Pariel/Enmore")] },
+    Area { prefix: 592271, descriptions: &[("en", "Canal No. 1/Canal No. 2")] },
+    Area { prefix: 592272, descriptions: &[("en", "B/V West")] },
+    Area { prefix: 592274, descriptions: &[("en", "Vigilance")] },
+    Area { prefix: 592275, descriptions: &[("en", "Met-en-Meer-Zorg")] },
+    Area { prefix: 592276, descriptions: &[("en", "Anna Catherina/ Cornelia Ida/Hague/Fellowship")] },
+    Area { prefix: 592277, descriptions: &[("en", "Zeeburg/Uitvlugt")] },
+    Area { prefix: 592279, descriptions: &[("en", "Good Hope/Stanleytown")] },
+    Area { prefix: 592322, descriptions: &[("en", "Kilcoy/Hampshire/Nigg")] },
+    Area { prefix: 592325, descriptions: &[("en", "Mibikuri/No: 34/Joppa/Brighton")] },
+    Area { prefix: 592326, descriptions: &[("en", "Adelphi/Fryish/No. 40")] },
+    Area { prefix: 592327, descriptions: &[("en", "Blairmont/Cumberland")] },
+    Area { prefix: 592328, descriptions: &[("en", "Cottage/Tempe/Onverwagt/Bath/Waterloo")] },
+    Area { prefix: 592329, descriptions: &[("en", "Willemstad/Fort Wellington/Ithaca")] },
+    Area { prefix: 592330, descriptions: &[("en", "Rosignol/Shieldstown")] },
+    Area { prefix: 592331, descriptions: &[("en", "Adventure/Joanna")] },
+    Area { prefix: 592332, descriptions: &[("en", "Sheet Anchor/Susannah")] },
+    Area { prefix: 592333, descriptions: &[("en", "New Amsterdam")] },
+    Area { prefix: 592334, descriptions: &[("en", "New Amsterdam")] },
+    Area { prefix: 592335, descriptions: &[("en", "Crabwood Creek/No: 76/Corentyne")] },
+    Area { prefix: 592336, descriptions: &[("en", "Edinburg/Port Mourant")] },
+    Area { prefix: 592337, descriptions: &[("en", "Whim/Bloomfield/Liverpool/Rose Hall")] },
+    Area { prefix: 592338, descriptions: &[("en", "Benab/No. 65 Village/Massiah")] },
+    Area { prefix: 592339, descriptions: &[("en", "No: 52/Skeldon")] },
+    Area { prefix: 592440, descriptions: &[("en", "Kwakwani")] },
+    Area { prefix: 592441, descriptions: &[("en", "Ituni")] },
+    Area { prefix: 592442, descriptions: &[("en", "Christianburg/Amelia’s Ward")] },
+    Area { prefix: 592444, descriptions: &[("en", "Linden/Canvas City/Wisroc")] },
+    Area { prefix: 592455, descriptions: &[("en", "Bartica")] },
+    Area { prefix: 592456, descriptions: &[("en", "Mahdia")] },
+    Area { prefix: 592772, descriptions: &[("en", "Lethem")] },
+    Area { prefix: 592773, descriptions: &[("en", "Aishalton")] },
+    Area { prefix: 592775, descriptions: &[("en", "Matthews Ridge")] },
+    Area { prefix: 592777, descriptions: &[("en", "Mabaruma/Port Kaituma")] },
+    Area { prefix: 595271, descriptions: &[("en", "Benjamin  Aceval")] },
+    Area { prefix: 595275, descriptions: &[("en", "Ypane")] },
+    Area { prefix: 595291, descriptions: &[("en", "Aregua")] },
+    Area { prefix: 595292, descriptions: &[("en", "Nueva Italia")] },
+    Area { prefix: 595293, descriptions: &[("en", "Guarambare")] },
+    Area { prefix: 595294, descriptions: &[("en", "Itaugua")] },
+    Area { prefix: 595295, descriptions: &[("en", "Jose Augusto Saldivar")] },
+    Area { prefix: 595345, descriptions: &[("en", "Corpus Christi")] },
+    Area { prefix: 595451, descriptions: &[("en", "Colonia Volendam")] },
+    Area { prefix: 595453, descriptions: &[("en", "Capiibary")] },
+    Area { prefix: 595550, descriptions: &[("en", "Mauricio Jose Troche")] },
+    Area { prefix: 595552, descriptions: &[("en", "Paso Yobay")] },
+    Area { prefix: 595553, descriptions: &[("en", "Tebicuary")] },
+    Area { prefix: 595554, descriptions: &[("en", "Itape")] },
+    Area { prefix: 595631, descriptions: &[("en", "Hernandarias")] },
+    Area { prefix: 595632, descriptions: &[("en", "Colonia Yguazu")] },
+    Area { prefix: 595633, descriptions: &[("en", "Cedrales")] },
+    Area { prefix: 595671, descriptions: &[("en", "Mayor Otano")] },
+    Area { prefix: 595672, descriptions: &[("en", "Kressburgo")] },
+    Area { prefix: 595673, descriptions: &[("en", "Santa Rita")] },
+    Area { prefix: 595674, descriptions: &[("en", "Juan E. O Leary")] },
+    Area { prefix: 595675, descriptions: &[("en", "Juan Leon Mallorquin")] },
+    Area { prefix: 595676, descriptions: &[("en", "Naranjal")] },
+    Area { prefix: 595677, descriptions: &[("en", "San Alberto")] },
+    Area { prefix: 595678, descriptions: &[("en", "Santa Rosa Del Monday")] },
+    Area { prefix: 595740, descriptions: &[("en", "General  Delgado")] },
+    Area { prefix: 595741, descriptions: &[("en", "Coronel Bogado")] },
+    Area { prefix: 595742, descriptions: &[("en", "San Pedro Del Parana")] },
+    Area { prefix: 595743, descriptions: &[("en", "General Artigas")] },
+    Area { prefix: 595761, descriptions: &[("en", "Colonia Fram")] },
+    Area { prefix: 595762, descriptions: &[("en", "Carmen Del Parana")] },
+    Area { prefix: 595763, descriptions: &[("en", "La Paz")] },
+    Area { prefix: 595764, descriptions: &[("en", "Maria Auxiliadora")] },
+    Area { prefix: 595767, descriptions: &[("en", "Bella Vista Sur")] },
+    Area { prefix: 595768, descriptions: &[("en", "Pirapo")] },
+    Area { prefix: 595780, descriptions: &[("en", "Alberdi")] },
+    Area { prefix: 595781, descriptions: &[("en", "Santa María / Misiones")] },
+    Area { prefix: 595782, descriptions: &[("en", "Santiago")] },
+    Area { prefix: 595783, descriptions: &[("en", "San Miguel / Misiones")] },
+    Area { prefix: 595784, descriptions: &[("en", "San Juan Neembucu")] },
+    Area { prefix: 595785, descriptions: &[("en", "Paso De Patria")] },
+    Area { prefix: 595787, descriptions: &[("en", "General  Diaz")] },
+    Area { prefix: 598433, descriptions: &[("en", "Canelones")] },
+    Area { prefix: 598434, descriptions: &[("en", "San Jose de Mayo")] },
+    Area { prefix: 598435, descriptions: &[("en", "Florida")] },
+    Area { prefix: 598436, descriptions: &[("en", "Durazno")] },
+    Area { prefix: 598444, descriptions: &[("en", "Minas/Lavalleja")] },
+    Area { prefix: 598445, descriptions: &[("en", "Treinta y Tres")] },
+    Area { prefix: 598447, descriptions: &[("en", "Rocha")] },
+    Area { prefix: 598452, descriptions: &[("en", "Colonia del Scaramento")] },
+    Area { prefix: 598453, descriptions: &[("en", "Mercedes/Soriano")] },
+    Area { prefix: 598456, descriptions: &[("en", "Fray Bentos/Rio Negro")] },
+    Area { prefix: 598462, descriptions: &[("en", "Rivera")] },
+    Area { prefix: 598463, descriptions: &[("en", "Tacuarembo")] },
+    Area { prefix: 598464, descriptions: &[("en", "Melo/Cerro Largo")] },
+    Area { prefix: 598472, descriptions: &[("en", "Paysandu")] },
+    Area { prefix: 598473, descriptions: &[("en", "Salto")] },
+    Area { prefix: 598477, descriptions: &[("en", "Artigas")] },
+    Area { prefix: 599417, descriptions: &[("en", "Saba")] },
+    Area { prefix: 673220, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673221, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673222, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673223, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673224, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673225, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673226, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 673227, descriptions: &[("en", "Brunei Muara")] },
+    Area { prefix: 680622, descriptions: &[("en", "Ngchesar State")] },
+    Area { prefix: 686650, descriptions: &[("en", "Bairiki")] },
+    Area { prefix: 686651, descriptions: &[("en", "Betio")] },
+    Area { prefix: 686652, descriptions: &[("en", "Bikenibeu")] },
+    Area { prefix: 686653, descriptions: &[("en", "Gilbert Islands")] },
+    Area { prefix: 686654, descriptions: &[("en", "Gilbert Islands")] },
+    Area { prefix: 686655, descriptions: &[("en", "Phoenix Islands")] },
+    Area { prefix: 689404, descriptions: &[("en", "Îles du Vent(IDV)")] },
+    Area { prefix: 689405, descriptions: &[("en", "Îles du Vent(IDV)")] },
+    Area { prefix: 689406, descriptions: &[("en", "Îles Sous-le-vent(ISLV)")] },
+    Area { prefix: 689408, descriptions: &[("en", "Îles du Vent(IDV)")] },
+    Area { prefix: 689409, descriptions: &[("en", "Remote Archipelago")] },
+    Area { prefix: 689494, descriptions: &[("en", "Polynesia")] },
+    Area { prefix: 689495, descriptions: &[("en", "Polynesia")] },
+    Area { prefix: 689496, descriptions: &[("en", "Polynesia")] },
+    Area { prefix: 689498, descriptions: &[("en", "Polynesia")] },
+    Area { prefix: 850195, descriptions: &[("en", "Pyongyang")] },
+    Area { prefix: 880316, descriptions: &[("en", "Chittagong")] },
+    Area { prefix: 880317, descriptions: &[("en", "Chittagong")] },
+    Area { prefix: 880318, descriptions: &[("en", "Chittagong")] },
+    Area { prefix: 880321, descriptions: &[("en", "Noakhali/Chatkhil")] },
+    Area { prefix: 880331, descriptions: &[("en", "Feni/Sonagazi/Chagalnaiya/Daganbhuyan")] },
+    Area { prefix: 880341, descriptions: &[("en", "Eidgaon/Cox's bazar")] },
+    Area { prefix: 880351, descriptions: &[("en", "Rangamati")] },
+    Area { prefix: 880352, descriptions: &[("en", "Kaptai")] },
+    Area { prefix: 880371, descriptions: &[("en", "Khagrachari")] },
+    Area { prefix: 880381, descriptions: &[("en", "Laximpur/Ramganj")] },
+    Area { prefix: 880403, descriptions: &[("en", "Dighalia")] },
+    Area { prefix: 880421, descriptions: &[("en", "Sharsa (Benapol)")] },
+    Area { prefix: 880431, descriptions: &[("en", "Barisal")] },
+    Area { prefix: 880433, descriptions: &[("en", "Banaripara")] },
+    Area { prefix: 880441, descriptions: &[("en", "Patuakhali")] },
+    Area { prefix: 880451, descriptions: &[("en", "Jhinaidah/Horinakunda")] },
+    Area { prefix: 880461, descriptions: &[("en", "Pirojpur")] },
+    Area { prefix: 880466, descriptions: &[("en", "Mongla")] },
+    Area { prefix: 880468, descriptions: &[("en", "Bagerhat/Mongla Port")] },
+    Area { prefix: 880481, descriptions: &[("en", "Narail")] },
+    Area { prefix: 880482, descriptions: &[("en", "Lohagara")] },
+    Area { prefix: 880485, descriptions: &[("en", "Sreepur")] },
+    Area { prefix: 880488, descriptions: &[("en", "Magura/Mohammadpur")] },
+    Area { prefix: 880491, descriptions: &[("en", "Bhola")] },
+    Area { prefix: 880495, descriptions: &[("en", "Nalcity")] },
+    Area { prefix: 880498, descriptions: &[("en", "Jhalakati")] },
+    Area { prefix: 880521, descriptions: &[("en", "Rangpur")] },
+    Area { prefix: 880531, descriptions: &[("en", "Dianjpur/Parbitipur/Hakimpur (Hili)")] },
+    Area { prefix: 880541, descriptions: &[("en", "Gaibandha/Gabindaganj")] },
+    Area { prefix: 880551, descriptions: &[("en", "Nilphamari/Domar")] },
+    Area { prefix: 880552, descriptions: &[("en", "Saidpur/Syedpur")] },
+    Area { prefix: 880561, descriptions: &[("en", "Thakurgoan")] },
+    Area { prefix: 880565, descriptions: &[("en", "Boda")] },
+    Area { prefix: 880568, descriptions: &[("en", "Panchagar/Tetulia")] },
+    Area { prefix: 880571, descriptions: &[("en", "Jhinaidah/Panchbibi")] },
+    Area { prefix: 880572, descriptions: &[("en", "Panchbibi")] },
+    Area { prefix: 880581, descriptions: &[("en", "Kurigram")] },
+    Area { prefix: 880582, descriptions: &[("en", "Nageswari")] },
+    Area { prefix: 880601, descriptions: &[("en", "Shariatpur Naria")] },
+    Area { prefix: 880628, descriptions: &[("en", "Narsingdi/Palash (Ghorasal)/Shibpur")] },
+    Area { prefix: 880631, descriptions: &[("en", "Faridpur")] },
+    Area { prefix: 880641, descriptions: &[("en", "Rajbari")] },
+    Area { prefix: 880651, descriptions: &[("en", "Maninganj/Singair/Daulatpur/Shibalaya")] },
+    Area { prefix: 880661, descriptions: &[("en", "Madaripur")] },
+    Area { prefix: 880668, descriptions: &[("en", "Gopalgonj")] },
+    Area { prefix: 880691, descriptions: &[("en", "Munsigonj/Tongibari")] },
+    Area { prefix: 880721, descriptions: &[("en", "Rajshahi")] },
+    Area { prefix: 880731, descriptions: &[("en", "Pabna  Bera")] },
+    Area { prefix: 880732, descriptions: &[("en", "Bera/Chatmohar/Faridpur/Ishwardi/Shathiya/Sathia/Bhangura/Sujanagar")] },
+    Area { prefix: 880741, descriptions: &[("en", "Nagoan/Santahar")] },
+    Area { prefix: 880751, descriptions: &[("en", "Sirajganj")] },
+    Area { prefix: 880761, descriptions: &[("en", "Chuadanga")] },
+    Area { prefix: 880762, descriptions: &[("en", "Alamdanga")] },
+    Area { prefix: 880771, descriptions: &[("en", "Natore")] },
+    Area { prefix: 880781, descriptions: &[("en", "Rahanpur/Shibganj/Chapai Nobabganj")] },
+    Area { prefix: 880802, descriptions: &[("en", "Chauddagram/Chandina/Chandiana/Daudkandi/Debidwar/Homna/Muradnagar/Brahmanpara/Barura/Burichang")] },
+    Area { prefix: 880823, descriptions: &[("en", "Sylhet")] },
+    Area { prefix: 880831, descriptions: &[("en", "Habiganj")] },
+    Area { prefix: 880832, descriptions: &[("en", "Chunarughat/Madabpur/Nabiganj")] },
+    Area { prefix: 880833, descriptions: &[("en", "Habiganj")] },
+    Area { prefix: 880841, descriptions: &[("en", "Chandpur")] },
+    Area { prefix: 880842, descriptions: &[("en", "Hajiganj/Kochua/Shahrasti/Matlab")] },
+    Area { prefix: 880851, descriptions: &[("en", "Brahmanbaria/Nabinagar")] },
+    Area { prefix: 880852, descriptions: &[("en", "Akhaura/Bancharampur/Kashba/Sarail/Quashba/Nabinagar/Ashuganj")] },
+    Area { prefix: 880861, descriptions: &[("en", "Maulavibazar/Rajnagar")] },
+    Area { prefix: 880862, descriptions: &[("en", "Baralekha/Komalgonj/Kulaura/Rajnagar/Sreemongal")] },
+    Area { prefix: 880871, descriptions: &[("en", "Sunamganj")] },
+    Area { prefix: 880872, descriptions: &[("en", "Chatak/Dharmapasha/Jaganathpur/Jagonnathpur")] },
+    Area { prefix: 880902, descriptions: &[("en", "Phulpur/Bhaluka/Gouripur/Gafargaon/Goforgaon/Iswarganj/Ishwargonj/Muktagacha")] },
+    Area { prefix: 880903, descriptions: &[("en", "Mymensingh")] },
+    Area { prefix: 880921, descriptions: &[("en", "Tangail")] },
+    Area { prefix: 880922, descriptions: &[("en", "Bashail/Bhuapur/Ghatail/Gopalpur/Kalihati/Elenga/Kalihati/Modhupur/Mirzapur")] },
+    Area { prefix: 880941, descriptions: &[("en", "Kishoreganj/Tarail")] },
+    Area { prefix: 880942, descriptions: &[("en", "Bajitpur/Bhairabbazar/Itna/Kotiadhi")] },
+    Area { prefix: 886402, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 886403, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 886404, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 886408, descriptions: &[("en", "Taichung/Changhua"), ("zh", "台中、彰化")] },
+    Area { prefix: 886823, descriptions: &[("en", "Kinmen"), ("zh", "金门"), ("zh-Hant", "金門")] },
+    Area { prefix: 886824, descriptions: &[("en", "Kinmen"), ("zh", "金门")] },
+    Area { prefix: 886825, descriptions: &[("en", "Kinmen"), ("zh", "金门")] },
+    Area { prefix: 886826, descriptions: &[("en", "Wuqiu"), ("zh", "乌丘"), ("zh-Hant", "烏坵")] },
+    Area { prefix: 886827, descriptions: &[("en", "Kinmen"), ("zh", "金门")] },
+    Area { prefix: 886828, descriptions: &[("en", "Kinmen"), ("zh", "金门")] },
+    Area { prefix: 960300, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960301, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960302, descriptions: &[("en", "Malé Region")] },
+    Area { prefix: 960303, descriptions: &[("en", "Malé Region")] },
+    Area { prefix: 960304, descriptions: &[("en", "Malé Region")] },
+    Area { prefix: 960330, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960331, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960332, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960333, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960334, descriptions: &[("en", "Malé/Hulhulé/Aarah")] },
+    Area { prefix: 960335, descriptions: &[("en", "Hulhumalé")] },
+    Area { prefix: 960339, descriptions: &[("en", "Vilimalé")] },
+    Area { prefix: 960650, descriptions: &[("en", "Haa Alifu")] },
+    Area { prefix: 960652, descriptions: &[("en", "Haa Dhaalu")] },
+    Area { prefix: 960654, descriptions: &[("en", "Shaviyani")] },
+    Area { prefix: 960656, descriptions: &[("en", "Noonu")] },
+    Area { prefix: 960658, descriptions: &[("en", "Raa")] },
+    Area { prefix: 960659, descriptions: &[("en", "Raa")] },
+    Area { prefix: 960660, descriptions: &[("en", "Baa")] },
+    Area { prefix: 960662, descriptions: &[("en", "Lhaviyani")] },
+    Area { prefix: 960664, descriptions: &[("en", "Kaafu")] },
+    Area { prefix: 960665, descriptions: &[("en", "Kaafu")] },
+    Area { prefix: 960666, descriptions: &[("en", "Alifu Alifu")] },
+    Area { prefix: 960668, descriptions: &[("en", "Alifu Dhaalu")] },
+    Area { prefix: 960670, descriptions: &[("en", "Vaavu")] },
+    Area { prefix: 960672, descriptions: &[("en", "Meemu")] },
+    Area { prefix: 960674, descriptions: &[("en", "Faafu")] },
+    Area { prefix: 960676, descriptions: &[("en", "Dhaalu")] },
+    Area { prefix: 960678, descriptions: &[("en", "Thaa")] },
+    Area { prefix: 960680, descriptions: &[("en", "Laamu")] },
+    Area { prefix: 960682, descriptions: &[("en", "Gaafu Alifu")] },
+    Area { prefix: 960684, descriptions: &[("en", "Gaafu Dhaalu")] },
+    Area { prefix: 960686, descriptions: &[("en", "Gnaviyani")] },
+    Area { prefix: 960688, descriptions: &[("en", "Addu")] },
+    Area { prefix: 960689, descriptions: &[("en", "Addu")] },
+    Area { prefix: 962262, descriptions: &[("en", "Mafraq")] },
+    Area { prefix: 962263, descriptions: &[("en", "Jarash")] },
+    Area { prefix: 962264, descriptions: &[("en", "Ajloun")] },
+    Area { prefix: 962265, descriptions: &[("en", "Irbid")] },
+    Area { prefix: 962266, descriptions: &[("en", "Mafraq")] },
+    Area { prefix: 962267, descriptions: &[("en", "Jarash")] },
+    Area { prefix: 962268, descriptions: &[("en", "Ajloun")] },
+    Area { prefix: 962269, descriptions: &[("en", "Irbid")] },
+    Area { prefix: 962320, descriptions: &[("en", "Aqaba")] },
+    Area { prefix: 962321, descriptions: &[("en", "Ma’an")] },
+    Area { prefix: 962322, descriptions: &[("en", "Tafileh")] },
+    Area { prefix: 962323, descriptions: &[("en", "Karak")] },
+    Area { prefix: 962324, descriptions: &[("en", "Aqaba")] },
+    Area { prefix: 962325, descriptions: &[("en", "Maan")] },
+    Area { prefix: 962327, descriptions: &[("en", "Karak")] },
+    Area { prefix: 962530, descriptions: &[("en", "Zarqa")] },
+    Area { prefix: 962531, descriptions: &[("en", "Madaba")] },
+    Area { prefix: 962532, descriptions: &[("en", "Madaba")] },
+    Area { prefix: 962533, descriptions: &[("en", "Balqa")] },
+    Area { prefix: 962534, descriptions: &[("en", "Balqa")] },
+    Area { prefix: 962535, descriptions: &[("en", "Balqa")] },
+    Area { prefix: 962536, descriptions: &[("en", "Zarqa")] },
+    Area { prefix: 962537, descriptions: &[("en", "Zarqa")] },
+    Area { prefix: 962538, descriptions: &[("en", "Zarqa")] },
+    Area { prefix: 962539, descriptions: &[("en", "Zarqa")] },
+    Area { prefix: 962620, descriptions: &[("en", "Amman")] },
+    Area { prefix: 962622, descriptions: &[("en", "Greater Amman")] },
+    Area { prefix: 962625, descriptions: &[("en", "Amman")] },
+    Area { prefix: 962647, descriptions: &[("en", "Greater Amman")] },
+    Area { prefix: 967280, descriptions: &[("en", "Aden")] },
+    Area { prefix: 967281, descriptions: &[("en", "Aden")] },
+    Area { prefix: 967282, descriptions: &[("en", "Aden")] },
+    Area { prefix: 967283, descriptions: &[("en", "Aden")] },
+    Area { prefix: 967284, descriptions: &[("en", "Dhalea")] },
+    Area { prefix: 967285, descriptions: &[("en", "Lahj")] },
+    Area { prefix: 967286, descriptions: &[("en", "Abyan")] },
+    Area { prefix: 967433, descriptions: &[("en", "Ibb")] },
+    Area { prefix: 967483, descriptions: &[("en", "Taiz")] },
+    Area { prefix: 967484, descriptions: &[("en", "Ibb")] },
+    Area { prefix: 967485, descriptions: &[("en", "Ibb")] },
+    Area { prefix: 967566, descriptions: &[("en", "Soqatrah")] },
+    Area { prefix: 967630, descriptions: &[("en", "Maareb")] },
+    Area { prefix: 967633, descriptions: &[("en", "Maareb")] },
+    Area { prefix: 967634, descriptions: &[("en", "Aljawf")] },
+    Area { prefix: 967636, descriptions: &[("en", "Maareb")] },
+    Area { prefix: 967638, descriptions: &[("en", "Maareb")] },
+    Area { prefix: 967639, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 967650, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 967651, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 967652, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967653, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967654, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967655, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967656, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967657, descriptions: &[("en", "Al Baidha")] },
+    Area { prefix: 967682, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 967683, descriptions: &[("en", "Maareb")] },
+    Area { prefix: 967684, descriptions: &[("en", "Dhamar")] },
+    Area { prefix: 967785, descriptions: &[("en", "Saadah")] },
+    Area { prefix: 967786, descriptions: &[("en", "Amran")] },
+    Area { prefix: 970222, descriptions: &[("en", "Jericho/Hebron")] },
+    Area { prefix: 970223, descriptions: &[("en", "Jerusalem")] },
+    Area { prefix: 970227, descriptions: &[("en", "Bethlehem")] },
+    Area { prefix: 970229, descriptions: &[("en", "Ramallah/Al-Bireh")] },
+    Area { prefix: 970424, descriptions: &[("en", "Jenin")] },
+    Area { prefix: 970820, descriptions: &[("en", "Khan Yunis")] },
+    Area { prefix: 970821, descriptions: &[("en", "Rafah")] },
+    Area { prefix: 970824, descriptions: &[("en", "North Gaza")] },
+    Area { prefix: 970825, descriptions: &[("en", "Deir al-Balah")] },
+    Area { prefix: 970826, descriptions: &[("en", "Gaza")] },
+    Area { prefix: 970828, descriptions: &[("en", "Gaza")] },
+    Area { prefix: 970923, descriptions: &[("en", "Nablus")] },
+    Area { prefix: 970925, descriptions: &[("en", "Tubas")] },
+    Area { prefix: 970926, descriptions: &[("en", "Tulkarm")] },
+    Area { prefix: 970929, descriptions: &[("en", "Qalqilya/Salfit")] },
+    Area { prefix: 976121, descriptions: &[("en", "Baganuur")] },
+    Area { prefix: 976122, descriptions: &[("en", "Bagakhangai")] },
+    Area { prefix: 976123, descriptions: &[("en", "Nalaikh")] },
+    Area { prefix: 976221, descriptions: &[("en", "Baganuur")] },
+    Area { prefix: 976222, descriptions: &[("en", "Bagakhangai")] },
+    Area { prefix: 976223, descriptions: &[("en", "Nalaikh")] },
+    Area { prefix: 994214, descriptions: &[("en", "Hajigabul")] },
+    Area { prefix: 994224, descriptions: &[("en", "Agstafa/Ganja/Yevlakh")] },
+    Area { prefix: 995341, descriptions: &[("en", "Rustavi")] },
+    Area { prefix: 995342, descriptions: &[("en", "Akhalgori")] },
+    Area { prefix: 995344, descriptions: &[("en", "Tskhinvali")] },
+    Area { prefix: 995345, descriptions: &[("en", "Stefanstminda/Kazbegi")] },
+    Area { prefix: 995346, descriptions: &[("en", "Dusheti")] },
+    Area { prefix: 995347, descriptions: &[("en", "Djava")] },
+    Area { prefix: 995348, descriptions: &[("en", "Tianeti")] },
+    Area { prefix: 995349, descriptions: &[("en", "Akhmeta")] },
+    Area { prefix: 995350, descriptions: &[("en", "Telavi")] },
+    Area { prefix: 995351, descriptions: &[("en", "Sagaredjo")] },
+    Area { prefix: 995352, descriptions: &[("en", "Kvareli")] },
+    Area { prefix: 995353, descriptions: &[("en", "Gurdjaani")] },
+    Area { prefix: 995354, descriptions: &[("en", "Lagodekhi")] },
+    Area { prefix: 995355, descriptions: &[("en", "Signagi")] },
+    Area { prefix: 995356, descriptions: &[("en", "DedoplisTskaro")] },
+    Area { prefix: 995357, descriptions: &[("en", "Marneuli")] },
+    Area { prefix: 995358, descriptions: &[("en", "Bolnisi")] },
+    Area { prefix: 995359, descriptions: &[("en", "TetriTskaro")] },
+    Area { prefix: 995360, descriptions: &[("en", "Dmanisi")] },
+    Area { prefix: 995361, descriptions: &[("en", "Ninotsminda")] },
+    Area { prefix: 995362, descriptions: &[("en", "Akhalkalaki")] },
+    Area { prefix: 995363, descriptions: &[("en", "Tsalka")] },
+    Area { prefix: 995364, descriptions: &[("en", "Aspindza")] },
+    Area { prefix: 995365, descriptions: &[("en", "Akhaltsikhe")] },
+    Area { prefix: 995366, descriptions: &[("en", "Adigeni")] },
+    Area { prefix: 995367, descriptions: &[("en", "Bordjomi")] },
+    Area { prefix: 995368, descriptions: &[("en", "Khashuri")] },
+    Area { prefix: 995369, descriptions: &[("en", "Kareli")] },
+    Area { prefix: 995370, descriptions: &[("en", "Gori")] },
+    Area { prefix: 995371, descriptions: &[("en", "Kaspi")] },
+    Area { prefix: 995372, descriptions: &[("en", "Gardabani")] },
+    Area { prefix: 995373, descriptions: &[("en", "Mtskheta")] },
+    Area { prefix: 995374, descriptions: &[("en", "Tigvi")] },
+    Area { prefix: 995410, descriptions: &[("en", "Mestia")] },
+    Area { prefix: 995411, descriptions: &[("en", "Samtredia")] },
+    Area { prefix: 995412, descriptions: &[("en", "Abasha")] },
+    Area { prefix: 995413, descriptions: &[("en", "Senaki")] },
+    Area { prefix: 995414, descriptions: &[("en", "Xobi")] },
+    Area { prefix: 995415, descriptions: &[("en", "Zugdidi")] },
+    Area { prefix: 995416, descriptions: &[("en", "Tsalendjikha")] },
+    Area { prefix: 995417, descriptions: &[("en", "Chkhorotskhu")] },
+    Area { prefix: 995418, descriptions: &[("en", "Martvili")] },
+    Area { prefix: 995419, descriptions: &[("en", "Choxatauri")] },
+    Area { prefix: 995422, descriptions: &[("en", "Batumi")] },
+    Area { prefix: 995423, descriptions: &[("en", "Xulo")] },
+    Area { prefix: 995424, descriptions: &[("en", "Shuaxevi")] },
+    Area { prefix: 995425, descriptions: &[("en", "Qeda")] },
+    Area { prefix: 995426, descriptions: &[("en", "Kobuleti")] },
+    Area { prefix: 995427, descriptions: &[("en", "Xelvachauri")] },
+    Area { prefix: 995431, descriptions: &[("en", "Kutaisi")] },
+    Area { prefix: 995432, descriptions: &[("en", "Vani")] },
+    Area { prefix: 995433, descriptions: &[("en", "Kharagauli")] },
+    Area { prefix: 995434, descriptions: &[("en", "Bagdati")] },
+    Area { prefix: 995435, descriptions: &[("en", "Sachkhere")] },
+    Area { prefix: 995436, descriptions: &[("en", "Tskaltubo")] },
+    Area { prefix: 995437, descriptions: &[("en", "Lentekhi")] },
+    Area { prefix: 995439, descriptions: &[("en", "Ambrolauri")] },
+    Area { prefix: 995442, descriptions: &[("en", "Sukhumi")] },
+    Area { prefix: 995443, descriptions: &[("en", "Gagra")] },
+    Area { prefix: 995444, descriptions: &[("en", "Gudauta")] },
+    Area { prefix: 995445, descriptions: &[("en", "Ochamchire")] },
+    Area { prefix: 995446, descriptions: &[("en", "Tkvarcheli")] },
+    Area { prefix: 995447, descriptions: &[("en", "Gali")] },
+    Area { prefix: 995448, descriptions: &[("en", "Gulripshi")] },
+    Area { prefix: 995472, descriptions: &[("en", "Tsageri")] },
+    Area { prefix: 995473, descriptions: &[("en", "Oni")] },
+    Area { prefix: 995479, descriptions: &[("en", "Chiatura")] },
+    Area { prefix: 995491, descriptions: &[("en", "Terdjola")] },
+    Area { prefix: 995492, descriptions: &[("en", "Zestafoni")] },
+    Area { prefix: 995493, descriptions: &[("en", "Poti")] },
+    Area { prefix: 995494, descriptions: &[("en", "lanchxuti")] },
+    Area { prefix: 995495, descriptions: &[("en", "Khoni")] },
+    Area { prefix: 995496, descriptions: &[("en", "Ozurgeti")] },
+    Area { prefix: 995497, descriptions: &[("en", "Tkibuli")] },
+    Area { prefix: 996322, descriptions: &[("en", "Osh")] },
+    Area { prefix: 996342, descriptions: &[("en", "Talas")] },
+    Area { prefix: 996352, descriptions: &[("en", "Naryn")] },
+    Area { prefix: 996362, descriptions: &[("en", "Batken, Naryn region")] },
+    Area { prefix: 996372, descriptions: &[("en", "Jalal-Abat")] },
+    Area { prefix: 996392, descriptions: &[("en", "Karakol, Issyk-Ko region")] },
+];
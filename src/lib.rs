@@ -1,263 +1,47 @@
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::num::{NonZeroU32, NonZeroU64};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 
-///
-/// All the ISO 3166 territorial codes. Most of these are country codes,
-/// but some territories still exist in the world today.
-///
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-#[non_exhaustive] // New territories may want independence
-pub enum TerritoryCode {
-    US,
-    CA,
-    AC,
-    AD,
-    AE,
-    AF,
-    AG,
-    AI,
-    AL,
-    AM,
-    AO,
-    AQ,
-    AR,
-    AS,
-    AT,
-    AU,
-    AW,
-    AX,
-    AZ,
-    BA,
-    BB,
-    BD,
-    BE,
-    BF,
-    BG,
-    BH,
-    BI,
-    BJ,
-    BM,
-    BN,
-    BO,
-    BQ,
-    BR,
-    BS,
-    BT,
-    BW,
-    BY,
-    BZ,
-    CC,
-    CD,
-    CF,
-    CG,
-    CH,
-    CI,
-    CK,
-    CL,
-    CM,
-    CN,
-    CO,
-    CR,
-    CU,
-    CV,
-    CW,
-    CX,
-    CY,
-    CZ,
-    DE,
-    DJ,
-    DK,
-    DM,
-    DO,
-    DZ,
-    EC,
-    EE,
-    EG,
-    ER,
-    ES,
-    ET,
-    FI,
-    FJ,
-    FK,
-    FM,
-    FO,
-    FR,
-    GA,
-    GB,
-    GD,
-    GE,
-    GF,
-    GG,
-    GH,
-    GI,
-    GL,
-    GM,
-    GN,
-    GP,
-    GQ,
-    GR,
-    GT,
-    GU,
-    GW,
-    GY,
-    HK,
-    HN,
-    HR,
-    HT,
-    HU,
-    ID,
-    IE,
-    IL,
-    IM,
-    IN,
-    IO,
-    IQ,
-    IR,
-    IS,
-    IT,
-    JE,
-    JM,
-    JO,
-    JP,
-    KE,
-    KG,
-    KH,
-    KI,
-    KM,
-    KN,
-    KP,
-    KR,
-    KW,
-    KY,
-    KZ,
-    LA,
-    LB,
-    LC,
-    LI,
-    LK,
-    LR,
-    LS,
-    LT,
-    LU,
-    LV,
-    LY,
-    MA,
-    MC,
-    MD,
-    ME,
-    MG,
-    MH,
-    MK,
-    ML,
-    MM,
-    MN,
-    MO,
-    MP,
-    MQ,
-    MR,
-    MS,
-    MT,
-    MU,
-    MV,
-    MW,
-    MX,
-    MY,
-    MZ,
-    NA,
-    NC,
-    NE,
-    NF,
-    NG,
-    NI,
-    NL,
-    NO,
-    NP,
-    NR,
-    NU,
-    NZ,
-    OM,
-    PA,
-    PE,
-    PF,
-    PG,
-    PH,
-    PK,
-    PL,
-    PM,
-    PR,
-    PS,
-    PT,
-    PW,
-    PY,
-    QA,
-    RE,
-    RO,
-    RS,
-    RU,
-    RW,
-    SA,
-    SB,
-    SC,
-    SD,
-    SE,
-    SG,
-    SH,
-    SI,
-    SJ,
-    SK,
-    SL,
-    SM,
-    SN,
-    SO,
-    SR,
-    SS,
-    ST,
-    SV,
-    SX,
-    SY,
-    SZ,
-    TC,
-    TD,
-    TG,
-    TH,
-    TJ,
-    TK,
-    TL,
-    TM,
-    TN,
-    TO,
-    TR,
-    TT,
-    TV,
-    TW,
-    TZ,
-    UA,
-    UG,
-    UY,
-    UZ,
-    VA,
-    VC,
-    VE,
-    VG,
-    VI,
-    VN,
-    VU,
-    WF,
-    WS,
-    XG,
-    XN,
-    XP,
-    XS,
-    XT,
-    XV,
-    YE,
-    YT,
-    ZA,
-    ZM,
-    ZW,
-}
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+
+#[cfg(feature = "carrier")]
+mod carrier;
+#[cfg(feature = "cldr-names")]
+mod cldr_names;
+mod format;
+#[cfg(feature = "geocoding")]
+mod geocoding;
+#[cfg(any(feature = "cldr-names", feature = "geocoding"))]
+mod locale;
+mod metadata;
+mod names;
+mod nanp;
+mod number_type;
+mod pattern;
+mod phone_number;
+mod prefix;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod subdivision;
+mod territories;
+mod time_zone;
+
+#[cfg(feature = "cldr-names")]
+pub use cldr_names::LocalizedName;
+pub use format::FormatStyle;
+pub use nanp::{AreaCodeStatus, NanpAreaCode};
+pub use number_type::NumberType;
+pub use phone_number::{LengthValidation, PhoneNumber};
+pub use subdivision::{FromCodeError, SubdivisionCode};
+pub use territories::TerritoryCode;
+
+/// The maximum number of digits of an international phone number, according to E.164
+const MAX_DIGITS: u32 = 15;
 
 impl Display for TerritoryCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -265,271 +49,128 @@ impl Display for TerritoryCode {
     }
 }
 
+impl FromStr for TerritoryCode {
+    type Err = FromNameError;
+
+    /// Parses a territory code by name, like [`TerritoryCode::from_name`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(FromNameError)
+    }
+}
+
+impl TryFrom<&str> for TerritoryCode {
+    type Error = FromNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl AsRef<str> for TerritoryCode {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl From<TerritoryCode> for &'static str {
+    fn from(value: TerritoryCode) -> Self {
+        value.name()
+    }
+}
+
 impl TerritoryCode {
+    /// The number of territory codes
+    pub const COUNT: usize = territories::ALL.len();
+
+    /// All the territory codes, as a slice:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(TerritoryCode::COUNT, TerritoryCode::all().len());
+    /// assert!(TerritoryCode::all().contains(&TerritoryCode::FR));
+    /// ```
+    pub const fn all() -> &'static [Self] {
+        &territories::ALL
+    }
+
+    /// Iterates over all the territory codes. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// let single_calling_code = TerritoryCode::iter()
+    ///     .filter(|territory| !territory.calling_codes().has_multiple())
+    ///     .count();
+    /// assert_eq!(TerritoryCode::COUNT - 2, single_calling_code);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Self> {
+        territories::ALL.iter().copied()
+    }
+
     /// Yields the associated calling codes. Most countries have only one code, in which case
     /// [`CallingCodes::primary`] will suffice.
     pub fn calling_codes(&self) -> CallingCodes {
-        macro_rules! single_calling_code {
-            ($value:literal) => {
-                CallingCodes(CallingCodesInner::Single(
-                    [NonZeroU32::new($value).unwrap()],
-                ))
-            };
-        }
-        macro_rules! cc {
-            ($value:literal) => {
-                NonZeroU32::new($value).unwrap()
-            };
-        }
-        match *self {
-            Self::US => single_calling_code!(1),
-            Self::CA => single_calling_code!(1),
-            Self::AC => single_calling_code!(247),
-            Self::AD => single_calling_code!(376),
-            Self::AE => single_calling_code!(971),
-            Self::AF => single_calling_code!(93),
-            Self::AG => single_calling_code!(1),
-            Self::AI => single_calling_code!(1),
-            Self::AL => single_calling_code!(355),
-            Self::AM => single_calling_code!(374),
-            Self::AO => single_calling_code!(244),
-            Self::AQ => single_calling_code!(672),
-            Self::AR => single_calling_code!(54),
-            Self::AS => single_calling_code!(1),
-            Self::AT => single_calling_code!(43),
-            Self::AU => single_calling_code!(61),
-            Self::AW => single_calling_code!(297),
-            Self::AX => single_calling_code!(35818),
-            Self::AZ => single_calling_code!(994),
-            Self::BA => single_calling_code!(387),
-            Self::BB => single_calling_code!(1),
-            Self::BD => single_calling_code!(880),
-            Self::BE => single_calling_code!(32),
-            Self::BF => single_calling_code!(226),
-            Self::BG => single_calling_code!(359),
-            Self::BH => single_calling_code!(973),
-            Self::BI => single_calling_code!(257),
-            Self::BJ => single_calling_code!(229),
-            Self::BM => single_calling_code!(1),
-            Self::BN => single_calling_code!(673),
-            Self::BO => single_calling_code!(591),
-            Self::BQ => CallingCodes(CallingCodesInner::Three([cc!(5993), cc!(5994), cc!(5997)])),
-            Self::BR => single_calling_code!(55),
-            Self::BS => single_calling_code!(1),
-            Self::BT => single_calling_code!(975),
-            Self::BW => single_calling_code!(267),
-            Self::BY => single_calling_code!(375),
-            Self::BZ => single_calling_code!(501),
-            Self::CC => single_calling_code!(6189162),
-            Self::CD => single_calling_code!(243),
-            Self::CF => single_calling_code!(236),
-            Self::CG => single_calling_code!(242),
-            Self::CH => single_calling_code!(41),
-            Self::CI => single_calling_code!(225),
-            Self::CK => single_calling_code!(682),
-            Self::CL => single_calling_code!(56),
-            Self::CM => single_calling_code!(237),
-            Self::CN => single_calling_code!(86),
-            Self::CO => single_calling_code!(57),
-            Self::CR => single_calling_code!(506),
-            Self::CU => single_calling_code!(53),
-            Self::CV => single_calling_code!(238),
-            Self::CW => single_calling_code!(5999),
-            Self::CX => single_calling_code!(6189164),
-            Self::CY => single_calling_code!(357),
-            Self::CZ => single_calling_code!(420),
-            Self::DE => single_calling_code!(49),
-            Self::DJ => single_calling_code!(253),
-            Self::DK => single_calling_code!(45),
-            Self::DM => single_calling_code!(1),
-            Self::DO => single_calling_code!(1),
-            Self::DZ => single_calling_code!(213),
-            Self::EC => single_calling_code!(593),
-            Self::EE => single_calling_code!(372),
-            Self::EG => single_calling_code!(20),
-            Self::ER => single_calling_code!(291),
-            Self::ES => single_calling_code!(34),
-            Self::ET => single_calling_code!(251),
-            Self::FI => single_calling_code!(358),
-            Self::FJ => single_calling_code!(679),
-            Self::FK => single_calling_code!(500),
-            Self::FM => single_calling_code!(691),
-            Self::FO => single_calling_code!(298),
-            Self::FR => single_calling_code!(33),
-            Self::GA => single_calling_code!(241),
-            Self::GB => single_calling_code!(44),
-            Self::GD => single_calling_code!(1),
-            Self::GE => single_calling_code!(995),
-            Self::GF => single_calling_code!(594),
-            Self::GG => single_calling_code!(441481),
-            Self::GH => single_calling_code!(233),
-            Self::GI => single_calling_code!(350),
-            Self::GL => single_calling_code!(299),
-            Self::GM => single_calling_code!(220),
-            Self::GN => single_calling_code!(224),
-            Self::GP => single_calling_code!(590),
-            Self::GQ => single_calling_code!(240),
-            Self::GR => single_calling_code!(30),
-            Self::GT => single_calling_code!(502),
-            Self::GU => single_calling_code!(1),
-            Self::GW => single_calling_code!(245),
-            Self::GY => single_calling_code!(592),
-            Self::HK => single_calling_code!(852),
-            Self::HN => single_calling_code!(504),
-            Self::HR => single_calling_code!(385),
-            Self::HT => single_calling_code!(509),
-            Self::HU => single_calling_code!(36),
-            Self::ID => single_calling_code!(62),
-            Self::IE => single_calling_code!(353),
-            Self::IL => single_calling_code!(972),
-            Self::IM => single_calling_code!(441624),
-            Self::IN => single_calling_code!(91),
-            Self::IO => single_calling_code!(246),
-            Self::IQ => single_calling_code!(964),
-            Self::IR => single_calling_code!(98),
-            Self::IS => single_calling_code!(354),
-            Self::IT => single_calling_code!(39),
-            Self::JE => single_calling_code!(441534),
-            Self::JM => single_calling_code!(1),
-            Self::JO => single_calling_code!(962),
-            Self::JP => single_calling_code!(81),
-            Self::KE => single_calling_code!(254),
-            Self::KG => single_calling_code!(996),
-            Self::KH => single_calling_code!(855),
-            Self::KI => single_calling_code!(686),
-            Self::KM => single_calling_code!(269),
-            Self::KN => single_calling_code!(1),
-            Self::KP => single_calling_code!(850),
-            Self::KR => single_calling_code!(82),
-            Self::KW => single_calling_code!(965),
-            Self::KY => single_calling_code!(1),
-            Self::KZ => single_calling_code!(7),
-            Self::LA => single_calling_code!(856),
-            Self::LB => single_calling_code!(961),
-            Self::LC => single_calling_code!(1),
-            Self::LI => single_calling_code!(423),
-            Self::LK => single_calling_code!(94),
-            Self::LR => single_calling_code!(231),
-            Self::LS => single_calling_code!(266),
-            Self::LT => single_calling_code!(370),
-            Self::LU => single_calling_code!(352),
-            Self::LV => single_calling_code!(371),
-            Self::LY => single_calling_code!(218),
-            Self::MA => single_calling_code!(212),
-            Self::MC => single_calling_code!(377),
-            Self::MD => single_calling_code!(373),
-            Self::ME => single_calling_code!(382),
-            Self::MG => single_calling_code!(261),
-            Self::MH => single_calling_code!(692),
-            Self::MK => single_calling_code!(389),
-            Self::ML => single_calling_code!(223),
-            Self::MM => single_calling_code!(95),
-            Self::MN => single_calling_code!(976),
-            Self::MO => single_calling_code!(853),
-            Self::MP => single_calling_code!(1),
-            Self::MQ => single_calling_code!(596),
-            Self::MR => single_calling_code!(222),
-            Self::MS => single_calling_code!(1),
-            Self::MT => single_calling_code!(356),
-            Self::MU => single_calling_code!(230),
-            Self::MV => single_calling_code!(960),
-            Self::MW => single_calling_code!(265),
-            Self::MX => single_calling_code!(52),
-            Self::MY => single_calling_code!(60),
-            Self::MZ => single_calling_code!(258),
-            Self::NA => single_calling_code!(264),
-            Self::NC => single_calling_code!(687),
-            Self::NE => single_calling_code!(227),
-            Self::NF => single_calling_code!(6723),
-            Self::NG => single_calling_code!(234),
-            Self::NI => single_calling_code!(505),
-            Self::NL => single_calling_code!(31),
-            Self::NO => single_calling_code!(47),
-            Self::NP => single_calling_code!(977),
-            Self::NR => single_calling_code!(674),
-            Self::NU => single_calling_code!(683),
-            Self::NZ => single_calling_code!(64),
-            Self::OM => single_calling_code!(968),
-            Self::PA => single_calling_code!(507),
-            Self::PE => single_calling_code!(51),
-            Self::PF => single_calling_code!(689),
-            Self::PG => single_calling_code!(675),
-            Self::PH => single_calling_code!(63),
-            Self::PK => single_calling_code!(92),
-            Self::PL => single_calling_code!(48),
-            Self::PM => single_calling_code!(508),
-            Self::PR => single_calling_code!(1),
-            Self::PS => single_calling_code!(970),
-            Self::PT => single_calling_code!(351),
-            Self::PW => single_calling_code!(680),
-            Self::PY => single_calling_code!(595),
-            Self::QA => single_calling_code!(974),
-            Self::RE => single_calling_code!(262),
-            Self::RO => single_calling_code!(40),
-            Self::RS => single_calling_code!(381),
-            Self::RU => single_calling_code!(7),
-            Self::RW => single_calling_code!(250),
-            Self::SA => single_calling_code!(966),
-            Self::SB => single_calling_code!(677),
-            Self::SC => single_calling_code!(248),
-            Self::SD => single_calling_code!(249),
-            Self::SE => single_calling_code!(46),
-            Self::SG => single_calling_code!(65),
-            Self::SH => single_calling_code!(290),
-            Self::SI => single_calling_code!(386),
-            Self::SJ => single_calling_code!(4779),
-            Self::SK => single_calling_code!(421),
-            Self::SL => single_calling_code!(232),
-            Self::SM => single_calling_code!(378),
-            Self::SN => single_calling_code!(221),
-            Self::SO => single_calling_code!(252),
-            Self::SR => single_calling_code!(597),
-            Self::SS => single_calling_code!(211),
-            Self::ST => single_calling_code!(239),
-            Self::SV => single_calling_code!(503),
-            Self::SX => single_calling_code!(1),
-            Self::SY => single_calling_code!(963),
-            Self::SZ => single_calling_code!(268),
-            Self::TC => single_calling_code!(1),
-            Self::TD => single_calling_code!(235),
-            Self::TG => single_calling_code!(228),
-            Self::TH => single_calling_code!(66),
-            Self::TJ => single_calling_code!(992),
-            Self::TK => single_calling_code!(690),
-            Self::TL => single_calling_code!(670),
-            Self::TM => single_calling_code!(993),
-            Self::TN => single_calling_code!(216),
-            Self::TO => single_calling_code!(676),
-            Self::TR => single_calling_code!(90),
-            Self::TT => single_calling_code!(1),
-            Self::TV => single_calling_code!(688),
-            Self::TW => single_calling_code!(886),
-            Self::TZ => single_calling_code!(255),
-            Self::UA => single_calling_code!(380),
-            Self::UG => single_calling_code!(256),
-            Self::UY => single_calling_code!(598),
-            Self::UZ => single_calling_code!(998),
-            Self::VA => single_calling_code!(379),
-            Self::VC => single_calling_code!(1),
-            Self::VE => single_calling_code!(58),
-            Self::VG => single_calling_code!(1),
-            Self::VI => single_calling_code!(1),
-            Self::VN => single_calling_code!(84),
-            Self::VU => single_calling_code!(678),
-            Self::WF => single_calling_code!(681),
-            Self::WS => single_calling_code!(685),
-            Self::XG => single_calling_code!(881),
-            Self::XN => single_calling_code!(870),
-            Self::XP => single_calling_code!(878),
-            Self::XS => single_calling_code!(808),
-            Self::XT => single_calling_code!(800),
-            Self::XV => CallingCodes(CallingCodesInner::Two([cc!(882), cc!(883)])),
-            Self::YE => single_calling_code!(967),
-            Self::YT => single_calling_code!(262),
-            Self::ZA => single_calling_code!(27),
-            Self::ZM => single_calling_code!(260),
-            Self::ZW => single_calling_code!(263),
-        }
+        territories::calling_codes(*self)
+    }
+
+    /// Gets the national prefix, or trunk prefix, dialed before national significant numbers
+    /// within this territory, e.g. `0` in the United Kingdom. Some territories have none.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some("0"), TerritoryCode::GB.national_prefix());
+    /// assert_eq!(Some("1"), TerritoryCode::US.national_prefix());
+    /// assert_eq!(None, TerritoryCode::IT.national_prefix());
+    /// ```
+    pub fn national_prefix(&self) -> Option<&'static str> {
+        metadata::national_prefix(*self)
+    }
+
+    /// Gets the international prefix, or IDD prefix, dialed before calling codes to call abroad
+    /// from this territory. There is none when several prefixes are in use without one being
+    /// preferred, e.g. the carrier selection codes of Brazil, and for non-geographic calling codes.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some("00"), TerritoryCode::DE.international_prefix());
+    /// assert_eq!(Some("011"), TerritoryCode::US.international_prefix());
+    /// assert_eq!(Some("810"), TerritoryCode::RU.international_prefix());
+    /// assert_eq!(Some("0011"), TerritoryCode::AU.international_prefix());
+    /// assert_eq!(None, TerritoryCode::BR.international_prefix());
+    /// ```
+    pub fn international_prefix(&self) -> Option<&'static str> {
+        metadata::international_prefix(*self)
+    }
+
+    /// The pattern of all the international prefixes accepted in this territory, when there are
+    /// more than the one of [`TerritoryCode::international_prefix`]
+    fn international_prefix_pattern(&self) -> Option<&'static str> {
+        metadata::international_prefix_pattern(*self)
+    }
+
+    /// Yields the main territory using a country calling code. I.e. [`TerritoryCode::RU`] for 7,
+    /// although [`TerritoryCode::KZ`] uses it too:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(TerritoryCode::RU), TerritoryCode::from_calling_code(7));
+    /// assert_eq!(Some(TerritoryCode::RE), TerritoryCode::from_calling_code(262));
+    /// assert_eq!(None, TerritoryCode::from_calling_code(999));
+    /// ```
+    pub fn from_calling_code(calling_code: u32) -> Option<Self> {
+        territories::main_territory(calling_code)
+            .or_else(|| Self::all_from_calling_code(calling_code).next())
+    }
+
+    /// Yields all the territories using a country calling code, according to
+    /// [`TerritoryCode::calling_codes`]. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// let territories: Vec<_> = TerritoryCode::all_from_calling_code(7).collect();
+    /// assert_eq!(vec![TerritoryCode::KZ, TerritoryCode::RU], territories);
+    /// ```
+    pub fn all_from_calling_code(calling_code: u32) -> impl Iterator<Item = Self> {
+        Self::iter().filter(move |territory| {
+            territory
+                .calling_codes()
+                .all()
+                .iter()
+                .any(|code| country_calling_code(*code) == calling_code)
+        })
     }
 
     /// Yields a territory code by name. I.e.:
@@ -538,256 +179,34 @@ impl TerritoryCode {
     /// assert_eq!(TerritoryCode::IN, TerritoryCode::from_name("IN").unwrap());
     /// ```
     pub fn from_name(name: &str) -> Option<Self> {
-        Some(match name {
-            "US" => Self::US,
-            "CA" => Self::CA,
-            "AC" => Self::AC,
-            "AD" => Self::AD,
-            "AE" => Self::AE,
-            "AF" => Self::AF,
-            "AG" => Self::AG,
-            "AI" => Self::AI,
-            "AL" => Self::AL,
-            "AM" => Self::AM,
-            "AO" => Self::AO,
-            "AQ" => Self::AQ,
-            "AR" => Self::AR,
-            "AS" => Self::AS,
-            "AT" => Self::AT,
-            "AU" => Self::AU,
-            "AW" => Self::AW,
-            "AX" => Self::AX,
-            "AZ" => Self::AZ,
-            "BA" => Self::BA,
-            "BB" => Self::BB,
-            "BD" => Self::BD,
-            "BE" => Self::BE,
-            "BF" => Self::BF,
-            "BG" => Self::BG,
-            "BH" => Self::BH,
-            "BI" => Self::BI,
-            "BJ" => Self::BJ,
-            "BM" => Self::BM,
-            "BN" => Self::BN,
-            "BO" => Self::BO,
-            "BQ" => Self::BQ,
-            "BR" => Self::BR,
-            "BS" => Self::BS,
-            "BT" => Self::BT,
-            "BW" => Self::BW,
-            "BY" => Self::BY,
-            "BZ" => Self::BZ,
-            "CC" => Self::CC,
-            "CD" => Self::CD,
-            "CF" => Self::CF,
-            "CG" => Self::CG,
-            "CH" => Self::CH,
-            "CI" => Self::CI,
-            "CK" => Self::CK,
-            "CL" => Self::CL,
-            "CM" => Self::CM,
-            "CN" => Self::CN,
-            "CO" => Self::CO,
-            "CR" => Self::CR,
-            "CU" => Self::CU,
-            "CV" => Self::CV,
-            "CW" => Self::CW,
-            "CX" => Self::CX,
-            "CY" => Self::CY,
-            "CZ" => Self::CZ,
-            "DE" => Self::DE,
-            "DJ" => Self::DJ,
-            "DK" => Self::DK,
-            "DM" => Self::DM,
-            "DO" => Self::DO,
-            "DZ" => Self::DZ,
-            "EC" => Self::EC,
-            "EE" => Self::EE,
-            "EG" => Self::EG,
-            "ER" => Self::ER,
-            "ES" => Self::ES,
-            "ET" => Self::ET,
-            "FI" => Self::FI,
-            "FJ" => Self::FJ,
-            "FK" => Self::FK,
-            "FM" => Self::FM,
-            "FO" => Self::FO,
-            "FR" => Self::FR,
-            "GA" => Self::GA,
-            "GB" => Self::GB,
-            "GD" => Self::GD,
-            "GE" => Self::GE,
-            "GF" => Self::GF,
-            "GG" => Self::GG,
-            "GH" => Self::GH,
-            "GI" => Self::GI,
-            "GL" => Self::GL,
-            "GM" => Self::GM,
-            "GN" => Self::GN,
-            "GP" => Self::GP,
-            "GQ" => Self::GQ,
-            "GR" => Self::GR,
-            "GT" => Self::GT,
-            "GU" => Self::GU,
-            "GW" => Self::GW,
-            "GY" => Self::GY,
-            "HK" => Self::HK,
-            "HN" => Self::HN,
-            "HR" => Self::HR,
-            "HT" => Self::HT,
-            "HU" => Self::HU,
-            "ID" => Self::ID,
-            "IE" => Self::IE,
-            "IL" => Self::IL,
-            "IM" => Self::IM,
-            "IN" => Self::IN,
-            "IO" => Self::IO,
-            "IQ" => Self::IQ,
-            "IR" => Self::IR,
-            "IS" => Self::IS,
-            "IT" => Self::IT,
-            "JE" => Self::JE,
-            "JM" => Self::JM,
-            "JO" => Self::JO,
-            "JP" => Self::JP,
-            "KE" => Self::KE,
-            "KG" => Self::KG,
-            "KH" => Self::KH,
-            "KI" => Self::KI,
-            "KM" => Self::KM,
-            "KN" => Self::KN,
-            "KP" => Self::KP,
-            "KR" => Self::KR,
-            "KW" => Self::KW,
-            "KY" => Self::KY,
-            "KZ" => Self::KZ,
-            "LA" => Self::LA,
-            "LB" => Self::LB,
-            "LC" => Self::LC,
-            "LI" => Self::LI,
-            "LK" => Self::LK,
-            "LR" => Self::LR,
-            "LS" => Self::LS,
-            "LT" => Self::LT,
-            "LU" => Self::LU,
-            "LV" => Self::LV,
-            "LY" => Self::LY,
-            "MA" => Self::MA,
-            "MC" => Self::MC,
-            "MD" => Self::MD,
-            "ME" => Self::ME,
-            "MG" => Self::MG,
-            "MH" => Self::MH,
-            "MK" => Self::MK,
-            "ML" => Self::ML,
-            "MM" => Self::MM,
-            "MN" => Self::MN,
-            "MO" => Self::MO,
-            "MP" => Self::MP,
-            "MQ" => Self::MQ,
-            "MR" => Self::MR,
-            "MS" => Self::MS,
-            "MT" => Self::MT,
-            "MU" => Self::MU,
-            "MV" => Self::MV,
-            "MW" => Self::MW,
-            "MX" => Self::MX,
-            "MY" => Self::MY,
-            "MZ" => Self::MZ,
-            "NA" => Self::NA,
-            "NC" => Self::NC,
-            "NE" => Self::NE,
-            "NF" => Self::NF,
-            "NG" => Self::NG,
-            "NI" => Self::NI,
-            "NL" => Self::NL,
-            "NO" => Self::NO,
-            "NP" => Self::NP,
-            "NR" => Self::NR,
-            "NU" => Self::NU,
-            "NZ" => Self::NZ,
-            "OM" => Self::OM,
-            "PA" => Self::PA,
-            "PE" => Self::PE,
-            "PF" => Self::PF,
-            "PG" => Self::PG,
-            "PH" => Self::PH,
-            "PK" => Self::PK,
-            "PL" => Self::PL,
-            "PM" => Self::PM,
-            "PR" => Self::PR,
-            "PS" => Self::PS,
-            "PT" => Self::PT,
-            "PW" => Self::PW,
-            "PY" => Self::PY,
-            "QA" => Self::QA,
-            "RE" => Self::RE,
-            "RO" => Self::RO,
-            "RS" => Self::RS,
-            "RU" => Self::RU,
-            "RW" => Self::RW,
-            "SA" => Self::SA,
-            "SB" => Self::SB,
-            "SC" => Self::SC,
-            "SD" => Self::SD,
-            "SE" => Self::SE,
-            "SG" => Self::SG,
-            "SH" => Self::SH,
-            "SI" => Self::SI,
-            "SJ" => Self::SJ,
-            "SK" => Self::SK,
-            "SL" => Self::SL,
-            "SM" => Self::SM,
-            "SN" => Self::SN,
-            "SO" => Self::SO,
-            "SR" => Self::SR,
-            "SS" => Self::SS,
-            "ST" => Self::ST,
-            "SV" => Self::SV,
-            "SX" => Self::SX,
-            "SY" => Self::SY,
-            "SZ" => Self::SZ,
-            "TC" => Self::TC,
-            "TD" => Self::TD,
-            "TG" => Self::TG,
-            "TH" => Self::TH,
-            "TJ" => Self::TJ,
-            "TK" => Self::TK,
-            "TL" => Self::TL,
-            "TM" => Self::TM,
-            "TN" => Self::TN,
-            "TO" => Self::TO,
-            "TR" => Self::TR,
-            "TT" => Self::TT,
-            "TV" => Self::TV,
-            "TW" => Self::TW,
-            "TZ" => Self::TZ,
-            "UA" => Self::UA,
-            "UG" => Self::UG,
-            "UY" => Self::UY,
-            "UZ" => Self::UZ,
-            "VA" => Self::VA,
-            "VC" => Self::VC,
-            "VE" => Self::VE,
-            "VG" => Self::VG,
-            "VI" => Self::VI,
-            "VN" => Self::VN,
-            "VU" => Self::VU,
-            "WF" => Self::WF,
-            "WS" => Self::WS,
-            "XG" => Self::XG,
-            "XN" => Self::XN,
-            "XP" => Self::XP,
-            "XS" => Self::XS,
-            "XT" => Self::XT,
-            "XV" => Self::XV,
-            "YE" => Self::YE,
-            "YT" => Self::YT,
-            "ZA" => Self::ZA,
-            "ZM" => Self::ZM,
-            "ZW" => Self::ZW,
-            _ => return None,
-        })
+        territories::from_name(name)
+    }
+
+    /// Yields a territory code by name, more leniently than [`TerritoryCode::from_name`]: case and
+    /// surrounding whitespace are ignored, and full-width letters are accepted. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(TerritoryCode::US), TerritoryCode::from_name_lenient(" us "));
+    /// assert_eq!(Some(TerritoryCode::JP), TerritoryCode::from_name_lenient("ＪＰ"));
+    /// ```
+    pub fn from_name_lenient(name: &str) -> Option<Self> {
+        let mut normalized = [0; 2];
+        let mut chars = name.trim().chars();
+        for byte in &mut normalized {
+            let ch = match chars.next()? {
+                // Full-width forms are laid out like ASCII, at a fixed offset
+                ch @ ('Ａ'..='Ｚ' | 'ａ'..='ｚ') => char::from_u32(ch as u32 - 0xFEE0)?,
+                ch => ch,
+            };
+            if !ch.is_ascii_alphabetic() {
+                return None;
+            }
+            *byte = ch.to_ascii_uppercase() as u8;
+        }
+        if chars.next().is_some() {
+            return None;
+        }
+        Self::from_name(std::str::from_utf8(&normalized).ok()?)
     }
 
     /// Gets the name of this territory code. The value is always uppercase.
@@ -796,931 +215,242 @@ impl TerritoryCode {
     /// assert_eq!("FR", TerritoryCode::FR.name())
     /// ```
     pub fn name(&self) -> &'static str {
-        match *self {
-            Self::US => "US",
-            Self::CA => "CA",
-            Self::AC => "AC",
-            Self::AD => "AD",
-            Self::AE => "AE",
-            Self::AF => "AF",
-            Self::AG => "AG",
-            Self::AI => "AI",
-            Self::AL => "AL",
-            Self::AM => "AM",
-            Self::AO => "AO",
-            Self::AQ => "AQ",
-            Self::AR => "AR",
-            Self::AS => "AS",
-            Self::AT => "AT",
-            Self::AU => "AU",
-            Self::AW => "AW",
-            Self::AX => "AX",
-            Self::AZ => "AZ",
-            Self::BA => "BA",
-            Self::BB => "BB",
-            Self::BD => "BD",
-            Self::BE => "BE",
-            Self::BF => "BF",
-            Self::BG => "BG",
-            Self::BH => "BH",
-            Self::BI => "BI",
-            Self::BJ => "BJ",
-            Self::BM => "BM",
-            Self::BN => "BN",
-            Self::BO => "BO",
-            Self::BQ => "BQ",
-            Self::BR => "BR",
-            Self::BS => "BS",
-            Self::BT => "BT",
-            Self::BW => "BW",
-            Self::BY => "BY",
-            Self::BZ => "BZ",
-            Self::CC => "CC",
-            Self::CD => "CD",
-            Self::CF => "CF",
-            Self::CG => "CG",
-            Self::CH => "CH",
-            Self::CI => "CI",
-            Self::CK => "CK",
-            Self::CL => "CL",
-            Self::CM => "CM",
-            Self::CN => "CN",
-            Self::CO => "CO",
-            Self::CR => "CR",
-            Self::CU => "CU",
-            Self::CV => "CV",
-            Self::CW => "CW",
-            Self::CX => "CX",
-            Self::CY => "CY",
-            Self::CZ => "CZ",
-            Self::DE => "DE",
-            Self::DJ => "DJ",
-            Self::DK => "DK",
-            Self::DM => "DM",
-            Self::DO => "DO",
-            Self::DZ => "DZ",
-            Self::EC => "EC",
-            Self::EE => "EE",
-            Self::EG => "EG",
-            Self::ER => "ER",
-            Self::ES => "ES",
-            Self::ET => "ET",
-            Self::FI => "FI",
-            Self::FJ => "FJ",
-            Self::FK => "FK",
-            Self::FM => "FM",
-            Self::FO => "FO",
-            Self::FR => "FR",
-            Self::GA => "GA",
-            Self::GB => "GB",
-            Self::GD => "GD",
-            Self::GE => "GE",
-            Self::GF => "GF",
-            Self::GG => "GG",
-            Self::GH => "GH",
-            Self::GI => "GI",
-            Self::GL => "GL",
-            Self::GM => "GM",
-            Self::GN => "GN",
-            Self::GP => "GP",
-            Self::GQ => "GQ",
-            Self::GR => "GR",
-            Self::GT => "GT",
-            Self::GU => "GU",
-            Self::GW => "GW",
-            Self::GY => "GY",
-            Self::HK => "HK",
-            Self::HN => "HN",
-            Self::HR => "HR",
-            Self::HT => "HT",
-            Self::HU => "HU",
-            Self::ID => "ID",
-            Self::IE => "IE",
-            Self::IL => "IL",
-            Self::IM => "IM",
-            Self::IN => "IN",
-            Self::IO => "IO",
-            Self::IQ => "IQ",
-            Self::IR => "IR",
-            Self::IS => "IS",
-            Self::IT => "IT",
-            Self::JE => "JE",
-            Self::JM => "JM",
-            Self::JO => "JO",
-            Self::JP => "JP",
-            Self::KE => "KE",
-            Self::KG => "KG",
-            Self::KH => "KH",
-            Self::KI => "KI",
-            Self::KM => "KM",
-            Self::KN => "KN",
-            Self::KP => "KP",
-            Self::KR => "KR",
-            Self::KW => "KW",
-            Self::KY => "KY",
-            Self::KZ => "KZ",
-            Self::LA => "LA",
-            Self::LB => "LB",
-            Self::LC => "LC",
-            Self::LI => "LI",
-            Self::LK => "LK",
-            Self::LR => "LR",
-            Self::LS => "LS",
-            Self::LT => "LT",
-            Self::LU => "LU",
-            Self::LV => "LV",
-            Self::LY => "LY",
-            Self::MA => "MA",
-            Self::MC => "MC",
-            Self::MD => "MD",
-            Self::ME => "ME",
-            Self::MG => "MG",
-            Self::MH => "MH",
-            Self::MK => "MK",
-            Self::ML => "ML",
-            Self::MM => "MM",
-            Self::MN => "MN",
-            Self::MO => "MO",
-            Self::MP => "MP",
-            Self::MQ => "MQ",
-            Self::MR => "MR",
-            Self::MS => "MS",
-            Self::MT => "MT",
-            Self::MU => "MU",
-            Self::MV => "MV",
-            Self::MW => "MW",
-            Self::MX => "MX",
-            Self::MY => "MY",
-            Self::MZ => "MZ",
-            Self::NA => "NA",
-            Self::NC => "NC",
-            Self::NE => "NE",
-            Self::NF => "NF",
-            Self::NG => "NG",
-            Self::NI => "NI",
-            Self::NL => "NL",
-            Self::NO => "NO",
-            Self::NP => "NP",
-            Self::NR => "NR",
-            Self::NU => "NU",
-            Self::NZ => "NZ",
-            Self::OM => "OM",
-            Self::PA => "PA",
-            Self::PE => "PE",
-            Self::PF => "PF",
-            Self::PG => "PG",
-            Self::PH => "PH",
-            Self::PK => "PK",
-            Self::PL => "PL",
-            Self::PM => "PM",
-            Self::PR => "PR",
-            Self::PS => "PS",
-            Self::PT => "PT",
-            Self::PW => "PW",
-            Self::PY => "PY",
-            Self::QA => "QA",
-            Self::RE => "RE",
-            Self::RO => "RO",
-            Self::RS => "RS",
-            Self::RU => "RU",
-            Self::RW => "RW",
-            Self::SA => "SA",
-            Self::SB => "SB",
-            Self::SC => "SC",
-            Self::SD => "SD",
-            Self::SE => "SE",
-            Self::SG => "SG",
-            Self::SH => "SH",
-            Self::SI => "SI",
-            Self::SJ => "SJ",
-            Self::SK => "SK",
-            Self::SL => "SL",
-            Self::SM => "SM",
-            Self::SN => "SN",
-            Self::SO => "SO",
-            Self::SR => "SR",
-            Self::SS => "SS",
-            Self::ST => "ST",
-            Self::SV => "SV",
-            Self::SX => "SX",
-            Self::SY => "SY",
-            Self::SZ => "SZ",
-            Self::TC => "TC",
-            Self::TD => "TD",
-            Self::TG => "TG",
-            Self::TH => "TH",
-            Self::TJ => "TJ",
-            Self::TK => "TK",
-            Self::TL => "TL",
-            Self::TM => "TM",
-            Self::TN => "TN",
-            Self::TO => "TO",
-            Self::TR => "TR",
-            Self::TT => "TT",
-            Self::TV => "TV",
-            Self::TW => "TW",
-            Self::TZ => "TZ",
-            Self::UA => "UA",
-            Self::UG => "UG",
-            Self::UY => "UY",
-            Self::UZ => "UZ",
-            Self::VA => "VA",
-            Self::VC => "VC",
-            Self::VE => "VE",
-            Self::VG => "VG",
-            Self::VI => "VI",
-            Self::VN => "VN",
-            Self::VU => "VU",
-            Self::WF => "WF",
-            Self::WS => "WS",
-            Self::XG => "XG",
-            Self::XN => "XN",
-            Self::XP => "XP",
-            Self::XS => "XS",
-            Self::XT => "XT",
-            Self::XV => "XV",
-            Self::YE => "YE",
-            Self::YT => "YT",
-            Self::ZA => "ZA",
-            Self::ZM => "ZM",
-            Self::ZW => "ZW",
-        }
+        territories::name(*self)
     }
 
-    fn lookup_table(prefix: u64) -> Option<TerritoryCode> {
-        Some(match prefix {
-            1201 => Self::US,
-            1202 => Self::US,
-            1203 => Self::US,
-            1204 => Self::CA,
-            1205 => Self::US,
-            1206 => Self::US,
-            1207 => Self::US,
-            1208 => Self::US,
-            1209 => Self::US,
-            1210 => Self::US,
-            1212 => Self::US,
-            1213 => Self::US,
-            1214 => Self::US,
-            1215 => Self::US,
-            1216 => Self::US,
-            1217 => Self::US,
-            1218 => Self::US,
-            1219 => Self::US,
-            1224 => Self::US,
-            1225 => Self::US,
-            1226 => Self::CA,
-            1228 => Self::US,
-            1229 => Self::US,
-            1231 => Self::US,
-            1234 => Self::US,
-            1236 => Self::CA,
-            1239 => Self::US,
-            1240 => Self::US,
-            1242 => Self::BS,
-            1246 => Self::BB,
-            1248 => Self::US,
-            1249 => Self::CA,
-            1250 => Self::CA,
-            1251 => Self::US,
-            1252 => Self::US,
-            1253 => Self::US,
-            1254 => Self::US,
-            1256 => Self::US,
-            1260 => Self::US,
-            1262 => Self::US,
-            1264 => Self::AI,
-            1267 => Self::US,
-            1268 => Self::AG,
-            1269 => Self::US,
-            1270 => Self::US,
-            1272 => Self::US,
-            1274 => Self::US,
-            1276 => Self::US,
-            1281 => Self::US,
-            1284 => Self::VG,
-            1289 => Self::CA,
-            1301 => Self::US,
-            1302 => Self::US,
-            1303 => Self::US,
-            1304 => Self::US,
-            1305 => Self::US,
-            1306 => Self::CA,
-            1307 => Self::US,
-            1308 => Self::US,
-            1309 => Self::US,
-            1310 => Self::US,
-            1312 => Self::US,
-            1313 => Self::US,
-            1314 => Self::US,
-            1315 => Self::US,
-            1316 => Self::US,
-            1317 => Self::US,
-            1318 => Self::US,
-            1319 => Self::US,
-            1320 => Self::US,
-            1321 => Self::US,
-            1323 => Self::US,
-            1325 => Self::US,
-            1330 => Self::US,
-            1331 => Self::US,
-            1334 => Self::US,
-            1336 => Self::US,
-            1337 => Self::US,
-            1339 => Self::US,
-            1340 => Self::VI,
-            1343 => Self::CA,
-            1345 => Self::KY,
-            1346 => Self::US,
-            1347 => Self::US,
-            1351 => Self::US,
-            1352 => Self::US,
-            1360 => Self::US,
-            1361 => Self::US,
-            1364 => Self::US,
-            1365 => Self::CA,
-            1385 => Self::US,
-            1386 => Self::US,
-            1401 => Self::US,
-            1402 => Self::US,
-            1403 => Self::CA,
-            1404 => Self::US,
-            1405 => Self::US,
-            1406 => Self::US,
-            1407 => Self::US,
-            1408 => Self::US,
-            1409 => Self::US,
-            1410 => Self::US,
-            1412 => Self::US,
-            1413 => Self::US,
-            1414 => Self::US,
-            1415 => Self::US,
-            1416 => Self::CA,
-            1417 => Self::US,
-            1418 => Self::CA,
-            1419 => Self::US,
-            1423 => Self::US,
-            1424 => Self::US,
-            1425 => Self::US,
-            1430 => Self::US,
-            1431 => Self::CA,
-            1432 => Self::US,
-            1434 => Self::US,
-            1435 => Self::US,
-            1437 => Self::CA,
-            1438 => Self::CA,
-            1440 => Self::US,
-            1441 => Self::BM,
-            1442 => Self::US,
-            1443 => Self::US,
-            1450 => Self::CA,
-            1457 => Self::CA,
-            1458 => Self::US,
-            1469 => Self::US,
-            1470 => Self::US,
-            1473 => Self::GD,
-            1475 => Self::US,
-            1478 => Self::US,
-            1479 => Self::US,
-            1480 => Self::US,
-            1484 => Self::US,
-            1500 => Self::US,
-            1501 => Self::US,
-            1502 => Self::US,
-            1503 => Self::US,
-            1504 => Self::US,
-            1505 => Self::US,
-            1506 => Self::CA,
-            1507 => Self::US,
-            1508 => Self::US,
-            1509 => Self::US,
-            1510 => Self::US,
-            1512 => Self::US,
-            1513 => Self::US,
-            1514 => Self::CA,
-            1515 => Self::US,
-            1516 => Self::US,
-            1517 => Self::US,
-            1518 => Self::US,
-            1519 => Self::CA,
-            1520 => Self::US,
-            1530 => Self::US,
-            1531 => Self::US,
-            1533 => Self::US,
-            1534 => Self::US,
-            1539 => Self::US,
-            1540 => Self::US,
-            1541 => Self::US,
-            1544 => Self::US,
-            1551 => Self::US,
-            1559 => Self::US,
-            1561 => Self::US,
-            1562 => Self::US,
-            1563 => Self::US,
-            1566 => Self::US,
-            1567 => Self::US,
-            1570 => Self::US,
-            1571 => Self::US,
-            1573 => Self::US,
-            1574 => Self::US,
-            1575 => Self::US,
-            1577 => Self::US,
-            1579 => Self::CA,
-            1580 => Self::US,
-            1581 => Self::CA,
-            1585 => Self::US,
-            1586 => Self::US,
-            1587 => Self::CA,
-            1600 => Self::CA,
-            1601 => Self::US,
-            1602 => Self::US,
-            1603 => Self::US,
-            1604 => Self::CA,
-            1605 => Self::US,
-            1606 => Self::US,
-            1607 => Self::US,
-            1608 => Self::US,
-            1609 => Self::US,
-            1610 => Self::US,
-            1612 => Self::US,
-            1613 => Self::CA,
-            1614 => Self::US,
-            1615 => Self::US,
-            1616 => Self::US,
-            1617 => Self::US,
-            1618 => Self::US,
-            1619 => Self::US,
-            1620 => Self::US,
-            1623 => Self::US,
-            1626 => Self::US,
-            1628 => Self::US,
-            1629 => Self::US,
-            1630 => Self::US,
-            1631 => Self::US,
-            1636 => Self::US,
-            1639 => Self::CA,
-            1641 => Self::US,
-            1646 => Self::US,
-            1647 => Self::CA,
-            1649 => Self::TC,
-            1650 => Self::US,
-            1651 => Self::US,
-            1657 => Self::US,
-            1660 => Self::US,
-            1661 => Self::US,
-            1662 => Self::US,
-            1664 => Self::MS,
-            1667 => Self::US,
-            1669 => Self::US,
-            1670 => Self::MP,
-            1671 => Self::GU,
-            1678 => Self::US,
-            1681 => Self::US,
-            1682 => Self::US,
-            1684 => Self::AS,
-            1700 => Self::US,
-            1701 => Self::US,
-            1702 => Self::US,
-            1703 => Self::US,
-            1704 => Self::US,
-            1705 => Self::CA,
-            1706 => Self::US,
-            1707 => Self::US,
-            1708 => Self::US,
-            1709 => Self::CA,
-            1710 => Self::US,
-            1712 => Self::US,
-            1713 => Self::US,
-            1714 => Self::US,
-            1715 => Self::US,
-            1716 => Self::US,
-            1717 => Self::US,
-            1718 => Self::US,
-            1719 => Self::US,
-            1720 => Self::US,
-            1721 => Self::SX,
-            1724 => Self::US,
-            1725 => Self::US,
-            1727 => Self::US,
-            1731 => Self::US,
-            1732 => Self::US,
-            1734 => Self::US,
-            1737 => Self::US,
-            1740 => Self::US,
-            1747 => Self::US,
-            1754 => Self::US,
-            1757 => Self::US,
-            1758 => Self::LC,
-            1760 => Self::US,
-            1762 => Self::US,
-            1763 => Self::US,
-            1765 => Self::US,
-            1767 => Self::DM,
-            1769 => Self::US,
-            1770 => Self::US,
-            1772 => Self::US,
-            1773 => Self::US,
-            1774 => Self::US,
-            1775 => Self::US,
-            1778 => Self::CA,
-            1779 => Self::US,
-            1780 => Self::CA,
-            1781 => Self::US,
-            1782 => Self::CA,
-            1784 => Self::VC,
-            1785 => Self::US,
-            1786 => Self::US,
-            1787 => Self::PR,
-            1800 => Self::US,
-            1801 => Self::US,
-            1802 => Self::US,
-            1803 => Self::US,
-            1804 => Self::US,
-            1805 => Self::US,
-            1806 => Self::US,
-            1807 => Self::CA,
-            1808 => Self::US,
-            1809 => Self::DO,
-            1810 => Self::US,
-            1812 => Self::US,
-            1813 => Self::US,
-            1814 => Self::US,
-            1815 => Self::US,
-            1816 => Self::US,
-            1817 => Self::US,
-            1818 => Self::US,
-            1819 => Self::CA,
-            1825 => Self::CA,
-            1828 => Self::US,
-            1829 => Self::DO,
-            1830 => Self::US,
-            1831 => Self::US,
-            1832 => Self::US,
-            1843 => Self::US,
-            1844 => Self::US,
-            1845 => Self::US,
-            1847 => Self::US,
-            1848 => Self::US,
-            1849 => Self::DO,
-            1850 => Self::US,
-            1855 => Self::US,
-            1856 => Self::US,
-            1857 => Self::US,
-            1858 => Self::US,
-            1859 => Self::US,
-            1860 => Self::US,
-            1862 => Self::US,
-            1863 => Self::US,
-            1864 => Self::US,
-            1865 => Self::US,
-            1866 => Self::US,
-            1867 => Self::CA,
-            1868 => Self::TT,
-            1869 => Self::KN,
-            1870 => Self::US,
-            1872 => Self::US,
-            1873 => Self::CA,
-            1876 => Self::JM,
-            1877 => Self::US,
-            1878 => Self::US,
-            1888 => Self::US,
-            1900 => Self::US,
-            1901 => Self::US,
-            1902 => Self::CA,
-            1903 => Self::US,
-            1904 => Self::US,
-            1905 => Self::CA,
-            1906 => Self::US,
-            1907 => Self::US,
-            1908 => Self::US,
-            1909 => Self::US,
-            1910 => Self::US,
-            1912 => Self::US,
-            1913 => Self::US,
-            1914 => Self::US,
-            1915 => Self::US,
-            1916 => Self::US,
-            1917 => Self::US,
-            1918 => Self::US,
-            1919 => Self::US,
-            1920 => Self::US,
-            1925 => Self::US,
-            1928 => Self::US,
-            1929 => Self::US,
-            1930 => Self::US,
-            1931 => Self::US,
-            1935 => Self::US,
-            1936 => Self::US,
-            1937 => Self::US,
-            1938 => Self::US,
-            1939 => Self::PR,
-            1940 => Self::US,
-            1941 => Self::US,
-            1947 => Self::US,
-            1949 => Self::US,
-            1951 => Self::US,
-            1952 => Self::US,
-            1954 => Self::US,
-            1956 => Self::US,
-            1959 => Self::US,
-            1970 => Self::US,
-            1971 => Self::US,
-            1972 => Self::US,
-            1973 => Self::US,
-            1978 => Self::US,
-            1979 => Self::US,
-            1980 => Self::US,
-            1984 => Self::US,
-            1985 => Self::US,
-            1989 => Self::US,
-            20 => Self::EG,
-            211 => Self::SS,
-            212 => Self::MA,
-            213 => Self::DZ,
-            216 => Self::TN,
-            218 => Self::LY,
-            220 => Self::GM,
-            221 => Self::SN,
-            222 => Self::MR,
-            223 => Self::ML,
-            224 => Self::GN,
-            225 => Self::CI,
-            226 => Self::BF,
-            227 => Self::NE,
-            228 => Self::TG,
-            229 => Self::BJ,
-            230 => Self::MU,
-            231 => Self::LR,
-            232 => Self::SL,
-            233 => Self::GH,
-            234 => Self::NG,
-            235 => Self::TD,
-            236 => Self::CF,
-            237 => Self::CM,
-            238 => Self::CV,
-            239 => Self::ST,
-            240 => Self::GQ,
-            241 => Self::GA,
-            242 => Self::CG,
-            243 => Self::CD,
-            244 => Self::AO,
-            245 => Self::GW,
-            246 => Self::IO,
-            247 => Self::AC,
-            248 => Self::SC,
-            249 => Self::SD,
-            250 => Self::RW,
-            251 => Self::ET,
-            252 => Self::SO,
-            253 => Self::DJ,
-            254 => Self::KE,
-            255 => Self::TZ,
-            256 => Self::UG,
-            257 => Self::BI,
-            258 => Self::MZ,
-            260 => Self::ZM,
-            261 => Self::MG,
-            262269 => Self::YT,
-            262639 => Self::YT,
-            262 => Self::RE,
-            263 => Self::ZW,
-            264 => Self::NA,
-            265 => Self::MW,
-            266 => Self::LS,
-            267 => Self::BW,
-            268 => Self::SZ,
-            269 => Self::KM,
-            27 => Self::ZA,
-            290 => Self::SH,
-            291 => Self::ER,
-            297 => Self::AW,
-            298 => Self::FO,
-            299 => Self::GL,
-            30 => Self::GR,
-            31 => Self::NL,
-            32 => Self::BE,
-            33 => Self::FR,
-            34 => Self::ES,
-            350 => Self::GI,
-            351 => Self::PT,
-            352 => Self::LU,
-            353 => Self::IE,
-            354 => Self::IS,
-            355 => Self::AL,
-            356 => Self::MT,
-            357 => Self::CY,
-            358 => Self::FI,
-            35818 => Self::AX,
-            359 => Self::BG,
-            36 => Self::HU,
-            370 => Self::LT,
-            371 => Self::LV,
-            372 => Self::EE,
-            373 => Self::MD,
-            374 => Self::AM,
-            375 => Self::BY,
-            376 => Self::AD,
-            377 => Self::MC,
-            378 => Self::SM,
-            379 => Self::VA,
-            380 => Self::UA,
-            381 => Self::RS,
-            382 => Self::ME,
-            385 => Self::HR,
-            386 => Self::SI,
-            387 => Self::BA,
-            389 => Self::MK,
-            39 => Self::IT,
-            40 => Self::RO,
-            41 => Self::CH,
-            420 => Self::CZ,
-            421 => Self::SK,
-            423 => Self::LI,
-            43 => Self::AT,
-            441481 => Self::GG,
-            441624 => Self::IM,
-            441534 => Self::JE,
-            44 => Self::GB,
-            45 => Self::DK,
-            46 => Self::SE,
-            47 => Self::NO,
-            4779 => Self::SJ,
-            48 => Self::PL,
-            49 => Self::DE,
-            500 => Self::FK,
-            501 => Self::BZ,
-            502 => Self::GT,
-            503 => Self::SV,
-            504 => Self::HN,
-            505 => Self::NI,
-            506 => Self::CR,
-            507 => Self::PA,
-            508 => Self::PM,
-            509 => Self::HT,
-            51 => Self::PE,
-            52 => Self::MX,
-            53 => Self::CU,
-            54 => Self::AR,
-            55 => Self::BR,
-            56 => Self::CL,
-            57 => Self::CO,
-            58 => Self::VE,
-            590 => Self::GP,
-            591 => Self::BO,
-            592 => Self::GY,
-            593 => Self::EC,
-            594 => Self::GF,
-            595 => Self::PY,
-            596 => Self::MQ,
-            597 => Self::SR,
-            598 => Self::UY,
-            5993 => Self::BQ,
-            5994 => Self::BQ,
-            5997 => Self::BQ,
-            5999 => Self::CW,
-            60 => Self::MY,
-            61 => Self::AU,
-            6189164 => Self::CX,
-            6189162 => Self::CC,
-            62 => Self::ID,
-            63 => Self::PH,
-            64 => Self::NZ,
-            65 => Self::SG,
-            66 => Self::TH,
-            670 => Self::TL,
-            6721 => Self::AQ,
-            6723 => Self::NF,
-            673 => Self::BN,
-            674 => Self::NR,
-            675 => Self::PG,
-            676 => Self::TO,
-            677 => Self::SB,
-            678 => Self::VU,
-            679 => Self::FJ,
-            680 => Self::PW,
-            681 => Self::WF,
-            682 => Self::CK,
-            683 => Self::NU,
-            685 => Self::WS,
-            686 => Self::KI,
-            687 => Self::NC,
-            688 => Self::TV,
-            689 => Self::PF,
-            690 => Self::TK,
-            691 => Self::FM,
-            692 => Self::MH,
-            7 => Self::RU,
-            76 => Self::KZ,
-            77 => Self::KZ,
-            800 => Self::XT,
-            808 => Self::XS,
-            81 => Self::JP,
-            82 => Self::KR,
-            84 => Self::VN,
-            850 => Self::KP,
-            852 => Self::HK,
-            853 => Self::MO,
-            855 => Self::KH,
-            856 => Self::LA,
-            86 => Self::CN,
-            870 => Self::XN,
-            878 => Self::XP,
-            880 => Self::BD,
-            881 => Self::XG,
-            882 => Self::XV,
-            883 => Self::XV,
-            886 => Self::TW,
-            90 => Self::TR,
-            91 => Self::IN,
-            92 => Self::PK,
-            93 => Self::AF,
-            94 => Self::LK,
-            95 => Self::MM,
-            960 => Self::MV,
-            961 => Self::LB,
-            962 => Self::JO,
-            963 => Self::SY,
-            964 => Self::IQ,
-            965 => Self::KW,
-            966 => Self::SA,
-            967 => Self::YE,
-            968 => Self::OM,
-            970 => Self::PS,
-            971 => Self::AE,
-            972 => Self::IL,
-            973 => Self::BH,
-            974 => Self::QA,
-            975 => Self::BT,
-            976 => Self::MN,
-            977 => Self::NP,
-            98 => Self::IR,
-            992 => Self::TJ,
-            993 => Self::TM,
-            994 => Self::AZ,
-            995 => Self::GE,
-            996 => Self::KG,
-            998 => Self::UZ,
-            _ => return None,
-        })
+    /// Gets the ISO 3166-1 alpha-3 code of this territory, which is always uppercase. There is
+    /// none for territories outside of ISO 3166-1, like [`TerritoryCode::AC`] and the
+    /// [`TerritoryCode::XT`] family of non-geographic calling codes.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some("GBR"), TerritoryCode::GB.alpha3());
+    /// assert_eq!(None, TerritoryCode::XT.alpha3());
+    /// ```
+    pub fn alpha3(&self) -> Option<&'static str> {
+        territories::alpha3(*self)
+    }
+
+    /// Yields a territory code by ISO 3166-1 alpha-3 code. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(TerritoryCode::US), TerritoryCode::from_alpha3("USA"));
+    /// ```
+    pub fn from_alpha3(alpha3: &str) -> Option<Self> {
+        territories::from_alpha3(alpha3)
     }
 
-    /// Attempts to find the territory code from a phone number
+    /// Gets the ISO 3166-1 numeric code of this territory. There is none for territories outside
+    /// of ISO 3166-1, like [`TerritoryCode::AC`] and the [`TerritoryCode::XT`] family of
+    /// non-geographic calling codes.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(826), TerritoryCode::GB.numeric());
+    /// assert_eq!(Some(4), TerritoryCode::AF.numeric(), "usually written as 004");
+    /// ```
+    pub fn numeric(&self) -> Option<u16> {
+        territories::numeric(*self)
+    }
+
+    /// Yields a territory code by ISO 3166-1 numeric code. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(TerritoryCode::US), TerritoryCode::from_numeric(840));
+    /// ```
+    pub fn from_numeric(numeric: u16) -> Option<Self> {
+        territories::from_numeric(numeric)
+    }
+
+    /// The range of valid lengths of national significant numbers in this territory, i.e. of phone
+    /// numbers without their calling code.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(4..=5, TerritoryCode::SH.national_number_lengths());
+    /// ```
+    pub fn national_number_lengths(&self) -> RangeInclusive<u32> {
+        metadata::national_number_lengths(*self)
+    }
+
+    /// Attempts to find the territory code from a phone number. Use [`PhoneNumber`] to also split
+    /// the number into its calling code and national significant number.
     pub fn from_phone_number(phone: u64) -> Result<Self, FromPhoneError> {
+        Self::find_by_prefix(phone).map(|(territory, _)| territory)
+    }
+
+    /// Finds the territory code of a phone number, along with the number of leading digits which
+    /// matched the lookup table
+    fn find_by_prefix(phone: u64) -> Result<(Self, u32), FromPhoneError> {
         let phone = NonZeroU64::new(phone).ok_or(FromPhoneError::InvalidPhoneNumber)?;
 
         let phone_len = 1 + phone.ilog10();
-        if phone_len < 10 {
-            return Err(FromPhoneError::InvalidPhoneNumber);
+        if phone_len > MAX_DIGITS {
+            return Err(FromPhoneError::ExceedsE164MaxLength { actual: phone_len });
         }
         let get_phone_leading_digits = |no_digits: u32| {
             let chop_digits = phone_len - no_digits;
             phone.get() / 10u64.pow(chop_digits)
         };
-        let lookup_table = Self::lookup_table;
+        let lookup_table = |prefix: u64| {
+            let table = &metadata::LOOKUP_TABLE;
+            let index = table.binary_search_by_key(&prefix, |&(prefix, _)| prefix);
+            index.ok().map(|index| table[index].1)
+        };
 
         // Shortcut for +1 country code
         Ok(if get_phone_leading_digits(1) == 1 {
+            if phone_len <= 4 {
+                return Err(FromPhoneError::NotFound);
+            }
             let first_four = get_phone_leading_digits(4);
-            match lookup_table(first_four) {
-                Some(found) => found,
-                None => return Err(FromPhoneError::NotFound),
+            let area_code = (first_four % 1000) as u16;
+            // The area code database is authoritative, the lookup table covers the rest
+            let found = match NanpAreaCode::from_npa(area_code) {
+                Some(area_code) => area_code.territory(),
+                None => lookup_table(first_four),
+            };
+            match found {
+                Some(found) => (found, 4),
+                None => return Err(FromPhoneError::UnassignedNanpAreaCode(area_code)),
             }
         } else {
-            // Keep trying based on a number of prefix digits
-            let mut prefix_len = 7;
+            // Keep trying based on a number of prefix digits, leaving at least one digit after it
+            let mut prefix_len = (phone_len - 1).min(7);
             loop {
                 if prefix_len == 0 {
-                    return Err(FromPhoneError::NotFound);
+                    return Err(Self::not_found_error(phone.get(), phone_len));
                 }
                 let leading_digits = get_phone_leading_digits(prefix_len);
                 if let Some(found) = lookup_table(leading_digits) {
-                    break found;
+                    break (found, prefix_len);
                 }
                 prefix_len -= 1;
             }
         })
     }
+
+    /// Tells apart phone numbers whose calling code is not used by any territory from phone
+    /// numbers whose territory is just not known
+    fn not_found_error(phone: u64, phone_len: u32) -> FromPhoneError {
+        if phone_len >= 2 {
+            let calling_code_len = calling_code_len(phone / 10u64.pow(phone_len - 2));
+            if calling_code_len < phone_len {
+                let calling_code = phone / 10u64.pow(phone_len - calling_code_len);
+                let calling_code = calling_code as u32;
+                if Self::all_from_calling_code(calling_code).next().is_none() {
+                    return FromPhoneError::UnassignedCallingCode(calling_code);
+                }
+            }
+        }
+        FromPhoneError::NotFound
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub enum FromPhoneError {
-    /// A phone number must be at least 10 digits
+    /// The phone number is malformed, e.g. empty or in national format
     InvalidPhoneNumber,
     /// The calling prefix did not match any territory code
     NotFound,
+    /// The national significant number is shorter than allowed in its territory
+    TooShort { min: u32, actual: u32 },
+    /// The national significant number is longer than allowed in its territory
+    TooLong { max: u32, actual: u32 },
+    /// The phone number is longer than the 15 digits allowed by E.164
+    ExceedsE164MaxLength { actual: u32 },
+    /// No territory uses this country calling code
+    UnassignedCallingCode(u32),
+    /// No territory uses this area code of the North American Numbering Plan, i.e. after +1
+    UnassignedNanpAreaCode(u16),
+    /// A phone number string contains a character which is neither a digit nor a separator. The
+    /// position is counted in characters, starting from zero.
+    InvalidCharacter { position: usize, ch: char },
 }
 
 impl Display for FromPhoneError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match *self {
-            Self::InvalidPhoneNumber => "Invalid phone. Must be at least 10 digits",
-            Self::NotFound => "Did not match any territory code",
-        })
+        match *self {
+            Self::InvalidPhoneNumber => f.write_str("Invalid phone number"),
+            Self::NotFound => f.write_str("Did not match any territory code"),
+            Self::TooShort { min, actual } => write!(
+                f,
+                "National number too short: {actual} digits, expected at least {min}"
+            ),
+            Self::TooLong { max, actual } => write!(
+                f,
+                "National number too long: {actual} digits, expected at most {max}"
+            ),
+            Self::ExceedsE164MaxLength { actual } => write!(
+                f,
+                "Phone number too long: {actual} digits, E.164 allows at most {MAX_DIGITS}"
+            ),
+            Self::UnassignedCallingCode(code) => {
+                write!(f, "Calling code +{code} is not assigned to any territory")
+            }
+            Self::UnassignedNanpAreaCode(code) => {
+                write!(
+                    f,
+                    "Area code {code} is not assigned in the +1 numbering plan"
+                )
+            }
+            Self::InvalidCharacter { position, ch } => {
+                write!(f, "Invalid character {ch:?} at position {position}")
+            }
+        }
     }
 }
 
 impl Error for FromPhoneError {}
 
+/// The name did not match any territory code
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FromNameError;
+
+impl Display for FromNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Did not match any territory code")
+    }
+}
+
+impl Error for FromNameError {}
+
+/// Number of digits taken by the ITU country calling code at the start of an international phone
+/// number. Country calling codes are prefix-free, so the first two digits are enough to tell.
+fn calling_code_len(first_two_digits: u64) -> u32 {
+    match first_two_digits {
+        10..=19 | 70..=79 => 1,
+        20
+        | 27
+        | 30..=34
+        | 36
+        | 39
+        | 40
+        | 41
+        | 43..=49
+        | 51..=58
+        | 60..=66
+        | 81
+        | 82
+        | 84
+        | 86
+        | 90..=95
+        | 98 => 2,
+        _ => 3,
+    }
+}
+
+/// The ITU country calling code at the start of a calling code, which may also include the first
+/// digits of national numbers. I.e. 44 for 441481.
+fn country_calling_code(code: NonZeroU32) -> u32 {
+    let code = code.get();
+    let code_len = 1 + code.ilog10();
+    let first_two_digits = match code_len {
+        1 => code * 10,
+        _ => code / 10u32.pow(code_len - 2),
+    };
+    let calling_code_len = calling_code_len(first_two_digits.into()).min(code_len);
+    code / 10u32.pow(code_len - calling_code_len)
+}
+
 /// A territory's calling codes. In rare cases, a territory may have more than one calling code.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct CallingCodes(CallingCodesInner);
@@ -1770,15 +500,18 @@ enum CallingCodesInner {
 ///
 /// Deprecated: This function has poor error handling. It panics if the given number is invalid
 /// or unparsable to an integer. Also, it yields an empty string when the territory code is not found.
+/// Parse a [`PhoneNumber`] instead.
 #[deprecated]
 pub fn find_iso_3166(phone: &str) -> &'static str {
     let phone = phone.parse::<u64>().unwrap();
     match TerritoryCode::from_phone_number(phone) {
         Ok(country) => country.name(),
-        Err(FromPhoneError::InvalidPhoneNumber) => {
-            panic!("phone length needs to be at least 10 digits")
-        }
-        Err(FromPhoneError::NotFound) => "",
+        Err(
+            FromPhoneError::NotFound
+            | FromPhoneError::UnassignedCallingCode(_)
+            | FromPhoneError::UnassignedNanpAreaCode(_),
+        ) => "",
+        Err(error) => panic!("{}", error),
     }
 }
 
@@ -1788,259 +521,9 @@ pub fn find_iso_3166(phone: &str) -> &'static str {
 /// is invalid. It also cannot accurately handle territories with multiple calling codes.
 #[deprecated]
 pub fn find_phone_cc(code: &str) -> &'static str {
-    let country = match TerritoryCode::from_name(code) {
-        Some(country) => country,
-        None => return "",
-    };
-    match country {
-        TerritoryCode::US => "1",
-        TerritoryCode::CA => "1",
-        TerritoryCode::AC => "247",
-        TerritoryCode::AD => "376",
-        TerritoryCode::AE => "971",
-        TerritoryCode::AF => "93",
-        TerritoryCode::AG => "1",
-        TerritoryCode::AI => "1",
-        TerritoryCode::AL => "355",
-        TerritoryCode::AM => "374",
-        TerritoryCode::AO => "244",
-        TerritoryCode::AQ => "672",
-        TerritoryCode::AR => "54",
-        TerritoryCode::AS => "1",
-        TerritoryCode::AT => "43",
-        TerritoryCode::AU => "61",
-        TerritoryCode::AW => "297",
-        TerritoryCode::AX => "35818",
-        TerritoryCode::AZ => "994",
-        TerritoryCode::BA => "387",
-        TerritoryCode::BB => "1",
-        TerritoryCode::BD => "880",
-        TerritoryCode::BE => "32",
-        TerritoryCode::BF => "226",
-        TerritoryCode::BG => "359",
-        TerritoryCode::BH => "973",
-        TerritoryCode::BI => "257",
-        TerritoryCode::BJ => "229",
-        TerritoryCode::BM => "1",
-        TerritoryCode::BN => "673",
-        TerritoryCode::BO => "591",
-        TerritoryCode::BQ => "599", //5993,5994,5997
-        TerritoryCode::BR => "55",
-        TerritoryCode::BS => "1",
-        TerritoryCode::BT => "975",
-        TerritoryCode::BW => "267",
-        TerritoryCode::BY => "375",
-        TerritoryCode::BZ => "501",
-        TerritoryCode::CC => "6189162",
-        TerritoryCode::CD => "243",
-        TerritoryCode::CF => "236",
-        TerritoryCode::CG => "242",
-        TerritoryCode::CH => "41",
-        TerritoryCode::CI => "225",
-        TerritoryCode::CK => "682",
-        TerritoryCode::CL => "56",
-        TerritoryCode::CM => "237",
-        TerritoryCode::CN => "86",
-        TerritoryCode::CO => "57",
-        TerritoryCode::CR => "506",
-        TerritoryCode::CU => "53",
-        TerritoryCode::CV => "238",
-        TerritoryCode::CW => "5999",
-        TerritoryCode::CX => "6189164",
-        TerritoryCode::CY => "357",
-        TerritoryCode::CZ => "420",
-        TerritoryCode::DE => "49",
-        TerritoryCode::DJ => "253",
-        TerritoryCode::DK => "45",
-        TerritoryCode::DM => "1",
-        TerritoryCode::DO => "1",
-        TerritoryCode::DZ => "213",
-        TerritoryCode::EC => "593",
-        TerritoryCode::EE => "372",
-        TerritoryCode::EG => "20",
-        TerritoryCode::ER => "291",
-        TerritoryCode::ES => "34",
-        TerritoryCode::ET => "251",
-        TerritoryCode::FI => "358",
-        TerritoryCode::FJ => "679",
-        TerritoryCode::FK => "500",
-        TerritoryCode::FM => "691",
-        TerritoryCode::FO => "298",
-        TerritoryCode::FR => "33",
-        TerritoryCode::GA => "241",
-        TerritoryCode::GB => "44",
-        TerritoryCode::GD => "1",
-        TerritoryCode::GE => "995",
-        TerritoryCode::GF => "594",
-        TerritoryCode::GG => "441481",
-        TerritoryCode::GH => "233",
-        TerritoryCode::GI => "350",
-        TerritoryCode::GL => "299",
-        TerritoryCode::GM => "220",
-        TerritoryCode::GN => "224",
-        TerritoryCode::GP => "590",
-        TerritoryCode::GQ => "240",
-        TerritoryCode::GR => "30",
-        TerritoryCode::GT => "502",
-        TerritoryCode::GU => "1",
-        TerritoryCode::GW => "245",
-        TerritoryCode::GY => "592",
-        TerritoryCode::HK => "852",
-        TerritoryCode::HN => "504",
-        TerritoryCode::HR => "385",
-        TerritoryCode::HT => "509",
-        TerritoryCode::HU => "36",
-        TerritoryCode::ID => "62",
-        TerritoryCode::IE => "353",
-        TerritoryCode::IL => "972",
-        TerritoryCode::IM => "441624",
-        TerritoryCode::IN => "91",
-        TerritoryCode::IO => "246",
-        TerritoryCode::IQ => "964",
-        TerritoryCode::IR => "98",
-        TerritoryCode::IS => "354",
-        TerritoryCode::IT => "39",
-        TerritoryCode::JE => "441534",
-        TerritoryCode::JM => "1",
-        TerritoryCode::JO => "962",
-        TerritoryCode::JP => "81",
-        TerritoryCode::KE => "254",
-        TerritoryCode::KG => "996",
-        TerritoryCode::KH => "855",
-        TerritoryCode::KI => "686",
-        TerritoryCode::KM => "269",
-        TerritoryCode::KN => "1",
-        TerritoryCode::KP => "850",
-        TerritoryCode::KR => "82",
-        TerritoryCode::KW => "965",
-        TerritoryCode::KY => "1",
-        TerritoryCode::KZ => "7",
-        TerritoryCode::LA => "856",
-        TerritoryCode::LB => "961",
-        TerritoryCode::LC => "1",
-        TerritoryCode::LI => "423",
-        TerritoryCode::LK => "94",
-        TerritoryCode::LR => "231",
-        TerritoryCode::LS => "266",
-        TerritoryCode::LT => "370",
-        TerritoryCode::LU => "352",
-        TerritoryCode::LV => "371",
-        TerritoryCode::LY => "218",
-        TerritoryCode::MA => "212",
-        TerritoryCode::MC => "377",
-        TerritoryCode::MD => "373",
-        TerritoryCode::ME => "382",
-        TerritoryCode::MG => "261",
-        TerritoryCode::MH => "692",
-        TerritoryCode::MK => "389",
-        TerritoryCode::ML => "223",
-        TerritoryCode::MM => "95",
-        TerritoryCode::MN => "976",
-        TerritoryCode::MO => "853",
-        TerritoryCode::MP => "1",
-        TerritoryCode::MQ => "596",
-        TerritoryCode::MR => "222",
-        TerritoryCode::MS => "1",
-        TerritoryCode::MT => "356",
-        TerritoryCode::MU => "230",
-        TerritoryCode::MV => "960",
-        TerritoryCode::MW => "265",
-        TerritoryCode::MX => "52",
-        TerritoryCode::MY => "60",
-        TerritoryCode::MZ => "258",
-        TerritoryCode::NA => "264",
-        TerritoryCode::NC => "687",
-        TerritoryCode::NE => "227",
-        TerritoryCode::NF => "6723",
-        TerritoryCode::NG => "234",
-        TerritoryCode::NI => "505",
-        TerritoryCode::NL => "31",
-        TerritoryCode::NO => "47",
-        TerritoryCode::NP => "977",
-        TerritoryCode::NR => "674",
-        TerritoryCode::NU => "683",
-        TerritoryCode::NZ => "64",
-        TerritoryCode::OM => "968",
-        TerritoryCode::PA => "507",
-        TerritoryCode::PE => "51",
-        TerritoryCode::PF => "689",
-        TerritoryCode::PG => "675",
-        TerritoryCode::PH => "63",
-        TerritoryCode::PK => "92",
-        TerritoryCode::PL => "48",
-        TerritoryCode::PM => "508",
-        TerritoryCode::PR => "1",
-        TerritoryCode::PS => "970",
-        TerritoryCode::PT => "351",
-        TerritoryCode::PW => "680",
-        TerritoryCode::PY => "595",
-        TerritoryCode::QA => "974",
-        TerritoryCode::RE => "262",
-        TerritoryCode::RO => "40",
-        TerritoryCode::RS => "381",
-        TerritoryCode::RU => "7",
-        TerritoryCode::RW => "250",
-        TerritoryCode::SA => "966",
-        TerritoryCode::SB => "677",
-        TerritoryCode::SC => "248",
-        TerritoryCode::SD => "249",
-        TerritoryCode::SE => "46",
-        TerritoryCode::SG => "65",
-        TerritoryCode::SH => "290",
-        TerritoryCode::SI => "386",
-        TerritoryCode::SJ => "4779",
-        TerritoryCode::SK => "421",
-        TerritoryCode::SL => "232",
-        TerritoryCode::SM => "378",
-        TerritoryCode::SN => "221",
-        TerritoryCode::SO => "252",
-        TerritoryCode::SR => "597",
-        TerritoryCode::SS => "211",
-        TerritoryCode::ST => "239",
-        TerritoryCode::SV => "503",
-        TerritoryCode::SX => "1",
-        TerritoryCode::SY => "963",
-        TerritoryCode::SZ => "268",
-        TerritoryCode::TC => "1",
-        TerritoryCode::TD => "235",
-        TerritoryCode::TG => "228",
-        TerritoryCode::TH => "66",
-        TerritoryCode::TJ => "992",
-        TerritoryCode::TK => "690",
-        TerritoryCode::TL => "670",
-        TerritoryCode::TM => "993",
-        TerritoryCode::TN => "216",
-        TerritoryCode::TO => "676",
-        TerritoryCode::TR => "90",
-        TerritoryCode::TT => "1",
-        TerritoryCode::TV => "688",
-        TerritoryCode::TW => "886",
-        TerritoryCode::TZ => "255",
-        TerritoryCode::UA => "380",
-        TerritoryCode::UG => "256",
-        TerritoryCode::UY => "598",
-        TerritoryCode::UZ => "998",
-        TerritoryCode::VA => "379",
-        TerritoryCode::VC => "1",
-        TerritoryCode::VE => "58",
-        TerritoryCode::VG => "1",
-        TerritoryCode::VI => "1",
-        TerritoryCode::VN => "84",
-        TerritoryCode::VU => "678",
-        TerritoryCode::WF => "681",
-        TerritoryCode::WS => "685",
-        TerritoryCode::XG => "881",
-        TerritoryCode::XN => "870",
-        TerritoryCode::XP => "878",
-        TerritoryCode::XS => "808",
-        TerritoryCode::XT => "800",
-        TerritoryCode::XV => "882",
-        //    CountryCode::XV => "883",
-        TerritoryCode::YE => "967",
-        TerritoryCode::YT => "262",
-        TerritoryCode::ZA => "27",
-        TerritoryCode::ZM => "260",
-        TerritoryCode::ZW => "263",
+    match TerritoryCode::from_name(code) {
+        Some(country) => territories::calling_code_text(country),
+        None => "",
     }
 }
 
@@ -2048,7 +531,7 @@ pub fn find_phone_cc(code: &str) -> &'static str {
 #[allow(deprecated)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn find_iso_3166_test() {
@@ -2064,6 +547,135 @@ mod tests {
         assert_eq!("", find_phone_cc("ZZ"));
     }
 
+    #[test]
+    fn iterate_all_territories() {
+        let territories: HashSet<_> = TerritoryCode::iter().collect();
+        assert_eq!(TerritoryCode::COUNT, territories.len());
+        for territory in TerritoryCode::iter() {
+            assert_eq!(Some(territory), TerritoryCode::from_name(territory.name()));
+            assert_eq!(territory.name(), territory.to_string());
+        }
+    }
+
+    /// The territory tables are generated from `data/territories.csv`, see `tools/metadata-gen`
+    #[test]
+    fn every_territory_is_complete() {
+        let table = &metadata::LOOKUP_TABLE;
+        assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for territory in TerritoryCode::iter() {
+            assert_eq!(2, territory.name().len());
+            assert!(!territory.calling_codes().all().is_empty());
+            assert!(
+                table.iter().any(|&(_, found)| found == territory),
+                "{} has no lookup prefix",
+                territory
+            );
+        }
+    }
+
+    #[test]
+    fn iso_3166_codes_round_trip() {
+        for territory in TerritoryCode::iter() {
+            assert_eq!(territory.alpha3().is_some(), territory.numeric().is_some());
+            if let Some(alpha3) = territory.alpha3() {
+                assert_eq!(Some(territory), TerritoryCode::from_alpha3(alpha3));
+            }
+            if let Some(numeric) = territory.numeric() {
+                assert_eq!(Some(territory), TerritoryCode::from_numeric(numeric));
+            }
+        }
+        assert_eq!(None, TerritoryCode::AC.alpha3());
+        assert_eq!(None, TerritoryCode::XV.numeric());
+        assert_eq!(None, TerritoryCode::from_alpha3("usa"));
+        assert_eq!(None, TerritoryCode::from_numeric(999));
+    }
+
+    #[test]
+    fn dialing_prefixes() {
+        for territory in TerritoryCode::iter() {
+            let prefixes = [
+                territory.national_prefix(),
+                territory.international_prefix(),
+            ];
+            for prefix in prefixes.iter().flatten() {
+                assert!(!prefix.is_empty(), "{}", territory);
+                assert!(prefix.bytes().all(|b| b.is_ascii_digit()), "{}", territory);
+            }
+        }
+        let nanp = TerritoryCode::all_from_calling_code(1);
+        for territory in nanp {
+            assert_eq!(Some("1"), territory.national_prefix(), "{territory}");
+            assert_eq!(Some("011"), territory.international_prefix(), "{territory}");
+        }
+        assert_eq!(None, TerritoryCode::ES.national_prefix());
+        assert_eq!(None, TerritoryCode::XT.international_prefix());
+    }
+
+    #[test]
+    fn from_name_lenient() {
+        for name in [
+            "GB",
+            "gb",
+            "Gb",
+            " GB",
+            "GB\n",
+            "\u{3000}ｇｂ\u{3000}",
+            "ＧＢ",
+            "Ｇb",
+        ] {
+            assert_eq!(
+                Some(TerritoryCode::GB),
+                TerritoryCode::from_name_lenient(name)
+            );
+        }
+        for name in ["", "G", "G B", "GBR", "ZZ", "Ｇ１", "ÉS"] {
+            assert_eq!(None, TerritoryCode::from_name_lenient(name), "{name}");
+        }
+        assert_eq!(None, TerritoryCode::from_name("gb"), "strict");
+    }
+
+    #[test]
+    fn conversion_traits() {
+        assert_eq!(Ok(TerritoryCode::IT), "IT".parse());
+        assert_eq!(Err(FromNameError), "ZZ".parse::<TerritoryCode>());
+        assert_eq!(Ok(TerritoryCode::DE), TerritoryCode::try_from("DE"));
+        assert_eq!("JP", TerritoryCode::JP.as_ref());
+        assert_eq!("JP", <&str>::from(TerritoryCode::JP));
+    }
+
+    #[test]
+    fn territories_sharing_calling_code() {
+        assert_eq!(25, TerritoryCode::all_from_calling_code(1).count());
+        let territories: Vec<_> = TerritoryCode::all_from_calling_code(44).collect();
+        let expected = [
+            TerritoryCode::GB,
+            TerritoryCode::GG,
+            TerritoryCode::IM,
+            TerritoryCode::JE,
+        ];
+        assert_eq!(&expected[..], &territories[..]);
+
+        for territory in TerritoryCode::iter() {
+            for code in territory.calling_codes().all() {
+                let code = country_calling_code(*code);
+                let main = TerritoryCode::from_calling_code(code).unwrap();
+                assert!(TerritoryCode::all_from_calling_code(code).any(|t| t == territory));
+                assert!(TerritoryCode::all_from_calling_code(code).any(|t| t == main));
+            }
+        }
+        assert_eq!(Some(TerritoryCode::US), TerritoryCode::from_calling_code(1));
+        assert_eq!(
+            Some(TerritoryCode::CW),
+            TerritoryCode::from_calling_code(599)
+        );
+        assert_eq!(
+            Some(TerritoryCode::IN),
+            TerritoryCode::from_calling_code(91)
+        );
+    }
+
+    /// The lookup table is generated from libphonenumber's metadata, see `tools/metadata-gen`: it
+    /// must agree with the hand-written table it replaced
     #[test]
     fn get_country_code_equals_old_impl() {
         let mut phone_prefix = HashMap::<u32, _>::new();
@@ -0,0 +1,69 @@
+/// The locale of last resort, which all localized data has, e.g.
+/// [`TerritoryCode::english_name`](crate::TerritoryCode::english_name)
+pub(crate) const FALLBACK_LOCALE: &str = "en";
+
+/// Yields a locale and all of its parents, in canonical case, ending with the fallback locale
+pub(crate) fn locale_fallbacks(locale: &str) -> Vec<String> {
+    let mut subtags: Vec<String> = locale
+        .trim()
+        .split(['-', '_'])
+        .filter(|subtag| !subtag.is_empty())
+        .enumerate()
+        .map(|(i, subtag)| match (i, subtag.len()) {
+            (0, _) => subtag.to_ascii_lowercase(),
+            (_, 2) => subtag.to_ascii_uppercase(),
+            (_, 4) => subtag[..1].to_ascii_uppercase() + &subtag[1..].to_ascii_lowercase(),
+            _ => subtag.to_ascii_lowercase(),
+        })
+        .collect();
+    if subtags.first().map(String::as_str) == Some("nb") {
+        subtags[0] = "no".into();
+    }
+
+    let mut fallbacks = Vec::new();
+    while !subtags.is_empty() {
+        fallbacks.push(subtags.join("-"));
+        // Parent locales which are not found by truncation, from the CLDR
+        let parent = match (subtags[0].as_str(), &subtags[1..]) {
+            ("zh", [region]) if matches!(region.as_str(), "TW" | "HK" | "MO") => "zh-Hant",
+            ("zh", [script]) if script == "Hant" => "",
+            ("es", [region]) if region != "ES" && region != "419" => "es-419",
+            ("pt", [region]) if region != "BR" && region != "PT" => "pt-PT",
+            _ => {
+                subtags.pop();
+                continue;
+            }
+        };
+        subtags = parent
+            .split('-')
+            .filter(|subtag| !subtag.is_empty())
+            .map(String::from)
+            .collect();
+    }
+    if fallbacks.last().map(String::as_str) != Some(FALLBACK_LOCALE) {
+        fallbacks.push(FALLBACK_LOCALE.into());
+    }
+    fallbacks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_chains() {
+        assert_eq!(vec!["pt-BR", "pt", "en"], locale_fallbacks("pt_br"));
+        assert_eq!(
+            vec!["es-MX", "es-419", "es", "en"],
+            locale_fallbacks("es-MX")
+        );
+        assert_eq!(vec!["zh-TW", "zh-Hant", "en"], locale_fallbacks("zh-tw"));
+        assert_eq!(
+            vec!["zh-Hans-CN", "zh-Hans", "zh", "en"],
+            locale_fallbacks("zh-Hans-CN")
+        );
+        assert_eq!(vec!["en-GB", "en"], locale_fallbacks("en-GB"));
+        assert_eq!(vec!["no", "en"], locale_fallbacks("nb"));
+        assert_eq!(vec!["en"], locale_fallbacks(""));
+    }
+}
@@ -0,0 +1,1405 @@
+// Generated by `cargo run -p metadata-gen -- PhoneNumberMetadata.xml`. Do not edit.
+// Territory metadata from libphonenumber's PhoneNumberMetadata.xml, see `tools/metadata-gen`.
+
+use std::ops::RangeInclusive;
+
+use crate::TerritoryCode;
+
+/// The territory of the phone numbers starting with each prefix, which includes the calling
+/// code. Sorted by prefix.
+#[rustfmt::skip]
+pub(crate) static LOOKUP_TABLE: [(u64, TerritoryCode); 719] = [
+    (7, TerritoryCode::RU),
+    (20, TerritoryCode::EG),
+    (27, TerritoryCode::ZA),
+    (30, TerritoryCode::GR),
+    (31, TerritoryCode::NL),
+    (32, TerritoryCode::BE),
+    (33, TerritoryCode::FR),
+    (34, TerritoryCode::ES),
+    (36, TerritoryCode::HU),
+    (39, TerritoryCode::IT),
+    (40, TerritoryCode::RO),
+    (41, TerritoryCode::CH),
+    (43, TerritoryCode::AT),
+    (44, TerritoryCode::GB),
+    (45, TerritoryCode::DK),
+    (46, TerritoryCode::SE),
+    (47, TerritoryCode::NO),
+    (48, TerritoryCode::PL),
+    (49, TerritoryCode::DE),
+    (51, TerritoryCode::PE),
+    (52, TerritoryCode::MX),
+    (53, TerritoryCode::CU),
+    (54, TerritoryCode::AR),
+    (55, TerritoryCode::BR),
+    (56, TerritoryCode::CL),
+    (57, TerritoryCode::CO),
+    (58, TerritoryCode::VE),
+    (60, TerritoryCode::MY),
+    (61, TerritoryCode::AU),
+    (62, TerritoryCode::ID),
+    (63, TerritoryCode::PH),
+    (64, TerritoryCode::NZ),
+    (65, TerritoryCode::SG),
+    (66, TerritoryCode::TH),
+    (76, TerritoryCode::KZ),
+    (77, TerritoryCode::KZ),
+    (81, TerritoryCode::JP),
+    (82, TerritoryCode::KR),
+    (84, TerritoryCode::VN),
+    (86, TerritoryCode::CN),
+    (90, TerritoryCode::TR),
+    (91, TerritoryCode::IN),
+    (92, TerritoryCode::PK),
+    (93, TerritoryCode::AF),
+    (94, TerritoryCode::LK),
+    (95, TerritoryCode::MM),
+    (98, TerritoryCode::IR),
+    (211, TerritoryCode::SS),
+    (212, TerritoryCode::MA),
+    (213, TerritoryCode::DZ),
+    (216, TerritoryCode::TN),
+    (218, TerritoryCode::LY),
+    (220, TerritoryCode::GM),
+    (221, TerritoryCode::SN),
+    (222, TerritoryCode::MR),
+    (223, TerritoryCode::ML),
+    (224, TerritoryCode::GN),
+    (225, TerritoryCode::CI),
+    (226, TerritoryCode::BF),
+    (227, TerritoryCode::NE),
+    (228, TerritoryCode::TG),
+    (229, TerritoryCode::BJ),
+    (230, TerritoryCode::MU),
+    (231, TerritoryCode::LR),
+    (232, TerritoryCode::SL),
+    (233, TerritoryCode::GH),
+    (234, TerritoryCode::NG),
+    (235, TerritoryCode::TD),
+    (236, TerritoryCode::CF),
+    (237, TerritoryCode::CM),
+    (238, TerritoryCode::CV),
+    (239, TerritoryCode::ST),
+    (240, TerritoryCode::GQ),
+    (241, TerritoryCode::GA),
+    (242, TerritoryCode::CG),
+    (243, TerritoryCode::CD),
+    (244, TerritoryCode::AO),
+    (245, TerritoryCode::GW),
+    (246, TerritoryCode::IO),
+    (247, TerritoryCode::AC),
+    (248, TerritoryCode::SC),
+    (249, TerritoryCode::SD),
+    (250, TerritoryCode::RW),
+    (251, TerritoryCode::ET),
+    (252, TerritoryCode::SO),
+    (253, TerritoryCode::DJ),
+    (254, TerritoryCode::KE),
+    (255, TerritoryCode::TZ),
+    (256, TerritoryCode::UG),
+    (257, TerritoryCode::BI),
+    (258, TerritoryCode::MZ),
+    (260, TerritoryCode::ZM),
+    (261, TerritoryCode::MG),
+    (262, TerritoryCode::RE),
+    (263, TerritoryCode::ZW),
+    (264, TerritoryCode::NA),
+    (265, TerritoryCode::MW),
+    (266, TerritoryCode::LS),
+    (267, TerritoryCode::BW),
+    (268, TerritoryCode::SZ),
+    (269, TerritoryCode::KM),
+    (290, TerritoryCode::SH),
+    (291, TerritoryCode::ER),
+    (297, TerritoryCode::AW),
+    (298, TerritoryCode::FO),
+    (299, TerritoryCode::GL),
+    (350, TerritoryCode::GI),
+    (351, TerritoryCode::PT),
+    (352, TerritoryCode::LU),
+    (353, TerritoryCode::IE),
+    (354, TerritoryCode::IS),
+    (355, TerritoryCode::AL),
+    (356, TerritoryCode::MT),
+    (357, TerritoryCode::CY),
+    (358, TerritoryCode::FI),
+    (359, TerritoryCode::BG),
+    (370, TerritoryCode::LT),
+    (371, TerritoryCode::LV),
+    (372, TerritoryCode::EE),
+    (373, TerritoryCode::MD),
+    (374, TerritoryCode::AM),
+    (375, TerritoryCode::BY),
+    (376, TerritoryCode::AD),
+    (377, TerritoryCode::MC),
+    (378, TerritoryCode::SM),
+    (379, TerritoryCode::VA),
+    (380, TerritoryCode::UA),
+    (381, TerritoryCode::RS),
+    (382, TerritoryCode::ME),
+    (385, TerritoryCode::HR),
+    (386, TerritoryCode::SI),
+    (387, TerritoryCode::BA),
+    (389, TerritoryCode::MK),
+    (420, TerritoryCode::CZ),
+    (421, TerritoryCode::SK),
+    (423, TerritoryCode::LI),
+    (500, TerritoryCode::FK),
+    (501, TerritoryCode::BZ),
+    (502, TerritoryCode::GT),
+    (503, TerritoryCode::SV),
+    (504, TerritoryCode::HN),
+    (505, TerritoryCode::NI),
+    (506, TerritoryCode::CR),
+    (507, TerritoryCode::PA),
+    (508, TerritoryCode::PM),
+    (509, TerritoryCode::HT),
+    (590, TerritoryCode::GP),
+    (591, TerritoryCode::BO),
+    (592, TerritoryCode::GY),
+    (593, TerritoryCode::EC),
+    (594, TerritoryCode::GF),
+    (595, TerritoryCode::PY),
+    (596, TerritoryCode::MQ),
+    (597, TerritoryCode::SR),
+    (598, TerritoryCode::UY),
+    (599, TerritoryCode::CW),
+    (670, TerritoryCode::TL),
+    (672, TerritoryCode::NF),
+    (673, TerritoryCode::BN),
+    (674, TerritoryCode::NR),
+    (675, TerritoryCode::PG),
+    (676, TerritoryCode::TO),
+    (677, TerritoryCode::SB),
+    (678, TerritoryCode::VU),
+    (679, TerritoryCode::FJ),
+    (680, TerritoryCode::PW),
+    (681, TerritoryCode::WF),
+    (682, TerritoryCode::CK),
+    (683, TerritoryCode::NU),
+    (685, TerritoryCode::WS),
+    (686, TerritoryCode::KI),
+    (687, TerritoryCode::NC),
+    (688, TerritoryCode::TV),
+    (689, TerritoryCode::PF),
+    (690, TerritoryCode::TK),
+    (691, TerritoryCode::FM),
+    (692, TerritoryCode::MH),
+    (800, TerritoryCode::XT),
+    (808, TerritoryCode::XS),
+    (850, TerritoryCode::KP),
+    (852, TerritoryCode::HK),
+    (853, TerritoryCode::MO),
+    (855, TerritoryCode::KH),
+    (856, TerritoryCode::LA),
+    (870, TerritoryCode::XN),
+    (878, TerritoryCode::XP),
+    (880, TerritoryCode::BD),
+    (881, TerritoryCode::XG),
+    (882, TerritoryCode::XV),
+    (883, TerritoryCode::XV),
+    (886, TerritoryCode::TW),
+    (960, TerritoryCode::MV),
+    (961, TerritoryCode::LB),
+    (962, TerritoryCode::JO),
+    (963, TerritoryCode::SY),
+    (964, TerritoryCode::IQ),
+    (965, TerritoryCode::KW),
+    (966, TerritoryCode::SA),
+    (967, TerritoryCode::YE),
+    (968, TerritoryCode::OM),
+    (970, TerritoryCode::PS),
+    (971, TerritoryCode::AE),
+    (972, TerritoryCode::IL),
+    (973, TerritoryCode::BH),
+    (974, TerritoryCode::QA),
+    (975, TerritoryCode::BT),
+    (976, TerritoryCode::MN),
+    (977, TerritoryCode::NP),
+    (992, TerritoryCode::TJ),
+    (993, TerritoryCode::TM),
+    (994, TerritoryCode::AZ),
+    (995, TerritoryCode::GE),
+    (996, TerritoryCode::KG),
+    (998, TerritoryCode::UZ),
+    (1201, TerritoryCode::US),
+    (1202, TerritoryCode::US),
+    (1203, TerritoryCode::US),
+    (1204, TerritoryCode::CA),
+    (1205, TerritoryCode::US),
+    (1206, TerritoryCode::US),
+    (1207, TerritoryCode::US),
+    (1208, TerritoryCode::US),
+    (1209, TerritoryCode::US),
+    (1210, TerritoryCode::US),
+    (1212, TerritoryCode::US),
+    (1213, TerritoryCode::US),
+    (1214, TerritoryCode::US),
+    (1215, TerritoryCode::US),
+    (1216, TerritoryCode::US),
+    (1217, TerritoryCode::US),
+    (1218, TerritoryCode::US),
+    (1219, TerritoryCode::US),
+    (1220, TerritoryCode::US),
+    (1223, TerritoryCode::US),
+    (1224, TerritoryCode::US),
+    (1225, TerritoryCode::US),
+    (1226, TerritoryCode::CA),
+    (1227, TerritoryCode::US),
+    (1228, TerritoryCode::US),
+    (1229, TerritoryCode::US),
+    (1231, TerritoryCode::US),
+    (1234, TerritoryCode::US),
+    (1235, TerritoryCode::US),
+    (1236, TerritoryCode::CA),
+    (1239, TerritoryCode::US),
+    (1240, TerritoryCode::US),
+    (1242, TerritoryCode::BS),
+    (1246, TerritoryCode::BB),
+    (1248, TerritoryCode::US),
+    (1249, TerritoryCode::CA),
+    (1250, TerritoryCode::CA),
+    (1251, TerritoryCode::US),
+    (1252, TerritoryCode::US),
+    (1253, TerritoryCode::US),
+    (1254, TerritoryCode::US),
+    (1256, TerritoryCode::US),
+    (1257, TerritoryCode::CA),
+    (1260, TerritoryCode::US),
+    (1262, TerritoryCode::US),
+    (1263, TerritoryCode::CA),
+    (1264, TerritoryCode::AI),
+    (1267, TerritoryCode::US),
+    (1268, TerritoryCode::AG),
+    (1269, TerritoryCode::US),
+    (1270, TerritoryCode::US),
+    (1272, TerritoryCode::US),
+    (1274, TerritoryCode::US),
+    (1276, TerritoryCode::US),
+    (1279, TerritoryCode::US),
+    (1281, TerritoryCode::US),
+    (1283, TerritoryCode::US),
+    (1284, TerritoryCode::VG),
+    (1289, TerritoryCode::CA),
+    (1301, TerritoryCode::US),
+    (1302, TerritoryCode::US),
+    (1303, TerritoryCode::US),
+    (1304, TerritoryCode::US),
+    (1305, TerritoryCode::US),
+    (1306, TerritoryCode::CA),
+    (1307, TerritoryCode::US),
+    (1308, TerritoryCode::US),
+    (1309, TerritoryCode::US),
+    (1310, TerritoryCode::US),
+    (1312, TerritoryCode::US),
+    (1313, TerritoryCode::US),
+    (1314, TerritoryCode::US),
+    (1315, TerritoryCode::US),
+    (1316, TerritoryCode::US),
+    (1317, TerritoryCode::US),
+    (1318, TerritoryCode::US),
+    (1319, TerritoryCode::US),
+    (1320, TerritoryCode::US),
+    (1321, TerritoryCode::US),
+    (1323, TerritoryCode::US),
+    (1324, TerritoryCode::US),
+    (1325, TerritoryCode::US),
+    (1326, TerritoryCode::US),
+    (1327, TerritoryCode::US),
+    (1329, TerritoryCode::US),
+    (1330, TerritoryCode::US),
+    (1331, TerritoryCode::US),
+    (1332, TerritoryCode::US),
+    (1334, TerritoryCode::US),
+    (1336, TerritoryCode::US),
+    (1337, TerritoryCode::US),
+    (1339, TerritoryCode::US),
+    (1340, TerritoryCode::VI),
+    (1341, TerritoryCode::US),
+    (1343, TerritoryCode::CA),
+    (1345, TerritoryCode::KY),
+    (1346, TerritoryCode::US),
+    (1347, TerritoryCode::US),
+    (1350, TerritoryCode::US),
+    (1351, TerritoryCode::US),
+    (1352, TerritoryCode::US),
+    (1353, TerritoryCode::US),
+    (1354, TerritoryCode::CA),
+    (1360, TerritoryCode::US),
+    (1361, TerritoryCode::US),
+    (1363, TerritoryCode::US),
+    (1364, TerritoryCode::US),
+    (1365, TerritoryCode::CA),
+    (1367, TerritoryCode::CA),
+    (1368, TerritoryCode::CA),
+    (1369, TerritoryCode::US),
+    (1380, TerritoryCode::US),
+    (1382, TerritoryCode::CA),
+    (1385, TerritoryCode::US),
+    (1386, TerritoryCode::US),
+    (1401, TerritoryCode::US),
+    (1402, TerritoryCode::US),
+    (1403, TerritoryCode::CA),
+    (1404, TerritoryCode::US),
+    (1405, TerritoryCode::US),
+    (1406, TerritoryCode::US),
+    (1407, TerritoryCode::US),
+    (1408, TerritoryCode::US),
+    (1409, TerritoryCode::US),
+    (1410, TerritoryCode::US),
+    (1412, TerritoryCode::US),
+    (1413, TerritoryCode::US),
+    (1414, TerritoryCode::US),
+    (1415, TerritoryCode::US),
+    (1416, TerritoryCode::CA),
+    (1417, TerritoryCode::US),
+    (1418, TerritoryCode::CA),
+    (1419, TerritoryCode::US),
+    (1423, TerritoryCode::US),
+    (1424, TerritoryCode::US),
+    (1425, TerritoryCode::US),
+    (1428, TerritoryCode::CA),
+    (1430, TerritoryCode::US),
+    (1431, TerritoryCode::CA),
+    (1432, TerritoryCode::US),
+    (1434, TerritoryCode::US),
+    (1435, TerritoryCode::US),
+    (1437, TerritoryCode::CA),
+    (1438, TerritoryCode::CA),
+    (1440, TerritoryCode::US),
+    (1441, TerritoryCode::BM),
+    (1442, TerritoryCode::US),
+    (1443, TerritoryCode::US),
+    (1445, TerritoryCode::US),
+    (1447, TerritoryCode::US),
+    (1448, TerritoryCode::US),
+    (1450, TerritoryCode::CA),
+    (1457, TerritoryCode::CA),
+    (1458, TerritoryCode::US),
+    (1463, TerritoryCode::US),
+    (1464, TerritoryCode::US),
+    (1468, TerritoryCode::CA),
+    (1469, TerritoryCode::US),
+    (1470, TerritoryCode::US),
+    (1472, TerritoryCode::US),
+    (1473, TerritoryCode::GD),
+    (1474, TerritoryCode::CA),
+    (1475, TerritoryCode::US),
+    (1478, TerritoryCode::US),
+    (1479, TerritoryCode::US),
+    (1480, TerritoryCode::US),
+    (1484, TerritoryCode::US),
+    (1500, TerritoryCode::US),
+    (1501, TerritoryCode::US),
+    (1502, TerritoryCode::US),
+    (1503, TerritoryCode::US),
+    (1504, TerritoryCode::US),
+    (1505, TerritoryCode::US),
+    (1506, TerritoryCode::CA),
+    (1507, TerritoryCode::US),
+    (1508, TerritoryCode::US),
+    (1509, TerritoryCode::US),
+    (1510, TerritoryCode::US),
+    (1512, TerritoryCode::US),
+    (1513, TerritoryCode::US),
+    (1514, TerritoryCode::CA),
+    (1515, TerritoryCode::US),
+    (1516, TerritoryCode::US),
+    (1517, TerritoryCode::US),
+    (1518, TerritoryCode::US),
+    (1519, TerritoryCode::CA),
+    (1520, TerritoryCode::US),
+    (1521, TerritoryCode::US),
+    (1522, TerritoryCode::US),
+    (1523, TerritoryCode::US),
+    (1524, TerritoryCode::US),
+    (1525, TerritoryCode::US),
+    (1526, TerritoryCode::US),
+    (1527, TerritoryCode::US),
+    (1528, TerritoryCode::US),
+    (1529, TerritoryCode::US),
+    (1530, TerritoryCode::US),
+    (1531, TerritoryCode::US),
+    (1533, TerritoryCode::US),
+    (1534, TerritoryCode::US),
+    (1539, TerritoryCode::US),
+    (1540, TerritoryCode::US),
+    (1541, TerritoryCode::US),
+    (1544, TerritoryCode::US),
+    (1548, TerritoryCode::CA),
+    (1551, TerritoryCode::US),
+    (1557, TerritoryCode::US),
+    (1559, TerritoryCode::US),
+    (1561, TerritoryCode::US),
+    (1562, TerritoryCode::US),
+    (1563, TerritoryCode::US),
+    (1564, TerritoryCode::US),
+    (1566, TerritoryCode::US),
+    (1567, TerritoryCode::US),
+    (1570, TerritoryCode::US),
+    (1571, TerritoryCode::US),
+    (1572, TerritoryCode::US),
+    (1573, TerritoryCode::US),
+    (1574, TerritoryCode::US),
+    (1575, TerritoryCode::US),
+    (1577, TerritoryCode::US),
+    (1579, TerritoryCode::CA),
+    (1580, TerritoryCode::US),
+    (1581, TerritoryCode::CA),
+    (1582, TerritoryCode::US),
+    (1584, TerritoryCode::CA),
+    (1585, TerritoryCode::US),
+    (1586, TerritoryCode::US),
+    (1587, TerritoryCode::CA),
+    (1588, TerritoryCode::US),
+    (1600, TerritoryCode::CA),
+    (1601, TerritoryCode::US),
+    (1602, TerritoryCode::US),
+    (1603, TerritoryCode::US),
+    (1604, TerritoryCode::CA),
+    (1605, TerritoryCode::US),
+    (1606, TerritoryCode::US),
+    (1607, TerritoryCode::US),
+    (1608, TerritoryCode::US),
+    (1609, TerritoryCode::US),
+    (1610, TerritoryCode::US),
+    (1612, TerritoryCode::US),
+    (1613, TerritoryCode::CA),
+    (1614, TerritoryCode::US),
+    (1615, TerritoryCode::US),
+    (1616, TerritoryCode::US),
+    (1617, TerritoryCode::US),
+    (1618, TerritoryCode::US),
+    (1619, TerritoryCode::US),
+    (1620, TerritoryCode::US),
+    (1622, TerritoryCode::CA),
+    (1623, TerritoryCode::US),
+    (1626, TerritoryCode::US),
+    (1628, TerritoryCode::US),
+    (1629, TerritoryCode::US),
+    (1630, TerritoryCode::US),
+    (1631, TerritoryCode::US),
+    (1633, TerritoryCode::CA),
+    (1636, TerritoryCode::US),
+    (1639, TerritoryCode::CA),
+    (1640, TerritoryCode::US),
+    (1641, TerritoryCode::US),
+    (1645, TerritoryCode::US),
+    (1646, TerritoryCode::US),
+    (1647, TerritoryCode::CA),
+    (1649, TerritoryCode::TC),
+    (1650, TerritoryCode::US),
+    (1651, TerritoryCode::US),
+    (1656, TerritoryCode::US),
+    (1657, TerritoryCode::US),
+    (1658, TerritoryCode::JM),
+    (1659, TerritoryCode::US),
+    (1660, TerritoryCode::US),
+    (1661, TerritoryCode::US),
+    (1662, TerritoryCode::US),
+    (1664, TerritoryCode::MS),
+    (1667, TerritoryCode::US),
+    (1669, TerritoryCode::US),
+    (1670, TerritoryCode::MP),
+    (1671, TerritoryCode::GU),
+    (1672, TerritoryCode::CA),
+    (1678, TerritoryCode::US),
+    (1680, TerritoryCode::US),
+    (1681, TerritoryCode::US),
+    (1682, TerritoryCode::US),
+    (1683, TerritoryCode::CA),
+    (1684, TerritoryCode::AS),
+    (1686, TerritoryCode::US),
+    (1689, TerritoryCode::US),
+    (1700, TerritoryCode::US),
+    (1701, TerritoryCode::US),
+    (1702, TerritoryCode::US),
+    (1703, TerritoryCode::US),
+    (1704, TerritoryCode::US),
+    (1705, TerritoryCode::CA),
+    (1706, TerritoryCode::US),
+    (1707, TerritoryCode::US),
+    (1708, TerritoryCode::US),
+    (1709, TerritoryCode::CA),
+    (1710, TerritoryCode::US),
+    (1712, TerritoryCode::US),
+    (1713, TerritoryCode::US),
+    (1714, TerritoryCode::US),
+    (1715, TerritoryCode::US),
+    (1716, TerritoryCode::US),
+    (1717, TerritoryCode::US),
+    (1718, TerritoryCode::US),
+    (1719, TerritoryCode::US),
+    (1720, TerritoryCode::US),
+    (1721, TerritoryCode::SX),
+    (1724, TerritoryCode::US),
+    (1725, TerritoryCode::US),
+    (1726, TerritoryCode::US),
+    (1727, TerritoryCode::US),
+    (1728, TerritoryCode::US),
+    (1730, TerritoryCode::US),
+    (1731, TerritoryCode::US),
+    (1732, TerritoryCode::US),
+    (1734, TerritoryCode::US),
+    (1737, TerritoryCode::US),
+    (1740, TerritoryCode::US),
+    (1742, TerritoryCode::CA),
+    (1743, TerritoryCode::US),
+    (1747, TerritoryCode::US),
+    (1748, TerritoryCode::US),
+    (1753, TerritoryCode::CA),
+    (1754, TerritoryCode::US),
+    (1757, TerritoryCode::US),
+    (1758, TerritoryCode::LC),
+    (1760, TerritoryCode::US),
+    (1762, TerritoryCode::US),
+    (1763, TerritoryCode::US),
+    (1765, TerritoryCode::US),
+    (1767, TerritoryCode::DM),
+    (1769, TerritoryCode::US),
+    (1770, TerritoryCode::US),
+    (1771, TerritoryCode::US),
+    (1772, TerritoryCode::US),
+    (1773, TerritoryCode::US),
+    (1774, TerritoryCode::US),
+    (1775, TerritoryCode::US),
+    (1778, TerritoryCode::CA),
+    (1779, TerritoryCode::US),
+    (1780, TerritoryCode::CA),
+    (1781, TerritoryCode::US),
+    (1782, TerritoryCode::CA),
+    (1784, TerritoryCode::VC),
+    (1785, TerritoryCode::US),
+    (1786, TerritoryCode::US),
+    (1787, TerritoryCode::PR),
+    (1800, TerritoryCode::US),
+    (1801, TerritoryCode::US),
+    (1802, TerritoryCode::US),
+    (1803, TerritoryCode::US),
+    (1804, TerritoryCode::US),
+    (1805, TerritoryCode::US),
+    (1806, TerritoryCode::US),
+    (1807, TerritoryCode::CA),
+    (1808, TerritoryCode::US),
+    (1809, TerritoryCode::DO),
+    (1810, TerritoryCode::US),
+    (1812, TerritoryCode::US),
+    (1813, TerritoryCode::US),
+    (1814, TerritoryCode::US),
+    (1815, TerritoryCode::US),
+    (1816, TerritoryCode::US),
+    (1817, TerritoryCode::US),
+    (1818, TerritoryCode::US),
+    (1819, TerritoryCode::CA),
+    (1820, TerritoryCode::US),
+    (1821, TerritoryCode::US),
+    (1825, TerritoryCode::CA),
+    (1826, TerritoryCode::US),
+    (1828, TerritoryCode::US),
+    (1829, TerritoryCode::DO),
+    (1830, TerritoryCode::US),
+    (1831, TerritoryCode::US),
+    (1832, TerritoryCode::US),
+    (1833, TerritoryCode::US),
+    (1835, TerritoryCode::US),
+    (1838, TerritoryCode::US),
+    (1839, TerritoryCode::US),
+    (1840, TerritoryCode::US),
+    (1843, TerritoryCode::US),
+    (1844, TerritoryCode::US),
+    (1845, TerritoryCode::US),
+    (1847, TerritoryCode::US),
+    (1848, TerritoryCode::US),
+    (1849, TerritoryCode::DO),
+    (1850, TerritoryCode::US),
+    (1854, TerritoryCode::US),
+    (1855, TerritoryCode::US),
+    (1856, TerritoryCode::US),
+    (1857, TerritoryCode::US),
+    (1858, TerritoryCode::US),
+    (1859, TerritoryCode::US),
+    (1860, TerritoryCode::US),
+    (1862, TerritoryCode::US),
+    (1863, TerritoryCode::US),
+    (1864, TerritoryCode::US),
+    (1865, TerritoryCode::US),
+    (1866, TerritoryCode::US),
+    (1867, TerritoryCode::CA),
+    (1868, TerritoryCode::TT),
+    (1869, TerritoryCode::KN),
+    (1870, TerritoryCode::US),
+    (1872, TerritoryCode::US),
+    (1873, TerritoryCode::CA),
+    (1876, TerritoryCode::JM),
+    (1877, TerritoryCode::US),
+    (1878, TerritoryCode::US),
+    (1879, TerritoryCode::CA),
+    (1888, TerritoryCode::US),
+    (1900, TerritoryCode::US),
+    (1901, TerritoryCode::US),
+    (1902, TerritoryCode::CA),
+    (1903, TerritoryCode::US),
+    (1904, TerritoryCode::US),
+    (1905, TerritoryCode::CA),
+    (1906, TerritoryCode::US),
+    (1907, TerritoryCode::US),
+    (1908, TerritoryCode::US),
+    (1909, TerritoryCode::US),
+    (1910, TerritoryCode::US),
+    (1912, TerritoryCode::US),
+    (1913, TerritoryCode::US),
+    (1914, TerritoryCode::US),
+    (1915, TerritoryCode::US),
+    (1916, TerritoryCode::US),
+    (1917, TerritoryCode::US),
+    (1918, TerritoryCode::US),
+    (1919, TerritoryCode::US),
+    (1920, TerritoryCode::US),
+    (1925, TerritoryCode::US),
+    (1928, TerritoryCode::US),
+    (1929, TerritoryCode::US),
+    (1930, TerritoryCode::US),
+    (1931, TerritoryCode::US),
+    (1934, TerritoryCode::US),
+    (1935, TerritoryCode::US),
+    (1936, TerritoryCode::US),
+    (1937, TerritoryCode::US),
+    (1938, TerritoryCode::US),
+    (1939, TerritoryCode::PR),
+    (1940, TerritoryCode::US),
+    (1941, TerritoryCode::US),
+    (1942, TerritoryCode::CA),
+    (1943, TerritoryCode::US),
+    (1945, TerritoryCode::US),
+    (1947, TerritoryCode::US),
+    (1948, TerritoryCode::US),
+    (1949, TerritoryCode::US),
+    (1951, TerritoryCode::US),
+    (1952, TerritoryCode::US),
+    (1954, TerritoryCode::US),
+    (1956, TerritoryCode::US),
+    (1959, TerritoryCode::US),
+    (1970, TerritoryCode::US),
+    (1971, TerritoryCode::US),
+    (1972, TerritoryCode::US),
+    (1973, TerritoryCode::US),
+    (1975, TerritoryCode::US),
+    (1978, TerritoryCode::US),
+    (1979, TerritoryCode::US),
+    (1980, TerritoryCode::US),
+    (1983, TerritoryCode::US),
+    (1984, TerritoryCode::US),
+    (1985, TerritoryCode::US),
+    (1986, TerritoryCode::US),
+    (1989, TerritoryCode::US),
+    (4779, TerritoryCode::SJ),
+    (5993, TerritoryCode::BQ),
+    (5994, TerritoryCode::BQ),
+    (5997, TerritoryCode::BQ),
+    (6721, TerritoryCode::AQ),
+    (26263, TerritoryCode::YT),
+    (35818, TerritoryCode::AX),
+    (44980, TerritoryCode::GG),
+    (44981, TerritoryCode::GG),
+    (262269, TerritoryCode::YT),
+    (441481, TerritoryCode::GG),
+    (441534, TerritoryCode::JE),
+    (441624, TerritoryCode::IM),
+    (447509, TerritoryCode::JE),
+    (447524, TerritoryCode::IM),
+    (447624, TerritoryCode::IM),
+    (447781, TerritoryCode::GG),
+    (447797, TerritoryCode::JE),
+    (447829, TerritoryCode::JE),
+    (447839, TerritoryCode::GG),
+    (447937, TerritoryCode::JE),
+    (733622, TerritoryCode::KZ),
+    (2627093, TerritoryCode::YT),
+    (2629398, TerritoryCode::YT),
+    (2629478, TerritoryCode::YT),
+    (2629769, TerritoryCode::YT),
+    (3906698, TerritoryCode::VA),
+    (4474576, TerritoryCode::IM),
+    (4477003, TerritoryCode::JE),
+    (4477007, TerritoryCode::JE),
+    (4477008, TerritoryCode::JE),
+    (4479111, TerritoryCode::GG),
+    (4479117, TerritoryCode::GG),
+    (6189162, TerritoryCode::CC),
+    (6189164, TerritoryCode::CX),
+];
+
+#[rustfmt::skip]
+pub(crate) fn national_number_lengths(territory: TerritoryCode) -> RangeInclusive<u32> {
+    match territory {
+        TerritoryCode::US => 10..=10,
+        TerritoryCode::CA => 7..=10,
+        TerritoryCode::AC => 5..=6,
+        TerritoryCode::AD => 6..=9,
+        TerritoryCode::AE => 5..=12,
+        TerritoryCode::AF => 9..=9,
+        TerritoryCode::AG => 10..=10,
+        TerritoryCode::AI => 10..=10,
+        TerritoryCode::AL => 6..=9,
+        TerritoryCode::AM => 8..=8,
+        TerritoryCode::AO => 9..=9,
+        TerritoryCode::AQ => 6..=6,
+        TerritoryCode::AR => 10..=11,
+        TerritoryCode::AS => 10..=10,
+        TerritoryCode::AT => 4..=13,
+        TerritoryCode::AU => 5..=12,
+        TerritoryCode::AW => 7..=7,
+        TerritoryCode::AX => 5..=12,
+        TerritoryCode::AZ => 9..=9,
+        TerritoryCode::BA => 8..=9,
+        TerritoryCode::BB => 10..=10,
+        TerritoryCode::BD => 6..=10,
+        TerritoryCode::BE => 8..=9,
+        TerritoryCode::BF => 8..=8,
+        TerritoryCode::BG => 6..=12,
+        TerritoryCode::BH => 8..=8,
+        TerritoryCode::BI => 8..=8,
+        TerritoryCode::BJ => 8..=10,
+        TerritoryCode::BM => 10..=10,
+        TerritoryCode::BN => 7..=7,
+        TerritoryCode::BO => 8..=9,
+        TerritoryCode::BQ => 7..=7,
+        TerritoryCode::BR => 8..=11,
+        TerritoryCode::BS => 10..=10,
+        TerritoryCode::BT => 7..=8,
+        TerritoryCode::BW => 7..=10,
+        TerritoryCode::BY => 6..=11,
+        TerritoryCode::BZ => 7..=11,
+        TerritoryCode::CC => 6..=12,
+        TerritoryCode::CD => 7..=10,
+        TerritoryCode::CF => 8..=8,
+        TerritoryCode::CG => 9..=9,
+        TerritoryCode::CH => 9..=12,
+        TerritoryCode::CI => 10..=10,
+        TerritoryCode::CK => 5..=5,
+        TerritoryCode::CL => 9..=11,
+        TerritoryCode::CM => 8..=9,
+        TerritoryCode::CN => 7..=12,
+        TerritoryCode::CO => 8..=11,
+        TerritoryCode::CR => 8..=10,
+        TerritoryCode::CU => 6..=10,
+        TerritoryCode::CV => 7..=7,
+        TerritoryCode::CW => 7..=8,
+        TerritoryCode::CX => 6..=12,
+        TerritoryCode::CY => 8..=8,
+        TerritoryCode::CZ => 9..=12,
+        TerritoryCode::DE => 4..=15,
+        TerritoryCode::DJ => 8..=8,
+        TerritoryCode::DK => 8..=8,
+        TerritoryCode::DM => 10..=10,
+        TerritoryCode::DO => 10..=10,
+        TerritoryCode::DZ => 8..=9,
+        TerritoryCode::EC => 8..=11,
+        TerritoryCode::EE => 7..=10,
+        TerritoryCode::EG => 8..=10,
+        TerritoryCode::ER => 7..=7,
+        TerritoryCode::ES => 9..=9,
+        TerritoryCode::ET => 9..=9,
+        TerritoryCode::FI => 5..=12,
+        TerritoryCode::FJ => 7..=11,
+        TerritoryCode::FK => 5..=5,
+        TerritoryCode::FM => 7..=7,
+        TerritoryCode::FO => 6..=6,
+        TerritoryCode::FR => 9..=9,
+        TerritoryCode::GA => 7..=8,
+        TerritoryCode::GB => 7..=10,
+        TerritoryCode::GD => 10..=10,
+        TerritoryCode::GE => 9..=9,
+        TerritoryCode::GF => 9..=9,
+        TerritoryCode::GG => 7..=10,
+        TerritoryCode::GH => 8..=9,
+        TerritoryCode::GI => 8..=8,
+        TerritoryCode::GL => 6..=6,
+        TerritoryCode::GM => 7..=7,
+        TerritoryCode::GN => 8..=9,
+        TerritoryCode::GP => 9..=9,
+        TerritoryCode::GQ => 9..=9,
+        TerritoryCode::GR => 10..=12,
+        TerritoryCode::GT => 8..=11,
+        TerritoryCode::GU => 10..=10,
+        TerritoryCode::GW => 7..=9,
+        TerritoryCode::GY => 7..=7,
+        TerritoryCode::HK => 5..=11,
+        TerritoryCode::HN => 8..=11,
+        TerritoryCode::HR => 7..=9,
+        TerritoryCode::HT => 8..=8,
+        TerritoryCode::HU => 8..=9,
+        TerritoryCode::ID => 7..=17,
+        TerritoryCode::IE => 7..=10,
+        TerritoryCode::IL => 7..=12,
+        TerritoryCode::IM => 10..=10,
+        TerritoryCode::IN => 8..=13,
+        TerritoryCode::IO => 7..=7,
+        TerritoryCode::IQ => 8..=10,
+        TerritoryCode::IR => 4..=10,
+        TerritoryCode::IS => 7..=9,
+        TerritoryCode::IT => 6..=12,
+        TerritoryCode::JE => 10..=10,
+        TerritoryCode::JM => 10..=10,
+        TerritoryCode::JO => 8..=9,
+        TerritoryCode::JP => 8..=17,
+        TerritoryCode::KE => 7..=10,
+        TerritoryCode::KG => 9..=10,
+        TerritoryCode::KH => 8..=10,
+        TerritoryCode::KI => 5..=8,
+        TerritoryCode::KM => 7..=7,
+        TerritoryCode::KN => 10..=10,
+        TerritoryCode::KP => 8..=10,
+        TerritoryCode::KR => 5..=14,
+        TerritoryCode::KW => 7..=8,
+        TerritoryCode::KY => 10..=10,
+        TerritoryCode::KZ => 10..=14,
+        TerritoryCode::LA => 8..=10,
+        TerritoryCode::LB => 7..=8,
+        TerritoryCode::LC => 10..=10,
+        TerritoryCode::LI => 7..=9,
+        TerritoryCode::LK => 9..=9,
+        TerritoryCode::LR => 7..=9,
+        TerritoryCode::LS => 8..=8,
+        TerritoryCode::LT => 8..=8,
+        TerritoryCode::LU => 4..=11,
+        TerritoryCode::LV => 8..=8,
+        TerritoryCode::LY => 9..=9,
+        TerritoryCode::MA => 9..=9,
+        TerritoryCode::MC => 8..=9,
+        TerritoryCode::MD => 8..=8,
+        TerritoryCode::ME => 8..=9,
+        TerritoryCode::MG => 9..=9,
+        TerritoryCode::MH => 7..=7,
+        TerritoryCode::MK => 8..=8,
+        TerritoryCode::ML => 8..=8,
+        TerritoryCode::MM => 6..=10,
+        TerritoryCode::MN => 8..=10,
+        TerritoryCode::MO => 7..=8,
+        TerritoryCode::MP => 10..=10,
+        TerritoryCode::MQ => 9..=9,
+        TerritoryCode::MR => 8..=8,
+        TerritoryCode::MS => 10..=10,
+        TerritoryCode::MT => 8..=8,
+        TerritoryCode::MU => 7..=10,
+        TerritoryCode::MV => 7..=10,
+        TerritoryCode::MW => 7..=9,
+        TerritoryCode::MX => 10..=10,
+        TerritoryCode::MY => 8..=10,
+        TerritoryCode::MZ => 8..=9,
+        TerritoryCode::NA => 8..=9,
+        TerritoryCode::NC => 6..=6,
+        TerritoryCode::NE => 8..=8,
+        TerritoryCode::NF => 6..=6,
+        TerritoryCode::NG => 10..=14,
+        TerritoryCode::NI => 8..=8,
+        TerritoryCode::NL => 5..=11,
+        TerritoryCode::NO => 5..=8,
+        TerritoryCode::NP => 8..=11,
+        TerritoryCode::NR => 7..=7,
+        TerritoryCode::NU => 4..=7,
+        TerritoryCode::NZ => 5..=10,
+        TerritoryCode::OM => 7..=9,
+        TerritoryCode::PA => 7..=11,
+        TerritoryCode::PE => 8..=9,
+        TerritoryCode::PF => 6..=9,
+        TerritoryCode::PG => 7..=8,
+        TerritoryCode::PH => 6..=13,
+        TerritoryCode::PK => 8..=12,
+        TerritoryCode::PL => 6..=10,
+        TerritoryCode::PM => 6..=9,
+        TerritoryCode::PR => 10..=10,
+        TerritoryCode::PS => 8..=10,
+        TerritoryCode::PT => 9..=9,
+        TerritoryCode::PW => 7..=7,
+        TerritoryCode::PY => 6..=11,
+        TerritoryCode::QA => 7..=11,
+        TerritoryCode::RE => 9..=9,
+        TerritoryCode::RO => 6..=9,
+        TerritoryCode::RS => 6..=12,
+        TerritoryCode::RU => 10..=14,
+        TerritoryCode::RW => 8..=9,
+        TerritoryCode::SA => 9..=10,
+        TerritoryCode::SB => 5..=7,
+        TerritoryCode::SC => 7..=7,
+        TerritoryCode::SD => 9..=9,
+        TerritoryCode::SE => 6..=12,
+        TerritoryCode::SG => 8..=11,
+        TerritoryCode::SH => 4..=5,
+        TerritoryCode::SI => 5..=8,
+        TerritoryCode::SJ => 5..=8,
+        TerritoryCode::SK => 6..=9,
+        TerritoryCode::SL => 8..=8,
+        TerritoryCode::SM => 8..=10,
+        TerritoryCode::SN => 9..=9,
+        TerritoryCode::SO => 6..=9,
+        TerritoryCode::SR => 6..=7,
+        TerritoryCode::SS => 9..=9,
+        TerritoryCode::ST => 7..=7,
+        TerritoryCode::SV => 7..=11,
+        TerritoryCode::SX => 10..=10,
+        TerritoryCode::SY => 8..=9,
+        TerritoryCode::SZ => 8..=9,
+        TerritoryCode::TC => 10..=10,
+        TerritoryCode::TD => 8..=8,
+        TerritoryCode::TG => 8..=8,
+        TerritoryCode::TH => 8..=13,
+        TerritoryCode::TJ => 9..=9,
+        TerritoryCode::TK => 4..=7,
+        TerritoryCode::TL => 7..=8,
+        TerritoryCode::TM => 8..=8,
+        TerritoryCode::TN => 8..=8,
+        TerritoryCode::TO => 5..=7,
+        TerritoryCode::TR => 7..=13,
+        TerritoryCode::TT => 10..=10,
+        TerritoryCode::TV => 5..=7,
+        TerritoryCode::TW => 7..=11,
+        TerritoryCode::TZ => 9..=9,
+        TerritoryCode::UA => 9..=10,
+        TerritoryCode::UG => 9..=9,
+        TerritoryCode::UY => 4..=13,
+        TerritoryCode::UZ => 9..=9,
+        TerritoryCode::VA => 6..=12,
+        TerritoryCode::VC => 10..=10,
+        TerritoryCode::VE => 10..=10,
+        TerritoryCode::VG => 10..=10,
+        TerritoryCode::VI => 10..=10,
+        TerritoryCode::VN => 7..=10,
+        TerritoryCode::VU => 5..=7,
+        TerritoryCode::WF => 6..=9,
+        TerritoryCode::WS => 5..=10,
+        TerritoryCode::XG => 9..=10,
+        TerritoryCode::XN => 9..=12,
+        TerritoryCode::XP => 12..=12,
+        TerritoryCode::XS => 8..=8,
+        TerritoryCode::XT => 8..=8,
+        TerritoryCode::XV => 7..=12,
+        TerritoryCode::YE => 7..=9,
+        TerritoryCode::YT => 9..=9,
+        TerritoryCode::ZA => 5..=10,
+        TerritoryCode::ZM => 9..=9,
+        TerritoryCode::ZW => 5..=10,
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn national_prefix(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+        TerritoryCode::US => "1",
+        TerritoryCode::CA => "1",
+        TerritoryCode::AE => "0",
+        TerritoryCode::AF => "0",
+        TerritoryCode::AG => "1",
+        TerritoryCode::AI => "1",
+        TerritoryCode::AL => "0",
+        TerritoryCode::AM => "0",
+        TerritoryCode::AR => "0",
+        TerritoryCode::AS => "1",
+        TerritoryCode::AT => "0",
+        TerritoryCode::AU => "0",
+        TerritoryCode::AX => "0",
+        TerritoryCode::AZ => "0",
+        TerritoryCode::BA => "0",
+        TerritoryCode::BB => "1",
+        TerritoryCode::BD => "0",
+        TerritoryCode::BE => "0",
+        TerritoryCode::BG => "0",
+        TerritoryCode::BM => "1",
+        TerritoryCode::BO => "0",
+        TerritoryCode::BR => "0",
+        TerritoryCode::BS => "1",
+        TerritoryCode::BY => "8",
+        TerritoryCode::CC => "0",
+        TerritoryCode::CD => "0",
+        TerritoryCode::CH => "0",
+        TerritoryCode::CN => "0",
+        TerritoryCode::CO => "0",
+        TerritoryCode::CU => "0",
+        TerritoryCode::CX => "0",
+        TerritoryCode::DE => "0",
+        TerritoryCode::DM => "1",
+        TerritoryCode::DO => "1",
+        TerritoryCode::DZ => "0",
+        TerritoryCode::EC => "0",
+        TerritoryCode::EG => "0",
+        TerritoryCode::ER => "0",
+        TerritoryCode::ET => "0",
+        TerritoryCode::FI => "0",
+        TerritoryCode::FR => "0",
+        TerritoryCode::GB => "0",
+        TerritoryCode::GD => "1",
+        TerritoryCode::GE => "0",
+        TerritoryCode::GF => "0",
+        TerritoryCode::GG => "0",
+        TerritoryCode::GH => "0",
+        TerritoryCode::GP => "0",
+        TerritoryCode::GU => "1",
+        TerritoryCode::HR => "0",
+        TerritoryCode::HU => "06",
+        TerritoryCode::ID => "0",
+        TerritoryCode::IE => "0",
+        TerritoryCode::IL => "0",
+        TerritoryCode::IM => "0",
+        TerritoryCode::IN => "0",
+        TerritoryCode::IQ => "0",
+        TerritoryCode::IR => "0",
+        TerritoryCode::JE => "0",
+        TerritoryCode::JM => "1",
+        TerritoryCode::JO => "0",
+        TerritoryCode::JP => "0",
+        TerritoryCode::KE => "0",
+        TerritoryCode::KG => "0",
+        TerritoryCode::KH => "0",
+        TerritoryCode::KI => "0",
+        TerritoryCode::KN => "1",
+        TerritoryCode::KP => "0",
+        TerritoryCode::KR => "0",
+        TerritoryCode::KY => "1",
+        TerritoryCode::KZ => "8",
+        TerritoryCode::LA => "0",
+        TerritoryCode::LB => "0",
+        TerritoryCode::LC => "1",
+        TerritoryCode::LI => "0",
+        TerritoryCode::LK => "0",
+        TerritoryCode::LR => "0",
+        TerritoryCode::LT => "0",
+        TerritoryCode::LY => "0",
+        TerritoryCode::MA => "0",
+        TerritoryCode::MC => "0",
+        TerritoryCode::MD => "0",
+        TerritoryCode::ME => "0",
+        TerritoryCode::MG => "0",
+        TerritoryCode::MH => "1",
+        TerritoryCode::MK => "0",
+        TerritoryCode::MM => "0",
+        TerritoryCode::MN => "0",
+        TerritoryCode::MP => "1",
+        TerritoryCode::MQ => "0",
+        TerritoryCode::MS => "1",
+        TerritoryCode::MW => "0",
+        TerritoryCode::MY => "0",
+        TerritoryCode::NA => "0",
+        TerritoryCode::NG => "0",
+        TerritoryCode::NL => "0",
+        TerritoryCode::NP => "0",
+        TerritoryCode::NZ => "0",
+        TerritoryCode::PE => "0",
+        TerritoryCode::PH => "0",
+        TerritoryCode::PK => "0",
+        TerritoryCode::PM => "0",
+        TerritoryCode::PR => "1",
+        TerritoryCode::PS => "0",
+        TerritoryCode::PY => "0",
+        TerritoryCode::RE => "0",
+        TerritoryCode::RO => "0",
+        TerritoryCode::RS => "0",
+        TerritoryCode::RU => "8",
+        TerritoryCode::RW => "0",
+        TerritoryCode::SA => "0",
+        TerritoryCode::SD => "0",
+        TerritoryCode::SE => "0",
+        TerritoryCode::SI => "0",
+        TerritoryCode::SK => "0",
+        TerritoryCode::SL => "0",
+        TerritoryCode::SO => "0",
+        TerritoryCode::SS => "0",
+        TerritoryCode::SX => "1",
+        TerritoryCode::SY => "0",
+        TerritoryCode::TC => "1",
+        TerritoryCode::TH => "0",
+        TerritoryCode::TM => "8",
+        TerritoryCode::TR => "0",
+        TerritoryCode::TT => "1",
+        TerritoryCode::TW => "0",
+        TerritoryCode::TZ => "0",
+        TerritoryCode::UA => "0",
+        TerritoryCode::UG => "0",
+        TerritoryCode::UY => "0",
+        TerritoryCode::VC => "1",
+        TerritoryCode::VE => "0",
+        TerritoryCode::VG => "1",
+        TerritoryCode::VI => "1",
+        TerritoryCode::VN => "0",
+        TerritoryCode::YE => "0",
+        TerritoryCode::YT => "0",
+        TerritoryCode::ZA => "0",
+        TerritoryCode::ZM => "0",
+        TerritoryCode::ZW => "0",
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn international_prefix(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+        TerritoryCode::US => "011",
+        TerritoryCode::CA => "011",
+        TerritoryCode::AC => "00",
+        TerritoryCode::AD => "00",
+        TerritoryCode::AE => "00",
+        TerritoryCode::AF => "00",
+        TerritoryCode::AG => "011",
+        TerritoryCode::AI => "011",
+        TerritoryCode::AL => "00",
+        TerritoryCode::AM => "00",
+        TerritoryCode::AO => "00",
+        TerritoryCode::AR => "00",
+        TerritoryCode::AS => "011",
+        TerritoryCode::AT => "00",
+        TerritoryCode::AU => "0011",
+        TerritoryCode::AW => "00",
+        TerritoryCode::AX => "00",
+        TerritoryCode::AZ => "00",
+        TerritoryCode::BA => "00",
+        TerritoryCode::BB => "011",
+        TerritoryCode::BD => "00",
+        TerritoryCode::BE => "00",
+        TerritoryCode::BF => "00",
+        TerritoryCode::BG => "00",
+        TerritoryCode::BH => "00",
+        TerritoryCode::BI => "00",
+        TerritoryCode::BJ => "00",
+        TerritoryCode::BM => "011",
+        TerritoryCode::BN => "00",
+        TerritoryCode::BQ => "00",
+        TerritoryCode::BS => "011",
+        TerritoryCode::BT => "00",
+        TerritoryCode::BW => "00",
+        TerritoryCode::BY => "810",
+        TerritoryCode::BZ => "00",
+        TerritoryCode::CC => "0011",
+        TerritoryCode::CD => "00",
+        TerritoryCode::CF => "00",
+        TerritoryCode::CG => "00",
+        TerritoryCode::CH => "00",
+        TerritoryCode::CI => "00",
+        TerritoryCode::CK => "00",
+        TerritoryCode::CM => "00",
+        TerritoryCode::CN => "00",
+        TerritoryCode::CR => "00",
+        TerritoryCode::CU => "119",
+        TerritoryCode::CV => "0",
+        TerritoryCode::CW => "00",
+        TerritoryCode::CX => "0011",
+        TerritoryCode::CY => "00",
+        TerritoryCode::CZ => "00",
+        TerritoryCode::DE => "00",
+        TerritoryCode::DJ => "00",
+        TerritoryCode::DK => "00",
+        TerritoryCode::DM => "011",
+        TerritoryCode::DO => "011",
+        TerritoryCode::DZ => "00",
+        TerritoryCode::EC => "00",
+        TerritoryCode::EE => "00",
+        TerritoryCode::EG => "00",
+        TerritoryCode::ER => "00",
+        TerritoryCode::ES => "00",
+        TerritoryCode::ET => "00",
+        TerritoryCode::FI => "00",
+        TerritoryCode::FJ => "00",
+        TerritoryCode::FK => "00",
+        TerritoryCode::FM => "00",
+        TerritoryCode::FO => "00",
+        TerritoryCode::FR => "00",
+        TerritoryCode::GA => "00",
+        TerritoryCode::GB => "00",
+        TerritoryCode::GD => "011",
+        TerritoryCode::GE => "00",
+        TerritoryCode::GF => "00",
+        TerritoryCode::GG => "00",
+        TerritoryCode::GH => "00",
+        TerritoryCode::GI => "00",
+        TerritoryCode::GL => "00",
+        TerritoryCode::GM => "00",
+        TerritoryCode::GN => "00",
+        TerritoryCode::GP => "00",
+        TerritoryCode::GQ => "00",
+        TerritoryCode::GR => "00",
+        TerritoryCode::GT => "00",
+        TerritoryCode::GU => "011",
+        TerritoryCode::GW => "00",
+        TerritoryCode::GY => "001",
+        TerritoryCode::HK => "00",
+        TerritoryCode::HN => "00",
+        TerritoryCode::HR => "00",
+        TerritoryCode::HT => "00",
+        TerritoryCode::HU => "00",
+        TerritoryCode::IE => "00",
+        TerritoryCode::IM => "00",
+        TerritoryCode::IN => "00",
+        TerritoryCode::IO => "00",
+        TerritoryCode::IQ => "00",
+        TerritoryCode::IR => "00",
+        TerritoryCode::IS => "00",
+        TerritoryCode::IT => "00",
+        TerritoryCode::JE => "00",
+        TerritoryCode::JM => "011",
+        TerritoryCode::JO => "00",
+        TerritoryCode::JP => "010",
+        TerritoryCode::KE => "000",
+        TerritoryCode::KG => "00",
+        TerritoryCode::KI => "00",
+        TerritoryCode::KM => "00",
+        TerritoryCode::KN => "011",
+        TerritoryCode::KW => "00",
+        TerritoryCode::KY => "011",
+        TerritoryCode::KZ => "810",
+        TerritoryCode::LA => "00",
+        TerritoryCode::LB => "00",
+        TerritoryCode::LC => "011",
+        TerritoryCode::LI => "00",
+        TerritoryCode::LK => "00",
+        TerritoryCode::LR => "00",
+        TerritoryCode::LS => "00",
+        TerritoryCode::LT => "00",
+        TerritoryCode::LU => "00",
+        TerritoryCode::LV => "00",
+        TerritoryCode::LY => "00",
+        TerritoryCode::MA => "00",
+        TerritoryCode::MC => "00",
+        TerritoryCode::MD => "00",
+        TerritoryCode::ME => "00",
+        TerritoryCode::MG => "00",
+        TerritoryCode::MH => "011",
+        TerritoryCode::MK => "00",
+        TerritoryCode::ML => "00",
+        TerritoryCode::MM => "00",
+        TerritoryCode::MN => "001",
+        TerritoryCode::MO => "00",
+        TerritoryCode::MP => "011",
+        TerritoryCode::MQ => "00",
+        TerritoryCode::MR => "00",
+        TerritoryCode::MS => "011",
+        TerritoryCode::MT => "00",
+        TerritoryCode::MU => "020",
+        TerritoryCode::MV => "00",
+        TerritoryCode::MW => "00",
+        TerritoryCode::MX => "00",
+        TerritoryCode::MY => "00",
+        TerritoryCode::MZ => "00",
+        TerritoryCode::NA => "00",
+        TerritoryCode::NC => "00",
+        TerritoryCode::NE => "00",
+        TerritoryCode::NF => "00",
+        TerritoryCode::NG => "009",
+        TerritoryCode::NI => "00",
+        TerritoryCode::NL => "00",
+        TerritoryCode::NO => "00",
+        TerritoryCode::NP => "00",
+        TerritoryCode::NR => "00",
+        TerritoryCode::NU => "00",
+        TerritoryCode::NZ => "00",
+        TerritoryCode::OM => "00",
+        TerritoryCode::PA => "00",
+        TerritoryCode::PE => "00",
+        TerritoryCode::PF => "00",
+        TerritoryCode::PG => "00",
+        TerritoryCode::PH => "00",
+        TerritoryCode::PK => "00",
+        TerritoryCode::PL => "00",
+        TerritoryCode::PM => "00",
+        TerritoryCode::PR => "011",
+        TerritoryCode::PS => "00",
+        TerritoryCode::PT => "00",
+        TerritoryCode::PY => "00",
+        TerritoryCode::QA => "00",
+        TerritoryCode::RE => "00",
+        TerritoryCode::RO => "00",
+        TerritoryCode::RS => "00",
+        TerritoryCode::RU => "810",
+        TerritoryCode::RW => "00",
+        TerritoryCode::SA => "00",
+        TerritoryCode::SC => "00",
+        TerritoryCode::SD => "00",
+        TerritoryCode::SE => "00",
+        TerritoryCode::SH => "00",
+        TerritoryCode::SI => "00",
+        TerritoryCode::SJ => "00",
+        TerritoryCode::SK => "00",
+        TerritoryCode::SL => "00",
+        TerritoryCode::SM => "00",
+        TerritoryCode::SN => "00",
+        TerritoryCode::SO => "00",
+        TerritoryCode::SR => "00",
+        TerritoryCode::SS => "00",
+        TerritoryCode::ST => "00",
+        TerritoryCode::SV => "00",
+        TerritoryCode::SX => "011",
+        TerritoryCode::SY => "00",
+        TerritoryCode::SZ => "00",
+        TerritoryCode::TC => "011",
+        TerritoryCode::TD => "00",
+        TerritoryCode::TG => "00",
+        TerritoryCode::TJ => "810",
+        TerritoryCode::TK => "00",
+        TerritoryCode::TL => "00",
+        TerritoryCode::TM => "810",
+        TerritoryCode::TN => "00",
+        TerritoryCode::TO => "00",
+        TerritoryCode::TR => "00",
+        TerritoryCode::TT => "011",
+        TerritoryCode::TV => "00",
+        TerritoryCode::UA => "00",
+        TerritoryCode::UY => "00",
+        TerritoryCode::UZ => "00",
+        TerritoryCode::VA => "00",
+        TerritoryCode::VC => "011",
+        TerritoryCode::VE => "00",
+        TerritoryCode::VG => "011",
+        TerritoryCode::VI => "011",
+        TerritoryCode::VN => "00",
+        TerritoryCode::VU => "00",
+        TerritoryCode::WF => "00",
+        TerritoryCode::WS => "0",
+        TerritoryCode::YE => "00",
+        TerritoryCode::YT => "00",
+        TerritoryCode::ZA => "00",
+        TerritoryCode::ZM => "00",
+        TerritoryCode::ZW => "00",
+        _ => return None,
+    })
+}
+
+/// The pattern of all the international prefixes accepted in a territory
+#[rustfmt::skip]
+pub(crate) fn international_prefix_pattern(territory: TerritoryCode) -> Option<&'static str> {
+    match territory {
+        TerritoryCode::AU => Some(r"001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"),
+        TerritoryCode::AX => Some(r"00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))"),
+        TerritoryCode::BO => Some(r"00(?:1\d)?"),
+        TerritoryCode::BR => Some(r"00(?:1[245]|2[1-35]|31|4[13]|[56]5|99)"),
+        TerritoryCode::CC => Some(r"001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"),
+        TerritoryCode::CL => Some(r"(?:0|1(?:1[0-69]|2[02-5]|5[13-58]|69|7[0167]|8[018]))0"),
+        TerritoryCode::CN => Some(r"00|1(?:[12]\d|79)\d\d00"),
+        TerritoryCode::CO => Some(r"00(?:4(?:[14]4|56)|[579])"),
+        TerritoryCode::CX => Some(r"001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"),
+        TerritoryCode::FI => Some(r"00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))"),
+        TerritoryCode::FJ => Some(r"0(?:0|52)"),
+        TerritoryCode::HK => Some(r"00(?:30|5[09]|[126-9]?)"),
+        TerritoryCode::ID => Some(r"00[89]"),
+        TerritoryCode::IL => Some(r"0(?:0|1[2-9])"),
+        TerritoryCode::IS => Some(r"00|1(?:0(?:01|[12]0)|100)"),
+        TerritoryCode::KH => Some(r"00[14-9]"),
+        TerritoryCode::KP => Some(r"00|99"),
+        TerritoryCode::KR => Some(r"00(?:[125689]|3(?:[46]5|91)|7(?:00|27|3|55|6[126]))"),
+        TerritoryCode::MU => Some(r"0(?:0|[24-7]0|3[03])"),
+        TerritoryCode::MV => Some(r"0(?:0|19)"),
+        TerritoryCode::MX => Some(r"0[09]"),
+        TerritoryCode::NZ => Some(r"0(?:0|161)"),
+        TerritoryCode::PE => Some(r"00|19(?:1[124]|77|90)00"),
+        TerritoryCode::PG => Some(r"00|140[1-3]"),
+        TerritoryCode::PW => Some(r"01[12]"),
+        TerritoryCode::SB => Some(r"0[01]"),
+        TerritoryCode::SC => Some(r"010|0[0-2]"),
+        TerritoryCode::SG => Some(r"0[0-3]\d"),
+        TerritoryCode::SI => Some(r"00|10(?:22|66|88|99)"),
+        TerritoryCode::TD => Some(r"00|16"),
+        TerritoryCode::TH => Some(r"00[1-9]"),
+        TerritoryCode::TW => Some(r"0(?:0[25-79]|19)"),
+        TerritoryCode::TZ => Some(r"00[056]"),
+        TerritoryCode::UG => Some(r"00[057]"),
+        TerritoryCode::UY => Some(r"0(?:0|1[3-9]\d)"),
+        _ => international_prefix(territory),
+    }
+}
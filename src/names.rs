@@ -0,0 +1,227 @@
+use std::sync::OnceLock;
+
+use crate::{territories, TerritoryCode};
+
+/// The normalized short and official names of the territories, in the order of their codes,
+/// normalized on first use
+static NORMALIZED_NAMES: OnceLock<Vec<(String, String)>> = OnceLock::new();
+
+impl TerritoryCode {
+    /// Gets the English short name of this territory, suitable for display. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!("United Kingdom", TerritoryCode::GB.english_name());
+    /// assert_eq!("International Freephone", TerritoryCode::XT.english_name());
+    /// ```
+    pub fn english_name(&self) -> &'static str {
+        territories::english_name(*self)
+    }
+
+    /// Gets the English official name of this territory. Territories without a distinct official
+    /// name yield their short name, like [`TerritoryCode::english_name`].
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(
+    ///     "United Kingdom of Great Britain and Northern Ireland",
+    ///     TerritoryCode::GB.official_name()
+    /// );
+    /// assert_eq!("Canada", TerritoryCode::CA.official_name());
+    /// ```
+    pub fn official_name(&self) -> &'static str {
+        territories::official_name(*self)
+    }
+
+    /// Yields a territory code by English name, ignoring case. Short and official names are
+    /// recognized, as well as the ISO 3166-1 alpha-2 and alpha-3 codes often written in their
+    /// place, and aliases. An alias is another English name of the whole territory: a former,
+    /// colloquial or alternative name, or an abbreviation of a name, with or without dots. Parts
+    /// of a territory, like England or Great Britain, are not aliases of it. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(Some(TerritoryCode::GB), TerritoryCode::from_english_name("UK"));
+    /// assert_eq!(Some(TerritoryCode::GB), TerritoryCode::from_english_name("GBR"));
+    /// assert_eq!(Some(TerritoryCode::CI), TerritoryCode::from_english_name("Ivory Coast"));
+    /// assert_eq!(Some(TerritoryCode::KN), TerritoryCode::from_english_name("St. Kitts & Nevis"));
+    /// assert_eq!(None, TerritoryCode::from_english_name("Scotland"));
+    /// ```
+    pub fn from_english_name(name: &str) -> Option<Self> {
+        let code = name.trim();
+        let name = normalize_english_name(name);
+        let names = NORMALIZED_NAMES.get_or_init(|| {
+            Self::iter()
+                .map(|territory| {
+                    (
+                        normalize_english_name(territory.english_name()),
+                        normalize_english_name(territory.official_name()),
+                    )
+                })
+                .collect()
+        });
+        Self::iter()
+            .zip(names)
+            .find(|(_, (english_name, official_name))| {
+                *english_name == name || *official_name == name
+            })
+            .map(|(territory, _)| territory)
+            .or_else(|| Self::from_code(code))
+            .or_else(|| Self::from_english_alias(&name))
+    }
+
+    /// A territory by ISO 3166-1 alpha-2 or alpha-3 code, ignoring case
+    fn from_code(code: &str) -> Option<Self> {
+        let code = code.to_ascii_uppercase();
+        match code.len() {
+            2 => Self::from_name(&code),
+            3 => Self::from_alpha3(&code),
+            _ => None,
+        }
+    }
+
+    /// The aliases of the territories, already normalized. The ISO 3166-1 codes are not repeated
+    /// here.
+    fn from_english_alias(name: &str) -> Option<Self> {
+        Some(match name {
+            "uk" | "u.k." | "britain" => Self::GB,
+            "u.s.a." | "u.s." => Self::US,
+            "ivory coast" | "cote d'ivoire" => Self::CI,
+            "drc" | "dr congo" | "congo-kinshasa" | "congo - kinshasa" | "zaire" => Self::CD,
+            "congo" | "congo-brazzaville" | "congo - brazzaville" => Self::CG,
+            "burma" | "myanmar (burma)" => Self::MM,
+            "holland" => Self::NL,
+            "swaziland" => Self::SZ,
+            "macedonia" => Self::MK,
+            "turkey" | "turkiye" => Self::TR,
+            "cabo verde" => Self::CV,
+            "east timor" => Self::TL,
+            "vatican" => Self::VA,
+            "viet nam" => Self::VN,
+            "lao pdr" => Self::LA,
+            "hong kong sar china" => Self::HK,
+            "macau" | "macao sar china" => Self::MO,
+            "palestinian territories" => Self::PS,
+            "uae" => Self::AE,
+            "curacao" => Self::CW,
+            "reunion" => Self::RE,
+            "sao tome and principe" => Self::ST,
+            "aland islands" => Self::AX,
+            "bosnia" => Self::BA,
+            "us virgin islands" | "united states virgin islands" => Self::VI,
+            _ => return None,
+        })
+    }
+}
+
+/// Normalizes an English territory name for comparison: lowercase, without a leading "the" and
+/// with a single spelling for whitespace, apostrophes, ampersands and "Saint"
+fn normalize_english_name(name: &str) -> String {
+    let name = name.to_lowercase().replace('’', "'");
+    let mut words = name.split_whitespace().peekable();
+    if words.peek() == Some(&"the") {
+        words.next();
+    }
+    let words: Vec<_> = words
+        .map(|word| match word {
+            "&" => "and",
+            "st." | "st" => "saint",
+            word => word,
+        })
+        .collect();
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_names_round_trip() {
+        for territory in TerritoryCode::iter() {
+            let english_name = territory.english_name();
+            assert_eq!(
+                Some(territory),
+                TerritoryCode::from_english_name(english_name)
+            );
+            let official_name = territory.official_name();
+            assert_eq!(
+                Some(territory),
+                TerritoryCode::from_english_name(official_name)
+            );
+        }
+    }
+
+    #[test]
+    fn from_english_name_aliases() {
+        for (name, territory) in [
+            ("  united   kingdom ", TerritoryCode::GB),
+            ("U.K.", TerritoryCode::GB),
+            ("USA", TerritoryCode::US),
+            ("United States of America", TerritoryCode::US),
+            ("Ivory Coast", TerritoryCode::CI),
+            ("Côte d’Ivoire", TerritoryCode::CI),
+            ("Cote d'Ivoire", TerritoryCode::CI),
+            ("DRC", TerritoryCode::CD),
+            ("The Netherlands", TerritoryCode::NL),
+            ("Bosnia & Herzegovina", TerritoryCode::BA),
+            ("Saint Helena", TerritoryCode::SH),
+            ("the gambia", TerritoryCode::GM),
+        ] {
+            assert_eq!(
+                Some(territory),
+                TerritoryCode::from_english_name(name),
+                "{name}"
+            );
+        }
+        assert_eq!(None, TerritoryCode::from_english_name("Atlantis"));
+        for part in [
+            "England",
+            "Scotland",
+            "Wales",
+            "Northern Ireland",
+            "Great Britain",
+        ] {
+            assert_eq!(None, TerritoryCode::from_english_name(part), "{part}");
+        }
+        assert_eq!(None, TerritoryCode::from_english_name("America"));
+    }
+
+    #[test]
+    fn from_english_name_codes() {
+        for territory in TerritoryCode::iter() {
+            let code = territory.name().to_lowercase();
+            assert_eq!(Some(territory), TerritoryCode::from_english_name(&code));
+            if let Some(alpha3) = territory.alpha3() {
+                assert_eq!(Some(territory), TerritoryCode::from_english_name(alpha3));
+            }
+        }
+        for (name, territory) in [
+            ("GB", TerritoryCode::GB),
+            ("us", TerritoryCode::US),
+            ("USA", TerritoryCode::US),
+            ("U.S.A.", TerritoryCode::US),
+            ("UK", TerritoryCode::GB),
+            ("UAE", TerritoryCode::AE),
+            ("ARE", TerritoryCode::AE),
+        ] {
+            assert_eq!(
+                Some(territory),
+                TerritoryCode::from_english_name(name),
+                "{name}"
+            );
+        }
+        assert_eq!(None, TerritoryCode::from_english_name("GBX"));
+    }
+
+    #[test]
+    fn aliases_are_not_codes() {
+        for territory in TerritoryCode::iter() {
+            for name in [territory.english_name(), territory.official_name()] {
+                let name = normalize_english_name(name);
+                let code = TerritoryCode::from_code(&name);
+                assert!(code.is_none() || code == Some(territory), "{}", name);
+            }
+        }
+        for code in TerritoryCode::iter().map(|territory| territory.name().to_lowercase()) {
+            assert_eq!(None, TerritoryCode::from_english_alias(&code), "{code}");
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{FromPhoneError, PhoneNumber, SubdivisionCode, TerritoryCode};
+
+mod data;
+
+///
+/// The status of an area code of the North American Numbering Plan, according to the NANPA
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AreaCodeStatus {
+    /// A geographic area code in service
+    InService,
+    /// A geographic area code assigned to relieve an area, which is not in service yet
+    Planned,
+    /// An area code set aside for the expansion of the numbering plan, like the `N9X` codes
+    Reserved,
+    /// A toll-free service code, like 800 or 888
+    TollFree,
+    /// The 900 premium rate service code
+    PremiumRate,
+    /// A personal communications service code, like 500 or 533
+    Personal,
+    /// Another non-geographic service code, like 600 in Canada or 710 for the US government
+    NonGeographic,
+}
+
+///
+/// An area code, or numbering plan area (NPA), of the North American Numbering Plan: the three
+/// digits after the calling code 1, shared by the United States, Canada and most of the Caribbean.
+///
+/// ```
+/// use e164_phones_countries::{AreaCodeStatus, NanpAreaCode, TerritoryCode};
+/// let area_code = NanpAreaCode::from_npa(205).unwrap();
+/// assert_eq!(Some(TerritoryCode::US), area_code.territory());
+/// assert_eq!(&["AL"], area_code.regions());
+/// assert_eq!(Some(1947), area_code.in_service_year());
+/// assert_eq!(AreaCodeStatus::InService, area_code.status());
+/// let overlay: Vec<u16> = area_code.overlay().map(|code| code.npa()).collect();
+/// assert_eq!(vec![205, 659], overlay);
+/// ```
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NanpAreaCode {
+    npa: u16,
+    territory: Option<TerritoryCode>,
+    regions: &'static [&'static str],
+    /// The oldest area codes of the overlay complexes
+    overlays: &'static [u16],
+    in_service: Option<u16>,
+    status: AreaCodeStatus,
+}
+
+impl NanpAreaCode {
+    /// Yields an area code known to the NANPA, whatever its status. I.e.:
+    /// ```
+    /// use e164_phones_countries::{AreaCodeStatus, NanpAreaCode};
+    /// assert_eq!(AreaCodeStatus::TollFree, NanpAreaCode::from_npa(888).unwrap().status());
+    /// assert_eq!(AreaCodeStatus::Reserved, NanpAreaCode::from_npa(999).unwrap().status());
+    /// assert_eq!(None, NanpAreaCode::from_npa(222));
+    /// ```
+    pub fn from_npa(npa: u16) -> Option<Self> {
+        let index = data::AREA_CODES.binary_search_by_key(&npa, |area_code| area_code.npa);
+        index.ok().map(|index| data::AREA_CODES[index])
+    }
+
+    /// Iterates over all the area codes known to the NANPA, in ascending order
+    pub fn iter() -> impl Iterator<Item = Self> {
+        data::AREA_CODES.iter().copied()
+    }
+
+    /// The three digits of this area code
+    pub fn npa(&self) -> u16 {
+        self.npa
+    }
+
+    /// The territory of the phone numbers of this area code. Non-geographic service codes belong
+    /// to the territory libphonenumber assigns them, e.g. [`TerritoryCode::US`] for toll-free
+    /// numbers, and reserved area codes to none.
+    pub fn territory(&self) -> Option<TerritoryCode> {
+        self.territory
+    }
+
+    /// The US states or Canadian provinces and territories served by this area code, as their
+    /// postal abbreviations, e.g. `NY` or `QC`. There are several for `902` (`NS` and `PE`) and
+    /// `867` (`YT`, `NT` and `NU`), and none outside of the United States and Canada or for
+    /// non-geographic area codes.
+    pub fn regions(&self) -> &'static [&'static str] {
+        self.regions
+    }
+
+    /// Iterates over the ISO 3166-2 subdivisions served by this area code, i.e. its
+    /// [`NanpAreaCode::regions`] within its territory
+    /// ```
+    /// use e164_phones_countries::NanpAreaCode;
+    /// let maritimes: Vec<_> = NanpAreaCode::from_npa(902).unwrap().subdivisions().collect();
+    /// assert_eq!("CA-NS", maritimes[0].code());
+    /// assert_eq!("CA-PE", maritimes[1].code());
+    /// ```
+    pub fn subdivisions(&self) -> impl Iterator<Item = SubdivisionCode> {
+        let territory = self.territory;
+        self.regions.iter().filter_map(move |region| {
+            territory.and_then(|territory| SubdivisionCode::find(territory, region))
+        })
+    }
+
+    /// Iterates over the area codes serving the same area as this one, or part of it, including
+    /// this one, in ascending order. Overlay area codes are added to an area instead of splitting
+    /// it, and some are added to several areas, like 917 in all of New York City.
+    /// ```
+    /// use e164_phones_countries::NanpAreaCode;
+    /// let manhattan = NanpAreaCode::from_npa(646).unwrap();
+    /// let overlay: Vec<u16> = manhattan.overlay().map(|code| code.npa()).collect();
+    /// assert_eq!(vec![212, 332, 646, 917], overlay);
+    /// let new_york_city = NanpAreaCode::from_npa(917).unwrap();
+    /// let overlay: Vec<u16> = new_york_city.overlay().map(|code| code.npa()).collect();
+    /// assert_eq!(vec![212, 332, 347, 646, 718, 917, 929], overlay);
+    /// assert_eq!(1, NanpAreaCode::from_npa(907).unwrap().overlay().count());
+    /// ```
+    pub fn overlay(&self) -> impl Iterator<Item = Self> {
+        let this = *self;
+        Self::iter().filter(move |area_code| {
+            area_code.npa == this.npa
+                || area_code
+                    .overlays
+                    .iter()
+                    .any(|overlay| this.overlays.contains(overlay))
+        })
+    }
+
+    /// The year this area code entered service, when known. There is none for area codes which
+    /// are not in service yet.
+    pub fn in_service_year(&self) -> Option<u16> {
+        self.in_service
+    }
+
+    /// The status of this area code
+    pub fn status(&self) -> AreaCodeStatus {
+        self.status
+    }
+
+    /// Tells whether this area code serves a geographic area, even if it is not in service yet
+    pub fn is_geographic(&self) -> bool {
+        matches!(
+            self.status,
+            AreaCodeStatus::InService | AreaCodeStatus::Planned
+        )
+    }
+}
+
+impl Display for NanpAreaCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.npa)
+    }
+}
+
+impl PhoneNumber {
+    /// Gets the area code of this phone number, when it belongs to the North American Numbering
+    /// Plan
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+1 (212) 555-0123".parse().unwrap();
+    /// assert_eq!(212, phone.nanp_area_code().unwrap().npa());
+    /// let phone: PhoneNumber = "+44 20 7946 0958".parse().unwrap();
+    /// assert_eq!(None, phone.nanp_area_code());
+    /// ```
+    pub fn nanp_area_code(&self) -> Option<NanpAreaCode> {
+        if self.calling_code().get() != 1 || self.national_number_len() != 10 {
+            return None;
+        }
+        NanpAreaCode::from_npa((self.national_number() / 10u64.pow(7)) as u16)
+    }
+
+    /// Gets the ISO 3166-2 subdivision of this phone number, i.e. the US state or the Canadian
+    /// province or territory of its area code. There is none for other phone numbers, and for area
+    /// codes serving several subdivisions, like 867 in the Canadian territories.
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+1 205 555 0123".parse().unwrap();
+    /// assert_eq!("US-AL", phone.subdivision().unwrap().code());
+    /// let phone: PhoneNumber = "+1 800 555 0123".parse().unwrap();
+    /// assert_eq!(None, phone.subdivision());
+    /// ```
+    pub fn subdivision(&self) -> Option<SubdivisionCode> {
+        single_subdivision(self.nanp_area_code()?)
+    }
+}
+
+impl TerritoryCode {
+    /// Attempts to find the territory code from a phone number like
+    /// [`TerritoryCode::from_phone_number`], along with the ISO 3166-2 subdivision of the phone
+    /// number when it can be told from its area code, as [`PhoneNumber::subdivision`] does. I.e.:
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// let (territory, subdivision) = TerritoryCode::from_phone_number_with_subdivision(12045550123).unwrap();
+    /// assert_eq!(TerritoryCode::CA, territory);
+    /// assert_eq!("CA-MB", subdivision.unwrap().code());
+    /// let (territory, subdivision) = TerritoryCode::from_phone_number_with_subdivision(33123456789).unwrap();
+    /// assert_eq!(TerritoryCode::FR, territory);
+    /// assert_eq!(None, subdivision);
+    /// ```
+    pub fn from_phone_number_with_subdivision(
+        phone: u64,
+    ) -> Result<(Self, Option<SubdivisionCode>), FromPhoneError> {
+        let territory = Self::from_phone_number(phone)?;
+        // Only the 10 digits after the calling code 1 make a full NANP number
+        let subdivision = match phone {
+            10_000_000_000..=19_999_999_999 => {
+                let npa = (phone / 10u64.pow(7)) % 1000;
+                NanpAreaCode::from_npa(npa as u16).and_then(single_subdivision)
+            }
+            _ => None,
+        };
+        Ok((territory, subdivision))
+    }
+}
+
+/// The subdivision of an area code, unless it serves several ones
+fn single_subdivision(area_code: NanpAreaCode) -> Option<SubdivisionCode> {
+    let mut subdivisions = area_code.subdivisions();
+    match (subdivisions.next(), subdivisions.next()) {
+        (Some(subdivision), None) => Some(subdivision),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata;
+
+    #[test]
+    fn area_codes_agree_with_lookup_table() {
+        for (prefix, territory) in metadata::LOOKUP_TABLE.iter().copied() {
+            if !(1000..2000).contains(&prefix) {
+                continue;
+            }
+            if let Some(area_code) = NanpAreaCode::from_npa((prefix % 1000) as u16) {
+                assert_eq!(Some(territory), area_code.territory(), "{}", prefix);
+            }
+        }
+        for area_code in NanpAreaCode::iter() {
+            assert_eq!(area_code.regions().len(), area_code.subdivisions().count());
+            for overlay in area_code.overlay() {
+                assert_eq!(area_code.territory(), overlay.territory(), "{}", area_code);
+                assert!(overlay.overlay().any(|code| code == area_code));
+            }
+        }
+    }
+
+    #[test]
+    fn find_territory_of_nanp_numbers() {
+        for (phone, territory) in [
+            (16455550123, TerritoryCode::US),
+            (18395550123, TerritoryCode::US),
+            (14685550123, TerritoryCode::CA),
+            (18765550123, TerritoryCode::JM),
+            (18005550123, TerritoryCode::US),
+        ] {
+            assert_eq!(Ok(territory), TerritoryCode::from_phone_number(phone));
+        }
+        assert_eq!(
+            Err(crate::FromPhoneError::UnassignedNanpAreaCode(370)),
+            TerritoryCode::from_phone_number(13705550123)
+        );
+    }
+}
@@ -0,0 +1,579 @@
+// Generated by `cargo run -p metadata-gen` from `data/nanp_area_codes.csv`. Do not edit.
+
+use super::AreaCodeStatus::*;
+use super::NanpAreaCode;
+use crate::TerritoryCode;
+
+/// All the area codes, sorted
+#[rustfmt::skip]
+pub(super) static AREA_CODES: [NanpAreaCode; 569] = [
+    NanpAreaCode { npa: 201, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[201], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 202, territory: Some(TerritoryCode::US), regions: &["DC"], overlays: &[202], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 203, territory: Some(TerritoryCode::US), regions: &["CT"], overlays: &[203], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 204, territory: Some(TerritoryCode::CA), regions: &["MB"], overlays: &[204], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 205, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[205], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 206, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 207, territory: Some(TerritoryCode::US), regions: &["ME"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 208, territory: Some(TerritoryCode::US), regions: &["ID"], overlays: &[208], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 209, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[209], in_service: Some(1958), status: InService },
+    NanpAreaCode { npa: 210, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[210], in_service: Some(1992), status: InService },
+    NanpAreaCode { npa: 212, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[212], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 213, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[213], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 214, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[214], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 215, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[215], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 216, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 217, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[217], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 218, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 219, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[], in_service: Some(1948), status: InService },
+    NanpAreaCode { npa: 220, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[740], in_service: Some(2015), status: InService },
+    NanpAreaCode { npa: 223, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[717], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 224, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[847], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 225, territory: Some(TerritoryCode::US), regions: &["LA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 226, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[519], in_service: Some(2006), status: InService },
+    NanpAreaCode { npa: 227, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[301], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 228, territory: Some(TerritoryCode::US), regions: &["MS"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 229, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 231, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 234, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[330], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 235, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[573], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 236, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 239, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 240, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[301], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 242, territory: Some(TerritoryCode::BS), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 246, territory: Some(TerritoryCode::BB), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 248, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[248], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 249, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[705], in_service: Some(2011), status: InService },
+    NanpAreaCode { npa: 250, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 251, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 252, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 253, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 254, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 256, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[256], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 257, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 260, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 262, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 263, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[514], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 264, territory: Some(TerritoryCode::AI), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 267, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[215], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 268, territory: Some(TerritoryCode::AG), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 269, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 270, territory: Some(TerritoryCode::US), regions: &["KY"], overlays: &[270], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 272, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[570], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 274, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[920], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 276, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 279, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[916], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 281, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[713], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 283, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[513], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 284, territory: Some(TerritoryCode::VG), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 289, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[905], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 290, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 291, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 292, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 293, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 294, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 295, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 296, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 297, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 298, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 299, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 301, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[301], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 302, territory: Some(TerritoryCode::US), regions: &["DE"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 303, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[303], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 304, territory: Some(TerritoryCode::US), regions: &["WV"], overlays: &[304], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 305, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[305], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 306, territory: Some(TerritoryCode::CA), regions: &["SK"], overlays: &[306], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 307, territory: Some(TerritoryCode::US), regions: &["WY"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 308, territory: Some(TerritoryCode::US), regions: &["NE"], overlays: &[], in_service: Some(1955), status: InService },
+    NanpAreaCode { npa: 309, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 310, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[310], in_service: Some(1991), status: InService },
+    NanpAreaCode { npa: 312, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[312], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 313, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 314, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[314], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 315, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[315], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 316, territory: Some(TerritoryCode::US), regions: &["KS"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 317, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[317], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 318, territory: Some(TerritoryCode::US), regions: &["LA"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 319, territory: Some(TerritoryCode::US), regions: &["IA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 320, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 321, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[407], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 323, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[213], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 324, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[904], in_service: Some(2024), status: InService },
+    NanpAreaCode { npa: 325, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(2003), status: InService },
+    NanpAreaCode { npa: 326, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[937], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 327, territory: Some(TerritoryCode::US), regions: &["AR"], overlays: &[870], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 329, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[845], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 330, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[330], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 331, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[630], in_service: Some(2007), status: InService },
+    NanpAreaCode { npa: 332, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[212], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 334, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 336, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[336], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 337, territory: Some(TerritoryCode::US), regions: &["LA"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 339, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[781], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 340, territory: Some(TerritoryCode::VI), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 341, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[510], in_service: None, status: Planned },
+    NanpAreaCode { npa: 343, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[613], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 345, territory: Some(TerritoryCode::KY), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 346, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[713], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 347, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[718], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 350, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[209], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 351, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[978], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 352, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 353, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[608], in_service: Some(2024), status: InService },
+    NanpAreaCode { npa: 354, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[450], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 360, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[360], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 361, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 363, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[516], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 364, territory: Some(TerritoryCode::US), regions: &["KY"], overlays: &[270], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 365, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[905], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 367, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[418], in_service: Some(2020), status: InService },
+    NanpAreaCode { npa: 368, territory: Some(TerritoryCode::CA), regions: &["AB"], overlays: &[403], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 369, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[707], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 370, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 371, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 372, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 373, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 374, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 375, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 376, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 377, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 378, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 379, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 380, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[614], in_service: Some(2020), status: InService },
+    NanpAreaCode { npa: 382, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[519], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 385, territory: Some(TerritoryCode::US), regions: &["UT"], overlays: &[801], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 386, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 390, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 391, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 392, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 393, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 394, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 395, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 396, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 397, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 398, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 399, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 401, territory: Some(TerritoryCode::US), regions: &["RI"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 402, territory: Some(TerritoryCode::US), regions: &["NE"], overlays: &[402], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 403, territory: Some(TerritoryCode::CA), regions: &["AB"], overlays: &[403], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 404, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[404], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 405, territory: Some(TerritoryCode::US), regions: &["OK"], overlays: &[405], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 406, territory: Some(TerritoryCode::US), regions: &["MT"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 407, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[407], in_service: Some(1988), status: InService },
+    NanpAreaCode { npa: 408, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[408], in_service: Some(1959), status: InService },
+    NanpAreaCode { npa: 409, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1983), status: InService },
+    NanpAreaCode { npa: 410, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[410], in_service: Some(1991), status: InService },
+    NanpAreaCode { npa: 412, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[412], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 413, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 414, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 415, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[415], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 416, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[416], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 417, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[], in_service: Some(1950), status: InService },
+    NanpAreaCode { npa: 418, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[418], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 419, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[419], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 423, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 424, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[310], in_service: Some(2006), status: InService },
+    NanpAreaCode { npa: 425, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 428, territory: Some(TerritoryCode::CA), regions: &["NB"], overlays: &[506], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 430, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[903], in_service: Some(2003), status: InService },
+    NanpAreaCode { npa: 431, territory: Some(TerritoryCode::CA), regions: &["MB"], overlays: &[204], in_service: Some(2012), status: InService },
+    NanpAreaCode { npa: 432, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(2003), status: InService },
+    NanpAreaCode { npa: 434, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 435, territory: Some(TerritoryCode::US), regions: &["UT"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 437, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[416], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 438, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[514], in_service: Some(2006), status: InService },
+    NanpAreaCode { npa: 440, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 441, territory: Some(TerritoryCode::BM), regions: &[], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 442, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[760], in_service: Some(2008), status: InService },
+    NanpAreaCode { npa: 443, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[410], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 445, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[215], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 447, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[217], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 448, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[850], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 450, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[450], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 456, territory: None, regions: &[], overlays: &[], in_service: None, status: NonGeographic },
+    NanpAreaCode { npa: 458, territory: Some(TerritoryCode::US), regions: &["OR"], overlays: &[541], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 463, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[317], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 464, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[708], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 468, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[819], in_service: Some(2024), status: InService },
+    NanpAreaCode { npa: 469, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[214], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 470, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[404], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 472, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[910], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 473, territory: Some(TerritoryCode::GD), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 474, territory: Some(TerritoryCode::CA), regions: &["SK"], overlays: &[306], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 475, territory: Some(TerritoryCode::US), regions: &["CT"], overlays: &[203], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 478, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 479, territory: Some(TerritoryCode::US), regions: &["AR"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 480, territory: Some(TerritoryCode::US), regions: &["AZ"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 484, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[610], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 490, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 491, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 492, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 493, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 494, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 495, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 496, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 497, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 498, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 499, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 500, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 501, territory: Some(TerritoryCode::US), regions: &["AR"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 502, territory: Some(TerritoryCode::US), regions: &["KY"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 503, territory: Some(TerritoryCode::US), regions: &["OR"], overlays: &[503], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 504, territory: Some(TerritoryCode::US), regions: &["LA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 505, territory: Some(TerritoryCode::US), regions: &["NM"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 506, territory: Some(TerritoryCode::CA), regions: &["NB"], overlays: &[506], in_service: Some(1955), status: InService },
+    NanpAreaCode { npa: 507, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(1954), status: InService },
+    NanpAreaCode { npa: 508, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[508], in_service: Some(1988), status: InService },
+    NanpAreaCode { npa: 509, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 510, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[510], in_service: Some(1991), status: InService },
+    NanpAreaCode { npa: 512, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[512], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 513, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[513], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 514, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[514], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 515, territory: Some(TerritoryCode::US), regions: &["IA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 516, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[516], in_service: Some(1951), status: InService },
+    NanpAreaCode { npa: 517, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 518, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[518], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 519, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[519], in_service: Some(1953), status: InService },
+    NanpAreaCode { npa: 520, territory: Some(TerritoryCode::US), regions: &["AZ"], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 521, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 522, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 523, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 524, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 525, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 526, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 527, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 528, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 529, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 530, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 531, territory: Some(TerritoryCode::US), regions: &["NE"], overlays: &[402], in_service: Some(2011), status: InService },
+    NanpAreaCode { npa: 533, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 534, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[715], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 539, territory: Some(TerritoryCode::US), regions: &["OK"], overlays: &[918], in_service: Some(2011), status: InService },
+    NanpAreaCode { npa: 540, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[540], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 541, territory: Some(TerritoryCode::US), regions: &["OR"], overlays: &[541], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 544, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 548, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[519], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 551, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[201], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 557, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[314], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 559, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 561, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[561], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 562, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 563, territory: Some(TerritoryCode::US), regions: &["IA"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 564, territory: Some(TerritoryCode::US), regions: &["WA"], overlays: &[360], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 566, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 567, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[419], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 570, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[570], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 571, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[703], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 572, territory: Some(TerritoryCode::US), regions: &["OK"], overlays: &[405], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 573, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[573], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 574, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 575, territory: Some(TerritoryCode::US), regions: &["NM"], overlays: &[], in_service: Some(2007), status: InService },
+    NanpAreaCode { npa: 577, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 579, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[450], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 580, territory: Some(TerritoryCode::US), regions: &["OK"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 581, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[418], in_service: Some(2008), status: InService },
+    NanpAreaCode { npa: 582, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[814], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 584, territory: Some(TerritoryCode::CA), regions: &["MB"], overlays: &[204], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 585, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 586, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 587, territory: Some(TerritoryCode::CA), regions: &["AB"], overlays: &[403], in_service: Some(2008), status: InService },
+    NanpAreaCode { npa: 588, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 590, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 591, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 592, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 593, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 594, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 595, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 596, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 597, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 598, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 599, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 600, territory: Some(TerritoryCode::CA), regions: &[], overlays: &[], in_service: None, status: NonGeographic },
+    NanpAreaCode { npa: 601, territory: Some(TerritoryCode::US), regions: &["MS"], overlays: &[601], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 602, territory: Some(TerritoryCode::US), regions: &["AZ"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 603, territory: Some(TerritoryCode::US), regions: &["NH"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 604, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 605, territory: Some(TerritoryCode::US), regions: &["SD"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 606, territory: Some(TerritoryCode::US), regions: &["KY"], overlays: &[], in_service: Some(1955), status: InService },
+    NanpAreaCode { npa: 607, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[], in_service: Some(1954), status: InService },
+    NanpAreaCode { npa: 608, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[608], in_service: Some(1955), status: InService },
+    NanpAreaCode { npa: 609, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[609], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 610, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[610], in_service: Some(1994), status: InService },
+    NanpAreaCode { npa: 612, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 613, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[613], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 614, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[614], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 615, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[615], in_service: Some(1954), status: InService },
+    NanpAreaCode { npa: 616, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 617, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[617], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 618, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[618], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 619, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1982), status: InService },
+    NanpAreaCode { npa: 620, territory: Some(TerritoryCode::US), regions: &["KS"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 622, territory: Some(TerritoryCode::CA), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 623, territory: Some(TerritoryCode::US), regions: &["AZ"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 626, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 628, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[415], in_service: Some(2015), status: InService },
+    NanpAreaCode { npa: 629, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[615], in_service: Some(2015), status: InService },
+    NanpAreaCode { npa: 630, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[630], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 631, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[631], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 633, territory: Some(TerritoryCode::CA), regions: &[], overlays: &[], in_service: None, status: Personal },
+    NanpAreaCode { npa: 636, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 639, territory: Some(TerritoryCode::CA), regions: &["SK"], overlays: &[306], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 640, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[609], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 641, territory: Some(TerritoryCode::US), regions: &["IA"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 645, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[305], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 646, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[212], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 647, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[416], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 649, territory: Some(TerritoryCode::TC), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 650, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 651, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 656, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[813], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 657, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[714], in_service: Some(2008), status: InService },
+    NanpAreaCode { npa: 658, territory: Some(TerritoryCode::JM), regions: &[], overlays: &[876], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 659, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[205], in_service: Some(2019), status: InService },
+    NanpAreaCode { npa: 660, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 661, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 662, territory: Some(TerritoryCode::US), regions: &["MS"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 664, territory: Some(TerritoryCode::MS), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 667, territory: Some(TerritoryCode::US), regions: &["MD"], overlays: &[410], in_service: Some(2012), status: InService },
+    NanpAreaCode { npa: 669, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[408], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 670, territory: Some(TerritoryCode::MP), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 671, territory: Some(TerritoryCode::GU), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 672, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(2019), status: InService },
+    NanpAreaCode { npa: 678, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[404], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 680, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[315], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 681, territory: Some(TerritoryCode::US), regions: &["WV"], overlays: &[304], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 682, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[817], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 683, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[705], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 684, territory: Some(TerritoryCode::AS), regions: &[], overlays: &[], in_service: Some(2004), status: InService },
+    NanpAreaCode { npa: 686, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[804], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 689, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[407], in_service: Some(2019), status: InService },
+    NanpAreaCode { npa: 690, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 691, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 692, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 693, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 694, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 695, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 696, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 697, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 698, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 699, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 700, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: NonGeographic },
+    NanpAreaCode { npa: 701, territory: Some(TerritoryCode::US), regions: &["ND"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 702, territory: Some(TerritoryCode::US), regions: &["NV"], overlays: &[702], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 703, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[703], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 704, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[704], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 705, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[705], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 706, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[706], in_service: Some(1992), status: InService },
+    NanpAreaCode { npa: 707, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[707], in_service: Some(1959), status: InService },
+    NanpAreaCode { npa: 708, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[708], in_service: Some(1989), status: InService },
+    NanpAreaCode { npa: 709, territory: Some(TerritoryCode::CA), regions: &["NL"], overlays: &[709], in_service: Some(1962), status: InService },
+    NanpAreaCode { npa: 710, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: NonGeographic },
+    NanpAreaCode { npa: 712, territory: Some(TerritoryCode::US), regions: &["IA"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 713, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[713], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 714, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[714], in_service: Some(1951), status: InService },
+    NanpAreaCode { npa: 715, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[715], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 716, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 717, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[717], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 718, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[718], in_service: Some(1984), status: InService },
+    NanpAreaCode { npa: 719, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[], in_service: Some(1988), status: InService },
+    NanpAreaCode { npa: 720, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[303], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 721, territory: Some(TerritoryCode::SX), regions: &[], overlays: &[], in_service: Some(2011), status: InService },
+    NanpAreaCode { npa: 724, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 725, territory: Some(TerritoryCode::US), regions: &["NV"], overlays: &[702], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 726, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[210], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 727, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 728, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[561], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 730, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[618], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 731, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 732, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[732], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 734, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 737, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[512], in_service: Some(2013), status: InService },
+    NanpAreaCode { npa: 740, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[740], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 742, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[905], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 743, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[336], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 747, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[818], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 748, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[970], in_service: None, status: Planned },
+    NanpAreaCode { npa: 753, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[613], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 754, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[954], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 757, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[757], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 758, territory: Some(TerritoryCode::LC), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 760, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[760], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 762, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[706], in_service: Some(2006), status: InService },
+    NanpAreaCode { npa: 763, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 765, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 767, territory: Some(TerritoryCode::DM), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 769, territory: Some(TerritoryCode::US), regions: &["MS"], overlays: &[601], in_service: Some(2005), status: InService },
+    NanpAreaCode { npa: 770, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[404], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 771, territory: Some(TerritoryCode::US), regions: &["DC"], overlays: &[202], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 772, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 773, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[773], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 774, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[508], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 775, territory: Some(TerritoryCode::US), regions: &["NV"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 778, territory: Some(TerritoryCode::CA), regions: &["BC"], overlays: &[604], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 779, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[815], in_service: Some(2007), status: InService },
+    NanpAreaCode { npa: 780, territory: Some(TerritoryCode::CA), regions: &["AB"], overlays: &[403], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 781, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[781], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 782, territory: Some(TerritoryCode::CA), regions: &["NS", "PE"], overlays: &[902], in_service: Some(2014), status: InService },
+    NanpAreaCode { npa: 784, territory: Some(TerritoryCode::VC), regions: &[], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 785, territory: Some(TerritoryCode::US), regions: &["KS"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 786, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[305], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 787, territory: Some(TerritoryCode::PR), regions: &[], overlays: &[787], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 790, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 791, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 792, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 793, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 794, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 795, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 796, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 797, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 798, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 799, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 800, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(1967), status: TollFree },
+    NanpAreaCode { npa: 801, territory: Some(TerritoryCode::US), regions: &["UT"], overlays: &[801], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 802, territory: Some(TerritoryCode::US), regions: &["VT"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 803, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[803], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 804, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[804], in_service: Some(1973), status: InService },
+    NanpAreaCode { npa: 805, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[805], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 806, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 807, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[], in_service: Some(1962), status: InService },
+    NanpAreaCode { npa: 808, territory: Some(TerritoryCode::US), regions: &["HI"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 809, territory: Some(TerritoryCode::DO), regions: &[], overlays: &[809], in_service: Some(1958), status: InService },
+    NanpAreaCode { npa: 810, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1993), status: InService },
+    NanpAreaCode { npa: 812, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[812], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 813, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[813], in_service: Some(1953), status: InService },
+    NanpAreaCode { npa: 814, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[814], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 815, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[815], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 816, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[816], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 817, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[817], in_service: Some(1953), status: InService },
+    NanpAreaCode { npa: 818, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[818], in_service: Some(1984), status: InService },
+    NanpAreaCode { npa: 819, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[819], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 820, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[805], in_service: Some(2018), status: InService },
+    NanpAreaCode { npa: 821, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[864], in_service: Some(2024), status: InService },
+    NanpAreaCode { npa: 825, territory: Some(TerritoryCode::CA), regions: &["AB"], overlays: &[403], in_service: Some(2016), status: InService },
+    NanpAreaCode { npa: 826, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[540], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 828, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 829, territory: Some(TerritoryCode::DO), regions: &[], overlays: &[809], in_service: Some(2005), status: InService },
+    NanpAreaCode { npa: 830, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 831, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 832, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[713], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 833, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(2017), status: TollFree },
+    NanpAreaCode { npa: 835, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[610], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 838, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[518], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 839, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[803], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 840, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[909], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 843, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[843], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 844, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(2013), status: TollFree },
+    NanpAreaCode { npa: 845, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[845], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 847, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[847], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 848, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[732], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 849, territory: Some(TerritoryCode::DO), regions: &[], overlays: &[809], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 850, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[850], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 854, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[843], in_service: Some(2015), status: InService },
+    NanpAreaCode { npa: 855, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(2010), status: TollFree },
+    NanpAreaCode { npa: 856, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 857, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[617], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 858, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 859, territory: Some(TerritoryCode::US), regions: &["KY"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 860, territory: Some(TerritoryCode::US), regions: &["CT"], overlays: &[860], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 862, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[973], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 863, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 864, territory: Some(TerritoryCode::US), regions: &["SC"], overlays: &[864], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 865, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[], in_service: Some(1999), status: InService },
+    NanpAreaCode { npa: 866, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(2000), status: TollFree },
+    NanpAreaCode { npa: 867, territory: Some(TerritoryCode::CA), regions: &["YT", "NT", "NU"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 868, territory: Some(TerritoryCode::TT), regions: &[], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 869, territory: Some(TerritoryCode::KN), regions: &[], overlays: &[], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 870, territory: Some(TerritoryCode::US), regions: &["AR"], overlays: &[870], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 872, territory: Some(TerritoryCode::US), regions: &["IL"], overlays: &[312, 773], in_service: Some(2009), status: InService },
+    NanpAreaCode { npa: 873, territory: Some(TerritoryCode::CA), regions: &["QC"], overlays: &[819], in_service: Some(2012), status: InService },
+    NanpAreaCode { npa: 876, territory: Some(TerritoryCode::JM), regions: &[], overlays: &[876], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 877, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(1998), status: TollFree },
+    NanpAreaCode { npa: 878, territory: Some(TerritoryCode::US), regions: &["PA"], overlays: &[412], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 879, territory: Some(TerritoryCode::CA), regions: &["NL"], overlays: &[709], in_service: Some(2022), status: InService },
+    NanpAreaCode { npa: 888, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: Some(1996), status: TollFree },
+    NanpAreaCode { npa: 890, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 891, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 892, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 893, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 894, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 895, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 896, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 897, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 898, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 899, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 900, territory: Some(TerritoryCode::US), regions: &[], overlays: &[], in_service: None, status: PremiumRate },
+    NanpAreaCode { npa: 901, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 902, territory: Some(TerritoryCode::CA), regions: &["NS", "PE"], overlays: &[902], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 903, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[903], in_service: Some(1990), status: InService },
+    NanpAreaCode { npa: 904, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[904], in_service: Some(1965), status: InService },
+    NanpAreaCode { npa: 905, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[905], in_service: Some(1993), status: InService },
+    NanpAreaCode { npa: 906, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(1961), status: InService },
+    NanpAreaCode { npa: 907, territory: Some(TerritoryCode::US), regions: &["AK"], overlays: &[], in_service: Some(1957), status: InService },
+    NanpAreaCode { npa: 908, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[], in_service: Some(1991), status: InService },
+    NanpAreaCode { npa: 909, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[909], in_service: Some(1992), status: InService },
+    NanpAreaCode { npa: 910, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[910], in_service: Some(1993), status: InService },
+    NanpAreaCode { npa: 912, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[], in_service: Some(1954), status: InService },
+    NanpAreaCode { npa: 913, territory: Some(TerritoryCode::US), regions: &["KS"], overlays: &[], in_service: Some(1951), status: InService },
+    NanpAreaCode { npa: 914, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 915, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 916, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[916], in_service: Some(1947), status: InService },
+    NanpAreaCode { npa: 917, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[212, 718], in_service: Some(1992), status: InService },
+    NanpAreaCode { npa: 918, territory: Some(TerritoryCode::US), regions: &["OK"], overlays: &[918], in_service: Some(1953), status: InService },
+    NanpAreaCode { npa: 919, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[919], in_service: Some(1954), status: InService },
+    NanpAreaCode { npa: 920, territory: Some(TerritoryCode::US), regions: &["WI"], overlays: &[920], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 925, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 928, territory: Some(TerritoryCode::US), regions: &["AZ"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 929, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[718], in_service: Some(2011), status: InService },
+    NanpAreaCode { npa: 930, territory: Some(TerritoryCode::US), regions: &["IN"], overlays: &[812], in_service: Some(2015), status: InService },
+    NanpAreaCode { npa: 931, territory: Some(TerritoryCode::US), regions: &["TN"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 934, territory: Some(TerritoryCode::US), regions: &["NY"], overlays: &[631], in_service: Some(2016), status: InService },
+    NanpAreaCode { npa: 936, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 937, territory: Some(TerritoryCode::US), regions: &["OH"], overlays: &[937], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 938, territory: Some(TerritoryCode::US), regions: &["AL"], overlays: &[256], in_service: Some(2010), status: InService },
+    NanpAreaCode { npa: 939, territory: Some(TerritoryCode::PR), regions: &[], overlays: &[787], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 940, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 941, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 942, territory: Some(TerritoryCode::CA), regions: &["ON"], overlays: &[416], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 943, territory: Some(TerritoryCode::US), regions: &["GA"], overlays: &[404], in_service: Some(2024), status: InService },
+    NanpAreaCode { npa: 945, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[214], in_service: Some(2021), status: InService },
+    NanpAreaCode { npa: 947, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[248], in_service: Some(2002), status: InService },
+    NanpAreaCode { npa: 948, territory: Some(TerritoryCode::US), regions: &["VA"], overlays: &[757], in_service: Some(2025), status: InService },
+    NanpAreaCode { npa: 949, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(1998), status: InService },
+    NanpAreaCode { npa: 951, territory: Some(TerritoryCode::US), regions: &["CA"], overlays: &[], in_service: Some(2004), status: InService },
+    NanpAreaCode { npa: 952, territory: Some(TerritoryCode::US), regions: &["MN"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 954, territory: Some(TerritoryCode::US), regions: &["FL"], overlays: &[954], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 956, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(1992), status: InService },
+    NanpAreaCode { npa: 959, territory: Some(TerritoryCode::US), regions: &["CT"], overlays: &[860], in_service: Some(2020), status: InService },
+    NanpAreaCode { npa: 960, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 961, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 962, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 963, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 964, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 965, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 966, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 967, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 968, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 969, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 970, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[970], in_service: Some(1995), status: InService },
+    NanpAreaCode { npa: 971, territory: Some(TerritoryCode::US), regions: &["OR"], overlays: &[503], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 972, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[214], in_service: Some(1996), status: InService },
+    NanpAreaCode { npa: 973, territory: Some(TerritoryCode::US), regions: &["NJ"], overlays: &[973], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 975, territory: Some(TerritoryCode::US), regions: &["MO"], overlays: &[816], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 978, territory: Some(TerritoryCode::US), regions: &["MA"], overlays: &[978], in_service: Some(1997), status: InService },
+    NanpAreaCode { npa: 979, territory: Some(TerritoryCode::US), regions: &["TX"], overlays: &[], in_service: Some(2000), status: InService },
+    NanpAreaCode { npa: 980, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[704], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 983, territory: Some(TerritoryCode::US), regions: &["CO"], overlays: &[303], in_service: Some(2023), status: InService },
+    NanpAreaCode { npa: 984, territory: Some(TerritoryCode::US), regions: &["NC"], overlays: &[919], in_service: Some(2012), status: InService },
+    NanpAreaCode { npa: 985, territory: Some(TerritoryCode::US), regions: &["LA"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 986, territory: Some(TerritoryCode::US), regions: &["ID"], overlays: &[208], in_service: Some(2017), status: InService },
+    NanpAreaCode { npa: 989, territory: Some(TerritoryCode::US), regions: &["MI"], overlays: &[], in_service: Some(2001), status: InService },
+    NanpAreaCode { npa: 990, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 991, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 992, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 993, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 994, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 995, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 996, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 997, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 998, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+    NanpAreaCode { npa: 999, territory: None, regions: &[], overlays: &[], in_service: None, status: Reserved },
+];
@@ -0,0 +1,187 @@
+use std::sync::OnceLock;
+
+use crate::pattern::Pattern;
+use crate::{PhoneNumber, TerritoryCode};
+
+mod data;
+
+/// The numbers of a territory within one of its calling codes
+struct TerritoryNumbers {
+    territory: TerritoryCode,
+    calling_code: u32,
+    /// The pattern all the national significant numbers of the territory match
+    pattern: &'static str,
+    types: &'static [NumberDesc],
+}
+
+/// The national significant numbers of a type
+struct NumberDesc {
+    number_type: NumberType,
+    lengths: &'static [u8],
+    pattern: &'static str,
+}
+
+/// The compiled patterns of a [`TerritoryNumbers`]
+struct CompiledNumbers {
+    pattern: Pattern,
+    /// The patterns of the types, in the same order
+    types: Vec<Pattern>,
+}
+
+impl CompiledNumbers {
+    fn new(numbers: &TerritoryNumbers) -> Self {
+        Self {
+            pattern: Pattern::new(numbers.pattern),
+            types: numbers
+                .types
+                .iter()
+                .map(|desc| Pattern::new(desc.pattern))
+                .collect(),
+        }
+    }
+}
+
+/// The patterns of [`data::TERRITORIES`], each compiled on first use
+static COMPILED: [OnceLock<CompiledNumbers>; data::TERRITORIES_LEN] =
+    [const { OnceLock::new() }; data::TERRITORIES_LEN];
+
+///
+/// The kinds of phone numbers, as classified by libphonenumber
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum NumberType {
+    /// A landline number
+    FixedLine,
+    /// A mobile number, which can usually receive SMS
+    Mobile,
+    /// A number which may be a landline or a mobile number, as both use the same numbering in
+    /// the territory, e.g. in the United States
+    FixedLineOrMobile,
+    /// A number which is free for the caller
+    TollFree,
+    /// A number at a higher rate, charged to the caller for a service
+    PremiumRate,
+    /// A number whose cost is shared between the caller and the recipient
+    SharedCost,
+    /// A number of a voice over IP service
+    Voip,
+    /// A number following a person across phones, which may be charged at a higher rate
+    PersonalNumber,
+    /// A number of a pager
+    Pager,
+    /// A universal access number, reaching a company on one number across its offices
+    Uan,
+    /// A number reaching a voicemail directly
+    Voicemail,
+    /// A number which does not match any type of its territory
+    Unknown,
+}
+
+impl PhoneNumber {
+    /// Classifies this phone number by matching its national significant number against the
+    /// numbers of each type in its territory
+    /// ```
+    /// use e164_phones_countries::{NumberType, PhoneNumber};
+    /// let mobile: PhoneNumber = "+44 7400 123456".parse().unwrap();
+    /// assert_eq!(NumberType::Mobile, mobile.number_type());
+    /// let premium: PhoneNumber = "+44 909 876 5432".parse().unwrap();
+    /// assert_eq!(NumberType::PremiumRate, premium.number_type());
+    /// let us: PhoneNumber = "+1 206 935 9290".parse().unwrap();
+    /// assert_eq!(NumberType::FixedLineOrMobile, us.number_type());
+    /// ```
+    pub fn number_type(&self) -> NumberType {
+        let calling_code = self.calling_code().get();
+        let index = data::TERRITORIES.iter().position(|numbers| {
+            numbers.territory == self.territory() && numbers.calling_code == calling_code
+        });
+        let index = match index {
+            Some(index) => index,
+            None => return NumberType::Unknown,
+        };
+        let numbers = &data::TERRITORIES[index];
+        let compiled = COMPILED[index].get_or_init(|| CompiledNumbers::new(numbers));
+        let national_number = self.national_number_digits();
+        if !compiled.pattern.matches(&national_number) {
+            return NumberType::Unknown;
+        }
+
+        let len = national_number.len();
+        let is_match = |index: usize| {
+            numbers.types[index]
+                .lengths
+                .iter()
+                .any(|&length| usize::from(length) == len)
+                && compiled.types[index].matches(&national_number)
+        };
+        let mobile = numbers
+            .types
+            .iter()
+            .position(|desc| desc.number_type == NumberType::Mobile);
+        for (index, desc) in numbers.types.iter().enumerate() {
+            if !is_match(index) {
+                continue;
+            }
+            return match (desc.number_type, mobile) {
+                (NumberType::FixedLine, Some(mobile))
+                    if numbers.types[mobile].pattern == desc.pattern || is_match(mobile) =>
+                {
+                    NumberType::FixedLineOrMobile
+                }
+                (number_type, _) => number_type,
+            };
+        }
+        NumberType::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_data_is_valid() {
+        for numbers in &data::TERRITORIES {
+            CompiledNumbers::new(numbers);
+            for desc in numbers.types {
+                assert!(!desc.lengths.is_empty(), "{}", numbers.territory);
+            }
+        }
+        for territory in TerritoryCode::iter().filter(|&territory| territory != TerritoryCode::AQ) {
+            assert!(
+                data::TERRITORIES
+                    .iter()
+                    .any(|numbers| numbers.territory == territory),
+                "{}",
+                territory
+            );
+        }
+    }
+
+    #[test]
+    fn classify_numbers() {
+        for (input, number_type) in [
+            ("+44 7400 123456", NumberType::Mobile),
+            ("+44 20 7946 0958", NumberType::FixedLine),
+            ("+44 800 123 4567", NumberType::TollFree),
+            ("+44 909 876 5432", NumberType::PremiumRate),
+            ("+39 848 123 456", NumberType::SharedCost),
+            ("+44 56 1234 5678", NumberType::Voip),
+            ("+44 70 1234 5678", NumberType::PersonalNumber),
+            ("+44 76 2345 6789", NumberType::Pager),
+            ("+44 55 1234 5678", NumberType::Uan),
+            ("+1 800 253 0000", NumberType::TollFree),
+            ("+1 206 935 9290", NumberType::FixedLineOrMobile),
+            ("+39 06 1234 5678", NumberType::FixedLine),
+            ("+39 312 345 6789", NumberType::Mobile),
+            ("+39 331 0123 4501", NumberType::Voicemail),
+            ("+33 6 12 34 56 78", NumberType::Mobile),
+            ("+49 1512 3456789", NumberType::Mobile),
+            ("+800 1234 5678", NumberType::TollFree),
+            ("+44 41 2345 6789", NumberType::Unknown),
+        ] {
+            let phone: PhoneNumber = input.parse().unwrap();
+            assert_eq!(number_type, phone.number_type(), "{input}");
+        }
+    }
+}
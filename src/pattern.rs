@@ -0,0 +1,354 @@
+//!
+//! A minimal regular expression engine for the digit patterns of libphonenumber's metadata.
+//!
+//! Only the syntax used by the metadata is supported: digits, `\d`, character classes like
+//! `[13-5]`, groups `(...)` and `(?:...)`, alternations `|` and the quantifiers `?`, `{n}` and
+//! `{n,m}`. Patterns are matched against strings of at most 31 digits, by tracking the set of
+//! positions reachable after each node as a bit mask.
+//!
+
+/// The largest number of digits a pattern can be matched against
+const MAX_INPUT_LEN: usize = 31;
+
+/// The bit mask of all the digits `0` to `9`
+const ANY_DIGIT: u16 = 0b11_1111_1111;
+
+#[derive(Debug)]
+enum Node {
+    /// One digit out of a set, as a bit mask
+    Digit(u16),
+    /// Alternative sequences of nodes
+    Group {
+        alternatives: Vec<Vec<Node>>,
+        capturing: bool,
+    },
+    Repeat {
+        node: Box<Node>,
+        min: u8,
+        max: u8,
+    },
+}
+
+/// A compiled digit pattern
+#[derive(Debug)]
+pub(crate) struct Pattern {
+    nodes: Vec<Node>,
+}
+
+impl Pattern {
+    /// Compiles a pattern. Panics on unsupported syntax, as patterns are part of the crate's data.
+    pub(crate) fn new(pattern: &str) -> Self {
+        let mut parser = Parser {
+            pattern: pattern.as_bytes(),
+            position: 0,
+        };
+        let mut alternatives = parser.alternatives();
+        if parser.position != pattern.len() {
+            panic!("Unbalanced parenthesis in pattern {:?}", pattern);
+        }
+        let nodes = match alternatives.len() {
+            1 => alternatives.remove(0),
+            _ => vec![Node::Group {
+                alternatives,
+                capturing: false,
+            }],
+        };
+        Self { nodes }
+    }
+
+    /// Whether the whole input matches the pattern
+    pub(crate) fn matches(&self, input: &str) -> bool {
+        match positions(input) {
+            Some(end) => ends(&self.nodes, input.as_bytes(), 1) & end != 0,
+            None => false,
+        }
+    }
+
+    /// Whether a prefix of the input matches the pattern
+    pub(crate) fn matches_start(&self, input: &str) -> bool {
+        self.longest_match_start(input).is_some()
+    }
+
+    /// The length of the longest prefix of the input matching the pattern
+    pub(crate) fn longest_match_start(&self, input: &str) -> Option<usize> {
+        positions(input)?;
+        match ends(&self.nodes, input.as_bytes(), 1) {
+            0 => None,
+            ends => Some((u32::BITS - 1 - ends.leading_zeros()) as usize),
+        }
+    }
+
+    /// Matches the whole input and yields the text of the capturing groups. Only capturing groups
+    /// at the top level of the pattern are supported. Like common regex engines, earlier groups
+    /// are greedy.
+    pub(crate) fn captures<'a>(&self, input: &'a str) -> Option<Vec<&'a str>> {
+        let end = positions(input)?;
+        let bytes = input.as_bytes();
+        let mut captures = Vec::new();
+        let mut start = 0;
+        for (i, node) in self.nodes.iter().enumerate() {
+            let rest = &self.nodes[i + 1..];
+            let next = (start..=input.len())
+                .rev()
+                .filter(|&next| {
+                    ends(std::slice::from_ref(node), bytes, 1 << start) & 1 << next != 0
+                })
+                .find(|&next| ends(rest, bytes, 1 << next) & end != 0)?;
+            if let Node::Group {
+                capturing: true, ..
+            } = node
+            {
+                captures.push(&input[start..next]);
+            }
+            start = next;
+        }
+        Some(captures)
+    }
+}
+
+/// The bit of the position at the end of the input, if the input can be matched
+fn positions(input: &str) -> Option<u32> {
+    if input.len() <= MAX_INPUT_LEN && input.bytes().all(|b| b.is_ascii_digit()) {
+        Some(1 << input.len())
+    } else {
+        None
+    }
+}
+
+/// The positions reachable by matching a sequence of nodes from any of the start positions
+fn ends(nodes: &[Node], input: &[u8], starts: u32) -> u32 {
+    nodes
+        .iter()
+        .fold(starts, |positions, node| node_ends(node, input, positions))
+}
+
+fn node_ends(node: &Node, input: &[u8], starts: u32) -> u32 {
+    match node {
+        Node::Digit(digits) => input
+            .iter()
+            .enumerate()
+            .filter(|&(i, &b)| starts & 1 << i != 0 && digits & 1 << (b - b'0') != 0)
+            .fold(0, |positions, (i, _)| positions | 1 << (i + 1)),
+        Node::Group { alternatives, .. } => alternatives
+            .iter()
+            .fold(0, |positions, nodes| positions | ends(nodes, input, starts)),
+        Node::Repeat { node, min, max } => {
+            let mut positions = starts;
+            for _ in 0..*min {
+                positions = node_ends(node, input, positions);
+            }
+            let mut all = positions;
+            for _ in *min..*max {
+                positions = node_ends(node, input, positions);
+                all |= positions;
+            }
+            all
+        }
+    }
+}
+
+struct Parser<'a> {
+    pattern: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.pattern.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> u8 {
+        let b = self.peek().unwrap_or_else(|| self.fail("Unexpected end"));
+        self.position += 1;
+        b
+    }
+
+    fn fail(&self, reason: &str) -> ! {
+        panic!(
+            "{} at position {} of pattern {:?}",
+            reason,
+            self.position,
+            String::from_utf8_lossy(self.pattern)
+        )
+    }
+
+    /// Parses alternatives up to a closing parenthesis or the end of the pattern
+    fn alternatives(&mut self) -> Vec<Vec<Node>> {
+        let mut alternatives = vec![Vec::new()];
+        while let Some(b) = self.peek() {
+            match b {
+                b')' => break,
+                b'|' => {
+                    self.position += 1;
+                    alternatives.push(Vec::new());
+                }
+                _ => {
+                    let node = self.atom();
+                    let node = self.quantifier(node);
+                    alternatives.last_mut().unwrap().push(node);
+                }
+            }
+        }
+        alternatives
+    }
+
+    fn atom(&mut self) -> Node {
+        match self.next() {
+            b @ b'0'..=b'9' => Node::Digit(1 << (b - b'0')),
+            b'\\' => self.escape(),
+            b'[' => self.class(),
+            b'(' => {
+                let capturing = !self.pattern[self.position..].starts_with(b"?:");
+                if !capturing {
+                    self.position += 2;
+                }
+                let alternatives = self.alternatives();
+                if self.next() != b')' {
+                    self.fail("Unclosed group");
+                }
+                Node::Group {
+                    alternatives,
+                    capturing,
+                }
+            }
+            _ => self.fail("Unsupported syntax"),
+        }
+    }
+
+    fn escape(&mut self) -> Node {
+        match self.next() {
+            b'd' => Node::Digit(ANY_DIGIT),
+            _ => self.fail("Unsupported escape"),
+        }
+    }
+
+    fn class(&mut self) -> Node {
+        let mut digits = 0;
+        loop {
+            let from = match self.next() {
+                b']' => return Node::Digit(digits),
+                b'\\' => match self.escape() {
+                    Node::Digit(escaped) => {
+                        digits |= escaped;
+                        continue;
+                    }
+                    _ => unreachable!(),
+                },
+                b @ b'0'..=b'9' => b - b'0',
+                _ => self.fail("Unsupported character class"),
+            };
+            let to = match self.peek() {
+                Some(b'-') => {
+                    self.position += 1;
+                    match self.next() {
+                        b @ b'0'..=b'9' => b - b'0',
+                        _ => self.fail("Unsupported range"),
+                    }
+                }
+                _ => from,
+            };
+            for digit in from..=to {
+                digits |= 1 << digit;
+            }
+        }
+    }
+
+    fn quantifier(&mut self, node: Node) -> Node {
+        let (min, max) = match self.peek() {
+            Some(b'?') => {
+                self.position += 1;
+                (0, 1)
+            }
+            Some(b'{') => {
+                self.position += 1;
+                let min = self.number();
+                let max = match self.next() {
+                    b',' => {
+                        let max = self.number();
+                        self.next();
+                        max
+                    }
+                    _ => min,
+                };
+                if self.pattern[self.position - 1] != b'}' {
+                    self.fail("Unclosed quantifier");
+                }
+                (min, max)
+            }
+            Some(b'*' | b'+') => self.fail("Unbounded quantifier"),
+            _ => return node,
+        };
+        Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+        }
+    }
+
+    fn number(&mut self) -> u8 {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.pattern[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .unwrap_or_else(|| self.fail("Invalid number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_whole_input_or_prefix() {
+        let pattern = Pattern::new(r"1(?:38|5[23]|69)\d{2,3}");
+        assert!(pattern.matches_start("13812"));
+        assert!(pattern.matches_start("1521234"));
+        assert!(!pattern.matches_start("154123"));
+        assert!(!pattern.matches_start("15"));
+
+        let pattern = Pattern::new(r"[24-9]|3(?:[02-9]|1[1-9])");
+        assert!(pattern.matches_start("2069"));
+        assert!(pattern.matches_start("3119"));
+        assert!(!pattern.matches_start("3109"));
+        assert!(!pattern.matches_start("1"));
+
+        assert!(!pattern.matches_start("2a"));
+
+        let pattern = Pattern::new(r"\d{7}(?:\d{3})?");
+        assert!(pattern.matches("1234567"));
+        assert!(pattern.matches("1234567890"));
+        assert!(!pattern.matches("12345678"));
+        assert!(!pattern.matches("12345a7"));
+
+        let pattern = Pattern::new(r"00(?:1\d)?");
+        assert_eq!(Some(4), pattern.longest_match_start("0012345"));
+        assert_eq!(Some(2), pattern.longest_match_start("0023"));
+        assert_eq!(None, pattern.longest_match_start("0123"));
+    }
+
+    #[test]
+    fn capture_groups() {
+        let pattern = Pattern::new(r"(\d{2})(\d{4})(\d{4})");
+        assert_eq!(
+            Some(vec!["20", "7946", "0958"]),
+            pattern.captures("2079460958")
+        );
+        assert_eq!(None, pattern.captures("207946095"));
+
+        let pattern = Pattern::new(r"(\d{3})(\d{3,4})(\d{2,3})");
+        assert_eq!(
+            Some(vec!["123", "4567", "89"]),
+            pattern.captures("123456789")
+        );
+        assert_eq!(Some(vec!["123", "456", "78"]), pattern.captures("12345678"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unbounded quantifier")]
+    fn reject_unsupported_syntax() {
+        Pattern::new(r"\d+");
+    }
+}
@@ -0,0 +1,470 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroU32;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::pattern::Pattern;
+use crate::{calling_code_len, country_calling_code, FromPhoneError, TerritoryCode, MAX_DIGITS};
+
+/// International prefixes accepted in place of a leading `+`
+const INTERNATIONAL_PREFIXES: [&str; 2] = ["00", "011"];
+
+/// The international prefix patterns of the territories, in the order of their codes, each
+/// compiled on first use
+static INTERNATIONAL_PREFIX_PATTERNS: [OnceLock<Option<Pattern>>; TerritoryCode::COUNT] =
+    [const { OnceLock::new() }; TerritoryCode::COUNT];
+
+///
+/// A phone number in international format, split into its country calling code and national
+/// significant number, and with its territory resolved.
+///
+/// Phone numbers are usually parsed from human-written strings:
+/// ```
+/// use e164_phones_countries::{PhoneNumber, TerritoryCode};
+/// let phone: PhoneNumber = "+44 20 7946 0958".parse().unwrap();
+/// assert_eq!(44, phone.calling_code().get());
+/// assert_eq!(2079460958, phone.national_number());
+/// assert_eq!(TerritoryCode::GB, phone.territory());
+/// ```
+///
+/// The digits of an international number can be split as well:
+/// ```
+/// use e164_phones_countries::PhoneNumber;
+/// use std::convert::TryFrom;
+/// let phone = PhoneNumber::try_from(390612345678).unwrap();
+/// assert_eq!(39, phone.calling_code().get());
+/// assert_eq!(612345678, phone.national_number());
+/// assert_eq!(10, phone.national_number_len(), "leading zero of the national number");
+/// ```
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PhoneNumber {
+    calling_code: NonZeroU32,
+    national_number: u64,
+    national_number_len: u8,
+    territory: TerritoryCode,
+    territory_prefix_len: u8,
+}
+
+impl PhoneNumber {
+    /// The ITU country calling code, e.g. 44 for the United Kingdom
+    pub fn calling_code(&self) -> NonZeroU32 {
+        self.calling_code
+    }
+
+    /// The national significant number, i.e. all the digits following the calling code
+    pub fn national_number(&self) -> u64 {
+        self.national_number
+    }
+
+    /// The number of digits of the national significant number. It may be larger than the number
+    /// of digits of [`PhoneNumber::national_number`], as national numbers can start with zeros.
+    pub fn national_number_len(&self) -> u32 {
+        self.national_number_len.into()
+    }
+
+    /// The territory this phone number belongs to
+    pub fn territory(&self) -> TerritoryCode {
+        self.territory
+    }
+
+    /// The number of leading digits, including the calling code, which identified the territory
+    pub fn territory_prefix_len(&self) -> u32 {
+        self.territory_prefix_len.into()
+    }
+
+    /// The digits of the national significant number, including leading zeros
+    pub(crate) fn national_number_digits(&self) -> String {
+        format!(
+            "{:0width$}",
+            self.national_number,
+            width = self.national_number_len.into()
+        )
+    }
+
+    /// Checks the length of the national significant number against the lengths allowed in the
+    /// territory, see [`TerritoryCode::national_number_lengths`]
+    /// ```
+    /// use e164_phones_countries::{LengthValidation, PhoneNumber};
+    /// use std::convert::TryFrom;
+    /// let phone = PhoneNumber::try_from(2908123).unwrap();
+    /// assert_eq!(LengthValidation::Valid, phone.validate());
+    /// let phone = PhoneNumber::try_from(44207946).unwrap();
+    /// assert_eq!(LengthValidation::TooShort, phone.validate());
+    /// ```
+    pub fn validate(&self) -> LengthValidation {
+        let lengths = self.territory.national_number_lengths();
+        let len = self.national_number_len();
+        if len < *lengths.start() {
+            LengthValidation::TooShort
+        } else if len > *lengths.end() {
+            LengthValidation::TooLong
+        } else {
+            LengthValidation::Valid
+        }
+    }
+}
+
+/// The outcome of checking the length of a phone number
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LengthValidation {
+    /// The national significant number is shorter than allowed in its territory
+    TooShort,
+    /// The national significant number has a valid length for its territory
+    Valid,
+    /// The national significant number is longer than allowed in its territory
+    TooLong,
+}
+
+impl Display for PhoneNumber {
+    /// Writes the phone number in E.164 format, e.g. `+442079460958`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "+{}{:0width$}",
+            self.calling_code,
+            self.national_number,
+            width = self.national_number_len.into()
+        )
+    }
+}
+
+impl TryFrom<u64> for PhoneNumber {
+    type Error = FromPhoneError;
+
+    /// Splits the digits of an international phone number, without any prefix. Unlike parsing
+    /// from a string, the length of the national significant number is not validated.
+    fn try_from(phone: u64) -> Result<Self, Self::Error> {
+        let (territory, territory_prefix_len) = TerritoryCode::find_by_prefix(phone)?;
+
+        let phone_len = 1 + phone.ilog10();
+        let calling_code_len = calling_code_len(phone / 10u64.pow(phone_len - 2));
+        let national_number_len = phone_len - calling_code_len;
+        let calling_code = (phone / 10u64.pow(national_number_len)) as u32;
+        Ok(Self {
+            calling_code: NonZeroU32::new(calling_code)
+                .ok_or(FromPhoneError::InvalidPhoneNumber)?,
+            national_number: phone % 10u64.pow(national_number_len),
+            national_number_len: national_number_len as u8,
+            territory,
+            territory_prefix_len: territory_prefix_len as u8,
+        })
+    }
+}
+
+impl PhoneNumber {
+    /// Parses a phone number written in the national format of a territory, i.e. without calling
+    /// code and usually with the national prefix of the territory, see
+    /// [`TerritoryCode::national_prefix`]. Separators are accepted as when parsing an
+    /// international number. The number may belong to another territory sharing the calling code.
+    /// ```
+    /// use e164_phones_countries::{PhoneNumber, TerritoryCode};
+    /// let phone = PhoneNumber::parse_national("020 7946 0958", TerritoryCode::GB).unwrap();
+    /// assert_eq!(44, phone.calling_code().get());
+    /// assert_eq!(2079460958, phone.national_number());
+    ///
+    /// let phone = PhoneNumber::parse_national("01481 123456", TerritoryCode::GB).unwrap();
+    /// assert_eq!(TerritoryCode::GG, phone.territory());
+    /// ```
+    pub fn parse_national(s: &str, territory: TerritoryCode) -> Result<Self, FromPhoneError> {
+        let (digits, has_plus) = digits(s)?;
+        if has_plus {
+            return Err(FromPhoneError::InvalidPhoneNumber);
+        }
+        Self::from_national_digits(&digits, territory)
+    }
+
+    /// Parses a phone number as typed by a user of a default territory: in international format
+    /// when it starts with `+` or with an international prefix of the territory, see
+    /// [`TerritoryCode::international_prefix`], and in the national format of the territory
+    /// otherwise, see [`PhoneNumber::parse_national`].
+    /// ```
+    /// use e164_phones_countries::{PhoneNumber, TerritoryCode};
+    /// for input in ["07700 900123", "+44 7700 900123", "00 44 7700 900123"] {
+    ///     let phone = PhoneNumber::parse_with_default(input, TerritoryCode::GB).unwrap();
+    ///     assert_eq!("+447700900123", phone.to_string());
+    /// }
+    ///
+    /// let phone = PhoneNumber::parse_with_default("0011 44 7700 900123", TerritoryCode::AU);
+    /// assert_eq!(TerritoryCode::GB, phone.unwrap().territory());
+    /// ```
+    pub fn parse_with_default(s: &str, territory: TerritoryCode) -> Result<Self, FromPhoneError> {
+        let (digits, has_plus) = digits(s)?;
+        if has_plus {
+            return Self::from_international_digits(&digits);
+        }
+        let international_prefix_len = INTERNATIONAL_PREFIX_PATTERNS[territory as usize]
+            .get_or_init(|| territory.international_prefix_pattern().map(Pattern::new))
+            .as_ref()
+            .and_then(|pattern| pattern.longest_match_start(&digits))
+            .filter(|&len| !digits[len..].starts_with('0'));
+        match international_prefix_len {
+            Some(len) => Self::from_international_digits(&digits[len..]),
+            None => Self::from_national_digits(&digits, territory),
+        }
+    }
+
+    /// Parses the digits of a number in the national format of a territory. The national prefix
+    /// is only stripped when the rest is a valid number, since national numbers may start with
+    /// the same digits, like the toll-free `800` numbers of Russia, whose national prefix is `8`.
+    fn from_national_digits(
+        digits: &str,
+        territory: TerritoryCode,
+    ) -> Result<Self, FromPhoneError> {
+        let calling_code = country_calling_code(territory.calling_codes().primary());
+        let parse = |national_number: &str| {
+            if national_number.is_empty() {
+                return Err(FromPhoneError::InvalidPhoneNumber);
+            }
+            Self::from_international_digits(&format!("{calling_code}{national_number}"))
+        };
+        match territory
+            .national_prefix()
+            .and_then(|prefix| digits.strip_prefix(prefix))
+        {
+            Some(national_number) => {
+                parse(national_number).or_else(|error| parse(digits).map_err(|_| error))
+            }
+            None => parse(digits),
+        }
+    }
+
+    /// Parses the digits of an international number, without any prefix, and validates the
+    /// length of its national significant number
+    fn from_international_digits(digits: &str) -> Result<Self, FromPhoneError> {
+        if digits.is_empty() || digits.starts_with('0') {
+            return Err(FromPhoneError::InvalidPhoneNumber);
+        }
+        if digits.len() > MAX_DIGITS as usize {
+            let actual = digits.len() as u32;
+            return Err(FromPhoneError::ExceedsE164MaxLength { actual });
+        }
+        let phone: u64 = digits
+            .parse()
+            .map_err(|_| FromPhoneError::InvalidPhoneNumber)?;
+        Self::try_from(phone)?.check_length()
+    }
+
+    /// Fails when the length of the national significant number is not valid for its territory,
+    /// see [`PhoneNumber::validate`]
+    pub(crate) fn check_length(self) -> Result<Self, FromPhoneError> {
+        let lengths = self.territory.national_number_lengths();
+        let actual = self.national_number_len();
+        match self.validate() {
+            LengthValidation::Valid => Ok(self),
+            LengthValidation::TooShort => Err(FromPhoneError::TooShort {
+                min: *lengths.start(),
+                actual,
+            }),
+            LengthValidation::TooLong => Err(FromPhoneError::TooLong {
+                max: *lengths.end(),
+                actual,
+            }),
+        }
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = FromPhoneError;
+
+    /// Parses a phone number written in international format. It may start with `+` or with one
+    /// of the international prefixes `00` and `011`, and digits may be separated by spaces, dots,
+    /// dashes and parentheses. The national significant number must have a valid length for its
+    /// territory.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, has_plus) = digits(s)?;
+        let mut digits = digits.as_str();
+        if !has_plus {
+            if let Some(prefix) = INTERNATIONAL_PREFIXES
+                .iter()
+                .find(|prefix| digits.starts_with(*prefix))
+            {
+                digits = &digits[prefix.len()..];
+            }
+        }
+        Self::from_international_digits(digits)
+    }
+}
+
+/// Extracts the digits of a human-written phone number, and whether they follow a leading `+`
+fn digits(s: &str) -> Result<(String, bool), FromPhoneError> {
+    let mut digits = String::with_capacity(s.len());
+    let mut has_plus = false;
+    for (position, ch) in s.trim_end().chars().enumerate() {
+        match ch {
+            '0'..='9' => digits.push(ch),
+            '+' if digits.is_empty() && !has_plus => has_plus = true,
+            ' ' | '.' | '-' | '(' | ')' => {}
+            ch if ch.is_whitespace() && digits.is_empty() && !has_plus => {}
+            _ => return Err(FromPhoneError::InvalidCharacter { position, ch }),
+        }
+    }
+    Ok((digits, has_plus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_human_written() {
+        for input in [
+            "+44 20 7946 0958",
+            "0044 20 7946 0958",
+            "011 44 20-7946-0958",
+            "  +44.20.7946.0958 ",
+            "442079460958",
+        ] {
+            let phone: PhoneNumber = input.parse().unwrap();
+            assert_eq!(44, phone.calling_code().get(), "{input}");
+            assert_eq!(2079460958, phone.national_number(), "{input}");
+            assert_eq!(TerritoryCode::GB, phone.territory(), "{input}");
+        }
+
+        let phone: PhoneNumber = "+1 (206) 935-9290".parse().unwrap();
+        assert_eq!(1, phone.calling_code().get());
+        assert_eq!(2069359290, phone.national_number());
+        assert_eq!(TerritoryCode::US, phone.territory());
+        assert_eq!("+12069359290", phone.to_string());
+    }
+
+    #[test]
+    fn split_into_parts() {
+        let phone = PhoneNumber::try_from(12069359290).unwrap();
+        assert_eq!(1, phone.calling_code().get());
+        assert_eq!(2069359290, phone.national_number());
+        assert_eq!(10, phone.national_number_len());
+        assert_eq!(4, phone.territory_prefix_len());
+
+        let phone = PhoneNumber::try_from(441481123456).unwrap();
+        assert_eq!(TerritoryCode::GG, phone.territory());
+        assert_eq!(44, phone.calling_code().get());
+        assert_eq!(1481123456, phone.national_number());
+        assert_eq!(6, phone.territory_prefix_len());
+
+        let phone = PhoneNumber::try_from(26226992902).unwrap();
+        assert_eq!(TerritoryCode::YT, phone.territory());
+        assert_eq!(262, phone.calling_code().get());
+        assert_eq!(26992902, phone.national_number());
+        assert_eq!(6, phone.territory_prefix_len());
+        assert_eq!("+26226992902", phone.to_string());
+    }
+
+    #[test]
+    fn validate_length() {
+        let phone: PhoneNumber = "+683 4002".parse().unwrap();
+        assert_eq!(TerritoryCode::NU, phone.territory());
+        assert_eq!(LengthValidation::Valid, phone.validate());
+
+        let phone = PhoneNumber::try_from(6834).unwrap();
+        assert_eq!(LengthValidation::TooShort, phone.validate());
+        let phone = PhoneNumber::try_from(9989012345678).unwrap();
+        assert_eq!(LengthValidation::TooLong, phone.validate());
+        let phone = PhoneNumber::try_from(998901234567).unwrap();
+        assert_eq!(LengthValidation::Valid, phone.validate());
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for input in ["", "+", "  ", "020 7946 0958", "00"] {
+            assert_eq!(
+                Err(FromPhoneError::InvalidPhoneNumber),
+                input.parse::<PhoneNumber>(),
+                "{input}"
+            );
+        }
+        let invalid_character = |position, ch| FromPhoneError::InvalidCharacter { position, ch };
+        for (input, error) in [
+            ("+44 20 7946 0958 ext. 1", invalid_character(17, 'e')),
+            ("44+2079460958", invalid_character(2, '+')),
+            ("++442079460958", invalid_character(1, '+')),
+            ("  +44\t2079460958", invalid_character(5, '\t')),
+        ] {
+            assert_eq!(Err(error), input.parse::<PhoneNumber>(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_reports_detailed_errors() {
+        for (input, error) in [
+            ("+44 20 794", FromPhoneError::TooShort { min: 7, actual: 5 }),
+            (
+                "+44 20 7946 09581",
+                FromPhoneError::TooLong {
+                    max: 10,
+                    actual: 11,
+                },
+            ),
+            (
+                "+44 2079 4609 5812 345",
+                FromPhoneError::ExceedsE164MaxLength { actual: 17 },
+            ),
+            ("+999 123 4567", FromPhoneError::UnassignedCallingCode(999)),
+            (
+                "+1 222 935 9290",
+                FromPhoneError::UnassignedNanpAreaCode(222),
+            ),
+            ("+1 206", FromPhoneError::NotFound),
+        ] {
+            assert_eq!(Err(error), input.parse::<PhoneNumber>(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_national_format() {
+        for (input, territory, expected) in [
+            ("07700 900123", TerritoryCode::GB, "+447700900123"),
+            ("7700 900123", TerritoryCode::GB, "+447700900123"),
+            ("(206) 935-9290", TerritoryCode::US, "+12069359290"),
+            ("1 206 935 9290", TerritoryCode::CA, "+12069359290"),
+            ("06 1234 5678", TerritoryCode::IT, "+390612345678"),
+            ("8 495 123-45-67", TerritoryCode::RU, "+74951234567"),
+            ("800 555 3535", TerritoryCode::RU, "+78005553535"),
+            ("8 800 555 3535", TerritoryCode::RU, "+78005553535"),
+            ("07781 123456", TerritoryCode::JE, "+447781123456"),
+        ] {
+            let phone = PhoneNumber::parse_national(input, territory).unwrap();
+            assert_eq!(expected, phone.to_string(), "{input}");
+        }
+        let phone = PhoneNumber::parse_national("(206) 935-9290", TerritoryCode::CA).unwrap();
+        assert_eq!(TerritoryCode::US, phone.territory());
+
+        for (input, error) in [
+            ("+44 7700 900123", FromPhoneError::InvalidPhoneNumber),
+            ("0", FromPhoneError::InvalidPhoneNumber),
+            ("020 794", FromPhoneError::TooShort { min: 7, actual: 5 }),
+        ] {
+            assert_eq!(
+                Err(error),
+                PhoneNumber::parse_national(input, TerritoryCode::GB),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_default_territory() {
+        for (input, territory, expected) in [
+            ("07700 900123", TerritoryCode::GB, "+447700900123"),
+            ("+44 7700 900123", TerritoryCode::GB, "+447700900123"),
+            ("00 44 7700 900123", TerritoryCode::GB, "+447700900123"),
+            ("011 44 7700 900123", TerritoryCode::US, "+447700900123"),
+            ("810 44 7700 900123", TerritoryCode::RU, "+447700900123"),
+            ("8 495 123-45-67", TerritoryCode::RU, "+74951234567"),
+            ("0011 44 7700 900123", TerritoryCode::AU, "+447700900123"),
+            ("0015 44 7700 900123", TerritoryCode::AU, "+447700900123"),
+            ("0021 44 7700 900123", TerritoryCode::BR, "+447700900123"),
+            ("(11) 91234-5678", TerritoryCode::BR, "+5511912345678"),
+            ("06 1234 5678", TerritoryCode::IT, "+390612345678"),
+            ("00 39 06 1234 5678", TerritoryCode::IT, "+390612345678"),
+        ] {
+            let phone = PhoneNumber::parse_with_default(input, territory).unwrap();
+            assert_eq!(expected, phone.to_string(), "{input}");
+        }
+        assert_eq!(
+            Err(FromPhoneError::TooShort { min: 9, actual: 4 }),
+            PhoneNumber::parse_with_default("0 1234", TerritoryCode::FR)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//!
+//! Tables of phone number prefixes: the leading digits of international phone numbers, including
+//! the calling code, like the prefixes of the lookup table, e.g. `4930` for Berlin.
+//!
+
+use crate::PhoneNumber;
+
+/// An entry of a table of prefixes
+pub(crate) trait Prefixed {
+    fn prefix(&self) -> u64;
+}
+
+impl<T> Prefixed for (u64, T) {
+    fn prefix(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Finds the entry of the longest prefix of a phone number in a table sorted by prefix
+pub(crate) fn longest_prefix<T: Prefixed>(
+    table: &'static [T],
+    phone: &PhoneNumber,
+) -> Option<&'static T> {
+    let digits = format!("{}{}", phone.calling_code(), phone.national_number_digits());
+    (1..=digits.len()).rev().find_map(|len| {
+        let prefix: u64 = digits[..len].parse().ok()?;
+        let index = table.binary_search_by_key(&prefix, Prefixed::prefix).ok()?;
+        Some(&table[index])
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::metadata;
+    use std::convert::TryFrom;
+
+    /// Checks that a table is sorted by prefix without duplicates, and that its prefixes extend
+    /// those of the lookup table or are extended by them
+    pub(crate) fn check_table<T: Prefixed>(table: &[T]) {
+        assert!(table
+            .windows(2)
+            .all(|pair| pair[0].prefix() < pair[1].prefix()));
+        for entry in table {
+            let prefix = entry.prefix().to_string();
+            let known = metadata::LOOKUP_TABLE.iter().any(|(lookup, _)| {
+                let lookup = lookup.to_string();
+                prefix.starts_with(&lookup) || lookup.starts_with(&prefix)
+            });
+            assert!(known, "{}", prefix);
+        }
+    }
+
+    #[test]
+    fn find_longest_prefixes() {
+        static TABLE: [(u64, &str); 4] = [
+            (1, "NANP"),
+            (44, "UK"),
+            (1212, "Manhattan"),
+            (4420, "London"),
+        ];
+        check_table(&TABLE);
+        for (phone, found) in [
+            (12125550123, Some("Manhattan")),
+            (13125550123, Some("NANP")),
+            (442079460958, Some("London")),
+            (441614960000, Some("UK")),
+            (33123456789, None),
+        ] {
+            let phone = PhoneNumber::try_from(phone).unwrap();
+            let entry = longest_prefix(&TABLE, &phone);
+            assert_eq!(found, entry.map(|&(_, name)| name), "{}", phone);
+        }
+    }
+}
@@ -0,0 +1,565 @@
+//!
+//! Serialization with [serde](https://serde.rs), enabled by the `serde` feature.
+//!
+//! By default, a [`TerritoryCode`] is its ISO 3166-1 alpha-2 code, a [`SubdivisionCode`] its
+//! ISO 3166-2 code, [`CallingCodes`] are an array of integers, a [`PhoneNumber`] is an E.164
+//! string like `"+442079460958"` and a [`FromPhoneError`] is a struct with the `kind` of error
+//! and its details, like `{"kind":"TooShort","min":7,"actual":5}`. The [`alpha3`] and [`numeric`]
+//! modules serialize territory codes differently:
+//! ```
+//! use e164_phones_countries::TerritoryCode;
+//! # extern crate serde_derive;
+//! # use serde_derive::Serialize;
+//! #[derive(Serialize)]
+//! struct Address {
+//!     #[serde(with = "e164_phones_countries::serde::alpha3")]
+//!     country: TerritoryCode,
+//! }
+//! let address = Address { country: TerritoryCode::NL };
+//! assert_eq!(r#"{"country":"NLD"}"#, serde_json::to_string(&address).unwrap());
+//! ```
+//!
+
+use std::convert::TryFrom;
+use std::fmt::Formatter;
+use std::num::NonZeroU32;
+
+use serde_crate::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde_crate::ser::{Error as _, SerializeSeq, SerializeStruct};
+use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    CallingCodes, CallingCodesInner, FromPhoneError, PhoneNumber, SubdivisionCode, TerritoryCode,
+};
+
+impl Serialize for TerritoryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for TerritoryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Alpha2Visitor;
+
+        impl Visitor<'_> for Alpha2Visitor {
+            type Value = TerritoryCode;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an ISO 3166-1 alpha-2 territory code")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                TerritoryCode::from_name(v)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Alpha2Visitor)
+    }
+}
+
+/// Serializes a [`TerritoryCode`] as its ISO 3166-1 alpha-3 code, e.g. `"NLD"`. Use it with
+/// `#[serde(with = "e164_phones_countries::serde::alpha3")]`.
+pub mod alpha3 {
+    use std::fmt::Formatter;
+
+    use serde_crate::de::{Error, Unexpected, Visitor};
+    use serde_crate::ser::Error as _;
+    use serde_crate::{Deserializer, Serializer};
+
+    use crate::TerritoryCode;
+
+    /// Serializes the alpha-3 code. Fails for the territories which have none, see
+    /// [`TerritoryCode::alpha3`].
+    pub fn serialize<S: Serializer>(
+        territory: &TerritoryCode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match territory.alpha3() {
+            Some(alpha3) => serializer.serialize_str(alpha3),
+            None => Err(S::Error::custom(format_args!(
+                "territory {territory} has no ISO 3166-1 alpha-3 code"
+            ))),
+        }
+    }
+
+    /// Deserializes an alpha-3 code
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TerritoryCode, D::Error> {
+        struct Alpha3Visitor;
+
+        impl Visitor<'_> for Alpha3Visitor {
+            type Value = TerritoryCode;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an ISO 3166-1 alpha-3 territory code")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                TerritoryCode::from_alpha3(v)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Alpha3Visitor)
+    }
+}
+
+/// Serializes a [`TerritoryCode`] as its ISO 3166-1 numeric code, e.g. `528`. Use it with
+/// `#[serde(with = "e164_phones_countries::serde::numeric")]`.
+pub mod numeric {
+    use std::convert::TryFrom;
+    use std::fmt::Formatter;
+
+    use serde_crate::de::{Error, Unexpected, Visitor};
+    use serde_crate::ser::Error as _;
+    use serde_crate::{Deserializer, Serializer};
+
+    use crate::TerritoryCode;
+
+    /// Serializes the numeric code. Fails for the territories which have none, see
+    /// [`TerritoryCode::numeric`].
+    pub fn serialize<S: Serializer>(
+        territory: &TerritoryCode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match territory.numeric() {
+            Some(numeric) => serializer.serialize_u16(numeric),
+            None => Err(S::Error::custom(format_args!(
+                "territory {territory} has no ISO 3166-1 numeric code"
+            ))),
+        }
+    }
+
+    /// Deserializes a numeric code
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TerritoryCode, D::Error> {
+        struct NumericVisitor;
+
+        impl Visitor<'_> for NumericVisitor {
+            type Value = TerritoryCode;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an ISO 3166-1 numeric territory code")
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+                u16::try_from(v)
+                    .ok()
+                    .and_then(TerritoryCode::from_numeric)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+                u16::try_from(v)
+                    .ok()
+                    .and_then(TerritoryCode::from_numeric)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+        }
+
+        deserializer.deserialize_u16(NumericVisitor)
+    }
+}
+
+impl Serialize for CallingCodes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.all().len()))?;
+        for code in self.all() {
+            seq.serialize_element(&code.get())?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CallingCodes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CallingCodesVisitor;
+
+        impl<'de> Visitor<'de> for CallingCodesVisitor {
+            type Value = CallingCodes;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an array of one to three calling codes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut codes = Vec::with_capacity(3);
+                while let Some(code) = seq.next_element::<u32>()? {
+                    if codes.len() == 3 {
+                        return Err(A::Error::invalid_length(codes.len() + 1, &self));
+                    }
+                    let code = NonZeroU32::new(code).ok_or_else(|| {
+                        A::Error::invalid_value(Unexpected::Unsigned(0), &"a non-zero calling code")
+                    })?;
+                    codes.push(code);
+                }
+                let inner = match codes[..] {
+                    [a] => CallingCodesInner::Single([a]),
+                    [a, b] => CallingCodesInner::Two([a, b]),
+                    [a, b, c] => CallingCodesInner::Three([a, b, c]),
+                    _ => return Err(A::Error::invalid_length(codes.len(), &self)),
+                };
+                Ok(CallingCodes(inner))
+            }
+        }
+
+        deserializer.deserialize_seq(CallingCodesVisitor)
+    }
+}
+
+impl Serialize for PhoneNumber {
+    /// Serializes the E.164 string. Fails when the national significant number has an invalid
+    /// length, as the string would not be deserialized, see [`PhoneNumber::validate`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.check_length() {
+            Ok(_) => serializer.collect_str(self),
+            Err(error) => Err(S::Error::custom(format_args!(
+                "cannot serialize phone number {self}: {error}"
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PhoneNumberVisitor;
+
+        impl Visitor<'_> for PhoneNumberVisitor {
+            type Value = PhoneNumber;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a phone number in international format")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map_err(|error| E::custom(format_args!("invalid phone number {v:?}: {error}")))
+            }
+        }
+
+        deserializer.deserialize_str(PhoneNumberVisitor)
+    }
+}
+
+impl Serialize for SubdivisionCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for SubdivisionCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SubdivisionVisitor;
+
+        impl Visitor<'_> for SubdivisionVisitor {
+            type Value = SubdivisionCode;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an ISO 3166-2 subdivision code")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                SubdivisionCode::from_code(v)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(SubdivisionVisitor)
+    }
+}
+
+/// The fields of a serialized [`FromPhoneError`]
+const ERROR_FIELDS: &[&str] = &["kind", "min", "max", "actual", "code", "position", "ch"];
+
+/// The kinds of [`FromPhoneError`], named after their variants
+const ERROR_KINDS: &[&str] = &[
+    "InvalidPhoneNumber",
+    "NotFound",
+    "TooShort",
+    "TooLong",
+    "ExceedsE164MaxLength",
+    "UnassignedCallingCode",
+    "UnassignedNanpAreaCode",
+    "InvalidCharacter",
+];
+
+impl Serialize for FromPhoneError {
+    /// Serializes the kind of error, i.e. the name of its variant, along with its fields, e.g.
+    /// `{"kind":"InvalidCharacter","position":3,"ch":"x"}`. The number of an unassigned calling
+    /// code or area code is its `code`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (kind, len) = match *self {
+            Self::InvalidPhoneNumber => ("InvalidPhoneNumber", 1),
+            Self::NotFound => ("NotFound", 1),
+            Self::TooShort { .. } => ("TooShort", 3),
+            Self::TooLong { .. } => ("TooLong", 3),
+            Self::ExceedsE164MaxLength { .. } => ("ExceedsE164MaxLength", 2),
+            Self::UnassignedCallingCode(_) => ("UnassignedCallingCode", 2),
+            Self::UnassignedNanpAreaCode(_) => ("UnassignedNanpAreaCode", 2),
+            Self::InvalidCharacter { .. } => ("InvalidCharacter", 3),
+        };
+        let mut error = serializer.serialize_struct("FromPhoneError", len)?;
+        error.serialize_field("kind", kind)?;
+        match *self {
+            Self::InvalidPhoneNumber | Self::NotFound => {}
+            Self::TooShort { min, actual } => {
+                error.serialize_field("min", &min)?;
+                error.serialize_field("actual", &actual)?;
+            }
+            Self::TooLong { max, actual } => {
+                error.serialize_field("max", &max)?;
+                error.serialize_field("actual", &actual)?;
+            }
+            Self::ExceedsE164MaxLength { actual } => error.serialize_field("actual", &actual)?,
+            Self::UnassignedCallingCode(code) => error.serialize_field("code", &code)?,
+            Self::UnassignedNanpAreaCode(code) => error.serialize_field("code", &code)?,
+            Self::InvalidCharacter { position, ch } => {
+                error.serialize_field("position", &position)?;
+                error.serialize_field("ch", &ch)?;
+            }
+        }
+        error.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FromPhoneError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FromPhoneErrorVisitor;
+
+        /// The value of a field required by the kind of error
+        fn required<T, E: Error>(value: Option<T>, field: &'static str) -> Result<T, E> {
+            value.ok_or_else(|| E::missing_field(field))
+        }
+
+        impl<'de> Visitor<'de> for FromPhoneErrorVisitor {
+            type Value = FromPhoneError;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a phone number error")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut kind: Option<String> = None;
+                let (mut min, mut max, mut actual, mut code) = (None, None, None, None);
+                let (mut position, mut ch) = (None, None);
+                while let Some(field) = map.next_key::<String>()? {
+                    match field.as_str() {
+                        "kind" => kind = Some(map.next_value()?),
+                        "min" => min = Some(map.next_value()?),
+                        "max" => max = Some(map.next_value()?),
+                        "actual" => actual = Some(map.next_value()?),
+                        "code" => code = Some(map.next_value::<u32>()?),
+                        "position" => position = Some(map.next_value()?),
+                        "ch" => ch = Some(map.next_value()?),
+                        _ => return Err(A::Error::unknown_field(&field, ERROR_FIELDS)),
+                    }
+                }
+                Ok(match required(kind, "kind")?.as_str() {
+                    "InvalidPhoneNumber" => FromPhoneError::InvalidPhoneNumber,
+                    "NotFound" => FromPhoneError::NotFound,
+                    "TooShort" => FromPhoneError::TooShort {
+                        min: required(min, "min")?,
+                        actual: required(actual, "actual")?,
+                    },
+                    "TooLong" => FromPhoneError::TooLong {
+                        max: required(max, "max")?,
+                        actual: required(actual, "actual")?,
+                    },
+                    "ExceedsE164MaxLength" => FromPhoneError::ExceedsE164MaxLength {
+                        actual: required(actual, "actual")?,
+                    },
+                    "UnassignedCallingCode" => {
+                        FromPhoneError::UnassignedCallingCode(required(code, "code")?)
+                    }
+                    "UnassignedNanpAreaCode" => {
+                        let code = required(code, "code")?;
+                        let code = u16::try_from(code).map_err(|_| {
+                            A::Error::invalid_value(
+                                Unexpected::Unsigned(code.into()),
+                                &"a NANP area code",
+                            )
+                        })?;
+                        FromPhoneError::UnassignedNanpAreaCode(code)
+                    }
+                    "InvalidCharacter" => FromPhoneError::InvalidCharacter {
+                        position: required(position, "position")?,
+                        ch: required(ch, "ch")?,
+                    },
+                    kind => return Err(A::Error::unknown_variant(kind, ERROR_KINDS)),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("FromPhoneError", ERROR_FIELDS, FromPhoneErrorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn territory_codes() {
+        assert_eq!(
+            r#""NL""#,
+            serde_json::to_string(&TerritoryCode::NL).unwrap()
+        );
+        assert_eq!(
+            TerritoryCode::NL,
+            serde_json::from_str::<TerritoryCode>(r#""NL""#).unwrap()
+        );
+        let error = serde_json::from_str::<TerritoryCode>(r#""QQ""#).unwrap_err();
+        assert_eq!(
+            r#"invalid value: string "QQ", expected an ISO 3166-1 alpha-2 territory code at line 1 column 4"#,
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn subdivision_codes() {
+        let bavaria = SubdivisionCode::from_code("DE-BY").unwrap();
+        assert_eq!(r#""DE-BY""#, serde_json::to_string(&bavaria).unwrap());
+        assert_eq!(
+            bavaria,
+            serde_json::from_str::<SubdivisionCode>(r#""DE-BY""#).unwrap()
+        );
+        let error = serde_json::from_str::<SubdivisionCode>(r#""DE-XX""#).unwrap_err();
+        assert_eq!(
+            r#"invalid value: string "DE-XX", expected an ISO 3166-2 subdivision code at line 1 column 7"#,
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn territory_codes_as_alpha3_and_numeric() {
+        let mut json = serde_json::Serializer::new(Vec::new());
+        alpha3::serialize(&TerritoryCode::NL, &mut json).unwrap();
+        assert_eq!(br#""NLD""#, &json.into_inner()[..]);
+        let mut json = serde_json::Deserializer::from_str(r#""NLD""#);
+        assert_eq!(TerritoryCode::NL, alpha3::deserialize(&mut json).unwrap());
+        let mut json = serde_json::Deserializer::from_str(r#""NL""#);
+        let error = alpha3::deserialize(&mut json).unwrap_err();
+        assert!(error.to_string().contains(r#"string "NL""#), "{}", error);
+
+        let mut json = serde_json::Serializer::new(Vec::new());
+        numeric::serialize(&TerritoryCode::NL, &mut json).unwrap();
+        assert_eq!(b"528", &json.into_inner()[..]);
+        let mut json = serde_json::Deserializer::from_str("528");
+        assert_eq!(TerritoryCode::NL, numeric::deserialize(&mut json).unwrap());
+        let mut json = serde_json::Deserializer::from_str("999");
+        let error = numeric::deserialize(&mut json).unwrap_err();
+        assert!(error.to_string().contains("integer `999`"), "{}", error);
+
+        let mut json = serde_json::Serializer::new(Vec::new());
+        let error = numeric::serialize(&TerritoryCode::XT, &mut json).unwrap_err();
+        assert_eq!(
+            "territory XT has no ISO 3166-1 numeric code",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn calling_codes() {
+        let codes = TerritoryCode::BQ.calling_codes();
+        let json = serde_json::to_string(&codes).unwrap();
+        assert_eq!("[5993,5994,5997]", json);
+        assert_eq!(codes, serde_json::from_str::<CallingCodes>(&json).unwrap());
+        assert_eq!(
+            TerritoryCode::FR.calling_codes(),
+            serde_json::from_str::<CallingCodes>("[33]").unwrap()
+        );
+        for (json, message) in [
+            ("[]", "invalid length 0"),
+            ("[1,2,3,4]", "invalid length 4"),
+            ("[0]", "invalid value: integer `0`"),
+        ] {
+            let error = serde_json::from_str::<CallingCodes>(json).unwrap_err();
+            assert!(
+                error.to_string().starts_with(message),
+                "{}: {}",
+                json,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn phone_numbers() {
+        let phone: PhoneNumber = "+39 06 1234 5678".parse().unwrap();
+        let json = serde_json::to_string(&phone).unwrap();
+        assert_eq!(r#""+390612345678""#, json);
+        assert_eq!(phone, serde_json::from_str::<PhoneNumber>(&json).unwrap());
+
+        let error = serde_json::from_str::<PhoneNumber>(r#""+44 20 794""#).unwrap_err();
+        assert_eq!(
+            r#"invalid phone number "+44 20 794": National number too short: 5 digits, expected at least 7 at line 1 column 12"#,
+            error.to_string()
+        );
+
+        for phone in [442079460958, 390612345678, 12125550123, 5511912345678] {
+            let phone = PhoneNumber::try_from(phone).unwrap();
+            let json = serde_json::to_string(&phone).unwrap();
+            assert_eq!(phone, serde_json::from_str::<PhoneNumber>(&json).unwrap());
+        }
+        let phone = PhoneNumber::try_from(4420794).unwrap();
+        let error = serde_json::to_string(&phone).unwrap_err();
+        assert_eq!(
+            "cannot serialize phone number +4420794: National number too short: 5 digits, expected at least 7",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn from_phone_errors() {
+        for (error, json) in [
+            (
+                FromPhoneError::InvalidPhoneNumber,
+                r#"{"kind":"InvalidPhoneNumber"}"#,
+            ),
+            (
+                FromPhoneError::TooShort { min: 7, actual: 5 },
+                r#"{"kind":"TooShort","min":7,"actual":5}"#,
+            ),
+            (
+                FromPhoneError::UnassignedNanpAreaCode(370),
+                r#"{"kind":"UnassignedNanpAreaCode","code":370}"#,
+            ),
+            (
+                FromPhoneError::InvalidCharacter {
+                    position: 3,
+                    ch: 'x',
+                },
+                r#"{"kind":"InvalidCharacter","position":3,"ch":"x"}"#,
+            ),
+        ] {
+            assert_eq!(json, serde_json::to_string(&error).unwrap());
+            assert_eq!(error, serde_json::from_str::<FromPhoneError>(json).unwrap());
+        }
+        for (json, message) in [
+            (r#"{"kind":"TooLong","actual":5}"#, "missing field `max`"),
+            (r#"{"kind":"TooLong"}"#, "missing field `max`"),
+            (r#"{"min":7}"#, "missing field `kind`"),
+            (r#"{"kind":"Unknown"}"#, "unknown variant `Unknown`"),
+            (
+                r#"{"kind":"NotFound","digits":3}"#,
+                "unknown field `digits`",
+            ),
+            (
+                r#"{"kind":"UnassignedNanpAreaCode","code":70000}"#,
+                "invalid value: integer `70000`",
+            ),
+        ] {
+            let error = serde_json::from_str::<FromPhoneError>(json).unwrap_err();
+            assert!(
+                error.to_string().starts_with(message),
+                "{}: {}",
+                json,
+                error
+            );
+        }
+    }
+}
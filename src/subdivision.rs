@@ -0,0 +1,208 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::TerritoryCode;
+
+mod data;
+
+/// A subdivision of a territory, from ISO 3166-2
+struct Subdivision {
+    code: &'static str,
+    territory: TerritoryCode,
+    category: &'static str,
+    name: &'static str,
+}
+
+///
+/// An ISO 3166-2 subdivision of a territory, like a US state, a French department or a German
+/// Land. Its code is made of the code of its territory, a hyphen and up to three letters or digits:
+///
+/// ```
+/// use e164_phones_countries::{SubdivisionCode, TerritoryCode};
+/// let scotland: SubdivisionCode = "GB-SCT".parse().unwrap();
+/// assert_eq!(TerritoryCode::GB, scotland.territory());
+/// assert_eq!("Scotland", scotland.name());
+/// assert_eq!("country", scotland.category());
+/// let paris = SubdivisionCode::from_code("FR-75").unwrap();
+/// assert_eq!("Paris", paris.name());
+/// ```
+///
+/// Subdivisions which are territories of their own, like `FR-GP` for Guadeloupe or `US-PR` for
+/// Puerto Rico, are [`TerritoryCode`]s instead.
+///
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SubdivisionCode(u16);
+
+impl SubdivisionCode {
+    fn data(&self) -> &'static Subdivision {
+        &data::SUBDIVISIONS[usize::from(self.0)]
+    }
+
+    /// Finds a subdivision by the code of its territory and the part of its code after the hyphen
+    pub(crate) fn find(territory: TerritoryCode, local_code: &str) -> Option<Self> {
+        let key = (territory.name(), local_code);
+        let index = data::SUBDIVISIONS
+            .binary_search_by_key(&key, |subdivision| {
+                (&subdivision.code[..2], &subdivision.code[3..])
+            })
+            .ok()?;
+        Some(Self(index as u16))
+    }
+
+    /// Yields a subdivision by ISO 3166-2 code, which must be uppercase. I.e.:
+    /// ```
+    /// use e164_phones_countries::SubdivisionCode;
+    /// assert_eq!("Bavaria", SubdivisionCode::from_code("DE-BY").unwrap().name());
+    /// assert_eq!(None, SubdivisionCode::from_code("DE-XX"));
+    /// assert_eq!(None, SubdivisionCode::from_code("de-by"));
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        let (territory, local_code) = code.split_at(code.find('-')?);
+        Self::find(TerritoryCode::from_name(territory)?, &local_code[1..])
+    }
+
+    /// Iterates over all the subdivisions, sorted by code
+    pub fn iter() -> impl Iterator<Item = Self> {
+        (0..data::SUBDIVISIONS.len()).map(|index| Self(index as u16))
+    }
+
+    /// Gets the ISO 3166-2 code of this subdivision, which is always uppercase, e.g. `US-NY`
+    pub fn code(&self) -> &'static str {
+        self.data().code
+    }
+
+    /// Gets the territory this subdivision belongs to
+    pub fn territory(&self) -> TerritoryCode {
+        self.data().territory
+    }
+
+    /// Gets the English name of this subdivision
+    pub fn name(&self) -> &'static str {
+        self.data().name
+    }
+
+    /// Gets the ISO 3166-2 category of this subdivision, e.g. `state`, `province` or
+    /// `metropolitan department`. It is lowercase, except for proper nouns like the German `Land`.
+    pub fn category(&self) -> &'static str {
+        self.data().category
+    }
+}
+
+impl TerritoryCode {
+    /// Iterates over the ISO 3166-2 subdivisions of this territory, sorted by code. These are
+    /// those of the first level, like the states of the United States, and also those of the
+    /// second level for Belgium, France, Ireland, Italy and Spain, like the departments within
+    /// the regions of France. The United Kingdom only has its four countries, without their
+    /// counties, council areas or districts, and the smallest territories have none.
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(16, TerritoryCode::DE.subdivisions().count());
+    /// assert_eq!(13 + 96, TerritoryCode::FR.subdivisions().count());
+    /// assert_eq!(4, TerritoryCode::GB.subdivisions().count());
+    /// assert_eq!(0, TerritoryCode::VA.subdivisions().count());
+    /// ```
+    pub fn subdivisions(&self) -> impl Iterator<Item = SubdivisionCode> {
+        let territory = self.name();
+        let start =
+            data::SUBDIVISIONS.partition_point(|subdivision| &subdivision.code[..2] < territory);
+        let end =
+            data::SUBDIVISIONS.partition_point(|subdivision| &subdivision.code[..2] <= territory);
+        (start..end).map(|index| SubdivisionCode(index as u16))
+    }
+}
+
+impl Debug for SubdivisionCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SubdivisionCode")
+            .field(&self.code())
+            .finish()
+    }
+}
+
+impl Display for SubdivisionCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for SubdivisionCode {
+    type Err = FromCodeError;
+
+    /// Parses a subdivision by code, like [`SubdivisionCode::from_code`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_code(s).ok_or(FromCodeError)
+    }
+}
+
+impl TryFrom<&str> for SubdivisionCode {
+    type Error = FromCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl AsRef<str> for SubdivisionCode {
+    fn as_ref(&self) -> &str {
+        self.code()
+    }
+}
+
+/// The code did not match any subdivision
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FromCodeError;
+
+impl Display for FromCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Did not match any subdivision code")
+    }
+}
+
+impl Error for FromCodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_subdivisions() {
+        let new_york = SubdivisionCode::find(TerritoryCode::US, "NY").unwrap();
+        assert_eq!("US-NY", new_york.code());
+        assert_eq!("US-NY", new_york.to_string());
+        assert_eq!("SubdivisionCode(\"US-NY\")", format!("{:?}", new_york));
+        assert_eq!(None, SubdivisionCode::find(TerritoryCode::CA, "NY"));
+        assert_eq!(Ok(new_york), "US-NY".parse());
+        for code in ["", "US", "US-", "-NY", "US-NY ", "XX-NY", "US-ny", "CA-NY"] {
+            assert_eq!(
+                Err(FromCodeError),
+                code.parse::<SubdivisionCode>(),
+                "{}",
+                code
+            );
+        }
+        assert_eq!(data::SUBDIVISIONS.len(), SubdivisionCode::iter().count());
+        let per_territory: usize = TerritoryCode::iter()
+            .map(|territory| territory.subdivisions().count())
+            .sum();
+        assert_eq!(data::SUBDIVISIONS.len(), per_territory);
+        assert_eq!(
+            vec!["AD-02", "AD-03", "AD-04", "AD-05", "AD-06", "AD-07", "AD-08"],
+            TerritoryCode::AD
+                .subdivisions()
+                .map(|subdivision| subdivision.code())
+                .collect::<Vec<_>>()
+        );
+        assert!(data::SUBDIVISIONS
+            .windows(2)
+            .all(|pair| pair[0].code < pair[1].code));
+        for subdivision in SubdivisionCode::iter() {
+            assert_eq!(subdivision.territory().name(), &subdivision.code()[..2]);
+            assert_eq!(
+                Some(subdivision),
+                SubdivisionCode::from_code(subdivision.code())
+            );
+        }
+    }
+}
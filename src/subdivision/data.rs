@@ -0,0 +1,3138 @@
+// Generated by `cargo run -p metadata-gen` from `data/subdivisions.csv`. Do not edit.
+
+use super::Subdivision;
+use crate::TerritoryCode;
+
+/// All the subdivisions, sorted by code
+#[rustfmt::skip]
+pub(super) static SUBDIVISIONS: [Subdivision; 3129] = [
+    Subdivision { code: "AD-02", territory: TerritoryCode::AD, category: "parish", name: "Canillo" },
+    Subdivision { code: "AD-03", territory: TerritoryCode::AD, category: "parish", name: "Encamp" },
+    Subdivision { code: "AD-04", territory: TerritoryCode::AD, category: "parish", name: "La Massana" },
+    Subdivision { code: "AD-05", territory: TerritoryCode::AD, category: "parish", name: "Ordino" },
+    Subdivision { code: "AD-06", territory: TerritoryCode::AD, category: "parish", name: "Sant Julià de Lòria" },
+    Subdivision { code: "AD-07", territory: TerritoryCode::AD, category: "parish", name: "Andorra la Vella" },
+    Subdivision { code: "AD-08", territory: TerritoryCode::AD, category: "parish", name: "Escaldes-Engordany" },
+    Subdivision { code: "AE-AJ", territory: TerritoryCode::AE, category: "emirate", name: "Ajman" },
+    Subdivision { code: "AE-AZ", territory: TerritoryCode::AE, category: "emirate", name: "Abu Dhabi" },
+    Subdivision { code: "AE-DU", territory: TerritoryCode::AE, category: "emirate", name: "Dubai" },
+    Subdivision { code: "AE-FU", territory: TerritoryCode::AE, category: "emirate", name: "Fujairah" },
+    Subdivision { code: "AE-RK", territory: TerritoryCode::AE, category: "emirate", name: "Ras al-Khaimah" },
+    Subdivision { code: "AE-SH", territory: TerritoryCode::AE, category: "emirate", name: "Sharjah" },
+    Subdivision { code: "AE-UQ", territory: TerritoryCode::AE, category: "emirate", name: "Umm al-Quwain" },
+    Subdivision { code: "AF-BAL", territory: TerritoryCode::AF, category: "province", name: "Balkh" },
+    Subdivision { code: "AF-BAM", territory: TerritoryCode::AF, category: "province", name: "Bamyan" },
+    Subdivision { code: "AF-BDG", territory: TerritoryCode::AF, category: "province", name: "Badghis" },
+    Subdivision { code: "AF-BDS", territory: TerritoryCode::AF, category: "province", name: "Badakhshan" },
+    Subdivision { code: "AF-BGL", territory: TerritoryCode::AF, category: "province", name: "Baghlan" },
+    Subdivision { code: "AF-DAY", territory: TerritoryCode::AF, category: "province", name: "Daykundi" },
+    Subdivision { code: "AF-FRA", territory: TerritoryCode::AF, category: "province", name: "Farah" },
+    Subdivision { code: "AF-FYB", territory: TerritoryCode::AF, category: "province", name: "Faryab" },
+    Subdivision { code: "AF-GHA", territory: TerritoryCode::AF, category: "province", name: "Ghazni" },
+    Subdivision { code: "AF-GHO", territory: TerritoryCode::AF, category: "province", name: "Ghor" },
+    Subdivision { code: "AF-HEL", territory: TerritoryCode::AF, category: "province", name: "Helmand" },
+    Subdivision { code: "AF-HER", territory: TerritoryCode::AF, category: "province", name: "Herat" },
+    Subdivision { code: "AF-JOW", territory: TerritoryCode::AF, category: "province", name: "Jowzjan" },
+    Subdivision { code: "AF-KAB", territory: TerritoryCode::AF, category: "province", name: "Kabul" },
+    Subdivision { code: "AF-KAN", territory: TerritoryCode::AF, category: "province", name: "Kandahar" },
+    Subdivision { code: "AF-KAP", territory: TerritoryCode::AF, category: "province", name: "Kapisa" },
+    Subdivision { code: "AF-KDZ", territory: TerritoryCode::AF, category: "province", name: "Kunduz" },
+    Subdivision { code: "AF-KHO", territory: TerritoryCode::AF, category: "province", name: "Khost" },
+    Subdivision { code: "AF-KNR", territory: TerritoryCode::AF, category: "province", name: "Kunar" },
+    Subdivision { code: "AF-LAG", territory: TerritoryCode::AF, category: "province", name: "Laghman" },
+    Subdivision { code: "AF-LOG", territory: TerritoryCode::AF, category: "province", name: "Logar" },
+    Subdivision { code: "AF-NAN", territory: TerritoryCode::AF, category: "province", name: "Nangarhar" },
+    Subdivision { code: "AF-NIM", territory: TerritoryCode::AF, category: "province", name: "Nimroz" },
+    Subdivision { code: "AF-NUR", territory: TerritoryCode::AF, category: "province", name: "Nuristan" },
+    Subdivision { code: "AF-PAN", territory: TerritoryCode::AF, category: "province", name: "Panjshir" },
+    Subdivision { code: "AF-PAR", territory: TerritoryCode::AF, category: "province", name: "Parwan" },
+    Subdivision { code: "AF-PIA", territory: TerritoryCode::AF, category: "province", name: "Paktia" },
+    Subdivision { code: "AF-PKA", territory: TerritoryCode::AF, category: "province", name: "Paktika" },
+    Subdivision { code: "AF-SAM", territory: TerritoryCode::AF, category: "province", name: "Samangan" },
+    Subdivision { code: "AF-SAR", territory: TerritoryCode::AF, category: "province", name: "Sar-e Pul" },
+    Subdivision { code: "AF-TAK", territory: TerritoryCode::AF, category: "province", name: "Takhar" },
+    Subdivision { code: "AF-URU", territory: TerritoryCode::AF, category: "province", name: "Uruzgan" },
+    Subdivision { code: "AF-WAR", territory: TerritoryCode::AF, category: "province", name: "Wardak" },
+    Subdivision { code: "AF-ZAB", territory: TerritoryCode::AF, category: "province", name: "Zabul" },
+    Subdivision { code: "AG-03", territory: TerritoryCode::AG, category: "parish", name: "Saint George" },
+    Subdivision { code: "AG-04", territory: TerritoryCode::AG, category: "parish", name: "Saint John" },
+    Subdivision { code: "AG-05", territory: TerritoryCode::AG, category: "parish", name: "Saint Mary" },
+    Subdivision { code: "AG-06", territory: TerritoryCode::AG, category: "parish", name: "Saint Paul" },
+    Subdivision { code: "AG-07", territory: TerritoryCode::AG, category: "parish", name: "Saint Peter" },
+    Subdivision { code: "AG-08", territory: TerritoryCode::AG, category: "parish", name: "Saint Philip" },
+    Subdivision { code: "AG-10", territory: TerritoryCode::AG, category: "dependency", name: "Barbuda" },
+    Subdivision { code: "AG-11", territory: TerritoryCode::AG, category: "dependency", name: "Redonda" },
+    Subdivision { code: "AL-01", territory: TerritoryCode::AL, category: "county", name: "Berat" },
+    Subdivision { code: "AL-02", territory: TerritoryCode::AL, category: "county", name: "Durrës" },
+    Subdivision { code: "AL-03", territory: TerritoryCode::AL, category: "county", name: "Elbasan" },
+    Subdivision { code: "AL-04", territory: TerritoryCode::AL, category: "county", name: "Fier" },
+    Subdivision { code: "AL-05", territory: TerritoryCode::AL, category: "county", name: "Gjirokastër" },
+    Subdivision { code: "AL-06", territory: TerritoryCode::AL, category: "county", name: "Korçë" },
+    Subdivision { code: "AL-07", territory: TerritoryCode::AL, category: "county", name: "Kukës" },
+    Subdivision { code: "AL-08", territory: TerritoryCode::AL, category: "county", name: "Lezhë" },
+    Subdivision { code: "AL-09", territory: TerritoryCode::AL, category: "county", name: "Dibër" },
+    Subdivision { code: "AL-10", territory: TerritoryCode::AL, category: "county", name: "Shkodër" },
+    Subdivision { code: "AL-11", territory: TerritoryCode::AL, category: "county", name: "Tirana" },
+    Subdivision { code: "AL-12", territory: TerritoryCode::AL, category: "county", name: "Vlorë" },
+    Subdivision { code: "AM-AG", territory: TerritoryCode::AM, category: "region", name: "Aragatsotn" },
+    Subdivision { code: "AM-AR", territory: TerritoryCode::AM, category: "region", name: "Ararat" },
+    Subdivision { code: "AM-AV", territory: TerritoryCode::AM, category: "region", name: "Armavir" },
+    Subdivision { code: "AM-ER", territory: TerritoryCode::AM, category: "city", name: "Yerevan" },
+    Subdivision { code: "AM-GR", territory: TerritoryCode::AM, category: "region", name: "Gegharkunik" },
+    Subdivision { code: "AM-KT", territory: TerritoryCode::AM, category: "region", name: "Kotayk" },
+    Subdivision { code: "AM-LO", territory: TerritoryCode::AM, category: "region", name: "Lori" },
+    Subdivision { code: "AM-SH", territory: TerritoryCode::AM, category: "region", name: "Shirak" },
+    Subdivision { code: "AM-SU", territory: TerritoryCode::AM, category: "region", name: "Syunik" },
+    Subdivision { code: "AM-TV", territory: TerritoryCode::AM, category: "region", name: "Tavush" },
+    Subdivision { code: "AM-VD", territory: TerritoryCode::AM, category: "region", name: "Vayots Dzor" },
+    Subdivision { code: "AO-BGO", territory: TerritoryCode::AO, category: "province", name: "Bengo" },
+    Subdivision { code: "AO-BGU", territory: TerritoryCode::AO, category: "province", name: "Benguela" },
+    Subdivision { code: "AO-BIE", territory: TerritoryCode::AO, category: "province", name: "Bié" },
+    Subdivision { code: "AO-CAB", territory: TerritoryCode::AO, category: "province", name: "Cabinda" },
+    Subdivision { code: "AO-CCU", territory: TerritoryCode::AO, category: "province", name: "Cuando Cubango" },
+    Subdivision { code: "AO-CNN", territory: TerritoryCode::AO, category: "province", name: "Cunene" },
+    Subdivision { code: "AO-CNO", territory: TerritoryCode::AO, category: "province", name: "Cuanza Norte" },
+    Subdivision { code: "AO-CUS", territory: TerritoryCode::AO, category: "province", name: "Cuanza Sul" },
+    Subdivision { code: "AO-HUA", territory: TerritoryCode::AO, category: "province", name: "Huambo" },
+    Subdivision { code: "AO-HUI", territory: TerritoryCode::AO, category: "province", name: "Huíla" },
+    Subdivision { code: "AO-LNO", territory: TerritoryCode::AO, category: "province", name: "Lunda Norte" },
+    Subdivision { code: "AO-LSU", territory: TerritoryCode::AO, category: "province", name: "Lunda Sul" },
+    Subdivision { code: "AO-LUA", territory: TerritoryCode::AO, category: "province", name: "Luanda" },
+    Subdivision { code: "AO-MAL", territory: TerritoryCode::AO, category: "province", name: "Malanje" },
+    Subdivision { code: "AO-MOX", territory: TerritoryCode::AO, category: "province", name: "Moxico" },
+    Subdivision { code: "AO-NAM", territory: TerritoryCode::AO, category: "province", name: "Namibe" },
+    Subdivision { code: "AO-UIG", territory: TerritoryCode::AO, category: "province", name: "Uíge" },
+    Subdivision { code: "AO-ZAI", territory: TerritoryCode::AO, category: "province", name: "Zaire" },
+    Subdivision { code: "AR-A", territory: TerritoryCode::AR, category: "province", name: "Salta" },
+    Subdivision { code: "AR-B", territory: TerritoryCode::AR, category: "province", name: "Buenos Aires" },
+    Subdivision { code: "AR-C", territory: TerritoryCode::AR, category: "city", name: "Buenos Aires City" },
+    Subdivision { code: "AR-D", territory: TerritoryCode::AR, category: "province", name: "San Luis" },
+    Subdivision { code: "AR-E", territory: TerritoryCode::AR, category: "province", name: "Entre Ríos" },
+    Subdivision { code: "AR-F", territory: TerritoryCode::AR, category: "province", name: "La Rioja" },
+    Subdivision { code: "AR-G", territory: TerritoryCode::AR, category: "province", name: "Santiago del Estero" },
+    Subdivision { code: "AR-H", territory: TerritoryCode::AR, category: "province", name: "Chaco" },
+    Subdivision { code: "AR-J", territory: TerritoryCode::AR, category: "province", name: "San Juan" },
+    Subdivision { code: "AR-K", territory: TerritoryCode::AR, category: "province", name: "Catamarca" },
+    Subdivision { code: "AR-L", territory: TerritoryCode::AR, category: "province", name: "La Pampa" },
+    Subdivision { code: "AR-M", territory: TerritoryCode::AR, category: "province", name: "Mendoza" },
+    Subdivision { code: "AR-N", territory: TerritoryCode::AR, category: "province", name: "Misiones" },
+    Subdivision { code: "AR-P", territory: TerritoryCode::AR, category: "province", name: "Formosa" },
+    Subdivision { code: "AR-Q", territory: TerritoryCode::AR, category: "province", name: "Neuquén" },
+    Subdivision { code: "AR-R", territory: TerritoryCode::AR, category: "province", name: "Río Negro" },
+    Subdivision { code: "AR-S", territory: TerritoryCode::AR, category: "province", name: "Santa Fe" },
+    Subdivision { code: "AR-T", territory: TerritoryCode::AR, category: "province", name: "Tucumán" },
+    Subdivision { code: "AR-U", territory: TerritoryCode::AR, category: "province", name: "Chubut" },
+    Subdivision { code: "AR-V", territory: TerritoryCode::AR, category: "province", name: "Tierra del Fuego" },
+    Subdivision { code: "AR-W", territory: TerritoryCode::AR, category: "province", name: "Corrientes" },
+    Subdivision { code: "AR-X", territory: TerritoryCode::AR, category: "province", name: "Córdoba" },
+    Subdivision { code: "AR-Y", territory: TerritoryCode::AR, category: "province", name: "Jujuy" },
+    Subdivision { code: "AR-Z", territory: TerritoryCode::AR, category: "province", name: "Santa Cruz" },
+    Subdivision { code: "AT-1", territory: TerritoryCode::AT, category: "state", name: "Burgenland" },
+    Subdivision { code: "AT-2", territory: TerritoryCode::AT, category: "state", name: "Carinthia" },
+    Subdivision { code: "AT-3", territory: TerritoryCode::AT, category: "state", name: "Lower Austria" },
+    Subdivision { code: "AT-4", territory: TerritoryCode::AT, category: "state", name: "Upper Austria" },
+    Subdivision { code: "AT-5", territory: TerritoryCode::AT, category: "state", name: "Salzburg" },
+    Subdivision { code: "AT-6", territory: TerritoryCode::AT, category: "state", name: "Styria" },
+    Subdivision { code: "AT-7", territory: TerritoryCode::AT, category: "state", name: "Tyrol" },
+    Subdivision { code: "AT-8", territory: TerritoryCode::AT, category: "state", name: "Vorarlberg" },
+    Subdivision { code: "AT-9", territory: TerritoryCode::AT, category: "state", name: "Vienna" },
+    Subdivision { code: "AU-ACT", territory: TerritoryCode::AU, category: "territory", name: "Australian Capital Territory" },
+    Subdivision { code: "AU-NSW", territory: TerritoryCode::AU, category: "state", name: "New South Wales" },
+    Subdivision { code: "AU-NT", territory: TerritoryCode::AU, category: "territory", name: "Northern Territory" },
+    Subdivision { code: "AU-QLD", territory: TerritoryCode::AU, category: "state", name: "Queensland" },
+    Subdivision { code: "AU-SA", territory: TerritoryCode::AU, category: "state", name: "South Australia" },
+    Subdivision { code: "AU-TAS", territory: TerritoryCode::AU, category: "state", name: "Tasmania" },
+    Subdivision { code: "AU-VIC", territory: TerritoryCode::AU, category: "state", name: "Victoria" },
+    Subdivision { code: "AU-WA", territory: TerritoryCode::AU, category: "state", name: "Western Australia" },
+    Subdivision { code: "BA-BIH", territory: TerritoryCode::BA, category: "entity", name: "Federation of Bosnia and Herzegovina" },
+    Subdivision { code: "BA-BRC", territory: TerritoryCode::BA, category: "district with special status", name: "Brčko District" },
+    Subdivision { code: "BA-SRP", territory: TerritoryCode::BA, category: "entity", name: "Republika Srpska" },
+    Subdivision { code: "BB-01", territory: TerritoryCode::BB, category: "parish", name: "Christ Church" },
+    Subdivision { code: "BB-02", territory: TerritoryCode::BB, category: "parish", name: "Saint Andrew" },
+    Subdivision { code: "BB-03", territory: TerritoryCode::BB, category: "parish", name: "Saint George" },
+    Subdivision { code: "BB-04", territory: TerritoryCode::BB, category: "parish", name: "Saint James" },
+    Subdivision { code: "BB-05", territory: TerritoryCode::BB, category: "parish", name: "Saint John" },
+    Subdivision { code: "BB-06", territory: TerritoryCode::BB, category: "parish", name: "Saint Joseph" },
+    Subdivision { code: "BB-07", territory: TerritoryCode::BB, category: "parish", name: "Saint Lucy" },
+    Subdivision { code: "BB-08", territory: TerritoryCode::BB, category: "parish", name: "Saint Michael" },
+    Subdivision { code: "BB-09", territory: TerritoryCode::BB, category: "parish", name: "Saint Peter" },
+    Subdivision { code: "BB-10", territory: TerritoryCode::BB, category: "parish", name: "Saint Philip" },
+    Subdivision { code: "BB-11", territory: TerritoryCode::BB, category: "parish", name: "Saint Thomas" },
+    Subdivision { code: "BD-A", territory: TerritoryCode::BD, category: "division", name: "Barishal" },
+    Subdivision { code: "BD-B", territory: TerritoryCode::BD, category: "division", name: "Chattogram" },
+    Subdivision { code: "BD-C", territory: TerritoryCode::BD, category: "division", name: "Dhaka" },
+    Subdivision { code: "BD-D", territory: TerritoryCode::BD, category: "division", name: "Khulna" },
+    Subdivision { code: "BD-E", territory: TerritoryCode::BD, category: "division", name: "Rajshahi" },
+    Subdivision { code: "BD-F", territory: TerritoryCode::BD, category: "division", name: "Rangpur" },
+    Subdivision { code: "BD-G", territory: TerritoryCode::BD, category: "division", name: "Sylhet" },
+    Subdivision { code: "BD-H", territory: TerritoryCode::BD, category: "division", name: "Mymensingh" },
+    Subdivision { code: "BE-BRU", territory: TerritoryCode::BE, category: "region", name: "Brussels-Capital Region" },
+    Subdivision { code: "BE-VAN", territory: TerritoryCode::BE, category: "province", name: "Antwerp" },
+    Subdivision { code: "BE-VBR", territory: TerritoryCode::BE, category: "province", name: "Flemish Brabant" },
+    Subdivision { code: "BE-VLG", territory: TerritoryCode::BE, category: "region", name: "Flemish Region" },
+    Subdivision { code: "BE-VLI", territory: TerritoryCode::BE, category: "province", name: "Limburg" },
+    Subdivision { code: "BE-VOV", territory: TerritoryCode::BE, category: "province", name: "East Flanders" },
+    Subdivision { code: "BE-VWV", territory: TerritoryCode::BE, category: "province", name: "West Flanders" },
+    Subdivision { code: "BE-WAL", territory: TerritoryCode::BE, category: "region", name: "Walloon Region" },
+    Subdivision { code: "BE-WBR", territory: TerritoryCode::BE, category: "province", name: "Walloon Brabant" },
+    Subdivision { code: "BE-WHT", territory: TerritoryCode::BE, category: "province", name: "Hainaut" },
+    Subdivision { code: "BE-WLG", territory: TerritoryCode::BE, category: "province", name: "Liège" },
+    Subdivision { code: "BE-WLX", territory: TerritoryCode::BE, category: "province", name: "Luxembourg" },
+    Subdivision { code: "BE-WNA", territory: TerritoryCode::BE, category: "province", name: "Namur" },
+    Subdivision { code: "BF-01", territory: TerritoryCode::BF, category: "region", name: "Boucle du Mouhoun" },
+    Subdivision { code: "BF-02", territory: TerritoryCode::BF, category: "region", name: "Cascades" },
+    Subdivision { code: "BF-03", territory: TerritoryCode::BF, category: "region", name: "Centre" },
+    Subdivision { code: "BF-04", territory: TerritoryCode::BF, category: "region", name: "Centre-Est" },
+    Subdivision { code: "BF-05", territory: TerritoryCode::BF, category: "region", name: "Centre-Nord" },
+    Subdivision { code: "BF-06", territory: TerritoryCode::BF, category: "region", name: "Centre-Ouest" },
+    Subdivision { code: "BF-07", territory: TerritoryCode::BF, category: "region", name: "Centre-Sud" },
+    Subdivision { code: "BF-08", territory: TerritoryCode::BF, category: "region", name: "Est" },
+    Subdivision { code: "BF-09", territory: TerritoryCode::BF, category: "region", name: "Hauts-Bassins" },
+    Subdivision { code: "BF-10", territory: TerritoryCode::BF, category: "region", name: "Nord" },
+    Subdivision { code: "BF-11", territory: TerritoryCode::BF, category: "region", name: "Plateau-Central" },
+    Subdivision { code: "BF-12", territory: TerritoryCode::BF, category: "region", name: "Sahel" },
+    Subdivision { code: "BF-13", territory: TerritoryCode::BF, category: "region", name: "Sud-Ouest" },
+    Subdivision { code: "BG-01", territory: TerritoryCode::BG, category: "province", name: "Blagoevgrad" },
+    Subdivision { code: "BG-02", territory: TerritoryCode::BG, category: "province", name: "Burgas" },
+    Subdivision { code: "BG-03", territory: TerritoryCode::BG, category: "province", name: "Varna" },
+    Subdivision { code: "BG-04", territory: TerritoryCode::BG, category: "province", name: "Veliko Tarnovo" },
+    Subdivision { code: "BG-05", territory: TerritoryCode::BG, category: "province", name: "Vidin" },
+    Subdivision { code: "BG-06", territory: TerritoryCode::BG, category: "province", name: "Vratsa" },
+    Subdivision { code: "BG-07", territory: TerritoryCode::BG, category: "province", name: "Gabrovo" },
+    Subdivision { code: "BG-08", territory: TerritoryCode::BG, category: "province", name: "Dobrich" },
+    Subdivision { code: "BG-09", territory: TerritoryCode::BG, category: "province", name: "Kardzhali" },
+    Subdivision { code: "BG-10", territory: TerritoryCode::BG, category: "province", name: "Kyustendil" },
+    Subdivision { code: "BG-11", territory: TerritoryCode::BG, category: "province", name: "Lovech" },
+    Subdivision { code: "BG-12", territory: TerritoryCode::BG, category: "province", name: "Montana" },
+    Subdivision { code: "BG-13", territory: TerritoryCode::BG, category: "province", name: "Pazardzhik" },
+    Subdivision { code: "BG-14", territory: TerritoryCode::BG, category: "province", name: "Pernik" },
+    Subdivision { code: "BG-15", territory: TerritoryCode::BG, category: "province", name: "Pleven" },
+    Subdivision { code: "BG-16", territory: TerritoryCode::BG, category: "province", name: "Plovdiv" },
+    Subdivision { code: "BG-17", territory: TerritoryCode::BG, category: "province", name: "Razgrad" },
+    Subdivision { code: "BG-18", territory: TerritoryCode::BG, category: "province", name: "Ruse" },
+    Subdivision { code: "BG-19", territory: TerritoryCode::BG, category: "province", name: "Silistra" },
+    Subdivision { code: "BG-20", territory: TerritoryCode::BG, category: "province", name: "Sliven" },
+    Subdivision { code: "BG-21", territory: TerritoryCode::BG, category: "province", name: "Smolyan" },
+    Subdivision { code: "BG-22", territory: TerritoryCode::BG, category: "province", name: "Sofia City" },
+    Subdivision { code: "BG-23", territory: TerritoryCode::BG, category: "province", name: "Sofia" },
+    Subdivision { code: "BG-24", territory: TerritoryCode::BG, category: "province", name: "Stara Zagora" },
+    Subdivision { code: "BG-25", territory: TerritoryCode::BG, category: "province", name: "Targovishte" },
+    Subdivision { code: "BG-26", territory: TerritoryCode::BG, category: "province", name: "Haskovo" },
+    Subdivision { code: "BG-27", territory: TerritoryCode::BG, category: "province", name: "Shumen" },
+    Subdivision { code: "BG-28", territory: TerritoryCode::BG, category: "province", name: "Yambol" },
+    Subdivision { code: "BH-13", territory: TerritoryCode::BH, category: "governorate", name: "Capital" },
+    Subdivision { code: "BH-14", territory: TerritoryCode::BH, category: "governorate", name: "Southern" },
+    Subdivision { code: "BH-15", territory: TerritoryCode::BH, category: "governorate", name: "Muharraq" },
+    Subdivision { code: "BH-17", territory: TerritoryCode::BH, category: "governorate", name: "Northern" },
+    Subdivision { code: "BI-BB", territory: TerritoryCode::BI, category: "province", name: "Bubanza" },
+    Subdivision { code: "BI-BL", territory: TerritoryCode::BI, category: "province", name: "Bujumbura Rural" },
+    Subdivision { code: "BI-BM", territory: TerritoryCode::BI, category: "province", name: "Bujumbura Mairie" },
+    Subdivision { code: "BI-BR", territory: TerritoryCode::BI, category: "province", name: "Bururi" },
+    Subdivision { code: "BI-CA", territory: TerritoryCode::BI, category: "province", name: "Cankuzo" },
+    Subdivision { code: "BI-CI", territory: TerritoryCode::BI, category: "province", name: "Cibitoke" },
+    Subdivision { code: "BI-GI", territory: TerritoryCode::BI, category: "province", name: "Gitega" },
+    Subdivision { code: "BI-KI", territory: TerritoryCode::BI, category: "province", name: "Kirundo" },
+    Subdivision { code: "BI-KR", territory: TerritoryCode::BI, category: "province", name: "Karuzi" },
+    Subdivision { code: "BI-KY", territory: TerritoryCode::BI, category: "province", name: "Kayanza" },
+    Subdivision { code: "BI-MA", territory: TerritoryCode::BI, category: "province", name: "Makamba" },
+    Subdivision { code: "BI-MU", territory: TerritoryCode::BI, category: "province", name: "Muramvya" },
+    Subdivision { code: "BI-MW", territory: TerritoryCode::BI, category: "province", name: "Mwaro" },
+    Subdivision { code: "BI-MY", territory: TerritoryCode::BI, category: "province", name: "Muyinga" },
+    Subdivision { code: "BI-NG", territory: TerritoryCode::BI, category: "province", name: "Ngozi" },
+    Subdivision { code: "BI-RM", territory: TerritoryCode::BI, category: "province", name: "Rumonge" },
+    Subdivision { code: "BI-RT", territory: TerritoryCode::BI, category: "province", name: "Rutana" },
+    Subdivision { code: "BI-RY", territory: TerritoryCode::BI, category: "province", name: "Ruyigi" },
+    Subdivision { code: "BJ-AK", territory: TerritoryCode::BJ, category: "department", name: "Atacora" },
+    Subdivision { code: "BJ-AL", territory: TerritoryCode::BJ, category: "department", name: "Alibori" },
+    Subdivision { code: "BJ-AQ", territory: TerritoryCode::BJ, category: "department", name: "Atlantique" },
+    Subdivision { code: "BJ-BO", territory: TerritoryCode::BJ, category: "department", name: "Borgou" },
+    Subdivision { code: "BJ-CO", territory: TerritoryCode::BJ, category: "department", name: "Collines" },
+    Subdivision { code: "BJ-DO", territory: TerritoryCode::BJ, category: "department", name: "Donga" },
+    Subdivision { code: "BJ-KO", territory: TerritoryCode::BJ, category: "department", name: "Couffo" },
+    Subdivision { code: "BJ-LI", territory: TerritoryCode::BJ, category: "department", name: "Littoral" },
+    Subdivision { code: "BJ-MO", territory: TerritoryCode::BJ, category: "department", name: "Mono" },
+    Subdivision { code: "BJ-OU", territory: TerritoryCode::BJ, category: "department", name: "Ouémé" },
+    Subdivision { code: "BJ-PL", territory: TerritoryCode::BJ, category: "department", name: "Plateau" },
+    Subdivision { code: "BJ-ZO", territory: TerritoryCode::BJ, category: "department", name: "Zou" },
+    Subdivision { code: "BN-BE", territory: TerritoryCode::BN, category: "district", name: "Belait" },
+    Subdivision { code: "BN-BM", territory: TerritoryCode::BN, category: "district", name: "Brunei-Muara" },
+    Subdivision { code: "BN-TE", territory: TerritoryCode::BN, category: "district", name: "Temburong" },
+    Subdivision { code: "BN-TU", territory: TerritoryCode::BN, category: "district", name: "Tutong" },
+    Subdivision { code: "BO-B", territory: TerritoryCode::BO, category: "department", name: "Beni" },
+    Subdivision { code: "BO-C", territory: TerritoryCode::BO, category: "department", name: "Cochabamba" },
+    Subdivision { code: "BO-H", territory: TerritoryCode::BO, category: "department", name: "Chuquisaca" },
+    Subdivision { code: "BO-L", territory: TerritoryCode::BO, category: "department", name: "La Paz" },
+    Subdivision { code: "BO-N", territory: TerritoryCode::BO, category: "department", name: "Pando" },
+    Subdivision { code: "BO-O", territory: TerritoryCode::BO, category: "department", name: "Oruro" },
+    Subdivision { code: "BO-P", territory: TerritoryCode::BO, category: "department", name: "Potosí" },
+    Subdivision { code: "BO-S", territory: TerritoryCode::BO, category: "department", name: "Santa Cruz" },
+    Subdivision { code: "BO-T", territory: TerritoryCode::BO, category: "department", name: "Tarija" },
+    Subdivision { code: "BQ-BO", territory: TerritoryCode::BQ, category: "special municipality", name: "Bonaire" },
+    Subdivision { code: "BQ-SA", territory: TerritoryCode::BQ, category: "special municipality", name: "Saba" },
+    Subdivision { code: "BQ-SE", territory: TerritoryCode::BQ, category: "special municipality", name: "Sint Eustatius" },
+    Subdivision { code: "BR-AC", territory: TerritoryCode::BR, category: "state", name: "Acre" },
+    Subdivision { code: "BR-AL", territory: TerritoryCode::BR, category: "state", name: "Alagoas" },
+    Subdivision { code: "BR-AM", territory: TerritoryCode::BR, category: "state", name: "Amazonas" },
+    Subdivision { code: "BR-AP", territory: TerritoryCode::BR, category: "state", name: "Amapá" },
+    Subdivision { code: "BR-BA", territory: TerritoryCode::BR, category: "state", name: "Bahia" },
+    Subdivision { code: "BR-CE", territory: TerritoryCode::BR, category: "state", name: "Ceará" },
+    Subdivision { code: "BR-DF", territory: TerritoryCode::BR, category: "federal district", name: "Federal District" },
+    Subdivision { code: "BR-ES", territory: TerritoryCode::BR, category: "state", name: "Espírito Santo" },
+    Subdivision { code: "BR-GO", territory: TerritoryCode::BR, category: "state", name: "Goiás" },
+    Subdivision { code: "BR-MA", territory: TerritoryCode::BR, category: "state", name: "Maranhão" },
+    Subdivision { code: "BR-MG", territory: TerritoryCode::BR, category: "state", name: "Minas Gerais" },
+    Subdivision { code: "BR-MS", territory: TerritoryCode::BR, category: "state", name: "Mato Grosso do Sul" },
+    Subdivision { code: "BR-MT", territory: TerritoryCode::BR, category: "state", name: "Mato Grosso" },
+    Subdivision { code: "BR-PA", territory: TerritoryCode::BR, category: "state", name: "Pará" },
+    Subdivision { code: "BR-PB", territory: TerritoryCode::BR, category: "state", name: "Paraíba" },
+    Subdivision { code: "BR-PE", territory: TerritoryCode::BR, category: "state", name: "Pernambuco" },
+    Subdivision { code: "BR-PI", territory: TerritoryCode::BR, category: "state", name: "Piauí" },
+    Subdivision { code: "BR-PR", territory: TerritoryCode::BR, category: "state", name: "Paraná" },
+    Subdivision { code: "BR-RJ", territory: TerritoryCode::BR, category: "state", name: "Rio de Janeiro" },
+    Subdivision { code: "BR-RN", territory: TerritoryCode::BR, category: "state", name: "Rio Grande do Norte" },
+    Subdivision { code: "BR-RO", territory: TerritoryCode::BR, category: "state", name: "Rondônia" },
+    Subdivision { code: "BR-RR", territory: TerritoryCode::BR, category: "state", name: "Roraima" },
+    Subdivision { code: "BR-RS", territory: TerritoryCode::BR, category: "state", name: "Rio Grande do Sul" },
+    Subdivision { code: "BR-SC", territory: TerritoryCode::BR, category: "state", name: "Santa Catarina" },
+    Subdivision { code: "BR-SE", territory: TerritoryCode::BR, category: "state", name: "Sergipe" },
+    Subdivision { code: "BR-SP", territory: TerritoryCode::BR, category: "state", name: "São Paulo" },
+    Subdivision { code: "BR-TO", territory: TerritoryCode::BR, category: "state", name: "Tocantins" },
+    Subdivision { code: "BT-11", territory: TerritoryCode::BT, category: "district", name: "Paro" },
+    Subdivision { code: "BT-12", territory: TerritoryCode::BT, category: "district", name: "Chhukha" },
+    Subdivision { code: "BT-13", territory: TerritoryCode::BT, category: "district", name: "Haa" },
+    Subdivision { code: "BT-14", territory: TerritoryCode::BT, category: "district", name: "Samtse" },
+    Subdivision { code: "BT-15", territory: TerritoryCode::BT, category: "district", name: "Thimphu" },
+    Subdivision { code: "BT-21", territory: TerritoryCode::BT, category: "district", name: "Tsirang" },
+    Subdivision { code: "BT-22", territory: TerritoryCode::BT, category: "district", name: "Dagana" },
+    Subdivision { code: "BT-23", territory: TerritoryCode::BT, category: "district", name: "Punakha" },
+    Subdivision { code: "BT-24", territory: TerritoryCode::BT, category: "district", name: "Wangdue Phodrang" },
+    Subdivision { code: "BT-31", territory: TerritoryCode::BT, category: "district", name: "Sarpang" },
+    Subdivision { code: "BT-32", territory: TerritoryCode::BT, category: "district", name: "Trongsa" },
+    Subdivision { code: "BT-33", territory: TerritoryCode::BT, category: "district", name: "Bumthang" },
+    Subdivision { code: "BT-34", territory: TerritoryCode::BT, category: "district", name: "Zhemgang" },
+    Subdivision { code: "BT-41", territory: TerritoryCode::BT, category: "district", name: "Trashigang" },
+    Subdivision { code: "BT-42", territory: TerritoryCode::BT, category: "district", name: "Mongar" },
+    Subdivision { code: "BT-43", territory: TerritoryCode::BT, category: "district", name: "Pemagatshel" },
+    Subdivision { code: "BT-44", territory: TerritoryCode::BT, category: "district", name: "Lhuentse" },
+    Subdivision { code: "BT-45", territory: TerritoryCode::BT, category: "district", name: "Samdrup Jongkhar" },
+    Subdivision { code: "BT-GA", territory: TerritoryCode::BT, category: "district", name: "Gasa" },
+    Subdivision { code: "BT-TY", territory: TerritoryCode::BT, category: "district", name: "Trashiyangtse" },
+    Subdivision { code: "BW-CE", territory: TerritoryCode::BW, category: "district", name: "Central" },
+    Subdivision { code: "BW-CH", territory: TerritoryCode::BW, category: "district", name: "Chobe" },
+    Subdivision { code: "BW-FR", territory: TerritoryCode::BW, category: "city", name: "Francistown" },
+    Subdivision { code: "BW-GA", territory: TerritoryCode::BW, category: "city", name: "Gaborone" },
+    Subdivision { code: "BW-GH", territory: TerritoryCode::BW, category: "district", name: "Ghanzi" },
+    Subdivision { code: "BW-JW", territory: TerritoryCode::BW, category: "town", name: "Jwaneng" },
+    Subdivision { code: "BW-KG", territory: TerritoryCode::BW, category: "district", name: "Kgalagadi" },
+    Subdivision { code: "BW-KL", territory: TerritoryCode::BW, category: "district", name: "Kgatleng" },
+    Subdivision { code: "BW-KW", territory: TerritoryCode::BW, category: "district", name: "Kweneng" },
+    Subdivision { code: "BW-LO", territory: TerritoryCode::BW, category: "town", name: "Lobatse" },
+    Subdivision { code: "BW-NE", territory: TerritoryCode::BW, category: "district", name: "North East" },
+    Subdivision { code: "BW-NW", territory: TerritoryCode::BW, category: "district", name: "North West" },
+    Subdivision { code: "BW-SE", territory: TerritoryCode::BW, category: "district", name: "South East" },
+    Subdivision { code: "BW-SO", territory: TerritoryCode::BW, category: "district", name: "Southern" },
+    Subdivision { code: "BW-SP", territory: TerritoryCode::BW, category: "town", name: "Selebi-Phikwe" },
+    Subdivision { code: "BW-ST", territory: TerritoryCode::BW, category: "town", name: "Sowa Town" },
+    Subdivision { code: "BY-BR", territory: TerritoryCode::BY, category: "region", name: "Brest" },
+    Subdivision { code: "BY-HM", territory: TerritoryCode::BY, category: "city", name: "Minsk City" },
+    Subdivision { code: "BY-HO", territory: TerritoryCode::BY, category: "region", name: "Gomel" },
+    Subdivision { code: "BY-HR", territory: TerritoryCode::BY, category: "region", name: "Grodno" },
+    Subdivision { code: "BY-MA", territory: TerritoryCode::BY, category: "region", name: "Mogilev" },
+    Subdivision { code: "BY-MI", territory: TerritoryCode::BY, category: "region", name: "Minsk" },
+    Subdivision { code: "BY-VI", territory: TerritoryCode::BY, category: "region", name: "Vitebsk" },
+    Subdivision { code: "BZ-BZ", territory: TerritoryCode::BZ, category: "district", name: "Belize" },
+    Subdivision { code: "BZ-CY", territory: TerritoryCode::BZ, category: "district", name: "Cayo" },
+    Subdivision { code: "BZ-CZL", territory: TerritoryCode::BZ, category: "district", name: "Corozal" },
+    Subdivision { code: "BZ-OW", territory: TerritoryCode::BZ, category: "district", name: "Orange Walk" },
+    Subdivision { code: "BZ-SC", territory: TerritoryCode::BZ, category: "district", name: "Stann Creek" },
+    Subdivision { code: "BZ-TOL", territory: TerritoryCode::BZ, category: "district", name: "Toledo" },
+    Subdivision { code: "CA-AB", territory: TerritoryCode::CA, category: "province", name: "Alberta" },
+    Subdivision { code: "CA-BC", territory: TerritoryCode::CA, category: "province", name: "British Columbia" },
+    Subdivision { code: "CA-MB", territory: TerritoryCode::CA, category: "province", name: "Manitoba" },
+    Subdivision { code: "CA-NB", territory: TerritoryCode::CA, category: "province", name: "New Brunswick" },
+    Subdivision { code: "CA-NL", territory: TerritoryCode::CA, category: "province", name: "Newfoundland and Labrador" },
+    Subdivision { code: "CA-NS", territory: TerritoryCode::CA, category: "province", name: "Nova Scotia" },
+    Subdivision { code: "CA-NT", territory: TerritoryCode::CA, category: "territory", name: "Northwest Territories" },
+    Subdivision { code: "CA-NU", territory: TerritoryCode::CA, category: "territory", name: "Nunavut" },
+    Subdivision { code: "CA-ON", territory: TerritoryCode::CA, category: "province", name: "Ontario" },
+    Subdivision { code: "CA-PE", territory: TerritoryCode::CA, category: "province", name: "Prince Edward Island" },
+    Subdivision { code: "CA-QC", territory: TerritoryCode::CA, category: "province", name: "Quebec" },
+    Subdivision { code: "CA-SK", territory: TerritoryCode::CA, category: "province", name: "Saskatchewan" },
+    Subdivision { code: "CA-YT", territory: TerritoryCode::CA, category: "territory", name: "Yukon" },
+    Subdivision { code: "CD-BC", territory: TerritoryCode::CD, category: "province", name: "Kongo Central" },
+    Subdivision { code: "CD-BU", territory: TerritoryCode::CD, category: "province", name: "Bas-Uélé" },
+    Subdivision { code: "CD-EQ", territory: TerritoryCode::CD, category: "province", name: "Équateur" },
+    Subdivision { code: "CD-HK", territory: TerritoryCode::CD, category: "province", name: "Haut-Katanga" },
+    Subdivision { code: "CD-HL", territory: TerritoryCode::CD, category: "province", name: "Haut-Lomami" },
+    Subdivision { code: "CD-HU", territory: TerritoryCode::CD, category: "province", name: "Haut-Uélé" },
+    Subdivision { code: "CD-IT", territory: TerritoryCode::CD, category: "province", name: "Ituri" },
+    Subdivision { code: "CD-KC", territory: TerritoryCode::CD, category: "province", name: "Kasaï Central" },
+    Subdivision { code: "CD-KE", territory: TerritoryCode::CD, category: "province", name: "Kasaï Oriental" },
+    Subdivision { code: "CD-KG", territory: TerritoryCode::CD, category: "province", name: "Kwango" },
+    Subdivision { code: "CD-KL", territory: TerritoryCode::CD, category: "province", name: "Kwilu" },
+    Subdivision { code: "CD-KN", territory: TerritoryCode::CD, category: "city", name: "Kinshasa" },
+    Subdivision { code: "CD-KS", territory: TerritoryCode::CD, category: "province", name: "Kasaï" },
+    Subdivision { code: "CD-LO", territory: TerritoryCode::CD, category: "province", name: "Lomami" },
+    Subdivision { code: "CD-LU", territory: TerritoryCode::CD, category: "province", name: "Lualaba" },
+    Subdivision { code: "CD-MA", territory: TerritoryCode::CD, category: "province", name: "Maniema" },
+    Subdivision { code: "CD-MN", territory: TerritoryCode::CD, category: "province", name: "Mai-Ndombe" },
+    Subdivision { code: "CD-MO", territory: TerritoryCode::CD, category: "province", name: "Mongala" },
+    Subdivision { code: "CD-NK", territory: TerritoryCode::CD, category: "province", name: "Nord-Kivu" },
+    Subdivision { code: "CD-NU", territory: TerritoryCode::CD, category: "province", name: "Nord-Ubangi" },
+    Subdivision { code: "CD-SA", territory: TerritoryCode::CD, category: "province", name: "Sankuru" },
+    Subdivision { code: "CD-SK", territory: TerritoryCode::CD, category: "province", name: "Sud-Kivu" },
+    Subdivision { code: "CD-SU", territory: TerritoryCode::CD, category: "province", name: "Sud-Ubangi" },
+    Subdivision { code: "CD-TA", territory: TerritoryCode::CD, category: "province", name: "Tanganyika" },
+    Subdivision { code: "CD-TO", territory: TerritoryCode::CD, category: "province", name: "Tshopo" },
+    Subdivision { code: "CD-TU", territory: TerritoryCode::CD, category: "province", name: "Tshuapa" },
+    Subdivision { code: "CF-AC", territory: TerritoryCode::CF, category: "prefecture", name: "Ouham" },
+    Subdivision { code: "CF-BB", territory: TerritoryCode::CF, category: "prefecture", name: "Bamingui-Bangoran" },
+    Subdivision { code: "CF-BGF", territory: TerritoryCode::CF, category: "commune", name: "Bangui" },
+    Subdivision { code: "CF-BK", territory: TerritoryCode::CF, category: "prefecture", name: "Basse-Kotto" },
+    Subdivision { code: "CF-HK", territory: TerritoryCode::CF, category: "prefecture", name: "Haute-Kotto" },
+    Subdivision { code: "CF-HM", territory: TerritoryCode::CF, category: "prefecture", name: "Haut-Mbomou" },
+    Subdivision { code: "CF-HS", territory: TerritoryCode::CF, category: "prefecture", name: "Mambéré-Kadéï" },
+    Subdivision { code: "CF-KB", territory: TerritoryCode::CF, category: "economic prefecture", name: "Nana-Grébizi" },
+    Subdivision { code: "CF-KG", territory: TerritoryCode::CF, category: "prefecture", name: "Kémo" },
+    Subdivision { code: "CF-LB", territory: TerritoryCode::CF, category: "prefecture", name: "Lobaye" },
+    Subdivision { code: "CF-MB", territory: TerritoryCode::CF, category: "prefecture", name: "Mbomou" },
+    Subdivision { code: "CF-MP", territory: TerritoryCode::CF, category: "prefecture", name: "Ombella-M'Poko" },
+    Subdivision { code: "CF-NM", territory: TerritoryCode::CF, category: "prefecture", name: "Nana-Mambéré" },
+    Subdivision { code: "CF-OP", territory: TerritoryCode::CF, category: "prefecture", name: "Ouham-Pendé" },
+    Subdivision { code: "CF-SE", territory: TerritoryCode::CF, category: "economic prefecture", name: "Sangha-Mbaéré" },
+    Subdivision { code: "CF-UK", territory: TerritoryCode::CF, category: "prefecture", name: "Ouaka" },
+    Subdivision { code: "CF-VK", territory: TerritoryCode::CF, category: "prefecture", name: "Vakaga" },
+    Subdivision { code: "CG-11", territory: TerritoryCode::CG, category: "department", name: "Bouenza" },
+    Subdivision { code: "CG-12", territory: TerritoryCode::CG, category: "department", name: "Pool" },
+    Subdivision { code: "CG-13", territory: TerritoryCode::CG, category: "department", name: "Sangha" },
+    Subdivision { code: "CG-14", territory: TerritoryCode::CG, category: "department", name: "Plateaux" },
+    Subdivision { code: "CG-15", territory: TerritoryCode::CG, category: "department", name: "Cuvette-Ouest" },
+    Subdivision { code: "CG-16", territory: TerritoryCode::CG, category: "department", name: "Pointe-Noire" },
+    Subdivision { code: "CG-2", territory: TerritoryCode::CG, category: "department", name: "Lékoumou" },
+    Subdivision { code: "CG-5", territory: TerritoryCode::CG, category: "department", name: "Kouilou" },
+    Subdivision { code: "CG-7", territory: TerritoryCode::CG, category: "department", name: "Likouala" },
+    Subdivision { code: "CG-8", territory: TerritoryCode::CG, category: "department", name: "Cuvette" },
+    Subdivision { code: "CG-9", territory: TerritoryCode::CG, category: "department", name: "Niari" },
+    Subdivision { code: "CG-BZV", territory: TerritoryCode::CG, category: "department", name: "Brazzaville" },
+    Subdivision { code: "CH-AG", territory: TerritoryCode::CH, category: "canton", name: "Aargau" },
+    Subdivision { code: "CH-AI", territory: TerritoryCode::CH, category: "canton", name: "Appenzell Innerrhoden" },
+    Subdivision { code: "CH-AR", territory: TerritoryCode::CH, category: "canton", name: "Appenzell Ausserrhoden" },
+    Subdivision { code: "CH-BE", territory: TerritoryCode::CH, category: "canton", name: "Bern" },
+    Subdivision { code: "CH-BL", territory: TerritoryCode::CH, category: "canton", name: "Basel-Landschaft" },
+    Subdivision { code: "CH-BS", territory: TerritoryCode::CH, category: "canton", name: "Basel-Stadt" },
+    Subdivision { code: "CH-FR", territory: TerritoryCode::CH, category: "canton", name: "Fribourg" },
+    Subdivision { code: "CH-GE", territory: TerritoryCode::CH, category: "canton", name: "Geneva" },
+    Subdivision { code: "CH-GL", territory: TerritoryCode::CH, category: "canton", name: "Glarus" },
+    Subdivision { code: "CH-GR", territory: TerritoryCode::CH, category: "canton", name: "Graubünden" },
+    Subdivision { code: "CH-JU", territory: TerritoryCode::CH, category: "canton", name: "Jura" },
+    Subdivision { code: "CH-LU", territory: TerritoryCode::CH, category: "canton", name: "Lucerne" },
+    Subdivision { code: "CH-NE", territory: TerritoryCode::CH, category: "canton", name: "Neuchâtel" },
+    Subdivision { code: "CH-NW", territory: TerritoryCode::CH, category: "canton", name: "Nidwalden" },
+    Subdivision { code: "CH-OW", territory: TerritoryCode::CH, category: "canton", name: "Obwalden" },
+    Subdivision { code: "CH-SG", territory: TerritoryCode::CH, category: "canton", name: "St. Gallen" },
+    Subdivision { code: "CH-SH", territory: TerritoryCode::CH, category: "canton", name: "Schaffhausen" },
+    Subdivision { code: "CH-SO", territory: TerritoryCode::CH, category: "canton", name: "Solothurn" },
+    Subdivision { code: "CH-SZ", territory: TerritoryCode::CH, category: "canton", name: "Schwyz" },
+    Subdivision { code: "CH-TG", territory: TerritoryCode::CH, category: "canton", name: "Thurgau" },
+    Subdivision { code: "CH-TI", territory: TerritoryCode::CH, category: "canton", name: "Ticino" },
+    Subdivision { code: "CH-UR", territory: TerritoryCode::CH, category: "canton", name: "Uri" },
+    Subdivision { code: "CH-VD", territory: TerritoryCode::CH, category: "canton", name: "Vaud" },
+    Subdivision { code: "CH-VS", territory: TerritoryCode::CH, category: "canton", name: "Valais" },
+    Subdivision { code: "CH-ZG", territory: TerritoryCode::CH, category: "canton", name: "Zug" },
+    Subdivision { code: "CH-ZH", territory: TerritoryCode::CH, category: "canton", name: "Zurich" },
+    Subdivision { code: "CI-AB", territory: TerritoryCode::CI, category: "autonomous district", name: "Abidjan" },
+    Subdivision { code: "CI-BS", territory: TerritoryCode::CI, category: "district", name: "Bas-Sassandra" },
+    Subdivision { code: "CI-CM", territory: TerritoryCode::CI, category: "district", name: "Comoé" },
+    Subdivision { code: "CI-DN", territory: TerritoryCode::CI, category: "district", name: "Denguélé" },
+    Subdivision { code: "CI-GD", territory: TerritoryCode::CI, category: "district", name: "Gôh-Djiboua" },
+    Subdivision { code: "CI-LC", territory: TerritoryCode::CI, category: "district", name: "Lacs" },
+    Subdivision { code: "CI-LG", territory: TerritoryCode::CI, category: "district", name: "Lagunes" },
+    Subdivision { code: "CI-MG", territory: TerritoryCode::CI, category: "district", name: "Montagnes" },
+    Subdivision { code: "CI-SM", territory: TerritoryCode::CI, category: "district", name: "Sassandra-Marahoué" },
+    Subdivision { code: "CI-SV", territory: TerritoryCode::CI, category: "district", name: "Savanes" },
+    Subdivision { code: "CI-VB", territory: TerritoryCode::CI, category: "district", name: "Vallée du Bandama" },
+    Subdivision { code: "CI-WR", territory: TerritoryCode::CI, category: "district", name: "Woroba" },
+    Subdivision { code: "CI-YM", territory: TerritoryCode::CI, category: "autonomous district", name: "Yamoussoukro" },
+    Subdivision { code: "CI-ZZ", territory: TerritoryCode::CI, category: "district", name: "Zanzan" },
+    Subdivision { code: "CL-AI", territory: TerritoryCode::CL, category: "region", name: "Aysén" },
+    Subdivision { code: "CL-AN", territory: TerritoryCode::CL, category: "region", name: "Antofagasta" },
+    Subdivision { code: "CL-AP", territory: TerritoryCode::CL, category: "region", name: "Arica y Parinacota" },
+    Subdivision { code: "CL-AR", territory: TerritoryCode::CL, category: "region", name: "Araucanía" },
+    Subdivision { code: "CL-AT", territory: TerritoryCode::CL, category: "region", name: "Atacama" },
+    Subdivision { code: "CL-BI", territory: TerritoryCode::CL, category: "region", name: "Biobío" },
+    Subdivision { code: "CL-CO", territory: TerritoryCode::CL, category: "region", name: "Coquimbo" },
+    Subdivision { code: "CL-LI", territory: TerritoryCode::CL, category: "region", name: "O'Higgins" },
+    Subdivision { code: "CL-LL", territory: TerritoryCode::CL, category: "region", name: "Los Lagos" },
+    Subdivision { code: "CL-LR", territory: TerritoryCode::CL, category: "region", name: "Los Ríos" },
+    Subdivision { code: "CL-MA", territory: TerritoryCode::CL, category: "region", name: "Magallanes" },
+    Subdivision { code: "CL-ML", territory: TerritoryCode::CL, category: "region", name: "Maule" },
+    Subdivision { code: "CL-NB", territory: TerritoryCode::CL, category: "region", name: "Ñuble" },
+    Subdivision { code: "CL-RM", territory: TerritoryCode::CL, category: "region", name: "Santiago Metropolitan" },
+    Subdivision { code: "CL-TA", territory: TerritoryCode::CL, category: "region", name: "Tarapacá" },
+    Subdivision { code: "CL-VS", territory: TerritoryCode::CL, category: "region", name: "Valparaíso" },
+    Subdivision { code: "CM-AD", territory: TerritoryCode::CM, category: "region", name: "Adamawa" },
+    Subdivision { code: "CM-CE", territory: TerritoryCode::CM, category: "region", name: "Centre" },
+    Subdivision { code: "CM-EN", territory: TerritoryCode::CM, category: "region", name: "Far North" },
+    Subdivision { code: "CM-ES", territory: TerritoryCode::CM, category: "region", name: "East" },
+    Subdivision { code: "CM-LT", territory: TerritoryCode::CM, category: "region", name: "Littoral" },
+    Subdivision { code: "CM-NO", territory: TerritoryCode::CM, category: "region", name: "North" },
+    Subdivision { code: "CM-NW", territory: TerritoryCode::CM, category: "region", name: "North-West" },
+    Subdivision { code: "CM-OU", territory: TerritoryCode::CM, category: "region", name: "West" },
+    Subdivision { code: "CM-SU", territory: TerritoryCode::CM, category: "region", name: "South" },
+    Subdivision { code: "CM-SW", territory: TerritoryCode::CM, category: "region", name: "South-West" },
+    Subdivision { code: "CN-AH", territory: TerritoryCode::CN, category: "province", name: "Anhui" },
+    Subdivision { code: "CN-BJ", territory: TerritoryCode::CN, category: "municipality", name: "Beijing" },
+    Subdivision { code: "CN-CQ", territory: TerritoryCode::CN, category: "municipality", name: "Chongqing" },
+    Subdivision { code: "CN-FJ", territory: TerritoryCode::CN, category: "province", name: "Fujian" },
+    Subdivision { code: "CN-GD", territory: TerritoryCode::CN, category: "province", name: "Guangdong" },
+    Subdivision { code: "CN-GS", territory: TerritoryCode::CN, category: "province", name: "Gansu" },
+    Subdivision { code: "CN-GX", territory: TerritoryCode::CN, category: "autonomous region", name: "Guangxi" },
+    Subdivision { code: "CN-GZ", territory: TerritoryCode::CN, category: "province", name: "Guizhou" },
+    Subdivision { code: "CN-HA", territory: TerritoryCode::CN, category: "province", name: "Henan" },
+    Subdivision { code: "CN-HB", territory: TerritoryCode::CN, category: "province", name: "Hubei" },
+    Subdivision { code: "CN-HE", territory: TerritoryCode::CN, category: "province", name: "Hebei" },
+    Subdivision { code: "CN-HI", territory: TerritoryCode::CN, category: "province", name: "Hainan" },
+    Subdivision { code: "CN-HL", territory: TerritoryCode::CN, category: "province", name: "Heilongjiang" },
+    Subdivision { code: "CN-HN", territory: TerritoryCode::CN, category: "province", name: "Hunan" },
+    Subdivision { code: "CN-JL", territory: TerritoryCode::CN, category: "province", name: "Jilin" },
+    Subdivision { code: "CN-JS", territory: TerritoryCode::CN, category: "province", name: "Jiangsu" },
+    Subdivision { code: "CN-JX", territory: TerritoryCode::CN, category: "province", name: "Jiangxi" },
+    Subdivision { code: "CN-LN", territory: TerritoryCode::CN, category: "province", name: "Liaoning" },
+    Subdivision { code: "CN-NM", territory: TerritoryCode::CN, category: "autonomous region", name: "Inner Mongolia" },
+    Subdivision { code: "CN-NX", territory: TerritoryCode::CN, category: "autonomous region", name: "Ningxia" },
+    Subdivision { code: "CN-QH", territory: TerritoryCode::CN, category: "province", name: "Qinghai" },
+    Subdivision { code: "CN-SC", territory: TerritoryCode::CN, category: "province", name: "Sichuan" },
+    Subdivision { code: "CN-SD", territory: TerritoryCode::CN, category: "province", name: "Shandong" },
+    Subdivision { code: "CN-SH", territory: TerritoryCode::CN, category: "municipality", name: "Shanghai" },
+    Subdivision { code: "CN-SN", territory: TerritoryCode::CN, category: "province", name: "Shaanxi" },
+    Subdivision { code: "CN-SX", territory: TerritoryCode::CN, category: "province", name: "Shanxi" },
+    Subdivision { code: "CN-TJ", territory: TerritoryCode::CN, category: "municipality", name: "Tianjin" },
+    Subdivision { code: "CN-XJ", territory: TerritoryCode::CN, category: "autonomous region", name: "Xinjiang" },
+    Subdivision { code: "CN-XZ", territory: TerritoryCode::CN, category: "autonomous region", name: "Tibet" },
+    Subdivision { code: "CN-YN", territory: TerritoryCode::CN, category: "province", name: "Yunnan" },
+    Subdivision { code: "CN-ZJ", territory: TerritoryCode::CN, category: "province", name: "Zhejiang" },
+    Subdivision { code: "CO-AMA", territory: TerritoryCode::CO, category: "department", name: "Amazonas" },
+    Subdivision { code: "CO-ANT", territory: TerritoryCode::CO, category: "department", name: "Antioquia" },
+    Subdivision { code: "CO-ARA", territory: TerritoryCode::CO, category: "department", name: "Arauca" },
+    Subdivision { code: "CO-ATL", territory: TerritoryCode::CO, category: "department", name: "Atlántico" },
+    Subdivision { code: "CO-BOL", territory: TerritoryCode::CO, category: "department", name: "Bolívar" },
+    Subdivision { code: "CO-BOY", territory: TerritoryCode::CO, category: "department", name: "Boyacá" },
+    Subdivision { code: "CO-CAL", territory: TerritoryCode::CO, category: "department", name: "Caldas" },
+    Subdivision { code: "CO-CAQ", territory: TerritoryCode::CO, category: "department", name: "Caquetá" },
+    Subdivision { code: "CO-CAS", territory: TerritoryCode::CO, category: "department", name: "Casanare" },
+    Subdivision { code: "CO-CAU", territory: TerritoryCode::CO, category: "department", name: "Cauca" },
+    Subdivision { code: "CO-CES", territory: TerritoryCode::CO, category: "department", name: "Cesar" },
+    Subdivision { code: "CO-CHO", territory: TerritoryCode::CO, category: "department", name: "Chocó" },
+    Subdivision { code: "CO-COR", territory: TerritoryCode::CO, category: "department", name: "Córdoba" },
+    Subdivision { code: "CO-CUN", territory: TerritoryCode::CO, category: "department", name: "Cundinamarca" },
+    Subdivision { code: "CO-DC", territory: TerritoryCode::CO, category: "capital district", name: "Bogotá" },
+    Subdivision { code: "CO-GUA", territory: TerritoryCode::CO, category: "department", name: "Guainía" },
+    Subdivision { code: "CO-GUV", territory: TerritoryCode::CO, category: "department", name: "Guaviare" },
+    Subdivision { code: "CO-HUI", territory: TerritoryCode::CO, category: "department", name: "Huila" },
+    Subdivision { code: "CO-LAG", territory: TerritoryCode::CO, category: "department", name: "La Guajira" },
+    Subdivision { code: "CO-MAG", territory: TerritoryCode::CO, category: "department", name: "Magdalena" },
+    Subdivision { code: "CO-MET", territory: TerritoryCode::CO, category: "department", name: "Meta" },
+    Subdivision { code: "CO-NAR", territory: TerritoryCode::CO, category: "department", name: "Nariño" },
+    Subdivision { code: "CO-NSA", territory: TerritoryCode::CO, category: "department", name: "Norte de Santander" },
+    Subdivision { code: "CO-PUT", territory: TerritoryCode::CO, category: "department", name: "Putumayo" },
+    Subdivision { code: "CO-QUI", territory: TerritoryCode::CO, category: "department", name: "Quindío" },
+    Subdivision { code: "CO-RIS", territory: TerritoryCode::CO, category: "department", name: "Risaralda" },
+    Subdivision { code: "CO-SAN", territory: TerritoryCode::CO, category: "department", name: "Santander" },
+    Subdivision { code: "CO-SAP", territory: TerritoryCode::CO, category: "department", name: "San Andrés, Providencia and Santa Catalina" },
+    Subdivision { code: "CO-SUC", territory: TerritoryCode::CO, category: "department", name: "Sucre" },
+    Subdivision { code: "CO-TOL", territory: TerritoryCode::CO, category: "department", name: "Tolima" },
+    Subdivision { code: "CO-VAC", territory: TerritoryCode::CO, category: "department", name: "Valle del Cauca" },
+    Subdivision { code: "CO-VAU", territory: TerritoryCode::CO, category: "department", name: "Vaupés" },
+    Subdivision { code: "CO-VID", territory: TerritoryCode::CO, category: "department", name: "Vichada" },
+    Subdivision { code: "CR-A", territory: TerritoryCode::CR, category: "province", name: "Alajuela" },
+    Subdivision { code: "CR-C", territory: TerritoryCode::CR, category: "province", name: "Cartago" },
+    Subdivision { code: "CR-G", territory: TerritoryCode::CR, category: "province", name: "Guanacaste" },
+    Subdivision { code: "CR-H", territory: TerritoryCode::CR, category: "province", name: "Heredia" },
+    Subdivision { code: "CR-L", territory: TerritoryCode::CR, category: "province", name: "Limón" },
+    Subdivision { code: "CR-P", territory: TerritoryCode::CR, category: "province", name: "Puntarenas" },
+    Subdivision { code: "CR-SJ", territory: TerritoryCode::CR, category: "province", name: "San José" },
+    Subdivision { code: "CU-01", territory: TerritoryCode::CU, category: "province", name: "Pinar del Río" },
+    Subdivision { code: "CU-03", territory: TerritoryCode::CU, category: "province", name: "Havana" },
+    Subdivision { code: "CU-04", territory: TerritoryCode::CU, category: "province", name: "Matanzas" },
+    Subdivision { code: "CU-05", territory: TerritoryCode::CU, category: "province", name: "Villa Clara" },
+    Subdivision { code: "CU-06", territory: TerritoryCode::CU, category: "province", name: "Cienfuegos" },
+    Subdivision { code: "CU-07", territory: TerritoryCode::CU, category: "province", name: "Sancti Spíritus" },
+    Subdivision { code: "CU-08", territory: TerritoryCode::CU, category: "province", name: "Ciego de Ávila" },
+    Subdivision { code: "CU-09", territory: TerritoryCode::CU, category: "province", name: "Camagüey" },
+    Subdivision { code: "CU-10", territory: TerritoryCode::CU, category: "province", name: "Las Tunas" },
+    Subdivision { code: "CU-11", territory: TerritoryCode::CU, category: "province", name: "Holguín" },
+    Subdivision { code: "CU-12", territory: TerritoryCode::CU, category: "province", name: "Granma" },
+    Subdivision { code: "CU-13", territory: TerritoryCode::CU, category: "province", name: "Santiago de Cuba" },
+    Subdivision { code: "CU-14", territory: TerritoryCode::CU, category: "province", name: "Guantánamo" },
+    Subdivision { code: "CU-15", territory: TerritoryCode::CU, category: "province", name: "Artemisa" },
+    Subdivision { code: "CU-16", territory: TerritoryCode::CU, category: "province", name: "Mayabeque" },
+    Subdivision { code: "CU-99", territory: TerritoryCode::CU, category: "special municipality", name: "Isla de la Juventud" },
+    Subdivision { code: "CV-B", territory: TerritoryCode::CV, category: "geographical region", name: "Barlavento Islands" },
+    Subdivision { code: "CV-S", territory: TerritoryCode::CV, category: "geographical region", name: "Sotavento Islands" },
+    Subdivision { code: "CY-01", territory: TerritoryCode::CY, category: "district", name: "Nicosia" },
+    Subdivision { code: "CY-02", territory: TerritoryCode::CY, category: "district", name: "Limassol" },
+    Subdivision { code: "CY-03", territory: TerritoryCode::CY, category: "district", name: "Larnaca" },
+    Subdivision { code: "CY-04", territory: TerritoryCode::CY, category: "district", name: "Famagusta" },
+    Subdivision { code: "CY-05", territory: TerritoryCode::CY, category: "district", name: "Paphos" },
+    Subdivision { code: "CY-06", territory: TerritoryCode::CY, category: "district", name: "Kyrenia" },
+    Subdivision { code: "CZ-10", territory: TerritoryCode::CZ, category: "capital city", name: "Prague" },
+    Subdivision { code: "CZ-20", territory: TerritoryCode::CZ, category: "region", name: "Central Bohemian" },
+    Subdivision { code: "CZ-31", territory: TerritoryCode::CZ, category: "region", name: "South Bohemian" },
+    Subdivision { code: "CZ-32", territory: TerritoryCode::CZ, category: "region", name: "Plzeň" },
+    Subdivision { code: "CZ-41", territory: TerritoryCode::CZ, category: "region", name: "Karlovy Vary" },
+    Subdivision { code: "CZ-42", territory: TerritoryCode::CZ, category: "region", name: "Ústí nad Labem" },
+    Subdivision { code: "CZ-51", territory: TerritoryCode::CZ, category: "region", name: "Liberec" },
+    Subdivision { code: "CZ-52", territory: TerritoryCode::CZ, category: "region", name: "Hradec Králové" },
+    Subdivision { code: "CZ-53", territory: TerritoryCode::CZ, category: "region", name: "Pardubice" },
+    Subdivision { code: "CZ-63", territory: TerritoryCode::CZ, category: "region", name: "Vysočina" },
+    Subdivision { code: "CZ-64", territory: TerritoryCode::CZ, category: "region", name: "South Moravian" },
+    Subdivision { code: "CZ-71", territory: TerritoryCode::CZ, category: "region", name: "Olomouc" },
+    Subdivision { code: "CZ-72", territory: TerritoryCode::CZ, category: "region", name: "Zlín" },
+    Subdivision { code: "CZ-80", territory: TerritoryCode::CZ, category: "region", name: "Moravian-Silesian" },
+    Subdivision { code: "DE-BB", territory: TerritoryCode::DE, category: "Land", name: "Brandenburg" },
+    Subdivision { code: "DE-BE", territory: TerritoryCode::DE, category: "Land", name: "Berlin" },
+    Subdivision { code: "DE-BW", territory: TerritoryCode::DE, category: "Land", name: "Baden-Württemberg" },
+    Subdivision { code: "DE-BY", territory: TerritoryCode::DE, category: "Land", name: "Bavaria" },
+    Subdivision { code: "DE-HB", territory: TerritoryCode::DE, category: "Land", name: "Bremen" },
+    Subdivision { code: "DE-HE", territory: TerritoryCode::DE, category: "Land", name: "Hesse" },
+    Subdivision { code: "DE-HH", territory: TerritoryCode::DE, category: "Land", name: "Hamburg" },
+    Subdivision { code: "DE-MV", territory: TerritoryCode::DE, category: "Land", name: "Mecklenburg-Western Pomerania" },
+    Subdivision { code: "DE-NI", territory: TerritoryCode::DE, category: "Land", name: "Lower Saxony" },
+    Subdivision { code: "DE-NW", territory: TerritoryCode::DE, category: "Land", name: "North Rhine-Westphalia" },
+    Subdivision { code: "DE-RP", territory: TerritoryCode::DE, category: "Land", name: "Rhineland-Palatinate" },
+    Subdivision { code: "DE-SH", territory: TerritoryCode::DE, category: "Land", name: "Schleswig-Holstein" },
+    Subdivision { code: "DE-SL", territory: TerritoryCode::DE, category: "Land", name: "Saarland" },
+    Subdivision { code: "DE-SN", territory: TerritoryCode::DE, category: "Land", name: "Saxony" },
+    Subdivision { code: "DE-ST", territory: TerritoryCode::DE, category: "Land", name: "Saxony-Anhalt" },
+    Subdivision { code: "DE-TH", territory: TerritoryCode::DE, category: "Land", name: "Thuringia" },
+    Subdivision { code: "DJ-AR", territory: TerritoryCode::DJ, category: "region", name: "Arta" },
+    Subdivision { code: "DJ-AS", territory: TerritoryCode::DJ, category: "region", name: "Ali Sabieh" },
+    Subdivision { code: "DJ-DI", territory: TerritoryCode::DJ, category: "region", name: "Dikhil" },
+    Subdivision { code: "DJ-DJ", territory: TerritoryCode::DJ, category: "city", name: "Djibouti" },
+    Subdivision { code: "DJ-OB", territory: TerritoryCode::DJ, category: "region", name: "Obock" },
+    Subdivision { code: "DJ-TA", territory: TerritoryCode::DJ, category: "region", name: "Tadjourah" },
+    Subdivision { code: "DK-81", territory: TerritoryCode::DK, category: "region", name: "North Denmark" },
+    Subdivision { code: "DK-82", territory: TerritoryCode::DK, category: "region", name: "Central Denmark" },
+    Subdivision { code: "DK-83", territory: TerritoryCode::DK, category: "region", name: "Southern Denmark" },
+    Subdivision { code: "DK-84", territory: TerritoryCode::DK, category: "region", name: "Capital Region of Denmark" },
+    Subdivision { code: "DK-85", territory: TerritoryCode::DK, category: "region", name: "Zealand" },
+    Subdivision { code: "DM-02", territory: TerritoryCode::DM, category: "parish", name: "Saint Andrew" },
+    Subdivision { code: "DM-03", territory: TerritoryCode::DM, category: "parish", name: "Saint David" },
+    Subdivision { code: "DM-04", territory: TerritoryCode::DM, category: "parish", name: "Saint George" },
+    Subdivision { code: "DM-05", territory: TerritoryCode::DM, category: "parish", name: "Saint John" },
+    Subdivision { code: "DM-06", territory: TerritoryCode::DM, category: "parish", name: "Saint Joseph" },
+    Subdivision { code: "DM-07", territory: TerritoryCode::DM, category: "parish", name: "Saint Luke" },
+    Subdivision { code: "DM-08", territory: TerritoryCode::DM, category: "parish", name: "Saint Mark" },
+    Subdivision { code: "DM-09", territory: TerritoryCode::DM, category: "parish", name: "Saint Patrick" },
+    Subdivision { code: "DM-10", territory: TerritoryCode::DM, category: "parish", name: "Saint Paul" },
+    Subdivision { code: "DM-11", territory: TerritoryCode::DM, category: "parish", name: "Saint Peter" },
+    Subdivision { code: "DO-01", territory: TerritoryCode::DO, category: "district", name: "Distrito Nacional" },
+    Subdivision { code: "DO-02", territory: TerritoryCode::DO, category: "province", name: "Azua" },
+    Subdivision { code: "DO-03", territory: TerritoryCode::DO, category: "province", name: "Baoruco" },
+    Subdivision { code: "DO-04", territory: TerritoryCode::DO, category: "province", name: "Barahona" },
+    Subdivision { code: "DO-05", territory: TerritoryCode::DO, category: "province", name: "Dajabón" },
+    Subdivision { code: "DO-06", territory: TerritoryCode::DO, category: "province", name: "Duarte" },
+    Subdivision { code: "DO-07", territory: TerritoryCode::DO, category: "province", name: "Elías Piña" },
+    Subdivision { code: "DO-08", territory: TerritoryCode::DO, category: "province", name: "El Seibo" },
+    Subdivision { code: "DO-09", territory: TerritoryCode::DO, category: "province", name: "Espaillat" },
+    Subdivision { code: "DO-10", territory: TerritoryCode::DO, category: "province", name: "Independencia" },
+    Subdivision { code: "DO-11", territory: TerritoryCode::DO, category: "province", name: "La Altagracia" },
+    Subdivision { code: "DO-12", territory: TerritoryCode::DO, category: "province", name: "La Romana" },
+    Subdivision { code: "DO-13", territory: TerritoryCode::DO, category: "province", name: "La Vega" },
+    Subdivision { code: "DO-14", territory: TerritoryCode::DO, category: "province", name: "María Trinidad Sánchez" },
+    Subdivision { code: "DO-15", territory: TerritoryCode::DO, category: "province", name: "Monte Cristi" },
+    Subdivision { code: "DO-16", territory: TerritoryCode::DO, category: "province", name: "Pedernales" },
+    Subdivision { code: "DO-17", territory: TerritoryCode::DO, category: "province", name: "Peravia" },
+    Subdivision { code: "DO-18", territory: TerritoryCode::DO, category: "province", name: "Puerto Plata" },
+    Subdivision { code: "DO-19", territory: TerritoryCode::DO, category: "province", name: "Hermanas Mirabal" },
+    Subdivision { code: "DO-20", territory: TerritoryCode::DO, category: "province", name: "Samaná" },
+    Subdivision { code: "DO-21", territory: TerritoryCode::DO, category: "province", name: "San Cristóbal" },
+    Subdivision { code: "DO-22", territory: TerritoryCode::DO, category: "province", name: "San Juan" },
+    Subdivision { code: "DO-23", territory: TerritoryCode::DO, category: "province", name: "San Pedro de Macorís" },
+    Subdivision { code: "DO-24", territory: TerritoryCode::DO, category: "province", name: "Sánchez Ramírez" },
+    Subdivision { code: "DO-25", territory: TerritoryCode::DO, category: "province", name: "Santiago" },
+    Subdivision { code: "DO-26", territory: TerritoryCode::DO, category: "province", name: "Santiago Rodríguez" },
+    Subdivision { code: "DO-27", territory: TerritoryCode::DO, category: "province", name: "Valverde" },
+    Subdivision { code: "DO-28", territory: TerritoryCode::DO, category: "province", name: "Monseñor Nouel" },
+    Subdivision { code: "DO-29", territory: TerritoryCode::DO, category: "province", name: "Monte Plata" },
+    Subdivision { code: "DO-30", territory: TerritoryCode::DO, category: "province", name: "Hato Mayor" },
+    Subdivision { code: "DO-31", territory: TerritoryCode::DO, category: "province", name: "San José de Ocoa" },
+    Subdivision { code: "DO-32", territory: TerritoryCode::DO, category: "province", name: "Santo Domingo" },
+    Subdivision { code: "DZ-01", territory: TerritoryCode::DZ, category: "province", name: "Adrar" },
+    Subdivision { code: "DZ-02", territory: TerritoryCode::DZ, category: "province", name: "Chlef" },
+    Subdivision { code: "DZ-03", territory: TerritoryCode::DZ, category: "province", name: "Laghouat" },
+    Subdivision { code: "DZ-04", territory: TerritoryCode::DZ, category: "province", name: "Oum El Bouaghi" },
+    Subdivision { code: "DZ-05", territory: TerritoryCode::DZ, category: "province", name: "Batna" },
+    Subdivision { code: "DZ-06", territory: TerritoryCode::DZ, category: "province", name: "Béjaïa" },
+    Subdivision { code: "DZ-07", territory: TerritoryCode::DZ, category: "province", name: "Biskra" },
+    Subdivision { code: "DZ-08", territory: TerritoryCode::DZ, category: "province", name: "Béchar" },
+    Subdivision { code: "DZ-09", territory: TerritoryCode::DZ, category: "province", name: "Blida" },
+    Subdivision { code: "DZ-10", territory: TerritoryCode::DZ, category: "province", name: "Bouira" },
+    Subdivision { code: "DZ-11", territory: TerritoryCode::DZ, category: "province", name: "Tamanrasset" },
+    Subdivision { code: "DZ-12", territory: TerritoryCode::DZ, category: "province", name: "Tébessa" },
+    Subdivision { code: "DZ-13", territory: TerritoryCode::DZ, category: "province", name: "Tlemcen" },
+    Subdivision { code: "DZ-14", territory: TerritoryCode::DZ, category: "province", name: "Tiaret" },
+    Subdivision { code: "DZ-15", territory: TerritoryCode::DZ, category: "province", name: "Tizi Ouzou" },
+    Subdivision { code: "DZ-16", territory: TerritoryCode::DZ, category: "province", name: "Algiers" },
+    Subdivision { code: "DZ-17", territory: TerritoryCode::DZ, category: "province", name: "Djelfa" },
+    Subdivision { code: "DZ-18", territory: TerritoryCode::DZ, category: "province", name: "Jijel" },
+    Subdivision { code: "DZ-19", territory: TerritoryCode::DZ, category: "province", name: "Sétif" },
+    Subdivision { code: "DZ-20", territory: TerritoryCode::DZ, category: "province", name: "Saïda" },
+    Subdivision { code: "DZ-21", territory: TerritoryCode::DZ, category: "province", name: "Skikda" },
+    Subdivision { code: "DZ-22", territory: TerritoryCode::DZ, category: "province", name: "Sidi Bel Abbès" },
+    Subdivision { code: "DZ-23", territory: TerritoryCode::DZ, category: "province", name: "Annaba" },
+    Subdivision { code: "DZ-24", territory: TerritoryCode::DZ, category: "province", name: "Guelma" },
+    Subdivision { code: "DZ-25", territory: TerritoryCode::DZ, category: "province", name: "Constantine" },
+    Subdivision { code: "DZ-26", territory: TerritoryCode::DZ, category: "province", name: "Médéa" },
+    Subdivision { code: "DZ-27", territory: TerritoryCode::DZ, category: "province", name: "Mostaganem" },
+    Subdivision { code: "DZ-28", territory: TerritoryCode::DZ, category: "province", name: "M'Sila" },
+    Subdivision { code: "DZ-29", territory: TerritoryCode::DZ, category: "province", name: "Mascara" },
+    Subdivision { code: "DZ-30", territory: TerritoryCode::DZ, category: "province", name: "Ouargla" },
+    Subdivision { code: "DZ-31", territory: TerritoryCode::DZ, category: "province", name: "Oran" },
+    Subdivision { code: "DZ-32", territory: TerritoryCode::DZ, category: "province", name: "El Bayadh" },
+    Subdivision { code: "DZ-33", territory: TerritoryCode::DZ, category: "province", name: "Illizi" },
+    Subdivision { code: "DZ-34", territory: TerritoryCode::DZ, category: "province", name: "Bordj Bou Arréridj" },
+    Subdivision { code: "DZ-35", territory: TerritoryCode::DZ, category: "province", name: "Boumerdès" },
+    Subdivision { code: "DZ-36", territory: TerritoryCode::DZ, category: "province", name: "El Tarf" },
+    Subdivision { code: "DZ-37", territory: TerritoryCode::DZ, category: "province", name: "Tindouf" },
+    Subdivision { code: "DZ-38", territory: TerritoryCode::DZ, category: "province", name: "Tissemsilt" },
+    Subdivision { code: "DZ-39", territory: TerritoryCode::DZ, category: "province", name: "El Oued" },
+    Subdivision { code: "DZ-40", territory: TerritoryCode::DZ, category: "province", name: "Khenchela" },
+    Subdivision { code: "DZ-41", territory: TerritoryCode::DZ, category: "province", name: "Souk Ahras" },
+    Subdivision { code: "DZ-42", territory: TerritoryCode::DZ, category: "province", name: "Tipaza" },
+    Subdivision { code: "DZ-43", territory: TerritoryCode::DZ, category: "province", name: "Mila" },
+    Subdivision { code: "DZ-44", territory: TerritoryCode::DZ, category: "province", name: "Aïn Defla" },
+    Subdivision { code: "DZ-45", territory: TerritoryCode::DZ, category: "province", name: "Naâma" },
+    Subdivision { code: "DZ-46", territory: TerritoryCode::DZ, category: "province", name: "Aïn Témouchent" },
+    Subdivision { code: "DZ-47", territory: TerritoryCode::DZ, category: "province", name: "Ghardaïa" },
+    Subdivision { code: "DZ-48", territory: TerritoryCode::DZ, category: "province", name: "Relizane" },
+    Subdivision { code: "DZ-49", territory: TerritoryCode::DZ, category: "province", name: "Timimoun" },
+    Subdivision { code: "DZ-50", territory: TerritoryCode::DZ, category: "province", name: "Bordj Badji Mokhtar" },
+    Subdivision { code: "DZ-51", territory: TerritoryCode::DZ, category: "province", name: "Ouled Djellal" },
+    Subdivision { code: "DZ-52", territory: TerritoryCode::DZ, category: "province", name: "Béni Abbès" },
+    Subdivision { code: "DZ-53", territory: TerritoryCode::DZ, category: "province", name: "In Salah" },
+    Subdivision { code: "DZ-54", territory: TerritoryCode::DZ, category: "province", name: "In Guezzam" },
+    Subdivision { code: "DZ-55", territory: TerritoryCode::DZ, category: "province", name: "Touggourt" },
+    Subdivision { code: "DZ-56", territory: TerritoryCode::DZ, category: "province", name: "Djanet" },
+    Subdivision { code: "DZ-57", territory: TerritoryCode::DZ, category: "province", name: "El M'Ghair" },
+    Subdivision { code: "DZ-58", territory: TerritoryCode::DZ, category: "province", name: "El Meniaa" },
+    Subdivision { code: "EC-A", territory: TerritoryCode::EC, category: "province", name: "Azuay" },
+    Subdivision { code: "EC-B", territory: TerritoryCode::EC, category: "province", name: "Bolívar" },
+    Subdivision { code: "EC-C", territory: TerritoryCode::EC, category: "province", name: "Carchi" },
+    Subdivision { code: "EC-D", territory: TerritoryCode::EC, category: "province", name: "Orellana" },
+    Subdivision { code: "EC-E", territory: TerritoryCode::EC, category: "province", name: "Esmeraldas" },
+    Subdivision { code: "EC-F", territory: TerritoryCode::EC, category: "province", name: "Cañar" },
+    Subdivision { code: "EC-G", territory: TerritoryCode::EC, category: "province", name: "Guayas" },
+    Subdivision { code: "EC-H", territory: TerritoryCode::EC, category: "province", name: "Chimborazo" },
+    Subdivision { code: "EC-I", territory: TerritoryCode::EC, category: "province", name: "Imbabura" },
+    Subdivision { code: "EC-L", territory: TerritoryCode::EC, category: "province", name: "Loja" },
+    Subdivision { code: "EC-M", territory: TerritoryCode::EC, category: "province", name: "Manabí" },
+    Subdivision { code: "EC-N", territory: TerritoryCode::EC, category: "province", name: "Napo" },
+    Subdivision { code: "EC-O", territory: TerritoryCode::EC, category: "province", name: "El Oro" },
+    Subdivision { code: "EC-P", territory: TerritoryCode::EC, category: "province", name: "Pichincha" },
+    Subdivision { code: "EC-R", territory: TerritoryCode::EC, category: "province", name: "Los Ríos" },
+    Subdivision { code: "EC-S", territory: TerritoryCode::EC, category: "province", name: "Morona Santiago" },
+    Subdivision { code: "EC-SD", territory: TerritoryCode::EC, category: "province", name: "Santo Domingo de los Tsáchilas" },
+    Subdivision { code: "EC-SE", territory: TerritoryCode::EC, category: "province", name: "Santa Elena" },
+    Subdivision { code: "EC-T", territory: TerritoryCode::EC, category: "province", name: "Tungurahua" },
+    Subdivision { code: "EC-U", territory: TerritoryCode::EC, category: "province", name: "Sucumbíos" },
+    Subdivision { code: "EC-W", territory: TerritoryCode::EC, category: "province", name: "Galápagos" },
+    Subdivision { code: "EC-X", territory: TerritoryCode::EC, category: "province", name: "Cotopaxi" },
+    Subdivision { code: "EC-Y", territory: TerritoryCode::EC, category: "province", name: "Pastaza" },
+    Subdivision { code: "EC-Z", territory: TerritoryCode::EC, category: "province", name: "Zamora Chinchipe" },
+    Subdivision { code: "EE-37", territory: TerritoryCode::EE, category: "county", name: "Harju" },
+    Subdivision { code: "EE-39", territory: TerritoryCode::EE, category: "county", name: "Hiiu" },
+    Subdivision { code: "EE-44", territory: TerritoryCode::EE, category: "county", name: "Ida-Viru" },
+    Subdivision { code: "EE-49", territory: TerritoryCode::EE, category: "county", name: "Jõgeva" },
+    Subdivision { code: "EE-51", territory: TerritoryCode::EE, category: "county", name: "Järva" },
+    Subdivision { code: "EE-57", territory: TerritoryCode::EE, category: "county", name: "Lääne" },
+    Subdivision { code: "EE-59", territory: TerritoryCode::EE, category: "county", name: "Lääne-Viru" },
+    Subdivision { code: "EE-65", territory: TerritoryCode::EE, category: "county", name: "Põlva" },
+    Subdivision { code: "EE-67", territory: TerritoryCode::EE, category: "county", name: "Pärnu" },
+    Subdivision { code: "EE-70", territory: TerritoryCode::EE, category: "county", name: "Rapla" },
+    Subdivision { code: "EE-74", territory: TerritoryCode::EE, category: "county", name: "Saare" },
+    Subdivision { code: "EE-78", territory: TerritoryCode::EE, category: "county", name: "Tartu" },
+    Subdivision { code: "EE-82", territory: TerritoryCode::EE, category: "county", name: "Valga" },
+    Subdivision { code: "EE-84", territory: TerritoryCode::EE, category: "county", name: "Viljandi" },
+    Subdivision { code: "EE-86", territory: TerritoryCode::EE, category: "county", name: "Võru" },
+    Subdivision { code: "EG-ALX", territory: TerritoryCode::EG, category: "governorate", name: "Alexandria" },
+    Subdivision { code: "EG-ASN", territory: TerritoryCode::EG, category: "governorate", name: "Aswan" },
+    Subdivision { code: "EG-AST", territory: TerritoryCode::EG, category: "governorate", name: "Asyut" },
+    Subdivision { code: "EG-BA", territory: TerritoryCode::EG, category: "governorate", name: "Red Sea" },
+    Subdivision { code: "EG-BH", territory: TerritoryCode::EG, category: "governorate", name: "Beheira" },
+    Subdivision { code: "EG-BNS", territory: TerritoryCode::EG, category: "governorate", name: "Beni Suef" },
+    Subdivision { code: "EG-C", territory: TerritoryCode::EG, category: "governorate", name: "Cairo" },
+    Subdivision { code: "EG-DK", territory: TerritoryCode::EG, category: "governorate", name: "Dakahlia" },
+    Subdivision { code: "EG-DT", territory: TerritoryCode::EG, category: "governorate", name: "Damietta" },
+    Subdivision { code: "EG-FYM", territory: TerritoryCode::EG, category: "governorate", name: "Faiyum" },
+    Subdivision { code: "EG-GH", territory: TerritoryCode::EG, category: "governorate", name: "Gharbia" },
+    Subdivision { code: "EG-GZ", territory: TerritoryCode::EG, category: "governorate", name: "Giza" },
+    Subdivision { code: "EG-IS", territory: TerritoryCode::EG, category: "governorate", name: "Ismailia" },
+    Subdivision { code: "EG-JS", territory: TerritoryCode::EG, category: "governorate", name: "South Sinai" },
+    Subdivision { code: "EG-KB", territory: TerritoryCode::EG, category: "governorate", name: "Qalyubia" },
+    Subdivision { code: "EG-KFS", territory: TerritoryCode::EG, category: "governorate", name: "Kafr el-Sheikh" },
+    Subdivision { code: "EG-KN", territory: TerritoryCode::EG, category: "governorate", name: "Qena" },
+    Subdivision { code: "EG-LX", territory: TerritoryCode::EG, category: "governorate", name: "Luxor" },
+    Subdivision { code: "EG-MN", territory: TerritoryCode::EG, category: "governorate", name: "Minya" },
+    Subdivision { code: "EG-MNF", territory: TerritoryCode::EG, category: "governorate", name: "Monufia" },
+    Subdivision { code: "EG-MT", territory: TerritoryCode::EG, category: "governorate", name: "Matrouh" },
+    Subdivision { code: "EG-PTS", territory: TerritoryCode::EG, category: "governorate", name: "Port Said" },
+    Subdivision { code: "EG-SHG", territory: TerritoryCode::EG, category: "governorate", name: "Sohag" },
+    Subdivision { code: "EG-SHR", territory: TerritoryCode::EG, category: "governorate", name: "Sharqia" },
+    Subdivision { code: "EG-SIN", territory: TerritoryCode::EG, category: "governorate", name: "North Sinai" },
+    Subdivision { code: "EG-SUZ", territory: TerritoryCode::EG, category: "governorate", name: "Suez" },
+    Subdivision { code: "EG-WAD", territory: TerritoryCode::EG, category: "governorate", name: "New Valley" },
+    Subdivision { code: "ER-AN", territory: TerritoryCode::ER, category: "region", name: "Anseba" },
+    Subdivision { code: "ER-DK", territory: TerritoryCode::ER, category: "region", name: "Southern Red Sea" },
+    Subdivision { code: "ER-DU", territory: TerritoryCode::ER, category: "region", name: "Southern" },
+    Subdivision { code: "ER-GB", territory: TerritoryCode::ER, category: "region", name: "Gash-Barka" },
+    Subdivision { code: "ER-MA", territory: TerritoryCode::ER, category: "region", name: "Central" },
+    Subdivision { code: "ER-SK", territory: TerritoryCode::ER, category: "region", name: "Northern Red Sea" },
+    Subdivision { code: "ES-A", territory: TerritoryCode::ES, category: "province", name: "Alicante" },
+    Subdivision { code: "ES-AB", territory: TerritoryCode::ES, category: "province", name: "Albacete" },
+    Subdivision { code: "ES-AL", territory: TerritoryCode::ES, category: "province", name: "Almería" },
+    Subdivision { code: "ES-AN", territory: TerritoryCode::ES, category: "autonomous community", name: "Andalusia" },
+    Subdivision { code: "ES-AR", territory: TerritoryCode::ES, category: "autonomous community", name: "Aragon" },
+    Subdivision { code: "ES-AS", territory: TerritoryCode::ES, category: "autonomous community", name: "Asturias" },
+    Subdivision { code: "ES-AV", territory: TerritoryCode::ES, category: "province", name: "Ávila" },
+    Subdivision { code: "ES-B", territory: TerritoryCode::ES, category: "province", name: "Barcelona" },
+    Subdivision { code: "ES-BA", territory: TerritoryCode::ES, category: "province", name: "Badajoz" },
+    Subdivision { code: "ES-BI", territory: TerritoryCode::ES, category: "province", name: "Biscay" },
+    Subdivision { code: "ES-BU", territory: TerritoryCode::ES, category: "province", name: "Burgos" },
+    Subdivision { code: "ES-C", territory: TerritoryCode::ES, category: "province", name: "A Coruña" },
+    Subdivision { code: "ES-CA", territory: TerritoryCode::ES, category: "province", name: "Cádiz" },
+    Subdivision { code: "ES-CB", territory: TerritoryCode::ES, category: "autonomous community", name: "Cantabria" },
+    Subdivision { code: "ES-CC", territory: TerritoryCode::ES, category: "province", name: "Cáceres" },
+    Subdivision { code: "ES-CE", territory: TerritoryCode::ES, category: "autonomous city", name: "Ceuta" },
+    Subdivision { code: "ES-CL", territory: TerritoryCode::ES, category: "autonomous community", name: "Castile and León" },
+    Subdivision { code: "ES-CM", territory: TerritoryCode::ES, category: "autonomous community", name: "Castilla-La Mancha" },
+    Subdivision { code: "ES-CN", territory: TerritoryCode::ES, category: "autonomous community", name: "Canary Islands" },
+    Subdivision { code: "ES-CO", territory: TerritoryCode::ES, category: "province", name: "Córdoba" },
+    Subdivision { code: "ES-CR", territory: TerritoryCode::ES, category: "province", name: "Ciudad Real" },
+    Subdivision { code: "ES-CS", territory: TerritoryCode::ES, category: "province", name: "Castellón" },
+    Subdivision { code: "ES-CT", territory: TerritoryCode::ES, category: "autonomous community", name: "Catalonia" },
+    Subdivision { code: "ES-CU", territory: TerritoryCode::ES, category: "province", name: "Cuenca" },
+    Subdivision { code: "ES-EX", territory: TerritoryCode::ES, category: "autonomous community", name: "Extremadura" },
+    Subdivision { code: "ES-GA", territory: TerritoryCode::ES, category: "autonomous community", name: "Galicia" },
+    Subdivision { code: "ES-GC", territory: TerritoryCode::ES, category: "province", name: "Las Palmas" },
+    Subdivision { code: "ES-GI", territory: TerritoryCode::ES, category: "province", name: "Girona" },
+    Subdivision { code: "ES-GR", territory: TerritoryCode::ES, category: "province", name: "Granada" },
+    Subdivision { code: "ES-GU", territory: TerritoryCode::ES, category: "province", name: "Guadalajara" },
+    Subdivision { code: "ES-H", territory: TerritoryCode::ES, category: "province", name: "Huelva" },
+    Subdivision { code: "ES-HU", territory: TerritoryCode::ES, category: "province", name: "Huesca" },
+    Subdivision { code: "ES-IB", territory: TerritoryCode::ES, category: "autonomous community", name: "Balearic Islands" },
+    Subdivision { code: "ES-J", territory: TerritoryCode::ES, category: "province", name: "Jaén" },
+    Subdivision { code: "ES-L", territory: TerritoryCode::ES, category: "province", name: "Lleida" },
+    Subdivision { code: "ES-LE", territory: TerritoryCode::ES, category: "province", name: "León" },
+    Subdivision { code: "ES-LO", territory: TerritoryCode::ES, category: "province", name: "La Rioja" },
+    Subdivision { code: "ES-LU", territory: TerritoryCode::ES, category: "province", name: "Lugo" },
+    Subdivision { code: "ES-M", territory: TerritoryCode::ES, category: "province", name: "Madrid" },
+    Subdivision { code: "ES-MA", territory: TerritoryCode::ES, category: "province", name: "Málaga" },
+    Subdivision { code: "ES-MC", territory: TerritoryCode::ES, category: "autonomous community", name: "Region of Murcia" },
+    Subdivision { code: "ES-MD", territory: TerritoryCode::ES, category: "autonomous community", name: "Community of Madrid" },
+    Subdivision { code: "ES-ML", territory: TerritoryCode::ES, category: "autonomous city", name: "Melilla" },
+    Subdivision { code: "ES-MU", territory: TerritoryCode::ES, category: "province", name: "Murcia" },
+    Subdivision { code: "ES-NA", territory: TerritoryCode::ES, category: "province", name: "Navarre" },
+    Subdivision { code: "ES-NC", territory: TerritoryCode::ES, category: "autonomous community", name: "Chartered Community of Navarre" },
+    Subdivision { code: "ES-O", territory: TerritoryCode::ES, category: "province", name: "Asturias" },
+    Subdivision { code: "ES-OR", territory: TerritoryCode::ES, category: "province", name: "Ourense" },
+    Subdivision { code: "ES-P", territory: TerritoryCode::ES, category: "province", name: "Palencia" },
+    Subdivision { code: "ES-PM", territory: TerritoryCode::ES, category: "province", name: "Balearic Islands" },
+    Subdivision { code: "ES-PO", territory: TerritoryCode::ES, category: "province", name: "Pontevedra" },
+    Subdivision { code: "ES-PV", territory: TerritoryCode::ES, category: "autonomous community", name: "Basque Country" },
+    Subdivision { code: "ES-RI", territory: TerritoryCode::ES, category: "autonomous community", name: "La Rioja" },
+    Subdivision { code: "ES-S", territory: TerritoryCode::ES, category: "province", name: "Cantabria" },
+    Subdivision { code: "ES-SA", territory: TerritoryCode::ES, category: "province", name: "Salamanca" },
+    Subdivision { code: "ES-SE", territory: TerritoryCode::ES, category: "province", name: "Seville" },
+    Subdivision { code: "ES-SG", territory: TerritoryCode::ES, category: "province", name: "Segovia" },
+    Subdivision { code: "ES-SO", territory: TerritoryCode::ES, category: "province", name: "Soria" },
+    Subdivision { code: "ES-SS", territory: TerritoryCode::ES, category: "province", name: "Gipuzkoa" },
+    Subdivision { code: "ES-T", territory: TerritoryCode::ES, category: "province", name: "Tarragona" },
+    Subdivision { code: "ES-TE", territory: TerritoryCode::ES, category: "province", name: "Teruel" },
+    Subdivision { code: "ES-TF", territory: TerritoryCode::ES, category: "province", name: "Santa Cruz de Tenerife" },
+    Subdivision { code: "ES-TO", territory: TerritoryCode::ES, category: "province", name: "Toledo" },
+    Subdivision { code: "ES-V", territory: TerritoryCode::ES, category: "province", name: "Valencia" },
+    Subdivision { code: "ES-VA", territory: TerritoryCode::ES, category: "province", name: "Valladolid" },
+    Subdivision { code: "ES-VC", territory: TerritoryCode::ES, category: "autonomous community", name: "Valencian Community" },
+    Subdivision { code: "ES-VI", territory: TerritoryCode::ES, category: "province", name: "Álava" },
+    Subdivision { code: "ES-Z", territory: TerritoryCode::ES, category: "province", name: "Zaragoza" },
+    Subdivision { code: "ES-ZA", territory: TerritoryCode::ES, category: "province", name: "Zamora" },
+    Subdivision { code: "ET-AA", territory: TerritoryCode::ET, category: "administration", name: "Addis Ababa" },
+    Subdivision { code: "ET-AF", territory: TerritoryCode::ET, category: "regional state", name: "Afar" },
+    Subdivision { code: "ET-AM", territory: TerritoryCode::ET, category: "regional state", name: "Amhara" },
+    Subdivision { code: "ET-BE", territory: TerritoryCode::ET, category: "regional state", name: "Benishangul-Gumuz" },
+    Subdivision { code: "ET-DD", territory: TerritoryCode::ET, category: "administration", name: "Dire Dawa" },
+    Subdivision { code: "ET-GA", territory: TerritoryCode::ET, category: "regional state", name: "Gambela" },
+    Subdivision { code: "ET-HA", territory: TerritoryCode::ET, category: "regional state", name: "Harari" },
+    Subdivision { code: "ET-OR", territory: TerritoryCode::ET, category: "regional state", name: "Oromia" },
+    Subdivision { code: "ET-SI", territory: TerritoryCode::ET, category: "regional state", name: "Sidama" },
+    Subdivision { code: "ET-SN", territory: TerritoryCode::ET, category: "regional state", name: "Southern Nations, Nationalities and Peoples" },
+    Subdivision { code: "ET-SO", territory: TerritoryCode::ET, category: "regional state", name: "Somali" },
+    Subdivision { code: "ET-SW", territory: TerritoryCode::ET, category: "regional state", name: "South West Ethiopia Peoples" },
+    Subdivision { code: "ET-TI", territory: TerritoryCode::ET, category: "regional state", name: "Tigray" },
+    Subdivision { code: "FI-02", territory: TerritoryCode::FI, category: "region", name: "South Karelia" },
+    Subdivision { code: "FI-03", territory: TerritoryCode::FI, category: "region", name: "South Ostrobothnia" },
+    Subdivision { code: "FI-04", territory: TerritoryCode::FI, category: "region", name: "South Savo" },
+    Subdivision { code: "FI-05", territory: TerritoryCode::FI, category: "region", name: "Kainuu" },
+    Subdivision { code: "FI-06", territory: TerritoryCode::FI, category: "region", name: "Tavastia Proper" },
+    Subdivision { code: "FI-07", territory: TerritoryCode::FI, category: "region", name: "Central Ostrobothnia" },
+    Subdivision { code: "FI-08", territory: TerritoryCode::FI, category: "region", name: "Central Finland" },
+    Subdivision { code: "FI-09", territory: TerritoryCode::FI, category: "region", name: "Kymenlaakso" },
+    Subdivision { code: "FI-10", territory: TerritoryCode::FI, category: "region", name: "Lapland" },
+    Subdivision { code: "FI-11", territory: TerritoryCode::FI, category: "region", name: "Pirkanmaa" },
+    Subdivision { code: "FI-12", territory: TerritoryCode::FI, category: "region", name: "Ostrobothnia" },
+    Subdivision { code: "FI-13", territory: TerritoryCode::FI, category: "region", name: "North Karelia" },
+    Subdivision { code: "FI-14", territory: TerritoryCode::FI, category: "region", name: "North Ostrobothnia" },
+    Subdivision { code: "FI-15", territory: TerritoryCode::FI, category: "region", name: "North Savo" },
+    Subdivision { code: "FI-16", territory: TerritoryCode::FI, category: "region", name: "Päijät-Häme" },
+    Subdivision { code: "FI-17", territory: TerritoryCode::FI, category: "region", name: "Satakunta" },
+    Subdivision { code: "FI-18", territory: TerritoryCode::FI, category: "region", name: "Uusimaa" },
+    Subdivision { code: "FI-19", territory: TerritoryCode::FI, category: "region", name: "Southwest Finland" },
+    Subdivision { code: "FJ-C", territory: TerritoryCode::FJ, category: "division", name: "Central" },
+    Subdivision { code: "FJ-E", territory: TerritoryCode::FJ, category: "division", name: "Eastern" },
+    Subdivision { code: "FJ-N", territory: TerritoryCode::FJ, category: "division", name: "Northern" },
+    Subdivision { code: "FJ-R", territory: TerritoryCode::FJ, category: "dependency", name: "Rotuma" },
+    Subdivision { code: "FJ-W", territory: TerritoryCode::FJ, category: "division", name: "Western" },
+    Subdivision { code: "FM-KSA", territory: TerritoryCode::FM, category: "state", name: "Kosrae" },
+    Subdivision { code: "FM-PNI", territory: TerritoryCode::FM, category: "state", name: "Pohnpei" },
+    Subdivision { code: "FM-TRK", territory: TerritoryCode::FM, category: "state", name: "Chuuk" },
+    Subdivision { code: "FM-YAP", territory: TerritoryCode::FM, category: "state", name: "Yap" },
+    Subdivision { code: "FR-01", territory: TerritoryCode::FR, category: "metropolitan department", name: "Ain" },
+    Subdivision { code: "FR-02", territory: TerritoryCode::FR, category: "metropolitan department", name: "Aisne" },
+    Subdivision { code: "FR-03", territory: TerritoryCode::FR, category: "metropolitan department", name: "Allier" },
+    Subdivision { code: "FR-04", territory: TerritoryCode::FR, category: "metropolitan department", name: "Alpes-de-Haute-Provence" },
+    Subdivision { code: "FR-05", territory: TerritoryCode::FR, category: "metropolitan department", name: "Hautes-Alpes" },
+    Subdivision { code: "FR-06", territory: TerritoryCode::FR, category: "metropolitan department", name: "Alpes-Maritimes" },
+    Subdivision { code: "FR-07", territory: TerritoryCode::FR, category: "metropolitan department", name: "Ardèche" },
+    Subdivision { code: "FR-08", territory: TerritoryCode::FR, category: "metropolitan department", name: "Ardennes" },
+    Subdivision { code: "FR-09", territory: TerritoryCode::FR, category: "metropolitan department", name: "Ariège" },
+    Subdivision { code: "FR-10", territory: TerritoryCode::FR, category: "metropolitan department", name: "Aube" },
+    Subdivision { code: "FR-11", territory: TerritoryCode::FR, category: "metropolitan department", name: "Aude" },
+    Subdivision { code: "FR-12", territory: TerritoryCode::FR, category: "metropolitan department", name: "Aveyron" },
+    Subdivision { code: "FR-13", territory: TerritoryCode::FR, category: "metropolitan department", name: "Bouches-du-Rhône" },
+    Subdivision { code: "FR-14", territory: TerritoryCode::FR, category: "metropolitan department", name: "Calvados" },
+    Subdivision { code: "FR-15", territory: TerritoryCode::FR, category: "metropolitan department", name: "Cantal" },
+    Subdivision { code: "FR-16", territory: TerritoryCode::FR, category: "metropolitan department", name: "Charente" },
+    Subdivision { code: "FR-17", territory: TerritoryCode::FR, category: "metropolitan department", name: "Charente-Maritime" },
+    Subdivision { code: "FR-18", territory: TerritoryCode::FR, category: "metropolitan department", name: "Cher" },
+    Subdivision { code: "FR-19", territory: TerritoryCode::FR, category: "metropolitan department", name: "Corrèze" },
+    Subdivision { code: "FR-20R", territory: TerritoryCode::FR, category: "metropolitan collectivity with special status", name: "Corsica" },
+    Subdivision { code: "FR-21", territory: TerritoryCode::FR, category: "metropolitan department", name: "Côte-d'Or" },
+    Subdivision { code: "FR-22", territory: TerritoryCode::FR, category: "metropolitan department", name: "Côtes-d'Armor" },
+    Subdivision { code: "FR-23", territory: TerritoryCode::FR, category: "metropolitan department", name: "Creuse" },
+    Subdivision { code: "FR-24", territory: TerritoryCode::FR, category: "metropolitan department", name: "Dordogne" },
+    Subdivision { code: "FR-25", territory: TerritoryCode::FR, category: "metropolitan department", name: "Doubs" },
+    Subdivision { code: "FR-26", territory: TerritoryCode::FR, category: "metropolitan department", name: "Drôme" },
+    Subdivision { code: "FR-27", territory: TerritoryCode::FR, category: "metropolitan department", name: "Eure" },
+    Subdivision { code: "FR-28", territory: TerritoryCode::FR, category: "metropolitan department", name: "Eure-et-Loir" },
+    Subdivision { code: "FR-29", territory: TerritoryCode::FR, category: "metropolitan department", name: "Finistère" },
+    Subdivision { code: "FR-2A", territory: TerritoryCode::FR, category: "metropolitan department", name: "Corse-du-Sud" },
+    Subdivision { code: "FR-2B", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Corse" },
+    Subdivision { code: "FR-30", territory: TerritoryCode::FR, category: "metropolitan department", name: "Gard" },
+    Subdivision { code: "FR-31", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Garonne" },
+    Subdivision { code: "FR-32", territory: TerritoryCode::FR, category: "metropolitan department", name: "Gers" },
+    Subdivision { code: "FR-33", territory: TerritoryCode::FR, category: "metropolitan department", name: "Gironde" },
+    Subdivision { code: "FR-34", territory: TerritoryCode::FR, category: "metropolitan department", name: "Hérault" },
+    Subdivision { code: "FR-35", territory: TerritoryCode::FR, category: "metropolitan department", name: "Ille-et-Vilaine" },
+    Subdivision { code: "FR-36", territory: TerritoryCode::FR, category: "metropolitan department", name: "Indre" },
+    Subdivision { code: "FR-37", territory: TerritoryCode::FR, category: "metropolitan department", name: "Indre-et-Loire" },
+    Subdivision { code: "FR-38", territory: TerritoryCode::FR, category: "metropolitan department", name: "Isère" },
+    Subdivision { code: "FR-39", territory: TerritoryCode::FR, category: "metropolitan department", name: "Jura" },
+    Subdivision { code: "FR-40", territory: TerritoryCode::FR, category: "metropolitan department", name: "Landes" },
+    Subdivision { code: "FR-41", territory: TerritoryCode::FR, category: "metropolitan department", name: "Loir-et-Cher" },
+    Subdivision { code: "FR-42", territory: TerritoryCode::FR, category: "metropolitan department", name: "Loire" },
+    Subdivision { code: "FR-43", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Loire" },
+    Subdivision { code: "FR-44", territory: TerritoryCode::FR, category: "metropolitan department", name: "Loire-Atlantique" },
+    Subdivision { code: "FR-45", territory: TerritoryCode::FR, category: "metropolitan department", name: "Loiret" },
+    Subdivision { code: "FR-46", territory: TerritoryCode::FR, category: "metropolitan department", name: "Lot" },
+    Subdivision { code: "FR-47", territory: TerritoryCode::FR, category: "metropolitan department", name: "Lot-et-Garonne" },
+    Subdivision { code: "FR-48", territory: TerritoryCode::FR, category: "metropolitan department", name: "Lozère" },
+    Subdivision { code: "FR-49", territory: TerritoryCode::FR, category: "metropolitan department", name: "Maine-et-Loire" },
+    Subdivision { code: "FR-50", territory: TerritoryCode::FR, category: "metropolitan department", name: "Manche" },
+    Subdivision { code: "FR-51", territory: TerritoryCode::FR, category: "metropolitan department", name: "Marne" },
+    Subdivision { code: "FR-52", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Marne" },
+    Subdivision { code: "FR-53", territory: TerritoryCode::FR, category: "metropolitan department", name: "Mayenne" },
+    Subdivision { code: "FR-54", territory: TerritoryCode::FR, category: "metropolitan department", name: "Meurthe-et-Moselle" },
+    Subdivision { code: "FR-55", territory: TerritoryCode::FR, category: "metropolitan department", name: "Meuse" },
+    Subdivision { code: "FR-56", territory: TerritoryCode::FR, category: "metropolitan department", name: "Morbihan" },
+    Subdivision { code: "FR-57", territory: TerritoryCode::FR, category: "metropolitan department", name: "Moselle" },
+    Subdivision { code: "FR-58", territory: TerritoryCode::FR, category: "metropolitan department", name: "Nièvre" },
+    Subdivision { code: "FR-59", territory: TerritoryCode::FR, category: "metropolitan department", name: "Nord" },
+    Subdivision { code: "FR-60", territory: TerritoryCode::FR, category: "metropolitan department", name: "Oise" },
+    Subdivision { code: "FR-61", territory: TerritoryCode::FR, category: "metropolitan department", name: "Orne" },
+    Subdivision { code: "FR-62", territory: TerritoryCode::FR, category: "metropolitan department", name: "Pas-de-Calais" },
+    Subdivision { code: "FR-63", territory: TerritoryCode::FR, category: "metropolitan department", name: "Puy-de-Dôme" },
+    Subdivision { code: "FR-64", territory: TerritoryCode::FR, category: "metropolitan department", name: "Pyrénées-Atlantiques" },
+    Subdivision { code: "FR-65", territory: TerritoryCode::FR, category: "metropolitan department", name: "Hautes-Pyrénées" },
+    Subdivision { code: "FR-66", territory: TerritoryCode::FR, category: "metropolitan department", name: "Pyrénées-Orientales" },
+    Subdivision { code: "FR-67", territory: TerritoryCode::FR, category: "metropolitan department", name: "Bas-Rhin" },
+    Subdivision { code: "FR-68", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haut-Rhin" },
+    Subdivision { code: "FR-69", territory: TerritoryCode::FR, category: "metropolitan department", name: "Rhône" },
+    Subdivision { code: "FR-70", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Saône" },
+    Subdivision { code: "FR-71", territory: TerritoryCode::FR, category: "metropolitan department", name: "Saône-et-Loire" },
+    Subdivision { code: "FR-72", territory: TerritoryCode::FR, category: "metropolitan department", name: "Sarthe" },
+    Subdivision { code: "FR-73", territory: TerritoryCode::FR, category: "metropolitan department", name: "Savoie" },
+    Subdivision { code: "FR-74", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Savoie" },
+    Subdivision { code: "FR-75", territory: TerritoryCode::FR, category: "metropolitan department", name: "Paris" },
+    Subdivision { code: "FR-76", territory: TerritoryCode::FR, category: "metropolitan department", name: "Seine-Maritime" },
+    Subdivision { code: "FR-77", territory: TerritoryCode::FR, category: "metropolitan department", name: "Seine-et-Marne" },
+    Subdivision { code: "FR-78", territory: TerritoryCode::FR, category: "metropolitan department", name: "Yvelines" },
+    Subdivision { code: "FR-79", territory: TerritoryCode::FR, category: "metropolitan department", name: "Deux-Sèvres" },
+    Subdivision { code: "FR-80", territory: TerritoryCode::FR, category: "metropolitan department", name: "Somme" },
+    Subdivision { code: "FR-81", territory: TerritoryCode::FR, category: "metropolitan department", name: "Tarn" },
+    Subdivision { code: "FR-82", territory: TerritoryCode::FR, category: "metropolitan department", name: "Tarn-et-Garonne" },
+    Subdivision { code: "FR-83", territory: TerritoryCode::FR, category: "metropolitan department", name: "Var" },
+    Subdivision { code: "FR-84", territory: TerritoryCode::FR, category: "metropolitan department", name: "Vaucluse" },
+    Subdivision { code: "FR-85", territory: TerritoryCode::FR, category: "metropolitan department", name: "Vendée" },
+    Subdivision { code: "FR-86", territory: TerritoryCode::FR, category: "metropolitan department", name: "Vienne" },
+    Subdivision { code: "FR-87", territory: TerritoryCode::FR, category: "metropolitan department", name: "Haute-Vienne" },
+    Subdivision { code: "FR-88", territory: TerritoryCode::FR, category: "metropolitan department", name: "Vosges" },
+    Subdivision { code: "FR-89", territory: TerritoryCode::FR, category: "metropolitan department", name: "Yonne" },
+    Subdivision { code: "FR-90", territory: TerritoryCode::FR, category: "metropolitan department", name: "Territoire de Belfort" },
+    Subdivision { code: "FR-91", territory: TerritoryCode::FR, category: "metropolitan department", name: "Essonne" },
+    Subdivision { code: "FR-92", territory: TerritoryCode::FR, category: "metropolitan department", name: "Hauts-de-Seine" },
+    Subdivision { code: "FR-93", territory: TerritoryCode::FR, category: "metropolitan department", name: "Seine-Saint-Denis" },
+    Subdivision { code: "FR-94", territory: TerritoryCode::FR, category: "metropolitan department", name: "Val-de-Marne" },
+    Subdivision { code: "FR-95", territory: TerritoryCode::FR, category: "metropolitan department", name: "Val-d'Oise" },
+    Subdivision { code: "FR-ARA", territory: TerritoryCode::FR, category: "metropolitan region", name: "Auvergne-Rhône-Alpes" },
+    Subdivision { code: "FR-BFC", territory: TerritoryCode::FR, category: "metropolitan region", name: "Bourgogne-Franche-Comté" },
+    Subdivision { code: "FR-BRE", territory: TerritoryCode::FR, category: "metropolitan region", name: "Brittany" },
+    Subdivision { code: "FR-CVL", territory: TerritoryCode::FR, category: "metropolitan region", name: "Centre-Val de Loire" },
+    Subdivision { code: "FR-GES", territory: TerritoryCode::FR, category: "metropolitan region", name: "Grand Est" },
+    Subdivision { code: "FR-HDF", territory: TerritoryCode::FR, category: "metropolitan region", name: "Hauts-de-France" },
+    Subdivision { code: "FR-IDF", territory: TerritoryCode::FR, category: "metropolitan region", name: "Île-de-France" },
+    Subdivision { code: "FR-NAQ", territory: TerritoryCode::FR, category: "metropolitan region", name: "Nouvelle-Aquitaine" },
+    Subdivision { code: "FR-NOR", territory: TerritoryCode::FR, category: "metropolitan region", name: "Normandy" },
+    Subdivision { code: "FR-OCC", territory: TerritoryCode::FR, category: "metropolitan region", name: "Occitanie" },
+    Subdivision { code: "FR-PAC", territory: TerritoryCode::FR, category: "metropolitan region", name: "Provence-Alpes-Côte d'Azur" },
+    Subdivision { code: "FR-PDL", territory: TerritoryCode::FR, category: "metropolitan region", name: "Pays de la Loire" },
+    Subdivision { code: "GA-1", territory: TerritoryCode::GA, category: "province", name: "Estuaire" },
+    Subdivision { code: "GA-2", territory: TerritoryCode::GA, category: "province", name: "Haut-Ogooué" },
+    Subdivision { code: "GA-3", territory: TerritoryCode::GA, category: "province", name: "Moyen-Ogooué" },
+    Subdivision { code: "GA-4", territory: TerritoryCode::GA, category: "province", name: "Ngounié" },
+    Subdivision { code: "GA-5", territory: TerritoryCode::GA, category: "province", name: "Nyanga" },
+    Subdivision { code: "GA-6", territory: TerritoryCode::GA, category: "province", name: "Ogooué-Ivindo" },
+    Subdivision { code: "GA-7", territory: TerritoryCode::GA, category: "province", name: "Ogooué-Lolo" },
+    Subdivision { code: "GA-8", territory: TerritoryCode::GA, category: "province", name: "Ogooué-Maritime" },
+    Subdivision { code: "GA-9", territory: TerritoryCode::GA, category: "province", name: "Woleu-Ntem" },
+    Subdivision { code: "GB-ENG", territory: TerritoryCode::GB, category: "country", name: "England" },
+    Subdivision { code: "GB-NIR", territory: TerritoryCode::GB, category: "province", name: "Northern Ireland" },
+    Subdivision { code: "GB-SCT", territory: TerritoryCode::GB, category: "country", name: "Scotland" },
+    Subdivision { code: "GB-WLS", territory: TerritoryCode::GB, category: "country", name: "Wales" },
+    Subdivision { code: "GD-01", territory: TerritoryCode::GD, category: "parish", name: "Saint Andrew" },
+    Subdivision { code: "GD-02", territory: TerritoryCode::GD, category: "parish", name: "Saint David" },
+    Subdivision { code: "GD-03", territory: TerritoryCode::GD, category: "parish", name: "Saint George" },
+    Subdivision { code: "GD-04", territory: TerritoryCode::GD, category: "parish", name: "Saint John" },
+    Subdivision { code: "GD-05", territory: TerritoryCode::GD, category: "parish", name: "Saint Mark" },
+    Subdivision { code: "GD-06", territory: TerritoryCode::GD, category: "parish", name: "Saint Patrick" },
+    Subdivision { code: "GD-10", territory: TerritoryCode::GD, category: "dependency", name: "Southern Grenadine Islands" },
+    Subdivision { code: "GE-AB", territory: TerritoryCode::GE, category: "autonomous republic", name: "Abkhazia" },
+    Subdivision { code: "GE-AJ", territory: TerritoryCode::GE, category: "autonomous republic", name: "Adjara" },
+    Subdivision { code: "GE-GU", territory: TerritoryCode::GE, category: "region", name: "Guria" },
+    Subdivision { code: "GE-IM", territory: TerritoryCode::GE, category: "region", name: "Imereti" },
+    Subdivision { code: "GE-KA", territory: TerritoryCode::GE, category: "region", name: "Kakheti" },
+    Subdivision { code: "GE-KK", territory: TerritoryCode::GE, category: "region", name: "Kvemo Kartli" },
+    Subdivision { code: "GE-MM", territory: TerritoryCode::GE, category: "region", name: "Mtskheta-Mtianeti" },
+    Subdivision { code: "GE-RL", territory: TerritoryCode::GE, category: "region", name: "Racha-Lechkhumi and Kvemo Svaneti" },
+    Subdivision { code: "GE-SJ", territory: TerritoryCode::GE, category: "region", name: "Samtskhe-Javakheti" },
+    Subdivision { code: "GE-SK", territory: TerritoryCode::GE, category: "region", name: "Shida Kartli" },
+    Subdivision { code: "GE-SZ", territory: TerritoryCode::GE, category: "region", name: "Samegrelo-Zemo Svaneti" },
+    Subdivision { code: "GE-TB", territory: TerritoryCode::GE, category: "city", name: "Tbilisi" },
+    Subdivision { code: "GH-AA", territory: TerritoryCode::GH, category: "region", name: "Greater Accra" },
+    Subdivision { code: "GH-AF", territory: TerritoryCode::GH, category: "region", name: "Ahafo" },
+    Subdivision { code: "GH-AH", territory: TerritoryCode::GH, category: "region", name: "Ashanti" },
+    Subdivision { code: "GH-BE", territory: TerritoryCode::GH, category: "region", name: "Bono East" },
+    Subdivision { code: "GH-BO", territory: TerritoryCode::GH, category: "region", name: "Bono" },
+    Subdivision { code: "GH-CP", territory: TerritoryCode::GH, category: "region", name: "Central" },
+    Subdivision { code: "GH-EP", territory: TerritoryCode::GH, category: "region", name: "Eastern" },
+    Subdivision { code: "GH-NE", territory: TerritoryCode::GH, category: "region", name: "North East" },
+    Subdivision { code: "GH-NP", territory: TerritoryCode::GH, category: "region", name: "Northern" },
+    Subdivision { code: "GH-OT", territory: TerritoryCode::GH, category: "region", name: "Oti" },
+    Subdivision { code: "GH-SV", territory: TerritoryCode::GH, category: "region", name: "Savannah" },
+    Subdivision { code: "GH-TV", territory: TerritoryCode::GH, category: "region", name: "Volta" },
+    Subdivision { code: "GH-UE", territory: TerritoryCode::GH, category: "region", name: "Upper East" },
+    Subdivision { code: "GH-UW", territory: TerritoryCode::GH, category: "region", name: "Upper West" },
+    Subdivision { code: "GH-WN", territory: TerritoryCode::GH, category: "region", name: "Western North" },
+    Subdivision { code: "GH-WP", territory: TerritoryCode::GH, category: "region", name: "Western" },
+    Subdivision { code: "GL-AV", territory: TerritoryCode::GL, category: "municipality", name: "Avannaata" },
+    Subdivision { code: "GL-KU", territory: TerritoryCode::GL, category: "municipality", name: "Kujalleq" },
+    Subdivision { code: "GL-QE", territory: TerritoryCode::GL, category: "municipality", name: "Qeqqata" },
+    Subdivision { code: "GL-QT", territory: TerritoryCode::GL, category: "municipality", name: "Qeqertalik" },
+    Subdivision { code: "GL-SM", territory: TerritoryCode::GL, category: "municipality", name: "Sermersooq" },
+    Subdivision { code: "GM-B", territory: TerritoryCode::GM, category: "city", name: "Banjul" },
+    Subdivision { code: "GM-L", territory: TerritoryCode::GM, category: "division", name: "Lower River" },
+    Subdivision { code: "GM-M", territory: TerritoryCode::GM, category: "division", name: "Central River" },
+    Subdivision { code: "GM-N", territory: TerritoryCode::GM, category: "division", name: "North Bank" },
+    Subdivision { code: "GM-U", territory: TerritoryCode::GM, category: "division", name: "Upper River" },
+    Subdivision { code: "GM-W", territory: TerritoryCode::GM, category: "division", name: "West Coast" },
+    Subdivision { code: "GN-B", territory: TerritoryCode::GN, category: "region", name: "Boké" },
+    Subdivision { code: "GN-C", territory: TerritoryCode::GN, category: "governorate", name: "Conakry" },
+    Subdivision { code: "GN-D", territory: TerritoryCode::GN, category: "region", name: "Kindia" },
+    Subdivision { code: "GN-F", territory: TerritoryCode::GN, category: "region", name: "Faranah" },
+    Subdivision { code: "GN-K", territory: TerritoryCode::GN, category: "region", name: "Kankan" },
+    Subdivision { code: "GN-L", territory: TerritoryCode::GN, category: "region", name: "Labé" },
+    Subdivision { code: "GN-M", territory: TerritoryCode::GN, category: "region", name: "Mamou" },
+    Subdivision { code: "GN-N", territory: TerritoryCode::GN, category: "region", name: "Nzérékoré" },
+    Subdivision { code: "GQ-AN", territory: TerritoryCode::GQ, category: "province", name: "Annobón" },
+    Subdivision { code: "GQ-BN", territory: TerritoryCode::GQ, category: "province", name: "Bioko Norte" },
+    Subdivision { code: "GQ-BS", territory: TerritoryCode::GQ, category: "province", name: "Bioko Sur" },
+    Subdivision { code: "GQ-C", territory: TerritoryCode::GQ, category: "region", name: "Continental Region" },
+    Subdivision { code: "GQ-CS", territory: TerritoryCode::GQ, category: "province", name: "Centro Sur" },
+    Subdivision { code: "GQ-DJ", territory: TerritoryCode::GQ, category: "province", name: "Djibloho" },
+    Subdivision { code: "GQ-I", territory: TerritoryCode::GQ, category: "region", name: "Insular Region" },
+    Subdivision { code: "GQ-KN", territory: TerritoryCode::GQ, category: "province", name: "Kié-Ntem" },
+    Subdivision { code: "GQ-LI", territory: TerritoryCode::GQ, category: "province", name: "Litoral" },
+    Subdivision { code: "GQ-WN", territory: TerritoryCode::GQ, category: "province", name: "Wele-Nzas" },
+    Subdivision { code: "GR-69", territory: TerritoryCode::GR, category: "self-governed part", name: "Mount Athos" },
+    Subdivision { code: "GR-A", territory: TerritoryCode::GR, category: "administrative region", name: "Eastern Macedonia and Thrace" },
+    Subdivision { code: "GR-B", territory: TerritoryCode::GR, category: "administrative region", name: "Central Macedonia" },
+    Subdivision { code: "GR-C", territory: TerritoryCode::GR, category: "administrative region", name: "Western Macedonia" },
+    Subdivision { code: "GR-D", territory: TerritoryCode::GR, category: "administrative region", name: "Epirus" },
+    Subdivision { code: "GR-E", territory: TerritoryCode::GR, category: "administrative region", name: "Thessaly" },
+    Subdivision { code: "GR-F", territory: TerritoryCode::GR, category: "administrative region", name: "Ionian Islands" },
+    Subdivision { code: "GR-G", territory: TerritoryCode::GR, category: "administrative region", name: "Western Greece" },
+    Subdivision { code: "GR-H", territory: TerritoryCode::GR, category: "administrative region", name: "Central Greece" },
+    Subdivision { code: "GR-I", territory: TerritoryCode::GR, category: "administrative region", name: "Attica" },
+    Subdivision { code: "GR-J", territory: TerritoryCode::GR, category: "administrative region", name: "Peloponnese" },
+    Subdivision { code: "GR-K", territory: TerritoryCode::GR, category: "administrative region", name: "North Aegean" },
+    Subdivision { code: "GR-L", territory: TerritoryCode::GR, category: "administrative region", name: "South Aegean" },
+    Subdivision { code: "GR-M", territory: TerritoryCode::GR, category: "administrative region", name: "Crete" },
+    Subdivision { code: "GT-01", territory: TerritoryCode::GT, category: "department", name: "Guatemala" },
+    Subdivision { code: "GT-02", territory: TerritoryCode::GT, category: "department", name: "El Progreso" },
+    Subdivision { code: "GT-03", territory: TerritoryCode::GT, category: "department", name: "Sacatepéquez" },
+    Subdivision { code: "GT-04", territory: TerritoryCode::GT, category: "department", name: "Chimaltenango" },
+    Subdivision { code: "GT-05", territory: TerritoryCode::GT, category: "department", name: "Escuintla" },
+    Subdivision { code: "GT-06", territory: TerritoryCode::GT, category: "department", name: "Santa Rosa" },
+    Subdivision { code: "GT-07", territory: TerritoryCode::GT, category: "department", name: "Sololá" },
+    Subdivision { code: "GT-08", territory: TerritoryCode::GT, category: "department", name: "Totonicapán" },
+    Subdivision { code: "GT-09", territory: TerritoryCode::GT, category: "department", name: "Quetzaltenango" },
+    Subdivision { code: "GT-10", territory: TerritoryCode::GT, category: "department", name: "Suchitepéquez" },
+    Subdivision { code: "GT-11", territory: TerritoryCode::GT, category: "department", name: "Retalhuleu" },
+    Subdivision { code: "GT-12", territory: TerritoryCode::GT, category: "department", name: "San Marcos" },
+    Subdivision { code: "GT-13", territory: TerritoryCode::GT, category: "department", name: "Huehuetenango" },
+    Subdivision { code: "GT-14", territory: TerritoryCode::GT, category: "department", name: "Quiché" },
+    Subdivision { code: "GT-15", territory: TerritoryCode::GT, category: "department", name: "Baja Verapaz" },
+    Subdivision { code: "GT-16", territory: TerritoryCode::GT, category: "department", name: "Alta Verapaz" },
+    Subdivision { code: "GT-17", territory: TerritoryCode::GT, category: "department", name: "Petén" },
+    Subdivision { code: "GT-18", territory: TerritoryCode::GT, category: "department", name: "Izabal" },
+    Subdivision { code: "GT-19", territory: TerritoryCode::GT, category: "department", name: "Zacapa" },
+    Subdivision { code: "GT-20", territory: TerritoryCode::GT, category: "department", name: "Chiquimula" },
+    Subdivision { code: "GT-21", territory: TerritoryCode::GT, category: "department", name: "Jalapa" },
+    Subdivision { code: "GT-22", territory: TerritoryCode::GT, category: "department", name: "Jutiapa" },
+    Subdivision { code: "GW-BA", territory: TerritoryCode::GW, category: "region", name: "Bafatá" },
+    Subdivision { code: "GW-BL", territory: TerritoryCode::GW, category: "region", name: "Bolama" },
+    Subdivision { code: "GW-BM", territory: TerritoryCode::GW, category: "region", name: "Biombo" },
+    Subdivision { code: "GW-BS", territory: TerritoryCode::GW, category: "autonomous sector", name: "Bissau" },
+    Subdivision { code: "GW-CA", territory: TerritoryCode::GW, category: "region", name: "Cacheu" },
+    Subdivision { code: "GW-GA", territory: TerritoryCode::GW, category: "region", name: "Gabú" },
+    Subdivision { code: "GW-OI", territory: TerritoryCode::GW, category: "region", name: "Oio" },
+    Subdivision { code: "GW-QU", territory: TerritoryCode::GW, category: "region", name: "Quinara" },
+    Subdivision { code: "GW-TO", territory: TerritoryCode::GW, category: "region", name: "Tombali" },
+    Subdivision { code: "GY-BA", territory: TerritoryCode::GY, category: "region", name: "Barima-Waini" },
+    Subdivision { code: "GY-CU", territory: TerritoryCode::GY, category: "region", name: "Cuyuni-Mazaruni" },
+    Subdivision { code: "GY-DE", territory: TerritoryCode::GY, category: "region", name: "Demerara-Mahaica" },
+    Subdivision { code: "GY-EB", territory: TerritoryCode::GY, category: "region", name: "East Berbice-Corentyne" },
+    Subdivision { code: "GY-ES", territory: TerritoryCode::GY, category: "region", name: "Essequibo Islands-West Demerara" },
+    Subdivision { code: "GY-MA", territory: TerritoryCode::GY, category: "region", name: "Mahaica-Berbice" },
+    Subdivision { code: "GY-PM", territory: TerritoryCode::GY, category: "region", name: "Pomeroon-Supenaam" },
+    Subdivision { code: "GY-PT", territory: TerritoryCode::GY, category: "region", name: "Potaro-Siparuni" },
+    Subdivision { code: "GY-UD", territory: TerritoryCode::GY, category: "region", name: "Upper Demerara-Berbice" },
+    Subdivision { code: "GY-UT", territory: TerritoryCode::GY, category: "region", name: "Upper Takutu-Upper Essequibo" },
+    Subdivision { code: "HN-AT", territory: TerritoryCode::HN, category: "department", name: "Atlántida" },
+    Subdivision { code: "HN-CH", territory: TerritoryCode::HN, category: "department", name: "Choluteca" },
+    Subdivision { code: "HN-CL", territory: TerritoryCode::HN, category: "department", name: "Colón" },
+    Subdivision { code: "HN-CM", territory: TerritoryCode::HN, category: "department", name: "Comayagua" },
+    Subdivision { code: "HN-CP", territory: TerritoryCode::HN, category: "department", name: "Copán" },
+    Subdivision { code: "HN-CR", territory: TerritoryCode::HN, category: "department", name: "Cortés" },
+    Subdivision { code: "HN-EP", territory: TerritoryCode::HN, category: "department", name: "El Paraíso" },
+    Subdivision { code: "HN-FM", territory: TerritoryCode::HN, category: "department", name: "Francisco Morazán" },
+    Subdivision { code: "HN-GD", territory: TerritoryCode::HN, category: "department", name: "Gracias a Dios" },
+    Subdivision { code: "HN-IB", territory: TerritoryCode::HN, category: "department", name: "Bay Islands" },
+    Subdivision { code: "HN-IN", territory: TerritoryCode::HN, category: "department", name: "Intibucá" },
+    Subdivision { code: "HN-LE", territory: TerritoryCode::HN, category: "department", name: "Lempira" },
+    Subdivision { code: "HN-LP", territory: TerritoryCode::HN, category: "department", name: "La Paz" },
+    Subdivision { code: "HN-OC", territory: TerritoryCode::HN, category: "department", name: "Ocotepeque" },
+    Subdivision { code: "HN-OL", territory: TerritoryCode::HN, category: "department", name: "Olancho" },
+    Subdivision { code: "HN-SB", territory: TerritoryCode::HN, category: "department", name: "Santa Bárbara" },
+    Subdivision { code: "HN-VA", territory: TerritoryCode::HN, category: "department", name: "Valle" },
+    Subdivision { code: "HN-YO", territory: TerritoryCode::HN, category: "department", name: "Yoro" },
+    Subdivision { code: "HR-01", territory: TerritoryCode::HR, category: "county", name: "Zagreb County" },
+    Subdivision { code: "HR-02", territory: TerritoryCode::HR, category: "county", name: "Krapina-Zagorje" },
+    Subdivision { code: "HR-03", territory: TerritoryCode::HR, category: "county", name: "Sisak-Moslavina" },
+    Subdivision { code: "HR-04", territory: TerritoryCode::HR, category: "county", name: "Karlovac" },
+    Subdivision { code: "HR-05", territory: TerritoryCode::HR, category: "county", name: "Varaždin" },
+    Subdivision { code: "HR-06", territory: TerritoryCode::HR, category: "county", name: "Koprivnica-Križevci" },
+    Subdivision { code: "HR-07", territory: TerritoryCode::HR, category: "county", name: "Bjelovar-Bilogora" },
+    Subdivision { code: "HR-08", territory: TerritoryCode::HR, category: "county", name: "Primorje-Gorski Kotar" },
+    Subdivision { code: "HR-09", territory: TerritoryCode::HR, category: "county", name: "Lika-Senj" },
+    Subdivision { code: "HR-10", territory: TerritoryCode::HR, category: "county", name: "Virovitica-Podravina" },
+    Subdivision { code: "HR-11", territory: TerritoryCode::HR, category: "county", name: "Požega-Slavonia" },
+    Subdivision { code: "HR-12", territory: TerritoryCode::HR, category: "county", name: "Brod-Posavina" },
+    Subdivision { code: "HR-13", territory: TerritoryCode::HR, category: "county", name: "Zadar" },
+    Subdivision { code: "HR-14", territory: TerritoryCode::HR, category: "county", name: "Osijek-Baranja" },
+    Subdivision { code: "HR-15", territory: TerritoryCode::HR, category: "county", name: "Šibenik-Knin" },
+    Subdivision { code: "HR-16", territory: TerritoryCode::HR, category: "county", name: "Vukovar-Syrmia" },
+    Subdivision { code: "HR-17", territory: TerritoryCode::HR, category: "county", name: "Split-Dalmatia" },
+    Subdivision { code: "HR-18", territory: TerritoryCode::HR, category: "county", name: "Istria" },
+    Subdivision { code: "HR-19", territory: TerritoryCode::HR, category: "county", name: "Dubrovnik-Neretva" },
+    Subdivision { code: "HR-20", territory: TerritoryCode::HR, category: "county", name: "Međimurje" },
+    Subdivision { code: "HR-21", territory: TerritoryCode::HR, category: "city", name: "Zagreb" },
+    Subdivision { code: "HT-AR", territory: TerritoryCode::HT, category: "department", name: "Artibonite" },
+    Subdivision { code: "HT-CE", territory: TerritoryCode::HT, category: "department", name: "Centre" },
+    Subdivision { code: "HT-GA", territory: TerritoryCode::HT, category: "department", name: "Grand'Anse" },
+    Subdivision { code: "HT-ND", territory: TerritoryCode::HT, category: "department", name: "Nord" },
+    Subdivision { code: "HT-NE", territory: TerritoryCode::HT, category: "department", name: "Nord-Est" },
+    Subdivision { code: "HT-NI", territory: TerritoryCode::HT, category: "department", name: "Nippes" },
+    Subdivision { code: "HT-NO", territory: TerritoryCode::HT, category: "department", name: "Nord-Ouest" },
+    Subdivision { code: "HT-OU", territory: TerritoryCode::HT, category: "department", name: "Ouest" },
+    Subdivision { code: "HT-SD", territory: TerritoryCode::HT, category: "department", name: "Sud" },
+    Subdivision { code: "HT-SE", territory: TerritoryCode::HT, category: "department", name: "Sud-Est" },
+    Subdivision { code: "HU-BA", territory: TerritoryCode::HU, category: "county", name: "Baranya" },
+    Subdivision { code: "HU-BE", territory: TerritoryCode::HU, category: "county", name: "Békés" },
+    Subdivision { code: "HU-BK", territory: TerritoryCode::HU, category: "county", name: "Bács-Kiskun" },
+    Subdivision { code: "HU-BU", territory: TerritoryCode::HU, category: "capital city", name: "Budapest" },
+    Subdivision { code: "HU-BZ", territory: TerritoryCode::HU, category: "county", name: "Borsod-Abaúj-Zemplén" },
+    Subdivision { code: "HU-CS", territory: TerritoryCode::HU, category: "county", name: "Csongrád-Csanád" },
+    Subdivision { code: "HU-FE", territory: TerritoryCode::HU, category: "county", name: "Fejér" },
+    Subdivision { code: "HU-GS", territory: TerritoryCode::HU, category: "county", name: "Győr-Moson-Sopron" },
+    Subdivision { code: "HU-HB", territory: TerritoryCode::HU, category: "county", name: "Hajdú-Bihar" },
+    Subdivision { code: "HU-HE", territory: TerritoryCode::HU, category: "county", name: "Heves" },
+    Subdivision { code: "HU-JN", territory: TerritoryCode::HU, category: "county", name: "Jász-Nagykun-Szolnok" },
+    Subdivision { code: "HU-KE", territory: TerritoryCode::HU, category: "county", name: "Komárom-Esztergom" },
+    Subdivision { code: "HU-NO", territory: TerritoryCode::HU, category: "county", name: "Nógrád" },
+    Subdivision { code: "HU-PE", territory: TerritoryCode::HU, category: "county", name: "Pest" },
+    Subdivision { code: "HU-SO", territory: TerritoryCode::HU, category: "county", name: "Somogy" },
+    Subdivision { code: "HU-SZ", territory: TerritoryCode::HU, category: "county", name: "Szabolcs-Szatmár-Bereg" },
+    Subdivision { code: "HU-TO", territory: TerritoryCode::HU, category: "county", name: "Tolna" },
+    Subdivision { code: "HU-VA", territory: TerritoryCode::HU, category: "county", name: "Vas" },
+    Subdivision { code: "HU-VE", territory: TerritoryCode::HU, category: "county", name: "Veszprém" },
+    Subdivision { code: "HU-ZA", territory: TerritoryCode::HU, category: "county", name: "Zala" },
+    Subdivision { code: "ID-AC", territory: TerritoryCode::ID, category: "province", name: "Aceh" },
+    Subdivision { code: "ID-BA", territory: TerritoryCode::ID, category: "province", name: "Bali" },
+    Subdivision { code: "ID-BB", territory: TerritoryCode::ID, category: "province", name: "Bangka Belitung Islands" },
+    Subdivision { code: "ID-BE", territory: TerritoryCode::ID, category: "province", name: "Bengkulu" },
+    Subdivision { code: "ID-BT", territory: TerritoryCode::ID, category: "province", name: "Banten" },
+    Subdivision { code: "ID-GO", territory: TerritoryCode::ID, category: "province", name: "Gorontalo" },
+    Subdivision { code: "ID-JA", territory: TerritoryCode::ID, category: "province", name: "Jambi" },
+    Subdivision { code: "ID-JB", territory: TerritoryCode::ID, category: "province", name: "West Java" },
+    Subdivision { code: "ID-JI", territory: TerritoryCode::ID, category: "province", name: "East Java" },
+    Subdivision { code: "ID-JK", territory: TerritoryCode::ID, category: "capital district", name: "Jakarta" },
+    Subdivision { code: "ID-JT", territory: TerritoryCode::ID, category: "province", name: "Central Java" },
+    Subdivision { code: "ID-KB", territory: TerritoryCode::ID, category: "province", name: "West Kalimantan" },
+    Subdivision { code: "ID-KI", territory: TerritoryCode::ID, category: "province", name: "East Kalimantan" },
+    Subdivision { code: "ID-KR", territory: TerritoryCode::ID, category: "province", name: "Riau Islands" },
+    Subdivision { code: "ID-KS", territory: TerritoryCode::ID, category: "province", name: "South Kalimantan" },
+    Subdivision { code: "ID-KT", territory: TerritoryCode::ID, category: "province", name: "Central Kalimantan" },
+    Subdivision { code: "ID-KU", territory: TerritoryCode::ID, category: "province", name: "North Kalimantan" },
+    Subdivision { code: "ID-LA", territory: TerritoryCode::ID, category: "province", name: "Lampung" },
+    Subdivision { code: "ID-MA", territory: TerritoryCode::ID, category: "province", name: "Maluku" },
+    Subdivision { code: "ID-MU", territory: TerritoryCode::ID, category: "province", name: "North Maluku" },
+    Subdivision { code: "ID-NB", territory: TerritoryCode::ID, category: "province", name: "West Nusa Tenggara" },
+    Subdivision { code: "ID-NT", territory: TerritoryCode::ID, category: "province", name: "East Nusa Tenggara" },
+    Subdivision { code: "ID-PA", territory: TerritoryCode::ID, category: "province", name: "Papua" },
+    Subdivision { code: "ID-PB", territory: TerritoryCode::ID, category: "province", name: "West Papua" },
+    Subdivision { code: "ID-PD", territory: TerritoryCode::ID, category: "province", name: "Southwest Papua" },
+    Subdivision { code: "ID-PE", territory: TerritoryCode::ID, category: "province", name: "Highland Papua" },
+    Subdivision { code: "ID-PS", territory: TerritoryCode::ID, category: "province", name: "South Papua" },
+    Subdivision { code: "ID-PT", territory: TerritoryCode::ID, category: "province", name: "Central Papua" },
+    Subdivision { code: "ID-RI", territory: TerritoryCode::ID, category: "province", name: "Riau" },
+    Subdivision { code: "ID-SA", territory: TerritoryCode::ID, category: "province", name: "North Sulawesi" },
+    Subdivision { code: "ID-SB", territory: TerritoryCode::ID, category: "province", name: "West Sumatra" },
+    Subdivision { code: "ID-SG", territory: TerritoryCode::ID, category: "province", name: "Southeast Sulawesi" },
+    Subdivision { code: "ID-SN", territory: TerritoryCode::ID, category: "province", name: "South Sulawesi" },
+    Subdivision { code: "ID-SR", territory: TerritoryCode::ID, category: "province", name: "West Sulawesi" },
+    Subdivision { code: "ID-SS", territory: TerritoryCode::ID, category: "province", name: "South Sumatra" },
+    Subdivision { code: "ID-ST", territory: TerritoryCode::ID, category: "province", name: "Central Sulawesi" },
+    Subdivision { code: "ID-SU", territory: TerritoryCode::ID, category: "province", name: "North Sumatra" },
+    Subdivision { code: "ID-YO", territory: TerritoryCode::ID, category: "special region", name: "Yogyakarta" },
+    Subdivision { code: "IE-C", territory: TerritoryCode::IE, category: "province", name: "Connacht" },
+    Subdivision { code: "IE-CE", territory: TerritoryCode::IE, category: "county", name: "Clare" },
+    Subdivision { code: "IE-CN", territory: TerritoryCode::IE, category: "county", name: "Cavan" },
+    Subdivision { code: "IE-CO", territory: TerritoryCode::IE, category: "county", name: "Cork" },
+    Subdivision { code: "IE-CW", territory: TerritoryCode::IE, category: "county", name: "Carlow" },
+    Subdivision { code: "IE-D", territory: TerritoryCode::IE, category: "county", name: "Dublin" },
+    Subdivision { code: "IE-DL", territory: TerritoryCode::IE, category: "county", name: "Donegal" },
+    Subdivision { code: "IE-G", territory: TerritoryCode::IE, category: "county", name: "Galway" },
+    Subdivision { code: "IE-KE", territory: TerritoryCode::IE, category: "county", name: "Kildare" },
+    Subdivision { code: "IE-KK", territory: TerritoryCode::IE, category: "county", name: "Kilkenny" },
+    Subdivision { code: "IE-KY", territory: TerritoryCode::IE, category: "county", name: "Kerry" },
+    Subdivision { code: "IE-L", territory: TerritoryCode::IE, category: "province", name: "Leinster" },
+    Subdivision { code: "IE-LD", territory: TerritoryCode::IE, category: "county", name: "Longford" },
+    Subdivision { code: "IE-LH", territory: TerritoryCode::IE, category: "county", name: "Louth" },
+    Subdivision { code: "IE-LK", territory: TerritoryCode::IE, category: "county", name: "Limerick" },
+    Subdivision { code: "IE-LM", territory: TerritoryCode::IE, category: "county", name: "Leitrim" },
+    Subdivision { code: "IE-LS", territory: TerritoryCode::IE, category: "county", name: "Laois" },
+    Subdivision { code: "IE-M", territory: TerritoryCode::IE, category: "province", name: "Munster" },
+    Subdivision { code: "IE-MH", territory: TerritoryCode::IE, category: "county", name: "Meath" },
+    Subdivision { code: "IE-MN", territory: TerritoryCode::IE, category: "county", name: "Monaghan" },
+    Subdivision { code: "IE-MO", territory: TerritoryCode::IE, category: "county", name: "Mayo" },
+    Subdivision { code: "IE-OY", territory: TerritoryCode::IE, category: "county", name: "Offaly" },
+    Subdivision { code: "IE-RN", territory: TerritoryCode::IE, category: "county", name: "Roscommon" },
+    Subdivision { code: "IE-SO", territory: TerritoryCode::IE, category: "county", name: "Sligo" },
+    Subdivision { code: "IE-TA", territory: TerritoryCode::IE, category: "county", name: "Tipperary" },
+    Subdivision { code: "IE-U", territory: TerritoryCode::IE, category: "province", name: "Ulster" },
+    Subdivision { code: "IE-WD", territory: TerritoryCode::IE, category: "county", name: "Waterford" },
+    Subdivision { code: "IE-WH", territory: TerritoryCode::IE, category: "county", name: "Westmeath" },
+    Subdivision { code: "IE-WW", territory: TerritoryCode::IE, category: "county", name: "Wicklow" },
+    Subdivision { code: "IE-WX", territory: TerritoryCode::IE, category: "county", name: "Wexford" },
+    Subdivision { code: "IL-D", territory: TerritoryCode::IL, category: "district", name: "Southern" },
+    Subdivision { code: "IL-HA", territory: TerritoryCode::IL, category: "district", name: "Haifa" },
+    Subdivision { code: "IL-JM", territory: TerritoryCode::IL, category: "district", name: "Jerusalem" },
+    Subdivision { code: "IL-M", territory: TerritoryCode::IL, category: "district", name: "Central" },
+    Subdivision { code: "IL-TA", territory: TerritoryCode::IL, category: "district", name: "Tel Aviv" },
+    Subdivision { code: "IL-Z", territory: TerritoryCode::IL, category: "district", name: "Northern" },
+    Subdivision { code: "IN-AN", territory: TerritoryCode::IN, category: "union territory", name: "Andaman and Nicobar Islands" },
+    Subdivision { code: "IN-AP", territory: TerritoryCode::IN, category: "state", name: "Andhra Pradesh" },
+    Subdivision { code: "IN-AR", territory: TerritoryCode::IN, category: "state", name: "Arunachal Pradesh" },
+    Subdivision { code: "IN-AS", territory: TerritoryCode::IN, category: "state", name: "Assam" },
+    Subdivision { code: "IN-BR", territory: TerritoryCode::IN, category: "state", name: "Bihar" },
+    Subdivision { code: "IN-CG", territory: TerritoryCode::IN, category: "state", name: "Chhattisgarh" },
+    Subdivision { code: "IN-CH", territory: TerritoryCode::IN, category: "union territory", name: "Chandigarh" },
+    Subdivision { code: "IN-DH", territory: TerritoryCode::IN, category: "union territory", name: "Dadra and Nagar Haveli and Daman and Diu" },
+    Subdivision { code: "IN-DL", territory: TerritoryCode::IN, category: "union territory", name: "Delhi" },
+    Subdivision { code: "IN-GA", territory: TerritoryCode::IN, category: "state", name: "Goa" },
+    Subdivision { code: "IN-GJ", territory: TerritoryCode::IN, category: "state", name: "Gujarat" },
+    Subdivision { code: "IN-HP", territory: TerritoryCode::IN, category: "state", name: "Himachal Pradesh" },
+    Subdivision { code: "IN-HR", territory: TerritoryCode::IN, category: "state", name: "Haryana" },
+    Subdivision { code: "IN-JH", territory: TerritoryCode::IN, category: "state", name: "Jharkhand" },
+    Subdivision { code: "IN-JK", territory: TerritoryCode::IN, category: "union territory", name: "Jammu and Kashmir" },
+    Subdivision { code: "IN-KA", territory: TerritoryCode::IN, category: "state", name: "Karnataka" },
+    Subdivision { code: "IN-KL", territory: TerritoryCode::IN, category: "state", name: "Kerala" },
+    Subdivision { code: "IN-LA", territory: TerritoryCode::IN, category: "union territory", name: "Ladakh" },
+    Subdivision { code: "IN-LD", territory: TerritoryCode::IN, category: "union territory", name: "Lakshadweep" },
+    Subdivision { code: "IN-MH", territory: TerritoryCode::IN, category: "state", name: "Maharashtra" },
+    Subdivision { code: "IN-ML", territory: TerritoryCode::IN, category: "state", name: "Meghalaya" },
+    Subdivision { code: "IN-MN", territory: TerritoryCode::IN, category: "state", name: "Manipur" },
+    Subdivision { code: "IN-MP", territory: TerritoryCode::IN, category: "state", name: "Madhya Pradesh" },
+    Subdivision { code: "IN-MZ", territory: TerritoryCode::IN, category: "state", name: "Mizoram" },
+    Subdivision { code: "IN-NL", territory: TerritoryCode::IN, category: "state", name: "Nagaland" },
+    Subdivision { code: "IN-OD", territory: TerritoryCode::IN, category: "state", name: "Odisha" },
+    Subdivision { code: "IN-PB", territory: TerritoryCode::IN, category: "state", name: "Punjab" },
+    Subdivision { code: "IN-PY", territory: TerritoryCode::IN, category: "union territory", name: "Puducherry" },
+    Subdivision { code: "IN-RJ", territory: TerritoryCode::IN, category: "state", name: "Rajasthan" },
+    Subdivision { code: "IN-SK", territory: TerritoryCode::IN, category: "state", name: "Sikkim" },
+    Subdivision { code: "IN-TN", territory: TerritoryCode::IN, category: "state", name: "Tamil Nadu" },
+    Subdivision { code: "IN-TR", territory: TerritoryCode::IN, category: "state", name: "Tripura" },
+    Subdivision { code: "IN-TS", territory: TerritoryCode::IN, category: "state", name: "Telangana" },
+    Subdivision { code: "IN-UK", territory: TerritoryCode::IN, category: "state", name: "Uttarakhand" },
+    Subdivision { code: "IN-UP", territory: TerritoryCode::IN, category: "state", name: "Uttar Pradesh" },
+    Subdivision { code: "IN-WB", territory: TerritoryCode::IN, category: "state", name: "West Bengal" },
+    Subdivision { code: "IQ-AN", territory: TerritoryCode::IQ, category: "governorate", name: "Anbar" },
+    Subdivision { code: "IQ-AR", territory: TerritoryCode::IQ, category: "governorate", name: "Erbil" },
+    Subdivision { code: "IQ-BA", territory: TerritoryCode::IQ, category: "governorate", name: "Basra" },
+    Subdivision { code: "IQ-BB", territory: TerritoryCode::IQ, category: "governorate", name: "Babylon" },
+    Subdivision { code: "IQ-BG", territory: TerritoryCode::IQ, category: "governorate", name: "Baghdad" },
+    Subdivision { code: "IQ-DA", territory: TerritoryCode::IQ, category: "governorate", name: "Duhok" },
+    Subdivision { code: "IQ-DI", territory: TerritoryCode::IQ, category: "governorate", name: "Diyala" },
+    Subdivision { code: "IQ-DQ", territory: TerritoryCode::IQ, category: "governorate", name: "Dhi Qar" },
+    Subdivision { code: "IQ-KA", territory: TerritoryCode::IQ, category: "governorate", name: "Karbala" },
+    Subdivision { code: "IQ-KI", territory: TerritoryCode::IQ, category: "governorate", name: "Kirkuk" },
+    Subdivision { code: "IQ-MA", territory: TerritoryCode::IQ, category: "governorate", name: "Maysan" },
+    Subdivision { code: "IQ-MU", territory: TerritoryCode::IQ, category: "governorate", name: "Muthanna" },
+    Subdivision { code: "IQ-NA", territory: TerritoryCode::IQ, category: "governorate", name: "Najaf" },
+    Subdivision { code: "IQ-NI", territory: TerritoryCode::IQ, category: "governorate", name: "Nineveh" },
+    Subdivision { code: "IQ-QA", territory: TerritoryCode::IQ, category: "governorate", name: "Al-Qadisiyah" },
+    Subdivision { code: "IQ-SD", territory: TerritoryCode::IQ, category: "governorate", name: "Saladin" },
+    Subdivision { code: "IQ-SU", territory: TerritoryCode::IQ, category: "governorate", name: "Sulaymaniyah" },
+    Subdivision { code: "IQ-WA", territory: TerritoryCode::IQ, category: "governorate", name: "Wasit" },
+    Subdivision { code: "IS-1", territory: TerritoryCode::IS, category: "region", name: "Capital Region" },
+    Subdivision { code: "IS-2", territory: TerritoryCode::IS, category: "region", name: "Southern Peninsula" },
+    Subdivision { code: "IS-3", territory: TerritoryCode::IS, category: "region", name: "Western Region" },
+    Subdivision { code: "IS-4", territory: TerritoryCode::IS, category: "region", name: "Westfjords" },
+    Subdivision { code: "IS-5", territory: TerritoryCode::IS, category: "region", name: "Northwestern Region" },
+    Subdivision { code: "IS-6", territory: TerritoryCode::IS, category: "region", name: "Northeastern Region" },
+    Subdivision { code: "IS-7", territory: TerritoryCode::IS, category: "region", name: "Eastern Region" },
+    Subdivision { code: "IS-8", territory: TerritoryCode::IS, category: "region", name: "Southern Region" },
+    Subdivision { code: "IT-21", territory: TerritoryCode::IT, category: "region", name: "Piedmont" },
+    Subdivision { code: "IT-23", territory: TerritoryCode::IT, category: "autonomous region", name: "Aosta Valley" },
+    Subdivision { code: "IT-25", territory: TerritoryCode::IT, category: "region", name: "Lombardy" },
+    Subdivision { code: "IT-32", territory: TerritoryCode::IT, category: "autonomous region", name: "Trentino-South Tyrol" },
+    Subdivision { code: "IT-34", territory: TerritoryCode::IT, category: "region", name: "Veneto" },
+    Subdivision { code: "IT-36", territory: TerritoryCode::IT, category: "autonomous region", name: "Friuli Venezia Giulia" },
+    Subdivision { code: "IT-42", territory: TerritoryCode::IT, category: "region", name: "Liguria" },
+    Subdivision { code: "IT-45", territory: TerritoryCode::IT, category: "region", name: "Emilia-Romagna" },
+    Subdivision { code: "IT-52", territory: TerritoryCode::IT, category: "region", name: "Tuscany" },
+    Subdivision { code: "IT-55", territory: TerritoryCode::IT, category: "region", name: "Umbria" },
+    Subdivision { code: "IT-57", territory: TerritoryCode::IT, category: "region", name: "Marche" },
+    Subdivision { code: "IT-62", territory: TerritoryCode::IT, category: "region", name: "Lazio" },
+    Subdivision { code: "IT-65", territory: TerritoryCode::IT, category: "region", name: "Abruzzo" },
+    Subdivision { code: "IT-67", territory: TerritoryCode::IT, category: "region", name: "Molise" },
+    Subdivision { code: "IT-72", territory: TerritoryCode::IT, category: "region", name: "Campania" },
+    Subdivision { code: "IT-75", territory: TerritoryCode::IT, category: "region", name: "Apulia" },
+    Subdivision { code: "IT-77", territory: TerritoryCode::IT, category: "region", name: "Basilicata" },
+    Subdivision { code: "IT-78", territory: TerritoryCode::IT, category: "region", name: "Calabria" },
+    Subdivision { code: "IT-82", territory: TerritoryCode::IT, category: "autonomous region", name: "Sicily" },
+    Subdivision { code: "IT-88", territory: TerritoryCode::IT, category: "autonomous region", name: "Sardinia" },
+    Subdivision { code: "IT-AG", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Agrigento" },
+    Subdivision { code: "IT-AL", territory: TerritoryCode::IT, category: "province", name: "Alessandria" },
+    Subdivision { code: "IT-AN", territory: TerritoryCode::IT, category: "province", name: "Ancona" },
+    Subdivision { code: "IT-AP", territory: TerritoryCode::IT, category: "province", name: "Ascoli Piceno" },
+    Subdivision { code: "IT-AQ", territory: TerritoryCode::IT, category: "province", name: "L'Aquila" },
+    Subdivision { code: "IT-AR", territory: TerritoryCode::IT, category: "province", name: "Arezzo" },
+    Subdivision { code: "IT-AT", territory: TerritoryCode::IT, category: "province", name: "Asti" },
+    Subdivision { code: "IT-AV", territory: TerritoryCode::IT, category: "province", name: "Avellino" },
+    Subdivision { code: "IT-BA", territory: TerritoryCode::IT, category: "metropolitan city", name: "Bari" },
+    Subdivision { code: "IT-BG", territory: TerritoryCode::IT, category: "province", name: "Bergamo" },
+    Subdivision { code: "IT-BI", territory: TerritoryCode::IT, category: "province", name: "Biella" },
+    Subdivision { code: "IT-BL", territory: TerritoryCode::IT, category: "province", name: "Belluno" },
+    Subdivision { code: "IT-BN", territory: TerritoryCode::IT, category: "province", name: "Benevento" },
+    Subdivision { code: "IT-BO", territory: TerritoryCode::IT, category: "metropolitan city", name: "Bologna" },
+    Subdivision { code: "IT-BR", territory: TerritoryCode::IT, category: "province", name: "Brindisi" },
+    Subdivision { code: "IT-BS", territory: TerritoryCode::IT, category: "province", name: "Brescia" },
+    Subdivision { code: "IT-BT", territory: TerritoryCode::IT, category: "province", name: "Barletta-Andria-Trani" },
+    Subdivision { code: "IT-BZ", territory: TerritoryCode::IT, category: "autonomous province", name: "South Tyrol" },
+    Subdivision { code: "IT-CA", territory: TerritoryCode::IT, category: "metropolitan city", name: "Cagliari" },
+    Subdivision { code: "IT-CB", territory: TerritoryCode::IT, category: "province", name: "Campobasso" },
+    Subdivision { code: "IT-CE", territory: TerritoryCode::IT, category: "province", name: "Caserta" },
+    Subdivision { code: "IT-CH", territory: TerritoryCode::IT, category: "province", name: "Chieti" },
+    Subdivision { code: "IT-CL", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Caltanissetta" },
+    Subdivision { code: "IT-CN", territory: TerritoryCode::IT, category: "province", name: "Cuneo" },
+    Subdivision { code: "IT-CO", territory: TerritoryCode::IT, category: "province", name: "Como" },
+    Subdivision { code: "IT-CR", territory: TerritoryCode::IT, category: "province", name: "Cremona" },
+    Subdivision { code: "IT-CS", territory: TerritoryCode::IT, category: "province", name: "Cosenza" },
+    Subdivision { code: "IT-CT", territory: TerritoryCode::IT, category: "metropolitan city", name: "Catania" },
+    Subdivision { code: "IT-CZ", territory: TerritoryCode::IT, category: "province", name: "Catanzaro" },
+    Subdivision { code: "IT-EN", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Enna" },
+    Subdivision { code: "IT-FC", territory: TerritoryCode::IT, category: "province", name: "Forlì-Cesena" },
+    Subdivision { code: "IT-FE", territory: TerritoryCode::IT, category: "province", name: "Ferrara" },
+    Subdivision { code: "IT-FG", territory: TerritoryCode::IT, category: "province", name: "Foggia" },
+    Subdivision { code: "IT-FI", territory: TerritoryCode::IT, category: "metropolitan city", name: "Florence" },
+    Subdivision { code: "IT-FM", territory: TerritoryCode::IT, category: "province", name: "Fermo" },
+    Subdivision { code: "IT-FR", territory: TerritoryCode::IT, category: "province", name: "Frosinone" },
+    Subdivision { code: "IT-GE", territory: TerritoryCode::IT, category: "metropolitan city", name: "Genoa" },
+    Subdivision { code: "IT-GO", territory: TerritoryCode::IT, category: "decentralized regional entity", name: "Gorizia" },
+    Subdivision { code: "IT-GR", territory: TerritoryCode::IT, category: "province", name: "Grosseto" },
+    Subdivision { code: "IT-IM", territory: TerritoryCode::IT, category: "province", name: "Imperia" },
+    Subdivision { code: "IT-IS", territory: TerritoryCode::IT, category: "province", name: "Isernia" },
+    Subdivision { code: "IT-KR", territory: TerritoryCode::IT, category: "province", name: "Crotone" },
+    Subdivision { code: "IT-LC", territory: TerritoryCode::IT, category: "province", name: "Lecco" },
+    Subdivision { code: "IT-LE", territory: TerritoryCode::IT, category: "province", name: "Lecce" },
+    Subdivision { code: "IT-LI", territory: TerritoryCode::IT, category: "province", name: "Livorno" },
+    Subdivision { code: "IT-LO", territory: TerritoryCode::IT, category: "province", name: "Lodi" },
+    Subdivision { code: "IT-LT", territory: TerritoryCode::IT, category: "province", name: "Latina" },
+    Subdivision { code: "IT-LU", territory: TerritoryCode::IT, category: "province", name: "Lucca" },
+    Subdivision { code: "IT-MB", territory: TerritoryCode::IT, category: "province", name: "Monza and Brianza" },
+    Subdivision { code: "IT-MC", territory: TerritoryCode::IT, category: "province", name: "Macerata" },
+    Subdivision { code: "IT-ME", territory: TerritoryCode::IT, category: "metropolitan city", name: "Messina" },
+    Subdivision { code: "IT-MI", territory: TerritoryCode::IT, category: "metropolitan city", name: "Milan" },
+    Subdivision { code: "IT-MN", territory: TerritoryCode::IT, category: "province", name: "Mantua" },
+    Subdivision { code: "IT-MO", territory: TerritoryCode::IT, category: "province", name: "Modena" },
+    Subdivision { code: "IT-MS", territory: TerritoryCode::IT, category: "province", name: "Massa-Carrara" },
+    Subdivision { code: "IT-MT", territory: TerritoryCode::IT, category: "province", name: "Matera" },
+    Subdivision { code: "IT-NA", territory: TerritoryCode::IT, category: "metropolitan city", name: "Naples" },
+    Subdivision { code: "IT-NO", territory: TerritoryCode::IT, category: "province", name: "Novara" },
+    Subdivision { code: "IT-NU", territory: TerritoryCode::IT, category: "province", name: "Nuoro" },
+    Subdivision { code: "IT-OR", territory: TerritoryCode::IT, category: "province", name: "Oristano" },
+    Subdivision { code: "IT-PA", territory: TerritoryCode::IT, category: "metropolitan city", name: "Palermo" },
+    Subdivision { code: "IT-PC", territory: TerritoryCode::IT, category: "province", name: "Piacenza" },
+    Subdivision { code: "IT-PD", territory: TerritoryCode::IT, category: "province", name: "Padua" },
+    Subdivision { code: "IT-PE", territory: TerritoryCode::IT, category: "province", name: "Pescara" },
+    Subdivision { code: "IT-PG", territory: TerritoryCode::IT, category: "province", name: "Perugia" },
+    Subdivision { code: "IT-PI", territory: TerritoryCode::IT, category: "province", name: "Pisa" },
+    Subdivision { code: "IT-PN", territory: TerritoryCode::IT, category: "decentralized regional entity", name: "Pordenone" },
+    Subdivision { code: "IT-PO", territory: TerritoryCode::IT, category: "province", name: "Prato" },
+    Subdivision { code: "IT-PR", territory: TerritoryCode::IT, category: "province", name: "Parma" },
+    Subdivision { code: "IT-PT", territory: TerritoryCode::IT, category: "province", name: "Pistoia" },
+    Subdivision { code: "IT-PU", territory: TerritoryCode::IT, category: "province", name: "Pesaro and Urbino" },
+    Subdivision { code: "IT-PV", territory: TerritoryCode::IT, category: "province", name: "Pavia" },
+    Subdivision { code: "IT-PZ", territory: TerritoryCode::IT, category: "province", name: "Potenza" },
+    Subdivision { code: "IT-RA", territory: TerritoryCode::IT, category: "province", name: "Ravenna" },
+    Subdivision { code: "IT-RC", territory: TerritoryCode::IT, category: "metropolitan city", name: "Reggio Calabria" },
+    Subdivision { code: "IT-RE", territory: TerritoryCode::IT, category: "province", name: "Reggio Emilia" },
+    Subdivision { code: "IT-RG", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Ragusa" },
+    Subdivision { code: "IT-RI", territory: TerritoryCode::IT, category: "province", name: "Rieti" },
+    Subdivision { code: "IT-RM", territory: TerritoryCode::IT, category: "metropolitan city", name: "Rome" },
+    Subdivision { code: "IT-RN", territory: TerritoryCode::IT, category: "province", name: "Rimini" },
+    Subdivision { code: "IT-RO", territory: TerritoryCode::IT, category: "province", name: "Rovigo" },
+    Subdivision { code: "IT-SA", territory: TerritoryCode::IT, category: "province", name: "Salerno" },
+    Subdivision { code: "IT-SI", territory: TerritoryCode::IT, category: "province", name: "Siena" },
+    Subdivision { code: "IT-SO", territory: TerritoryCode::IT, category: "province", name: "Sondrio" },
+    Subdivision { code: "IT-SP", territory: TerritoryCode::IT, category: "province", name: "La Spezia" },
+    Subdivision { code: "IT-SR", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Syracuse" },
+    Subdivision { code: "IT-SS", territory: TerritoryCode::IT, category: "province", name: "Sassari" },
+    Subdivision { code: "IT-SU", territory: TerritoryCode::IT, category: "province", name: "South Sardinia" },
+    Subdivision { code: "IT-SV", territory: TerritoryCode::IT, category: "province", name: "Savona" },
+    Subdivision { code: "IT-TA", territory: TerritoryCode::IT, category: "province", name: "Taranto" },
+    Subdivision { code: "IT-TE", territory: TerritoryCode::IT, category: "province", name: "Teramo" },
+    Subdivision { code: "IT-TN", territory: TerritoryCode::IT, category: "autonomous province", name: "Trentino" },
+    Subdivision { code: "IT-TO", territory: TerritoryCode::IT, category: "metropolitan city", name: "Turin" },
+    Subdivision { code: "IT-TP", territory: TerritoryCode::IT, category: "free municipal consortium", name: "Trapani" },
+    Subdivision { code: "IT-TR", territory: TerritoryCode::IT, category: "province", name: "Terni" },
+    Subdivision { code: "IT-TS", territory: TerritoryCode::IT, category: "decentralized regional entity", name: "Trieste" },
+    Subdivision { code: "IT-TV", territory: TerritoryCode::IT, category: "province", name: "Treviso" },
+    Subdivision { code: "IT-UD", territory: TerritoryCode::IT, category: "decentralized regional entity", name: "Udine" },
+    Subdivision { code: "IT-VA", territory: TerritoryCode::IT, category: "province", name: "Varese" },
+    Subdivision { code: "IT-VB", territory: TerritoryCode::IT, category: "province", name: "Verbano-Cusio-Ossola" },
+    Subdivision { code: "IT-VC", territory: TerritoryCode::IT, category: "province", name: "Vercelli" },
+    Subdivision { code: "IT-VE", territory: TerritoryCode::IT, category: "metropolitan city", name: "Venice" },
+    Subdivision { code: "IT-VI", territory: TerritoryCode::IT, category: "province", name: "Vicenza" },
+    Subdivision { code: "IT-VR", territory: TerritoryCode::IT, category: "province", name: "Verona" },
+    Subdivision { code: "IT-VT", territory: TerritoryCode::IT, category: "province", name: "Viterbo" },
+    Subdivision { code: "IT-VV", territory: TerritoryCode::IT, category: "province", name: "Vibo Valentia" },
+    Subdivision { code: "JM-01", territory: TerritoryCode::JM, category: "parish", name: "Kingston" },
+    Subdivision { code: "JM-02", territory: TerritoryCode::JM, category: "parish", name: "Saint Andrew" },
+    Subdivision { code: "JM-03", territory: TerritoryCode::JM, category: "parish", name: "Saint Thomas" },
+    Subdivision { code: "JM-04", territory: TerritoryCode::JM, category: "parish", name: "Portland" },
+    Subdivision { code: "JM-05", territory: TerritoryCode::JM, category: "parish", name: "Saint Mary" },
+    Subdivision { code: "JM-06", territory: TerritoryCode::JM, category: "parish", name: "Saint Ann" },
+    Subdivision { code: "JM-07", territory: TerritoryCode::JM, category: "parish", name: "Trelawny" },
+    Subdivision { code: "JM-08", territory: TerritoryCode::JM, category: "parish", name: "Saint James" },
+    Subdivision { code: "JM-09", territory: TerritoryCode::JM, category: "parish", name: "Hanover" },
+    Subdivision { code: "JM-10", territory: TerritoryCode::JM, category: "parish", name: "Westmoreland" },
+    Subdivision { code: "JM-11", territory: TerritoryCode::JM, category: "parish", name: "Saint Elizabeth" },
+    Subdivision { code: "JM-12", territory: TerritoryCode::JM, category: "parish", name: "Manchester" },
+    Subdivision { code: "JM-13", territory: TerritoryCode::JM, category: "parish", name: "Clarendon" },
+    Subdivision { code: "JM-14", territory: TerritoryCode::JM, category: "parish", name: "Saint Catherine" },
+    Subdivision { code: "JO-AJ", territory: TerritoryCode::JO, category: "governorate", name: "Ajloun" },
+    Subdivision { code: "JO-AM", territory: TerritoryCode::JO, category: "governorate", name: "Amman" },
+    Subdivision { code: "JO-AQ", territory: TerritoryCode::JO, category: "governorate", name: "Aqaba" },
+    Subdivision { code: "JO-AT", territory: TerritoryCode::JO, category: "governorate", name: "Tafilah" },
+    Subdivision { code: "JO-AZ", territory: TerritoryCode::JO, category: "governorate", name: "Zarqa" },
+    Subdivision { code: "JO-BA", territory: TerritoryCode::JO, category: "governorate", name: "Balqa" },
+    Subdivision { code: "JO-IR", territory: TerritoryCode::JO, category: "governorate", name: "Irbid" },
+    Subdivision { code: "JO-JA", territory: TerritoryCode::JO, category: "governorate", name: "Jerash" },
+    Subdivision { code: "JO-KA", territory: TerritoryCode::JO, category: "governorate", name: "Karak" },
+    Subdivision { code: "JO-MA", territory: TerritoryCode::JO, category: "governorate", name: "Mafraq" },
+    Subdivision { code: "JO-MD", territory: TerritoryCode::JO, category: "governorate", name: "Madaba" },
+    Subdivision { code: "JO-MN", territory: TerritoryCode::JO, category: "governorate", name: "Ma'an" },
+    Subdivision { code: "JP-01", territory: TerritoryCode::JP, category: "prefecture", name: "Hokkaido" },
+    Subdivision { code: "JP-02", territory: TerritoryCode::JP, category: "prefecture", name: "Aomori" },
+    Subdivision { code: "JP-03", territory: TerritoryCode::JP, category: "prefecture", name: "Iwate" },
+    Subdivision { code: "JP-04", territory: TerritoryCode::JP, category: "prefecture", name: "Miyagi" },
+    Subdivision { code: "JP-05", territory: TerritoryCode::JP, category: "prefecture", name: "Akita" },
+    Subdivision { code: "JP-06", territory: TerritoryCode::JP, category: "prefecture", name: "Yamagata" },
+    Subdivision { code: "JP-07", territory: TerritoryCode::JP, category: "prefecture", name: "Fukushima" },
+    Subdivision { code: "JP-08", territory: TerritoryCode::JP, category: "prefecture", name: "Ibaraki" },
+    Subdivision { code: "JP-09", territory: TerritoryCode::JP, category: "prefecture", name: "Tochigi" },
+    Subdivision { code: "JP-10", territory: TerritoryCode::JP, category: "prefecture", name: "Gunma" },
+    Subdivision { code: "JP-11", territory: TerritoryCode::JP, category: "prefecture", name: "Saitama" },
+    Subdivision { code: "JP-12", territory: TerritoryCode::JP, category: "prefecture", name: "Chiba" },
+    Subdivision { code: "JP-13", territory: TerritoryCode::JP, category: "prefecture", name: "Tokyo" },
+    Subdivision { code: "JP-14", territory: TerritoryCode::JP, category: "prefecture", name: "Kanagawa" },
+    Subdivision { code: "JP-15", territory: TerritoryCode::JP, category: "prefecture", name: "Niigata" },
+    Subdivision { code: "JP-16", territory: TerritoryCode::JP, category: "prefecture", name: "Toyama" },
+    Subdivision { code: "JP-17", territory: TerritoryCode::JP, category: "prefecture", name: "Ishikawa" },
+    Subdivision { code: "JP-18", territory: TerritoryCode::JP, category: "prefecture", name: "Fukui" },
+    Subdivision { code: "JP-19", territory: TerritoryCode::JP, category: "prefecture", name: "Yamanashi" },
+    Subdivision { code: "JP-20", territory: TerritoryCode::JP, category: "prefecture", name: "Nagano" },
+    Subdivision { code: "JP-21", territory: TerritoryCode::JP, category: "prefecture", name: "Gifu" },
+    Subdivision { code: "JP-22", territory: TerritoryCode::JP, category: "prefecture", name: "Shizuoka" },
+    Subdivision { code: "JP-23", territory: TerritoryCode::JP, category: "prefecture", name: "Aichi" },
+    Subdivision { code: "JP-24", territory: TerritoryCode::JP, category: "prefecture", name: "Mie" },
+    Subdivision { code: "JP-25", territory: TerritoryCode::JP, category: "prefecture", name: "Shiga" },
+    Subdivision { code: "JP-26", territory: TerritoryCode::JP, category: "prefecture", name: "Kyoto" },
+    Subdivision { code: "JP-27", territory: TerritoryCode::JP, category: "prefecture", name: "Osaka" },
+    Subdivision { code: "JP-28", territory: TerritoryCode::JP, category: "prefecture", name: "Hyogo" },
+    Subdivision { code: "JP-29", territory: TerritoryCode::JP, category: "prefecture", name: "Nara" },
+    Subdivision { code: "JP-30", territory: TerritoryCode::JP, category: "prefecture", name: "Wakayama" },
+    Subdivision { code: "JP-31", territory: TerritoryCode::JP, category: "prefecture", name: "Tottori" },
+    Subdivision { code: "JP-32", territory: TerritoryCode::JP, category: "prefecture", name: "Shimane" },
+    Subdivision { code: "JP-33", territory: TerritoryCode::JP, category: "prefecture", name: "Okayama" },
+    Subdivision { code: "JP-34", territory: TerritoryCode::JP, category: "prefecture", name: "Hiroshima" },
+    Subdivision { code: "JP-35", territory: TerritoryCode::JP, category: "prefecture", name: "Yamaguchi" },
+    Subdivision { code: "JP-36", territory: TerritoryCode::JP, category: "prefecture", name: "Tokushima" },
+    Subdivision { code: "JP-37", territory: TerritoryCode::JP, category: "prefecture", name: "Kagawa" },
+    Subdivision { code: "JP-38", territory: TerritoryCode::JP, category: "prefecture", name: "Ehime" },
+    Subdivision { code: "JP-39", territory: TerritoryCode::JP, category: "prefecture", name: "Kochi" },
+    Subdivision { code: "JP-40", territory: TerritoryCode::JP, category: "prefecture", name: "Fukuoka" },
+    Subdivision { code: "JP-41", territory: TerritoryCode::JP, category: "prefecture", name: "Saga" },
+    Subdivision { code: "JP-42", territory: TerritoryCode::JP, category: "prefecture", name: "Nagasaki" },
+    Subdivision { code: "JP-43", territory: TerritoryCode::JP, category: "prefecture", name: "Kumamoto" },
+    Subdivision { code: "JP-44", territory: TerritoryCode::JP, category: "prefecture", name: "Oita" },
+    Subdivision { code: "JP-45", territory: TerritoryCode::JP, category: "prefecture", name: "Miyazaki" },
+    Subdivision { code: "JP-46", territory: TerritoryCode::JP, category: "prefecture", name: "Kagoshima" },
+    Subdivision { code: "JP-47", territory: TerritoryCode::JP, category: "prefecture", name: "Okinawa" },
+    Subdivision { code: "KE-01", territory: TerritoryCode::KE, category: "county", name: "Baringo" },
+    Subdivision { code: "KE-02", territory: TerritoryCode::KE, category: "county", name: "Bomet" },
+    Subdivision { code: "KE-03", territory: TerritoryCode::KE, category: "county", name: "Bungoma" },
+    Subdivision { code: "KE-04", territory: TerritoryCode::KE, category: "county", name: "Busia" },
+    Subdivision { code: "KE-05", territory: TerritoryCode::KE, category: "county", name: "Elgeyo-Marakwet" },
+    Subdivision { code: "KE-06", territory: TerritoryCode::KE, category: "county", name: "Embu" },
+    Subdivision { code: "KE-07", territory: TerritoryCode::KE, category: "county", name: "Garissa" },
+    Subdivision { code: "KE-08", territory: TerritoryCode::KE, category: "county", name: "Homa Bay" },
+    Subdivision { code: "KE-09", territory: TerritoryCode::KE, category: "county", name: "Isiolo" },
+    Subdivision { code: "KE-10", territory: TerritoryCode::KE, category: "county", name: "Kajiado" },
+    Subdivision { code: "KE-11", territory: TerritoryCode::KE, category: "county", name: "Kakamega" },
+    Subdivision { code: "KE-12", territory: TerritoryCode::KE, category: "county", name: "Kericho" },
+    Subdivision { code: "KE-13", territory: TerritoryCode::KE, category: "county", name: "Kiambu" },
+    Subdivision { code: "KE-14", territory: TerritoryCode::KE, category: "county", name: "Kilifi" },
+    Subdivision { code: "KE-15", territory: TerritoryCode::KE, category: "county", name: "Kirinyaga" },
+    Subdivision { code: "KE-16", territory: TerritoryCode::KE, category: "county", name: "Kisii" },
+    Subdivision { code: "KE-17", territory: TerritoryCode::KE, category: "county", name: "Kisumu" },
+    Subdivision { code: "KE-18", territory: TerritoryCode::KE, category: "county", name: "Kitui" },
+    Subdivision { code: "KE-19", territory: TerritoryCode::KE, category: "county", name: "Kwale" },
+    Subdivision { code: "KE-20", territory: TerritoryCode::KE, category: "county", name: "Laikipia" },
+    Subdivision { code: "KE-21", territory: TerritoryCode::KE, category: "county", name: "Lamu" },
+    Subdivision { code: "KE-22", territory: TerritoryCode::KE, category: "county", name: "Machakos" },
+    Subdivision { code: "KE-23", territory: TerritoryCode::KE, category: "county", name: "Makueni" },
+    Subdivision { code: "KE-24", territory: TerritoryCode::KE, category: "county", name: "Mandera" },
+    Subdivision { code: "KE-25", territory: TerritoryCode::KE, category: "county", name: "Marsabit" },
+    Subdivision { code: "KE-26", territory: TerritoryCode::KE, category: "county", name: "Meru" },
+    Subdivision { code: "KE-27", territory: TerritoryCode::KE, category: "county", name: "Migori" },
+    Subdivision { code: "KE-28", territory: TerritoryCode::KE, category: "county", name: "Mombasa" },
+    Subdivision { code: "KE-29", territory: TerritoryCode::KE, category: "county", name: "Murang'a" },
+    Subdivision { code: "KE-30", territory: TerritoryCode::KE, category: "county", name: "Nairobi City" },
+    Subdivision { code: "KE-31", territory: TerritoryCode::KE, category: "county", name: "Nakuru" },
+    Subdivision { code: "KE-32", territory: TerritoryCode::KE, category: "county", name: "Nandi" },
+    Subdivision { code: "KE-33", territory: TerritoryCode::KE, category: "county", name: "Narok" },
+    Subdivision { code: "KE-34", territory: TerritoryCode::KE, category: "county", name: "Nyamira" },
+    Subdivision { code: "KE-35", territory: TerritoryCode::KE, category: "county", name: "Nyandarua" },
+    Subdivision { code: "KE-36", territory: TerritoryCode::KE, category: "county", name: "Nyeri" },
+    Subdivision { code: "KE-37", territory: TerritoryCode::KE, category: "county", name: "Samburu" },
+    Subdivision { code: "KE-38", territory: TerritoryCode::KE, category: "county", name: "Siaya" },
+    Subdivision { code: "KE-39", territory: TerritoryCode::KE, category: "county", name: "Taita-Taveta" },
+    Subdivision { code: "KE-40", territory: TerritoryCode::KE, category: "county", name: "Tana River" },
+    Subdivision { code: "KE-41", territory: TerritoryCode::KE, category: "county", name: "Tharaka-Nithi" },
+    Subdivision { code: "KE-42", territory: TerritoryCode::KE, category: "county", name: "Trans-Nzoia" },
+    Subdivision { code: "KE-43", territory: TerritoryCode::KE, category: "county", name: "Turkana" },
+    Subdivision { code: "KE-44", territory: TerritoryCode::KE, category: "county", name: "Uasin Gishu" },
+    Subdivision { code: "KE-45", territory: TerritoryCode::KE, category: "county", name: "Vihiga" },
+    Subdivision { code: "KE-46", territory: TerritoryCode::KE, category: "county", name: "Wajir" },
+    Subdivision { code: "KE-47", territory: TerritoryCode::KE, category: "county", name: "West Pokot" },
+    Subdivision { code: "KG-B", territory: TerritoryCode::KG, category: "region", name: "Batken" },
+    Subdivision { code: "KG-C", territory: TerritoryCode::KG, category: "region", name: "Chuy" },
+    Subdivision { code: "KG-GB", territory: TerritoryCode::KG, category: "city", name: "Bishkek" },
+    Subdivision { code: "KG-GO", territory: TerritoryCode::KG, category: "city", name: "Osh City" },
+    Subdivision { code: "KG-J", territory: TerritoryCode::KG, category: "region", name: "Jalal-Abad" },
+    Subdivision { code: "KG-N", territory: TerritoryCode::KG, category: "region", name: "Naryn" },
+    Subdivision { code: "KG-O", territory: TerritoryCode::KG, category: "region", name: "Osh" },
+    Subdivision { code: "KG-T", territory: TerritoryCode::KG, category: "region", name: "Talas" },
+    Subdivision { code: "KG-Y", territory: TerritoryCode::KG, category: "region", name: "Issyk-Kul" },
+    Subdivision { code: "KH-1", territory: TerritoryCode::KH, category: "province", name: "Banteay Meanchey" },
+    Subdivision { code: "KH-10", territory: TerritoryCode::KH, category: "province", name: "Kratie" },
+    Subdivision { code: "KH-11", territory: TerritoryCode::KH, category: "province", name: "Mondulkiri" },
+    Subdivision { code: "KH-12", territory: TerritoryCode::KH, category: "autonomous municipality", name: "Phnom Penh" },
+    Subdivision { code: "KH-13", territory: TerritoryCode::KH, category: "province", name: "Preah Vihear" },
+    Subdivision { code: "KH-14", territory: TerritoryCode::KH, category: "province", name: "Prey Veng" },
+    Subdivision { code: "KH-15", territory: TerritoryCode::KH, category: "province", name: "Pursat" },
+    Subdivision { code: "KH-16", territory: TerritoryCode::KH, category: "province", name: "Ratanakiri" },
+    Subdivision { code: "KH-17", territory: TerritoryCode::KH, category: "province", name: "Siem Reap" },
+    Subdivision { code: "KH-18", territory: TerritoryCode::KH, category: "province", name: "Preah Sihanouk" },
+    Subdivision { code: "KH-19", territory: TerritoryCode::KH, category: "province", name: "Stung Treng" },
+    Subdivision { code: "KH-2", territory: TerritoryCode::KH, category: "province", name: "Battambang" },
+    Subdivision { code: "KH-20", territory: TerritoryCode::KH, category: "province", name: "Svay Rieng" },
+    Subdivision { code: "KH-21", territory: TerritoryCode::KH, category: "province", name: "Takeo" },
+    Subdivision { code: "KH-22", territory: TerritoryCode::KH, category: "province", name: "Oddar Meanchey" },
+    Subdivision { code: "KH-23", territory: TerritoryCode::KH, category: "province", name: "Kep" },
+    Subdivision { code: "KH-24", territory: TerritoryCode::KH, category: "province", name: "Pailin" },
+    Subdivision { code: "KH-25", territory: TerritoryCode::KH, category: "province", name: "Tbong Khmum" },
+    Subdivision { code: "KH-3", territory: TerritoryCode::KH, category: "province", name: "Kampong Cham" },
+    Subdivision { code: "KH-4", territory: TerritoryCode::KH, category: "province", name: "Kampong Chhnang" },
+    Subdivision { code: "KH-5", territory: TerritoryCode::KH, category: "province", name: "Kampong Speu" },
+    Subdivision { code: "KH-6", territory: TerritoryCode::KH, category: "province", name: "Kampong Thom" },
+    Subdivision { code: "KH-7", territory: TerritoryCode::KH, category: "province", name: "Kampot" },
+    Subdivision { code: "KH-8", territory: TerritoryCode::KH, category: "province", name: "Kandal" },
+    Subdivision { code: "KH-9", territory: TerritoryCode::KH, category: "province", name: "Koh Kong" },
+    Subdivision { code: "KI-G", territory: TerritoryCode::KI, category: "island group", name: "Gilbert Islands" },
+    Subdivision { code: "KI-L", territory: TerritoryCode::KI, category: "island group", name: "Line Islands" },
+    Subdivision { code: "KI-P", territory: TerritoryCode::KI, category: "island group", name: "Phoenix Islands" },
+    Subdivision { code: "KM-A", territory: TerritoryCode::KM, category: "island", name: "Anjouan" },
+    Subdivision { code: "KM-G", territory: TerritoryCode::KM, category: "island", name: "Grande Comore" },
+    Subdivision { code: "KM-M", territory: TerritoryCode::KM, category: "island", name: "Mohéli" },
+    Subdivision { code: "KN-K", territory: TerritoryCode::KN, category: "state", name: "Saint Kitts" },
+    Subdivision { code: "KN-N", territory: TerritoryCode::KN, category: "state", name: "Nevis" },
+    Subdivision { code: "KP-01", territory: TerritoryCode::KP, category: "capital city", name: "Pyongyang" },
+    Subdivision { code: "KP-02", territory: TerritoryCode::KP, category: "province", name: "South Pyongan" },
+    Subdivision { code: "KP-03", territory: TerritoryCode::KP, category: "province", name: "North Pyongan" },
+    Subdivision { code: "KP-04", territory: TerritoryCode::KP, category: "province", name: "Chagang" },
+    Subdivision { code: "KP-05", territory: TerritoryCode::KP, category: "province", name: "South Hwanghae" },
+    Subdivision { code: "KP-06", territory: TerritoryCode::KP, category: "province", name: "North Hwanghae" },
+    Subdivision { code: "KP-07", territory: TerritoryCode::KP, category: "province", name: "Kangwon" },
+    Subdivision { code: "KP-08", territory: TerritoryCode::KP, category: "province", name: "South Hamgyong" },
+    Subdivision { code: "KP-09", territory: TerritoryCode::KP, category: "province", name: "North Hamgyong" },
+    Subdivision { code: "KP-10", territory: TerritoryCode::KP, category: "province", name: "Ryanggang" },
+    Subdivision { code: "KP-13", territory: TerritoryCode::KP, category: "special city", name: "Rason" },
+    Subdivision { code: "KP-14", territory: TerritoryCode::KP, category: "metropolitan city", name: "Nampo" },
+    Subdivision { code: "KR-11", territory: TerritoryCode::KR, category: "special city", name: "Seoul" },
+    Subdivision { code: "KR-26", territory: TerritoryCode::KR, category: "metropolitan city", name: "Busan" },
+    Subdivision { code: "KR-27", territory: TerritoryCode::KR, category: "metropolitan city", name: "Daegu" },
+    Subdivision { code: "KR-28", territory: TerritoryCode::KR, category: "metropolitan city", name: "Incheon" },
+    Subdivision { code: "KR-29", territory: TerritoryCode::KR, category: "metropolitan city", name: "Gwangju" },
+    Subdivision { code: "KR-30", territory: TerritoryCode::KR, category: "metropolitan city", name: "Daejeon" },
+    Subdivision { code: "KR-31", territory: TerritoryCode::KR, category: "metropolitan city", name: "Ulsan" },
+    Subdivision { code: "KR-41", territory: TerritoryCode::KR, category: "province", name: "Gyeonggi" },
+    Subdivision { code: "KR-42", territory: TerritoryCode::KR, category: "special self-governing province", name: "Gangwon" },
+    Subdivision { code: "KR-43", territory: TerritoryCode::KR, category: "province", name: "North Chungcheong" },
+    Subdivision { code: "KR-44", territory: TerritoryCode::KR, category: "province", name: "South Chungcheong" },
+    Subdivision { code: "KR-45", territory: TerritoryCode::KR, category: "special self-governing province", name: "North Jeolla" },
+    Subdivision { code: "KR-46", territory: TerritoryCode::KR, category: "province", name: "South Jeolla" },
+    Subdivision { code: "KR-47", territory: TerritoryCode::KR, category: "province", name: "North Gyeongsang" },
+    Subdivision { code: "KR-48", territory: TerritoryCode::KR, category: "province", name: "South Gyeongsang" },
+    Subdivision { code: "KR-49", territory: TerritoryCode::KR, category: "special self-governing province", name: "Jeju" },
+    Subdivision { code: "KR-50", territory: TerritoryCode::KR, category: "special self-governing city", name: "Sejong" },
+    Subdivision { code: "KW-AH", territory: TerritoryCode::KW, category: "governorate", name: "Ahmadi" },
+    Subdivision { code: "KW-FA", territory: TerritoryCode::KW, category: "governorate", name: "Farwaniya" },
+    Subdivision { code: "KW-HA", territory: TerritoryCode::KW, category: "governorate", name: "Hawalli" },
+    Subdivision { code: "KW-JA", territory: TerritoryCode::KW, category: "governorate", name: "Jahra" },
+    Subdivision { code: "KW-KU", territory: TerritoryCode::KW, category: "governorate", name: "Capital" },
+    Subdivision { code: "KW-MU", territory: TerritoryCode::KW, category: "governorate", name: "Mubarak Al-Kabeer" },
+    Subdivision { code: "LA-AT", territory: TerritoryCode::LA, category: "province", name: "Attapeu" },
+    Subdivision { code: "LA-BK", territory: TerritoryCode::LA, category: "province", name: "Bokeo" },
+    Subdivision { code: "LA-BL", territory: TerritoryCode::LA, category: "province", name: "Bolikhamsai" },
+    Subdivision { code: "LA-CH", territory: TerritoryCode::LA, category: "province", name: "Champasak" },
+    Subdivision { code: "LA-HO", territory: TerritoryCode::LA, category: "province", name: "Houaphanh" },
+    Subdivision { code: "LA-KH", territory: TerritoryCode::LA, category: "province", name: "Khammouane" },
+    Subdivision { code: "LA-LM", territory: TerritoryCode::LA, category: "province", name: "Luang Namtha" },
+    Subdivision { code: "LA-LP", territory: TerritoryCode::LA, category: "province", name: "Luang Prabang" },
+    Subdivision { code: "LA-OU", territory: TerritoryCode::LA, category: "province", name: "Oudomxay" },
+    Subdivision { code: "LA-PH", territory: TerritoryCode::LA, category: "province", name: "Phongsaly" },
+    Subdivision { code: "LA-SL", territory: TerritoryCode::LA, category: "province", name: "Salavan" },
+    Subdivision { code: "LA-SV", territory: TerritoryCode::LA, category: "province", name: "Savannakhet" },
+    Subdivision { code: "LA-VI", territory: TerritoryCode::LA, category: "province", name: "Vientiane Province" },
+    Subdivision { code: "LA-VT", territory: TerritoryCode::LA, category: "prefecture", name: "Vientiane Prefecture" },
+    Subdivision { code: "LA-XA", territory: TerritoryCode::LA, category: "province", name: "Sainyabuli" },
+    Subdivision { code: "LA-XE", territory: TerritoryCode::LA, category: "province", name: "Sekong" },
+    Subdivision { code: "LA-XI", territory: TerritoryCode::LA, category: "province", name: "Xiangkhouang" },
+    Subdivision { code: "LA-XS", territory: TerritoryCode::LA, category: "province", name: "Xaisomboun" },
+    Subdivision { code: "LB-AK", territory: TerritoryCode::LB, category: "governorate", name: "Akkar" },
+    Subdivision { code: "LB-AS", territory: TerritoryCode::LB, category: "governorate", name: "North" },
+    Subdivision { code: "LB-BA", territory: TerritoryCode::LB, category: "governorate", name: "Beirut" },
+    Subdivision { code: "LB-BH", territory: TerritoryCode::LB, category: "governorate", name: "Baalbek-Hermel" },
+    Subdivision { code: "LB-BI", territory: TerritoryCode::LB, category: "governorate", name: "Beqaa" },
+    Subdivision { code: "LB-JA", territory: TerritoryCode::LB, category: "governorate", name: "South" },
+    Subdivision { code: "LB-JL", territory: TerritoryCode::LB, category: "governorate", name: "Mount Lebanon" },
+    Subdivision { code: "LB-NA", territory: TerritoryCode::LB, category: "governorate", name: "Nabatieh" },
+    Subdivision { code: "LC-01", territory: TerritoryCode::LC, category: "district", name: "Anse la Raye" },
+    Subdivision { code: "LC-02", territory: TerritoryCode::LC, category: "district", name: "Castries" },
+    Subdivision { code: "LC-03", territory: TerritoryCode::LC, category: "district", name: "Choiseul" },
+    Subdivision { code: "LC-05", territory: TerritoryCode::LC, category: "district", name: "Dennery" },
+    Subdivision { code: "LC-06", territory: TerritoryCode::LC, category: "district", name: "Gros Islet" },
+    Subdivision { code: "LC-07", territory: TerritoryCode::LC, category: "district", name: "Laborie" },
+    Subdivision { code: "LC-08", territory: TerritoryCode::LC, category: "district", name: "Micoud" },
+    Subdivision { code: "LC-10", territory: TerritoryCode::LC, category: "district", name: "Soufrière" },
+    Subdivision { code: "LC-11", territory: TerritoryCode::LC, category: "district", name: "Vieux Fort" },
+    Subdivision { code: "LC-12", territory: TerritoryCode::LC, category: "district", name: "Canaries" },
+    Subdivision { code: "LI-01", territory: TerritoryCode::LI, category: "commune", name: "Balzers" },
+    Subdivision { code: "LI-02", territory: TerritoryCode::LI, category: "commune", name: "Eschen" },
+    Subdivision { code: "LI-03", territory: TerritoryCode::LI, category: "commune", name: "Gamprin" },
+    Subdivision { code: "LI-04", territory: TerritoryCode::LI, category: "commune", name: "Mauren" },
+    Subdivision { code: "LI-05", territory: TerritoryCode::LI, category: "commune", name: "Planken" },
+    Subdivision { code: "LI-06", territory: TerritoryCode::LI, category: "commune", name: "Ruggell" },
+    Subdivision { code: "LI-07", territory: TerritoryCode::LI, category: "commune", name: "Schaan" },
+    Subdivision { code: "LI-08", territory: TerritoryCode::LI, category: "commune", name: "Schellenberg" },
+    Subdivision { code: "LI-09", territory: TerritoryCode::LI, category: "commune", name: "Triesen" },
+    Subdivision { code: "LI-10", territory: TerritoryCode::LI, category: "commune", name: "Triesenberg" },
+    Subdivision { code: "LI-11", territory: TerritoryCode::LI, category: "commune", name: "Vaduz" },
+    Subdivision { code: "LK-1", territory: TerritoryCode::LK, category: "province", name: "Western" },
+    Subdivision { code: "LK-2", territory: TerritoryCode::LK, category: "province", name: "Central" },
+    Subdivision { code: "LK-3", territory: TerritoryCode::LK, category: "province", name: "Southern" },
+    Subdivision { code: "LK-4", territory: TerritoryCode::LK, category: "province", name: "Northern" },
+    Subdivision { code: "LK-5", territory: TerritoryCode::LK, category: "province", name: "Eastern" },
+    Subdivision { code: "LK-6", territory: TerritoryCode::LK, category: "province", name: "North Western" },
+    Subdivision { code: "LK-7", territory: TerritoryCode::LK, category: "province", name: "North Central" },
+    Subdivision { code: "LK-8", territory: TerritoryCode::LK, category: "province", name: "Uva" },
+    Subdivision { code: "LK-9", territory: TerritoryCode::LK, category: "province", name: "Sabaragamuwa" },
+    Subdivision { code: "LR-BG", territory: TerritoryCode::LR, category: "county", name: "Bong" },
+    Subdivision { code: "LR-BM", territory: TerritoryCode::LR, category: "county", name: "Bomi" },
+    Subdivision { code: "LR-CM", territory: TerritoryCode::LR, category: "county", name: "Grand Cape Mount" },
+    Subdivision { code: "LR-GB", territory: TerritoryCode::LR, category: "county", name: "Grand Bassa" },
+    Subdivision { code: "LR-GG", territory: TerritoryCode::LR, category: "county", name: "Grand Gedeh" },
+    Subdivision { code: "LR-GK", territory: TerritoryCode::LR, category: "county", name: "Grand Kru" },
+    Subdivision { code: "LR-GP", territory: TerritoryCode::LR, category: "county", name: "Gbarpolu" },
+    Subdivision { code: "LR-LO", territory: TerritoryCode::LR, category: "county", name: "Lofa" },
+    Subdivision { code: "LR-MG", territory: TerritoryCode::LR, category: "county", name: "Margibi" },
+    Subdivision { code: "LR-MO", territory: TerritoryCode::LR, category: "county", name: "Montserrado" },
+    Subdivision { code: "LR-MY", territory: TerritoryCode::LR, category: "county", name: "Maryland" },
+    Subdivision { code: "LR-NI", territory: TerritoryCode::LR, category: "county", name: "Nimba" },
+    Subdivision { code: "LR-RG", territory: TerritoryCode::LR, category: "county", name: "River Gee" },
+    Subdivision { code: "LR-RI", territory: TerritoryCode::LR, category: "county", name: "River Cess" },
+    Subdivision { code: "LR-SI", territory: TerritoryCode::LR, category: "county", name: "Sinoe" },
+    Subdivision { code: "LS-A", territory: TerritoryCode::LS, category: "district", name: "Maseru" },
+    Subdivision { code: "LS-B", territory: TerritoryCode::LS, category: "district", name: "Butha-Buthe" },
+    Subdivision { code: "LS-C", territory: TerritoryCode::LS, category: "district", name: "Leribe" },
+    Subdivision { code: "LS-D", territory: TerritoryCode::LS, category: "district", name: "Berea" },
+    Subdivision { code: "LS-E", territory: TerritoryCode::LS, category: "district", name: "Mafeteng" },
+    Subdivision { code: "LS-F", territory: TerritoryCode::LS, category: "district", name: "Mohale's Hoek" },
+    Subdivision { code: "LS-G", territory: TerritoryCode::LS, category: "district", name: "Quthing" },
+    Subdivision { code: "LS-H", territory: TerritoryCode::LS, category: "district", name: "Qacha's Nek" },
+    Subdivision { code: "LS-J", territory: TerritoryCode::LS, category: "district", name: "Mokhotlong" },
+    Subdivision { code: "LS-K", territory: TerritoryCode::LS, category: "district", name: "Thaba-Tseka" },
+    Subdivision { code: "LT-AL", territory: TerritoryCode::LT, category: "county", name: "Alytus" },
+    Subdivision { code: "LT-KL", territory: TerritoryCode::LT, category: "county", name: "Klaipėda" },
+    Subdivision { code: "LT-KU", territory: TerritoryCode::LT, category: "county", name: "Kaunas" },
+    Subdivision { code: "LT-MR", territory: TerritoryCode::LT, category: "county", name: "Marijampolė" },
+    Subdivision { code: "LT-PN", territory: TerritoryCode::LT, category: "county", name: "Panevėžys" },
+    Subdivision { code: "LT-SA", territory: TerritoryCode::LT, category: "county", name: "Šiauliai" },
+    Subdivision { code: "LT-TA", territory: TerritoryCode::LT, category: "county", name: "Tauragė" },
+    Subdivision { code: "LT-TE", territory: TerritoryCode::LT, category: "county", name: "Telšiai" },
+    Subdivision { code: "LT-UT", territory: TerritoryCode::LT, category: "county", name: "Utena" },
+    Subdivision { code: "LT-VL", territory: TerritoryCode::LT, category: "county", name: "Vilnius" },
+    Subdivision { code: "LU-CA", territory: TerritoryCode::LU, category: "canton", name: "Capellen" },
+    Subdivision { code: "LU-CL", territory: TerritoryCode::LU, category: "canton", name: "Clervaux" },
+    Subdivision { code: "LU-DI", territory: TerritoryCode::LU, category: "canton", name: "Diekirch" },
+    Subdivision { code: "LU-EC", territory: TerritoryCode::LU, category: "canton", name: "Echternach" },
+    Subdivision { code: "LU-ES", territory: TerritoryCode::LU, category: "canton", name: "Esch-sur-Alzette" },
+    Subdivision { code: "LU-GR", territory: TerritoryCode::LU, category: "canton", name: "Grevenmacher" },
+    Subdivision { code: "LU-LU", territory: TerritoryCode::LU, category: "canton", name: "Luxembourg" },
+    Subdivision { code: "LU-ME", territory: TerritoryCode::LU, category: "canton", name: "Mersch" },
+    Subdivision { code: "LU-RD", territory: TerritoryCode::LU, category: "canton", name: "Redange" },
+    Subdivision { code: "LU-RM", territory: TerritoryCode::LU, category: "canton", name: "Remich" },
+    Subdivision { code: "LU-VD", territory: TerritoryCode::LU, category: "canton", name: "Vianden" },
+    Subdivision { code: "LU-WI", territory: TerritoryCode::LU, category: "canton", name: "Wiltz" },
+    Subdivision { code: "LY-BA", territory: TerritoryCode::LY, category: "district", name: "Benghazi" },
+    Subdivision { code: "LY-BU", territory: TerritoryCode::LY, category: "district", name: "Butnan" },
+    Subdivision { code: "LY-DR", territory: TerritoryCode::LY, category: "district", name: "Derna" },
+    Subdivision { code: "LY-GT", territory: TerritoryCode::LY, category: "district", name: "Ghat" },
+    Subdivision { code: "LY-JA", territory: TerritoryCode::LY, category: "district", name: "Jabal al Akhdar" },
+    Subdivision { code: "LY-JG", territory: TerritoryCode::LY, category: "district", name: "Jabal al Gharbi" },
+    Subdivision { code: "LY-JI", territory: TerritoryCode::LY, category: "district", name: "Jafara" },
+    Subdivision { code: "LY-JU", territory: TerritoryCode::LY, category: "district", name: "Jufra" },
+    Subdivision { code: "LY-KF", territory: TerritoryCode::LY, category: "district", name: "Kufra" },
+    Subdivision { code: "LY-MB", territory: TerritoryCode::LY, category: "district", name: "Murqub" },
+    Subdivision { code: "LY-MI", territory: TerritoryCode::LY, category: "district", name: "Misrata" },
+    Subdivision { code: "LY-MJ", territory: TerritoryCode::LY, category: "district", name: "Marj" },
+    Subdivision { code: "LY-MQ", territory: TerritoryCode::LY, category: "district", name: "Murzuq" },
+    Subdivision { code: "LY-NL", territory: TerritoryCode::LY, category: "district", name: "Nalut" },
+    Subdivision { code: "LY-NQ", territory: TerritoryCode::LY, category: "district", name: "Nuqat al Khams" },
+    Subdivision { code: "LY-SB", territory: TerritoryCode::LY, category: "district", name: "Sabha" },
+    Subdivision { code: "LY-SR", territory: TerritoryCode::LY, category: "district", name: "Sirte" },
+    Subdivision { code: "LY-TB", territory: TerritoryCode::LY, category: "district", name: "Tripoli" },
+    Subdivision { code: "LY-WA", territory: TerritoryCode::LY, category: "district", name: "Al Wahat" },
+    Subdivision { code: "LY-WD", territory: TerritoryCode::LY, category: "district", name: "Wadi al Hayaa" },
+    Subdivision { code: "LY-WS", territory: TerritoryCode::LY, category: "district", name: "Wadi al Shatii" },
+    Subdivision { code: "LY-ZA", territory: TerritoryCode::LY, category: "district", name: "Zawiya" },
+    Subdivision { code: "MA-01", territory: TerritoryCode::MA, category: "region", name: "Tanger-Tétouan-Al Hoceïma" },
+    Subdivision { code: "MA-02", territory: TerritoryCode::MA, category: "region", name: "Oriental" },
+    Subdivision { code: "MA-03", territory: TerritoryCode::MA, category: "region", name: "Fès-Meknès" },
+    Subdivision { code: "MA-04", territory: TerritoryCode::MA, category: "region", name: "Rabat-Salé-Kénitra" },
+    Subdivision { code: "MA-05", territory: TerritoryCode::MA, category: "region", name: "Béni Mellal-Khénifra" },
+    Subdivision { code: "MA-06", territory: TerritoryCode::MA, category: "region", name: "Casablanca-Settat" },
+    Subdivision { code: "MA-07", territory: TerritoryCode::MA, category: "region", name: "Marrakesh-Safi" },
+    Subdivision { code: "MA-08", territory: TerritoryCode::MA, category: "region", name: "Drâa-Tafilalet" },
+    Subdivision { code: "MA-09", territory: TerritoryCode::MA, category: "region", name: "Souss-Massa" },
+    Subdivision { code: "MA-10", territory: TerritoryCode::MA, category: "region", name: "Guelmim-Oued Noun" },
+    Subdivision { code: "MA-11", territory: TerritoryCode::MA, category: "region", name: "Laâyoune-Sakia El Hamra" },
+    Subdivision { code: "MA-12", territory: TerritoryCode::MA, category: "region", name: "Dakhla-Oued Ed-Dahab" },
+    Subdivision { code: "MC-CL", territory: TerritoryCode::MC, category: "quarter", name: "La Colle" },
+    Subdivision { code: "MC-CO", territory: TerritoryCode::MC, category: "quarter", name: "La Condamine" },
+    Subdivision { code: "MC-FO", territory: TerritoryCode::MC, category: "quarter", name: "Fontvieille" },
+    Subdivision { code: "MC-GA", territory: TerritoryCode::MC, category: "quarter", name: "La Gare" },
+    Subdivision { code: "MC-JE", territory: TerritoryCode::MC, category: "quarter", name: "Jardin Exotique" },
+    Subdivision { code: "MC-LA", territory: TerritoryCode::MC, category: "quarter", name: "Larvotto" },
+    Subdivision { code: "MC-MA", territory: TerritoryCode::MC, category: "quarter", name: "Malbousquet" },
+    Subdivision { code: "MC-MC", territory: TerritoryCode::MC, category: "quarter", name: "Monte-Carlo" },
+    Subdivision { code: "MC-MG", territory: TerritoryCode::MC, category: "quarter", name: "Moneghetti" },
+    Subdivision { code: "MC-MO", territory: TerritoryCode::MC, category: "quarter", name: "Monaco-Ville" },
+    Subdivision { code: "MC-MU", territory: TerritoryCode::MC, category: "quarter", name: "Moulins" },
+    Subdivision { code: "MC-PH", territory: TerritoryCode::MC, category: "quarter", name: "Port-Hercule" },
+    Subdivision { code: "MC-SD", territory: TerritoryCode::MC, category: "quarter", name: "Sainte-Dévote" },
+    Subdivision { code: "MC-SO", territory: TerritoryCode::MC, category: "quarter", name: "La Source" },
+    Subdivision { code: "MC-SP", territory: TerritoryCode::MC, category: "quarter", name: "Spélugues" },
+    Subdivision { code: "MC-SR", territory: TerritoryCode::MC, category: "quarter", name: "Saint-Roman" },
+    Subdivision { code: "MC-VR", territory: TerritoryCode::MC, category: "quarter", name: "Vallon de la Rousse" },
+    Subdivision { code: "MG-A", territory: TerritoryCode::MG, category: "province", name: "Toamasina" },
+    Subdivision { code: "MG-D", territory: TerritoryCode::MG, category: "province", name: "Antsiranana" },
+    Subdivision { code: "MG-F", territory: TerritoryCode::MG, category: "province", name: "Fianarantsoa" },
+    Subdivision { code: "MG-M", territory: TerritoryCode::MG, category: "province", name: "Mahajanga" },
+    Subdivision { code: "MG-T", territory: TerritoryCode::MG, category: "province", name: "Antananarivo" },
+    Subdivision { code: "MG-U", territory: TerritoryCode::MG, category: "province", name: "Toliara" },
+    Subdivision { code: "MM-01", territory: TerritoryCode::MM, category: "region", name: "Sagaing" },
+    Subdivision { code: "MM-02", territory: TerritoryCode::MM, category: "region", name: "Bago" },
+    Subdivision { code: "MM-03", territory: TerritoryCode::MM, category: "region", name: "Magway" },
+    Subdivision { code: "MM-04", territory: TerritoryCode::MM, category: "region", name: "Mandalay" },
+    Subdivision { code: "MM-05", territory: TerritoryCode::MM, category: "region", name: "Tanintharyi" },
+    Subdivision { code: "MM-06", territory: TerritoryCode::MM, category: "region", name: "Yangon" },
+    Subdivision { code: "MM-07", territory: TerritoryCode::MM, category: "region", name: "Ayeyarwady" },
+    Subdivision { code: "MM-11", territory: TerritoryCode::MM, category: "state", name: "Kachin" },
+    Subdivision { code: "MM-12", territory: TerritoryCode::MM, category: "state", name: "Kayah" },
+    Subdivision { code: "MM-13", territory: TerritoryCode::MM, category: "state", name: "Kayin" },
+    Subdivision { code: "MM-14", territory: TerritoryCode::MM, category: "state", name: "Chin" },
+    Subdivision { code: "MM-15", territory: TerritoryCode::MM, category: "state", name: "Mon" },
+    Subdivision { code: "MM-16", territory: TerritoryCode::MM, category: "state", name: "Rakhine" },
+    Subdivision { code: "MM-17", territory: TerritoryCode::MM, category: "state", name: "Shan" },
+    Subdivision { code: "MM-18", territory: TerritoryCode::MM, category: "union territory", name: "Naypyidaw" },
+    Subdivision { code: "MN-035", territory: TerritoryCode::MN, category: "province", name: "Orkhon" },
+    Subdivision { code: "MN-037", territory: TerritoryCode::MN, category: "province", name: "Darkhan-Uul" },
+    Subdivision { code: "MN-039", territory: TerritoryCode::MN, category: "province", name: "Khentii" },
+    Subdivision { code: "MN-041", territory: TerritoryCode::MN, category: "province", name: "Khövsgöl" },
+    Subdivision { code: "MN-043", territory: TerritoryCode::MN, category: "province", name: "Khovd" },
+    Subdivision { code: "MN-046", territory: TerritoryCode::MN, category: "province", name: "Uvs" },
+    Subdivision { code: "MN-047", territory: TerritoryCode::MN, category: "province", name: "Töv" },
+    Subdivision { code: "MN-049", territory: TerritoryCode::MN, category: "province", name: "Selenge" },
+    Subdivision { code: "MN-051", territory: TerritoryCode::MN, category: "province", name: "Sükhbaatar" },
+    Subdivision { code: "MN-053", territory: TerritoryCode::MN, category: "province", name: "Ömnögovi" },
+    Subdivision { code: "MN-055", territory: TerritoryCode::MN, category: "province", name: "Övörkhangai" },
+    Subdivision { code: "MN-057", territory: TerritoryCode::MN, category: "province", name: "Zavkhan" },
+    Subdivision { code: "MN-059", territory: TerritoryCode::MN, category: "province", name: "Dundgovi" },
+    Subdivision { code: "MN-061", territory: TerritoryCode::MN, category: "province", name: "Dornod" },
+    Subdivision { code: "MN-063", territory: TerritoryCode::MN, category: "province", name: "Dornogovi" },
+    Subdivision { code: "MN-064", territory: TerritoryCode::MN, category: "province", name: "Govisümber" },
+    Subdivision { code: "MN-065", territory: TerritoryCode::MN, category: "province", name: "Govi-Altai" },
+    Subdivision { code: "MN-067", territory: TerritoryCode::MN, category: "province", name: "Bulgan" },
+    Subdivision { code: "MN-069", territory: TerritoryCode::MN, category: "province", name: "Bayankhongor" },
+    Subdivision { code: "MN-071", territory: TerritoryCode::MN, category: "province", name: "Bayan-Ölgii" },
+    Subdivision { code: "MN-073", territory: TerritoryCode::MN, category: "province", name: "Arkhangai" },
+    Subdivision { code: "MN-1", territory: TerritoryCode::MN, category: "capital city", name: "Ulaanbaatar" },
+    Subdivision { code: "MR-01", territory: TerritoryCode::MR, category: "region", name: "Hodh Ech Chargui" },
+    Subdivision { code: "MR-02", territory: TerritoryCode::MR, category: "region", name: "Hodh El Gharbi" },
+    Subdivision { code: "MR-03", territory: TerritoryCode::MR, category: "region", name: "Assaba" },
+    Subdivision { code: "MR-04", territory: TerritoryCode::MR, category: "region", name: "Gorgol" },
+    Subdivision { code: "MR-05", territory: TerritoryCode::MR, category: "region", name: "Brakna" },
+    Subdivision { code: "MR-06", territory: TerritoryCode::MR, category: "region", name: "Trarza" },
+    Subdivision { code: "MR-07", territory: TerritoryCode::MR, category: "region", name: "Adrar" },
+    Subdivision { code: "MR-08", territory: TerritoryCode::MR, category: "region", name: "Dakhlet Nouadhibou" },
+    Subdivision { code: "MR-09", territory: TerritoryCode::MR, category: "region", name: "Tagant" },
+    Subdivision { code: "MR-10", territory: TerritoryCode::MR, category: "region", name: "Guidimaka" },
+    Subdivision { code: "MR-11", territory: TerritoryCode::MR, category: "region", name: "Tiris Zemmour" },
+    Subdivision { code: "MR-12", territory: TerritoryCode::MR, category: "region", name: "Inchiri" },
+    Subdivision { code: "MR-13", territory: TerritoryCode::MR, category: "region", name: "Nouakchott-Ouest" },
+    Subdivision { code: "MR-14", territory: TerritoryCode::MR, category: "region", name: "Nouakchott-Nord" },
+    Subdivision { code: "MR-15", territory: TerritoryCode::MR, category: "region", name: "Nouakchott-Sud" },
+    Subdivision { code: "MU-AG", territory: TerritoryCode::MU, category: "dependency", name: "Agaléga" },
+    Subdivision { code: "MU-BL", territory: TerritoryCode::MU, category: "district", name: "Black River" },
+    Subdivision { code: "MU-CC", territory: TerritoryCode::MU, category: "dependency", name: "Cargados Carajos Shoals" },
+    Subdivision { code: "MU-FL", territory: TerritoryCode::MU, category: "district", name: "Flacq" },
+    Subdivision { code: "MU-GP", territory: TerritoryCode::MU, category: "district", name: "Grand Port" },
+    Subdivision { code: "MU-MO", territory: TerritoryCode::MU, category: "district", name: "Moka" },
+    Subdivision { code: "MU-PA", territory: TerritoryCode::MU, category: "district", name: "Pamplemousses" },
+    Subdivision { code: "MU-PL", territory: TerritoryCode::MU, category: "district", name: "Port Louis" },
+    Subdivision { code: "MU-PW", territory: TerritoryCode::MU, category: "district", name: "Plaines Wilhems" },
+    Subdivision { code: "MU-RO", territory: TerritoryCode::MU, category: "dependency", name: "Rodrigues" },
+    Subdivision { code: "MU-RR", territory: TerritoryCode::MU, category: "district", name: "Rivière du Rempart" },
+    Subdivision { code: "MU-SA", territory: TerritoryCode::MU, category: "district", name: "Savanne" },
+    Subdivision { code: "MW-C", territory: TerritoryCode::MW, category: "region", name: "Central Region" },
+    Subdivision { code: "MW-N", territory: TerritoryCode::MW, category: "region", name: "Northern Region" },
+    Subdivision { code: "MW-S", territory: TerritoryCode::MW, category: "region", name: "Southern Region" },
+    Subdivision { code: "MX-AGU", territory: TerritoryCode::MX, category: "state", name: "Aguascalientes" },
+    Subdivision { code: "MX-BCN", territory: TerritoryCode::MX, category: "state", name: "Baja California" },
+    Subdivision { code: "MX-BCS", territory: TerritoryCode::MX, category: "state", name: "Baja California Sur" },
+    Subdivision { code: "MX-CAM", territory: TerritoryCode::MX, category: "state", name: "Campeche" },
+    Subdivision { code: "MX-CHH", territory: TerritoryCode::MX, category: "state", name: "Chihuahua" },
+    Subdivision { code: "MX-CHP", territory: TerritoryCode::MX, category: "state", name: "Chiapas" },
+    Subdivision { code: "MX-CMX", territory: TerritoryCode::MX, category: "federal entity", name: "Mexico City" },
+    Subdivision { code: "MX-COA", territory: TerritoryCode::MX, category: "state", name: "Coahuila" },
+    Subdivision { code: "MX-COL", territory: TerritoryCode::MX, category: "state", name: "Colima" },
+    Subdivision { code: "MX-DUR", territory: TerritoryCode::MX, category: "state", name: "Durango" },
+    Subdivision { code: "MX-GRO", territory: TerritoryCode::MX, category: "state", name: "Guerrero" },
+    Subdivision { code: "MX-GUA", territory: TerritoryCode::MX, category: "state", name: "Guanajuato" },
+    Subdivision { code: "MX-HID", territory: TerritoryCode::MX, category: "state", name: "Hidalgo" },
+    Subdivision { code: "MX-JAL", territory: TerritoryCode::MX, category: "state", name: "Jalisco" },
+    Subdivision { code: "MX-MEX", territory: TerritoryCode::MX, category: "state", name: "State of Mexico" },
+    Subdivision { code: "MX-MIC", territory: TerritoryCode::MX, category: "state", name: "Michoacán" },
+    Subdivision { code: "MX-MOR", territory: TerritoryCode::MX, category: "state", name: "Morelos" },
+    Subdivision { code: "MX-NAY", territory: TerritoryCode::MX, category: "state", name: "Nayarit" },
+    Subdivision { code: "MX-NLE", territory: TerritoryCode::MX, category: "state", name: "Nuevo León" },
+    Subdivision { code: "MX-OAX", territory: TerritoryCode::MX, category: "state", name: "Oaxaca" },
+    Subdivision { code: "MX-PUE", territory: TerritoryCode::MX, category: "state", name: "Puebla" },
+    Subdivision { code: "MX-QUE", territory: TerritoryCode::MX, category: "state", name: "Querétaro" },
+    Subdivision { code: "MX-ROO", territory: TerritoryCode::MX, category: "state", name: "Quintana Roo" },
+    Subdivision { code: "MX-SIN", territory: TerritoryCode::MX, category: "state", name: "Sinaloa" },
+    Subdivision { code: "MX-SLP", territory: TerritoryCode::MX, category: "state", name: "San Luis Potosí" },
+    Subdivision { code: "MX-SON", territory: TerritoryCode::MX, category: "state", name: "Sonora" },
+    Subdivision { code: "MX-TAB", territory: TerritoryCode::MX, category: "state", name: "Tabasco" },
+    Subdivision { code: "MX-TAM", territory: TerritoryCode::MX, category: "state", name: "Tamaulipas" },
+    Subdivision { code: "MX-TLA", territory: TerritoryCode::MX, category: "state", name: "Tlaxcala" },
+    Subdivision { code: "MX-VER", territory: TerritoryCode::MX, category: "state", name: "Veracruz" },
+    Subdivision { code: "MX-YUC", territory: TerritoryCode::MX, category: "state", name: "Yucatán" },
+    Subdivision { code: "MX-ZAC", territory: TerritoryCode::MX, category: "state", name: "Zacatecas" },
+    Subdivision { code: "MY-01", territory: TerritoryCode::MY, category: "state", name: "Johor" },
+    Subdivision { code: "MY-02", territory: TerritoryCode::MY, category: "state", name: "Kedah" },
+    Subdivision { code: "MY-03", territory: TerritoryCode::MY, category: "state", name: "Kelantan" },
+    Subdivision { code: "MY-04", territory: TerritoryCode::MY, category: "state", name: "Malacca" },
+    Subdivision { code: "MY-05", territory: TerritoryCode::MY, category: "state", name: "Negeri Sembilan" },
+    Subdivision { code: "MY-06", territory: TerritoryCode::MY, category: "state", name: "Pahang" },
+    Subdivision { code: "MY-07", territory: TerritoryCode::MY, category: "state", name: "Penang" },
+    Subdivision { code: "MY-08", territory: TerritoryCode::MY, category: "state", name: "Perak" },
+    Subdivision { code: "MY-09", territory: TerritoryCode::MY, category: "state", name: "Perlis" },
+    Subdivision { code: "MY-10", territory: TerritoryCode::MY, category: "state", name: "Selangor" },
+    Subdivision { code: "MY-11", territory: TerritoryCode::MY, category: "state", name: "Terengganu" },
+    Subdivision { code: "MY-12", territory: TerritoryCode::MY, category: "state", name: "Sabah" },
+    Subdivision { code: "MY-13", territory: TerritoryCode::MY, category: "state", name: "Sarawak" },
+    Subdivision { code: "MY-14", territory: TerritoryCode::MY, category: "federal territory", name: "Kuala Lumpur" },
+    Subdivision { code: "MY-15", territory: TerritoryCode::MY, category: "federal territory", name: "Labuan" },
+    Subdivision { code: "MY-16", territory: TerritoryCode::MY, category: "federal territory", name: "Putrajaya" },
+    Subdivision { code: "MZ-A", territory: TerritoryCode::MZ, category: "province", name: "Niassa" },
+    Subdivision { code: "MZ-B", territory: TerritoryCode::MZ, category: "province", name: "Manica" },
+    Subdivision { code: "MZ-G", territory: TerritoryCode::MZ, category: "province", name: "Gaza" },
+    Subdivision { code: "MZ-I", territory: TerritoryCode::MZ, category: "province", name: "Inhambane" },
+    Subdivision { code: "MZ-L", territory: TerritoryCode::MZ, category: "province", name: "Maputo" },
+    Subdivision { code: "MZ-MPM", territory: TerritoryCode::MZ, category: "city", name: "Maputo City" },
+    Subdivision { code: "MZ-N", territory: TerritoryCode::MZ, category: "province", name: "Nampula" },
+    Subdivision { code: "MZ-P", territory: TerritoryCode::MZ, category: "province", name: "Cabo Delgado" },
+    Subdivision { code: "MZ-Q", territory: TerritoryCode::MZ, category: "province", name: "Zambezia" },
+    Subdivision { code: "MZ-S", territory: TerritoryCode::MZ, category: "province", name: "Sofala" },
+    Subdivision { code: "MZ-T", territory: TerritoryCode::MZ, category: "province", name: "Tete" },
+    Subdivision { code: "NA-CA", territory: TerritoryCode::NA, category: "region", name: "Zambezi" },
+    Subdivision { code: "NA-ER", territory: TerritoryCode::NA, category: "region", name: "Erongo" },
+    Subdivision { code: "NA-HA", territory: TerritoryCode::NA, category: "region", name: "Hardap" },
+    Subdivision { code: "NA-KA", territory: TerritoryCode::NA, category: "region", name: "Karas" },
+    Subdivision { code: "NA-KE", territory: TerritoryCode::NA, category: "region", name: "Kavango East" },
+    Subdivision { code: "NA-KH", territory: TerritoryCode::NA, category: "region", name: "Khomas" },
+    Subdivision { code: "NA-KU", territory: TerritoryCode::NA, category: "region", name: "Kunene" },
+    Subdivision { code: "NA-KW", territory: TerritoryCode::NA, category: "region", name: "Kavango West" },
+    Subdivision { code: "NA-OD", territory: TerritoryCode::NA, category: "region", name: "Otjozondjupa" },
+    Subdivision { code: "NA-OH", territory: TerritoryCode::NA, category: "region", name: "Omaheke" },
+    Subdivision { code: "NA-ON", territory: TerritoryCode::NA, category: "region", name: "Oshana" },
+    Subdivision { code: "NA-OS", territory: TerritoryCode::NA, category: "region", name: "Omusati" },
+    Subdivision { code: "NA-OT", territory: TerritoryCode::NA, category: "region", name: "Oshikoto" },
+    Subdivision { code: "NA-OW", territory: TerritoryCode::NA, category: "region", name: "Ohangwena" },
+    Subdivision { code: "NE-1", territory: TerritoryCode::NE, category: "region", name: "Agadez" },
+    Subdivision { code: "NE-2", territory: TerritoryCode::NE, category: "region", name: "Diffa" },
+    Subdivision { code: "NE-3", territory: TerritoryCode::NE, category: "region", name: "Dosso" },
+    Subdivision { code: "NE-4", territory: TerritoryCode::NE, category: "region", name: "Maradi" },
+    Subdivision { code: "NE-5", territory: TerritoryCode::NE, category: "region", name: "Tahoua" },
+    Subdivision { code: "NE-6", territory: TerritoryCode::NE, category: "region", name: "Tillabéri" },
+    Subdivision { code: "NE-7", territory: TerritoryCode::NE, category: "region", name: "Zinder" },
+    Subdivision { code: "NE-8", territory: TerritoryCode::NE, category: "urban community", name: "Niamey" },
+    Subdivision { code: "NG-AB", territory: TerritoryCode::NG, category: "state", name: "Abia" },
+    Subdivision { code: "NG-AD", territory: TerritoryCode::NG, category: "state", name: "Adamawa" },
+    Subdivision { code: "NG-AK", territory: TerritoryCode::NG, category: "state", name: "Akwa Ibom" },
+    Subdivision { code: "NG-AN", territory: TerritoryCode::NG, category: "state", name: "Anambra" },
+    Subdivision { code: "NG-BA", territory: TerritoryCode::NG, category: "state", name: "Bauchi" },
+    Subdivision { code: "NG-BE", territory: TerritoryCode::NG, category: "state", name: "Benue" },
+    Subdivision { code: "NG-BO", territory: TerritoryCode::NG, category: "state", name: "Borno" },
+    Subdivision { code: "NG-BY", territory: TerritoryCode::NG, category: "state", name: "Bayelsa" },
+    Subdivision { code: "NG-CR", territory: TerritoryCode::NG, category: "state", name: "Cross River" },
+    Subdivision { code: "NG-DE", territory: TerritoryCode::NG, category: "state", name: "Delta" },
+    Subdivision { code: "NG-EB", territory: TerritoryCode::NG, category: "state", name: "Ebonyi" },
+    Subdivision { code: "NG-ED", territory: TerritoryCode::NG, category: "state", name: "Edo" },
+    Subdivision { code: "NG-EK", territory: TerritoryCode::NG, category: "state", name: "Ekiti" },
+    Subdivision { code: "NG-EN", territory: TerritoryCode::NG, category: "state", name: "Enugu" },
+    Subdivision { code: "NG-FC", territory: TerritoryCode::NG, category: "capital territory", name: "Federal Capital Territory" },
+    Subdivision { code: "NG-GO", territory: TerritoryCode::NG, category: "state", name: "Gombe" },
+    Subdivision { code: "NG-IM", territory: TerritoryCode::NG, category: "state", name: "Imo" },
+    Subdivision { code: "NG-JI", territory: TerritoryCode::NG, category: "state", name: "Jigawa" },
+    Subdivision { code: "NG-KD", territory: TerritoryCode::NG, category: "state", name: "Kaduna" },
+    Subdivision { code: "NG-KE", territory: TerritoryCode::NG, category: "state", name: "Kebbi" },
+    Subdivision { code: "NG-KN", territory: TerritoryCode::NG, category: "state", name: "Kano" },
+    Subdivision { code: "NG-KO", territory: TerritoryCode::NG, category: "state", name: "Kogi" },
+    Subdivision { code: "NG-KT", territory: TerritoryCode::NG, category: "state", name: "Katsina" },
+    Subdivision { code: "NG-KW", territory: TerritoryCode::NG, category: "state", name: "Kwara" },
+    Subdivision { code: "NG-LA", territory: TerritoryCode::NG, category: "state", name: "Lagos" },
+    Subdivision { code: "NG-NA", territory: TerritoryCode::NG, category: "state", name: "Nasarawa" },
+    Subdivision { code: "NG-NI", territory: TerritoryCode::NG, category: "state", name: "Niger" },
+    Subdivision { code: "NG-OG", territory: TerritoryCode::NG, category: "state", name: "Ogun" },
+    Subdivision { code: "NG-ON", territory: TerritoryCode::NG, category: "state", name: "Ondo" },
+    Subdivision { code: "NG-OS", territory: TerritoryCode::NG, category: "state", name: "Osun" },
+    Subdivision { code: "NG-OY", territory: TerritoryCode::NG, category: "state", name: "Oyo" },
+    Subdivision { code: "NG-PL", territory: TerritoryCode::NG, category: "state", name: "Plateau" },
+    Subdivision { code: "NG-RI", territory: TerritoryCode::NG, category: "state", name: "Rivers" },
+    Subdivision { code: "NG-SO", territory: TerritoryCode::NG, category: "state", name: "Sokoto" },
+    Subdivision { code: "NG-TA", territory: TerritoryCode::NG, category: "state", name: "Taraba" },
+    Subdivision { code: "NG-YO", territory: TerritoryCode::NG, category: "state", name: "Yobe" },
+    Subdivision { code: "NG-ZA", territory: TerritoryCode::NG, category: "state", name: "Zamfara" },
+    Subdivision { code: "NI-AN", territory: TerritoryCode::NI, category: "autonomous region", name: "North Caribbean Coast" },
+    Subdivision { code: "NI-AS", territory: TerritoryCode::NI, category: "autonomous region", name: "South Caribbean Coast" },
+    Subdivision { code: "NI-BO", territory: TerritoryCode::NI, category: "department", name: "Boaco" },
+    Subdivision { code: "NI-CA", territory: TerritoryCode::NI, category: "department", name: "Carazo" },
+    Subdivision { code: "NI-CI", territory: TerritoryCode::NI, category: "department", name: "Chinandega" },
+    Subdivision { code: "NI-CO", territory: TerritoryCode::NI, category: "department", name: "Chontales" },
+    Subdivision { code: "NI-ES", territory: TerritoryCode::NI, category: "department", name: "Estelí" },
+    Subdivision { code: "NI-GR", territory: TerritoryCode::NI, category: "department", name: "Granada" },
+    Subdivision { code: "NI-JI", territory: TerritoryCode::NI, category: "department", name: "Jinotega" },
+    Subdivision { code: "NI-LE", territory: TerritoryCode::NI, category: "department", name: "León" },
+    Subdivision { code: "NI-MD", territory: TerritoryCode::NI, category: "department", name: "Madriz" },
+    Subdivision { code: "NI-MN", territory: TerritoryCode::NI, category: "department", name: "Managua" },
+    Subdivision { code: "NI-MS", territory: TerritoryCode::NI, category: "department", name: "Masaya" },
+    Subdivision { code: "NI-MT", territory: TerritoryCode::NI, category: "department", name: "Matagalpa" },
+    Subdivision { code: "NI-NS", territory: TerritoryCode::NI, category: "department", name: "Nueva Segovia" },
+    Subdivision { code: "NI-RI", territory: TerritoryCode::NI, category: "department", name: "Rivas" },
+    Subdivision { code: "NI-SJ", territory: TerritoryCode::NI, category: "department", name: "Río San Juan" },
+    Subdivision { code: "NL-DR", territory: TerritoryCode::NL, category: "province", name: "Drenthe" },
+    Subdivision { code: "NL-FL", territory: TerritoryCode::NL, category: "province", name: "Flevoland" },
+    Subdivision { code: "NL-FR", territory: TerritoryCode::NL, category: "province", name: "Friesland" },
+    Subdivision { code: "NL-GE", territory: TerritoryCode::NL, category: "province", name: "Gelderland" },
+    Subdivision { code: "NL-GR", territory: TerritoryCode::NL, category: "province", name: "Groningen" },
+    Subdivision { code: "NL-LI", territory: TerritoryCode::NL, category: "province", name: "Limburg" },
+    Subdivision { code: "NL-NB", territory: TerritoryCode::NL, category: "province", name: "North Brabant" },
+    Subdivision { code: "NL-NH", territory: TerritoryCode::NL, category: "province", name: "North Holland" },
+    Subdivision { code: "NL-OV", territory: TerritoryCode::NL, category: "province", name: "Overijssel" },
+    Subdivision { code: "NL-UT", territory: TerritoryCode::NL, category: "province", name: "Utrecht" },
+    Subdivision { code: "NL-ZE", territory: TerritoryCode::NL, category: "province", name: "Zeeland" },
+    Subdivision { code: "NL-ZH", territory: TerritoryCode::NL, category: "province", name: "South Holland" },
+    Subdivision { code: "NO-03", territory: TerritoryCode::NO, category: "county", name: "Oslo" },
+    Subdivision { code: "NO-11", territory: TerritoryCode::NO, category: "county", name: "Rogaland" },
+    Subdivision { code: "NO-15", territory: TerritoryCode::NO, category: "county", name: "Møre og Romsdal" },
+    Subdivision { code: "NO-18", territory: TerritoryCode::NO, category: "county", name: "Nordland" },
+    Subdivision { code: "NO-31", territory: TerritoryCode::NO, category: "county", name: "Østfold" },
+    Subdivision { code: "NO-32", territory: TerritoryCode::NO, category: "county", name: "Akershus" },
+    Subdivision { code: "NO-33", territory: TerritoryCode::NO, category: "county", name: "Buskerud" },
+    Subdivision { code: "NO-34", territory: TerritoryCode::NO, category: "county", name: "Innlandet" },
+    Subdivision { code: "NO-39", territory: TerritoryCode::NO, category: "county", name: "Vestfold" },
+    Subdivision { code: "NO-40", territory: TerritoryCode::NO, category: "county", name: "Telemark" },
+    Subdivision { code: "NO-42", territory: TerritoryCode::NO, category: "county", name: "Agder" },
+    Subdivision { code: "NO-46", territory: TerritoryCode::NO, category: "county", name: "Vestland" },
+    Subdivision { code: "NO-50", territory: TerritoryCode::NO, category: "county", name: "Trøndelag" },
+    Subdivision { code: "NO-55", territory: TerritoryCode::NO, category: "county", name: "Troms" },
+    Subdivision { code: "NO-56", territory: TerritoryCode::NO, category: "county", name: "Finnmark" },
+    Subdivision { code: "NP-P1", territory: TerritoryCode::NP, category: "province", name: "Koshi" },
+    Subdivision { code: "NP-P2", territory: TerritoryCode::NP, category: "province", name: "Madhesh" },
+    Subdivision { code: "NP-P3", territory: TerritoryCode::NP, category: "province", name: "Bagmati" },
+    Subdivision { code: "NP-P4", territory: TerritoryCode::NP, category: "province", name: "Gandaki" },
+    Subdivision { code: "NP-P5", territory: TerritoryCode::NP, category: "province", name: "Lumbini" },
+    Subdivision { code: "NP-P6", territory: TerritoryCode::NP, category: "province", name: "Karnali" },
+    Subdivision { code: "NP-P7", territory: TerritoryCode::NP, category: "province", name: "Sudurpashchim" },
+    Subdivision { code: "NR-01", territory: TerritoryCode::NR, category: "district", name: "Aiwo" },
+    Subdivision { code: "NR-02", territory: TerritoryCode::NR, category: "district", name: "Anabar" },
+    Subdivision { code: "NR-03", territory: TerritoryCode::NR, category: "district", name: "Anetan" },
+    Subdivision { code: "NR-04", territory: TerritoryCode::NR, category: "district", name: "Anibare" },
+    Subdivision { code: "NR-05", territory: TerritoryCode::NR, category: "district", name: "Baitsi" },
+    Subdivision { code: "NR-06", territory: TerritoryCode::NR, category: "district", name: "Boe" },
+    Subdivision { code: "NR-07", territory: TerritoryCode::NR, category: "district", name: "Buada" },
+    Subdivision { code: "NR-08", territory: TerritoryCode::NR, category: "district", name: "Denigomodu" },
+    Subdivision { code: "NR-09", territory: TerritoryCode::NR, category: "district", name: "Ewa" },
+    Subdivision { code: "NR-10", territory: TerritoryCode::NR, category: "district", name: "Ijuw" },
+    Subdivision { code: "NR-11", territory: TerritoryCode::NR, category: "district", name: "Meneng" },
+    Subdivision { code: "NR-12", territory: TerritoryCode::NR, category: "district", name: "Nibok" },
+    Subdivision { code: "NR-13", territory: TerritoryCode::NR, category: "district", name: "Uaboe" },
+    Subdivision { code: "NR-14", territory: TerritoryCode::NR, category: "district", name: "Yaren" },
+    Subdivision { code: "NZ-AUK", territory: TerritoryCode::NZ, category: "region", name: "Auckland" },
+    Subdivision { code: "NZ-BOP", territory: TerritoryCode::NZ, category: "region", name: "Bay of Plenty" },
+    Subdivision { code: "NZ-CAN", territory: TerritoryCode::NZ, category: "region", name: "Canterbury" },
+    Subdivision { code: "NZ-CIT", territory: TerritoryCode::NZ, category: "special island authority", name: "Chatham Islands Territory" },
+    Subdivision { code: "NZ-GIS", territory: TerritoryCode::NZ, category: "region", name: "Gisborne" },
+    Subdivision { code: "NZ-HKB", territory: TerritoryCode::NZ, category: "region", name: "Hawke's Bay" },
+    Subdivision { code: "NZ-MBH", territory: TerritoryCode::NZ, category: "region", name: "Marlborough" },
+    Subdivision { code: "NZ-MWT", territory: TerritoryCode::NZ, category: "region", name: "Manawatū-Whanganui" },
+    Subdivision { code: "NZ-NSN", territory: TerritoryCode::NZ, category: "region", name: "Nelson" },
+    Subdivision { code: "NZ-NTL", territory: TerritoryCode::NZ, category: "region", name: "Northland" },
+    Subdivision { code: "NZ-OTA", territory: TerritoryCode::NZ, category: "region", name: "Otago" },
+    Subdivision { code: "NZ-STL", territory: TerritoryCode::NZ, category: "region", name: "Southland" },
+    Subdivision { code: "NZ-TAS", territory: TerritoryCode::NZ, category: "region", name: "Tasman" },
+    Subdivision { code: "NZ-TKI", territory: TerritoryCode::NZ, category: "region", name: "Taranaki" },
+    Subdivision { code: "NZ-WGN", territory: TerritoryCode::NZ, category: "region", name: "Greater Wellington" },
+    Subdivision { code: "NZ-WKO", territory: TerritoryCode::NZ, category: "region", name: "Waikato" },
+    Subdivision { code: "NZ-WTC", territory: TerritoryCode::NZ, category: "region", name: "West Coast" },
+    Subdivision { code: "OM-BJ", territory: TerritoryCode::OM, category: "governorate", name: "South Al Batinah" },
+    Subdivision { code: "OM-BS", territory: TerritoryCode::OM, category: "governorate", name: "North Al Batinah" },
+    Subdivision { code: "OM-BU", territory: TerritoryCode::OM, category: "governorate", name: "Al Buraimi" },
+    Subdivision { code: "OM-DA", territory: TerritoryCode::OM, category: "governorate", name: "Ad Dakhiliyah" },
+    Subdivision { code: "OM-MA", territory: TerritoryCode::OM, category: "governorate", name: "Muscat" },
+    Subdivision { code: "OM-MU", territory: TerritoryCode::OM, category: "governorate", name: "Musandam" },
+    Subdivision { code: "OM-SJ", territory: TerritoryCode::OM, category: "governorate", name: "South Ash Sharqiyah" },
+    Subdivision { code: "OM-SS", territory: TerritoryCode::OM, category: "governorate", name: "North Ash Sharqiyah" },
+    Subdivision { code: "OM-WU", territory: TerritoryCode::OM, category: "governorate", name: "Al Wusta" },
+    Subdivision { code: "OM-ZA", territory: TerritoryCode::OM, category: "governorate", name: "Ad Dhahirah" },
+    Subdivision { code: "OM-ZU", territory: TerritoryCode::OM, category: "governorate", name: "Dhofar" },
+    Subdivision { code: "PA-1", territory: TerritoryCode::PA, category: "province", name: "Bocas del Toro" },
+    Subdivision { code: "PA-10", territory: TerritoryCode::PA, category: "province", name: "Panamá Oeste" },
+    Subdivision { code: "PA-2", territory: TerritoryCode::PA, category: "province", name: "Coclé" },
+    Subdivision { code: "PA-3", territory: TerritoryCode::PA, category: "province", name: "Colón" },
+    Subdivision { code: "PA-4", territory: TerritoryCode::PA, category: "province", name: "Chiriquí" },
+    Subdivision { code: "PA-5", territory: TerritoryCode::PA, category: "province", name: "Darién" },
+    Subdivision { code: "PA-6", territory: TerritoryCode::PA, category: "province", name: "Herrera" },
+    Subdivision { code: "PA-7", territory: TerritoryCode::PA, category: "province", name: "Los Santos" },
+    Subdivision { code: "PA-8", territory: TerritoryCode::PA, category: "province", name: "Panamá" },
+    Subdivision { code: "PA-9", territory: TerritoryCode::PA, category: "province", name: "Veraguas" },
+    Subdivision { code: "PA-EM", territory: TerritoryCode::PA, category: "indigenous region", name: "Emberá" },
+    Subdivision { code: "PA-KY", territory: TerritoryCode::PA, category: "indigenous region", name: "Guna Yala" },
+    Subdivision { code: "PA-NB", territory: TerritoryCode::PA, category: "indigenous region", name: "Ngäbe-Buglé" },
+    Subdivision { code: "PA-NT", territory: TerritoryCode::PA, category: "indigenous region", name: "Naso Tjër Di" },
+    Subdivision { code: "PE-AMA", territory: TerritoryCode::PE, category: "region", name: "Amazonas" },
+    Subdivision { code: "PE-ANC", territory: TerritoryCode::PE, category: "region", name: "Ancash" },
+    Subdivision { code: "PE-APU", territory: TerritoryCode::PE, category: "region", name: "Apurímac" },
+    Subdivision { code: "PE-ARE", territory: TerritoryCode::PE, category: "region", name: "Arequipa" },
+    Subdivision { code: "PE-AYA", territory: TerritoryCode::PE, category: "region", name: "Ayacucho" },
+    Subdivision { code: "PE-CAJ", territory: TerritoryCode::PE, category: "region", name: "Cajamarca" },
+    Subdivision { code: "PE-CAL", territory: TerritoryCode::PE, category: "region", name: "Callao" },
+    Subdivision { code: "PE-CUS", territory: TerritoryCode::PE, category: "region", name: "Cusco" },
+    Subdivision { code: "PE-HUC", territory: TerritoryCode::PE, category: "region", name: "Huánuco" },
+    Subdivision { code: "PE-HUV", territory: TerritoryCode::PE, category: "region", name: "Huancavelica" },
+    Subdivision { code: "PE-ICA", territory: TerritoryCode::PE, category: "region", name: "Ica" },
+    Subdivision { code: "PE-JUN", territory: TerritoryCode::PE, category: "region", name: "Junín" },
+    Subdivision { code: "PE-LAL", territory: TerritoryCode::PE, category: "region", name: "La Libertad" },
+    Subdivision { code: "PE-LAM", territory: TerritoryCode::PE, category: "region", name: "Lambayeque" },
+    Subdivision { code: "PE-LIM", territory: TerritoryCode::PE, category: "region", name: "Lima" },
+    Subdivision { code: "PE-LMA", territory: TerritoryCode::PE, category: "municipality", name: "Lima Metropolitan" },
+    Subdivision { code: "PE-LOR", territory: TerritoryCode::PE, category: "region", name: "Loreto" },
+    Subdivision { code: "PE-MDD", territory: TerritoryCode::PE, category: "region", name: "Madre de Dios" },
+    Subdivision { code: "PE-MOQ", territory: TerritoryCode::PE, category: "region", name: "Moquegua" },
+    Subdivision { code: "PE-PAS", territory: TerritoryCode::PE, category: "region", name: "Pasco" },
+    Subdivision { code: "PE-PIU", territory: TerritoryCode::PE, category: "region", name: "Piura" },
+    Subdivision { code: "PE-PUN", territory: TerritoryCode::PE, category: "region", name: "Puno" },
+    Subdivision { code: "PE-SAM", territory: TerritoryCode::PE, category: "region", name: "San Martín" },
+    Subdivision { code: "PE-TAC", territory: TerritoryCode::PE, category: "region", name: "Tacna" },
+    Subdivision { code: "PE-TUM", territory: TerritoryCode::PE, category: "region", name: "Tumbes" },
+    Subdivision { code: "PE-UCA", territory: TerritoryCode::PE, category: "region", name: "Ucayali" },
+    Subdivision { code: "PG-CPK", territory: TerritoryCode::PG, category: "province", name: "Chimbu" },
+    Subdivision { code: "PG-CPM", territory: TerritoryCode::PG, category: "province", name: "Central" },
+    Subdivision { code: "PG-EBR", territory: TerritoryCode::PG, category: "province", name: "East New Britain" },
+    Subdivision { code: "PG-EHG", territory: TerritoryCode::PG, category: "province", name: "Eastern Highlands" },
+    Subdivision { code: "PG-EPW", territory: TerritoryCode::PG, category: "province", name: "Enga" },
+    Subdivision { code: "PG-ESW", territory: TerritoryCode::PG, category: "province", name: "East Sepik" },
+    Subdivision { code: "PG-GPK", territory: TerritoryCode::PG, category: "province", name: "Gulf" },
+    Subdivision { code: "PG-HLA", territory: TerritoryCode::PG, category: "province", name: "Hela" },
+    Subdivision { code: "PG-JWK", territory: TerritoryCode::PG, category: "province", name: "Jiwaka" },
+    Subdivision { code: "PG-MBA", territory: TerritoryCode::PG, category: "province", name: "Milne Bay" },
+    Subdivision { code: "PG-MPL", territory: TerritoryCode::PG, category: "province", name: "Morobe" },
+    Subdivision { code: "PG-MPM", territory: TerritoryCode::PG, category: "province", name: "Madang" },
+    Subdivision { code: "PG-MRL", territory: TerritoryCode::PG, category: "province", name: "Manus" },
+    Subdivision { code: "PG-NCD", territory: TerritoryCode::PG, category: "district", name: "National Capital District" },
+    Subdivision { code: "PG-NIK", territory: TerritoryCode::PG, category: "province", name: "New Ireland" },
+    Subdivision { code: "PG-NPP", territory: TerritoryCode::PG, category: "province", name: "Oro" },
+    Subdivision { code: "PG-NSB", territory: TerritoryCode::PG, category: "autonomous region", name: "Bougainville" },
+    Subdivision { code: "PG-SAN", territory: TerritoryCode::PG, category: "province", name: "Sandaun" },
+    Subdivision { code: "PG-SHM", territory: TerritoryCode::PG, category: "province", name: "Southern Highlands" },
+    Subdivision { code: "PG-WBK", territory: TerritoryCode::PG, category: "province", name: "West New Britain" },
+    Subdivision { code: "PG-WHM", territory: TerritoryCode::PG, category: "province", name: "Western Highlands" },
+    Subdivision { code: "PG-WPD", territory: TerritoryCode::PG, category: "province", name: "Western" },
+    Subdivision { code: "PH-00", territory: TerritoryCode::PH, category: "region", name: "National Capital Region" },
+    Subdivision { code: "PH-01", territory: TerritoryCode::PH, category: "region", name: "Ilocos" },
+    Subdivision { code: "PH-02", territory: TerritoryCode::PH, category: "region", name: "Cagayan Valley" },
+    Subdivision { code: "PH-03", territory: TerritoryCode::PH, category: "region", name: "Central Luzon" },
+    Subdivision { code: "PH-05", territory: TerritoryCode::PH, category: "region", name: "Bicol" },
+    Subdivision { code: "PH-06", territory: TerritoryCode::PH, category: "region", name: "Western Visayas" },
+    Subdivision { code: "PH-07", territory: TerritoryCode::PH, category: "region", name: "Central Visayas" },
+    Subdivision { code: "PH-08", territory: TerritoryCode::PH, category: "region", name: "Eastern Visayas" },
+    Subdivision { code: "PH-09", territory: TerritoryCode::PH, category: "region", name: "Zamboanga Peninsula" },
+    Subdivision { code: "PH-10", territory: TerritoryCode::PH, category: "region", name: "Northern Mindanao" },
+    Subdivision { code: "PH-11", territory: TerritoryCode::PH, category: "region", name: "Davao" },
+    Subdivision { code: "PH-12", territory: TerritoryCode::PH, category: "region", name: "Soccsksargen" },
+    Subdivision { code: "PH-13", territory: TerritoryCode::PH, category: "region", name: "Caraga" },
+    Subdivision { code: "PH-14", territory: TerritoryCode::PH, category: "autonomous region", name: "Bangsamoro" },
+    Subdivision { code: "PH-15", territory: TerritoryCode::PH, category: "administrative region", name: "Cordillera" },
+    Subdivision { code: "PH-40", territory: TerritoryCode::PH, category: "region", name: "Calabarzon" },
+    Subdivision { code: "PH-41", territory: TerritoryCode::PH, category: "region", name: "Mimaropa" },
+    Subdivision { code: "PK-BA", territory: TerritoryCode::PK, category: "province", name: "Balochistan" },
+    Subdivision { code: "PK-GB", territory: TerritoryCode::PK, category: "administrative territory", name: "Gilgit-Baltistan" },
+    Subdivision { code: "PK-IS", territory: TerritoryCode::PK, category: "federal capital territory", name: "Islamabad" },
+    Subdivision { code: "PK-JK", territory: TerritoryCode::PK, category: "administrative territory", name: "Azad Jammu and Kashmir" },
+    Subdivision { code: "PK-KP", territory: TerritoryCode::PK, category: "province", name: "Khyber Pakhtunkhwa" },
+    Subdivision { code: "PK-PB", territory: TerritoryCode::PK, category: "province", name: "Punjab" },
+    Subdivision { code: "PK-SD", territory: TerritoryCode::PK, category: "province", name: "Sindh" },
+    Subdivision { code: "PL-02", territory: TerritoryCode::PL, category: "voivodeship", name: "Lower Silesia" },
+    Subdivision { code: "PL-04", territory: TerritoryCode::PL, category: "voivodeship", name: "Kuyavia-Pomerania" },
+    Subdivision { code: "PL-06", territory: TerritoryCode::PL, category: "voivodeship", name: "Lublin" },
+    Subdivision { code: "PL-08", territory: TerritoryCode::PL, category: "voivodeship", name: "Lubusz" },
+    Subdivision { code: "PL-10", territory: TerritoryCode::PL, category: "voivodeship", name: "Łódź" },
+    Subdivision { code: "PL-12", territory: TerritoryCode::PL, category: "voivodeship", name: "Lesser Poland" },
+    Subdivision { code: "PL-14", territory: TerritoryCode::PL, category: "voivodeship", name: "Masovia" },
+    Subdivision { code: "PL-16", territory: TerritoryCode::PL, category: "voivodeship", name: "Opole" },
+    Subdivision { code: "PL-18", territory: TerritoryCode::PL, category: "voivodeship", name: "Subcarpathia" },
+    Subdivision { code: "PL-20", territory: TerritoryCode::PL, category: "voivodeship", name: "Podlaskie" },
+    Subdivision { code: "PL-22", territory: TerritoryCode::PL, category: "voivodeship", name: "Pomerania" },
+    Subdivision { code: "PL-24", territory: TerritoryCode::PL, category: "voivodeship", name: "Silesia" },
+    Subdivision { code: "PL-26", territory: TerritoryCode::PL, category: "voivodeship", name: "Holy Cross" },
+    Subdivision { code: "PL-28", territory: TerritoryCode::PL, category: "voivodeship", name: "Warmia-Masuria" },
+    Subdivision { code: "PL-30", territory: TerritoryCode::PL, category: "voivodeship", name: "Greater Poland" },
+    Subdivision { code: "PL-32", territory: TerritoryCode::PL, category: "voivodeship", name: "West Pomerania" },
+    Subdivision { code: "PS-BTH", territory: TerritoryCode::PS, category: "governorate", name: "Bethlehem" },
+    Subdivision { code: "PS-DEB", territory: TerritoryCode::PS, category: "governorate", name: "Deir al-Balah" },
+    Subdivision { code: "PS-GZA", territory: TerritoryCode::PS, category: "governorate", name: "Gaza" },
+    Subdivision { code: "PS-HBN", territory: TerritoryCode::PS, category: "governorate", name: "Hebron" },
+    Subdivision { code: "PS-JEM", territory: TerritoryCode::PS, category: "governorate", name: "Jerusalem" },
+    Subdivision { code: "PS-JEN", territory: TerritoryCode::PS, category: "governorate", name: "Jenin" },
+    Subdivision { code: "PS-JRH", territory: TerritoryCode::PS, category: "governorate", name: "Jericho" },
+    Subdivision { code: "PS-KYS", territory: TerritoryCode::PS, category: "governorate", name: "Khan Yunis" },
+    Subdivision { code: "PS-NBS", territory: TerritoryCode::PS, category: "governorate", name: "Nablus" },
+    Subdivision { code: "PS-NGZ", territory: TerritoryCode::PS, category: "governorate", name: "North Gaza" },
+    Subdivision { code: "PS-QQA", territory: TerritoryCode::PS, category: "governorate", name: "Qalqilya" },
+    Subdivision { code: "PS-RBH", territory: TerritoryCode::PS, category: "governorate", name: "Ramallah and al-Bireh" },
+    Subdivision { code: "PS-RFH", territory: TerritoryCode::PS, category: "governorate", name: "Rafah" },
+    Subdivision { code: "PS-SLT", territory: TerritoryCode::PS, category: "governorate", name: "Salfit" },
+    Subdivision { code: "PS-TBS", territory: TerritoryCode::PS, category: "governorate", name: "Tubas" },
+    Subdivision { code: "PS-TKM", territory: TerritoryCode::PS, category: "governorate", name: "Tulkarm" },
+    Subdivision { code: "PT-01", territory: TerritoryCode::PT, category: "district", name: "Aveiro" },
+    Subdivision { code: "PT-02", territory: TerritoryCode::PT, category: "district", name: "Beja" },
+    Subdivision { code: "PT-03", territory: TerritoryCode::PT, category: "district", name: "Braga" },
+    Subdivision { code: "PT-04", territory: TerritoryCode::PT, category: "district", name: "Bragança" },
+    Subdivision { code: "PT-05", territory: TerritoryCode::PT, category: "district", name: "Castelo Branco" },
+    Subdivision { code: "PT-06", territory: TerritoryCode::PT, category: "district", name: "Coimbra" },
+    Subdivision { code: "PT-07", territory: TerritoryCode::PT, category: "district", name: "Évora" },
+    Subdivision { code: "PT-08", territory: TerritoryCode::PT, category: "district", name: "Faro" },
+    Subdivision { code: "PT-09", territory: TerritoryCode::PT, category: "district", name: "Guarda" },
+    Subdivision { code: "PT-10", territory: TerritoryCode::PT, category: "district", name: "Leiria" },
+    Subdivision { code: "PT-11", territory: TerritoryCode::PT, category: "district", name: "Lisbon" },
+    Subdivision { code: "PT-12", territory: TerritoryCode::PT, category: "district", name: "Portalegre" },
+    Subdivision { code: "PT-13", territory: TerritoryCode::PT, category: "district", name: "Porto" },
+    Subdivision { code: "PT-14", territory: TerritoryCode::PT, category: "district", name: "Santarém" },
+    Subdivision { code: "PT-15", territory: TerritoryCode::PT, category: "district", name: "Setúbal" },
+    Subdivision { code: "PT-16", territory: TerritoryCode::PT, category: "district", name: "Viana do Castelo" },
+    Subdivision { code: "PT-17", territory: TerritoryCode::PT, category: "district", name: "Vila Real" },
+    Subdivision { code: "PT-18", territory: TerritoryCode::PT, category: "district", name: "Viseu" },
+    Subdivision { code: "PT-20", territory: TerritoryCode::PT, category: "autonomous region", name: "Azores" },
+    Subdivision { code: "PT-30", territory: TerritoryCode::PT, category: "autonomous region", name: "Madeira" },
+    Subdivision { code: "PY-1", territory: TerritoryCode::PY, category: "department", name: "Concepción" },
+    Subdivision { code: "PY-10", territory: TerritoryCode::PY, category: "department", name: "Alto Paraná" },
+    Subdivision { code: "PY-11", territory: TerritoryCode::PY, category: "department", name: "Central" },
+    Subdivision { code: "PY-12", territory: TerritoryCode::PY, category: "department", name: "Ñeembucú" },
+    Subdivision { code: "PY-13", territory: TerritoryCode::PY, category: "department", name: "Amambay" },
+    Subdivision { code: "PY-14", territory: TerritoryCode::PY, category: "department", name: "Canindeyú" },
+    Subdivision { code: "PY-15", territory: TerritoryCode::PY, category: "department", name: "Presidente Hayes" },
+    Subdivision { code: "PY-16", territory: TerritoryCode::PY, category: "department", name: "Alto Paraguay" },
+    Subdivision { code: "PY-19", territory: TerritoryCode::PY, category: "department", name: "Boquerón" },
+    Subdivision { code: "PY-2", territory: TerritoryCode::PY, category: "department", name: "San Pedro" },
+    Subdivision { code: "PY-3", territory: TerritoryCode::PY, category: "department", name: "Cordillera" },
+    Subdivision { code: "PY-4", territory: TerritoryCode::PY, category: "department", name: "Guairá" },
+    Subdivision { code: "PY-5", territory: TerritoryCode::PY, category: "department", name: "Caaguazú" },
+    Subdivision { code: "PY-6", territory: TerritoryCode::PY, category: "department", name: "Caazapá" },
+    Subdivision { code: "PY-7", territory: TerritoryCode::PY, category: "department", name: "Itapúa" },
+    Subdivision { code: "PY-8", territory: TerritoryCode::PY, category: "department", name: "Misiones" },
+    Subdivision { code: "PY-9", territory: TerritoryCode::PY, category: "department", name: "Paraguarí" },
+    Subdivision { code: "PY-ASU", territory: TerritoryCode::PY, category: "capital district", name: "Asunción" },
+    Subdivision { code: "QA-DA", territory: TerritoryCode::QA, category: "municipality", name: "Doha" },
+    Subdivision { code: "QA-KH", territory: TerritoryCode::QA, category: "municipality", name: "Al Khor" },
+    Subdivision { code: "QA-MS", territory: TerritoryCode::QA, category: "municipality", name: "Al Shamal" },
+    Subdivision { code: "QA-RA", territory: TerritoryCode::QA, category: "municipality", name: "Al Rayyan" },
+    Subdivision { code: "QA-SH", territory: TerritoryCode::QA, category: "municipality", name: "Al-Shahaniya" },
+    Subdivision { code: "QA-US", territory: TerritoryCode::QA, category: "municipality", name: "Umm Salal" },
+    Subdivision { code: "QA-WA", territory: TerritoryCode::QA, category: "municipality", name: "Al Wakrah" },
+    Subdivision { code: "QA-ZA", territory: TerritoryCode::QA, category: "municipality", name: "Al Daayen" },
+    Subdivision { code: "RO-AB", territory: TerritoryCode::RO, category: "county", name: "Alba" },
+    Subdivision { code: "RO-AG", territory: TerritoryCode::RO, category: "county", name: "Argeș" },
+    Subdivision { code: "RO-AR", territory: TerritoryCode::RO, category: "county", name: "Arad" },
+    Subdivision { code: "RO-B", territory: TerritoryCode::RO, category: "municipality", name: "Bucharest" },
+    Subdivision { code: "RO-BC", territory: TerritoryCode::RO, category: "county", name: "Bacău" },
+    Subdivision { code: "RO-BH", territory: TerritoryCode::RO, category: "county", name: "Bihor" },
+    Subdivision { code: "RO-BN", territory: TerritoryCode::RO, category: "county", name: "Bistrița-Năsăud" },
+    Subdivision { code: "RO-BR", territory: TerritoryCode::RO, category: "county", name: "Brăila" },
+    Subdivision { code: "RO-BT", territory: TerritoryCode::RO, category: "county", name: "Botoșani" },
+    Subdivision { code: "RO-BV", territory: TerritoryCode::RO, category: "county", name: "Brașov" },
+    Subdivision { code: "RO-BZ", territory: TerritoryCode::RO, category: "county", name: "Buzău" },
+    Subdivision { code: "RO-CJ", territory: TerritoryCode::RO, category: "county", name: "Cluj" },
+    Subdivision { code: "RO-CL", territory: TerritoryCode::RO, category: "county", name: "Călărași" },
+    Subdivision { code: "RO-CS", territory: TerritoryCode::RO, category: "county", name: "Caraș-Severin" },
+    Subdivision { code: "RO-CT", territory: TerritoryCode::RO, category: "county", name: "Constanța" },
+    Subdivision { code: "RO-CV", territory: TerritoryCode::RO, category: "county", name: "Covasna" },
+    Subdivision { code: "RO-DB", territory: TerritoryCode::RO, category: "county", name: "Dâmbovița" },
+    Subdivision { code: "RO-DJ", territory: TerritoryCode::RO, category: "county", name: "Dolj" },
+    Subdivision { code: "RO-GJ", territory: TerritoryCode::RO, category: "county", name: "Gorj" },
+    Subdivision { code: "RO-GL", territory: TerritoryCode::RO, category: "county", name: "Galați" },
+    Subdivision { code: "RO-GR", territory: TerritoryCode::RO, category: "county", name: "Giurgiu" },
+    Subdivision { code: "RO-HD", territory: TerritoryCode::RO, category: "county", name: "Hunedoara" },
+    Subdivision { code: "RO-HR", territory: TerritoryCode::RO, category: "county", name: "Harghita" },
+    Subdivision { code: "RO-IF", territory: TerritoryCode::RO, category: "county", name: "Ilfov" },
+    Subdivision { code: "RO-IL", territory: TerritoryCode::RO, category: "county", name: "Ialomița" },
+    Subdivision { code: "RO-IS", territory: TerritoryCode::RO, category: "county", name: "Iași" },
+    Subdivision { code: "RO-MH", territory: TerritoryCode::RO, category: "county", name: "Mehedinți" },
+    Subdivision { code: "RO-MM", territory: TerritoryCode::RO, category: "county", name: "Maramureș" },
+    Subdivision { code: "RO-MS", territory: TerritoryCode::RO, category: "county", name: "Mureș" },
+    Subdivision { code: "RO-NT", territory: TerritoryCode::RO, category: "county", name: "Neamț" },
+    Subdivision { code: "RO-OT", territory: TerritoryCode::RO, category: "county", name: "Olt" },
+    Subdivision { code: "RO-PH", territory: TerritoryCode::RO, category: "county", name: "Prahova" },
+    Subdivision { code: "RO-SB", territory: TerritoryCode::RO, category: "county", name: "Sibiu" },
+    Subdivision { code: "RO-SJ", territory: TerritoryCode::RO, category: "county", name: "Sălaj" },
+    Subdivision { code: "RO-SM", territory: TerritoryCode::RO, category: "county", name: "Satu Mare" },
+    Subdivision { code: "RO-SV", territory: TerritoryCode::RO, category: "county", name: "Suceava" },
+    Subdivision { code: "RO-TL", territory: TerritoryCode::RO, category: "county", name: "Tulcea" },
+    Subdivision { code: "RO-TM", territory: TerritoryCode::RO, category: "county", name: "Timiș" },
+    Subdivision { code: "RO-TR", territory: TerritoryCode::RO, category: "county", name: "Teleorman" },
+    Subdivision { code: "RO-VL", territory: TerritoryCode::RO, category: "county", name: "Vâlcea" },
+    Subdivision { code: "RO-VN", territory: TerritoryCode::RO, category: "county", name: "Vrancea" },
+    Subdivision { code: "RO-VS", territory: TerritoryCode::RO, category: "county", name: "Vaslui" },
+    Subdivision { code: "RS-00", territory: TerritoryCode::RS, category: "city", name: "Belgrade" },
+    Subdivision { code: "RS-01", territory: TerritoryCode::RS, category: "district", name: "North Bačka" },
+    Subdivision { code: "RS-02", territory: TerritoryCode::RS, category: "district", name: "Central Banat" },
+    Subdivision { code: "RS-03", territory: TerritoryCode::RS, category: "district", name: "North Banat" },
+    Subdivision { code: "RS-04", territory: TerritoryCode::RS, category: "district", name: "South Banat" },
+    Subdivision { code: "RS-05", territory: TerritoryCode::RS, category: "district", name: "West Bačka" },
+    Subdivision { code: "RS-06", territory: TerritoryCode::RS, category: "district", name: "South Bačka" },
+    Subdivision { code: "RS-07", territory: TerritoryCode::RS, category: "district", name: "Srem" },
+    Subdivision { code: "RS-08", territory: TerritoryCode::RS, category: "district", name: "Mačva" },
+    Subdivision { code: "RS-09", territory: TerritoryCode::RS, category: "district", name: "Kolubara" },
+    Subdivision { code: "RS-10", territory: TerritoryCode::RS, category: "district", name: "Podunavlje" },
+    Subdivision { code: "RS-11", territory: TerritoryCode::RS, category: "district", name: "Braničevo" },
+    Subdivision { code: "RS-12", territory: TerritoryCode::RS, category: "district", name: "Šumadija" },
+    Subdivision { code: "RS-13", territory: TerritoryCode::RS, category: "district", name: "Pomoravlje" },
+    Subdivision { code: "RS-14", territory: TerritoryCode::RS, category: "district", name: "Bor" },
+    Subdivision { code: "RS-15", territory: TerritoryCode::RS, category: "district", name: "Zaječar" },
+    Subdivision { code: "RS-16", territory: TerritoryCode::RS, category: "district", name: "Zlatibor" },
+    Subdivision { code: "RS-17", territory: TerritoryCode::RS, category: "district", name: "Moravica" },
+    Subdivision { code: "RS-18", territory: TerritoryCode::RS, category: "district", name: "Raška" },
+    Subdivision { code: "RS-19", territory: TerritoryCode::RS, category: "district", name: "Rasina" },
+    Subdivision { code: "RS-20", territory: TerritoryCode::RS, category: "district", name: "Nišava" },
+    Subdivision { code: "RS-21", territory: TerritoryCode::RS, category: "district", name: "Toplica" },
+    Subdivision { code: "RS-22", territory: TerritoryCode::RS, category: "district", name: "Pirot" },
+    Subdivision { code: "RS-23", territory: TerritoryCode::RS, category: "district", name: "Jablanica" },
+    Subdivision { code: "RS-24", territory: TerritoryCode::RS, category: "district", name: "Pčinja" },
+    Subdivision { code: "RS-25", territory: TerritoryCode::RS, category: "district", name: "Kosovo" },
+    Subdivision { code: "RS-26", territory: TerritoryCode::RS, category: "district", name: "Peć" },
+    Subdivision { code: "RS-27", territory: TerritoryCode::RS, category: "district", name: "Prizren" },
+    Subdivision { code: "RS-28", territory: TerritoryCode::RS, category: "district", name: "Kosovska Mitrovica" },
+    Subdivision { code: "RS-29", territory: TerritoryCode::RS, category: "district", name: "Kosovo-Pomoravlje" },
+    Subdivision { code: "RS-KM", territory: TerritoryCode::RS, category: "autonomous province", name: "Kosovo-Metohija" },
+    Subdivision { code: "RS-VO", territory: TerritoryCode::RS, category: "autonomous province", name: "Vojvodina" },
+    Subdivision { code: "RU-AD", territory: TerritoryCode::RU, category: "republic", name: "Adygea" },
+    Subdivision { code: "RU-AL", territory: TerritoryCode::RU, category: "republic", name: "Altai Republic" },
+    Subdivision { code: "RU-ALT", territory: TerritoryCode::RU, category: "krai", name: "Altai Krai" },
+    Subdivision { code: "RU-AMU", territory: TerritoryCode::RU, category: "oblast", name: "Amur" },
+    Subdivision { code: "RU-ARK", territory: TerritoryCode::RU, category: "oblast", name: "Arkhangelsk" },
+    Subdivision { code: "RU-AST", territory: TerritoryCode::RU, category: "oblast", name: "Astrakhan" },
+    Subdivision { code: "RU-BA", territory: TerritoryCode::RU, category: "republic", name: "Bashkortostan" },
+    Subdivision { code: "RU-BEL", territory: TerritoryCode::RU, category: "oblast", name: "Belgorod" },
+    Subdivision { code: "RU-BRY", territory: TerritoryCode::RU, category: "oblast", name: "Bryansk" },
+    Subdivision { code: "RU-BU", territory: TerritoryCode::RU, category: "republic", name: "Buryatia" },
+    Subdivision { code: "RU-CE", territory: TerritoryCode::RU, category: "republic", name: "Chechnya" },
+    Subdivision { code: "RU-CHE", territory: TerritoryCode::RU, category: "oblast", name: "Chelyabinsk" },
+    Subdivision { code: "RU-CHU", territory: TerritoryCode::RU, category: "autonomous okrug", name: "Chukotka" },
+    Subdivision { code: "RU-CU", territory: TerritoryCode::RU, category: "republic", name: "Chuvashia" },
+    Subdivision { code: "RU-DA", territory: TerritoryCode::RU, category: "republic", name: "Dagestan" },
+    Subdivision { code: "RU-IN", territory: TerritoryCode::RU, category: "republic", name: "Ingushetia" },
+    Subdivision { code: "RU-IRK", territory: TerritoryCode::RU, category: "oblast", name: "Irkutsk" },
+    Subdivision { code: "RU-IVA", territory: TerritoryCode::RU, category: "oblast", name: "Ivanovo" },
+    Subdivision { code: "RU-KAM", territory: TerritoryCode::RU, category: "krai", name: "Kamchatka" },
+    Subdivision { code: "RU-KB", territory: TerritoryCode::RU, category: "republic", name: "Kabardino-Balkaria" },
+    Subdivision { code: "RU-KC", territory: TerritoryCode::RU, category: "republic", name: "Karachay-Cherkessia" },
+    Subdivision { code: "RU-KDA", territory: TerritoryCode::RU, category: "krai", name: "Krasnodar" },
+    Subdivision { code: "RU-KEM", territory: TerritoryCode::RU, category: "oblast", name: "Kemerovo" },
+    Subdivision { code: "RU-KGD", territory: TerritoryCode::RU, category: "oblast", name: "Kaliningrad" },
+    Subdivision { code: "RU-KGN", territory: TerritoryCode::RU, category: "oblast", name: "Kurgan" },
+    Subdivision { code: "RU-KHA", territory: TerritoryCode::RU, category: "krai", name: "Khabarovsk" },
+    Subdivision { code: "RU-KHM", territory: TerritoryCode::RU, category: "autonomous okrug", name: "Khanty-Mansi" },
+    Subdivision { code: "RU-KIR", territory: TerritoryCode::RU, category: "oblast", name: "Kirov" },
+    Subdivision { code: "RU-KK", territory: TerritoryCode::RU, category: "republic", name: "Khakassia" },
+    Subdivision { code: "RU-KL", territory: TerritoryCode::RU, category: "republic", name: "Kalmykia" },
+    Subdivision { code: "RU-KLU", territory: TerritoryCode::RU, category: "oblast", name: "Kaluga" },
+    Subdivision { code: "RU-KO", territory: TerritoryCode::RU, category: "republic", name: "Komi" },
+    Subdivision { code: "RU-KOS", territory: TerritoryCode::RU, category: "oblast", name: "Kostroma" },
+    Subdivision { code: "RU-KR", territory: TerritoryCode::RU, category: "republic", name: "Karelia" },
+    Subdivision { code: "RU-KRS", territory: TerritoryCode::RU, category: "oblast", name: "Kursk" },
+    Subdivision { code: "RU-KYA", territory: TerritoryCode::RU, category: "krai", name: "Krasnoyarsk" },
+    Subdivision { code: "RU-LEN", territory: TerritoryCode::RU, category: "oblast", name: "Leningrad" },
+    Subdivision { code: "RU-LIP", territory: TerritoryCode::RU, category: "oblast", name: "Lipetsk" },
+    Subdivision { code: "RU-MAG", territory: TerritoryCode::RU, category: "oblast", name: "Magadan" },
+    Subdivision { code: "RU-ME", territory: TerritoryCode::RU, category: "republic", name: "Mari El" },
+    Subdivision { code: "RU-MO", territory: TerritoryCode::RU, category: "republic", name: "Mordovia" },
+    Subdivision { code: "RU-MOS", territory: TerritoryCode::RU, category: "oblast", name: "Moscow Oblast" },
+    Subdivision { code: "RU-MOW", territory: TerritoryCode::RU, category: "federal city", name: "Moscow" },
+    Subdivision { code: "RU-MUR", territory: TerritoryCode::RU, category: "oblast", name: "Murmansk" },
+    Subdivision { code: "RU-NEN", territory: TerritoryCode::RU, category: "autonomous okrug", name: "Nenets" },
+    Subdivision { code: "RU-NGR", territory: TerritoryCode::RU, category: "oblast", name: "Novgorod" },
+    Subdivision { code: "RU-NIZ", territory: TerritoryCode::RU, category: "oblast", name: "Nizhny Novgorod" },
+    Subdivision { code: "RU-NVS", territory: TerritoryCode::RU, category: "oblast", name: "Novosibirsk" },
+    Subdivision { code: "RU-OMS", territory: TerritoryCode::RU, category: "oblast", name: "Omsk" },
+    Subdivision { code: "RU-ORE", territory: TerritoryCode::RU, category: "oblast", name: "Orenburg" },
+    Subdivision { code: "RU-ORL", territory: TerritoryCode::RU, category: "oblast", name: "Oryol" },
+    Subdivision { code: "RU-PER", territory: TerritoryCode::RU, category: "krai", name: "Perm" },
+    Subdivision { code: "RU-PNZ", territory: TerritoryCode::RU, category: "oblast", name: "Penza" },
+    Subdivision { code: "RU-PRI", territory: TerritoryCode::RU, category: "krai", name: "Primorsky" },
+    Subdivision { code: "RU-PSK", territory: TerritoryCode::RU, category: "oblast", name: "Pskov" },
+    Subdivision { code: "RU-ROS", territory: TerritoryCode::RU, category: "oblast", name: "Rostov" },
+    Subdivision { code: "RU-RYA", territory: TerritoryCode::RU, category: "oblast", name: "Ryazan" },
+    Subdivision { code: "RU-SA", territory: TerritoryCode::RU, category: "republic", name: "Sakha" },
+    Subdivision { code: "RU-SAK", territory: TerritoryCode::RU, category: "oblast", name: "Sakhalin" },
+    Subdivision { code: "RU-SAM", territory: TerritoryCode::RU, category: "oblast", name: "Samara" },
+    Subdivision { code: "RU-SAR", territory: TerritoryCode::RU, category: "oblast", name: "Saratov" },
+    Subdivision { code: "RU-SE", territory: TerritoryCode::RU, category: "republic", name: "North Ossetia-Alania" },
+    Subdivision { code: "RU-SMO", territory: TerritoryCode::RU, category: "oblast", name: "Smolensk" },
+    Subdivision { code: "RU-SPE", territory: TerritoryCode::RU, category: "federal city", name: "Saint Petersburg" },
+    Subdivision { code: "RU-STA", territory: TerritoryCode::RU, category: "krai", name: "Stavropol" },
+    Subdivision { code: "RU-SVE", territory: TerritoryCode::RU, category: "oblast", name: "Sverdlovsk" },
+    Subdivision { code: "RU-TA", territory: TerritoryCode::RU, category: "republic", name: "Tatarstan" },
+    Subdivision { code: "RU-TAM", territory: TerritoryCode::RU, category: "oblast", name: "Tambov" },
+    Subdivision { code: "RU-TOM", territory: TerritoryCode::RU, category: "oblast", name: "Tomsk" },
+    Subdivision { code: "RU-TUL", territory: TerritoryCode::RU, category: "oblast", name: "Tula" },
+    Subdivision { code: "RU-TVE", territory: TerritoryCode::RU, category: "oblast", name: "Tver" },
+    Subdivision { code: "RU-TY", territory: TerritoryCode::RU, category: "republic", name: "Tuva" },
+    Subdivision { code: "RU-TYU", territory: TerritoryCode::RU, category: "oblast", name: "Tyumen" },
+    Subdivision { code: "RU-UD", territory: TerritoryCode::RU, category: "republic", name: "Udmurtia" },
+    Subdivision { code: "RU-ULY", territory: TerritoryCode::RU, category: "oblast", name: "Ulyanovsk" },
+    Subdivision { code: "RU-VGG", territory: TerritoryCode::RU, category: "oblast", name: "Volgograd" },
+    Subdivision { code: "RU-VLA", territory: TerritoryCode::RU, category: "oblast", name: "Vladimir" },
+    Subdivision { code: "RU-VLG", territory: TerritoryCode::RU, category: "oblast", name: "Vologda" },
+    Subdivision { code: "RU-VOR", territory: TerritoryCode::RU, category: "oblast", name: "Voronezh" },
+    Subdivision { code: "RU-YAN", territory: TerritoryCode::RU, category: "autonomous okrug", name: "Yamalo-Nenets" },
+    Subdivision { code: "RU-YAR", territory: TerritoryCode::RU, category: "oblast", name: "Yaroslavl" },
+    Subdivision { code: "RU-YEV", territory: TerritoryCode::RU, category: "autonomous oblast", name: "Jewish Autonomous Oblast" },
+    Subdivision { code: "RU-ZAB", territory: TerritoryCode::RU, category: "krai", name: "Zabaykalsky" },
+    Subdivision { code: "RW-01", territory: TerritoryCode::RW, category: "city", name: "Kigali" },
+    Subdivision { code: "RW-02", territory: TerritoryCode::RW, category: "province", name: "Eastern" },
+    Subdivision { code: "RW-03", territory: TerritoryCode::RW, category: "province", name: "Northern" },
+    Subdivision { code: "RW-04", territory: TerritoryCode::RW, category: "province", name: "Western" },
+    Subdivision { code: "RW-05", territory: TerritoryCode::RW, category: "province", name: "Southern" },
+    Subdivision { code: "SA-01", territory: TerritoryCode::SA, category: "province", name: "Riyadh" },
+    Subdivision { code: "SA-02", territory: TerritoryCode::SA, category: "province", name: "Makkah" },
+    Subdivision { code: "SA-03", territory: TerritoryCode::SA, category: "province", name: "Madinah" },
+    Subdivision { code: "SA-04", territory: TerritoryCode::SA, category: "province", name: "Eastern" },
+    Subdivision { code: "SA-05", territory: TerritoryCode::SA, category: "province", name: "Al-Qassim" },
+    Subdivision { code: "SA-06", territory: TerritoryCode::SA, category: "province", name: "Ha'il" },
+    Subdivision { code: "SA-07", territory: TerritoryCode::SA, category: "province", name: "Tabuk" },
+    Subdivision { code: "SA-08", territory: TerritoryCode::SA, category: "province", name: "Northern Borders" },
+    Subdivision { code: "SA-09", territory: TerritoryCode::SA, category: "province", name: "Jazan" },
+    Subdivision { code: "SA-10", territory: TerritoryCode::SA, category: "province", name: "Najran" },
+    Subdivision { code: "SA-11", territory: TerritoryCode::SA, category: "province", name: "Al-Bahah" },
+    Subdivision { code: "SA-12", territory: TerritoryCode::SA, category: "province", name: "Al-Jawf" },
+    Subdivision { code: "SA-14", territory: TerritoryCode::SA, category: "province", name: "Asir" },
+    Subdivision { code: "SB-CE", territory: TerritoryCode::SB, category: "province", name: "Central" },
+    Subdivision { code: "SB-CH", territory: TerritoryCode::SB, category: "province", name: "Choiseul" },
+    Subdivision { code: "SB-CT", territory: TerritoryCode::SB, category: "capital territory", name: "Honiara" },
+    Subdivision { code: "SB-GU", territory: TerritoryCode::SB, category: "province", name: "Guadalcanal" },
+    Subdivision { code: "SB-IS", territory: TerritoryCode::SB, category: "province", name: "Isabel" },
+    Subdivision { code: "SB-MK", territory: TerritoryCode::SB, category: "province", name: "Makira-Ulawa" },
+    Subdivision { code: "SB-ML", territory: TerritoryCode::SB, category: "province", name: "Malaita" },
+    Subdivision { code: "SB-RB", territory: TerritoryCode::SB, category: "province", name: "Rennell and Bellona" },
+    Subdivision { code: "SB-TE", territory: TerritoryCode::SB, category: "province", name: "Temotu" },
+    Subdivision { code: "SB-WE", territory: TerritoryCode::SB, category: "province", name: "Western" },
+    Subdivision { code: "SD-DC", territory: TerritoryCode::SD, category: "state", name: "Central Darfur" },
+    Subdivision { code: "SD-DE", territory: TerritoryCode::SD, category: "state", name: "East Darfur" },
+    Subdivision { code: "SD-DN", territory: TerritoryCode::SD, category: "state", name: "North Darfur" },
+    Subdivision { code: "SD-DS", territory: TerritoryCode::SD, category: "state", name: "South Darfur" },
+    Subdivision { code: "SD-DW", territory: TerritoryCode::SD, category: "state", name: "West Darfur" },
+    Subdivision { code: "SD-GD", territory: TerritoryCode::SD, category: "state", name: "Gedaref" },
+    Subdivision { code: "SD-GK", territory: TerritoryCode::SD, category: "state", name: "West Kordofan" },
+    Subdivision { code: "SD-GZ", territory: TerritoryCode::SD, category: "state", name: "Gezira" },
+    Subdivision { code: "SD-KA", territory: TerritoryCode::SD, category: "state", name: "Kassala" },
+    Subdivision { code: "SD-KH", territory: TerritoryCode::SD, category: "state", name: "Khartoum" },
+    Subdivision { code: "SD-KN", territory: TerritoryCode::SD, category: "state", name: "North Kordofan" },
+    Subdivision { code: "SD-KS", territory: TerritoryCode::SD, category: "state", name: "South Kordofan" },
+    Subdivision { code: "SD-NB", territory: TerritoryCode::SD, category: "state", name: "Blue Nile" },
+    Subdivision { code: "SD-NO", territory: TerritoryCode::SD, category: "state", name: "Northern" },
+    Subdivision { code: "SD-NR", territory: TerritoryCode::SD, category: "state", name: "River Nile" },
+    Subdivision { code: "SD-NW", territory: TerritoryCode::SD, category: "state", name: "White Nile" },
+    Subdivision { code: "SD-RS", territory: TerritoryCode::SD, category: "state", name: "Red Sea" },
+    Subdivision { code: "SD-SI", territory: TerritoryCode::SD, category: "state", name: "Sennar" },
+    Subdivision { code: "SE-AB", territory: TerritoryCode::SE, category: "county", name: "Stockholm" },
+    Subdivision { code: "SE-AC", territory: TerritoryCode::SE, category: "county", name: "Västerbotten" },
+    Subdivision { code: "SE-BD", territory: TerritoryCode::SE, category: "county", name: "Norrbotten" },
+    Subdivision { code: "SE-C", territory: TerritoryCode::SE, category: "county", name: "Uppsala" },
+    Subdivision { code: "SE-D", territory: TerritoryCode::SE, category: "county", name: "Södermanland" },
+    Subdivision { code: "SE-E", territory: TerritoryCode::SE, category: "county", name: "Östergötland" },
+    Subdivision { code: "SE-F", territory: TerritoryCode::SE, category: "county", name: "Jönköping" },
+    Subdivision { code: "SE-G", territory: TerritoryCode::SE, category: "county", name: "Kronoberg" },
+    Subdivision { code: "SE-H", territory: TerritoryCode::SE, category: "county", name: "Kalmar" },
+    Subdivision { code: "SE-I", territory: TerritoryCode::SE, category: "county", name: "Gotland" },
+    Subdivision { code: "SE-K", territory: TerritoryCode::SE, category: "county", name: "Blekinge" },
+    Subdivision { code: "SE-M", territory: TerritoryCode::SE, category: "county", name: "Skåne" },
+    Subdivision { code: "SE-N", territory: TerritoryCode::SE, category: "county", name: "Halland" },
+    Subdivision { code: "SE-O", territory: TerritoryCode::SE, category: "county", name: "Västra Götaland" },
+    Subdivision { code: "SE-S", territory: TerritoryCode::SE, category: "county", name: "Värmland" },
+    Subdivision { code: "SE-T", territory: TerritoryCode::SE, category: "county", name: "Örebro" },
+    Subdivision { code: "SE-U", territory: TerritoryCode::SE, category: "county", name: "Västmanland" },
+    Subdivision { code: "SE-W", territory: TerritoryCode::SE, category: "county", name: "Dalarna" },
+    Subdivision { code: "SE-X", territory: TerritoryCode::SE, category: "county", name: "Gävleborg" },
+    Subdivision { code: "SE-Y", territory: TerritoryCode::SE, category: "county", name: "Västernorrland" },
+    Subdivision { code: "SE-Z", territory: TerritoryCode::SE, category: "county", name: "Jämtland" },
+    Subdivision { code: "SG-01", territory: TerritoryCode::SG, category: "district", name: "Central Singapore" },
+    Subdivision { code: "SG-02", territory: TerritoryCode::SG, category: "district", name: "North East" },
+    Subdivision { code: "SG-03", territory: TerritoryCode::SG, category: "district", name: "North West" },
+    Subdivision { code: "SG-04", territory: TerritoryCode::SG, category: "district", name: "South East" },
+    Subdivision { code: "SG-05", territory: TerritoryCode::SG, category: "district", name: "South West" },
+    Subdivision { code: "SH-HL", territory: TerritoryCode::SH, category: "geographical entity", name: "Saint Helena" },
+    Subdivision { code: "SH-TA", territory: TerritoryCode::SH, category: "geographical entity", name: "Tristan da Cunha" },
+    Subdivision { code: "SK-BC", territory: TerritoryCode::SK, category: "region", name: "Banská Bystrica" },
+    Subdivision { code: "SK-BL", territory: TerritoryCode::SK, category: "region", name: "Bratislava" },
+    Subdivision { code: "SK-KI", territory: TerritoryCode::SK, category: "region", name: "Košice" },
+    Subdivision { code: "SK-NI", territory: TerritoryCode::SK, category: "region", name: "Nitra" },
+    Subdivision { code: "SK-PV", territory: TerritoryCode::SK, category: "region", name: "Prešov" },
+    Subdivision { code: "SK-TA", territory: TerritoryCode::SK, category: "region", name: "Trnava" },
+    Subdivision { code: "SK-TC", territory: TerritoryCode::SK, category: "region", name: "Trenčín" },
+    Subdivision { code: "SK-ZI", territory: TerritoryCode::SK, category: "region", name: "Žilina" },
+    Subdivision { code: "SL-E", territory: TerritoryCode::SL, category: "province", name: "Eastern" },
+    Subdivision { code: "SL-N", territory: TerritoryCode::SL, category: "province", name: "Northern" },
+    Subdivision { code: "SL-NW", territory: TerritoryCode::SL, category: "province", name: "North Western" },
+    Subdivision { code: "SL-S", territory: TerritoryCode::SL, category: "province", name: "Southern" },
+    Subdivision { code: "SL-W", territory: TerritoryCode::SL, category: "area", name: "Western Area" },
+    Subdivision { code: "SM-01", territory: TerritoryCode::SM, category: "municipality", name: "Acquaviva" },
+    Subdivision { code: "SM-02", territory: TerritoryCode::SM, category: "municipality", name: "Chiesanuova" },
+    Subdivision { code: "SM-03", territory: TerritoryCode::SM, category: "municipality", name: "Domagnano" },
+    Subdivision { code: "SM-04", territory: TerritoryCode::SM, category: "municipality", name: "Faetano" },
+    Subdivision { code: "SM-05", territory: TerritoryCode::SM, category: "municipality", name: "Fiorentino" },
+    Subdivision { code: "SM-06", territory: TerritoryCode::SM, category: "municipality", name: "Borgo Maggiore" },
+    Subdivision { code: "SM-07", territory: TerritoryCode::SM, category: "municipality", name: "City of San Marino" },
+    Subdivision { code: "SM-08", territory: TerritoryCode::SM, category: "municipality", name: "Montegiardino" },
+    Subdivision { code: "SM-09", territory: TerritoryCode::SM, category: "municipality", name: "Serravalle" },
+    Subdivision { code: "SN-DB", territory: TerritoryCode::SN, category: "region", name: "Diourbel" },
+    Subdivision { code: "SN-DK", territory: TerritoryCode::SN, category: "region", name: "Dakar" },
+    Subdivision { code: "SN-FK", territory: TerritoryCode::SN, category: "region", name: "Fatick" },
+    Subdivision { code: "SN-KA", territory: TerritoryCode::SN, category: "region", name: "Kaffrine" },
+    Subdivision { code: "SN-KD", territory: TerritoryCode::SN, category: "region", name: "Kolda" },
+    Subdivision { code: "SN-KE", territory: TerritoryCode::SN, category: "region", name: "Kédougou" },
+    Subdivision { code: "SN-KL", territory: TerritoryCode::SN, category: "region", name: "Kaolack" },
+    Subdivision { code: "SN-LG", territory: TerritoryCode::SN, category: "region", name: "Louga" },
+    Subdivision { code: "SN-MT", territory: TerritoryCode::SN, category: "region", name: "Matam" },
+    Subdivision { code: "SN-SE", territory: TerritoryCode::SN, category: "region", name: "Sédhiou" },
+    Subdivision { code: "SN-SL", territory: TerritoryCode::SN, category: "region", name: "Saint-Louis" },
+    Subdivision { code: "SN-TC", territory: TerritoryCode::SN, category: "region", name: "Tambacounda" },
+    Subdivision { code: "SN-TH", territory: TerritoryCode::SN, category: "region", name: "Thiès" },
+    Subdivision { code: "SN-ZG", territory: TerritoryCode::SN, category: "region", name: "Ziguinchor" },
+    Subdivision { code: "SO-AW", territory: TerritoryCode::SO, category: "region", name: "Awdal" },
+    Subdivision { code: "SO-BK", territory: TerritoryCode::SO, category: "region", name: "Bakool" },
+    Subdivision { code: "SO-BN", territory: TerritoryCode::SO, category: "region", name: "Banaadir" },
+    Subdivision { code: "SO-BR", territory: TerritoryCode::SO, category: "region", name: "Bari" },
+    Subdivision { code: "SO-BY", territory: TerritoryCode::SO, category: "region", name: "Bay" },
+    Subdivision { code: "SO-GA", territory: TerritoryCode::SO, category: "region", name: "Galguduud" },
+    Subdivision { code: "SO-GE", territory: TerritoryCode::SO, category: "region", name: "Gedo" },
+    Subdivision { code: "SO-HI", territory: TerritoryCode::SO, category: "region", name: "Hiiraan" },
+    Subdivision { code: "SO-JD", territory: TerritoryCode::SO, category: "region", name: "Middle Juba" },
+    Subdivision { code: "SO-JH", territory: TerritoryCode::SO, category: "region", name: "Lower Juba" },
+    Subdivision { code: "SO-MU", territory: TerritoryCode::SO, category: "region", name: "Mudug" },
+    Subdivision { code: "SO-NU", territory: TerritoryCode::SO, category: "region", name: "Nugaal" },
+    Subdivision { code: "SO-SA", territory: TerritoryCode::SO, category: "region", name: "Sanaag" },
+    Subdivision { code: "SO-SD", territory: TerritoryCode::SO, category: "region", name: "Middle Shabelle" },
+    Subdivision { code: "SO-SH", territory: TerritoryCode::SO, category: "region", name: "Lower Shabelle" },
+    Subdivision { code: "SO-SO", territory: TerritoryCode::SO, category: "region", name: "Sool" },
+    Subdivision { code: "SO-TO", territory: TerritoryCode::SO, category: "region", name: "Togdheer" },
+    Subdivision { code: "SO-WO", territory: TerritoryCode::SO, category: "region", name: "Woqooyi Galbeed" },
+    Subdivision { code: "SR-BR", territory: TerritoryCode::SR, category: "district", name: "Brokopondo" },
+    Subdivision { code: "SR-CM", territory: TerritoryCode::SR, category: "district", name: "Commewijne" },
+    Subdivision { code: "SR-CR", territory: TerritoryCode::SR, category: "district", name: "Coronie" },
+    Subdivision { code: "SR-MA", territory: TerritoryCode::SR, category: "district", name: "Marowijne" },
+    Subdivision { code: "SR-NI", territory: TerritoryCode::SR, category: "district", name: "Nickerie" },
+    Subdivision { code: "SR-PM", territory: TerritoryCode::SR, category: "district", name: "Paramaribo" },
+    Subdivision { code: "SR-PR", territory: TerritoryCode::SR, category: "district", name: "Para" },
+    Subdivision { code: "SR-SA", territory: TerritoryCode::SR, category: "district", name: "Saramacca" },
+    Subdivision { code: "SR-SI", territory: TerritoryCode::SR, category: "district", name: "Sipaliwini" },
+    Subdivision { code: "SR-WA", territory: TerritoryCode::SR, category: "district", name: "Wanica" },
+    Subdivision { code: "SS-BN", territory: TerritoryCode::SS, category: "state", name: "Northern Bahr el Ghazal" },
+    Subdivision { code: "SS-BW", territory: TerritoryCode::SS, category: "state", name: "Western Bahr el Ghazal" },
+    Subdivision { code: "SS-EC", territory: TerritoryCode::SS, category: "state", name: "Central Equatoria" },
+    Subdivision { code: "SS-EE", territory: TerritoryCode::SS, category: "state", name: "Eastern Equatoria" },
+    Subdivision { code: "SS-EW", territory: TerritoryCode::SS, category: "state", name: "Western Equatoria" },
+    Subdivision { code: "SS-JG", territory: TerritoryCode::SS, category: "state", name: "Jonglei" },
+    Subdivision { code: "SS-LK", territory: TerritoryCode::SS, category: "state", name: "Lakes" },
+    Subdivision { code: "SS-NU", territory: TerritoryCode::SS, category: "state", name: "Upper Nile" },
+    Subdivision { code: "SS-UY", territory: TerritoryCode::SS, category: "state", name: "Unity" },
+    Subdivision { code: "SS-WR", territory: TerritoryCode::SS, category: "state", name: "Warrap" },
+    Subdivision { code: "SV-AH", territory: TerritoryCode::SV, category: "department", name: "Ahuachapán" },
+    Subdivision { code: "SV-CA", territory: TerritoryCode::SV, category: "department", name: "Cabañas" },
+    Subdivision { code: "SV-CH", territory: TerritoryCode::SV, category: "department", name: "Chalatenango" },
+    Subdivision { code: "SV-CU", territory: TerritoryCode::SV, category: "department", name: "Cuscatlán" },
+    Subdivision { code: "SV-LI", territory: TerritoryCode::SV, category: "department", name: "La Libertad" },
+    Subdivision { code: "SV-MO", territory: TerritoryCode::SV, category: "department", name: "Morazán" },
+    Subdivision { code: "SV-PA", territory: TerritoryCode::SV, category: "department", name: "La Paz" },
+    Subdivision { code: "SV-SA", territory: TerritoryCode::SV, category: "department", name: "Santa Ana" },
+    Subdivision { code: "SV-SM", territory: TerritoryCode::SV, category: "department", name: "San Miguel" },
+    Subdivision { code: "SV-SO", territory: TerritoryCode::SV, category: "department", name: "Sonsonate" },
+    Subdivision { code: "SV-SS", territory: TerritoryCode::SV, category: "department", name: "San Salvador" },
+    Subdivision { code: "SV-SV", territory: TerritoryCode::SV, category: "department", name: "San Vicente" },
+    Subdivision { code: "SV-UN", territory: TerritoryCode::SV, category: "department", name: "La Unión" },
+    Subdivision { code: "SV-US", territory: TerritoryCode::SV, category: "department", name: "Usulután" },
+    Subdivision { code: "SY-DI", territory: TerritoryCode::SY, category: "governorate", name: "Damascus" },
+    Subdivision { code: "SY-DR", territory: TerritoryCode::SY, category: "governorate", name: "Daraa" },
+    Subdivision { code: "SY-DY", territory: TerritoryCode::SY, category: "governorate", name: "Deir ez-Zor" },
+    Subdivision { code: "SY-HA", territory: TerritoryCode::SY, category: "governorate", name: "Al-Hasakah" },
+    Subdivision { code: "SY-HI", territory: TerritoryCode::SY, category: "governorate", name: "Homs" },
+    Subdivision { code: "SY-HL", territory: TerritoryCode::SY, category: "governorate", name: "Aleppo" },
+    Subdivision { code: "SY-HM", territory: TerritoryCode::SY, category: "governorate", name: "Hama" },
+    Subdivision { code: "SY-ID", territory: TerritoryCode::SY, category: "governorate", name: "Idlib" },
+    Subdivision { code: "SY-LA", territory: TerritoryCode::SY, category: "governorate", name: "Latakia" },
+    Subdivision { code: "SY-QU", territory: TerritoryCode::SY, category: "governorate", name: "Quneitra" },
+    Subdivision { code: "SY-RA", territory: TerritoryCode::SY, category: "governorate", name: "Raqqa" },
+    Subdivision { code: "SY-RD", territory: TerritoryCode::SY, category: "governorate", name: "Rif Dimashq" },
+    Subdivision { code: "SY-SU", territory: TerritoryCode::SY, category: "governorate", name: "As-Suwayda" },
+    Subdivision { code: "SY-TA", territory: TerritoryCode::SY, category: "governorate", name: "Tartus" },
+    Subdivision { code: "SZ-HH", territory: TerritoryCode::SZ, category: "region", name: "Hhohho" },
+    Subdivision { code: "SZ-LU", territory: TerritoryCode::SZ, category: "region", name: "Lubombo" },
+    Subdivision { code: "SZ-MA", territory: TerritoryCode::SZ, category: "region", name: "Manzini" },
+    Subdivision { code: "SZ-SH", territory: TerritoryCode::SZ, category: "region", name: "Shiselweni" },
+    Subdivision { code: "TD-BA", territory: TerritoryCode::TD, category: "province", name: "Batha" },
+    Subdivision { code: "TD-BG", territory: TerritoryCode::TD, category: "province", name: "Bahr el Gazel" },
+    Subdivision { code: "TD-BO", territory: TerritoryCode::TD, category: "province", name: "Borkou" },
+    Subdivision { code: "TD-CB", territory: TerritoryCode::TD, category: "province", name: "Chari-Baguirmi" },
+    Subdivision { code: "TD-EE", territory: TerritoryCode::TD, category: "province", name: "Ennedi-Est" },
+    Subdivision { code: "TD-EO", territory: TerritoryCode::TD, category: "province", name: "Ennedi-Ouest" },
+    Subdivision { code: "TD-GR", territory: TerritoryCode::TD, category: "province", name: "Guéra" },
+    Subdivision { code: "TD-HL", territory: TerritoryCode::TD, category: "province", name: "Hadjer-Lamis" },
+    Subdivision { code: "TD-KA", territory: TerritoryCode::TD, category: "province", name: "Kanem" },
+    Subdivision { code: "TD-LC", territory: TerritoryCode::TD, category: "province", name: "Lac" },
+    Subdivision { code: "TD-LO", territory: TerritoryCode::TD, category: "province", name: "Logone Occidental" },
+    Subdivision { code: "TD-LR", territory: TerritoryCode::TD, category: "province", name: "Logone Oriental" },
+    Subdivision { code: "TD-MA", territory: TerritoryCode::TD, category: "province", name: "Mandoul" },
+    Subdivision { code: "TD-MC", territory: TerritoryCode::TD, category: "province", name: "Moyen-Chari" },
+    Subdivision { code: "TD-ME", territory: TerritoryCode::TD, category: "province", name: "Mayo-Kebbi Est" },
+    Subdivision { code: "TD-MO", territory: TerritoryCode::TD, category: "province", name: "Mayo-Kebbi Ouest" },
+    Subdivision { code: "TD-ND", territory: TerritoryCode::TD, category: "capital", name: "N'Djamena" },
+    Subdivision { code: "TD-OD", territory: TerritoryCode::TD, category: "province", name: "Ouaddaï" },
+    Subdivision { code: "TD-SA", territory: TerritoryCode::TD, category: "province", name: "Salamat" },
+    Subdivision { code: "TD-SI", territory: TerritoryCode::TD, category: "province", name: "Sila" },
+    Subdivision { code: "TD-TA", territory: TerritoryCode::TD, category: "province", name: "Tandjilé" },
+    Subdivision { code: "TD-TI", territory: TerritoryCode::TD, category: "province", name: "Tibesti" },
+    Subdivision { code: "TD-WF", territory: TerritoryCode::TD, category: "province", name: "Wadi Fira" },
+    Subdivision { code: "TG-C", territory: TerritoryCode::TG, category: "region", name: "Centrale" },
+    Subdivision { code: "TG-K", territory: TerritoryCode::TG, category: "region", name: "Kara" },
+    Subdivision { code: "TG-M", territory: TerritoryCode::TG, category: "region", name: "Maritime" },
+    Subdivision { code: "TG-P", territory: TerritoryCode::TG, category: "region", name: "Plateaux" },
+    Subdivision { code: "TG-S", territory: TerritoryCode::TG, category: "region", name: "Savanes" },
+    Subdivision { code: "TH-10", territory: TerritoryCode::TH, category: "metropolitan administration", name: "Bangkok" },
+    Subdivision { code: "TH-11", territory: TerritoryCode::TH, category: "province", name: "Samut Prakan" },
+    Subdivision { code: "TH-12", territory: TerritoryCode::TH, category: "province", name: "Nonthaburi" },
+    Subdivision { code: "TH-13", territory: TerritoryCode::TH, category: "province", name: "Pathum Thani" },
+    Subdivision { code: "TH-14", territory: TerritoryCode::TH, category: "province", name: "Phra Nakhon Si Ayutthaya" },
+    Subdivision { code: "TH-15", territory: TerritoryCode::TH, category: "province", name: "Ang Thong" },
+    Subdivision { code: "TH-16", territory: TerritoryCode::TH, category: "province", name: "Lopburi" },
+    Subdivision { code: "TH-17", territory: TerritoryCode::TH, category: "province", name: "Sing Buri" },
+    Subdivision { code: "TH-18", territory: TerritoryCode::TH, category: "province", name: "Chai Nat" },
+    Subdivision { code: "TH-19", territory: TerritoryCode::TH, category: "province", name: "Saraburi" },
+    Subdivision { code: "TH-20", territory: TerritoryCode::TH, category: "province", name: "Chonburi" },
+    Subdivision { code: "TH-21", territory: TerritoryCode::TH, category: "province", name: "Rayong" },
+    Subdivision { code: "TH-22", territory: TerritoryCode::TH, category: "province", name: "Chanthaburi" },
+    Subdivision { code: "TH-23", territory: TerritoryCode::TH, category: "province", name: "Trat" },
+    Subdivision { code: "TH-24", territory: TerritoryCode::TH, category: "province", name: "Chachoengsao" },
+    Subdivision { code: "TH-25", territory: TerritoryCode::TH, category: "province", name: "Prachinburi" },
+    Subdivision { code: "TH-26", territory: TerritoryCode::TH, category: "province", name: "Nakhon Nayok" },
+    Subdivision { code: "TH-27", territory: TerritoryCode::TH, category: "province", name: "Sa Kaeo" },
+    Subdivision { code: "TH-30", territory: TerritoryCode::TH, category: "province", name: "Nakhon Ratchasima" },
+    Subdivision { code: "TH-31", territory: TerritoryCode::TH, category: "province", name: "Buriram" },
+    Subdivision { code: "TH-32", territory: TerritoryCode::TH, category: "province", name: "Surin" },
+    Subdivision { code: "TH-33", territory: TerritoryCode::TH, category: "province", name: "Sisaket" },
+    Subdivision { code: "TH-34", territory: TerritoryCode::TH, category: "province", name: "Ubon Ratchathani" },
+    Subdivision { code: "TH-35", territory: TerritoryCode::TH, category: "province", name: "Yasothon" },
+    Subdivision { code: "TH-36", territory: TerritoryCode::TH, category: "province", name: "Chaiyaphum" },
+    Subdivision { code: "TH-37", territory: TerritoryCode::TH, category: "province", name: "Amnat Charoen" },
+    Subdivision { code: "TH-38", territory: TerritoryCode::TH, category: "province", name: "Bueng Kan" },
+    Subdivision { code: "TH-39", territory: TerritoryCode::TH, category: "province", name: "Nong Bua Lamphu" },
+    Subdivision { code: "TH-40", territory: TerritoryCode::TH, category: "province", name: "Khon Kaen" },
+    Subdivision { code: "TH-41", territory: TerritoryCode::TH, category: "province", name: "Udon Thani" },
+    Subdivision { code: "TH-42", territory: TerritoryCode::TH, category: "province", name: "Loei" },
+    Subdivision { code: "TH-43", territory: TerritoryCode::TH, category: "province", name: "Nong Khai" },
+    Subdivision { code: "TH-44", territory: TerritoryCode::TH, category: "province", name: "Maha Sarakham" },
+    Subdivision { code: "TH-45", territory: TerritoryCode::TH, category: "province", name: "Roi Et" },
+    Subdivision { code: "TH-46", territory: TerritoryCode::TH, category: "province", name: "Kalasin" },
+    Subdivision { code: "TH-47", territory: TerritoryCode::TH, category: "province", name: "Sakon Nakhon" },
+    Subdivision { code: "TH-48", territory: TerritoryCode::TH, category: "province", name: "Nakhon Phanom" },
+    Subdivision { code: "TH-49", territory: TerritoryCode::TH, category: "province", name: "Mukdahan" },
+    Subdivision { code: "TH-50", territory: TerritoryCode::TH, category: "province", name: "Chiang Mai" },
+    Subdivision { code: "TH-51", territory: TerritoryCode::TH, category: "province", name: "Lamphun" },
+    Subdivision { code: "TH-52", territory: TerritoryCode::TH, category: "province", name: "Lampang" },
+    Subdivision { code: "TH-53", territory: TerritoryCode::TH, category: "province", name: "Uttaradit" },
+    Subdivision { code: "TH-54", territory: TerritoryCode::TH, category: "province", name: "Phrae" },
+    Subdivision { code: "TH-55", territory: TerritoryCode::TH, category: "province", name: "Nan" },
+    Subdivision { code: "TH-56", territory: TerritoryCode::TH, category: "province", name: "Phayao" },
+    Subdivision { code: "TH-57", territory: TerritoryCode::TH, category: "province", name: "Chiang Rai" },
+    Subdivision { code: "TH-58", territory: TerritoryCode::TH, category: "province", name: "Mae Hong Son" },
+    Subdivision { code: "TH-60", territory: TerritoryCode::TH, category: "province", name: "Nakhon Sawan" },
+    Subdivision { code: "TH-61", territory: TerritoryCode::TH, category: "province", name: "Uthai Thani" },
+    Subdivision { code: "TH-62", territory: TerritoryCode::TH, category: "province", name: "Kamphaeng Phet" },
+    Subdivision { code: "TH-63", territory: TerritoryCode::TH, category: "province", name: "Tak" },
+    Subdivision { code: "TH-64", territory: TerritoryCode::TH, category: "province", name: "Sukhothai" },
+    Subdivision { code: "TH-65", territory: TerritoryCode::TH, category: "province", name: "Phitsanulok" },
+    Subdivision { code: "TH-66", territory: TerritoryCode::TH, category: "province", name: "Phichit" },
+    Subdivision { code: "TH-67", territory: TerritoryCode::TH, category: "province", name: "Phetchabun" },
+    Subdivision { code: "TH-70", territory: TerritoryCode::TH, category: "province", name: "Ratchaburi" },
+    Subdivision { code: "TH-71", territory: TerritoryCode::TH, category: "province", name: "Kanchanaburi" },
+    Subdivision { code: "TH-72", territory: TerritoryCode::TH, category: "province", name: "Suphan Buri" },
+    Subdivision { code: "TH-73", territory: TerritoryCode::TH, category: "province", name: "Nakhon Pathom" },
+    Subdivision { code: "TH-74", territory: TerritoryCode::TH, category: "province", name: "Samut Sakhon" },
+    Subdivision { code: "TH-75", territory: TerritoryCode::TH, category: "province", name: "Samut Songkhram" },
+    Subdivision { code: "TH-76", territory: TerritoryCode::TH, category: "province", name: "Phetchaburi" },
+    Subdivision { code: "TH-77", territory: TerritoryCode::TH, category: "province", name: "Prachuap Khiri Khan" },
+    Subdivision { code: "TH-80", territory: TerritoryCode::TH, category: "province", name: "Nakhon Si Thammarat" },
+    Subdivision { code: "TH-81", territory: TerritoryCode::TH, category: "province", name: "Krabi" },
+    Subdivision { code: "TH-82", territory: TerritoryCode::TH, category: "province", name: "Phang Nga" },
+    Subdivision { code: "TH-83", territory: TerritoryCode::TH, category: "province", name: "Phuket" },
+    Subdivision { code: "TH-84", territory: TerritoryCode::TH, category: "province", name: "Surat Thani" },
+    Subdivision { code: "TH-85", territory: TerritoryCode::TH, category: "province", name: "Ranong" },
+    Subdivision { code: "TH-86", territory: TerritoryCode::TH, category: "province", name: "Chumphon" },
+    Subdivision { code: "TH-90", territory: TerritoryCode::TH, category: "province", name: "Songkhla" },
+    Subdivision { code: "TH-91", territory: TerritoryCode::TH, category: "province", name: "Satun" },
+    Subdivision { code: "TH-92", territory: TerritoryCode::TH, category: "province", name: "Trang" },
+    Subdivision { code: "TH-93", territory: TerritoryCode::TH, category: "province", name: "Phatthalung" },
+    Subdivision { code: "TH-94", territory: TerritoryCode::TH, category: "province", name: "Pattani" },
+    Subdivision { code: "TH-95", territory: TerritoryCode::TH, category: "province", name: "Yala" },
+    Subdivision { code: "TH-96", territory: TerritoryCode::TH, category: "province", name: "Narathiwat" },
+    Subdivision { code: "TH-S", territory: TerritoryCode::TH, category: "special administrative city", name: "Pattaya" },
+    Subdivision { code: "TJ-DU", territory: TerritoryCode::TJ, category: "capital territory", name: "Dushanbe" },
+    Subdivision { code: "TJ-GB", territory: TerritoryCode::TJ, category: "autonomous region", name: "Gorno-Badakhshan" },
+    Subdivision { code: "TJ-KT", territory: TerritoryCode::TJ, category: "region", name: "Khatlon" },
+    Subdivision { code: "TJ-RA", territory: TerritoryCode::TJ, category: "districts under republic administration", name: "Districts of Republican Subordination" },
+    Subdivision { code: "TJ-SU", territory: TerritoryCode::TJ, category: "region", name: "Sughd" },
+    Subdivision { code: "TL-AL", territory: TerritoryCode::TL, category: "municipality", name: "Aileu" },
+    Subdivision { code: "TL-AN", territory: TerritoryCode::TL, category: "municipality", name: "Ainaro" },
+    Subdivision { code: "TL-BA", territory: TerritoryCode::TL, category: "municipality", name: "Baucau" },
+    Subdivision { code: "TL-BO", territory: TerritoryCode::TL, category: "municipality", name: "Bobonaro" },
+    Subdivision { code: "TL-CO", territory: TerritoryCode::TL, category: "municipality", name: "Cova Lima" },
+    Subdivision { code: "TL-DI", territory: TerritoryCode::TL, category: "municipality", name: "Dili" },
+    Subdivision { code: "TL-ER", territory: TerritoryCode::TL, category: "municipality", name: "Ermera" },
+    Subdivision { code: "TL-LA", territory: TerritoryCode::TL, category: "municipality", name: "Lautém" },
+    Subdivision { code: "TL-LI", territory: TerritoryCode::TL, category: "municipality", name: "Liquiçá" },
+    Subdivision { code: "TL-MF", territory: TerritoryCode::TL, category: "municipality", name: "Manufahi" },
+    Subdivision { code: "TL-MT", territory: TerritoryCode::TL, category: "municipality", name: "Manatuto" },
+    Subdivision { code: "TL-OE", territory: TerritoryCode::TL, category: "special administrative region", name: "Oecusse" },
+    Subdivision { code: "TL-VI", territory: TerritoryCode::TL, category: "municipality", name: "Viqueque" },
+    Subdivision { code: "TM-A", territory: TerritoryCode::TM, category: "region", name: "Ahal" },
+    Subdivision { code: "TM-B", territory: TerritoryCode::TM, category: "region", name: "Balkan" },
+    Subdivision { code: "TM-D", territory: TerritoryCode::TM, category: "region", name: "Daşoguz" },
+    Subdivision { code: "TM-L", territory: TerritoryCode::TM, category: "region", name: "Lebap" },
+    Subdivision { code: "TM-M", territory: TerritoryCode::TM, category: "region", name: "Mary" },
+    Subdivision { code: "TM-S", territory: TerritoryCode::TM, category: "city", name: "Ashgabat" },
+    Subdivision { code: "TN-11", territory: TerritoryCode::TN, category: "governorate", name: "Tunis" },
+    Subdivision { code: "TN-12", territory: TerritoryCode::TN, category: "governorate", name: "Ariana" },
+    Subdivision { code: "TN-13", territory: TerritoryCode::TN, category: "governorate", name: "Ben Arous" },
+    Subdivision { code: "TN-14", territory: TerritoryCode::TN, category: "governorate", name: "Manouba" },
+    Subdivision { code: "TN-21", territory: TerritoryCode::TN, category: "governorate", name: "Nabeul" },
+    Subdivision { code: "TN-22", territory: TerritoryCode::TN, category: "governorate", name: "Zaghouan" },
+    Subdivision { code: "TN-23", territory: TerritoryCode::TN, category: "governorate", name: "Bizerte" },
+    Subdivision { code: "TN-31", territory: TerritoryCode::TN, category: "governorate", name: "Béja" },
+    Subdivision { code: "TN-32", territory: TerritoryCode::TN, category: "governorate", name: "Jendouba" },
+    Subdivision { code: "TN-33", territory: TerritoryCode::TN, category: "governorate", name: "Kef" },
+    Subdivision { code: "TN-34", territory: TerritoryCode::TN, category: "governorate", name: "Siliana" },
+    Subdivision { code: "TN-41", territory: TerritoryCode::TN, category: "governorate", name: "Kairouan" },
+    Subdivision { code: "TN-42", territory: TerritoryCode::TN, category: "governorate", name: "Kasserine" },
+    Subdivision { code: "TN-43", territory: TerritoryCode::TN, category: "governorate", name: "Sidi Bouzid" },
+    Subdivision { code: "TN-51", territory: TerritoryCode::TN, category: "governorate", name: "Sousse" },
+    Subdivision { code: "TN-52", territory: TerritoryCode::TN, category: "governorate", name: "Monastir" },
+    Subdivision { code: "TN-53", territory: TerritoryCode::TN, category: "governorate", name: "Mahdia" },
+    Subdivision { code: "TN-61", territory: TerritoryCode::TN, category: "governorate", name: "Sfax" },
+    Subdivision { code: "TN-71", territory: TerritoryCode::TN, category: "governorate", name: "Gafsa" },
+    Subdivision { code: "TN-72", territory: TerritoryCode::TN, category: "governorate", name: "Tozeur" },
+    Subdivision { code: "TN-73", territory: TerritoryCode::TN, category: "governorate", name: "Kebili" },
+    Subdivision { code: "TN-81", territory: TerritoryCode::TN, category: "governorate", name: "Gabès" },
+    Subdivision { code: "TN-82", territory: TerritoryCode::TN, category: "governorate", name: "Medenine" },
+    Subdivision { code: "TN-83", territory: TerritoryCode::TN, category: "governorate", name: "Tataouine" },
+    Subdivision { code: "TO-01", territory: TerritoryCode::TO, category: "division", name: "ʻEua" },
+    Subdivision { code: "TO-02", territory: TerritoryCode::TO, category: "division", name: "Haʻapai" },
+    Subdivision { code: "TO-03", territory: TerritoryCode::TO, category: "division", name: "Niuas" },
+    Subdivision { code: "TO-04", territory: TerritoryCode::TO, category: "division", name: "Tongatapu" },
+    Subdivision { code: "TO-05", territory: TerritoryCode::TO, category: "division", name: "Vavaʻu" },
+    Subdivision { code: "TR-01", territory: TerritoryCode::TR, category: "province", name: "Adana" },
+    Subdivision { code: "TR-02", territory: TerritoryCode::TR, category: "province", name: "Adıyaman" },
+    Subdivision { code: "TR-03", territory: TerritoryCode::TR, category: "province", name: "Afyonkarahisar" },
+    Subdivision { code: "TR-04", territory: TerritoryCode::TR, category: "province", name: "Ağrı" },
+    Subdivision { code: "TR-05", territory: TerritoryCode::TR, category: "province", name: "Amasya" },
+    Subdivision { code: "TR-06", territory: TerritoryCode::TR, category: "province", name: "Ankara" },
+    Subdivision { code: "TR-07", territory: TerritoryCode::TR, category: "province", name: "Antalya" },
+    Subdivision { code: "TR-08", territory: TerritoryCode::TR, category: "province", name: "Artvin" },
+    Subdivision { code: "TR-09", territory: TerritoryCode::TR, category: "province", name: "Aydın" },
+    Subdivision { code: "TR-10", territory: TerritoryCode::TR, category: "province", name: "Balıkesir" },
+    Subdivision { code: "TR-11", territory: TerritoryCode::TR, category: "province", name: "Bilecik" },
+    Subdivision { code: "TR-12", territory: TerritoryCode::TR, category: "province", name: "Bingöl" },
+    Subdivision { code: "TR-13", territory: TerritoryCode::TR, category: "province", name: "Bitlis" },
+    Subdivision { code: "TR-14", territory: TerritoryCode::TR, category: "province", name: "Bolu" },
+    Subdivision { code: "TR-15", territory: TerritoryCode::TR, category: "province", name: "Burdur" },
+    Subdivision { code: "TR-16", territory: TerritoryCode::TR, category: "province", name: "Bursa" },
+    Subdivision { code: "TR-17", territory: TerritoryCode::TR, category: "province", name: "Çanakkale" },
+    Subdivision { code: "TR-18", territory: TerritoryCode::TR, category: "province", name: "Çankırı" },
+    Subdivision { code: "TR-19", territory: TerritoryCode::TR, category: "province", name: "Çorum" },
+    Subdivision { code: "TR-20", territory: TerritoryCode::TR, category: "province", name: "Denizli" },
+    Subdivision { code: "TR-21", territory: TerritoryCode::TR, category: "province", name: "Diyarbakır" },
+    Subdivision { code: "TR-22", territory: TerritoryCode::TR, category: "province", name: "Edirne" },
+    Subdivision { code: "TR-23", territory: TerritoryCode::TR, category: "province", name: "Elazığ" },
+    Subdivision { code: "TR-24", territory: TerritoryCode::TR, category: "province", name: "Erzincan" },
+    Subdivision { code: "TR-25", territory: TerritoryCode::TR, category: "province", name: "Erzurum" },
+    Subdivision { code: "TR-26", territory: TerritoryCode::TR, category: "province", name: "Eskişehir" },
+    Subdivision { code: "TR-27", territory: TerritoryCode::TR, category: "province", name: "Gaziantep" },
+    Subdivision { code: "TR-28", territory: TerritoryCode::TR, category: "province", name: "Giresun" },
+    Subdivision { code: "TR-29", territory: TerritoryCode::TR, category: "province", name: "Gümüşhane" },
+    Subdivision { code: "TR-30", territory: TerritoryCode::TR, category: "province", name: "Hakkâri" },
+    Subdivision { code: "TR-31", territory: TerritoryCode::TR, category: "province", name: "Hatay" },
+    Subdivision { code: "TR-32", territory: TerritoryCode::TR, category: "province", name: "Isparta" },
+    Subdivision { code: "TR-33", territory: TerritoryCode::TR, category: "province", name: "Mersin" },
+    Subdivision { code: "TR-34", territory: TerritoryCode::TR, category: "province", name: "Istanbul" },
+    Subdivision { code: "TR-35", territory: TerritoryCode::TR, category: "province", name: "İzmir" },
+    Subdivision { code: "TR-36", territory: TerritoryCode::TR, category: "province", name: "Kars" },
+    Subdivision { code: "TR-37", territory: TerritoryCode::TR, category: "province", name: "Kastamonu" },
+    Subdivision { code: "TR-38", territory: TerritoryCode::TR, category: "province", name: "Kayseri" },
+    Subdivision { code: "TR-39", territory: TerritoryCode::TR, category: "province", name: "Kırklareli" },
+    Subdivision { code: "TR-40", territory: TerritoryCode::TR, category: "province", name: "Kırşehir" },
+    Subdivision { code: "TR-41", territory: TerritoryCode::TR, category: "province", name: "Kocaeli" },
+    Subdivision { code: "TR-42", territory: TerritoryCode::TR, category: "province", name: "Konya" },
+    Subdivision { code: "TR-43", territory: TerritoryCode::TR, category: "province", name: "Kütahya" },
+    Subdivision { code: "TR-44", territory: TerritoryCode::TR, category: "province", name: "Malatya" },
+    Subdivision { code: "TR-45", territory: TerritoryCode::TR, category: "province", name: "Manisa" },
+    Subdivision { code: "TR-46", territory: TerritoryCode::TR, category: "province", name: "Kahramanmaraş" },
+    Subdivision { code: "TR-47", territory: TerritoryCode::TR, category: "province", name: "Mardin" },
+    Subdivision { code: "TR-48", territory: TerritoryCode::TR, category: "province", name: "Muğla" },
+    Subdivision { code: "TR-49", territory: TerritoryCode::TR, category: "province", name: "Muş" },
+    Subdivision { code: "TR-50", territory: TerritoryCode::TR, category: "province", name: "Nevşehir" },
+    Subdivision { code: "TR-51", territory: TerritoryCode::TR, category: "province", name: "Niğde" },
+    Subdivision { code: "TR-52", territory: TerritoryCode::TR, category: "province", name: "Ordu" },
+    Subdivision { code: "TR-53", territory: TerritoryCode::TR, category: "province", name: "Rize" },
+    Subdivision { code: "TR-54", territory: TerritoryCode::TR, category: "province", name: "Sakarya" },
+    Subdivision { code: "TR-55", territory: TerritoryCode::TR, category: "province", name: "Samsun" },
+    Subdivision { code: "TR-56", territory: TerritoryCode::TR, category: "province", name: "Siirt" },
+    Subdivision { code: "TR-57", territory: TerritoryCode::TR, category: "province", name: "Sinop" },
+    Subdivision { code: "TR-58", territory: TerritoryCode::TR, category: "province", name: "Sivas" },
+    Subdivision { code: "TR-59", territory: TerritoryCode::TR, category: "province", name: "Tekirdağ" },
+    Subdivision { code: "TR-60", territory: TerritoryCode::TR, category: "province", name: "Tokat" },
+    Subdivision { code: "TR-61", territory: TerritoryCode::TR, category: "province", name: "Trabzon" },
+    Subdivision { code: "TR-62", territory: TerritoryCode::TR, category: "province", name: "Tunceli" },
+    Subdivision { code: "TR-63", territory: TerritoryCode::TR, category: "province", name: "Şanlıurfa" },
+    Subdivision { code: "TR-64", territory: TerritoryCode::TR, category: "province", name: "Uşak" },
+    Subdivision { code: "TR-65", territory: TerritoryCode::TR, category: "province", name: "Van" },
+    Subdivision { code: "TR-66", territory: TerritoryCode::TR, category: "province", name: "Yozgat" },
+    Subdivision { code: "TR-67", territory: TerritoryCode::TR, category: "province", name: "Zonguldak" },
+    Subdivision { code: "TR-68", territory: TerritoryCode::TR, category: "province", name: "Aksaray" },
+    Subdivision { code: "TR-69", territory: TerritoryCode::TR, category: "province", name: "Bayburt" },
+    Subdivision { code: "TR-70", territory: TerritoryCode::TR, category: "province", name: "Karaman" },
+    Subdivision { code: "TR-71", territory: TerritoryCode::TR, category: "province", name: "Kırıkkale" },
+    Subdivision { code: "TR-72", territory: TerritoryCode::TR, category: "province", name: "Batman" },
+    Subdivision { code: "TR-73", territory: TerritoryCode::TR, category: "province", name: "Şırnak" },
+    Subdivision { code: "TR-74", territory: TerritoryCode::TR, category: "province", name: "Bartın" },
+    Subdivision { code: "TR-75", territory: TerritoryCode::TR, category: "province", name: "Ardahan" },
+    Subdivision { code: "TR-76", territory: TerritoryCode::TR, category: "province", name: "Iğdır" },
+    Subdivision { code: "TR-77", territory: TerritoryCode::TR, category: "province", name: "Yalova" },
+    Subdivision { code: "TR-78", territory: TerritoryCode::TR, category: "province", name: "Karabük" },
+    Subdivision { code: "TR-79", territory: TerritoryCode::TR, category: "province", name: "Kilis" },
+    Subdivision { code: "TR-80", territory: TerritoryCode::TR, category: "province", name: "Osmaniye" },
+    Subdivision { code: "TR-81", territory: TerritoryCode::TR, category: "province", name: "Düzce" },
+    Subdivision { code: "TT-ARI", territory: TerritoryCode::TT, category: "borough", name: "Arima" },
+    Subdivision { code: "TT-CHA", territory: TerritoryCode::TT, category: "borough", name: "Chaguanas" },
+    Subdivision { code: "TT-CTT", territory: TerritoryCode::TT, category: "region", name: "Couva-Tabaquite-Talparo" },
+    Subdivision { code: "TT-DMN", territory: TerritoryCode::TT, category: "region", name: "Diego Martin" },
+    Subdivision { code: "TT-MRC", territory: TerritoryCode::TT, category: "region", name: "Mayaro-Rio Claro" },
+    Subdivision { code: "TT-PED", territory: TerritoryCode::TT, category: "region", name: "Penal-Debe" },
+    Subdivision { code: "TT-POS", territory: TerritoryCode::TT, category: "city", name: "Port of Spain" },
+    Subdivision { code: "TT-PRT", territory: TerritoryCode::TT, category: "region", name: "Princes Town" },
+    Subdivision { code: "TT-PTF", territory: TerritoryCode::TT, category: "borough", name: "Point Fortin" },
+    Subdivision { code: "TT-SFO", territory: TerritoryCode::TT, category: "city", name: "San Fernando" },
+    Subdivision { code: "TT-SGE", territory: TerritoryCode::TT, category: "region", name: "Sangre Grande" },
+    Subdivision { code: "TT-SIP", territory: TerritoryCode::TT, category: "region", name: "Siparia" },
+    Subdivision { code: "TT-SJL", territory: TerritoryCode::TT, category: "region", name: "San Juan-Laventille" },
+    Subdivision { code: "TT-TOB", territory: TerritoryCode::TT, category: "ward", name: "Tobago" },
+    Subdivision { code: "TT-TUP", territory: TerritoryCode::TT, category: "region", name: "Tunapuna-Piarco" },
+    Subdivision { code: "TV-FUN", territory: TerritoryCode::TV, category: "town council", name: "Funafuti" },
+    Subdivision { code: "TV-NIT", territory: TerritoryCode::TV, category: "island council", name: "Niutao" },
+    Subdivision { code: "TV-NKF", territory: TerritoryCode::TV, category: "island council", name: "Nukufetau" },
+    Subdivision { code: "TV-NKL", territory: TerritoryCode::TV, category: "island council", name: "Nukulaelae" },
+    Subdivision { code: "TV-NMA", territory: TerritoryCode::TV, category: "island council", name: "Nanumea" },
+    Subdivision { code: "TV-NMG", territory: TerritoryCode::TV, category: "island council", name: "Nanumanga" },
+    Subdivision { code: "TV-NUI", territory: TerritoryCode::TV, category: "island council", name: "Nui" },
+    Subdivision { code: "TV-VAI", territory: TerritoryCode::TV, category: "island council", name: "Vaitupu" },
+    Subdivision { code: "TW-CHA", territory: TerritoryCode::TW, category: "county", name: "Changhua" },
+    Subdivision { code: "TW-CYI", territory: TerritoryCode::TW, category: "city", name: "Chiayi City" },
+    Subdivision { code: "TW-CYQ", territory: TerritoryCode::TW, category: "county", name: "Chiayi" },
+    Subdivision { code: "TW-HSQ", territory: TerritoryCode::TW, category: "county", name: "Hsinchu" },
+    Subdivision { code: "TW-HSZ", territory: TerritoryCode::TW, category: "city", name: "Hsinchu City" },
+    Subdivision { code: "TW-HUA", territory: TerritoryCode::TW, category: "county", name: "Hualien" },
+    Subdivision { code: "TW-ILA", territory: TerritoryCode::TW, category: "county", name: "Yilan" },
+    Subdivision { code: "TW-KEE", territory: TerritoryCode::TW, category: "city", name: "Keelung" },
+    Subdivision { code: "TW-KHH", territory: TerritoryCode::TW, category: "special municipality", name: "Kaohsiung" },
+    Subdivision { code: "TW-KIN", territory: TerritoryCode::TW, category: "county", name: "Kinmen" },
+    Subdivision { code: "TW-LIE", territory: TerritoryCode::TW, category: "county", name: "Lienchiang" },
+    Subdivision { code: "TW-MIA", territory: TerritoryCode::TW, category: "county", name: "Miaoli" },
+    Subdivision { code: "TW-NAN", territory: TerritoryCode::TW, category: "county", name: "Nantou" },
+    Subdivision { code: "TW-NWT", territory: TerritoryCode::TW, category: "special municipality", name: "New Taipei" },
+    Subdivision { code: "TW-PEN", territory: TerritoryCode::TW, category: "county", name: "Penghu" },
+    Subdivision { code: "TW-PIF", territory: TerritoryCode::TW, category: "county", name: "Pingtung" },
+    Subdivision { code: "TW-TAO", territory: TerritoryCode::TW, category: "special municipality", name: "Taoyuan" },
+    Subdivision { code: "TW-TNN", territory: TerritoryCode::TW, category: "special municipality", name: "Tainan" },
+    Subdivision { code: "TW-TPE", territory: TerritoryCode::TW, category: "special municipality", name: "Taipei" },
+    Subdivision { code: "TW-TTT", territory: TerritoryCode::TW, category: "county", name: "Taitung" },
+    Subdivision { code: "TW-TXG", territory: TerritoryCode::TW, category: "special municipality", name: "Taichung" },
+    Subdivision { code: "TW-YUN", territory: TerritoryCode::TW, category: "county", name: "Yunlin" },
+    Subdivision { code: "TZ-01", territory: TerritoryCode::TZ, category: "region", name: "Arusha" },
+    Subdivision { code: "TZ-02", territory: TerritoryCode::TZ, category: "region", name: "Dar es Salaam" },
+    Subdivision { code: "TZ-03", territory: TerritoryCode::TZ, category: "region", name: "Dodoma" },
+    Subdivision { code: "TZ-04", territory: TerritoryCode::TZ, category: "region", name: "Iringa" },
+    Subdivision { code: "TZ-05", territory: TerritoryCode::TZ, category: "region", name: "Kagera" },
+    Subdivision { code: "TZ-06", territory: TerritoryCode::TZ, category: "region", name: "Pemba North" },
+    Subdivision { code: "TZ-07", territory: TerritoryCode::TZ, category: "region", name: "Zanzibar North" },
+    Subdivision { code: "TZ-08", territory: TerritoryCode::TZ, category: "region", name: "Kigoma" },
+    Subdivision { code: "TZ-09", territory: TerritoryCode::TZ, category: "region", name: "Kilimanjaro" },
+    Subdivision { code: "TZ-10", territory: TerritoryCode::TZ, category: "region", name: "Pemba South" },
+    Subdivision { code: "TZ-11", territory: TerritoryCode::TZ, category: "region", name: "Zanzibar South" },
+    Subdivision { code: "TZ-12", territory: TerritoryCode::TZ, category: "region", name: "Lindi" },
+    Subdivision { code: "TZ-13", territory: TerritoryCode::TZ, category: "region", name: "Mara" },
+    Subdivision { code: "TZ-14", territory: TerritoryCode::TZ, category: "region", name: "Mbeya" },
+    Subdivision { code: "TZ-15", territory: TerritoryCode::TZ, category: "region", name: "Zanzibar West" },
+    Subdivision { code: "TZ-16", territory: TerritoryCode::TZ, category: "region", name: "Morogoro" },
+    Subdivision { code: "TZ-17", territory: TerritoryCode::TZ, category: "region", name: "Mtwara" },
+    Subdivision { code: "TZ-18", territory: TerritoryCode::TZ, category: "region", name: "Mwanza" },
+    Subdivision { code: "TZ-19", territory: TerritoryCode::TZ, category: "region", name: "Pwani" },
+    Subdivision { code: "TZ-20", territory: TerritoryCode::TZ, category: "region", name: "Rukwa" },
+    Subdivision { code: "TZ-21", territory: TerritoryCode::TZ, category: "region", name: "Ruvuma" },
+    Subdivision { code: "TZ-22", territory: TerritoryCode::TZ, category: "region", name: "Shinyanga" },
+    Subdivision { code: "TZ-23", territory: TerritoryCode::TZ, category: "region", name: "Singida" },
+    Subdivision { code: "TZ-24", territory: TerritoryCode::TZ, category: "region", name: "Tabora" },
+    Subdivision { code: "TZ-25", territory: TerritoryCode::TZ, category: "region", name: "Tanga" },
+    Subdivision { code: "TZ-26", territory: TerritoryCode::TZ, category: "region", name: "Manyara" },
+    Subdivision { code: "TZ-27", territory: TerritoryCode::TZ, category: "region", name: "Geita" },
+    Subdivision { code: "TZ-28", territory: TerritoryCode::TZ, category: "region", name: "Katavi" },
+    Subdivision { code: "TZ-29", territory: TerritoryCode::TZ, category: "region", name: "Njombe" },
+    Subdivision { code: "TZ-30", territory: TerritoryCode::TZ, category: "region", name: "Simiyu" },
+    Subdivision { code: "TZ-31", territory: TerritoryCode::TZ, category: "region", name: "Songwe" },
+    Subdivision { code: "UA-05", territory: TerritoryCode::UA, category: "region", name: "Vinnytsia" },
+    Subdivision { code: "UA-07", territory: TerritoryCode::UA, category: "region", name: "Volyn" },
+    Subdivision { code: "UA-09", territory: TerritoryCode::UA, category: "region", name: "Luhansk" },
+    Subdivision { code: "UA-12", territory: TerritoryCode::UA, category: "region", name: "Dnipropetrovsk" },
+    Subdivision { code: "UA-14", territory: TerritoryCode::UA, category: "region", name: "Donetsk" },
+    Subdivision { code: "UA-18", territory: TerritoryCode::UA, category: "region", name: "Zhytomyr" },
+    Subdivision { code: "UA-21", territory: TerritoryCode::UA, category: "region", name: "Zakarpattia" },
+    Subdivision { code: "UA-23", territory: TerritoryCode::UA, category: "region", name: "Zaporizhzhia" },
+    Subdivision { code: "UA-26", territory: TerritoryCode::UA, category: "region", name: "Ivano-Frankivsk" },
+    Subdivision { code: "UA-30", territory: TerritoryCode::UA, category: "city", name: "Kyiv" },
+    Subdivision { code: "UA-32", territory: TerritoryCode::UA, category: "region", name: "Kyiv Oblast" },
+    Subdivision { code: "UA-35", territory: TerritoryCode::UA, category: "region", name: "Kirovohrad" },
+    Subdivision { code: "UA-40", territory: TerritoryCode::UA, category: "city", name: "Sevastopol" },
+    Subdivision { code: "UA-43", territory: TerritoryCode::UA, category: "republic", name: "Crimea" },
+    Subdivision { code: "UA-46", territory: TerritoryCode::UA, category: "region", name: "Lviv" },
+    Subdivision { code: "UA-48", territory: TerritoryCode::UA, category: "region", name: "Mykolaiv" },
+    Subdivision { code: "UA-51", territory: TerritoryCode::UA, category: "region", name: "Odesa" },
+    Subdivision { code: "UA-53", territory: TerritoryCode::UA, category: "region", name: "Poltava" },
+    Subdivision { code: "UA-56", territory: TerritoryCode::UA, category: "region", name: "Rivne" },
+    Subdivision { code: "UA-59", territory: TerritoryCode::UA, category: "region", name: "Sumy" },
+    Subdivision { code: "UA-61", territory: TerritoryCode::UA, category: "region", name: "Ternopil" },
+    Subdivision { code: "UA-63", territory: TerritoryCode::UA, category: "region", name: "Kharkiv" },
+    Subdivision { code: "UA-65", territory: TerritoryCode::UA, category: "region", name: "Kherson" },
+    Subdivision { code: "UA-68", territory: TerritoryCode::UA, category: "region", name: "Khmelnytskyi" },
+    Subdivision { code: "UA-71", territory: TerritoryCode::UA, category: "region", name: "Cherkasy" },
+    Subdivision { code: "UA-74", territory: TerritoryCode::UA, category: "region", name: "Chernihiv" },
+    Subdivision { code: "UA-77", territory: TerritoryCode::UA, category: "region", name: "Chernivtsi" },
+    Subdivision { code: "UG-C", territory: TerritoryCode::UG, category: "geographical region", name: "Central" },
+    Subdivision { code: "UG-E", territory: TerritoryCode::UG, category: "geographical region", name: "Eastern" },
+    Subdivision { code: "UG-N", territory: TerritoryCode::UG, category: "geographical region", name: "Northern" },
+    Subdivision { code: "UG-W", territory: TerritoryCode::UG, category: "geographical region", name: "Western" },
+    Subdivision { code: "US-AK", territory: TerritoryCode::US, category: "state", name: "Alaska" },
+    Subdivision { code: "US-AL", territory: TerritoryCode::US, category: "state", name: "Alabama" },
+    Subdivision { code: "US-AR", territory: TerritoryCode::US, category: "state", name: "Arkansas" },
+    Subdivision { code: "US-AZ", territory: TerritoryCode::US, category: "state", name: "Arizona" },
+    Subdivision { code: "US-CA", territory: TerritoryCode::US, category: "state", name: "California" },
+    Subdivision { code: "US-CO", territory: TerritoryCode::US, category: "state", name: "Colorado" },
+    Subdivision { code: "US-CT", territory: TerritoryCode::US, category: "state", name: "Connecticut" },
+    Subdivision { code: "US-DC", territory: TerritoryCode::US, category: "district", name: "District of Columbia" },
+    Subdivision { code: "US-DE", territory: TerritoryCode::US, category: "state", name: "Delaware" },
+    Subdivision { code: "US-FL", territory: TerritoryCode::US, category: "state", name: "Florida" },
+    Subdivision { code: "US-GA", territory: TerritoryCode::US, category: "state", name: "Georgia" },
+    Subdivision { code: "US-HI", territory: TerritoryCode::US, category: "state", name: "Hawaii" },
+    Subdivision { code: "US-IA", territory: TerritoryCode::US, category: "state", name: "Iowa" },
+    Subdivision { code: "US-ID", territory: TerritoryCode::US, category: "state", name: "Idaho" },
+    Subdivision { code: "US-IL", territory: TerritoryCode::US, category: "state", name: "Illinois" },
+    Subdivision { code: "US-IN", territory: TerritoryCode::US, category: "state", name: "Indiana" },
+    Subdivision { code: "US-KS", territory: TerritoryCode::US, category: "state", name: "Kansas" },
+    Subdivision { code: "US-KY", territory: TerritoryCode::US, category: "state", name: "Kentucky" },
+    Subdivision { code: "US-LA", territory: TerritoryCode::US, category: "state", name: "Louisiana" },
+    Subdivision { code: "US-MA", territory: TerritoryCode::US, category: "state", name: "Massachusetts" },
+    Subdivision { code: "US-MD", territory: TerritoryCode::US, category: "state", name: "Maryland" },
+    Subdivision { code: "US-ME", territory: TerritoryCode::US, category: "state", name: "Maine" },
+    Subdivision { code: "US-MI", territory: TerritoryCode::US, category: "state", name: "Michigan" },
+    Subdivision { code: "US-MN", territory: TerritoryCode::US, category: "state", name: "Minnesota" },
+    Subdivision { code: "US-MO", territory: TerritoryCode::US, category: "state", name: "Missouri" },
+    Subdivision { code: "US-MS", territory: TerritoryCode::US, category: "state", name: "Mississippi" },
+    Subdivision { code: "US-MT", territory: TerritoryCode::US, category: "state", name: "Montana" },
+    Subdivision { code: "US-NC", territory: TerritoryCode::US, category: "state", name: "North Carolina" },
+    Subdivision { code: "US-ND", territory: TerritoryCode::US, category: "state", name: "North Dakota" },
+    Subdivision { code: "US-NE", territory: TerritoryCode::US, category: "state", name: "Nebraska" },
+    Subdivision { code: "US-NH", territory: TerritoryCode::US, category: "state", name: "New Hampshire" },
+    Subdivision { code: "US-NJ", territory: TerritoryCode::US, category: "state", name: "New Jersey" },
+    Subdivision { code: "US-NM", territory: TerritoryCode::US, category: "state", name: "New Mexico" },
+    Subdivision { code: "US-NV", territory: TerritoryCode::US, category: "state", name: "Nevada" },
+    Subdivision { code: "US-NY", territory: TerritoryCode::US, category: "state", name: "New York" },
+    Subdivision { code: "US-OH", territory: TerritoryCode::US, category: "state", name: "Ohio" },
+    Subdivision { code: "US-OK", territory: TerritoryCode::US, category: "state", name: "Oklahoma" },
+    Subdivision { code: "US-OR", territory: TerritoryCode::US, category: "state", name: "Oregon" },
+    Subdivision { code: "US-PA", territory: TerritoryCode::US, category: "state", name: "Pennsylvania" },
+    Subdivision { code: "US-RI", territory: TerritoryCode::US, category: "state", name: "Rhode Island" },
+    Subdivision { code: "US-SC", territory: TerritoryCode::US, category: "state", name: "South Carolina" },
+    Subdivision { code: "US-SD", territory: TerritoryCode::US, category: "state", name: "South Dakota" },
+    Subdivision { code: "US-TN", territory: TerritoryCode::US, category: "state", name: "Tennessee" },
+    Subdivision { code: "US-TX", territory: TerritoryCode::US, category: "state", name: "Texas" },
+    Subdivision { code: "US-UT", territory: TerritoryCode::US, category: "state", name: "Utah" },
+    Subdivision { code: "US-VA", territory: TerritoryCode::US, category: "state", name: "Virginia" },
+    Subdivision { code: "US-VT", territory: TerritoryCode::US, category: "state", name: "Vermont" },
+    Subdivision { code: "US-WA", territory: TerritoryCode::US, category: "state", name: "Washington" },
+    Subdivision { code: "US-WI", territory: TerritoryCode::US, category: "state", name: "Wisconsin" },
+    Subdivision { code: "US-WV", territory: TerritoryCode::US, category: "state", name: "West Virginia" },
+    Subdivision { code: "US-WY", territory: TerritoryCode::US, category: "state", name: "Wyoming" },
+    Subdivision { code: "UY-AR", territory: TerritoryCode::UY, category: "department", name: "Artigas" },
+    Subdivision { code: "UY-CA", territory: TerritoryCode::UY, category: "department", name: "Canelones" },
+    Subdivision { code: "UY-CL", territory: TerritoryCode::UY, category: "department", name: "Cerro Largo" },
+    Subdivision { code: "UY-CO", territory: TerritoryCode::UY, category: "department", name: "Colonia" },
+    Subdivision { code: "UY-DU", territory: TerritoryCode::UY, category: "department", name: "Durazno" },
+    Subdivision { code: "UY-FD", territory: TerritoryCode::UY, category: "department", name: "Florida" },
+    Subdivision { code: "UY-FS", territory: TerritoryCode::UY, category: "department", name: "Flores" },
+    Subdivision { code: "UY-LA", territory: TerritoryCode::UY, category: "department", name: "Lavalleja" },
+    Subdivision { code: "UY-MA", territory: TerritoryCode::UY, category: "department", name: "Maldonado" },
+    Subdivision { code: "UY-MO", territory: TerritoryCode::UY, category: "department", name: "Montevideo" },
+    Subdivision { code: "UY-PA", territory: TerritoryCode::UY, category: "department", name: "Paysandú" },
+    Subdivision { code: "UY-RN", territory: TerritoryCode::UY, category: "department", name: "Río Negro" },
+    Subdivision { code: "UY-RO", territory: TerritoryCode::UY, category: "department", name: "Rocha" },
+    Subdivision { code: "UY-RV", territory: TerritoryCode::UY, category: "department", name: "Rivera" },
+    Subdivision { code: "UY-SA", territory: TerritoryCode::UY, category: "department", name: "Salto" },
+    Subdivision { code: "UY-SJ", territory: TerritoryCode::UY, category: "department", name: "San José" },
+    Subdivision { code: "UY-SO", territory: TerritoryCode::UY, category: "department", name: "Soriano" },
+    Subdivision { code: "UY-TA", territory: TerritoryCode::UY, category: "department", name: "Tacuarembó" },
+    Subdivision { code: "UY-TT", territory: TerritoryCode::UY, category: "department", name: "Treinta y Tres" },
+    Subdivision { code: "UZ-AN", territory: TerritoryCode::UZ, category: "region", name: "Andijan" },
+    Subdivision { code: "UZ-BU", territory: TerritoryCode::UZ, category: "region", name: "Bukhara" },
+    Subdivision { code: "UZ-FA", territory: TerritoryCode::UZ, category: "region", name: "Fergana" },
+    Subdivision { code: "UZ-JI", territory: TerritoryCode::UZ, category: "region", name: "Jizzakh" },
+    Subdivision { code: "UZ-NG", territory: TerritoryCode::UZ, category: "region", name: "Namangan" },
+    Subdivision { code: "UZ-NW", territory: TerritoryCode::UZ, category: "region", name: "Navoiy" },
+    Subdivision { code: "UZ-QA", territory: TerritoryCode::UZ, category: "region", name: "Kashkadarya" },
+    Subdivision { code: "UZ-QR", territory: TerritoryCode::UZ, category: "republic", name: "Karakalpakstan" },
+    Subdivision { code: "UZ-SA", territory: TerritoryCode::UZ, category: "region", name: "Samarkand" },
+    Subdivision { code: "UZ-SI", territory: TerritoryCode::UZ, category: "region", name: "Sirdaryo" },
+    Subdivision { code: "UZ-SU", territory: TerritoryCode::UZ, category: "region", name: "Surxondaryo" },
+    Subdivision { code: "UZ-TK", territory: TerritoryCode::UZ, category: "city", name: "Tashkent" },
+    Subdivision { code: "UZ-TO", territory: TerritoryCode::UZ, category: "region", name: "Tashkent Region" },
+    Subdivision { code: "UZ-XO", territory: TerritoryCode::UZ, category: "region", name: "Xorazm" },
+    Subdivision { code: "VC-01", territory: TerritoryCode::VC, category: "parish", name: "Charlotte" },
+    Subdivision { code: "VC-02", territory: TerritoryCode::VC, category: "parish", name: "Saint Andrew" },
+    Subdivision { code: "VC-03", territory: TerritoryCode::VC, category: "parish", name: "Saint David" },
+    Subdivision { code: "VC-04", territory: TerritoryCode::VC, category: "parish", name: "Saint George" },
+    Subdivision { code: "VC-05", territory: TerritoryCode::VC, category: "parish", name: "Saint Patrick" },
+    Subdivision { code: "VC-06", territory: TerritoryCode::VC, category: "parish", name: "Grenadines" },
+    Subdivision { code: "VE-A", territory: TerritoryCode::VE, category: "capital district", name: "Capital District" },
+    Subdivision { code: "VE-B", territory: TerritoryCode::VE, category: "state", name: "Anzoátegui" },
+    Subdivision { code: "VE-C", territory: TerritoryCode::VE, category: "state", name: "Apure" },
+    Subdivision { code: "VE-D", territory: TerritoryCode::VE, category: "state", name: "Aragua" },
+    Subdivision { code: "VE-E", territory: TerritoryCode::VE, category: "state", name: "Barinas" },
+    Subdivision { code: "VE-F", territory: TerritoryCode::VE, category: "state", name: "Bolívar" },
+    Subdivision { code: "VE-G", territory: TerritoryCode::VE, category: "state", name: "Carabobo" },
+    Subdivision { code: "VE-H", territory: TerritoryCode::VE, category: "state", name: "Cojedes" },
+    Subdivision { code: "VE-I", territory: TerritoryCode::VE, category: "state", name: "Falcón" },
+    Subdivision { code: "VE-J", territory: TerritoryCode::VE, category: "state", name: "Guárico" },
+    Subdivision { code: "VE-K", territory: TerritoryCode::VE, category: "state", name: "Lara" },
+    Subdivision { code: "VE-L", territory: TerritoryCode::VE, category: "state", name: "Mérida" },
+    Subdivision { code: "VE-M", territory: TerritoryCode::VE, category: "state", name: "Miranda" },
+    Subdivision { code: "VE-N", territory: TerritoryCode::VE, category: "state", name: "Monagas" },
+    Subdivision { code: "VE-O", territory: TerritoryCode::VE, category: "state", name: "Nueva Esparta" },
+    Subdivision { code: "VE-P", territory: TerritoryCode::VE, category: "state", name: "Portuguesa" },
+    Subdivision { code: "VE-R", territory: TerritoryCode::VE, category: "state", name: "Sucre" },
+    Subdivision { code: "VE-S", territory: TerritoryCode::VE, category: "state", name: "Táchira" },
+    Subdivision { code: "VE-T", territory: TerritoryCode::VE, category: "state", name: "Trujillo" },
+    Subdivision { code: "VE-U", territory: TerritoryCode::VE, category: "state", name: "Yaracuy" },
+    Subdivision { code: "VE-V", territory: TerritoryCode::VE, category: "state", name: "Zulia" },
+    Subdivision { code: "VE-W", territory: TerritoryCode::VE, category: "federal dependency", name: "Federal Dependencies" },
+    Subdivision { code: "VE-X", territory: TerritoryCode::VE, category: "state", name: "La Guaira" },
+    Subdivision { code: "VE-Y", territory: TerritoryCode::VE, category: "state", name: "Delta Amacuro" },
+    Subdivision { code: "VE-Z", territory: TerritoryCode::VE, category: "state", name: "Amazonas" },
+    Subdivision { code: "VU-MAP", territory: TerritoryCode::VU, category: "province", name: "Malampa" },
+    Subdivision { code: "VU-PAM", territory: TerritoryCode::VU, category: "province", name: "Penama" },
+    Subdivision { code: "VU-SAM", territory: TerritoryCode::VU, category: "province", name: "Sanma" },
+    Subdivision { code: "VU-SEE", territory: TerritoryCode::VU, category: "province", name: "Shefa" },
+    Subdivision { code: "VU-TAE", territory: TerritoryCode::VU, category: "province", name: "Tafea" },
+    Subdivision { code: "VU-TOB", territory: TerritoryCode::VU, category: "province", name: "Torba" },
+    Subdivision { code: "WS-AA", territory: TerritoryCode::WS, category: "district", name: "Aʻana" },
+    Subdivision { code: "WS-AL", territory: TerritoryCode::WS, category: "district", name: "Aiga-i-le-Tai" },
+    Subdivision { code: "WS-AT", territory: TerritoryCode::WS, category: "district", name: "Atua" },
+    Subdivision { code: "WS-FA", territory: TerritoryCode::WS, category: "district", name: "Faʻasaleleaga" },
+    Subdivision { code: "WS-GE", territory: TerritoryCode::WS, category: "district", name: "Gagaʻemauga" },
+    Subdivision { code: "WS-GI", territory: TerritoryCode::WS, category: "district", name: "Gagaʻifomauga" },
+    Subdivision { code: "WS-PA", territory: TerritoryCode::WS, category: "district", name: "Palauli" },
+    Subdivision { code: "WS-SA", territory: TerritoryCode::WS, category: "district", name: "Satupaʻitea" },
+    Subdivision { code: "WS-TU", territory: TerritoryCode::WS, category: "district", name: "Tuamasaga" },
+    Subdivision { code: "WS-VF", territory: TerritoryCode::WS, category: "district", name: "Vaʻa-o-Fonoti" },
+    Subdivision { code: "WS-VS", territory: TerritoryCode::WS, category: "district", name: "Vaisigano" },
+    Subdivision { code: "YE-AB", territory: TerritoryCode::YE, category: "governorate", name: "Abyan" },
+    Subdivision { code: "YE-AD", territory: TerritoryCode::YE, category: "governorate", name: "Aden" },
+    Subdivision { code: "YE-AM", territory: TerritoryCode::YE, category: "governorate", name: "Amran" },
+    Subdivision { code: "YE-BA", territory: TerritoryCode::YE, category: "governorate", name: "Al Bayda" },
+    Subdivision { code: "YE-DA", territory: TerritoryCode::YE, category: "governorate", name: "Al Dhale'e" },
+    Subdivision { code: "YE-DH", territory: TerritoryCode::YE, category: "governorate", name: "Dhamar" },
+    Subdivision { code: "YE-HD", territory: TerritoryCode::YE, category: "governorate", name: "Hadramaut" },
+    Subdivision { code: "YE-HJ", territory: TerritoryCode::YE, category: "governorate", name: "Hajjah" },
+    Subdivision { code: "YE-HU", territory: TerritoryCode::YE, category: "governorate", name: "Al Hudaydah" },
+    Subdivision { code: "YE-IB", territory: TerritoryCode::YE, category: "governorate", name: "Ibb" },
+    Subdivision { code: "YE-JA", territory: TerritoryCode::YE, category: "governorate", name: "Al Jawf" },
+    Subdivision { code: "YE-LA", territory: TerritoryCode::YE, category: "governorate", name: "Lahij" },
+    Subdivision { code: "YE-MA", territory: TerritoryCode::YE, category: "governorate", name: "Marib" },
+    Subdivision { code: "YE-MR", territory: TerritoryCode::YE, category: "governorate", name: "Al Mahrah" },
+    Subdivision { code: "YE-MW", territory: TerritoryCode::YE, category: "governorate", name: "Al Mahwit" },
+    Subdivision { code: "YE-RA", territory: TerritoryCode::YE, category: "governorate", name: "Raymah" },
+    Subdivision { code: "YE-SA", territory: TerritoryCode::YE, category: "municipality", name: "Amanat Al Asimah" },
+    Subdivision { code: "YE-SD", territory: TerritoryCode::YE, category: "governorate", name: "Saada" },
+    Subdivision { code: "YE-SH", territory: TerritoryCode::YE, category: "governorate", name: "Shabwah" },
+    Subdivision { code: "YE-SN", territory: TerritoryCode::YE, category: "governorate", name: "Sanaa" },
+    Subdivision { code: "YE-SU", territory: TerritoryCode::YE, category: "governorate", name: "Socotra" },
+    Subdivision { code: "YE-TA", territory: TerritoryCode::YE, category: "governorate", name: "Taiz" },
+    Subdivision { code: "ZA-EC", territory: TerritoryCode::ZA, category: "province", name: "Eastern Cape" },
+    Subdivision { code: "ZA-FS", territory: TerritoryCode::ZA, category: "province", name: "Free State" },
+    Subdivision { code: "ZA-GP", territory: TerritoryCode::ZA, category: "province", name: "Gauteng" },
+    Subdivision { code: "ZA-KZN", territory: TerritoryCode::ZA, category: "province", name: "KwaZulu-Natal" },
+    Subdivision { code: "ZA-LP", territory: TerritoryCode::ZA, category: "province", name: "Limpopo" },
+    Subdivision { code: "ZA-MP", territory: TerritoryCode::ZA, category: "province", name: "Mpumalanga" },
+    Subdivision { code: "ZA-NC", territory: TerritoryCode::ZA, category: "province", name: "Northern Cape" },
+    Subdivision { code: "ZA-NW", territory: TerritoryCode::ZA, category: "province", name: "North West" },
+    Subdivision { code: "ZA-WC", territory: TerritoryCode::ZA, category: "province", name: "Western Cape" },
+    Subdivision { code: "ZM-01", territory: TerritoryCode::ZM, category: "province", name: "Western" },
+    Subdivision { code: "ZM-02", territory: TerritoryCode::ZM, category: "province", name: "Central" },
+    Subdivision { code: "ZM-03", territory: TerritoryCode::ZM, category: "province", name: "Eastern" },
+    Subdivision { code: "ZM-04", territory: TerritoryCode::ZM, category: "province", name: "Luapula" },
+    Subdivision { code: "ZM-05", territory: TerritoryCode::ZM, category: "province", name: "Northern" },
+    Subdivision { code: "ZM-06", territory: TerritoryCode::ZM, category: "province", name: "North-Western" },
+    Subdivision { code: "ZM-07", territory: TerritoryCode::ZM, category: "province", name: "Southern" },
+    Subdivision { code: "ZM-08", territory: TerritoryCode::ZM, category: "province", name: "Copperbelt" },
+    Subdivision { code: "ZM-09", territory: TerritoryCode::ZM, category: "province", name: "Lusaka" },
+    Subdivision { code: "ZM-10", territory: TerritoryCode::ZM, category: "province", name: "Muchinga" },
+    Subdivision { code: "ZW-BU", territory: TerritoryCode::ZW, category: "city", name: "Bulawayo" },
+    Subdivision { code: "ZW-HA", territory: TerritoryCode::ZW, category: "city", name: "Harare" },
+    Subdivision { code: "ZW-MA", territory: TerritoryCode::ZW, category: "province", name: "Manicaland" },
+    Subdivision { code: "ZW-MC", territory: TerritoryCode::ZW, category: "province", name: "Mashonaland Central" },
+    Subdivision { code: "ZW-ME", territory: TerritoryCode::ZW, category: "province", name: "Mashonaland East" },
+    Subdivision { code: "ZW-MI", territory: TerritoryCode::ZW, category: "province", name: "Midlands" },
+    Subdivision { code: "ZW-MN", territory: TerritoryCode::ZW, category: "province", name: "Matabeleland North" },
+    Subdivision { code: "ZW-MS", territory: TerritoryCode::ZW, category: "province", name: "Matabeleland South" },
+    Subdivision { code: "ZW-MV", territory: TerritoryCode::ZW, category: "province", name: "Masvingo" },
+    Subdivision { code: "ZW-MW", territory: TerritoryCode::ZW, category: "province", name: "Mashonaland West" },
+];
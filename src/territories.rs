@@ -0,0 +1,3043 @@
+// Generated by `cargo run -p metadata-gen` from `data/territories.csv`. Do not edit.
+
+use std::num::NonZeroU32;
+
+use crate::{CallingCodes, CallingCodesInner};
+
+///
+/// All the ISO 3166 territorial codes. Most of these are country codes,
+/// but some territories still exist in the world today.
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive] // New territories may want independence
+pub enum TerritoryCode {
+    US,
+    CA,
+    AC,
+    AD,
+    AE,
+    AF,
+    AG,
+    AI,
+    AL,
+    AM,
+    AO,
+    AQ,
+    AR,
+    AS,
+    AT,
+    AU,
+    AW,
+    AX,
+    AZ,
+    BA,
+    BB,
+    BD,
+    BE,
+    BF,
+    BG,
+    BH,
+    BI,
+    BJ,
+    BM,
+    BN,
+    BO,
+    BQ,
+    BR,
+    BS,
+    BT,
+    BW,
+    BY,
+    BZ,
+    CC,
+    CD,
+    CF,
+    CG,
+    CH,
+    CI,
+    CK,
+    CL,
+    CM,
+    CN,
+    CO,
+    CR,
+    CU,
+    CV,
+    CW,
+    CX,
+    CY,
+    CZ,
+    DE,
+    DJ,
+    DK,
+    DM,
+    DO,
+    DZ,
+    EC,
+    EE,
+    EG,
+    ER,
+    ES,
+    ET,
+    FI,
+    FJ,
+    FK,
+    FM,
+    FO,
+    FR,
+    GA,
+    GB,
+    GD,
+    GE,
+    GF,
+    GG,
+    GH,
+    GI,
+    GL,
+    GM,
+    GN,
+    GP,
+    GQ,
+    GR,
+    GT,
+    GU,
+    GW,
+    GY,
+    HK,
+    HN,
+    HR,
+    HT,
+    HU,
+    ID,
+    IE,
+    IL,
+    IM,
+    IN,
+    IO,
+    IQ,
+    IR,
+    IS,
+    IT,
+    JE,
+    JM,
+    JO,
+    JP,
+    KE,
+    KG,
+    KH,
+    KI,
+    KM,
+    KN,
+    KP,
+    KR,
+    KW,
+    KY,
+    KZ,
+    LA,
+    LB,
+    LC,
+    LI,
+    LK,
+    LR,
+    LS,
+    LT,
+    LU,
+    LV,
+    LY,
+    MA,
+    MC,
+    MD,
+    ME,
+    MG,
+    MH,
+    MK,
+    ML,
+    MM,
+    MN,
+    MO,
+    MP,
+    MQ,
+    MR,
+    MS,
+    MT,
+    MU,
+    MV,
+    MW,
+    MX,
+    MY,
+    MZ,
+    NA,
+    NC,
+    NE,
+    NF,
+    NG,
+    NI,
+    NL,
+    NO,
+    NP,
+    NR,
+    NU,
+    NZ,
+    OM,
+    PA,
+    PE,
+    PF,
+    PG,
+    PH,
+    PK,
+    PL,
+    PM,
+    PR,
+    PS,
+    PT,
+    PW,
+    PY,
+    QA,
+    RE,
+    RO,
+    RS,
+    RU,
+    RW,
+    SA,
+    SB,
+    SC,
+    SD,
+    SE,
+    SG,
+    SH,
+    SI,
+    SJ,
+    SK,
+    SL,
+    SM,
+    SN,
+    SO,
+    SR,
+    SS,
+    ST,
+    SV,
+    SX,
+    SY,
+    SZ,
+    TC,
+    TD,
+    TG,
+    TH,
+    TJ,
+    TK,
+    TL,
+    TM,
+    TN,
+    TO,
+    TR,
+    TT,
+    TV,
+    TW,
+    TZ,
+    UA,
+    UG,
+    UY,
+    UZ,
+    VA,
+    VC,
+    VE,
+    VG,
+    VI,
+    VN,
+    VU,
+    WF,
+    WS,
+    XG,
+    XN,
+    XP,
+    XS,
+    XT,
+    XV,
+    YE,
+    YT,
+    ZA,
+    ZM,
+    ZW,
+}
+
+/// All the territory codes, in the order of the enum
+#[rustfmt::skip]
+pub(crate) const ALL: [TerritoryCode; 247] = [
+    TerritoryCode::US,
+    TerritoryCode::CA,
+    TerritoryCode::AC,
+    TerritoryCode::AD,
+    TerritoryCode::AE,
+    TerritoryCode::AF,
+    TerritoryCode::AG,
+    TerritoryCode::AI,
+    TerritoryCode::AL,
+    TerritoryCode::AM,
+    TerritoryCode::AO,
+    TerritoryCode::AQ,
+    TerritoryCode::AR,
+    TerritoryCode::AS,
+    TerritoryCode::AT,
+    TerritoryCode::AU,
+    TerritoryCode::AW,
+    TerritoryCode::AX,
+    TerritoryCode::AZ,
+    TerritoryCode::BA,
+    TerritoryCode::BB,
+    TerritoryCode::BD,
+    TerritoryCode::BE,
+    TerritoryCode::BF,
+    TerritoryCode::BG,
+    TerritoryCode::BH,
+    TerritoryCode::BI,
+    TerritoryCode::BJ,
+    TerritoryCode::BM,
+    TerritoryCode::BN,
+    TerritoryCode::BO,
+    TerritoryCode::BQ,
+    TerritoryCode::BR,
+    TerritoryCode::BS,
+    TerritoryCode::BT,
+    TerritoryCode::BW,
+    TerritoryCode::BY,
+    TerritoryCode::BZ,
+    TerritoryCode::CC,
+    TerritoryCode::CD,
+    TerritoryCode::CF,
+    TerritoryCode::CG,
+    TerritoryCode::CH,
+    TerritoryCode::CI,
+    TerritoryCode::CK,
+    TerritoryCode::CL,
+    TerritoryCode::CM,
+    TerritoryCode::CN,
+    TerritoryCode::CO,
+    TerritoryCode::CR,
+    TerritoryCode::CU,
+    TerritoryCode::CV,
+    TerritoryCode::CW,
+    TerritoryCode::CX,
+    TerritoryCode::CY,
+    TerritoryCode::CZ,
+    TerritoryCode::DE,
+    TerritoryCode::DJ,
+    TerritoryCode::DK,
+    TerritoryCode::DM,
+    TerritoryCode::DO,
+    TerritoryCode::DZ,
+    TerritoryCode::EC,
+    TerritoryCode::EE,
+    TerritoryCode::EG,
+    TerritoryCode::ER,
+    TerritoryCode::ES,
+    TerritoryCode::ET,
+    TerritoryCode::FI,
+    TerritoryCode::FJ,
+    TerritoryCode::FK,
+    TerritoryCode::FM,
+    TerritoryCode::FO,
+    TerritoryCode::FR,
+    TerritoryCode::GA,
+    TerritoryCode::GB,
+    TerritoryCode::GD,
+    TerritoryCode::GE,
+    TerritoryCode::GF,
+    TerritoryCode::GG,
+    TerritoryCode::GH,
+    TerritoryCode::GI,
+    TerritoryCode::GL,
+    TerritoryCode::GM,
+    TerritoryCode::GN,
+    TerritoryCode::GP,
+    TerritoryCode::GQ,
+    TerritoryCode::GR,
+    TerritoryCode::GT,
+    TerritoryCode::GU,
+    TerritoryCode::GW,
+    TerritoryCode::GY,
+    TerritoryCode::HK,
+    TerritoryCode::HN,
+    TerritoryCode::HR,
+    TerritoryCode::HT,
+    TerritoryCode::HU,
+    TerritoryCode::ID,
+    TerritoryCode::IE,
+    TerritoryCode::IL,
+    TerritoryCode::IM,
+    TerritoryCode::IN,
+    TerritoryCode::IO,
+    TerritoryCode::IQ,
+    TerritoryCode::IR,
+    TerritoryCode::IS,
+    TerritoryCode::IT,
+    TerritoryCode::JE,
+    TerritoryCode::JM,
+    TerritoryCode::JO,
+    TerritoryCode::JP,
+    TerritoryCode::KE,
+    TerritoryCode::KG,
+    TerritoryCode::KH,
+    TerritoryCode::KI,
+    TerritoryCode::KM,
+    TerritoryCode::KN,
+    TerritoryCode::KP,
+    TerritoryCode::KR,
+    TerritoryCode::KW,
+    TerritoryCode::KY,
+    TerritoryCode::KZ,
+    TerritoryCode::LA,
+    TerritoryCode::LB,
+    TerritoryCode::LC,
+    TerritoryCode::LI,
+    TerritoryCode::LK,
+    TerritoryCode::LR,
+    TerritoryCode::LS,
+    TerritoryCode::LT,
+    TerritoryCode::LU,
+    TerritoryCode::LV,
+    TerritoryCode::LY,
+    TerritoryCode::MA,
+    TerritoryCode::MC,
+    TerritoryCode::MD,
+    TerritoryCode::ME,
+    TerritoryCode::MG,
+    TerritoryCode::MH,
+    TerritoryCode::MK,
+    TerritoryCode::ML,
+    TerritoryCode::MM,
+    TerritoryCode::MN,
+    TerritoryCode::MO,
+    TerritoryCode::MP,
+    TerritoryCode::MQ,
+    TerritoryCode::MR,
+    TerritoryCode::MS,
+    TerritoryCode::MT,
+    TerritoryCode::MU,
+    TerritoryCode::MV,
+    TerritoryCode::MW,
+    TerritoryCode::MX,
+    TerritoryCode::MY,
+    TerritoryCode::MZ,
+    TerritoryCode::NA,
+    TerritoryCode::NC,
+    TerritoryCode::NE,
+    TerritoryCode::NF,
+    TerritoryCode::NG,
+    TerritoryCode::NI,
+    TerritoryCode::NL,
+    TerritoryCode::NO,
+    TerritoryCode::NP,
+    TerritoryCode::NR,
+    TerritoryCode::NU,
+    TerritoryCode::NZ,
+    TerritoryCode::OM,
+    TerritoryCode::PA,
+    TerritoryCode::PE,
+    TerritoryCode::PF,
+    TerritoryCode::PG,
+    TerritoryCode::PH,
+    TerritoryCode::PK,
+    TerritoryCode::PL,
+    TerritoryCode::PM,
+    TerritoryCode::PR,
+    TerritoryCode::PS,
+    TerritoryCode::PT,
+    TerritoryCode::PW,
+    TerritoryCode::PY,
+    TerritoryCode::QA,
+    TerritoryCode::RE,
+    TerritoryCode::RO,
+    TerritoryCode::RS,
+    TerritoryCode::RU,
+    TerritoryCode::RW,
+    TerritoryCode::SA,
+    TerritoryCode::SB,
+    TerritoryCode::SC,
+    TerritoryCode::SD,
+    TerritoryCode::SE,
+    TerritoryCode::SG,
+    TerritoryCode::SH,
+    TerritoryCode::SI,
+    TerritoryCode::SJ,
+    TerritoryCode::SK,
+    TerritoryCode::SL,
+    TerritoryCode::SM,
+    TerritoryCode::SN,
+    TerritoryCode::SO,
+    TerritoryCode::SR,
+    TerritoryCode::SS,
+    TerritoryCode::ST,
+    TerritoryCode::SV,
+    TerritoryCode::SX,
+    TerritoryCode::SY,
+    TerritoryCode::SZ,
+    TerritoryCode::TC,
+    TerritoryCode::TD,
+    TerritoryCode::TG,
+    TerritoryCode::TH,
+    TerritoryCode::TJ,
+    TerritoryCode::TK,
+    TerritoryCode::TL,
+    TerritoryCode::TM,
+    TerritoryCode::TN,
+    TerritoryCode::TO,
+    TerritoryCode::TR,
+    TerritoryCode::TT,
+    TerritoryCode::TV,
+    TerritoryCode::TW,
+    TerritoryCode::TZ,
+    TerritoryCode::UA,
+    TerritoryCode::UG,
+    TerritoryCode::UY,
+    TerritoryCode::UZ,
+    TerritoryCode::VA,
+    TerritoryCode::VC,
+    TerritoryCode::VE,
+    TerritoryCode::VG,
+    TerritoryCode::VI,
+    TerritoryCode::VN,
+    TerritoryCode::VU,
+    TerritoryCode::WF,
+    TerritoryCode::WS,
+    TerritoryCode::XG,
+    TerritoryCode::XN,
+    TerritoryCode::XP,
+    TerritoryCode::XS,
+    TerritoryCode::XT,
+    TerritoryCode::XV,
+    TerritoryCode::YE,
+    TerritoryCode::YT,
+    TerritoryCode::ZA,
+    TerritoryCode::ZM,
+    TerritoryCode::ZW,
+];
+
+#[rustfmt::skip]
+pub(crate) fn calling_codes(territory: TerritoryCode) -> CallingCodes {
+    macro_rules! cc {
+        ($value:literal) => {
+            NonZeroU32::new($value).unwrap()
+        };
+    }
+    CallingCodes(match territory {
+        TerritoryCode::US => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::CA => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::AC => CallingCodesInner::Single([cc!(247)]),
+        TerritoryCode::AD => CallingCodesInner::Single([cc!(376)]),
+        TerritoryCode::AE => CallingCodesInner::Single([cc!(971)]),
+        TerritoryCode::AF => CallingCodesInner::Single([cc!(93)]),
+        TerritoryCode::AG => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::AI => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::AL => CallingCodesInner::Single([cc!(355)]),
+        TerritoryCode::AM => CallingCodesInner::Single([cc!(374)]),
+        TerritoryCode::AO => CallingCodesInner::Single([cc!(244)]),
+        TerritoryCode::AQ => CallingCodesInner::Single([cc!(672)]),
+        TerritoryCode::AR => CallingCodesInner::Single([cc!(54)]),
+        TerritoryCode::AS => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::AT => CallingCodesInner::Single([cc!(43)]),
+        TerritoryCode::AU => CallingCodesInner::Single([cc!(61)]),
+        TerritoryCode::AW => CallingCodesInner::Single([cc!(297)]),
+        TerritoryCode::AX => CallingCodesInner::Single([cc!(35818)]),
+        TerritoryCode::AZ => CallingCodesInner::Single([cc!(994)]),
+        TerritoryCode::BA => CallingCodesInner::Single([cc!(387)]),
+        TerritoryCode::BB => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::BD => CallingCodesInner::Single([cc!(880)]),
+        TerritoryCode::BE => CallingCodesInner::Single([cc!(32)]),
+        TerritoryCode::BF => CallingCodesInner::Single([cc!(226)]),
+        TerritoryCode::BG => CallingCodesInner::Single([cc!(359)]),
+        TerritoryCode::BH => CallingCodesInner::Single([cc!(973)]),
+        TerritoryCode::BI => CallingCodesInner::Single([cc!(257)]),
+        TerritoryCode::BJ => CallingCodesInner::Single([cc!(229)]),
+        TerritoryCode::BM => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::BN => CallingCodesInner::Single([cc!(673)]),
+        TerritoryCode::BO => CallingCodesInner::Single([cc!(591)]),
+        TerritoryCode::BQ => CallingCodesInner::Three([cc!(5993), cc!(5994), cc!(5997)]),
+        TerritoryCode::BR => CallingCodesInner::Single([cc!(55)]),
+        TerritoryCode::BS => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::BT => CallingCodesInner::Single([cc!(975)]),
+        TerritoryCode::BW => CallingCodesInner::Single([cc!(267)]),
+        TerritoryCode::BY => CallingCodesInner::Single([cc!(375)]),
+        TerritoryCode::BZ => CallingCodesInner::Single([cc!(501)]),
+        TerritoryCode::CC => CallingCodesInner::Single([cc!(6189162)]),
+        TerritoryCode::CD => CallingCodesInner::Single([cc!(243)]),
+        TerritoryCode::CF => CallingCodesInner::Single([cc!(236)]),
+        TerritoryCode::CG => CallingCodesInner::Single([cc!(242)]),
+        TerritoryCode::CH => CallingCodesInner::Single([cc!(41)]),
+        TerritoryCode::CI => CallingCodesInner::Single([cc!(225)]),
+        TerritoryCode::CK => CallingCodesInner::Single([cc!(682)]),
+        TerritoryCode::CL => CallingCodesInner::Single([cc!(56)]),
+        TerritoryCode::CM => CallingCodesInner::Single([cc!(237)]),
+        TerritoryCode::CN => CallingCodesInner::Single([cc!(86)]),
+        TerritoryCode::CO => CallingCodesInner::Single([cc!(57)]),
+        TerritoryCode::CR => CallingCodesInner::Single([cc!(506)]),
+        TerritoryCode::CU => CallingCodesInner::Single([cc!(53)]),
+        TerritoryCode::CV => CallingCodesInner::Single([cc!(238)]),
+        TerritoryCode::CW => CallingCodesInner::Single([cc!(5999)]),
+        TerritoryCode::CX => CallingCodesInner::Single([cc!(6189164)]),
+        TerritoryCode::CY => CallingCodesInner::Single([cc!(357)]),
+        TerritoryCode::CZ => CallingCodesInner::Single([cc!(420)]),
+        TerritoryCode::DE => CallingCodesInner::Single([cc!(49)]),
+        TerritoryCode::DJ => CallingCodesInner::Single([cc!(253)]),
+        TerritoryCode::DK => CallingCodesInner::Single([cc!(45)]),
+        TerritoryCode::DM => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::DO => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::DZ => CallingCodesInner::Single([cc!(213)]),
+        TerritoryCode::EC => CallingCodesInner::Single([cc!(593)]),
+        TerritoryCode::EE => CallingCodesInner::Single([cc!(372)]),
+        TerritoryCode::EG => CallingCodesInner::Single([cc!(20)]),
+        TerritoryCode::ER => CallingCodesInner::Single([cc!(291)]),
+        TerritoryCode::ES => CallingCodesInner::Single([cc!(34)]),
+        TerritoryCode::ET => CallingCodesInner::Single([cc!(251)]),
+        TerritoryCode::FI => CallingCodesInner::Single([cc!(358)]),
+        TerritoryCode::FJ => CallingCodesInner::Single([cc!(679)]),
+        TerritoryCode::FK => CallingCodesInner::Single([cc!(500)]),
+        TerritoryCode::FM => CallingCodesInner::Single([cc!(691)]),
+        TerritoryCode::FO => CallingCodesInner::Single([cc!(298)]),
+        TerritoryCode::FR => CallingCodesInner::Single([cc!(33)]),
+        TerritoryCode::GA => CallingCodesInner::Single([cc!(241)]),
+        TerritoryCode::GB => CallingCodesInner::Single([cc!(44)]),
+        TerritoryCode::GD => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::GE => CallingCodesInner::Single([cc!(995)]),
+        TerritoryCode::GF => CallingCodesInner::Single([cc!(594)]),
+        TerritoryCode::GG => CallingCodesInner::Single([cc!(441481)]),
+        TerritoryCode::GH => CallingCodesInner::Single([cc!(233)]),
+        TerritoryCode::GI => CallingCodesInner::Single([cc!(350)]),
+        TerritoryCode::GL => CallingCodesInner::Single([cc!(299)]),
+        TerritoryCode::GM => CallingCodesInner::Single([cc!(220)]),
+        TerritoryCode::GN => CallingCodesInner::Single([cc!(224)]),
+        TerritoryCode::GP => CallingCodesInner::Single([cc!(590)]),
+        TerritoryCode::GQ => CallingCodesInner::Single([cc!(240)]),
+        TerritoryCode::GR => CallingCodesInner::Single([cc!(30)]),
+        TerritoryCode::GT => CallingCodesInner::Single([cc!(502)]),
+        TerritoryCode::GU => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::GW => CallingCodesInner::Single([cc!(245)]),
+        TerritoryCode::GY => CallingCodesInner::Single([cc!(592)]),
+        TerritoryCode::HK => CallingCodesInner::Single([cc!(852)]),
+        TerritoryCode::HN => CallingCodesInner::Single([cc!(504)]),
+        TerritoryCode::HR => CallingCodesInner::Single([cc!(385)]),
+        TerritoryCode::HT => CallingCodesInner::Single([cc!(509)]),
+        TerritoryCode::HU => CallingCodesInner::Single([cc!(36)]),
+        TerritoryCode::ID => CallingCodesInner::Single([cc!(62)]),
+        TerritoryCode::IE => CallingCodesInner::Single([cc!(353)]),
+        TerritoryCode::IL => CallingCodesInner::Single([cc!(972)]),
+        TerritoryCode::IM => CallingCodesInner::Single([cc!(441624)]),
+        TerritoryCode::IN => CallingCodesInner::Single([cc!(91)]),
+        TerritoryCode::IO => CallingCodesInner::Single([cc!(246)]),
+        TerritoryCode::IQ => CallingCodesInner::Single([cc!(964)]),
+        TerritoryCode::IR => CallingCodesInner::Single([cc!(98)]),
+        TerritoryCode::IS => CallingCodesInner::Single([cc!(354)]),
+        TerritoryCode::IT => CallingCodesInner::Single([cc!(39)]),
+        TerritoryCode::JE => CallingCodesInner::Single([cc!(441534)]),
+        TerritoryCode::JM => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::JO => CallingCodesInner::Single([cc!(962)]),
+        TerritoryCode::JP => CallingCodesInner::Single([cc!(81)]),
+        TerritoryCode::KE => CallingCodesInner::Single([cc!(254)]),
+        TerritoryCode::KG => CallingCodesInner::Single([cc!(996)]),
+        TerritoryCode::KH => CallingCodesInner::Single([cc!(855)]),
+        TerritoryCode::KI => CallingCodesInner::Single([cc!(686)]),
+        TerritoryCode::KM => CallingCodesInner::Single([cc!(269)]),
+        TerritoryCode::KN => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::KP => CallingCodesInner::Single([cc!(850)]),
+        TerritoryCode::KR => CallingCodesInner::Single([cc!(82)]),
+        TerritoryCode::KW => CallingCodesInner::Single([cc!(965)]),
+        TerritoryCode::KY => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::KZ => CallingCodesInner::Single([cc!(7)]),
+        TerritoryCode::LA => CallingCodesInner::Single([cc!(856)]),
+        TerritoryCode::LB => CallingCodesInner::Single([cc!(961)]),
+        TerritoryCode::LC => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::LI => CallingCodesInner::Single([cc!(423)]),
+        TerritoryCode::LK => CallingCodesInner::Single([cc!(94)]),
+        TerritoryCode::LR => CallingCodesInner::Single([cc!(231)]),
+        TerritoryCode::LS => CallingCodesInner::Single([cc!(266)]),
+        TerritoryCode::LT => CallingCodesInner::Single([cc!(370)]),
+        TerritoryCode::LU => CallingCodesInner::Single([cc!(352)]),
+        TerritoryCode::LV => CallingCodesInner::Single([cc!(371)]),
+        TerritoryCode::LY => CallingCodesInner::Single([cc!(218)]),
+        TerritoryCode::MA => CallingCodesInner::Single([cc!(212)]),
+        TerritoryCode::MC => CallingCodesInner::Single([cc!(377)]),
+        TerritoryCode::MD => CallingCodesInner::Single([cc!(373)]),
+        TerritoryCode::ME => CallingCodesInner::Single([cc!(382)]),
+        TerritoryCode::MG => CallingCodesInner::Single([cc!(261)]),
+        TerritoryCode::MH => CallingCodesInner::Single([cc!(692)]),
+        TerritoryCode::MK => CallingCodesInner::Single([cc!(389)]),
+        TerritoryCode::ML => CallingCodesInner::Single([cc!(223)]),
+        TerritoryCode::MM => CallingCodesInner::Single([cc!(95)]),
+        TerritoryCode::MN => CallingCodesInner::Single([cc!(976)]),
+        TerritoryCode::MO => CallingCodesInner::Single([cc!(853)]),
+        TerritoryCode::MP => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::MQ => CallingCodesInner::Single([cc!(596)]),
+        TerritoryCode::MR => CallingCodesInner::Single([cc!(222)]),
+        TerritoryCode::MS => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::MT => CallingCodesInner::Single([cc!(356)]),
+        TerritoryCode::MU => CallingCodesInner::Single([cc!(230)]),
+        TerritoryCode::MV => CallingCodesInner::Single([cc!(960)]),
+        TerritoryCode::MW => CallingCodesInner::Single([cc!(265)]),
+        TerritoryCode::MX => CallingCodesInner::Single([cc!(52)]),
+        TerritoryCode::MY => CallingCodesInner::Single([cc!(60)]),
+        TerritoryCode::MZ => CallingCodesInner::Single([cc!(258)]),
+        TerritoryCode::NA => CallingCodesInner::Single([cc!(264)]),
+        TerritoryCode::NC => CallingCodesInner::Single([cc!(687)]),
+        TerritoryCode::NE => CallingCodesInner::Single([cc!(227)]),
+        TerritoryCode::NF => CallingCodesInner::Single([cc!(6723)]),
+        TerritoryCode::NG => CallingCodesInner::Single([cc!(234)]),
+        TerritoryCode::NI => CallingCodesInner::Single([cc!(505)]),
+        TerritoryCode::NL => CallingCodesInner::Single([cc!(31)]),
+        TerritoryCode::NO => CallingCodesInner::Single([cc!(47)]),
+        TerritoryCode::NP => CallingCodesInner::Single([cc!(977)]),
+        TerritoryCode::NR => CallingCodesInner::Single([cc!(674)]),
+        TerritoryCode::NU => CallingCodesInner::Single([cc!(683)]),
+        TerritoryCode::NZ => CallingCodesInner::Single([cc!(64)]),
+        TerritoryCode::OM => CallingCodesInner::Single([cc!(968)]),
+        TerritoryCode::PA => CallingCodesInner::Single([cc!(507)]),
+        TerritoryCode::PE => CallingCodesInner::Single([cc!(51)]),
+        TerritoryCode::PF => CallingCodesInner::Single([cc!(689)]),
+        TerritoryCode::PG => CallingCodesInner::Single([cc!(675)]),
+        TerritoryCode::PH => CallingCodesInner::Single([cc!(63)]),
+        TerritoryCode::PK => CallingCodesInner::Single([cc!(92)]),
+        TerritoryCode::PL => CallingCodesInner::Single([cc!(48)]),
+        TerritoryCode::PM => CallingCodesInner::Single([cc!(508)]),
+        TerritoryCode::PR => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::PS => CallingCodesInner::Single([cc!(970)]),
+        TerritoryCode::PT => CallingCodesInner::Single([cc!(351)]),
+        TerritoryCode::PW => CallingCodesInner::Single([cc!(680)]),
+        TerritoryCode::PY => CallingCodesInner::Single([cc!(595)]),
+        TerritoryCode::QA => CallingCodesInner::Single([cc!(974)]),
+        TerritoryCode::RE => CallingCodesInner::Single([cc!(262)]),
+        TerritoryCode::RO => CallingCodesInner::Single([cc!(40)]),
+        TerritoryCode::RS => CallingCodesInner::Single([cc!(381)]),
+        TerritoryCode::RU => CallingCodesInner::Single([cc!(7)]),
+        TerritoryCode::RW => CallingCodesInner::Single([cc!(250)]),
+        TerritoryCode::SA => CallingCodesInner::Single([cc!(966)]),
+        TerritoryCode::SB => CallingCodesInner::Single([cc!(677)]),
+        TerritoryCode::SC => CallingCodesInner::Single([cc!(248)]),
+        TerritoryCode::SD => CallingCodesInner::Single([cc!(249)]),
+        TerritoryCode::SE => CallingCodesInner::Single([cc!(46)]),
+        TerritoryCode::SG => CallingCodesInner::Single([cc!(65)]),
+        TerritoryCode::SH => CallingCodesInner::Single([cc!(290)]),
+        TerritoryCode::SI => CallingCodesInner::Single([cc!(386)]),
+        TerritoryCode::SJ => CallingCodesInner::Single([cc!(4779)]),
+        TerritoryCode::SK => CallingCodesInner::Single([cc!(421)]),
+        TerritoryCode::SL => CallingCodesInner::Single([cc!(232)]),
+        TerritoryCode::SM => CallingCodesInner::Single([cc!(378)]),
+        TerritoryCode::SN => CallingCodesInner::Single([cc!(221)]),
+        TerritoryCode::SO => CallingCodesInner::Single([cc!(252)]),
+        TerritoryCode::SR => CallingCodesInner::Single([cc!(597)]),
+        TerritoryCode::SS => CallingCodesInner::Single([cc!(211)]),
+        TerritoryCode::ST => CallingCodesInner::Single([cc!(239)]),
+        TerritoryCode::SV => CallingCodesInner::Single([cc!(503)]),
+        TerritoryCode::SX => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::SY => CallingCodesInner::Single([cc!(963)]),
+        TerritoryCode::SZ => CallingCodesInner::Single([cc!(268)]),
+        TerritoryCode::TC => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::TD => CallingCodesInner::Single([cc!(235)]),
+        TerritoryCode::TG => CallingCodesInner::Single([cc!(228)]),
+        TerritoryCode::TH => CallingCodesInner::Single([cc!(66)]),
+        TerritoryCode::TJ => CallingCodesInner::Single([cc!(992)]),
+        TerritoryCode::TK => CallingCodesInner::Single([cc!(690)]),
+        TerritoryCode::TL => CallingCodesInner::Single([cc!(670)]),
+        TerritoryCode::TM => CallingCodesInner::Single([cc!(993)]),
+        TerritoryCode::TN => CallingCodesInner::Single([cc!(216)]),
+        TerritoryCode::TO => CallingCodesInner::Single([cc!(676)]),
+        TerritoryCode::TR => CallingCodesInner::Single([cc!(90)]),
+        TerritoryCode::TT => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::TV => CallingCodesInner::Single([cc!(688)]),
+        TerritoryCode::TW => CallingCodesInner::Single([cc!(886)]),
+        TerritoryCode::TZ => CallingCodesInner::Single([cc!(255)]),
+        TerritoryCode::UA => CallingCodesInner::Single([cc!(380)]),
+        TerritoryCode::UG => CallingCodesInner::Single([cc!(256)]),
+        TerritoryCode::UY => CallingCodesInner::Single([cc!(598)]),
+        TerritoryCode::UZ => CallingCodesInner::Single([cc!(998)]),
+        TerritoryCode::VA => CallingCodesInner::Single([cc!(379)]),
+        TerritoryCode::VC => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::VE => CallingCodesInner::Single([cc!(58)]),
+        TerritoryCode::VG => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::VI => CallingCodesInner::Single([cc!(1)]),
+        TerritoryCode::VN => CallingCodesInner::Single([cc!(84)]),
+        TerritoryCode::VU => CallingCodesInner::Single([cc!(678)]),
+        TerritoryCode::WF => CallingCodesInner::Single([cc!(681)]),
+        TerritoryCode::WS => CallingCodesInner::Single([cc!(685)]),
+        TerritoryCode::XG => CallingCodesInner::Single([cc!(881)]),
+        TerritoryCode::XN => CallingCodesInner::Single([cc!(870)]),
+        TerritoryCode::XP => CallingCodesInner::Single([cc!(878)]),
+        TerritoryCode::XS => CallingCodesInner::Single([cc!(808)]),
+        TerritoryCode::XT => CallingCodesInner::Single([cc!(800)]),
+        TerritoryCode::XV => CallingCodesInner::Two([cc!(882), cc!(883)]),
+        TerritoryCode::YE => CallingCodesInner::Single([cc!(967)]),
+        TerritoryCode::YT => CallingCodesInner::Single([cc!(262)]),
+        TerritoryCode::ZA => CallingCodesInner::Single([cc!(27)]),
+        TerritoryCode::ZM => CallingCodesInner::Single([cc!(260)]),
+        TerritoryCode::ZW => CallingCodesInner::Single([cc!(263)]),
+    })
+}
+
+/// The main territory of each country calling code shared by several territories
+#[rustfmt::skip]
+pub(crate) fn main_territory(calling_code: u32) -> Option<TerritoryCode> {
+    Some(match calling_code {
+        1 => TerritoryCode::US,
+        7 => TerritoryCode::RU,
+        44 => TerritoryCode::GB,
+        47 => TerritoryCode::NO,
+        61 => TerritoryCode::AU,
+        262 => TerritoryCode::RE,
+        358 => TerritoryCode::FI,
+        599 => TerritoryCode::CW,
+        672 => TerritoryCode::NF,
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_name(name: &str) -> Option<TerritoryCode> {
+    Some(match name {
+        "US" => TerritoryCode::US,
+        "CA" => TerritoryCode::CA,
+        "AC" => TerritoryCode::AC,
+        "AD" => TerritoryCode::AD,
+        "AE" => TerritoryCode::AE,
+        "AF" => TerritoryCode::AF,
+        "AG" => TerritoryCode::AG,
+        "AI" => TerritoryCode::AI,
+        "AL" => TerritoryCode::AL,
+        "AM" => TerritoryCode::AM,
+        "AO" => TerritoryCode::AO,
+        "AQ" => TerritoryCode::AQ,
+        "AR" => TerritoryCode::AR,
+        "AS" => TerritoryCode::AS,
+        "AT" => TerritoryCode::AT,
+        "AU" => TerritoryCode::AU,
+        "AW" => TerritoryCode::AW,
+        "AX" => TerritoryCode::AX,
+        "AZ" => TerritoryCode::AZ,
+        "BA" => TerritoryCode::BA,
+        "BB" => TerritoryCode::BB,
+        "BD" => TerritoryCode::BD,
+        "BE" => TerritoryCode::BE,
+        "BF" => TerritoryCode::BF,
+        "BG" => TerritoryCode::BG,
+        "BH" => TerritoryCode::BH,
+        "BI" => TerritoryCode::BI,
+        "BJ" => TerritoryCode::BJ,
+        "BM" => TerritoryCode::BM,
+        "BN" => TerritoryCode::BN,
+        "BO" => TerritoryCode::BO,
+        "BQ" => TerritoryCode::BQ,
+        "BR" => TerritoryCode::BR,
+        "BS" => TerritoryCode::BS,
+        "BT" => TerritoryCode::BT,
+        "BW" => TerritoryCode::BW,
+        "BY" => TerritoryCode::BY,
+        "BZ" => TerritoryCode::BZ,
+        "CC" => TerritoryCode::CC,
+        "CD" => TerritoryCode::CD,
+        "CF" => TerritoryCode::CF,
+        "CG" => TerritoryCode::CG,
+        "CH" => TerritoryCode::CH,
+        "CI" => TerritoryCode::CI,
+        "CK" => TerritoryCode::CK,
+        "CL" => TerritoryCode::CL,
+        "CM" => TerritoryCode::CM,
+        "CN" => TerritoryCode::CN,
+        "CO" => TerritoryCode::CO,
+        "CR" => TerritoryCode::CR,
+        "CU" => TerritoryCode::CU,
+        "CV" => TerritoryCode::CV,
+        "CW" => TerritoryCode::CW,
+        "CX" => TerritoryCode::CX,
+        "CY" => TerritoryCode::CY,
+        "CZ" => TerritoryCode::CZ,
+        "DE" => TerritoryCode::DE,
+        "DJ" => TerritoryCode::DJ,
+        "DK" => TerritoryCode::DK,
+        "DM" => TerritoryCode::DM,
+        "DO" => TerritoryCode::DO,
+        "DZ" => TerritoryCode::DZ,
+        "EC" => TerritoryCode::EC,
+        "EE" => TerritoryCode::EE,
+        "EG" => TerritoryCode::EG,
+        "ER" => TerritoryCode::ER,
+        "ES" => TerritoryCode::ES,
+        "ET" => TerritoryCode::ET,
+        "FI" => TerritoryCode::FI,
+        "FJ" => TerritoryCode::FJ,
+        "FK" => TerritoryCode::FK,
+        "FM" => TerritoryCode::FM,
+        "FO" => TerritoryCode::FO,
+        "FR" => TerritoryCode::FR,
+        "GA" => TerritoryCode::GA,
+        "GB" => TerritoryCode::GB,
+        "GD" => TerritoryCode::GD,
+        "GE" => TerritoryCode::GE,
+        "GF" => TerritoryCode::GF,
+        "GG" => TerritoryCode::GG,
+        "GH" => TerritoryCode::GH,
+        "GI" => TerritoryCode::GI,
+        "GL" => TerritoryCode::GL,
+        "GM" => TerritoryCode::GM,
+        "GN" => TerritoryCode::GN,
+        "GP" => TerritoryCode::GP,
+        "GQ" => TerritoryCode::GQ,
+        "GR" => TerritoryCode::GR,
+        "GT" => TerritoryCode::GT,
+        "GU" => TerritoryCode::GU,
+        "GW" => TerritoryCode::GW,
+        "GY" => TerritoryCode::GY,
+        "HK" => TerritoryCode::HK,
+        "HN" => TerritoryCode::HN,
+        "HR" => TerritoryCode::HR,
+        "HT" => TerritoryCode::HT,
+        "HU" => TerritoryCode::HU,
+        "ID" => TerritoryCode::ID,
+        "IE" => TerritoryCode::IE,
+        "IL" => TerritoryCode::IL,
+        "IM" => TerritoryCode::IM,
+        "IN" => TerritoryCode::IN,
+        "IO" => TerritoryCode::IO,
+        "IQ" => TerritoryCode::IQ,
+        "IR" => TerritoryCode::IR,
+        "IS" => TerritoryCode::IS,
+        "IT" => TerritoryCode::IT,
+        "JE" => TerritoryCode::JE,
+        "JM" => TerritoryCode::JM,
+        "JO" => TerritoryCode::JO,
+        "JP" => TerritoryCode::JP,
+        "KE" => TerritoryCode::KE,
+        "KG" => TerritoryCode::KG,
+        "KH" => TerritoryCode::KH,
+        "KI" => TerritoryCode::KI,
+        "KM" => TerritoryCode::KM,
+        "KN" => TerritoryCode::KN,
+        "KP" => TerritoryCode::KP,
+        "KR" => TerritoryCode::KR,
+        "KW" => TerritoryCode::KW,
+        "KY" => TerritoryCode::KY,
+        "KZ" => TerritoryCode::KZ,
+        "LA" => TerritoryCode::LA,
+        "LB" => TerritoryCode::LB,
+        "LC" => TerritoryCode::LC,
+        "LI" => TerritoryCode::LI,
+        "LK" => TerritoryCode::LK,
+        "LR" => TerritoryCode::LR,
+        "LS" => TerritoryCode::LS,
+        "LT" => TerritoryCode::LT,
+        "LU" => TerritoryCode::LU,
+        "LV" => TerritoryCode::LV,
+        "LY" => TerritoryCode::LY,
+        "MA" => TerritoryCode::MA,
+        "MC" => TerritoryCode::MC,
+        "MD" => TerritoryCode::MD,
+        "ME" => TerritoryCode::ME,
+        "MG" => TerritoryCode::MG,
+        "MH" => TerritoryCode::MH,
+        "MK" => TerritoryCode::MK,
+        "ML" => TerritoryCode::ML,
+        "MM" => TerritoryCode::MM,
+        "MN" => TerritoryCode::MN,
+        "MO" => TerritoryCode::MO,
+        "MP" => TerritoryCode::MP,
+        "MQ" => TerritoryCode::MQ,
+        "MR" => TerritoryCode::MR,
+        "MS" => TerritoryCode::MS,
+        "MT" => TerritoryCode::MT,
+        "MU" => TerritoryCode::MU,
+        "MV" => TerritoryCode::MV,
+        "MW" => TerritoryCode::MW,
+        "MX" => TerritoryCode::MX,
+        "MY" => TerritoryCode::MY,
+        "MZ" => TerritoryCode::MZ,
+        "NA" => TerritoryCode::NA,
+        "NC" => TerritoryCode::NC,
+        "NE" => TerritoryCode::NE,
+        "NF" => TerritoryCode::NF,
+        "NG" => TerritoryCode::NG,
+        "NI" => TerritoryCode::NI,
+        "NL" => TerritoryCode::NL,
+        "NO" => TerritoryCode::NO,
+        "NP" => TerritoryCode::NP,
+        "NR" => TerritoryCode::NR,
+        "NU" => TerritoryCode::NU,
+        "NZ" => TerritoryCode::NZ,
+        "OM" => TerritoryCode::OM,
+        "PA" => TerritoryCode::PA,
+        "PE" => TerritoryCode::PE,
+        "PF" => TerritoryCode::PF,
+        "PG" => TerritoryCode::PG,
+        "PH" => TerritoryCode::PH,
+        "PK" => TerritoryCode::PK,
+        "PL" => TerritoryCode::PL,
+        "PM" => TerritoryCode::PM,
+        "PR" => TerritoryCode::PR,
+        "PS" => TerritoryCode::PS,
+        "PT" => TerritoryCode::PT,
+        "PW" => TerritoryCode::PW,
+        "PY" => TerritoryCode::PY,
+        "QA" => TerritoryCode::QA,
+        "RE" => TerritoryCode::RE,
+        "RO" => TerritoryCode::RO,
+        "RS" => TerritoryCode::RS,
+        "RU" => TerritoryCode::RU,
+        "RW" => TerritoryCode::RW,
+        "SA" => TerritoryCode::SA,
+        "SB" => TerritoryCode::SB,
+        "SC" => TerritoryCode::SC,
+        "SD" => TerritoryCode::SD,
+        "SE" => TerritoryCode::SE,
+        "SG" => TerritoryCode::SG,
+        "SH" => TerritoryCode::SH,
+        "SI" => TerritoryCode::SI,
+        "SJ" => TerritoryCode::SJ,
+        "SK" => TerritoryCode::SK,
+        "SL" => TerritoryCode::SL,
+        "SM" => TerritoryCode::SM,
+        "SN" => TerritoryCode::SN,
+        "SO" => TerritoryCode::SO,
+        "SR" => TerritoryCode::SR,
+        "SS" => TerritoryCode::SS,
+        "ST" => TerritoryCode::ST,
+        "SV" => TerritoryCode::SV,
+        "SX" => TerritoryCode::SX,
+        "SY" => TerritoryCode::SY,
+        "SZ" => TerritoryCode::SZ,
+        "TC" => TerritoryCode::TC,
+        "TD" => TerritoryCode::TD,
+        "TG" => TerritoryCode::TG,
+        "TH" => TerritoryCode::TH,
+        "TJ" => TerritoryCode::TJ,
+        "TK" => TerritoryCode::TK,
+        "TL" => TerritoryCode::TL,
+        "TM" => TerritoryCode::TM,
+        "TN" => TerritoryCode::TN,
+        "TO" => TerritoryCode::TO,
+        "TR" => TerritoryCode::TR,
+        "TT" => TerritoryCode::TT,
+        "TV" => TerritoryCode::TV,
+        "TW" => TerritoryCode::TW,
+        "TZ" => TerritoryCode::TZ,
+        "UA" => TerritoryCode::UA,
+        "UG" => TerritoryCode::UG,
+        "UY" => TerritoryCode::UY,
+        "UZ" => TerritoryCode::UZ,
+        "VA" => TerritoryCode::VA,
+        "VC" => TerritoryCode::VC,
+        "VE" => TerritoryCode::VE,
+        "VG" => TerritoryCode::VG,
+        "VI" => TerritoryCode::VI,
+        "VN" => TerritoryCode::VN,
+        "VU" => TerritoryCode::VU,
+        "WF" => TerritoryCode::WF,
+        "WS" => TerritoryCode::WS,
+        "XG" => TerritoryCode::XG,
+        "XN" => TerritoryCode::XN,
+        "XP" => TerritoryCode::XP,
+        "XS" => TerritoryCode::XS,
+        "XT" => TerritoryCode::XT,
+        "XV" => TerritoryCode::XV,
+        "YE" => TerritoryCode::YE,
+        "YT" => TerritoryCode::YT,
+        "ZA" => TerritoryCode::ZA,
+        "ZM" => TerritoryCode::ZM,
+        "ZW" => TerritoryCode::ZW,
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn name(territory: TerritoryCode) -> &'static str {
+    match territory {
+        TerritoryCode::US => "US",
+        TerritoryCode::CA => "CA",
+        TerritoryCode::AC => "AC",
+        TerritoryCode::AD => "AD",
+        TerritoryCode::AE => "AE",
+        TerritoryCode::AF => "AF",
+        TerritoryCode::AG => "AG",
+        TerritoryCode::AI => "AI",
+        TerritoryCode::AL => "AL",
+        TerritoryCode::AM => "AM",
+        TerritoryCode::AO => "AO",
+        TerritoryCode::AQ => "AQ",
+        TerritoryCode::AR => "AR",
+        TerritoryCode::AS => "AS",
+        TerritoryCode::AT => "AT",
+        TerritoryCode::AU => "AU",
+        TerritoryCode::AW => "AW",
+        TerritoryCode::AX => "AX",
+        TerritoryCode::AZ => "AZ",
+        TerritoryCode::BA => "BA",
+        TerritoryCode::BB => "BB",
+        TerritoryCode::BD => "BD",
+        TerritoryCode::BE => "BE",
+        TerritoryCode::BF => "BF",
+        TerritoryCode::BG => "BG",
+        TerritoryCode::BH => "BH",
+        TerritoryCode::BI => "BI",
+        TerritoryCode::BJ => "BJ",
+        TerritoryCode::BM => "BM",
+        TerritoryCode::BN => "BN",
+        TerritoryCode::BO => "BO",
+        TerritoryCode::BQ => "BQ",
+        TerritoryCode::BR => "BR",
+        TerritoryCode::BS => "BS",
+        TerritoryCode::BT => "BT",
+        TerritoryCode::BW => "BW",
+        TerritoryCode::BY => "BY",
+        TerritoryCode::BZ => "BZ",
+        TerritoryCode::CC => "CC",
+        TerritoryCode::CD => "CD",
+        TerritoryCode::CF => "CF",
+        TerritoryCode::CG => "CG",
+        TerritoryCode::CH => "CH",
+        TerritoryCode::CI => "CI",
+        TerritoryCode::CK => "CK",
+        TerritoryCode::CL => "CL",
+        TerritoryCode::CM => "CM",
+        TerritoryCode::CN => "CN",
+        TerritoryCode::CO => "CO",
+        TerritoryCode::CR => "CR",
+        TerritoryCode::CU => "CU",
+        TerritoryCode::CV => "CV",
+        TerritoryCode::CW => "CW",
+        TerritoryCode::CX => "CX",
+        TerritoryCode::CY => "CY",
+        TerritoryCode::CZ => "CZ",
+        TerritoryCode::DE => "DE",
+        TerritoryCode::DJ => "DJ",
+        TerritoryCode::DK => "DK",
+        TerritoryCode::DM => "DM",
+        TerritoryCode::DO => "DO",
+        TerritoryCode::DZ => "DZ",
+        TerritoryCode::EC => "EC",
+        TerritoryCode::EE => "EE",
+        TerritoryCode::EG => "EG",
+        TerritoryCode::ER => "ER",
+        TerritoryCode::ES => "ES",
+        TerritoryCode::ET => "ET",
+        TerritoryCode::FI => "FI",
+        TerritoryCode::FJ => "FJ",
+        TerritoryCode::FK => "FK",
+        TerritoryCode::FM => "FM",
+        TerritoryCode::FO => "FO",
+        TerritoryCode::FR => "FR",
+        TerritoryCode::GA => "GA",
+        TerritoryCode::GB => "GB",
+        TerritoryCode::GD => "GD",
+        TerritoryCode::GE => "GE",
+        TerritoryCode::GF => "GF",
+        TerritoryCode::GG => "GG",
+        TerritoryCode::GH => "GH",
+        TerritoryCode::GI => "GI",
+        TerritoryCode::GL => "GL",
+        TerritoryCode::GM => "GM",
+        TerritoryCode::GN => "GN",
+        TerritoryCode::GP => "GP",
+        TerritoryCode::GQ => "GQ",
+        TerritoryCode::GR => "GR",
+        TerritoryCode::GT => "GT",
+        TerritoryCode::GU => "GU",
+        TerritoryCode::GW => "GW",
+        TerritoryCode::GY => "GY",
+        TerritoryCode::HK => "HK",
+        TerritoryCode::HN => "HN",
+        TerritoryCode::HR => "HR",
+        TerritoryCode::HT => "HT",
+        TerritoryCode::HU => "HU",
+        TerritoryCode::ID => "ID",
+        TerritoryCode::IE => "IE",
+        TerritoryCode::IL => "IL",
+        TerritoryCode::IM => "IM",
+        TerritoryCode::IN => "IN",
+        TerritoryCode::IO => "IO",
+        TerritoryCode::IQ => "IQ",
+        TerritoryCode::IR => "IR",
+        TerritoryCode::IS => "IS",
+        TerritoryCode::IT => "IT",
+        TerritoryCode::JE => "JE",
+        TerritoryCode::JM => "JM",
+        TerritoryCode::JO => "JO",
+        TerritoryCode::JP => "JP",
+        TerritoryCode::KE => "KE",
+        TerritoryCode::KG => "KG",
+        TerritoryCode::KH => "KH",
+        TerritoryCode::KI => "KI",
+        TerritoryCode::KM => "KM",
+        TerritoryCode::KN => "KN",
+        TerritoryCode::KP => "KP",
+        TerritoryCode::KR => "KR",
+        TerritoryCode::KW => "KW",
+        TerritoryCode::KY => "KY",
+        TerritoryCode::KZ => "KZ",
+        TerritoryCode::LA => "LA",
+        TerritoryCode::LB => "LB",
+        TerritoryCode::LC => "LC",
+        TerritoryCode::LI => "LI",
+        TerritoryCode::LK => "LK",
+        TerritoryCode::LR => "LR",
+        TerritoryCode::LS => "LS",
+        TerritoryCode::LT => "LT",
+        TerritoryCode::LU => "LU",
+        TerritoryCode::LV => "LV",
+        TerritoryCode::LY => "LY",
+        TerritoryCode::MA => "MA",
+        TerritoryCode::MC => "MC",
+        TerritoryCode::MD => "MD",
+        TerritoryCode::ME => "ME",
+        TerritoryCode::MG => "MG",
+        TerritoryCode::MH => "MH",
+        TerritoryCode::MK => "MK",
+        TerritoryCode::ML => "ML",
+        TerritoryCode::MM => "MM",
+        TerritoryCode::MN => "MN",
+        TerritoryCode::MO => "MO",
+        TerritoryCode::MP => "MP",
+        TerritoryCode::MQ => "MQ",
+        TerritoryCode::MR => "MR",
+        TerritoryCode::MS => "MS",
+        TerritoryCode::MT => "MT",
+        TerritoryCode::MU => "MU",
+        TerritoryCode::MV => "MV",
+        TerritoryCode::MW => "MW",
+        TerritoryCode::MX => "MX",
+        TerritoryCode::MY => "MY",
+        TerritoryCode::MZ => "MZ",
+        TerritoryCode::NA => "NA",
+        TerritoryCode::NC => "NC",
+        TerritoryCode::NE => "NE",
+        TerritoryCode::NF => "NF",
+        TerritoryCode::NG => "NG",
+        TerritoryCode::NI => "NI",
+        TerritoryCode::NL => "NL",
+        TerritoryCode::NO => "NO",
+        TerritoryCode::NP => "NP",
+        TerritoryCode::NR => "NR",
+        TerritoryCode::NU => "NU",
+        TerritoryCode::NZ => "NZ",
+        TerritoryCode::OM => "OM",
+        TerritoryCode::PA => "PA",
+        TerritoryCode::PE => "PE",
+        TerritoryCode::PF => "PF",
+        TerritoryCode::PG => "PG",
+        TerritoryCode::PH => "PH",
+        TerritoryCode::PK => "PK",
+        TerritoryCode::PL => "PL",
+        TerritoryCode::PM => "PM",
+        TerritoryCode::PR => "PR",
+        TerritoryCode::PS => "PS",
+        TerritoryCode::PT => "PT",
+        TerritoryCode::PW => "PW",
+        TerritoryCode::PY => "PY",
+        TerritoryCode::QA => "QA",
+        TerritoryCode::RE => "RE",
+        TerritoryCode::RO => "RO",
+        TerritoryCode::RS => "RS",
+        TerritoryCode::RU => "RU",
+        TerritoryCode::RW => "RW",
+        TerritoryCode::SA => "SA",
+        TerritoryCode::SB => "SB",
+        TerritoryCode::SC => "SC",
+        TerritoryCode::SD => "SD",
+        TerritoryCode::SE => "SE",
+        TerritoryCode::SG => "SG",
+        TerritoryCode::SH => "SH",
+        TerritoryCode::SI => "SI",
+        TerritoryCode::SJ => "SJ",
+        TerritoryCode::SK => "SK",
+        TerritoryCode::SL => "SL",
+        TerritoryCode::SM => "SM",
+        TerritoryCode::SN => "SN",
+        TerritoryCode::SO => "SO",
+        TerritoryCode::SR => "SR",
+        TerritoryCode::SS => "SS",
+        TerritoryCode::ST => "ST",
+        TerritoryCode::SV => "SV",
+        TerritoryCode::SX => "SX",
+        TerritoryCode::SY => "SY",
+        TerritoryCode::SZ => "SZ",
+        TerritoryCode::TC => "TC",
+        TerritoryCode::TD => "TD",
+        TerritoryCode::TG => "TG",
+        TerritoryCode::TH => "TH",
+        TerritoryCode::TJ => "TJ",
+        TerritoryCode::TK => "TK",
+        TerritoryCode::TL => "TL",
+        TerritoryCode::TM => "TM",
+        TerritoryCode::TN => "TN",
+        TerritoryCode::TO => "TO",
+        TerritoryCode::TR => "TR",
+        TerritoryCode::TT => "TT",
+        TerritoryCode::TV => "TV",
+        TerritoryCode::TW => "TW",
+        TerritoryCode::TZ => "TZ",
+        TerritoryCode::UA => "UA",
+        TerritoryCode::UG => "UG",
+        TerritoryCode::UY => "UY",
+        TerritoryCode::UZ => "UZ",
+        TerritoryCode::VA => "VA",
+        TerritoryCode::VC => "VC",
+        TerritoryCode::VE => "VE",
+        TerritoryCode::VG => "VG",
+        TerritoryCode::VI => "VI",
+        TerritoryCode::VN => "VN",
+        TerritoryCode::VU => "VU",
+        TerritoryCode::WF => "WF",
+        TerritoryCode::WS => "WS",
+        TerritoryCode::XG => "XG",
+        TerritoryCode::XN => "XN",
+        TerritoryCode::XP => "XP",
+        TerritoryCode::XS => "XS",
+        TerritoryCode::XT => "XT",
+        TerritoryCode::XV => "XV",
+        TerritoryCode::YE => "YE",
+        TerritoryCode::YT => "YT",
+        TerritoryCode::ZA => "ZA",
+        TerritoryCode::ZM => "ZM",
+        TerritoryCode::ZW => "ZW",
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn alpha3(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+        TerritoryCode::US => "USA",
+        TerritoryCode::CA => "CAN",
+        TerritoryCode::AD => "AND",
+        TerritoryCode::AE => "ARE",
+        TerritoryCode::AF => "AFG",
+        TerritoryCode::AG => "ATG",
+        TerritoryCode::AI => "AIA",
+        TerritoryCode::AL => "ALB",
+        TerritoryCode::AM => "ARM",
+        TerritoryCode::AO => "AGO",
+        TerritoryCode::AQ => "ATA",
+        TerritoryCode::AR => "ARG",
+        TerritoryCode::AS => "ASM",
+        TerritoryCode::AT => "AUT",
+        TerritoryCode::AU => "AUS",
+        TerritoryCode::AW => "ABW",
+        TerritoryCode::AX => "ALA",
+        TerritoryCode::AZ => "AZE",
+        TerritoryCode::BA => "BIH",
+        TerritoryCode::BB => "BRB",
+        TerritoryCode::BD => "BGD",
+        TerritoryCode::BE => "BEL",
+        TerritoryCode::BF => "BFA",
+        TerritoryCode::BG => "BGR",
+        TerritoryCode::BH => "BHR",
+        TerritoryCode::BI => "BDI",
+        TerritoryCode::BJ => "BEN",
+        TerritoryCode::BM => "BMU",
+        TerritoryCode::BN => "BRN",
+        TerritoryCode::BO => "BOL",
+        TerritoryCode::BQ => "BES",
+        TerritoryCode::BR => "BRA",
+        TerritoryCode::BS => "BHS",
+        TerritoryCode::BT => "BTN",
+        TerritoryCode::BW => "BWA",
+        TerritoryCode::BY => "BLR",
+        TerritoryCode::BZ => "BLZ",
+        TerritoryCode::CC => "CCK",
+        TerritoryCode::CD => "COD",
+        TerritoryCode::CF => "CAF",
+        TerritoryCode::CG => "COG",
+        TerritoryCode::CH => "CHE",
+        TerritoryCode::CI => "CIV",
+        TerritoryCode::CK => "COK",
+        TerritoryCode::CL => "CHL",
+        TerritoryCode::CM => "CMR",
+        TerritoryCode::CN => "CHN",
+        TerritoryCode::CO => "COL",
+        TerritoryCode::CR => "CRI",
+        TerritoryCode::CU => "CUB",
+        TerritoryCode::CV => "CPV",
+        TerritoryCode::CW => "CUW",
+        TerritoryCode::CX => "CXR",
+        TerritoryCode::CY => "CYP",
+        TerritoryCode::CZ => "CZE",
+        TerritoryCode::DE => "DEU",
+        TerritoryCode::DJ => "DJI",
+        TerritoryCode::DK => "DNK",
+        TerritoryCode::DM => "DMA",
+        TerritoryCode::DO => "DOM",
+        TerritoryCode::DZ => "DZA",
+        TerritoryCode::EC => "ECU",
+        TerritoryCode::EE => "EST",
+        TerritoryCode::EG => "EGY",
+        TerritoryCode::ER => "ERI",
+        TerritoryCode::ES => "ESP",
+        TerritoryCode::ET => "ETH",
+        TerritoryCode::FI => "FIN",
+        TerritoryCode::FJ => "FJI",
+        TerritoryCode::FK => "FLK",
+        TerritoryCode::FM => "FSM",
+        TerritoryCode::FO => "FRO",
+        TerritoryCode::FR => "FRA",
+        TerritoryCode::GA => "GAB",
+        TerritoryCode::GB => "GBR",
+        TerritoryCode::GD => "GRD",
+        TerritoryCode::GE => "GEO",
+        TerritoryCode::GF => "GUF",
+        TerritoryCode::GG => "GGY",
+        TerritoryCode::GH => "GHA",
+        TerritoryCode::GI => "GIB",
+        TerritoryCode::GL => "GRL",
+        TerritoryCode::GM => "GMB",
+        TerritoryCode::GN => "GIN",
+        TerritoryCode::GP => "GLP",
+        TerritoryCode::GQ => "GNQ",
+        TerritoryCode::GR => "GRC",
+        TerritoryCode::GT => "GTM",
+        TerritoryCode::GU => "GUM",
+        TerritoryCode::GW => "GNB",
+        TerritoryCode::GY => "GUY",
+        TerritoryCode::HK => "HKG",
+        TerritoryCode::HN => "HND",
+        TerritoryCode::HR => "HRV",
+        TerritoryCode::HT => "HTI",
+        TerritoryCode::HU => "HUN",
+        TerritoryCode::ID => "IDN",
+        TerritoryCode::IE => "IRL",
+        TerritoryCode::IL => "ISR",
+        TerritoryCode::IM => "IMN",
+        TerritoryCode::IN => "IND",
+        TerritoryCode::IO => "IOT",
+        TerritoryCode::IQ => "IRQ",
+        TerritoryCode::IR => "IRN",
+        TerritoryCode::IS => "ISL",
+        TerritoryCode::IT => "ITA",
+        TerritoryCode::JE => "JEY",
+        TerritoryCode::JM => "JAM",
+        TerritoryCode::JO => "JOR",
+        TerritoryCode::JP => "JPN",
+        TerritoryCode::KE => "KEN",
+        TerritoryCode::KG => "KGZ",
+        TerritoryCode::KH => "KHM",
+        TerritoryCode::KI => "KIR",
+        TerritoryCode::KM => "COM",
+        TerritoryCode::KN => "KNA",
+        TerritoryCode::KP => "PRK",
+        TerritoryCode::KR => "KOR",
+        TerritoryCode::KW => "KWT",
+        TerritoryCode::KY => "CYM",
+        TerritoryCode::KZ => "KAZ",
+        TerritoryCode::LA => "LAO",
+        TerritoryCode::LB => "LBN",
+        TerritoryCode::LC => "LCA",
+        TerritoryCode::LI => "LIE",
+        TerritoryCode::LK => "LKA",
+        TerritoryCode::LR => "LBR",
+        TerritoryCode::LS => "LSO",
+        TerritoryCode::LT => "LTU",
+        TerritoryCode::LU => "LUX",
+        TerritoryCode::LV => "LVA",
+        TerritoryCode::LY => "LBY",
+        TerritoryCode::MA => "MAR",
+        TerritoryCode::MC => "MCO",
+        TerritoryCode::MD => "MDA",
+        TerritoryCode::ME => "MNE",
+        TerritoryCode::MG => "MDG",
+        TerritoryCode::MH => "MHL",
+        TerritoryCode::MK => "MKD",
+        TerritoryCode::ML => "MLI",
+        TerritoryCode::MM => "MMR",
+        TerritoryCode::MN => "MNG",
+        TerritoryCode::MO => "MAC",
+        TerritoryCode::MP => "MNP",
+        TerritoryCode::MQ => "MTQ",
+        TerritoryCode::MR => "MRT",
+        TerritoryCode::MS => "MSR",
+        TerritoryCode::MT => "MLT",
+        TerritoryCode::MU => "MUS",
+        TerritoryCode::MV => "MDV",
+        TerritoryCode::MW => "MWI",
+        TerritoryCode::MX => "MEX",
+        TerritoryCode::MY => "MYS",
+        TerritoryCode::MZ => "MOZ",
+        TerritoryCode::NA => "NAM",
+        TerritoryCode::NC => "NCL",
+        TerritoryCode::NE => "NER",
+        TerritoryCode::NF => "NFK",
+        TerritoryCode::NG => "NGA",
+        TerritoryCode::NI => "NIC",
+        TerritoryCode::NL => "NLD",
+        TerritoryCode::NO => "NOR",
+        TerritoryCode::NP => "NPL",
+        TerritoryCode::NR => "NRU",
+        TerritoryCode::NU => "NIU",
+        TerritoryCode::NZ => "NZL",
+        TerritoryCode::OM => "OMN",
+        TerritoryCode::PA => "PAN",
+        TerritoryCode::PE => "PER",
+        TerritoryCode::PF => "PYF",
+        TerritoryCode::PG => "PNG",
+        TerritoryCode::PH => "PHL",
+        TerritoryCode::PK => "PAK",
+        TerritoryCode::PL => "POL",
+        TerritoryCode::PM => "SPM",
+        TerritoryCode::PR => "PRI",
+        TerritoryCode::PS => "PSE",
+        TerritoryCode::PT => "PRT",
+        TerritoryCode::PW => "PLW",
+        TerritoryCode::PY => "PRY",
+        TerritoryCode::QA => "QAT",
+        TerritoryCode::RE => "REU",
+        TerritoryCode::RO => "ROU",
+        TerritoryCode::RS => "SRB",
+        TerritoryCode::RU => "RUS",
+        TerritoryCode::RW => "RWA",
+        TerritoryCode::SA => "SAU",
+        TerritoryCode::SB => "SLB",
+        TerritoryCode::SC => "SYC",
+        TerritoryCode::SD => "SDN",
+        TerritoryCode::SE => "SWE",
+        TerritoryCode::SG => "SGP",
+        TerritoryCode::SH => "SHN",
+        TerritoryCode::SI => "SVN",
+        TerritoryCode::SJ => "SJM",
+        TerritoryCode::SK => "SVK",
+        TerritoryCode::SL => "SLE",
+        TerritoryCode::SM => "SMR",
+        TerritoryCode::SN => "SEN",
+        TerritoryCode::SO => "SOM",
+        TerritoryCode::SR => "SUR",
+        TerritoryCode::SS => "SSD",
+        TerritoryCode::ST => "STP",
+        TerritoryCode::SV => "SLV",
+        TerritoryCode::SX => "SXM",
+        TerritoryCode::SY => "SYR",
+        TerritoryCode::SZ => "SWZ",
+        TerritoryCode::TC => "TCA",
+        TerritoryCode::TD => "TCD",
+        TerritoryCode::TG => "TGO",
+        TerritoryCode::TH => "THA",
+        TerritoryCode::TJ => "TJK",
+        TerritoryCode::TK => "TKL",
+        TerritoryCode::TL => "TLS",
+        TerritoryCode::TM => "TKM",
+        TerritoryCode::TN => "TUN",
+        TerritoryCode::TO => "TON",
+        TerritoryCode::TR => "TUR",
+        TerritoryCode::TT => "TTO",
+        TerritoryCode::TV => "TUV",
+        TerritoryCode::TW => "TWN",
+        TerritoryCode::TZ => "TZA",
+        TerritoryCode::UA => "UKR",
+        TerritoryCode::UG => "UGA",
+        TerritoryCode::UY => "URY",
+        TerritoryCode::UZ => "UZB",
+        TerritoryCode::VA => "VAT",
+        TerritoryCode::VC => "VCT",
+        TerritoryCode::VE => "VEN",
+        TerritoryCode::VG => "VGB",
+        TerritoryCode::VI => "VIR",
+        TerritoryCode::VN => "VNM",
+        TerritoryCode::VU => "VUT",
+        TerritoryCode::WF => "WLF",
+        TerritoryCode::WS => "WSM",
+        TerritoryCode::YE => "YEM",
+        TerritoryCode::YT => "MYT",
+        TerritoryCode::ZA => "ZAF",
+        TerritoryCode::ZM => "ZMB",
+        TerritoryCode::ZW => "ZWE",
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_alpha3(alpha3: &str) -> Option<TerritoryCode> {
+    Some(match alpha3 {
+        "USA" => TerritoryCode::US,
+        "CAN" => TerritoryCode::CA,
+        "AND" => TerritoryCode::AD,
+        "ARE" => TerritoryCode::AE,
+        "AFG" => TerritoryCode::AF,
+        "ATG" => TerritoryCode::AG,
+        "AIA" => TerritoryCode::AI,
+        "ALB" => TerritoryCode::AL,
+        "ARM" => TerritoryCode::AM,
+        "AGO" => TerritoryCode::AO,
+        "ATA" => TerritoryCode::AQ,
+        "ARG" => TerritoryCode::AR,
+        "ASM" => TerritoryCode::AS,
+        "AUT" => TerritoryCode::AT,
+        "AUS" => TerritoryCode::AU,
+        "ABW" => TerritoryCode::AW,
+        "ALA" => TerritoryCode::AX,
+        "AZE" => TerritoryCode::AZ,
+        "BIH" => TerritoryCode::BA,
+        "BRB" => TerritoryCode::BB,
+        "BGD" => TerritoryCode::BD,
+        "BEL" => TerritoryCode::BE,
+        "BFA" => TerritoryCode::BF,
+        "BGR" => TerritoryCode::BG,
+        "BHR" => TerritoryCode::BH,
+        "BDI" => TerritoryCode::BI,
+        "BEN" => TerritoryCode::BJ,
+        "BMU" => TerritoryCode::BM,
+        "BRN" => TerritoryCode::BN,
+        "BOL" => TerritoryCode::BO,
+        "BES" => TerritoryCode::BQ,
+        "BRA" => TerritoryCode::BR,
+        "BHS" => TerritoryCode::BS,
+        "BTN" => TerritoryCode::BT,
+        "BWA" => TerritoryCode::BW,
+        "BLR" => TerritoryCode::BY,
+        "BLZ" => TerritoryCode::BZ,
+        "CCK" => TerritoryCode::CC,
+        "COD" => TerritoryCode::CD,
+        "CAF" => TerritoryCode::CF,
+        "COG" => TerritoryCode::CG,
+        "CHE" => TerritoryCode::CH,
+        "CIV" => TerritoryCode::CI,
+        "COK" => TerritoryCode::CK,
+        "CHL" => TerritoryCode::CL,
+        "CMR" => TerritoryCode::CM,
+        "CHN" => TerritoryCode::CN,
+        "COL" => TerritoryCode::CO,
+        "CRI" => TerritoryCode::CR,
+        "CUB" => TerritoryCode::CU,
+        "CPV" => TerritoryCode::CV,
+        "CUW" => TerritoryCode::CW,
+        "CXR" => TerritoryCode::CX,
+        "CYP" => TerritoryCode::CY,
+        "CZE" => TerritoryCode::CZ,
+        "DEU" => TerritoryCode::DE,
+        "DJI" => TerritoryCode::DJ,
+        "DNK" => TerritoryCode::DK,
+        "DMA" => TerritoryCode::DM,
+        "DOM" => TerritoryCode::DO,
+        "DZA" => TerritoryCode::DZ,
+        "ECU" => TerritoryCode::EC,
+        "EST" => TerritoryCode::EE,
+        "EGY" => TerritoryCode::EG,
+        "ERI" => TerritoryCode::ER,
+        "ESP" => TerritoryCode::ES,
+        "ETH" => TerritoryCode::ET,
+        "FIN" => TerritoryCode::FI,
+        "FJI" => TerritoryCode::FJ,
+        "FLK" => TerritoryCode::FK,
+        "FSM" => TerritoryCode::FM,
+        "FRO" => TerritoryCode::FO,
+        "FRA" => TerritoryCode::FR,
+        "GAB" => TerritoryCode::GA,
+        "GBR" => TerritoryCode::GB,
+        "GRD" => TerritoryCode::GD,
+        "GEO" => TerritoryCode::GE,
+        "GUF" => TerritoryCode::GF,
+        "GGY" => TerritoryCode::GG,
+        "GHA" => TerritoryCode::GH,
+        "GIB" => TerritoryCode::GI,
+        "GRL" => TerritoryCode::GL,
+        "GMB" => TerritoryCode::GM,
+        "GIN" => TerritoryCode::GN,
+        "GLP" => TerritoryCode::GP,
+        "GNQ" => TerritoryCode::GQ,
+        "GRC" => TerritoryCode::GR,
+        "GTM" => TerritoryCode::GT,
+        "GUM" => TerritoryCode::GU,
+        "GNB" => TerritoryCode::GW,
+        "GUY" => TerritoryCode::GY,
+        "HKG" => TerritoryCode::HK,
+        "HND" => TerritoryCode::HN,
+        "HRV" => TerritoryCode::HR,
+        "HTI" => TerritoryCode::HT,
+        "HUN" => TerritoryCode::HU,
+        "IDN" => TerritoryCode::ID,
+        "IRL" => TerritoryCode::IE,
+        "ISR" => TerritoryCode::IL,
+        "IMN" => TerritoryCode::IM,
+        "IND" => TerritoryCode::IN,
+        "IOT" => TerritoryCode::IO,
+        "IRQ" => TerritoryCode::IQ,
+        "IRN" => TerritoryCode::IR,
+        "ISL" => TerritoryCode::IS,
+        "ITA" => TerritoryCode::IT,
+        "JEY" => TerritoryCode::JE,
+        "JAM" => TerritoryCode::JM,
+        "JOR" => TerritoryCode::JO,
+        "JPN" => TerritoryCode::JP,
+        "KEN" => TerritoryCode::KE,
+        "KGZ" => TerritoryCode::KG,
+        "KHM" => TerritoryCode::KH,
+        "KIR" => TerritoryCode::KI,
+        "COM" => TerritoryCode::KM,
+        "KNA" => TerritoryCode::KN,
+        "PRK" => TerritoryCode::KP,
+        "KOR" => TerritoryCode::KR,
+        "KWT" => TerritoryCode::KW,
+        "CYM" => TerritoryCode::KY,
+        "KAZ" => TerritoryCode::KZ,
+        "LAO" => TerritoryCode::LA,
+        "LBN" => TerritoryCode::LB,
+        "LCA" => TerritoryCode::LC,
+        "LIE" => TerritoryCode::LI,
+        "LKA" => TerritoryCode::LK,
+        "LBR" => TerritoryCode::LR,
+        "LSO" => TerritoryCode::LS,
+        "LTU" => TerritoryCode::LT,
+        "LUX" => TerritoryCode::LU,
+        "LVA" => TerritoryCode::LV,
+        "LBY" => TerritoryCode::LY,
+        "MAR" => TerritoryCode::MA,
+        "MCO" => TerritoryCode::MC,
+        "MDA" => TerritoryCode::MD,
+        "MNE" => TerritoryCode::ME,
+        "MDG" => TerritoryCode::MG,
+        "MHL" => TerritoryCode::MH,
+        "MKD" => TerritoryCode::MK,
+        "MLI" => TerritoryCode::ML,
+        "MMR" => TerritoryCode::MM,
+        "MNG" => TerritoryCode::MN,
+        "MAC" => TerritoryCode::MO,
+        "MNP" => TerritoryCode::MP,
+        "MTQ" => TerritoryCode::MQ,
+        "MRT" => TerritoryCode::MR,
+        "MSR" => TerritoryCode::MS,
+        "MLT" => TerritoryCode::MT,
+        "MUS" => TerritoryCode::MU,
+        "MDV" => TerritoryCode::MV,
+        "MWI" => TerritoryCode::MW,
+        "MEX" => TerritoryCode::MX,
+        "MYS" => TerritoryCode::MY,
+        "MOZ" => TerritoryCode::MZ,
+        "NAM" => TerritoryCode::NA,
+        "NCL" => TerritoryCode::NC,
+        "NER" => TerritoryCode::NE,
+        "NFK" => TerritoryCode::NF,
+        "NGA" => TerritoryCode::NG,
+        "NIC" => TerritoryCode::NI,
+        "NLD" => TerritoryCode::NL,
+        "NOR" => TerritoryCode::NO,
+        "NPL" => TerritoryCode::NP,
+        "NRU" => TerritoryCode::NR,
+        "NIU" => TerritoryCode::NU,
+        "NZL" => TerritoryCode::NZ,
+        "OMN" => TerritoryCode::OM,
+        "PAN" => TerritoryCode::PA,
+        "PER" => TerritoryCode::PE,
+        "PYF" => TerritoryCode::PF,
+        "PNG" => TerritoryCode::PG,
+        "PHL" => TerritoryCode::PH,
+        "PAK" => TerritoryCode::PK,
+        "POL" => TerritoryCode::PL,
+        "SPM" => TerritoryCode::PM,
+        "PRI" => TerritoryCode::PR,
+        "PSE" => TerritoryCode::PS,
+        "PRT" => TerritoryCode::PT,
+        "PLW" => TerritoryCode::PW,
+        "PRY" => TerritoryCode::PY,
+        "QAT" => TerritoryCode::QA,
+        "REU" => TerritoryCode::RE,
+        "ROU" => TerritoryCode::RO,
+        "SRB" => TerritoryCode::RS,
+        "RUS" => TerritoryCode::RU,
+        "RWA" => TerritoryCode::RW,
+        "SAU" => TerritoryCode::SA,
+        "SLB" => TerritoryCode::SB,
+        "SYC" => TerritoryCode::SC,
+        "SDN" => TerritoryCode::SD,
+        "SWE" => TerritoryCode::SE,
+        "SGP" => TerritoryCode::SG,
+        "SHN" => TerritoryCode::SH,
+        "SVN" => TerritoryCode::SI,
+        "SJM" => TerritoryCode::SJ,
+        "SVK" => TerritoryCode::SK,
+        "SLE" => TerritoryCode::SL,
+        "SMR" => TerritoryCode::SM,
+        "SEN" => TerritoryCode::SN,
+        "SOM" => TerritoryCode::SO,
+        "SUR" => TerritoryCode::SR,
+        "SSD" => TerritoryCode::SS,
+        "STP" => TerritoryCode::ST,
+        "SLV" => TerritoryCode::SV,
+        "SXM" => TerritoryCode::SX,
+        "SYR" => TerritoryCode::SY,
+        "SWZ" => TerritoryCode::SZ,
+        "TCA" => TerritoryCode::TC,
+        "TCD" => TerritoryCode::TD,
+        "TGO" => TerritoryCode::TG,
+        "THA" => TerritoryCode::TH,
+        "TJK" => TerritoryCode::TJ,
+        "TKL" => TerritoryCode::TK,
+        "TLS" => TerritoryCode::TL,
+        "TKM" => TerritoryCode::TM,
+        "TUN" => TerritoryCode::TN,
+        "TON" => TerritoryCode::TO,
+        "TUR" => TerritoryCode::TR,
+        "TTO" => TerritoryCode::TT,
+        "TUV" => TerritoryCode::TV,
+        "TWN" => TerritoryCode::TW,
+        "TZA" => TerritoryCode::TZ,
+        "UKR" => TerritoryCode::UA,
+        "UGA" => TerritoryCode::UG,
+        "URY" => TerritoryCode::UY,
+        "UZB" => TerritoryCode::UZ,
+        "VAT" => TerritoryCode::VA,
+        "VCT" => TerritoryCode::VC,
+        "VEN" => TerritoryCode::VE,
+        "VGB" => TerritoryCode::VG,
+        "VIR" => TerritoryCode::VI,
+        "VNM" => TerritoryCode::VN,
+        "VUT" => TerritoryCode::VU,
+        "WLF" => TerritoryCode::WF,
+        "WSM" => TerritoryCode::WS,
+        "YEM" => TerritoryCode::YE,
+        "MYT" => TerritoryCode::YT,
+        "ZAF" => TerritoryCode::ZA,
+        "ZMB" => TerritoryCode::ZM,
+        "ZWE" => TerritoryCode::ZW,
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn numeric(territory: TerritoryCode) -> Option<u16> {
+    Some(match territory {
+        TerritoryCode::US => 840,
+        TerritoryCode::CA => 124,
+        TerritoryCode::AD => 20,
+        TerritoryCode::AE => 784,
+        TerritoryCode::AF => 4,
+        TerritoryCode::AG => 28,
+        TerritoryCode::AI => 660,
+        TerritoryCode::AL => 8,
+        TerritoryCode::AM => 51,
+        TerritoryCode::AO => 24,
+        TerritoryCode::AQ => 10,
+        TerritoryCode::AR => 32,
+        TerritoryCode::AS => 16,
+        TerritoryCode::AT => 40,
+        TerritoryCode::AU => 36,
+        TerritoryCode::AW => 533,
+        TerritoryCode::AX => 248,
+        TerritoryCode::AZ => 31,
+        TerritoryCode::BA => 70,
+        TerritoryCode::BB => 52,
+        TerritoryCode::BD => 50,
+        TerritoryCode::BE => 56,
+        TerritoryCode::BF => 854,
+        TerritoryCode::BG => 100,
+        TerritoryCode::BH => 48,
+        TerritoryCode::BI => 108,
+        TerritoryCode::BJ => 204,
+        TerritoryCode::BM => 60,
+        TerritoryCode::BN => 96,
+        TerritoryCode::BO => 68,
+        TerritoryCode::BQ => 535,
+        TerritoryCode::BR => 76,
+        TerritoryCode::BS => 44,
+        TerritoryCode::BT => 64,
+        TerritoryCode::BW => 72,
+        TerritoryCode::BY => 112,
+        TerritoryCode::BZ => 84,
+        TerritoryCode::CC => 166,
+        TerritoryCode::CD => 180,
+        TerritoryCode::CF => 140,
+        TerritoryCode::CG => 178,
+        TerritoryCode::CH => 756,
+        TerritoryCode::CI => 384,
+        TerritoryCode::CK => 184,
+        TerritoryCode::CL => 152,
+        TerritoryCode::CM => 120,
+        TerritoryCode::CN => 156,
+        TerritoryCode::CO => 170,
+        TerritoryCode::CR => 188,
+        TerritoryCode::CU => 192,
+        TerritoryCode::CV => 132,
+        TerritoryCode::CW => 531,
+        TerritoryCode::CX => 162,
+        TerritoryCode::CY => 196,
+        TerritoryCode::CZ => 203,
+        TerritoryCode::DE => 276,
+        TerritoryCode::DJ => 262,
+        TerritoryCode::DK => 208,
+        TerritoryCode::DM => 212,
+        TerritoryCode::DO => 214,
+        TerritoryCode::DZ => 12,
+        TerritoryCode::EC => 218,
+        TerritoryCode::EE => 233,
+        TerritoryCode::EG => 818,
+        TerritoryCode::ER => 232,
+        TerritoryCode::ES => 724,
+        TerritoryCode::ET => 231,
+        TerritoryCode::FI => 246,
+        TerritoryCode::FJ => 242,
+        TerritoryCode::FK => 238,
+        TerritoryCode::FM => 583,
+        TerritoryCode::FO => 234,
+        TerritoryCode::FR => 250,
+        TerritoryCode::GA => 266,
+        TerritoryCode::GB => 826,
+        TerritoryCode::GD => 308,
+        TerritoryCode::GE => 268,
+        TerritoryCode::GF => 254,
+        TerritoryCode::GG => 831,
+        TerritoryCode::GH => 288,
+        TerritoryCode::GI => 292,
+        TerritoryCode::GL => 304,
+        TerritoryCode::GM => 270,
+        TerritoryCode::GN => 324,
+        TerritoryCode::GP => 312,
+        TerritoryCode::GQ => 226,
+        TerritoryCode::GR => 300,
+        TerritoryCode::GT => 320,
+        TerritoryCode::GU => 316,
+        TerritoryCode::GW => 624,
+        TerritoryCode::GY => 328,
+        TerritoryCode::HK => 344,
+        TerritoryCode::HN => 340,
+        TerritoryCode::HR => 191,
+        TerritoryCode::HT => 332,
+        TerritoryCode::HU => 348,
+        TerritoryCode::ID => 360,
+        TerritoryCode::IE => 372,
+        TerritoryCode::IL => 376,
+        TerritoryCode::IM => 833,
+        TerritoryCode::IN => 356,
+        TerritoryCode::IO => 86,
+        TerritoryCode::IQ => 368,
+        TerritoryCode::IR => 364,
+        TerritoryCode::IS => 352,
+        TerritoryCode::IT => 380,
+        TerritoryCode::JE => 832,
+        TerritoryCode::JM => 388,
+        TerritoryCode::JO => 400,
+        TerritoryCode::JP => 392,
+        TerritoryCode::KE => 404,
+        TerritoryCode::KG => 417,
+        TerritoryCode::KH => 116,
+        TerritoryCode::KI => 296,
+        TerritoryCode::KM => 174,
+        TerritoryCode::KN => 659,
+        TerritoryCode::KP => 408,
+        TerritoryCode::KR => 410,
+        TerritoryCode::KW => 414,
+        TerritoryCode::KY => 136,
+        TerritoryCode::KZ => 398,
+        TerritoryCode::LA => 418,
+        TerritoryCode::LB => 422,
+        TerritoryCode::LC => 662,
+        TerritoryCode::LI => 438,
+        TerritoryCode::LK => 144,
+        TerritoryCode::LR => 430,
+        TerritoryCode::LS => 426,
+        TerritoryCode::LT => 440,
+        TerritoryCode::LU => 442,
+        TerritoryCode::LV => 428,
+        TerritoryCode::LY => 434,
+        TerritoryCode::MA => 504,
+        TerritoryCode::MC => 492,
+        TerritoryCode::MD => 498,
+        TerritoryCode::ME => 499,
+        TerritoryCode::MG => 450,
+        TerritoryCode::MH => 584,
+        TerritoryCode::MK => 807,
+        TerritoryCode::ML => 466,
+        TerritoryCode::MM => 104,
+        TerritoryCode::MN => 496,
+        TerritoryCode::MO => 446,
+        TerritoryCode::MP => 580,
+        TerritoryCode::MQ => 474,
+        TerritoryCode::MR => 478,
+        TerritoryCode::MS => 500,
+        TerritoryCode::MT => 470,
+        TerritoryCode::MU => 480,
+        TerritoryCode::MV => 462,
+        TerritoryCode::MW => 454,
+        TerritoryCode::MX => 484,
+        TerritoryCode::MY => 458,
+        TerritoryCode::MZ => 508,
+        TerritoryCode::NA => 516,
+        TerritoryCode::NC => 540,
+        TerritoryCode::NE => 562,
+        TerritoryCode::NF => 574,
+        TerritoryCode::NG => 566,
+        TerritoryCode::NI => 558,
+        TerritoryCode::NL => 528,
+        TerritoryCode::NO => 578,
+        TerritoryCode::NP => 524,
+        TerritoryCode::NR => 520,
+        TerritoryCode::NU => 570,
+        TerritoryCode::NZ => 554,
+        TerritoryCode::OM => 512,
+        TerritoryCode::PA => 591,
+        TerritoryCode::PE => 604,
+        TerritoryCode::PF => 258,
+        TerritoryCode::PG => 598,
+        TerritoryCode::PH => 608,
+        TerritoryCode::PK => 586,
+        TerritoryCode::PL => 616,
+        TerritoryCode::PM => 666,
+        TerritoryCode::PR => 630,
+        TerritoryCode::PS => 275,
+        TerritoryCode::PT => 620,
+        TerritoryCode::PW => 585,
+        TerritoryCode::PY => 600,
+        TerritoryCode::QA => 634,
+        TerritoryCode::RE => 638,
+        TerritoryCode::RO => 642,
+        TerritoryCode::RS => 688,
+        TerritoryCode::RU => 643,
+        TerritoryCode::RW => 646,
+        TerritoryCode::SA => 682,
+        TerritoryCode::SB => 90,
+        TerritoryCode::SC => 690,
+        TerritoryCode::SD => 729,
+        TerritoryCode::SE => 752,
+        TerritoryCode::SG => 702,
+        TerritoryCode::SH => 654,
+        TerritoryCode::SI => 705,
+        TerritoryCode::SJ => 744,
+        TerritoryCode::SK => 703,
+        TerritoryCode::SL => 694,
+        TerritoryCode::SM => 674,
+        TerritoryCode::SN => 686,
+        TerritoryCode::SO => 706,
+        TerritoryCode::SR => 740,
+        TerritoryCode::SS => 728,
+        TerritoryCode::ST => 678,
+        TerritoryCode::SV => 222,
+        TerritoryCode::SX => 534,
+        TerritoryCode::SY => 760,
+        TerritoryCode::SZ => 748,
+        TerritoryCode::TC => 796,
+        TerritoryCode::TD => 148,
+        TerritoryCode::TG => 768,
+        TerritoryCode::TH => 764,
+        TerritoryCode::TJ => 762,
+        TerritoryCode::TK => 772,
+        TerritoryCode::TL => 626,
+        TerritoryCode::TM => 795,
+        TerritoryCode::TN => 788,
+        TerritoryCode::TO => 776,
+        TerritoryCode::TR => 792,
+        TerritoryCode::TT => 780,
+        TerritoryCode::TV => 798,
+        TerritoryCode::TW => 158,
+        TerritoryCode::TZ => 834,
+        TerritoryCode::UA => 804,
+        TerritoryCode::UG => 800,
+        TerritoryCode::UY => 858,
+        TerritoryCode::UZ => 860,
+        TerritoryCode::VA => 336,
+        TerritoryCode::VC => 670,
+        TerritoryCode::VE => 862,
+        TerritoryCode::VG => 92,
+        TerritoryCode::VI => 850,
+        TerritoryCode::VN => 704,
+        TerritoryCode::VU => 548,
+        TerritoryCode::WF => 876,
+        TerritoryCode::WS => 882,
+        TerritoryCode::YE => 887,
+        TerritoryCode::YT => 175,
+        TerritoryCode::ZA => 710,
+        TerritoryCode::ZM => 894,
+        TerritoryCode::ZW => 716,
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_numeric(numeric: u16) -> Option<TerritoryCode> {
+    Some(match numeric {
+        840 => TerritoryCode::US,
+        124 => TerritoryCode::CA,
+        20 => TerritoryCode::AD,
+        784 => TerritoryCode::AE,
+        4 => TerritoryCode::AF,
+        28 => TerritoryCode::AG,
+        660 => TerritoryCode::AI,
+        8 => TerritoryCode::AL,
+        51 => TerritoryCode::AM,
+        24 => TerritoryCode::AO,
+        10 => TerritoryCode::AQ,
+        32 => TerritoryCode::AR,
+        16 => TerritoryCode::AS,
+        40 => TerritoryCode::AT,
+        36 => TerritoryCode::AU,
+        533 => TerritoryCode::AW,
+        248 => TerritoryCode::AX,
+        31 => TerritoryCode::AZ,
+        70 => TerritoryCode::BA,
+        52 => TerritoryCode::BB,
+        50 => TerritoryCode::BD,
+        56 => TerritoryCode::BE,
+        854 => TerritoryCode::BF,
+        100 => TerritoryCode::BG,
+        48 => TerritoryCode::BH,
+        108 => TerritoryCode::BI,
+        204 => TerritoryCode::BJ,
+        60 => TerritoryCode::BM,
+        96 => TerritoryCode::BN,
+        68 => TerritoryCode::BO,
+        535 => TerritoryCode::BQ,
+        76 => TerritoryCode::BR,
+        44 => TerritoryCode::BS,
+        64 => TerritoryCode::BT,
+        72 => TerritoryCode::BW,
+        112 => TerritoryCode::BY,
+        84 => TerritoryCode::BZ,
+        166 => TerritoryCode::CC,
+        180 => TerritoryCode::CD,
+        140 => TerritoryCode::CF,
+        178 => TerritoryCode::CG,
+        756 => TerritoryCode::CH,
+        384 => TerritoryCode::CI,
+        184 => TerritoryCode::CK,
+        152 => TerritoryCode::CL,
+        120 => TerritoryCode::CM,
+        156 => TerritoryCode::CN,
+        170 => TerritoryCode::CO,
+        188 => TerritoryCode::CR,
+        192 => TerritoryCode::CU,
+        132 => TerritoryCode::CV,
+        531 => TerritoryCode::CW,
+        162 => TerritoryCode::CX,
+        196 => TerritoryCode::CY,
+        203 => TerritoryCode::CZ,
+        276 => TerritoryCode::DE,
+        262 => TerritoryCode::DJ,
+        208 => TerritoryCode::DK,
+        212 => TerritoryCode::DM,
+        214 => TerritoryCode::DO,
+        12 => TerritoryCode::DZ,
+        218 => TerritoryCode::EC,
+        233 => TerritoryCode::EE,
+        818 => TerritoryCode::EG,
+        232 => TerritoryCode::ER,
+        724 => TerritoryCode::ES,
+        231 => TerritoryCode::ET,
+        246 => TerritoryCode::FI,
+        242 => TerritoryCode::FJ,
+        238 => TerritoryCode::FK,
+        583 => TerritoryCode::FM,
+        234 => TerritoryCode::FO,
+        250 => TerritoryCode::FR,
+        266 => TerritoryCode::GA,
+        826 => TerritoryCode::GB,
+        308 => TerritoryCode::GD,
+        268 => TerritoryCode::GE,
+        254 => TerritoryCode::GF,
+        831 => TerritoryCode::GG,
+        288 => TerritoryCode::GH,
+        292 => TerritoryCode::GI,
+        304 => TerritoryCode::GL,
+        270 => TerritoryCode::GM,
+        324 => TerritoryCode::GN,
+        312 => TerritoryCode::GP,
+        226 => TerritoryCode::GQ,
+        300 => TerritoryCode::GR,
+        320 => TerritoryCode::GT,
+        316 => TerritoryCode::GU,
+        624 => TerritoryCode::GW,
+        328 => TerritoryCode::GY,
+        344 => TerritoryCode::HK,
+        340 => TerritoryCode::HN,
+        191 => TerritoryCode::HR,
+        332 => TerritoryCode::HT,
+        348 => TerritoryCode::HU,
+        360 => TerritoryCode::ID,
+        372 => TerritoryCode::IE,
+        376 => TerritoryCode::IL,
+        833 => TerritoryCode::IM,
+        356 => TerritoryCode::IN,
+        86 => TerritoryCode::IO,
+        368 => TerritoryCode::IQ,
+        364 => TerritoryCode::IR,
+        352 => TerritoryCode::IS,
+        380 => TerritoryCode::IT,
+        832 => TerritoryCode::JE,
+        388 => TerritoryCode::JM,
+        400 => TerritoryCode::JO,
+        392 => TerritoryCode::JP,
+        404 => TerritoryCode::KE,
+        417 => TerritoryCode::KG,
+        116 => TerritoryCode::KH,
+        296 => TerritoryCode::KI,
+        174 => TerritoryCode::KM,
+        659 => TerritoryCode::KN,
+        408 => TerritoryCode::KP,
+        410 => TerritoryCode::KR,
+        414 => TerritoryCode::KW,
+        136 => TerritoryCode::KY,
+        398 => TerritoryCode::KZ,
+        418 => TerritoryCode::LA,
+        422 => TerritoryCode::LB,
+        662 => TerritoryCode::LC,
+        438 => TerritoryCode::LI,
+        144 => TerritoryCode::LK,
+        430 => TerritoryCode::LR,
+        426 => TerritoryCode::LS,
+        440 => TerritoryCode::LT,
+        442 => TerritoryCode::LU,
+        428 => TerritoryCode::LV,
+        434 => TerritoryCode::LY,
+        504 => TerritoryCode::MA,
+        492 => TerritoryCode::MC,
+        498 => TerritoryCode::MD,
+        499 => TerritoryCode::ME,
+        450 => TerritoryCode::MG,
+        584 => TerritoryCode::MH,
+        807 => TerritoryCode::MK,
+        466 => TerritoryCode::ML,
+        104 => TerritoryCode::MM,
+        496 => TerritoryCode::MN,
+        446 => TerritoryCode::MO,
+        580 => TerritoryCode::MP,
+        474 => TerritoryCode::MQ,
+        478 => TerritoryCode::MR,
+        500 => TerritoryCode::MS,
+        470 => TerritoryCode::MT,
+        480 => TerritoryCode::MU,
+        462 => TerritoryCode::MV,
+        454 => TerritoryCode::MW,
+        484 => TerritoryCode::MX,
+        458 => TerritoryCode::MY,
+        508 => TerritoryCode::MZ,
+        516 => TerritoryCode::NA,
+        540 => TerritoryCode::NC,
+        562 => TerritoryCode::NE,
+        574 => TerritoryCode::NF,
+        566 => TerritoryCode::NG,
+        558 => TerritoryCode::NI,
+        528 => TerritoryCode::NL,
+        578 => TerritoryCode::NO,
+        524 => TerritoryCode::NP,
+        520 => TerritoryCode::NR,
+        570 => TerritoryCode::NU,
+        554 => TerritoryCode::NZ,
+        512 => TerritoryCode::OM,
+        591 => TerritoryCode::PA,
+        604 => TerritoryCode::PE,
+        258 => TerritoryCode::PF,
+        598 => TerritoryCode::PG,
+        608 => TerritoryCode::PH,
+        586 => TerritoryCode::PK,
+        616 => TerritoryCode::PL,
+        666 => TerritoryCode::PM,
+        630 => TerritoryCode::PR,
+        275 => TerritoryCode::PS,
+        620 => TerritoryCode::PT,
+        585 => TerritoryCode::PW,
+        600 => TerritoryCode::PY,
+        634 => TerritoryCode::QA,
+        638 => TerritoryCode::RE,
+        642 => TerritoryCode::RO,
+        688 => TerritoryCode::RS,
+        643 => TerritoryCode::RU,
+        646 => TerritoryCode::RW,
+        682 => TerritoryCode::SA,
+        90 => TerritoryCode::SB,
+        690 => TerritoryCode::SC,
+        729 => TerritoryCode::SD,
+        752 => TerritoryCode::SE,
+        702 => TerritoryCode::SG,
+        654 => TerritoryCode::SH,
+        705 => TerritoryCode::SI,
+        744 => TerritoryCode::SJ,
+        703 => TerritoryCode::SK,
+        694 => TerritoryCode::SL,
+        674 => TerritoryCode::SM,
+        686 => TerritoryCode::SN,
+        706 => TerritoryCode::SO,
+        740 => TerritoryCode::SR,
+        728 => TerritoryCode::SS,
+        678 => TerritoryCode::ST,
+        222 => TerritoryCode::SV,
+        534 => TerritoryCode::SX,
+        760 => TerritoryCode::SY,
+        748 => TerritoryCode::SZ,
+        796 => TerritoryCode::TC,
+        148 => TerritoryCode::TD,
+        768 => TerritoryCode::TG,
+        764 => TerritoryCode::TH,
+        762 => TerritoryCode::TJ,
+        772 => TerritoryCode::TK,
+        626 => TerritoryCode::TL,
+        795 => TerritoryCode::TM,
+        788 => TerritoryCode::TN,
+        776 => TerritoryCode::TO,
+        792 => TerritoryCode::TR,
+        780 => TerritoryCode::TT,
+        798 => TerritoryCode::TV,
+        158 => TerritoryCode::TW,
+        834 => TerritoryCode::TZ,
+        804 => TerritoryCode::UA,
+        800 => TerritoryCode::UG,
+        858 => TerritoryCode::UY,
+        860 => TerritoryCode::UZ,
+        336 => TerritoryCode::VA,
+        670 => TerritoryCode::VC,
+        862 => TerritoryCode::VE,
+        92 => TerritoryCode::VG,
+        850 => TerritoryCode::VI,
+        704 => TerritoryCode::VN,
+        548 => TerritoryCode::VU,
+        876 => TerritoryCode::WF,
+        882 => TerritoryCode::WS,
+        887 => TerritoryCode::YE,
+        175 => TerritoryCode::YT,
+        710 => TerritoryCode::ZA,
+        894 => TerritoryCode::ZM,
+        716 => TerritoryCode::ZW,
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn english_name(territory: TerritoryCode) -> &'static str {
+    match territory {
+        TerritoryCode::US => "United States",
+        TerritoryCode::CA => "Canada",
+        TerritoryCode::AC => "Ascension Island",
+        TerritoryCode::AD => "Andorra",
+        TerritoryCode::AE => "United Arab Emirates",
+        TerritoryCode::AF => "Afghanistan",
+        TerritoryCode::AG => "Antigua and Barbuda",
+        TerritoryCode::AI => "Anguilla",
+        TerritoryCode::AL => "Albania",
+        TerritoryCode::AM => "Armenia",
+        TerritoryCode::AO => "Angola",
+        TerritoryCode::AQ => "Antarctica",
+        TerritoryCode::AR => "Argentina",
+        TerritoryCode::AS => "American Samoa",
+        TerritoryCode::AT => "Austria",
+        TerritoryCode::AU => "Australia",
+        TerritoryCode::AW => "Aruba",
+        TerritoryCode::AX => "Åland Islands",
+        TerritoryCode::AZ => "Azerbaijan",
+        TerritoryCode::BA => "Bosnia and Herzegovina",
+        TerritoryCode::BB => "Barbados",
+        TerritoryCode::BD => "Bangladesh",
+        TerritoryCode::BE => "Belgium",
+        TerritoryCode::BF => "Burkina Faso",
+        TerritoryCode::BG => "Bulgaria",
+        TerritoryCode::BH => "Bahrain",
+        TerritoryCode::BI => "Burundi",
+        TerritoryCode::BJ => "Benin",
+        TerritoryCode::BM => "Bermuda",
+        TerritoryCode::BN => "Brunei",
+        TerritoryCode::BO => "Bolivia",
+        TerritoryCode::BQ => "Caribbean Netherlands",
+        TerritoryCode::BR => "Brazil",
+        TerritoryCode::BS => "Bahamas",
+        TerritoryCode::BT => "Bhutan",
+        TerritoryCode::BW => "Botswana",
+        TerritoryCode::BY => "Belarus",
+        TerritoryCode::BZ => "Belize",
+        TerritoryCode::CC => "Cocos (Keeling) Islands",
+        TerritoryCode::CD => "Democratic Republic of the Congo",
+        TerritoryCode::CF => "Central African Republic",
+        TerritoryCode::CG => "Republic of the Congo",
+        TerritoryCode::CH => "Switzerland",
+        TerritoryCode::CI => "Côte d'Ivoire",
+        TerritoryCode::CK => "Cook Islands",
+        TerritoryCode::CL => "Chile",
+        TerritoryCode::CM => "Cameroon",
+        TerritoryCode::CN => "China",
+        TerritoryCode::CO => "Colombia",
+        TerritoryCode::CR => "Costa Rica",
+        TerritoryCode::CU => "Cuba",
+        TerritoryCode::CV => "Cape Verde",
+        TerritoryCode::CW => "Curaçao",
+        TerritoryCode::CX => "Christmas Island",
+        TerritoryCode::CY => "Cyprus",
+        TerritoryCode::CZ => "Czechia",
+        TerritoryCode::DE => "Germany",
+        TerritoryCode::DJ => "Djibouti",
+        TerritoryCode::DK => "Denmark",
+        TerritoryCode::DM => "Dominica",
+        TerritoryCode::DO => "Dominican Republic",
+        TerritoryCode::DZ => "Algeria",
+        TerritoryCode::EC => "Ecuador",
+        TerritoryCode::EE => "Estonia",
+        TerritoryCode::EG => "Egypt",
+        TerritoryCode::ER => "Eritrea",
+        TerritoryCode::ES => "Spain",
+        TerritoryCode::ET => "Ethiopia",
+        TerritoryCode::FI => "Finland",
+        TerritoryCode::FJ => "Fiji",
+        TerritoryCode::FK => "Falkland Islands",
+        TerritoryCode::FM => "Micronesia",
+        TerritoryCode::FO => "Faroe Islands",
+        TerritoryCode::FR => "France",
+        TerritoryCode::GA => "Gabon",
+        TerritoryCode::GB => "United Kingdom",
+        TerritoryCode::GD => "Grenada",
+        TerritoryCode::GE => "Georgia",
+        TerritoryCode::GF => "French Guiana",
+        TerritoryCode::GG => "Guernsey",
+        TerritoryCode::GH => "Ghana",
+        TerritoryCode::GI => "Gibraltar",
+        TerritoryCode::GL => "Greenland",
+        TerritoryCode::GM => "Gambia",
+        TerritoryCode::GN => "Guinea",
+        TerritoryCode::GP => "Guadeloupe",
+        TerritoryCode::GQ => "Equatorial Guinea",
+        TerritoryCode::GR => "Greece",
+        TerritoryCode::GT => "Guatemala",
+        TerritoryCode::GU => "Guam",
+        TerritoryCode::GW => "Guinea-Bissau",
+        TerritoryCode::GY => "Guyana",
+        TerritoryCode::HK => "Hong Kong",
+        TerritoryCode::HN => "Honduras",
+        TerritoryCode::HR => "Croatia",
+        TerritoryCode::HT => "Haiti",
+        TerritoryCode::HU => "Hungary",
+        TerritoryCode::ID => "Indonesia",
+        TerritoryCode::IE => "Ireland",
+        TerritoryCode::IL => "Israel",
+        TerritoryCode::IM => "Isle of Man",
+        TerritoryCode::IN => "India",
+        TerritoryCode::IO => "British Indian Ocean Territory",
+        TerritoryCode::IQ => "Iraq",
+        TerritoryCode::IR => "Iran",
+        TerritoryCode::IS => "Iceland",
+        TerritoryCode::IT => "Italy",
+        TerritoryCode::JE => "Jersey",
+        TerritoryCode::JM => "Jamaica",
+        TerritoryCode::JO => "Jordan",
+        TerritoryCode::JP => "Japan",
+        TerritoryCode::KE => "Kenya",
+        TerritoryCode::KG => "Kyrgyzstan",
+        TerritoryCode::KH => "Cambodia",
+        TerritoryCode::KI => "Kiribati",
+        TerritoryCode::KM => "Comoros",
+        TerritoryCode::KN => "Saint Kitts and Nevis",
+        TerritoryCode::KP => "North Korea",
+        TerritoryCode::KR => "South Korea",
+        TerritoryCode::KW => "Kuwait",
+        TerritoryCode::KY => "Cayman Islands",
+        TerritoryCode::KZ => "Kazakhstan",
+        TerritoryCode::LA => "Laos",
+        TerritoryCode::LB => "Lebanon",
+        TerritoryCode::LC => "Saint Lucia",
+        TerritoryCode::LI => "Liechtenstein",
+        TerritoryCode::LK => "Sri Lanka",
+        TerritoryCode::LR => "Liberia",
+        TerritoryCode::LS => "Lesotho",
+        TerritoryCode::LT => "Lithuania",
+        TerritoryCode::LU => "Luxembourg",
+        TerritoryCode::LV => "Latvia",
+        TerritoryCode::LY => "Libya",
+        TerritoryCode::MA => "Morocco",
+        TerritoryCode::MC => "Monaco",
+        TerritoryCode::MD => "Moldova",
+        TerritoryCode::ME => "Montenegro",
+        TerritoryCode::MG => "Madagascar",
+        TerritoryCode::MH => "Marshall Islands",
+        TerritoryCode::MK => "North Macedonia",
+        TerritoryCode::ML => "Mali",
+        TerritoryCode::MM => "Myanmar",
+        TerritoryCode::MN => "Mongolia",
+        TerritoryCode::MO => "Macao",
+        TerritoryCode::MP => "Northern Mariana Islands",
+        TerritoryCode::MQ => "Martinique",
+        TerritoryCode::MR => "Mauritania",
+        TerritoryCode::MS => "Montserrat",
+        TerritoryCode::MT => "Malta",
+        TerritoryCode::MU => "Mauritius",
+        TerritoryCode::MV => "Maldives",
+        TerritoryCode::MW => "Malawi",
+        TerritoryCode::MX => "Mexico",
+        TerritoryCode::MY => "Malaysia",
+        TerritoryCode::MZ => "Mozambique",
+        TerritoryCode::NA => "Namibia",
+        TerritoryCode::NC => "New Caledonia",
+        TerritoryCode::NE => "Niger",
+        TerritoryCode::NF => "Norfolk Island",
+        TerritoryCode::NG => "Nigeria",
+        TerritoryCode::NI => "Nicaragua",
+        TerritoryCode::NL => "Netherlands",
+        TerritoryCode::NO => "Norway",
+        TerritoryCode::NP => "Nepal",
+        TerritoryCode::NR => "Nauru",
+        TerritoryCode::NU => "Niue",
+        TerritoryCode::NZ => "New Zealand",
+        TerritoryCode::OM => "Oman",
+        TerritoryCode::PA => "Panama",
+        TerritoryCode::PE => "Peru",
+        TerritoryCode::PF => "French Polynesia",
+        TerritoryCode::PG => "Papua New Guinea",
+        TerritoryCode::PH => "Philippines",
+        TerritoryCode::PK => "Pakistan",
+        TerritoryCode::PL => "Poland",
+        TerritoryCode::PM => "Saint Pierre and Miquelon",
+        TerritoryCode::PR => "Puerto Rico",
+        TerritoryCode::PS => "Palestine",
+        TerritoryCode::PT => "Portugal",
+        TerritoryCode::PW => "Palau",
+        TerritoryCode::PY => "Paraguay",
+        TerritoryCode::QA => "Qatar",
+        TerritoryCode::RE => "Réunion",
+        TerritoryCode::RO => "Romania",
+        TerritoryCode::RS => "Serbia",
+        TerritoryCode::RU => "Russia",
+        TerritoryCode::RW => "Rwanda",
+        TerritoryCode::SA => "Saudi Arabia",
+        TerritoryCode::SB => "Solomon Islands",
+        TerritoryCode::SC => "Seychelles",
+        TerritoryCode::SD => "Sudan",
+        TerritoryCode::SE => "Sweden",
+        TerritoryCode::SG => "Singapore",
+        TerritoryCode::SH => "Saint Helena",
+        TerritoryCode::SI => "Slovenia",
+        TerritoryCode::SJ => "Svalbard and Jan Mayen",
+        TerritoryCode::SK => "Slovakia",
+        TerritoryCode::SL => "Sierra Leone",
+        TerritoryCode::SM => "San Marino",
+        TerritoryCode::SN => "Senegal",
+        TerritoryCode::SO => "Somalia",
+        TerritoryCode::SR => "Suriname",
+        TerritoryCode::SS => "South Sudan",
+        TerritoryCode::ST => "São Tomé and Príncipe",
+        TerritoryCode::SV => "El Salvador",
+        TerritoryCode::SX => "Sint Maarten",
+        TerritoryCode::SY => "Syria",
+        TerritoryCode::SZ => "Eswatini",
+        TerritoryCode::TC => "Turks and Caicos Islands",
+        TerritoryCode::TD => "Chad",
+        TerritoryCode::TG => "Togo",
+        TerritoryCode::TH => "Thailand",
+        TerritoryCode::TJ => "Tajikistan",
+        TerritoryCode::TK => "Tokelau",
+        TerritoryCode::TL => "Timor-Leste",
+        TerritoryCode::TM => "Turkmenistan",
+        TerritoryCode::TN => "Tunisia",
+        TerritoryCode::TO => "Tonga",
+        TerritoryCode::TR => "Türkiye",
+        TerritoryCode::TT => "Trinidad and Tobago",
+        TerritoryCode::TV => "Tuvalu",
+        TerritoryCode::TW => "Taiwan",
+        TerritoryCode::TZ => "Tanzania",
+        TerritoryCode::UA => "Ukraine",
+        TerritoryCode::UG => "Uganda",
+        TerritoryCode::UY => "Uruguay",
+        TerritoryCode::UZ => "Uzbekistan",
+        TerritoryCode::VA => "Vatican City",
+        TerritoryCode::VC => "Saint Vincent and the Grenadines",
+        TerritoryCode::VE => "Venezuela",
+        TerritoryCode::VG => "British Virgin Islands",
+        TerritoryCode::VI => "U.S. Virgin Islands",
+        TerritoryCode::VN => "Vietnam",
+        TerritoryCode::VU => "Vanuatu",
+        TerritoryCode::WF => "Wallis and Futuna",
+        TerritoryCode::WS => "Samoa",
+        TerritoryCode::XG => "Global Mobile Satellite System",
+        TerritoryCode::XN => "Inmarsat",
+        TerritoryCode::XP => "Universal Personal Telecommunications",
+        TerritoryCode::XS => "International Shared Cost Service",
+        TerritoryCode::XT => "International Freephone",
+        TerritoryCode::XV => "International Networks",
+        TerritoryCode::YE => "Yemen",
+        TerritoryCode::YT => "Mayotte",
+        TerritoryCode::ZA => "South Africa",
+        TerritoryCode::ZM => "Zambia",
+        TerritoryCode::ZW => "Zimbabwe",
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn official_name(territory: TerritoryCode) -> &'static str {
+    match territory {
+        TerritoryCode::US => "United States of America",
+        TerritoryCode::CA => "Canada",
+        TerritoryCode::AC => "Ascension Island",
+        TerritoryCode::AD => "Principality of Andorra",
+        TerritoryCode::AE => "United Arab Emirates",
+        TerritoryCode::AF => "Islamic Republic of Afghanistan",
+        TerritoryCode::AG => "Antigua and Barbuda",
+        TerritoryCode::AI => "Anguilla",
+        TerritoryCode::AL => "Republic of Albania",
+        TerritoryCode::AM => "Republic of Armenia",
+        TerritoryCode::AO => "Republic of Angola",
+        TerritoryCode::AQ => "Antarctica",
+        TerritoryCode::AR => "Argentine Republic",
+        TerritoryCode::AS => "American Samoa",
+        TerritoryCode::AT => "Republic of Austria",
+        TerritoryCode::AU => "Commonwealth of Australia",
+        TerritoryCode::AW => "Aruba",
+        TerritoryCode::AX => "Åland Islands",
+        TerritoryCode::AZ => "Republic of Azerbaijan",
+        TerritoryCode::BA => "Bosnia and Herzegovina",
+        TerritoryCode::BB => "Barbados",
+        TerritoryCode::BD => "People's Republic of Bangladesh",
+        TerritoryCode::BE => "Kingdom of Belgium",
+        TerritoryCode::BF => "Burkina Faso",
+        TerritoryCode::BG => "Republic of Bulgaria",
+        TerritoryCode::BH => "Kingdom of Bahrain",
+        TerritoryCode::BI => "Republic of Burundi",
+        TerritoryCode::BJ => "Republic of Benin",
+        TerritoryCode::BM => "Bermuda",
+        TerritoryCode::BN => "Brunei Darussalam",
+        TerritoryCode::BO => "Plurinational State of Bolivia",
+        TerritoryCode::BQ => "Bonaire, Sint Eustatius and Saba",
+        TerritoryCode::BR => "Federative Republic of Brazil",
+        TerritoryCode::BS => "Commonwealth of The Bahamas",
+        TerritoryCode::BT => "Kingdom of Bhutan",
+        TerritoryCode::BW => "Republic of Botswana",
+        TerritoryCode::BY => "Republic of Belarus",
+        TerritoryCode::BZ => "Belize",
+        TerritoryCode::CC => "Cocos (Keeling) Islands",
+        TerritoryCode::CD => "Democratic Republic of the Congo",
+        TerritoryCode::CF => "Central African Republic",
+        TerritoryCode::CG => "Republic of the Congo",
+        TerritoryCode::CH => "Swiss Confederation",
+        TerritoryCode::CI => "Republic of Côte d'Ivoire",
+        TerritoryCode::CK => "Cook Islands",
+        TerritoryCode::CL => "Republic of Chile",
+        TerritoryCode::CM => "Republic of Cameroon",
+        TerritoryCode::CN => "People's Republic of China",
+        TerritoryCode::CO => "Republic of Colombia",
+        TerritoryCode::CR => "Republic of Costa Rica",
+        TerritoryCode::CU => "Republic of Cuba",
+        TerritoryCode::CV => "Republic of Cabo Verde",
+        TerritoryCode::CW => "Curaçao",
+        TerritoryCode::CX => "Christmas Island",
+        TerritoryCode::CY => "Republic of Cyprus",
+        TerritoryCode::CZ => "Czech Republic",
+        TerritoryCode::DE => "Federal Republic of Germany",
+        TerritoryCode::DJ => "Republic of Djibouti",
+        TerritoryCode::DK => "Kingdom of Denmark",
+        TerritoryCode::DM => "Commonwealth of Dominica",
+        TerritoryCode::DO => "Dominican Republic",
+        TerritoryCode::DZ => "People's Democratic Republic of Algeria",
+        TerritoryCode::EC => "Republic of Ecuador",
+        TerritoryCode::EE => "Republic of Estonia",
+        TerritoryCode::EG => "Arab Republic of Egypt",
+        TerritoryCode::ER => "State of Eritrea",
+        TerritoryCode::ES => "Kingdom of Spain",
+        TerritoryCode::ET => "Federal Democratic Republic of Ethiopia",
+        TerritoryCode::FI => "Republic of Finland",
+        TerritoryCode::FJ => "Republic of Fiji",
+        TerritoryCode::FK => "Falkland Islands (Malvinas)",
+        TerritoryCode::FM => "Federated States of Micronesia",
+        TerritoryCode::FO => "Faroe Islands",
+        TerritoryCode::FR => "French Republic",
+        TerritoryCode::GA => "Gabonese Republic",
+        TerritoryCode::GB => "United Kingdom of Great Britain and Northern Ireland",
+        TerritoryCode::GD => "Grenada",
+        TerritoryCode::GE => "Georgia",
+        TerritoryCode::GF => "French Guiana",
+        TerritoryCode::GG => "Bailiwick of Guernsey",
+        TerritoryCode::GH => "Republic of Ghana",
+        TerritoryCode::GI => "Gibraltar",
+        TerritoryCode::GL => "Greenland",
+        TerritoryCode::GM => "Republic of The Gambia",
+        TerritoryCode::GN => "Republic of Guinea",
+        TerritoryCode::GP => "Guadeloupe",
+        TerritoryCode::GQ => "Republic of Equatorial Guinea",
+        TerritoryCode::GR => "Hellenic Republic",
+        TerritoryCode::GT => "Republic of Guatemala",
+        TerritoryCode::GU => "Guam",
+        TerritoryCode::GW => "Republic of Guinea-Bissau",
+        TerritoryCode::GY => "Co-operative Republic of Guyana",
+        TerritoryCode::HK => "Hong Kong Special Administrative Region of China",
+        TerritoryCode::HN => "Republic of Honduras",
+        TerritoryCode::HR => "Republic of Croatia",
+        TerritoryCode::HT => "Republic of Haiti",
+        TerritoryCode::HU => "Hungary",
+        TerritoryCode::ID => "Republic of Indonesia",
+        TerritoryCode::IE => "Ireland",
+        TerritoryCode::IL => "State of Israel",
+        TerritoryCode::IM => "Isle of Man",
+        TerritoryCode::IN => "Republic of India",
+        TerritoryCode::IO => "British Indian Ocean Territory",
+        TerritoryCode::IQ => "Republic of Iraq",
+        TerritoryCode::IR => "Islamic Republic of Iran",
+        TerritoryCode::IS => "Iceland",
+        TerritoryCode::IT => "Italian Republic",
+        TerritoryCode::JE => "Bailiwick of Jersey",
+        TerritoryCode::JM => "Jamaica",
+        TerritoryCode::JO => "Hashemite Kingdom of Jordan",
+        TerritoryCode::JP => "Japan",
+        TerritoryCode::KE => "Republic of Kenya",
+        TerritoryCode::KG => "Kyrgyz Republic",
+        TerritoryCode::KH => "Kingdom of Cambodia",
+        TerritoryCode::KI => "Republic of Kiribati",
+        TerritoryCode::KM => "Union of the Comoros",
+        TerritoryCode::KN => "Saint Kitts and Nevis",
+        TerritoryCode::KP => "Democratic People's Republic of Korea",
+        TerritoryCode::KR => "Republic of Korea",
+        TerritoryCode::KW => "State of Kuwait",
+        TerritoryCode::KY => "Cayman Islands",
+        TerritoryCode::KZ => "Republic of Kazakhstan",
+        TerritoryCode::LA => "Lao People's Democratic Republic",
+        TerritoryCode::LB => "Lebanese Republic",
+        TerritoryCode::LC => "Saint Lucia",
+        TerritoryCode::LI => "Principality of Liechtenstein",
+        TerritoryCode::LK => "Democratic Socialist Republic of Sri Lanka",
+        TerritoryCode::LR => "Republic of Liberia",
+        TerritoryCode::LS => "Kingdom of Lesotho",
+        TerritoryCode::LT => "Republic of Lithuania",
+        TerritoryCode::LU => "Grand Duchy of Luxembourg",
+        TerritoryCode::LV => "Republic of Latvia",
+        TerritoryCode::LY => "State of Libya",
+        TerritoryCode::MA => "Kingdom of Morocco",
+        TerritoryCode::MC => "Principality of Monaco",
+        TerritoryCode::MD => "Republic of Moldova",
+        TerritoryCode::ME => "Montenegro",
+        TerritoryCode::MG => "Republic of Madagascar",
+        TerritoryCode::MH => "Republic of the Marshall Islands",
+        TerritoryCode::MK => "Republic of North Macedonia",
+        TerritoryCode::ML => "Republic of Mali",
+        TerritoryCode::MM => "Republic of the Union of Myanmar",
+        TerritoryCode::MN => "Mongolia",
+        TerritoryCode::MO => "Macao Special Administrative Region of China",
+        TerritoryCode::MP => "Commonwealth of the Northern Mariana Islands",
+        TerritoryCode::MQ => "Martinique",
+        TerritoryCode::MR => "Islamic Republic of Mauritania",
+        TerritoryCode::MS => "Montserrat",
+        TerritoryCode::MT => "Republic of Malta",
+        TerritoryCode::MU => "Republic of Mauritius",
+        TerritoryCode::MV => "Republic of Maldives",
+        TerritoryCode::MW => "Republic of Malawi",
+        TerritoryCode::MX => "United Mexican States",
+        TerritoryCode::MY => "Malaysia",
+        TerritoryCode::MZ => "Republic of Mozambique",
+        TerritoryCode::NA => "Republic of Namibia",
+        TerritoryCode::NC => "New Caledonia",
+        TerritoryCode::NE => "Republic of the Niger",
+        TerritoryCode::NF => "Norfolk Island",
+        TerritoryCode::NG => "Federal Republic of Nigeria",
+        TerritoryCode::NI => "Republic of Nicaragua",
+        TerritoryCode::NL => "Kingdom of the Netherlands",
+        TerritoryCode::NO => "Kingdom of Norway",
+        TerritoryCode::NP => "Federal Democratic Republic of Nepal",
+        TerritoryCode::NR => "Republic of Nauru",
+        TerritoryCode::NU => "Niue",
+        TerritoryCode::NZ => "New Zealand",
+        TerritoryCode::OM => "Sultanate of Oman",
+        TerritoryCode::PA => "Republic of Panama",
+        TerritoryCode::PE => "Republic of Peru",
+        TerritoryCode::PF => "French Polynesia",
+        TerritoryCode::PG => "Independent State of Papua New Guinea",
+        TerritoryCode::PH => "Republic of the Philippines",
+        TerritoryCode::PK => "Islamic Republic of Pakistan",
+        TerritoryCode::PL => "Republic of Poland",
+        TerritoryCode::PM => "Saint Pierre and Miquelon",
+        TerritoryCode::PR => "Commonwealth of Puerto Rico",
+        TerritoryCode::PS => "State of Palestine",
+        TerritoryCode::PT => "Portuguese Republic",
+        TerritoryCode::PW => "Republic of Palau",
+        TerritoryCode::PY => "Republic of Paraguay",
+        TerritoryCode::QA => "State of Qatar",
+        TerritoryCode::RE => "Réunion",
+        TerritoryCode::RO => "Romania",
+        TerritoryCode::RS => "Republic of Serbia",
+        TerritoryCode::RU => "Russian Federation",
+        TerritoryCode::RW => "Republic of Rwanda",
+        TerritoryCode::SA => "Kingdom of Saudi Arabia",
+        TerritoryCode::SB => "Solomon Islands",
+        TerritoryCode::SC => "Republic of Seychelles",
+        TerritoryCode::SD => "Republic of the Sudan",
+        TerritoryCode::SE => "Kingdom of Sweden",
+        TerritoryCode::SG => "Republic of Singapore",
+        TerritoryCode::SH => "Saint Helena, Ascension and Tristan da Cunha",
+        TerritoryCode::SI => "Republic of Slovenia",
+        TerritoryCode::SJ => "Svalbard and Jan Mayen",
+        TerritoryCode::SK => "Slovak Republic",
+        TerritoryCode::SL => "Republic of Sierra Leone",
+        TerritoryCode::SM => "Republic of San Marino",
+        TerritoryCode::SN => "Republic of Senegal",
+        TerritoryCode::SO => "Federal Republic of Somalia",
+        TerritoryCode::SR => "Republic of Suriname",
+        TerritoryCode::SS => "Republic of South Sudan",
+        TerritoryCode::ST => "Democratic Republic of São Tomé and Príncipe",
+        TerritoryCode::SV => "Republic of El Salvador",
+        TerritoryCode::SX => "Sint Maarten (Dutch part)",
+        TerritoryCode::SY => "Syrian Arab Republic",
+        TerritoryCode::SZ => "Kingdom of Eswatini",
+        TerritoryCode::TC => "Turks and Caicos Islands",
+        TerritoryCode::TD => "Republic of Chad",
+        TerritoryCode::TG => "Togolese Republic",
+        TerritoryCode::TH => "Kingdom of Thailand",
+        TerritoryCode::TJ => "Republic of Tajikistan",
+        TerritoryCode::TK => "Tokelau",
+        TerritoryCode::TL => "Democratic Republic of Timor-Leste",
+        TerritoryCode::TM => "Turkmenistan",
+        TerritoryCode::TN => "Republic of Tunisia",
+        TerritoryCode::TO => "Kingdom of Tonga",
+        TerritoryCode::TR => "Republic of Türkiye",
+        TerritoryCode::TT => "Republic of Trinidad and Tobago",
+        TerritoryCode::TV => "Tuvalu",
+        TerritoryCode::TW => "Taiwan",
+        TerritoryCode::TZ => "United Republic of Tanzania",
+        TerritoryCode::UA => "Ukraine",
+        TerritoryCode::UG => "Republic of Uganda",
+        TerritoryCode::UY => "Eastern Republic of Uruguay",
+        TerritoryCode::UZ => "Republic of Uzbekistan",
+        TerritoryCode::VA => "Holy See",
+        TerritoryCode::VC => "Saint Vincent and the Grenadines",
+        TerritoryCode::VE => "Bolivarian Republic of Venezuela",
+        TerritoryCode::VG => "Virgin Islands (British)",
+        TerritoryCode::VI => "Virgin Islands of the United States",
+        TerritoryCode::VN => "Socialist Republic of Viet Nam",
+        TerritoryCode::VU => "Republic of Vanuatu",
+        TerritoryCode::WF => "Territory of the Wallis and Futuna Islands",
+        TerritoryCode::WS => "Independent State of Samoa",
+        TerritoryCode::XG => "Global Mobile Satellite System",
+        TerritoryCode::XN => "Inmarsat",
+        TerritoryCode::XP => "Universal Personal Telecommunications",
+        TerritoryCode::XS => "International Shared Cost Service",
+        TerritoryCode::XT => "International Freephone",
+        TerritoryCode::XV => "International Networks",
+        TerritoryCode::YE => "Republic of Yemen",
+        TerritoryCode::YT => "Mayotte",
+        TerritoryCode::ZA => "Republic of South Africa",
+        TerritoryCode::ZM => "Republic of Zambia",
+        TerritoryCode::ZW => "Republic of Zimbabwe",
+    }
+}
+
+/// The calling code of a territory as written by the deprecated `find_phone_cc`
+#[rustfmt::skip]
+pub(crate) fn calling_code_text(territory: TerritoryCode) -> &'static str {
+    match territory {
+        TerritoryCode::US => "1",
+        TerritoryCode::CA => "1",
+        TerritoryCode::AC => "247",
+        TerritoryCode::AD => "376",
+        TerritoryCode::AE => "971",
+        TerritoryCode::AF => "93",
+        TerritoryCode::AG => "1",
+        TerritoryCode::AI => "1",
+        TerritoryCode::AL => "355",
+        TerritoryCode::AM => "374",
+        TerritoryCode::AO => "244",
+        TerritoryCode::AQ => "672",
+        TerritoryCode::AR => "54",
+        TerritoryCode::AS => "1",
+        TerritoryCode::AT => "43",
+        TerritoryCode::AU => "61",
+        TerritoryCode::AW => "297",
+        TerritoryCode::AX => "35818",
+        TerritoryCode::AZ => "994",
+        TerritoryCode::BA => "387",
+        TerritoryCode::BB => "1",
+        TerritoryCode::BD => "880",
+        TerritoryCode::BE => "32",
+        TerritoryCode::BF => "226",
+        TerritoryCode::BG => "359",
+        TerritoryCode::BH => "973",
+        TerritoryCode::BI => "257",
+        TerritoryCode::BJ => "229",
+        TerritoryCode::BM => "1",
+        TerritoryCode::BN => "673",
+        TerritoryCode::BO => "591",
+        TerritoryCode::BQ => "599",
+        TerritoryCode::BR => "55",
+        TerritoryCode::BS => "1",
+        TerritoryCode::BT => "975",
+        TerritoryCode::BW => "267",
+        TerritoryCode::BY => "375",
+        TerritoryCode::BZ => "501",
+        TerritoryCode::CC => "6189162",
+        TerritoryCode::CD => "243",
+        TerritoryCode::CF => "236",
+        TerritoryCode::CG => "242",
+        TerritoryCode::CH => "41",
+        TerritoryCode::CI => "225",
+        TerritoryCode::CK => "682",
+        TerritoryCode::CL => "56",
+        TerritoryCode::CM => "237",
+        TerritoryCode::CN => "86",
+        TerritoryCode::CO => "57",
+        TerritoryCode::CR => "506",
+        TerritoryCode::CU => "53",
+        TerritoryCode::CV => "238",
+        TerritoryCode::CW => "5999",
+        TerritoryCode::CX => "6189164",
+        TerritoryCode::CY => "357",
+        TerritoryCode::CZ => "420",
+        TerritoryCode::DE => "49",
+        TerritoryCode::DJ => "253",
+        TerritoryCode::DK => "45",
+        TerritoryCode::DM => "1",
+        TerritoryCode::DO => "1",
+        TerritoryCode::DZ => "213",
+        TerritoryCode::EC => "593",
+        TerritoryCode::EE => "372",
+        TerritoryCode::EG => "20",
+        TerritoryCode::ER => "291",
+        TerritoryCode::ES => "34",
+        TerritoryCode::ET => "251",
+        TerritoryCode::FI => "358",
+        TerritoryCode::FJ => "679",
+        TerritoryCode::FK => "500",
+        TerritoryCode::FM => "691",
+        TerritoryCode::FO => "298",
+        TerritoryCode::FR => "33",
+        TerritoryCode::GA => "241",
+        TerritoryCode::GB => "44",
+        TerritoryCode::GD => "1",
+        TerritoryCode::GE => "995",
+        TerritoryCode::GF => "594",
+        TerritoryCode::GG => "441481",
+        TerritoryCode::GH => "233",
+        TerritoryCode::GI => "350",
+        TerritoryCode::GL => "299",
+        TerritoryCode::GM => "220",
+        TerritoryCode::GN => "224",
+        TerritoryCode::GP => "590",
+        TerritoryCode::GQ => "240",
+        TerritoryCode::GR => "30",
+        TerritoryCode::GT => "502",
+        TerritoryCode::GU => "1",
+        TerritoryCode::GW => "245",
+        TerritoryCode::GY => "592",
+        TerritoryCode::HK => "852",
+        TerritoryCode::HN => "504",
+        TerritoryCode::HR => "385",
+        TerritoryCode::HT => "509",
+        TerritoryCode::HU => "36",
+        TerritoryCode::ID => "62",
+        TerritoryCode::IE => "353",
+        TerritoryCode::IL => "972",
+        TerritoryCode::IM => "441624",
+        TerritoryCode::IN => "91",
+        TerritoryCode::IO => "246",
+        TerritoryCode::IQ => "964",
+        TerritoryCode::IR => "98",
+        TerritoryCode::IS => "354",
+        TerritoryCode::IT => "39",
+        TerritoryCode::JE => "441534",
+        TerritoryCode::JM => "1",
+        TerritoryCode::JO => "962",
+        TerritoryCode::JP => "81",
+        TerritoryCode::KE => "254",
+        TerritoryCode::KG => "996",
+        TerritoryCode::KH => "855",
+        TerritoryCode::KI => "686",
+        TerritoryCode::KM => "269",
+        TerritoryCode::KN => "1",
+        TerritoryCode::KP => "850",
+        TerritoryCode::KR => "82",
+        TerritoryCode::KW => "965",
+        TerritoryCode::KY => "1",
+        TerritoryCode::KZ => "7",
+        TerritoryCode::LA => "856",
+        TerritoryCode::LB => "961",
+        TerritoryCode::LC => "1",
+        TerritoryCode::LI => "423",
+        TerritoryCode::LK => "94",
+        TerritoryCode::LR => "231",
+        TerritoryCode::LS => "266",
+        TerritoryCode::LT => "370",
+        TerritoryCode::LU => "352",
+        TerritoryCode::LV => "371",
+        TerritoryCode::LY => "218",
+        TerritoryCode::MA => "212",
+        TerritoryCode::MC => "377",
+        TerritoryCode::MD => "373",
+        TerritoryCode::ME => "382",
+        TerritoryCode::MG => "261",
+        TerritoryCode::MH => "692",
+        TerritoryCode::MK => "389",
+        TerritoryCode::ML => "223",
+        TerritoryCode::MM => "95",
+        TerritoryCode::MN => "976",
+        TerritoryCode::MO => "853",
+        TerritoryCode::MP => "1",
+        TerritoryCode::MQ => "596",
+        TerritoryCode::MR => "222",
+        TerritoryCode::MS => "1",
+        TerritoryCode::MT => "356",
+        TerritoryCode::MU => "230",
+        TerritoryCode::MV => "960",
+        TerritoryCode::MW => "265",
+        TerritoryCode::MX => "52",
+        TerritoryCode::MY => "60",
+        TerritoryCode::MZ => "258",
+        TerritoryCode::NA => "264",
+        TerritoryCode::NC => "687",
+        TerritoryCode::NE => "227",
+        TerritoryCode::NF => "6723",
+        TerritoryCode::NG => "234",
+        TerritoryCode::NI => "505",
+        TerritoryCode::NL => "31",
+        TerritoryCode::NO => "47",
+        TerritoryCode::NP => "977",
+        TerritoryCode::NR => "674",
+        TerritoryCode::NU => "683",
+        TerritoryCode::NZ => "64",
+        TerritoryCode::OM => "968",
+        TerritoryCode::PA => "507",
+        TerritoryCode::PE => "51",
+        TerritoryCode::PF => "689",
+        TerritoryCode::PG => "675",
+        TerritoryCode::PH => "63",
+        TerritoryCode::PK => "92",
+        TerritoryCode::PL => "48",
+        TerritoryCode::PM => "508",
+        TerritoryCode::PR => "1",
+        TerritoryCode::PS => "970",
+        TerritoryCode::PT => "351",
+        TerritoryCode::PW => "680",
+        TerritoryCode::PY => "595",
+        TerritoryCode::QA => "974",
+        TerritoryCode::RE => "262",
+        TerritoryCode::RO => "40",
+        TerritoryCode::RS => "381",
+        TerritoryCode::RU => "7",
+        TerritoryCode::RW => "250",
+        TerritoryCode::SA => "966",
+        TerritoryCode::SB => "677",
+        TerritoryCode::SC => "248",
+        TerritoryCode::SD => "249",
+        TerritoryCode::SE => "46",
+        TerritoryCode::SG => "65",
+        TerritoryCode::SH => "290",
+        TerritoryCode::SI => "386",
+        TerritoryCode::SJ => "4779",
+        TerritoryCode::SK => "421",
+        TerritoryCode::SL => "232",
+        TerritoryCode::SM => "378",
+        TerritoryCode::SN => "221",
+        TerritoryCode::SO => "252",
+        TerritoryCode::SR => "597",
+        TerritoryCode::SS => "211",
+        TerritoryCode::ST => "239",
+        TerritoryCode::SV => "503",
+        TerritoryCode::SX => "1",
+        TerritoryCode::SY => "963",
+        TerritoryCode::SZ => "268",
+        TerritoryCode::TC => "1",
+        TerritoryCode::TD => "235",
+        TerritoryCode::TG => "228",
+        TerritoryCode::TH => "66",
+        TerritoryCode::TJ => "992",
+        TerritoryCode::TK => "690",
+        TerritoryCode::TL => "670",
+        TerritoryCode::TM => "993",
+        TerritoryCode::TN => "216",
+        TerritoryCode::TO => "676",
+        TerritoryCode::TR => "90",
+        TerritoryCode::TT => "1",
+        TerritoryCode::TV => "688",
+        TerritoryCode::TW => "886",
+        TerritoryCode::TZ => "255",
+        TerritoryCode::UA => "380",
+        TerritoryCode::UG => "256",
+        TerritoryCode::UY => "598",
+        TerritoryCode::UZ => "998",
+        TerritoryCode::VA => "379",
+        TerritoryCode::VC => "1",
+        TerritoryCode::VE => "58",
+        TerritoryCode::VG => "1",
+        TerritoryCode::VI => "1",
+        TerritoryCode::VN => "84",
+        TerritoryCode::VU => "678",
+        TerritoryCode::WF => "681",
+        TerritoryCode::WS => "685",
+        TerritoryCode::XG => "881",
+        TerritoryCode::XN => "870",
+        TerritoryCode::XP => "878",
+        TerritoryCode::XS => "808",
+        TerritoryCode::XT => "800",
+        TerritoryCode::XV => "882",
+        TerritoryCode::YE => "967",
+        TerritoryCode::YT => "262",
+        TerritoryCode::ZA => "27",
+        TerritoryCode::ZM => "260",
+        TerritoryCode::ZW => "263",
+    }
+}
@@ -0,0 +1,163 @@
+use crate::prefix::longest_prefix;
+use crate::{NanpAreaCode, PhoneNumber, TerritoryCode};
+
+mod data;
+
+impl TerritoryCode {
+    /// The IANA time zone identifiers of this territory, main first. There are several for the
+    /// largest territories, and none for non-geographic ones like [`TerritoryCode::XT`].
+    /// ```
+    /// use e164_phones_countries::TerritoryCode;
+    /// assert_eq!(&["Europe/Paris"], TerritoryCode::FR.time_zones());
+    /// assert!(TerritoryCode::RU.time_zones().contains(&"Asia/Vladivostok"));
+    /// assert!(TerritoryCode::XT.time_zones().is_empty());
+    /// ```
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        data::territory_time_zones(*self)
+    }
+}
+
+impl PhoneNumber {
+    /// The IANA time zone identifiers this phone number may be in, main first: those of the
+    /// longest known prefix of the number, or else those of its territory. Geographic area codes
+    /// of the United States and Canada have a single time zone, unless they straddle a time zone
+    /// boundary, and so do the area codes of the largest cities elsewhere.
+    ///
+    /// Mobile numbers may be in any time zone of their territory, and roaming ones in any other.
+    /// ```
+    /// use e164_phones_countries::PhoneNumber;
+    /// let phone: PhoneNumber = "+1 212 555 0123".parse().unwrap();
+    /// assert_eq!(&["America/New_York"], phone.time_zones());
+    /// let phone: PhoneNumber = "+61 2 5550 1234".parse().unwrap();
+    /// assert_eq!(&["Australia/Sydney"], phone.time_zones());
+    /// let mobile: PhoneNumber = "+7 912 345 67 89".parse().unwrap();
+    /// assert!(mobile.time_zones().len() > 10);
+    /// ```
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        longest_prefix(&data::PREFIXES, self).map_or_else(
+            || self.territory().time_zones(),
+            |&(_, time_zones)| time_zones,
+        )
+    }
+}
+
+impl NanpAreaCode {
+    /// The IANA time zone identifiers of the phone numbers of this area code, like
+    /// [`PhoneNumber::time_zones`]: a single one for most geographic area codes, and those of
+    /// its territory otherwise.
+    /// ```
+    /// use e164_phones_countries::NanpAreaCode;
+    /// assert_eq!(&["America/Chicago"], NanpAreaCode::from_npa(312).unwrap().time_zones());
+    /// let idaho = NanpAreaCode::from_npa(208).unwrap();
+    /// assert_eq!(&["America/Denver", "America/Los_Angeles"], idaho.time_zones());
+    /// assert_eq!(&["America/Jamaica"], NanpAreaCode::from_npa(876).unwrap().time_zones());
+    /// ```
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        let prefix = 1000 + u64::from(self.npa());
+        match data::PREFIXES.binary_search_by_key(&prefix, |&(prefix, _)| prefix) {
+            Ok(index) => data::PREFIXES[index].1,
+            Err(_) => self
+                .territory()
+                .map_or(&[], |territory| territory.time_zones()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata;
+    use crate::prefix::tests::check_table;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn prefixes_agree_with_territories() {
+        check_table(&data::PREFIXES);
+        for &(prefix, time_zones) in &data::PREFIXES {
+            let digits = prefix.to_string();
+            let territory = metadata::LOOKUP_TABLE
+                .iter()
+                .filter(|(lookup, _)| digits.starts_with(&lookup.to_string()))
+                .max_by_key(|(lookup, _)| *lookup)
+                .map(|&(_, territory)| territory)
+                .unwrap();
+            for time_zone in time_zones {
+                assert!(territory.time_zones().contains(time_zone), "{}", prefix);
+            }
+        }
+        for area_code in NanpAreaCode::iter().filter(|area_code| area_code.is_geographic()) {
+            assert!(!area_code.time_zones().is_empty(), "{}", area_code);
+        }
+    }
+
+    #[test]
+    fn area_codes_agree_with_regions_and_overlays() {
+        let region_time_zones = |region| match region {
+            "CT" | "DC" | "DE" | "GA" | "MA" | "MD" | "ME" | "NC" | "NH" | "NJ" | "NY" | "OH"
+            | "PA" | "RI" | "SC" | "VA" | "VT" | "WV" => &["America/New_York"][..],
+            "FL" | "IN" | "KY" | "MI" | "TN" => &["America/New_York", "America/Chicago"],
+            "AL" | "AR" | "IA" | "IL" | "LA" | "MN" | "MO" | "MS" | "OK" | "WI" => {
+                &["America/Chicago"]
+            }
+            "KS" | "ND" | "NE" | "SD" | "TX" => &["America/Chicago", "America/Denver"],
+            "CO" | "MT" | "NM" | "UT" | "WY" => &["America/Denver"],
+            "AZ" => &["America/Phoenix", "America/Denver"],
+            "ID" | "OR" => &["America/Denver", "America/Los_Angeles"],
+            "CA" | "NV" | "WA" => &["America/Los_Angeles"],
+            "AK" => &["America/Anchorage", "America/Adak"],
+            "HI" => &["Pacific/Honolulu"],
+            "NL" => &["America/St_Johns", "America/Halifax"],
+            "NB" | "NS" | "PE" => &["America/Halifax"],
+            "QC" => &["America/Toronto", "America/Halifax"],
+            "ON" => &["America/Toronto", "America/Winnipeg"],
+            "NU" => &["America/Toronto", "America/Winnipeg", "America/Edmonton"],
+            "MB" => &["America/Winnipeg"],
+            "SK" => &["America/Regina"],
+            "AB" | "NT" => &["America/Edmonton"],
+            "BC" => &["America/Vancouver", "America/Edmonton"],
+            "YT" => &["America/Whitehorse"],
+            region => panic!("No time zones of {}", region),
+        };
+        for area_code in NanpAreaCode::iter().filter(|area_code| !area_code.regions().is_empty()) {
+            for time_zone in area_code.time_zones() {
+                let in_regions = area_code
+                    .regions()
+                    .iter()
+                    .any(|&region| region_time_zones(region).contains(time_zone));
+                assert!(in_regions, "{}: {}", area_code, time_zone);
+                let mut overlay = area_code
+                    .overlay()
+                    .filter(|other| other != &area_code)
+                    .peekable();
+                if overlay.peek().is_some() {
+                    let in_overlay = overlay.any(|other| other.time_zones().contains(time_zone));
+                    assert!(in_overlay, "{}: {}", area_code, time_zone);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn time_zones_of_phone_numbers() {
+        for (phone, time_zones) in [
+            (12125550123, &["America/New_York"][..]),
+            (19075550123, &["America/Anchorage", "America/Adak"]),
+            (14165550123, &["America/Toronto"]),
+            (18685550123, &["America/Port_of_Spain"]),
+            (33123456789, &["Europe/Paris"]),
+            (61755501234, &["Australia/Brisbane", "Australia/Lindeman"]),
+            (5511955501234, &["America/Sao_Paulo"]),
+            (556855501234, &["America/Rio_Branco"]),
+            (74232555012, &["Asia/Vladivostok"]),
+            (74955550123, &["Europe/Moscow"]),
+            (80012345678, &[]),
+        ] {
+            let phone = PhoneNumber::try_from(phone).unwrap();
+            assert_eq!(time_zones, phone.time_zones(), "{}", phone);
+        }
+        let mobile = PhoneNumber::try_from(61412345678).unwrap();
+        assert_eq!(TerritoryCode::AU.time_zones(), mobile.time_zones());
+        let toll_free = PhoneNumber::try_from(18005550123).unwrap();
+        assert_eq!(TerritoryCode::US.time_zones(), toll_free.time_zones());
+    }
+}
@@ -0,0 +1,765 @@
+// Generated by `cargo run -p metadata-gen` from `data/time_zones.csv`. Do not edit.
+
+use crate::TerritoryCode;
+
+/// The time zones of a territory, main first
+#[rustfmt::skip]
+pub(super) fn territory_time_zones(territory: TerritoryCode) -> &'static [&'static str] {
+    match territory {
+        TerritoryCode::US => &["America/New_York", "America/Chicago", "America/Denver", "America/Phoenix", "America/Los_Angeles", "America/Anchorage", "America/Adak", "Pacific/Honolulu"],
+        TerritoryCode::CA => &["America/Toronto", "America/St_Johns", "America/Halifax", "America/Winnipeg", "America/Regina", "America/Edmonton", "America/Vancouver", "America/Whitehorse"],
+        TerritoryCode::AC => &["Atlantic/St_Helena"],
+        TerritoryCode::AD => &["Europe/Andorra"],
+        TerritoryCode::AE => &["Asia/Dubai"],
+        TerritoryCode::AF => &["Asia/Kabul"],
+        TerritoryCode::AG => &["America/Antigua"],
+        TerritoryCode::AI => &["America/Anguilla"],
+        TerritoryCode::AL => &["Europe/Tirane"],
+        TerritoryCode::AM => &["Asia/Yerevan"],
+        TerritoryCode::AO => &["Africa/Luanda"],
+        TerritoryCode::AQ => &["Antarctica/Casey", "Antarctica/Davis", "Antarctica/Mawson"],
+        TerritoryCode::AR => &["America/Argentina/Buenos_Aires", "America/Argentina/Cordoba", "America/Argentina/Salta", "America/Argentina/Jujuy", "America/Argentina/Tucuman", "America/Argentina/Catamarca", "America/Argentina/La_Rioja", "America/Argentina/San_Juan", "America/Argentina/Mendoza", "America/Argentina/San_Luis", "America/Argentina/Rio_Gallegos", "America/Argentina/Ushuaia"],
+        TerritoryCode::AS => &["Pacific/Pago_Pago"],
+        TerritoryCode::AT => &["Europe/Vienna"],
+        TerritoryCode::AU => &["Australia/Sydney", "Australia/Melbourne", "Australia/Brisbane", "Australia/Adelaide", "Australia/Darwin", "Australia/Perth", "Australia/Hobart", "Australia/Broken_Hill", "Australia/Lindeman", "Australia/Eucla", "Australia/Lord_Howe", "Antarctica/Macquarie"],
+        TerritoryCode::AW => &["America/Aruba"],
+        TerritoryCode::AX => &["Europe/Mariehamn"],
+        TerritoryCode::AZ => &["Asia/Baku"],
+        TerritoryCode::BA => &["Europe/Sarajevo"],
+        TerritoryCode::BB => &["America/Barbados"],
+        TerritoryCode::BD => &["Asia/Dhaka"],
+        TerritoryCode::BE => &["Europe/Brussels"],
+        TerritoryCode::BF => &["Africa/Ouagadougou"],
+        TerritoryCode::BG => &["Europe/Sofia"],
+        TerritoryCode::BH => &["Asia/Bahrain"],
+        TerritoryCode::BI => &["Africa/Bujumbura"],
+        TerritoryCode::BJ => &["Africa/Porto-Novo"],
+        TerritoryCode::BM => &["Atlantic/Bermuda"],
+        TerritoryCode::BN => &["Asia/Brunei"],
+        TerritoryCode::BO => &["America/La_Paz"],
+        TerritoryCode::BQ => &["America/Kralendijk"],
+        TerritoryCode::BR => &["America/Sao_Paulo", "America/Noronha", "America/Belem", "America/Fortaleza", "America/Recife", "America/Araguaina", "America/Maceio", "America/Bahia", "America/Campo_Grande", "America/Cuiaba", "America/Santarem", "America/Porto_Velho", "America/Boa_Vista", "America/Manaus", "America/Eirunepe", "America/Rio_Branco"],
+        TerritoryCode::BS => &["America/Nassau"],
+        TerritoryCode::BT => &["Asia/Thimphu"],
+        TerritoryCode::BW => &["Africa/Gaborone"],
+        TerritoryCode::BY => &["Europe/Minsk"],
+        TerritoryCode::BZ => &["America/Belize"],
+        TerritoryCode::CC => &["Indian/Cocos"],
+        TerritoryCode::CD => &["Africa/Kinshasa", "Africa/Lubumbashi"],
+        TerritoryCode::CF => &["Africa/Bangui"],
+        TerritoryCode::CG => &["Africa/Brazzaville"],
+        TerritoryCode::CH => &["Europe/Zurich"],
+        TerritoryCode::CI => &["Africa/Abidjan"],
+        TerritoryCode::CK => &["Pacific/Rarotonga"],
+        TerritoryCode::CL => &["America/Santiago", "America/Punta_Arenas", "Pacific/Easter"],
+        TerritoryCode::CM => &["Africa/Douala"],
+        TerritoryCode::CN => &["Asia/Shanghai", "Asia/Urumqi"],
+        TerritoryCode::CO => &["America/Bogota"],
+        TerritoryCode::CR => &["America/Costa_Rica"],
+        TerritoryCode::CU => &["America/Havana"],
+        TerritoryCode::CV => &["Atlantic/Cape_Verde"],
+        TerritoryCode::CW => &["America/Curacao"],
+        TerritoryCode::CX => &["Indian/Christmas"],
+        TerritoryCode::CY => &["Asia/Nicosia", "Asia/Famagusta"],
+        TerritoryCode::CZ => &["Europe/Prague"],
+        TerritoryCode::DE => &["Europe/Berlin"],
+        TerritoryCode::DJ => &["Africa/Djibouti"],
+        TerritoryCode::DK => &["Europe/Copenhagen"],
+        TerritoryCode::DM => &["America/Dominica"],
+        TerritoryCode::DO => &["America/Santo_Domingo"],
+        TerritoryCode::DZ => &["Africa/Algiers"],
+        TerritoryCode::EC => &["America/Guayaquil", "Pacific/Galapagos"],
+        TerritoryCode::EE => &["Europe/Tallinn"],
+        TerritoryCode::EG => &["Africa/Cairo"],
+        TerritoryCode::ER => &["Africa/Asmara"],
+        TerritoryCode::ES => &["Europe/Madrid", "Africa/Ceuta", "Atlantic/Canary"],
+        TerritoryCode::ET => &["Africa/Addis_Ababa"],
+        TerritoryCode::FI => &["Europe/Helsinki"],
+        TerritoryCode::FJ => &["Pacific/Fiji"],
+        TerritoryCode::FK => &["Atlantic/Stanley"],
+        TerritoryCode::FM => &["Pacific/Chuuk", "Pacific/Pohnpei", "Pacific/Kosrae"],
+        TerritoryCode::FO => &["Atlantic/Faroe"],
+        TerritoryCode::FR => &["Europe/Paris"],
+        TerritoryCode::GA => &["Africa/Libreville"],
+        TerritoryCode::GB => &["Europe/London"],
+        TerritoryCode::GD => &["America/Grenada"],
+        TerritoryCode::GE => &["Asia/Tbilisi"],
+        TerritoryCode::GF => &["America/Cayenne"],
+        TerritoryCode::GG => &["Europe/Guernsey"],
+        TerritoryCode::GH => &["Africa/Accra"],
+        TerritoryCode::GI => &["Europe/Gibraltar"],
+        TerritoryCode::GL => &["America/Nuuk", "America/Danmarkshavn", "America/Scoresbysund", "America/Thule"],
+        TerritoryCode::GM => &["Africa/Banjul"],
+        TerritoryCode::GN => &["Africa/Conakry"],
+        TerritoryCode::GP => &["America/Guadeloupe"],
+        TerritoryCode::GQ => &["Africa/Malabo"],
+        TerritoryCode::GR => &["Europe/Athens"],
+        TerritoryCode::GT => &["America/Guatemala"],
+        TerritoryCode::GU => &["Pacific/Guam"],
+        TerritoryCode::GW => &["Africa/Bissau"],
+        TerritoryCode::GY => &["America/Guyana"],
+        TerritoryCode::HK => &["Asia/Hong_Kong"],
+        TerritoryCode::HN => &["America/Tegucigalpa"],
+        TerritoryCode::HR => &["Europe/Zagreb"],
+        TerritoryCode::HT => &["America/Port-au-Prince"],
+        TerritoryCode::HU => &["Europe/Budapest"],
+        TerritoryCode::ID => &["Asia/Jakarta", "Asia/Pontianak", "Asia/Makassar", "Asia/Jayapura"],
+        TerritoryCode::IE => &["Europe/Dublin"],
+        TerritoryCode::IL => &["Asia/Jerusalem"],
+        TerritoryCode::IM => &["Europe/Isle_of_Man"],
+        TerritoryCode::IN => &["Asia/Kolkata"],
+        TerritoryCode::IO => &["Indian/Chagos"],
+        TerritoryCode::IQ => &["Asia/Baghdad"],
+        TerritoryCode::IR => &["Asia/Tehran"],
+        TerritoryCode::IS => &["Atlantic/Reykjavik"],
+        TerritoryCode::IT => &["Europe/Rome"],
+        TerritoryCode::JE => &["Europe/Jersey"],
+        TerritoryCode::JM => &["America/Jamaica"],
+        TerritoryCode::JO => &["Asia/Amman"],
+        TerritoryCode::JP => &["Asia/Tokyo"],
+        TerritoryCode::KE => &["Africa/Nairobi"],
+        TerritoryCode::KG => &["Asia/Bishkek"],
+        TerritoryCode::KH => &["Asia/Phnom_Penh"],
+        TerritoryCode::KI => &["Pacific/Tarawa", "Pacific/Kanton", "Pacific/Kiritimati"],
+        TerritoryCode::KM => &["Indian/Comoro"],
+        TerritoryCode::KN => &["America/St_Kitts"],
+        TerritoryCode::KP => &["Asia/Pyongyang"],
+        TerritoryCode::KR => &["Asia/Seoul"],
+        TerritoryCode::KW => &["Asia/Kuwait"],
+        TerritoryCode::KY => &["America/Cayman"],
+        TerritoryCode::KZ => &["Asia/Almaty", "Asia/Qyzylorda", "Asia/Qostanay", "Asia/Aqtobe", "Asia/Aqtau", "Asia/Atyrau", "Asia/Oral"],
+        TerritoryCode::LA => &["Asia/Vientiane"],
+        TerritoryCode::LB => &["Asia/Beirut"],
+        TerritoryCode::LC => &["America/St_Lucia"],
+        TerritoryCode::LI => &["Europe/Vaduz"],
+        TerritoryCode::LK => &["Asia/Colombo"],
+        TerritoryCode::LR => &["Africa/Monrovia"],
+        TerritoryCode::LS => &["Africa/Maseru"],
+        TerritoryCode::LT => &["Europe/Vilnius"],
+        TerritoryCode::LU => &["Europe/Luxembourg"],
+        TerritoryCode::LV => &["Europe/Riga"],
+        TerritoryCode::LY => &["Africa/Tripoli"],
+        TerritoryCode::MA => &["Africa/Casablanca"],
+        TerritoryCode::MC => &["Europe/Monaco"],
+        TerritoryCode::MD => &["Europe/Chisinau"],
+        TerritoryCode::ME => &["Europe/Podgorica"],
+        TerritoryCode::MG => &["Indian/Antananarivo"],
+        TerritoryCode::MH => &["Pacific/Majuro", "Pacific/Kwajalein"],
+        TerritoryCode::MK => &["Europe/Skopje"],
+        TerritoryCode::ML => &["Africa/Bamako"],
+        TerritoryCode::MM => &["Asia/Yangon"],
+        TerritoryCode::MN => &["Asia/Ulaanbaatar", "Asia/Hovd"],
+        TerritoryCode::MO => &["Asia/Macau"],
+        TerritoryCode::MP => &["Pacific/Saipan"],
+        TerritoryCode::MQ => &["America/Martinique"],
+        TerritoryCode::MR => &["Africa/Nouakchott"],
+        TerritoryCode::MS => &["America/Montserrat"],
+        TerritoryCode::MT => &["Europe/Malta"],
+        TerritoryCode::MU => &["Indian/Mauritius"],
+        TerritoryCode::MV => &["Indian/Maldives"],
+        TerritoryCode::MW => &["Africa/Blantyre"],
+        TerritoryCode::MX => &["America/Mexico_City", "America/Cancun", "America/Merida", "America/Monterrey", "America/Matamoros", "America/Chihuahua", "America/Ciudad_Juarez", "America/Ojinaga", "America/Mazatlan", "America/Bahia_Banderas", "America/Hermosillo", "America/Tijuana"],
+        TerritoryCode::MY => &["Asia/Kuala_Lumpur", "Asia/Kuching"],
+        TerritoryCode::MZ => &["Africa/Maputo"],
+        TerritoryCode::NA => &["Africa/Windhoek"],
+        TerritoryCode::NC => &["Pacific/Noumea"],
+        TerritoryCode::NE => &["Africa/Niamey"],
+        TerritoryCode::NF => &["Pacific/Norfolk"],
+        TerritoryCode::NG => &["Africa/Lagos"],
+        TerritoryCode::NI => &["America/Managua"],
+        TerritoryCode::NL => &["Europe/Amsterdam"],
+        TerritoryCode::NO => &["Europe/Oslo"],
+        TerritoryCode::NP => &["Asia/Kathmandu"],
+        TerritoryCode::NR => &["Pacific/Nauru"],
+        TerritoryCode::NU => &["Pacific/Niue"],
+        TerritoryCode::NZ => &["Pacific/Auckland", "Pacific/Chatham"],
+        TerritoryCode::OM => &["Asia/Muscat"],
+        TerritoryCode::PA => &["America/Panama"],
+        TerritoryCode::PE => &["America/Lima"],
+        TerritoryCode::PF => &["Pacific/Tahiti", "Pacific/Marquesas", "Pacific/Gambier"],
+        TerritoryCode::PG => &["Pacific/Port_Moresby", "Pacific/Bougainville"],
+        TerritoryCode::PH => &["Asia/Manila"],
+        TerritoryCode::PK => &["Asia/Karachi"],
+        TerritoryCode::PL => &["Europe/Warsaw"],
+        TerritoryCode::PM => &["America/Miquelon"],
+        TerritoryCode::PR => &["America/Puerto_Rico"],
+        TerritoryCode::PS => &["Asia/Gaza", "Asia/Hebron"],
+        TerritoryCode::PT => &["Europe/Lisbon", "Atlantic/Madeira", "Atlantic/Azores"],
+        TerritoryCode::PW => &["Pacific/Palau"],
+        TerritoryCode::PY => &["America/Asuncion"],
+        TerritoryCode::QA => &["Asia/Qatar"],
+        TerritoryCode::RE => &["Indian/Reunion"],
+        TerritoryCode::RO => &["Europe/Bucharest"],
+        TerritoryCode::RS => &["Europe/Belgrade"],
+        TerritoryCode::RU => &["Europe/Moscow", "Europe/Kaliningrad", "Europe/Kirov", "Europe/Volgograd", "Europe/Astrakhan", "Europe/Saratov", "Europe/Ulyanovsk", "Europe/Samara", "Asia/Yekaterinburg", "Asia/Omsk", "Asia/Novosibirsk", "Asia/Barnaul", "Asia/Tomsk", "Asia/Novokuznetsk", "Asia/Krasnoyarsk", "Asia/Irkutsk", "Asia/Chita", "Asia/Yakutsk", "Asia/Khandyga", "Asia/Vladivostok", "Asia/Ust-Nera", "Asia/Magadan", "Asia/Sakhalin", "Asia/Srednekolymsk", "Asia/Kamchatka", "Asia/Anadyr"],
+        TerritoryCode::RW => &["Africa/Kigali"],
+        TerritoryCode::SA => &["Asia/Riyadh"],
+        TerritoryCode::SB => &["Pacific/Guadalcanal"],
+        TerritoryCode::SC => &["Indian/Mahe"],
+        TerritoryCode::SD => &["Africa/Khartoum"],
+        TerritoryCode::SE => &["Europe/Stockholm"],
+        TerritoryCode::SG => &["Asia/Singapore"],
+        TerritoryCode::SH => &["Atlantic/St_Helena"],
+        TerritoryCode::SI => &["Europe/Ljubljana"],
+        TerritoryCode::SJ => &["Arctic/Longyearbyen"],
+        TerritoryCode::SK => &["Europe/Bratislava"],
+        TerritoryCode::SL => &["Africa/Freetown"],
+        TerritoryCode::SM => &["Europe/San_Marino"],
+        TerritoryCode::SN => &["Africa/Dakar"],
+        TerritoryCode::SO => &["Africa/Mogadishu"],
+        TerritoryCode::SR => &["America/Paramaribo"],
+        TerritoryCode::SS => &["Africa/Juba"],
+        TerritoryCode::ST => &["Africa/Sao_Tome"],
+        TerritoryCode::SV => &["America/El_Salvador"],
+        TerritoryCode::SX => &["America/Lower_Princes"],
+        TerritoryCode::SY => &["Asia/Damascus"],
+        TerritoryCode::SZ => &["Africa/Mbabane"],
+        TerritoryCode::TC => &["America/Grand_Turk"],
+        TerritoryCode::TD => &["Africa/Ndjamena"],
+        TerritoryCode::TG => &["Africa/Lome"],
+        TerritoryCode::TH => &["Asia/Bangkok"],
+        TerritoryCode::TJ => &["Asia/Dushanbe"],
+        TerritoryCode::TK => &["Pacific/Fakaofo"],
+        TerritoryCode::TL => &["Asia/Dili"],
+        TerritoryCode::TM => &["Asia/Ashgabat"],
+        TerritoryCode::TN => &["Africa/Tunis"],
+        TerritoryCode::TO => &["Pacific/Tongatapu"],
+        TerritoryCode::TR => &["Europe/Istanbul"],
+        TerritoryCode::TT => &["America/Port_of_Spain"],
+        TerritoryCode::TV => &["Pacific/Funafuti"],
+        TerritoryCode::TW => &["Asia/Taipei"],
+        TerritoryCode::TZ => &["Africa/Dar_es_Salaam"],
+        TerritoryCode::UA => &["Europe/Kyiv", "Europe/Simferopol"],
+        TerritoryCode::UG => &["Africa/Kampala"],
+        TerritoryCode::UY => &["America/Montevideo"],
+        TerritoryCode::UZ => &["Asia/Tashkent", "Asia/Samarkand"],
+        TerritoryCode::VA => &["Europe/Vatican"],
+        TerritoryCode::VC => &["America/St_Vincent"],
+        TerritoryCode::VE => &["America/Caracas"],
+        TerritoryCode::VG => &["America/Tortola"],
+        TerritoryCode::VI => &["America/St_Thomas"],
+        TerritoryCode::VN => &["Asia/Ho_Chi_Minh"],
+        TerritoryCode::VU => &["Pacific/Efate"],
+        TerritoryCode::WF => &["Pacific/Wallis"],
+        TerritoryCode::WS => &["Pacific/Apia"],
+        TerritoryCode::YE => &["Asia/Aden"],
+        TerritoryCode::YT => &["Indian/Mayotte"],
+        TerritoryCode::ZA => &["Africa/Johannesburg"],
+        TerritoryCode::ZM => &["Africa/Lusaka"],
+        TerritoryCode::ZW => &["Africa/Harare"],
+        _ => &[],
+    }
+}
+
+/// The time zones of the prefixes, sorted by prefix
+#[rustfmt::skip]
+pub(super) static PREFIXES: [(u64, &[&str]); 508] = [
+    (612, &["Australia/Sydney"]),
+    (613, &["Australia/Melbourne", "Australia/Hobart"]),
+    (617, &["Australia/Brisbane", "Australia/Lindeman"]),
+    (618, &["Australia/Adelaide", "Australia/Perth", "Australia/Darwin", "Australia/Broken_Hill", "Australia/Eucla"]),
+    (1201, &["America/New_York"]),
+    (1202, &["America/New_York"]),
+    (1203, &["America/New_York"]),
+    (1204, &["America/Winnipeg"]),
+    (1205, &["America/Chicago"]),
+    (1206, &["America/Los_Angeles"]),
+    (1207, &["America/New_York"]),
+    (1208, &["America/Denver", "America/Los_Angeles"]),
+    (1209, &["America/Los_Angeles"]),
+    (1210, &["America/Chicago"]),
+    (1212, &["America/New_York"]),
+    (1213, &["America/Los_Angeles"]),
+    (1214, &["America/Chicago"]),
+    (1215, &["America/New_York"]),
+    (1216, &["America/New_York"]),
+    (1217, &["America/Chicago"]),
+    (1218, &["America/Chicago"]),
+    (1219, &["America/Chicago"]),
+    (1220, &["America/New_York"]),
+    (1223, &["America/New_York"]),
+    (1224, &["America/Chicago"]),
+    (1225, &["America/Chicago"]),
+    (1226, &["America/Toronto"]),
+    (1227, &["America/New_York"]),
+    (1228, &["America/Chicago"]),
+    (1229, &["America/New_York"]),
+    (1231, &["America/New_York"]),
+    (1234, &["America/New_York"]),
+    (1235, &["America/Chicago"]),
+    (1236, &["America/Vancouver", "America/Edmonton"]),
+    (1239, &["America/New_York"]),
+    (1240, &["America/New_York"]),
+    (1248, &["America/New_York"]),
+    (1249, &["America/Toronto"]),
+    (1250, &["America/Vancouver", "America/Edmonton"]),
+    (1251, &["America/Chicago"]),
+    (1252, &["America/New_York"]),
+    (1253, &["America/Los_Angeles"]),
+    (1254, &["America/Chicago"]),
+    (1256, &["America/Chicago"]),
+    (1257, &["America/Vancouver", "America/Edmonton"]),
+    (1260, &["America/New_York"]),
+    (1262, &["America/Chicago"]),
+    (1263, &["America/Toronto"]),
+    (1267, &["America/New_York"]),
+    (1269, &["America/New_York"]),
+    (1270, &["America/Chicago", "America/New_York"]),
+    (1272, &["America/New_York"]),
+    (1274, &["America/Chicago"]),
+    (1276, &["America/New_York"]),
+    (1279, &["America/Los_Angeles"]),
+    (1281, &["America/Chicago"]),
+    (1283, &["America/New_York"]),
+    (1289, &["America/Toronto"]),
+    (1301, &["America/New_York"]),
+    (1302, &["America/New_York"]),
+    (1303, &["America/Denver"]),
+    (1304, &["America/New_York"]),
+    (1305, &["America/New_York"]),
+    (1306, &["America/Regina"]),
+    (1307, &["America/Denver"]),
+    (1308, &["America/Chicago", "America/Denver"]),
+    (1309, &["America/Chicago"]),
+    (1310, &["America/Los_Angeles"]),
+    (1312, &["America/Chicago"]),
+    (1313, &["America/New_York"]),
+    (1314, &["America/Chicago"]),
+    (1315, &["America/New_York"]),
+    (1316, &["America/Chicago"]),
+    (1317, &["America/New_York"]),
+    (1318, &["America/Chicago"]),
+    (1319, &["America/Chicago"]),
+    (1320, &["America/Chicago"]),
+    (1321, &["America/New_York"]),
+    (1323, &["America/Los_Angeles"]),
+    (1324, &["America/New_York"]),
+    (1325, &["America/Chicago"]),
+    (1326, &["America/New_York"]),
+    (1327, &["America/Chicago"]),
+    (1329, &["America/New_York"]),
+    (1330, &["America/New_York"]),
+    (1331, &["America/Chicago"]),
+    (1332, &["America/New_York"]),
+    (1334, &["America/Chicago"]),
+    (1336, &["America/New_York"]),
+    (1337, &["America/Chicago"]),
+    (1339, &["America/New_York"]),
+    (1341, &["America/Los_Angeles"]),
+    (1343, &["America/Toronto"]),
+    (1346, &["America/Chicago"]),
+    (1347, &["America/New_York"]),
+    (1350, &["America/Los_Angeles"]),
+    (1351, &["America/New_York"]),
+    (1352, &["America/New_York"]),
+    (1353, &["America/Chicago"]),
+    (1354, &["America/Toronto"]),
+    (1360, &["America/Los_Angeles"]),
+    (1361, &["America/Chicago"]),
+    (1363, &["America/New_York"]),
+    (1364, &["America/Chicago", "America/New_York"]),
+    (1365, &["America/Toronto"]),
+    (1367, &["America/Toronto", "America/Halifax"]),
+    (1368, &["America/Edmonton"]),
+    (1369, &["America/Los_Angeles"]),
+    (1380, &["America/New_York"]),
+    (1382, &["America/Toronto"]),
+    (1385, &["America/Denver"]),
+    (1386, &["America/New_York"]),
+    (1401, &["America/New_York"]),
+    (1402, &["America/Chicago"]),
+    (1403, &["America/Edmonton"]),
+    (1404, &["America/New_York"]),
+    (1405, &["America/Chicago"]),
+    (1406, &["America/Denver"]),
+    (1407, &["America/New_York"]),
+    (1408, &["America/Los_Angeles"]),
+    (1409, &["America/Chicago"]),
+    (1410, &["America/New_York"]),
+    (1412, &["America/New_York"]),
+    (1413, &["America/New_York"]),
+    (1414, &["America/Chicago"]),
+    (1415, &["America/Los_Angeles"]),
+    (1416, &["America/Toronto"]),
+    (1417, &["America/Chicago"]),
+    (1418, &["America/Toronto", "America/Halifax"]),
+    (1419, &["America/New_York"]),
+    (1423, &["America/New_York"]),
+    (1424, &["America/Los_Angeles"]),
+    (1425, &["America/Los_Angeles"]),
+    (1428, &["America/Halifax"]),
+    (1430, &["America/Chicago"]),
+    (1431, &["America/Winnipeg"]),
+    (1432, &["America/Chicago"]),
+    (1434, &["America/New_York"]),
+    (1435, &["America/Denver"]),
+    (1437, &["America/Toronto"]),
+    (1438, &["America/Toronto"]),
+    (1440, &["America/New_York"]),
+    (1442, &["America/Los_Angeles"]),
+    (1443, &["America/New_York"]),
+    (1445, &["America/New_York"]),
+    (1447, &["America/Chicago"]),
+    (1448, &["America/Chicago", "America/New_York"]),
+    (1450, &["America/Toronto"]),
+    (1458, &["America/Los_Angeles", "America/Denver"]),
+    (1463, &["America/New_York"]),
+    (1464, &["America/Chicago"]),
+    (1468, &["America/Toronto"]),
+    (1469, &["America/Chicago"]),
+    (1470, &["America/New_York"]),
+    (1472, &["America/New_York"]),
+    (1474, &["America/Regina"]),
+    (1475, &["America/New_York"]),
+    (1478, &["America/New_York"]),
+    (1479, &["America/Chicago"]),
+    (1480, &["America/Phoenix"]),
+    (1484, &["America/New_York"]),
+    (1501, &["America/Chicago"]),
+    (1502, &["America/New_York"]),
+    (1503, &["America/Los_Angeles"]),
+    (1504, &["America/Chicago"]),
+    (1505, &["America/Denver"]),
+    (1506, &["America/Halifax"]),
+    (1507, &["America/Chicago"]),
+    (1508, &["America/New_York"]),
+    (1509, &["America/Los_Angeles"]),
+    (1510, &["America/Los_Angeles"]),
+    (1512, &["America/Chicago"]),
+    (1513, &["America/New_York"]),
+    (1514, &["America/Toronto"]),
+    (1515, &["America/Chicago"]),
+    (1516, &["America/New_York"]),
+    (1517, &["America/New_York"]),
+    (1518, &["America/New_York"]),
+    (1519, &["America/Toronto"]),
+    (1520, &["America/Phoenix"]),
+    (1530, &["America/Los_Angeles"]),
+    (1531, &["America/Chicago"]),
+    (1534, &["America/Chicago"]),
+    (1539, &["America/Chicago"]),
+    (1540, &["America/New_York"]),
+    (1541, &["America/Los_Angeles", "America/Denver"]),
+    (1548, &["America/Toronto"]),
+    (1551, &["America/New_York"]),
+    (1557, &["America/Chicago"]),
+    (1559, &["America/Los_Angeles"]),
+    (1561, &["America/New_York"]),
+    (1562, &["America/Los_Angeles"]),
+    (1563, &["America/Chicago"]),
+    (1564, &["America/Los_Angeles"]),
+    (1567, &["America/New_York"]),
+    (1570, &["America/New_York"]),
+    (1571, &["America/New_York"]),
+    (1572, &["America/Chicago"]),
+    (1573, &["America/Chicago"]),
+    (1574, &["America/New_York", "America/Chicago"]),
+    (1575, &["America/Denver"]),
+    (1579, &["America/Toronto"]),
+    (1580, &["America/Chicago"]),
+    (1581, &["America/Toronto", "America/Halifax"]),
+    (1582, &["America/New_York"]),
+    (1584, &["America/Winnipeg"]),
+    (1585, &["America/New_York"]),
+    (1586, &["America/New_York"]),
+    (1587, &["America/Edmonton"]),
+    (1601, &["America/Chicago"]),
+    (1602, &["America/Phoenix"]),
+    (1603, &["America/New_York"]),
+    (1604, &["America/Vancouver"]),
+    (1605, &["America/Chicago", "America/Denver"]),
+    (1606, &["America/New_York"]),
+    (1607, &["America/New_York"]),
+    (1608, &["America/Chicago"]),
+    (1609, &["America/New_York"]),
+    (1610, &["America/New_York"]),
+    (1612, &["America/Chicago"]),
+    (1613, &["America/Toronto"]),
+    (1614, &["America/New_York"]),
+    (1615, &["America/Chicago"]),
+    (1616, &["America/New_York"]),
+    (1617, &["America/New_York"]),
+    (1618, &["America/Chicago"]),
+    (1619, &["America/Los_Angeles"]),
+    (1620, &["America/Chicago", "America/Denver"]),
+    (1623, &["America/Phoenix"]),
+    (1626, &["America/Los_Angeles"]),
+    (1628, &["America/Los_Angeles"]),
+    (1629, &["America/Chicago"]),
+    (1630, &["America/Chicago"]),
+    (1631, &["America/New_York"]),
+    (1636, &["America/Chicago"]),
+    (1639, &["America/Regina"]),
+    (1640, &["America/New_York"]),
+    (1641, &["America/Chicago"]),
+    (1645, &["America/New_York"]),
+    (1646, &["America/New_York"]),
+    (1647, &["America/Toronto"]),
+    (1650, &["America/Los_Angeles"]),
+    (1651, &["America/Chicago"]),
+    (1656, &["America/New_York"]),
+    (1657, &["America/Los_Angeles"]),
+    (1659, &["America/Chicago"]),
+    (1660, &["America/Chicago"]),
+    (1661, &["America/Los_Angeles"]),
+    (1662, &["America/Chicago"]),
+    (1667, &["America/New_York"]),
+    (1669, &["America/Los_Angeles"]),
+    (1672, &["America/Vancouver", "America/Edmonton"]),
+    (1678, &["America/New_York"]),
+    (1680, &["America/New_York"]),
+    (1681, &["America/New_York"]),
+    (1682, &["America/Chicago"]),
+    (1683, &["America/Toronto"]),
+    (1686, &["America/New_York"]),
+    (1689, &["America/New_York"]),
+    (1701, &["America/Chicago", "America/Denver"]),
+    (1702, &["America/Los_Angeles"]),
+    (1703, &["America/New_York"]),
+    (1704, &["America/New_York"]),
+    (1705, &["America/Toronto"]),
+    (1706, &["America/New_York"]),
+    (1707, &["America/Los_Angeles"]),
+    (1708, &["America/Chicago"]),
+    (1709, &["America/St_Johns", "America/Halifax"]),
+    (1712, &["America/Chicago"]),
+    (1713, &["America/Chicago"]),
+    (1714, &["America/Los_Angeles"]),
+    (1715, &["America/Chicago"]),
+    (1716, &["America/New_York"]),
+    (1717, &["America/New_York"]),
+    (1718, &["America/New_York"]),
+    (1719, &["America/Denver"]),
+    (1720, &["America/Denver"]),
+    (1724, &["America/New_York"]),
+    (1725, &["America/Los_Angeles"]),
+    (1726, &["America/Chicago"]),
+    (1727, &["America/New_York"]),
+    (1728, &["America/New_York"]),
+    (1730, &["America/Chicago"]),
+    (1731, &["America/Chicago"]),
+    (1732, &["America/New_York"]),
+    (1734, &["America/New_York"]),
+    (1737, &["America/Chicago"]),
+    (1740, &["America/New_York"]),
+    (1742, &["America/Toronto"]),
+    (1743, &["America/New_York"]),
+    (1747, &["America/Los_Angeles"]),
+    (1748, &["America/Denver"]),
+    (1753, &["America/Toronto"]),
+    (1754, &["America/New_York"]),
+    (1757, &["America/New_York"]),
+    (1760, &["America/Los_Angeles"]),
+    (1762, &["America/New_York"]),
+    (1763, &["America/Chicago"]),
+    (1765, &["America/New_York"]),
+    (1769, &["America/Chicago"]),
+    (1770, &["America/New_York"]),
+    (1771, &["America/New_York"]),
+    (1772, &["America/New_York"]),
+    (1773, &["America/Chicago"]),
+    (1774, &["America/New_York"]),
+    (1775, &["America/Los_Angeles"]),
+    (1778, &["America/Vancouver", "America/Edmonton"]),
+    (1779, &["America/Chicago"]),
+    (1780, &["America/Edmonton"]),
+    (1781, &["America/New_York"]),
+    (1782, &["America/Halifax"]),
+    (1785, &["America/Chicago", "America/Denver"]),
+    (1786, &["America/New_York"]),
+    (1801, &["America/Denver"]),
+    (1802, &["America/New_York"]),
+    (1803, &["America/New_York"]),
+    (1804, &["America/New_York"]),
+    (1805, &["America/Los_Angeles"]),
+    (1806, &["America/Chicago"]),
+    (1807, &["America/Toronto", "America/Winnipeg"]),
+    (1808, &["Pacific/Honolulu"]),
+    (1810, &["America/New_York"]),
+    (1812, &["America/New_York", "America/Chicago"]),
+    (1813, &["America/New_York"]),
+    (1814, &["America/New_York"]),
+    (1815, &["America/Chicago"]),
+    (1816, &["America/Chicago"]),
+    (1817, &["America/Chicago"]),
+    (1818, &["America/Los_Angeles"]),
+    (1819, &["America/Toronto"]),
+    (1820, &["America/Los_Angeles"]),
+    (1821, &["America/New_York"]),
+    (1825, &["America/Edmonton"]),
+    (1826, &["America/New_York"]),
+    (1828, &["America/New_York"]),
+    (1830, &["America/Chicago"]),
+    (1831, &["America/Los_Angeles"]),
+    (1832, &["America/Chicago"]),
+    (1835, &["America/New_York"]),
+    (1838, &["America/New_York"]),
+    (1839, &["America/New_York"]),
+    (1840, &["America/Los_Angeles"]),
+    (1843, &["America/New_York"]),
+    (1845, &["America/New_York"]),
+    (1847, &["America/Chicago"]),
+    (1848, &["America/New_York"]),
+    (1850, &["America/Chicago", "America/New_York"]),
+    (1854, &["America/New_York"]),
+    (1856, &["America/New_York"]),
+    (1857, &["America/New_York"]),
+    (1858, &["America/Los_Angeles"]),
+    (1859, &["America/New_York"]),
+    (1860, &["America/New_York"]),
+    (1862, &["America/New_York"]),
+    (1863, &["America/New_York"]),
+    (1864, &["America/New_York"]),
+    (1865, &["America/New_York"]),
+    (1867, &["America/Whitehorse", "America/Edmonton", "America/Winnipeg", "America/Toronto"]),
+    (1870, &["America/Chicago"]),
+    (1872, &["America/Chicago"]),
+    (1873, &["America/Toronto"]),
+    (1878, &["America/New_York"]),
+    (1879, &["America/St_Johns", "America/Halifax"]),
+    (1901, &["America/Chicago"]),
+    (1902, &["America/Halifax"]),
+    (1903, &["America/Chicago"]),
+    (1904, &["America/New_York"]),
+    (1905, &["America/Toronto"]),
+    (1906, &["America/New_York", "America/Chicago"]),
+    (1907, &["America/Anchorage", "America/Adak"]),
+    (1908, &["America/New_York"]),
+    (1909, &["America/Los_Angeles"]),
+    (1910, &["America/New_York"]),
+    (1912, &["America/New_York"]),
+    (1913, &["America/Chicago"]),
+    (1914, &["America/New_York"]),
+    (1915, &["America/Denver"]),
+    (1916, &["America/Los_Angeles"]),
+    (1917, &["America/New_York"]),
+    (1918, &["America/Chicago"]),
+    (1919, &["America/New_York"]),
+    (1920, &["America/Chicago"]),
+    (1925, &["America/Los_Angeles"]),
+    (1928, &["America/Phoenix", "America/Denver"]),
+    (1929, &["America/New_York"]),
+    (1930, &["America/New_York", "America/Chicago"]),
+    (1931, &["America/Chicago", "America/New_York"]),
+    (1934, &["America/New_York"]),
+    (1936, &["America/Chicago"]),
+    (1937, &["America/New_York"]),
+    (1938, &["America/Chicago"]),
+    (1940, &["America/Chicago"]),
+    (1941, &["America/New_York"]),
+    (1942, &["America/Toronto"]),
+    (1943, &["America/New_York"]),
+    (1945, &["America/Chicago"]),
+    (1947, &["America/New_York"]),
+    (1948, &["America/New_York"]),
+    (1949, &["America/Los_Angeles"]),
+    (1951, &["America/Los_Angeles"]),
+    (1952, &["America/Chicago"]),
+    (1954, &["America/New_York"]),
+    (1956, &["America/Chicago"]),
+    (1959, &["America/New_York"]),
+    (1970, &["America/Denver"]),
+    (1971, &["America/Los_Angeles"]),
+    (1972, &["America/Chicago"]),
+    (1973, &["America/New_York"]),
+    (1975, &["America/Chicago"]),
+    (1978, &["America/New_York"]),
+    (1979, &["America/Chicago"]),
+    (1980, &["America/New_York"]),
+    (1983, &["America/Denver"]),
+    (1984, &["America/New_York"]),
+    (1985, &["America/Chicago"]),
+    (1986, &["America/Denver", "America/Los_Angeles"]),
+    (1989, &["America/New_York"]),
+    (5511, &["America/Sao_Paulo"]),
+    (5512, &["America/Sao_Paulo"]),
+    (5513, &["America/Sao_Paulo"]),
+    (5514, &["America/Sao_Paulo"]),
+    (5515, &["America/Sao_Paulo"]),
+    (5516, &["America/Sao_Paulo"]),
+    (5517, &["America/Sao_Paulo"]),
+    (5518, &["America/Sao_Paulo"]),
+    (5519, &["America/Sao_Paulo"]),
+    (5521, &["America/Sao_Paulo"]),
+    (5522, &["America/Sao_Paulo"]),
+    (5524, &["America/Sao_Paulo"]),
+    (5527, &["America/Sao_Paulo"]),
+    (5528, &["America/Sao_Paulo"]),
+    (5531, &["America/Sao_Paulo"]),
+    (5532, &["America/Sao_Paulo"]),
+    (5533, &["America/Sao_Paulo"]),
+    (5534, &["America/Sao_Paulo"]),
+    (5535, &["America/Sao_Paulo"]),
+    (5537, &["America/Sao_Paulo"]),
+    (5538, &["America/Sao_Paulo"]),
+    (5541, &["America/Sao_Paulo"]),
+    (5542, &["America/Sao_Paulo"]),
+    (5543, &["America/Sao_Paulo"]),
+    (5544, &["America/Sao_Paulo"]),
+    (5545, &["America/Sao_Paulo"]),
+    (5546, &["America/Sao_Paulo"]),
+    (5547, &["America/Sao_Paulo"]),
+    (5548, &["America/Sao_Paulo"]),
+    (5549, &["America/Sao_Paulo"]),
+    (5551, &["America/Sao_Paulo"]),
+    (5553, &["America/Sao_Paulo"]),
+    (5554, &["America/Sao_Paulo"]),
+    (5555, &["America/Sao_Paulo"]),
+    (5561, &["America/Sao_Paulo"]),
+    (5562, &["America/Sao_Paulo"]),
+    (5563, &["America/Araguaina"]),
+    (5564, &["America/Sao_Paulo"]),
+    (5565, &["America/Cuiaba"]),
+    (5566, &["America/Cuiaba"]),
+    (5567, &["America/Campo_Grande"]),
+    (5568, &["America/Rio_Branco"]),
+    (5569, &["America/Porto_Velho"]),
+    (5571, &["America/Bahia"]),
+    (5573, &["America/Bahia"]),
+    (5574, &["America/Bahia"]),
+    (5575, &["America/Bahia"]),
+    (5577, &["America/Bahia"]),
+    (5579, &["America/Maceio"]),
+    (5581, &["America/Recife", "America/Noronha"]),
+    (5582, &["America/Maceio"]),
+    (5583, &["America/Fortaleza"]),
+    (5584, &["America/Fortaleza"]),
+    (5585, &["America/Fortaleza"]),
+    (5586, &["America/Fortaleza"]),
+    (5587, &["America/Recife"]),
+    (5588, &["America/Fortaleza"]),
+    (5589, &["America/Fortaleza"]),
+    (5591, &["America/Belem"]),
+    (5592, &["America/Manaus"]),
+    (5593, &["America/Santarem"]),
+    (5594, &["America/Belem"]),
+    (5595, &["America/Boa_Vista"]),
+    (5596, &["America/Belem"]),
+    (5597, &["America/Manaus", "America/Eirunepe"]),
+    (5598, &["America/Fortaleza"]),
+    (5599, &["America/Fortaleza"]),
+    (7342, &["Asia/Yekaterinburg"]),
+    (7343, &["Asia/Yekaterinburg"]),
+    (7347, &["Asia/Yekaterinburg"]),
+    (7351, &["Asia/Yekaterinburg"]),
+    (7381, &["Asia/Omsk"]),
+    (7383, &["Asia/Novosibirsk"]),
+    (7391, &["Asia/Krasnoyarsk"]),
+    (7395, &["Asia/Irkutsk"]),
+    (7401, &["Europe/Kaliningrad"]),
+    (7411, &["Asia/Yakutsk"]),
+    (7413, &["Asia/Magadan"]),
+    (7415, &["Asia/Kamchatka"]),
+    (7421, &["Asia/Vladivostok"]),
+    (7423, &["Asia/Vladivostok"]),
+    (7424, &["Asia/Sakhalin"]),
+    (7495, &["Europe/Moscow"]),
+    (7499, &["Europe/Moscow"]),
+    (7812, &["Europe/Moscow"]),
+    (7831, &["Europe/Moscow"]),
+    (7843, &["Europe/Moscow"]),
+    (7844, &["Europe/Volgograd"]),
+    (7846, &["Europe/Samara"]),
+    (7861, &["Europe/Moscow"]),
+    (7863, &["Europe/Moscow"]),
+];
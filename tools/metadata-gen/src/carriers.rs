@@ -0,0 +1,87 @@
+//! The carriers of mobile phone number ranges, from `data/carriers.csv`
+//!
+//! Its columns are:
+//! - `prefix`: the leading digits of international phone numbers, including the calling code, like
+//!   the prefixes of the lookup table, e.g. `49151` for a German mobile range
+//! - `carrier`: the name of the carrier the range was allocated to, or of its successor after a
+//!   merger, e.g. `Telekom`, which may contain commas
+//!
+//! The file is imported from the English carrier resources of libphonenumber by the `carriers`
+//! step.
+
+use crate::resources;
+use crate::territories::Territory;
+
+const HEADER: &str = "prefix,carrier";
+
+/// The most digits of a prefix, as the longest international phone numbers
+const MAX_PREFIX_LEN: usize = 15;
+
+#[derive(Debug)]
+pub struct Range {
+    pub prefix: u64,
+    pub carrier: String,
+}
+
+/// Reads and checks the ranges, sorted by prefix
+pub fn parse(csv: &str, territories: &[Territory]) -> Result<Vec<Range>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut ranges = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let range =
+            range(line, territories).map_err(|error| format!("Line {}: {error}", number + 2))?;
+        ranges.push(range);
+    }
+    ranges.sort_by_key(|range| range.prefix);
+    if let Some(pair) = ranges
+        .windows(2)
+        .find(|pair| pair[0].prefix == pair[1].prefix)
+    {
+        return Err(format!("Duplicate carrier of {}", pair[0].prefix));
+    }
+    Ok(ranges)
+}
+
+fn range(line: &str, territories: &[Territory]) -> Result<Range, String> {
+    let fields: Vec<&str> = line.splitn(2, ',').collect();
+    let [prefix, carrier] = fields[..] else {
+        return Err(format!("Expected 2 fields in {line:?}"));
+    };
+    let valid = (1..=MAX_PREFIX_LEN).contains(&prefix.len())
+        && prefix.bytes().all(|b| b.is_ascii_digit())
+        && !prefix.starts_with('0');
+    if !valid {
+        return Err(format!("Invalid prefix {prefix:?}"));
+    }
+    let extends = territories
+        .iter()
+        .flat_map(|territory| &territory.calling_codes)
+        .map(|code| code.country.to_string())
+        .any(|country| prefix.starts_with(&country) && prefix.len() > country.len());
+    if !extends {
+        return Err(format!("{prefix} does not extend a calling code"));
+    }
+    if carrier.is_empty() {
+        return Err(format!("{prefix} has no carrier"));
+    }
+    Ok(Range {
+        prefix: prefix.parse().unwrap(),
+        carrier: carrier.into(),
+    })
+}
+
+/// The contents of the file, imported from libphonenumber's `resources/carrier` directory. Its
+/// files are read with `read_resource`, from their paths relative to the directory.
+pub fn import(
+    read_resource: impl Fn(&str) -> Result<Option<String>, String>,
+    territories: &[Territory],
+) -> Result<String, String> {
+    let mut csv = format!("{HEADER}\n");
+    for (prefix, carrier) in resources::entries(&read_resource, "en", territories)? {
+        csv.push_str(&format!("{prefix},{carrier}\n"));
+    }
+    Ok(csv)
+}
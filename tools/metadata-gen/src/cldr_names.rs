@@ -0,0 +1,115 @@
+//! The territory names of the Unicode CLDR, from the `<locale>/territories.json` files of the
+//! `cldr-localenames-full/main` directory of its JSON distribution
+//!
+//! Territories unknown to the CLDR, like `XT`, are named in English in every locale, by their
+//! `english_name` in `data/territories.csv`, and so are all territories in English. The sort keys
+//! of a locale are the positions of the names shown in that locale, English ones included, once
+//! sorted with the CLDR collation of the locale.
+
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::Locale;
+use serde_json::Value;
+
+use crate::territories::Territory;
+
+/// The locales with names of their own, besides English
+pub const LOCALES: [&str; 34] = [
+    "ar", "bn", "cs", "da", "de", "el", "es", "es-419", "fa", "fi", "fr", "he", "hi", "hu", "id",
+    "it", "ja", "ko", "ms", "nl", "no", "pl", "pt", "pt-PT", "ro", "ru", "sk", "sv", "th", "tr",
+    "uk", "vi", "zh", "zh-Hant",
+];
+
+/// The locale whose names are the `english_name` of the territories
+const ENGLISH: &str = "en";
+
+#[derive(Debug)]
+pub struct LocaleNames {
+    pub locale: &'static str,
+    /// The names in the order of the territories, empty for those unknown to the CLDR. None in
+    /// English.
+    pub names: Vec<String>,
+    pub sort_keys: Vec<u16>,
+}
+
+/// The CLDR version and the names of all the locales, sorted by locale. The `territories.json`
+/// file of each locale is read with `read_locale`, from the locale.
+pub fn parse(
+    read_locale: impl Fn(&str) -> Result<String, String>,
+    territories: &[Territory],
+) -> Result<(String, Vec<LocaleNames>), String> {
+    let english: Vec<&str> = territories
+        .iter()
+        .map(|territory| territory.english_name.as_str())
+        .collect();
+
+    let mut version = None;
+    let mut locales = Vec::new();
+    for locale in LOCALES {
+        let json = read_locale(locale)?;
+        let (cldr_version, names) = locale_names(&json, locale, territories)
+            .map_err(|error| format!("{locale}: {error}"))?;
+        if version.get_or_insert_with(|| cldr_version.clone()) != &cldr_version {
+            return Err(format!("{locale}: Expected CLDR {}", version.unwrap()));
+        }
+        let shown: Vec<&str> = names
+            .iter()
+            .zip(&english)
+            .map(|(name, &english)| if name.is_empty() { english } else { name })
+            .collect();
+        locales.push(LocaleNames {
+            locale,
+            sort_keys: sort_keys(&shown, locale)?,
+            names,
+        });
+    }
+    locales.push(LocaleNames {
+        locale: ENGLISH,
+        names: Vec::new(),
+        sort_keys: sort_keys(&english, ENGLISH)?,
+    });
+    locales.sort_by_key(|names| names.locale);
+    Ok((version.unwrap_or_default(), locales))
+}
+
+/// The CLDR version and the names of a `territories.json` file. Territories with ISO 3166-1 codes
+/// must have one.
+fn locale_names(
+    json: &str,
+    locale: &str,
+    territories: &[Territory],
+) -> Result<(String, Vec<String>), String> {
+    let json: Value = serde_json::from_str(json).map_err(|error| error.to_string())?;
+    let main = &json["main"][locale];
+    let Some(version) = main["identity"]["version"]["_cldrVersion"].as_str() else {
+        return Err("No CLDR version".into());
+    };
+    let Some(names) = main["localeDisplayNames"]["territories"].as_object() else {
+        return Err("No territory names".into());
+    };
+    let names = territories
+        .iter()
+        .map(|territory| match names.get(&territory.code) {
+            Some(Value::String(name)) if !name.is_empty() => Ok(name.clone()),
+            Some(name) => Err(format!("Invalid name of {}: {name}", territory.code)),
+            None if territory.alpha3.is_some() => Err(format!("No name of {}", territory.code)),
+            None => Ok(String::new()),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((version.into(), names))
+}
+
+/// The positions of the names once sorted with the collation of the locale
+fn sort_keys(names: &[&str], locale: &str) -> Result<Vec<u16>, String> {
+    let locale: Locale = locale
+        .parse()
+        .map_err(|_| format!("Invalid locale {locale}"))?;
+    let collator = Collator::try_new(&(&locale).into(), CollatorOptions::new())
+        .map_err(|error| format!("No collation of {locale}: {error}"))?;
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    order.sort_by(|&a, &b| collator.compare(names[a], names[b]).then(a.cmp(&b)));
+    let mut sort_keys = vec![0; names.len()];
+    for (position, index) in order.into_iter().enumerate() {
+        sort_keys[index] = position as u16;
+    }
+    Ok(sort_keys)
+}
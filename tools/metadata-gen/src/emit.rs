@@ -0,0 +1,869 @@
+//! Writing of the generated Rust files
+
+use std::fmt::Write;
+
+use crate::carriers::Range;
+use crate::cldr_names::LocaleNames;
+use crate::geocoding::Area;
+use crate::lookup::{lookup_prefixes, territory_of};
+use crate::metadata::Region;
+use crate::nanp::AreaCode;
+use crate::subdivisions::Subdivision;
+use crate::territories::{main_territories, Territory};
+use crate::time_zones::TimeZones;
+
+/// The order number types are checked in: the most specific types first, fixed-line and mobile
+/// numbers last
+const NUMBER_TYPES: [(&str, &str); 10] = [
+    ("premiumRate", "PremiumRate"),
+    ("tollFree", "TollFree"),
+    ("sharedCost", "SharedCost"),
+    ("voip", "Voip"),
+    ("personalNumber", "PersonalNumber"),
+    ("pager", "Pager"),
+    ("uan", "Uan"),
+    ("voicemail", "Voicemail"),
+    ("fixedLine", "FixedLine"),
+    ("mobile", "Mobile"),
+];
+
+/// Lengths of national significant numbers in territories libphonenumber does not know
+const SUPPLEMENTARY_LENGTHS: [(&str, u8, u8); 1] = [("AQ", 6, 6)];
+
+const GENERATED: &str =
+    "// Generated by `cargo run -p metadata-gen -- PhoneNumberMetadata.xml`. Do not edit.\n";
+
+/// The geographic region of a territory
+fn region<'a>(regions: &'a [Region], territory: &Territory) -> Option<&'a Region> {
+    regions.iter().find(|region| region.id == territory.code)
+}
+
+/// All the regions of a territory, including non-geographic ones
+fn regions_of<'a>(
+    regions: &'a [Region],
+    territories: &[Territory],
+    territory: &Territory,
+) -> Vec<&'a Region> {
+    regions
+        .iter()
+        .filter(|region| {
+            territory_of(region, territories).is_some_and(|found| found.code == territory.code)
+        })
+        .collect()
+}
+
+/// The main region of each calling code, sorted by calling code
+fn main_regions(regions: &[Region]) -> Vec<&Region> {
+    let mut main: Vec<&Region> = Vec::new();
+    for region in regions {
+        let shared = regions
+            .iter()
+            .any(|other| other.country_code == region.country_code && !std::ptr::eq(other, region));
+        if !shared || region.main_country_for_code {
+            main.push(region);
+        }
+    }
+    main.sort_by_key(|region| region.country_code);
+    main
+}
+
+fn optional(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some(\"{value}\")"),
+        None => "None".into(),
+    }
+}
+
+/// The contents of `src/metadata.rs`: the lookup table and the dialing data of territories
+pub fn territory_metadata(regions: &[Region], territories: &[Territory]) -> Result<String, String> {
+    let mut out = String::from(GENERATED);
+    out.push_str(
+        "// Territory metadata from libphonenumber's PhoneNumberMetadata.xml, see `tools/metadata-gen`.
+
+use std::ops::RangeInclusive;
+
+use crate::TerritoryCode;
+
+",
+    );
+    let prefixes = lookup_prefixes(regions, territories)?;
+    writeln!(
+        out,
+        "/// The territory of the phone numbers starting with each prefix, which includes the calling
+/// code. Sorted by prefix.
+#[rustfmt::skip]
+pub(crate) static LOOKUP_TABLE: [(u64, TerritoryCode); {}] = [",
+        prefixes.len()
+    )
+    .unwrap();
+    for (prefix, territory) in prefixes {
+        writeln!(out, "    ({prefix}, TerritoryCode::{territory}),").unwrap();
+    }
+    out.push_str(
+        "];
+
+#[rustfmt::skip]
+pub(crate) fn national_number_lengths(territory: TerritoryCode) -> RangeInclusive<u32> {
+    match territory {
+",
+    );
+    for territory in territories {
+        let mut lengths: Vec<u8> = regions_of(regions, territories, territory)
+            .iter()
+            .flat_map(|region| region.lengths())
+            .collect();
+        lengths.extend(
+            SUPPLEMENTARY_LENGTHS
+                .iter()
+                .filter(|(code, _, _)| *code == territory.code)
+                .flat_map(|&(_, min, max)| [min, max]),
+        );
+        let code = &territory.code;
+        let (min, max) = match (lengths.iter().min(), lengths.iter().max()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Err(format!("No lengths for {code}")),
+        };
+        writeln!(out, "        TerritoryCode::{code} => {min}..={max},").unwrap();
+    }
+    out.push_str(
+        "    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn national_prefix(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+",
+    );
+    let mut patterns = Vec::new();
+    let mut international = Vec::new();
+    for territory in territories {
+        let Some(region) = region(regions, territory) else {
+            continue;
+        };
+        let territory = &territory.code;
+        if let Some(prefix) = region.national_prefix.as_deref().filter(|p| !p.is_empty()) {
+            writeln!(out, "        TerritoryCode::{territory} => \"{prefix}\",").unwrap();
+        }
+        let pattern = region.international_prefix.as_deref();
+        let preferred = region
+            .preferred_international_prefix
+            .as_deref()
+            .map(|prefix| prefix.replace('~', ""))
+            .filter(|prefix| !prefix.is_empty());
+        let dialed = preferred.or_else(|| {
+            pattern
+                .filter(|pattern| pattern.bytes().all(|b| b.is_ascii_digit()))
+                .map(String::from)
+        });
+        if let Some(dialed) = &dialed {
+            international.push(format!(
+                "        TerritoryCode::{territory} => \"{dialed}\","
+            ));
+        }
+        if let Some(pattern) = pattern.filter(|&pattern| Some(pattern) != dialed.as_deref()) {
+            patterns.push(format!(
+                "        TerritoryCode::{territory} => Some(r\"{pattern}\"),"
+            ));
+        }
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn international_prefix(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+",
+    );
+    for line in international {
+        writeln!(out, "{line}").unwrap();
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+/// The pattern of all the international prefixes accepted in a territory
+#[rustfmt::skip]
+pub(crate) fn international_prefix_pattern(territory: TerritoryCode) -> Option<&'static str> {
+    match territory {
+",
+    );
+    for line in patterns {
+        writeln!(out, "{line}").unwrap();
+    }
+    out.push_str(
+        "        _ => international_prefix(territory),
+    }
+}
+",
+    );
+    Ok(out)
+}
+
+/// The contents of `src/format/data.rs`
+pub fn formats(regions: &[Region]) -> String {
+    let mut out = String::from(GENERATED);
+    out.push_str(
+        "// Number formats of libphonenumber's PhoneNumberMetadata.xml, keyed by country calling code and
+// taken from the main territory of each code. National prefix formatting rules have `$NP`
+// replaced by the national prefix. Formats which must not be used internationally have no
+// `international` format.
+
+use super::NumberFormat;
+
+",
+    );
+    let mut entries = Vec::new();
+    for region in main_regions(regions) {
+        if region.formats.is_empty() {
+            continue;
+        }
+        let national_prefix = region.national_prefix.as_deref().unwrap_or_default();
+        let mut entry = format!("    (\n        {},\n        &[\n", region.country_code);
+        for format in &region.formats {
+            let rule = format
+                .national_prefix_rule
+                .as_deref()
+                .map(|rule| rule.replace("$NP", national_prefix))
+                .filter(|rule| rule != "$FG");
+            let international = match format.intl_format.as_deref() {
+                Some("NA") => None,
+                Some("") | None => Some(format.format.as_str()),
+                Some(international) => Some(international),
+            };
+            let leading = format.leading_digits.last().map_or("", String::as_str);
+            write!(
+                entry,
+                "            NumberFormat {{
+                pattern: r\"{}\",
+                leading_digits: r\"{leading}\",
+                national: \"{}\",
+                international: {},
+                national_prefix_rule: {},
+            }},
+",
+                format.pattern,
+                format.format,
+                optional(international),
+                optional(rule.as_deref()),
+            )
+            .unwrap();
+        }
+        entry.push_str("        ],\n    ),");
+        entries.push(entry);
+    }
+    writeln!(
+        out,
+        "/// The number of calling codes with formats
+pub(super) const FORMATS_LEN: usize = {};
+
+#[rustfmt::skip]
+pub(super) static FORMATS: [(u32, &[NumberFormat]); FORMATS_LEN] = [",
+        entries.len()
+    )
+    .unwrap();
+    for entry in entries {
+        writeln!(out, "{entry}").unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/number_type/data.rs`
+pub fn number_types(regions: &[Region], territories: &[Territory]) -> String {
+    let mut out = String::from(GENERATED);
+    out.push_str(
+        "// Number types of libphonenumber's PhoneNumberMetadata.xml, for each territory and calling code.
+// Types are in the order they are checked in, fixed-line and mobile numbers last.
+
+use super::NumberType::*;
+use super::{NumberDesc, TerritoryNumbers};
+use crate::TerritoryCode;
+
+",
+    );
+    let mut entries = Vec::new();
+    for territory in territories {
+        for region in regions_of(regions, territories, territory) {
+            let all_lengths = region.lengths();
+            let mut entry = format!(
+                "    TerritoryNumbers {{
+        territory: TerritoryCode::{},
+        calling_code: {},
+        pattern: r\"{}\",
+        types: &[
+",
+                territory.code, region.country_code, region.general
+            );
+            for (name, number_type) in NUMBER_TYPES {
+                let Some(desc) = region.types.iter().find(|desc| desc.name == name) else {
+                    continue;
+                };
+                let lengths = match desc.lengths.is_empty() {
+                    true => &all_lengths,
+                    false => &desc.lengths,
+                };
+                let lengths: Vec<String> = lengths.iter().map(u8::to_string).collect();
+                write!(
+                    entry,
+                    "            NumberDesc {{
+                number_type: {number_type},
+                lengths: &[{}],
+                pattern: r\"{}\",
+            }},
+",
+                    lengths.join(", "),
+                    desc.pattern
+                )
+                .unwrap();
+            }
+            entry.push_str("        ],\n    },");
+            entries.push(entry);
+        }
+    }
+    writeln!(
+        out,
+        "/// The number of territories with number patterns
+pub(super) const TERRITORIES_LEN: usize = {};
+
+#[rustfmt::skip]
+pub(super) static TERRITORIES: [TerritoryNumbers; TERRITORIES_LEN] = [",
+        entries.len()
+    )
+    .unwrap();
+    for entry in entries {
+        writeln!(out, "{entry}").unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/territories.rs`: the territory codes and their tables
+pub fn territory_codes(territories: &[Territory]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/territories.csv`. Do not edit.
+
+use std::num::NonZeroU32;
+
+use crate::{CallingCodes, CallingCodesInner};
+
+///
+/// All the ISO 3166 territorial codes. Most of these are country codes,
+/// but some territories still exist in the world today.
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive] // New territories may want independence
+pub enum TerritoryCode {
+",
+    );
+    for territory in territories {
+        writeln!(out, "    {},", territory.code).unwrap();
+    }
+    writeln!(
+        out,
+        "}}
+
+/// All the territory codes, in the order of the enum
+#[rustfmt::skip]
+pub(crate) const ALL: [TerritoryCode; {}] = [",
+        territories.len()
+    )
+    .unwrap();
+    for territory in territories {
+        writeln!(out, "    TerritoryCode::{},", territory.code).unwrap();
+    }
+    out.push_str(
+        "];
+
+#[rustfmt::skip]
+pub(crate) fn calling_codes(territory: TerritoryCode) -> CallingCodes {
+    macro_rules! cc {
+        ($value:literal) => {
+            NonZeroU32::new($value).unwrap()
+        };
+    }
+    CallingCodes(match territory {
+",
+    );
+    for territory in territories {
+        let inner = ["Single", "Two", "Three"][territory.calling_codes.len() - 1];
+        let codes: Vec<String> = territory
+            .calling_codes
+            .iter()
+            .map(|code| format!("cc!({})", code.value()))
+            .collect();
+        writeln!(
+            out,
+            "        TerritoryCode::{} => CallingCodesInner::{inner}([{}]),",
+            territory.code,
+            codes.join(", ")
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "    })
+}
+
+/// The main territory of each country calling code shared by several territories
+#[rustfmt::skip]
+pub(crate) fn main_territory(calling_code: u32) -> Option<TerritoryCode> {
+    Some(match calling_code {
+",
+    );
+    for (calling_code, territory) in main_territories(territories) {
+        writeln!(
+            out,
+            "        {calling_code} => TerritoryCode::{},",
+            territory.code
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_name(name: &str) -> Option<TerritoryCode> {
+    Some(match name {
+",
+    );
+    for territory in territories {
+        writeln!(
+            out,
+            "        \"{0}\" => TerritoryCode::{0},",
+            territory.code
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn name(territory: TerritoryCode) -> &'static str {
+    match territory {
+",
+    );
+    for territory in territories {
+        writeln!(
+            out,
+            "        TerritoryCode::{0} => \"{0}\",",
+            territory.code
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn alpha3(territory: TerritoryCode) -> Option<&'static str> {
+    Some(match territory {
+",
+    );
+    for territory in territories {
+        if let Some(alpha3) = &territory.alpha3 {
+            writeln!(
+                out,
+                "        TerritoryCode::{} => \"{alpha3}\",",
+                territory.code
+            )
+            .unwrap();
+        }
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_alpha3(alpha3: &str) -> Option<TerritoryCode> {
+    Some(match alpha3 {
+",
+    );
+    for territory in territories {
+        if let Some(alpha3) = &territory.alpha3 {
+            writeln!(
+                out,
+                "        \"{alpha3}\" => TerritoryCode::{},",
+                territory.code
+            )
+            .unwrap();
+        }
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn numeric(territory: TerritoryCode) -> Option<u16> {
+    Some(match territory {
+",
+    );
+    for territory in territories {
+        if let Some(numeric) = territory.numeric {
+            writeln!(
+                out,
+                "        TerritoryCode::{} => {numeric},",
+                territory.code
+            )
+            .unwrap();
+        }
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn from_numeric(numeric: u16) -> Option<TerritoryCode> {
+    Some(match numeric {
+",
+    );
+    for territory in territories {
+        if let Some(numeric) = territory.numeric {
+            writeln!(
+                out,
+                "        {numeric} => TerritoryCode::{},",
+                territory.code
+            )
+            .unwrap();
+        }
+    }
+    out.push_str(
+        "        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+pub(crate) fn english_name(territory: TerritoryCode) -> &'static str {
+    match territory {
+",
+    );
+    for territory in territories {
+        writeln!(
+            out,
+            "        TerritoryCode::{} => {},",
+            territory.code,
+            quoted(&territory.english_name)
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn official_name(territory: TerritoryCode) -> &'static str {
+    match territory {
+",
+    );
+    for territory in territories {
+        writeln!(
+            out,
+            "        TerritoryCode::{} => {},",
+            territory.code,
+            quoted(&territory.official_name)
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "    }
+}
+
+/// The calling code of a territory as written by the deprecated `find_phone_cc`
+#[rustfmt::skip]
+pub(crate) fn calling_code_text(territory: TerritoryCode) -> &'static str {
+    match territory {
+",
+    );
+    for territory in territories {
+        writeln!(
+            out,
+            "        TerritoryCode::{} => \"{}\",",
+            territory.code,
+            territory.calling_code_text()
+        )
+        .unwrap();
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+/// The contents of `src/nanp/data.rs`: the area codes of the North American Numbering Plan
+pub fn nanp_area_codes(area_codes: &[AreaCode]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/nanp_area_codes.csv`. Do not edit.
+
+use super::AreaCodeStatus::*;
+use super::NanpAreaCode;
+use crate::TerritoryCode;
+
+",
+    );
+    writeln!(
+        out,
+        "/// All the area codes, sorted
+#[rustfmt::skip]
+pub(super) static AREA_CODES: [NanpAreaCode; {}] = [",
+        area_codes.len()
+    )
+    .unwrap();
+    for area_code in area_codes {
+        let territory = match &area_code.territory {
+            Some(code) => format!("Some(TerritoryCode::{code})"),
+            None => "None".into(),
+        };
+        let regions: Vec<String> = area_code
+            .regions
+            .iter()
+            .map(|region| format!("\"{region}\""))
+            .collect();
+        let overlays: Vec<String> = area_code
+            .overlays
+            .iter()
+            .map(|overlay| overlay.to_string())
+            .collect();
+        writeln!(
+            out,
+            "    NanpAreaCode {{ npa: {}, territory: {territory}, regions: &[{}], overlays: &[{}], in_service: {}, status: {} }},",
+            area_code.npa,
+            regions.join(", "),
+            overlays.join(", "),
+            match area_code.in_service {
+                Some(year) => format!("Some({year})"),
+                None => "None".into(),
+            },
+            area_code.status
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/subdivision/data.rs`: the ISO 3166-2 subdivisions
+pub fn subdivisions(subdivisions: &[Subdivision]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/subdivisions.csv`. Do not edit.
+
+use super::Subdivision;
+use crate::TerritoryCode;
+
+",
+    );
+    writeln!(
+        out,
+        "/// All the subdivisions, sorted by code
+#[rustfmt::skip]
+pub(super) static SUBDIVISIONS: [Subdivision; {}] = [",
+        subdivisions.len()
+    )
+    .unwrap();
+    for subdivision in subdivisions {
+        writeln!(
+            out,
+            "    Subdivision {{ code: \"{}\", territory: TerritoryCode::{}, category: {:?}, name: {:?} }},",
+            subdivision.code,
+            subdivision.territory(),
+            subdivision.category,
+            subdivision.name
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+pub fn geocoding(areas: &[Area]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/geocoding.csv`. Do not edit.
+
+use super::Area;
+
+",
+    );
+    writeln!(
+        out,
+        "/// All the areas, sorted by prefix
+#[rustfmt::skip]
+pub(super) static AREAS: [Area; {}] = [",
+        areas.len()
+    )
+    .unwrap();
+    for area in areas {
+        let descriptions: Vec<String> = area
+            .descriptions
+            .iter()
+            .map(|(locale, description)| format!("({locale:?}, {})", quoted(description)))
+            .collect();
+        writeln!(
+            out,
+            "    Area {{ prefix: {}, descriptions: &[{}] }},",
+            area.prefix,
+            descriptions.join(", ")
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/time_zone/data.rs`: the time zones of the territories and prefixes
+pub fn time_zones(rows: &[TimeZones]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/time_zones.csv`. Do not edit.
+
+use crate::TerritoryCode;
+
+/// The time zones of a territory, main first
+#[rustfmt::skip]
+pub(super) fn territory_time_zones(territory: TerritoryCode) -> &'static [&'static str] {
+    match territory {
+",
+    );
+    let list = |time_zones: &[String]| {
+        let time_zones: Vec<String> = time_zones
+            .iter()
+            .map(|time_zone| format!("\"{time_zone}\""))
+            .collect();
+        format!("&[{}]", time_zones.join(", "))
+    };
+    let (territories, prefixes): (Vec<&TimeZones>, Vec<&TimeZones>) =
+        rows.iter().partition(|row| row.prefix.is_none());
+    for row in territories {
+        writeln!(
+            out,
+            "        TerritoryCode::{} => {},",
+            row.territory,
+            list(&row.time_zones)
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "        _ => &[],
+    }}
+}}
+
+/// The time zones of the prefixes, sorted by prefix
+#[rustfmt::skip]
+pub(super) static PREFIXES: [(u64, &[&str]); {}] = [",
+        prefixes.len()
+    )
+    .unwrap();
+    for row in prefixes {
+        writeln!(
+            out,
+            "    ({}, {}),",
+            row.prefix.unwrap(),
+            list(&row.time_zones)
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/carrier/data.rs`: the carriers of mobile phone number ranges
+pub fn carriers(ranges: &[Range]) -> String {
+    let mut out = String::from(
+        "// Generated by `cargo run -p metadata-gen` from `data/carriers.csv`. Do not edit.
+
+",
+    );
+    writeln!(
+        out,
+        "/// The carriers of the prefixes, sorted by prefix
+#[rustfmt::skip]
+pub(super) static CARRIERS: [(u64, &str); {}] = [",
+        ranges.len()
+    )
+    .unwrap();
+    for range in ranges {
+        writeln!(out, "    ({}, {}),", range.prefix, quoted(&range.carrier)).unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The contents of `src/cldr_names/data.rs`: the territory names of the CLDR locales
+pub fn cldr_names(version: &str, locales: &[LocaleNames]) -> String {
+    let mut out = format!(
+        "// Generated by `cargo run -p metadata-gen -- cldr-names path/to/cldr-localenames-full/main`.
+// Do not edit.
+// Territory display names from CLDR {version}, in the order of `TerritoryCode::all`. Territories
+// unknown to CLDR have an empty name. English names are those of `TerritoryCode::english_name`.
+// Sort keys are the positions of the names once sorted with the CLDR collation of each locale,
+// English names included.
+
+use super::LocaleNames;
+
+#[rustfmt::skip]
+pub(super) static LOCALES: [LocaleNames; {}] = [
+",
+        locales.len()
+    );
+    for names in locales {
+        writeln!(
+            out,
+            "    LocaleNames {{\n        locale: {:?},",
+            names.locale
+        )
+        .unwrap();
+        if names.names.is_empty() {
+            out.push_str("        names: &[],\n");
+        } else {
+            let quoted: Vec<String> = names.names.iter().map(|name| quoted(name)).collect();
+            writeln!(out, "        names: &[\n{}        ],", wrapped(&quoted)).unwrap();
+        }
+        let sort_keys: Vec<String> = names.sort_keys.iter().map(u16::to_string).collect();
+        writeln!(
+            out,
+            "        sort_keys: &[\n{}        ],",
+            wrapped(&sort_keys)
+        )
+        .unwrap();
+        out.push_str("    },\n");
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// A string literal of a name. Not debug-formatted, which escapes combining marks, but with the
+/// invisible characters escaped, like the soft hyphens of some carrier names.
+fn quoted(name: &str) -> String {
+    let mut out = String::from('"');
+    for c in name.chars() {
+        match c {
+            '\\' | '"' => write!(out, "\\{c}").unwrap(),
+            '\u{ad}' | '\u{200b}' | '\u{2060}' => write!(out, "{}", c.escape_unicode()).unwrap(),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The items of an array, as many on each line as fit in 100 columns
+fn wrapped(items: &[String]) -> String {
+    const INDENT: &str = "            ";
+    let mut out = String::new();
+    let mut line = String::from(INDENT);
+    for item in items {
+        if line.len() > INDENT.len() && line.chars().count() + item.chars().count() + 1 > 100 {
+            out.push_str(line.trim_end());
+            out.push('\n');
+            line = String::from(INDENT);
+        }
+        write!(line, "{item}, ").unwrap();
+    }
+    out.push_str(line.trim_end());
+    out.push('\n');
+    out
+}
@@ -0,0 +1,196 @@
+//! The geographic areas of phone number prefixes, from `data/geocoding.csv`
+//!
+//! Its columns are:
+//! - `prefix`: the leading digits of international phone numbers, including the calling code, like
+//!   the prefixes of the lookup table, e.g. `4930` for Berlin
+//! - `locale`: the BCP 47 locale of the description. Every prefix has an `en` description, which
+//!   comes first
+//! - `description`: the name of the area, which may contain commas, e.g. `New York, NY`
+//!
+//! The file is imported from the geocoding resources of libphonenumber by the `geocoding` step,
+//! keeping the prefixes of at most [`MAX_NATIONAL_DIGITS`] digits after the calling code which
+//! have an English description. Descriptions identical to the English one are left out.
+
+use std::collections::BTreeMap;
+
+use crate::resources;
+use crate::territories::Territory;
+
+const HEADER: &str = "prefix,locale,description";
+
+/// The locale every prefix must have a description in
+const FALLBACK_LOCALE: &str = "en";
+
+/// The most digits of a prefix, as the longest international phone numbers
+const MAX_PREFIX_LEN: usize = 15;
+
+/// The most digits after the calling code of the imported prefixes, which describe areas rather
+/// than single exchanges, e.g. `1212` for Manhattan or `49201` for Essen
+pub const MAX_NATIONAL_DIGITS: usize = 3;
+
+/// The languages of libphonenumber's geocoding resources, with their BCP 47 locales
+pub const LANGUAGES: [(&str, &str); 33] = [
+    ("ar", "ar"),
+    ("be", "be"),
+    ("bg", "bg"),
+    ("bs", "bs"),
+    ("de", "de"),
+    ("el", "el"),
+    ("en", "en"),
+    ("es", "es"),
+    ("fa", "fa"),
+    ("fi", "fi"),
+    ("fr", "fr"),
+    ("hr", "hr"),
+    ("hu", "hu"),
+    ("hy", "hy"),
+    ("id", "id"),
+    ("it", "it"),
+    ("iw", "he"),
+    ("ja", "ja"),
+    ("ko", "ko"),
+    ("nl", "nl"),
+    ("pl", "pl"),
+    ("pt", "pt"),
+    ("ro", "ro"),
+    ("ru", "ru"),
+    ("sq", "sq"),
+    ("sr", "sr"),
+    ("sv", "sv"),
+    ("th", "th"),
+    ("tr", "tr"),
+    ("uk", "uk"),
+    ("vi", "vi"),
+    ("zh", "zh"),
+    ("zh_Hant", "zh-Hant"),
+];
+
+#[derive(Debug)]
+pub struct Area {
+    pub prefix: u64,
+    /// The locales and descriptions, English first
+    pub descriptions: Vec<(String, String)>,
+}
+
+/// Reads and checks the areas, sorted by prefix
+pub fn parse(csv: &str, territories: &[Territory]) -> Result<Vec<Area>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut areas: Vec<Area> = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let (prefix, locale, description) = description(line, territories)
+            .map_err(|error| format!("Line {}: {error}", number + 2))?;
+        match areas.iter_mut().find(|area| area.prefix == prefix) {
+            Some(area) if area.descriptions.iter().any(|(known, _)| *known == locale) => {
+                return Err(format!("Duplicate description of {prefix} in {locale}"));
+            }
+            Some(area) => area.descriptions.push((locale, description)),
+            None => areas.push(Area {
+                prefix,
+                descriptions: vec![(locale, description)],
+            }),
+        }
+    }
+    for area in &mut areas {
+        let Some(english) = area
+            .descriptions
+            .iter()
+            .position(|(locale, _)| locale == FALLBACK_LOCALE)
+        else {
+            return Err(format!(
+                "{} has no description in {FALLBACK_LOCALE}",
+                area.prefix
+            ));
+        };
+        let english = area.descriptions.remove(english);
+        area.descriptions.sort();
+        area.descriptions.insert(0, english);
+    }
+    areas.sort_by_key(|area| area.prefix);
+    Ok(areas)
+}
+
+fn description(line: &str, territories: &[Territory]) -> Result<(u64, String, String), String> {
+    let fields: Vec<&str> = line.splitn(3, ',').collect();
+    let [prefix, locale, description] = fields[..] else {
+        return Err(format!("Expected 3 fields in {line:?}"));
+    };
+    let valid = (1..=MAX_PREFIX_LEN).contains(&prefix.len())
+        && prefix.bytes().all(|b| b.is_ascii_digit())
+        && !prefix.starts_with('0');
+    if !valid {
+        return Err(format!("Invalid prefix {prefix:?}"));
+    }
+    // The calling codes are prefix-free, so a prefix starts with a single one of them
+    let geographic = territories
+        .iter()
+        .flat_map(|territory| &territory.calling_codes)
+        .map(|code| code.country.to_string())
+        .any(|country| prefix.starts_with(&country) && prefix.len() > country.len());
+    if !geographic {
+        return Err(format!("{prefix} does not extend a calling code"));
+    }
+    let valid = !locale.is_empty()
+        && locale
+            .split('-')
+            .all(|subtag| !subtag.is_empty() && subtag.bytes().all(|b| b.is_ascii_alphanumeric()));
+    if !valid {
+        return Err(format!("Invalid locale {locale:?}"));
+    }
+    if description.is_empty() {
+        return Err(format!("{prefix} has an empty description in {locale}"));
+    }
+    Ok((prefix.parse().unwrap(), locale.into(), description.into()))
+}
+
+/// The contents of the file, imported from libphonenumber's `resources/geocoding` directory. Its
+/// files are read with `read_resource`, from their paths relative to the directory.
+pub fn import(
+    read_resource: impl Fn(&str) -> Result<Option<String>, String>,
+    territories: &[Territory],
+) -> Result<String, String> {
+    let mut countries: Vec<String> = territories
+        .iter()
+        .flat_map(|territory| &territory.calling_codes)
+        .map(|code| code.country.to_string())
+        .collect();
+    countries.sort();
+    countries.dedup();
+    let is_area = |prefix: &str| {
+        countries.iter().any(|country| {
+            prefix.starts_with(country.as_str())
+                && (1..=MAX_NATIONAL_DIGITS).contains(&(prefix.len() - country.len()))
+        })
+    };
+
+    // English first, since the descriptions of other languages are only kept for its prefixes
+    let mut languages = LANGUAGES.to_vec();
+    languages.sort_by_key(|&(_, locale)| locale != FALLBACK_LOCALE);
+    let mut descriptions: BTreeMap<u64, Vec<(&str, String)>> = BTreeMap::new();
+    for (language, locale) in languages {
+        for (prefix, description) in resources::entries(&read_resource, language, territories)? {
+            if !is_area(&prefix) || description.is_empty() {
+                continue;
+            }
+            let prefix = prefix
+                .parse()
+                .map_err(|_| format!("{language}: Invalid prefix {prefix:?}"))?;
+            match descriptions.get_mut(&prefix) {
+                Some(known) if known[0].1 != description => known.push((locale, description)),
+                None if locale == FALLBACK_LOCALE => {
+                    descriptions.insert(prefix, vec![(locale, description)]);
+                }
+                _ => {}
+            }
+        }
+    }
+    let mut csv = format!("{HEADER}\n");
+    for (prefix, descriptions) in descriptions {
+        for (locale, description) in descriptions {
+            csv.push_str(&format!("{prefix},{locale},{description}\n"));
+        }
+    }
+    Ok(csv)
+}
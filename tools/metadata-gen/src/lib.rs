@@ -0,0 +1,763 @@
+//!
+//! Generates the data tables of e164-phones-countries. The territory codes and their ISO 3166 and
+//! calling codes come from `data/territories.csv`, their ISO 3166-2 subdivisions from
+//! `data/subdivisions.csv`, the area codes of the North American Numbering Plan from
+//! `data/nanp_area_codes.csv`, the geographic areas of phone number prefixes from
+//! `data/geocoding.csv`, the time zones of the territories and prefixes from
+//! `data/time_zones.csv`, and the carriers of mobile phone number ranges from
+//! `data/carriers.csv`. The prefix lookup table, the lengths of national significant numbers, the
+//! national and international prefixes, the number formats and the number types come from
+//! libphonenumber's `PhoneNumberMetadata.xml`, and the localized territory names from the JSON
+//! distribution of the Unicode CLDR. `data/geocoding.csv` and `data/carriers.csv` are themselves
+//! imported from libphonenumber's geocoding and carrier resources.
+//!
+
+mod carriers;
+mod cldr_names;
+mod emit;
+mod geocoding;
+mod lookup;
+mod metadata;
+mod nanp;
+mod pattern;
+mod resources;
+mod subdivisions;
+mod territories;
+mod time_zones;
+
+/// The path of the territories, relative to the crate directory
+pub const TERRITORIES_PATH: &str = "data/territories.csv";
+
+/// The path of the subdivisions, relative to the crate directory
+pub const SUBDIVISIONS_PATH: &str = "data/subdivisions.csv";
+
+/// The path of the NANP area codes, relative to the crate directory
+pub const NANP_AREA_CODES_PATH: &str = "data/nanp_area_codes.csv";
+
+/// The path of the geographic areas, relative to the crate directory
+pub const GEOCODING_PATH: &str = "data/geocoding.csv";
+
+/// The path of the time zones, relative to the crate directory
+pub const TIME_ZONES_PATH: &str = "data/time_zones.csv";
+
+/// The path of the carriers, relative to the crate directory
+pub const CARRIERS_PATH: &str = "data/carriers.csv";
+
+/// The generated files, as paths relative to the crate directory and their contents. The data
+/// files are read with `read_data`, from their paths relative to the crate directory. Without
+/// libphonenumber's metadata, only the tables of the data files are generated.
+pub fn generate(
+    read_data: impl Fn(&str) -> Result<String, String>,
+    metadata_xml: Option<&str>,
+) -> Result<Vec<(&'static str, String)>, String> {
+    let territories = territories::parse(&read_data(TERRITORIES_PATH)?)?;
+    let subdivisions = subdivisions::parse(&read_data(SUBDIVISIONS_PATH)?, &territories)?;
+    let area_codes = nanp::parse(
+        &read_data(NANP_AREA_CODES_PATH)?,
+        &territories,
+        &subdivisions,
+    )?;
+    let areas = geocoding::parse(&read_data(GEOCODING_PATH)?, &territories)?;
+    let time_zones = time_zones::parse(&read_data(TIME_ZONES_PATH)?, &territories)?;
+    let ranges = carriers::parse(&read_data(CARRIERS_PATH)?, &territories)?;
+    let mut files = vec![
+        ("src/territories.rs", emit::territory_codes(&territories)),
+        ("src/subdivision/data.rs", emit::subdivisions(&subdivisions)),
+        ("src/nanp/data.rs", emit::nanp_area_codes(&area_codes)),
+        ("src/geocoding/data.rs", emit::geocoding(&areas)),
+        ("src/time_zone/data.rs", emit::time_zones(&time_zones)),
+        ("src/carrier/data.rs", emit::carriers(&ranges)),
+    ];
+    if let Some(xml) = metadata_xml {
+        let regions = metadata::parse(xml)?;
+        files.extend([
+            (
+                "src/metadata.rs",
+                emit::territory_metadata(&regions, &territories)?,
+            ),
+            ("src/format/data.rs", emit::formats(&regions)),
+            (
+                "src/number_type/data.rs",
+                emit::number_types(&regions, &territories),
+            ),
+        ]);
+    }
+    Ok(files)
+}
+
+/// The generated file of the CLDR territory names, as its path relative to the crate directory
+/// and its contents. The data files are read with `read_data`, like
+/// [`generate`], and the `territories.json` file of each locale with `read_locale`, from the
+/// locale.
+pub fn generate_cldr_names(
+    read_data: impl Fn(&str) -> Result<String, String>,
+    read_locale: impl Fn(&str) -> Result<String, String>,
+) -> Result<(&'static str, String), String> {
+    let territories = territories::parse(&read_data(TERRITORIES_PATH)?)?;
+    let (version, locales) = cldr_names::parse(read_locale, &territories)?;
+    Ok((
+        "src/cldr_names/data.rs",
+        emit::cldr_names(&version, &locales),
+    ))
+}
+
+/// The imported `data/geocoding.csv` and its generated table, as paths relative to the crate
+/// directory and their contents. The data files are read with `read_data`, like [`generate`], and
+/// the files of libphonenumber's `resources/geocoding` directory with `read_resource`, from their
+/// paths relative to the directory. Missing files are `None`.
+pub fn import_geocoding(
+    read_data: impl Fn(&str) -> Result<String, String>,
+    read_resource: impl Fn(&str) -> Result<Option<String>, String>,
+) -> Result<Vec<(&'static str, String)>, String> {
+    let territories = territories::parse(&read_data(TERRITORIES_PATH)?)?;
+    let csv = geocoding::import(read_resource, &territories)?;
+    let areas = geocoding::parse(&csv, &territories)?;
+    Ok(vec![
+        (GEOCODING_PATH, csv),
+        ("src/geocoding/data.rs", emit::geocoding(&areas)),
+    ])
+}
+
+/// The imported `data/carriers.csv` and its generated table, like [`import_geocoding`], from
+/// libphonenumber's `resources/carrier` directory
+pub fn import_carriers(
+    read_data: impl Fn(&str) -> Result<String, String>,
+    read_resource: impl Fn(&str) -> Result<Option<String>, String>,
+) -> Result<Vec<(&'static str, String)>, String> {
+    let territories = territories::parse(&read_data(TERRITORIES_PATH)?)?;
+    let csv = carriers::import(read_resource, &territories)?;
+    let ranges = carriers::parse(&csv, &territories)?;
+    Ok(vec![
+        (CARRIERS_PATH, csv),
+        ("src/carrier/data.rs", emit::carriers(&ranges)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const TERRITORIES: &str =
+        "code,alpha3,numeric,calling_codes,main,prefixes,english_name,official_name
+GB,GBR,826,44,true,,United Kingdom,United Kingdom of Great Britain and Northern Ireland
+GG,GGY,831,44-1481,,,Guernsey,Bailiwick of Guernsey
+NO,NOR,578,47,true,,Norway,Kingdom of Norway
+SJ,SJM,744,47-79,,,Svalbard and Jan Mayen,Svalbard and Jan Mayen
+";
+
+    const METADATA: &str = r#"<phoneNumberMetadata><territories>
+        <territory id="GB" mainCountryForCode="true" countryCode="44" nationalPrefix="0">
+          <generalDesc><nationalNumberPattern>[1-9]\d{9}</nationalNumberPattern></generalDesc>
+          <fixedLine>
+            <possibleLengths national="10"/>
+            <nationalNumberPattern>
+              1(?:48[02-9]|[2-35]\d\d)\d{6}
+            </nationalNumberPattern>
+          </fixedLine>
+          <mobile>
+            <possibleLengths national="10"/>
+            <nationalNumberPattern>7(?:[1-69]\d|7[0-79]|8[0-24-9])\d{7}</nationalNumberPattern>
+          </mobile>
+          <tollFree>
+            <possibleLengths national="[9-10]"/>
+            <nationalNumberPattern>80[08]\d{6,7}</nationalNumberPattern>
+          </tollFree>
+          <availableFormats>
+            <numberFormat pattern="(\d{2})(\d{4})(\d{4})" nationalPrefixFormattingRule="$NP$FG">
+              <leadingDigits>2</leadingDigits>
+              <format>$1 $2 $3</format>
+            </numberFormat>
+          </availableFormats>
+        </territory>
+        <territory id="GG" countryCode="44" nationalPrefix="0">
+          <generalDesc><nationalNumberPattern>[17]\d{9}</nationalNumberPattern></generalDesc>
+          <fixedLine>
+            <possibleLengths national="10"/>
+            <nationalNumberPattern>1481[25-9]\d{5}</nationalNumberPattern>
+          </fixedLine>
+          <mobile>
+            <possibleLengths national="10"/>
+            <nationalNumberPattern>7(?:781|839)\d{6}</nationalNumberPattern>
+          </mobile>
+          <availableFormats>
+            <numberFormat pattern="(\d{4})(\d{6})">
+              <leadingDigits>1</leadingDigits>
+              <format>$1 $2</format>
+            </numberFormat>
+          </availableFormats>
+        </territory>
+        <territory id="NO" mainCountryForCode="true" countryCode="47" leadingDigits="[02-689]|7[0-8]">
+          <generalDesc><nationalNumberPattern>\d{8}</nationalNumberPattern></generalDesc>
+          <fixedLine>
+            <possibleLengths national="8"/>
+            <nationalNumberPattern>[2-7]\d{7}</nationalNumberPattern>
+          </fixedLine>
+          <availableFormats>
+            <numberFormat pattern="(\d{3})(\d{2})(\d{3})">
+              <leadingDigits>
+                8
+              </leadingDigits>
+              <format>$1 $2 $3</format>
+              <intlFormat>NA</intlFormat>
+            </numberFormat>
+          </availableFormats>
+        </territory>
+        <territory id="SJ" countryCode="47" leadingDigits="79">
+          <generalDesc><nationalNumberPattern>\d{8}</nationalNumberPattern></generalDesc>
+          <fixedLine>
+            <possibleLengths national="8"/>
+            <nationalNumberPattern>79\d{6}</nationalNumberPattern>
+          </fixedLine>
+        </territory>
+    </territories></phoneNumberMetadata>"#;
+
+    #[test]
+    fn formats_of_main_territories() {
+        let formats = emit::formats(&metadata::parse(METADATA).unwrap());
+        assert!(formats.contains("pub(super) const FORMATS_LEN: usize = 2;"));
+        assert!(formats.contains("national_prefix_rule: Some(\"0$FG\")"));
+        assert!(!formats.contains(r"(\d{4})(\d{6})"));
+        assert!(formats.contains("leading_digits: r\"8\""));
+        assert!(formats.contains("international: None"));
+    }
+
+    #[test]
+    fn number_types_in_checking_order() {
+        let regions = metadata::parse(METADATA).unwrap();
+        let territories = territories::parse(TERRITORIES).unwrap();
+        let number_types = emit::number_types(&regions, &territories);
+        assert!(number_types.contains("pub(super) const TERRITORIES_LEN: usize = 4;"));
+        let toll_free = number_types.find("number_type: TollFree").unwrap();
+        let fixed_line = number_types.find("number_type: FixedLine").unwrap();
+        let mobile = number_types.find("number_type: Mobile").unwrap();
+        assert!(toll_free < fixed_line && fixed_line < mobile);
+        assert!(number_types.contains("lengths: &[9, 10]"));
+        assert!(number_types.contains(r#"pattern: r"1(?:48[02-9]|[2-35]\d\d)\d{6}""#));
+    }
+
+    #[test]
+    fn derive_prefixes_of_shared_calling_codes() {
+        let regions = metadata::parse(METADATA).unwrap();
+        let territories = territories::parse(TERRITORIES).unwrap();
+        assert_eq!(
+            vec![
+                (44, "GB"),
+                (47, "NO"),
+                (4779, "SJ"),
+                (44778, "GG"),
+                (44783, "GG"),
+                (441481, "GG"),
+            ],
+            lookup::lookup_prefixes(&regions, &territories).unwrap()
+        );
+
+        let territories = territories::parse(&format!(
+            "{TERRITORIES}AQ,ATA,10,672,,,Antarctica,Antarctica\n"
+        ))
+        .unwrap();
+        assert_eq!(
+            Err("No lookup prefix for AQ".into()),
+            lookup::lookup_prefixes(&regions, &territories)
+        );
+        let territories = territories::parse(&format!(
+            "{TERRITORIES}AQ,ATA,10,672,,6721,Antarctica,Antarctica\n"
+        ))
+        .unwrap();
+        let prefixes = lookup::lookup_prefixes(&regions, &territories).unwrap();
+        assert!(prefixes.contains(&(6721, "AQ")));
+    }
+
+    #[test]
+    fn reject_inconsistent_territories() {
+        for (csv, error) in [
+            (
+                format!("{TERRITORIES}GB,GBX,1,44,,,Britain,Britain\n"),
+                "Duplicate territory GB",
+            ),
+            (
+                format!("{TERRITORIES}JE,JEY,832,44-1534,true,,Jersey,Bailiwick of Jersey\n"),
+                "Calling code 44 needs a single main territory when shared",
+            ),
+            (
+                TERRITORIES.replace("NO,NOR,578,47,true,", "NO,NOR,578,47,,"),
+                "Calling code 47 needs a single main territory when shared",
+            ),
+            (
+                format!("{TERRITORIES}XT,,,,,,International Freephone,International Freephone\n"),
+                "XT must have 1 to 3 calling codes",
+            ),
+            (
+                format!("{TERRITORIES}AQ,ATA,,672,,,Antarctica,Antarctica\n"),
+                "AQ must have both ISO 3166-1 codes or none",
+            ),
+            (
+                format!("{TERRITORIES}aq,,,672,,,Antarctica,Antarctica\n"),
+                "Line 6: Invalid code \"aq\"",
+            ),
+            (
+                format!("{TERRITORIES}JE,JEY,832,44-1534,,,Jersey,\n"),
+                "Line 6: JE has no English or official name",
+            ),
+            (
+                format!("{TERRITORIES}JE,JEY,832,44-1534,,\n"),
+                "Line 6: Expected 8 fields in \"JE,JEY,832,44-1534,,\"",
+            ),
+        ] {
+            assert_eq!(Err(error.to_string()), territories::parse(&csv).map(|_| ()));
+        }
+    }
+
+    #[test]
+    fn reject_inconsistent_subdivisions() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        const HEADER: &str = "code,category,name\n";
+        let subdivisions = subdivisions::parse(
+            &format!("{HEADER}NO-03,county,Oslo, the capital\nGB-SCT,country,Scotland\n"),
+            &territories,
+        )
+        .unwrap();
+        assert_eq!("GB-SCT", subdivisions[0].code);
+        assert_eq!("Oslo, the capital", subdivisions[1].name);
+        for (csv, error) in [
+            (
+                format!("{HEADER}GB-SCT,country,Scotland\nGB-SCT,country,Scotland\n"),
+                "Duplicate subdivision GB-SCT",
+            ),
+            (
+                format!("{HEADER}GB-SCT,country\n"),
+                "Line 2: Expected 3 fields in \"GB-SCT,country\"",
+            ),
+            (
+                format!("{HEADER}GB-SCT,,Scotland\n"),
+                "Line 2: GB-SCT has no category or name",
+            ),
+            (
+                format!("{HEADER}GB-sct,country,Scotland\n"),
+                "Line 2: Invalid subdivision code \"GB-sct\"",
+            ),
+            (
+                format!("{HEADER}FR-75,metropolitan department,Paris\n"),
+                "Line 2: Invalid subdivision code \"FR-75\"",
+            ),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                subdivisions::parse(&csv, &territories).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn reject_inconsistent_area_codes() {
+        let territories = territories::parse(
+            "code,alpha3,numeric,calling_codes,main,prefixes,english_name,official_name
+US,USA,840,1,true,,United States,United States of America
+CA,CAN,124,1,,,Canada,Canada
+GB,GBR,826,44,,,United Kingdom,United Kingdom
+",
+        )
+        .unwrap();
+        let subdivisions = subdivisions::parse(
+            "code,category,name\nCA-ON,province,Ontario\nUS-NY,state,New York\n",
+            &territories,
+        )
+        .unwrap();
+        const AREA_CODES: &str = "npa,territory,regions,overlays,in_service,status
+212,US,NY,212,1947,in-service
+646,US,NY,212,1999,in-service
+718,US,NY,718,1984,in-service
+800,US,,,1967,toll-free
+917,US,NY,212 718,1992,in-service
+";
+        assert_eq!(
+            5,
+            nanp::parse(AREA_CODES, &territories, &subdivisions)
+                .unwrap()
+                .len()
+        );
+        for (csv, error) in [
+            (
+                format!("{AREA_CODES}212,US,NY,,1947,in-service\n"),
+                "Duplicate area code 212",
+            ),
+            (
+                format!("{AREA_CODES}207,GB,,,1947,in-service\n"),
+                "207: GB is not a territory of calling code 1",
+            ),
+            (
+                format!("{AREA_CODES}207,,,,,planned\n"),
+                "207 is geographic but has no territory",
+            ),
+            (
+                format!("{AREA_CODES}207,US,,,1947,in-service\n"),
+                "207 must have regions only when geographic in the US or Canada",
+            ),
+            (
+                format!("{AREA_CODES}888,US,NY,,1996,toll-free\n"),
+                "888 must have regions only when geographic in the US or Canada",
+            ),
+            (
+                format!("{AREA_CODES}332,US,NY,646,2017,in-service\n"),
+                "332: invalid overlay 646",
+            ),
+            (
+                format!("{AREA_CODES}347,US,NY,718 917,1999,in-service\n"),
+                "347: invalid overlay 917",
+            ),
+            (
+                format!("{AREA_CODES}332,CA,ON,212,2017,in-service\n"),
+                "332: invalid overlay 212",
+            ),
+            (
+                format!("{AREA_CODES}332,US,NY,,,in-service\n"),
+                "332 is in service but has no year",
+            ),
+            (
+                format!("{AREA_CODES}201,US,NJ,,1947,in-service\n"),
+                "201: invalid region \"NJ\"",
+            ),
+            (
+                format!("{AREA_CODES}112,US,NY,,1947,in-service\n"),
+                "Line 7: Invalid area code \"112\"",
+            ),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                nanp::parse(&csv, &territories, &subdivisions).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn reject_inconsistent_areas() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        const AREAS: &str = "prefix,locale,description
+4420,fr,Londres
+4420,en,London
+44161,en,Manchester
+4722,en,Oslo, the capital
+";
+        let areas = geocoding::parse(AREAS, &territories).unwrap();
+        assert_eq!(
+            vec![4420, 4722, 44161],
+            areas.iter().map(|area| area.prefix).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                ("en".into(), "London".into()),
+                ("fr".into(), "Londres".into())
+            ],
+            areas[0].descriptions
+        );
+        assert_eq!("Oslo, the capital", areas[1].descriptions[0].1);
+        for (csv, error) in [
+            (
+                format!("{AREAS}4420,fr,Londres\n"),
+                "Duplicate description of 4420 in fr",
+            ),
+            (
+                format!("{AREAS}44113,de,Leeds\n"),
+                "44113 has no description in en",
+            ),
+            (
+                format!("{AREAS}44,en,United Kingdom\n"),
+                "Line 6: 44 does not extend a calling code",
+            ),
+            (
+                format!("{AREAS}3312,en,Paris\n"),
+                "Line 6: 3312 does not extend a calling code",
+            ),
+            (
+                format!("{AREAS}44 20,en,London\n"),
+                "Line 6: Invalid prefix \"44 20\"",
+            ),
+            (
+                format!("{AREAS}44113,en_GB,Leeds\n"),
+                "Line 6: Invalid locale \"en_GB\"",
+            ),
+            (
+                format!("{AREAS}44113,en\n"),
+                "Line 6: Expected 3 fields in \"44113,en\"",
+            ),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                geocoding::parse(&csv, &territories).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn import_geocoding_resources() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        let resources = |path: &str| {
+            Ok(match path {
+                "en/44.txt" => {
+                    Some("# Comment\n\n4420|London\n44161|Manchester\n441614|Stretford\n")
+                }
+                "en/47.txt" => Some("4722|Oslo\n4733|\n"),
+                "de/44.txt" => Some("4420|London\n44161|Manchester\n4422|Kent\n"),
+                "fr/44.txt" => Some("4420|Londres\n"),
+                "iw/47.txt" => Some("4722|אוסלו\n"),
+                _ => None,
+            }
+            .map(String::from))
+        };
+        let csv = geocoding::import(resources, &territories).unwrap();
+        assert_eq!(
+            "prefix,locale,description
+4420,en,London
+4420,fr,Londres
+4722,en,Oslo
+4722,he,אוסלו
+44161,en,Manchester
+",
+            csv
+        );
+        assert_eq!(3, geocoding::parse(&csv, &territories).unwrap().len());
+
+        let error = geocoding::import(
+            |path: &str| Ok((path == "en/47.txt").then(|| "4722 Oslo".into())),
+            &territories,
+        );
+        assert_eq!(
+            Err("en/47.txt, line 1: Invalid entry \"4722 Oslo\"".into()),
+            error
+        );
+    }
+
+    #[test]
+    fn reject_inconsistent_time_zones() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        const TIME_ZONES: &str = "territory,prefix,time_zones
+GB,,Europe/London
+GG,,Europe/Guernsey
+NO,,Europe/Oslo
+SJ,,Arctic/Longyearbyen
+GB,44161,Europe/London
+GG,4414812,Europe/Guernsey
+";
+        let rows = time_zones::parse(TIME_ZONES, &territories).unwrap();
+        assert_eq!(
+            vec![None, None, None, None, Some(44161), Some(4414812)],
+            rows.iter().map(|row| row.prefix).collect::<Vec<_>>()
+        );
+        for (csv, error) in [
+            (
+                TIME_ZONES.replace("NO,,Europe/Oslo\n", ""),
+                "NO has no time zones",
+            ),
+            (
+                format!("{TIME_ZONES}GB,,Europe/London\n"),
+                "Duplicate time zones of GB",
+            ),
+            (
+                format!("{TIME_ZONES}GB,4414812,Europe/London\n"),
+                "Duplicate time zones of 4414812",
+            ),
+            (
+                format!("{TIME_ZONES}GB,44113,Europe/Dublin\n"),
+                "44113: Europe/Dublin is not a time zone of GB",
+            ),
+            (
+                format!("{TIME_ZONES}GG,44113,Europe/Guernsey\n"),
+                "Line 8: 44113 does not extend a calling code of GG",
+            ),
+            (
+                format!("{TIME_ZONES}NO,4722,Europe/Oslo Europe/Oslo\n"),
+                "Line 8: Duplicate time zone Europe/Oslo",
+            ),
+            (
+                format!("{TIME_ZONES}NO,4722,Oslo\n"),
+                "Line 8: Invalid time zone \"Oslo\"",
+            ),
+            (
+                format!("{TIME_ZONES}NO,4722,europe/oslo\n"),
+                "Line 8: Invalid time zone \"europe/oslo\"",
+            ),
+            (
+                format!("{TIME_ZONES}NO,47 22,Europe/Oslo\n"),
+                "Line 8: Invalid prefix \"47 22\"",
+            ),
+            (
+                format!("{TIME_ZONES}FR,,Europe/Paris\n"),
+                "Line 8: Invalid territory \"FR\"",
+            ),
+            (
+                format!("{TIME_ZONES}NO,Europe/Oslo\n"),
+                "Line 8: Expected 3 fields in \"NO,Europe/Oslo\"",
+            ),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                time_zones::parse(&csv, &territories).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn reject_inconsistent_carriers() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        const CARRIERS: &str = "prefix,carrier
+477,Telenor
+4477,O2
+447700,Vodafone
+";
+        let ranges = carriers::parse(CARRIERS, &territories).unwrap();
+        assert_eq!(
+            vec![477, 4477, 447700],
+            ranges.iter().map(|range| range.prefix).collect::<Vec<_>>()
+        );
+        for (csv, error) in [
+            (format!("{CARRIERS}4477,EE\n"), "Duplicate carrier of 4477"),
+            (
+                format!("{CARRIERS}44,BT\n"),
+                "Line 5: 44 does not extend a calling code",
+            ),
+            (
+                format!("{CARRIERS}3367,Orange\n"),
+                "Line 5: 3367 does not extend a calling code",
+            ),
+            (
+                format!("{CARRIERS}+4479,Three\n"),
+                "Line 5: Invalid prefix \"+4479\"",
+            ),
+            (format!("{CARRIERS}4479,\n"), "Line 5: 4479 has no carrier"),
+            (
+                format!("{CARRIERS}4479\n"),
+                "Line 5: Expected 2 fields in \"4479\"",
+            ),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                carriers::parse(&csv, &territories).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn import_carrier_resources() {
+        let territories = territories::parse(TERRITORIES).unwrap();
+        let resources = |path: &str| {
+            Ok(match path {
+                "en/44.txt" => Some("4477|O2\n447700|Vodafone, Ltd\n"),
+                "en/47.txt" => Some("# Comment\n4790|Telenor\n"),
+                "ru/47.txt" => Some("4790|Теленор\n"),
+                _ => None,
+            }
+            .map(String::from))
+        };
+        let csv = carriers::import(resources, &territories).unwrap();
+        assert_eq!(
+            "prefix,carrier\n4477,O2\n447700,Vodafone, Ltd\n4790,Telenor\n",
+            csv
+        );
+        let ranges = carriers::parse(&csv, &territories).unwrap();
+        assert_eq!("Vodafone, Ltd", ranges[2].carrier);
+    }
+
+    #[test]
+    fn sort_untranslated_names() {
+        let territories = territories::parse(&format!(
+            "{TERRITORIES}XT,,,800,,,International Freephone,International Freephone\n"
+        ))
+        .unwrap();
+        let cldr = |names: &'static str| {
+            move |locale: &str| {
+                Ok(format!(
+                    r#"{{"main": {{"{locale}": {{
+                        "identity": {{"version": {{"_cldrVersion": "46"}}}},
+                        "localeDisplayNames": {{"territories": {{{names}}}}}
+                    }}}}}}"#
+                ))
+            }
+        };
+        let (version, locales) = cldr_names::parse(
+            cldr(r#""GB": "Royaume-Uni", "GG": "Guernesey", "NO": "Norvège", "SJ": "Svalbard""#),
+            &territories,
+        )
+        .unwrap();
+        assert_eq!("46", version);
+        let fr = locales.iter().find(|names| names.locale == "fr").unwrap();
+        assert_eq!(
+            ["Royaume-Uni", "Guernesey", "Norvège", "Svalbard", ""],
+            fr.names[..]
+        );
+        // Guernesey, International Freephone, Norvège, Royaume-Uni, Svalbard
+        assert_eq!([3, 0, 2, 4, 1], fr.sort_keys[..]);
+        let en = locales.iter().find(|names| names.locale == "en").unwrap();
+        assert!(en.names.is_empty());
+        // Guernsey, International Freephone, Norway, Svalbard & Jan Mayen, United Kingdom
+        assert_eq!([4, 0, 2, 3, 1], en.sort_keys[..]);
+
+        let error = cldr_names::parse(cldr(r#""GB": "Royaume-Uni""#), &territories).unwrap_err();
+        assert_eq!("ar: No name of GG", error);
+    }
+
+    fn read_data(path: &str) -> Result<String, String> {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        std::fs::read_to_string(crate_dir.join(path)).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn data_tables_are_up_to_date() {
+        for (path, contents) in generate(read_data, None).unwrap() {
+            let committed = read_data(path).unwrap();
+            assert!(committed == contents, "{path} is not up to date");
+        }
+    }
+
+    #[test]
+    #[ignore = "needs PHONE_NUMBER_METADATA to point to libphonenumber's PhoneNumberMetadata.xml"]
+    fn generated_files_are_up_to_date() {
+        let Some(path) = std::env::var_os("PHONE_NUMBER_METADATA") else {
+            eprintln!("PHONE_NUMBER_METADATA is not set, skipping");
+            return;
+        };
+        let xml = std::fs::read_to_string(path).unwrap();
+        for (path, contents) in generate(read_data, Some(&xml)).unwrap() {
+            let committed = read_data(path).unwrap();
+            assert!(committed == contents, "{path} is not up to date");
+        }
+    }
+
+    #[test]
+    #[ignore = "needs CLDR_LOCALENAMES to point to the cldr-localenames-full/main directory"]
+    fn cldr_names_are_up_to_date() {
+        let Some(dir) = std::env::var_os("CLDR_LOCALENAMES") else {
+            eprintln!("CLDR_LOCALENAMES is not set, skipping");
+            return;
+        };
+        let read_locale = |locale: &str| {
+            let path = Path::new(&dir).join(locale).join("territories.json");
+            std::fs::read_to_string(path).map_err(|error| error.to_string())
+        };
+        let (path, contents) = generate_cldr_names(read_data, read_locale).unwrap();
+        let committed = read_data(path).unwrap();
+        assert!(committed == contents, "{path} is not up to date");
+    }
+
+    #[test]
+    #[ignore = "needs LIBPHONENUMBER_RESOURCES to point to libphonenumber's resources directory"]
+    fn imported_files_are_up_to_date() {
+        let Some(dir) = std::env::var_os("LIBPHONENUMBER_RESOURCES") else {
+            eprintln!("LIBPHONENUMBER_RESOURCES is not set, skipping");
+            return;
+        };
+        let read_resource = |resources: &str| {
+            let dir = Path::new(&dir).join(resources);
+            move |path: &str| match std::fs::read_to_string(dir.join(path)) {
+                Ok(text) => Ok(Some(text)),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(error) => Err(error.to_string()),
+            }
+        };
+        let geocoding = import_geocoding(read_data, read_resource("geocoding")).unwrap();
+        let carriers = import_carriers(read_data, read_resource("carrier")).unwrap();
+        for (path, contents) in geocoding.into_iter().chain(carriers) {
+            let committed = read_data(path).unwrap();
+            assert!(committed == contents, "{path} is not up to date");
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Derivation of the prefix lookup table, which tells the territory of a phone number from its
+//! leading digits
+//!
+//! Within a calling code shared by several territories, libphonenumber tries the territories in
+//! turn, the main one first: a territory with leading digits claims the numbers starting with
+//! them, and other territories claim the numbers matching one of their types. The table follows
+//! the same order by refining prefixes digit by digit until a single territory can claim the
+//! numbers starting with them, up to 7 digits in total. Numbers claimed by no other territory
+//! belong to the main territory.
+
+use std::collections::BTreeMap;
+
+use crate::metadata::Region;
+use crate::pattern::Pattern;
+use crate::territories::Territory;
+
+/// The longest prefix the crate looks phone numbers up with
+const MAX_PREFIX_LEN: usize = 7;
+
+/// The territory of the crate a region of the metadata belongs to, if any. Non-geographic regions
+/// belong to the pseudo territory with their calling code.
+pub fn territory_of<'a>(region: &Region, territories: &'a [Territory]) -> Option<&'a Territory> {
+    territories
+        .iter()
+        .find(|territory| match region.id.as_str() {
+            "001" => territory
+                .calling_codes
+                .iter()
+                .any(|code| code.value() == region.country_code),
+            id => territory.code == id,
+        })
+}
+
+/// The prefixes of the lookup table, including the calling code, with the codes of their
+/// territories. Fails when a territory has no prefix.
+pub fn lookup_prefixes<'a>(
+    regions: &[Region],
+    territories: &'a [Territory],
+) -> Result<Vec<(u64, &'a str)>, String> {
+    let mut by_calling_code: BTreeMap<u32, Vec<(&str, &Region)>> = BTreeMap::new();
+    for region in regions {
+        if let Some(territory) = territory_of(region, territories) {
+            let territories = by_calling_code.entry(region.country_code).or_default();
+            territories.push((territory.code.as_str(), region));
+        }
+    }
+
+    let mut prefixes = BTreeMap::new();
+    for (calling_code, mut sharing) in by_calling_code {
+        sharing.sort_by_key(|(_, region)| !region.main_country_for_code);
+        let claimants = Claimants::new(&sharing);
+        let mut prefix = String::new();
+        if calling_code == 1 {
+            // The whole area code always decides, so that unassigned ones are told apart
+            nanp_area_codes(&claimants, &mut prefix, &mut prefixes);
+            continue;
+        }
+        prefixes.insert(calling_code.into(), sharing[0].0);
+        if sharing.len() > 1 {
+            let max_len = MAX_PREFIX_LEN - calling_code.to_string().len();
+            let node = claimants.resolve(&mut prefix, max_len);
+            node.collect(&mut calling_code.to_string(), &mut prefixes);
+        }
+    }
+    for territory in territories {
+        for &prefix in &territory.prefixes {
+            prefixes.insert(prefix, territory.code.as_str());
+        }
+    }
+    for territory in territories {
+        if !prefixes.values().any(|&code| code == territory.code) {
+            return Err(format!("No lookup prefix for {}", territory.code));
+        }
+    }
+    Ok(prefixes.into_iter().collect())
+}
+
+fn nanp_area_codes<'a>(
+    claimants: &Claimants<'a>,
+    prefix: &mut String,
+    prefixes: &mut BTreeMap<u64, &'a str>,
+) {
+    if prefix.len() == 3 {
+        if let Some(&first) = claimants.claiming(prefix).first() {
+            prefixes.insert(
+                format!("1{prefix}").parse().unwrap(),
+                claimants.territories[first],
+            );
+        }
+        return;
+    }
+    for digit in '0'..='9' {
+        prefix.push(digit);
+        nanp_area_codes(claimants, prefix, prefixes);
+        prefix.pop();
+    }
+}
+
+/// The territories of a calling code, in the order libphonenumber tries them
+struct Claimants<'a> {
+    territories: Vec<&'a str>,
+    leading_digits: Vec<Option<Pattern>>,
+    /// The possible lengths and pattern of the types of each territory, without the types equal
+    /// to one of an earlier territory, as the earlier territory claims those numbers first
+    types: Vec<Vec<(Vec<u8>, Pattern)>>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Claim {
+    None,
+    Some,
+    All,
+}
+
+/// The territories of the numbers starting with a prefix
+#[derive(Debug, PartialEq)]
+enum Node<'a> {
+    /// The first territory of the calling code
+    Default,
+    Territory(&'a str),
+    /// The territories for each next digit
+    Split(Vec<Node<'a>>),
+}
+
+impl<'a> Claimants<'a> {
+    fn new(territories: &[(&'a str, &Region)]) -> Self {
+        let types = territories.iter().enumerate().map(|(i, (_, region))| {
+            let lengths = region.lengths();
+            region
+                .types
+                .iter()
+                .filter(|desc| {
+                    !territories[..i].iter().any(|(_, earlier)| {
+                        earlier.types.iter().any(|other| {
+                            other.name == desc.name
+                                && other.pattern == desc.pattern
+                                && other.lengths == desc.lengths
+                        })
+                    })
+                })
+                .map(|desc| {
+                    let lengths = match desc.lengths.is_empty() {
+                        true => lengths.clone(),
+                        false => desc.lengths.clone(),
+                    };
+                    (lengths, Pattern::new(&desc.pattern))
+                })
+                .collect()
+        });
+        Self {
+            territories: territories
+                .iter()
+                .map(|(territory, _)| *territory)
+                .collect(),
+            leading_digits: territories
+                .iter()
+                .map(|(_, region)| region.leading_digits.as_deref().map(Pattern::new))
+                .collect(),
+            types: types.collect(),
+        }
+    }
+
+    fn claim(&self, index: usize, prefix: &str) -> Claim {
+        match &self.leading_digits[index] {
+            Some(pattern) if pattern.matches_start(prefix) => Claim::All,
+            Some(pattern) if pattern.may_match_start(prefix) => Claim::Some,
+            Some(_) => Claim::None,
+            None => {
+                let matches = self.types[index].iter().any(|(lengths, pattern)| {
+                    lengths
+                        .iter()
+                        .any(|&len| pattern.matches_completion(prefix, len.into()))
+                });
+                match matches {
+                    true => Claim::Some,
+                    false => Claim::None,
+                }
+            }
+        }
+    }
+
+    /// The territories which may claim numbers starting with the prefix, in order
+    fn claiming(&self, prefix: &str) -> Vec<usize> {
+        let mut claiming = Vec::new();
+        for index in 0..self.territories.len() {
+            match self.claim(index, prefix) {
+                Claim::None => {}
+                Claim::Some => claiming.push(index),
+                Claim::All => {
+                    claiming.push(index);
+                    break;
+                }
+            }
+        }
+        claiming
+    }
+
+    fn node(&self, index: usize) -> Node<'a> {
+        match index {
+            0 => Node::Default,
+            _ => Node::Territory(self.territories[index]),
+        }
+    }
+
+    /// Finds the territories of the national significant numbers starting with a prefix. At the
+    /// longest prefix, the first territory which may claim some of the numbers is kept.
+    fn resolve(&self, prefix: &mut String, max_len: usize) -> Node<'a> {
+        match self.claiming(prefix).as_slice() {
+            [] => Node::Default,
+            [only] => self.node(*only),
+            [first, ..] if prefix.len() == max_len => self.node(*first),
+            _ => {
+                let mut children: Vec<Node> = ('0'..='9')
+                    .map(|digit| {
+                        prefix.push(digit);
+                        let node = self.resolve(prefix, max_len);
+                        prefix.pop();
+                        node
+                    })
+                    .collect();
+                let same = children.iter().all(|child| *child == children[0]);
+                match children[0] {
+                    Node::Default | Node::Territory(_) if same => children.swap_remove(0),
+                    _ => Node::Split(children),
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Node<'a> {
+    fn collect(&self, prefix: &mut String, prefixes: &mut BTreeMap<u64, &'a str>) {
+        match self {
+            Node::Default => {}
+            Node::Territory(territory) => {
+                prefixes.insert(prefix.parse().unwrap(), territory);
+            }
+            Node::Split(children) => {
+                for (digit, child) in ('0'..='9').zip(children) {
+                    prefix.push(digit);
+                    child.collect(prefix, prefixes);
+                    prefix.pop();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: metadata-gen [PhoneNumberMetadata.xml [crate directory]]
+       metadata-gen cldr-names <cldr-localenames-full/main directory> [crate directory]
+       metadata-gen geocoding <libphonenumber resources/geocoding directory> [crate directory]
+       metadata-gen carriers <libphonenumber resources/carrier directory> [crate directory]
+
+Without libphonenumber's metadata, only the tables of the data files are generated. The
+cldr-names step only generates the localized territory names, from the JSON distribution of the
+Unicode CLDR. The geocoding and carriers steps import data/geocoding.csv and data/carriers.csv
+from libphonenumber's resources, and generate their tables.";
+
+/// The steps generating files from a directory instead of libphonenumber's metadata
+const STEPS: [&str; 3] = ["cldr-names", "geocoding", "carriers"];
+
+fn main() -> ExitCode {
+    let mut args = std::env::args_os().skip(1);
+    let first = args.next();
+    if first.as_ref().is_some_and(|arg| arg == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let step = first
+        .as_ref()
+        .and_then(|arg| STEPS.iter().find(|&&step| arg == step));
+    let (step_dir, xml_path) = match step {
+        Some(&step) => {
+            let Some(dir) = args.next() else {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            };
+            (Some((step, PathBuf::from(dir))), None)
+        }
+        None => (None, first),
+    };
+    let crate_dir = args.next().map(PathBuf::from).unwrap_or_else(|| {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../..")
+            .canonicalize()
+            .unwrap()
+    });
+
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))
+    };
+    let read_data = |path: &str| read(&crate_dir.join(path));
+    let read_resource = |dir: &Path, path: &str| {
+        let path = dir.join(path);
+        if path.exists() {
+            read(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+    let files = match step_dir {
+        Some(("cldr-names", dir)) => metadata_gen::generate_cldr_names(read_data, |locale| {
+            read(&dir.join(locale).join("territories.json"))
+        })
+        .map(|file| vec![file]),
+        Some(("geocoding", dir)) => {
+            metadata_gen::import_geocoding(read_data, |path| read_resource(&dir, path))
+        }
+        Some((_, dir)) => {
+            metadata_gen::import_carriers(read_data, |path| read_resource(&dir, path))
+        }
+        None => {
+            let xml = match xml_path.map(|path| read(Path::new(&path))).transpose() {
+                Ok(xml) => xml,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            metadata_gen::generate(read_data, xml.as_deref())
+        }
+    };
+    let files = match files {
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("Invalid data: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for (path, contents) in files {
+        let path = crate_dir.join(path);
+        if let Err(error) = std::fs::write(&path, contents) {
+            eprintln!("Cannot write {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Generated {}", path.display());
+    }
+    ExitCode::SUCCESS
+}
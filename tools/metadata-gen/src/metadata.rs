@@ -0,0 +1,198 @@
+//! The parts of libphonenumber's `PhoneNumberMetadata.xml` used by the crate
+
+use roxmltree::Node;
+
+/// The names of the number type elements of a territory
+const TYPES: [&str; 10] = [
+    "fixedLine",
+    "mobile",
+    "pager",
+    "tollFree",
+    "premiumRate",
+    "sharedCost",
+    "personalNumber",
+    "voip",
+    "uan",
+    "voicemail",
+];
+
+/// A region of the metadata: a territory, or a non-geographic calling code with the id `001`
+#[derive(Debug)]
+pub struct Region {
+    pub id: String,
+    pub country_code: u32,
+    pub main_country_for_code: bool,
+    /// The pattern the start of national significant numbers of the region matches, when the
+    /// region shares its calling code
+    pub leading_digits: Option<String>,
+    pub national_prefix: Option<String>,
+    pub international_prefix: Option<String>,
+    pub preferred_international_prefix: Option<String>,
+    /// The pattern of all the national significant numbers of the region
+    pub general: String,
+    pub types: Vec<NumberDesc>,
+    pub formats: Vec<NumberFormat>,
+}
+
+/// The national significant numbers of a type
+#[derive(Debug)]
+pub struct NumberDesc {
+    /// The name of the element, e.g. `fixedLine`
+    pub name: &'static str,
+    /// The possible lengths, empty when the element does not list them
+    pub lengths: Vec<u8>,
+    pub pattern: String,
+}
+
+#[derive(Debug)]
+pub struct NumberFormat {
+    pub pattern: String,
+    pub leading_digits: Vec<String>,
+    pub format: String,
+    pub intl_format: Option<String>,
+    pub national_prefix_rule: Option<String>,
+}
+
+impl Region {
+    /// The possible lengths of all the types of the region
+    pub fn lengths(&self) -> Vec<u8> {
+        let mut lengths: Vec<u8> = self
+            .types
+            .iter()
+            .flat_map(|desc| desc.lengths.iter().copied())
+            .collect();
+        lengths.sort_unstable();
+        lengths.dedup();
+        lengths
+    }
+}
+
+/// Reads the regions of the metadata, in the order of the file
+pub fn parse(xml: &str) -> Result<Vec<Region>, String> {
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let document =
+        roxmltree::Document::parse_with_options(xml, options).map_err(|error| error.to_string())?;
+    document
+        .descendants()
+        .filter(|node| node.has_tag_name("territory"))
+        .map(region)
+        .collect()
+}
+
+fn region(node: Node) -> Result<Region, String> {
+    let id = node.attribute("id").ok_or("Territory without id")?;
+    let country_code = node
+        .attribute("countryCode")
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| format!("Invalid country code of {id}"))?;
+    let attribute = |name: &str| node.attribute(name).map(String::from);
+    let general = child(node, "generalDesc")
+        .and_then(|desc| child(desc, "nationalNumberPattern"))
+        .map(pattern_text)
+        .ok_or_else(|| format!("No general pattern for {id}"))?;
+
+    let mut types = Vec::new();
+    for name in TYPES {
+        let Some(desc) = child(node, name) else {
+            continue;
+        };
+        let lengths = match child(desc, "possibleLengths").and_then(|l| l.attribute("national")) {
+            Some(lengths) => parse_lengths(lengths).map_err(|error| format!("{id}: {error}"))?,
+            None => Vec::new(),
+        };
+        let pattern = child(desc, "nationalNumberPattern")
+            .map(pattern_text)
+            .unwrap_or_default();
+        types.push(NumberDesc {
+            name,
+            lengths,
+            pattern,
+        });
+    }
+
+    // Older versions of the metadata set the rule on the territory rather than on each format
+    let national_prefix_rule = attribute("nationalPrefixFormattingRule");
+    let formats = child(node, "availableFormats")
+        .into_iter()
+        .flat_map(|formats| formats.children())
+        .filter(|format| format.has_tag_name("numberFormat"))
+        .map(|format| NumberFormat {
+            pattern: format.attribute("pattern").unwrap_or_default().into(),
+            leading_digits: format
+                .children()
+                .filter(|leading| leading.has_tag_name("leadingDigits"))
+                .map(pattern_text)
+                .collect(),
+            format: child(format, "format")
+                .and_then(|text| text.text())
+                .unwrap_or_default()
+                .into(),
+            intl_format: child(format, "intlFormat")
+                .map(|text| text.text().unwrap_or_default().into()),
+            national_prefix_rule: format
+                .attribute("nationalPrefixFormattingRule")
+                .map(String::from)
+                .or_else(|| national_prefix_rule.clone()),
+        })
+        .collect();
+
+    Ok(Region {
+        id: id.into(),
+        country_code,
+        main_country_for_code: node.attribute("mainCountryForCode") == Some("true"),
+        leading_digits: node
+            .attribute("leadingDigits")
+            .map(|pattern| pattern.split_whitespace().collect()),
+        national_prefix: attribute("nationalPrefix"),
+        international_prefix: attribute("internationalPrefix"),
+        preferred_international_prefix: attribute("preferredInternationalPrefix"),
+        general,
+        types,
+        formats,
+    })
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(name))
+}
+
+/// The text of a pattern element, without the whitespace used to wrap long patterns
+fn pattern_text(node: Node) -> String {
+    node.descendants()
+        .filter(|text| text.is_text())
+        .flat_map(|text| text.text().unwrap_or_default().split_whitespace())
+        .collect()
+}
+
+/// Parses lengths like `[4-6],8`. The `-1` of types without numbers yields no lengths.
+fn parse_lengths(lengths: &str) -> Result<Vec<u8>, String> {
+    let mut parsed = Vec::new();
+    for part in lengths.split(',').map(str::trim) {
+        let number = |number: &str| {
+            number
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid possible lengths {lengths:?}"))
+        };
+        if part == "-1" {
+            continue;
+        }
+        match part
+            .strip_prefix('[')
+            .and_then(|part| part.strip_suffix(']'))
+        {
+            Some(range) => {
+                let (from, to) = range
+                    .split_once('-')
+                    .ok_or_else(|| format!("Invalid possible lengths {lengths:?}"))?;
+                parsed.extend(number(from)?..=number(to)?);
+            }
+            None => parsed.push(number(part)?),
+        }
+    }
+    parsed.sort_unstable();
+    parsed.dedup();
+    Ok(parsed)
+}
@@ -0,0 +1,170 @@
+//! The area codes of the North American Numbering Plan, from `data/nanp_area_codes.csv`, compiled
+//! by hand from the NPA database of the NANPA as it stood at the end of 2025
+//!
+//! Its columns are:
+//! - `npa`: the area code, i.e. the three digits after the calling code 1
+//! - `territory`: the code of the territory, empty for area codes of no territory
+//! - `regions`: the US states, Canadian provinces and territories of geographic area codes in the
+//!   United States and Canada, separated by spaces. Each is an ISO 3166-2 subdivision without the
+//!   code of its territory, e.g. `NY` for `US-NY`
+//! - `overlays`: the overlay complexes of the area code, each named after its oldest area code,
+//!   separated by spaces, empty when there is none. An overlay area code added to several areas
+//!   belongs to several complexes, e.g. `917` to those of `212` in Manhattan and `718` in the
+//!   other boroughs of New York City
+//! - `in_service`: the year the area code entered service, if known
+//! - `status`: one of [`STATUSES`]
+
+use std::collections::HashMap;
+
+use crate::subdivisions::Subdivision;
+use crate::territories::Territory;
+
+const HEADER: &str = "npa,territory,regions,overlays,in_service,status";
+
+/// The statuses of the file, with the variants of `AreaCodeStatus` in the crate
+pub const STATUSES: [(&str, &str); 7] = [
+    ("in-service", "InService"),
+    ("planned", "Planned"),
+    ("reserved", "Reserved"),
+    ("toll-free", "TollFree"),
+    ("premium", "PremiumRate"),
+    ("personal", "Personal"),
+    ("non-geographic", "NonGeographic"),
+];
+
+/// The territories whose geographic area codes are split into regions
+const TERRITORIES_WITH_REGIONS: [&str; 2] = ["US", "CA"];
+
+#[derive(Debug)]
+pub struct AreaCode {
+    pub npa: u16,
+    pub territory: Option<String>,
+    pub regions: Vec<String>,
+    pub overlays: Vec<u16>,
+    pub in_service: Option<u16>,
+    /// The variant of `AreaCodeStatus`
+    pub status: &'static str,
+}
+
+impl AreaCode {
+    fn is_geographic(&self) -> bool {
+        matches!(self.status, "InService" | "Planned")
+    }
+}
+
+/// Reads and checks the area codes, sorted by area code
+pub fn parse(
+    csv: &str,
+    territories: &[Territory],
+    subdivisions: &[Subdivision],
+) -> Result<Vec<AreaCode>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut area_codes = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let area_code = area_code(line).map_err(|error| format!("Line {}: {error}", number + 2))?;
+        area_codes.push(area_code);
+    }
+    area_codes.sort_by_key(|area_code| area_code.npa);
+    check(&area_codes, territories, subdivisions)?;
+    Ok(area_codes)
+}
+
+fn area_code(line: &str) -> Result<AreaCode, String> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let [npa, territory, regions, overlays, in_service, status] = fields[..] else {
+        return Err(format!("Expected 6 fields in {line:?}"));
+    };
+    let parse_npa = |npa: &str| match npa.parse() {
+        Ok(npa @ 200..=999) => Ok(npa),
+        _ => Err(format!("Invalid area code {npa:?}")),
+    };
+    let in_service = match in_service {
+        "" => None,
+        year => Some(year.parse().map_err(|_| format!("Invalid year {year:?}"))?),
+    };
+    let status = STATUSES
+        .iter()
+        .find(|(name, _)| *name == status)
+        .map(|&(_, variant)| variant)
+        .ok_or_else(|| format!("Invalid status {status:?}"))?;
+    Ok(AreaCode {
+        npa: parse_npa(npa)?,
+        territory: Some(territory.to_string()).filter(|territory| !territory.is_empty()),
+        regions: regions.split_whitespace().map(str::to_string).collect(),
+        overlays: overlays
+            .split_whitespace()
+            .map(parse_npa)
+            .collect::<Result<_, _>>()?,
+        in_service,
+        status,
+    })
+}
+
+/// Checks that area codes are unique and belong to a territory of calling code 1 when they are
+/// geographic, that US and Canadian geographic area codes have regions among the subdivisions, and
+/// that overlays are made of geographic area codes of a single territory
+fn check(
+    area_codes: &[AreaCode],
+    territories: &[Territory],
+    subdivisions: &[Subdivision],
+) -> Result<(), String> {
+    let by_npa: HashMap<u16, &AreaCode> = area_codes
+        .iter()
+        .map(|area_code| (area_code.npa, area_code))
+        .collect();
+    if by_npa.len() != area_codes.len() {
+        let duplicate = area_codes
+            .windows(2)
+            .find(|pair| pair[0].npa == pair[1].npa);
+        return Err(format!("Duplicate area code {}", duplicate.unwrap()[0].npa));
+    }
+    for area_code in area_codes {
+        let npa = area_code.npa;
+        if let Some(code) = &area_code.territory {
+            let nanp = territories
+                .iter()
+                .find(|territory| territory.code == *code)
+                .is_some_and(|territory| territory.calling_codes[0].value() == 1);
+            if !nanp {
+                return Err(format!(
+                    "{npa}: {code} is not a territory of calling code 1"
+                ));
+            }
+        } else if area_code.is_geographic() {
+            return Err(format!("{npa} is geographic but has no territory"));
+        }
+        let with_regions = area_code.is_geographic()
+            && TERRITORIES_WITH_REGIONS.contains(&area_code.territory.as_deref().unwrap_or(""));
+        if with_regions == area_code.regions.is_empty() {
+            return Err(format!(
+                "{npa} must have regions only when geographic in the US or Canada"
+            ));
+        }
+        let territory = area_code.territory.as_deref().unwrap_or("");
+        if let Some(region) = area_code.regions.iter().find(|region| {
+            let code = format!("{territory}-{region}");
+            !subdivisions
+                .iter()
+                .any(|subdivision| subdivision.code == code)
+        }) {
+            return Err(format!("{npa}: invalid region {region:?}"));
+        }
+        if area_code.status == "InService" && area_code.in_service.is_none() {
+            return Err(format!("{npa} is in service but has no year"));
+        }
+        for &overlay in &area_code.overlays {
+            let consistent = by_npa.get(&overlay).is_some_and(|oldest| {
+                oldest.overlays.contains(&overlay)
+                    && oldest.territory == area_code.territory
+                    && area_code.is_geographic()
+            });
+            if !consistent {
+                return Err(format!("{npa}: invalid overlay {overlay}"));
+            }
+        }
+    }
+    Ok(())
+}
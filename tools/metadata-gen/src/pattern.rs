@@ -0,0 +1,133 @@
+//! Matching of the metadata's patterns against numbers whose trailing digits are unknown
+
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+/// The largest number of digits a pattern can be matched against
+const MAX_INPUT_LEN: usize = 63;
+
+/// A parsed pattern of the metadata
+pub struct Pattern(Hir);
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Self {
+        let hir = regex_syntax::ParserBuilder::new()
+            .unicode(false)
+            .utf8(false)
+            .build()
+            .parse(pattern)
+            .unwrap_or_else(|error| panic!("Invalid pattern {pattern:?}: {error}"));
+        Self(hir)
+    }
+
+    /// Whether a number of `len` digits starting with the prefix can match the whole pattern
+    pub fn matches_completion(&self, prefix: &str, len: usize) -> bool {
+        if len < prefix.len() || len > MAX_INPUT_LEN {
+            return false;
+        }
+        let input = with_unknown_digits(prefix, len);
+        ends(&self.0, &input, 1) & 1 << len != 0
+    }
+
+    /// Whether the pattern can match the start of a number starting with the prefix
+    pub fn may_match_start(&self, prefix: &str) -> bool {
+        let input = with_unknown_digits(prefix, MAX_INPUT_LEN);
+        ends(&self.0, &input, 1) & !1 != 0
+    }
+
+    /// Whether the pattern matches the start of all the numbers starting with the prefix
+    pub fn matches_start(&self, prefix: &str) -> bool {
+        let input = with_unknown_digits(prefix, prefix.len());
+        ends(&self.0, &input, 1) & !1 != 0
+    }
+}
+
+/// The digits of the prefix followed by unknown digits, up to a length
+fn with_unknown_digits(prefix: &str, len: usize) -> Vec<Option<u8>> {
+    prefix
+        .bytes()
+        .map(Some)
+        .chain(std::iter::repeat(None))
+        .take(len)
+        .collect()
+}
+
+/// The positions reachable by matching the pattern from any of the start positions, as a bit
+/// mask. Unknown digits match any digit.
+fn ends(hir: &Hir, input: &[Option<u8>], starts: u64) -> u64 {
+    match hir.kind() {
+        HirKind::Empty => starts,
+        HirKind::Literal(literal) => literal.0.iter().fold(starts, |positions, &b| {
+            step(input, positions, |digit| digit == b)
+        }),
+        HirKind::Class(class) => step(input, starts, |digit| contains(class, digit)),
+        HirKind::Look(look) => panic!("Unsupported assertion {look:?}"),
+        HirKind::Repetition(repetition) => {
+            let mut positions = starts;
+            for _ in 0..repetition.min {
+                positions = ends(&repetition.sub, input, positions);
+            }
+            let mut all = positions;
+            let mut count = repetition.min;
+            while repetition.max != Some(count) {
+                positions = ends(&repetition.sub, input, positions);
+                if positions & !all == 0 {
+                    break;
+                }
+                all |= positions;
+                count += 1;
+            }
+            all
+        }
+        HirKind::Capture(capture) => ends(&capture.sub, input, starts),
+        HirKind::Concat(hirs) => hirs
+            .iter()
+            .fold(starts, |positions, hir| ends(hir, input, positions)),
+        HirKind::Alternation(hirs) => hirs
+            .iter()
+            .fold(0, |positions, hir| positions | ends(hir, input, starts)),
+    }
+}
+
+/// The positions reachable by matching one digit accepted by a predicate
+fn step(input: &[Option<u8>], starts: u64, accept: impl Fn(u8) -> bool) -> u64 {
+    let unknown = (b'0'..=b'9').any(&accept);
+    input
+        .iter()
+        .enumerate()
+        .filter(|&(i, digit)| starts & 1 << i != 0 && digit.map_or(unknown, &accept))
+        .fold(0, |positions, (i, _)| positions | 1 << (i + 1))
+}
+
+fn contains(class: &Class, b: u8) -> bool {
+    match class {
+        Class::Unicode(class) => class
+            .ranges()
+            .iter()
+            .any(|range| (range.start()..=range.end()).contains(&char::from(b))),
+        Class::Bytes(class) => class
+            .ranges()
+            .iter()
+            .any(|range| (range.start()..=range.end()).contains(&b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_numbers_with_unknown_digits() {
+        let pattern = Pattern::new(r"1481[25-9]\d{5}");
+        assert!(pattern.matches_completion("148", 10));
+        assert!(pattern.matches_completion("14812", 10));
+        assert!(!pattern.matches_completion("14813", 10));
+        assert!(!pattern.matches_completion("148", 9));
+
+        let pattern = Pattern::new(r"1[03-79]|[2-9]");
+        assert!(pattern.may_match_start("1"));
+        assert!(!pattern.matches_start("1"));
+        assert!(pattern.matches_start("13"));
+        assert!(pattern.matches_start("2"));
+        assert!(!pattern.may_match_start("18"));
+    }
+}
@@ -0,0 +1,43 @@
+//! The prefix files of libphonenumber's resource directories, like `resources/geocoding`, one per
+//! language and country calling code, e.g. `de/49.txt`
+//!
+//! Each line of a file is a prefix, including the calling code, and its description separated by
+//! `|`, e.g. `4930|Berlin`. Lines starting with `#` are comments.
+
+use crate::territories::Territory;
+
+/// The entries of the files of a language, for all the country calling codes of the territories,
+/// in ascending order. The file of each calling code is read with `read_resource`, from its path
+/// relative to the resource directory, and may be missing.
+pub fn entries(
+    read_resource: &impl Fn(&str) -> Result<Option<String>, String>,
+    language: &str,
+    territories: &[Territory],
+) -> Result<Vec<(String, String)>, String> {
+    let mut calling_codes: Vec<u32> = territories
+        .iter()
+        .flat_map(|territory| &territory.calling_codes)
+        .map(|code| code.country)
+        .collect();
+    calling_codes.sort_unstable();
+    calling_codes.dedup();
+    let mut entries = Vec::new();
+    for calling_code in calling_codes {
+        let path = format!("{language}/{calling_code}.txt");
+        let Some(text) = read_resource(&path)? else {
+            continue;
+        };
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = line
+                .split_once('|')
+                .filter(|(prefix, _)| prefix.starts_with(&calling_code.to_string()))
+                .ok_or_else(|| format!("{path}, line {}: Invalid entry {line:?}", number + 1))?;
+            entries.push((entry.0.into(), entry.1.into()));
+        }
+    }
+    Ok(entries)
+}
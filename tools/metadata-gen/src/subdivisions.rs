@@ -0,0 +1,77 @@
+//! The ISO 3166-2 subdivisions of the territories, from `data/subdivisions.csv`
+//!
+//! Its columns are:
+//! - `code`: the ISO 3166-2 code, made of the code of the territory, a hyphen and up to three
+//!   letters or digits, e.g. `US-NY`
+//! - `category`: the ISO 3166-2 subdivision category in lowercase, e.g. `state` or
+//!   `metropolitan department`, except for proper nouns like the German `Land`
+//! - `name`: the English name, which may contain commas
+//!
+//! It has the first level of subdivisions of every territory, and the second one for Belgium,
+//! France, Ireland, Italy and Spain only. Subdivisions which are territories of their own, like
+//! `FR-GP` for Guadeloupe or `CN-HK` for Hong Kong, are left out.
+
+use crate::territories::Territory;
+
+const HEADER: &str = "code,category,name";
+
+#[derive(Debug)]
+pub struct Subdivision {
+    pub code: String,
+    pub category: String,
+    pub name: String,
+}
+
+impl Subdivision {
+    /// The code of the territory of the subdivision
+    pub fn territory(&self) -> &str {
+        &self.code[..2]
+    }
+}
+
+/// Reads and checks the subdivisions, sorted by code
+pub fn parse(csv: &str, territories: &[Territory]) -> Result<Vec<Subdivision>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut subdivisions = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let subdivision = subdivision(line, territories)
+            .map_err(|error| format!("Line {}: {error}", number + 2))?;
+        subdivisions.push(subdivision);
+    }
+    subdivisions.sort_by(|a, b| a.code.cmp(&b.code));
+    if let Some(pair) = subdivisions
+        .windows(2)
+        .find(|pair| pair[0].code == pair[1].code)
+    {
+        return Err(format!("Duplicate subdivision {}", pair[0].code));
+    }
+    Ok(subdivisions)
+}
+
+fn subdivision(line: &str, territories: &[Territory]) -> Result<Subdivision, String> {
+    let fields: Vec<&str> = line.splitn(3, ',').collect();
+    let [code, category, name] = fields[..] else {
+        return Err(format!("Expected 3 fields in {line:?}"));
+    };
+    let valid = code.split_once('-').is_some_and(|(territory, local)| {
+        territories.iter().any(|known| known.code == territory)
+            && (1..=3).contains(&local.len())
+            && local
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    });
+    if !valid {
+        return Err(format!("Invalid subdivision code {code:?}"));
+    }
+    if category.is_empty() || name.is_empty() {
+        return Err(format!("{code} has no category or name"));
+    }
+    Ok(Subdivision {
+        code: code.into(),
+        category: category.into(),
+        name: name.into(),
+    })
+}
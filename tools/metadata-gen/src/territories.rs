@@ -0,0 +1,224 @@
+//! The territories of the crate, from `data/territories.csv`
+//!
+//! Its columns are:
+//! - `code`: the name of the territory, usually its ISO 3166-1 alpha-2 code
+//! - `alpha3` and `numeric`: the ISO 3166-1 codes, empty outside of ISO 3166-1
+//! - `calling_codes`: the calling codes separated by spaces, primary first. A `-` separates the
+//!   country calling code from the leading digits of national numbers, e.g. `44-1481`
+//! - `main`: `true` for the main territory of a country calling code shared by several ones
+//! - `prefixes`: lookup table prefixes, including the calling code, which libphonenumber's
+//!   metadata does not yield, separated by spaces
+//! - `english_name`: the English short name, without commas
+//! - `official_name`: the English official name, the short name again when there is no distinct
+//!   one
+
+use std::collections::HashSet;
+
+const HEADER: &str = "code,alpha3,numeric,calling_codes,main,prefixes,english_name,official_name";
+
+/// The most calling codes a territory can have
+const MAX_CALLING_CODES: usize = 3;
+
+/// The most digits of a calling code, as the longest prefix of the lookup table
+const MAX_CALLING_CODE_LEN: usize = 7;
+
+#[derive(Debug)]
+pub struct Territory {
+    pub code: String,
+    pub alpha3: Option<String>,
+    pub numeric: Option<u16>,
+    pub calling_codes: Vec<CallingCode>,
+    pub main: bool,
+    pub prefixes: Vec<u64>,
+    pub english_name: String,
+    pub official_name: String,
+}
+
+#[derive(Debug)]
+pub struct CallingCode {
+    /// The ITU country calling code
+    pub country: u32,
+    /// The leading digits of national numbers within the country calling code, if any
+    pub national: String,
+}
+
+impl CallingCode {
+    pub fn value(&self) -> u32 {
+        format!("{}{}", self.country, self.national)
+            .parse()
+            .unwrap()
+    }
+}
+
+impl Territory {
+    /// The calling code written by the deprecated `find_phone_cc`: the only country calling code
+    /// of all the calling codes when there are several, and the primary calling code otherwise
+    pub fn calling_code_text(&self) -> String {
+        let country = self.calling_codes[0].country;
+        if self.calling_codes.len() > 1
+            && self
+                .calling_codes
+                .iter()
+                .all(|code| code.country == country)
+        {
+            return country.to_string();
+        }
+        self.calling_codes[0].value().to_string()
+    }
+}
+
+/// Reads and checks the territories, in the order of the file
+pub fn parse(csv: &str) -> Result<Vec<Territory>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut territories = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let territory = territory(line).map_err(|error| format!("Line {}: {error}", number + 2))?;
+        territories.push(territory);
+    }
+    check(&territories)?;
+    Ok(territories)
+}
+
+fn territory(line: &str) -> Result<Territory, String> {
+    let fields: Vec<&str> = line.splitn(8, ',').collect();
+    let [code, alpha3, numeric, calling_codes, main, prefixes, english_name, official_name] =
+        fields[..]
+    else {
+        return Err(format!("Expected 8 fields in {line:?}"));
+    };
+    let is_code =
+        |code: &str, len| code.len() == len && code.bytes().all(|b| b.is_ascii_uppercase());
+    if !is_code(code, 2) {
+        return Err(format!("Invalid code {code:?}"));
+    }
+    if !alpha3.is_empty() && !is_code(alpha3, 3) {
+        return Err(format!("Invalid alpha-3 code {alpha3:?}"));
+    }
+    let numeric = match numeric {
+        "" => None,
+        numeric => Some(
+            numeric
+                .parse()
+                .map_err(|_| format!("Invalid numeric code {numeric:?}"))?,
+        ),
+    };
+    let calling_codes = calling_codes
+        .split_whitespace()
+        .map(|calling_code| {
+            let (country, national) = calling_code.split_once('-').unwrap_or((calling_code, ""));
+            let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+            match country.parse::<u32>() {
+                Ok(country @ 1..=999)
+                    if is_digits(national) && calling_code.len() <= MAX_CALLING_CODE_LEN + 1 =>
+                {
+                    Ok(CallingCode {
+                        country,
+                        national: national.into(),
+                    })
+                }
+                _ => Err(format!("Invalid calling code {calling_code:?}")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if english_name.is_empty() || official_name.is_empty() {
+        return Err(format!("{code} has no English or official name"));
+    }
+    let main = match main {
+        "" => false,
+        "true" => true,
+        main => return Err(format!("Invalid main flag {main:?}")),
+    };
+    let prefixes = prefixes
+        .split_whitespace()
+        .map(|prefix| {
+            prefix
+                .parse()
+                .map_err(|_| format!("Invalid prefix {prefix:?}"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Territory {
+        code: code.into(),
+        alpha3: Some(alpha3.to_string()).filter(|alpha3| !alpha3.is_empty()),
+        numeric,
+        calling_codes,
+        main,
+        prefixes,
+        english_name: english_name.into(),
+        official_name: official_name.into(),
+    })
+}
+
+/// Checks that codes are unique, that every territory has a calling code, and that every country
+/// calling code shared by several territories has a main territory
+fn check(territories: &[Territory]) -> Result<(), String> {
+    let mut codes = HashSet::new();
+    let mut alpha3_codes = HashSet::new();
+    let mut numeric_codes = HashSet::new();
+    for territory in territories {
+        let code = &territory.code;
+        if !codes.insert(code) {
+            return Err(format!("Duplicate territory {code}"));
+        }
+        if territory.alpha3.is_some() != territory.numeric.is_some() {
+            return Err(format!("{code} must have both ISO 3166-1 codes or none"));
+        }
+        if let Some(alpha3) = &territory.alpha3 {
+            if !alpha3_codes.insert(alpha3) {
+                return Err(format!("Duplicate alpha-3 code {alpha3}"));
+            }
+        }
+        if let Some(numeric) = territory.numeric {
+            if !numeric_codes.insert(numeric) {
+                return Err(format!("Duplicate numeric code {numeric}"));
+            }
+        }
+        if !(1..=MAX_CALLING_CODES).contains(&territory.calling_codes.len()) {
+            return Err(format!(
+                "{code} must have 1 to {MAX_CALLING_CODES} calling codes"
+            ));
+        }
+    }
+
+    let mut country_codes: Vec<u32> = territories
+        .iter()
+        .flat_map(|territory| &territory.calling_codes)
+        .map(|code| code.country)
+        .collect();
+    country_codes.sort_unstable();
+    country_codes.dedup();
+    for country_code in country_codes {
+        let sharing: Vec<&Territory> = territories
+            .iter()
+            .filter(|territory| {
+                territory
+                    .calling_codes
+                    .iter()
+                    .any(|code| code.country == country_code)
+            })
+            .collect();
+        let main = sharing.iter().filter(|territory| territory.main).count();
+        match (sharing.len(), main) {
+            (1, 0) | (2.., 1) => {}
+            _ => {
+                return Err(format!(
+                    "Calling code {country_code} needs a single main territory when shared"
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The main territory of each country calling code shared by several territories
+pub fn main_territories(territories: &[Territory]) -> Vec<(u32, &Territory)> {
+    let mut main: Vec<(u32, &Territory)> = territories
+        .iter()
+        .filter(|territory| territory.main)
+        .map(|territory| (territory.calling_codes[0].country, territory))
+        .collect();
+    main.sort_by_key(|&(country_code, _)| country_code);
+    main
+}
@@ -0,0 +1,151 @@
+//! The IANA time zones of the territories and of phone number prefixes, from
+//! `data/time_zones.csv`
+//!
+//! Its columns are:
+//! - `territory`: the code of the territory
+//! - `prefix`: empty for the time zones of the whole territory, which every territory with ISO
+//!   3166-1 codes has. Otherwise the leading digits of the international phone numbers of the
+//!   territory, including the calling code, like the prefixes of the lookup table, e.g. `1212` for
+//!   Manhattan
+//! - `time_zones`: the IANA time zone identifiers, separated by spaces, main first. Those of a
+//!   prefix are among those of its territory
+//!
+//! Every geographic NANP area code of the United States and Canada has a prefix, whose time zones
+//! are those of its state or province unless it straddles a time zone boundary.
+
+use crate::territories::Territory;
+
+const HEADER: &str = "territory,prefix,time_zones";
+
+/// The most digits of a prefix, as the longest international phone numbers
+const MAX_PREFIX_LEN: usize = 15;
+
+#[derive(Debug)]
+pub struct TimeZones {
+    pub territory: String,
+    pub prefix: Option<u64>,
+    pub time_zones: Vec<String>,
+}
+
+/// Reads and checks the time zones: those of the territories in the order of `territories`,
+/// followed by those of the prefixes sorted by prefix
+pub fn parse(csv: &str, territories: &[Territory]) -> Result<Vec<TimeZones>, String> {
+    let mut lines = csv.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(format!("The header must be {HEADER:?}"));
+    }
+    let mut rows = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let row = time_zones(line, territories)
+            .map_err(|error| format!("Line {}: {error}", number + 2))?;
+        rows.push(row);
+    }
+    let position = |code: &str| territories.iter().position(|known| known.code == code);
+    rows.sort_by_key(|row| (row.prefix, position(&row.territory)));
+    if let Some(pair) = rows.windows(2).find(|pair| {
+        pair[0].prefix == pair[1].prefix
+            && (pair[0].prefix.is_some() || pair[0].territory == pair[1].territory)
+    }) {
+        return Err(match pair[0].prefix {
+            Some(prefix) => format!("Duplicate time zones of {prefix}"),
+            None => format!("Duplicate time zones of {}", pair[0].territory),
+        });
+    }
+    check(&rows, territories)?;
+    Ok(rows)
+}
+
+fn time_zones(line: &str, territories: &[Territory]) -> Result<TimeZones, String> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let [territory, prefix, time_zones] = fields[..] else {
+        return Err(format!("Expected 3 fields in {line:?}"));
+    };
+    let Some(known) = territories.iter().find(|known| known.code == territory) else {
+        return Err(format!("Invalid territory {territory:?}"));
+    };
+    let prefix = match prefix {
+        "" => None,
+        prefix => {
+            let valid = (1..=MAX_PREFIX_LEN).contains(&prefix.len())
+                && prefix.bytes().all(|b| b.is_ascii_digit())
+                && !prefix.starts_with('0');
+            if !valid {
+                return Err(format!("Invalid prefix {prefix:?}"));
+            }
+            let extends = known.calling_codes.iter().any(|code| {
+                let code = code.value().to_string();
+                prefix.starts_with(&code) && prefix.len() > code.len()
+            });
+            if !extends {
+                return Err(format!(
+                    "{prefix} does not extend a calling code of {territory}"
+                ));
+            }
+            Some(prefix.parse().unwrap())
+        }
+    };
+    let time_zones: Vec<String> = time_zones.split(' ').map(str::to_string).collect();
+    if let Some(time_zone) = time_zones.iter().find(|time_zone| !is_time_zone(time_zone)) {
+        return Err(format!("Invalid time zone {time_zone:?}"));
+    }
+    if let Some((_, time_zone)) = time_zones
+        .iter()
+        .enumerate()
+        .find(|(index, time_zone)| time_zones[..*index].contains(time_zone))
+    {
+        return Err(format!("Duplicate time zone {time_zone}"));
+    }
+    Ok(TimeZones {
+        territory: territory.into(),
+        prefix,
+        time_zones,
+    })
+}
+
+/// Tells whether an identifier looks like an IANA time zone of a location, e.g.
+/// `America/Argentina/Buenos_Aires` or `America/Port-au-Prince`
+fn is_time_zone(time_zone: &str) -> bool {
+    let mut parts = time_zone.split('/');
+    let area = parts.next().unwrap_or("");
+    let location: Vec<&str> = parts.collect();
+    area.bytes().all(|b| b.is_ascii_alphabetic())
+        && area.starts_with(|c: char| c.is_ascii_uppercase())
+        && !location.is_empty()
+        && location.iter().all(|part| {
+            part.starts_with(|c: char| c.is_ascii_uppercase())
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_alphabetic() || b == b'_' || b == b'-')
+        })
+}
+
+/// Checks that every territory with ISO 3166-1 codes has time zones, and that the time zones of
+/// the prefixes are among those of their territories
+fn check(rows: &[TimeZones], territories: &[Territory]) -> Result<(), String> {
+    for territory in territories {
+        let time_zones = rows
+            .iter()
+            .find(|row| row.prefix.is_none() && row.territory == territory.code);
+        if territory.alpha3.is_some() && time_zones.is_none() {
+            return Err(format!("{} has no time zones", territory.code));
+        }
+    }
+    for row in rows {
+        let Some(prefix) = row.prefix else {
+            continue;
+        };
+        let territory = rows
+            .iter()
+            .find(|known| known.prefix.is_none() && known.territory == row.territory);
+        let foreign = row.time_zones.iter().find(|time_zone| {
+            !territory.is_some_and(|territory| territory.time_zones.contains(time_zone))
+        });
+        if let Some(time_zone) = foreign {
+            return Err(format!(
+                "{prefix}: {time_zone} is not a time zone of {}",
+                row.territory
+            ));
+        }
+    }
+    Ok(())
+}